@@ -5,6 +5,7 @@ use cargo_metadata::camino::Utf8PathBuf;
 
 use crate::{
     command::{docker::create_docker_command, local::create_local_command, utils::execute_command},
+    stack::check_stack_usage,
     utils::{cargo_rerun_if_changed, current_datetime},
     BuildArgs, BUILD_TARGET, HELPER_TARGET_SUBDIR,
 };
@@ -50,6 +51,11 @@ pub fn execute_build_program(
 
     print_elf_paths_cargo_directives(&target_elf_paths);
 
+    // Warn about the programs which may overflow their stack.
+    for (target_name, elf_path) in target_elf_paths.iter() {
+        check_stack_usage(target_name, elf_path, args.stack_size);
+    }
+
     Ok(target_elf_paths)
 }
 
@@ -1,8 +1,10 @@
 mod build;
 mod command;
+mod stack;
 mod utils;
 use build::build_program_internal;
 pub use build::{execute_build_program, generate_elf_paths};
+pub use stack::{FunctionStackUsage, StackUsage, DEFAULT_STACK_SIZE};
 
 use clap::Parser;
 
@@ -76,6 +78,13 @@ pub struct BuildArgs {
         default_value = DEFAULT_OUTPUT_DIR
     )]
     pub output_directory: String,
+    #[clap(
+        long,
+        action,
+        help = "Warn if the worst-case stack usage of the program exceeds this many bytes",
+        default_value_t = DEFAULT_STACK_SIZE
+    )]
+    pub stack_size: u32,
 }
 
 // Implement default args to match clap defaults.
@@ -93,6 +102,7 @@ impl Default for BuildArgs {
             output_directory: DEFAULT_OUTPUT_DIR.to_string(),
             locked: false,
             no_default_features: false,
+            stack_size: DEFAULT_STACK_SIZE,
         }
     }
 }
@@ -0,0 +1,523 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs,
+};
+
+use anyhow::{anyhow, Context, Result};
+use cargo_metadata::camino::Utf8Path;
+use elf::{
+    abi::{PF_X, PT_LOAD, STT_FUNC},
+    endian::LittleEndian,
+    ElfBytes,
+};
+use rustc_demangle::demangle;
+
+/// The default size of the stack of a program, in bytes.
+///
+/// The stack grows down from `0x0020_0400`, just below the text of the program, so a program which
+/// uses more than this silently overwrites the memory below it.
+pub const DEFAULT_STACK_SIZE: u32 = 0x0020_0000;
+
+/// The stack pointer register.
+const SP: usize = 2;
+
+/// The return address register.
+const RA: usize = 1;
+
+/// The registers which are not preserved across calls.
+const CALLER_SAVED: [usize; 16] = [1, 5, 6, 7, 10, 11, 12, 13, 14, 15, 16, 17, 28, 29, 30, 31];
+
+/// The stack usage of a function of a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionStackUsage {
+    /// The demangled name of the function.
+    pub name: String,
+    /// The address of the function.
+    pub address: u32,
+    /// The number of bytes the function allocates on the stack for its own frame.
+    pub frame: u32,
+    /// The worst-case number of bytes of stack used by the function and the functions it calls.
+    pub max: u32,
+    /// Whether `max` is only a lower bound, because the function or one of the functions it calls
+    /// recurses, makes indirect calls, or allocates frames of a dynamic size.
+    pub unbounded: bool,
+    /// The index of the callee with the deepest stack usage.
+    deepest_callee: Option<usize>,
+}
+
+/// The worst-case static stack usage of every function of a program.
+///
+/// The usage is computed from the machine code: the frame of a function is the sum of the
+/// decrements of the stack pointer in its body, and its worst case adds the worst case of the
+/// deepest function it calls.
+#[derive(Debug, Clone)]
+pub struct StackUsage {
+    /// The functions, sorted by address.
+    functions: Vec<FunctionStackUsage>,
+    /// The index of the function containing the entrypoint.
+    entry: usize,
+}
+
+impl StackUsage {
+    /// Analyzes the stack usage of the functions of a program.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the ELF is not valid, has no symbol table, or if its
+    /// entrypoint is not in a function.
+    pub fn from_elf(input: &[u8]) -> Result<Self> {
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(input)?;
+        let (symtab, strtab) =
+            elf.symbol_table()?.ok_or_else(|| anyhow!("the elf has no symbol table"))?;
+        let segments = elf.segments().ok_or_else(|| anyhow!("failed to get segments"))?;
+        let text: Vec<_> = segments
+            .iter()
+            .filter(|segment| segment.p_type == PT_LOAD && segment.p_flags & PF_X != 0)
+            .collect();
+
+        let mut functions = Vec::new();
+        for sym in symtab.iter().filter(|sym| sym.st_symtype() == STT_FUNC && sym.st_size > 0) {
+            let name = strtab.get(sym.st_name as usize)?;
+            let start: u32 = sym.st_value.try_into()?;
+            let end = start.saturating_add(sym.st_size.try_into()?);
+            let segment = text
+                .iter()
+                .find(|segment| {
+                    segment.p_vaddr <= start as u64
+                        && end as u64 <= segment.p_vaddr + segment.p_filesz
+                })
+                .ok_or_else(|| anyhow!("{name} is not in an executable segment"))?;
+            let offset = (segment.p_offset + (start as u64 - segment.p_vaddr)) as usize;
+            let code = input
+                .get(offset..offset + (end - start) as usize)
+                .ok_or_else(|| anyhow!("{name} is out of bounds"))?;
+            functions.push((
+                format!("{:#}", demangle(name)),
+                start,
+                end,
+                scan_function(start, end, code),
+            ));
+        }
+        functions.sort_by_key(|&(_, start, _, _)| start);
+        functions.dedup_by_key(|&mut (_, start, _, _)| start);
+
+        let entry: u32 = elf.ehdr.e_entry.try_into()?;
+        let entry = function_at(&functions, entry)
+            .ok_or_else(|| anyhow!("the entrypoint {entry:#010x} is not in a function"))?;
+
+        // Resolve the calls of every function.
+        let graph = functions
+            .iter()
+            .map(|(_, _, _, scan)| {
+                let callees: Option<Vec<_>> =
+                    scan.calls.iter().map(|&target| function_at(&functions, target)).collect();
+                // A call to an unknown function is as good as an indirect call.
+                let unbounded = scan.dynamic || scan.indirect || callees.is_none();
+                (callees.unwrap_or_default(), unbounded)
+            })
+            .collect::<Vec<_>>();
+
+        let mut functions: Vec<_> = functions
+            .into_iter()
+            .map(|(name, address, _, scan)| FunctionStackUsage {
+                name,
+                address,
+                frame: scan.frame,
+                max: 0,
+                unbounded: false,
+                deepest_callee: None,
+            })
+            .collect();
+        let mut visits = vec![Visit::Pending; functions.len()];
+        for index in 0..functions.len() {
+            worst_case(index, &graph, &mut functions, &mut visits);
+        }
+
+        Ok(Self { functions, entry })
+    }
+
+    /// The function containing the entrypoint of the program, whose worst case is the worst case
+    /// of the whole program.
+    #[must_use]
+    pub fn entry(&self) -> &FunctionStackUsage {
+        &self.functions[self.entry]
+    }
+
+    /// The functions of the program, sorted by address.
+    #[must_use]
+    pub fn functions(&self) -> &[FunctionStackUsage] {
+        &self.functions
+    }
+
+    /// The chain of calls from the entrypoint to the deepest function.
+    pub fn deepest_path(&self) -> impl Iterator<Item = &FunctionStackUsage> {
+        let mut next = Some(self.entry);
+        std::iter::from_fn(move || {
+            let function = &self.functions[next?];
+            next = function.deepest_callee;
+            Some(function)
+        })
+    }
+}
+
+impl Display for StackUsage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "Worst-case stack usage in bytes, with `+` marking lower bounds because of")?;
+        writeln!(f, "recursion, indirect calls, or frames of a dynamic size.")?;
+        writeln!(f)?;
+        writeln!(f, "{:>10} {:>10}  function", "max", "frame")?;
+        let mut functions: Vec<_> = self.functions.iter().collect();
+        functions.sort_by(|a, b| b.max.cmp(&a.max).then_with(|| a.name.cmp(&b.name)));
+        for function in functions {
+            let max = format!("{}{}", function.max, if function.unbounded { "+" } else { "" });
+            writeln!(f, "{:>10} {:>10}  {}", max, function.frame, function.name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Analyzes the stack usage of a built program, writes the usage of every function next to its
+/// ELF, and prints a warning if it may exceed the stack size.
+pub(crate) fn check_stack_usage(target_name: &str, elf_path: &Utf8Path, stack_size: u32) {
+    let usage = match fs::read(elf_path)
+        .with_context(|| format!("failed to read {elf_path}"))
+        .and_then(|elf| StackUsage::from_elf(&elf))
+    {
+        Ok(usage) => usage,
+        Err(err) => {
+            println!("cargo:warning=Failed to analyze the stack usage of {target_name}: {err}");
+            return;
+        }
+    };
+
+    let report_path = format!("{elf_path}.stack");
+    if let Err(err) = fs::write(&report_path, usage.to_string()) {
+        println!("cargo:warning=Failed to write {report_path}: {err}");
+    }
+
+    let entry = usage.entry();
+    if entry.max > stack_size {
+        println!(
+            "cargo:warning={target_name} may use {} bytes of stack, more than the stack size of \
+             {stack_size} bytes, through:",
+            entry.max
+        );
+        for function in usage.deepest_path() {
+            println!("cargo:warning={:>10} {}", function.max, function.name);
+        }
+        println!("cargo:warning=See {report_path} for the stack usage of every function.");
+    }
+}
+
+/// The index of the function containing `pc`.
+fn function_at(functions: &[(String, u32, u32, FunctionScan)], pc: u32) -> Option<usize> {
+    let index = functions.partition_point(|&(_, start, _, _)| start <= pc).checked_sub(1)?;
+    (pc < functions[index].2).then_some(index)
+}
+
+/// The state of a function in the traversal of the call graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    Pending,
+    InProgress,
+    Done,
+}
+
+/// Computes the worst-case stack usage of a function from the worst case of its callees.
+///
+/// A call to a function which is still in progress is a recursion, whose depth is unknown.
+fn worst_case(
+    index: usize,
+    graph: &[(Vec<usize>, bool)],
+    functions: &mut [FunctionStackUsage],
+    visits: &mut [Visit],
+) {
+    if visits[index] != Visit::Pending {
+        return;
+    }
+    visits[index] = Visit::InProgress;
+
+    let (callees, mut unbounded) = &graph[index];
+    let mut deepest: Option<(u32, usize)> = None;
+    for &callee in callees {
+        if visits[callee] == Visit::InProgress {
+            unbounded = true;
+            continue;
+        }
+        worst_case(callee, graph, functions, visits);
+        let callee_usage = &functions[callee];
+        unbounded |= callee_usage.unbounded;
+        if deepest.filter(|&(max, _)| max >= callee_usage.max).is_none() {
+            deepest = Some((callee_usage.max, callee));
+        }
+    }
+
+    let function = &mut functions[index];
+    function.max = function.frame.saturating_add(deepest.map_or(0, |(max, _)| max));
+    function.unbounded = unbounded;
+    function.deepest_callee = deepest.map(|(_, callee)| callee);
+    visits[index] = Visit::Done;
+}
+
+/// The stack frame and the calls of a function, as found in its machine code.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct FunctionScan {
+    /// The number of bytes the function allocates on the stack.
+    frame: u32,
+    /// Whether the function decrements the stack pointer by an unknown amount.
+    dynamic: bool,
+    /// Whether the function calls an unknown address.
+    indirect: bool,
+    /// The addresses called by the function, including the tail calls.
+    calls: Vec<u32>,
+}
+
+/// Scans the machine code of a function spanning `start..end`, tracking the constant values of the
+/// registers to resolve the calls through `auipc` and `jalr`, and the frames too large for an
+/// immediate.
+fn scan_function(start: u32, end: u32, code: &[u8]) -> FunctionScan {
+    let mut scanner = Scanner { start, end, regs: [None; 32], scan: FunctionScan::default() };
+    scanner.regs[0] = Some(0);
+    let mut offset = 0;
+    while offset + 2 <= code.len() {
+        let pc = start + offset as u32;
+        let half = u16::from_le_bytes([code[offset], code[offset + 1]]);
+        if half & 0b11 != 0b11 {
+            scanner.step_compressed(pc, half);
+            offset += 2;
+        } else if let Some(bytes) = code.get(offset..offset + 4) {
+            scanner.step(pc, u32::from_le_bytes(bytes.try_into().unwrap()));
+            offset += 4;
+        } else {
+            break;
+        }
+    }
+    scanner.scan
+}
+
+/// The state of the scan of a function.
+struct Scanner {
+    start: u32,
+    end: u32,
+    regs: [Option<u32>; 32],
+    scan: FunctionScan,
+}
+
+impl Scanner {
+    /// Scans a 32-bit instruction.
+    fn step(&mut self, pc: u32, insn: u32) {
+        let rd = ((insn >> 7) & 0x1f) as usize;
+        let funct3 = (insn >> 12) & 0b111;
+        let rs1 = ((insn >> 15) & 0x1f) as usize;
+        let rs2 = ((insn >> 20) & 0x1f) as usize;
+        let imm_i = (insn as i32) >> 20;
+        match insn & 0x7f {
+            // lui
+            0b0110111 => self.set(rd, Some(insn & 0xffff_f000)),
+            // auipc
+            0b0010111 => self.set(rd, Some(pc.wrapping_add(insn & 0xffff_f000))),
+            // addi, and the other immediate operations.
+            0b0010011 if rd == SP => {
+                if rs1 == SP && funct3 == 0b000 {
+                    self.adjust_sp(imm_i);
+                } else if rs1 == SP && funct3 == 0b111 && imm_i < 0 {
+                    // Aligning the stack pointer down skips up to `-imm - 1` bytes.
+                    self.adjust_sp(imm_i + 1);
+                }
+            }
+            0b0010011 => {
+                let value = (funct3 == 0b000)
+                    .then(|| self.regs[rs1].map(|value| value.wrapping_add(imm_i as u32)))
+                    .flatten();
+                self.set(rd, value);
+            }
+            // add and sub.
+            0b0110011 if rd == SP && rs1 == SP && funct3 == 0b000 => {
+                match (insn >> 25, self.regs[rs2]) {
+                    (0b0000000, Some(value)) => self.adjust_sp(value as i32),
+                    (0b0100000, Some(value)) => self.adjust_sp((value as i32).wrapping_neg()),
+                    _ => self.scan.dynamic = true,
+                }
+            }
+            // jal
+            0b1101111 => {
+                let imm = ((insn & 0x8000_0000) as i32 >> 11) as u32
+                    | (insn & 0x000f_f000)
+                    | ((insn >> 9) & 0x800)
+                    | ((insn >> 20) & 0x7fe);
+                self.jump(rd, Some(pc.wrapping_add(imm)));
+            }
+            // jalr
+            0b1100111 => {
+                let target = self.regs[rs1].map(|value| value.wrapping_add(imm_i as u32) & !1);
+                self.jump(rd, target);
+            }
+            // The loads, and the other instructions writing to a register.
+            0b0000011 | 0b0110011 | 0b1110011 | 0b0101111 if rd != SP => self.set(rd, None),
+            _ => {}
+        }
+    }
+
+    /// Scans a 16-bit compressed instruction.
+    fn step_compressed(&mut self, pc: u32, half: u16) {
+        let half = half as u32;
+        let funct3 = half >> 13;
+        let rd = ((half >> 7) & 0x1f) as usize;
+        let rs2 = ((half >> 2) & 0x1f) as usize;
+        let rd_prime = (((half >> 2) & 0b111) + 8) as usize;
+        let rs1_prime = (((half >> 7) & 0b111) + 8) as usize;
+        // The 6-bit immediate of `c.addi`, `c.li` and `c.lui`.
+        let imm6 = (((((half >> 12) & 1) << 5) | ((half >> 2) & 0x1f)) as i32) << 26 >> 26;
+        // The 12-bit offset of `c.j` and `c.jal`.
+        let imm_j = || {
+            let imm = ((half >> 1) & 0x800)
+                | ((half >> 7) & 0x10)
+                | ((half >> 1) & 0x300)
+                | ((half << 2) & 0x400)
+                | ((half >> 1) & 0x40)
+                | ((half << 1) & 0x80)
+                | ((half >> 2) & 0xe)
+                | ((half << 3) & 0x20);
+            ((imm as i32) << 20 >> 20) as u32
+        };
+        match (half & 0b11, funct3) {
+            // c.addi4spn, c.lw and c.flw.
+            (0b00, 0b000 | 0b010 | 0b011) => self.set(rd_prime, None),
+            // c.addi
+            (0b01, 0b000) if rd == SP => self.adjust_sp(imm6),
+            (0b01, 0b000) => {
+                self.set(rd, self.regs[rd].map(|value| value.wrapping_add(imm6 as u32)))
+            }
+            // c.jal
+            (0b01, 0b001) => self.jump(RA, Some(pc.wrapping_add(imm_j()))),
+            // c.li
+            (0b01, 0b010) => self.set(rd, Some(imm6 as u32)),
+            // c.addi16sp
+            (0b01, 0b011) if rd == SP => {
+                let imm = ((half >> 3) & 0x200)
+                    | ((half >> 2) & 0x10)
+                    | ((half << 1) & 0x40)
+                    | ((half << 4) & 0x180)
+                    | ((half << 3) & 0x20);
+                self.adjust_sp((imm as i32) << 22 >> 22);
+            }
+            // c.lui
+            (0b01, 0b011) => self.set(rd, Some((imm6 as u32) << 12)),
+            // The compressed arithmetic instructions.
+            (0b01, 0b100) => self.set(rs1_prime, None),
+            // c.j
+            (0b01, 0b101) => self.jump(0, Some(pc.wrapping_add(imm_j()))),
+            // c.slli and c.lwsp.
+            (0b10, 0b000 | 0b010) if rd != SP => self.set(rd, None),
+            (0b10, 0b100) => match ((half >> 12) & 1, rs2) {
+                // c.jr
+                (0, 0) => self.jump(0, self.regs[rd]),
+                // c.mv
+                (0, _) if rd != SP => self.set(rd, self.regs[rs2]),
+                // c.jalr
+                (1, 0) if rd != 0 => self.jump(RA, self.regs[rd]),
+                // c.add
+                (1, _) if rd == SP => match self.regs[rs2] {
+                    Some(value) => self.adjust_sp(value as i32),
+                    None => self.scan.dynamic = true,
+                },
+                (1, _) => self.set(rd, None),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Sets the value of a register, if known.
+    fn set(&mut self, rd: usize, value: Option<u32>) {
+        if rd != 0 {
+            self.regs[rd] = value;
+        }
+    }
+
+    /// Adds `delta` to the stack pointer, growing the frame if negative.
+    fn adjust_sp(&mut self, delta: i32) {
+        if delta < 0 {
+            self.scan.frame = self.scan.frame.saturating_add(delta.unsigned_abs());
+        }
+    }
+
+    /// Jumps to `target`, linking the return address to `rd`.
+    ///
+    /// Jumps which link are calls, as are the jumps leaving the function, which are tail calls.
+    /// Jumps to an unknown address which do not link are returns or jump tables.
+    fn jump(&mut self, rd: usize, target: Option<u32>) {
+        match target {
+            Some(target) if rd != 0 || !(self.start..self.end).contains(&target) => {
+                self.scan.calls.push(target);
+            }
+            None if rd != 0 => self.scan.indirect = true,
+            _ => {}
+        }
+        if rd != 0 {
+            for reg in CALLER_SAVED {
+                self.regs[reg] = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes the instructions of a function.
+    fn code(insns: &[u32]) -> Vec<u8> {
+        insns.iter().flat_map(|insn| insn.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_scan_frame() {
+        // addi sp, sp, -32; lui t0, 1; sub sp, sp, t0; addi sp, sp, 32; ret
+        let insns = [0xfe010113, 0x000012b7, 0x40510133, 0x02010113, 0x00008067];
+        let scan = scan_function(0x1000, 0x1014, &code(&insns));
+        assert_eq!(scan, FunctionScan { frame: 32 + 4096, ..Default::default() });
+
+        // c.addi16sp sp, -64; c.addi sp, -16; c.addi16sp sp, 64; c.jr ra
+        let halves: [u16; 4] = [0x7139, 0x1141, 0x6121, 0x8082];
+        let code: Vec<u8> = halves.iter().flat_map(|half| half.to_le_bytes()).collect();
+        let scan = scan_function(0x1000, 0x1008, &code);
+        assert_eq!(scan, FunctionScan { frame: 64 + 16, ..Default::default() });
+    }
+
+    #[test]
+    fn test_scan_calls() {
+        // jal ra, 0x100; auipc ra, 1; jalr ra, 16(ra); jalr ra, 0(a0); jal x0, -12; jal x0, 16
+        let insns = [0x100000ef, 0x00001097, 0x010080e7, 0x000500e7, 0xff5ff06f, 0x0100006f];
+        let scan = scan_function(0x1000, 0x1018, &code(&insns));
+        assert_eq!(scan.calls, vec![0x1100, 0x2014, 0x1024]);
+        assert!(scan.indirect);
+        assert!(!scan.dynamic);
+    }
+
+    #[test]
+    fn test_worst_case() {
+        // 0 calls 1 and 2, 2 calls 3, and 3 calls itself.
+        let frames = [16, 64, 32, 8];
+        let graph = vec![(vec![1, 2], false), (vec![], false), (vec![3], false), (vec![3], false)];
+        let mut functions: Vec<_> = frames
+            .iter()
+            .enumerate()
+            .map(|(index, &frame)| FunctionStackUsage {
+                name: index.to_string(),
+                address: 0,
+                frame,
+                max: 0,
+                unbounded: false,
+                deepest_callee: None,
+            })
+            .collect();
+        let mut visits = vec![Visit::Pending; functions.len()];
+        worst_case(0, &graph, &mut functions, &mut visits);
+
+        let worst_cases: Vec<_> =
+            functions.iter().map(|function| (function.max, function.unbounded)).collect();
+        assert_eq!(worst_cases, vec![(80, true), (64, false), (40, true), (8, true)]);
+        assert_eq!(functions[0].deepest_callee, Some(1));
+    }
+}
@@ -0,0 +1,53 @@
+//! The aggregator program used by `cargo prove aggregate`, which verifies a batch of compressed
+//! proofs with possibly different verification keys.
+
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sha2::{Digest, Sha256};
+
+pub fn words_to_bytes_le(words: &[u32; 8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for i in 0..8 {
+        bytes[i * 4..(i + 1) * 4].copy_from_slice(&words[i].to_le_bytes());
+    }
+    bytes
+}
+
+/// Encode a list of vkeys and committed values into a single byte array.
+///
+/// ( vkeys.len() || vkeys || committed_values[0].len as u32 || committed_values[0] || ... )
+pub fn commit_proof_pairs(vkeys: &[[u32; 8]], committed_values: &[Vec<u8>]) -> Vec<u8> {
+    let mut res = Vec::with_capacity(
+        4 + vkeys.len() * 32
+            + committed_values.len() * 4
+            + committed_values.iter().map(|vals| vals.len()).sum::<usize>(),
+    );
+
+    // Note we use big endian because abi.encodePacked in solidity does also
+    res.extend_from_slice(&(vkeys.len() as u32).to_be_bytes());
+    for vkey in vkeys.iter() {
+        res.extend_from_slice(&words_to_bytes_le(vkey));
+    }
+    for vals in committed_values.iter() {
+        res.extend_from_slice(&(vals.len() as u32).to_be_bytes());
+        res.extend_from_slice(vals);
+    }
+
+    res
+}
+
+pub fn main() {
+    // Read the verification keys and the public values of the proofs.
+    let vkeys = sp1_zkvm::io::read::<Vec<[u32; 8]>>();
+    let public_values = sp1_zkvm::io::read::<Vec<Vec<u8>>>();
+    assert_eq!(vkeys.len(), public_values.len());
+
+    // Verify the proofs, which are read from the proof input stream by the prover.
+    for (vkey, public_values) in vkeys.iter().zip(public_values.iter()) {
+        let public_values_digest = Sha256::digest(public_values);
+        sp1_zkvm::lib::verify::verify_sp1_proof(vkey, &public_values_digest.into());
+    }
+
+    sp1_zkvm::io::commit_slice(&commit_proof_pairs(&vkeys, &public_values));
+}
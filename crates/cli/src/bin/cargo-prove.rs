@@ -2,8 +2,9 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use sp1_cli::{
     commands::{
-        build::BuildCmd, build_toolchain::BuildToolchainCmd,
-        install_toolchain::InstallToolchainCmd, new::NewCmd, trace::TraceCmd, vkey::VkeyCmd,
+        aggregate::AggregateCmd, build::BuildCmd, build_toolchain::BuildToolchainCmd,
+        debug::DebugCmd, inspect::InspectCmd, install_toolchain::InstallToolchainCmd, new::NewCmd,
+        shadow::ShadowCmd, trace::TraceCmd, vkey::VkeyCmd,
     },
     SP1_VERSION_MESSAGE,
 };
@@ -29,6 +30,10 @@ pub enum ProveCliCommands {
     InstallToolchain(InstallToolchainCmd),
     Trace(TraceCmd),
     Vkey(VkeyCmd),
+    Inspect(InspectCmd),
+    Shadow(ShadowCmd),
+    Aggregate(AggregateCmd),
+    Debug(DebugCmd),
 }
 
 fn main() -> Result<()> {
@@ -41,5 +46,9 @@ fn main() -> Result<()> {
         ProveCliCommands::InstallToolchain(cmd) => cmd.run(),
         ProveCliCommands::Trace(cmd) => cmd.run(),
         ProveCliCommands::Vkey(cmd) => cmd.run(),
+        ProveCliCommands::Inspect(cmd) => cmd.run(),
+        ProveCliCommands::Shadow(cmd) => cmd.run(),
+        ProveCliCommands::Aggregate(cmd) => cmd.run(),
+        ProveCliCommands::Debug(cmd) => cmd.run(),
     }
 }
@@ -0,0 +1,191 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use sp1_build::{execute_build_program, BuildArgs};
+use sp1_sdk::{
+    HashableKey, ProverClient, SP1Proof, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey,
+};
+use sysinfo::System;
+use yansi::Paint;
+
+/// The source of the aggregator program built when no `--aggregator` ELF is given.
+const AGGREGATOR_PROGRAM: &str = include_str!("../assets/aggregator.rs");
+
+/// The `sp1-zkvm` crate of the source tree the CLI was built from.
+///
+/// The aggregator depends on it rather than on a published release, so that it verifies proofs
+/// with the recursion programs of this prover.
+const ZKVM_CRATE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../zkvm/entrypoint");
+
+#[derive(Parser)]
+#[command(
+    name = "aggregate",
+    about = "Aggregate compressed proofs saved with `SP1ProofWithPublicValues::save` into one."
+)]
+pub struct AggregateCmd {
+    /// Paths to the compressed proofs.
+    #[arg(long, num_args = 1.., required = true)]
+    proofs: Vec<PathBuf>,
+
+    /// Paths to the ELFs the proofs were generated for, either one per proof or a single one
+    /// shared by all the proofs.
+    #[arg(long, num_args = 1.., required = true)]
+    elfs: Vec<PathBuf>,
+
+    /// Path to write the aggregated proof to.
+    #[arg(long)]
+    out: PathBuf,
+
+    /// Path to the ELF of an aggregator program. Defaults to the bundled aggregator program.
+    #[arg(long)]
+    aggregator: Option<PathBuf>,
+
+    /// The maximum number of proofs aggregated by a single proof. Defaults to a size based on the
+    /// memory of the machine.
+    #[arg(long)]
+    batch_size: Option<usize>,
+}
+
+/// A proof to aggregate, with the verification key of its program.
+struct AggregationInput {
+    proof: SP1ProofWithPublicValues,
+    vk: SP1VerifyingKey,
+}
+
+/// The default number of proofs aggregated by a single proof, as the proofs of a batch are all
+/// held in memory while it is proven.
+fn default_batch_size() -> usize {
+    let total_available_mem = System::new_all().total_memory() / (1024 * 1024 * 1024);
+    match total_available_mem {
+        0..=16 => 4,
+        17..=64 => 16,
+        _ => 64,
+    }
+}
+
+/// Build the bundled aggregator program, returning its ELF.
+fn build_aggregator() -> Result<Vec<u8>> {
+    let version = env!("CARGO_PKG_VERSION");
+    let zkvm_dir = fs::canonicalize(ZKVM_CRATE_DIR).with_context(|| {
+        format!(
+            "the sp1-zkvm crate the CLI was built from is missing at {ZKVM_CRATE_DIR}, pass the \
+             ELF of an aggregator program with --aggregator"
+        )
+    })?;
+    let zkvm_dir = zkvm_dir.to_string_lossy();
+    let dir = dirs::home_dir()
+        .context("failed to get the home directory")?
+        .join(".sp1")
+        .join("aggregator")
+        .join(version);
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[workspace]\n\n[package]\nname = \"sp1-aggregator\"\nversion = \"{version}\"\n\
+             edition = \"2021\"\npublish = false\n\n[dependencies]\nsha2 = \"0.10.8\"\n\
+             sp1-zkvm = {{ path = {zkvm_dir:?}, features = [\"verify\"] }}\n"
+        ),
+    )?;
+    fs::write(dir.join("src").join("main.rs"), AGGREGATOR_PROGRAM)?;
+
+    let elf_paths = execute_build_program(&BuildArgs::default(), Some(dir))?;
+    let (_, elf_path) = elf_paths.first().context("the aggregator program has no binary")?;
+    Ok(fs::read(elf_path)?)
+}
+
+impl AggregateCmd {
+    pub fn run(&self) -> Result<()> {
+        if self.elfs.len() != 1 && self.elfs.len() != self.proofs.len() {
+            bail!("expected one ELF per proof or a single ELF, got {} ELFs", self.elfs.len());
+        }
+        let batch_size = self.batch_size.unwrap_or_else(default_batch_size);
+        if batch_size < 2 {
+            bail!("the batch size must be at least 2");
+        }
+
+        let client = ProverClient::new();
+
+        // Load the proofs, and check them against the verification keys of their programs.
+        let mut vks: HashMap<&PathBuf, SP1VerifyingKey> = HashMap::new();
+        let mut inputs = Vec::with_capacity(self.proofs.len());
+        for (i, path) in self.proofs.iter().enumerate() {
+            let elf_path = &self.elfs[if self.elfs.len() == 1 { 0 } else { i }];
+            if !vks.contains_key(elf_path) {
+                let elf = fs::read(elf_path)
+                    .with_context(|| format!("failed to read {}", elf_path.display()))?;
+                vks.insert(elf_path, client.setup(&elf).1);
+            }
+            let vk = vks[elf_path].clone();
+
+            let proof = SP1ProofWithPublicValues::load(path)
+                .with_context(|| format!("failed to load {}", path.display()))?;
+            if !matches!(proof.proof, SP1Proof::Compressed(_)) {
+                bail!("{} is not a compressed proof", path.display());
+            }
+            client.verify(&proof, &vk).with_context(|| {
+                format!("{} does not verify against {}", path.display(), elf_path.display())
+            })?;
+            inputs.push(AggregationInput { proof, vk });
+        }
+
+        let aggregator_elf = match &self.aggregator {
+            Some(path) => fs::read(path)?,
+            None => build_aggregator()?,
+        };
+        let (aggregator_pk, aggregator_vk) = client.setup(&aggregator_elf);
+
+        // Aggregate the proofs in batches, and the batches in turn, until a single proof is left.
+        // From level 1 on, the proofs of a batch are proofs of the aggregator, so the committed
+        // vkeys are the aggregator vkey and the public values are the commitments of the level
+        // below.
+        let mut level = 0;
+        loop {
+            println!(
+                "     \x1b[1m{}\x1b[0m level {level}: {} proofs in batches of {batch_size}",
+                Paint::green("Aggregating"),
+                inputs.len(),
+            );
+            inputs = inputs
+                .chunks(batch_size)
+                .map(|batch| {
+                    let mut stdin = SP1Stdin::new();
+                    let vkeys = batch.iter().map(|input| input.vk.hash_u32()).collect::<Vec<_>>();
+                    stdin.write::<Vec<[u32; 8]>>(&vkeys);
+                    let public_values = batch
+                        .iter()
+                        .map(|input| input.proof.public_values.to_vec())
+                        .collect::<Vec<_>>();
+                    stdin.write::<Vec<Vec<u8>>>(&public_values);
+                    for input in batch {
+                        let SP1Proof::Compressed(proof) = &input.proof.proof else {
+                            unreachable!()
+                        };
+                        stdin.write_proof(*proof.clone(), input.vk.vk.clone());
+                    }
+
+                    let proof = client.prove(&aggregator_pk, stdin).compressed().run()?;
+                    Ok(AggregationInput { proof, vk: aggregator_vk.clone() })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            level += 1;
+
+            if inputs.len() == 1 {
+                break;
+            }
+        }
+
+        let AggregationInput { proof, vk } = inputs.pop().unwrap();
+        proof.save(&self.out)?;
+        println!(
+            "     \x1b[1m{}\x1b[0m {} proofs to {} with aggregator vkey {}",
+            Paint::green("Aggregated"),
+            self.proofs.len(),
+            self.out.display(),
+            vk.bytes32(),
+        );
+
+        Ok(())
+    }
+}
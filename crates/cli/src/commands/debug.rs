@@ -0,0 +1,46 @@
+use std::{fs, net::TcpListener, path::PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use clap::Parser;
+use sp1_core_executor::{Executor, Program};
+use sp1_sdk::SP1Stdin;
+use sp1_stark::SP1CoreOpts;
+
+#[derive(Parser)]
+#[command(name = "debug", about = "Execute a program under the control of a GDB debugger.")]
+pub struct DebugCmd {
+    /// Path to the ELF.
+    #[arg(long, required = true)]
+    elf: PathBuf,
+
+    /// Path to the input, as a bincode encoded `SP1Stdin`. The input is empty if not set.
+    #[arg(long)]
+    stdin: Option<PathBuf>,
+
+    /// The port to wait for the debugger on.
+    #[arg(long, default_value_t = 9000)]
+    port: u16,
+}
+
+impl DebugCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf = fs::read(&self.elf).context("failed to read the elf")?;
+        let stdin: SP1Stdin = match &self.stdin {
+            Some(path) => bincode::deserialize(&fs::read(path).context("failed to read stdin")?)?,
+            None => SP1Stdin::new(),
+        };
+        let program =
+            Program::from(&elf).map_err(|err| anyhow!("failed to load the elf: {err}"))?;
+
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        stdin.write_to(&mut runtime);
+
+        let listener = TcpListener::bind(("127.0.0.1", self.port))
+            .with_context(|| format!("failed to listen on port {}", self.port))?;
+        println!("Waiting for a debugger on port {}, connect with:", self.port);
+        println!("  (gdb) file {}", self.elf.display());
+        println!("  (gdb) target remote localhost:{}", self.port);
+        runtime.run_gdb(&listener).context("the debugger connection failed")?;
+        Ok(())
+    }
+}
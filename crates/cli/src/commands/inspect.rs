@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use sp1_sdk::{inspect::inspect, SP1ProofWithPublicValues};
+
+#[derive(Parser)]
+#[command(name = "inspect", about = "Inspect a proof saved with `SP1ProofWithPublicValues::save`.")]
+pub struct InspectCmd {
+    /// Path to the proof.
+    proof: PathBuf,
+}
+
+impl InspectCmd {
+    pub fn run(&self) -> Result<()> {
+        let proof = SP1ProofWithPublicValues::load(&self.proof)?;
+        print!("{}", inspect(&proof));
+        Ok(())
+    }
+}
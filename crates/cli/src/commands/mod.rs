@@ -1,6 +1,10 @@
+pub mod aggregate;
 pub mod build;
 pub mod build_toolchain;
+pub mod debug;
+pub mod inspect;
 pub mod install_toolchain;
 pub mod new;
+pub mod shadow;
 pub mod trace;
 pub mod vkey;
@@ -0,0 +1,97 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use sp1_sdk::{
+    shadow::{compare, summarize, ExecutionSummary},
+    SP1Stdin,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "shadow",
+    about = "Execute a program and compare the execution with another build of the executor."
+)]
+pub struct ShadowCmd {
+    /// Path to the ELF.
+    #[arg(long, required = true)]
+    elf: PathBuf,
+
+    /// Path to the input, as a bincode encoded `SP1Stdin`. The input is empty if not set.
+    #[arg(long)]
+    stdin: Option<PathBuf>,
+
+    /// Write the summary of the execution as JSON to the given path.
+    #[arg(long)]
+    emit: Option<PathBuf>,
+
+    /// Compare with a summary written with `--emit` by the old build.
+    #[arg(long, conflicts_with = "baseline_bin")]
+    against: Option<PathBuf>,
+
+    /// Compare with the execution of the old build's `cargo-prove` binary.
+    #[arg(long)]
+    baseline_bin: Option<PathBuf>,
+}
+
+impl ShadowCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf = fs::read(&self.elf).context("failed to read the elf")?;
+        let stdin = match &self.stdin {
+            Some(path) => bincode::deserialize(&fs::read(path).context("failed to read stdin")?)?,
+            None => SP1Stdin::new(),
+        };
+        let summary = summarize(&elf, &stdin)?;
+        if let Some(path) = &self.emit {
+            fs::write(path, serde_json::to_string_pretty(&summary)?)?;
+        }
+
+        let baseline = if let Some(path) = &self.against {
+            Some(read_summary(path)?)
+        } else if let Some(bin) = &self.baseline_bin {
+            Some(self.run_baseline(bin)?)
+        } else {
+            None
+        };
+
+        match baseline {
+            Some(baseline) => {
+                let report = compare(&baseline, &summary)?;
+                print!("{report}");
+                if !report.is_qualified() {
+                    bail!("the executor is not qualified");
+                }
+            }
+            None if self.emit.is_none() => println!("{}", serde_json::to_string_pretty(&summary)?),
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Runs the old build on the same program and input, and reads back its summary.
+    fn run_baseline(&self, bin: &Path) -> Result<ExecutionSummary> {
+        let path = std::env::temp_dir().join(format!("sp1-shadow-{}.json", std::process::id()));
+        let mut command = Command::new(bin);
+        command.args(["prove", "shadow", "--elf"]).arg(&self.elf).arg("--emit").arg(&path);
+        if let Some(stdin) = &self.stdin {
+            command.arg("--stdin").arg(stdin);
+        }
+        let status = command.status().context("failed to run the baseline binary")?;
+        if !status.success() {
+            bail!("the baseline binary failed with {status}");
+        }
+        let summary = read_summary(&path);
+        fs::remove_file(&path).ok();
+        summary
+    }
+}
+
+fn read_summary(path: &Path) -> Result<ExecutionSummary> {
+    let summary = fs::read_to_string(path)
+        .with_context(|| format!("failed to read the summary at {}", path.display()))?;
+    Ok(serde_json::from_str(&summary)?)
+}
@@ -0,0 +1,56 @@
+use elf::{endian::LittleEndian, ElfBytes};
+
+/// The version of the syscall ABI implemented by the executor.
+///
+/// The version is bumped whenever the syscalls change in a way that a program built against the
+/// new version can't run on an older executor, e.g. a new syscall or a new layout of the arguments
+/// of an existing one. It must match `SYSCALL_ABI_VERSION` in the `sp1-zkvm` entrypoint crate.
+pub const SYSCALL_ABI_VERSION: u32 = 1;
+
+/// The name of the ELF section in which the entrypoint crate records the version of the syscall
+/// ABI a program is built against, as a little-endian `u32`.
+pub const SYSCALL_ABI_SECTION: &str = ".sp1.abi";
+
+/// Read the version of the syscall ABI recorded in an ELF, returning `None` if it has none, as is
+/// the case of the programs built before the ABI was versioned.
+pub(crate) fn decode_abi_version(elf: &ElfBytes<'_, LittleEndian>) -> eyre::Result<Option<u32>> {
+    let Some(shdr) = elf.section_header_by_name(SYSCALL_ABI_SECTION)? else {
+        return Ok(None);
+    };
+    let (data, _) = elf.section_data(&shdr)?;
+    let bytes = data
+        .get(..4)
+        .ok_or_else(|| eyre::eyre!("the {SYSCALL_ABI_SECTION} section is truncated"))?;
+    Ok(Some(u32::from_le_bytes(bytes.try_into().unwrap())))
+}
+
+/// Check that the executor implements the syscall ABI a program is built against.
+///
+/// # Errors
+///
+/// This function returns an error if the program is built against a newer version of the ABI
+/// than [`SYSCALL_ABI_VERSION`].
+pub(crate) fn check_abi_version(version: Option<u32>) -> eyre::Result<()> {
+    match version {
+        Some(version) if version > SYSCALL_ABI_VERSION => eyre::bail!(
+            "the program is built against version {version} of the syscall ABI, but this \
+             executor only supports versions up to {SYSCALL_ABI_VERSION}: upgrade SP1 to run it"
+        ),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_abi_version, SYSCALL_ABI_VERSION};
+
+    #[test]
+    fn test_check_abi_version() {
+        check_abi_version(None).unwrap();
+        check_abi_version(Some(0)).unwrap();
+        check_abi_version(Some(SYSCALL_ABI_VERSION)).unwrap();
+
+        let error = check_abi_version(Some(SYSCALL_ABI_VERSION + 1)).unwrap_err();
+        assert!(error.to_string().contains("upgrade SP1"), "{error}");
+    }
+}
@@ -0,0 +1,113 @@
+//! Support for the [`rkyv`] archives of the execution states and records, which are mapped from
+//! checkpoint files and validated in place rather than decoded from a byte stream.
+
+use enum_map::Enum;
+use rkyv::{
+    rancor::{Fallible, Source},
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    with::{ArchiveWith, DeserializeWith, SerializeWith},
+    Archive, Deserialize, Place, Serialize,
+};
+use serde::{de::DeserializeOwned, Serialize as SerdeSerialize};
+
+use crate::{events::Bn254FieldOperation, syscalls::SyscallCode, ByteOpcode, Opcode};
+
+/// An [`ArchiveWith`] wrapper archiving a field as its `bincode` encoding.
+///
+/// This is meant for the small fields whose types come from other crates, e.g. the proofs of the
+/// proof stream, which have no [`Archive`] implementation of their own.
+pub struct Bincode;
+
+/// The resolver of a field archived with [`Bincode`].
+pub struct BincodeResolver {
+    /// The length of the encoding.
+    len: usize,
+    /// The resolver of the bytes of the encoding.
+    bytes: VecResolver,
+}
+
+impl<T: SerdeSerialize> ArchiveWith<T> for Bincode {
+    type Archived = ArchivedVec<u8>;
+    type Resolver = BincodeResolver;
+
+    fn resolve_with(_: &T, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(resolver.len, resolver.bytes, out);
+    }
+}
+
+impl<T, S> SerializeWith<T, S> for Bincode
+where
+    T: SerdeSerialize,
+    S: Fallible + Allocator + Writer + ?Sized,
+    S::Error: Source,
+{
+    fn serialize_with(field: &T, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let encoding = bincode::serialize(field).map_err(S::Error::new)?;
+        let bytes = ArchivedVec::<u8>::serialize_from_slice(&encoding, serializer)?;
+        Ok(BincodeResolver { len: encoding.len(), bytes })
+    }
+}
+
+impl<T, D> DeserializeWith<ArchivedVec<u8>, T, D> for Bincode
+where
+    T: DeserializeOwned,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_with(field: &ArchivedVec<u8>, _: &mut D) -> Result<T, D::Error> {
+        bincode::deserialize(field.as_slice()).map_err(D::Error::new)
+    }
+}
+
+/// The error of an archived enum whose variant index is out of range.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid variant index {0} of an archived {1}")]
+struct InvalidVariant(usize, &'static str);
+
+/// The index of the variant of a fieldless enum, given by its [`Enum`] implementation.
+#[derive(Archive, Serialize)]
+#[rkyv(derive(Debug, Hash, PartialEq, Eq))]
+pub struct EnumIndex(u32);
+
+/// Implements [`Archive`] for fieldless enums by archiving the index of their variant as an
+/// [`ArchivedEnumIndex`].
+///
+/// The enums with explicit discriminants, e.g. the syscall codes, are archived this way so that
+/// their archives do not depend on the discriminants. The archived index is also hashable, so that
+/// the enums can key archived maps.
+macro_rules! archive_enum_index {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Archive for $ty {
+            type Archived = ArchivedEnumIndex;
+            type Resolver = ();
+
+            fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+                EnumIndex(Enum::into_usize(*self) as u32).resolve(EnumIndexResolver(()), out);
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $ty {
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D> Deserialize<$ty, D> for ArchivedEnumIndex
+        where
+            D: Fallible + ?Sized,
+            D::Error: Source,
+        {
+            fn deserialize(&self, _: &mut D) -> Result<$ty, D::Error> {
+                let index = self.0.to_native() as usize;
+                if index < <$ty as Enum>::LENGTH {
+                    Ok(<$ty as Enum>::from_usize(index))
+                } else {
+                    Err(D::Error::new(InvalidVariant(index, stringify!($ty))))
+                }
+            }
+        }
+    )*};
+}
+
+archive_enum_index!(Opcode, ByteOpcode, SyscallCode, Bn254FieldOperation);
@@ -0,0 +1,197 @@
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+use addr2line::{
+    gimli::{Dwarf, EndianArcSlice, RunTimeEndian},
+    Context,
+};
+use elf::{endian::LittleEndian, ElfBytes};
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{Instruction, Opcode, Program};
+
+/// The maximum number of calls tracked by a [`CallStack`]. Deeper calls are counted, but not
+/// reported in backtraces.
+pub const MAX_BACKTRACE_DEPTH: usize = 256;
+
+/// The register holding the return address of a call.
+const RA: u8 = 1;
+
+/// The sections of the DWARF debug info of an ELF, used to symbolicate backtraces.
+///
+/// The sections are only parsed when a backtrace is symbolicated, so that programs built with debug
+/// info don't pay for it unless they trap.
+#[derive(Default)]
+pub struct DebugInfo {
+    /// The uncompressed `.debug_*` sections, by name.
+    sections: HashMap<String, Arc<[u8]>>,
+}
+
+impl DebugInfo {
+    /// Read the debug info of an ELF, returning `None` if it has none.
+    ///
+    /// Compressed sections are skipped, since they can't be parsed as is.
+    pub(crate) fn from_elf(input: &[u8]) -> eyre::Result<Option<Self>> {
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(input)?;
+        let (Some(shdrs), Some(strtab)) = elf.section_headers_with_strtab()? else {
+            return Ok(None);
+        };
+
+        let mut sections = HashMap::new();
+        for shdr in shdrs.iter() {
+            let name = strtab.get(shdr.sh_name as usize)?;
+            if !name.starts_with(".debug_") {
+                continue;
+            }
+            let (data, compression) = elf.section_data(&shdr)?;
+            if compression.is_none() {
+                sections.insert(name.to_string(), Arc::from(data));
+            }
+        }
+
+        Ok(sections.contains_key(".debug_info").then_some(Self { sections }))
+    }
+
+    /// Parse the sections to a context resolving addresses to source locations.
+    fn context(&self) -> Result<Context<EndianArcSlice<RunTimeEndian>>, addr2line::gimli::Error> {
+        let dwarf = Dwarf::load(|id| {
+            let data = self.sections.get(id.name()).cloned().unwrap_or_else(|| Arc::from(&[][..]));
+            Ok::<_, addr2line::gimli::Error>(EndianArcSlice::new(data, RunTimeEndian::Little))
+        })?;
+        Context::from_dwarf(dwarf)
+    }
+}
+
+impl Debug for DebugInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("DebugInfo").field("sections", &self.sections.len()).finish()
+    }
+}
+
+/// The calls made by a program which have not returned yet, tracked by the executor from the jumps
+/// that link the return address register.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CallStack {
+    /// The program counters of the call instructions, from the outermost.
+    calls: Vec<u32>,
+    /// The number of calls deeper than [`MAX_BACKTRACE_DEPTH`].
+    overflow: usize,
+}
+
+impl CallStack {
+    /// Track the call or the return made by a jump instruction at `pc`.
+    #[inline]
+    pub(crate) fn step(&mut self, pc: u32, instruction: &Instruction) {
+        let is_call = instruction.op_a == RA;
+        let is_return = instruction.opcode == Opcode::JALR
+            && instruction.op_a == 0
+            && instruction.op_b == u32::from(RA);
+        if is_call {
+            if self.calls.len() < MAX_BACKTRACE_DEPTH {
+                self.calls.push(pc);
+            } else {
+                self.overflow += 1;
+            }
+        } else if is_return {
+            if self.overflow > 0 {
+                self.overflow -= 1;
+            } else {
+                self.calls.pop();
+            }
+        }
+    }
+
+    /// The number of calls which have not returned yet.
+    pub(crate) fn depth(&self) -> usize {
+        self.calls.len() + self.overflow
+    }
+
+    /// Drop the calls made since the stack was `depth` calls deep, e.g. the calls of an
+    /// unconstrained block which never returned.
+    pub(crate) fn truncate(&mut self, depth: usize) {
+        let overflow = depth.saturating_sub(MAX_BACKTRACE_DEPTH);
+        self.overflow = self.overflow.min(overflow);
+        self.calls.truncate(depth - overflow);
+    }
+
+    /// The backtrace of the program at `pc`, symbolicated with the debug info of the program if
+    /// it has some, or else with its symbols.
+    pub(crate) fn backtrace(&self, program: &Program, pc: u32) -> GuestBacktrace {
+        let context = program.debug_info.as_ref().and_then(|debug_info| debug_info.context().ok());
+
+        let mut frames = Vec::new();
+        for pc in std::iter::once(pc).chain(self.calls.iter().rev().copied()) {
+            let start = frames.len();
+            if let Some(context) = &context {
+                if let Ok(mut iter) = context.find_frames(u64::from(pc)).skip_all_loads() {
+                    while let Ok(Some(frame)) = iter.next() {
+                        let function = frame
+                            .function
+                            .and_then(|function| function.demangle().ok().map(Cow::into_owned));
+                        let (file, line) = frame.location.map_or((None, None), |location| {
+                            (location.file.map(str::to_string), location.line)
+                        });
+                        frames.push(GuestFrame { pc, function, file, line });
+                    }
+                }
+            }
+            // Without debug info, fall back to the symbol of the function containing the pc.
+            if frames.len() == start {
+                let function = program.symbol_at(pc).map(|symbol| symbol.name.clone());
+                frames.push(GuestFrame { pc, function, file: None, line: None });
+            }
+        }
+
+        GuestBacktrace { frames, truncated: self.overflow }
+    }
+}
+
+/// A frame of the backtrace of a guest program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuestFrame {
+    /// The program counter of the frame, which is the call instruction for the outer frames.
+    pub pc: u32,
+    /// The name of the function, if known.
+    pub function: Option<String>,
+    /// The source file, if the program has debug info.
+    pub file: Option<String>,
+    /// The line in the source file, if the program has debug info.
+    pub line: Option<u32>,
+}
+
+/// The backtrace of a guest program, from the innermost frame. Functions inlined into a frame are
+/// reported as frames of their own, at the same program counter.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuestBacktrace {
+    /// The frames of the backtrace.
+    pub frames: Vec<GuestFrame>,
+    /// The number of calls omitted for being deeper than [`MAX_BACKTRACE_DEPTH`], which were made
+    /// between the outer frames and the innermost one.
+    pub truncated: usize,
+}
+
+impl Display for GuestBacktrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "guest backtrace:")?;
+        let innermost =
+            self.frames.iter().take_while(|frame| frame.pc == self.frames[0].pc).count();
+        for (i, frame) in self.frames.iter().enumerate() {
+            let function = frame.function.as_deref().unwrap_or("<unknown>");
+            writeln!(f, "{i:>4}: 0x{:08x} - {function}", frame.pc)?;
+            if let Some(file) = &frame.file {
+                match frame.line {
+                    Some(line) => writeln!(f, "            at {file}:{line}")?,
+                    None => writeln!(f, "            at {file}")?,
+                }
+            }
+            if i + 1 == innermost && self.truncated > 0 {
+                writeln!(f, "      ... {} frames omitted", self.truncated)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(feature = "fs")]
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use sp1_stark::air::PublicValues;
+
+use crate::{report::ExecutionReport, state::ExecutionState};
+
+/// A snapshot of an execution taken between two batches of shards, from which it can be resumed
+/// later, possibly on another machine.
+///
+/// Unlike the [`ExecutionState`] checkpoints used to trace a batch of shards, it holds everything
+/// needed to carry on with the execution: the registers and memory image, the clocks, the cursors
+/// in the input and proof streams, the public values committed so far and the report.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExecutionCheckpoint {
+    /// The state of the execution.
+    pub state: ExecutionState,
+    /// The public values committed so far.
+    pub public_values: PublicValues<u32, u32>,
+    /// The report of the execution so far.
+    pub report: ExecutionReport,
+    /// The cycle trackers that have been entered but not exited yet.
+    pub cycle_tracker: HashMap<String, (u64, u32)>,
+    /// The lines of stdout and stderr that have not been terminated yet.
+    pub io_buf: HashMap<u32, String>,
+}
+
+#[cfg(feature = "fs")]
+impl ExecutionCheckpoint {
+    /// Save the checkpoint to a file.
+    pub fn save(&self, path: impl AsRef<Path>) -> bincode::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        bincode::serialize_into(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load a checkpoint saved with [`ExecutionCheckpoint::save`].
+    pub fn load(path: impl AsRef<Path>) -> bincode::Result<Self> {
+        bincode::deserialize_from(BufReader::new(File::open(path)?))
+    }
+}
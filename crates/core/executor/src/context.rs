@@ -1,11 +1,19 @@
 use core::mem::take;
-use std::sync::Arc;
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+use std::{ops::Range, sync::Arc};
 
 use hashbrown::HashMap;
 
+#[cfg(feature = "threads")]
+use crate::HintPrefetch;
 use crate::{
     hook::{hookify, BoxedHook, HookEnv, HookRegistry},
+    instruction_trace::{InstructionFilter, InstructionTracer, TracedInstruction},
+    output::{CycleTrackerEvent, OutputRegistry},
     subproof::SubproofVerifier,
+    watch::{WatchRegistry, WatchedWrite},
+    GasCostTable, HintReplay, MemcpySymbols,
 };
 
 /// Context to run a program inside SP1.
@@ -24,6 +32,57 @@ pub struct SP1Context<'a> {
 
     /// Skip deferred proof verification.
     pub skip_deferred_proof_verification: bool,
+
+    /// The symbols used to attribute the cycles of the software memory routines to their call
+    /// sites in the execution report.
+    ///
+    /// Note: `None` disables the tracking.
+    pub memcpy_symbols: Option<MemcpySymbols>,
+
+    /// The weights used to meter the gas used by the execution.
+    ///
+    /// Note: `None` denotes the default weights.
+    pub gas_costs: Option<GasCostTable>,
+
+    /// The path of the file to record the accesses to the input stream to.
+    ///
+    /// Note: `None` disables the recording.
+    #[cfg(feature = "fs")]
+    pub record_hints: Option<PathBuf>,
+
+    /// The recorded accesses to the input stream to replay the execution from.
+    ///
+    /// Note: `None` disables the replay.
+    pub replay_hints: Option<HintReplay>,
+
+    /// The hook calls to fetch the responses of ahead of the program, on a background thread.
+    ///
+    /// Note: `None` disables the prefetching.
+    #[cfg(feature = "threads")]
+    pub prefetch_hints: Option<HintPrefetch>,
+
+    /// The path of the trace-event file to write the profile of the execution to.
+    ///
+    /// Note: `None` disables the profiling.
+    #[cfg(feature = "fs")]
+    pub trace_events: Option<PathBuf>,
+
+    /// Whether to report the changes made by the execution to the memory of the program.
+    pub state_diff: bool,
+
+    /// Whether to profile the basic blocks and loops executed by the program.
+    pub profile_blocks: bool,
+
+    /// The breakpoints and watchpoints through which to inspect the state of the program.
+    pub watch_registry: WatchRegistry<'a>,
+
+    /// The callbacks intercepting the outputs of the program.
+    pub output_registry: OutputRegistry<'a>,
+
+    /// The sink to stream the executed instructions passing a filter to.
+    ///
+    /// Note: `None` disables the instruction tracing.
+    pub instruction_tracer: Option<InstructionTracer<'a>>,
 }
 
 /// A builder for [`SP1Context`].
@@ -34,6 +93,20 @@ pub struct SP1ContextBuilder<'a> {
     subproof_verifier: Option<Arc<dyn SubproofVerifier + 'a>>,
     max_cycles: Option<u64>,
     skip_deferred_proof_verification: bool,
+    memcpy_symbols: Option<MemcpySymbols>,
+    gas_costs: Option<GasCostTable>,
+    #[cfg(feature = "fs")]
+    record_hints: Option<PathBuf>,
+    replay_hints: Option<HintReplay>,
+    #[cfg(feature = "threads")]
+    prefetch_hints: Option<HintPrefetch>,
+    #[cfg(feature = "fs")]
+    trace_events: Option<PathBuf>,
+    state_diff: bool,
+    profile_blocks: bool,
+    watch_registry: WatchRegistry<'a>,
+    output_registry: OutputRegistry<'a>,
+    instruction_tracer: Option<InstructionTracer<'a>>,
 }
 
 impl<'a> SP1Context<'a> {
@@ -73,11 +146,39 @@ impl<'a> SP1ContextBuilder<'a> {
         let subproof_verifier = take(&mut self.subproof_verifier);
         let cycle_limit = take(&mut self.max_cycles);
         let skip_deferred_proof_verification = take(&mut self.skip_deferred_proof_verification);
+        let memcpy_symbols = take(&mut self.memcpy_symbols);
+        let gas_costs = take(&mut self.gas_costs);
+        #[cfg(feature = "fs")]
+        let record_hints = take(&mut self.record_hints);
+        let replay_hints = take(&mut self.replay_hints);
+        #[cfg(feature = "threads")]
+        let prefetch_hints = take(&mut self.prefetch_hints);
+        #[cfg(feature = "fs")]
+        let trace_events = take(&mut self.trace_events);
+        let state_diff = take(&mut self.state_diff);
+        let profile_blocks = take(&mut self.profile_blocks);
+        let watch_registry = take(&mut self.watch_registry);
+        let output_registry = take(&mut self.output_registry);
+        let instruction_tracer = take(&mut self.instruction_tracer);
         SP1Context {
             hook_registry,
             subproof_verifier,
             max_cycles: cycle_limit,
             skip_deferred_proof_verification,
+            memcpy_symbols,
+            gas_costs,
+            #[cfg(feature = "fs")]
+            record_hints,
+            replay_hints,
+            #[cfg(feature = "threads")]
+            prefetch_hints,
+            #[cfg(feature = "fs")]
+            trace_events,
+            state_diff,
+            profile_blocks,
+            watch_registry,
+            output_registry,
+            instruction_tracer,
         }
     }
 
@@ -126,6 +227,120 @@ impl<'a> SP1ContextBuilder<'a> {
         self.skip_deferred_proof_verification = skip;
         self
     }
+
+    /// Track the calls to the software memory routines, with the symbols of the program.
+    ///
+    /// The calls are reported by call site in [`crate::ExecutionReport::memcpy_call_sites`].
+    pub fn memcpy_advice(&mut self, symbols: MemcpySymbols) -> &mut Self {
+        self.memcpy_symbols = Some(symbols);
+        self
+    }
+
+    /// Set the weights used to meter the gas reported in [`crate::ExecutionReport::gas_used`].
+    pub fn gas_costs(&mut self, gas_costs: GasCostTable) -> &mut Self {
+        self.gas_costs = Some(gas_costs);
+        self
+    }
+
+    /// Record every access of the program to the input stream to a replay file at `path`, from
+    /// which the execution can be reproduced with [`Self::replay_hints`].
+    #[cfg(feature = "fs")]
+    pub fn record_hints(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.record_hints = Some(path.into());
+        self
+    }
+
+    /// Reproduce a recorded execution, serving the program the recorded inputs instead of its
+    /// stdin and the outputs of its hooks.
+    pub fn replay_hints(&mut self, replay: HintReplay) -> &mut Self {
+        self.replay_hints = Some(replay);
+        self
+    }
+
+    /// Fetch the responses of the hook calls the program is known to make on a background
+    /// thread, hiding the latency of the fetches behind the execution.
+    ///
+    /// The calls are typically those recorded by a prior run, see [`HintReplay::hook_keys`].
+    #[cfg(feature = "threads")]
+    pub fn prefetch_hints(&mut self, prefetch: HintPrefetch) -> &mut Self {
+        self.prefetch_hints = Some(prefetch);
+        self
+    }
+
+    /// Write the cycle tracker spans, the syscall invocations and the shard boundaries of the
+    /// execution to a trace-event file at `path`, which can be opened in Perfetto.
+    #[cfg(feature = "fs")]
+    pub fn trace_events(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.trace_events = Some(path.into());
+        self
+    }
+
+    /// Report the changes made by the execution to the memory of the program, by page, in
+    /// [`crate::ExecutionReport::state_diff`].
+    pub fn state_diff(&mut self) -> &mut Self {
+        self.state_diff = true;
+        self
+    }
+
+    /// Profile the basic blocks and loops executed by the program by clock cycles, in
+    /// [`crate::ExecutionReport::basic_blocks`] and [`crate::ExecutionReport::loops`], to find
+    /// the hottest code paths of the program.
+    pub fn profile_blocks(&mut self) -> &mut Self {
+        self.profile_blocks = true;
+        self
+    }
+
+    /// Add a breakpoint, calling `f` with the state of the program every time it is about to
+    /// execute the instruction at `pc`.
+    pub fn breakpoint(&mut self, pc: u32, f: impl FnMut(HookEnv) + Send + Sync + 'a) -> &mut Self {
+        self.watch_registry.breakpoint(pc, f);
+        self
+    }
+
+    /// Add a watchpoint, calling `f` with the state of the program after every write to memory
+    /// overlapping the bytes in `addrs`.
+    pub fn watchpoint(
+        &mut self,
+        addrs: Range<u32>,
+        f: impl FnMut(HookEnv, WatchedWrite) + Send + Sync + 'a,
+    ) -> &mut Self {
+        self.watch_registry.watchpoint(addrs, f);
+        self
+    }
+
+    /// Intercept the writes of the program to the file descriptor `fd` with `f`, instead of
+    /// printing them for stdout (fd 1) and stderr (fd 2), or invoking the hook of `fd`.
+    ///
+    /// The writes to stdout and stderr are passed line by line. See [`OutputRegistry`].
+    pub fn output(
+        &mut self,
+        fd: u32,
+        f: impl FnMut(HookEnv, &[u8]) + Send + Sync + 'a,
+    ) -> &mut Self {
+        self.output_registry.output(fd, f);
+        self
+    }
+
+    /// Call `f` with the events of the cycle tracker of the program.
+    pub fn cycle_tracker(
+        &mut self,
+        f: impl FnMut(HookEnv, CycleTrackerEvent) + Send + Sync + 'a,
+    ) -> &mut Self {
+        self.output_registry.cycle_tracker(f);
+        self
+    }
+
+    /// Stream the executed instructions passing `filter` to `f`, in the order of execution.
+    ///
+    /// See [`InstructionTracer`] for the instructions which are traced.
+    pub fn trace_instructions(
+        &mut self,
+        filter: InstructionFilter,
+        f: impl FnMut(TracedInstruction) + Send + Sync + 'a,
+    ) -> &mut Self {
+        self.instruction_tracer = Some(InstructionTracer::new(filter, f));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,56 @@
+//! Opcode denylists for restricted machine profiles.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Opcode;
+
+/// The word-aligned address at which a non-empty denylist is written into the memory image.
+///
+/// The denylist occupies two words, starting at this address, directly above the registers. Since
+/// the memory image is part of the preprocessed traces, the denylist is committed into the
+/// verifying key of any program it is applied to.
+pub const OPCODE_DENYLIST_ADDR: u32 = 0x20;
+
+/// A set of opcodes that a program is not allowed to contain or execute.
+///
+/// Each opcode is represented by the bit at the position of its discriminant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OpcodeDenylist(u64);
+
+impl OpcodeDenylist {
+    /// Create an empty [`OpcodeDenylist`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    /// Add an opcode to the denylist.
+    #[must_use]
+    pub const fn deny(self, opcode: Opcode) -> Self {
+        Self(self.0 | (1 << opcode as u64))
+    }
+
+    /// Whether the given opcode is denied.
+    #[must_use]
+    pub const fn is_denied(self, opcode: Opcode) -> bool {
+        self.0 & (1 << opcode as u64) != 0
+    }
+
+    /// Whether no opcode is denied.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The words written into the memory image at [`OPCODE_DENYLIST_ADDR`].
+    #[must_use]
+    pub const fn to_words(self) -> [u32; 2] {
+        [self.0 as u32, (self.0 >> 32) as u32]
+    }
+}
+
+impl FromIterator<Opcode> for OpcodeDenylist {
+    fn from_iter<I: IntoIterator<Item = Opcode>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), Self::deny)
+    }
+}
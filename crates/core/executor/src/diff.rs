@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{executor::hint_region_word, Executor, MEMORY_PAGE_BYTES};
+
+/// The number of registers, whose addresses precede the main memory.
+const NUM_REGISTERS: u32 = 32;
+
+/// A word of memory whose value was changed by an execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordDiff {
+    /// The address of the word.
+    pub addr: u32,
+    /// The value of the word before the execution.
+    pub before: u32,
+    /// The value of the word after the execution.
+    pub after: u32,
+}
+
+/// The words changed by an execution in a page of [`MEMORY_PAGE_BYTES`] bytes of memory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageDiff {
+    /// The address of the first byte of the page.
+    pub base: u32,
+    /// The words changed in the page, sorted by address.
+    pub words: Vec<WordDiff>,
+}
+
+/// The changes made by an execution to the memory of a program, i.e. the words whose value after
+/// the execution differs from their value before it.
+///
+/// The value of a word before the execution is that of the memory image of the program, or that
+/// written by the hints the program read into memory, or zero. Words written back with their
+/// previous value are not changed, and the registers are left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateDiff {
+    /// The pages with a changed word, sorted by address.
+    pub pages: Vec<PageDiff>,
+}
+
+impl StateDiff {
+    /// The changed words, sorted by address.
+    pub fn words(&self) -> impl Iterator<Item = &WordDiff> {
+        self.pages.iter().flat_map(|page| &page.words)
+    }
+
+    /// The change to the word at `addr`, if it was changed.
+    #[must_use]
+    pub fn get(&self, addr: u32) -> Option<&WordDiff> {
+        let base = page_base(addr);
+        let page = &self.pages[self.pages.binary_search_by_key(&base, |page| page.base).ok()?];
+        page.words.binary_search_by_key(&addr, |word| word.addr).ok().map(|i| &page.words[i])
+    }
+
+    /// The number of changed words.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pages.iter().map(|page| page.words.len()).sum()
+    }
+
+    /// Whether no word was changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+}
+
+/// The address of the first byte of the page containing `addr`.
+fn page_base(addr: u32) -> u32 {
+    addr - addr % MEMORY_PAGE_BYTES as u32
+}
+
+impl Executor<'_> {
+    /// The changes made so far by the execution to the memory of the program.
+    #[must_use]
+    pub fn state_diff(&self) -> StateDiff {
+        let mut words = self
+            .state
+            .memory
+            .keys()
+            .filter(|&addr| addr >= NUM_REGISTERS)
+            .filter_map(|addr| {
+                let before = self
+                    .program
+                    .memory_image
+                    .get(&addr)
+                    .or_else(|| self.state.uninitialized_memory.get(addr))
+                    .copied()
+                    .or_else(|| hint_region_word(&self.state.hint_regions, addr))
+                    .unwrap_or(0);
+                let after = self.state.memory.get(addr).unwrap().value;
+                (before != after).then_some(WordDiff { addr, before, after })
+            })
+            .collect::<Vec<_>>();
+        words.sort_unstable_by_key(|word| word.addr);
+
+        let mut pages: Vec<PageDiff> = Vec::new();
+        for word in words {
+            let base = page_base(word.addr);
+            match pages.last_mut() {
+                Some(page) if page.base == base => page.words.push(word),
+                _ => pages.push(PageDiff { base, words: vec![word] }),
+            }
+        }
+        StateDiff { pages }
+    }
+}
@@ -1,21 +1,28 @@
 use std::cmp::min;
 
 use elf::{
-    abi::{EM_RISCV, ET_EXEC, PF_X, PT_LOAD},
+    abi::{EM_RISCV, ET_EXEC, PF_X, PT_LOAD, STT_FUNC},
     endian::LittleEndian,
     file::Class,
     ElfBytes,
 };
 use hashbrown::HashMap;
+use rustc_demangle::demangle;
 use sp1_primitives::consts::{MAXIMUM_MEMORY_SIZE, WORD_SIZE};
 
+use crate::{abi::decode_abi_version, ProgramSymbol};
+
+/// The flag of the ELF header set when the program uses the compressed (C) extension.
+const EF_RISCV_RVC: u32 = 0x0001;
+
 /// RISC-V 32IM ELF (Executable and Linkable Format) File.
 ///
-/// This file represents a binary in the ELF format, specifically the RISC-V 32IM architecture
-/// with the following extensions:
+/// This file represents a binary in the ELF format, specifically the RISC-V 32IM (or 64IM)
+/// architecture with the following extensions:
 ///
 /// - Base Integer Instruction Set (I)
 /// - Integer Multiplication and Division (M)
+/// - Compressed Instructions (C), optionally
 ///
 /// This format is commonly used in embedded systems and is supported by many compilers.
 #[derive(Debug, Clone)]
@@ -28,18 +35,40 @@ pub(crate) struct Elf {
     pub(crate) pc_base: u32,
     /// The initial memory image, useful for global constants.
     pub(crate) memory_image: HashMap<u32, u32>,
+    /// Whether the program is an RV64IM program.
+    pub(crate) rv64: bool,
+    /// Whether the program uses the compressed (C) extension.
+    pub(crate) compressed: bool,
+    /// The functions of the symbol table, sorted by start address. Empty for stripped ELFs.
+    pub(crate) symbols: Vec<ProgramSymbol>,
+    /// The version of the syscall ABI the program is built against, if recorded.
+    pub(crate) abi_version: Option<u32>,
 }
 
 impl Elf {
     /// Create a new [Elf].
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) const fn new(
         instructions: Vec<u32>,
         pc_start: u32,
         pc_base: u32,
         memory_image: HashMap<u32, u32>,
+        rv64: bool,
+        compressed: bool,
+        symbols: Vec<ProgramSymbol>,
+        abi_version: Option<u32>,
     ) -> Self {
-        Self { instructions, pc_start, pc_base, memory_image }
+        Self {
+            instructions,
+            pc_start,
+            pc_base,
+            memory_image,
+            rv64,
+            compressed,
+            symbols,
+            abi_version,
+        }
     }
 
     /// Parse the ELF file into a vector of 32-bit encoded instructions and the first memory
@@ -56,10 +85,10 @@ impl Elf {
         // Parse the ELF file assuming that it is little-endian..
         let elf = ElfBytes::<LittleEndian>::minimal_parse(input)?;
 
-        // Some sanity checks to make sure that the ELF file is valid.
-        if elf.ehdr.class != Class::ELF32 {
-            eyre::bail!("must be a 32-bit elf");
-        } else if elf.ehdr.e_machine != EM_RISCV {
+        // Some sanity checks to make sure that the ELF file is valid. 64-bit ELFs are RV64IM
+        // programs, whose segments must still fit in the 32-bit address space.
+        let rv64 = elf.ehdr.class == Class::ELF64;
+        if elf.ehdr.e_machine != EM_RISCV {
             eyre::bail!("must be a riscv machine");
         } else if elf.ehdr.e_type != ET_EXEC {
             eyre::bail!("must be executable");
@@ -68,8 +97,11 @@ impl Elf {
         // Get the entrypoint of the ELF file as an u32.
         let entry: u32 = elf.ehdr.e_entry.try_into()?;
 
-        // Make sure the entrypoint is valid.
-        if entry == MAXIMUM_MEMORY_SIZE || entry % WORD_SIZE as u32 != 0 {
+        // Make sure the entrypoint is valid. With the C extension, instructions are only aligned
+        // to 2 bytes.
+        let compressed = elf.ehdr.e_flags & EF_RISCV_RVC != 0;
+        let alignment = if compressed { 2 } else { WORD_SIZE as u32 };
+        if entry == MAXIMUM_MEMORY_SIZE || entry % alignment != 0 {
             eyre::bail!("invalid entrypoint");
         }
 
@@ -143,6 +175,36 @@ impl Elf {
             }
         }
 
-        Ok(Elf::new(instructions, entry, base_address, image))
+        let symbols = Self::decode_symbols(&elf)?;
+        let abi_version = decode_abi_version(&elf)?;
+
+        Ok(Elf::new(
+            instructions,
+            entry,
+            base_address,
+            image,
+            rv64,
+            compressed,
+            symbols,
+            abi_version,
+        ))
+    }
+
+    /// Read the functions of the symbol table of the ELF, with their demangled names.
+    fn decode_symbols(elf: &ElfBytes<'_, LittleEndian>) -> eyre::Result<Vec<ProgramSymbol>> {
+        let Some((symtab, strtab)) = elf.symbol_table()? else {
+            return Ok(Vec::new());
+        };
+
+        let mut symbols = Vec::new();
+        for sym in symtab.iter().filter(|sym| sym.st_symtype() == STT_FUNC && sym.st_size > 0) {
+            let name = strtab.get(sym.st_name as usize)?;
+            let start: u32 = sym.st_value.try_into()?;
+            let end = start.saturating_add(sym.st_size.try_into()?);
+            symbols.push(ProgramSymbol { name: format!("{:#}", demangle(name)), start, end });
+        }
+        symbols.sort_by_key(|symbol| symbol.start);
+
+        Ok(symbols)
     }
 }
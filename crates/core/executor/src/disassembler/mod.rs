@@ -2,6 +2,10 @@
 
 mod elf;
 mod rrs;
+mod rv64;
+mod rvc;
 
 pub(crate) use elf::*;
 pub(crate) use rrs::*;
+pub(crate) use rv64::*;
+pub(crate) use rvc::*;
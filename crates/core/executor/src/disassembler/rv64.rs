@@ -0,0 +1,141 @@
+use rrs_lib::process_instruction;
+
+use super::InstructionTranspiler;
+use crate::{Instruction, Opcode};
+
+/// The major opcode of the load instructions.
+const OPCODE_LOAD: u32 = 0b000_0011;
+/// The major opcode of the ALU instructions with an immediate.
+const OPCODE_OP_IMM: u32 = 0b001_0011;
+/// The major opcode of the 32-bit ALU instructions with an immediate.
+const OPCODE_OP_IMM_32: u32 = 0b001_1011;
+/// The major opcode of the store instructions.
+const OPCODE_STORE: u32 = 0b010_0011;
+/// The major opcode of the 32-bit ALU instructions.
+const OPCODE_OP_32: u32 = 0b011_1011;
+
+/// Decode an instruction that only exists in RV64IM, or whose encoding is different in RV64IM.
+///
+/// Returns `None` for the instructions that are encoded as in RV32IM.
+pub(super) fn decode_rv64(insn: u32) -> Option<Instruction> {
+    let major = insn & 0x7f;
+    let rd = ((insn >> 7) & 0x1f) as u8;
+    let funct3 = (insn >> 12) & 0x7;
+    let rs1 = (insn >> 15) & 0x1f;
+    let rs2 = (insn >> 20) & 0x1f;
+    let funct7 = insn >> 25;
+    let imm_i = ((insn as i32) >> 20) as u32;
+    let imm_s = ((((insn as i32) >> 25) << 5) as u32) | ((insn >> 7) & 0x1f);
+
+    // Shifts by an immediate take a 6-bit shift amount in RV64IM.
+    let shamt = (insn >> 20) & 0x3f;
+    let funct6 = insn >> 26;
+
+    let instruction = match (major, funct3) {
+        (OPCODE_LOAD, 0b110) => Instruction::new(Opcode::LWU, rd, rs1, imm_i, false, true),
+        (OPCODE_LOAD, 0b011) => Instruction::new(Opcode::LD, rd, rs1, imm_i, false, true),
+        (OPCODE_STORE, 0b011) => Instruction::new(Opcode::SD, rs2 as u8, rs1, imm_s, false, true),
+        (OPCODE_OP_IMM, 0b001) if funct6 == 0 => {
+            Instruction::new(Opcode::SLL, rd, rs1, shamt, false, true)
+        }
+        (OPCODE_OP_IMM, 0b101) if funct6 == 0 => {
+            Instruction::new(Opcode::SRL, rd, rs1, shamt, false, true)
+        }
+        (OPCODE_OP_IMM, 0b101) if funct6 == 0b01_0000 => {
+            Instruction::new(Opcode::SRA, rd, rs1, shamt, false, true)
+        }
+        (OPCODE_OP_IMM_32, 0b000) => Instruction::new(Opcode::ADDW, rd, rs1, imm_i, false, true),
+        (OPCODE_OP_IMM_32, 0b001) if funct7 == 0 => {
+            Instruction::new(Opcode::SLLW, rd, rs1, rs2, false, true)
+        }
+        (OPCODE_OP_IMM_32, 0b101) if funct7 == 0 => {
+            Instruction::new(Opcode::SRLW, rd, rs1, rs2, false, true)
+        }
+        (OPCODE_OP_IMM_32, 0b101) if funct7 == 0b010_0000 => {
+            Instruction::new(Opcode::SRAW, rd, rs1, rs2, false, true)
+        }
+        (OPCODE_OP_32, _) => {
+            let opcode = match (funct7, funct3) {
+                (0b000_0000, 0b000) => Opcode::ADDW,
+                (0b010_0000, 0b000) => Opcode::SUBW,
+                (0b000_0000, 0b001) => Opcode::SLLW,
+                (0b000_0000, 0b101) => Opcode::SRLW,
+                (0b010_0000, 0b101) => Opcode::SRAW,
+                (0b000_0001, 0b000) => Opcode::MULW,
+                (0b000_0001, 0b100) => Opcode::DIVW,
+                (0b000_0001, 0b101) => Opcode::DIVUW,
+                (0b000_0001, 0b110) => Opcode::REMW,
+                (0b000_0001, 0b111) => Opcode::REMUW,
+                _ => return None,
+            };
+            Instruction::new(opcode, rd, rs1, rs2, false, false)
+        }
+        _ => return None,
+    };
+    Some(instruction)
+}
+
+/// Transpile the [`Instruction`]s from the 32-bit encoded instructions of an RV64IM program.
+///
+/// # Panics
+///
+/// This function will panic if the [`Instruction`] cannot be processed.
+#[must_use]
+pub(crate) fn transpile_rv64(instructions_u32: &[u32]) -> Vec<Instruction> {
+    let mut transpiler = InstructionTranspiler;
+    instructions_u32
+        .iter()
+        .map(|&instruction_u32| {
+            decode_rv64(instruction_u32)
+                .or_else(|| process_instruction(&mut transpiler, instruction_u32))
+                .unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpile_rv64() {
+        let instructions = transpile_rv64(&[
+            // ld a0, 8(a1)
+            0x0085_b503,
+            // sd a0, -8(sp)
+            0xfea1_3c23,
+            // lwu a0, 4(a1)
+            0x0045_e503,
+            // slli a0, a0, 40
+            0x0285_1513,
+            // srai a0, a0, 33
+            0x4215_5513,
+            // addiw a0, a0, -1
+            0xfff5_051b,
+            // subw a0, a1, a2
+            0x40c5_853b,
+            // remuw a0, a1, a2
+            0x02c5_f53b,
+            // add a0, a1, a2
+            0x00c5_8533,
+        ]);
+        let decoded = instructions
+            .iter()
+            .map(|i| (i.opcode, i.op_a, i.op_b, i.op_c, i.imm_c))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decoded,
+            vec![
+                (Opcode::LD, 10, 11, 8, true),
+                (Opcode::SD, 10, 2, (-8i32) as u32, true),
+                (Opcode::LWU, 10, 11, 4, true),
+                (Opcode::SLL, 10, 10, 40, true),
+                (Opcode::SRA, 10, 10, 33, true),
+                (Opcode::ADDW, 10, 10, u32::MAX, true),
+                (Opcode::SUBW, 10, 11, 12, false),
+                (Opcode::REMUW, 10, 11, 12, false),
+                (Opcode::ADD, 10, 11, 12, false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,395 @@
+use rrs_lib::process_instruction;
+
+use super::{decode_rv64, InstructionTranspiler};
+use crate::Instruction;
+
+/// The major opcode of the load instructions.
+const OPCODE_LOAD: u32 = 0b000_0011;
+/// The major opcode of the ALU instructions with an immediate.
+const OPCODE_OP_IMM: u32 = 0b001_0011;
+/// The major opcode of the 32-bit ALU instructions with an immediate.
+const OPCODE_OP_IMM_32: u32 = 0b001_1011;
+/// The major opcode of the store instructions.
+const OPCODE_STORE: u32 = 0b010_0011;
+/// The major opcode of the ALU instructions.
+const OPCODE_OP: u32 = 0b011_0011;
+/// The major opcode of the LUI instruction.
+const OPCODE_LUI: u32 = 0b011_0111;
+/// The major opcode of the 32-bit ALU instructions.
+const OPCODE_OP_32: u32 = 0b011_1011;
+/// The major opcode of the branch instructions.
+const OPCODE_BRANCH: u32 = 0b110_0011;
+/// The major opcode of the JALR instruction.
+const OPCODE_JALR: u32 = 0b110_0111;
+/// The major opcode of the JAL instruction.
+const OPCODE_JAL: u32 = 0b110_1111;
+
+/// The encoding of the EBREAK instruction.
+const EBREAK: u32 = 0x0010_0073;
+
+/// Returns the bits `hi..=lo` of a compressed instruction, shifted to start at bit `at`.
+#[inline]
+const fn bits(insn: u32, hi: u32, lo: u32, at: u32) -> u32 {
+    ((insn >> lo) & ((1 << (hi - lo + 1)) - 1)) << at
+}
+
+/// Sign-extends the lowest `width` bits of a value.
+#[inline]
+const fn sext(value: u32, width: u32) -> u32 {
+    (((value << (32 - width)) as i32) >> (32 - width)) as u32
+}
+
+const fn r_type(funct7: u32, rs2: u32, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    (funct7 << 25) | (rs2 << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+const fn i_type(imm: u32, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    ((imm & 0xfff) << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+const fn s_type(imm: u32, rs2: u32, rs1: u32, funct3: u32, opcode: u32) -> u32 {
+    (bits(imm, 11, 5, 25))
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | bits(imm, 4, 0, 7)
+        | opcode
+}
+
+const fn b_type(imm: u32, rs2: u32, rs1: u32, funct3: u32, opcode: u32) -> u32 {
+    bits(imm, 12, 12, 31)
+        | bits(imm, 10, 5, 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | bits(imm, 4, 1, 8)
+        | bits(imm, 11, 11, 7)
+        | opcode
+}
+
+const fn j_type(imm: u32, rd: u32, opcode: u32) -> u32 {
+    bits(imm, 20, 20, 31)
+        | bits(imm, 10, 1, 21)
+        | bits(imm, 11, 11, 20)
+        | bits(imm, 19, 12, 12)
+        | (rd << 7)
+        | opcode
+}
+
+/// Expand a compressed instruction into the 32-bit instruction it stands for.
+///
+/// Returns `None` for the illegal, reserved and floating-point compressed instructions.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn expand_compressed(insn: u16, rv64: bool) -> Option<u32> {
+    let c = u32::from(insn);
+    let funct3 = c >> 13;
+
+    // The full register fields, and the 3-bit fields which address the registers x8 to x15.
+    let rd = bits(c, 11, 7, 0);
+    let rs2 = bits(c, 6, 2, 0);
+    let rd_short = 8 + bits(c, 4, 2, 0);
+    let rs1_short = 8 + bits(c, 9, 7, 0);
+
+    // The immediates shared by several instructions.
+    let imm6 = sext(bits(c, 12, 12, 5) | bits(c, 6, 2, 0), 6);
+    let shamt = bits(c, 12, 12, 5) | bits(c, 6, 2, 0);
+    let offset_w = bits(c, 12, 10, 3) | bits(c, 6, 6, 2) | bits(c, 5, 5, 6);
+    let offset_d = bits(c, 12, 10, 3) | bits(c, 6, 5, 6);
+    let offset_j = sext(
+        bits(c, 12, 12, 11)
+            | bits(c, 11, 11, 4)
+            | bits(c, 10, 9, 8)
+            | bits(c, 8, 8, 10)
+            | bits(c, 7, 7, 6)
+            | bits(c, 6, 6, 7)
+            | bits(c, 5, 3, 1)
+            | bits(c, 2, 2, 5),
+        12,
+    );
+
+    let expanded = match (c & 0b11, funct3) {
+        // C.ADDI4SPN
+        (0b00, 0b000) => {
+            let imm = bits(c, 12, 11, 4) | bits(c, 10, 7, 6) | bits(c, 6, 6, 2) | bits(c, 5, 5, 3);
+            if imm == 0 {
+                return None;
+            }
+            i_type(imm, 2, 0b000, rd_short, OPCODE_OP_IMM)
+        }
+        // C.LW
+        (0b00, 0b010) => i_type(offset_w, rs1_short, 0b010, rd_short, OPCODE_LOAD),
+        // C.LD
+        (0b00, 0b011) if rv64 => i_type(offset_d, rs1_short, 0b011, rd_short, OPCODE_LOAD),
+        // C.SW
+        (0b00, 0b110) => s_type(offset_w, rd_short, rs1_short, 0b010, OPCODE_STORE),
+        // C.SD
+        (0b00, 0b111) if rv64 => s_type(offset_d, rd_short, rs1_short, 0b011, OPCODE_STORE),
+
+        // C.ADDI and C.NOP
+        (0b01, 0b000) => i_type(imm6, rd, 0b000, rd, OPCODE_OP_IMM),
+        // C.ADDIW
+        (0b01, 0b001) if rv64 => {
+            if rd == 0 {
+                return None;
+            }
+            i_type(imm6, rd, 0b000, rd, OPCODE_OP_IMM_32)
+        }
+        // C.JAL
+        (0b01, 0b001) => j_type(offset_j, 1, OPCODE_JAL),
+        // C.LI
+        (0b01, 0b010) => i_type(imm6, 0, 0b000, rd, OPCODE_OP_IMM),
+        // C.ADDI16SP
+        (0b01, 0b011) if rd == 2 => {
+            let imm = sext(
+                bits(c, 12, 12, 9)
+                    | bits(c, 6, 6, 4)
+                    | bits(c, 5, 5, 6)
+                    | bits(c, 4, 3, 7)
+                    | bits(c, 2, 2, 5),
+                10,
+            );
+            if imm == 0 {
+                return None;
+            }
+            i_type(imm, 2, 0b000, 2, OPCODE_OP_IMM)
+        }
+        // C.LUI
+        (0b01, 0b011) => {
+            if imm6 == 0 {
+                return None;
+            }
+            (imm6 << 12) | (rd << 7) | OPCODE_LUI
+        }
+        (0b01, 0b100) => match (bits(c, 11, 10, 0), bits(c, 12, 12, 0), bits(c, 6, 5, 0)) {
+            // Shift amounts of 32 or more only exist in RV64C.
+            (0b00 | 0b01, _, _) if !rv64 && shamt >= 32 => return None,
+            // C.SRLI
+            (0b00, _, _) => i_type(shamt, rs1_short, 0b101, rs1_short, OPCODE_OP_IMM),
+            // C.SRAI
+            (0b01, _, _) => i_type(0x400 | shamt, rs1_short, 0b101, rs1_short, OPCODE_OP_IMM),
+            // C.ANDI
+            (0b10, _, _) => i_type(imm6, rs1_short, 0b111, rs1_short, OPCODE_OP_IMM),
+            // C.SUB, C.XOR, C.OR and C.AND
+            (0b11, 0, op) => {
+                let (funct7, funct3) =
+                    [(0b010_0000, 0b000), (0, 0b100), (0, 0b110), (0, 0b111)][op as usize];
+                r_type(funct7, rd_short, rs1_short, funct3, rs1_short, OPCODE_OP)
+            }
+            // C.SUBW
+            (0b11, 1, 0b00) if rv64 => {
+                r_type(0b010_0000, rd_short, rs1_short, 0b000, rs1_short, OPCODE_OP_32)
+            }
+            // C.ADDW
+            (0b11, 1, 0b01) if rv64 => {
+                r_type(0, rd_short, rs1_short, 0b000, rs1_short, OPCODE_OP_32)
+            }
+            _ => return None,
+        },
+        // C.J
+        (0b01, 0b101) => j_type(offset_j, 0, OPCODE_JAL),
+        // C.BEQZ and C.BNEZ
+        (0b01, 0b110 | 0b111) => {
+            let imm = sext(
+                bits(c, 12, 12, 8)
+                    | bits(c, 11, 10, 3)
+                    | bits(c, 6, 5, 6)
+                    | bits(c, 4, 3, 1)
+                    | bits(c, 2, 2, 5),
+                9,
+            );
+            b_type(imm, 0, rs1_short, funct3 & 1, OPCODE_BRANCH)
+        }
+
+        // C.SLLI
+        (0b10, 0b000) if !rv64 && shamt >= 32 => return None,
+        (0b10, 0b000) => i_type(shamt, rd, 0b001, rd, OPCODE_OP_IMM),
+        // C.LWSP
+        (0b10, 0b010) => {
+            if rd == 0 {
+                return None;
+            }
+            let imm = bits(c, 12, 12, 5) | bits(c, 6, 4, 2) | bits(c, 3, 2, 6);
+            i_type(imm, 2, 0b010, rd, OPCODE_LOAD)
+        }
+        // C.LDSP
+        (0b10, 0b011) if rv64 => {
+            if rd == 0 {
+                return None;
+            }
+            let imm = bits(c, 12, 12, 5) | bits(c, 6, 5, 3) | bits(c, 4, 2, 6);
+            i_type(imm, 2, 0b011, rd, OPCODE_LOAD)
+        }
+        (0b10, 0b100) => match (bits(c, 12, 12, 0), rd, rs2) {
+            // C.JR
+            (0, 1.., 0) => i_type(0, rd, 0b000, 0, OPCODE_JALR),
+            // C.MV
+            (0, _, 1..) => r_type(0, rs2, 0, 0b000, rd, OPCODE_OP),
+            // C.EBREAK
+            (1, 0, 0) => EBREAK,
+            // C.JALR
+            (1, _, 0) => i_type(0, rd, 0b000, 1, OPCODE_JALR),
+            // C.ADD
+            (1, _, _) => r_type(0, rs2, rd, 0b000, rd, OPCODE_OP),
+            _ => return None,
+        },
+        // C.SWSP
+        (0b10, 0b110) => {
+            let imm = bits(c, 12, 9, 2) | bits(c, 8, 7, 6);
+            s_type(imm, rs2, 2, 0b010, OPCODE_STORE)
+        }
+        // C.SDSP
+        (0b10, 0b111) if rv64 => {
+            let imm = bits(c, 12, 10, 3) | bits(c, 9, 7, 6);
+            s_type(imm, rs2, 2, 0b011, OPCODE_STORE)
+        }
+
+        _ => return None,
+    };
+    Some(expanded)
+}
+
+/// Transpile the [`Instruction`]s of a program that uses the compressed (C) extension.
+///
+/// Since instructions may start at any 2-byte boundary, one [`Instruction`] is transpiled for
+/// every halfword of the code, along with whether it is compressed. The halfwords which do not
+/// start a valid instruction, such as the upper halves of 32-bit instructions, are transpiled to
+/// [`Instruction::unimp`].
+#[must_use]
+pub(crate) fn transpile_compressed(
+    instructions_u32: &[u32],
+    rv64: bool,
+) -> (Vec<Instruction>, Vec<bool>) {
+    let halfwords = instructions_u32
+        .iter()
+        .flat_map(|&word| [word as u16, (word >> 16) as u16])
+        .collect::<Vec<_>>();
+
+    let mut transpiler = InstructionTranspiler;
+    let mut decode = |insn: u32| {
+        let instruction = if rv64 { decode_rv64(insn) } else { None };
+        instruction
+            .or_else(|| process_instruction(&mut transpiler, insn))
+            .unwrap_or_else(Instruction::unimp)
+    };
+
+    let mut instructions = Vec::with_capacity(halfwords.len());
+    let mut compressed = Vec::with_capacity(halfwords.len());
+    for (i, &halfword) in halfwords.iter().enumerate() {
+        if halfword & 0b11 == 0b11 {
+            let instruction = match halfwords.get(i + 1) {
+                Some(&hi) => decode(u32::from(halfword) | (u32::from(hi) << 16)),
+                None => Instruction::unimp(),
+            };
+            instructions.push(instruction);
+            compressed.push(false);
+        } else {
+            let instruction =
+                expand_compressed(halfword, rv64).map_or_else(Instruction::unimp, &mut decode);
+            instructions.push(instruction);
+            compressed.push(true);
+        }
+    }
+    (instructions, compressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Opcode;
+
+    #[test]
+    fn test_expand_compressed() {
+        let cases: &[(u16, bool, u32)] = &[
+            // c.addi4spn a0, sp, 16 -> addi a0, sp, 16
+            (0x0808, false, 0x0101_0513),
+            // c.lw a0, 4(a1) -> lw a0, 4(a1)
+            (0x41c8, false, 0x0045_a503),
+            // c.sw a0, 8(a1) -> sw a0, 8(a1)
+            (0xc588, false, 0x00a5_a423),
+            // c.addi a0, -1 -> addi a0, a0, -1
+            (0x157d, false, 0xfff5_0513),
+            // c.jal 8 -> jal ra, 8
+            (0x2021, false, 0x0080_00ef),
+            // c.addiw a0, 1 -> addiw a0, a0, 1
+            (0x2505, true, 0x0015_051b),
+            // c.li a0, 5 -> addi a0, zero, 5
+            (0x4515, false, 0x0050_0513),
+            // c.addi16sp sp, -32 -> addi sp, sp, -32
+            (0x713d, false, 0xfe01_0113),
+            // c.lui a0, 1 -> lui a0, 1
+            (0x6505, false, 0x0000_1537),
+            // c.srli a0, 1 -> srli a0, a0, 1
+            (0x8105, false, 0x0015_5513),
+            // c.srai a0, 1 -> srai a0, a0, 1
+            (0x8505, false, 0x4015_5513),
+            // c.andi a0, 3 -> andi a0, a0, 3
+            (0x890d, false, 0x0035_7513),
+            // c.sub a0, a1 -> sub a0, a0, a1
+            (0x8d0d, false, 0x40b5_0533),
+            // c.and a0, a1 -> and a0, a0, a1
+            (0x8d6d, false, 0x00b5_7533),
+            // c.j -2 -> jal zero, -2
+            (0xbffd, false, 0xffff_f06f),
+            // c.beqz a0, 4 -> beq a0, zero, 4
+            (0xc111, false, 0x0005_0263),
+            // c.bnez a0, -4 -> bne a0, zero, -4
+            (0xfd75, false, 0xfe05_1ee3),
+            // c.slli a0, 2 -> slli a0, a0, 2
+            (0x050a, false, 0x0025_1513),
+            // c.lwsp a0, 4(sp) -> lw a0, 4(sp)
+            (0x4512, false, 0x0041_2503),
+            // c.ldsp a0, 8(sp) -> ld a0, 8(sp)
+            (0x6522, true, 0x0081_3503),
+            // c.jr ra -> jalr zero, 0(ra)
+            (0x8082, false, 0x0000_8067),
+            // c.mv a0, a1 -> add a0, zero, a1
+            (0x852e, false, 0x00b0_0533),
+            // c.ebreak -> ebreak
+            (0x9002, false, 0x0010_0073),
+            // c.jalr a0 -> jalr ra, 0(a0)
+            (0x9502, false, 0x0005_00e7),
+            // c.add a0, a1 -> add a0, a0, a1
+            (0x952e, false, 0x00b5_0533),
+            // c.swsp a0, 12(sp) -> sw a0, 12(sp)
+            (0xc62a, false, 0x00a1_2623),
+            // c.sdsp a0, 8(sp) -> sd a0, 8(sp)
+            (0xe42a, true, 0x00a1_3423),
+            // c.ld a0, 8(a1) -> ld a0, 8(a1)
+            (0x6588, true, 0x0085_b503),
+            // c.sd a0, 16(a1) -> sd a0, 16(a1)
+            (0xe988, true, 0x00a5_b823),
+            // c.subw a0, a1 -> subw a0, a0, a1
+            (0x9d0d, true, 0x40b5_053b),
+            // c.addw a0, a1 -> addw a0, a0, a1
+            (0x9d2d, true, 0x00b5_053b),
+            // c.srli a0, 40 -> srli a0, a0, 40
+            (0x9121, true, 0x0285_5513),
+        ];
+        for &(insn, rv64, expected) in cases {
+            assert_eq!(expand_compressed(insn, rv64), Some(expected), "{insn:#06x}");
+        }
+
+        // The all-zero halfword is illegal, and C.FLD has no integer equivalent.
+        assert_eq!(expand_compressed(0x0000, false), None);
+        assert_eq!(expand_compressed(0x2000, false), None);
+        // Shift amounts of 32 or more are reserved in RV32C.
+        assert_eq!(expand_compressed(0x1502, false), None);
+        assert_eq!(expand_compressed(0x1502, true), Some(0x0205_1513));
+        assert_eq!(expand_compressed(0x9121, false), None);
+    }
+
+    #[test]
+    fn test_transpile_compressed() {
+        // c.li a0, 5; addi a1, a0, 1 (at a 2-byte boundary); c.add a0, a1; padding.
+        let code = [0x0593_4515, 0x952e_0015, 0x0000_0001];
+        let (instructions, compressed) = transpile_compressed(&code, false);
+        let decoded =
+            instructions.iter().map(|i| (i.opcode, i.op_a, i.op_b, i.op_c)).collect::<Vec<_>>();
+        assert_eq!(compressed, vec![true, false, true, true, true, true]);
+        assert_eq!(decoded[0], (Opcode::ADD, 10, 0, 5));
+        assert_eq!(decoded[1], (Opcode::ADD, 11, 10, 1));
+        assert_eq!(decoded[3], (Opcode::ADD, 10, 10, 11));
+        assert_eq!(decoded[4], (Opcode::ADD, 0, 0, 0));
+        assert_eq!(decoded[5].0, Opcode::UNIMP);
+    }
+}
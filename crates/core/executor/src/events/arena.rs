@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+/// A reference to a run of consecutive items of an [`EventArena`], used by the events in place
+/// of a `Vec` of their own.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub struct ArenaSlice {
+    /// The index of the first item.
+    start: u32,
+    /// The number of items.
+    len: u32,
+}
+
+impl ArenaSlice {
+    /// The number of items of the slice.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether the slice has no items.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
+    /// The slice moved by an [`EventArena::append`] returning `offset`.
+    #[must_use]
+    pub(crate) const fn offset(self, offset: u32) -> Self {
+        Self { start: self.start + offset, len: self.len }
+    }
+
+    /// The slice of the items past `start` in an arena of `end` items.
+    fn between(start: usize, end: usize) -> Self {
+        Self { start: start as u32, len: (end - start) as u32 }
+    }
+}
+
+/// Append-only storage for the items of the events of a record, e.g. their memory records.
+///
+/// Keeping the items of all the events in a single buffer saves the allocation of a `Vec` per
+/// event, which dominates the execution time of the programs making many small precompile calls.
+/// The events refer to their items by [`ArenaSlice`], which are only valid in the arena the items
+/// were allocated in.
+#[derive(
+    Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct EventArena<T> {
+    items: Vec<T>,
+}
+
+impl<T> Default for EventArena<T> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<T> EventArena<T> {
+    /// The number of items of the arena.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether the arena has no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Add an item to the arena, e.g. while building a slice with [`Self::slice_from`].
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// The slice of the items added since the arena had `start` items.
+    #[must_use]
+    pub fn slice_from(&self, start: usize) -> ArenaSlice {
+        ArenaSlice::between(start, self.items.len())
+    }
+
+    /// Add items to the arena, returning their slice.
+    pub fn alloc(&mut self, items: impl IntoIterator<Item = T>) -> ArenaSlice {
+        let start = self.items.len();
+        self.items.extend(items);
+        self.slice_from(start)
+    }
+
+    /// The items of a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice was not allocated in this arena.
+    #[must_use]
+    pub fn get(&self, slice: ArenaSlice) -> &[T] {
+        &self.items[slice.start as usize..][..slice.len()]
+    }
+
+    /// Move the items of another arena to the end of this one, returning the offset to add to the
+    /// start of the slices of `other` for them to refer to the moved items.
+    pub(crate) fn append(&mut self, other: &mut Self) -> u32 {
+        let offset = self.items.len() as u32;
+        self.items.append(&mut other.items);
+        offset
+    }
+}
+
+impl<T: Clone> EventArena<T> {
+    /// Copy the items of a slice of another arena to this one, returning their new slice.
+    #[must_use]
+    pub fn copy_from(&mut self, other: &Self, slice: ArenaSlice) -> ArenaSlice {
+        self.alloc(other.get(slice).iter().cloned())
+    }
+
+    /// The slice of the items of `first` followed by those of `second`, which are copied to the
+    /// end of the arena unless `second` already follows `first`.
+    #[must_use]
+    pub fn concat(&mut self, first: ArenaSlice, second: ArenaSlice) -> ArenaSlice {
+        if first.start + first.len == second.start {
+            return ArenaSlice { start: first.start, len: first.len + second.len };
+        }
+        let start = self.items.len();
+        self.items.extend_from_within(first.start as usize..(first.start + first.len) as usize);
+        self.items.extend_from_within(second.start as usize..(second.start + second.len) as usize);
+        self.slice_from(start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventArena;
+
+    #[test]
+    fn test_event_arena() {
+        let mut arena = EventArena::default();
+        let first = arena.alloc([1, 2]);
+        let second = arena.alloc([3]);
+        assert_eq!(arena.get(first), [1, 2]);
+        let joined = arena.concat(first, second);
+        assert_eq!(arena.get(joined), [1, 2, 3]);
+        assert_eq!(arena.len(), 3);
+
+        // Slices which don't follow each other are copied.
+        let other = arena.alloc([4]);
+        let joined = arena.concat(first, other);
+        assert_eq!(arena.get(joined), [1, 2, 4]);
+        assert_eq!(arena.len(), 7);
+
+        let mut moved = EventArena::default();
+        let copied = moved.copy_from(&arena, joined);
+        assert_eq!(moved.get(copied), [1, 2, 4]);
+        let offset = moved.append(&mut arena);
+        assert_eq!(moved.get(second.offset(offset)), [3]);
+        assert!(arena.is_empty());
+    }
+}
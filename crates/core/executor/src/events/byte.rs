@@ -11,13 +11,26 @@ use serde::{Deserialize, Serialize};
 use crate::{ByteOpcode, Opcode};
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 12;
 
 /// Byte Lookup Event.
 ///
 /// This object encapsulates the information needed to prove a byte lookup operation. This includes
 /// the shard, opcode, operands, and other relevant information.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    Hash,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+#[rkyv(derive(Hash, PartialEq, Eq))]
 pub struct ByteLookupEvent {
     /// The shard number.
     pub shard: u32,
@@ -232,6 +245,9 @@ impl ByteOpcode {
             ByteOpcode::LTU,
             ByteOpcode::MSB,
             ByteOpcode::U16Range,
+            ByteOpcode::AesSbox,
+            ByteOpcode::HexDecode,
+            ByteOpcode::Base64Decode,
         ];
         debug_assert_eq!(opcodes.len(), NUM_BYTE_OPS);
         opcodes
@@ -6,7 +6,9 @@ use super::{memory::MemoryRecordEnum, LookupId};
 ///
 /// This object encapsulates the information needed to prove a CPU operation. This includes its
 /// shard, opcode, operands, and other relevant information.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct CpuEvent {
     /// The clock cycle.
     pub clk: u32,
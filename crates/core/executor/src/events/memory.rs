@@ -4,7 +4,17 @@ use serde::{Deserialize, Serialize};
 ///
 /// This object encapsulates the information needed to prove a memory access operation. This
 /// includes the shard, timestamp, and value of the memory address.
-#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
 pub struct MemoryRecord {
     /// The shard number.
     pub shard: u32,
@@ -38,7 +48,18 @@ pub enum MemoryAccessPosition {
 /// This object encapsulates the information needed to prove a memory read operation. This
 /// includes the value, shard, timestamp, and previous shard and timestamp.
 #[allow(clippy::manual_non_exhaustive)]
-#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+#[repr(C)]
 pub struct MemoryReadRecord {
     /// The value.
     pub value: u32,
@@ -57,7 +78,18 @@ pub struct MemoryReadRecord {
 /// This object encapsulates the information needed to prove a memory write operation. This
 /// includes the value, shard, timestamp, previous value, previous shard, and previous timestamp.
 #[allow(clippy::manual_non_exhaustive)]
-#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+#[repr(C)]
 pub struct MemoryWriteRecord {
     /// The value.
     pub value: u32,
@@ -77,7 +109,9 @@ pub struct MemoryWriteRecord {
 ///
 /// This enum represents the different types of memory records that can be stored in the memory
 /// event such as reads and writes.
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(
+    Debug, Copy, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub enum MemoryRecordEnum {
     /// Read.
     Read(MemoryReadRecord),
@@ -126,7 +160,9 @@ impl MemoryRecordEnum {
 /// This object encapsulates the information needed to prove a memory initialize or finalize
 /// operation. This includes the address, value, shard, timestamp, and whether the memory is
 /// initialized or finalized.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct MemoryInitializeFinalizeEvent {
     /// The address.
     pub addr: u32,
@@ -223,7 +259,9 @@ impl From<MemoryWriteRecord> for MemoryRecordEnum {
 /// This object encapsulates the information needed to prove a memory access operation within a
 /// shard. This includes the address, initial memory access, and final memory access within a
 /// shard.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct MemoryLocalEvent {
     /// The address.
     pub addr: u32,
@@ -1,6 +1,7 @@
 //! Type definitions for the events emitted by the [`crate::Executor`] during execution.
 
 mod alu;
+mod arena;
 mod byte;
 mod cpu;
 mod memory;
@@ -9,6 +10,7 @@ mod syscall;
 mod utils;
 
 pub use alu::*;
+pub use arena::*;
 pub use byte::*;
 pub use cpu::*;
 pub use memory::*;
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// Barrett Reduce Event.
+///
+/// This event is emitted when a 512-bit value is reduced modulo a 256-bit modulus.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct BarrettReduceEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the modulus.
+    pub modulus_ptr: u32,
+    /// The modulus as a list of words.
+    pub modulus: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the modulus.
+    pub modulus_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -1,14 +1,19 @@
+use hashbrown::HashMap;
+use num::{BigUint, One};
 use serde::{Deserialize, Serialize};
+use sp1_curves::{params::FieldParameters, weierstrass::bn254::Bn254ScalarField};
 
 use crate::events::{
     memory::{MemoryReadRecord, MemoryWriteRecord},
-    LookupId, MemoryLocalEvent,
+    ArenaSlice, LookupId, MemoryLocalEvent, PrecompileEvent, SyscallEvent,
 };
 
 /// Bn254 MulAdd Event.
 ///
 /// This event is emitted when a uint256 mul operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct Bn254MulAddEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
@@ -16,6 +21,8 @@ pub struct Bn254MulAddEvent {
     pub shard: u32,
     /// The clock cycle.
     pub clk: u32,
+    /// Whether the operands and the result are big-endian, as for `BN254_MULADD_BE`.
+    pub big_endian: bool,
     /// The pointer to the x value.
     pub x_ptr: u32,
     /// The x value as a list of words.
@@ -26,12 +33,255 @@ pub struct Bn254MulAddEvent {
     pub a: Vec<u32>,
     /// The b value as a list of words.
     pub b: Vec<u32>,
+    /// The memory records for the x value, in [`super::PrecompileEvents::write_arena`].
+    pub x_memory_records: ArenaSlice,
+    /// The memory records for the y value, in [`super::PrecompileEvents::read_arena`].
+    pub a_memory_records: ArenaSlice,
+    /// The memory records for the modulus, in [`super::PrecompileEvents::read_arena`].
+    pub b_memory_records: ArenaSlice,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+impl Bn254MulAddEvent {
+    /// Returns an operand of the event as little endian words, reversing the bytes of big-endian
+    /// operands.
+    #[must_use]
+    pub fn to_le(&self, words: &[u32]) -> Vec<u32> {
+        if self.big_endian {
+            bn254_swap_endianness(words)
+        } else {
+            words.to_vec()
+        }
+    }
+}
+
+/// Reverses the order of the bytes of a value given as a list of words, turning the words of a
+/// big-endian value into the little endian words of the same value and back.
+#[must_use]
+pub fn bn254_swap_endianness(words: &[u32]) -> Vec<u32> {
+    words.iter().rev().map(|word| word.swap_bytes()).collect()
+}
+
+/// A product `a * b` computed by one or more of a shard's bn254 mul-add events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bn254MulMemoEntry {
+    /// The shard number of the first event multiplying a by b.
+    pub shard: u32,
+    /// The a value as a list of words.
+    pub a: Vec<u32>,
+    /// The b value as a list of words.
+    pub b: Vec<u32>,
+    /// The number of events multiplying a by b.
+    pub multiplicity: usize,
+}
+
+/// Collects the products computed by a shard's bn254 mul-add events.
+///
+/// If `dedup` is set, the events multiplying the same operands share an entry, in order of their
+/// first appearance. Otherwise, every event gets its own entry with a multiplicity of one.
+#[must_use]
+pub fn bn254_mul_memo(
+    events: &[(SyscallEvent, PrecompileEvent)],
+    dedup: bool,
+) -> Vec<Bn254MulMemoEntry> {
+    let mut entries: Vec<Bn254MulMemoEntry> = Vec::new();
+    let mut index: HashMap<(Vec<u32>, Vec<u32>), usize> = HashMap::new();
+    for (_, event) in events {
+        let event =
+            if let PrecompileEvent::Bn254MulAdd(event) = event { event } else { unreachable!() };
+        // The products are looked up on the little endian operands, whatever their byte order.
+        let (a, b) = (event.to_le(&event.a), event.to_le(&event.b));
+        if dedup {
+            if let Some(&i) = index.get(&(a.clone(), b.clone())) {
+                entries[i].multiplicity += 1;
+                continue;
+            }
+            index.insert((a.clone(), b.clone()), entries.len());
+        }
+        entries.push(Bn254MulMemoEntry { shard: event.shard, a, b, multiplicity: 1 });
+    }
+    entries
+}
+
+/// The maximum number of `(a, b)` pairs of a single bn254 inner product syscall.
+pub const BN254_INNER_PRODUCT_MAX_LEN: usize = 32;
+
+/// Bn254 Inner Product Event.
+///
+/// This event is emitted when `x += sum(a_i * b_i)` is computed over a length-prefixed array of
+/// `(a_i, b_i)` pairs of BN254 scalar field elements.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct Bn254InnerProductEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the length of the array, which is followed by the pairs.
+    pub pairs_ptr: u32,
+    /// The a values as lists of words.
+    pub a: Vec<Vec<u32>>,
+    /// The b values as lists of words.
+    pub b: Vec<Vec<u32>>,
     /// The memory records for the x value.
     pub x_memory_records: Vec<MemoryWriteRecord>,
-    /// The memory records for the y value.
-    pub a_memory_records: Vec<MemoryReadRecord>,
-    /// The memory records for the modulus.
-    pub b_memory_records: Vec<MemoryReadRecord>,
+    /// The memory record for the length of the array.
+    pub len_memory_record: MemoryReadRecord,
+    /// The memory records for the a values.
+    pub a_memory_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for the b values.
+    pub b_memory_records: Vec<Vec<MemoryReadRecord>>,
     /// The local memory access records.
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
+
+/// Bn254 Montgomery Event.
+///
+/// This event is emitted when a BN254 scalar field element is converted to or from Montgomery
+/// form.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct Bn254MontEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// Whether the conversion is into Montgomery form, as opposed to out of it.
+    pub to_mont: bool,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// The factor an element of the BN254 scalar field is multiplied by when converting it to
+/// Montgomery form, `R = 2^256 mod r`, or out of it, `R^-1 mod r`.
+#[must_use]
+pub fn bn254_scalar_mont_factor(to_mont: bool) -> BigUint {
+    let modulus = Bn254ScalarField::modulus();
+    let r = (BigUint::one() << 256) % &modulus;
+    if to_mont {
+        r
+    } else {
+        r.modpow(&(&modulus - 2u32), &modulus)
+    }
+}
+
+/// The maximum number of `(c, y)` pairs of a single `FR_NTT` syscall.
+pub const FR_NTT_MAX_LEN: usize = 32;
+
+/// The number of words of the state of an `FR_NTT` check: the point `r`, the root of unity `w`,
+/// the running powers of both, and the running sums over the coefficients and the evaluations.
+pub const FR_NTT_STATE_WORDS: usize = 6 * 8;
+
+/// Fr NTT Event.
+///
+/// This event is emitted when a chunk of the coefficients `c_i` of a polynomial and of its
+/// evaluations `y_i` over a radix-2 domain are folded into the state of a random-point check of
+/// the NTT.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct FrNttEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The state as a list of words.
+    pub state: Vec<u32>,
+    /// The pointer to the length of the array, which is followed by the pairs.
+    pub pairs_ptr: u32,
+    /// The coefficients as lists of words.
+    pub c: Vec<Vec<u32>>,
+    /// The evaluations as lists of words.
+    pub y: Vec<Vec<u32>>,
+    /// The memory records for the state.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory record for the length of the array.
+    pub len_memory_record: MemoryReadRecord,
+    /// The memory records for the coefficients.
+    pub c_memory_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for the evaluations.
+    pub y_memory_records: Vec<Vec<MemoryReadRecord>>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// The two-adicity of the BN254 scalar field: `2^28` divides `r - 1`, so the largest radix-2
+/// domain of an NTT has `2^28` elements.
+pub const FR_TWO_ADICITY: usize = 28;
+
+/// A generator of the multiplicative group of the BN254 scalar field.
+const FR_GENERATOR: u32 = 5;
+
+/// A primitive `2^log_n`-th root of unity of the BN254 scalar field, which generates the domain
+/// of an NTT of `2^log_n` elements.
+///
+/// # Panics
+///
+/// Panics if `log_n` exceeds [`FR_TWO_ADICITY`], as the field has no such root.
+#[must_use]
+pub fn fr_root_of_unity(log_n: usize) -> BigUint {
+    assert!(
+        log_n <= FR_TWO_ADICITY,
+        "domain of size 2^{log_n} exceeds the two-adicity {FR_TWO_ADICITY} of the bn254 scalar field"
+    );
+    let modulus = Bn254ScalarField::modulus();
+    let exponent = (&modulus - 1u32) >> log_n;
+    BigUint::from(FR_GENERATOR).modpow(&exponent, &modulus)
+}
+
+/// The NTT of the coefficients of a polynomial over the BN254 scalar field: its evaluations at
+/// the successive powers of the root of unity of the domain of the same size.
+///
+/// # Panics
+///
+/// Panics if the number of coefficients is not a power of two, or exceeds the largest domain.
+#[must_use]
+pub fn fr_ntt(coeffs: &[BigUint]) -> Vec<BigUint> {
+    let n = coeffs.len();
+    assert!(n.is_power_of_two(), "ntt size {n} is not a power of two");
+    let log_n = n.trailing_zeros() as usize;
+    let modulus = Bn254ScalarField::modulus();
+
+    // Iterative radix-2 Cooley-Tukey, on the coefficients in bit-reversed order.
+    let mut values = vec![BigUint::default(); n];
+    for (i, coeff) in coeffs.iter().enumerate() {
+        let j = if log_n == 0 { 0 } else { i.reverse_bits() >> (usize::BITS as usize - log_n) };
+        values[j] = coeff % &modulus;
+    }
+    for log_len in 1..=log_n {
+        let len = 1 << log_len;
+        let root = fr_root_of_unity(log_len);
+        for start in (0..n).step_by(len) {
+            let mut twiddle = BigUint::one();
+            for i in start..start + len / 2 {
+                let u = values[i].clone();
+                let v = &values[i + len / 2] * &twiddle % &modulus;
+                values[i] = (&u + &v) % &modulus;
+                values[i + len / 2] = (&u + &modulus - &v) % &modulus;
+                twiddle = twiddle * &root % &modulus;
+            }
+        }
+    }
+    values
+}
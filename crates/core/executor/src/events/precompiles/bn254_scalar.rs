@@ -1,3 +1,4 @@
+use enum_map::Enum;
 use num::BigUint;
 use sp1_curves::{
     params::{FieldParameters, NumWords},
@@ -16,7 +17,7 @@ use super::FieldOperation;
 
 pub const NUM_WORDS_PER_FE: usize = 8;
 
-#[derive(Default, PartialEq, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(Default, PartialEq, Copy, Clone, Debug, Serialize, Deserialize, Enum)]
 pub enum Bn254FieldOperation {
     #[default]
     Invalid = 0,
@@ -35,7 +36,9 @@ impl Bn254FieldOperation {
 }
 
 /// Bn254 scalar Event.
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(
+    Default, Clone, Debug, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct Bn254FieldArithEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
@@ -135,7 +138,9 @@ pub fn create_bn254_scalar_arith_event(
     }
 }
 
-#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+#[derive(
+    Default, Clone, Debug, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct FieldArithMemoryAccess<T> {
     pub ptr: u32,
     pub memory_records: Vec<T>,
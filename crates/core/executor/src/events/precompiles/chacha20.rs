@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, LookupId, MemoryLocalEvent};
+
+/// The number of words in the ChaCha20 state.
+pub const CHACHA20_STATE_WORDS: usize = 16;
+
+/// The number of double rounds in the ChaCha20 block function.
+pub const CHACHA20_DOUBLE_ROUNDS: usize = 10;
+
+/// The quarter rounds of a double round, as the indices of the state words `(a, b, c, d)` they
+/// act on: four column rounds followed by four diagonal rounds.
+pub const CHACHA20_QUARTER_ROUNDS: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// The steps of a quarter round on `(a, b, c, d)`.
+///
+/// A step `(x, y, z, r)` computes `x += y; z ^= x; z <<<= r`, where `x`, `y` and `z` are positions
+/// in `(a, b, c, d)`.
+pub const CHACHA20_QUARTER_ROUND_STEPS: [(usize, usize, usize, u32); 4] =
+    [(0, 1, 3, 16), (2, 3, 1, 12), (0, 1, 3, 8), (2, 3, 1, 7)];
+
+/// Applies a ChaCha20 double round to the state.
+pub fn chacha20_double_round(state: &mut [u32; CHACHA20_STATE_WORDS]) {
+    for quarter_round in CHACHA20_QUARTER_ROUNDS {
+        for (x, y, z, r) in CHACHA20_QUARTER_ROUND_STEPS {
+            let (x, y, z) = (quarter_round[x], quarter_round[y], quarter_round[z]);
+            state[x] = state[x].wrapping_add(state[y]);
+            state[z] = (state[z] ^ state[x]).rotate_left(r);
+        }
+    }
+}
+
+/// Applies the ChaCha20 block function to the state.
+#[must_use]
+pub fn chacha20_block(state: &[u32; CHACHA20_STATE_WORDS]) -> [u32; CHACHA20_STATE_WORDS] {
+    let mut working_state = *state;
+    for _ in 0..CHACHA20_DOUBLE_ROUNDS {
+        chacha20_double_round(&mut working_state);
+    }
+    core::array::from_fn(|i| working_state[i].wrapping_add(state[i]))
+}
+
+/// ChaCha20 Block Event.
+///
+/// This event is emitted when the ChaCha20 block function is applied to a state.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct ChaCha20BlockEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The state as a list of words.
+    pub state: Vec<u32>,
+    /// The memory records for the state.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
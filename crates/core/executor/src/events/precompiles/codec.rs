@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// The number of ASCII characters decoded by a single codec call.
+pub const DECODE_INPUT_BYTES: usize = 16;
+
+/// The number of words holding the characters decoded by a single codec call.
+pub const DECODE_INPUT_WORDS: usize = 4;
+
+/// The number of bytes produced by `HEX_DECODE`.
+pub const HEX_DECODE_OUTPUT_BYTES: usize = 8;
+
+/// The number of words produced by `HEX_DECODE`.
+pub const HEX_DECODE_OUTPUT_WORDS: usize = 2;
+
+/// The number of bytes produced by `BASE64_DECODE`.
+pub const BASE64_DECODE_OUTPUT_BYTES: usize = 12;
+
+/// The number of words produced by `BASE64_DECODE`.
+pub const BASE64_DECODE_OUTPUT_WORDS: usize = 3;
+
+/// A text encoding of binary data supported by the codec precompiles.
+#[derive(
+    Default,
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub enum Encoding {
+    /// Hexadecimal, where each character encodes 4 bits. Both lower and upper case digits are
+    /// accepted.
+    #[default]
+    Hex,
+    /// Base64 with the standard alphabet of RFC 4648, where each character encodes 6 bits.
+    /// Padding is not accepted.
+    Base64,
+}
+
+impl Encoding {
+    /// The number of bits encoded by a single character.
+    #[must_use]
+    pub const fn bits_per_char(self) -> usize {
+        match self {
+            Encoding::Hex => 4,
+            Encoding::Base64 => 6,
+        }
+    }
+
+    /// The number of words written by a single decode call.
+    #[must_use]
+    pub const fn output_words(self) -> usize {
+        match self {
+            Encoding::Hex => HEX_DECODE_OUTPUT_WORDS,
+            Encoding::Base64 => BASE64_DECODE_OUTPUT_WORDS,
+        }
+    }
+
+    /// Decodes a single character, returning `None` if it is not in the alphabet.
+    #[must_use]
+    pub const fn decode_char(self, c: u8) -> Option<u8> {
+        match self {
+            Encoding::Hex => hex_decode_char(c),
+            Encoding::Base64 => base64_decode_char(c),
+        }
+    }
+
+    /// Decodes a block of characters, returning `None` if any of them is not in the alphabet.
+    ///
+    /// The decoded bits are packed big-endian, as in the `hex` and `base64` crates.
+    #[must_use]
+    pub fn decode(self, input: &[u8; DECODE_INPUT_BYTES]) -> Option<Vec<u8>> {
+        let bits = self.bits_per_char();
+        let mut output = Vec::with_capacity(DECODE_INPUT_BYTES * bits / 8);
+        let mut acc = 0u32;
+        let mut acc_bits = 0;
+        for &c in input {
+            acc = (acc << bits) | u32::from(self.decode_char(c)?);
+            acc_bits += bits;
+            if acc_bits >= 8 {
+                acc_bits -= 8;
+                output.push((acc >> acc_bits) as u8);
+                acc &= (1 << acc_bits) - 1;
+            }
+        }
+        Some(output)
+    }
+}
+
+/// Decodes a hexadecimal digit, returning `None` if it is not one.
+#[must_use]
+pub const fn hex_decode_char(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes a character of the standard base64 alphabet, returning `None` if it is not one.
+#[must_use]
+pub const fn base64_decode_char(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Decode Event.
+///
+/// This event is emitted when a `HEX_DECODE` or `BASE64_DECODE` operation is performed.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct DecodeEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The encoding of the input.
+    pub encoding: Encoding,
+    /// The pointer to the output.
+    pub output_ptr: u32,
+    /// The pointer to the input.
+    pub input_ptr: u32,
+    /// The input characters as words.
+    pub input: [u32; DECODE_INPUT_WORDS],
+    /// Whether the input contained a character outside of the alphabet, leaving the output
+    /// unchanged.
+    pub is_invalid: bool,
+    /// The memory records for the output.
+    pub output_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the input.
+    pub input_memory_records: [MemoryReadRecord; DECODE_INPUT_WORDS],
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
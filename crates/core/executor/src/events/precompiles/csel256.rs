@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// Conditional Select Event.
+///
+/// This event is emitted when a `CSEL256` call selects between the 32-byte values at `a_ptr` and
+/// `b_ptr`, writing `a` back if `cond` is one and `b` if it is zero.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct Csel256Event {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the a value, which receives the selected value.
+    pub a_ptr: u32,
+    /// The a value as a list of words.
+    pub a: Vec<u32>,
+    /// The pointer to the b value.
+    pub b_ptr: u32,
+    /// The b value as a list of words.
+    pub b: Vec<u32>,
+    /// The condition, which is either zero or one.
+    pub cond: u32,
+    /// The memory record for the condition register.
+    pub cond_memory: MemoryReadRecord,
+    /// The memory records for the a value.
+    pub a_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the b value.
+    pub b_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -1,10 +1,15 @@
+use num::BigUint;
 use serde::{Deserialize, Serialize};
 
 use sp1_curves::{
-    params::{NumLimbs, NumWords},
+    params::{FieldParameters, NumLimbs, NumWords},
+    utils::is_square,
     weierstrass::{
-        bls12_381::bls12381_decompress, secp256k1::secp256k1_decompress,
+        bls12_381::bls12381_decompress,
+        bn254::{bn254_decompress, bn254_g2_decompress, Bn254BaseField},
+        secp256k1::secp256k1_decompress,
         secp256r1::secp256r1_decompress,
+        WeierstrassParameters,
     },
     AffinePoint, CurveType, EllipticCurve,
 };
@@ -22,7 +27,9 @@ use crate::{
 /// Elliptic Curve Add Event.
 ///
 /// This event is emitted when an elliptic curve addition operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct EllipticCurveAddEvent {
     pub(crate) lookup_id: LookupId,
     /// The shard number.
@@ -48,7 +55,9 @@ pub struct EllipticCurveAddEvent {
 /// Elliptic Curve Double Event.
 ///
 /// This event is emitted when an elliptic curve doubling operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct EllipticCurveDoubleEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
@@ -69,7 +78,9 @@ pub struct EllipticCurveDoubleEvent {
 /// Elliptic Curve Point Decompress Event.
 ///
 /// This event is emitted when an elliptic curve point decompression operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct EllipticCurveDecompressEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
@@ -83,8 +94,11 @@ pub struct EllipticCurveDecompressEvent {
     pub sign_bit: bool,
     /// The x coordinate as a list of bytes.
     pub x_bytes: Vec<u8>,
-    /// The decompressed y coordinate as a list of bytes.
+    /// The decompressed y coordinate as a list of bytes, or the previous value of the y
+    /// coordinate if the point is invalid.
     pub decompressed_y_bytes: Vec<u8>,
+    /// Whether there is no point with the x coordinate, in which case the syscall fails.
+    pub is_invalid: bool,
     /// The memory records for the x coordinate.
     pub x_memory_records: Vec<MemoryReadRecord>,
     /// The memory records for the y coordinate.
@@ -186,7 +200,7 @@ pub fn create_ec_double_event<E: EllipticCurve>(
 ///
 /// It takes a pointer to a memory location, reads the point from memory, decompresses it, and
 /// writes the result back to the memory location.
-pub fn create_ec_decompress_event<E: EllipticCurve>(
+pub fn create_ec_decompress_event<E: EllipticCurve + WeierstrassParameters>(
     rt: &mut SyscallContext,
     slice_ptr: u32,
     sign_bit: u32,
@@ -205,18 +219,31 @@ pub fn create_ec_decompress_event<E: EllipticCurve>(
     let mut x_bytes_be = x_bytes.clone();
     x_bytes_be.reverse();
 
-    let decompress_fn = match E::CURVE_TYPE {
-        CurveType::Secp256k1 => secp256k1_decompress::<E>,
-        CurveType::Secp256r1 => secp256r1_decompress::<E>,
-        CurveType::Bls12381 => bls12381_decompress::<E>,
-        _ => panic!("Unsupported curve"),
-    };
+    // If `x^3 + ax + b` is not a square, there is no point with this x, the syscall fails and y is
+    // left as it was.
+    let modulus = E::BaseField::modulus();
+    let x = BigUint::from_bytes_le(&x_bytes);
+    let y_squared = (&x * &x * &x + E::a_int() * &x + E::b_int()) % &modulus;
+    let is_invalid = !is_square(&y_squared, &modulus);
+
+    let y_words = if is_invalid {
+        rt.slice_unsafe(slice_ptr, num_words_field_element)
+    } else {
+        let decompress_fn = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => secp256k1_decompress::<E>,
+            CurveType::Secp256r1 => secp256r1_decompress::<E>,
+            CurveType::Bls12381 => bls12381_decompress::<E>,
+            CurveType::Bn254 => bn254_decompress::<E>,
+            _ => panic!("Unsupported curve"),
+        };
 
-    let computed_point: AffinePoint<E> = decompress_fn(&x_bytes_be, sign_bit);
+        let computed_point: AffinePoint<E> = decompress_fn(&x_bytes_be, sign_bit);
 
-    let mut decompressed_y_bytes = computed_point.y.to_bytes_le();
-    decompressed_y_bytes.resize(num_limbs, 0u8);
-    let y_words = bytes_to_words_le_vec(&decompressed_y_bytes);
+        let mut decompressed_y_bytes = computed_point.y.to_bytes_le();
+        decompressed_y_bytes.resize(num_limbs, 0u8);
+        bytes_to_words_le_vec(&decompressed_y_bytes)
+    };
+    let decompressed_y_bytes = words_to_bytes_le_vec(&y_words);
 
     let y_memory_records = rt.mw_slice(slice_ptr, &y_words);
 
@@ -228,6 +255,67 @@ pub fn create_ec_decompress_event<E: EllipticCurve>(
         sign_bit: sign_bit != 0,
         x_bytes: x_bytes.clone(),
         decompressed_y_bytes,
+        is_invalid,
+        x_memory_records,
+        y_memory_records,
+        local_mem_access: rt.postprocess(),
+    }
+}
+
+/// Create a BN254 G2 point decompression event. The x coordinate `x0 + x1 * u` is read from
+/// `slice_ptr + 64` as `x0` then `x1`, and the y coordinate is written to `slice_ptr` in the same
+/// layout.
+pub fn create_bn254_g2_decompress_event(
+    rt: &mut SyscallContext,
+    slice_ptr: u32,
+    sign_bit: u32,
+) -> EllipticCurveDecompressEvent {
+    let start_clk = rt.clk;
+    assert!(slice_ptr % 4 == 0, "slice_ptr must be 4-byte aligned");
+    assert!(sign_bit <= 1, "is_odd must be 0 or 1");
+
+    let num_limbs = <Bn254BaseField as NumLimbs>::Limbs::USIZE;
+    let num_words_fp2_element = 2 * num_limbs / 4;
+
+    let (x_memory_records, x_vec) =
+        rt.mr_slice(slice_ptr + 2 * (num_limbs as u32), num_words_fp2_element);
+
+    let x_bytes = words_to_bytes_le_vec(&x_vec);
+    let x = [
+        BigUint::from_bytes_le(&x_bytes[..num_limbs]),
+        BigUint::from_bytes_le(&x_bytes[num_limbs..]),
+    ];
+
+    // If `x^3 + b` is not a square, there is no point with this x, the syscall fails and y is left
+    // as it was.
+    let decompressed_y = bn254_g2_decompress(&x, sign_bit);
+    let is_invalid = decompressed_y.is_none();
+
+    let y_words = match decompressed_y {
+        None => rt.slice_unsafe(slice_ptr, num_words_fp2_element),
+        Some(y) => {
+            let mut decompressed_y_bytes = Vec::with_capacity(2 * num_limbs);
+            for c in y {
+                let mut bytes = c.to_bytes_le();
+                bytes.resize(num_limbs, 0u8);
+                decompressed_y_bytes.extend(bytes);
+            }
+            bytes_to_words_le_vec(&decompressed_y_bytes)
+        }
+    };
+    let decompressed_y_bytes = words_to_bytes_le_vec(&y_words);
+
+    let y_memory_records = rt.mw_slice(slice_ptr, &y_words);
+
+    EllipticCurveDecompressEvent {
+        lookup_id: rt.syscall_lookup_id,
+        shard: rt.current_shard(),
+        clk: start_clk,
+        ptr: slice_ptr,
+        sign_bit: sign_bit != 0,
+        x_bytes,
+        decompressed_y_bytes,
+        is_invalid,
         x_memory_records,
         y_memory_records,
         local_mem_access: rt.postprocess(),
@@ -0,0 +1,285 @@
+use num::{BigUint, Integer, One, Zero};
+use serde::{Deserialize, Serialize};
+use sp1_curves::{
+    params::FieldParameters,
+    weierstrass::{
+        secp256k1::{Secp256k1BaseField, Secp256k1Parameters, Secp256k1ScalarField},
+        WeierstrassParameters,
+    },
+};
+
+use crate::events::{
+    keccak_merkle_state,
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent, SyscallEvent,
+};
+
+/// The number of rows of a single ecrecover event, with a row for every digit of the signed-digit
+/// expansions of the two scalars, which have 257 digits.
+pub const ECRECOVER_ROWS: usize = 257;
+
+/// The number of words of the input: the hash, `v`, `r` and `s`, as 32-byte big-endian integers.
+pub const ECRECOVER_INPUT_WORDS: usize = 32;
+
+/// The offset in bytes of the keccak state from the input pointer. The state is used as scratch
+/// space to hash the recovered public key.
+pub const ECRECOVER_STATE_OFFSET: u32 = 4 * ECRECOVER_INPUT_WORDS as u32;
+
+/// The number of words of the output, which is the address left-padded to 32 bytes.
+pub const ECRECOVER_OUTPUT_WORDS: usize = 8;
+
+/// The cycle, from the clock of the syscall, of the `KECCAK_PERMUTE` call hashing the public key.
+pub const ECRECOVER_PERMUTE_OFFSET: u32 = 1;
+
+/// The cycle, from the clock of the syscall, at which the hash is read and the address written.
+pub const ECRECOVER_OUTPUT_OFFSET: u32 = 3;
+
+/// Secp256k1 ECRecover Event.
+///
+/// This event is emitted when a secp256k1 public key is recovered from a hash and a signature with
+/// the semantics of the EVM `ecrecover` precompile, and hashed into an address by a
+/// `KECCAK_PERMUTE` call, emitted as an event of its own.
+///
+/// A call that does not recover the key accesses no memory, and only records its arguments.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct Secp256k1EcRecoverEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the output.
+    pub out_ptr: u32,
+    /// The pointer to the input, which is followed by the keccak state.
+    pub input_ptr: u32,
+    /// Whether the public key was recovered.
+    pub is_recovered: bool,
+    /// The input as a list of words.
+    pub input: Vec<u32>,
+    /// The memory records for the input.
+    pub input_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the keccak state, written with the padded public key.
+    pub state_write_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the hash of the public key.
+    pub hash_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the output.
+    pub out_write_records: Vec<MemoryWriteRecord>,
+    /// The `KECCAK_PERMUTE` syscall hashing the public key.
+    pub permute_syscall: Option<SyscallEvent>,
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// A secp256k1 point in affine coordinates.
+pub type EcRecoverPoint = (BigUint, BigUint);
+
+/// The witness of the recovery of a public key `Q = u1 * G + u2 * R`, where `u1 = -z / r` and
+/// `u2 = s / r` modulo the group order.
+///
+/// Every scalar `u` is made odd by adding the group order if needed, and the resulting `k` is
+/// written with 257 digits in `{-1, 1}` as `k = 2 * m - (2^257 - 1)`, whose digits are the bits of
+/// `m`. The key is the sum of the digits of both scalars, from the most significant one, with a
+/// double-and-add step per digit that adds one of `G + R`, `G - R` and their negations.
+///
+/// The accumulator starts from the point of [`ecrecover_offset`] rather than from the point at
+/// infinity, so that no step adds a point to itself, and the offset is removed from the result by
+/// adding the point of [`ecrecover_offset_correction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcRecoverWitness {
+    /// The hash, as an integer.
+    pub z: BigUint,
+    /// The x-coordinate of `R`.
+    pub r: BigUint,
+    /// The second half of the signature.
+    pub s: BigUint,
+    /// Whether the y-coordinate of `R` is odd, which is given by `v = 27 + recid`.
+    pub recid: bool,
+    /// The y-coordinate of `R`.
+    pub r_y: BigUint,
+    /// The scalars `u1` and `u2`.
+    pub scalars: [BigUint; 2],
+    /// Whether the group order is added to the scalars to make them odd.
+    pub odd_shifts: [bool; 2],
+    /// The integers `m` whose bits are the digits of the scalars.
+    pub digits: [BigUint; 2],
+    /// The points `G + R` and `G - R`.
+    pub table: [EcRecoverPoint; 2],
+    /// The recovered public key.
+    pub public_key: EcRecoverPoint,
+}
+
+impl EcRecoverWitness {
+    /// The digits of the scalars on `row`, where `true` stands for 1 and `false` for -1.
+    #[must_use]
+    pub fn row_digits(&self, row: usize) -> [bool; 2] {
+        let bit = (ECRECOVER_ROWS - 1 - row) as u64;
+        [self.digits[0].bit(bit), self.digits[1].bit(bit)]
+    }
+
+    /// The point added on `row`, which is the sum of the digits of the row times `G` and `R`.
+    #[must_use]
+    pub fn row_point(&self, row: usize) -> EcRecoverPoint {
+        let [d1, d2] = self.row_digits(row);
+        let (x, y) = if d1 == d2 { &self.table[0] } else { &self.table[1] };
+        if d1 {
+            (x.clone(), y.clone())
+        } else {
+            (x.clone(), secp256k1_neg(y))
+        }
+    }
+}
+
+fn secp256k1_neg(y: &BigUint) -> BigUint {
+    let p = Secp256k1BaseField::modulus();
+    (&p - y) % &p
+}
+
+fn secp256k1_inverse(x: &BigUint) -> BigUint {
+    let p = Secp256k1BaseField::modulus();
+    x.modpow(&(&p - 2u32), &p)
+}
+
+/// The sum of two points with distinct x-coordinates.
+fn secp256k1_add_distinct(a: &EcRecoverPoint, b: &EcRecoverPoint) -> Option<EcRecoverPoint> {
+    let p = Secp256k1BaseField::modulus();
+    if a.0 == b.0 {
+        return None;
+    }
+    let lambda = (&p + &b.1 - &a.1) * secp256k1_inverse(&((&p + &b.0 - &a.0) % &p)) % &p;
+    let x = (&lambda * &lambda + &p + &p - &a.0 - &b.0) % &p;
+    let y = (&lambda * ((&p + &a.0 - &x) % &p) + &p - &a.1) % &p;
+    Some((x, y))
+}
+
+/// The double of a point whose y-coordinate is not zero.
+fn secp256k1_double(a: &EcRecoverPoint) -> EcRecoverPoint {
+    let p = Secp256k1BaseField::modulus();
+    let lambda = 3u32 * &a.0 * &a.0 * secp256k1_inverse(&(2u32 * &a.1 % &p)) % &p;
+    let x = (&lambda * &lambda + &p + &p - &a.0 - &a.0) % &p;
+    let y = (&lambda * ((&p + &a.0 - &x) % &p) + &p - &a.1) % &p;
+    (x, y)
+}
+
+/// The point `H` the accumulator of the ladder starts from, which is the point with x-coordinate 1
+/// and an even y-coordinate.
+#[must_use]
+pub fn ecrecover_offset() -> EcRecoverPoint {
+    let p = Secp256k1BaseField::modulus();
+    let y = BigUint::from(8u32).modpow(&((&p + 1u32) >> 2), &p);
+    let y = if y.is_odd() { &p - y } else { y };
+    (BigUint::one(), y)
+}
+
+/// The point `-2^257 * H`, which removes the offset from the accumulator at the end of the ladder.
+#[must_use]
+pub fn ecrecover_offset_correction() -> EcRecoverPoint {
+    let (x, y) = (0..ECRECOVER_ROWS).fold(ecrecover_offset(), |acc, _| secp256k1_double(&acc));
+    (x, secp256k1_neg(&y))
+}
+
+/// The point `2 * acc + t`, computed as `(acc + t) + acc`.
+///
+/// Returns `None` if either addition has operands with the same x-coordinate, in which case the
+/// formulas do not apply.
+#[must_use]
+pub fn secp256k1_double_and_add(
+    acc: &EcRecoverPoint,
+    t: &EcRecoverPoint,
+) -> Option<EcRecoverPoint> {
+    let sum = secp256k1_add_distinct(acc, t)?;
+    secp256k1_add_distinct(&sum, acc)
+}
+
+/// Computes the witness of the recovery of the public key from the input of the EVM `ecrecover`
+/// precompile, made of the hash, `v`, `r` and `s` as 32-byte big-endian integers.
+///
+/// Returns `None` if the input is invalid, that is if `v` is not 27 or 28, if `r` or `s` is not in
+/// `[1, n)`, or if `r` is not the x-coordinate of a point, and also if a step of the ladder does
+/// not apply, in which case the key is left to be recovered otherwise.
+#[must_use]
+pub fn secp256k1_ecrecover_witness(input: &[u8]) -> Option<EcRecoverWitness> {
+    let p = Secp256k1BaseField::modulus();
+    let n = Secp256k1ScalarField::modulus();
+    let z = BigUint::from_bytes_be(&input[..32]);
+    let v = BigUint::from_bytes_be(&input[32..64]);
+    let r = BigUint::from_bytes_be(&input[64..96]);
+    let s = BigUint::from_bytes_be(&input[96..128]);
+
+    // The EVM precompile does not restrict `s` to the lower half of the order.
+    let recid = match u32::try_from(&v) {
+        Ok(27) => false,
+        Ok(28) => true,
+        _ => return None,
+    };
+    if r.is_zero() || r >= n || s.is_zero() || s >= n {
+        return None;
+    }
+
+    // Decompress `R`, whose y-coordinate has the parity of `recid`. The base field modulus is
+    // 3 mod 4, so the square root is a power of the input.
+    let rhs = (&r * &r * &r + 7u32) % &p;
+    let mut r_y = rhs.modpow(&((&p + 1u32) >> 2), &p);
+    if (&r_y * &r_y) % &p != rhs {
+        return None;
+    }
+    if r_y.bit(0) != recid {
+        r_y = &p - r_y;
+    }
+    let big_r = (r.clone(), r_y.clone());
+
+    let r_inv = r.modpow(&(&n - 2u32), &n);
+    let u1 = (&n - &z % &n) * &r_inv % &n;
+    let u2 = &s * &r_inv % &n;
+
+    let mut odd_shifts = [false; 2];
+    let mut digits = [BigUint::zero(), BigUint::zero()];
+    for (i, u) in [&u1, &u2].into_iter().enumerate() {
+        odd_shifts[i] = u.is_even();
+        let k = if odd_shifts[i] { u + &n } else { u.clone() };
+        digits[i] = (k + (BigUint::one() << ECRECOVER_ROWS) - 1u32) >> 1;
+    }
+
+    let generator = Secp256k1Parameters::generator();
+    let plus = secp256k1_add_distinct(&generator, &big_r)?;
+    let minus = secp256k1_add_distinct(&generator, &(r.clone(), secp256k1_neg(&r_y)))?;
+
+    let witness = EcRecoverWitness {
+        z,
+        r,
+        s,
+        recid,
+        r_y,
+        scalars: [u1, u2],
+        odd_shifts,
+        digits,
+        table: [plus, minus],
+        public_key: (BigUint::zero(), BigUint::zero()),
+    };
+
+    let mut acc = ecrecover_offset();
+    for row in 0..ECRECOVER_ROWS {
+        acc = secp256k1_double_and_add(&acc, &witness.row_point(row))?;
+    }
+    let public_key = secp256k1_add_distinct(&acc, &ecrecover_offset_correction())?;
+
+    Some(EcRecoverWitness { public_key, ..witness })
+}
+
+/// The words of the keccak state hashing the uncompressed public key `(x, y)`, without its SEC1
+/// prefix, whose 64 bytes fit in a single block.
+#[must_use]
+pub fn ecrecover_keccak_state(public_key: &EcRecoverPoint) -> Vec<u32> {
+    let words = |coordinate: &BigUint| {
+        let mut bytes = [0u8; 32];
+        let be_bytes = coordinate.to_bytes_be();
+        bytes[32 - be_bytes.len()..].copy_from_slice(&be_bytes);
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>()
+    };
+    keccak_merkle_state(&words(&public_key.0), &words(&public_key.1))
+}
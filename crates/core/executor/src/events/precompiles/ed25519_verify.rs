@@ -0,0 +1,108 @@
+use num::BigUint;
+use serde::{Deserialize, Serialize};
+use sp1_curves::{
+    edwards::{
+        ed25519::{Ed25519, Ed25519BaseField, Ed25519Parameters},
+        EdwardsParameters,
+    },
+    params::FieldParameters,
+    AffinePoint, EllipticCurve,
+};
+
+use crate::events::{memory::MemoryReadRecord, LookupId, MemoryLocalEvent};
+
+/// The number of rows of a single Ed25519 verify event, with a row for every bit of the 257-bit
+/// registers holding the scalars in the chip. The scalars are smaller than the order of the prime
+/// subgroup, itself smaller than `2^253`, so the first four rows only double the identity.
+pub const ED25519_VERIFY_ROWS: usize = 257;
+
+/// The number of words of the public key, made of its affine coordinates `x` and `y`.
+pub const ED25519_KEY_WORDS: usize = 16;
+
+/// The number of words of the signature, made of `R`, `S`, and the SHA-512 digest of `R`, the
+/// compressed public key and the message.
+pub const ED25519_SIGNATURE_WORDS: usize = 32;
+
+/// The offset in words of `S` in the signature.
+pub const ED25519_SIGNATURE_S_OFFSET: usize = 8;
+
+/// The offset in words of the digest in the signature.
+pub const ED25519_SIGNATURE_DIGEST_OFFSET: usize = 16;
+
+/// Ed25519 Verify Event.
+///
+/// This event is emitted when the verification equation of an Ed25519 signature is checked, which
+/// holds if `[S]B - [k]A` compresses to `R`, where `k` is the digest reduced modulo the order of
+/// the prime subgroup.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct Ed25519VerifyEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the public key.
+    pub key_ptr: u32,
+    /// The pointer to the signature.
+    pub sig_ptr: u32,
+    /// The public key as a list of words.
+    pub key: Vec<u32>,
+    /// The signature as a list of words.
+    pub sig: Vec<u32>,
+    /// Whether the verification equation holds.
+    pub is_valid: bool,
+    /// The memory records for the public key.
+    pub key_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the signature.
+    pub sig_read_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// Reduces a SHA-512 digest, given as little-endian bytes, modulo the order of the prime subgroup.
+#[must_use]
+pub fn ed25519_reduce_digest(digest: &[u8]) -> BigUint {
+    BigUint::from_bytes_le(digest) % Ed25519Parameters::prime_group_order()
+}
+
+/// The points added at every step of the verification ladder, indexed by the bits of `S` and `k`:
+/// the identity, `-A`, `B` and `B - A`.
+#[must_use]
+pub fn ed25519_verify_table(key: &AffinePoint<Ed25519>) -> [AffinePoint<Ed25519>; 4] {
+    let modulus = Ed25519BaseField::modulus();
+    let neg_key = AffinePoint::new((&modulus - &key.x % &modulus) % &modulus, key.y.clone());
+    let base = Ed25519::ec_generator();
+    let base_minus_key = &base + &neg_key;
+    [Ed25519::neutral(), neg_key, base, base_minus_key]
+}
+
+/// Computes `[S]B - [k]A` with the ladder of the Ed25519 verify chip, which doubles the
+/// accumulator and adds a point of the table for every bit of the scalars, from the top one.
+#[must_use]
+pub fn ed25519_verify_point(
+    key: &AffinePoint<Ed25519>,
+    s: &BigUint,
+    k: &BigUint,
+) -> AffinePoint<Ed25519> {
+    let table = ed25519_verify_table(key);
+    let mut acc = Ed25519::neutral();
+    for bit in (0..ED25519_VERIFY_ROWS as u64).rev() {
+        let index = 2 * usize::from(s.bit(bit)) + usize::from(k.bit(bit));
+        acc = &(&acc + &acc) + &table[index];
+    }
+    acc
+}
+
+/// Compresses an Ed25519 point into the little-endian bytes of `y`, with the parity of `x` as the
+/// top bit.
+#[must_use]
+pub fn ed25519_compress(point: &AffinePoint<Ed25519>) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    let y = point.y.to_bytes_le();
+    bytes[..y.len()].copy_from_slice(&y);
+    bytes[31] |= u8::from(point.x.bit(0)) << 7;
+    bytes
+}
@@ -9,7 +9,9 @@ use crate::events::{
 /// Edwards Decompress Event.
 ///
 /// This event is emitted when an edwards decompression operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct EdDecompressEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
@@ -23,8 +25,11 @@ pub struct EdDecompressEvent {
     pub sign: bool,
     /// The comprssed y coordinate as a list of bytes.
     pub y_bytes: [u8; COMPRESSED_POINT_BYTES],
-    /// The decompressed x coordinate as a list of bytes.
+    /// The decompressed x coordinate as a list of bytes, or the previous value of the x
+    /// coordinate if the point is invalid.
     pub decompressed_x_bytes: [u8; NUM_BYTES_FIELD_ELEMENT],
+    /// Whether there is no point with the y coordinate, in which case the syscall fails.
+    pub is_invalid: bool,
     /// The memory records for the x coordinate.
     pub x_memory_records: [MemoryWriteRecord; WORDS_FIELD_ELEMENT],
     /// The memory records for the y coordinate.
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// An arithmetic operation on IEEE-754 double precision floats.
+#[derive(
+    Default,
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub enum F64Operation {
+    /// Addition.
+    #[default]
+    Add,
+    /// Multiplication.
+    Mul,
+    /// Division.
+    Div,
+}
+
+impl F64Operation {
+    /// Computes `a op b`, rounding to nearest with ties to even.
+    ///
+    /// Returns `None` unless both operands and the result are normal numbers, which are the only
+    /// ones the precompiles support. Results in the lowest binade are excluded too, since they may
+    /// have been rounded from a subnormal, which has a coarser precision.
+    #[must_use]
+    pub fn apply(self, a: f64, b: f64) -> Option<f64> {
+        if !a.is_normal() || !b.is_normal() {
+            return None;
+        }
+        let result = match self {
+            F64Operation::Add => a + b,
+            F64Operation::Mul => a * b,
+            F64Operation::Div => a / b,
+        };
+        (result.is_finite() && result.abs() >= 2.0 * f64::MIN_POSITIVE).then_some(result)
+    }
+}
+
+/// F64 Operation Event.
+///
+/// This event is emitted when an `F64_ADD`, `F64_MUL` or `F64_DIV` operation is performed.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct F64OpEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The operation to perform.
+    pub op: F64Operation,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as words.
+    pub x: [u32; 2],
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as words.
+    pub y: [u32; 2],
+    /// Whether the operation fell outside of the supported range, leaving x unchanged.
+    pub is_fallback: bool,
+    /// The memory records for the x value.
+    pub x_memory_records: [MemoryWriteRecord; 2],
+    /// The memory records for the y value.
+    pub y_memory_records: [MemoryReadRecord; 2],
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
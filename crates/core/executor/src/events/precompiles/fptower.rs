@@ -3,7 +3,18 @@ use serde::{Deserialize, Serialize};
 use crate::events::{LookupId, MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord};
 
 /// This is an arithmetic operation for emulating modular arithmetic.
-#[derive(Default, PartialEq, Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(
+    Default,
+    PartialEq,
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
 pub enum FieldOperation {
     /// Addition.
     #[default]
@@ -19,7 +30,9 @@ pub enum FieldOperation {
 /// Emulated Field Operation Events.
 ///
 /// This event is emitted when an emulated field operation is performed on the input operands.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct FpOpEvent {
     /// The lookup id.
     pub lookup_id: LookupId,
@@ -48,7 +61,9 @@ pub struct FpOpEvent {
 /// Emulated Degree 2 Field Addition/Subtraction Events.
 ///
 /// This event is emitted when an emulated degree 2 field operation is performed on the input
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct Fp2AddSubEvent {
     /// The lookup id.
     pub lookup_id: LookupId,
@@ -75,7 +90,9 @@ pub struct Fp2AddSubEvent {
 }
 
 /// Emulated Degree 2 Field Multiplication Events.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct Fp2MulEvent {
     /// The lookup id.
     pub lookup_id: LookupId,
@@ -98,3 +115,33 @@ pub struct Fp2MulEvent {
     /// The local memory access records.
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
+
+/// Emulated Degree 6 Field Multiplication Events.
+///
+/// The degree 6 field is the cubic extension `Fp2[v] / (v^3 - (c + u))` of the degree 2 field,
+/// with `c = 1` for BLS12-381 and `c = 9` for BN254.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct Fp6MulEvent {
+    /// The lookup id.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x operand.
+    pub x_ptr: u32,
+    /// The x operand.
+    pub x: Vec<u32>,
+    /// The pointer to the y operand.
+    pub y_ptr: u32,
+    /// The y operand.
+    pub y: Vec<u32>,
+    /// The memory records for the x operand.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the y operand.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent, SyscallEvent,
+};
+
+/// The number of SHA-256 compressions in an HMAC-SHA256 computation over a single block message:
+/// the inner key block, the message block, the outer key block and the inner hash.
+pub const HMAC_SHA256_STEPS: usize = 4;
+
+/// The number of cycles taken by each step: one to write the block, 48 for the `SHA_EXTEND` call
+/// and two for the `SHA_COMPRESS` call.
+pub const HMAC_SHA256_STEP_CYCLES: u32 = 51;
+
+/// The offset of the `SHA_EXTEND` call from the start of a step.
+pub const HMAC_SHA256_EXTEND_OFFSET: u32 = 1;
+
+/// The offset of the `SHA_COMPRESS` call from the start of a step.
+pub const HMAC_SHA256_COMPRESS_OFFSET: u32 = 49;
+
+/// The byte offset of the SHA-256 state from the message schedule in the HMAC-SHA256 buffer.
+pub const HMAC_SHA256_H_OFFSET: u32 = 64 * 4;
+
+/// The inner padding, repeated over a word.
+pub const HMAC_SHA256_IPAD: u32 = 0x3636_3636;
+
+/// The outer padding, repeated over a word.
+pub const HMAC_SHA256_OPAD: u32 = 0x5c5c_5c5c;
+
+/// The SHA-256 initial hash value.
+pub const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The padding following the inner hash in the last block of the outer hash, which covers the
+/// 64-byte outer key block and the 32-byte inner hash.
+pub const HMAC_SHA256_OUTER_PADDING: [u32; 8] = [0x8000_0000, 0, 0, 0, 0, 0, 0, (64 + 32) * 8];
+
+/// HMAC-SHA256 Event.
+///
+/// This event is emitted when an HMAC-SHA256 tag is computed. The SHA-256 extend and compress
+/// operations it is made of are emitted as events of their own, whose syscalls are listed in
+/// `sha_syscalls`.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct HmacSha256Event {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the key block.
+    pub key_ptr: u32,
+    /// The pointer to the message schedule, followed by the SHA-256 state.
+    pub w_ptr: u32,
+    /// The memory records for the key block.
+    pub key_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the block written at each step.
+    pub block_write_records: Vec<Vec<MemoryWriteRecord>>,
+    /// The memory records for the SHA-256 state reset before the inner and the outer hash.
+    pub h_write_records: Vec<Vec<MemoryWriteRecord>>,
+    /// The `SHA_EXTEND` and `SHA_COMPRESS` syscalls of each step.
+    pub sha_syscalls: Vec<(SyscallEvent, SyscallEvent)>,
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
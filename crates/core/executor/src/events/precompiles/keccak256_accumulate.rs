@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+use super::keccak256_permute::STATE_SIZE;
+
+/// Keccak-256 Accumulate Event.
+///
+/// This event is emitted when a rate-sized block is absorbed into a keccak-256 accumulator. The
+/// sponge state is carried from one event of an accumulator to the next, rather than through
+/// memory, so a single hash may span several shards.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct KeccakAccumulateEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The address of the block being absorbed.
+    pub block_ptr: u32,
+    /// The address of the accumulator control word.
+    pub acc_ptr: u32,
+    /// The shard of the first block absorbed by the accumulator, which identifies the accumulator
+    /// along with `acc_clk`, since the control word may be reused once the hash is finalized.
+    pub acc_shard: u32,
+    /// The clock cycle of the first block absorbed by the accumulator.
+    pub acc_clk: u32,
+    /// The position of this block within the accumulator.
+    pub index: u32,
+    /// Whether this is the first block absorbed by the accumulator.
+    pub is_first: bool,
+    /// Whether this is the last block absorbed by the accumulator.
+    pub is_final: bool,
+    /// The sponge state before absorbing the block.
+    pub pre_state: [u64; STATE_SIZE],
+    /// The block being absorbed, as a list of u32 words.
+    pub block: Vec<u32>,
+    /// The sponge state after absorbing the block and permuting.
+    pub post_state: [u64; STATE_SIZE],
+    /// The memory record for the accumulator control word.
+    pub flags_read_record: MemoryReadRecord,
+    /// The memory records for the block.
+    pub block_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the digest, only present on the final block.
+    pub digest_write_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent, SyscallEvent,
+};
+
+/// The maximum number of levels of the path of a single Keccak Merkle root syscall.
+pub const KECCAK_MERKLE_MAX_DEPTH: usize = 8;
+
+/// The number of cycles taken by each level: one to write the state, and two for the
+/// `KECCAK_PERMUTE` call.
+pub const KECCAK_MERKLE_STEP_CYCLES: u32 = 3;
+
+/// Keccak-256 Merkle Root Event.
+///
+/// This event is emitted when the root of a keccak-256 Merkle path is computed from a node kept in
+/// the first 32 bytes of a keccak state. For every level, the state is overwritten with the padded
+/// concatenation of the node and its sibling, and then permuted by a `KECCAK_PERMUTE` call,
+/// emitted as an event of its own, which leaves the hash in the first 32 bytes of the state.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct KeccakMerkleEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the keccak state, which starts with the node.
+    pub state_ptr: u32,
+    /// The pointer to the depth of the path, which is followed by the index and the siblings.
+    pub path_ptr: u32,
+    /// The number of levels of the path.
+    pub depth: u32,
+    /// The index of the node, whose `i`-th bit is set if the node is the right child at level `i`.
+    pub index: u32,
+    /// The memory record for the depth of the path.
+    pub depth_memory_record: MemoryReadRecord,
+    /// The memory record for the index of the node.
+    pub index_memory_record: MemoryReadRecord,
+    /// The memory records for the sibling of every level.
+    pub sibling_read_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for the state of every level, written with the padded children.
+    pub state_write_records: Vec<Vec<MemoryWriteRecord>>,
+    /// The `KECCAK_PERMUTE` syscall of every level.
+    pub permute_syscalls: Vec<SyscallEvent>,
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// The words of the keccak state hashing `left` and `right`, which are 8 words each.
+///
+/// The 64 bytes of the children fit in a single block, so the state is the padded block.
+pub fn keccak_merkle_state(left: &[u32], right: &[u32]) -> Vec<u32> {
+    let mut state = vec![0u32; 50];
+    state[..8].copy_from_slice(left);
+    state[8..16].copy_from_slice(right);
+    state[16] = 0x01;
+    state[33] = 0x8000_0000;
+    state
+}
@@ -10,7 +10,9 @@ pub(crate) const STATE_SIZE: usize = 25;
 /// Keccak-256 Permutation Event.
 ///
 /// This event is emitted when a keccak-256 permutation operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct KeccakPermuteEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent, SyscallEvent,
+};
+
+/// The keccak-256 rate in bytes.
+pub const KECCAK_SPONGE_RATE_BYTES: usize = 136;
+
+/// The keccak-256 rate in 32-bit words.
+pub const KECCAK_SPONGE_RATE_WORDS: usize = KECCAK_SPONGE_RATE_BYTES / 4;
+
+/// Keccak-256 Sponge Event.
+///
+/// This event is emitted when a block is absorbed into a keccak-256 sponge kept in guest memory,
+/// by either `KECCAK_ABSORB` or `KECCAK_FINALIZE`. The block is xored into the rate of the state
+/// and the state is then permuted by a `KECCAK_PERMUTE` call, emitted as an event of its own.
+#[derive(
+    Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct KeccakSpongeEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the sponge state.
+    pub state_ptr: u32,
+    /// The pointer to the block.
+    pub block_ptr: u32,
+    /// Whether the block is the padded tail of the input.
+    pub is_final: bool,
+    /// The memory records for the block.
+    pub block_read_records: Vec<MemoryReadRecord>,
+    /// The memory record for the length of the tail, only present for the final block.
+    pub len_read_record: Option<MemoryReadRecord>,
+    /// The memory records for the rate of the state, written with the block absorbed.
+    pub state_write_records: Vec<MemoryWriteRecord>,
+    /// The `KECCAK_PERMUTE` syscall permuting the state.
+    pub permute_syscall: SyscallEvent,
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// Pads the tail of a keccak-256 input, given as a block whose first `len` bytes are the tail.
+///
+/// The bytes of the block past the tail are ignored.
+pub fn keccak_pad_block(block: &[u32], len: usize) -> Vec<u32> {
+    let mut bytes = block.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>();
+    bytes[len..].fill(0);
+    bytes[len] ^= 0x01;
+    bytes[KECCAK_SPONGE_RATE_BYTES - 1] ^= 0x80;
+    bytes.chunks_exact(4).map(|word| u32::from_le_bytes(word.try_into().unwrap())).collect()
+}
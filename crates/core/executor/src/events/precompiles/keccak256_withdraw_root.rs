@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent, SyscallEvent,
+};
+
+/// The maximum number of levels of the tree of a single withdrawal root syscall.
+pub const WITHDRAW_ROOT_MAX_DEPTH: usize = 4;
+
+/// The number of hashes of a tree of [`WITHDRAW_ROOT_MAX_DEPTH`] levels, which is the number of
+/// steps of every withdrawal root syscall.
+pub const WITHDRAW_ROOT_HASHES: usize = (1 << WITHDRAW_ROOT_MAX_DEPTH) - 1;
+
+/// The number of cycles taken by each hash: one to write the state, two for the `KECCAK_PERMUTE`
+/// call, and one to copy the hash to the parent node.
+pub const WITHDRAW_ROOT_STEP_CYCLES: u32 = 4;
+
+/// The node computed by the given step of a withdrawal root syscall, as its level, starting at
+/// one for the parents of the leaves, and its index within the level.
+///
+/// The steps go through the levels in order, and are laid out for a tree of
+/// [`WITHDRAW_ROOT_MAX_DEPTH`] levels.
+#[must_use]
+pub const fn withdraw_root_node(step: usize) -> (usize, usize) {
+    let (mut level, mut index) = (1, step);
+    while index >= 1 << (WITHDRAW_ROOT_MAX_DEPTH - level) {
+        index -= 1 << (WITHDRAW_ROOT_MAX_DEPTH - level);
+        level += 1;
+    }
+    (level, index)
+}
+
+/// Whether the given step of a withdrawal root syscall hashes a node of a tree of `depth` levels.
+#[must_use]
+pub const fn withdraw_root_is_hash(step: usize, depth: usize) -> bool {
+    let (level, index) = withdraw_root_node(step);
+    level <= depth && index < 1 << (depth - level)
+}
+
+/// Keccak-256 Withdrawal Root Event.
+///
+/// This event is emitted when the root of a balanced keccak-256 Merkle tree is computed in place
+/// over its leaves. Every node is hashed by overwriting a keccak state with the padded
+/// concatenation of its children, permuting it with a `KECCAK_PERMUTE` call, emitted as an event
+/// of its own, and copying the hash from the first 32 bytes of the state over the leaves. The
+/// `j`-th node of a level is written over the `j`-th leaf, once both of its children have been read
+/// from the `2j`-th and `2j + 1`-th leaves, so the root ends up in the first leaf.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct WithdrawRootEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the keccak state used to hash the nodes.
+    pub state_ptr: u32,
+    /// The pointer to the depth of the tree, which is followed by the leaves.
+    pub tree_ptr: u32,
+    /// The number of levels of the tree.
+    pub depth: u32,
+    /// The memory record for the depth of the tree.
+    pub depth_memory_record: MemoryReadRecord,
+    /// The memory records for the children of every node, in the order of the steps.
+    pub children_read_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for the state of every node, written with the padded children.
+    pub state_write_records: Vec<Vec<MemoryWriteRecord>>,
+    /// The memory records for the hash of every node, read from the permuted state.
+    pub hash_read_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for every node, written over the leaf of the same index.
+    pub node_write_records: Vec<Vec<MemoryWriteRecord>>,
+    /// The `KECCAK_PERMUTE` syscall of every node.
+    pub permute_syscalls: Vec<SyscallEvent>,
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{ArenaSlice, LookupId, MemoryLocalEvent, SyscallEvent};
+
+/// Memory Copy Event.
+///
+/// This event is emitted when a `MEMCPY_32` or `MEMCPY_64` call copies words from `src_ptr` to
+/// `dst_ptr`. Two back-to-back `MEMCPY_32` calls copying contiguous chunks are coalesced into a
+/// single `MEMCPY_64` event, in which case each half keeps the clock cycle of its own call.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct MemCopyEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the source.
+    pub src_ptr: u32,
+    /// The pointer to the destination.
+    pub dst_ptr: u32,
+    /// The memory records for the source, in [`super::PrecompileEvents::read_arena`].
+    pub read_records: ArenaSlice,
+    /// The memory records for the destination, in [`super::PrecompileEvents::write_arena`].
+    pub write_records: ArenaSlice,
+    /// The second `MEMCPY_32` call, copying the upper half, if the event was coalesced.
+    pub coalesced_syscall: Option<SyscallEvent>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
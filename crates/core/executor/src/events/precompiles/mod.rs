@@ -1,34 +1,96 @@
+mod aes;
+mod barrett_reduce;
 mod bn254;
 mod bn254_scalar;
+mod chacha20;
+mod codec;
+mod csel256;
 mod ec;
+mod ecrecover;
+mod ed25519_verify;
 mod edwards;
+mod f64;
 mod fptower;
+mod hmac_sha256;
+mod keccak256_accumulate;
+mod keccak256_merkle;
 mod keccak256_permute;
+mod keccak256_sponge;
+mod keccak256_withdraw_root;
+mod memcopy;
+mod plugin;
+mod poseidon_merkle;
+mod rlp_scan;
+mod rsa;
+mod sha256_blocks;
 mod sha256_compress;
 mod sha256_extend;
+mod sha512_compress;
 mod u256x2048_mul;
+mod u256x4096_mul;
 mod uint256;
+mod uint384;
+mod zktrie;
 
 use crate::syscalls::SyscallCode;
-pub use bn254::Bn254MulAddEvent;
+pub use aes::*;
+pub use barrett_reduce::*;
+pub use bn254::{
+    bn254_mul_memo, bn254_scalar_mont_factor, bn254_swap_endianness, fr_ntt, fr_root_of_unity,
+    Bn254InnerProductEvent, Bn254MontEvent, Bn254MulAddEvent, Bn254MulMemoEntry, FrNttEvent,
+    BN254_INNER_PRODUCT_MAX_LEN, FR_NTT_MAX_LEN, FR_NTT_STATE_WORDS, FR_TWO_ADICITY,
+};
 pub use bn254_scalar::{
-    create_bn254_scalar_arith_event, Bn254FieldArithEvent, Bn254FieldOperation, NUM_WORDS_PER_FE,
+    create_bn254_scalar_arith_event, Bn254FieldArithEvent, Bn254FieldOperation,
+    FieldArithMemoryAccess, NUM_WORDS_PER_FE,
 };
+pub use chacha20::*;
+pub use codec::*;
+pub use csel256::*;
 pub use ec::*;
+pub use ecrecover::*;
+pub use ed25519_verify::*;
 pub use edwards::*;
+pub use f64::*;
 pub use fptower::*;
 use hashbrown::HashMap;
+pub use hmac_sha256::*;
+pub use keccak256_accumulate::*;
+pub use keccak256_merkle::*;
 pub use keccak256_permute::*;
+pub use keccak256_sponge::*;
+pub use keccak256_withdraw_root::*;
+pub use memcopy::*;
+pub use plugin::*;
+pub use poseidon_merkle::*;
+pub use rlp_scan::*;
+pub use rsa::*;
 use serde::{Deserialize, Serialize};
+pub use sha256_blocks::*;
 pub use sha256_compress::*;
 pub use sha256_extend::*;
+pub use sha512_compress::*;
 use strum::{EnumIter, IntoEnumIterator};
 pub use u256x2048_mul::*;
+pub use u256x4096_mul::*;
 pub use uint256::*;
+pub use uint384::*;
+pub use zktrie::*;
 
-use super::{MemoryLocalEvent, SyscallEvent};
+use super::{
+    ArenaSlice, EventArena, MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord, SyscallEvent,
+};
 
-#[derive(Clone, Debug, Serialize, Deserialize, EnumIter)]
+#[derive(
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    EnumIter,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
 /// Precompile event.  There should be one variant for every precompile syscall.
 pub enum PrecompileEvent {
     /// Sha256 extend precompile event.
@@ -37,16 +99,28 @@ pub enum PrecompileEvent {
     ShaCompress(ShaCompressEvent),
     /// Keccak256 permute precompile event.
     KeccakPermute(KeccakPermuteEvent),
+    /// Keccak256 accumulate precompile event.
+    KeccakAccumulate(KeccakAccumulateEvent),
+    /// Keccak256 absorb and finalize precompile event.
+    KeccakSponge(KeccakSpongeEvent),
+    /// Keccak256 Merkle root precompile event.
+    KeccakMerkle(KeccakMerkleEvent),
+    /// Keccak256 withdrawal root precompile event.
+    WithdrawRoot(WithdrawRootEvent),
     /// Edwards curve add precompile event.
     EdAdd(EllipticCurveAddEvent),
     /// Edwards curve decompress precompile event.
     EdDecompress(EdDecompressEvent),
+    /// Ed25519 signature verification precompile event.
+    Ed25519Verify(Ed25519VerifyEvent),
     /// Secp256k1 curve add precompile event.
     Secp256k1Add(EllipticCurveAddEvent),
     /// Secp256k1 curve double precompile event.
     Secp256k1Double(EllipticCurveDoubleEvent),
     /// Secp256k1 curve decompress precompile event.
     Secp256k1Decompress(EllipticCurveDecompressEvent),
+    /// Secp256k1 ecrecover precompile event.
+    Secp256k1EcRecover(Secp256k1EcRecoverEvent),
     /// Secp256r1 curve add precompile event.
     Secp256r1Add(EllipticCurveAddEvent),
     /// Secp256r1 curve double precompile event.
@@ -65,26 +139,105 @@ pub enum PrecompileEvent {
     Bn254Fp2AddSub(Fp2AddSubEvent),
     /// Bn254 quadratic field mul precompile event.
     Bn254Fp2Mul(Fp2MulEvent),
+    /// Bn254 sextic field mul precompile event.
+    Bn254Fp6Mul(Fp6MulEvent),
     /// Bls12-381 curve add precompile event.
     Bls12381Add(EllipticCurveAddEvent),
     /// Bls12-381 curve double precompile event.
     Bls12381Double(EllipticCurveDoubleEvent),
     /// Bls12-381 curve decompress precompile event.
     Bls12381Decompress(EllipticCurveDecompressEvent),
+    /// Bn254 G1 point decompress precompile event.
+    Bn254Decompress(EllipticCurveDecompressEvent),
+    /// Bn254 G2 point decompress precompile event.
+    Bn254G2Decompress(EllipticCurveDecompressEvent),
+    /// Jubjub curve add precompile event.
+    JubjubAdd(EllipticCurveAddEvent),
+    /// Baby Jubjub curve add precompile event.
+    BabyJubjubAdd(EllipticCurveAddEvent),
     /// Bls12-381 base field operation precompile event.
     Bls12381Fp(FpOpEvent),
     /// Bls12-381 quadratic field add/sub precompile event.
     Bls12381Fp2AddSub(Fp2AddSubEvent),
     /// Bls12-381 quadratic field mul precompile event.
     Bls12381Fp2Mul(Fp2MulEvent),
+    /// Bls12-381 sextic field mul precompile event.
+    Bls12381Fp6Mul(Fp6MulEvent),
     /// Uint256 mul precompile event.
     Uint256Mul(Uint256MulEvent),
     /// U256XU2048 mul precompile event.
     U256xU2048Mul(U256xU2048MulEvent),
+    /// U256XU4096 mul precompile event.
+    U256xU4096Mul(U256xU4096MulEvent),
+    /// RSA-2048 and RSA-4096 modexp precompile event.
+    RsaModExp(RsaModExpEvent),
     /// Bn254Scalar mul_add precompile event.
     Bn254ScalarMulAdd(Bn254FieldArithEvent),
     /// Bn254Scalar mul_add precompile event base on uint256 mul.
     Bn254MulAdd(Bn254MulAddEvent),
+    /// Bn254 scalar field Montgomery form conversion precompile event.
+    Bn254Mont(Bn254MontEvent),
+    /// Bn254 scalar field inner product precompile event.
+    Bn254InnerProduct(Bn254InnerProductEvent),
+    /// Bn254 scalar field NTT check precompile event.
+    FrNtt(FrNttEvent),
+    /// Memory copy precompile event for `MEMCPY_32`.
+    MemCopy32(MemCopyEvent),
+    /// Memory copy precompile event for `MEMCPY_64`, including coalesced `MEMCPY_32` pairs.
+    MemCopy64(MemCopyEvent),
+    /// Conditional select precompile event.
+    Csel256(Csel256Event),
+    /// RLP scan precompile event.
+    RlpScan(RlpScanEvent),
+    /// Poseidon Merkle root precompile event.
+    PoseidonMerkle(PoseidonMerkleEvent),
+    /// zkTrie node hash precompile event.
+    ZkTrieNode(ZkTrieNodeEvent),
+    /// Uint384 mul_add precompile event.
+    Uint384MulAdd(Uint384MulAddEvent),
+    /// Barrett reduction precompile event.
+    BarrettReduce(BarrettReduceEvent),
+    /// AES encryption round precompile event.
+    AesRound(AesRoundEvent),
+    /// ChaCha20 block function precompile event.
+    ChaCha20Block(ChaCha20BlockEvent),
+    /// HMAC-SHA256 precompile event.
+    HmacSha256(HmacSha256Event),
+    /// SHA-256 compress blocks precompile event.
+    Sha256CompressBlocks(Sha256CompressBlocksEvent),
+    /// SHA-512 compress precompile event.
+    Sha512Compress(Sha512CompressEvent),
+    /// F64 add, mul and div precompile event.
+    F64Op(F64OpEvent),
+    /// Hex decode precompile event.
+    HexDecode(DecodeEvent),
+    /// Base64 decode precompile event.
+    Base64Decode(DecodeEvent),
+    /// Out-of-tree precompile event.
+    Plugin(PluginEvent),
+}
+
+impl PrecompileEvent {
+    /// Map the slices of the memory records the event keeps in the arenas of its
+    /// [`PrecompileEvents`], e.g. to move them to other arenas.
+    fn map_arena_slices(
+        &mut self,
+        mut reads: impl FnMut(ArenaSlice) -> ArenaSlice,
+        mut writes: impl FnMut(ArenaSlice) -> ArenaSlice,
+    ) {
+        match self {
+            PrecompileEvent::MemCopy32(e) | PrecompileEvent::MemCopy64(e) => {
+                e.read_records = reads(e.read_records);
+                e.write_records = writes(e.write_records);
+            }
+            PrecompileEvent::Bn254MulAdd(e) => {
+                e.x_memory_records = writes(e.x_memory_records);
+                e.a_memory_records = reads(e.a_memory_records);
+                e.b_memory_records = reads(e.b_memory_records);
+            }
+            _ => {}
+        }
+    }
 }
 
 /// Trait to retrieve all the local memory events from a vec of precompile events.
@@ -108,12 +261,29 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::KeccakPermute(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::KeccakAccumulate(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::KeccakSponge(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::KeccakMerkle(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::WithdrawRoot(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::EdDecompress(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::Ed25519Verify(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::Secp256k1Add(e)
                 | PrecompileEvent::Secp256r1Add(e)
                 | PrecompileEvent::EdAdd(e)
+                | PrecompileEvent::JubjubAdd(e)
+                | PrecompileEvent::BabyJubjubAdd(e)
                 | PrecompileEvent::Bn254Add(e)
                 | PrecompileEvent::Bls12381Add(e) => {
                     iterators.push(e.local_mem_access.iter());
@@ -127,7 +297,12 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::Secp256k1Decompress(e)
                 | PrecompileEvent::Secp256r1Decompress(e)
                 | PrecompileEvent::K256Decompress(e)
-                | PrecompileEvent::Bls12381Decompress(e) => {
+                | PrecompileEvent::Bls12381Decompress(e)
+                | PrecompileEvent::Bn254Decompress(e)
+                | PrecompileEvent::Bn254G2Decompress(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Secp256k1EcRecover(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
                 PrecompileEvent::Uint256Mul(e) => {
@@ -136,6 +311,12 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::U256xU2048Mul(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::U256xU4096Mul(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::RsaModExp(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::Bls12381Fp(e) | PrecompileEvent::Bn254Fp(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
@@ -145,12 +326,69 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::Bls12381Fp2Mul(e) | PrecompileEvent::Bn254Fp2Mul(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::Bls12381Fp6Mul(e) | PrecompileEvent::Bn254Fp6Mul(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
                 PrecompileEvent::Bn254ScalarMulAdd(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
                 PrecompileEvent::Bn254MulAdd(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::Bn254Mont(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Bn254InnerProduct(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::FrNtt(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::MemCopy32(e) | PrecompileEvent::MemCopy64(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Csel256(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::RlpScan(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::PoseidonMerkle(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::ZkTrieNode(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Uint384MulAdd(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::BarrettReduce(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::AesRound(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::ChaCha20Block(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::HmacSha256(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Sha256CompressBlocks(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Sha512Compress(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::F64Op(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::HexDecode(e) | PrecompileEvent::Base64Decode(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Plugin(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
             }
         }
 
@@ -159,9 +397,18 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
 }
 
 /// A record of all the precompile events.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+///
+/// The memory records of the most frequent events are kept in arenas shared by all the events,
+/// rather than in a `Vec` per event, and are looked up with [`Self::reads`] and [`Self::writes`].
+#[derive(
+    Clone, Debug, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct PrecompileEvents {
     events: HashMap<SyscallCode, Vec<(SyscallEvent, PrecompileEvent)>>,
+    /// The memory read records of the events.
+    pub read_arena: EventArena<MemoryReadRecord>,
+    /// The memory write records of the events.
+    pub write_arena: EventArena<MemoryWriteRecord>,
 }
 
 impl Default for PrecompileEvents {
@@ -173,19 +420,41 @@ impl Default for PrecompileEvents {
             }
         }
 
-        Self { events }
+        Self { events, read_arena: EventArena::default(), write_arena: EventArena::default() }
     }
 }
 
 impl PrecompileEvents {
     pub(crate) fn append(&mut self, other: &mut PrecompileEvents) {
+        let read_offset = self.read_arena.append(&mut other.read_arena);
+        let write_offset = self.write_arena.append(&mut other.write_arena);
         for (syscall, events) in other.events.iter_mut() {
             if !events.is_empty() {
+                for (_, event) in events.iter_mut() {
+                    event.map_arena_slices(
+                        |slice| slice.offset(read_offset),
+                        |slice| slice.offset(write_offset),
+                    );
+                }
                 self.events.entry(*syscall).or_default().append(events);
             }
         }
     }
 
+    /// The memory read records of a slice of [`Self::read_arena`].
+    #[inline]
+    #[must_use]
+    pub fn reads(&self, slice: ArenaSlice) -> &[MemoryReadRecord] {
+        self.read_arena.get(slice)
+    }
+
+    /// The memory write records of a slice of [`Self::write_arena`].
+    #[inline]
+    #[must_use]
+    pub fn writes(&self, slice: ArenaSlice) -> &[MemoryWriteRecord] {
+        self.write_arena.get(slice)
+    }
+
     #[inline]
     /// Add a precompile event for a given syscall code.
     pub fn add_event(
@@ -210,17 +479,41 @@ impl PrecompileEvents {
         self.events.values().flatten()
     }
 
-    #[inline]
-    /// Insert a vector of precompile events for a given syscall code.
+    /// Insert a vector of precompile events for a given syscall code, taken from `source` along
+    /// with their memory records.
     pub(crate) fn insert(
         &mut self,
         syscall_code: SyscallCode,
-        events: Vec<(SyscallEvent, PrecompileEvent)>,
+        mut events: Vec<(SyscallEvent, PrecompileEvent)>,
+        source: &PrecompileEvents,
     ) {
         assert!(syscall_code.should_send() == 1);
+        for (_, event) in &mut events {
+            event.map_arena_slices(
+                |slice| self.read_arena.copy_from(&source.read_arena, slice),
+                |slice| self.write_arena.copy_from(&source.write_arena, slice),
+            );
+        }
         self.events.insert(syscall_code, events);
     }
 
+    /// Take out the events, leaving their memory records, which are moved along with the events
+    /// by [`Self::insert`].
+    pub(crate) fn take_events(
+        &mut self,
+    ) -> HashMap<SyscallCode, Vec<(SyscallEvent, PrecompileEvent)>> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Remove the last precompile event for a given syscall code.
+    #[inline]
+    pub(crate) fn pop_event(
+        &mut self,
+        syscall_code: SyscallCode,
+    ) -> Option<(SyscallEvent, PrecompileEvent)> {
+        self.events.get_mut(&syscall_code).and_then(Vec::pop)
+    }
+
     /// Get the number of precompile events.
     #[inline]
     #[must_use]
@@ -228,13 +521,6 @@ impl PrecompileEvents {
         self.events.len()
     }
 
-    #[inline]
-    pub(crate) fn into_iter(
-        self,
-    ) -> impl Iterator<Item = (SyscallCode, Vec<(SyscallEvent, PrecompileEvent)>)> {
-        self.events.into_iter()
-    }
-
     #[inline]
     pub(crate) fn iter(
         &self,
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{LookupId, MemoryLocalEvent};
+
+/// Plugin Event.
+///
+/// This event is emitted by the syscalls registered for out-of-tree precompiles with
+/// [`crate::Executor::register_syscall`]. The executor does not interpret the payload: it is encoded
+/// by the syscall and decoded by the chip of the same precompile.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct PluginEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The payload of the event, typically the memory records of the precompile and its inputs.
+    pub data: Vec<u8>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
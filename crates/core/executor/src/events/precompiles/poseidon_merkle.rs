@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use sp1_curves::poseidon::POSEIDON_NUM_ROUNDS;
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// The maximum number of levels of the path of a single Poseidon Merkle root syscall.
+pub const POSEIDON_MERKLE_MAX_DEPTH: usize = 8;
+
+/// The number of rows of a single Poseidon Merkle root event, with a row for every round of the
+/// permutation of every level.
+pub const POSEIDON_MERKLE_ROWS: usize = POSEIDON_MERKLE_MAX_DEPTH * POSEIDON_NUM_ROUNDS;
+
+/// Poseidon Merkle Root Event.
+///
+/// This event is emitted when the root of a Poseidon Merkle path is computed from a node.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct PoseidonMerkleEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the node, which is replaced by the root.
+    pub node_ptr: u32,
+    /// The node as a list of words.
+    pub node: Vec<u32>,
+    /// The pointer to the depth of the path, which is followed by the index and the levels.
+    pub path_ptr: u32,
+    /// The number of levels of the path.
+    pub depth: u32,
+    /// The index of the node, whose `i`-th bit is set if the node is the right child at level `i`.
+    pub index: u32,
+    /// The siblings of every level as lists of words.
+    pub siblings: Vec<Vec<u32>>,
+    /// The domains of every level as lists of words.
+    pub domains: Vec<Vec<u32>>,
+    /// The memory records for the node.
+    pub node_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory record for the depth of the path.
+    pub depth_memory_record: MemoryReadRecord,
+    /// The memory record for the index of the node.
+    pub index_memory_record: MemoryReadRecord,
+    /// The memory records for the sibling and domain of every level.
+    pub level_memory_records: Vec<Vec<MemoryReadRecord>>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
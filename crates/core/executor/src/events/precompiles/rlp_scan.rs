@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// The maximum number of items scanned by a single `RLP_SCAN` syscall.
+pub const RLP_SCAN_MAX_ITEMS: usize = 16;
+
+/// The number of words of the state of an `RLP_SCAN` syscall: the pointer to the payload, the
+/// length of the payload, the offset of the next item, and the number of items scanned.
+pub const RLP_SCAN_STATE_WORDS: usize = 4;
+
+/// The number of words of an entry of the table written by `RLP_SCAN`: the offset of the payload
+/// of the item, and its length with the list flag in the top byte.
+pub const RLP_SCAN_ENTRY_WORDS: usize = 2;
+
+/// The number of words read around the header of an item, which cover the header at any
+/// alignment.
+pub const RLP_SCAN_WINDOW_WORDS: usize = 2;
+
+/// The maximum number of bytes of an RLP header accepted by `RLP_SCAN`: the prefix, followed by
+/// at most three bytes of length, so that lengths are below `2^24`.
+pub const RLP_MAX_HEADER_BYTES: usize = 4;
+
+/// The kind of an RLP item, determined by the first byte of its encoding.
+#[derive(
+    Default,
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub enum RlpKind {
+    /// A single byte below `0x80`, which is its own encoding.
+    #[default]
+    Byte,
+    /// A string of at most 55 bytes, prefixed by `0x80` plus its length.
+    ShortString,
+    /// A longer string, prefixed by `0xb7` plus the length of its big-endian length.
+    LongString,
+    /// A list whose payload takes at most 55 bytes, prefixed by `0xc0` plus its length.
+    ShortList,
+    /// A longer list, prefixed by `0xf7` plus the length of its big-endian length.
+    LongList,
+}
+
+impl RlpKind {
+    /// All the kinds, in the order of their prefixes.
+    pub const ALL: [RlpKind; 5] = [
+        RlpKind::Byte,
+        RlpKind::ShortString,
+        RlpKind::LongString,
+        RlpKind::ShortList,
+        RlpKind::LongList,
+    ];
+
+    /// The kind of the item starting with `prefix`.
+    #[must_use]
+    pub const fn from_prefix(prefix: u8) -> Self {
+        match prefix {
+            0x00..=0x7f => RlpKind::Byte,
+            0x80..=0xb7 => RlpKind::ShortString,
+            0xb8..=0xbf => RlpKind::LongString,
+            0xc0..=0xf7 => RlpKind::ShortList,
+            0xf8..=0xff => RlpKind::LongList,
+        }
+    }
+
+    /// The smallest prefix of the kind.
+    #[must_use]
+    pub const fn base(self) -> u8 {
+        match self {
+            RlpKind::Byte => 0x00,
+            RlpKind::ShortString => 0x80,
+            RlpKind::LongString => 0xb8,
+            RlpKind::ShortList => 0xc0,
+            RlpKind::LongList => 0xf8,
+        }
+    }
+
+    /// The number of prefixes of the kind.
+    #[must_use]
+    pub const fn width(self) -> u8 {
+        match self {
+            RlpKind::Byte => 0x80,
+            RlpKind::ShortString | RlpKind::ShortList => 56,
+            RlpKind::LongString | RlpKind::LongList => 8,
+        }
+    }
+
+    /// Whether the items of the kind are lists.
+    #[must_use]
+    pub const fn is_list(self) -> bool {
+        matches!(self, RlpKind::ShortList | RlpKind::LongList)
+    }
+
+    /// Whether the items of the kind encode the length of their payload after the prefix.
+    #[must_use]
+    pub const fn is_long(self) -> bool {
+        matches!(self, RlpKind::LongString | RlpKind::LongList)
+    }
+}
+
+/// The decoded header of an RLP item.
+#[derive(
+    Default,
+    PartialEq,
+    Eq,
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub struct RlpHeader {
+    /// The kind of the item.
+    pub kind: RlpKind,
+    /// The number of bytes of the header, which is zero for a single byte.
+    pub header_len: u32,
+    /// The number of bytes of the payload of the item.
+    pub len: u32,
+}
+
+impl RlpHeader {
+    /// Decodes the header at the start of `bytes`, which holds at least [`RLP_MAX_HEADER_BYTES`]
+    /// bytes, ignoring the bytes past the header.
+    ///
+    /// Returns `None` if the header is not in canonical form, or if the length of the payload
+    /// takes more than three bytes.
+    #[must_use]
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let prefix = bytes[0];
+        let kind = RlpKind::from_prefix(prefix);
+        let kind_offset = u32::from(prefix - kind.base());
+        let (header_len, len) = match kind {
+            RlpKind::Byte => (0, 1),
+            RlpKind::ShortString | RlpKind::ShortList => {
+                // A single byte below `0x80` must be encoded as itself.
+                if kind == RlpKind::ShortString && kind_offset == 1 && bytes[1] < 0x80 {
+                    return None;
+                }
+                (1, kind_offset)
+            }
+            RlpKind::LongString | RlpKind::LongList => {
+                let len_len = kind_offset as usize + 1;
+                if len_len >= RLP_MAX_HEADER_BYTES {
+                    return None;
+                }
+                // The length has no leading zeros, and doesn't fit in the short form.
+                if bytes[1] == 0 {
+                    return None;
+                }
+                let len =
+                    bytes[1..=len_len].iter().fold(0u32, |acc, &byte| (acc << 8) | u32::from(byte));
+                if len < 56 {
+                    return None;
+                }
+                (1 + len_len as u32, len)
+            }
+        };
+        Some(Self { kind, header_len, len })
+    }
+}
+
+/// RLP Scan Event.
+///
+/// This event is emitted when the items of an RLP payload are scanned from an offset, writing the
+/// offset and the length of the payload of every item to a table.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct RlpScanEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The original state as a list of words.
+    pub state: Vec<u32>,
+    /// The pointer to the table of items.
+    pub table_ptr: u32,
+    /// The words around the header of every item scanned.
+    pub windows: Vec<Vec<u32>>,
+    /// The entries of the table, for every item scanned.
+    pub entries: Vec<Vec<u32>>,
+    /// The memory records for the words around the headers.
+    pub window_memory_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for the entries of the table.
+    pub entry_memory_records: Vec<Vec<MemoryWriteRecord>>,
+    /// The memory records for the state.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// The number of steps of the exponentiation ladder of a single RSA modexp syscall.
+///
+/// Every step is a modular squaring or a multiplication by the base, which covers the ladders of
+/// the common public exponents 3, 17, 257 and 65537.
+pub const RSA_LADDER_STEPS: usize = 17;
+
+/// RSA Modexp Event.
+///
+/// This event is emitted when a base is raised to a public exponent modulo an RSA-2048 or RSA-4096
+/// modulus, which is the core of RSA signature verification.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct RsaModExpEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the base, which is overwritten with the result.
+    pub x_ptr: u32,
+    /// The base as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the modulus, which is followed by the exponent.
+    pub params_ptr: u32,
+    /// The modulus as a list of words.
+    pub modulus: Vec<u32>,
+    /// The public exponent.
+    pub exponent: u32,
+    /// The memory records for the base, written with the result.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the modulus and the exponent.
+    pub params_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// The steps of the square-and-multiply ladder raising a base to `exponent`, where `true` is a
+/// squaring and `false` a multiplication by the base.
+///
+/// Returns `None` if the exponent is smaller than two, or if its ladder takes more than
+/// [`RSA_LADDER_STEPS`] steps.
+#[must_use]
+pub fn rsa_ladder(exponent: u32) -> Option<Vec<bool>> {
+    if exponent < 2 {
+        return None;
+    }
+    let mut steps = Vec::new();
+    for i in (0..31 - exponent.leading_zeros()).rev() {
+        steps.push(true);
+        if (exponent >> i) & 1 == 1 {
+            steps.push(false);
+        }
+    }
+    (steps.len() <= RSA_LADDER_STEPS).then_some(steps)
+}
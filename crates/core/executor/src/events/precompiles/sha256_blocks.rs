@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent, SyscallEvent,
+};
+
+/// The largest number of blocks compressed by a single `SHA256_COMPRESS_BLOCKS` call, which is
+/// the most whose steps fit in the cycles of a syscall.
+pub const SHA256_BLOCKS_MAX_BLOCKS: usize = 5;
+
+/// The number of cycles taken by each block: one to write the block, 48 for the `SHA_EXTEND` call
+/// and two for the `SHA_COMPRESS` call.
+pub const SHA256_BLOCKS_STEP_CYCLES: u32 = 51;
+
+/// The offset of the `SHA_EXTEND` call from the start of a block.
+pub const SHA256_BLOCKS_EXTEND_OFFSET: u32 = 1;
+
+/// The offset of the `SHA_COMPRESS` call from the start of a block.
+pub const SHA256_BLOCKS_COMPRESS_OFFSET: u32 = 49;
+
+/// The byte offset of the SHA-256 state from the message schedule in the buffer.
+pub const SHA256_BLOCKS_H_OFFSET: u32 = 64 * 4;
+
+/// The byte offset of the number of blocks from the message schedule in the buffer.
+pub const SHA256_BLOCKS_COUNT_OFFSET: u32 = (64 + 8) * 4;
+
+/// SHA-256 Compress Blocks Event.
+///
+/// This event is emitted when consecutive blocks are compressed into a SHA-256 state. The SHA-256
+/// extend and compress operations of each block are emitted as events of their own, whose
+/// syscalls are listed in `sha_syscalls`.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct Sha256CompressBlocksEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the message schedule, followed by the SHA-256 state and the number of
+    /// blocks.
+    pub w_ptr: u32,
+    /// The pointer to the blocks.
+    pub blocks_ptr: u32,
+    /// The memory record for the number of blocks.
+    pub count_read_record: MemoryReadRecord,
+    /// The memory records for each block.
+    pub block_read_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for each block written to the message schedule.
+    pub w_write_records: Vec<Vec<MemoryWriteRecord>>,
+    /// The `SHA_EXTEND` and `SHA_COMPRESS` syscalls of each block.
+    pub sha_syscalls: Vec<(SyscallEvent, SyscallEvent)>,
+    /// The local memory accesses.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
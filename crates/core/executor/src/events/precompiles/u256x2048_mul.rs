@@ -8,7 +8,9 @@ use crate::events::{
 /// `U256xU2048` Mul Event.
 ///
 /// This event is emitted when a `U256xU2048` mul operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct U256xU2048MulEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
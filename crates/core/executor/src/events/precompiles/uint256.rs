@@ -8,7 +8,9 @@ use crate::events::{
 /// Uint256 Mul Event.
 ///
 /// This event is emitted when a uint256 mul operation is performed.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct Uint256MulEvent {
     /// The lookup identifier.
     pub lookup_id: LookupId,
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use sp1_curves::poseidon::POSEIDON_NUM_ROUNDS;
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId, MemoryLocalEvent,
+};
+
+/// The number of rows of a single zkTrie node hash event, with a row for every round of the
+/// permutation.
+pub const ZKTRIE_NODE_ROWS: usize = POSEIDON_NUM_ROUNDS;
+
+/// zkTrie Node Hash Event.
+///
+/// This event is emitted when the hash of a zkTrie node is computed from its type and its two
+/// children, with the Poseidon permutation applied to the state `[type, left, right]`.
+#[derive(
+    Default, Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct ZkTrieNodeEvent {
+    /// The lookup identifier.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the hash.
+    pub hash_ptr: u32,
+    /// The pointer to the node type, which is followed by the left and the right child.
+    pub node_ptr: u32,
+    /// The type of the node.
+    pub node_type: u32,
+    /// The left child as a list of words.
+    pub left: Vec<u32>,
+    /// The right child as a list of words.
+    pub right: Vec<u32>,
+    /// The memory records for the node type and the children.
+    pub node_memory_records: Vec<MemoryReadRecord>,
+    /// The memory records for the hash.
+    pub hash_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
@@ -6,7 +6,9 @@ use super::LookupId;
 ///
 /// This object encapsulated the information needed to prove a syscall invocation from the CPU table.
 /// This includes its shard, clk, syscall id, arguments, other relevant information.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct SyscallEvent {
     /// The shard number.
     pub shard: u32,
@@ -3,7 +3,20 @@ use serde::Serialize;
 use std::fmt::Display;
 
 /// A unique identifier for lookups.
-#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
 
 pub struct LookupId(pub u64);
 
@@ -6,23 +6,35 @@ use std::{
 
 use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
-use sp1_stark::SP1CoreOpts;
+use sp1_stark::{SP1CoreOpts, ShardPolicy};
 use thiserror::Error;
 
 use crate::{
+    backtrace::{CallStack, GuestBacktrace},
+    checkpoint::ExecutionCheckpoint,
     context::SP1Context,
     dependencies::{emit_cpu_dependencies, emit_divrem_dependencies},
     events::{
         AluEvent, CpuEvent, LookupId, MemoryAccessPosition, MemoryInitializeFinalizeEvent,
         MemoryLocalEvent, MemoryReadRecord, MemoryRecord, MemoryWriteRecord, SyscallEvent,
     },
+    gas::GasCostTable,
     hook::{HookEnv, HookRegistry},
+    hotspots::BlockProfiler,
+    instruction_trace::{InstructionTracer, TracedInstruction},
+    memcpy::MemcpyTracker,
     memory::{Entry, PagedMemory},
-    record::{ExecutionRecord, MemoryAccessRecord},
+    output::OutputRegistry,
+    prefetch::HintPrefetcher,
+    record::{split_threshold, ExecutionRecord, MemoryAccessRecord},
+    replay::{HintAccess, HintRecorder, HintReplay},
     report::ExecutionReport,
-    state::{ExecutionState, ForkState},
+    state::{ExecutionState, ForkState, HintRegion},
+    stream::StdinStream,
     subproof::{DefaultSubproofVerifier, SubproofVerifier},
-    syscalls::{default_syscall_map, Syscall, SyscallCode, SyscallContext},
+    syscalls::{default_syscall_map, Syscall, SyscallCode, SyscallContext, HALT_TYPED_EXIT},
+    trace_events::TraceEventWriter,
+    watch::{WatchRegistry, WatchedWrite},
     Instruction, Opcode, Program, Register,
 };
 
@@ -83,10 +95,14 @@ pub struct Executor<'a> {
     /// checkpoints. The value stored is whether or not it had a value at the beginning of the batch.
     pub uninitialized_memory_checkpoint: PagedMemory<bool>,
 
+    /// The address of a write to a hint region during the current cycle, which fails it.
+    pub hint_region_write: Option<u32>,
+
     /// The memory accesses for the current cycle.
     pub memory_accesses: MemoryAccessRecord,
 
-    /// The maximum number of cpu cycles to use for execution.
+    /// The maximum number of cpu cycles to use for execution, the lowest of the limits of the
+    /// context and of the options.
     pub max_cycles: Option<u64>,
 
     /// Skip deferred proof verification.
@@ -113,22 +129,82 @@ pub struct Executor<'a> {
     /// A buffer for writing trace events to a file.
     pub trace_buf: Option<BufWriter<File>>,
 
+    /// The recorder of the accesses to the input stream, if recording.
+    pub hint_recorder: Option<HintRecorder>,
+
+    /// The recorded accesses to the input stream, if replaying.
+    pub hint_replay: Option<HintReplay>,
+
+    /// The responses of the hook calls fetched ahead of the program, if prefetching.
+    pub hint_prefetcher: Option<HintPrefetcher>,
+
+    /// The source the input stream is read from lazily once its buffered inputs run out, if any.
+    pub stdin_stream: Option<StdinStream>,
+
+    /// The inputs read from the stdin stream since the last checkpoint, which the checkpoint must
+    /// hold to execute the shard again.
+    pub(crate) streamed_inputs: Vec<Vec<u8>>,
+
+    /// The writer of the profile of the execution, if profiling.
+    pub trace_events: Option<TraceEventWriter>,
+
     /// The state of the runtime when in unconstrained mode.
     pub unconstrained_state: ForkState,
 
     /// Report of the program execution.
     pub report: ExecutionReport,
 
+    /// Tracks the calls to the software memory routines for the report, if enabled.
+    pub memcpy_tracker: Option<MemcpyTracker>,
+
+    /// Whether to report the changes made by the execution to the memory of the program in
+    /// [`ExecutionReport::state_diff`].
+    pub report_state_diff: bool,
+
+    /// Profiles the basic blocks and loops of the program for the report, if enabled.
+    pub block_profiler: Option<BlockProfiler>,
+
+    /// The calls of the program which have not returned yet, for the backtraces of traps.
+    pub(crate) call_stack: CallStack,
+
+    /// The weights used to meter the gas used by the execution.
+    pub gas_costs: GasCostTable,
+
     /// Verifier used to sanity check `verify_sp1_proof` during runtime.
     pub subproof_verifier: Arc<dyn SubproofVerifier + 'a>,
 
     /// Registry of hooks, to be invoked by writing to certain file descriptors.
     pub hook_registry: HookRegistry<'a>,
 
+    /// Registry of the breakpoints and watchpoints, called as the program runs.
+    pub watch_registry: WatchRegistry<'a>,
+
+    /// Registry of the callbacks intercepting the outputs of the program.
+    pub output_registry: OutputRegistry<'a>,
+
+    /// The sink streaming the executed instructions passing a filter, if enabled.
+    pub instruction_tracer: Option<InstructionTracer<'a>>,
+
     /// The maximal shapes for the program.
     pub maximal_shapes: Option<Vec<HashMap<String, usize>>>,
+
+    /// The caps on the number of events of a syscall put in a single shard, which must match the
+    /// caps the deferred events are split with.
+    pub split_caps: HashMap<SyscallCode, usize>,
 }
 
+/// The number of instructions logged on each side of the program counter the execution failed at.
+const FAULT_CONTEXT_INSTRUCTIONS: u32 = 4;
+
+/// The logarithm of the maximum number of shards of a provable execution.
+///
+/// Clocks are relative to their shard, and the CPU chip range checks the shard to 24 bits, which
+/// is the bound the memory access timestamp checks rely on.
+pub const MAX_LOG_NUMBER_OF_SHARDS: usize = 24;
+
+/// The maximum number of shards of a provable execution.
+pub const MAX_NUMBER_OF_SHARDS: usize = 1 << MAX_LOG_NUMBER_OF_SHARDS;
+
 /// The different modes the executor can run in.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExecutorMode {
@@ -159,9 +235,14 @@ pub enum ExecutionError {
     #[error("breakpoint encountered")]
     Breakpoint(),
 
-    /// The execution failed with an exceeded cycle limit.
-    #[error("exceeded cycle limit of {0}")]
-    ExceededCycleLimit(u64),
+    /// The execution ran out of cycles before halting.
+    #[error("exceeded cycle limit of {cycles} cycles at pc {pc:#x}")]
+    CycleLimitExceeded {
+        /// The number of cycles executed, which is the cycle limit.
+        cycles: u64,
+        /// The program counter of the next instruction.
+        pc: u32,
+    },
 
     /// The execution failed because the syscall was called in unconstrained mode.
     #[error("syscall called in unconstrained mode")]
@@ -171,9 +252,80 @@ pub enum ExecutionError {
     #[error("got unimplemented as opcode")]
     Unimplemented(),
 
+    /// The execution failed because the program's denylist forbids the opcode.
+    #[error("denied opcode {0} at pc {1:#x}")]
+    DeniedOpcode(Opcode, u32),
+
     /// The program ended in unconstrained mode.
     #[error("program ended in unconstrained mode")]
     EndInUnconstrained(),
+
+    /// The program halted without zeroizing a region declared with `ZEROIZE_ON_HALT`.
+    #[error("secret region at address {0:#x} was not zeroized before halt")]
+    SecretRegionNotZeroized(u32),
+
+    /// The program halted while a keccak accumulator was still waiting for its final block.
+    #[error("keccak accumulator at address {0:#x} was not finalized before halt")]
+    UnfinalizedKeccakAccumulator(u32),
+
+    /// A checkpoint was taken in the middle of a shard or of an unconstrained block.
+    #[error("cannot checkpoint the execution at clk {0}")]
+    CheckpointUnavailable(u64),
+
+    /// An RV64IM program was executed in a mode other than [`ExecutorMode::Simple`].
+    #[error("RV64IM programs can only be executed, not proven")]
+    UnprovableRv64Program(),
+
+    /// A program using the compressed (C) extension was executed in a mode other than
+    /// [`ExecutorMode::Simple`].
+    #[error("programs using the compressed extension can only be executed, not proven")]
+    UnprovableCompressedProgram(),
+
+    /// The program wrote to a region mapped from the input stream by `HINT_MAP`, which is
+    /// read-only.
+    #[error("write to read-only hint region at address {0:#x}")]
+    HintRegionWrite(u32),
+
+    /// The execution needs more shards than the shard range check of the CPU chip allows.
+    #[error("exceeded the limit of {} shards", MAX_NUMBER_OF_SHARDS)]
+    ShardLimitExceeded(),
+
+    /// The program panicked or trapped, with its backtrace at that point.
+    #[error("{error}\n{backtrace}")]
+    Trap {
+        /// The error the program trapped with.
+        error: Box<ExecutionError>,
+        /// The backtrace of the program.
+        backtrace: GuestBacktrace,
+    },
+}
+
+impl ExecutionError {
+    /// The error the execution failed with, without the backtrace of a trap.
+    #[must_use]
+    pub fn cause(&self) -> &ExecutionError {
+        match self {
+            ExecutionError::Trap { error, .. } => error,
+            error => error,
+        }
+    }
+
+    /// Whether the error is a panic or a trap of the program, as opposed to a limit of the
+    /// execution or a misuse of the executor.
+    #[must_use]
+    pub const fn is_trap(&self) -> bool {
+        matches!(
+            self,
+            ExecutionError::HaltWithNonZeroExitCode(_)
+                | ExecutionError::InvalidMemoryAccess(..)
+                | ExecutionError::UnsupportedSyscall(_)
+                | ExecutionError::Breakpoint()
+                | ExecutionError::InvalidSyscallUsage(_)
+                | ExecutionError::Unimplemented()
+                | ExecutionError::DeniedOpcode(..)
+                | ExecutionError::HintRegionWrite(_)
+        )
+    }
 }
 
 macro_rules! assert_valid_memory_access {
@@ -194,7 +346,9 @@ impl<'a> Executor<'a> {
     ///
     /// # Panics
     ///
-    /// This function may panic if it fails to create the trace file if `TRACE_FILE` is set.
+    /// This function may panic if it fails to create the trace file if `TRACE_FILE` is set, the
+    /// replay file if the context records the hints, or the trace-event file if it profiles the
+    /// execution.
     #[must_use]
     pub fn with_context(program: Program, opts: SP1CoreOpts, context: SP1Context<'a>) -> Self {
         // Create a shared reference to the program.
@@ -204,12 +358,15 @@ impl<'a> Executor<'a> {
         let record = ExecutionRecord::new(program.clone());
 
         // If `TRACE_FILE`` is set, initialize the trace buffer.
+        #[cfg(feature = "fs")]
         let trace_buf = if let Ok(trace_file) = std::env::var("TRACE_FILE") {
             let file = File::create(trace_file).unwrap();
             Some(BufWriter::new(file))
         } else {
             None
         };
+        #[cfg(not(feature = "fs"))]
+        let trace_buf = None;
 
         // Determine the maximum number of cycles for any syscall.
         let syscall_map = default_syscall_map();
@@ -219,6 +376,17 @@ impl<'a> Executor<'a> {
         let subproof_verifier =
             context.subproof_verifier.unwrap_or_else(|| Arc::new(DefaultSubproofVerifier::new()));
         let hook_registry = context.hook_registry.unwrap_or_default();
+        let max_cycles = context.max_cycles.into_iter().chain(opts.max_cycles).min();
+        #[cfg(feature = "fs")]
+        let hint_recorder = context
+            .record_hints
+            .map(|path| HintRecorder::create(path).expect("failed to create the hint replay file"));
+        #[cfg(feature = "fs")]
+        let trace_events = context.trace_events.map(|path| {
+            TraceEventWriter::create(path).expect("failed to create the trace-event file")
+        });
+        #[cfg(not(feature = "fs"))]
+        let (hint_recorder, trace_events) = (None, None);
 
         Self {
             record,
@@ -231,6 +399,15 @@ impl<'a> Executor<'a> {
             cycle_tracker: HashMap::new(),
             io_buf: HashMap::new(),
             trace_buf,
+            hint_recorder,
+            hint_replay: context.replay_hints,
+            #[cfg(feature = "threads")]
+            hint_prefetcher: context.prefetch_hints.map(HintPrefetcher::spawn),
+            #[cfg(not(feature = "threads"))]
+            hint_prefetcher: None,
+            stdin_stream: None,
+            streamed_inputs: Vec::new(),
+            trace_events,
             unconstrained: false,
             unconstrained_state: ForkState::default(),
             syscall_map,
@@ -238,11 +415,19 @@ impl<'a> Executor<'a> {
             emit_global_memory_events: true,
             max_syscall_cycles,
             report: ExecutionReport::default(),
+            memcpy_tracker: context.memcpy_symbols.map(MemcpyTracker::new),
+            report_state_diff: context.state_diff,
+            block_profiler: context.profile_blocks.then(BlockProfiler::default),
+            call_stack: CallStack::default(),
+            gas_costs: context.gas_costs.unwrap_or_default(),
             print_report: false,
             subproof_verifier,
             hook_registry,
+            watch_registry: context.watch_registry,
+            output_registry: context.output_registry,
+            instruction_tracer: context.instruction_tracer,
             opts,
-            max_cycles: context.max_cycles,
+            max_cycles,
             deferred_proof_verification: if context.skip_deferred_proof_verification {
                 DeferredProofVerification::Disabled
             } else {
@@ -250,11 +435,26 @@ impl<'a> Executor<'a> {
             },
             memory_checkpoint: PagedMemory::new_preallocated(),
             uninitialized_memory_checkpoint: PagedMemory::new_preallocated(),
+            hint_region_write: None,
             local_memory_access: HashMap::new(),
             maximal_shapes: None,
+            split_caps: HashMap::new(),
         }
     }
 
+    /// Registers the implementation of a syscall, returning the one it replaces, if any.
+    ///
+    /// Out-of-tree precompiles are expected to use one of the reserved `PLUGIN_*` syscall codes,
+    /// and to emit [`crate::events::PrecompileEvent::Plugin`] events for the chip that proves them.
+    pub fn register_syscall(
+        &mut self,
+        code: SyscallCode,
+        syscall: Arc<dyn Syscall>,
+    ) -> Option<Arc<dyn Syscall>> {
+        self.max_syscall_cycles = self.max_syscall_cycles.max(syscall.num_extra_cycles());
+        self.syscall_map.insert(code, syscall)
+    }
+
     /// Invokes a hook with the given file descriptor `fd` with the data `buf`.
     ///
     /// # Errors
@@ -275,6 +475,81 @@ impl<'a> Executor<'a> {
         HookEnv { runtime: self }
     }
 
+    /// Records an access to the input stream, or checks it against the recorded one when replaying.
+    ///
+    /// The access is only built if recording or replaying.
+    pub(crate) fn hint_access(&mut self, access: impl FnOnce() -> HintAccess) {
+        if let Some(recorder) = &mut self.hint_recorder {
+            recorder.record(&access()).expect("failed to record the hint access");
+        } else if let Some(replay) = &mut self.hint_replay {
+            replay.check(&access());
+        }
+    }
+
+    /// Records a call to the hook of `fd`, and inserts its outputs at the front of the input stream.
+    pub(crate) fn hook_call(&mut self, fd: u32, request: &[u8], outputs: Vec<Vec<u8>>) {
+        let offset = self.state.input_stream_ptr;
+        let global_clk = self.state.global_clk;
+        self.hint_access(|| HintAccess::Hook { offset, global_clk, fd, request: request.to_vec() });
+        self.state.input_stream.splice(offset..offset, outputs);
+    }
+
+    /// Reads the next input from the stdin stream if the buffered inputs were all consumed.
+    pub(crate) fn pull_input(&mut self) {
+        if self.state.input_stream_ptr >= self.state.input_stream.len() {
+            self.read_stdin_stream();
+        }
+    }
+
+    /// Reads the next input from the stdin stream to the end of the input stream, returning
+    /// whether there was one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stdin stream fails to be read.
+    pub(crate) fn read_stdin_stream(&mut self) -> bool {
+        // A replayed input stream holds every input served, including the streamed ones.
+        if self.state.replaying_hints {
+            return false;
+        }
+        let Some(stream) = &self.stdin_stream else { return false };
+        let Some(input) = stream.next_frame().expect("failed to read the stdin stream") else {
+            return false;
+        };
+        // An unconstrained block runs in the simple mode, but its reads are checkpointed too.
+        let mode = if self.unconstrained {
+            self.unconstrained_state.executor_mode
+        } else {
+            self.executor_mode
+        };
+        if mode == ExecutorMode::Checkpoint {
+            self.streamed_inputs.push(input.clone());
+        }
+        self.state.input_stream.push(input);
+        true
+    }
+
+    /// Writes an event to the profile of the execution, if profiling.
+    pub(crate) fn trace_event(
+        &mut self,
+        write: impl FnOnce(&mut TraceEventWriter) -> std::io::Result<()>,
+    ) {
+        if let Some(writer) = &mut self.trace_events {
+            write(writer).expect("failed to write the trace event");
+        }
+    }
+
+    /// Replaces the input stream with the replayed one at the start of the execution.
+    pub(crate) fn start_hint_replay(&mut self) {
+        if let Some(replay) = &self.hint_replay {
+            if self.state.global_clk == 0 && !self.state.replaying_hints {
+                self.state.input_stream = replay.input_stream();
+                self.state.input_stream_ptr = 0;
+                self.state.replaying_hints = true;
+            }
+        }
+    }
+
     /// Recover runtime state from a program and existing execution state.
     #[must_use]
     pub fn recover(program: Program, state: ExecutionState, opts: SP1CoreOpts) -> Self {
@@ -283,6 +558,43 @@ impl<'a> Executor<'a> {
         runtime
     }
 
+    /// Resume an execution from a checkpoint taken with [`Executor::checkpoint`].
+    ///
+    /// The program must be the one the checkpoint was taken from.
+    #[must_use]
+    pub fn resume(
+        program: Program,
+        checkpoint: ExecutionCheckpoint,
+        opts: SP1CoreOpts,
+        context: SP1Context<'a>,
+    ) -> Self {
+        let mut runtime = Self::with_context(program, opts, context);
+        runtime.state = checkpoint.state;
+        runtime.record.public_values = checkpoint.public_values;
+        runtime.report = checkpoint.report;
+        runtime.cycle_tracker = checkpoint.cycle_tracker;
+        runtime.io_buf = checkpoint.io_buf;
+        runtime
+    }
+
+    /// Take a checkpoint of the execution, from which it can be resumed with [`Executor::resume`].
+    ///
+    /// Checkpoints can only be taken between two calls to [`Executor::execute`], outside of
+    /// unconstrained blocks. The events of the shards executed so far are not part of the
+    /// checkpoint, so they must be taken from [`Executor::records`] beforehand.
+    pub fn checkpoint(&self) -> Result<ExecutionCheckpoint, ExecutionError> {
+        if self.unconstrained || !self.record.cpu_events.is_empty() {
+            return Err(ExecutionError::CheckpointUnavailable(self.state.global_clk));
+        }
+        Ok(ExecutionCheckpoint {
+            state: self.state.clone(),
+            public_values: self.record.public_values,
+            report: self.report.clone(),
+            cycle_tracker: self.cycle_tracker.clone(),
+            io_buf: self.io_buf.clone(),
+        })
+    }
+
     /// Get the current values of the registers.
     #[allow(clippy::single_match_else)]
     #[must_use]
@@ -354,9 +666,16 @@ impl<'a> Executor<'a> {
             }
         }
 
+        // An untouched word holds the value it will be initialized with on its first access.
         match record {
             Some(record) => record.value,
-            None => 0,
+            None => self
+                .state
+                .uninitialized_memory
+                .get(addr)
+                .copied()
+                .or_else(|| hint_region_word(&self.state.hint_regions, addr))
+                .unwrap_or(0),
         }
     }
 
@@ -416,10 +735,14 @@ impl<'a> Executor<'a> {
         let record: &mut MemoryRecord = match entry {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
-                // If addr has a specific value to be initialized with, use that, otherwise 0.
-                let value = self.state.uninitialized_memory.get(addr).unwrap_or(&0);
-                self.uninitialized_memory_checkpoint.entry(addr).or_insert_with(|| *value != 0);
-                entry.insert(MemoryRecord { value: *value, shard: 0, timestamp: 0 })
+                // If addr has a specific value to be initialized with, written by a hint read or
+                // mapped from a hint region, use that, otherwise 0.
+                let value = self.state.uninitialized_memory.get(addr).copied();
+                self.uninitialized_memory_checkpoint
+                    .entry(addr)
+                    .or_insert_with(|| value.is_some_and(|value| value != 0));
+                let value = value.or_else(|| hint_region_word(&self.state.hint_regions, addr));
+                entry.insert(MemoryRecord { value: value.unwrap_or(0), shard: 0, timestamp: 0 })
             }
         };
 
@@ -465,6 +788,11 @@ impl<'a> Executor<'a> {
         timestamp: u32,
         local_memory_access: Option<&mut HashMap<u32, MemoryLocalEvent>>,
     ) -> MemoryWriteRecord {
+        // The write goes through, and the cycle fails once the instruction has run.
+        if hint_region(&self.state.hint_regions, addr).is_some() {
+            self.hint_region_write.get_or_insert(addr);
+        }
+
         // Get the memory record entry.
         let entry = self.state.memory.entry(addr);
         if self.executor_mode == ExecutorMode::Checkpoint || self.unconstrained {
@@ -493,11 +821,14 @@ impl<'a> Executor<'a> {
         let record: &mut MemoryRecord = match entry {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
-                // If addr has a specific value to be initialized with, use that, otherwise 0.
-                let value = self.state.uninitialized_memory.get(addr).unwrap_or(&0);
-                self.uninitialized_memory_checkpoint.entry(addr).or_insert_with(|| *value != 0);
-
-                entry.insert(MemoryRecord { value: *value, shard: 0, timestamp: 0 })
+                // If addr has a specific value to be initialized with, written by a hint read or
+                // mapped from a hint region, use that, otherwise 0.
+                let value = self.state.uninitialized_memory.get(addr).copied();
+                self.uninitialized_memory_checkpoint
+                    .entry(addr)
+                    .or_insert_with(|| value.is_some_and(|value| value != 0));
+                let value = value.or_else(|| hint_region_word(&self.state.hint_regions, addr));
+                entry.insert(MemoryRecord { value: value.unwrap_or(0), shard: 0, timestamp: 0 })
             }
         };
 
@@ -526,14 +857,21 @@ impl<'a> Executor<'a> {
         }
 
         // Construct the memory write record.
-        MemoryWriteRecord::new(
+        let write_record = MemoryWriteRecord::new(
             record.value,
             record.shard,
             record.timestamp,
             prev_record.value,
             prev_record.shard,
             prev_record.timestamp,
-        )
+        );
+
+        // Let the host inspect the state of the program after a watched write.
+        if !self.watch_registry.watchpoints.is_empty() {
+            let (prev_value, value) = (write_record.prev_value, write_record.value);
+            self.hit_watchpoints(WatchedWrite { addr, prev_value, value });
+        }
+        write_record
     }
 
     /// Read from memory, assuming that all addresses are aligned.
@@ -701,8 +1039,10 @@ impl<'a> Executor<'a> {
         }
     }
 
+    /// Creates the syscall event of a call, with the nonce assigned to its lookup id.
     #[inline]
-    pub(crate) fn syscall_event(
+    #[must_use]
+    pub fn syscall_event(
         &self,
         clk: u32,
         syscall_id: u32,
@@ -721,6 +1061,23 @@ impl<'a> Executor<'a> {
         }
     }
 
+    /// Update the syscall counts and assign the nonce of the syscall with the given lookup id.
+    ///
+    /// The nonce is the row of the syscall's first event in the trace of its precompile shard.
+    pub(crate) fn assign_syscall_nonce(&mut self, syscall: SyscallCode, lookup_id: LookupId) {
+        // Syscalls in unconstrained mode are pruned from the record, so they take no row.
+        if self.unconstrained {
+            return;
+        }
+        let syscall_for_count = syscall.count_map();
+        let syscall_count = self.state.syscall_counts.entry(syscall_for_count).or_insert(0);
+        let threshold = split_threshold(syscall_for_count, self.opts.split_opts, &self.split_caps);
+        let multiplier = syscall_for_count.rows_per_event();
+        let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
+        self.record.nonce_lookup[lookup_id.0 as usize] = nonce;
+        *syscall_count += 1;
+    }
+
     fn emit_syscall(
         &mut self,
         clk: u32,
@@ -806,12 +1163,20 @@ impl<'a> Executor<'a> {
 
     /// Execute the given instruction over the current state of the runtime.
     #[allow(clippy::too_many_lines)]
-    fn execute_instruction(&mut self, instruction: &Instruction) -> Result<(), ExecutionError> {
+    pub(crate) fn execute_instruction(
+        &mut self,
+        instruction: &Instruction,
+    ) -> Result<(), ExecutionError> {
         let mut pc = self.state.pc;
         let mut clk = self.state.clk;
         let mut exit_code = 0u32;
+        // The syscalls leaving the unconstrained mode change `pc`, so the tracer keeps the
+        // original one.
+        let traced_pc = self.state.pc;
+        let mut traced_syscall = None;
 
-        let mut next_pc = self.state.pc.wrapping_add(4);
+        let instruction_size = self.program.instruction_size(self.state.pc);
+        let mut next_pc = self.state.pc.wrapping_add(instruction_size);
 
         let (a, b, c): (u32, u32, u32);
 
@@ -832,6 +1197,8 @@ impl<'a> Executor<'a> {
         if !self.unconstrained {
             self.report.opcode_counts[instruction.opcode] += 1;
             self.report.event_counts[instruction.opcode] += 1;
+            self.report.gas_used =
+                self.report.gas_used.saturating_add(self.gas_costs.opcodes[instruction.opcode]);
             match instruction.opcode {
                 Opcode::LB | Opcode::LH | Opcode::LW | Opcode::LBU | Opcode::LHU => {
                     self.report.event_counts[Opcode::ADD] += 2;
@@ -899,14 +1266,14 @@ impl<'a> Executor<'a> {
             Opcode::JAL => {
                 let (rd, imm) = instruction.j_type();
                 (b, c) = (imm, 0);
-                a = self.state.pc + 4;
+                a = self.state.pc + instruction_size;
                 self.rw(rd, a);
                 next_pc = self.state.pc.wrapping_add(imm);
             }
             Opcode::JALR => {
                 let (rd, rs1, imm) = instruction.i_type();
                 (b, c) = (self.rr(rs1, MemoryAccessPosition::B), imm);
-                a = self.state.pc + 4;
+                a = self.state.pc + instruction_size;
                 self.rw(rd, a);
                 next_pc = b.wrapping_add(c);
             }
@@ -928,40 +1295,41 @@ impl<'a> Executor<'a> {
                 c = self.rr(Register::X11, MemoryAccessPosition::C);
                 b = self.rr(Register::X10, MemoryAccessPosition::B);
                 let syscall = SyscallCode::from_u32(syscall_id);
+                traced_syscall = Some(syscall);
 
-                if self.print_report && !self.unconstrained {
+                // Whether to report the syscall is decided before running it, since some syscalls
+                // enter or leave the unconstrained mode.
+                let report_syscall = self.print_report && !self.unconstrained;
+                let trace_syscall = !self.unconstrained;
+                if report_syscall {
                     self.report.syscall_counts[syscall] += 1;
                 }
+                if !self.unconstrained {
+                    self.report.gas_used =
+                        self.report.gas_used.saturating_add(self.gas_costs.syscalls[syscall]);
+                }
 
-                // `hint_slice` is allowed in unconstrained mode since it is used to write the hint.
-                // Other syscalls are not allowed because they can lead to non-deterministic
-                // behavior, especially since many syscalls modify memory in place,
-                // which is not permitted in unconstrained mode. This will result in
-                // non-zero memory interactions when generating a proof.
-
+                // `hint_slice` is allowed in unconstrained mode since it is used to write the hint,
+                // and so are precompiles, which are used to compute hints. The events and memory
+                // records of precompiles in unconstrained mode are pruned from the record, since
+                // their effects are rolled back when leaving the unconstrained block. Other
+                // syscalls are not allowed because they can lead to non-deterministic behavior.
                 if self.unconstrained
-                    && (syscall != SyscallCode::EXIT_UNCONSTRAINED && syscall != SyscallCode::WRITE)
+                    && syscall != SyscallCode::EXIT_UNCONSTRAINED
+                    && syscall != SyscallCode::WRITE
+                    && syscall.should_send() != 1
                 {
                     return Err(ExecutionError::InvalidSyscallUsage(syscall_id as u64));
                 }
 
-                // Update the syscall counts.
-                let syscall_for_count = syscall.count_map();
-                let syscall_count = self.state.syscall_counts.entry(syscall_for_count).or_insert(0);
-                let (threshold, multiplier) = match syscall_for_count {
-                    SyscallCode::KECCAK_PERMUTE => (self.opts.split_opts.keccak, 24),
-                    SyscallCode::SHA_EXTEND => (self.opts.split_opts.sha_extend, 48),
-                    SyscallCode::SHA_COMPRESS => (self.opts.split_opts.sha_compress, 80),
-                    _ => (self.opts.split_opts.deferred, 1),
-                };
-                let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
-                self.record.nonce_lookup[syscall_lookup_id.0 as usize] = nonce;
-                *syscall_count += 1;
+                self.assign_syscall_nonce(syscall, syscall_lookup_id);
 
-                let syscall_impl = self.get_syscall(syscall).cloned();
-                if syscall.should_send() != 0 && self.executor_mode == ExecutorMode::Trace {
-                    self.emit_syscall(clk, syscall.syscall_id(), b, c, syscall_lookup_id);
+                // Only back-to-back `MEMCPY_32` calls are coalesced.
+                if syscall != SyscallCode::MEMCPY_32 && syscall != SyscallCode::MEMCPY_64 {
+                    self.state.pending_memcpy = None;
                 }
+
+                let syscall_impl = self.get_syscall(syscall).cloned();
                 let mut precompile_rt = SyscallContext::new(self);
                 precompile_rt.syscall_lookup_id = syscall_lookup_id;
                 let (precompile_next_pc, precompile_cycles, returned_exit_code) =
@@ -976,8 +1344,12 @@ impl<'a> Executor<'a> {
                             a = syscall_id;
                         }
 
-                        // If the syscall is `HALT` and the exit code is non-zero, return an error.
-                        if syscall == SyscallCode::HALT && precompile_rt.exit_code != 0 {
+                        // If the syscall is `HALT` and the exit code is non-zero, return an error
+                        // unless the program chose the exit code itself.
+                        if syscall == SyscallCode::HALT
+                            && precompile_rt.exit_code != 0
+                            && c != HALT_TYPED_EXIT
+                        {
                             return Err(ExecutionError::HaltWithNonZeroExitCode(
                                 precompile_rt.exit_code,
                             ));
@@ -992,6 +1364,20 @@ impl<'a> Executor<'a> {
                         return Err(ExecutionError::UnsupportedSyscall(syscall_id));
                     };
 
+                // The syscall is emitted once it has run, since its identifier in the syscall
+                // interaction carries the status it returned.
+                if syscall.should_send() != 0
+                    && !self.unconstrained
+                    && self.executor_mode == ExecutorMode::Trace
+                {
+                    let syscall_id = if syscall.returns_status() {
+                        syscall.status_syscall_id(a)
+                    } else {
+                        syscall.syscall_id()
+                    };
+                    self.emit_syscall(clk, syscall_id, b, c, syscall_lookup_id);
+                }
+
                 // Allow the syscall impl to modify state.clk/pc (exit unconstrained does this)
                 clk = self.state.clk;
                 pc = self.state.pc;
@@ -999,7 +1385,25 @@ impl<'a> Executor<'a> {
                 self.rw(t0, a);
                 next_pc = precompile_next_pc;
                 self.state.clk += precompile_cycles;
+                if trace_syscall && syscall.should_send() == 1 {
+                    self.state.shard_precompile_rows = self
+                        .state
+                        .shard_precompile_rows
+                        .saturating_add(syscall.rows_per_event() as u32);
+                }
+                if report_syscall {
+                    self.report.syscall_cycles[syscall] += 4 + u64::from(precompile_cycles);
+                }
+                if trace_syscall {
+                    let (global_clk, shard) = (self.state.global_clk, self.state.current_shard);
+                    self.trace_event(|trace| {
+                        trace.syscall(syscall, global_clk, 4 + u64::from(precompile_cycles), shard)
+                    });
+                }
                 exit_code = returned_exit_code;
+                if syscall == SyscallCode::HALT {
+                    self.report.exit_code = exit_code;
+                }
             }
             Opcode::EBREAK => {
                 return Err(ExecutionError::Breakpoint());
@@ -1009,6 +1413,23 @@ impl<'a> Executor<'a> {
             Opcode::UNIMP => {
                 return Err(ExecutionError::Unimplemented());
             }
+
+            // RV64IM instructions are executed by `execute_instruction_rv64`.
+            Opcode::LWU
+            | Opcode::LD
+            | Opcode::SD
+            | Opcode::ADDW
+            | Opcode::SUBW
+            | Opcode::SLLW
+            | Opcode::SRLW
+            | Opcode::SRAW
+            | Opcode::MULW
+            | Opcode::DIVW
+            | Opcode::DIVUW
+            | Opcode::REMW
+            | Opcode::REMUW => {
+                return Err(ExecutionError::Unimplemented());
+            }
         }
 
         // Update the program counter.
@@ -1017,6 +1438,20 @@ impl<'a> Executor<'a> {
         // Update the clk to the next cycle.
         self.state.clk += 4;
 
+        // Stream the instruction to the instruction tracer, if it passes the filter.
+        if let Some(tracer) = &self.instruction_tracer {
+            if tracer.filter.matches(traced_pc, instruction.opcode) {
+                tracer.trace(TracedInstruction {
+                    global_clk: self.state.global_clk,
+                    pc: traced_pc,
+                    opcode: instruction.opcode,
+                    rd: instruction.op_a,
+                    operands: [a, b, c],
+                    syscall: traced_syscall,
+                });
+            }
+        }
+
         // Emit the CPU event for this cycle.
         if self.executor_mode == ExecutorMode::Trace {
             self.emit_cpu(
@@ -1185,23 +1620,83 @@ impl<'a> Executor<'a> {
     /// Executes one cycle of the program, returning whether the program has finished.
     #[inline]
     #[allow(clippy::too_many_lines)]
-    fn execute_cycle(&mut self) -> Result<bool, ExecutionError> {
+    pub(crate) fn execute_cycle(&mut self) -> Result<bool, ExecutionError> {
+        // Let the host inspect the state of the program before the instruction.
+        if !self.watch_registry.breakpoints.is_empty() {
+            self.hit_breakpoints();
+        }
+
         // Fetch the instruction at the current program counter.
         let instruction = self.fetch();
 
+        // Enforce the opcode denylist of the program's machine profile.
+        if self.program.opcode_denylist.is_denied(instruction.opcode) {
+            return Err(ExecutionError::DeniedOpcode(instruction.opcode, self.state.pc));
+        }
+
         // Log the current state of the runtime.
         #[cfg(debug_assertions)]
         self.log(&instruction);
 
-        // Execute the instruction.
-        self.execute_instruction(&instruction)?;
+        // Track the calls and returns of the program, for the backtraces of traps.
+        if instruction.is_jump_instruction() {
+            self.call_stack.step(self.state.pc, &instruction);
+        }
+
+        // Attribute the cycle to a call site if it is spent in a software memory routine.
+        if let Some(tracker) = &mut self.memcpy_tracker {
+            if self.print_report && !self.unconstrained {
+                let len =
+                    self.state.memory.get(Register::X12 as u32).map_or(0, |record| record.value);
+                tracker.step(self.state.pc, len, &mut self.report.memcpy_call_sites);
+            }
+        }
+
+        // Execute the instruction. Since the machine only has the chips to prove 4-byte
+        // instructions, programs using the compressed extension can only be executed.
+        if self.program.is_compressed() && self.executor_mode != ExecutorMode::Simple {
+            return Err(ExecutionError::UnprovableCompressedProgram());
+        }
+        let (pc, clk) = (self.state.pc, self.state.clk);
+        let profile_block = self.print_report && !self.unconstrained;
+        if self.program.rv64 {
+            self.execute_instruction_rv64(&instruction)?;
+        } else {
+            self.execute_instruction(&instruction)?;
+        }
+        if let Some(addr) = self.hint_region_write.take() {
+            return Err(ExecutionError::HintRegionWrite(addr));
+        }
+
+        // Attribute the cycles of the instruction to its basic block.
+        if let Some(profiler) = &mut self.block_profiler {
+            if profile_block && !self.unconstrained {
+                profiler.step(
+                    &self.program,
+                    pc,
+                    self.state.pc,
+                    u64::from(self.state.clk - clk),
+                    &mut self.report.basic_blocks,
+                    &mut self.report.loops,
+                );
+            }
+        }
 
         // Increment the clock.
         self.state.global_clk += 1;
 
         if !self.unconstrained {
             // If there's not enough cycles left for another instruction, move to the next shard.
-            let cpu_exit = self.max_syscall_cycles + self.state.clk >= self.shard_size;
+            // Under the adaptive policy, the rows of the precompile events count as cycles too.
+            let shard_clk = match self.opts.shard_policy {
+                ShardPolicy::Fixed => self.state.clk,
+                ShardPolicy::Adaptive { precompile_weight } => self.state.clk.saturating_add(
+                    self.state
+                        .shard_precompile_rows
+                        .saturating_mul(precompile_weight.saturating_mul(4)),
+                ),
+            };
+            let cpu_exit = self.max_syscall_cycles.saturating_add(shard_clk) >= self.shard_size;
 
             // Every N cycles, check if there exists at least one shape that fits.
             //
@@ -1323,23 +1818,36 @@ impl<'a> Executor<'a> {
             }
 
             if cpu_exit || !shape_match_found {
+                // Executions which are proven can't go past the last shard the CPU chip accepts.
+                if self.executor_mode != ExecutorMode::Simple
+                    && self.state.current_shard as usize + 1 >= MAX_NUMBER_OF_SHARDS
+                {
+                    return Err(ExecutionError::ShardLimitExceeded());
+                }
                 self.state.current_shard += 1;
                 self.state.clk = 0;
+                self.state.shard_precompile_rows = 0;
                 self.report.event_counts = Box::default();
                 self.bump_record();
+
+                let (shard, global_clk) = (self.state.current_shard, self.state.global_clk);
+                self.trace_event(|trace| trace.shard(shard, global_clk));
             }
         }
 
         // If the cycle limit is exceeded, return an error.
         if let Some(max_cycles) = self.max_cycles {
             if self.state.global_clk >= max_cycles {
-                return Err(ExecutionError::ExceededCycleLimit(max_cycles));
+                return Err(ExecutionError::CycleLimitExceeded {
+                    cycles: self.state.global_clk,
+                    pc: self.state.pc,
+                });
             }
         }
 
         let done = self.state.pc == 0
             || self.state.pc.wrapping_sub(self.program.pc_base)
-                >= (self.program.instructions.len() * 4) as u32;
+                >= self.program.instructions.len() as u32 * self.program.instruction_stride();
         if done && self.unconstrained {
             log::error!("program ended in unconstrained mode at clk {}", self.state.global_clk);
             return Err(ExecutionError::EndInUnconstrained());
@@ -1395,6 +1903,9 @@ impl<'a> Executor<'a> {
         self.executor_mode = ExecutorMode::Checkpoint;
         self.emit_global_memory_events = emit_global_memory_events;
 
+        // The checkpoint must hold the replayed input stream, as it is taken before executing.
+        self.start_hint_replay();
+
         // Clone self.state without memory and uninitialized_memory in it so it's faster.
         let memory = std::mem::take(&mut self.state.memory);
         let uninitialized_memory = std::mem::take(&mut self.state.uninitialized_memory);
@@ -1403,6 +1914,8 @@ impl<'a> Executor<'a> {
         self.state.uninitialized_memory = uninitialized_memory;
 
         let done = tracing::debug_span!("execute").in_scope(|| self.execute())?;
+        // The inputs read from the stdin stream during the shard come after those it started with.
+        checkpoint.input_stream.append(&mut self.streamed_inputs);
         // Create a checkpoint using `memory_checkpoint`. Just include all memory if `done` since we
         // need it all for MemoryFinalize.
         tracing::debug_span!("create memory checkpoint").in_scope(|| {
@@ -1446,7 +1959,7 @@ impl<'a> Executor<'a> {
         Ok((checkpoint, done))
     }
 
-    fn initialize(&mut self) {
+    pub(crate) fn initialize(&mut self) {
         self.record.nonce_lookup = vec![0; self.opts.shard_size * 32];
 
         self.state.clk = 0;
@@ -1481,6 +1994,33 @@ impl<'a> Executor<'a> {
         Ok(())
     }
 
+    /// The backtrace of the program at the current program counter, symbolicated with the debug
+    /// info of its ELF if it has some, or else with its symbols.
+    ///
+    /// The calls made before the execution was resumed from a checkpoint are missing.
+    #[must_use]
+    pub fn backtrace(&self) -> GuestBacktrace {
+        self.call_stack.backtrace(&self.program, self.state.pc)
+    }
+
+    /// Log the instructions around the program counter the execution failed at, unless the program
+    /// halted itself, e.g. on a panic, where they would only show the halt.
+    fn log_fault(&self, err: &ExecutionError) {
+        if matches!(err, ExecutionError::HaltWithNonZeroExitCode(_)) {
+            return;
+        }
+        let pc = self.state.pc;
+        let context = FAULT_CONTEXT_INSTRUCTIONS * self.program.instruction_stride();
+        let disassembly = self
+            .program
+            .disassemble(pc.saturating_sub(context)..=pc.saturating_add(context))
+            .iter()
+            .map(|line| format!("{} {line}", if line.pc == pc { "=>" } else { "  " }))
+            .collect::<Vec<_>>()
+            .join("\n");
+        log::error!("{err} at pc {pc:#x}:\n{disassembly}");
+    }
+
     /// Executes up to `self.shard_batch_size` cycles of the program, returning whether the program
     /// has finished.
     pub fn execute(&mut self) -> Result<bool, ExecutionError> {
@@ -1498,6 +2038,7 @@ impl<'a> Executor<'a> {
         // If it's the first cycle, initialize the program.
         if self.state.global_clk == 0 {
             self.initialize();
+            self.start_hint_replay();
         }
 
         // Loop until we've executed `self.shard_batch_size` shards if `self.shard_batch_size` is
@@ -1506,7 +2047,15 @@ impl<'a> Executor<'a> {
         let mut current_shard = self.state.current_shard;
         let mut num_shards_executed = 0;
         loop {
-            if self.execute_cycle()? {
+            let cycle = self.execute_cycle().map_err(|err| {
+                self.log_fault(&err);
+                if err.is_trap() {
+                    ExecutionError::Trap { error: Box::new(err), backtrace: self.backtrace() }
+                } else {
+                    err
+                }
+            });
+            if cycle? {
                 done = true;
                 break;
             }
@@ -1524,7 +2073,7 @@ impl<'a> Executor<'a> {
         let public_values = self.record.public_values;
 
         if done {
-            self.postprocess();
+            self.finalize()?;
 
             // Push the remaining execution record with memory initialize & finalize events.
             self.bump_record();
@@ -1537,7 +2086,6 @@ impl<'a> Executor<'a> {
 
         // Set the global public values for all shards.
         let mut last_next_pc = 0;
-        let mut last_exit_code = 0;
         for (i, record) in self.records.iter_mut().enumerate() {
             record.program = program.clone();
             record.public_values = public_values;
@@ -1547,32 +2095,47 @@ impl<'a> Executor<'a> {
             if record.cpu_events.is_empty() {
                 record.public_values.start_pc = last_next_pc;
                 record.public_values.next_pc = last_next_pc;
-                record.public_values.exit_code = last_exit_code;
+                record.public_values.exit_code = 0;
             } else {
                 record.public_values.start_pc = record.cpu_events[0].pc;
                 record.public_values.next_pc = record.cpu_events.last().unwrap().next_pc;
                 record.public_values.exit_code = record.cpu_events.last().unwrap().exit_code;
                 last_next_pc = record.public_values.next_pc;
-                last_exit_code = record.public_values.exit_code;
             }
         }
 
         Ok(done)
     }
 
+    /// Checks the final state of a program that finished, and postprocesses it.
+    pub(crate) fn finalize(&mut self) -> Result<(), ExecutionError> {
+        self.check_zeroized_regions()?;
+        if let Some(&acc_ptr) = self.state.keccak_accumulators.keys().min() {
+            return Err(ExecutionError::UnfinalizedKeccakAccumulator(acc_ptr));
+        }
+        self.postprocess();
+        Ok(())
+    }
+
+    /// Checks that every region declared with `ZEROIZE_ON_HALT` holds only zeroes, so that no
+    /// secret ends up in the memory finalize events.
+    fn check_zeroized_regions(&self) -> Result<(), ExecutionError> {
+        for &(ptr, len) in &self.state.zeroize_regions {
+            for addr in ptr..ptr + len {
+                let value = self.state.memory.get(align(addr)).map_or(0, |record| record.value);
+                if (value >> ((addr % 4) * 8)) as u8 != 0 {
+                    return Err(ExecutionError::SecretRegionNotZeroized(addr));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn postprocess(&mut self) {
         // Flush remaining stdout/stderr
-        for (fd, buf) in &self.io_buf {
-            if !buf.is_empty() {
-                match fd {
-                    1 => {
-                        println!("stdout: {buf}");
-                    }
-                    2 => {
-                        println!("stderr: {buf}");
-                    }
-                    _ => {}
-                }
+        for fd in [1, 2] {
+            if let Some(buf) = self.io_buf.get(&fd).filter(|buf| !buf.is_empty()).cloned() {
+                self.output_line(fd, &buf);
             }
         }
 
@@ -1592,6 +2155,11 @@ impl<'a> Executor<'a> {
             tracing::warn!("Not all input bytes were read.");
         }
 
+        self.report.touched_memory_pages = self.state.memory.page_count() as u64;
+        if self.report_state_diff {
+            self.report.state_diff = Some(self.state_diff());
+        }
+
         if self.emit_global_memory_events
             && (self.executor_mode == ExecutorMode::Trace
                 || self.executor_mode == ExecutorMode::Checkpoint)
@@ -1628,10 +2196,16 @@ impl<'a> Executor<'a> {
                 // Program memory is initialized in the MemoryProgram chip and doesn't require any
                 // events, so we only send init events for other memory addresses.
                 if !self.record.program.memory_image.contains_key(&addr) {
-                    let initial_value = self.state.uninitialized_memory.get(addr).unwrap_or(&0);
+                    let initial_value = self
+                        .state
+                        .uninitialized_memory
+                        .get(addr)
+                        .copied()
+                        .or_else(|| hint_region_word(&self.state.hint_regions, addr))
+                        .unwrap_or(0);
                     memory_initialize_events.push(MemoryInitializeFinalizeEvent::initialize(
                         addr,
-                        *initial_value,
+                        initial_value,
                         true,
                     ));
                 }
@@ -1643,7 +2217,7 @@ impl<'a> Executor<'a> {
         }
     }
 
-    fn get_syscall(&mut self, code: SyscallCode) -> Option<&Arc<dyn Syscall>> {
+    pub(crate) fn get_syscall(&mut self, code: SyscallCode) -> Option<&Arc<dyn Syscall>> {
         self.syscall_map.get(&code)
     }
 
@@ -1680,19 +2254,41 @@ fn log2_ceil_usize(n: usize) -> usize {
     (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize
 }
 
+/// The hint region containing the given address, if any, among regions sorted by address.
+pub(crate) fn hint_region(hint_regions: &[HintRegion], addr: u32) -> Option<&HintRegion> {
+    let index = hint_regions.partition_point(|region| region.ptr <= addr).checked_sub(1)?;
+    Some(&hint_regions[index]).filter(|region| region.contains(addr))
+}
+
+/// The value of the word at the given address in the hint region containing it, if any.
+pub(crate) fn hint_region_word(hint_regions: &[HintRegion], addr: u32) -> Option<u32> {
+    hint_region(hint_regions, addr).and_then(|region| region.word(addr))
+}
+
 #[cfg(test)]
 mod tests {
 
-    use sp1_stark::SP1CoreOpts;
+    use sp1_primitives::io::hint_digest;
+    use sp1_stark::{SP1CoreOpts, ShardPolicy};
 
     use crate::programs::tests::{
-        fibonacci_program, panic_program, secp256r1_add_program, secp256r1_double_program,
+        committed_values_program, env_values_program, fibonacci_program, hint_bounded_program,
+        hint_verified_program, panic_program, secp256r1_add_program, secp256r1_double_program,
         simple_memory_program, simple_program, ssz_withdrawals_program, u256xu2048_mul_program,
     };
 
-    use crate::Register;
+    use std::sync::Arc;
+
+    use crate::{
+        events::{MemoryWriteRecord, PluginEvent, PrecompileEvent},
+        syscalls::{Syscall, SyscallCode, SyscallContext},
+        BasicBlockStats, CycleTrackerEvent, ExecutionCheckpoint, GasCostTable, HintAccess, HintKey,
+        HintPrefetch, HintReplay, InstructionFilter, OpcodeDenylist, Register, SP1Context,
+        StdinStream, SyscallReport, TracedInstruction, WatchedWrite, WordDiff, DEFAULT_HOT_LOOPS,
+        OPCODE_DENYLIST_ADDR,
+    };
 
-    use super::{Executor, Instruction, Opcode, Program};
+    use super::{ExecutionError, Executor, ExecutorMode, Instruction, Opcode, Program};
 
     fn _assert_send<T: Send>() {}
 
@@ -1716,6 +2312,57 @@ mod tests {
         runtime.run().unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "fs")]
+    fn test_checkpoint_resume() {
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 10;
+        opts.shard_batch_size = 1;
+
+        let mut expected = Executor::new(fibonacci_program(), opts);
+        expected.run_fast().unwrap();
+
+        let mut runtime = Executor::new(fibonacci_program(), opts);
+        runtime.executor_mode = ExecutorMode::Simple;
+        runtime.print_report = true;
+        assert!(!runtime.execute().unwrap());
+
+        let path = std::env::temp_dir().join(format!("checkpoint-{}.bin", std::process::id()));
+        runtime.checkpoint().unwrap().save(&path).unwrap();
+        let checkpoint = ExecutionCheckpoint::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(checkpoint.state.global_clk, runtime.state.global_clk);
+
+        let mut resumed =
+            Executor::resume(fibonacci_program(), checkpoint, opts, SP1Context::default());
+        resumed.run_fast().unwrap();
+        assert_eq!(resumed.state.global_clk, expected.state.global_clk);
+        assert_eq!(resumed.report, expected.report);
+        assert_eq!(resumed.registers(), expected.registers());
+    }
+
+    #[test]
+    fn test_adaptive_shard_policy() {
+        let run = |shard_policy| {
+            let mut opts = SP1CoreOpts::default();
+            opts.shard_size = 1 << 16;
+            opts.shard_policy = shard_policy;
+            let mut runtime = Executor::new(u256xu2048_mul_program(), opts);
+            runtime.run().unwrap();
+            let calls = runtime.state.syscall_counts[&SyscallCode::U256XU2048_MUL] as u32;
+            (runtime.state.current_shard, runtime.state.global_clk, calls)
+        };
+
+        // A precompile row weighing as much as a whole shard ends the shard after every call,
+        // without changing the execution.
+        let (fixed_shards, fixed_clk, fixed_calls) = run(ShardPolicy::Fixed);
+        let (adaptive_shards, adaptive_clk, adaptive_calls) =
+            run(ShardPolicy::Adaptive { precompile_weight: 1 << 16 });
+        assert_eq!(fixed_shards, 1);
+        assert_eq!(adaptive_shards, fixed_shards + adaptive_calls);
+        assert_eq!((adaptive_clk, adaptive_calls), (fixed_clk, fixed_calls));
+    }
+
     #[test]
     fn test_secp256r1_add_program_run() {
         let program = secp256r1_add_program();
@@ -1752,6 +2399,126 @@ mod tests {
         runtime.run().unwrap();
     }
 
+    #[test]
+    fn test_panic_backtrace() {
+        let program = panic_program();
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        let ExecutionError::Trap { error, backtrace } = &err else {
+            panic!("expected a trap, got {err}");
+        };
+        assert!(matches!(**error, ExecutionError::HaltWithNonZeroExitCode(1)));
+
+        // The backtrace starts at the halt and goes through the main function of the program.
+        assert_eq!(backtrace.frames[0].pc, runtime.state.pc);
+        assert!(backtrace
+            .frames
+            .iter()
+            .any(|frame| frame.function.as_deref().is_some_and(|name| name.ends_with("main"))));
+        assert!(err.to_string().contains("guest backtrace:"));
+    }
+
+    #[test]
+    fn test_hint_bounded() {
+        let program = hint_bounded_program();
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.write_stdin_slice(&[7u8; 64]);
+        runtime.write_stdin(&vec![7u8; 64]);
+        runtime.run().unwrap();
+        assert_eq!(runtime.state.public_values_stream, vec![7u8; 64]);
+    }
+
+    #[test]
+    fn test_hint_bounded_oversized() {
+        let program = hint_bounded_program();
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.write_stdin_slice(&[7u8; 65]);
+        runtime.write_stdin(&vec![7u8; 65]);
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err.cause(), ExecutionError::HaltWithNonZeroExitCode(1)));
+    }
+
+    fn run_hint_verified(digest: [u8; 32]) -> Result<Executor<'static>, ExecutionError> {
+        let mut runtime = Executor::new(hint_verified_program(), SP1CoreOpts::default());
+        runtime.write_stdin(&digest);
+        runtime.write_stdin(&vec![7u8; 64]);
+        runtime.write_stdin(&vec![7u8; 64]);
+        runtime.run()?;
+        Ok(runtime)
+    }
+
+    #[test]
+    fn test_hint_verified() {
+        let digest = hint_digest(&bincode::serialize(&vec![7u8; 64]).unwrap());
+        let runtime = run_hint_verified(digest).unwrap();
+        // The digest of the committed hint is the public values.
+        assert_eq!(runtime.state.public_values_stream, digest);
+    }
+
+    #[test]
+    fn test_hint_verified_mismatch() {
+        let err = run_hint_verified([0; 32]).err().unwrap();
+        assert!(matches!(err.cause(), ExecutionError::HaltWithNonZeroExitCode(1)));
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let program = fibonacci_program();
+        let lines = program.disassemble(program.pc_start..program.pc_start + 8);
+        assert_eq!(
+            lines.iter().map(|line| line.pc).collect::<Vec<_>>(),
+            [program.pc_start, program.pc_start + 4]
+        );
+        assert_eq!(lines[0].instruction.opcode, program.fetch(program.pc_start).opcode);
+
+        // The symbols of the ELF name the functions of the program.
+        let start = program.symbols.iter().find(|symbol| symbol.name == "__start").unwrap();
+        let lines = program.disassemble(start.start..);
+        assert_eq!(lines[0].symbol, Some(("__start".to_string(), 0)));
+        assert_eq!(lines[1].symbol, Some(("__start".to_string(), 4)));
+        assert!(lines[0]
+            .to_string()
+            .ends_with(&format!("<__start+0>: {:?}", lines[0].instruction)));
+
+        // The range is clamped to the program.
+        assert!(program.disassemble(..program.pc_base).is_empty());
+        assert_eq!(program.disassemble(..).len(), program.instructions.len());
+    }
+
+    fn run_committed_values(swap: bool) -> Result<Executor<'static>, ExecutionError> {
+        let mut runtime = Executor::new(committed_values_program(), SP1CoreOpts::default());
+        runtime.write_stdin(&swap);
+        runtime.run()?;
+        Ok(runtime)
+    }
+
+    #[test]
+    fn test_committed_values() {
+        let runtime = run_committed_values(false).unwrap();
+        // The program commits the digest of its output, as seen by the verifier.
+        let (output, digest) = runtime.state.public_values_stream.split_at(7);
+        assert_eq!(output, [7, 0, 0, 0, b'o', b'u', b't']);
+        assert_eq!(digest, hint_digest(output));
+    }
+
+    #[test]
+    fn test_committed_values_out_of_order() {
+        let err = run_committed_values(true).err().unwrap();
+        assert!(matches!(err.cause(), ExecutionError::HaltWithNonZeroExitCode(1)));
+    }
+
+    #[test]
+    fn test_env_values() {
+        let mut runtime = Executor::new(env_values_program(), SP1CoreOpts::default());
+        runtime.write_stdin(&1_700_000_000u64);
+        runtime.write_stdin(&[3u8; 32]);
+        runtime.write_stdin(&5u32);
+        runtime.run().unwrap();
+        // The values of the environment are committed once, ahead of the values of the program.
+        let expected = bincode::serialize(&(1_700_000_000u64, [3u8; 32], 5u32)).unwrap();
+        assert_eq!(runtime.state.public_values_stream, expected);
+    }
+
     #[test]
     fn test_add() {
         // main:
@@ -2276,6 +3043,412 @@ mod tests {
         simple_op_code_test(Opcode::SRA, 0xffffffff, 0x81818181, 31);
     }
 
+    fn zeroize_program(zeroize: bool) -> Program {
+        let mut instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 0xdead_beef, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::ZEROIZE_ON_HALT as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 4, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        if zeroize {
+            instructions.push(Instruction::new(Opcode::SW, 0, 0, 0x1000, false, true));
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_zeroize_on_halt() {
+        let mut runtime = Executor::new(zeroize_program(true), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.state.zeroize_regions, vec![(0x1000, 4)]);
+    }
+
+    #[test]
+    fn test_zeroize_on_halt_not_zeroized() {
+        let mut runtime = Executor::new(zeroize_program(false), SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err, ExecutionError::SecretRegionNotZeroized(0x1000)));
+    }
+
+    fn hint_map_program(store: bool) -> Program {
+        let mut instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HINT_MAP as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 10, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::LW, 12, 0, 0x1000, false, true),
+            Instruction::new(Opcode::LW, 13, 0, 0x1004, false, true),
+        ];
+        if store {
+            instructions.push(Instruction::new(Opcode::SW, 12, 0, 0x1004, false, true));
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_hint_map() {
+        let mut runtime = Executor::new(hint_map_program(false), SP1CoreOpts::default());
+        runtime.write_stdin_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        runtime.run().unwrap();
+        assert_eq!(runtime.register(Register::X12), 0x0403_0201);
+        assert_eq!(runtime.register(Register::X13), 0x0807_0605);
+        // The last word of the region was never accessed, so it was never loaded.
+        assert!(runtime.state.memory.get(0x1008).is_none());
+        assert_eq!(runtime.state.hint_regions[0].word(0x1008), Some(0x0a09));
+    }
+
+    #[test]
+    fn test_hint_map_write() {
+        let mut runtime = Executor::new(hint_map_program(true), SP1CoreOpts::default());
+        runtime.write_stdin_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err.cause(), ExecutionError::HintRegionWrite(0x1004)));
+    }
+
+    #[test]
+    fn test_hint_map_commit() {
+        // Commits the region without loading any of its words first.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HINT_MAP as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 10, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::WRITE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 3, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 12, 0, 10, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let mut runtime = Executor::new(Program::new(instructions, 0, 0), SP1CoreOpts::default());
+        runtime.write_stdin_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        runtime.run().unwrap();
+        assert_eq!(runtime.state.public_values_stream, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    fn halt_program(exit_code: u32, typed: bool) -> Program {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HALT as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, exit_code, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, u32::from(typed), false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_halt_with_typed_exit_code() {
+        let mut runtime = Executor::new(halt_program(7, true), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.report.exit_code, 7);
+    }
+
+    #[test]
+    fn test_halt_with_untyped_exit_code() {
+        let mut runtime = Executor::new(halt_program(7, false), SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err.cause(), ExecutionError::HaltWithNonZeroExitCode(7)));
+    }
+
+    #[test]
+    fn test_exit_code_committed_to_public_values() {
+        use test_artifacts::EXIT_CODE_ELF;
+
+        let program = Program::from(EXIT_CODE_ELF).unwrap();
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.report.exit_code, 3);
+
+        // The exit code follows the committed values, so that their digest covers it.
+        let mut expected = bincode::serialize(&42u32).unwrap();
+        expected.extend_from_slice(&3u32.to_be_bytes());
+        assert_eq!(runtime.state.public_values_stream, expected);
+    }
+
+    #[test]
+    fn test_cycle_limit_exceeded() {
+        // Loop forever on a jump to itself.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::JAL, 0, 0, 0, true, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut opts = SP1CoreOpts::default();
+        opts.max_cycles = Some(10);
+        let mut runtime = Executor::new(program.clone(), opts);
+        let err = runtime.run_fast().unwrap_err();
+        assert!(matches!(err, ExecutionError::CycleLimitExceeded { cycles: 10, pc: 4 }), "{err}");
+
+        // The lowest of the limits of the context and of the options applies.
+        let context = SP1Context::builder().max_cycles(5).build();
+        let mut runtime = Executor::with_context(program, opts, context);
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err, ExecutionError::CycleLimitExceeded { cycles: 5, pc: 4 }), "{err}");
+    }
+
+    #[test]
+    fn test_shard_limit_exceeded() {
+        // Loop forever on a jump to itself, starting two shards before the limit.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::JAL, 0, 0, 0, true, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 10;
+        opts.max_cycles = Some(1 << 16);
+        let mut runtime = Executor::new(program.clone(), opts);
+        runtime.state.current_shard = MAX_NUMBER_OF_SHARDS as u32 - 2;
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err, ExecutionError::ShardLimitExceeded()), "{err}");
+        assert_eq!(runtime.state.current_shard, MAX_NUMBER_OF_SHARDS as u32 - 1);
+
+        // Executions which are not proven only stop at the cycle limit.
+        let mut runtime = Executor::new(program, opts);
+        runtime.state.current_shard = MAX_NUMBER_OF_SHARDS as u32 - 2;
+        let err = runtime.run_fast().unwrap_err();
+        assert!(matches!(err, ExecutionError::CycleLimitExceeded { .. }), "{err}");
+    }
+
+    #[test]
+    fn test_gas_used() {
+        let mut runtime = Executor::new(halt_program(7, true), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.report.gas_used, 4);
+
+        let gas_costs = GasCostTable::default()
+            .with_opcode_cost(Opcode::ADD, 2)
+            .with_opcode_cost(Opcode::ECALL, 10)
+            .with_syscall_cost(SyscallCode::HALT, 5);
+        let context = SP1Context::builder().gas_costs(gas_costs).build();
+        let mut runtime =
+            Executor::with_context(halt_program(7, true), SP1CoreOpts::default(), context);
+        runtime.run().unwrap();
+        assert_eq!(runtime.report.gas_used, 3 * 2 + 10 + 5);
+    }
+
+    #[test]
+    fn test_keccak_accumulate_not_finalized() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 1, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x2000, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::KECCAK_ACCUMULATE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0x2000, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err, ExecutionError::UnfinalizedKeccakAccumulator(0x2000)));
+    }
+
+    #[test]
+    fn test_syscall_status() {
+        let decompress = |x: u32| {
+            let instructions = vec![
+                Instruction::new(Opcode::ADD, 29, 0, x, false, true),
+                Instruction::new(Opcode::SW, 29, 0, 0x1020, false, true),
+                Instruction::new(
+                    Opcode::ADD,
+                    5,
+                    0,
+                    SyscallCode::SECP256K1_DECOMPRESS as u32,
+                    false,
+                    true,
+                ),
+                Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+                Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+                Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            ];
+            let program = Program::new(instructions, 0, 0);
+            let mut runtime = Executor::new(program, SP1CoreOpts::default());
+            runtime.run().unwrap();
+            (runtime.register(Register::X5), runtime.word(0x1000))
+        };
+
+        // 1 + 7 is a square modulo the secp256k1 prime, so there is a point with x = 1.
+        let (status, y) = decompress(1);
+        assert_eq!(status, 0);
+        assert_ne!(y, 0);
+
+        // 125 + 7 is not a square, so the decompression fails and leaves y as it was.
+        assert_eq!(decompress(5), (1, 0));
+    }
+
+    #[test]
+    fn test_opcode_denylist() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::MUL, 30, 29, 29, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+
+        let denylist = OpcodeDenylist::new().deny(Opcode::DIV).deny(Opcode::REM);
+        let restricted = program.clone().with_opcode_denylist(denylist).unwrap();
+        assert_eq!(restricted.memory_image[&OPCODE_DENYLIST_ADDR], denylist.to_words()[0]);
+        let mut runtime = Executor::new(restricted, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.register(Register::X30), 25);
+
+        let denylist = OpcodeDenylist::new().deny(Opcode::MUL);
+        assert!(program.clone().with_opcode_denylist(denylist).is_err());
+
+        let mut program = program;
+        program.opcode_denylist = denylist;
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        let err = runtime.run().unwrap_err();
+        assert!(matches!(err.cause(), ExecutionError::DeniedOpcode(Opcode::MUL, 4)));
+    }
+
+    struct AddPlugin;
+
+    impl Syscall for AddPlugin {
+        fn execute(
+            &self,
+            rt: &mut SyscallContext,
+            syscall_code: SyscallCode,
+            arg1: u32,
+            arg2: u32,
+        ) -> Option<u32> {
+            let start_clk = rt.clk;
+            let (_, value) = rt.mr(arg1);
+            let write_record = rt.mw(arg1, value + arg2);
+            let lookup_id = rt.syscall_lookup_id;
+            let event = PrecompileEvent::Plugin(PluginEvent {
+                lookup_id,
+                shard: rt.current_shard(),
+                clk: start_clk,
+                data: bincode::serialize(&write_record).unwrap(),
+                local_mem_access: rt.postprocess(),
+            });
+            let syscall_event =
+                rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+            rt.add_precompile_event(syscall_code, syscall_event, event);
+            None
+        }
+    }
+
+    #[test]
+    fn test_register_syscall() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::PLUGIN_0 as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 7, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        assert!(runtime.register_syscall(SyscallCode::PLUGIN_0, Arc::new(AddPlugin)).is_none());
+        runtime.run().unwrap();
+        assert_eq!(runtime.word(0x1000), 12);
+
+        let events = runtime.records[0].get_precompile_events(SyscallCode::PLUGIN_0);
+        assert_eq!(events.len(), 1);
+        let PrecompileEvent::Plugin(event) = &events[0].1 else {
+            panic!("expected a plugin event")
+        };
+        let write_record: MemoryWriteRecord = bincode::deserialize(&event.data).unwrap();
+        assert_eq!((write_record.prev_value, write_record.value), (5, 12));
+    }
+
+    #[test]
+    fn test_unconstrained_precompile_pruned() {
+        // The block after `ENTER_UNCONSTRAINED` is executed twice: first in unconstrained mode,
+        // and then again in constrained mode once `EXIT_UNCONSTRAINED` rolls execution back.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x1000, false, true),
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::ENTER_UNCONSTRAINED as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::PLUGIN_0 as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 7, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::EXIT_UNCONSTRAINED as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.register_syscall(SyscallCode::PLUGIN_0, Arc::new(AddPlugin));
+        runtime.run().unwrap();
+        assert_eq!(runtime.word(0x1000), 12);
+        assert_eq!(runtime.state.syscall_counts[&SyscallCode::PLUGIN_0], 1);
+
+        // Only the constrained call is recorded, with the first row of the table.
+        let events = runtime.records[0].get_precompile_events(SyscallCode::PLUGIN_0);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0.nonce, 0);
+        let PrecompileEvent::Plugin(event) = &events[0].1 else {
+            panic!("expected a plugin event")
+        };
+        let write_record: MemoryWriteRecord = bincode::deserialize(&event.data).unwrap();
+        assert_eq!((write_record.prev_value, write_record.value), (5, 12));
+    }
+
+    #[test]
+    fn test_unconstrained_curve_precompile_pruned() {
+        // Decompressing x = 0 on secp256r1 in an unconstrained block, and again once execution is
+        // rolled back.
+        let instructions = vec![
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::ENTER_UNCONSTRAINED as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::SECP256R1_DECOMPRESS as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::EXIT_UNCONSTRAINED as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        // Only the constrained call is recorded.
+        let events = runtime.records[0].get_precompile_events(SyscallCode::SECP256R1_DECOMPRESS);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0.nonce, 0);
+        assert!(matches!(events[0].1, PrecompileEvent::Secp256r1Decompress(_)));
+    }
+
     #[test]
     #[allow(clippy::unreadable_literal)]
     fn test_simple_memory_program_run() {
@@ -2314,4 +3487,496 @@ mod tests {
         assert_eq!(runtime.register(Register::X12), 0x12346525);
         assert_eq!(runtime.register(Register::X11), 0x65256525);
     }
+
+    #[test]
+    fn test_memcpy_advice() {
+        use test_artifacts::MEMCPY_BENCH_ELF;
+
+        use crate::{MemcpyKind, MemcpyRecommendation, MemcpySymbols, SP1Context};
+
+        let program = Program::from(MEMCPY_BENCH_ELF).unwrap();
+        let symbols = MemcpySymbols::from_elf(MEMCPY_BENCH_ELF).unwrap();
+        let context = SP1Context::builder().memcpy_advice(symbols).build();
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.run_fast().unwrap();
+
+        let sites = &runtime.report.memcpy_call_sites;
+        let bulk = sites
+            .values()
+            .find(|site| site.kind == MemcpyKind::Memcpy && site.bytes == 32 * 4096)
+            .unwrap();
+        assert_eq!(bulk.calls, 32);
+        assert!(bulk.caller.as_deref().unwrap().contains("copy_bulk"));
+        assert!(sites.values().any(|site| site.kind == MemcpyKind::Memset && site.calls >= 32));
+
+        let advice = runtime.report.memcpy_advice(crate::DEFAULT_MEMCPY_ADVICE_THRESHOLD);
+        let copy = advice.iter().find(|advice| advice.site.kind == MemcpyKind::Memcpy).unwrap();
+        assert_eq!(copy.site.bytes, bulk.bytes);
+        assert_eq!(copy.recommendation, MemcpyRecommendation::Memcopy64);
+        assert!(advice.iter().all(|advice| advice.site.bytes > 32 * 5));
+    }
+
+    #[test]
+    fn test_memcopy_coalescing() {
+        use test_artifacts::MEMCOPY_ELF;
+
+        use crate::events::PrecompileEvent;
+
+        let program = Program::from(MEMCOPY_ELF).unwrap();
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        let mut coalesced = 0;
+        let mut memcopy64 = 0;
+        let mut memcopy32 = 0;
+        for record in &runtime.records {
+            for (syscall_event, event) in record.get_precompile_events(SyscallCode::MEMCPY_64) {
+                let PrecompileEvent::MemCopy64(event) = event else { unreachable!() };
+                assert_eq!(event.read_records.len(), 16);
+                assert_eq!(event.write_records.len(), 16);
+                // The coalesced halves are joined in the arenas of the record.
+                let reads = record.precompile_events.reads(event.read_records);
+                let writes = record.precompile_events.writes(event.write_records);
+                for (read, write) in reads.iter().zip(writes) {
+                    assert_eq!(read.value, write.value);
+                }
+                match &event.coalesced_syscall {
+                    Some(upper) => {
+                        coalesced += 1;
+                        assert_eq!(syscall_event.syscall_id, SyscallCode::MEMCPY_32.syscall_id());
+                        assert_eq!(upper.nonce, syscall_event.nonce);
+                        assert_eq!(upper.arg1, event.src_ptr + 32);
+                        assert_eq!(upper.arg2, event.dst_ptr + 32);
+                        assert!(upper.clk > event.clk);
+                    }
+                    None => memcopy64 += 1,
+                }
+            }
+            memcopy32 += record.get_precompile_events(SyscallCode::MEMCPY_32).len();
+        }
+
+        // Every `MEMCPY_32` call is either coalesced or recorded on its own.
+        assert!(coalesced >= 6);
+        assert_eq!(2 * coalesced + memcopy32, 15);
+        assert_eq!(memcopy64, 4);
+    }
+
+    #[test]
+    fn test_compressed_program() {
+        // c.li a0, 5; addi a1, a0, 1; c.jal 4; c.li a0, 0; c.add a0, a1; c.mv a2, ra; c.nop.
+        let code = [0x0593_4515, 0x2011_0015, 0x952e_4501, 0x0001_8606];
+        let (instructions, compressed) = crate::disassembler::transpile_compressed(&code, false);
+        let mut program = Program::new(instructions, 0, 0);
+        program.compressed = compressed;
+
+        let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+        runtime.run_fast().unwrap();
+        assert_eq!(runtime.register(Register::X10), 11);
+        assert_eq!(runtime.register(Register::X11), 6);
+        // The compressed jump links to the instruction 2 bytes after it.
+        assert_eq!(runtime.register(Register::X12), 8);
+        assert_eq!(runtime.state.global_clk, 6);
+
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        assert!(matches!(runtime.run(), Err(ExecutionError::UnprovableCompressedProgram())));
+    }
+
+    #[test]
+    fn test_touched_memory_pages() {
+        // Store to a word next to the registers and to a word 256 MiB away from them.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 0x1000_0000, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x1000, false, true),
+            Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run_fast().unwrap();
+        assert_eq!(runtime.report.touched_memory_pages, 2);
+
+        // The pages of the reports of several segments of an execution are not summed.
+        let report = runtime.report.clone() + runtime.report.clone();
+        assert_eq!(report.touched_memory_pages, 2);
+    }
+
+    #[test]
+    fn test_state_diff() {
+        // Store to a word, store zero to an untouched word, and overwrite a word of the memory
+        // image with its own value.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 0x1000_0000, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x1000, false, true),
+            Instruction::new(Opcode::SW, 0, 30, 0, false, true),
+            Instruction::new(Opcode::LW, 31, 0, 0x2000, false, true),
+            Instruction::new(Opcode::SW, 31, 30, 4, false, true),
+            Instruction::new(Opcode::SW, 31, 0, 0x2000, false, true),
+        ];
+        let mut program = Program::new(instructions, 0, 0);
+        program.memory_image.insert(0x2000, 7);
+        let context = SP1Context::builder().state_diff().build();
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.run_fast().unwrap();
+
+        let diff = runtime.report.state_diff.clone().unwrap();
+        assert_eq!(diff, runtime.state_diff());
+        assert_eq!(
+            diff.words().copied().collect::<Vec<_>>(),
+            [
+                WordDiff { addr: 0x1000, before: 0, after: 5 },
+                WordDiff { addr: 0x1000_0004, before: 0, after: 7 },
+            ]
+        );
+        assert_eq!(diff.pages.len(), 2);
+        assert_eq!(diff.pages[1].base, 0x1000_0000);
+        assert_eq!(diff.get(0x1000_0004).map(|word| word.after), Some(7));
+        assert_eq!(diff.get(0x2000), None);
+    }
+
+    #[test]
+    fn test_profile_blocks() {
+        // Count x29 down from 3 to 0 in a loop over 4..=8.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 3, false, true),
+            Instruction::new(Opcode::SUB, 29, 29, 1, false, true),
+            Instruction::new(Opcode::BNE, 29, 0, (-4i32) as u32, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let context = SP1Context::builder().profile_blocks().build();
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.run_fast().unwrap();
+
+        let report = &runtime.report;
+        assert_eq!(
+            report.basic_blocks[&0],
+            BasicBlockStats { executions: 1, instructions: 3, cycles: 12 }
+        );
+        assert_eq!(
+            report.basic_blocks[&4],
+            BasicBlockStats { executions: 2, instructions: 4, cycles: 16 }
+        );
+        let hot_loops = report.hot_loops(DEFAULT_HOT_LOOPS);
+        assert_eq!(hot_loops.len(), 1);
+        assert_eq!((hot_loops[0].latch, hot_loops[0].stats.head), (8, 4));
+        assert_eq!((hot_loops[0].stats.iterations, hot_loops[0].cycles), (2, 16));
+    }
+
+    #[test]
+    fn test_trace_instructions() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 30, 29, 3, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HALT as u32, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut traced = Vec::new();
+        let filter = InstructionFilter::new().pcs(4..20).opcodes([Opcode::ADD, Opcode::ECALL]);
+        let context =
+            SP1Context::builder().trace_instructions(filter, |instr| traced.push(instr)).build();
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.run_fast().unwrap();
+        drop(runtime);
+
+        assert_eq!(traced.len(), 3);
+        assert_eq!(
+            traced[0],
+            TracedInstruction {
+                global_clk: 1,
+                pc: 4,
+                opcode: Opcode::ADD,
+                rd: 30,
+                operands: [8, 5, 3],
+                syscall: None,
+            }
+        );
+        assert_eq!((traced[1].pc, traced[1].rd), (12, 5));
+        assert_eq!((traced[2].pc, traced[2].syscall), (16, Some(SyscallCode::HALT)));
+    }
+
+    #[test]
+    fn test_syscall_cycles() {
+        // Permute a zero keccak state twice, then halt.
+        let mut instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::KECCAK_PERMUTE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        instructions.extend(halt_program(0, false).instructions);
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run_fast().unwrap();
+
+        let report = &runtime.report;
+        assert_eq!(report.syscall_counts[SyscallCode::KECCAK_PERMUTE], 2);
+        assert_eq!(report.syscall_cycles[SyscallCode::KECCAK_PERMUTE], 2 * (4 + 1));
+        assert_eq!(report.syscall_cycles[SyscallCode::HALT], 4);
+        assert_eq!(report.total_syscall_cycles(), 14);
+
+        let breakdown = report.syscall_breakdown();
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(
+            breakdown[0],
+            SyscallReport { syscall: SyscallCode::KECCAK_PERMUTE, count: 2, cycles: 10 }
+        );
+        assert_eq!(breakdown[1], SyscallReport { syscall: SyscallCode::HALT, count: 1, cycles: 4 });
+    }
+
+    #[test]
+    fn test_collections() {
+        use test_artifacts::COLLECTIONS_ELF;
+
+        let program = Program::from(COLLECTIONS_ELF).unwrap();
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+
+        // Relocating the interleaved vectors goes through the memcopy precompiles.
+        let memcopy64: usize = runtime
+            .records
+            .iter()
+            .map(|record| record.get_precompile_events(SyscallCode::MEMCPY_64).len())
+            .sum();
+        assert!(memcopy64 > 0);
+    }
+
+    /// Reads the length of the next input, calls the hook of fd 5, then reads the length of the
+    /// next input and the input itself.
+    fn hint_replay_program() -> Program {
+        let mut instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HINT_LEN as u32, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 14, 5, 0, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::WRITE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0x2000, false, true),
+            Instruction::new(Opcode::ADD, 12, 0, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HINT_LEN as u32, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 15, 5, 0, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HINT_READ as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 4, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::LW, 16, 0, 0x1000, false, true),
+        ];
+        instructions.extend(halt_program(0, false).instructions);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    #[cfg(all(feature = "fs", feature = "threads"))]
+    fn test_hint_record_replay() {
+        let path = std::env::temp_dir().join(format!("hint-replay-{}.bin", std::process::id()));
+        let context =
+            SP1Context::builder().hook(5, |_, _| vec![vec![9; 4]]).record_hints(&path).build();
+        let mut recorded =
+            Executor::with_context(hint_replay_program(), SP1CoreOpts::default(), context);
+        recorded.write_stdin_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        recorded.run().unwrap();
+        assert_eq!(recorded.register(Register::X14), 8);
+        assert_eq!(recorded.register(Register::X15), 4);
+        assert_eq!(recorded.register(Register::X16), 0x0909_0909);
+
+        // The hook output is spliced in front of the stdin, so every access is at offset 0.
+        recorded.hint_recorder = None;
+        let replay = HintReplay::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            replay.accesses,
+            vec![
+                HintAccess::Len { offset: 0, global_clk: 1, len: 8 },
+                HintAccess::Hook { offset: 0, global_clk: 7, fd: 5, request: vec![] },
+                HintAccess::Len { offset: 0, global_clk: 9, len: 4 },
+                HintAccess::Read { offset: 0, global_clk: 14, data: vec![9; 4] },
+            ]
+        );
+
+        // The replay needs neither the stdin nor the hook.
+        let keys = replay.hook_keys();
+        let context = SP1Context::builder().replay_hints(replay).build();
+        let mut replayed =
+            Executor::with_context(hint_replay_program(), SP1CoreOpts::default(), context);
+        replayed.run().unwrap();
+        assert_eq!(replayed.state.global_clk, recorded.state.global_clk);
+        assert_eq!(replayed.registers(), recorded.registers());
+
+        // The recorded hook calls are served by the prefetch instead of the hook.
+        assert_eq!(keys, vec![HintKey { fd: 5, request: vec![] }]);
+        let prefetch = HintPrefetch::new(keys, |_, _| vec![vec![9; 4]]);
+        let context = SP1Context::builder()
+            .hook(5, |_, _| panic!("the hook call should have been prefetched"))
+            .prefetch_hints(prefetch)
+            .build();
+        let mut prefetched =
+            Executor::with_context(hint_replay_program(), SP1CoreOpts::default(), context);
+        prefetched.write_stdin_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        prefetched.run().unwrap();
+        assert_eq!(prefetched.registers(), recorded.registers());
+    }
+
+    #[test]
+    #[should_panic(expected = "execution diverged from the replay")]
+    fn test_hint_replay_diverged() {
+        let replay = HintReplay::new(vec![
+            HintAccess::Len { offset: 0, global_clk: 1, len: 8 },
+            HintAccess::Read { offset: 0, global_clk: 9, data: vec![9; 4] },
+        ]);
+        let context = SP1Context::builder().replay_hints(replay).build();
+        let mut runtime =
+            Executor::with_context(hint_replay_program(), SP1CoreOpts::default(), context);
+        runtime.run().unwrap();
+    }
+
+    #[test]
+    fn test_stdin_stream() {
+        let mut bytes = Vec::new();
+        StdinStream::write_frame(&mut bytes, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let context = SP1Context::builder().hook(5, |_, _| vec![vec![9; 4]]).build();
+        let mut runtime =
+            Executor::with_context(hint_replay_program(), SP1CoreOpts::default(), context);
+        runtime.write_stdin_stream(StdinStream::new(std::io::Cursor::new(bytes)));
+        let (checkpoint, done) = runtime.execute_state(false).unwrap();
+        assert!(done);
+        assert_eq!(runtime.register(Register::X14), 8);
+        assert_eq!(runtime.register(Register::X15), 4);
+        assert_eq!(runtime.register(Register::X16), 0x0909_0909);
+
+        // The checkpoint holds the streamed input, as it was taken before the input was read.
+        assert_eq!(checkpoint.input_stream, vec![vec![1, 2, 3, 4, 5, 6, 7, 8]]);
+        assert_eq!(checkpoint.input_stream_ptr, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
+    fn test_trace_events() {
+        use test_artifacts::CYCLE_TRACKER_ELF;
+
+        let path = std::env::temp_dir().join(format!("trace-events-{}.json", std::process::id()));
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 10;
+        let context = SP1Context::builder().trace_events(&path).build();
+        let mut runtime =
+            Executor::with_context(Program::from(CYCLE_TRACKER_ELF).unwrap(), opts, context);
+        runtime.run().unwrap();
+        let shards = runtime.state.current_shard;
+        drop(runtime);
+
+        let events: Vec<serde_json::Value> =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let names = |tid: u32| {
+            events
+                .iter()
+                .filter(|event| event["ph"] == "X" && event["tid"] == tid)
+                .map(|event| event["name"].as_str().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(1), ["f", "g"]);
+        assert_eq!(names(2).last(), Some(&"HALT"));
+        assert!(shards > 1);
+        let boundaries = events.iter().filter(|event| event["ph"] == "i").count();
+        assert_eq!(boundaries, shards as usize - 1);
+    }
+
+    #[test]
+    fn test_breakpoints_and_watchpoints() {
+        let mut hits = 0;
+        let mut writes = Vec::new();
+        let context = SP1Context::builder()
+            .breakpoint(8, |env| {
+                assert_eq!(env.pc(), 8);
+                assert_eq!(env.register(Register::X29), 0x12348765);
+                assert_eq!(env.word(0x27654320), 0x12348765);
+                hits += 1;
+            })
+            .watchpoint(0x43627531..0x43627532, |_, write| writes.push(write))
+            .build();
+        let mut runtime =
+            Executor::with_context(simple_memory_program(), SP1CoreOpts::default(), context);
+        runtime.run().unwrap();
+        drop(runtime);
+
+        assert_eq!(hits, 1);
+        // Every store to the word overlaps the watched byte, even the ones not writing to it.
+        assert_eq!(writes.len(), 8);
+        assert_eq!(writes[0], WatchedWrite { addr: 0x43627530, prev_value: 0, value: 0x12348765 });
+        assert_eq!(writes[4].value, 0x25252525);
+        assert_eq!(writes[7].value, 0x65256525);
+    }
+
+    #[test]
+    fn test_output_callbacks() {
+        // Writes "hi\n" to stdout, then "hi" to fd 7, then "hi" to stdout without a newline.
+        let mut instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 0x000a_6968, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::WRITE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 1, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0x1000, false, true),
+            Instruction::new(Opcode::ADD, 12, 0, 3, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 10, 0, 7, false, true),
+            Instruction::new(Opcode::ADD, 12, 0, 2, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 10, 0, 1, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        instructions.extend(halt_program(0, false).instructions);
+
+        let mut stdout = Vec::new();
+        let mut custom = Vec::new();
+        let context = SP1Context::builder()
+            .hook(7, |_, _| panic!("the output callback should take precedence over the hook"))
+            .output(1, |_, line| stdout.push(line.to_vec()))
+            .output(7, |_, bytes| custom.push(bytes.to_vec()))
+            .build();
+        let mut runtime = Executor::with_context(
+            Program::new(instructions, 0, 0),
+            SP1CoreOpts::default(),
+            context,
+        );
+        runtime.run().unwrap();
+        // The intercepted lines, including the unterminated one flushed at the end of the
+        // execution, are still recorded in the guest log.
+        assert_eq!(runtime.report.guest_log.tail, vec!["stdout: hi".to_string(); 2]);
+        drop(runtime);
+
+        assert_eq!(stdout, vec![b"hi".to_vec(); 2]);
+        assert_eq!(custom, vec![b"hi".to_vec()]);
+    }
+
+    #[test]
+    fn test_cycle_tracker_callback() {
+        use test_artifacts::CYCLE_TRACKER_ELF;
+
+        let mut events = Vec::new();
+        let context = SP1Context::builder().cycle_tracker(|_, event| events.push(event)).build();
+        let mut runtime = Executor::with_context(
+            Program::from(CYCLE_TRACKER_ELF).unwrap(),
+            SP1CoreOpts::default(),
+            context,
+        );
+        runtime.run().unwrap();
+        drop(runtime);
+
+        let names = events
+            .iter()
+            .map(|event| match event {
+                CycleTrackerEvent::Start { name, .. } => format!("start {name}"),
+                CycleTrackerEvent::End { name, .. } => format!("end {name}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["start f", "end f", "start g", "end g"]);
+        let CycleTrackerEvent::End { cycles, depth, report, .. } = &events[3] else {
+            panic!("expected the end of a span");
+        };
+        assert!(*cycles > 0);
+        assert_eq!((*depth, *report), (0, false));
+    }
 }
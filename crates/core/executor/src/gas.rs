@@ -0,0 +1,49 @@
+//! Gas metering of executions.
+
+use enum_map::EnumMap;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{syscalls::SyscallCode, Opcode};
+
+/// The weights of the instructions and syscalls of an execution, accumulated into
+/// [`ExecutionReport::gas_used`](crate::ExecutionReport::gas_used).
+///
+/// The gas used by an execution only depends on the program and its input, so it can be charged
+/// before the execution is proven. By default, every instruction costs one gas, and every call to
+/// a precompile costs as much gas as the number of rows it takes in the table of the precompile,
+/// on top of its `ecall` instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasCostTable {
+    /// The gas charged for every executed instruction, by opcode.
+    pub opcodes: Box<EnumMap<Opcode, u64>>,
+    /// The gas charged for every syscall, by syscall code.
+    pub syscalls: Box<EnumMap<SyscallCode, u64>>,
+}
+
+impl Default for GasCostTable {
+    fn default() -> Self {
+        let opcodes = Box::new(EnumMap::from_fn(|_| 1));
+        let mut syscalls = Box::new(EnumMap::default());
+        for syscall in SyscallCode::iter().filter(|syscall| syscall.should_send() == 1) {
+            syscalls[syscall] = syscall.rows_per_event() as u64;
+        }
+        Self { opcodes, syscalls }
+    }
+}
+
+impl GasCostTable {
+    /// Set the gas charged for every execution of an opcode.
+    #[must_use]
+    pub fn with_opcode_cost(mut self, opcode: Opcode, gas: u64) -> Self {
+        self.opcodes[opcode] = gas;
+        self
+    }
+
+    /// Set the gas charged for every call to a syscall.
+    #[must_use]
+    pub fn with_syscall_cost(mut self, syscall: SyscallCode, gas: u64) -> Self {
+        self.syscalls[syscall] = gas;
+        self
+    }
+}
@@ -0,0 +1,543 @@
+//! A GDB remote stub for the executor.
+//!
+//! [`Executor::run_gdb`] executes a program under the control of a debugger speaking the GDB
+//! Remote Serial Protocol, such as `riscv64-unknown-elf-gdb` or `gdb-multiarch`:
+//!
+//! ```text
+//! (gdb) file target/elf-compilation/riscv32im-succinct-zkvm-elf/release/program
+//! (gdb) target remote localhost:9000
+//! ```
+//!
+//! The stub exposes the registers and the memory of the program, software and hardware
+//! breakpoints, single-stepping, and interrupting a running program with Ctrl-C. A failed
+//! execution stops the program at the faulting instruction with a signal instead of ending the
+//! session, so that the state of the crash can be inspected.
+
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, BufReader, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::{align, events::MemoryRecord, ExecutionError, Executor, ExecutorMode};
+
+/// The names of the registers in the `org.gnu.gdb.riscv.cpu` feature, in the order of their
+/// numbers: `x0` to `x31`, then the program counter.
+const REGISTER_NAMES: [&str; 33] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "fp", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6", "pc",
+];
+
+/// The number of the program counter register.
+const PC_REGISTER: usize = 32;
+
+/// The maximum size of the payload of a packet, advertised to the debugger.
+const MAX_PACKET_SIZE: usize = 0x4000;
+
+/// The number of cycles between two checks for an interrupt from the debugger.
+const INTERRUPT_POLL_CYCLES: u64 = 1 << 16;
+
+/// The byte the debugger sends to interrupt a running program.
+const INTERRUPT: u8 = 0x03;
+
+/// The GDB signal numbers reported to the debugger.
+const SIGINT: u8 = 2;
+const SIGILL: u8 = 4;
+const SIGTRAP: u8 = 5;
+const SIGABRT: u8 = 6;
+const SIGSEGV: u8 = 11;
+const SIGXCPU: u8 = 24;
+
+/// Why the program stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stop {
+    /// The program stopped with a signal, and can be resumed.
+    Signal(u8),
+    /// The execution failed with a signal, and cannot be resumed.
+    Fault(u8),
+    /// The program finished with an exit code.
+    Exited(u32),
+}
+
+impl Executor<'_> {
+    /// Executes the program under the control of the first debugger connecting to the listener.
+    ///
+    /// The program is stopped before its first instruction. This function returns when the
+    /// debugger detaches, kills the program, or disconnects.
+    pub fn run_gdb(&mut self, listener: &TcpListener) -> io::Result<()> {
+        let (stream, peer) = listener.accept()?;
+        tracing::info!("debugger connected from {peer}");
+        stream.set_nodelay(true)?;
+
+        self.executor_mode = ExecutorMode::Simple;
+        self.print_report = true;
+        if self.state.global_clk == 0 {
+            self.initialize();
+            self.start_hint_replay();
+        }
+
+        let mut session = GdbSession {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            executor: self,
+            breakpoints: HashSet::new(),
+            stop: Stop::Signal(SIGTRAP),
+        };
+        session.serve()
+    }
+}
+
+/// A connection to a debugger controlling an execution.
+struct GdbSession<'e, 'a> {
+    executor: &'e mut Executor<'a>,
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    breakpoints: HashSet<u32>,
+    stop: Stop,
+}
+
+impl GdbSession<'_, '_> {
+    /// Answers the packets of the debugger until the session ends.
+    fn serve(&mut self) -> io::Result<()> {
+        loop {
+            let packet = match self.read_packet() {
+                Ok(packet) => packet,
+                Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(()),
+                Err(err) => return Err(err),
+            };
+            match self.handle(&packet)? {
+                Some(reply) => self.write_packet(&reply)?,
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Answers a packet, or returns `None` if the session ends.
+    fn handle(&mut self, packet: &str) -> io::Result<Option<String>> {
+        let reply = if packet == "?" {
+            Some(self.stop_reply())
+        } else if packet.starts_with("qSupported") {
+            Some(format!("PacketSize={MAX_PACKET_SIZE:x};qXfer:features:read+"))
+        } else if let Some(args) = packet.strip_prefix("qXfer:features:read:") {
+            self.read_features(args)
+        } else if packet == "qAttached" {
+            Some("1".to_string())
+        } else if packet == "qC" {
+            Some("QC1".to_string())
+        } else if packet == "qfThreadInfo" {
+            Some("m1".to_string())
+        } else if packet == "qsThreadInfo" {
+            Some("l".to_string())
+        } else if packet == "D" || packet.starts_with("vKill") {
+            self.write_packet("OK")?;
+            return Ok(None);
+        } else if packet == "k" {
+            return Ok(None);
+        } else if packet.starts_with('H') || packet.starts_with('T') {
+            Some("OK".to_string())
+        } else if packet == "g" {
+            Some(self.read_registers())
+        } else if let Some(args) = packet.strip_prefix('G') {
+            self.write_registers(args)
+        } else if let Some(args) = packet.strip_prefix('p') {
+            self.read_register(args)
+        } else if let Some(args) = packet.strip_prefix('P') {
+            self.write_register(args)
+        } else if let Some(args) = packet.strip_prefix('m') {
+            self.read_memory(args)
+        } else if let Some(args) = packet.strip_prefix('M') {
+            self.write_memory(args)
+        } else if let Some(args) = packet.strip_prefix('Z') {
+            self.set_breakpoint(args, true)
+        } else if let Some(args) = packet.strip_prefix('z') {
+            self.set_breakpoint(args, false)
+        } else if let Some(args) = packet.strip_prefix('s') {
+            self.resume(args, true)?
+        } else if let Some(args) = packet.strip_prefix('c') {
+            self.resume(args, false)?
+        } else {
+            Some(String::new())
+        };
+        Ok(Some(reply.unwrap_or_else(|| "E01".to_string())))
+    }
+
+    /// The reply describing why the program stopped.
+    fn stop_reply(&self) -> String {
+        match self.stop {
+            Stop::Signal(signal) | Stop::Fault(signal) => format!("S{signal:02x}"),
+            Stop::Exited(code) => format!("W{code:02x}"),
+        }
+    }
+
+    /// Resumes the program, optionally from another address, for a single instruction or until
+    /// it stops.
+    fn resume(&mut self, args: &str, step: bool) -> io::Result<Option<String>> {
+        if !args.is_empty() {
+            let Some(pc) = parse_hex(args) else { return Ok(None) };
+            self.executor.state.pc = pc as u32;
+        }
+        if let Stop::Signal(_) = self.stop {
+            self.stop = self.run(step)?;
+        }
+        Ok(Some(self.stop_reply()))
+    }
+
+    /// Executes the program for a single instruction or until it stops.
+    fn run(&mut self, step: bool) -> io::Result<Stop> {
+        loop {
+            let result = match self.executor.execute_cycle() {
+                Ok(true) => self.executor.finalize().map(|()| true),
+                result => result,
+            };
+            // The records are never proven, so there is no need to keep them around.
+            self.executor.records.clear();
+            match result {
+                Ok(true) => return Ok(Stop::Exited(self.executor.report.exit_code)),
+                Ok(false) => {}
+                Err(ExecutionError::Breakpoint()) => return Ok(Stop::Signal(SIGTRAP)),
+                Err(err) => {
+                    // Show the error in the debugger, since a signal alone does not tell much.
+                    self.write_packet(&format!("O{}", hex::encode(format!("{err}\n"))))?;
+                    return Ok(Stop::Fault(fault_signal(&err)));
+                }
+            }
+
+            if step || self.breakpoints.contains(&self.executor.state.pc) {
+                return Ok(Stop::Signal(SIGTRAP));
+            }
+            if self.executor.state.global_clk % INTERRUPT_POLL_CYCLES == 0 && self.interrupted()? {
+                return Ok(Stop::Signal(SIGINT));
+            }
+        }
+    }
+
+    /// Whether the debugger sent an interrupt, without blocking.
+    fn interrupted(&mut self) -> io::Result<bool> {
+        if self.reader.buffer().is_empty() {
+            self.reader.get_ref().set_nonblocking(true)?;
+            let result = self.reader.fill_buf().map(<[u8]>::is_empty);
+            self.reader.get_ref().set_nonblocking(false)?;
+            match result {
+                Ok(true) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(false) => {}
+                Err(err) if err.kind() == ErrorKind::WouldBlock => return Ok(false),
+                Err(err) => return Err(err),
+            }
+        }
+        if self.reader.buffer()[0] == INTERRUPT {
+            self.reader.consume(1);
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// The target description, declaring the architecture and the registers.
+    fn target_xml(&self) -> String {
+        let (architecture, bits) =
+            if self.executor.program.rv64 { ("riscv:rv64", 64) } else { ("riscv:rv32", 32) };
+        let registers = REGISTER_NAMES
+            .iter()
+            .enumerate()
+            .map(|(regnum, name)| {
+                let ty = match regnum {
+                    1 | PC_REGISTER => "code_ptr",
+                    2 | 8 => "data_ptr",
+                    _ => "int",
+                };
+                format!(
+                    "<reg name=\"{name}\" bitsize=\"{bits}\" type=\"{ty}\" regnum=\"{regnum}\"/>"
+                )
+            })
+            .collect::<String>();
+        format!(
+            "<?xml version=\"1.0\"?><!DOCTYPE target SYSTEM \"gdb-target.dtd\">\
+             <target version=\"1.0\"><architecture>{architecture}</architecture>\
+             <feature name=\"org.gnu.gdb.riscv.cpu\">{registers}</feature></target>"
+        )
+    }
+
+    /// Answers `qXfer:features:read:target.xml:offset,length`.
+    fn read_features(&self, args: &str) -> Option<String> {
+        let (annex, range) = args.split_once(':')?;
+        if annex != "target.xml" {
+            return Some("E00".to_string());
+        }
+        let (offset, length) = range.split_once(',')?;
+        let (offset, length) = (parse_hex(offset)? as usize, parse_hex(length)? as usize);
+
+        let xml = self.target_xml();
+        let start = offset.min(xml.len());
+        let end = offset.saturating_add(length).min(xml.len());
+        let more = if end < xml.len() { 'm' } else { 'l' };
+        Some(format!("{more}{}", &xml[start..end]))
+    }
+
+    /// The size in bytes of a register.
+    fn register_size(&self) -> usize {
+        if self.executor.program.rv64 {
+            8
+        } else {
+            4
+        }
+    }
+
+    fn register(&self, regnum: usize) -> u64 {
+        if regnum == PC_REGISTER {
+            return u64::from(self.executor.state.pc);
+        }
+        let lo = self.executor.state.memory.get(regnum as u32).map_or(0, |record| record.value);
+        let hi =
+            if self.executor.program.rv64 { self.executor.state.registers_hi[regnum] } else { 0 };
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+
+    fn set_register(&mut self, regnum: usize, value: u64) {
+        if regnum == PC_REGISTER {
+            self.executor.state.pc = value as u32;
+        } else if regnum != 0 {
+            self.set_word(regnum as u32, value as u32);
+            if self.executor.program.rv64 {
+                self.executor.state.registers_hi[regnum] = (value >> 32) as u32;
+            }
+        }
+    }
+
+    fn encode_register(&self, regnum: usize) -> String {
+        hex::encode(&self.register(regnum).to_le_bytes()[..self.register_size()])
+    }
+
+    fn read_registers(&self) -> String {
+        (0..REGISTER_NAMES.len()).map(|regnum| self.encode_register(regnum)).collect()
+    }
+
+    fn write_registers(&mut self, args: &str) -> Option<String> {
+        let bytes = hex::decode(args).ok()?;
+        let size = self.register_size();
+        if bytes.len() != REGISTER_NAMES.len() * size {
+            return None;
+        }
+        for (regnum, value) in bytes.chunks(size).enumerate() {
+            self.set_register(regnum, le_value(value));
+        }
+        Some("OK".to_string())
+    }
+
+    fn read_register(&self, args: &str) -> Option<String> {
+        let regnum = parse_hex(args)? as usize;
+        (regnum < REGISTER_NAMES.len()).then(|| self.encode_register(regnum))
+    }
+
+    fn write_register(&mut self, args: &str) -> Option<String> {
+        let (regnum, value) = args.split_once('=')?;
+        let regnum = parse_hex(regnum)? as usize;
+        let value = hex::decode(value).ok()?;
+        if regnum >= REGISTER_NAMES.len() || value.len() != self.register_size() {
+            return None;
+        }
+        self.set_register(regnum, le_value(&value));
+        Some("OK".to_string())
+    }
+
+    /// Answers `m addr,length`. Memory that was never written reads as zeroes, as it does for the
+    /// program.
+    fn read_memory(&self, args: &str) -> Option<String> {
+        let (addr, length) = args.split_once(',')?;
+        let (addr, length) = (parse_hex(addr)? as u32, parse_hex(length)? as u32);
+        let length = length.min(MAX_PACKET_SIZE as u32 / 2);
+        let bytes = (0..length)
+            .map(|i| {
+                let addr = addr.wrapping_add(i);
+                let word =
+                    self.executor.state.memory.get(align(addr)).map_or(0, |record| record.value);
+                (word >> ((addr % 4) * 8)) as u8
+            })
+            .collect::<Vec<_>>();
+        Some(hex::encode(bytes))
+    }
+
+    /// Answers `M addr,length:bytes`.
+    fn write_memory(&mut self, args: &str) -> Option<String> {
+        let (range, bytes) = args.split_once(':')?;
+        let (addr, length) = range.split_once(',')?;
+        let (addr, length) = (parse_hex(addr)? as u32, parse_hex(length)? as usize);
+        let bytes = hex::decode(bytes).ok()?;
+        if bytes.len() != length {
+            return None;
+        }
+        for (i, byte) in bytes.into_iter().enumerate() {
+            let addr = addr.wrapping_add(i as u32);
+            let shift = (addr % 4) * 8;
+            let word = self.executor.state.memory.get(align(addr)).map_or(0, |record| record.value);
+            self.set_word(align(addr), word & !(0xff << shift) | (u32::from(byte) << shift));
+        }
+        Some("OK".to_string())
+    }
+
+    /// Overwrites a word of memory, keeping the timestamp of its last access.
+    fn set_word(&mut self, addr: u32, value: u32) {
+        self.executor
+            .state
+            .memory
+            .entry(addr)
+            .or_insert(MemoryRecord { value: 0, shard: 0, timestamp: 0 })
+            .value = value;
+    }
+
+    /// Answers `Z type,addr,kind` and `z type,addr,kind`. Software and hardware breakpoints are
+    /// both stops at an address.
+    fn set_breakpoint(&mut self, args: &str, insert: bool) -> Option<String> {
+        let mut args = args.split(',');
+        let ty = args.next()?;
+        let addr = parse_hex(args.next()?)? as u32;
+        if ty != "0" && ty != "1" {
+            return Some(String::new());
+        }
+        if insert {
+            self.breakpoints.insert(addr);
+        } else {
+            self.breakpoints.remove(&addr);
+        }
+        Some("OK".to_string())
+    }
+
+    /// Reads the next packet, acknowledging it, and skipping the acknowledgements of the debugger.
+    fn read_packet(&mut self) -> io::Result<String> {
+        loop {
+            if self.read_byte()? != b'$' {
+                continue;
+            }
+            let mut payload = Vec::new();
+            self.reader.read_until(b'#', &mut payload)?;
+            if payload.pop() != Some(b'#') {
+                return Err(ErrorKind::UnexpectedEof.into());
+            }
+            let mut checksum = [0; 2];
+            self.reader.read_exact(&mut checksum)?;
+
+            let expected = std::str::from_utf8(&checksum).ok().and_then(parse_hex);
+            if expected == Some(u64::from(checksum_of(&payload))) {
+                self.writer.write_all(b"+")?;
+                return Ok(String::from_utf8_lossy(&payload).into_owned());
+            }
+            self.writer.write_all(b"-")?;
+        }
+    }
+
+    fn read_byte(&mut self) -> io::Result<u8> {
+        let mut byte = [0];
+        self.reader.read_exact(&mut byte)?;
+        Ok(byte[0])
+    }
+
+    fn write_packet(&mut self, payload: &str) -> io::Result<()> {
+        let checksum = checksum_of(payload.as_bytes());
+        self.writer.write_all(format!("${payload}#{checksum:02x}").as_bytes())?;
+        self.writer.flush()
+    }
+}
+
+/// The signal reported for a failed execution.
+fn fault_signal(err: &ExecutionError) -> u8 {
+    match err {
+        ExecutionError::InvalidMemoryAccess(..) => SIGSEGV,
+        ExecutionError::UnsupportedSyscall(_)
+        | ExecutionError::Unimplemented()
+        | ExecutionError::DeniedOpcode(..) => SIGILL,
+        ExecutionError::CycleLimitExceeded { .. } => SIGXCPU,
+        _ => SIGABRT,
+    }
+}
+
+fn checksum_of(payload: &[u8]) -> u8 {
+    payload.iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte))
+}
+
+fn parse_hex(value: &str) -> Option<u64> {
+    u64::from_str_radix(value, 16).ok()
+}
+
+/// The value of a register encoded in little-endian bytes.
+fn le_value(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |value, &byte| (value << 8) | u64::from(byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    use sp1_stark::SP1CoreOpts;
+
+    use super::checksum_of;
+    use crate::{programs::tests::simple_program, Executor, Register};
+
+    /// A minimal debugger, sending a packet and reading the reply.
+    struct Client(TcpStream);
+
+    impl Client {
+        fn send(&mut self, payload: &str) -> String {
+            let packet = format!("${payload}#{:02x}", checksum_of(payload.as_bytes()));
+            self.0.write_all(packet.as_bytes()).unwrap();
+            self.reply()
+        }
+
+        fn reply(&mut self) -> String {
+            let mut byte = [0];
+            loop {
+                self.0.read_exact(&mut byte).unwrap();
+                if byte[0] == b'$' {
+                    break;
+                }
+            }
+            let mut payload = Vec::new();
+            loop {
+                self.0.read_exact(&mut byte).unwrap();
+                if byte[0] == b'#' {
+                    break;
+                }
+                payload.push(byte[0]);
+            }
+            self.0.read_exact(&mut [0; 2]).unwrap();
+            self.0.write_all(b"+").unwrap();
+            String::from_utf8(payload).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_gdb_session() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let debugger = thread::spawn(move || {
+            let stream = TcpStream::connect(addr).unwrap();
+            stream.set_nodelay(true).unwrap();
+            let mut client = Client(stream);
+            assert_eq!(client.send("?"), "S05");
+            assert!(client.send("qXfer:features:read:target.xml:0,1000").contains("riscv:rv32"));
+            assert_eq!(client.send("p20"), "00000000");
+
+            // Stop after the second instruction, and single-step the third.
+            assert_eq!(client.send("Z0,8,4"), "OK");
+            assert_eq!(client.send("c"), "S05");
+            assert_eq!(client.send("p20"), "08000000");
+            assert_eq!(client.send("p1d"), "05000000");
+            assert_eq!(client.send("M101,2:beef"), "OK");
+            assert_eq!(client.send("m100,4"), "00beef00");
+
+            // The program finishes with the third instruction, adding the overwritten register.
+            assert_eq!(client.send("P1e=07000000"), "OK");
+            assert_eq!(client.send("s"), "W00");
+            assert_eq!(client.send("p1f"), "0c000000");
+            assert_eq!(client.send("c"), "W00");
+            client.send("D")
+        });
+
+        let mut runtime = Executor::new(simple_program(), SP1CoreOpts::default());
+        runtime.run_gdb(&listener).unwrap();
+        assert_eq!(debugger.join().unwrap(), "OK");
+        assert_eq!(runtime.register(Register::X31), 12);
+    }
+}
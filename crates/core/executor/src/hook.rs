@@ -3,10 +3,11 @@ use core::fmt::Debug;
 use std::sync::{Arc, RwLock, RwLockWriteGuard};
 
 use hashbrown::HashMap;
+use num::BigUint;
 use sp1_curves::k256::{Invert, RecoveryId, Signature, VerifyingKey};
 use sp1_curves::p256::Signature as p256Signature;
 
-use crate::Executor;
+use crate::{events::fr_ntt, executor::hint_region_word, Executor, Register};
 
 /// A runtime hook, wrapped in a smart pointer.
 pub type BoxedHook<'a> = Arc<RwLock<dyn Hook + Send + Sync + 'a>>;
@@ -15,6 +16,8 @@ pub type BoxedHook<'a> = Arc<RwLock<dyn Hook + Send + Sync + 'a>>;
 pub const K1_ECRECOVER_HOOK: u32 = 5;
 /// The file descriptor through which to access `hook_r1_ecrecover`.
 pub const R1_ECRECOVER_HOOK: u32 = 6;
+/// The file descriptor through which to access `hook_fr_ntt`.
+pub const FR_NTT_HOOK: u32 = 7;
 
 /// A runtime hook. May be called during execution by writing to a specified file descriptor,
 /// accepting and returning arbitrary data.
@@ -80,6 +83,7 @@ impl<'a> Default for HookRegistry<'a> {
             // add an assertion to the test `hook_fds_match` below.
             (K1_ECRECOVER_HOOK, hookify(hook_k1_ecrecover)),
             (R1_ECRECOVER_HOOK, hookify(hook_r1_ecrecover)),
+            (FR_NTT_HOOK, hookify(hook_fr_ntt)),
         ]);
 
         Self { table }
@@ -105,6 +109,33 @@ pub struct HookEnv<'a, 'b: 'a> {
     pub runtime: &'a Executor<'b>,
 }
 
+impl HookEnv<'_, '_> {
+    /// The current program counter.
+    #[must_use]
+    pub fn pc(&self) -> u32 {
+        self.runtime.state.pc
+    }
+
+    /// The current value of a register.
+    #[must_use]
+    pub fn register(&self, register: Register) -> u32 {
+        self.word(register as u32)
+    }
+
+    /// The current value of a word of memory, as the program would read it.
+    #[must_use]
+    pub fn word(&self, addr: u32) -> u32 {
+        let state = &self.runtime.state;
+        state
+            .memory
+            .get(addr)
+            .map(|record| record.value)
+            .or_else(|| state.uninitialized_memory.get(addr).copied())
+            .or_else(|| hint_region_word(&state.hint_regions, addr))
+            .unwrap_or(0)
+    }
+}
+
 /// Recovers the public key from the signature and message hash using the k256 crate.
 ///
 /// # Arguments
@@ -166,6 +197,34 @@ pub fn hook_r1_ecrecover(_: HookEnv, buf: &[u8]) -> Vec<Vec<u8>> {
     vec![s_inverse.to_bytes().to_vec()]
 }
 
+/// Computes the NTT of a polynomial over the BN254 scalar field.
+///
+/// # Arguments
+///
+/// * `env` - The environment in which the hook is invoked.
+/// * `buf` - The buffer containing the coefficients of the polynomial, as 32 little endian bytes
+///   each. Their number must be a power of two.
+///
+/// The result is a single vector containing the evaluations of the polynomial over the domain of
+/// the same size, as 32 little endian bytes each.
+///
+/// WARNING: This function is used to compute the NTT outside of the zkVM context. These values
+/// must be constrained by the zkVM for correctness.
+#[must_use]
+pub fn hook_fr_ntt(_: HookEnv, buf: &[u8]) -> Vec<Vec<u8>> {
+    assert_eq!(buf.len() % 32, 0, "ntt input should be a whole number of field elements");
+    let coeffs = buf.chunks(32).map(BigUint::from_bytes_le).collect::<Vec<_>>();
+    let evals = fr_ntt(&coeffs)
+        .into_iter()
+        .flat_map(|eval| {
+            let mut bytes = eval.to_bytes_le();
+            bytes.resize(32, 0);
+            bytes
+        })
+        .collect();
+    vec![evals]
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -175,6 +234,17 @@ pub mod tests {
         use sp1_zkvm::lib::io;
         assert_eq!(K1_ECRECOVER_HOOK, io::K1_ECRECOVER_HOOK);
         assert_eq!(R1_ECRECOVER_HOOK, io::R1_ECRECOVER_HOOK);
+        assert_eq!(FR_NTT_HOOK, io::FR_NTT_HOOK);
+    }
+
+    #[test]
+    pub fn fr_roots_of_unity_match() {
+        use crate::events::{fr_root_of_unity, FR_TWO_ADICITY};
+        use sp1_zkvm::lib::ntt;
+        assert_eq!(FR_TWO_ADICITY, ntt::FR_TWO_ADICITY);
+        for (log_n, root) in ntt::FR_ROOTS_OF_UNITY.iter().enumerate() {
+            assert_eq!(fr_root_of_unity(log_n), BigUint::from_slice(root));
+        }
     }
 
     #[test]
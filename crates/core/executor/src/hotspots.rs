@@ -0,0 +1,188 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::Program;
+
+/// The default number of hottest loops and basic blocks printed with an execution report.
+pub const DEFAULT_HOT_LOOPS: usize = 10;
+
+/// The executions of a basic block of the program, i.e. a sequence of instructions entered by a
+/// jump or a taken branch and left by the next one.
+///
+/// The blocks are found as the program runs, so a block entered both by a jump and by falling
+/// through from the instruction before it is counted from its first instruction only when it is
+/// jumped to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BasicBlockStats {
+    /// The number of times the block was entered.
+    pub executions: u64,
+    /// The number of instructions executed in the block, over all of its executions.
+    pub instructions: u64,
+    /// The clock cycles taken by the block, counting the extra cycles of its syscalls.
+    pub cycles: u64,
+}
+
+/// The iterations of a loop of the program, found from the backward jump or branch closing it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoopStats {
+    /// The program counter of the first instruction of the loop, targeted by the backward jump.
+    pub head: u32,
+    /// The function containing the loop, if the program has its symbols.
+    pub function: Option<String>,
+    /// The number of times the backward jump was taken.
+    pub iterations: u64,
+}
+
+/// A loop of the program with the clock cycles spent in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotLoop {
+    /// The program counter of the backward jump or branch closing the loop.
+    pub latch: u32,
+    /// The iterations of the loop.
+    pub stats: LoopStats,
+    /// The clock cycles taken by the basic blocks between the head and the latch of the loop.
+    ///
+    /// The cycles of the functions called from the loop are not counted, since their blocks lie
+    /// outside of it.
+    pub cycles: u64,
+}
+
+impl HotLoop {
+    /// The average number of clock cycles taken by an iteration of the loop.
+    #[must_use]
+    pub fn cycles_per_iteration(&self) -> u64 {
+        self.cycles / self.stats.iterations.max(1)
+    }
+}
+
+impl Display for HotLoop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "0x{:08x}..=0x{:08x} in {}: {} iterations, {} cycles each",
+            self.stats.head,
+            self.latch,
+            self.stats.function.as_deref().unwrap_or("<unknown>"),
+            self.stats.iterations,
+            self.cycles_per_iteration(),
+        )
+    }
+}
+
+/// Builds the histogram of the basic blocks of the program by clock cycles, along with the loops
+/// closed by the backward jumps and branches it takes.
+#[derive(Debug, Clone, Default)]
+pub struct BlockProfiler {
+    /// The program counter of the first instruction of the current block, unless the previous
+    /// instruction left its block.
+    leader: Option<u32>,
+}
+
+impl BlockProfiler {
+    /// Account for the instruction at `pc`, which took `cycles` clock cycles and continued at
+    /// `next_pc`.
+    pub(crate) fn step(
+        &mut self,
+        program: &Program,
+        pc: u32,
+        next_pc: u32,
+        cycles: u64,
+        blocks: &mut HashMap<u32, BasicBlockStats>,
+        loops: &mut HashMap<u32, LoopStats>,
+    ) {
+        let leader = *self.leader.get_or_insert(pc);
+        let block = blocks.entry(leader).or_default();
+        if leader == pc {
+            block.executions += 1;
+        }
+        block.instructions += 1;
+        block.cycles += cycles;
+
+        // Any instruction not falling through to the next one ends the block.
+        if next_pc != pc.wrapping_add(program.instruction_size(pc)) {
+            self.leader = None;
+            // The halt syscall jumps to 0, which closes no loop.
+            if next_pc <= pc && next_pc != 0 {
+                let stats = loops.entry(pc).or_insert_with(|| LoopStats {
+                    head: next_pc,
+                    function: program.symbol_at(pc).map(|symbol| symbol.name.clone()),
+                    iterations: 0,
+                });
+                stats.iterations += 1;
+            }
+        }
+    }
+}
+
+/// The `n` loops which took the most clock cycles, sorted by cycles.
+pub(crate) fn hot_loops(
+    blocks: &HashMap<u32, BasicBlockStats>,
+    loops: &HashMap<u32, LoopStats>,
+    n: usize,
+) -> Vec<HotLoop> {
+    // The cycles of the blocks sorted by leader, summed up to every block.
+    let mut cumulative: Vec<_> = blocks.iter().map(|(&pc, block)| (pc, block.cycles)).collect();
+    cumulative.sort_unstable_by_key(|&(pc, _)| pc);
+    let mut total = 0;
+    for (_, cycles) in &mut cumulative {
+        total += *cycles;
+        *cycles = total;
+    }
+    // The cycles of the blocks whose leader precedes `pc`.
+    let cycles_before = |pc: u32| {
+        let index = cumulative.partition_point(|&(leader, _)| leader < pc);
+        index.checked_sub(1).map_or(0, |index| cumulative[index].1)
+    };
+
+    let mut hot_loops: Vec<_> = loops
+        .iter()
+        .map(|(&latch, stats)| HotLoop {
+            latch,
+            stats: stats.clone(),
+            cycles: cycles_before(latch.saturating_add(1)) - cycles_before(stats.head),
+        })
+        .collect();
+    hot_loops.sort_by_key(|hot_loop| (std::cmp::Reverse(hot_loop.cycles), hot_loop.latch));
+    hot_loops.truncate(n);
+    hot_loops
+}
+
+#[cfg(test)]
+mod tests {
+    use hashbrown::HashMap;
+
+    use super::{hot_loops, BasicBlockStats, BlockProfiler};
+    use crate::{Instruction, Opcode, Program};
+
+    #[test]
+    fn test_block_profiler() {
+        // Enter a loop over 4..=16 by falling through from 0, and iterate it three times
+        // before branching out of it to 24.
+        let instructions = vec![Instruction::new(Opcode::ADD, 0, 0, 0, false, true); 6];
+        let program = Program::new(instructions, 0, 0);
+        let mut profiler = BlockProfiler::default();
+        let (mut blocks, mut loops) = (HashMap::new(), HashMap::new());
+        let mut step =
+            |pc, next_pc| profiler.step(&program, pc, next_pc, 1, &mut blocks, &mut loops);
+        step(0, 4);
+        for iteration in 0..3 {
+            step(4, 8);
+            step(8, 12);
+            step(12, 16);
+            step(16, if iteration < 2 { 4 } else { 24 });
+        }
+        step(24, 28);
+
+        // The first iteration is part of the block entered at 0.
+        assert_eq!(blocks[&0], BasicBlockStats { executions: 1, instructions: 5, cycles: 5 });
+        assert_eq!(blocks[&4], BasicBlockStats { executions: 2, instructions: 8, cycles: 8 });
+        assert_eq!(blocks[&24], BasicBlockStats { executions: 1, instructions: 1, cycles: 1 });
+        assert_eq!((loops[&16].head, loops[&16].iterations), (4, 2));
+
+        let hot = hot_loops(&blocks, &loops, 1);
+        assert_eq!(hot.len(), 1);
+        assert_eq!((hot[0].latch, hot[0].cycles, hot[0].cycles_per_iteration()), (16, 8, 4));
+    }
+}
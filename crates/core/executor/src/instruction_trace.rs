@@ -0,0 +1,156 @@
+use core::fmt::Debug;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Range,
+    sync::{Arc, RwLock},
+};
+
+use hashbrown::HashSet;
+
+use crate::{syscalls::SyscallCode, Opcode};
+
+/// An instruction trace sink, wrapped in a smart pointer.
+pub type BoxedInstructionSink<'a> = Arc<RwLock<dyn FnMut(TracedInstruction) + Send + Sync + 'a>>;
+
+/// An instruction executed by the program, as passed to an instruction trace sink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TracedInstruction {
+    /// The number of cycles executed before the instruction.
+    pub global_clk: u64,
+    /// The program counter of the instruction.
+    pub pc: u32,
+    /// The opcode of the instruction.
+    pub opcode: Opcode,
+    /// The register of the operand `a`, i.e. the destination register, or the source register of
+    /// the value stored or compared by a store or a branch.
+    pub rd: u8,
+    /// The values of the operands `a`, `b` and `c` of the instruction, as in the CPU events.
+    pub operands: [u32; 3],
+    /// The syscall made by the instruction, for `ECALL`.
+    pub syscall: Option<SyscallCode>,
+}
+
+impl Display for TracedInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let [a, b, c] = self.operands;
+        write!(
+            f,
+            "{} 0x{:08x} {} x{} a=0x{a:08x} b=0x{b:08x} c=0x{c:08x}",
+            self.global_clk, self.pc, self.opcode, self.rd
+        )?;
+        if let Some(syscall) = self.syscall {
+            write!(f, " {syscall:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The instructions passed to an instruction trace sink, which must satisfy all the conditions
+/// of the filter. The default filter passes every instruction.
+#[derive(Debug, Clone, Default)]
+pub struct InstructionFilter {
+    pcs: Option<Range<u32>>,
+    opcodes: Option<HashSet<Opcode>>,
+    syscalls_only: bool,
+}
+
+impl InstructionFilter {
+    /// Create a filter passing every instruction.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only pass the instructions whose program counter is in `pcs`.
+    #[must_use]
+    pub fn pcs(mut self, pcs: Range<u32>) -> Self {
+        self.pcs = Some(pcs);
+        self
+    }
+
+    /// Only pass the instructions with one of `opcodes`.
+    #[must_use]
+    pub fn opcodes(mut self, opcodes: impl IntoIterator<Item = Opcode>) -> Self {
+        self.opcodes = Some(opcodes.into_iter().collect());
+        self
+    }
+
+    /// Only pass the syscalls.
+    #[must_use]
+    pub fn syscalls_only(mut self) -> Self {
+        self.syscalls_only = true;
+        self
+    }
+
+    /// Whether the filter passes the instruction at `pc` with `opcode`.
+    #[must_use]
+    pub fn matches(&self, pc: u32, opcode: Opcode) -> bool {
+        if let Some(pcs) = &self.pcs {
+            if !pcs.contains(&pc) {
+                return false;
+            }
+        }
+        if let Some(opcodes) = &self.opcodes {
+            if !opcodes.contains(&opcode) {
+                return false;
+            }
+        }
+        !self.syscalls_only || opcode == Opcode::ECALL
+    }
+}
+
+/// A sink streaming the instructions executed by the program that pass a filter, through which
+/// a host can debug a region of the program without dumping the trace of the whole execution.
+///
+/// The instructions are passed once they have executed, in the order of execution, including
+/// those of the unconstrained blocks. The instructions that trap are not passed. Of the
+/// instructions of RV64IM programs, only the system ones are passed.
+#[derive(Clone)]
+pub struct InstructionTracer<'a> {
+    pub(crate) filter: InstructionFilter,
+    pub(crate) sink: BoxedInstructionSink<'a>,
+}
+
+impl<'a> InstructionTracer<'a> {
+    /// Pass the instructions matching `filter` to `sink`.
+    pub fn new(
+        filter: InstructionFilter,
+        sink: impl FnMut(TracedInstruction) + Send + Sync + 'a,
+    ) -> Self {
+        Self { filter, sink: Arc::new(RwLock::new(sink)) }
+    }
+
+    /// Pass an instruction to the sink. The caller checks it matches the filter.
+    pub(crate) fn trace(&self, instruction: TracedInstruction) {
+        // Calling `.unwrap()` panics on a poisoned lock. Should never happen normally.
+        (self.sink.write().unwrap())(instruction);
+    }
+}
+
+impl<'a> Debug for InstructionTracer<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InstructionTracer").field("filter", &self.filter).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InstructionFilter;
+    use crate::Opcode;
+
+    #[test]
+    fn test_instruction_filter() {
+        let filter = InstructionFilter::new();
+        assert!(filter.matches(0, Opcode::ADD));
+
+        let filter =
+            InstructionFilter::new().pcs(0x100..0x200).opcodes([Opcode::ADD, Opcode::ECALL]);
+        assert!(filter.matches(0x100, Opcode::ADD));
+        assert!(!filter.matches(0x200, Opcode::ADD));
+        assert!(!filter.matches(0x100, Opcode::SUB));
+
+        let filter = filter.syscalls_only();
+        assert!(filter.matches(0x1fc, Opcode::ECALL));
+        assert!(!filter.matches(0x1fc, Opcode::ADD));
+    }
+}
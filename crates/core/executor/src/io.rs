@@ -4,7 +4,7 @@ use serde::{de::DeserializeOwned, Serialize};
 use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, StarkVerifyingKey};
 
 use super::Executor;
-use crate::SP1ReduceProof;
+use crate::{SP1ReduceProof, StdinStream};
 
 impl<'a> Read for Executor<'a> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
@@ -33,6 +33,14 @@ impl<'a> Executor<'a> {
         }
     }
 
+    /// Read the standard input stream from `stream` once the inputs written so far are consumed.
+    ///
+    /// The inputs of the stream are read lazily as the program consumes them, so that they are
+    /// never all held in memory at once.
+    pub fn write_stdin_stream(&mut self, stream: StdinStream) {
+        self.stdin_stream = Some(stream);
+    }
+
     /// Write a proof and verifying key to the proof stream.
     pub fn write_proof(
         &mut self,
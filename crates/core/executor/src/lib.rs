@@ -1,4 +1,8 @@
 //! An implementation of an exucutor for the SP1 RISC-V zkVM.
+//!
+//! The `fs` and `threads` features, enabled by default, provide the file system and the
+//! background threads to the executor. Without them, it compiles to `wasm32-unknown-unknown`, e.g.
+//! to simulate programs and estimate their cycles in a browser.
 
 #![warn(clippy::pedantic)]
 #![allow(clippy::similar_names)]
@@ -19,39 +23,73 @@
 #![allow(clippy::explicit_iter_loop)]
 #![warn(missing_docs)]
 
+mod abi;
+mod archive;
+mod backtrace;
+mod checkpoint;
 mod context;
+mod denylist;
 mod dependencies;
+mod diff;
 mod disassembler;
 pub mod events;
 mod executor;
+mod gas;
+mod gdb;
 mod hook;
+mod hotspots;
 mod instruction;
+mod instruction_trace;
 mod io;
+mod memcpy;
 mod memory;
 mod opcode;
+mod output;
+mod prefetch;
 mod program;
 #[cfg(any(test, feature = "programs"))]
 pub mod programs;
 mod record;
 mod reduce;
 mod register;
+mod replay;
 mod report;
+mod rv64;
 mod shape;
 mod state;
+mod stream;
 pub mod subproof;
 pub mod syscalls;
+mod trace_events;
 mod utils;
+mod watch;
 
+pub use abi::{SYSCALL_ABI_SECTION, SYSCALL_ABI_VERSION};
+pub use backtrace::*;
+pub use checkpoint::*;
 pub use context::*;
+pub use denylist::*;
+pub use diff::*;
 pub use executor::*;
+pub use gas::*;
 pub use hook::*;
+pub use hotspots::*;
 pub use instruction::*;
+pub use instruction_trace::*;
+pub use memcpy::*;
+pub use memory::MEMORY_PAGE_BYTES;
 pub use opcode::*;
+pub use output::*;
+pub use prefetch::*;
 pub use program::*;
 pub use record::*;
 pub use reduce::*;
 pub use register::*;
+pub use replay::*;
 pub use report::*;
 pub use shape::*;
 pub use state::*;
+pub use stream::*;
+pub use trace_events::*;
 pub use utils::*;
+pub use watch::*;
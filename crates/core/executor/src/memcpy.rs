@@ -0,0 +1,267 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use elf::{abi::STT_FUNC, endian::LittleEndian, ElfBytes};
+use hashbrown::HashMap;
+use rustc_demangle::demangle;
+use serde::{Deserialize, Serialize};
+
+/// The default number of cycles a call site must save with a memcopy precompile to be advised.
+pub const DEFAULT_MEMCPY_ADVICE_THRESHOLD: u64 = 10_000;
+
+/// The estimated number of cycles of a single `syscall_memcopy32` or `syscall_memcopy64` call,
+/// including setting up its arguments and advancing the pointers.
+pub const MEMCOPY_CYCLES_PER_CHUNK: u64 = 6;
+
+/// The estimated number of cycles of the loop driving the memcopy precompile for a single copy.
+pub const MEMCOPY_CYCLES_PER_CALL: u64 = 12;
+
+/// The software memory routines linked into a guest program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MemcpyKind {
+    /// `memcpy`.
+    Memcpy,
+    /// `memmove`.
+    Memmove,
+    /// `memset`.
+    Memset,
+}
+
+impl MemcpyKind {
+    /// All the tracked routines.
+    pub const ALL: [Self; 3] = [Self::Memcpy, Self::Memmove, Self::Memset];
+
+    /// The symbol of the routine in the ELF.
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Memcpy => "memcpy",
+            Self::Memmove => "memmove",
+            Self::Memset => "memset",
+        }
+    }
+}
+
+impl Display for MemcpyKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// The memcopy precompile a call site is advised to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemcpyRecommendation {
+    /// Copy in chunks of 32 bytes with `syscall_memcopy32`.
+    Memcopy32,
+    /// Copy in chunks of 64 bytes with `syscall_memcopy64`.
+    Memcopy64,
+}
+
+impl MemcpyRecommendation {
+    /// The number of bytes copied by a single call to the precompile.
+    #[must_use]
+    pub const fn chunk_bytes(self) -> u64 {
+        match self {
+            Self::Memcopy32 => 32,
+            Self::Memcopy64 => 64,
+        }
+    }
+}
+
+impl Display for MemcpyRecommendation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Memcopy32 => write!(f, "syscall_memcopy32"),
+            Self::Memcopy64 => write!(f, "syscall_memcopy64"),
+        }
+    }
+}
+
+/// The address ranges of the software memory routines and of every function of a guest program,
+/// read from the symbol table of its ELF.
+#[derive(Debug, Clone, Default)]
+pub struct MemcpySymbols {
+    /// The routines with their start and end (exclusive) addresses.
+    routines: Vec<(MemcpyKind, u32, u32)>,
+    /// The functions with their start and end (exclusive) addresses, sorted by start address.
+    functions: Vec<(u32, u32, String)>,
+}
+
+impl MemcpySymbols {
+    /// Read the symbols from an ELF.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the ELF is not valid or has no symbol table.
+    pub fn from_elf(input: &[u8]) -> eyre::Result<Self> {
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(input)?;
+        let (symtab, strtab) =
+            elf.symbol_table()?.ok_or_else(|| eyre::eyre!("the elf has no symbol table"))?;
+
+        let mut routines = Vec::new();
+        let mut functions = Vec::new();
+        for sym in symtab.iter().filter(|sym| sym.st_symtype() == STT_FUNC && sym.st_size > 0) {
+            let name = strtab.get(sym.st_name as usize)?;
+            let start: u32 = sym.st_value.try_into()?;
+            let end = start.saturating_add(sym.st_size.try_into()?);
+            if let Some(kind) = MemcpyKind::ALL.into_iter().find(|kind| kind.symbol() == name) {
+                routines.push((kind, start, end));
+            }
+            functions.push((start, end, format!("{:#}", demangle(name))));
+        }
+        functions.sort_by_key(|&(start, _, _)| start);
+
+        Ok(Self { routines, functions })
+    }
+
+    /// The routine containing `pc`, with its start address.
+    #[must_use]
+    pub fn routine_at(&self, pc: u32) -> Option<(MemcpyKind, u32)> {
+        self.routines
+            .iter()
+            .find(|&&(_, start, end)| (start..end).contains(&pc))
+            .map(|&(kind, start, _)| (kind, start))
+    }
+
+    /// The name of the function containing `pc`.
+    #[must_use]
+    pub fn function_at(&self, pc: u32) -> Option<&str> {
+        let index = self.functions.partition_point(|&(start, _, _)| start <= pc).checked_sub(1)?;
+        let (_, end, name) = &self.functions[index];
+        (pc < *end).then_some(name.as_str())
+    }
+}
+
+/// The software memory routine calls made from a single call site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemcpyCallSite {
+    /// The routine that is called.
+    pub kind: MemcpyKind,
+    /// The function containing the call site, if known.
+    pub caller: Option<String>,
+    /// The number of calls.
+    pub calls: u64,
+    /// The total number of bytes copied or set.
+    pub bytes: u64,
+    /// The total number of cycles spent in the routine.
+    pub cycles: u64,
+    /// The total number of whole 32 byte chunks, summed over the calls.
+    pub chunks_32: u64,
+    /// The total number of whole 64 byte chunks, summed over the calls.
+    pub chunks_64: u64,
+}
+
+impl MemcpyCallSite {
+    /// The estimated number of cycles of the calls if they used the given precompile.
+    ///
+    /// The bytes that don't fill a whole chunk are still handled by the software routine, at its
+    /// observed cost per byte.
+    #[must_use]
+    pub fn estimated_cycles(&self, recommendation: MemcpyRecommendation) -> u64 {
+        let chunks = match recommendation {
+            MemcpyRecommendation::Memcopy32 => self.chunks_32,
+            MemcpyRecommendation::Memcopy64 => self.chunks_64,
+        };
+        let tail_bytes = self.bytes - chunks * recommendation.chunk_bytes();
+        let tail_cycles = if self.bytes == 0 { 0 } else { tail_bytes * self.cycles / self.bytes };
+        chunks * MEMCOPY_CYCLES_PER_CHUNK + self.calls * MEMCOPY_CYCLES_PER_CALL + tail_cycles
+    }
+
+    /// The precompile that would save the most cycles at this call site, with the estimated
+    /// number of cycles saved.
+    #[must_use]
+    pub fn best_recommendation(&self) -> (MemcpyRecommendation, u64) {
+        [MemcpyRecommendation::Memcopy64, MemcpyRecommendation::Memcopy32]
+            .into_iter()
+            .map(|recommendation| {
+                (recommendation, self.cycles.saturating_sub(self.estimated_cycles(recommendation)))
+            })
+            .max_by_key(|&(_, saved)| saved)
+            .unwrap()
+    }
+}
+
+/// Advice to replace the software memory routine calls of a call site with a precompile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemcpyAdvice {
+    /// The program counter of the call site.
+    pub pc: u32,
+    /// The calls made from the call site.
+    pub site: MemcpyCallSite,
+    /// The precompile to use.
+    pub recommendation: MemcpyRecommendation,
+    /// The estimated number of cycles saved.
+    pub saved_cycles: u64,
+}
+
+impl Display for MemcpyAdvice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "0x{:08x} in {}: {} calls to {}, {} bytes in {} cycles, use {} to save ~{} cycles",
+            self.pc,
+            self.site.caller.as_deref().unwrap_or("<unknown>"),
+            self.site.calls,
+            self.site.kind,
+            self.site.bytes,
+            self.site.cycles,
+            self.recommendation,
+            self.saved_cycles,
+        )
+    }
+}
+
+/// Attributes the cycles spent in the software memory routines to the call sites they were
+/// called from.
+#[derive(Debug, Clone)]
+pub struct MemcpyTracker {
+    /// The symbols of the program.
+    symbols: MemcpySymbols,
+    /// The program counter of the previous cycle.
+    prev_pc: u32,
+    /// The call site of the routine currently running.
+    active: Option<u32>,
+}
+
+impl MemcpyTracker {
+    /// Create a new [`MemcpyTracker`].
+    #[must_use]
+    pub const fn new(symbols: MemcpySymbols) -> Self {
+        Self { symbols, prev_pc: 0, active: None }
+    }
+
+    /// Account for the cycle at `pc`, where `len` is the value of the length argument register.
+    pub(crate) fn step(&mut self, pc: u32, len: u32, sites: &mut HashMap<u32, MemcpyCallSite>) {
+        let prev_pc = std::mem::replace(&mut self.prev_pc, pc);
+        let Some((kind, start)) = self.symbols.routine_at(pc) else {
+            self.active = None;
+            return;
+        };
+
+        let call_site = match self.active {
+            Some(call_site) => call_site,
+            // Only a jump to the start of the routine is a call.
+            None if pc == start => {
+                let site = sites.entry(prev_pc).or_insert_with(|| MemcpyCallSite {
+                    kind,
+                    caller: self.symbols.function_at(prev_pc).map(str::to_string),
+                    calls: 0,
+                    bytes: 0,
+                    cycles: 0,
+                    chunks_32: 0,
+                    chunks_64: 0,
+                });
+                let len = u64::from(len);
+                site.calls += 1;
+                site.bytes += len;
+                site.chunks_32 += len / 32;
+                site.chunks_64 += len / 64;
+                self.active = Some(prev_pc);
+                prev_pc
+            }
+            None => return,
+        };
+        if let Some(site) = sites.get_mut(&call_site) {
+            site.cycles += 1;
+        }
+    }
+}
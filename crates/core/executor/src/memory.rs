@@ -1,6 +1,14 @@
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, DeserializeOwned, Error as _, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use vec_map::VecMap;
 
+use crate::events::MemoryRecord;
+
 /// A page of memory.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Page<V>(VecMap<V>);
@@ -13,11 +21,17 @@ impl<V> Default for Page<V> {
 
 const LOG_PAGE_LEN: usize = 14;
 const PAGE_LEN: usize = 1 << LOG_PAGE_LEN;
+/// The number of bytes of the address space covered by a page of memory.
+pub const MEMORY_PAGE_BYTES: usize = PAGE_LEN << 2;
 const MAX_PAGE_COUNT: usize = ((1 << 31) - (1 << 27)) / 4 / PAGE_LEN + 1;
 const NO_PAGE: u16 = u16::MAX;
 const PAGE_MASK: usize = PAGE_LEN - 1;
+/// The number of bytes of the bitmap of the occupied slots of a page in the raw format.
+const BITMAP_BYTES: usize = PAGE_LEN / 8;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 #[serde(bound(serialize = "V: Serialize"))]
 #[serde(bound(deserialize = "V: DeserializeOwned"))]
 pub struct NewPage<V>(Vec<Option<V>>);
@@ -35,9 +49,11 @@ impl<V: Copy> Default for NewPage<V> {
 }
 
 /// Paged memory. Balances both memory locality and total memory usage.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(bound(serialize = "V: Serialize"))]
-#[serde(bound(deserialize = "V: DeserializeOwned"))]
+///
+/// Besides the raw format of its [`Serialize`] implementation, it is archived with [`rkyv`] as is,
+/// pages and index alike, so that the archived memory of a mapped checkpoint can be looked up in
+/// place.
+#[derive(Debug, Clone, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
 pub struct PagedMemory<V: Copy> {
     /// The internal page table.
     pub page_table: Vec<NewPage<V>>,
@@ -120,6 +136,11 @@ impl<V: Copy> PagedMemory<V> {
         }
     }
 
+    /// Returns the number of pages that have been allocated, since the last [`Self::clear`].
+    pub fn page_count(&self) -> usize {
+        self.page_table.len()
+    }
+
     /// Returns an iterator over the occupied addresses.
     pub fn keys(&self) -> impl Iterator<Item = u32> + '_ {
         self.index.iter().enumerate().filter(|(_, &i)| i != NO_PAGE).flat_map(|(i, index)| {
@@ -167,6 +188,176 @@ impl<V: Copy> PagedMemory<V> {
     }
 }
 
+impl<V: Copy + rkyv::Archive> ArchivedPagedMemory<V> {
+    /// Get a reference to the archived memory value at the given address, if it exists.
+    pub fn get(&self, addr: u32) -> Option<&V::Archived> {
+        let (upper, lower) = PagedMemory::<V>::indices(addr);
+        let index = self.index[upper].to_native();
+        if index == NO_PAGE {
+            None
+        } else {
+            self.page_table[index as usize].0[lower].as_ref()
+        }
+    }
+}
+
+/// A page of memory in the raw format written by the [`Serialize`] implementation of
+/// [`PagedMemory`].
+struct RawPage<'a>(&'a [u8]);
+
+impl Serialize for RawPage<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// The memory is serialized as a sequence of pages in a raw format: for every page, a byte string
+/// holding the upper bits of its addresses, the bitmap of its occupied slots and the words of
+/// their values, as little-endian words. Unlike the values of each slot, the byte strings are
+/// written and read in one go by binary formats, and are decoded straight from the input when it
+/// is borrowed, e.g. from a mapped file.
+impl<V: RawValue> Serialize for PagedMemory<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.page_table.len()))?;
+        let mut bytes = Vec::with_capacity(4 + BITMAP_BYTES + 4 * PAGE_LEN * V::WORDS);
+        for (upper, &index) in self.index.iter().enumerate().filter(|(_, &i)| i != NO_PAGE) {
+            bytes.clear();
+            bytes.extend((upper as u32).to_le_bytes());
+            bytes.resize(4 + BITMAP_BYTES, 0);
+            for (lower, value) in self.page_table[index as usize].0.iter().enumerate() {
+                if let Some(value) = value {
+                    bytes[4 + lower / 8] |= 1 << (lower % 8);
+                    value.push_words(&mut bytes);
+                }
+            }
+            seq.serialize_element(&RawPage(&bytes))?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, V: RawValue> Deserialize<'de> for PagedMemory<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(PagedMemoryVisitor(PhantomData))
+    }
+}
+
+struct PagedMemoryVisitor<V>(PhantomData<V>);
+
+impl<'de, V: RawValue> Visitor<'de> for PagedMemoryVisitor<V> {
+    type Value = PagedMemory<V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of raw memory pages")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut memory = PagedMemory::default();
+        while let Some(DecodedPage(upper, page)) = seq.next_element()? {
+            let index = memory
+                .index
+                .get_mut(upper)
+                .filter(|index| **index == NO_PAGE)
+                .ok_or_else(|| A::Error::custom("out of range or duplicate memory page"))?;
+            *index = memory.page_table.len() as u16;
+            memory.page_table.push(page);
+        }
+        Ok(memory)
+    }
+}
+
+/// A page decoded from the raw format, along with the upper bits of its addresses.
+struct DecodedPage<V>(usize, NewPage<V>);
+
+impl<'de, V: RawValue> Deserialize<'de> for DecodedPage<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(DecodedPageVisitor(PhantomData))
+    }
+}
+
+struct DecodedPageVisitor<V>(PhantomData<V>);
+
+impl<'de, V: RawValue> Visitor<'de> for DecodedPageVisitor<V> {
+    type Value = DecodedPage<V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a raw memory page")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        let malformed = || E::custom("malformed memory page");
+        let (upper, rest) = bytes.split_at_checked(4).ok_or_else(malformed)?;
+        let (bitmap, mut rest) = rest.split_at_checked(BITMAP_BYTES).ok_or_else(malformed)?;
+        let mut page = NewPage::new();
+        for (i, &bits) in bitmap.iter().enumerate() {
+            let mut bits = bits;
+            while bits != 0 {
+                let lower = i * 8 + bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                let (value, tail) = rest.split_at_checked(4 * V::WORDS).ok_or_else(malformed)?;
+                page.0[lower] = Some(V::from_words(value));
+                rest = tail;
+            }
+        }
+        if !rest.is_empty() {
+            return Err(malformed());
+        }
+        Ok(DecodedPage(u32::from_le_bytes(upper.try_into().unwrap()) as usize, page))
+    }
+
+    // Self-describing formats such as JSON write byte strings as sequences of bytes.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+/// A value of a [`PagedMemory`] stored as a fixed number of words in the raw format.
+pub(crate) trait RawValue: Copy {
+    /// The number of words of a value.
+    const WORDS: usize;
+
+    /// Append the words of the value to `bytes`, as little-endian words.
+    fn push_words(&self, bytes: &mut Vec<u8>);
+
+    /// Read a value from the bytes of its [`Self::WORDS`] little-endian words.
+    fn from_words(bytes: &[u8]) -> Self;
+}
+
+/// Read the `i`-th little-endian word of `bytes`.
+fn word(bytes: &[u8], i: usize) -> u32 {
+    u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap())
+}
+
+impl RawValue for u32 {
+    const WORDS: usize = 1;
+
+    fn push_words(&self, bytes: &mut Vec<u8>) {
+        bytes.extend(self.to_le_bytes());
+    }
+
+    fn from_words(bytes: &[u8]) -> Self {
+        word(bytes, 0)
+    }
+}
+
+impl RawValue for MemoryRecord {
+    const WORDS: usize = 3;
+
+    fn push_words(&self, bytes: &mut Vec<u8>) {
+        for word in [self.shard, self.timestamp, self.value] {
+            bytes.extend(word.to_le_bytes());
+        }
+    }
+
+    fn from_words(bytes: &[u8]) -> Self {
+        Self { shard: word(bytes, 0), timestamp: word(bytes, 1), value: word(bytes, 2) }
+    }
+}
+
 impl<V: Copy> Default for PagedMemory<V> {
     fn default() -> Self {
         Self { page_table: Vec::new(), index: vec![NO_PAGE; MAX_PAGE_COUNT] }
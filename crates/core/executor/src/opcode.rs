@@ -20,6 +20,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Refer to the "RV32I Reference Card" [here](https://github.com/johnwinans/rvalp/releases) for
 /// more details.
+///
+/// The opcodes marked as RV64 only are those of RV64IM programs, which can be executed but not
+/// proven.
 #[allow(non_camel_case_types)]
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord, Enum,
@@ -101,6 +104,32 @@ pub enum Opcode {
     REMU = 37,
     /// Unimplemented instruction.
     UNIMP = 38,
+    /// rd ← zx(m32(rs1 + imm)), pc ← pc + 4 (RV64 only)
+    LWU = 39,
+    /// rd ← m64(rs1 + imm), pc ← pc + 4 (RV64 only)
+    LD = 40,
+    /// m64(rs1 + imm) ← rs2[63:0], pc ← pc + 4 (RV64 only)
+    SD = 41,
+    /// rd ← sx((rs1 + rs2)[31:0]), pc ← pc + 4 (RV64 only)
+    ADDW = 42,
+    /// rd ← sx((rs1 - rs2)[31:0]), pc ← pc + 4 (RV64 only)
+    SUBW = 43,
+    /// rd ← sx(rs1[31:0] << rs2), pc ← pc + 4 (RV64 only)
+    SLLW = 44,
+    /// rd ← sx(rs1[31:0] >> rs2) (logical), pc ← pc + 4 (RV64 only)
+    SRLW = 45,
+    /// rd ← sx(rs1[31:0] >> rs2) (arithmetic), pc ← pc + 4 (RV64 only)
+    SRAW = 46,
+    /// rd ← sx((rs1 * rs2)[31:0]), pc ← pc + 4 (RV64 only)
+    MULW = 47,
+    /// rd ← sx(rs1[31:0] / rs2[31:0]) (signed), pc ← pc + 4 (RV64 only)
+    DIVW = 48,
+    /// rd ← sx(rs1[31:0] / rs2[31:0]) (unsigned), pc ← pc + 4 (RV64 only)
+    DIVUW = 49,
+    /// rd ← sx(rs1[31:0] % rs2[31:0]) (signed), pc ← pc + 4 (RV64 only)
+    REMW = 50,
+    /// rd ← sx(rs1[31:0] % rs2[31:0]) (unsigned), pc ← pc + 4 (RV64 only)
+    REMUW = 51,
 }
 
 /// Byte Opcode.
@@ -108,7 +137,9 @@ pub enum Opcode {
 /// This represents a basic operation that can be performed on a byte. Usually, these operations
 /// are performed via lookup tables on that iterate over the domain of two 8-bit values. The
 /// operations include both bitwise operations (AND, OR, XOR) as well as basic arithmetic.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Enum,
+)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum ByteOpcode {
     /// Bitwise AND.
@@ -129,6 +160,12 @@ pub enum ByteOpcode {
     MSB = 7,
     /// Unsigned 16-bit Range Check.
     U16Range = 8,
+    /// AES S-box substitution.
+    AesSbox = 9,
+    /// Hexadecimal digit decoding, with a flag for whether the digit is valid.
+    HexDecode = 10,
+    /// Base64 character decoding, with a flag for whether the character is valid.
+    Base64Decode = 11,
 }
 
 impl Opcode {
@@ -174,6 +211,19 @@ impl Opcode {
             Opcode::REM => "rem",
             Opcode::REMU => "remu",
             Opcode::UNIMP => "unimp",
+            Opcode::LWU => "lwu",
+            Opcode::LD => "ld",
+            Opcode::SD => "sd",
+            Opcode::ADDW => "addw",
+            Opcode::SUBW => "subw",
+            Opcode::SLLW => "sllw",
+            Opcode::SRLW => "srlw",
+            Opcode::SRAW => "sraw",
+            Opcode::MULW => "mulw",
+            Opcode::DIVW => "divw",
+            Opcode::DIVUW => "divuw",
+            Opcode::REMW => "remw",
+            Opcode::REMUW => "remuw",
         }
     }
 
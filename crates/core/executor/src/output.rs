@@ -0,0 +1,121 @@
+use core::fmt::Debug;
+use std::sync::{Arc, RwLock};
+
+use hashbrown::HashMap;
+
+use crate::{Executor, HookEnv};
+
+/// An output callback, wrapped in a smart pointer.
+pub type BoxedOutput<'a> = Arc<RwLock<dyn FnMut(HookEnv, &[u8]) + Send + Sync + 'a>>;
+
+/// A cycle tracker callback, wrapped in a smart pointer.
+pub type BoxedCycleTracker<'a> =
+    Arc<RwLock<dyn FnMut(HookEnv, CycleTrackerEvent) + Send + Sync + 'a>>;
+
+/// An event of the cycle tracker of the program, written to stdout as
+/// `cycle-tracker-[report-]{start,end}: <name>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CycleTrackerEvent {
+    /// The start of a span.
+    Start {
+        /// The name of the span.
+        name: String,
+        /// The number of spans open when the span started.
+        depth: u32,
+        /// The global clock at the start of the span.
+        global_clk: u64,
+    },
+    /// The end of a span.
+    End {
+        /// The name of the span.
+        name: String,
+        /// The number of spans open when the span started.
+        depth: u32,
+        /// The number of cycles spent in the span.
+        cycles: u64,
+        /// Whether the cycles of the span are accumulated in the execution report.
+        report: bool,
+    },
+}
+
+/// A registry of the callbacks intercepting the outputs of the program, through which a host
+/// embedding the executor can route them to its own logging stack.
+///
+/// The lines written to stdout (fd 1) and stderr (fd 2) are passed to their callback without their
+/// trailing newline, instead of being printed. They are still recorded in the guest log of the
+/// report. The writes to any other file descriptor are passed as is, and take precedence over the
+/// hooks of the descriptor. The cycle tracker commands written to stdout are parsed, and passed to
+/// the cycle tracker callbacks as events.
+#[derive(Clone, Default)]
+pub struct OutputRegistry<'a> {
+    pub(crate) outputs: HashMap<u32, BoxedOutput<'a>>,
+    pub(crate) cycle_trackers: Vec<BoxedCycleTracker<'a>>,
+}
+
+impl<'a> OutputRegistry<'a> {
+    /// Create an empty [`OutputRegistry`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intercept the writes of the program to the file descriptor `fd`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fd` is that of the public values (3) or the input stream (4).
+    pub fn output(&mut self, fd: u32, f: impl FnMut(HookEnv, &[u8]) + Send + Sync + 'a) {
+        assert!(fd != 3 && fd != 4, "the writes to fd {fd} can't be intercepted");
+        self.outputs.insert(fd, Arc::new(RwLock::new(f)));
+    }
+
+    /// Add a callback for the events of the cycle tracker.
+    pub fn cycle_tracker(&mut self, f: impl FnMut(HookEnv, CycleTrackerEvent) + Send + Sync + 'a) {
+        self.cycle_trackers.push(Arc::new(RwLock::new(f)));
+    }
+
+    /// Whether the registry has no callbacks.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.outputs.is_empty() && self.cycle_trackers.is_empty()
+    }
+}
+
+impl<'a> Debug for OutputRegistry<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut fds = self.outputs.keys().collect::<Vec<_>>();
+        fds.sort_unstable();
+        f.debug_struct("OutputRegistry")
+            .field("outputs", &fds)
+            .field("cycle_trackers", &self.cycle_trackers.len())
+            .finish()
+    }
+}
+
+impl Executor<'_> {
+    /// Pass a write to `fd` to its callback, returning whether it has one.
+    pub(crate) fn intercept_output(&self, fd: u32, bytes: &[u8]) -> bool {
+        let Some(output) = self.output_registry.outputs.get(&fd) else { return false };
+        // Calling `.unwrap()` panics on a poisoned lock. Should never happen normally.
+        (output.write().unwrap())(self.hook_env(), bytes);
+        true
+    }
+
+    /// Pass a line written to stdout or stderr to the callback of `fd`, or print it if `fd` has
+    /// none, and record it in the guest log.
+    pub(crate) fn output_line(&mut self, fd: u32, line: &str) {
+        let intercepted = self.intercept_output(fd, line.as_bytes());
+        let line = format!("{}: {line}", if fd == 1 { "stdout" } else { "stderr" });
+        if !intercepted {
+            println!("{line}");
+        }
+        self.report.guest_log.push(line);
+    }
+
+    /// Pass an event of the cycle tracker to the cycle tracker callbacks.
+    pub(crate) fn track_cycles(&self, event: &CycleTrackerEvent) {
+        for cycle_tracker in &self.output_registry.cycle_trackers {
+            (cycle_tracker.write().unwrap())(self.hook_env(), event.clone());
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use core::fmt::Debug;
+use std::{
+    collections::VecDeque,
+    sync::{mpsc::Receiver, Arc},
+};
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// A call of the program to a hook, identified by its file descriptor and its request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HintKey {
+    /// The file descriptor of the hook.
+    pub fd: u32,
+    /// The data written to the hook.
+    pub request: Vec<u8>,
+}
+
+/// A function fetching the response of a hook to a request, on a background thread.
+pub type BoxedFetch = Arc<dyn Fn(u32, &[u8]) -> Vec<Vec<u8>> + Send + Sync>;
+
+/// The hook calls a program is known to make, as recorded in the replay file of a prior run, along
+/// with the function fetching their responses.
+///
+/// The responses are fetched ahead of the calls on a background thread, so that the latency of
+/// the fetches (e.g. a database or an RPC) is hidden behind the execution of the program. A call
+/// whose response was not prefetched is served by its hook as usual.
+#[derive(Clone)]
+pub struct HintPrefetch {
+    /// The hook calls to prefetch the responses of, in the order they are expected.
+    pub keys: Vec<HintKey>,
+    /// The maximum number of responses fetched ahead of the program.
+    pub depth: usize,
+    /// The function fetching the response to a hook call.
+    pub fetch: BoxedFetch,
+}
+
+impl HintPrefetch {
+    /// The default maximum number of responses fetched ahead of the program.
+    pub const DEFAULT_DEPTH: usize = 16;
+
+    /// Prefetch the responses to the given hook calls with `fetch`.
+    #[must_use]
+    pub fn new(
+        keys: Vec<HintKey>,
+        fetch: impl Fn(u32, &[u8]) -> Vec<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        Self { keys, depth: Self::DEFAULT_DEPTH, fetch: Arc::new(fetch) }
+    }
+
+    /// Set the maximum number of responses fetched ahead of the program.
+    #[must_use]
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+}
+
+impl Debug for HintPrefetch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HintPrefetch")
+            .field("keys", &self.keys.len())
+            .field("depth", &self.depth)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The responses of a [`HintPrefetch`], staged by a background thread.
+#[derive(Debug)]
+pub struct HintPrefetcher {
+    receiver: Receiver<(HintKey, Vec<Vec<u8>>)>,
+    /// The responses received ahead of their calls.
+    staged: HashMap<HintKey, VecDeque<Vec<Vec<u8>>>>,
+    /// The number of responses of every call still to be received.
+    pending: HashMap<HintKey, usize>,
+}
+
+impl HintPrefetcher {
+    /// Start fetching the responses of the prefetch on a background thread.
+    ///
+    /// The thread stops once every response is fetched, or as soon as the prefetcher is dropped.
+    #[cfg(feature = "threads")]
+    #[must_use]
+    pub fn spawn(prefetch: HintPrefetch) -> Self {
+        let mut pending = HashMap::<HintKey, usize>::new();
+        for key in &prefetch.keys {
+            *pending.entry(key.clone()).or_default() += 1;
+        }
+        let (sender, receiver) = std::sync::mpsc::sync_channel(prefetch.depth);
+        let HintPrefetch { keys, fetch, .. } = prefetch;
+        std::thread::spawn(move || {
+            for key in keys {
+                let response = fetch(key.fd, &key.request);
+                if sender.send((key, response)).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { receiver, staged: HashMap::new(), pending }
+    }
+
+    /// Take the prefetched response to a hook call, waiting for it if it is still being fetched.
+    ///
+    /// Returns `None` if the call was not expected, or if the fetch failed.
+    pub(crate) fn take(&mut self, fd: u32, request: &[u8]) -> Option<Vec<Vec<u8>>> {
+        let key = HintKey { fd, request: request.to_vec() };
+        if let Some(response) = self.staged.get_mut(&key).and_then(VecDeque::pop_front) {
+            return Some(response);
+        }
+        if self.pending.get(&key).copied().unwrap_or(0) == 0 {
+            return None;
+        }
+        // The calls may come in another order than expected, so the responses received meanwhile
+        // are staged for later.
+        while let Ok((received, response)) = self.receiver.recv() {
+            *self.pending.get_mut(&received).unwrap() -= 1;
+            if received == key {
+                return Some(response);
+            }
+            self.staged.entry(received).or_default().push_back(response);
+        }
+        // The thread stopped early, so the remaining responses will never come.
+        self.pending.clear();
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::{HintKey, HintPrefetch, HintPrefetcher};
+
+    fn key(fd: u32, request: u8) -> HintKey {
+        HintKey { fd, request: vec![request] }
+    }
+
+    #[test]
+    #[cfg(feature = "threads")]
+    fn test_hint_prefetcher() {
+        let fetched = Arc::new(Mutex::new(Vec::new()));
+        let log = fetched.clone();
+        let prefetch = HintPrefetch::new(vec![key(5, 1), key(5, 2), key(6, 1)], move |fd, req| {
+            log.lock().unwrap().push(HintKey { fd, request: req.to_vec() });
+            vec![vec![fd as u8, req[0]]]
+        })
+        .depth(1);
+        let mut prefetcher = HintPrefetcher::spawn(prefetch);
+
+        // Calls out of order are served from the staged responses.
+        assert_eq!(prefetcher.take(6, &[1]), Some(vec![vec![6, 1]]));
+        assert_eq!(prefetcher.take(5, &[1]), Some(vec![vec![5, 1]]));
+        // Unexpected calls, and calls whose responses were all served, fall back to the hooks.
+        assert_eq!(prefetcher.take(7, &[1]), None);
+        assert_eq!(prefetcher.take(6, &[1]), None);
+        assert_eq!(prefetcher.take(5, &[2]), Some(vec![vec![5, 2]]));
+        assert_eq!(*fetched.lock().unwrap(), vec![key(5, 1), key(5, 2), key(6, 1)]);
+    }
+}
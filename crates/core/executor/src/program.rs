@@ -1,6 +1,10 @@
 //! Programs that can be executed by the SP1 zkVM.
 
-use std::{fs::File, io::Read};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Bound, RangeBounds},
+    sync::Arc,
+};
 
 use hashbrown::HashMap;
 use p3_field::Field;
@@ -8,9 +12,10 @@ use serde::{Deserialize, Serialize};
 use sp1_stark::air::{MachineAir, MachineProgram};
 
 use crate::{
-    disassembler::{transpile, Elf},
+    abi::check_abi_version,
+    disassembler::{transpile, transpile_compressed, transpile_rv64, Elf},
     instruction::Instruction,
-    CoreShape,
+    CoreShape, DebugInfo, OpcodeDenylist, OPCODE_DENYLIST_ADDR, SYSCALL_ABI_VERSION,
 };
 
 /// A program that can be executed by the SP1 zkVM.
@@ -29,6 +34,63 @@ pub struct Program {
     pub memory_image: HashMap<u32, u32>,
     /// The shape for the preprocessed tables.
     pub preprocessed_shape: Option<CoreShape>,
+    /// The opcodes that the program is not allowed to execute.
+    #[serde(default)]
+    pub opcode_denylist: OpcodeDenylist,
+    /// Whether the program is an RV64IM program, which can be executed but not proven.
+    #[serde(default)]
+    pub rv64: bool,
+    /// For programs that use the compressed (C) extension, whether each instruction is
+    /// compressed. The instructions of such programs are laid out every 2 bytes instead of every
+    /// 4 bytes. Empty for the other programs.
+    #[serde(default)]
+    pub compressed: Vec<bool>,
+    /// The functions of the symbol table of the ELF, sorted by start address. Empty for programs
+    /// not built from an ELF, or built from a stripped one.
+    #[serde(default)]
+    pub symbols: Vec<ProgramSymbol>,
+    /// The version of the syscall ABI the program is built against, recorded in its ELF by the
+    /// entrypoint crate. `None` for programs not built from an ELF, or built before the ABI was
+    /// versioned.
+    #[serde(default)]
+    pub abi_version: Option<u32>,
+    /// The DWARF debug info of the ELF, used to symbolicate the backtraces of traps. It is not
+    /// serialized, so that it doesn't weigh on the keys of the program.
+    #[serde(skip)]
+    pub debug_info: Option<Arc<DebugInfo>>,
+}
+
+/// A function of the symbol table of a program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramSymbol {
+    /// The demangled name of the function.
+    pub name: String,
+    /// The start address of the function.
+    pub start: u32,
+    /// The end address (exclusive) of the function.
+    pub end: u32,
+}
+
+/// An instruction of a program, with its address and the function containing it.
+#[derive(Debug, Clone)]
+pub struct DisassembledInstruction {
+    /// The program counter of the instruction.
+    pub pc: u32,
+    /// The instruction.
+    pub instruction: Instruction,
+    /// The name of the function containing the instruction, with the offset of the instruction
+    /// in the function, if known.
+    pub symbol: Option<(String, u32)>,
+}
+
+impl Display for DisassembledInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "0x{:08x}", self.pc)?;
+        if let Some((name, offset)) = &self.symbol {
+            write!(f, " <{name}+{offset}>")?;
+        }
+        write!(f, ": {:?}", self.instruction)
+    }
 }
 
 impl Program {
@@ -41,20 +103,35 @@ impl Program {
             pc_base,
             memory_image: HashMap::new(),
             preprocessed_shape: None,
+            opcode_denylist: OpcodeDenylist::new(),
+            rv64: false,
+            compressed: Vec::new(),
+            symbols: Vec::new(),
+            abi_version: None,
+            debug_info: None,
         }
     }
 
-    /// Disassemble a RV32IM ELF to a program that be executed by the VM.
+    /// Disassemble a RV32IM or RV64IM ELF, possibly with the compressed (C) extension, to a program
+    /// that be executed by the VM.
     ///
     /// # Errors
     ///
-    /// This function may return an error if the ELF is not valid.
+    /// This function may return an error if the ELF is not valid, or if it is built against a
+    /// newer version of the syscall ABI than [`SYSCALL_ABI_VERSION`].
     pub fn from(input: &[u8]) -> eyre::Result<Self> {
-        // Decode the bytes as an ELF.
+        // Decode the bytes as an ELF, and make sure the executor implements its syscalls.
         let elf = Elf::decode(input)?;
+        check_abi_version(elf.abi_version)?;
 
-        // Transpile the RV32IM instructions.
-        let instructions = transpile(&elf.instructions);
+        // Transpile the RV32IM or RV64IM instructions.
+        let (instructions, compressed) = if elf.compressed {
+            transpile_compressed(&elf.instructions, elf.rv64)
+        } else if elf.rv64 {
+            (transpile_rv64(&elf.instructions), Vec::new())
+        } else {
+            (transpile(&elf.instructions), Vec::new())
+        };
 
         // Return the program.
         Ok(Program {
@@ -63,6 +140,12 @@ impl Program {
             pc_base: elf.pc_base,
             memory_image: elf.memory_image,
             preprocessed_shape: None,
+            opcode_denylist: OpcodeDenylist::new(),
+            rv64: elf.rv64,
+            compressed,
+            symbols: elf.symbols,
+            abi_version: elf.abi_version,
+            debug_info: DebugInfo::from_elf(input)?.map(Arc::new),
         })
     }
 
@@ -71,10 +154,48 @@ impl Program {
     /// # Errors
     ///
     /// This function will return an error if the file cannot be opened or read.
+    #[cfg(feature = "fs")]
     pub fn from_elf(path: &str) -> eyre::Result<Self> {
-        let mut elf_code = Vec::new();
-        File::open(path)?.read_to_end(&mut elf_code)?;
-        Program::from(&elf_code)
+        Program::from(&std::fs::read(path)?)
+    }
+
+    /// Restrict the program to a machine profile that forbids the given opcodes.
+    ///
+    /// The denylist is written into the memory image at [`OPCODE_DENYLIST_ADDR`], so that it is
+    /// committed into the verifying key, and is enforced by the executor on every instruction.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the program contains a denied opcode, if the
+    /// reserved denylist words are already part of the memory image, or if a denylist has
+    /// already been applied.
+    pub fn with_opcode_denylist(mut self, denylist: OpcodeDenylist) -> eyre::Result<Self> {
+        if !self.opcode_denylist.is_empty() {
+            eyre::bail!("an opcode denylist has already been applied to the program");
+        }
+        if denylist.is_empty() {
+            return Ok(self);
+        }
+
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            if denylist.is_denied(instruction.opcode) {
+                eyre::bail!(
+                    "program contains denied opcode {} at pc {:#x}",
+                    instruction.opcode,
+                    self.pc_base + self.instruction_stride() * i as u32
+                );
+            }
+        }
+
+        for (i, word) in denylist.to_words().into_iter().enumerate() {
+            let addr = OPCODE_DENYLIST_ADDR + 4 * i as u32;
+            if self.memory_image.insert(addr, word).is_some() {
+                eyre::bail!("memory image already contains the reserved address {:#x}", addr);
+            }
+        }
+        self.opcode_denylist = denylist;
+
+        Ok(self)
     }
 
     /// Custom logic for padding the trace to a power of two according to the proof shape.
@@ -90,12 +211,70 @@ impl Program {
             .copied()
     }
 
+    /// Whether the program uses the compressed (C) extension.
+    #[must_use]
+    pub fn is_compressed(&self) -> bool {
+        !self.compressed.is_empty()
+    }
+
+    /// The distance in bytes between the addresses of two consecutive instructions.
+    pub(crate) fn instruction_stride(&self) -> u32 {
+        if self.is_compressed() {
+            2
+        } else {
+            4
+        }
+    }
+
     #[must_use]
     /// Fetch the instruction at the given program counter.
     pub fn fetch(&self, pc: u32) -> &Instruction {
-        let idx = ((pc - self.pc_base) / 4) as usize;
+        let idx = ((pc - self.pc_base) / self.instruction_stride()) as usize;
         &self.instructions[idx]
     }
+
+    /// The size in bytes of the instruction at the given program counter.
+    #[must_use]
+    pub fn instruction_size(&self, pc: u32) -> u32 {
+        if self.is_compressed() && self.compressed[((pc - self.pc_base) / 2) as usize] {
+            2
+        } else {
+            4
+        }
+    }
+
+    /// The function containing the given program counter, if the program has its symbols.
+    #[must_use]
+    pub fn symbol_at(&self, pc: u32) -> Option<&ProgramSymbol> {
+        let index = self.symbols.partition_point(|symbol| symbol.start <= pc).checked_sub(1)?;
+        let symbol = &self.symbols[index];
+        (pc < symbol.end).then_some(symbol)
+    }
+
+    /// Disassemble the instructions whose program counters are in the given range, e.g. the
+    /// instructions around a faulting program counter.
+    ///
+    /// The range is clamped to the instructions of the program.
+    #[must_use]
+    pub fn disassemble(&self, range: impl RangeBounds<u32>) -> Vec<DisassembledInstruction> {
+        let stride = self.instruction_stride();
+        let pc_end = self.pc_base + stride * self.instructions.len() as u32;
+        let mut pc = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => self.pc_base,
+        };
+        // Align the start to an instruction of the program.
+        pc = pc.max(self.pc_base).saturating_add(stride - 1) / stride * stride;
+
+        let mut instructions = Vec::new();
+        while pc < pc_end && range.contains(&pc) {
+            let symbol = self.symbol_at(pc).map(|symbol| (symbol.name.clone(), pc - symbol.start));
+            instructions.push(DisassembledInstruction { pc, instruction: *self.fetch(pc), symbol });
+            pc += self.instruction_size(pc);
+        }
+        instructions
+    }
 }
 
 impl<F: Field> MachineProgram<F> for Program {
@@ -6,8 +6,8 @@ pub mod tests {
     use crate::{Instruction, Opcode, Program};
 
     use test_artifacts::{
-        FIBONACCI_ELF, KECCAK_PERMUTE_ELF, PANIC_ELF, SECP256R1_ADD_ELF, SECP256R1_DOUBLE_ELF,
-        U256XU2048_MUL_ELF,
+        COMMITTED_VALUES_ELF, ENV_VALUES_ELF, FIBONACCI_ELF, HINT_BOUNDED_ELF, HINT_VERIFIED_ELF,
+        KECCAK_PERMUTE_ELF, PANIC_ELF, SECP256R1_ADD_ELF, SECP256R1_DOUBLE_ELF, U256XU2048_MUL_ELF,
     };
 
     #[must_use]
@@ -80,6 +80,46 @@ pub mod tests {
         Program::from(PANIC_ELF).unwrap()
     }
 
+    /// Get the program reading hints with a maximum size.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the program fails to load.
+    #[must_use]
+    pub fn hint_bounded_program() -> Program {
+        Program::from(HINT_BOUNDED_ELF).unwrap()
+    }
+
+    /// Get the program reading hints checked against their digests.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the program fails to load.
+    #[must_use]
+    pub fn hint_verified_program() -> Program {
+        Program::from(HINT_VERIFIED_ELF).unwrap()
+    }
+
+    /// Get the program checking the public values it committed before halting.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the program fails to load.
+    #[must_use]
+    pub fn committed_values_program() -> Program {
+        Program::from(COMMITTED_VALUES_ELF).unwrap()
+    }
+
+    /// Get the program reading the timestamp and the seed of its environment.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the program fails to load.
+    #[must_use]
+    pub fn env_values_program() -> Program {
+        Program::from(ENV_VALUES_ELF).unwrap()
+    }
+
     #[must_use]
     #[allow(clippy::unreadable_literal)]
     pub fn simple_memory_program() -> Program {
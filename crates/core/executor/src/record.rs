@@ -3,7 +3,12 @@ use itertools::{EitherOrBoth, Itertools};
 use p3_field::{AbstractField, PrimeField};
 use sp1_stark::{
     air::{MachineAir, PublicValues},
-    MachineRecord, SP1CoreOpts, SplitOpts,
+    DeferralPolicy, MachineRecord, SP1CoreOpts, SplitOpts,
+};
+#[cfg(feature = "fs")]
+use std::{
+    fs::File,
+    io::{Error as IoError, ErrorKind, Seek, Write},
 };
 use std::{mem::take, sync::Arc};
 
@@ -11,6 +16,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{program::Program, Opcode};
 use crate::{
+    archive::Bincode,
     events::{
         add_sharded_byte_lookup_events, AluEvent, ByteLookupEvent, ByteRecord, CpuEvent, LookupId,
         MemoryInitializeFinalizeEvent, MemoryLocalEvent, MemoryRecordEnum, PrecompileEvent,
@@ -23,9 +29,15 @@ use crate::{
 /// A record of the execution of a program.
 ///
 /// The trace of the execution is represented as a list of "events" that occur every cycle.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+///
+/// Records are archived with [`rkyv`] without their program, which is shared by all the records
+/// of an execution and attached again when they are loaded, see [`ExecutionRecord::load_all`].
+#[derive(
+    Clone, Debug, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 pub struct ExecutionRecord {
     /// The program.
+    #[rkyv(with = rkyv::with::Skip)]
     pub program: Arc<Program>,
     /// A trace of the CPU events which get emitted during execution.
     pub cpu_events: Vec<CpuEvent>,
@@ -47,6 +59,8 @@ pub struct ExecutionRecord {
     pub lt_events: Vec<AluEvent>,
     /// A trace of the byte lookups that are needed.
     pub byte_lookups: HashMap<u32, HashMap<ByteLookupEvent, usize>>,
+    /// A trace of the byte lookups that are carried on the secondary byte bus.
+    pub secondary_byte_lookups: HashMap<u32, HashMap<ByteLookupEvent, usize>>,
     /// A trace of the precompile events.
     pub precompile_events: PrecompileEvents,
     /// A trace of the global memory initialize events.
@@ -58,13 +72,17 @@ pub struct ExecutionRecord {
     /// A trace of all the syscall events.
     pub syscall_events: Vec<SyscallEvent>,
     /// The public values.
+    #[rkyv(with = Bincode)]
     pub public_values: PublicValues<u32, u32>,
     /// The nonce lookup.
     pub nonce_lookup: Vec<u32>,
     /// The next nonce to use for a new lookup.
     pub next_nonce: u64,
     /// The shape of the proof.
+    #[rkyv(with = Bincode)]
     pub shape: Option<CoreShape>,
+    /// Whether the bn254 mul-add events with identical operands share a product row.
+    pub bn254_mul_memo: bool,
 }
 
 impl Default for ExecutionRecord {
@@ -81,6 +99,7 @@ impl Default for ExecutionRecord {
             divrem_events: Vec::default(),
             lt_events: Vec::default(),
             byte_lookups: HashMap::default(),
+            secondary_byte_lookups: HashMap::default(),
             precompile_events: PrecompileEvents::default(),
             global_memory_initialize_events: Vec::default(),
             global_memory_finalize_events: Vec::default(),
@@ -90,6 +109,7 @@ impl Default for ExecutionRecord {
             nonce_lookup: Vec::default(),
             next_nonce: 0,
             shape: None,
+            bn254_mul_memo: false,
         };
         res.nonce_lookup.insert(0, 0);
         res
@@ -105,6 +125,37 @@ impl ExecutionRecord {
         res
     }
 
+    /// Save records to a file, as an [`rkyv`] archive.
+    #[cfg(feature = "fs")]
+    #[allow(clippy::ptr_arg)]
+    pub fn save_all(records: &Vec<Self>, file: &mut File) -> std::io::Result<()> {
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(records)
+            .map_err(|e| IoError::new(ErrorKind::InvalidData, e))?;
+        file.write_all(&bytes)?;
+        file.flush()?;
+        file.seek(std::io::SeekFrom::Start(0))?;
+        Ok(())
+    }
+
+    /// Load records saved with [`ExecutionRecord::save_all`] from a file, attaching `program` to
+    /// them.
+    ///
+    /// The file is mapped rather than read, and the archive is validated in place, so the events
+    /// are copied out of the mapping instead of being decoded one by one.
+    #[cfg(feature = "fs")]
+    pub fn load_all(file: &File, program: &Arc<Program>) -> std::io::Result<Vec<Self>> {
+        // SAFETY: checkpoint files are private to the prover, and not modified while mapped.
+        let map = unsafe { memmap2::Mmap::map(file)? };
+        let archived = rkyv::access::<rkyv::Archived<Vec<Self>>, rkyv::rancor::Error>(&map)
+            .map_err(|e| IoError::new(ErrorKind::InvalidData, e))?;
+        let mut records = rkyv::deserialize::<Vec<Self>, rkyv::rancor::Error>(archived)
+            .map_err(|e| IoError::new(ErrorKind::InvalidData, e))?;
+        for record in &mut records {
+            record.program = Arc::clone(program);
+        }
+        Ok(records)
+    }
+
     /// Create a lookup id for an event.
     pub fn create_lookup_id(&mut self) -> LookupId {
         // let id = self.nonce_lookup.len() as u64;
@@ -164,11 +215,21 @@ impl ExecutionRecord {
     /// Take out events from the [`ExecutionRecord`] that should be deferred to a separate shard.
     ///
     /// Note: we usually defer events that would increase the recursion cost significantly if
-    /// included in every shard.
+    /// included in every shard. The precompile events kept by the deferral policy of `opts` stay
+    /// in this record.
     #[must_use]
-    pub fn defer(&mut self) -> ExecutionRecord {
+    pub fn defer(&mut self, opts: SplitOpts) -> ExecutionRecord {
         let mut execution_record = ExecutionRecord::new(self.program.clone());
-        execution_record.precompile_events = std::mem::take(&mut self.precompile_events);
+        let mut precompile_events = take(&mut self.precompile_events);
+        for (syscall_code, events) in precompile_events.take_events() {
+            if is_deferred(syscall_code, opts.deferral) {
+                execution_record.precompile_events.insert(syscall_code, events, &precompile_events);
+            } else {
+                self.precompile_events.insert(syscall_code, events, &precompile_events);
+            }
+        }
+        // The bn254 mul-add events kept in this record share their products as in a deferred shard.
+        self.bn254_mul_memo = opts.bn254_mul_memo;
         execution_record.global_memory_initialize_events =
             std::mem::take(&mut self.global_memory_initialize_events);
         execution_record.global_memory_finalize_events =
@@ -178,34 +239,51 @@ impl ExecutionRecord {
 
     /// Splits the deferred [`ExecutionRecord`] into multiple [`ExecutionRecord`]s, each which
     /// contain a "reasonable" number of deferred events.
-    pub fn split(&mut self, last: bool, opts: SplitOpts) -> Vec<ExecutionRecord> {
+    ///
+    /// `caps` optionally lowers the number of events of a syscall put in a single shard. The events
+    /// past a cap spill over to the next shard of the same syscall.
+    pub fn split(
+        &mut self,
+        last: bool,
+        opts: SplitOpts,
+        caps: &HashMap<SyscallCode, usize>,
+    ) -> Vec<ExecutionRecord> {
         let mut shards = Vec::new();
 
-        let precompile_events = take(&mut self.precompile_events);
+        let mut precompile_events = take(&mut self.precompile_events);
 
-        for (syscall_code, events) in precompile_events.into_iter() {
-            let threshold = match syscall_code {
-                SyscallCode::KECCAK_PERMUTE => opts.keccak,
-                SyscallCode::SHA_EXTEND => opts.sha_extend,
-                SyscallCode::SHA_COMPRESS => opts.sha_compress,
-                _ => opts.deferred,
-            };
+        for (syscall_code, events) in precompile_events.take_events() {
+            let threshold = split_threshold(syscall_code, opts, caps);
 
             let chunks = events.chunks_exact(threshold);
             if last {
                 let remainder = chunks.remainder().to_vec();
                 if !remainder.is_empty() {
                     let mut execution_record = ExecutionRecord::new(self.program.clone());
-                    execution_record.precompile_events.insert(syscall_code, remainder);
+                    execution_record.precompile_events.insert(
+                        syscall_code,
+                        remainder,
+                        &precompile_events,
+                    );
+                    execution_record.bn254_mul_memo = opts.bn254_mul_memo;
                     shards.push(execution_record);
                 }
             } else {
-                self.precompile_events.insert(syscall_code, chunks.remainder().to_vec());
+                self.precompile_events.insert(
+                    syscall_code,
+                    chunks.remainder().to_vec(),
+                    &precompile_events,
+                );
             }
             let mut event_shards = chunks
                 .map(|chunk| {
                     let mut execution_record = ExecutionRecord::new(self.program.clone());
-                    execution_record.precompile_events.insert(syscall_code, chunk.to_vec());
+                    execution_record.precompile_events.insert(
+                        syscall_code,
+                        chunk.to_vec(),
+                        &precompile_events,
+                    );
+                    execution_record.bn254_mul_memo = opts.bn254_mul_memo;
                     execution_record
                 })
                 .collect::<Vec<_>>();
@@ -277,6 +355,9 @@ impl ExecutionRecord {
 
     #[inline]
     /// Add a precompile event to the execution record.
+    ///
+    /// Syscalls go through [`crate::syscalls::SyscallContext::add_precompile_event`] instead, which
+    /// drops the events of precompiles run in unconstrained mode.
     pub fn add_precompile_event(
         &mut self,
         syscall_code: SyscallCode,
@@ -304,6 +385,46 @@ impl ExecutionRecord {
     }
 }
 
+/// The number of events of a syscall put in a single shard when splitting the deferred events.
+pub(crate) fn split_threshold(
+    syscall_code: SyscallCode,
+    opts: SplitOpts,
+    caps: &HashMap<SyscallCode, usize>,
+) -> usize {
+    let threshold = match syscall_code {
+        SyscallCode::KECCAK_PERMUTE | SyscallCode::KECCAK_ACCUMULATE => opts.keccak,
+        SyscallCode::SHA_EXTEND => opts.sha_extend,
+        SyscallCode::SHA_COMPRESS => opts.sha_compress,
+        SyscallCode::CHACHA20_BLOCK => opts.chacha20,
+        SyscallCode::BN254_INNER_PRODUCT => opts.bn254_inner_product,
+        SyscallCode::FR_NTT => opts.fr_ntt,
+        SyscallCode::RLP_SCAN => opts.rlp_scan,
+        SyscallCode::POSEIDON_MERKLE_ROOT => opts.poseidon_merkle,
+        SyscallCode::ZKTRIE_HASH_NODE => opts.zktrie_node,
+        SyscallCode::KECCAK_MERKLE_ROOT => opts.keccak_merkle,
+        SyscallCode::WITHDRAW_ROOT => opts.withdraw_root,
+        SyscallCode::RSA2048_MODEXP | SyscallCode::RSA4096_MODEXP => opts.rsa_modexp,
+        SyscallCode::SECP256K1_ECRECOVER => opts.ecrecover,
+        SyscallCode::SHA512_COMPRESS => opts.sha512_compress,
+        SyscallCode::ED25519_VERIFY => opts.ed25519_verify,
+        _ => opts.deferred,
+    };
+    match caps.get(&syscall_code) {
+        Some(&cap) => threshold.min(cap).max(1),
+        None => threshold,
+    }
+}
+
+/// Whether the events of a syscall are deferred into dedicated shards under `policy`.
+fn is_deferred(syscall_code: SyscallCode, policy: DeferralPolicy) -> bool {
+    match syscall_code {
+        SyscallCode::KECCAK_PERMUTE | SyscallCode::KECCAK_ACCUMULATE => policy.keccak,
+        SyscallCode::BN254_MULADD | SyscallCode::BN254_MULADD_BE => policy.bn254_muladd,
+        SyscallCode::MEMCPY_32 | SyscallCode::MEMCPY_64 => policy.memcpy,
+        _ => true,
+    }
+}
+
 /// A memory access record.
 #[derive(Debug, Copy, Clone, Default)]
 pub struct MemoryAccessRecord {
@@ -351,6 +472,10 @@ impl MachineRecord for ExecutionRecord {
                 "byte_lookups".to_string(),
                 self.byte_lookups.get(&shard).map_or(0, hashbrown::HashMap::len),
             );
+            stats.insert(
+                "secondary_byte_lookups".to_string(),
+                self.secondary_byte_lookups.get(&shard).map_or(0, hashbrown::HashMap::len),
+            );
         }
         // Filter out the empty events.
         stats.retain(|_, v| *v != 0);
@@ -376,12 +501,29 @@ impl MachineRecord for ExecutionRecord {
         } else {
             self.add_sharded_byte_lookup_events(vec![&other.byte_lookups]);
         }
+        if self.secondary_byte_lookups.is_empty() {
+            self.secondary_byte_lookups = std::mem::take(&mut other.secondary_byte_lookups);
+        } else {
+            add_sharded_byte_lookup_events(
+                &mut self.secondary_byte_lookups,
+                vec![&other.secondary_byte_lookups],
+            );
+        }
 
         self.global_memory_initialize_events.append(&mut other.global_memory_initialize_events);
         self.global_memory_finalize_events.append(&mut other.global_memory_finalize_events);
         self.cpu_local_memory_access.append(&mut other.cpu_local_memory_access);
     }
 
+    fn move_byte_lookups_to_secondary_bus(&mut self) {
+        if self.secondary_byte_lookups.is_empty() {
+            self.secondary_byte_lookups = std::mem::take(&mut self.byte_lookups);
+        } else {
+            let byte_lookups = std::mem::take(&mut self.byte_lookups);
+            add_sharded_byte_lookup_events(&mut self.secondary_byte_lookups, vec![&byte_lookups]);
+        }
+    }
+
     fn register_nonces(&mut self, _opts: &Self::Config) {
         self.add_events.iter().enumerate().for_each(|(i, event)| {
             self.nonce_lookup[event.lookup_id.0 as usize] = i as u32;
@@ -435,3 +577,60 @@ impl ByteRecord for ExecutionRecord {
         add_sharded_byte_lookup_events(&mut self.byte_lookups, new_events);
     }
 }
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use std::{fs::File, sync::Arc};
+
+    use sp1_stark::SP1CoreOpts;
+
+    use super::ExecutionRecord;
+    use crate::{
+        events::PrecompileEvent, programs::tests::secp256r1_add_program, syscalls::SyscallCode,
+        Executor,
+    };
+
+    #[test]
+    fn test_records_round_trip() {
+        let program = Arc::new(secp256r1_add_program());
+        let mut runtime = Executor::new(program.as_ref().clone(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let records = std::mem::take(&mut runtime.records);
+
+        let path = std::env::temp_dir().join(format!("records-{}.bin", std::process::id()));
+        let mut file =
+            File::options().read(true).write(true).create(true).truncate(true).open(&path).unwrap();
+        ExecutionRecord::save_all(&records, &mut file).unwrap();
+        let loaded = ExecutionRecord::load_all(&file, &program).unwrap();
+        assert_eq!(loaded.len(), records.len());
+        for (loaded, record) in loaded.iter().zip(&records) {
+            assert!(Arc::ptr_eq(&loaded.program, &program));
+            assert_eq!(loaded.cpu_events.len(), record.cpu_events.len());
+            assert_eq!(loaded.add_events.len(), record.add_events.len());
+            assert_eq!(loaded.byte_lookups, record.byte_lookups);
+            assert_eq!(loaded.public_values.shard, record.public_values.shard);
+            assert_eq!(loaded.next_nonce, record.next_nonce);
+
+            let events = loaded.precompile_events.get_events(SyscallCode::SECP256R1_ADD);
+            let expected = record.precompile_events.get_events(SyscallCode::SECP256R1_ADD);
+            assert_eq!(events.map(Vec::len), expected.map(Vec::len));
+            for ((_, event), (_, expected)) in
+                events.into_iter().flatten().zip(expected.into_iter().flatten())
+            {
+                let PrecompileEvent::Secp256r1Add(event) = event else { unreachable!() };
+                let PrecompileEvent::Secp256r1Add(expected) = expected else { unreachable!() };
+                assert_eq!((&event.p, &event.q), (&expected.p, &expected.q));
+                assert_eq!(event.p_memory_records.len(), expected.p_memory_records.len());
+            }
+        }
+        assert!(records.iter().any(|record| record
+            .precompile_events
+            .get_events(SyscallCode::SECP256R1_ADD)
+            .is_some()));
+
+        // A truncated archive is rejected.
+        file.set_len(file.metadata().unwrap().len() - 4).unwrap();
+        assert!(ExecutionRecord::load_all(&file, &program).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
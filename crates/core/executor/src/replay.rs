@@ -0,0 +1,203 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::File,
+    io::{BufWriter, Write},
+};
+#[cfg(feature = "fs")]
+use std::{
+    io::{BufReader, ErrorKind},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::HintKey;
+
+/// An access of a program to the input stream, as recorded in a replay file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HintAccess {
+    /// The length of the next element was read with `HINT_LEN`.
+    Len {
+        /// The offset of the element in the input stream.
+        offset: usize,
+        /// The global clock of the syscall.
+        global_clk: u64,
+        /// The length of the element.
+        len: u32,
+    },
+    /// The next element was consumed with `HINT_READ` or `HINT_MAP`.
+    Read {
+        /// The offset of the element in the input stream.
+        offset: usize,
+        /// The global clock of the syscall.
+        global_clk: u64,
+        /// The element.
+        data: Vec<u8>,
+    },
+    /// A hook was called by writing to its file descriptor.
+    Hook {
+        /// The offset in the input stream at which the outputs of the hook were inserted.
+        offset: usize,
+        /// The global clock of the syscall.
+        global_clk: u64,
+        /// The file descriptor of the hook.
+        fd: u32,
+        /// The data written to the hook.
+        request: Vec<u8>,
+    },
+}
+
+impl HintAccess {
+    /// The offset of the accessed element in the input stream.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::Len { offset, .. } | Self::Read { offset, .. } | Self::Hook { offset, .. } => {
+                *offset
+            }
+        }
+    }
+
+    /// The global clock of the access.
+    #[must_use]
+    pub fn global_clk(&self) -> u64 {
+        match self {
+            Self::Len { global_clk, .. }
+            | Self::Read { global_clk, .. }
+            | Self::Hook { global_clk, .. } => *global_clk,
+        }
+    }
+
+    /// The data the access served to the program. The content of an element whose length only was
+    /// read is never observed, so it is replayed as zeroes. A hook call serves no element itself,
+    /// its outputs being served by the accesses that follow.
+    fn served(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Len { len, .. } => Some(vec![0; *len as usize]),
+            Self::Read { data, .. } => Some(data.clone()),
+            Self::Hook { .. } => None,
+        }
+    }
+
+    /// Whether both accesses are of the same kind, to the same element, at the same clock.
+    fn matches(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+            && self.offset() == other.offset()
+            && self.global_clk() == other.global_clk()
+    }
+}
+
+impl Display for HintAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (offset, global_clk) = (self.offset(), self.global_clk());
+        match self {
+            Self::Len { .. } => write!(f, "length read of input {offset} at clk {global_clk}"),
+            Self::Read { .. } => write!(f, "read of input {offset} at clk {global_clk}"),
+            Self::Hook { fd, .. } => write!(f, "call of hook {fd} at clk {global_clk}"),
+        }
+    }
+}
+
+/// Records the accesses to the input stream of an execution to a replay file.
+///
+/// Every access is flushed to the file as soon as it is made, so that the file holds all the
+/// accesses up to a failure, even if the execution never returns.
+#[derive(Debug)]
+pub struct HintRecorder {
+    writer: BufWriter<File>,
+}
+
+impl HintRecorder {
+    /// Create a replay file at the given path.
+    #[cfg(feature = "fs")]
+    pub fn create(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self { writer: BufWriter::new(File::create(path)?) })
+    }
+
+    /// Append an access to the replay file.
+    pub fn record(&mut self, access: &HintAccess) -> bincode::Result<()> {
+        bincode::serialize_into(&mut self.writer, access)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// The accesses to the input stream of a recorded execution, from which the execution can be
+/// reproduced exactly, without its stdin nor its hooks.
+///
+/// When replaying, every access of the program is served the data it was served in the recorded
+/// execution, in order, and the execution panics as soon as an access differs from the recorded
+/// one in its kind, its offset or its clock.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HintReplay {
+    /// The recorded accesses, in order.
+    pub accesses: Vec<HintAccess>,
+    /// The index of the next access to check.
+    next: usize,
+}
+
+impl HintReplay {
+    /// Create a replay of the given accesses.
+    #[must_use]
+    pub fn new(accesses: Vec<HintAccess>) -> Self {
+        Self { accesses, next: 0 }
+    }
+
+    /// Load the accesses recorded with a [`HintRecorder`].
+    ///
+    /// An access truncated by a crash of the recorded execution is ignored.
+    #[cfg(feature = "fs")]
+    pub fn load(path: impl AsRef<Path>) -> bincode::Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut accesses = Vec::new();
+        loop {
+            match bincode::deserialize_from(&mut reader) {
+                Ok(access) => accesses.push(access),
+                Err(err) => match *err {
+                    bincode::ErrorKind::Io(ref io) if io.kind() == ErrorKind::UnexpectedEof => {
+                        break
+                    }
+                    _ => return Err(err),
+                },
+            }
+        }
+        Ok(Self::new(accesses))
+    }
+
+    /// The input stream of a replayed execution, with the data served to every access in order.
+    #[must_use]
+    pub fn input_stream(&self) -> Vec<Vec<u8>> {
+        self.accesses.iter().filter_map(HintAccess::served).collect()
+    }
+
+    /// The hook calls of the recorded execution, in order, to prefetch the responses of in a later
+    /// execution of the program with a [`crate::HintPrefetch`].
+    #[must_use]
+    pub fn hook_keys(&self) -> Vec<HintKey> {
+        self.accesses
+            .iter()
+            .filter_map(|access| match access {
+                HintAccess::Hook { fd, request, .. } => {
+                    Some(HintKey { fd: *fd, request: request.clone() })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Check that an access of the replayed execution matches the next recorded one.
+    ///
+    /// The hooks are not called when replaying, so the recorded hook calls are skipped.
+    pub(crate) fn check(&mut self, access: &HintAccess) {
+        while matches!(self.accesses.get(self.next), Some(HintAccess::Hook { .. })) {
+            self.next += 1;
+        }
+        match self.accesses.get(self.next) {
+            Some(expected) if expected.matches(access) => self.next += 1,
+            Some(expected) => {
+                panic!("execution diverged from the replay: expected a {expected}, got a {access}")
+            }
+            None => panic!("execution diverged from the replay: unexpected {access}"),
+        }
+    }
+}
@@ -1,15 +1,22 @@
 use std::{
+    collections::VecDeque,
     fmt::{Display, Formatter, Result as FmtResult},
     ops::{Add, AddAssign},
 };
 
 use enum_map::{EnumArray, EnumMap};
-use hashbrown::HashMap;
+use hashbrown::{hash_map::Entry, HashMap};
+use serde::{Deserialize, Serialize};
+use tiny_keccak::{Hasher, Keccak};
 
-use crate::{events::generate_execution_report, syscalls::SyscallCode, Opcode};
+use crate::{
+    events::generate_execution_report, hotspots::hot_loops, syscalls::SyscallCode, BasicBlockStats,
+    HotLoop, LoopStats, MemcpyAdvice, MemcpyCallSite, MemcpyKind, Opcode, StateDiff,
+    DEFAULT_HOT_LOOPS, DEFAULT_MEMCPY_ADVICE_THRESHOLD,
+};
 
 /// An execution report.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExecutionReport {
     /// The event counts.
     pub event_counts: Box<EnumMap<Opcode, u64>>,
@@ -17,10 +24,39 @@ pub struct ExecutionReport {
     pub opcode_counts: Box<EnumMap<Opcode, u64>>,
     /// The syscall counts.
     pub syscall_counts: Box<EnumMap<SyscallCode, u64>>,
+    /// The clock cycles taken by each syscall, counting the `ECALL` instruction along with the
+    /// extra cycles of the syscall.
+    #[serde(default)]
+    pub syscall_cycles: Box<EnumMap<SyscallCode, u64>>,
     /// The cycle tracker counts.
     pub cycle_tracker: HashMap<String, u64>,
     /// The unique memory address counts.
     pub touched_memory_addresses: u64,
+    /// The number of memory pages allocated by the executor by the end of the execution, each
+    /// covering [`crate::MEMORY_PAGE_BYTES`] bytes of the address space. Merged reports keep the
+    /// largest count.
+    #[serde(default)]
+    pub touched_memory_pages: u64,
+    /// The exit code the program halted with.
+    pub exit_code: u32,
+    /// The software memory routine calls by call site, if they were tracked.
+    pub memcpy_call_sites: HashMap<u32, MemcpyCallSite>,
+    /// The lines the program wrote to stdout and stderr, prefixed with their stream.
+    #[serde(default)]
+    pub guest_log: GuestLog,
+    /// The gas used by the execution, as metered by the [`crate::GasCostTable`] of the executor.
+    pub gas_used: u64,
+    /// The changes made by the execution to the memory of the program, if they were reported.
+    #[serde(default)]
+    pub state_diff: Option<StateDiff>,
+    /// The basic blocks executed by the program by leading program counter, if they were
+    /// profiled.
+    #[serde(default)]
+    pub basic_blocks: HashMap<u32, BasicBlockStats>,
+    /// The loops iterated by the program by the program counter of their backward jump, if the
+    /// basic blocks were profiled.
+    #[serde(default)]
+    pub loops: HashMap<u32, LoopStats>,
 }
 
 impl ExecutionReport {
@@ -35,6 +71,111 @@ impl ExecutionReport {
     pub fn total_syscall_count(&self) -> u64 {
         self.syscall_counts.values().sum()
     }
+
+    /// Compute the total number of clock cycles taken by syscalls during the execution.
+    #[must_use]
+    pub fn total_syscall_cycles(&self) -> u64 {
+        self.syscall_cycles.values().sum()
+    }
+
+    /// The invocation count and clock cycles of every syscall made during the execution, sorted by
+    /// the cycles taken.
+    #[must_use]
+    pub fn syscall_breakdown(&self) -> Vec<SyscallReport> {
+        let mut breakdown: Vec<_> = self
+            .syscall_counts
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .map(|(syscall, &count)| SyscallReport {
+                syscall,
+                count,
+                cycles: self.syscall_cycles[syscall],
+            })
+            .collect();
+        breakdown.sort_by_key(|report| (std::cmp::Reverse(report.cycles), report.syscall));
+        breakdown
+    }
+
+    /// The `n` basic blocks which took the most clock cycles with their leading program counter,
+    /// sorted by cycles.
+    #[must_use]
+    pub fn hot_blocks(&self, n: usize) -> Vec<(u32, BasicBlockStats)> {
+        let mut blocks: Vec<_> =
+            self.basic_blocks.iter().map(|(&pc, &block)| (pc, block)).collect();
+        blocks.sort_by_key(|&(pc, block)| (std::cmp::Reverse(block.cycles), pc));
+        blocks.truncate(n);
+        blocks
+    }
+
+    /// The `n` loops which took the most clock cycles, sorted by cycles. See [`HotLoop::cycles`]
+    /// for what counts as the cycles of a loop.
+    #[must_use]
+    pub fn hot_loops(&self, n: usize) -> Vec<HotLoop> {
+        hot_loops(&self.basic_blocks, &self.loops, n)
+    }
+
+    /// The call sites that would save at least `threshold` cycles by using a memcopy precompile
+    /// instead of a software memory routine, sorted by the cycles saved.
+    #[must_use]
+    pub fn memcpy_advice(&self, threshold: u64) -> Vec<MemcpyAdvice> {
+        let mut advice: Vec<_> = self
+            .memcpy_call_sites
+            .iter()
+            .filter_map(|(&pc, site)| {
+                let (recommendation, saved_cycles) = site.best_recommendation();
+                (saved_cycles >= threshold).then(|| MemcpyAdvice {
+                    pc,
+                    site: site.clone(),
+                    recommendation,
+                    saved_cycles,
+                })
+            })
+            .collect();
+        advice.sort_by_key(|advice| (std::cmp::Reverse(advice.saved_cycles), advice.pc));
+        advice
+    }
+
+    /// The estimated number of cycles saved by patching the symbol of a software memory routine to
+    /// use a memcopy precompile, summed over all of its call sites.
+    #[must_use]
+    pub fn memcpy_patch_savings(&self, kind: MemcpyKind) -> u64 {
+        self.memcpy_call_sites
+            .values()
+            .filter(|site| site.kind == kind)
+            .map(|site| site.best_recommendation().1)
+            .sum()
+    }
+}
+
+/// The invocation count and clock cycles of a syscall over an execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyscallReport {
+    /// The syscall.
+    pub syscall: SyscallCode,
+    /// The number of times the syscall was made.
+    pub count: u64,
+    /// The clock cycles taken by the syscall, over all of its invocations.
+    pub cycles: u64,
+}
+
+impl SyscallReport {
+    /// The average number of clock cycles taken by an invocation of the syscall.
+    #[must_use]
+    pub fn cycles_per_call(&self) -> u64 {
+        self.cycles / self.count.max(1)
+    }
+}
+
+impl Display for SyscallReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} ({} calls, {} cycles each)",
+            self.syscall.to_string().to_lowercase(),
+            self.count,
+            self.cycles_per_call()
+        )
+    }
 }
 
 /// Combines two `HashMap`s together. If a key is in both maps, the values are added together.
@@ -48,11 +189,124 @@ where
     }
 }
 
+/// The lines a program wrote to stdout and stderr, kept as a running digest of all of them and
+/// the last [`GuestLog::TAIL_LINES`] of them, so that a chatty program does not grow the report
+/// without bound.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuestLog {
+    /// The number of lines written.
+    pub lines: u64,
+    /// The Keccak-256 digest of the lines, chained: every line is hashed after the digest of the
+    /// lines before it, starting from zeroes.
+    pub digest: [u8; 32],
+    /// The last lines written.
+    pub tail: VecDeque<String>,
+}
+
+impl GuestLog {
+    /// The maximum number of lines kept in the tail.
+    pub const TAIL_LINES: usize = 1 << 10;
+
+    /// Append a line to the log.
+    pub fn push(&mut self, line: String) {
+        self.digest = Self::chain(&self.digest, line.as_bytes());
+        self.lines += 1;
+        if self.tail.len() == Self::TAIL_LINES {
+            self.tail.pop_front();
+        }
+        self.tail.push_back(line);
+    }
+
+    /// Whether the tail holds all the lines written.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.lines == self.tail.len() as u64
+    }
+
+    /// Whether the digest matches the tail, if the tail holds all the lines written. A truncated
+    /// log cannot be checked.
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        !self.is_complete()
+            || self.tail.iter().fold([0; 32], |digest, line| Self::chain(&digest, line.as_bytes()))
+                == self.digest
+    }
+
+    fn chain(digest: &[u8; 32], bytes: &[u8]) -> [u8; 32] {
+        let mut hasher = Keccak::v256();
+        hasher.update(digest);
+        hasher.update(bytes);
+        let mut output = [0; 32];
+        hasher.finalize(&mut output);
+        output
+    }
+}
+
+/// Appends the lines of a log written after `self`.
+///
+/// If `rhs` is truncated, its lines cannot be chained one by one, so its digest is chained as a
+/// whole instead, and the digest then depends on how the execution was split.
+impl AddAssign for GuestLog {
+    fn add_assign(&mut self, rhs: Self) {
+        if rhs.is_complete() {
+            for line in rhs.tail {
+                self.push(line);
+            }
+            return;
+        }
+        self.digest = Self::chain(&self.digest, &rhs.digest);
+        self.lines += rhs.lines;
+        self.tail.extend(rhs.tail);
+        let excess = self.tail.len().saturating_sub(Self::TAIL_LINES);
+        self.tail.drain(..excess);
+    }
+}
+
 impl AddAssign for ExecutionReport {
     fn add_assign(&mut self, rhs: Self) {
         counts_add_assign(&mut self.opcode_counts, *rhs.opcode_counts);
         counts_add_assign(&mut self.syscall_counts, *rhs.syscall_counts);
+        counts_add_assign(&mut self.syscall_cycles, *rhs.syscall_cycles);
         self.touched_memory_addresses += rhs.touched_memory_addresses;
+        // Every report counts the pages of the whole memory at its end, which only grows.
+        self.touched_memory_pages = self.touched_memory_pages.max(rhs.touched_memory_pages);
+        // Only the report covering the halt carries a non-zero exit code.
+        self.exit_code |= rhs.exit_code;
+        for (pc, site) in rhs.memcpy_call_sites {
+            match self.memcpy_call_sites.entry(pc) {
+                Entry::Occupied(mut entry) => {
+                    let entry = entry.get_mut();
+                    entry.calls += site.calls;
+                    entry.bytes += site.bytes;
+                    entry.cycles += site.cycles;
+                    entry.chunks_32 += site.chunks_32;
+                    entry.chunks_64 += site.chunks_64;
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(site);
+                }
+            }
+        }
+        self.guest_log += rhs.guest_log;
+        self.gas_used = self.gas_used.saturating_add(rhs.gas_used);
+        for (pc, block) in rhs.basic_blocks {
+            let entry = self.basic_blocks.entry(pc).or_default();
+            entry.executions += block.executions;
+            entry.instructions += block.instructions;
+            entry.cycles += block.cycles;
+        }
+        for (pc, stats) in rhs.loops {
+            match self.loops.entry(pc) {
+                Entry::Occupied(mut entry) => entry.get_mut().iterations += stats.iterations,
+                Entry::Vacant(entry) => {
+                    entry.insert(stats);
+                }
+            }
+        }
+        // The changes reported last cover all the execution before them.
+        if rhs.state_diff.is_some() {
+            self.state_diff = rhs.state_diff;
+        }
     }
 }
 
@@ -77,6 +331,91 @@ impl Display for ExecutionReport {
             writeln!(f, "  {line}")?;
         }
 
+        let breakdown = self.syscall_breakdown();
+        if !breakdown.is_empty() {
+            writeln!(f, "syscall cycles ({} total clock cycles):", self.total_syscall_cycles())?;
+            let width = breakdown[0].cycles.to_string().len();
+            for report in breakdown {
+                writeln!(f, "    {:>width$} {report}", report.cycles)?;
+            }
+        }
+
+        writeln!(f, "gas used: {}", self.gas_used)?;
+
+        if self.touched_memory_pages > 0 {
+            writeln!(
+                f,
+                "touched memory pages: {} ({} KiB of address space)",
+                self.touched_memory_pages,
+                self.touched_memory_pages * crate::MEMORY_PAGE_BYTES as u64 / 1024
+            )?;
+        }
+
+        if !self.memcpy_call_sites.is_empty() {
+            let advice = self.memcpy_advice(DEFAULT_MEMCPY_ADVICE_THRESHOLD);
+            writeln!(f, "memcpy advice ({} call sites over the threshold):", advice.len())?;
+            for advice in advice {
+                writeln!(f, "  {advice}")?;
+            }
+            for kind in MemcpyKind::ALL {
+                let saved_cycles = self.memcpy_patch_savings(kind);
+                if saved_cycles > 0 {
+                    writeln!(f, "  patching {kind} would save ~{saved_cycles} cycles")?;
+                }
+            }
+        }
+
+        if !self.loops.is_empty() {
+            writeln!(f, "hot loops ({} loops):", self.loops.len())?;
+            for hot_loop in self.hot_loops(DEFAULT_HOT_LOOPS) {
+                writeln!(f, "  {:>12} cycles {hot_loop}", hot_loop.cycles)?;
+            }
+        }
+        if !self.basic_blocks.is_empty() {
+            writeln!(f, "hot basic blocks ({} blocks):", self.basic_blocks.len())?;
+            for (pc, block) in self.hot_blocks(DEFAULT_HOT_LOOPS) {
+                writeln!(
+                    f,
+                    "  {:>12} cycles 0x{pc:08x}: {} executions, {} instructions",
+                    block.cycles, block.executions, block.instructions
+                )?;
+            }
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::GuestLog;
+
+    #[test]
+    fn test_guest_log() {
+        let mut log = GuestLog::default();
+        (0..GuestLog::TAIL_LINES + 5).for_each(|i| log.push(format!("stdout: {i}")));
+        assert_eq!(log.lines, GuestLog::TAIL_LINES as u64 + 5);
+        assert_eq!(log.tail.len(), GuestLog::TAIL_LINES);
+        assert_eq!(log.tail[0], "stdout: 5");
+        assert!(!log.is_complete() && log.is_consistent());
+
+        // Merging complete logs gives the log of the whole execution.
+        let [mut head, mut rest, mut whole]: [GuestLog; 3] = Default::default();
+        for i in 0..8 {
+            let part = if i < 3 { &mut head } else { &mut rest };
+            part.push(format!("stderr: {i}"));
+            whole.push(format!("stderr: {i}"));
+        }
+        head += rest;
+        assert_eq!(head, whole);
+        assert!(head.is_consistent());
+
+        // Merging a truncated log keeps the tail bounded.
+        head += log;
+        assert_eq!(head.lines, GuestLog::TAIL_LINES as u64 + 13);
+        assert_eq!(head.tail.len(), GuestLog::TAIL_LINES);
+
+        whole.tail[0] = "stderr: tampered".to_string();
+        assert!(!whole.is_consistent());
+    }
+}
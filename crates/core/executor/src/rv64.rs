@@ -0,0 +1,395 @@
+//! Execution of RV64IM programs.
+//!
+//! The registers of an RV64IM program hold 64-bit values, whose lower halves are stored in memory
+//! like the registers of an RV32IM program, and whose upper halves are stored in
+//! [`crate::ExecutionState::registers_hi`]. Addresses must still fit in 32 bits, so that syscalls
+//! can read their arguments from the lower halves of the registers.
+//!
+//! RV64IM programs can only be executed in [`ExecutorMode::Simple`], since the machine does not
+//! have the chips to prove them.
+
+use crate::{
+    align, events::MemoryAccessPosition, ExecutionError, Executor, ExecutorMode, Instruction,
+    Opcode, Register,
+};
+
+/// Sign-extend a 32-bit value, such as an immediate or the result of a 32-bit operation.
+#[inline]
+const fn sext(value: u32) -> u64 {
+    value as i32 as i64 as u64
+}
+
+impl Executor<'_> {
+    /// Read a register of an RV64IM program.
+    pub fn rr64(&mut self, register: Register, position: MemoryAccessPosition) -> u64 {
+        let lo = self.rr(register, position);
+        (u64::from(self.state.registers_hi[register as usize]) << 32) | u64::from(lo)
+    }
+
+    /// Write to a register of an RV64IM program.
+    pub fn rw64(&mut self, register: Register, value: u64) {
+        self.rw(register, value as u32);
+        if register != Register::X0 {
+            self.state.registers_hi[register as usize] = (value >> 32) as u32;
+        }
+    }
+
+    /// Get the current value of a register of an RV64IM program.
+    #[must_use]
+    pub fn register64(&mut self, register: Register) -> u64 {
+        let lo = self.register(register);
+        (u64::from(self.state.registers_hi[register as usize]) << 32) | u64::from(lo)
+    }
+
+    /// Execute the given instruction of an RV64IM program over the current state of the runtime.
+    pub(crate) fn execute_instruction_rv64(
+        &mut self,
+        instruction: &Instruction,
+    ) -> Result<(), ExecutionError> {
+        if self.executor_mode != ExecutorMode::Simple {
+            return Err(ExecutionError::UnprovableRv64Program());
+        }
+
+        // System instructions are executed as in RV32IM, since syscalls take 32-bit arguments.
+        // The value they return in t0 is sign-extended like any 32-bit value.
+        if matches!(instruction.opcode, Opcode::ECALL | Opcode::EBREAK | Opcode::UNIMP) {
+            self.execute_instruction(instruction)?;
+            let t0 = self.register(Register::X5);
+            self.state.registers_hi[Register::X5 as usize] = (sext(t0) >> 32) as u32;
+            return Ok(());
+        }
+
+        if !self.unconstrained {
+            self.report.opcode_counts[instruction.opcode] += 1;
+            self.report.gas_used =
+                self.report.gas_used.saturating_add(self.gas_costs.opcodes[instruction.opcode]);
+        }
+
+        let pc = self.state.pc;
+        let instruction_size = self.program.instruction_size(pc);
+        let mut next_pc = pc.wrapping_add(instruction_size);
+        match instruction.opcode {
+            // Load instructions.
+            Opcode::LB
+            | Opcode::LH
+            | Opcode::LW
+            | Opcode::LBU
+            | Opcode::LHU
+            | Opcode::LWU
+            | Opcode::LD => {
+                let (rd, rs1, imm) = instruction.i_type();
+                let addr = self.address64(instruction.opcode, rs1, imm)?;
+                let a = self.execute_load64(instruction.opcode, addr)?;
+                self.rw64(rd, a);
+            }
+
+            // Store instructions.
+            Opcode::SB | Opcode::SH | Opcode::SW | Opcode::SD => {
+                let (rs2, rs1, imm) = instruction.s_type();
+                let value = self.rr64(rs2, MemoryAccessPosition::A);
+                let addr = self.address64(instruction.opcode, rs1, imm)?;
+                self.execute_store64(instruction.opcode, addr, value)?;
+            }
+
+            // Branch instructions.
+            Opcode::BEQ | Opcode::BNE | Opcode::BLT | Opcode::BGE | Opcode::BLTU | Opcode::BGEU => {
+                let (rs1, rs2, imm) = instruction.b_type();
+                let b = self.rr64(rs2, MemoryAccessPosition::B);
+                let a = self.rr64(rs1, MemoryAccessPosition::A);
+                let branch = match instruction.opcode {
+                    Opcode::BEQ => a == b,
+                    Opcode::BNE => a != b,
+                    Opcode::BLT => (a as i64) < (b as i64),
+                    Opcode::BGE => (a as i64) >= (b as i64),
+                    Opcode::BLTU => a < b,
+                    Opcode::BGEU => a >= b,
+                    _ => unreachable!(),
+                };
+                if branch {
+                    next_pc = pc.wrapping_add(imm);
+                }
+            }
+
+            // Jump instructions.
+            Opcode::JAL => {
+                let (rd, imm) = instruction.j_type();
+                self.rw64(rd, u64::from(pc + instruction_size));
+                next_pc = pc.wrapping_add(imm);
+            }
+            Opcode::JALR => {
+                let (rd, rs1, imm) = instruction.i_type();
+                let b = self.rr64(rs1, MemoryAccessPosition::B);
+                self.rw64(rd, u64::from(pc + instruction_size));
+                next_pc = b.wrapping_add(sext(imm)) as u32;
+            }
+
+            // Upper immediate instructions.
+            Opcode::AUIPC => {
+                let (rd, imm) = instruction.u_type();
+                self.rw64(rd, u64::from(pc).wrapping_add(sext(imm)));
+            }
+
+            // Arithmetic instructions.
+            _ => {
+                let (rd, b, c) = self.alu_rr64(instruction);
+                self.rw64(rd, alu64(instruction.opcode, b, c));
+            }
+        }
+
+        self.state.pc = next_pc;
+        self.state.clk += 4;
+        Ok(())
+    }
+
+    /// Fetch the destination register and 64-bit input operand values for an ALU instruction.
+    fn alu_rr64(&mut self, instruction: &Instruction) -> (Register, u64, u64) {
+        if !instruction.imm_c {
+            let (rd, rs1, rs2) = instruction.r_type();
+            let c = self.rr64(rs2, MemoryAccessPosition::C);
+            let b = self.rr64(rs1, MemoryAccessPosition::B);
+            (rd, b, c)
+        } else if !instruction.imm_b {
+            let (rd, rs1, imm) = instruction.i_type();
+            (rd, self.rr64(rs1, MemoryAccessPosition::B), sext(imm))
+        } else {
+            (Register::from_u8(instruction.op_a), sext(instruction.op_b), sext(instruction.op_c))
+        }
+    }
+
+    /// Compute the address of a load or store, which must fit in 32 bits.
+    fn address64(
+        &mut self,
+        opcode: Opcode,
+        rs1: Register,
+        imm: u32,
+    ) -> Result<u32, ExecutionError> {
+        let addr = self.rr64(rs1, MemoryAccessPosition::B).wrapping_add(sext(imm));
+        u32::try_from(addr).map_err(|_| ExecutionError::InvalidMemoryAccess(opcode, addr as u32))
+    }
+
+    /// Load the value of a load instruction, extended to 64 bits.
+    fn execute_load64(&mut self, opcode: Opcode, addr: u32) -> Result<u64, ExecutionError> {
+        let alignment = match opcode {
+            Opcode::LH | Opcode::LHU => 2,
+            Opcode::LW | Opcode::LWU => 4,
+            Opcode::LD => 8,
+            _ => 1,
+        };
+        if addr % alignment != 0 {
+            return Err(ExecutionError::InvalidMemoryAccess(opcode, addr));
+        }
+
+        if opcode == Opcode::LD {
+            let lo = self.mr_cpu(addr, MemoryAccessPosition::Memory);
+            let hi = self.mr_cpu(addr + 4, MemoryAccessPosition::Memory);
+            return Ok((u64::from(hi) << 32) | u64::from(lo));
+        }
+
+        let word = self.mr_cpu(align(addr), MemoryAccessPosition::Memory);
+        let value = match opcode {
+            Opcode::LB => sext(((word >> ((addr % 4) * 8)) & 0xFF) as i8 as i32 as u32),
+            Opcode::LH => sext(((word >> (((addr / 2) % 2) * 16)) & 0xFFFF) as i16 as i32 as u32),
+            Opcode::LW => sext(word),
+            Opcode::LBU => u64::from((word >> ((addr % 4) * 8)) & 0xFF),
+            Opcode::LHU => u64::from((word >> (((addr / 2) % 2) * 16)) & 0xFFFF),
+            Opcode::LWU => u64::from(word),
+            _ => unreachable!(),
+        };
+        Ok(value)
+    }
+
+    /// Store the value of a store instruction, truncated to its width.
+    fn execute_store64(
+        &mut self,
+        opcode: Opcode,
+        addr: u32,
+        value: u64,
+    ) -> Result<(), ExecutionError> {
+        let alignment = match opcode {
+            Opcode::SH => 2,
+            Opcode::SW => 4,
+            Opcode::SD => 8,
+            _ => 1,
+        };
+        if addr % alignment != 0 {
+            return Err(ExecutionError::InvalidMemoryAccess(opcode, addr));
+        }
+
+        let a = value as u32;
+        let word = match opcode {
+            Opcode::SB => {
+                let shift = (addr % 4) * 8;
+                ((a & 0xFF) << shift) | (self.word(align(addr)) & !(0xFF << shift))
+            }
+            Opcode::SH => {
+                let shift = ((addr / 2) % 2) * 16;
+                ((a & 0xFFFF) << shift) | (self.word(align(addr)) & !(0xFFFF << shift))
+            }
+            Opcode::SW => a,
+            Opcode::SD => {
+                self.mw_cpu(addr, a, MemoryAccessPosition::Memory);
+                self.mw_cpu(addr + 4, (value >> 32) as u32, MemoryAccessPosition::Memory);
+                return Ok(());
+            }
+            _ => unreachable!(),
+        };
+        self.mw_cpu(align(addr), word, MemoryAccessPosition::Memory);
+        Ok(())
+    }
+}
+
+/// Compute the result of an RV64IM ALU instruction.
+fn alu64(opcode: Opcode, b: u64, c: u64) -> u64 {
+    let (b32, c32) = (b as u32, c as u32);
+    match opcode {
+        Opcode::ADD => b.wrapping_add(c),
+        Opcode::SUB => b.wrapping_sub(c),
+        Opcode::XOR => b ^ c,
+        Opcode::OR => b | c,
+        Opcode::AND => b & c,
+        Opcode::SLL => b.wrapping_shl(c32),
+        Opcode::SRL => b.wrapping_shr(c32),
+        Opcode::SRA => (b as i64).wrapping_shr(c32) as u64,
+        Opcode::SLT => u64::from((b as i64) < (c as i64)),
+        Opcode::SLTU => u64::from(b < c),
+        Opcode::MUL => b.wrapping_mul(c),
+        Opcode::MULH => (i128::from(b as i64).wrapping_mul(i128::from(c as i64)) >> 64) as u64,
+        Opcode::MULHU => (u128::from(b).wrapping_mul(u128::from(c)) >> 64) as u64,
+        Opcode::MULHSU => (i128::from(b as i64).wrapping_mul(i128::from(c)) >> 64) as u64,
+        Opcode::DIV => {
+            if c == 0 {
+                u64::MAX
+            } else {
+                (b as i64).wrapping_div(c as i64) as u64
+            }
+        }
+        Opcode::DIVU => {
+            if c == 0 {
+                u64::MAX
+            } else {
+                b / c
+            }
+        }
+        Opcode::REM => {
+            if c == 0 {
+                b
+            } else {
+                (b as i64).wrapping_rem(c as i64) as u64
+            }
+        }
+        Opcode::REMU => {
+            if c == 0 {
+                b
+            } else {
+                b % c
+            }
+        }
+        Opcode::ADDW => sext(b32.wrapping_add(c32)),
+        Opcode::SUBW => sext(b32.wrapping_sub(c32)),
+        Opcode::SLLW => sext(b32.wrapping_shl(c32)),
+        Opcode::SRLW => sext(b32.wrapping_shr(c32)),
+        Opcode::SRAW => sext((b32 as i32).wrapping_shr(c32) as u32),
+        Opcode::MULW => sext(b32.wrapping_mul(c32)),
+        Opcode::DIVW => {
+            if c32 == 0 {
+                u64::MAX
+            } else {
+                sext((b32 as i32).wrapping_div(c32 as i32) as u32)
+            }
+        }
+        Opcode::DIVUW => {
+            if c32 == 0 {
+                u64::MAX
+            } else {
+                sext(b32 / c32)
+            }
+        }
+        Opcode::REMW => {
+            if c32 == 0 {
+                sext(b32)
+            } else {
+                sext((b32 as i32).wrapping_rem(c32 as i32) as u32)
+            }
+        }
+        Opcode::REMUW => {
+            if c32 == 0 {
+                sext(b32)
+            } else {
+                sext(b32 % c32)
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_stark::SP1CoreOpts;
+
+    use crate::{ExecutionError, Executor, Instruction, Opcode, Program, Register};
+
+    fn run_rv64(instructions: Vec<Instruction>) -> Executor<'static> {
+        let mut program = Program::new(instructions, 0, 0);
+        program.rv64 = true;
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run_fast().unwrap();
+        runtime
+    }
+
+    #[test]
+    fn test_rv64_alu() {
+        let mut runtime = run_rv64(vec![
+            // x29 = -1, x30 = 0x1_0000_0000.
+            Instruction::new(Opcode::ADD, 29, 0, u32::MAX, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 1, false, true),
+            Instruction::new(Opcode::SLL, 30, 30, 32, false, true),
+            // x31 = x30 - 1 = 0xffff_ffff, which ADDW sign-extends.
+            Instruction::new(Opcode::ADD, 31, 30, u32::MAX, false, true),
+            Instruction::new(Opcode::ADDW, 28, 31, 0, false, true),
+            // x27 = x30 * x30 >> 64 = 1.
+            Instruction::new(Opcode::MULHU, 27, 30, 30, false, false),
+            // x26 = -1 >> 60 (logical) = 0xf.
+            Instruction::new(Opcode::SRL, 26, 29, 60, false, true),
+            // x25 = DIVW by zero = -1, x24 = REMUW of 0xffff_ffff by 0 = -1.
+            Instruction::new(Opcode::DIVW, 25, 30, 0, false, false),
+            Instruction::new(Opcode::REMUW, 24, 31, 0, false, false),
+        ]);
+        assert_eq!(runtime.register64(Register::X29), u64::MAX);
+        assert_eq!(runtime.register64(Register::X30), 1 << 32);
+        assert_eq!(runtime.register64(Register::X31), 0xffff_ffff);
+        assert_eq!(runtime.register64(Register::X28), u64::MAX);
+        assert_eq!(runtime.register64(Register::X27), 1);
+        assert_eq!(runtime.register64(Register::X26), 0xf);
+        assert_eq!(runtime.register64(Register::X25), u64::MAX);
+        assert_eq!(runtime.register64(Register::X24), u64::MAX);
+    }
+
+    #[test]
+    fn test_rv64_load_store() {
+        let mut runtime = run_rv64(vec![
+            // x29 = 0x8000_0000_0000_0001, stored as a doubleword at 0x1000.
+            Instruction::new(Opcode::ADD, 29, 0, 1, false, true),
+            Instruction::new(Opcode::SLL, 29, 29, 63, false, true),
+            Instruction::new(Opcode::ADD, 29, 29, 1, false, true),
+            Instruction::new(Opcode::SD, 29, 0, 0x1000, false, true),
+            Instruction::new(Opcode::LD, 30, 0, 0x1000, false, true),
+            // The upper word is negative, so LW sign-extends it and LWU does not.
+            Instruction::new(Opcode::LW, 31, 0, 0x1004, false, true),
+            Instruction::new(Opcode::LWU, 28, 0, 0x1004, false, true),
+        ]);
+        assert_eq!(runtime.word(0x1000), 1);
+        assert_eq!(runtime.word(0x1004), 0x8000_0000);
+        assert_eq!(runtime.register64(Register::X30), 0x8000_0000_0000_0001);
+        assert_eq!(runtime.register64(Register::X31), 0xffff_ffff_8000_0000);
+        assert_eq!(runtime.register64(Register::X28), 0x8000_0000);
+    }
+
+    #[test]
+    fn test_rv64_not_provable() {
+        let mut program =
+            Program::new(vec![Instruction::new(Opcode::ADD, 29, 0, 5, false, true)], 0, 0);
+        program.rv64 = true;
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        assert!(matches!(runtime.run(), Err(ExecutionError::UnprovableRv64Program())));
+    }
+}
@@ -1,6 +1,7 @@
+#[cfg(feature = "fs")]
 use std::{
     fs::File,
-    io::{Seek, Write},
+    io::{Error as IoError, ErrorKind, Seek, Write},
 };
 
 use hashbrown::HashMap;
@@ -8,7 +9,8 @@ use serde::{Deserialize, Serialize};
 use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, StarkVerifyingKey};
 
 use crate::{
-    events::MemoryRecord,
+    archive::Bincode,
+    events::{LookupId, MemoryRecord},
     memory::PagedMemory,
     record::{ExecutionRecord, MemoryAccessRecord},
     syscalls::SyscallCode,
@@ -16,7 +18,9 @@ use crate::{
 };
 
 /// Holds data describing the current state of a program's execution.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Default, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
 #[repr(C)]
 pub struct ExecutionState {
     /// The program counter.
@@ -46,7 +50,19 @@ pub struct ExecutionState {
     /// A ptr to the current position in the input stream incremented by `HINT_READ` opcode.
     pub input_stream_ptr: usize,
 
+    /// Whether the input stream is replayed from a [`crate::HintReplay`], in which case it holds
+    /// the data served to every access in order, so `HINT_LEN` advances the pointer too, and the
+    /// writes to the input stream and to the hooks are ignored.
+    #[serde(default)]
+    pub replaying_hints: bool,
+
+    /// Read-only regions of memory mapped to elements of the input stream by `HINT_MAP`, sorted by
+    /// address. Their words are only loaded into memory when first accessed.
+    #[serde(default)]
+    pub hint_regions: Vec<HintRegion>,
+
     /// A stream of proofs (reduce vk, proof, verifying key) inputted to the program.
+    #[rkyv(with = Bincode)]
     pub proof_stream:
         Vec<(SP1ReduceProof<BabyBearPoseidon2>, StarkVerifyingKey<BabyBearPoseidon2>)>,
 
@@ -62,6 +78,29 @@ pub struct ExecutionState {
 
     /// Keeps track of how many times a certain syscall has been called.
     pub syscall_counts: HashMap<SyscallCode, u64>,
+
+    /// Memory regions, as `(ptr, len)` in bytes, declared secret via `ZEROIZE_ON_HALT`. They must
+    /// be all zero by the time the final memory state is recorded.
+    pub zeroize_regions: Vec<(u32, u32)>,
+
+    /// Keccak accumulators that have been started but not yet finalized, keyed by the address of
+    /// their control word, as `(shard of the first block, clk of the first block, index of the next
+    /// block, sponge state)`.
+    pub keccak_accumulators: HashMap<u32, (u32, u32, u32, [u64; 25])>,
+
+    /// The last `MEMCPY_32` call, as `(shard, src, dst, lookup id)`, if no other syscall has been
+    /// made since and it has not been coalesced yet.
+    pub pending_memcpy: Option<(u32, u32, u32, LookupId)>,
+
+    /// The number of rows the precompile events of this shard take in the tables of their chips,
+    /// which ends the shard early under [`sp1_stark::ShardPolicy::Adaptive`].
+    #[serde(default)]
+    pub shard_precompile_rows: u32,
+
+    /// The upper halves of the registers of an RV64IM program, whose lower halves are stored in
+    /// memory like the registers of an RV32IM program.
+    #[serde(default)]
+    pub registers_hi: [u32; 32],
 }
 
 impl ExecutionState {
@@ -78,12 +117,52 @@ impl ExecutionState {
             uninitialized_memory: PagedMemory::default(),
             input_stream: Vec::new(),
             input_stream_ptr: 0,
+            replaying_hints: false,
+            hint_regions: Vec::new(),
             public_values_stream: Vec::new(),
             public_values_stream_ptr: 0,
             proof_stream: Vec::new(),
             proof_stream_ptr: 0,
             syscall_counts: HashMap::new(),
+            zeroize_regions: Vec::new(),
+            keccak_accumulators: HashMap::new(),
+            pending_memcpy: None,
+            shard_precompile_rows: 0,
+            registers_hi: [0; 32],
+        }
+    }
+}
+
+/// A read-only region of memory holding an element of the input stream, mapped by `HINT_MAP`.
+#[derive(
+    Debug, Clone, Default, Serialize, Deserialize, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize,
+)]
+pub struct HintRegion {
+    /// The address of the first word of the region, which is aligned to 4 bytes.
+    pub ptr: u32,
+    /// The bytes of the region.
+    pub data: Vec<u8>,
+}
+
+impl HintRegion {
+    /// Whether the word at the given address lies in the region.
+    #[must_use]
+    pub fn contains(&self, addr: u32) -> bool {
+        addr >= self.ptr && ((addr - self.ptr) as usize) < self.data.len()
+    }
+
+    /// The word at the given aligned address, right-padded with zeroes past the end of the data,
+    /// or `None` if it does not lie in the region.
+    #[must_use]
+    pub fn word(&self, addr: u32) -> Option<u32> {
+        if !self.contains(addr) {
+            return None;
         }
+        let offset = (addr - self.ptr) as usize;
+        let mut bytes = [0; 4];
+        let end = (offset + 4).min(self.data.len());
+        bytes[..end - offset].copy_from_slice(&self.data[offset..end]);
+        Some(u32::from_le_bytes(bytes))
     }
 }
 
@@ -105,15 +184,82 @@ pub struct ForkState {
     pub record: ExecutionRecord,
     /// Whether `emit_events` was enabled at the fork point.
     pub executor_mode: ExecutorMode,
+    /// The original upper halves of the registers of an RV64IM program at the fork point.
+    pub registers_hi: [u32; 32],
+    /// The depth of the call stack at the fork point.
+    pub call_depth: usize,
 }
 
 impl ExecutionState {
-    /// Save the execution state to a file.
+    /// Save the execution state to a file, as an [`rkyv`] archive.
+    #[cfg(feature = "fs")]
     pub fn save(&self, file: &mut File) -> std::io::Result<()> {
-        let mut writer = std::io::BufWriter::new(file);
-        bincode::serialize_into(&mut writer, self).unwrap();
-        writer.flush()?;
-        writer.seek(std::io::SeekFrom::Start(0))?;
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(self)
+            .map_err(|e| IoError::new(ErrorKind::InvalidData, e))?;
+        file.write_all(&bytes)?;
+        file.flush()?;
+        file.seek(std::io::SeekFrom::Start(0))?;
         Ok(())
     }
+
+    /// Load an execution state saved with [`ExecutionState::save`] from a file.
+    ///
+    /// The file is mapped rather than read, and the archive is validated in place. The state is
+    /// then deserialized from the mapping, since the execution goes on to mutate it.
+    #[cfg(feature = "fs")]
+    pub fn load(file: &File) -> std::io::Result<Self> {
+        // SAFETY: checkpoint files are private to the prover, and not modified while mapped.
+        let map = unsafe { memmap2::Mmap::map(file)? };
+        let archived = rkyv::access::<ArchivedExecutionState, rkyv::rancor::Error>(&map)
+            .map_err(|e| IoError::new(ErrorKind::InvalidData, e))?;
+        rkyv::deserialize::<Self, rkyv::rancor::Error>(archived)
+            .map_err(|e| IoError::new(ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use std::fs::File;
+
+    use super::{ArchivedExecutionState, ExecutionState, HintRegion};
+    use crate::{events::MemoryRecord, syscalls::SyscallCode};
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let mut state = ExecutionState::new(0x1000);
+        state.global_clk = 42;
+        state.input_stream.push(vec![1, 2, 3]);
+        for addr in [0, 31, 0x1000, 0x1004, 0x7800_0000] {
+            state.memory.insert(addr, MemoryRecord { shard: 1, timestamp: addr, value: !addr });
+        }
+        state.uninitialized_memory.insert(0x2000, 7);
+        state.hint_regions.push(HintRegion { ptr: 0x3000, data: vec![4, 5] });
+        state.syscall_counts.insert(SyscallCode::SHA_EXTEND, 3);
+
+        let path = std::env::temp_dir().join(format!("state-{}.bin", std::process::id()));
+        let mut file =
+            File::options().read(true).write(true).create(true).truncate(true).open(&path).unwrap();
+        state.save(&mut file).unwrap();
+        let loaded = ExecutionState::load(&file).unwrap();
+        assert_eq!((loaded.pc, loaded.global_clk), (0x1000, 42));
+        assert_eq!(loaded.input_stream, state.input_stream);
+        assert_eq!(loaded.memory.keys().collect::<Vec<_>>(), [0, 31, 0x1000, 0x1004, 0x7800_0000]);
+        assert_eq!(loaded.memory.get(0x1004).unwrap().value, !0x1004);
+        assert_eq!(loaded.uninitialized_memory.get(0x2000), Some(&7));
+        assert_eq!(loaded.hint_regions[0].word(0x3000), Some(0x0504));
+        assert_eq!(loaded.syscall_counts[&SyscallCode::SHA_EXTEND], 3);
+
+        // The memory can also be read from the archive in place.
+        // SAFETY: the file is not modified while mapped.
+        let map = unsafe { memmap2::Mmap::map(&file).unwrap() };
+        let archived = rkyv::access::<ArchivedExecutionState, rkyv::rancor::Error>(&map).unwrap();
+        assert_eq!(archived.memory.get(0x7800_0000).unwrap().timestamp.to_native(), 0x7800_0000);
+        assert!(archived.memory.get(0x1008).is_none());
+        drop(map);
+
+        // A truncated checkpoint is rejected.
+        file.set_len(file.metadata().unwrap().len() - 4).unwrap();
+        assert!(ExecutionState::load(&file).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
 }
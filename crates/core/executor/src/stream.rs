@@ -0,0 +1,82 @@
+use core::fmt::Debug;
+use std::{
+    io::{ErrorKind, Read, Result as IoResult, Write},
+    sync::{Arc, Mutex},
+};
+
+/// A source of inputs read lazily as the program consumes the input stream, e.g. a file or a
+/// socket, so that large inputs are never held in memory all at once.
+///
+/// The source holds a sequence of frames, each of which is an element of the input stream: the
+/// length of the frame as a little-endian `u64`, followed by its bytes. Frames are written with
+/// [`StdinStream::write_frame`].
+///
+/// Clones share the same source, so a frame read through one of them is not seen by the others.
+#[derive(Clone)]
+pub struct StdinStream {
+    reader: Arc<Mutex<Box<dyn Read + Send>>>,
+}
+
+impl StdinStream {
+    /// Read the frames of the input stream from `reader`.
+    pub fn new(reader: impl Read + Send + 'static) -> Self {
+        Self { reader: Arc::new(Mutex::new(Box::new(reader))) }
+    }
+
+    /// Write `data` to `writer` as a frame of an input stream.
+    pub fn write_frame<W: Write>(writer: &mut W, data: &[u8]) -> IoResult<()> {
+        writer.write_all(&(data.len() as u64).to_le_bytes())?;
+        writer.write_all(data)
+    }
+
+    /// Read the next frame of the source, returning `None` if the source ended between frames.
+    pub fn next_frame(&self) -> IoResult<Option<Vec<u8>>> {
+        let mut reader = self.reader.lock().unwrap();
+        let mut len = [0u8; 8];
+        match reader.read_exact(&mut len) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+        let len = u64::from_le_bytes(len);
+        let mut frame = Vec::new();
+        reader.by_ref().take(len).read_to_end(&mut frame)?;
+        if frame.len() as u64 != len {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+        Ok(Some(frame))
+    }
+}
+
+impl Debug for StdinStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StdinStream").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, ErrorKind};
+
+    use super::StdinStream;
+
+    #[test]
+    fn test_stdin_stream() {
+        let mut bytes = Vec::new();
+        StdinStream::write_frame(&mut bytes, &[1, 2, 3]).unwrap();
+        StdinStream::write_frame(&mut bytes, &[]).unwrap();
+        StdinStream::write_frame(&mut bytes, &[4; 1000]).unwrap();
+
+        let stream = StdinStream::new(Cursor::new(bytes.clone()));
+        assert_eq!(stream.next_frame().unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(stream.clone().next_frame().unwrap(), Some(vec![]));
+        assert_eq!(stream.next_frame().unwrap(), Some(vec![4; 1000]));
+        assert_eq!(stream.next_frame().unwrap(), None);
+
+        // A frame cut short is an error, rather than the end of the stream.
+        let stream = StdinStream::new(Cursor::new(bytes[..bytes.len() - 1].to_vec()));
+        stream.next_frame().unwrap();
+        stream.next_frame().unwrap();
+        assert_eq!(stream.next_frame().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    }
+}
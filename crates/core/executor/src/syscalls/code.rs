@@ -2,6 +2,12 @@ use enum_map::Enum;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
+use crate::events::{
+    BN254_INNER_PRODUCT_MAX_LEN, ECRECOVER_ROWS, ED25519_VERIFY_ROWS, FR_NTT_MAX_LEN,
+    KECCAK_MERKLE_MAX_DEPTH, POSEIDON_MERKLE_ROWS, RLP_SCAN_MAX_ITEMS, RSA_LADDER_STEPS,
+    SHA512_COMPRESS_ROWS, WITHDRAW_ROOT_HASHES, ZKTRIE_NODE_ROWS,
+};
+
 /// System Calls.
 ///
 /// A system call is invoked by the the `ecall` instruction with a specific value in register t0.
@@ -9,7 +15,7 @@ use strum_macros::EnumIter;
 ///
 /// | Byte 0 | Byte 1 | Byte 2 | Byte 3 |
 /// | ------ | ------ | ------ | ------ |
-/// |   ID   | Table  | Cycles | Unused |
+/// |   ID   | Table  | Cycles | Status |
 ///
 /// where:
 /// - Byte 0: The system call identifier.
@@ -17,7 +23,9 @@ use strum_macros::EnumIter;
 ///   table to determine whether to lookup the syscall using the syscall interaction.
 /// - Byte 2: The number of additional cycles the syscall uses. This is used to make sure the # of
 ///   memory accesses is bounded.
-/// - Byte 3: Currently unused.
+/// - Byte 3: Whether the system call returns a status in t0, which is 0 on success and 1 on
+///   failure. The CPU table constrains the status to be a bit and sends it to the handler's table
+///   along with the identifier, see [`SyscallCode::status_syscall_id`].
 #[derive(
     Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, Ord, PartialOrd, Serialize, Deserialize, Enum,
 )]
@@ -46,7 +54,7 @@ pub enum SyscallCode {
     ED_ADD = 0x00_01_01_07,
 
     /// Executes the `ED_DECOMPRESS` precompile.
-    ED_DECOMPRESS = 0x00_00_01_08,
+    ED_DECOMPRESS = 0x01_00_01_08,
 
     /// Executes the `KECCAK_PERMUTE` precompile.
     KECCAK_PERMUTE = 0x00_01_01_09,
@@ -58,7 +66,7 @@ pub enum SyscallCode {
     SECP256K1_DOUBLE = 0x00_00_01_0B,
 
     /// Executes the `SECP256K1_DECOMPRESS` precompile.
-    SECP256K1_DECOMPRESS = 0x00_00_01_0C,
+    SECP256K1_DECOMPRESS = 0x01_00_01_0C,
 
     /// Executes the `BN254_ADD` precompile.
     BN254_ADD = 0x00_01_01_0E,
@@ -69,6 +77,9 @@ pub enum SyscallCode {
     /// Executes the `COMMIT` precompile.
     COMMIT = 0x00_00_00_10,
 
+    /// Declares a secret memory region that must be zeroized before halting.
+    ZEROIZE_ON_HALT = 0x00_00_00_11,
+
     /// Executes the `COMMIT_DEFERRED_PROOFS` precompile.
     COMMIT_DEFERRED_PROOFS = 0x00_00_00_1A,
 
@@ -76,7 +87,7 @@ pub enum SyscallCode {
     VERIFY_SP1_PROOF = 0x00_00_00_1B,
 
     /// Executes the `BLS12381_DECOMPRESS` precompile.
-    BLS12381_DECOMPRESS = 0x00_00_01_1C,
+    BLS12381_DECOMPRESS = 0x01_00_01_1C,
 
     /// Executes the `HINT_LEN` precompile.
     HINT_LEN = 0x00_00_00_F0,
@@ -84,6 +95,9 @@ pub enum SyscallCode {
     /// Executes the `HINT_READ` precompile.
     HINT_READ = 0x00_00_00_F1,
 
+    /// Executes the `HINT_MAP` precompile.
+    HINT_MAP = 0x00_00_00_F2,
+
     /// Executes the `UINT256_MUL` precompile.
     UINT256_MUL = 0x00_01_01_1D,
 
@@ -139,13 +153,145 @@ pub enum SyscallCode {
     SECP256R1_DOUBLE = 0x00_00_01_2D,
 
     /// Executes the `SECP256R1_DECOMPRESS` precompile.
-    SECP256R1_DECOMPRESS = 0x00_00_01_2E,
+    SECP256R1_DECOMPRESS = 0x01_00_01_2E,
 
     /// Execute the `BN254_SCALAR_MULADD` precompile.
     BN254_SCALAR_MULADD = 0x00_01_01_31,
 
     /// Execute the `BN254_SCALAR_MULADD` precompile base on uint256.
     BN254_MULADD = 0x00_01_01_1F,
+
+    /// Executes the `UINT384_MULADD` precompile.
+    UINT384_MULADD = 0x00_01_01_32,
+
+    /// Executes the `KECCAK_ACCUMULATE` precompile.
+    KECCAK_ACCUMULATE = 0x00_01_01_33,
+
+    /// Executes the `U256XU4096_MUL` precompile.
+    U256XU4096_MUL = 0x00_01_01_34,
+
+    /// Executes the `BN254_SCALAR_TO_MONT` precompile.
+    BN254_SCALAR_TO_MONT = 0x00_01_01_35,
+
+    /// Executes the `BN254_SCALAR_FROM_MONT` precompile.
+    BN254_SCALAR_FROM_MONT = 0x00_01_01_36,
+
+    /// Executes the `BARRETT_REDUCE` precompile.
+    BARRETT_REDUCE = 0x00_01_01_37,
+
+    /// Executes the `AES_ENC_ROUND` precompile.
+    AES_ENC_ROUND = 0x00_01_01_38,
+
+    /// Executes the `AES_ENC_LAST_ROUND` precompile.
+    AES_ENC_LAST_ROUND = 0x00_01_01_39,
+
+    /// Executes the `CHACHA20_BLOCK` precompile.
+    CHACHA20_BLOCK = 0x00_01_01_3A,
+
+    /// Executes the `HMAC_SHA256` precompile.
+    HMAC_SHA256 = 0x00_CC_01_3B,
+
+    /// Executes the `KECCAK_ABSORB` precompile.
+    KECCAK_ABSORB = 0x00_02_01_3C,
+
+    /// Executes the `KECCAK_FINALIZE` precompile.
+    KECCAK_FINALIZE = 0x00_02_01_3D,
+
+    /// Executes the `SHA256_COMPRESS_BLOCKS` precompile.
+    SHA256_COMPRESS_BLOCKS = 0x00_FF_01_3E,
+
+    /// Executes the `F64_ADD` precompile.
+    F64_ADD = 0x01_01_01_3F,
+
+    /// Executes the `F64_MUL` precompile.
+    F64_MUL = 0x01_01_01_40,
+
+    /// Executes the `F64_DIV` precompile.
+    F64_DIV = 0x01_01_01_41,
+
+    /// Executes the `BN254_DECOMPRESS` precompile.
+    BN254_DECOMPRESS = 0x01_00_01_42,
+
+    /// Executes the `BN254_G2_DECOMPRESS` precompile.
+    BN254_G2_DECOMPRESS = 0x01_00_01_43,
+
+    /// Executes the `JUBJUB_ADD` precompile.
+    JUBJUB_ADD = 0x00_01_01_44,
+
+    /// Executes the `BABY_JUBJUB_ADD` precompile.
+    BABY_JUBJUB_ADD = 0x00_01_01_45,
+
+    /// Executes the `HEX_DECODE` precompile.
+    HEX_DECODE = 0x01_01_01_46,
+
+    /// Executes the `BASE64_DECODE` precompile.
+    BASE64_DECODE = 0x01_01_01_47,
+
+    /// Executes the `BLS12381_FP6_MUL` precompile.
+    BLS12381_FP6_MUL = 0x00_01_01_48,
+
+    /// Executes the `BN254_FP6_MUL` precompile.
+    BN254_FP6_MUL = 0x00_01_01_49,
+
+    /// Executes the `BN254_INNER_PRODUCT` precompile.
+    BN254_INNER_PRODUCT = 0x00_01_01_4A,
+
+    /// Executes the `MEMCPY_32` precompile.
+    MEMCPY_32 = 0x00_01_01_4B,
+
+    /// Executes the `MEMCPY_64` precompile.
+    MEMCPY_64 = 0x00_01_01_4C,
+
+    /// Executes the `POSEIDON_MERKLE_ROOT` precompile.
+    POSEIDON_MERKLE_ROOT = 0x00_01_01_4D,
+
+    /// Executes the `KECCAK_MERKLE_ROOT` precompile.
+    KECCAK_MERKLE_ROOT = 0x00_18_01_4E,
+
+    /// Executes the `RSA2048_MODEXP` precompile.
+    RSA2048_MODEXP = 0x00_01_01_4F,
+
+    /// Executes the `RSA4096_MODEXP` precompile.
+    RSA4096_MODEXP = 0x00_01_01_50,
+
+    /// Executes the `ZKTRIE_HASH_NODE` precompile.
+    ZKTRIE_HASH_NODE = 0x00_01_01_51,
+
+    /// Executes the `BN254_MULADD` precompile on big-endian operands.
+    BN254_MULADD_BE = 0x00_01_01_52,
+
+    /// Executes the `SECP256K1_ECRECOVER` precompile.
+    SECP256K1_ECRECOVER = 0x01_03_01_53,
+
+    /// Executes the `SHA512_COMPRESS` precompile.
+    SHA512_COMPRESS = 0x00_01_01_54,
+
+    /// Executes the `ED25519_VERIFY` precompile.
+    ED25519_VERIFY = 0x01_00_01_55,
+
+    /// Executes the `WITHDRAW_ROOT` precompile.
+    WITHDRAW_ROOT = 0x00_3C_01_56,
+
+    /// Executes the `CSEL256` precompile.
+    CSEL256 = 0x00_01_01_57,
+
+    /// Executes the `FR_NTT` precompile.
+    FR_NTT = 0x00_01_01_58,
+
+    /// Executes the `RLP_SCAN` precompile.
+    RLP_SCAN = 0x00_20_01_59,
+
+    /// Reserved for an out-of-tree precompile, see [`crate::Executor::register_syscall`].
+    PLUGIN_0 = 0x00_01_01_E0,
+
+    /// Reserved for an out-of-tree precompile, see [`crate::Executor::register_syscall`].
+    PLUGIN_1 = 0x00_01_01_E1,
+
+    /// Reserved for an out-of-tree precompile, see [`crate::Executor::register_syscall`].
+    PLUGIN_2 = 0x00_01_01_E2,
+
+    /// Reserved for an out-of-tree precompile, see [`crate::Executor::register_syscall`].
+    PLUGIN_3 = 0x00_01_01_E3,
 }
 
 impl SyscallCode {
@@ -160,20 +306,22 @@ impl SyscallCode {
             0x00_30_01_05 => SyscallCode::SHA_EXTEND,
             0x00_01_01_06 => SyscallCode::SHA_COMPRESS,
             0x00_01_01_07 => SyscallCode::ED_ADD,
-            0x00_00_01_08 => SyscallCode::ED_DECOMPRESS,
+            0x01_00_01_08 => SyscallCode::ED_DECOMPRESS,
             0x00_01_01_09 => SyscallCode::KECCAK_PERMUTE,
             0x00_01_01_0A => SyscallCode::SECP256K1_ADD,
             0x00_00_01_0B => SyscallCode::SECP256K1_DOUBLE,
-            0x00_00_01_0C => SyscallCode::SECP256K1_DECOMPRESS,
+            0x01_00_01_0C => SyscallCode::SECP256K1_DECOMPRESS,
             0x00_01_01_0E => SyscallCode::BN254_ADD,
             0x00_00_01_0F => SyscallCode::BN254_DOUBLE,
             0x00_01_01_1E => SyscallCode::BLS12381_ADD,
             0x00_00_01_1F => SyscallCode::BLS12381_DOUBLE,
             0x00_00_00_10 => SyscallCode::COMMIT,
+            0x00_00_00_11 => SyscallCode::ZEROIZE_ON_HALT,
             0x00_00_00_1A => SyscallCode::COMMIT_DEFERRED_PROOFS,
             0x00_00_00_1B => SyscallCode::VERIFY_SP1_PROOF,
             0x00_00_00_F0 => SyscallCode::HINT_LEN,
             0x00_00_00_F1 => SyscallCode::HINT_READ,
+            0x00_00_00_F2 => SyscallCode::HINT_MAP,
             0x00_01_01_1D => SyscallCode::UINT256_MUL,
             0x00_01_01_2F => SyscallCode::U256XU2048_MUL,
             0x00_01_01_20 => SyscallCode::BLS12381_FP_ADD,
@@ -188,12 +336,56 @@ impl SyscallCode {
             0x00_01_01_29 => SyscallCode::BN254_FP2_ADD,
             0x00_01_01_2A => SyscallCode::BN254_FP2_SUB,
             0x00_01_01_2B => SyscallCode::BN254_FP2_MUL,
-            0x00_00_01_1C => SyscallCode::BLS12381_DECOMPRESS,
+            0x01_00_01_1C => SyscallCode::BLS12381_DECOMPRESS,
             0x00_01_01_2C => SyscallCode::SECP256R1_ADD,
             0x00_00_01_2D => SyscallCode::SECP256R1_DOUBLE,
-            0x00_00_01_2E => SyscallCode::SECP256R1_DECOMPRESS,
+            0x01_00_01_2E => SyscallCode::SECP256R1_DECOMPRESS,
             0x00_01_01_31 => SyscallCode::BN254_SCALAR_MULADD,
             0x00_01_01_1F => SyscallCode::BN254_MULADD,
+            0x00_01_01_32 => SyscallCode::UINT384_MULADD,
+            0x00_01_01_33 => SyscallCode::KECCAK_ACCUMULATE,
+            0x00_01_01_34 => SyscallCode::U256XU4096_MUL,
+            0x00_01_01_35 => SyscallCode::BN254_SCALAR_TO_MONT,
+            0x00_01_01_36 => SyscallCode::BN254_SCALAR_FROM_MONT,
+            0x00_01_01_37 => SyscallCode::BARRETT_REDUCE,
+            0x00_01_01_38 => SyscallCode::AES_ENC_ROUND,
+            0x00_01_01_39 => SyscallCode::AES_ENC_LAST_ROUND,
+            0x00_01_01_3A => SyscallCode::CHACHA20_BLOCK,
+            0x00_CC_01_3B => SyscallCode::HMAC_SHA256,
+            0x00_02_01_3C => SyscallCode::KECCAK_ABSORB,
+            0x00_02_01_3D => SyscallCode::KECCAK_FINALIZE,
+            0x00_FF_01_3E => SyscallCode::SHA256_COMPRESS_BLOCKS,
+            0x01_01_01_3F => SyscallCode::F64_ADD,
+            0x01_01_01_40 => SyscallCode::F64_MUL,
+            0x01_01_01_41 => SyscallCode::F64_DIV,
+            0x01_00_01_42 => SyscallCode::BN254_DECOMPRESS,
+            0x01_00_01_43 => SyscallCode::BN254_G2_DECOMPRESS,
+            0x00_01_01_44 => SyscallCode::JUBJUB_ADD,
+            0x00_01_01_45 => SyscallCode::BABY_JUBJUB_ADD,
+            0x01_01_01_46 => SyscallCode::HEX_DECODE,
+            0x01_01_01_47 => SyscallCode::BASE64_DECODE,
+            0x00_01_01_48 => SyscallCode::BLS12381_FP6_MUL,
+            0x00_01_01_49 => SyscallCode::BN254_FP6_MUL,
+            0x00_01_01_4A => SyscallCode::BN254_INNER_PRODUCT,
+            0x00_01_01_4B => SyscallCode::MEMCPY_32,
+            0x00_01_01_4C => SyscallCode::MEMCPY_64,
+            0x00_01_01_4D => SyscallCode::POSEIDON_MERKLE_ROOT,
+            0x00_18_01_4E => SyscallCode::KECCAK_MERKLE_ROOT,
+            0x00_01_01_4F => SyscallCode::RSA2048_MODEXP,
+            0x00_01_01_50 => SyscallCode::RSA4096_MODEXP,
+            0x00_01_01_51 => SyscallCode::ZKTRIE_HASH_NODE,
+            0x00_01_01_52 => SyscallCode::BN254_MULADD_BE,
+            0x01_03_01_53 => SyscallCode::SECP256K1_ECRECOVER,
+            0x00_01_01_54 => SyscallCode::SHA512_COMPRESS,
+            0x01_00_01_55 => SyscallCode::ED25519_VERIFY,
+            0x00_3C_01_56 => SyscallCode::WITHDRAW_ROOT,
+            0x00_01_01_57 => SyscallCode::CSEL256,
+            0x00_01_01_58 => SyscallCode::FR_NTT,
+            0x00_20_01_59 => SyscallCode::RLP_SCAN,
+            0x00_01_01_E0 => SyscallCode::PLUGIN_0,
+            0x00_01_01_E1 => SyscallCode::PLUGIN_1,
+            0x00_01_01_E2 => SyscallCode::PLUGIN_2,
+            0x00_01_01_E3 => SyscallCode::PLUGIN_3,
             _ => panic!("invalid syscall number: {value}"),
         }
     }
@@ -216,6 +408,47 @@ impl SyscallCode {
         (self as u32).to_le_bytes()[2].into()
     }
 
+    /// Get whether the system call returns a status in t0.
+    #[must_use]
+    pub fn returns_status(self) -> bool {
+        (self as u32).to_le_bytes()[3] == 1
+    }
+
+    /// Get the identifier sent in the syscall interaction of a call that returned `status`.
+    ///
+    /// The status of a call is folded into the identifier, so that the handler's table receives
+    /// the same status as the CPU table writes to t0. System calls that do not return a status
+    /// always return 0.
+    #[must_use]
+    pub fn status_syscall_id(self, status: u32) -> u32 {
+        self.syscall_id() + (status << 8)
+    }
+
+    /// Get the number of rows a call to the system call takes in the table of its handler.
+    ///
+    /// System calls without a table of their own are counted as a single row.
+    #[must_use]
+    pub fn rows_per_event(self) -> usize {
+        match self.count_map() {
+            SyscallCode::KECCAK_PERMUTE | SyscallCode::KECCAK_ACCUMULATE => 24,
+            SyscallCode::SHA_EXTEND => 48,
+            SyscallCode::SHA_COMPRESS => 80,
+            SyscallCode::CHACHA20_BLOCK => 10,
+            SyscallCode::BN254_INNER_PRODUCT => BN254_INNER_PRODUCT_MAX_LEN,
+            SyscallCode::FR_NTT => FR_NTT_MAX_LEN,
+            SyscallCode::RLP_SCAN => RLP_SCAN_MAX_ITEMS,
+            SyscallCode::POSEIDON_MERKLE_ROOT => POSEIDON_MERKLE_ROWS,
+            SyscallCode::ZKTRIE_HASH_NODE => ZKTRIE_NODE_ROWS,
+            SyscallCode::KECCAK_MERKLE_ROOT => KECCAK_MERKLE_MAX_DEPTH,
+            SyscallCode::RSA2048_MODEXP | SyscallCode::RSA4096_MODEXP => RSA_LADDER_STEPS,
+            SyscallCode::SECP256K1_ECRECOVER => ECRECOVER_ROWS,
+            SyscallCode::SHA512_COMPRESS => SHA512_COMPRESS_ROWS,
+            SyscallCode::ED25519_VERIFY => ED25519_VERIFY_ROWS,
+            SyscallCode::WITHDRAW_ROOT => WITHDRAW_ROOT_HASHES,
+            _ => 1,
+        }
+    }
+
     /// Map a syscall to another one in order to coalesce their counts.
     #[must_use]
     #[allow(clippy::match_same_arms)]
@@ -227,6 +460,10 @@ impl SyscallCode {
             SyscallCode::BLS12381_FP_SUB => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP_MUL => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP2_SUB => SyscallCode::BLS12381_FP2_ADD,
+            SyscallCode::BN254_SCALAR_FROM_MONT => SyscallCode::BN254_SCALAR_TO_MONT,
+            SyscallCode::BN254_MULADD_BE => SyscallCode::BN254_MULADD,
+            SyscallCode::AES_ENC_LAST_ROUND => SyscallCode::AES_ENC_ROUND,
+            SyscallCode::KECCAK_FINALIZE => SyscallCode::KECCAK_ABSORB,
             _ => *self,
         }
     }
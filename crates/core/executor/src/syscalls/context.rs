@@ -2,8 +2,8 @@ use hashbrown::HashMap;
 
 use crate::{
     events::{
-        LookupId, MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord, PrecompileEvent,
-        SyscallEvent,
+        ArenaSlice, LookupId, MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord,
+        PrecompileEvent, SyscallEvent,
     },
     record::ExecutionRecord,
     Executor, ExecutorMode, Register,
@@ -54,17 +54,25 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
 
     #[inline]
     /// Add a precompile event to the execution record.
+    ///
+    /// Events of precompiles executed in unconstrained mode are pruned, since their effects are
+    /// rolled back when leaving the unconstrained block.
     pub fn add_precompile_event(
         &mut self,
         syscall_code: SyscallCode,
         syscall_event: SyscallEvent,
         event: PrecompileEvent,
     ) {
-        if self.rt.executor_mode == ExecutorMode::Trace {
+        if self.keeps_events() {
             self.record_mut().precompile_events.add_event(syscall_code, syscall_event, event);
         }
     }
 
+    /// Whether the events of the syscall are kept in the execution record.
+    fn keeps_events(&self) -> bool {
+        !self.rt.unconstrained && self.rt.executor_mode == ExecutorMode::Trace
+    }
+
     /// Get the current shard.
     #[must_use]
     pub fn current_shard(&self) -> u32 {
@@ -90,6 +98,24 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
         (records, values)
     }
 
+    /// Read a slice of words from memory into `values`, keeping the memory records in the read
+    /// arena of the precompile events rather than in a `Vec` of their own.
+    ///
+    /// The records are only kept if the events of the syscall are, so the returned slice is
+    /// empty otherwise.
+    pub fn mr_slice_arena(&mut self, addr: u32, values: &mut [u32]) -> ArenaSlice {
+        let keep = self.keeps_events();
+        let start = self.rt.record.precompile_events.read_arena.len();
+        for (i, value) in values.iter_mut().enumerate() {
+            let (record, read) = self.mr(addr + i as u32 * 4);
+            *value = read;
+            if keep {
+                self.rt.record.precompile_events.read_arena.push(record);
+            }
+        }
+        self.rt.record.precompile_events.read_arena.slice_from(start)
+    }
+
     /// Write a word to memory.
     pub fn mw(&mut self, addr: u32, value: u32) -> MemoryWriteRecord {
         self.rt.mw(addr, value, self.current_shard, self.clk, Some(&mut self.local_memory_access))
@@ -105,6 +131,20 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
         records
     }
 
+    /// Write a slice of words to memory, keeping the memory records in the write arena of the
+    /// precompile events as [`Self::mr_slice_arena`] does.
+    pub fn mw_slice_arena(&mut self, addr: u32, values: &[u32]) -> ArenaSlice {
+        let keep = self.keeps_events();
+        let start = self.rt.record.precompile_events.write_arena.len();
+        for (i, &value) in values.iter().enumerate() {
+            let record = self.mw(addr + i as u32 * 4, value);
+            if keep {
+                self.rt.record.precompile_events.write_arena.push(record);
+            }
+        }
+        self.rt.record.precompile_events.write_arena.slice_from(start)
+    }
+
     /// Postprocess the syscall.  Specifically will process the syscall's memory local events.
     pub fn postprocess(&mut self) -> Vec<MemoryLocalEvent> {
         let mut syscall_local_mem_events = Vec::new();
@@ -127,6 +167,44 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
         syscall_local_mem_events
     }
 
+    /// Execute another precompile on behalf of the current syscall.
+    ///
+    /// The precompile is given its own lookup id and nonce, so that its event is proven by its
+    /// own chip like any other call, and returns the syscall event the calling precompile's chip
+    /// must send. The memory accesses of the current syscall must be postprocessed beforehand.
+    pub fn execute_nested(
+        &mut self,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> SyscallEvent {
+        assert!(self.local_memory_access.is_empty(), "pending memory accesses");
+        let syscall_impl = self
+            .rt
+            .get_syscall(syscall_code)
+            .cloned()
+            .unwrap_or_else(|| panic!("unsupported syscall {syscall_code}"));
+
+        let lookup_id = if self.rt.executor_mode == ExecutorMode::Trace {
+            self.rt.record.create_lookup_id()
+        } else {
+            LookupId::default()
+        };
+        self.rt.assign_syscall_nonce(syscall_code, lookup_id);
+
+        let clk = self.clk;
+        let caller_lookup_id = std::mem::replace(&mut self.syscall_lookup_id, lookup_id);
+        let status = syscall_impl.execute(self, syscall_code, arg1, arg2);
+        self.syscall_lookup_id = caller_lookup_id;
+
+        let syscall_id = if syscall_code.returns_status() {
+            syscall_code.status_syscall_id(status.unwrap_or_default())
+        } else {
+            syscall_code.syscall_id()
+        };
+        self.rt.syscall_event(clk, syscall_id, arg1, arg2, lookup_id)
+    }
+
     /// Get the current value of a register, but doesn't use a memory record.
     /// This is generally unconstrained, so you must be careful using it.
     #[must_use]
@@ -1,5 +1,9 @@
 use super::{context::SyscallContext, Syscall, SyscallCode};
 
+/// The value of the second argument of `HALT` which marks the exit code as chosen by the program,
+/// rather than the result of a failure such as a panic.
+pub const HALT_TYPED_EXIT: u32 = 1;
+
 pub(crate) struct HaltSyscall;
 
 impl Syscall for HaltSyscall {
@@ -1,3 +1,5 @@
+use crate::{HintAccess, HintRegion};
+
 use super::{Syscall, SyscallCode, SyscallContext};
 
 pub(crate) struct HintLenSyscall;
@@ -10,6 +12,7 @@ impl Syscall for HintLenSyscall {
         _arg1: u32,
         _arg2: u32,
     ) -> Option<u32> {
+        ctx.rt.pull_input();
         if ctx.rt.state.input_stream_ptr >= ctx.rt.state.input_stream.len() {
             panic!(
                 "failed reading stdin due to insufficient input data: input_stream_ptr={}, input_stream_len={}",
@@ -17,7 +20,15 @@ impl Syscall for HintLenSyscall {
                 ctx.rt.state.input_stream.len()
             );
         }
-        Some(ctx.rt.state.input_stream[ctx.rt.state.input_stream_ptr].len() as u32)
+        let offset = ctx.rt.state.input_stream_ptr;
+        let len = ctx.rt.state.input_stream[offset].len() as u32;
+        let global_clk = ctx.rt.state.global_clk;
+        ctx.rt.hint_access(|| HintAccess::Len { offset, global_clk, len });
+        // A replayed input stream holds the data served to every access, including this one.
+        if ctx.rt.state.replaying_hints {
+            ctx.rt.state.input_stream_ptr += 1;
+        }
+        Some(len)
     }
 }
 
@@ -25,6 +36,7 @@ pub(crate) struct HintReadSyscall;
 
 impl Syscall for HintReadSyscall {
     fn execute(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, len: u32) -> Option<u32> {
+        ctx.rt.pull_input();
         if ctx.rt.state.input_stream_ptr >= ctx.rt.state.input_stream.len() {
             panic!(
                 "failed reading stdin due to insufficient input data: input_stream_ptr={}, input_stream_len={}",
@@ -32,8 +44,11 @@ impl Syscall for HintReadSyscall {
                 ctx.rt.state.input_stream.len()
             );
         }
-        let vec = &ctx.rt.state.input_stream[ctx.rt.state.input_stream_ptr];
+        let offset = ctx.rt.state.input_stream_ptr;
+        let global_clk = ctx.rt.state.global_clk;
+        let vec = std::mem::take(&mut ctx.rt.state.input_stream[offset]);
         ctx.rt.state.input_stream_ptr += 1;
+        ctx.rt.hint_access(|| HintAccess::Read { offset, global_clk, data: vec.clone() });
         assert!(!ctx.rt.unconstrained, "hint read should not be used in a unconstrained block");
         assert_eq!(vec.len() as u32, len, "hint input stream read length mismatch");
         assert_eq!(ptr % 4, 0, "hint read address not aligned to 4 bytes");
@@ -61,3 +76,46 @@ impl Syscall for HintReadSyscall {
         None
     }
 }
+
+/// Maps the next element of the input stream to `len` bytes of read-only memory starting at `ptr`.
+///
+/// Unlike `HINT_READ`, no word is written to memory here: the words of the region are loaded when
+/// the program first accesses them, so a large input is never copied upfront and the parts of it
+/// that the program does not touch cost nothing.
+pub(crate) struct HintMapSyscall;
+
+impl Syscall for HintMapSyscall {
+    fn execute(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, len: u32) -> Option<u32> {
+        ctx.rt.pull_input();
+        if ctx.rt.state.input_stream_ptr >= ctx.rt.state.input_stream.len() {
+            panic!(
+                "failed reading stdin due to insufficient input data: input_stream_ptr={}, input_stream_len={}",
+                ctx.rt.state.input_stream_ptr,
+                ctx.rt.state.input_stream.len()
+            );
+        }
+        let offset = ctx.rt.state.input_stream_ptr;
+        let global_clk = ctx.rt.state.global_clk;
+        let data = std::mem::take(&mut ctx.rt.state.input_stream[offset]);
+        ctx.rt.state.input_stream_ptr += 1;
+        ctx.rt.hint_access(|| HintAccess::Read { offset, global_clk, data: data.clone() });
+        assert!(!ctx.rt.unconstrained, "hint map should not be used in a unconstrained block");
+        assert_eq!(data.len() as u32, len, "hint input stream map length mismatch");
+        assert_eq!(ptr % 4, 0, "hint map address not aligned to 4 bytes");
+        assert!(ptr.checked_add(len).is_some(), "hint region overflows the address space");
+        assert!(
+            ctx.rt.state.hint_regions.iter().all(|region| {
+                ptr >= region.ptr + region.data.len() as u32 || ptr + len <= region.ptr
+            }),
+            "hint region overlaps a mapped region already"
+        );
+        // The regions are kept sorted by address, so that the memory accesses find them with a
+        // binary search.
+        if len > 0 {
+            let regions = &mut ctx.rt.state.hint_regions;
+            let index = regions.partition_point(|region| region.ptr < ptr);
+            regions.insert(index, HintRegion { ptr, data });
+        }
+        None
+    }
+}
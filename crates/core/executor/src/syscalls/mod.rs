@@ -10,6 +10,7 @@ mod precompiles;
 mod unconstrained;
 mod verify;
 mod write;
+mod zeroize;
 
 use std::sync::Arc;
 
@@ -17,26 +18,58 @@ use commit::CommitSyscall;
 use deferred::CommitDeferredSyscall;
 use halt::HaltSyscall;
 use hashbrown::HashMap;
+use typenum::{U16, U8};
 
 pub use code::*;
 pub use context::*;
-use hint::{HintLenSyscall, HintReadSyscall};
+pub use halt::HALT_TYPED_EXIT;
+use hint::{HintLenSyscall, HintMapSyscall, HintReadSyscall};
 use precompiles::{
-    bn254::Bn254MulAddSyscall,
-    edwards::{add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall},
-    fptower::{Fp2AddSubSyscall, Fp2MulSyscall, FpOpSyscall},
-    keccak256::permute::Keccak256PermuteSyscall,
-    sha256::{compress::Sha256CompressSyscall, extend::Sha256ExtendSyscall},
+    aes::AesRoundSyscall,
+    barrett_reduce::BarrettReduceSyscall,
+    bn254::{Bn254InnerProductSyscall, Bn254MontSyscall, Bn254MulAddSyscall, FrNttSyscall},
+    chacha20::ChaCha20BlockSyscall,
+    codec::DecodeSyscall,
+    csel256::Csel256Syscall,
+    edwards::{
+        add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall,
+        verify::Ed25519VerifySyscall,
+    },
+    f64::F64OpSyscall,
+    fptower::{Fp2AddSubSyscall, Fp2MulSyscall, Fp6MulSyscall, FpOpSyscall},
+    keccak256::{
+        accumulate::Keccak256AccumulateSyscall, merkle::Keccak256MerkleSyscall,
+        permute::Keccak256PermuteSyscall, sponge::Keccak256SpongeSyscall,
+        withdraw_root::Keccak256WithdrawRootSyscall,
+    },
+    memcopy::MemCopySyscall,
+    poseidon_merkle::PoseidonMerkleSyscall,
+    rlp_scan::RlpScanSyscall,
+    rsa::RsaModExpSyscall,
+    sha256::{
+        blocks::Sha256CompressBlocksSyscall, compress::Sha256CompressSyscall,
+        extend::Sha256ExtendSyscall, hmac::HmacSha256Syscall,
+    },
+    sha512::Sha512CompressSyscall,
     u256x2048_mul::U256xU2048MulSyscall,
+    u256x4096_mul::U256xU4096MulSyscall,
     uint256::Uint256MulSyscall,
+    uint384::Uint384MulAddSyscall,
     weierstrass::{
-        add::WeierstrassAddAssignSyscall, decompress::WeierstrassDecompressSyscall,
+        add::WeierstrassAddAssignSyscall,
+        decompress::{Bn254G2DecompressSyscall, WeierstrassDecompressSyscall},
         double::WeierstrassDoubleAssignSyscall,
+        ecrecover::Secp256k1EcRecoverSyscall,
     },
+    zktrie::ZkTrieNodeSyscall,
 };
 
 use sp1_curves::{
-    edwards::ed25519::{Ed25519, Ed25519Parameters},
+    edwards::{
+        baby_jubjub::BabyJubjub,
+        ed25519::{Ed25519, Ed25519Parameters},
+        jubjub::Jubjub,
+    },
     weierstrass::{
         bls12_381::{Bls12381, Bls12381BaseField},
         bn254::{Bn254, Bn254BaseField},
@@ -47,8 +80,9 @@ use sp1_curves::{
 use unconstrained::{EnterUnconstrainedSyscall, ExitUnconstrainedSyscall};
 use verify::VerifySyscall;
 use write::WriteSyscall;
+use zeroize::ZeroizeOnHaltSyscall;
 
-use crate::events::FieldOperation;
+use crate::events::{Encoding, F64Operation, FieldOperation};
 
 /// A system call in the SP1 RISC-V zkVM.
 ///
@@ -95,8 +129,16 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(EdwardsDecompressSyscall::<Ed25519Parameters>::new()),
     );
 
+    syscall_map.insert(SyscallCode::ED25519_VERIFY, Arc::new(Ed25519VerifySyscall));
+
     syscall_map.insert(SyscallCode::KECCAK_PERMUTE, Arc::new(Keccak256PermuteSyscall));
 
+    syscall_map.insert(SyscallCode::KECCAK_ACCUMULATE, Arc::new(Keccak256AccumulateSyscall));
+
+    syscall_map.insert(SyscallCode::KECCAK_ABSORB, Arc::new(Keccak256SpongeSyscall));
+
+    syscall_map.insert(SyscallCode::KECCAK_FINALIZE, Arc::new(Keccak256SpongeSyscall));
+
     syscall_map.insert(
         SyscallCode::SECP256K1_ADD,
         Arc::new(WeierstrassAddAssignSyscall::<Secp256k1>::new()),
@@ -112,6 +154,8 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDecompressSyscall::<Secp256k1>::new()),
     );
 
+    syscall_map.insert(SyscallCode::SECP256K1_ECRECOVER, Arc::new(Secp256k1EcRecoverSyscall));
+
     syscall_map.insert(
         SyscallCode::SECP256R1_ADD,
         Arc::new(WeierstrassAddAssignSyscall::<Secp256r1>::new()),
@@ -135,6 +179,20 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDoubleAssignSyscall::<Bn254>::new()),
     );
 
+    syscall_map.insert(
+        SyscallCode::BN254_DECOMPRESS,
+        Arc::new(WeierstrassDecompressSyscall::<Bn254>::new()),
+    );
+
+    syscall_map.insert(SyscallCode::BN254_G2_DECOMPRESS, Arc::new(Bn254G2DecompressSyscall));
+
+    syscall_map.insert(SyscallCode::JUBJUB_ADD, Arc::new(EdwardsAddAssignSyscall::<Jubjub>::new()));
+
+    syscall_map.insert(
+        SyscallCode::BABY_JUBJUB_ADD,
+        Arc::new(EdwardsAddAssignSyscall::<BabyJubjub>::new()),
+    );
+
     syscall_map.insert(
         SyscallCode::BLS12381_ADD,
         Arc::new(WeierstrassAddAssignSyscall::<Bls12381>::new()),
@@ -147,10 +205,68 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
 
     syscall_map.insert(SyscallCode::UINT256_MUL, Arc::new(Uint256MulSyscall));
 
-    syscall_map.insert(SyscallCode::BN254_MULADD, Arc::new(Bn254MulAddSyscall));
+    syscall_map.insert(SyscallCode::BN254_MULADD, Arc::new(Bn254MulAddSyscall::new(false)));
+
+    syscall_map.insert(SyscallCode::BN254_MULADD_BE, Arc::new(Bn254MulAddSyscall::new(true)));
+
+    syscall_map.insert(SyscallCode::BN254_SCALAR_TO_MONT, Arc::new(Bn254MontSyscall::new(true)));
+
+    syscall_map.insert(SyscallCode::BN254_SCALAR_FROM_MONT, Arc::new(Bn254MontSyscall::new(false)));
+
+    syscall_map.insert(SyscallCode::BN254_INNER_PRODUCT, Arc::new(Bn254InnerProductSyscall));
+
+    syscall_map.insert(SyscallCode::FR_NTT, Arc::new(FrNttSyscall));
+
+    syscall_map.insert(SyscallCode::RLP_SCAN, Arc::new(RlpScanSyscall));
+
+    syscall_map.insert(SyscallCode::MEMCPY_32, Arc::new(MemCopySyscall::<U8>::new()));
+
+    syscall_map.insert(SyscallCode::MEMCPY_64, Arc::new(MemCopySyscall::<U16>::new()));
+
+    syscall_map.insert(SyscallCode::CSEL256, Arc::new(Csel256Syscall));
+
+    syscall_map.insert(SyscallCode::POSEIDON_MERKLE_ROOT, Arc::new(PoseidonMerkleSyscall));
+
+    syscall_map.insert(SyscallCode::ZKTRIE_HASH_NODE, Arc::new(ZkTrieNodeSyscall));
+
+    syscall_map.insert(SyscallCode::KECCAK_MERKLE_ROOT, Arc::new(Keccak256MerkleSyscall));
+
+    syscall_map.insert(SyscallCode::WITHDRAW_ROOT, Arc::new(Keccak256WithdrawRootSyscall));
+
+    syscall_map.insert(SyscallCode::UINT384_MULADD, Arc::new(Uint384MulAddSyscall));
 
     syscall_map.insert(SyscallCode::U256XU2048_MUL, Arc::new(U256xU2048MulSyscall));
 
+    syscall_map.insert(SyscallCode::U256XU4096_MUL, Arc::new(U256xU4096MulSyscall));
+
+    syscall_map.insert(SyscallCode::RSA2048_MODEXP, Arc::new(RsaModExpSyscall::<64>));
+
+    syscall_map.insert(SyscallCode::RSA4096_MODEXP, Arc::new(RsaModExpSyscall::<128>));
+
+    syscall_map.insert(SyscallCode::BARRETT_REDUCE, Arc::new(BarrettReduceSyscall));
+
+    syscall_map.insert(SyscallCode::AES_ENC_ROUND, Arc::new(AesRoundSyscall::new(true)));
+
+    syscall_map.insert(SyscallCode::AES_ENC_LAST_ROUND, Arc::new(AesRoundSyscall::new(false)));
+
+    syscall_map.insert(SyscallCode::CHACHA20_BLOCK, Arc::new(ChaCha20BlockSyscall));
+
+    syscall_map.insert(SyscallCode::HMAC_SHA256, Arc::new(HmacSha256Syscall));
+
+    syscall_map.insert(SyscallCode::SHA256_COMPRESS_BLOCKS, Arc::new(Sha256CompressBlocksSyscall));
+
+    syscall_map.insert(SyscallCode::SHA512_COMPRESS, Arc::new(Sha512CompressSyscall));
+
+    syscall_map.insert(SyscallCode::F64_ADD, Arc::new(F64OpSyscall::new(F64Operation::Add)));
+
+    syscall_map.insert(SyscallCode::F64_MUL, Arc::new(F64OpSyscall::new(F64Operation::Mul)));
+
+    syscall_map.insert(SyscallCode::F64_DIV, Arc::new(F64OpSyscall::new(F64Operation::Div)));
+
+    syscall_map.insert(SyscallCode::HEX_DECODE, Arc::new(DecodeSyscall::new(Encoding::Hex)));
+
+    syscall_map.insert(SyscallCode::BASE64_DECODE, Arc::new(DecodeSyscall::new(Encoding::Base64)));
+
     syscall_map.insert(
         SyscallCode::BLS12381_FP_ADD,
         Arc::new(FpOpSyscall::<Bls12381BaseField>::new(FieldOperation::Add)),
@@ -179,6 +295,9 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     syscall_map
         .insert(SyscallCode::BLS12381_FP2_MUL, Arc::new(Fp2MulSyscall::<Bls12381BaseField>::new()));
 
+    syscall_map
+        .insert(SyscallCode::BLS12381_FP6_MUL, Arc::new(Fp6MulSyscall::<Bls12381BaseField>::new()));
+
     syscall_map.insert(
         SyscallCode::BN254_FP_ADD,
         Arc::new(FpOpSyscall::<Bn254BaseField>::new(FieldOperation::Add)),
@@ -207,6 +326,9 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     syscall_map
         .insert(SyscallCode::BN254_FP2_MUL, Arc::new(Fp2MulSyscall::<Bn254BaseField>::new()));
 
+    syscall_map
+        .insert(SyscallCode::BN254_FP6_MUL, Arc::new(Fp6MulSyscall::<Bn254BaseField>::new()));
+
     syscall_map.insert(SyscallCode::ENTER_UNCONSTRAINED, Arc::new(EnterUnconstrainedSyscall));
 
     syscall_map.insert(SyscallCode::EXIT_UNCONSTRAINED, Arc::new(ExitUnconstrainedSyscall));
@@ -223,6 +345,10 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
 
     syscall_map.insert(SyscallCode::HINT_READ, Arc::new(HintReadSyscall));
 
+    syscall_map.insert(SyscallCode::HINT_MAP, Arc::new(HintMapSyscall));
+
+    syscall_map.insert(SyscallCode::ZEROIZE_ON_HALT, Arc::new(ZeroizeOnHaltSyscall));
+
     syscall_map.insert(
         SyscallCode::BLS12381_DECOMPRESS,
         Arc::new(WeierstrassDecompressSyscall::<Bls12381>::new()),
@@ -0,0 +1,90 @@
+use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le};
+
+use crate::{
+    events::{aes_enc_round, AesRoundEvent, PrecompileEvent, AES_STATE_BYTES, AES_STATE_WORDS},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+pub(crate) struct AesRoundSyscall {
+    mix_columns: bool,
+}
+
+impl AesRoundSyscall {
+    pub const fn new(mix_columns: bool) -> Self {
+        Self { mix_columns }
+    }
+}
+
+impl Syscall for AesRoundSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let state_ptr = arg1;
+        if state_ptr % 4 != 0 {
+            panic!();
+        }
+        let round_key_ptr = arg2;
+        if round_key_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // Read the words for the state. We can read a slice_unsafe here because we write the new
+        // state later.
+        let state = rt.slice_unsafe(state_ptr, AES_STATE_WORDS);
+
+        // Read the round key.
+        let (round_key_memory_records, round_key) = rt.mr_slice(round_key_ptr, AES_STATE_WORDS);
+
+        let result = aes_enc_round(
+            &words_to_bytes_le::<AES_STATE_BYTES>(&state),
+            &words_to_bytes_le::<AES_STATE_BYTES>(&round_key),
+            self.mix_columns,
+        );
+        let result = bytes_to_words_le::<AES_STATE_WORDS>(&result);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the new state and keep track of the memory records.
+        let state_memory_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::AesRound(AesRoundEvent {
+            lookup_id,
+            shard,
+            clk,
+            mix_columns: self.mix_columns,
+            state_ptr,
+            state,
+            round_key_ptr,
+            round_key,
+            state_memory_records,
+            round_key_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+
+        // Both kinds of rounds are proven by the same chip, so their events are coalesced under
+        // the `AES_ENC_ROUND` syscall code.
+        let syscall_code_key = match syscall_code {
+            SyscallCode::AES_ENC_ROUND | SyscallCode::AES_ENC_LAST_ROUND => {
+                SyscallCode::AES_ENC_ROUND
+            }
+            _ => unreachable!(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code_key, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,84 @@
+use num::{BigUint, One};
+
+use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec};
+
+use crate::{
+    events::{BarrettReduceEvent, PrecompileEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// The number of words in the reduced value.
+const X_NUM_WORDS: usize = 16;
+
+/// The number of words in the modulus.
+const MODULUS_NUM_WORDS: usize = 8;
+
+pub(crate) struct BarrettReduceSyscall;
+
+impl Syscall for BarrettReduceSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let modulus_ptr = arg2;
+        if modulus_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, X_NUM_WORDS);
+
+        // Read the modulus.
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, MODULUS_NUM_WORDS);
+
+        let uint512_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+        assert!(uint256_modulus > BigUint::one(), "the modulus must be greater than one");
+
+        let result = uint512_x % uint256_modulus;
+
+        // The result fits in the low half of x, the high half is cleared.
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(64, 0u8);
+        let result = bytes_to_words_le::<16>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::BarrettReduce(BarrettReduceEvent {
+            lookup_id,
+            shard,
+            clk,
+            x_ptr,
+            x,
+            modulus_ptr,
+            modulus,
+            x_memory_records,
+            modulus_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
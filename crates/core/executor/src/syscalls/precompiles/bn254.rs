@@ -6,11 +6,23 @@ use sp1_curves::{
 use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec, WORD_SIZE};
 
 use crate::{
-    events::{Bn254MulAddEvent, PrecompileEvent},
+    events::{
+        bn254_scalar_mont_factor, bn254_swap_endianness, Bn254InnerProductEvent, Bn254MontEvent,
+        Bn254MulAddEvent, FrNttEvent, PrecompileEvent, BN254_INNER_PRODUCT_MAX_LEN, FR_NTT_MAX_LEN,
+        FR_NTT_STATE_WORDS,
+    },
     syscalls::{Syscall, SyscallCode, SyscallContext},
 };
 
-pub(crate) struct Bn254MulAddSyscall;
+pub(crate) struct Bn254MulAddSyscall {
+    big_endian: bool,
+}
+
+impl Bn254MulAddSyscall {
+    pub const fn new(big_endian: bool) -> Self {
+        Self { big_endian }
+    }
+}
 
 impl Syscall for Bn254MulAddSyscall {
     fn execute(
@@ -36,16 +48,26 @@ impl Syscall for Bn254MulAddSyscall {
         let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
 
         // Read the y value.
-        let (y_memory_records, y) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+        let mut y = vec![0; WORDS_FIELD_ELEMENT];
+        let y_memory_records = rt.mr_slice_arena(y_ptr, &mut y);
 
         // The b value is stored after the a value. We increment the pointer by the number of words.
         let b_ptr = y_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
-        let (b_memory_records, b) = rt.mr_slice(b_ptr, WORDS_FIELD_ELEMENT);
+        let mut b = vec![0; WORDS_FIELD_ELEMENT];
+        let b_memory_records = rt.mr_slice_arena(b_ptr, &mut b);
 
-        // Get the BigUint values for x, y, and the modulus.
-        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
-        let uint256_a = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
-        let uint256_b = BigUint::from_bytes_le(&words_to_bytes_le_vec(&b));
+        // Get the BigUint values for x, y, and the modulus, reversing the bytes of big-endian
+        // operands.
+        let to_le = |words: &[u32]| {
+            if self.big_endian {
+                bn254_swap_endianness(words)
+            } else {
+                words.to_vec()
+            }
+        };
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&to_le(&x)));
+        let uint256_a = BigUint::from_bytes_le(&words_to_bytes_le_vec(&to_le(&y)));
+        let uint256_b = BigUint::from_bytes_le(&words_to_bytes_le_vec(&to_le(&b)));
         let modulus = Bn254ScalarField::modulus();
 
         // Perform the multiplication and take the result modulo the modulus.
@@ -54,13 +76,13 @@ impl Syscall for Bn254MulAddSyscall {
         let mut result_bytes = result.to_bytes_le();
         result_bytes.resize(32, 0u8); // Pad the result to 32 bytes.
 
-        // Convert the result to little endian u32 words.
-        let result = bytes_to_words_le::<8>(&result_bytes);
+        // Convert the result to little endian u32 words, in the byte order of the operands.
+        let result = to_le(&bytes_to_words_le::<8>(&result_bytes));
 
         // Increment clk so that the write is not at the same cycle as the read.
         rt.clk += 1;
         // Write the result to x and keep track of the memory records.
-        let x_memory_records = rt.mw_slice(x_ptr, &result);
+        let x_memory_records = rt.mw_slice_arena(x_ptr, &result);
 
         let lookup_id = rt.syscall_lookup_id;
         let shard = rt.current_shard();
@@ -68,6 +90,7 @@ impl Syscall for Bn254MulAddSyscall {
             lookup_id,
             shard,
             clk,
+            big_endian: self.big_endian,
             x_ptr,
             x,
             y_ptr,
@@ -78,9 +101,300 @@ impl Syscall for Bn254MulAddSyscall {
             b_memory_records,
             local_mem_access: rt.postprocess(),
         });
+
+        // Both byte orders are proven by the same chip, so their events are coalesced under the
+        // `BN254_MULADD` syscall code.
+        let syscall_code_key = match syscall_code {
+            SyscallCode::BN254_MULADD | SyscallCode::BN254_MULADD_BE => SyscallCode::BN254_MULADD,
+            _ => unreachable!(),
+        };
         let sycall_event =
             rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
-        rt.add_precompile_event(syscall_code, sycall_event, event);
+        rt.add_precompile_event(syscall_code_key, sycall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Bn254InnerProductSyscall;
+
+impl Syscall for Bn254InnerProductSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let pairs_ptr = arg2;
+        if pairs_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the length of the array, which is followed by the (a, b) pairs.
+        let (len_memory_record, len) = rt.mr(pairs_ptr);
+        let len = len as usize;
+        assert!(
+            (1..=BN254_INNER_PRODUCT_MAX_LEN).contains(&len),
+            "bn254 inner product length {len} is not in 1..={BN254_INNER_PRODUCT_MAX_LEN}"
+        );
+
+        // Accumulate the products of the pairs into x, reducing after every pair like the chip.
+        let modulus = Bn254ScalarField::modulus();
+        let mut result = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let (mut a, mut b) = (Vec::with_capacity(len), Vec::with_capacity(len));
+        let (mut a_memory_records, mut b_memory_records) =
+            (Vec::with_capacity(len), Vec::with_capacity(len));
+        let pair_size = 2 * WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        for i in 0..len as u32 {
+            let a_ptr = pairs_ptr + WORD_SIZE as u32 + i * pair_size;
+            let b_ptr = a_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+            let (a_records, a_i) = rt.mr_slice(a_ptr, WORDS_FIELD_ELEMENT);
+            let (b_records, b_i) = rt.mr_slice(b_ptr, WORDS_FIELD_ELEMENT);
+
+            let uint256_a = BigUint::from_bytes_le(&words_to_bytes_le_vec(&a_i));
+            let uint256_b = BigUint::from_bytes_le(&words_to_bytes_le_vec(&b_i));
+            result = (result + (uint256_a * uint256_b) % &modulus) % &modulus;
+
+            a.push(a_i);
+            b.push(b_i);
+            a_memory_records.push(a_records);
+            b_memory_records.push(b_records);
+        }
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0u8); // Pad the result to 32 bytes.
+        let result = bytes_to_words_le::<8>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::Bn254InnerProduct(Bn254InnerProductEvent {
+            lookup_id,
+            shard,
+            clk,
+            x_ptr,
+            x,
+            pairs_ptr,
+            a,
+            b,
+            x_memory_records,
+            len_memory_record,
+            a_memory_records,
+            b_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct FrNttSyscall;
+
+impl Syscall for FrNttSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let state_ptr = arg1;
+        if state_ptr % 4 != 0 {
+            panic!();
+        }
+        let pairs_ptr = arg2;
+        if pairs_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // First read the words of the state. We can read a slice_unsafe here because we write the
+        // updated state later.
+        let state = rt.slice_unsafe(state_ptr, FR_NTT_STATE_WORDS);
+        let modulus = Bn254ScalarField::modulus();
+        let [r, w, mut r_pow, mut w_pow, mut acc_c, mut acc_y] = [0, 1, 2, 3, 4, 5].map(|i| {
+            let words = &state[i * WORDS_FIELD_ELEMENT..(i + 1) * WORDS_FIELD_ELEMENT];
+            let value = BigUint::from_bytes_le(&words_to_bytes_le_vec(words));
+            assert!(value < modulus, "fr ntt state element {i} is not reduced");
+            value
+        });
+
+        // Read the length of the array, which is followed by the (c, y) pairs.
+        let (len_memory_record, len) = rt.mr(pairs_ptr);
+        let len = len as usize;
+        assert!(
+            (1..=FR_NTT_MAX_LEN).contains(&len),
+            "fr ntt length {len} is not in 1..={FR_NTT_MAX_LEN}"
+        );
+
+        // Fold the pairs into the sums `c_i * r^i` and `y_i * w^i / (r - w^i)`, reducing after
+        // every operation like the chip.
+        let (mut c, mut y) = (Vec::with_capacity(len), Vec::with_capacity(len));
+        let (mut c_memory_records, mut y_memory_records) =
+            (Vec::with_capacity(len), Vec::with_capacity(len));
+        let pair_size = 2 * WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        for i in 0..len as u32 {
+            let c_ptr = pairs_ptr + WORD_SIZE as u32 + i * pair_size;
+            let y_ptr = c_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+            let (c_records, c_i) = rt.mr_slice(c_ptr, WORDS_FIELD_ELEMENT);
+            let (y_records, y_i) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+
+            let uint256_c = BigUint::from_bytes_le(&words_to_bytes_le_vec(&c_i));
+            let uint256_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y_i));
+            acc_c = (acc_c + (uint256_c * &r_pow) % &modulus) % &modulus;
+            let den = (&r + &modulus - &w_pow) % &modulus;
+            assert!(!den.is_zero(), "fr ntt point is in the evaluation domain");
+            let den_inv = den.modpow(&(&modulus - 2u32), &modulus);
+            let quot = ((uint256_y * &w_pow) % &modulus * den_inv) % &modulus;
+            acc_y = (acc_y + quot) % &modulus;
+            r_pow = (r_pow * &r) % &modulus;
+            w_pow = (w_pow * &w) % &modulus;
+
+            c.push(c_i);
+            y.push(y_i);
+            c_memory_records.push(c_records);
+            y_memory_records.push(y_records);
+        }
+
+        let result = [r, w, r_pow, w_pow, acc_c, acc_y]
+            .iter()
+            .flat_map(|value| {
+                let mut bytes = value.to_bytes_le();
+                bytes.resize(32, 0u8); // Pad the value to 32 bytes.
+                bytes_to_words_le::<8>(&bytes)
+            })
+            .collect::<Vec<_>>();
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the updated state and keep track of the memory records.
+        let state_memory_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::FrNtt(FrNttEvent {
+            lookup_id,
+            shard,
+            clk,
+            state_ptr,
+            state,
+            pairs_ptr,
+            c,
+            y,
+            state_memory_records,
+            len_memory_record,
+            c_memory_records,
+            y_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct Bn254MontSyscall {
+    to_mont: bool,
+}
+
+impl Bn254MontSyscall {
+    pub const fn new(to_mont: bool) -> Self {
+        Self { to_mont }
+    }
+}
+
+impl Syscall for Bn254MontSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // Read the words for the x value. We can read a slice_unsafe here because we write the
+        // converted value to x later.
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+
+        // Multiply by the Montgomery factor of the conversion, modulo the scalar field modulus.
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let result: BigUint =
+            (uint256_x * bn254_scalar_mont_factor(self.to_mont)) % Bn254ScalarField::modulus();
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0u8); // Pad the result to 32 bytes.
+
+        // Convert the result to little endian u32 words.
+        let result = bytes_to_words_le::<8>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::Bn254Mont(Bn254MontEvent {
+            lookup_id,
+            shard,
+            clk,
+            to_mont: self.to_mont,
+            x_ptr,
+            x,
+            x_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+
+        // Both conversions are proven by the same chip, so their events are coalesced under the
+        // `BN254_SCALAR_TO_MONT` syscall code.
+        let syscall_code_key = match syscall_code {
+            SyscallCode::BN254_SCALAR_TO_MONT | SyscallCode::BN254_SCALAR_FROM_MONT => {
+                SyscallCode::BN254_SCALAR_TO_MONT
+            }
+            _ => unreachable!(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code_key, syscall_event, event);
 
         None
     }
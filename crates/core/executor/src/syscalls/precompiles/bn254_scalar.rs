@@ -18,7 +18,7 @@ impl Syscall for Bn254ScalarMulAddSyscall {
         let syscall_event =
             rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, event.lookup_id);
 
-        rt.record_mut().add_precompile_event(
+        rt.add_precompile_event(
             syscall_code,
             syscall_event,
             PrecompileEvent::Bn254ScalarMulAdd(event),
@@ -0,0 +1,58 @@
+use crate::{
+    events::{chacha20_block, ChaCha20BlockEvent, PrecompileEvent, CHACHA20_STATE_WORDS},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+pub(crate) struct ChaCha20BlockSyscall;
+
+impl Syscall for ChaCha20BlockSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let state_ptr = arg1;
+        if state_ptr % 4 != 0 {
+            panic!();
+        }
+        if arg2 != 0 {
+            panic!("Expected arg2 to be 0, got {arg2}");
+        }
+
+        // Read the words for the state. We can read a slice_unsafe here because we write the new
+        // state later.
+        let state = rt.slice_unsafe(state_ptr, CHACHA20_STATE_WORDS);
+
+        let result = chacha20_block(&state.clone().try_into().unwrap());
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the new state and keep track of the memory records.
+        let state_memory_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::ChaCha20Block(ChaCha20BlockEvent {
+            lookup_id,
+            shard,
+            clk,
+            state_ptr,
+            state,
+            state_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,87 @@
+use sp1_primitives::consts::{bytes_to_words_le_vec, words_to_bytes_le};
+
+use crate::{
+    events::{DecodeEvent, Encoding, PrecompileEvent, DECODE_INPUT_BYTES, DECODE_INPUT_WORDS},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Decodes a block of 16 ASCII characters at `arg2` into the words at `arg1`.
+///
+/// If any character is outside of the alphabet of the encoding, the syscall fails and the output
+/// is left unchanged, so that the caller can report the error.
+pub(crate) struct DecodeSyscall {
+    encoding: Encoding,
+}
+
+impl DecodeSyscall {
+    pub const fn new(encoding: Encoding) -> Self {
+        Self { encoding }
+    }
+}
+
+impl Syscall for DecodeSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        let output_ptr = arg1;
+        assert!(output_ptr % 4 == 0, "output_ptr must be 4-byte aligned");
+        let input_ptr = arg2;
+        assert!(input_ptr % 4 == 0, "input_ptr must be 4-byte aligned");
+
+        let output_words = self.encoding.output_words();
+        let output = rt.slice_unsafe(output_ptr, output_words);
+        let (input_memory_records, input) = rt.mr_slice(input_ptr, DECODE_INPUT_WORDS);
+        let input: [u32; DECODE_INPUT_WORDS] = input.try_into().unwrap();
+
+        let decoded = self.encoding.decode(&words_to_bytes_le::<DECODE_INPUT_BYTES>(&input));
+        let is_invalid = decoded.is_none();
+        let result = match decoded {
+            Some(bytes) => bytes_to_words_le_vec(&bytes),
+            None => output,
+        };
+        debug_assert_eq!(result.len(), output_words);
+
+        // Write the output at the next cycle, since the input and output may overlap.
+        rt.clk += 1;
+        let output_memory_records = rt.mw_slice(output_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = DecodeEvent {
+            lookup_id,
+            shard,
+            clk,
+            encoding: self.encoding,
+            output_ptr,
+            input_ptr,
+            input,
+            is_invalid,
+            output_memory_records,
+            input_memory_records: input_memory_records.try_into().unwrap(),
+            local_mem_access: rt.postprocess(),
+        };
+        let status = u32::from(is_invalid);
+        let syscall_event = rt.rt.syscall_event(
+            clk,
+            syscall_code.status_syscall_id(status),
+            arg1,
+            arg2,
+            event.lookup_id,
+        );
+        let event = match self.encoding {
+            Encoding::Hex => PrecompileEvent::HexDecode(event),
+            Encoding::Base64 => PrecompileEvent::Base64Decode(event),
+        };
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+        Some(status)
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    events::{Csel256Event, PrecompileEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+    Register::X12,
+};
+
+/// The number of words of the selected values.
+const NUM_WORDS: usize = 8;
+
+pub(crate) struct Csel256Syscall;
+
+impl Syscall for Csel256Syscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let a_ptr = arg1;
+        if a_ptr % 4 != 0 {
+            panic!();
+        }
+        let b_ptr = arg2;
+        if b_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // The condition is passed in the third argument register.
+        let (cond_memory, cond) = rt.mr(X12 as u32);
+        assert!(cond <= 1, "CSEL256 condition must be 0 or 1, got {cond}");
+
+        // Read a with slice_unsafe, since the selected value is written over it later.
+        let a = rt.slice_unsafe(a_ptr, NUM_WORDS);
+        let (b_memory_records, b) = rt.mr_slice(b_ptr, NUM_WORDS);
+
+        let result = if cond == 1 { a.clone() } else { b.clone() };
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let a_memory_records = rt.mw_slice(a_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::Csel256(Csel256Event {
+            lookup_id,
+            shard,
+            clk,
+            a_ptr,
+            a,
+            b_ptr,
+            b,
+            cond,
+            cond_memory,
+            a_memory_records,
+            b_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
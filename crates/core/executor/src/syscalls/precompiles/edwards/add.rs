@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use sp1_curves::{edwards::EdwardsParameters, EllipticCurve};
+use sp1_curves::{edwards::EdwardsParameters, CurveType, EllipticCurve};
 
 use crate::{
     events::{create_ec_add_event, PrecompileEvent},
@@ -33,7 +33,24 @@ impl<E: EllipticCurve + EdwardsParameters> Syscall for EdwardsAddAssignSyscall<E
         let event = create_ec_add_event::<E>(rt, arg1, arg2);
         let syscall_event =
             rt.rt.syscall_event(event.clk, syscall_code.syscall_id(), arg1, arg2, event.lookup_id);
-        rt.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::EdAdd(event));
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => {
+                rt.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::EdAdd(event));
+            }
+            CurveType::Jubjub => {
+                rt.add_precompile_event(
+                    syscall_code,
+                    syscall_event,
+                    PrecompileEvent::JubjubAdd(event),
+                );
+            }
+            CurveType::BabyJubjub => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::BabyJubjubAdd(event),
+            ),
+            _ => panic!("Unsupported curve"),
+        }
         None
     }
 }
@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use sp1_curves::{
     curve25519_dalek::CompressedEdwardsY,
-    edwards::{ed25519::decompress, EdwardsParameters, WORDS_FIELD_ELEMENT},
+    edwards::{ed25519::try_decompress, EdwardsParameters, WORDS_FIELD_ELEMENT},
     COMPRESSED_POINT_BYTES,
 };
 use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le};
@@ -51,14 +51,21 @@ impl<E: EdwardsParameters> Syscall for EdwardsDecompressSyscall<E> {
         compressed_edwards_y[compressed_edwards_y.len() - 1] &= 0b0111_1111;
         compressed_edwards_y[compressed_edwards_y.len() - 1] |= (sign as u8) << 7;
 
-        // Compute actual decompressed X
+        // Compute actual decompressed X. If there is no point with this Y, the syscall fails and
+        // X is left as it was.
         let compressed_y = CompressedEdwardsY(compressed_edwards_y);
-        let decompressed = decompress(&compressed_y);
+        let decompressed = try_decompress(&compressed_y);
+        let is_invalid = decompressed.is_none();
 
-        let mut decompressed_x_bytes = decompressed.x.to_bytes_le();
-        decompressed_x_bytes.resize(32, 0u8);
-        let decompressed_x_words: [u32; WORDS_FIELD_ELEMENT] =
-            bytes_to_words_le(&decompressed_x_bytes);
+        let decompressed_x_words: [u32; WORDS_FIELD_ELEMENT] = match decompressed {
+            Some(decompressed) => {
+                let mut decompressed_x_bytes = decompressed.x.to_bytes_le();
+                decompressed_x_bytes.resize(32, 0u8);
+                bytes_to_words_le(&decompressed_x_bytes)
+            }
+            None => rt.slice_unsafe(slice_ptr, WORDS_FIELD_ELEMENT).try_into().unwrap(),
+        };
+        let decompressed_x_bytes = words_to_bytes_le(&decompressed_x_words);
 
         // Write decompressed X into slice
         let x_memory_records_vec = rt.mw_slice(slice_ptr, &decompressed_x_words);
@@ -73,15 +80,22 @@ impl<E: EdwardsParameters> Syscall for EdwardsDecompressSyscall<E> {
             ptr: slice_ptr,
             sign: sign_bool,
             y_bytes,
-            decompressed_x_bytes: decompressed_x_bytes.try_into().unwrap(),
+            decompressed_x_bytes,
+            is_invalid,
             x_memory_records,
             y_memory_records,
             local_mem_access: rt.postprocess(),
         };
-        let syscall_event =
-            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, sign, event.lookup_id);
+        let status = u32::from(is_invalid);
+        let syscall_event = rt.rt.syscall_event(
+            start_clk,
+            syscall_code.status_syscall_id(status),
+            arg1,
+            sign,
+            event.lookup_id,
+        );
         rt.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::EdDecompress(event));
-        None
+        Some(status)
     }
 
     fn num_extra_cycles(&self) -> u32 {
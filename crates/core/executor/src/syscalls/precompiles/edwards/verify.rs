@@ -0,0 +1,87 @@
+use num::BigUint;
+use sp1_curves::{
+    edwards::{ed25519::Ed25519Parameters, EdwardsParameters},
+    AffinePoint,
+};
+
+use crate::{
+    events::{
+        ed25519_compress, ed25519_reduce_digest, ed25519_verify_point, Ed25519VerifyEvent,
+        PrecompileEvent, ED25519_KEY_WORDS, ED25519_SIGNATURE_DIGEST_OFFSET,
+        ED25519_SIGNATURE_S_OFFSET, ED25519_SIGNATURE_WORDS,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Checks the verification equation of an Ed25519 signature.
+///
+/// The syscall takes a pointer to the public key, given by its affine coordinates as decompressed
+/// by `ED_DECOMPRESS`, and a pointer to the signature `R || S`, followed by the SHA-512 digest of
+/// `R`, the compressed public key and the message. The status is 0 if `[S]B - [k]A` compresses to
+/// `R`, where `k` is the digest reduced modulo the order of the prime subgroup, and 1 otherwise.
+///
+/// `S` must be smaller than the order of the prime subgroup.
+pub(crate) struct Ed25519VerifySyscall;
+
+impl Syscall for Ed25519VerifySyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let key_ptr = arg1;
+        let sig_ptr = arg2;
+        assert!(key_ptr % 4 == 0 && sig_ptr % 4 == 0, "pointers must be word aligned");
+
+        let (key_read_records, key) = rt.mr_slice(key_ptr, ED25519_KEY_WORDS);
+        let (sig_read_records, sig) = rt.mr_slice(sig_ptr, ED25519_SIGNATURE_WORDS);
+        let s =
+            BigUint::from_slice(&sig[ED25519_SIGNATURE_S_OFFSET..ED25519_SIGNATURE_DIGEST_OFFSET]);
+        assert!(
+            s < Ed25519Parameters::prime_group_order(),
+            "the scalar of the signature must be reduced"
+        );
+
+        let key_point = AffinePoint::from_words_le(&key);
+        let digest = sig[ED25519_SIGNATURE_DIGEST_OFFSET..]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        let k = ed25519_reduce_digest(&digest);
+        let r = sig[..ED25519_SIGNATURE_S_OFFSET]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        let is_valid = ed25519_compress(&ed25519_verify_point(&key_point, &s, &k))[..] == r[..];
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = Ed25519VerifyEvent {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            key_ptr,
+            sig_ptr,
+            key,
+            sig,
+            is_valid,
+            key_read_records,
+            sig_read_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let status = u32::from(!is_valid);
+        let syscall_event = rt.rt.syscall_event(
+            start_clk,
+            syscall_code.status_syscall_id(status),
+            arg1,
+            arg2,
+            lookup_id,
+        );
+        rt.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::Ed25519Verify(event));
+
+        Some(status)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    events::{F64OpEvent, F64Operation, PrecompileEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Computes `x = x op y` for two IEEE-754 double precision floats.
+///
+/// Only normal operands with a normal result are supported. Otherwise, the syscall fails and `x`
+/// is left unchanged, so that the caller falls back to software floating point.
+pub(crate) struct F64OpSyscall {
+    op: F64Operation,
+}
+
+impl F64OpSyscall {
+    pub const fn new(op: F64Operation) -> Self {
+        Self { op }
+    }
+}
+
+impl Syscall for F64OpSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        let x_ptr = arg1;
+        assert!(x_ptr % 4 == 0, "x_ptr must be 4-byte aligned");
+        let y_ptr = arg2;
+        assert!(y_ptr % 4 == 0, "y_ptr must be 4-byte aligned");
+
+        let x: [u32; 2] = rt.slice_unsafe(x_ptr, 2).try_into().unwrap();
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, 2);
+        let y: [u32; 2] = y.try_into().unwrap();
+
+        let a = f64::from_bits(u64::from(x[0]) | (u64::from(x[1]) << 32));
+        let b = f64::from_bits(u64::from(y[0]) | (u64::from(y[1]) << 32));
+        let result = self.op.apply(a, b);
+        let is_fallback = result.is_none();
+        let result = match result {
+            Some(result) => {
+                let bits = result.to_bits();
+                [bits as u32, (bits >> 32) as u32]
+            }
+            None => x,
+        };
+
+        // Write the result at the next cycle, since x and y may be the same.
+        rt.clk += 1;
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = F64OpEvent {
+            lookup_id,
+            shard,
+            clk,
+            op: self.op,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            is_fallback,
+            x_memory_records: x_memory_records.try_into().unwrap(),
+            y_memory_records: y_memory_records.try_into().unwrap(),
+            local_mem_access: rt.postprocess(),
+        };
+        let status = u32::from(is_fallback);
+        let syscall_event = rt.rt.syscall_event(
+            clk,
+            syscall_code.status_syscall_id(status),
+            arg1,
+            arg2,
+            event.lookup_id,
+        );
+        rt.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::F64Op(event));
+        Some(status)
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,143 @@
+use std::marker::PhantomData;
+
+use num::BigUint;
+use sp1_curves::{
+    params::NumWords,
+    weierstrass::{FieldType, FpOpField},
+};
+use typenum::Unsigned;
+
+use crate::{
+    events::{Fp6MulEvent, PrecompileEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Multiplies two elements of the degree 6 field `Fp2[v] / (v^3 - (c + u))` of a pairing tower,
+/// where `c` is given by [`FieldType::fp6_non_residue`].
+///
+/// Each operand is three degree 2 field elements `c0 + c1 * v + c2 * v^2`, each of which is two
+/// base field elements in the same layout as for the `FP2_MUL` syscalls.
+pub struct Fp6MulSyscall<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P> Fp6MulSyscall<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+/// Multiplies two degree 2 field elements.
+fn fp2_mul(a: &[BigUint; 2], b: &[BigUint; 2], modulus: &BigUint) -> [BigUint; 2] {
+    [
+        ((&a[0] * &b[0]) % modulus + modulus - (&a[1] * &b[1]) % modulus) % modulus,
+        (&a[0] * &b[1] + &a[1] * &b[0]) % modulus,
+    ]
+}
+
+/// Adds two degree 2 field elements.
+fn fp2_add(a: &[BigUint; 2], b: &[BigUint; 2], modulus: &BigUint) -> [BigUint; 2] {
+    [(&a[0] + &b[0]) % modulus, (&a[1] + &b[1]) % modulus]
+}
+
+/// Multiplies a degree 2 field element by the cubic non-residue `c + u`.
+fn fp2_mul_by_non_residue(a: &[BigUint; 2], c: u32, modulus: &BigUint) -> [BigUint; 2] {
+    let a0 = &a[0] % modulus;
+    let a1 = &a[1] % modulus;
+    [(&a0 * c + modulus - &a1) % modulus, (a0 + a1 * c) % modulus]
+}
+
+/// Multiplies two degree 6 field elements given by their degree 2 coefficients.
+fn fp6_mul<P: FpOpField>(a: &[[BigUint; 2]; 3], b: &[[BigUint; 2]; 3]) -> [[BigUint; 2]; 3] {
+    let modulus = &P::modulus();
+    let c = P::FIELD_TYPE.fp6_non_residue();
+    let mul = |i: usize, j: usize| fp2_mul(&a[i], &b[j], modulus);
+    let c0 = fp2_add(
+        &mul(0, 0),
+        &fp2_mul_by_non_residue(&fp2_add(&mul(1, 2), &mul(2, 1), modulus), c, modulus),
+        modulus,
+    );
+    let c1 = fp2_add(
+        &fp2_add(&mul(0, 1), &mul(1, 0), modulus),
+        &fp2_mul_by_non_residue(&mul(2, 2), c, modulus),
+        modulus,
+    );
+    let c2 = fp2_add(&fp2_add(&mul(0, 2), &mul(1, 1), modulus), &mul(2, 0), modulus);
+    [c0, c1, c2]
+}
+
+impl<P: FpOpField> Syscall for Fp6MulSyscall<P> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if y_ptr % 4 != 0 {
+            panic!();
+        }
+
+        let num_words_fp = <P as NumWords>::WordsFieldElement::USIZE;
+        let num_words = 6 * num_words_fp;
+
+        let x = rt.slice_unsafe(x_ptr, num_words);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, num_words);
+        rt.clk += 1;
+
+        // Splits the words of a degree 6 field element into its degree 2 coefficients.
+        let fp6_from_words = |words: &[u32]| -> [[BigUint; 2]; 3] {
+            let mut coeffs = words.chunks_exact(num_words_fp).map(BigUint::from_slice);
+            core::array::from_fn(|_| core::array::from_fn(|_| coeffs.next().unwrap()))
+        };
+        let c = fp6_mul::<P>(&fp6_from_words(&x), &fp6_from_words(&y));
+        let mut result = Vec::with_capacity(num_words);
+        for coeff in c.iter().flatten() {
+            let mut words = coeff.to_u32_digits();
+            words.resize(num_words_fp, 0);
+            result.extend(words);
+        }
+
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = Fp6MulEvent {
+            lookup_id,
+            shard,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, event.lookup_id);
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::Bn254Fp6Mul(event),
+            ),
+            FieldType::Bls12381 => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::Bls12381Fp6Mul(event),
+            ),
+        };
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -1,7 +1,9 @@
 mod fp;
 mod fp2_addsub;
 mod fp2_mul;
+mod fp6_mul;
 
 pub use fp::*;
 pub use fp2_addsub::*;
 pub use fp2_mul::*;
+pub use fp6_mul::*;
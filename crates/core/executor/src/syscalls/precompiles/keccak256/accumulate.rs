@@ -0,0 +1,111 @@
+use crate::{
+    events::{KeccakAccumulateEvent, PrecompileEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+use tiny_keccak::keccakf;
+
+use super::permute::STATE_SIZE;
+
+/// The number of 32-bit words absorbed per call, i.e. the keccak-256 rate of 136 bytes.
+pub const RATE_NUM_WORDS: usize = 34;
+
+/// The number of 32-bit words in the digest written on the final block.
+pub const DIGEST_NUM_WORDS: usize = 8;
+
+/// Flag set in the control word on the first block absorbed by an accumulator.
+pub const KECCAK_ACCUMULATE_FIRST: u32 = 1;
+
+/// Flag set in the control word on the last block absorbed by an accumulator.
+pub const KECCAK_ACCUMULATE_FINAL: u32 = 2;
+
+pub(crate) struct Keccak256AccumulateSyscall;
+
+impl Syscall for Keccak256AccumulateSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let block_ptr = arg1;
+        let acc_ptr = arg2;
+
+        let (flags_read_record, flags) = rt.mr(acc_ptr);
+        if flags & !(KECCAK_ACCUMULATE_FIRST | KECCAK_ACCUMULATE_FINAL) != 0 {
+            panic!("invalid keccak accumulator flags {flags:#x}");
+        }
+        let is_first = flags & KECCAK_ACCUMULATE_FIRST != 0;
+        let is_final = flags & KECCAK_ACCUMULATE_FINAL != 0;
+
+        let (block_read_records, block) = rt.mr_slice(block_ptr, RATE_NUM_WORDS);
+
+        // The sponge state is not kept in guest memory, so look up the state left behind by the
+        // previous block absorbed into this accumulator.
+        let shard = rt.current_shard();
+        let accumulators = &mut rt.rt.state.keccak_accumulators;
+        let (acc_shard, acc_clk, index, pre_state) = if is_first {
+            if accumulators.contains_key(&acc_ptr) {
+                panic!("keccak accumulator at {acc_ptr:#x} was restarted before being finalized");
+            }
+            (shard, start_clk, 0, [0u64; STATE_SIZE])
+        } else {
+            accumulators.remove(&acc_ptr).unwrap_or_else(|| {
+                panic!("keccak accumulator at {acc_ptr:#x} was used before being started")
+            })
+        };
+
+        let mut state = pre_state;
+        for (i, words) in block.chunks_exact(2).enumerate() {
+            state[i] ^= words[0] as u64 + ((words[1] as u64) << 32);
+        }
+        keccakf(&mut state);
+
+        // Increment the clk by 1 before writing because we read from memory at start_clk.
+        rt.clk += 1;
+        let digest_write_records = if is_final {
+            let mut digest = Vec::with_capacity(DIGEST_NUM_WORDS);
+            for lane in state.iter().take(DIGEST_NUM_WORDS / 2) {
+                digest.push((lane & 0xFFFFFFFF) as u32);
+                digest.push((lane >> 32) as u32);
+            }
+            rt.mw_slice(acc_ptr + 4, &digest)
+        } else {
+            rt.rt.state.keccak_accumulators.insert(acc_ptr, (acc_shard, acc_clk, index + 1, state));
+            Vec::new()
+        };
+
+        // Push the Keccak accumulate event.
+        let lookup_id = rt.syscall_lookup_id;
+        let event = PrecompileEvent::KeccakAccumulate(KeccakAccumulateEvent {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            block_ptr,
+            acc_ptr,
+            acc_shard,
+            acc_clk,
+            index,
+            is_first,
+            is_final,
+            pre_state,
+            block,
+            post_state: state,
+            flags_read_record,
+            block_read_records,
+            digest_write_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
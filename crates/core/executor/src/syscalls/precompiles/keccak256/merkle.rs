@@ -0,0 +1,105 @@
+use crate::{
+    events::{
+        keccak_merkle_state, KeccakMerkleEvent, PrecompileEvent, KECCAK_MERKLE_MAX_DEPTH,
+        KECCAK_MERKLE_STEP_CYCLES,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+use super::permute::STATE_NUM_WORDS;
+
+/// The number of words of a node.
+const NODE_NUM_WORDS: usize = 8;
+
+/// Computes the root of a keccak-256 Merkle path.
+///
+/// The syscall takes a pointer to a keccak state whose first 32 bytes hold the node, and a pointer
+/// to the path, made of the depth, the index of the node and the sibling of every level. Each level
+/// hashes the node with its sibling by writing the padded children to the state and permuting it
+/// with a `KECCAK_PERMUTE` call, so the root is left in the first 32 bytes of the state.
+pub(crate) struct Keccak256MerkleSyscall;
+
+impl Syscall for Keccak256MerkleSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        KECCAK_MERKLE_MAX_DEPTH as u32 * KECCAK_MERKLE_STEP_CYCLES
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        let path_ptr = arg2;
+        assert!(state_ptr % 4 == 0 && path_ptr % 4 == 0, "pointers must be word aligned");
+
+        let (depth_memory_record, depth) = rt.mr(path_ptr);
+        assert!(
+            (1..=KECCAK_MERKLE_MAX_DEPTH as u32).contains(&depth),
+            "keccak merkle path depth {depth} is not in 1..={KECCAK_MERKLE_MAX_DEPTH}"
+        );
+        let (index_memory_record, index) = rt.mr(path_ptr + 4);
+        assert!(
+            index >> KECCAK_MERKLE_MAX_DEPTH == 0,
+            "keccak merkle path index {index} has more than {KECCAK_MERKLE_MAX_DEPTH} bits"
+        );
+        let path_len = 2 + depth * NODE_NUM_WORDS as u32;
+        assert!(
+            state_ptr + STATE_NUM_WORDS as u32 * 4 <= path_ptr
+                || path_ptr + path_len * 4 <= state_ptr,
+            "the path overlaps the keccak state"
+        );
+
+        let mut sibling_read_records = Vec::with_capacity(depth as usize);
+        let mut state_write_records = Vec::with_capacity(depth as usize);
+        let mut permute_syscalls = Vec::with_capacity(depth as usize);
+        let mut local_mem_access = Vec::new();
+        for i in 0..depth {
+            // Write the node and its sibling, in the order given by the index, to the state.
+            let sibling_ptr = path_ptr + 8 + i * NODE_NUM_WORDS as u32 * 4;
+            let (records, sibling) = rt.mr_slice(sibling_ptr, NODE_NUM_WORDS);
+            let node = rt.slice_unsafe(state_ptr, NODE_NUM_WORDS);
+            let state = if (index >> i) & 1 == 0 {
+                keccak_merkle_state(&node, &sibling)
+            } else {
+                keccak_merkle_state(&sibling, &node)
+            };
+            sibling_read_records.push(records);
+            state_write_records.push(rt.mw_slice(state_ptr, &state));
+
+            // The permutation accesses the state in its own event, so the accesses of this level
+            // are split off before making it.
+            local_mem_access.extend(rt.postprocess());
+            rt.clk += 1;
+            permute_syscalls.push(rt.execute_nested(SyscallCode::KECCAK_PERMUTE, state_ptr, 0));
+            rt.clk += 1;
+        }
+
+        // Push the Keccak Merkle event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::KeccakMerkle(KeccakMerkleEvent {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            state_ptr,
+            path_ptr,
+            depth,
+            index,
+            depth_memory_record,
+            index_memory_record,
+            sibling_read_records,
+            state_write_records,
+            permute_syscalls,
+            local_mem_access,
+        });
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
@@ -1 +1,5 @@
+pub mod accumulate;
+pub mod merkle;
 pub mod permute;
+pub mod sponge;
+pub mod withdraw_root;
@@ -0,0 +1,87 @@
+use crate::{
+    events::{
+        keccak_pad_block, KeccakSpongeEvent, PrecompileEvent, KECCAK_SPONGE_RATE_BYTES,
+        KECCAK_SPONGE_RATE_WORDS,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+use super::permute::STATE_NUM_WORDS;
+
+/// Absorbs a block into a keccak-256 sponge kept in guest memory.
+///
+/// `KECCAK_ABSORB` takes a pointer to the sponge state and a pointer to a full block.
+/// `KECCAK_FINALIZE` takes a pointer to the sponge state and a pointer to a block holding the tail
+/// of the input, followed by a word with the length of the tail in bytes, and pads the tail before
+/// absorbing it. The digest is then the first 32 bytes of the state.
+pub(crate) struct Keccak256SpongeSyscall;
+
+impl Syscall for Keccak256SpongeSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        2
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        let block_ptr = arg2;
+        let is_final = syscall_code == SyscallCode::KECCAK_FINALIZE;
+        let block_len = KECCAK_SPONGE_RATE_WORDS as u32 + is_final as u32;
+        assert!(state_ptr % 4 == 0 && block_ptr % 4 == 0, "pointers must be word aligned");
+        assert!(
+            state_ptr + STATE_NUM_WORDS as u32 * 4 <= block_ptr
+                || block_ptr + block_len * 4 <= state_ptr,
+            "the block overlaps the sponge state"
+        );
+
+        let (block_read_records, block) = rt.mr_slice(block_ptr, KECCAK_SPONGE_RATE_WORDS);
+        let (len_read_record, block) = if is_final {
+            let (record, len) = rt.mr(block_ptr + KECCAK_SPONGE_RATE_BYTES as u32);
+            if len as usize >= KECCAK_SPONGE_RATE_BYTES {
+                panic!("keccak tail of {len} bytes does not fit in a block");
+            }
+            (Some(record), keccak_pad_block(&block, len as usize))
+        } else {
+            (None, block)
+        };
+
+        // Absorb the block into the rate of the state.
+        let rate = rt.slice_unsafe(state_ptr, KECCAK_SPONGE_RATE_WORDS);
+        let absorbed = rate.iter().zip(block.iter()).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+        let state_write_records = rt.mw_slice(state_ptr, &absorbed);
+
+        // The permutation accesses the state in its own event, so the accesses of the absorption
+        // are split off before making it.
+        let local_mem_access = rt.postprocess();
+        rt.clk += 1;
+        let permute_syscall = rt.execute_nested(SyscallCode::KECCAK_PERMUTE, state_ptr, 0);
+
+        // Push the Keccak sponge event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::KeccakSponge(KeccakSpongeEvent {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            state_ptr,
+            block_ptr,
+            is_final,
+            block_read_records,
+            len_read_record,
+            state_write_records,
+            permute_syscall,
+            local_mem_access,
+        });
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(SyscallCode::KECCAK_ABSORB, syscall_event, event);
+
+        None
+    }
+}
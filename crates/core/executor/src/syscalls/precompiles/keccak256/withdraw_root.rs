@@ -0,0 +1,115 @@
+use crate::{
+    events::{
+        keccak_merkle_state, withdraw_root_is_hash, withdraw_root_node, PrecompileEvent,
+        WithdrawRootEvent, WITHDRAW_ROOT_HASHES, WITHDRAW_ROOT_MAX_DEPTH,
+        WITHDRAW_ROOT_STEP_CYCLES,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+use super::permute::STATE_NUM_WORDS;
+
+/// The number of words of a node.
+const NODE_NUM_WORDS: usize = 8;
+
+/// Computes the root of a balanced keccak-256 Merkle tree over its leaves.
+///
+/// The syscall takes a pointer to a keccak state, and a pointer to the depth of the tree followed
+/// by its `2^depth` leaves. The nodes are computed level by level, in place: each node is hashed by
+/// writing its padded children to the state and permuting it with a `KECCAK_PERMUTE` call, and the
+/// hash is copied over the leaf of the same index as the node within its level. The root is thus
+/// left in the first leaf, as well as in the first 32 bytes of the state.
+pub(crate) struct Keccak256WithdrawRootSyscall;
+
+impl Syscall for Keccak256WithdrawRootSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        WITHDRAW_ROOT_HASHES as u32 * WITHDRAW_ROOT_STEP_CYCLES
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        let tree_ptr = arg2;
+        assert!(state_ptr % 4 == 0 && tree_ptr % 4 == 0, "pointers must be word aligned");
+
+        let (depth_memory_record, depth) = rt.mr(tree_ptr);
+        assert!(
+            (1..=WITHDRAW_ROOT_MAX_DEPTH as u32).contains(&depth),
+            "withdrawal tree depth {depth} is not in 1..={WITHDRAW_ROOT_MAX_DEPTH}"
+        );
+        let tree_len = 1 + (NODE_NUM_WORDS << depth) as u32;
+        assert!(
+            state_ptr + STATE_NUM_WORDS as u32 * 4 <= tree_ptr
+                || tree_ptr + tree_len * 4 <= state_ptr,
+            "the tree overlaps the keccak state"
+        );
+
+        let num_hashes = (1 << depth) - 1;
+        let mut children_read_records = Vec::with_capacity(num_hashes);
+        let mut state_write_records = Vec::with_capacity(num_hashes);
+        let mut hash_read_records = Vec::with_capacity(num_hashes);
+        let mut node_write_records = Vec::with_capacity(num_hashes);
+        let mut permute_syscalls = Vec::with_capacity(num_hashes);
+        let mut local_mem_access = Vec::new();
+        for step in 0..WITHDRAW_ROOT_HASHES {
+            if !withdraw_root_is_hash(step, depth as usize) {
+                continue;
+            }
+            // Every step has its own cycles, whether it hashes a node or not.
+            rt.clk = start_clk + step as u32 * WITHDRAW_ROOT_STEP_CYCLES;
+            let (_, index) = withdraw_root_node(step);
+            let node_ptr = tree_ptr + 4 + (index * NODE_NUM_WORDS * 4) as u32;
+            let children_ptr = tree_ptr + 4 + (2 * index * NODE_NUM_WORDS * 4) as u32;
+
+            // Write the children to the state.
+            let (records, children) = rt.mr_slice(children_ptr, 2 * NODE_NUM_WORDS);
+            let state =
+                keccak_merkle_state(&children[..NODE_NUM_WORDS], &children[NODE_NUM_WORDS..]);
+            children_read_records.push(records);
+            state_write_records.push(rt.mw_slice(state_ptr, &state));
+
+            // The permutation accesses the state in its own event, so the accesses of this step
+            // are split off before making it.
+            local_mem_access.extend(rt.postprocess());
+            rt.clk += 1;
+            permute_syscalls.push(rt.execute_nested(SyscallCode::KECCAK_PERMUTE, state_ptr, 0));
+            rt.clk += 1;
+
+            // Copy the hash to the node.
+            let (records, hash) = rt.mr_slice(state_ptr, NODE_NUM_WORDS);
+            hash_read_records.push(records);
+            node_write_records.push(rt.mw_slice(node_ptr, &hash));
+        }
+        local_mem_access.extend(rt.postprocess());
+
+        // Push the withdrawal root event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::WithdrawRoot(WithdrawRootEvent {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            state_ptr,
+            tree_ptr,
+            depth,
+            depth_memory_record,
+            children_read_records,
+            state_write_records,
+            hash_read_records,
+            node_write_records,
+            permute_syscalls,
+            local_mem_access,
+        });
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
@@ -1,25 +1,27 @@
 use std::marker::PhantomData;
 
-use generic_array::ArrayLength;
+use typenum::Unsigned;
 
 use crate::{
-    events::{MemCopyEvent, PrecompileEvent},
+    events::{LookupId, MemCopyEvent, PrecompileEvent},
     syscalls::{Syscall, SyscallCode, SyscallContext},
+    ExecutorMode,
 };
 
-pub struct MemCopySyscall<NumWords: ArrayLength, NumBytes: ArrayLength> {
-    _marker: PhantomData<(NumWords, NumBytes)>,
+/// The number of words copied by the largest memory copy precompile, `MEMCPY_64`.
+const MAX_WORDS: usize = 16;
+
+pub(crate) struct MemCopySyscall<NumWords: Unsigned> {
+    _marker: PhantomData<NumWords>,
 }
 
-impl<NumWords: ArrayLength, NumBytes: ArrayLength> MemCopySyscall<NumWords, NumBytes> {
+impl<NumWords: Unsigned> MemCopySyscall<NumWords> {
     pub const fn new() -> Self {
         Self { _marker: PhantomData }
     }
 }
 
-impl<NumWords: ArrayLength + Send + Sync, NumBytes: ArrayLength + Send + Sync> Syscall
-    for MemCopySyscall<NumWords, NumBytes>
-{
+impl<NumWords: Unsigned + Send + Sync> Syscall for MemCopySyscall<NumWords> {
     fn execute(
         &self,
         rt: &mut SyscallContext,
@@ -27,38 +29,62 @@ impl<NumWords: ArrayLength + Send + Sync, NumBytes: ArrayLength + Send + Sync> S
         src: u32,
         dst: u32,
     ) -> Option<u32> {
+        if src % 4 != 0 || dst % 4 != 0 {
+            panic!("memcopy pointers must be word aligned");
+        }
+
+        // The copied words are kept on the stack, and the memory records in the arenas of the
+        // record, since the precompile is called for every chunk of the large copies.
         let start_clk = rt.clk;
-        let (read, read_bytes) = rt.mr_slice(src, NumWords::USIZE);
+        let mut words = [0u32; MAX_WORDS];
+        let words = &mut words[..NumWords::USIZE];
+        let read_records = rt.mr_slice_arena(src, words);
 
         // dst == src is supported, even it is actually a no-op.
         rt.clk += 1;
 
-        let write = rt.mw_slice(dst, &read_bytes);
+        let write_records = rt.mw_slice_arena(dst, words);
 
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
         let event = MemCopyEvent {
-            lookup_id: rt.syscall_lookup_id,
-            shard: rt.current_shard(),
+            lookup_id,
+            shard,
             clk: start_clk,
             src_ptr: src,
             dst_ptr: dst,
-            read_records: read,
-            write_records: write,
+            read_records,
+            write_records,
+            coalesced_syscall: None,
             local_mem_access: rt.postprocess(),
         };
-        let precompile_event = match NumWords::USIZE {
-            8 => PrecompileEvent::MemCopy32(event),
-            16 => PrecompileEvent::MemCopy64(event),
-            _ => panic!("invalid uszie {}", NumWords::USIZE),
-        };
-        let syscall_event = rt.rt.syscall_event(
-            start_clk,
-            syscall_code.syscall_id(),
-            src,
-            dst,
-            rt.syscall_lookup_id,
-        );
 
-        rt.record_mut().add_precompile_event(syscall_code, syscall_event, precompile_event);
+        // A `MEMCPY_32` call continuing the previous one is coalesced with it. Calls in
+        // unconstrained mode are pruned from the record, so there is nothing to coalesce.
+        let pending = rt.rt.state.pending_memcpy.take();
+        if syscall_code == SyscallCode::MEMCPY_32 && !rt.rt.unconstrained {
+            let chunk_bytes = NumWords::U32 * 4;
+            match pending {
+                Some((pending_shard, pending_src, pending_dst, pending_lookup_id))
+                    if pending_shard == shard
+                        && pending_src.wrapping_add(chunk_bytes) == src
+                        && pending_dst.wrapping_add(chunk_bytes) == dst =>
+                {
+                    coalesce(rt, pending_lookup_id, event);
+                    return None;
+                }
+                _ => rt.rt.state.pending_memcpy = Some((shard, src, dst, lookup_id)),
+            }
+        }
+
+        let precompile_event = match syscall_code {
+            SyscallCode::MEMCPY_32 => PrecompileEvent::MemCopy32(event),
+            SyscallCode::MEMCPY_64 => PrecompileEvent::MemCopy64(event),
+            _ => unreachable!(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), src, dst, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, precompile_event);
 
         None
     }
@@ -66,4 +92,55 @@ impl<NumWords: ArrayLength + Send + Sync, NumBytes: ArrayLength + Send + Sync> S
     fn num_extra_cycles(&self) -> u32 {
         1
     }
-}
\ No newline at end of file
+}
+
+/// Coalesce a `MEMCPY_32` call with the pending one before it into a single `MEMCPY_64` event.
+///
+/// Both calls are moved from the `MEMCPY_32` count to a single `MEMCPY_64` event, and share its
+/// nonce, since they are received by the same row of the `MEMCPY_64` chip.
+fn coalesce(rt: &mut SyscallContext, pending_lookup_id: LookupId, event: MemCopyEvent) {
+    // The current call has already been counted, along with the pending one.
+    *rt.rt.state.syscall_counts.get_mut(&SyscallCode::MEMCPY_32).unwrap() -= 2;
+    rt.rt.assign_syscall_nonce(SyscallCode::MEMCPY_64, pending_lookup_id);
+    let nonce = rt.rt.record.nonce_lookup[pending_lookup_id.0 as usize];
+    rt.rt.record.nonce_lookup[event.lookup_id.0 as usize] = nonce;
+
+    if rt.rt.executor_mode != ExecutorMode::Trace {
+        return;
+    }
+
+    let record = rt.record_mut();
+    let (mut syscall_event, pending_event) =
+        record.precompile_events.pop_event(SyscallCode::MEMCPY_32).unwrap();
+    let PrecompileEvent::MemCopy32(mut pending_event) = pending_event else { unreachable!() };
+    assert_eq!(pending_event.lookup_id, pending_lookup_id);
+    syscall_event.nonce = nonce;
+
+    // The pending call has already been sent to the core shard's syscall table.
+    if let Some(emitted) =
+        record.syscall_events.iter_mut().rev().find(|e| e.lookup_id == pending_lookup_id)
+    {
+        emitted.nonce = nonce;
+    }
+
+    let coalesced_syscall = rt.rt.syscall_event(
+        event.clk,
+        SyscallCode::MEMCPY_32.syscall_id(),
+        event.src_ptr,
+        event.dst_ptr,
+        event.lookup_id,
+    );
+    let events = &mut rt.record_mut().precompile_events;
+    pending_event.read_records =
+        events.read_arena.concat(pending_event.read_records, event.read_records);
+    pending_event.write_records =
+        events.write_arena.concat(pending_event.write_records, event.write_records);
+    pending_event.local_mem_access.extend(event.local_mem_access);
+    pending_event.coalesced_syscall = Some(coalesced_syscall);
+
+    rt.record_mut().precompile_events.add_event(
+        SyscallCode::MEMCPY_64,
+        syscall_event,
+        PrecompileEvent::MemCopy64(pending_event),
+    );
+}
@@ -0,0 +1,111 @@
+use num::BigUint;
+
+use sp1_curves::{edwards::WORDS_FIELD_ELEMENT, poseidon::poseidon_hash_with_domain};
+use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec, WORD_SIZE};
+
+use crate::{
+    events::{PoseidonMerkleEvent, PrecompileEvent, POSEIDON_MERKLE_MAX_DEPTH},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+pub(crate) struct PoseidonMerkleSyscall;
+
+impl Syscall for PoseidonMerkleSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let node_ptr = arg1;
+        if node_ptr % 4 != 0 {
+            panic!();
+        }
+        let path_ptr = arg2;
+        if path_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // First read the words for the node. We can read a slice_unsafe here because we write
+        // the computed root to the node later.
+        let node = rt.slice_unsafe(node_ptr, WORDS_FIELD_ELEMENT);
+
+        // Read the depth and the index, which are followed by the sibling and domain of every
+        // level.
+        let (depth_memory_record, depth) = rt.mr(path_ptr);
+        assert!(
+            (1..=POSEIDON_MERKLE_MAX_DEPTH as u32).contains(&depth),
+            "poseidon merkle path depth {depth} is not in 1..={POSEIDON_MERKLE_MAX_DEPTH}"
+        );
+        let (index_memory_record, index) = rt.mr(path_ptr + WORD_SIZE as u32);
+        assert!(
+            index >> POSEIDON_MERKLE_MAX_DEPTH == 0,
+            "poseidon merkle path index {index} has more than {POSEIDON_MERKLE_MAX_DEPTH} bits"
+        );
+
+        // Hash the node with the sibling of every level, from the bottom up.
+        let mut result = BigUint::from_bytes_le(&words_to_bytes_le_vec(&node));
+        let (mut siblings, mut domains) =
+            (Vec::with_capacity(depth as usize), Vec::with_capacity(depth as usize));
+        let mut level_memory_records = Vec::with_capacity(depth as usize);
+        let level_size = 2 * WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        for i in 0..depth {
+            let level_ptr = path_ptr + 2 * WORD_SIZE as u32 + i * level_size;
+            let (records, words) = rt.mr_slice(level_ptr, 2 * WORDS_FIELD_ELEMENT);
+            let (sibling, domain) = words.split_at(WORDS_FIELD_ELEMENT);
+
+            let sibling_value = BigUint::from_bytes_le(&words_to_bytes_le_vec(sibling));
+            let domain_value = BigUint::from_bytes_le(&words_to_bytes_le_vec(domain));
+            result = if (index >> i) & 1 == 0 {
+                poseidon_hash_with_domain(&result, &sibling_value, &domain_value)
+            } else {
+                poseidon_hash_with_domain(&sibling_value, &result, &domain_value)
+            };
+
+            siblings.push(sibling.to_vec());
+            domains.push(domain.to_vec());
+            level_memory_records.push(records);
+        }
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0u8); // Pad the result to 32 bytes.
+        let result = bytes_to_words_le::<8>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the root to the node and keep track of the memory records.
+        let node_memory_records = rt.mw_slice(node_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::PoseidonMerkle(PoseidonMerkleEvent {
+            lookup_id,
+            shard,
+            clk,
+            node_ptr,
+            node,
+            path_ptr,
+            depth,
+            index,
+            siblings,
+            domains,
+            node_memory_records,
+            depth_memory_record,
+            index_memory_record,
+            level_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,109 @@
+use sp1_primitives::consts::{words_to_bytes_le, WORD_SIZE};
+
+use crate::{
+    events::{
+        PrecompileEvent, RlpHeader, RlpScanEvent, RLP_MAX_HEADER_BYTES, RLP_SCAN_ENTRY_WORDS,
+        RLP_SCAN_MAX_ITEMS, RLP_SCAN_STATE_WORDS, RLP_SCAN_WINDOW_WORDS,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Scans the items of the RLP payload described by the state at `arg1`, from the offset of the
+/// state, and writes the offset and the length of the payload of every item to the table at
+/// `arg2`.
+///
+/// The scan stops at the end of the payload, or after [`RLP_SCAN_MAX_ITEMS`] items. The offset of
+/// the state is advanced past the items scanned, and their number is written to the state.
+pub(crate) struct RlpScanSyscall;
+
+impl Syscall for RlpScanSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        let state_ptr = arg1;
+        assert!(state_ptr % 4 == 0, "state_ptr must be 4-byte aligned");
+        let table_ptr = arg2;
+        assert!(table_ptr % 4 == 0, "table_ptr must be 4-byte aligned");
+
+        // Read the state. We can read a slice_unsafe here because we write the updated state
+        // later.
+        let state = rt.slice_unsafe(state_ptr, RLP_SCAN_STATE_WORDS);
+        let (payload_ptr, payload_len, mut offset) = (state[0], state[1], state[2]);
+        assert!(payload_ptr % 4 == 0, "payload_ptr must be 4-byte aligned");
+        assert!(payload_len < 1 << 24, "rlp payload length {payload_len} is not below 2^24");
+        assert!(offset <= payload_len, "rlp offset {offset} is past the payload");
+
+        let mut windows = Vec::new();
+        let mut entries = Vec::new();
+        let mut window_memory_records = Vec::new();
+        let mut entry_memory_records = Vec::new();
+        for i in 0..RLP_SCAN_MAX_ITEMS as u32 {
+            if offset == payload_len {
+                break;
+            }
+
+            // Read the words around the header of the item, which may be past the end of the
+            // payload.
+            rt.clk = clk + 2 * i;
+            let align = offset % WORD_SIZE as u32;
+            let (records, window) =
+                rt.mr_slice(payload_ptr + offset - align, RLP_SCAN_WINDOW_WORDS);
+            let bytes = words_to_bytes_le::<{ RLP_SCAN_WINDOW_WORDS * WORD_SIZE }>(&window);
+            let header =
+                RlpHeader::decode(&bytes[align as usize..align as usize + RLP_MAX_HEADER_BYTES])
+                    .unwrap_or_else(|| panic!("malformed rlp item at offset {offset}"));
+            let start = offset + header.header_len;
+            let end = start + header.len;
+            assert!(end <= payload_len, "rlp item at offset {offset} overruns the payload");
+
+            // Write the entry of the item at the next cycle, since the table and the payload may
+            // overlap.
+            rt.clk += 1;
+            let entry = vec![start, header.len | (u32::from(header.kind.is_list()) << 24)];
+            let records_entry =
+                rt.mw_slice(table_ptr + i * (RLP_SCAN_ENTRY_WORDS * WORD_SIZE) as u32, &entry);
+
+            windows.push(window);
+            entries.push(entry);
+            window_memory_records.push(records);
+            entry_memory_records.push(records_entry);
+            offset = end;
+        }
+
+        // Write the updated state after the cycles of every item.
+        rt.clk = clk + 2 * RLP_SCAN_MAX_ITEMS as u32;
+        let result = [payload_ptr, payload_len, offset, entries.len() as u32];
+        let state_memory_records = rt.mw_slice(state_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::RlpScan(RlpScanEvent {
+            lookup_id,
+            shard,
+            clk,
+            state_ptr,
+            state,
+            table_ptr,
+            windows,
+            entries,
+            window_memory_records,
+            entry_memory_records,
+            state_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        2 * RLP_SCAN_MAX_ITEMS as u32
+    }
+}
@@ -0,0 +1,88 @@
+use num::{BigUint, One};
+
+use sp1_primitives::consts::{bytes_to_words_le_vec, words_to_bytes_le_vec, WORD_SIZE};
+
+use crate::{
+    events::{rsa_ladder, PrecompileEvent, RsaModExpEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Raises a base of `NUM_WORDS` words to a public exponent modulo a modulus of the same size.
+///
+/// The first argument points to the base, which is overwritten with the result. The second one
+/// points to the modulus, which is followed by the exponent.
+pub(crate) struct RsaModExpSyscall<const NUM_WORDS: usize>;
+
+impl<const NUM_WORDS: usize> Syscall for RsaModExpSyscall<NUM_WORDS> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let params_ptr = arg2;
+        if params_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // First read the words for the base. We can read a slice_unsafe here because we write
+        // the computed result to it later.
+        let x = rt.slice_unsafe(x_ptr, NUM_WORDS);
+
+        // Read the modulus and the exponent stored after it.
+        let (params_memory_records, params) = rt.mr_slice(params_ptr, NUM_WORDS + 1);
+        let modulus = params[..NUM_WORDS].to_vec();
+        let exponent = params[NUM_WORDS];
+
+        let base = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let big_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+        assert!(big_modulus > BigUint::one(), "the modulus must be greater than one");
+        assert!(base < big_modulus, "the base must be reduced modulo the modulus");
+        assert!(
+            rsa_ladder(exponent).is_some(),
+            "the ladder of exponent {exponent} does not fit in a single call"
+        );
+
+        let result = base.modpow(&BigUint::from(exponent), &big_modulus);
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(NUM_WORDS * WORD_SIZE, 0u8);
+        let result = bytes_to_words_le_vec(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to the base and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::RsaModExp(RsaModExpEvent {
+            lookup_id,
+            shard,
+            clk,
+            x_ptr,
+            x,
+            params_ptr,
+            modulus,
+            exponent,
+            x_memory_records,
+            params_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    events::{
+        PrecompileEvent, Sha256CompressBlocksEvent, SHA256_BLOCKS_COUNT_OFFSET,
+        SHA256_BLOCKS_H_OFFSET, SHA256_BLOCKS_MAX_BLOCKS, SHA256_BLOCKS_STEP_CYCLES,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+pub(crate) struct Sha256CompressBlocksSyscall;
+
+impl Syscall for Sha256CompressBlocksSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        SHA256_BLOCKS_MAX_BLOCKS as u32 * SHA256_BLOCKS_STEP_CYCLES
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let w_ptr = arg1;
+        let blocks_ptr = arg2;
+        let h_ptr = w_ptr + SHA256_BLOCKS_H_OFFSET;
+        assert!(w_ptr % 4 == 0 && blocks_ptr % 4 == 0, "pointers must be word aligned");
+
+        let (count_read_record, num_blocks) = rt.mr(w_ptr + SHA256_BLOCKS_COUNT_OFFSET);
+        let num_blocks = num_blocks as usize;
+        assert!(
+            (1..=SHA256_BLOCKS_MAX_BLOCKS).contains(&num_blocks),
+            "expected 1 to {SHA256_BLOCKS_MAX_BLOCKS} blocks, got {num_blocks}"
+        );
+        assert!(
+            blocks_ptr + num_blocks as u32 * 16 * 4 <= w_ptr
+                || w_ptr + SHA256_BLOCKS_COUNT_OFFSET + 4 <= blocks_ptr,
+            "the blocks overlap the buffer"
+        );
+
+        let mut block_read_records = Vec::new();
+        let mut w_write_records = Vec::new();
+        let mut sha_syscalls = Vec::new();
+        let mut local_mem_access = Vec::new();
+        for i in 0..num_blocks as u32 {
+            // Copy the block to the start of the message schedule.
+            let (records, block) = rt.mr_slice(blocks_ptr + i * 16 * 4, 16);
+            block_read_records.push(records);
+            w_write_records.push(rt.mw_slice(w_ptr, &block));
+
+            // The extend and compress calls access the buffer in their own events, so the
+            // accesses of this block are split off before making them.
+            local_mem_access.extend(rt.postprocess());
+            rt.clk += 1;
+            let extend = rt.execute_nested(SyscallCode::SHA_EXTEND, w_ptr, 0);
+            let compress = rt.execute_nested(SyscallCode::SHA_COMPRESS, w_ptr, h_ptr);
+            sha_syscalls.push((extend, compress));
+            rt.clk += 1;
+        }
+
+        // Push the SHA-256 compress blocks event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::Sha256CompressBlocks(Sha256CompressBlocksEvent {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            w_ptr,
+            blocks_ptr,
+            count_read_record,
+            block_read_records,
+            w_write_records,
+            sha_syscalls,
+            local_mem_access,
+        });
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    events::{
+        HmacSha256Event, PrecompileEvent, HMAC_SHA256_H_OFFSET, HMAC_SHA256_IPAD, HMAC_SHA256_OPAD,
+        HMAC_SHA256_OUTER_PADDING, HMAC_SHA256_STEPS, HMAC_SHA256_STEP_CYCLES, SHA256_IV,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+pub(crate) struct HmacSha256Syscall;
+
+impl Syscall for HmacSha256Syscall {
+    fn num_extra_cycles(&self) -> u32 {
+        HMAC_SHA256_STEPS as u32 * HMAC_SHA256_STEP_CYCLES
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let key_ptr = arg1;
+        let w_ptr = arg2;
+        let h_ptr = w_ptr + HMAC_SHA256_H_OFFSET;
+        assert!(key_ptr % 4 == 0 && w_ptr % 4 == 0, "pointers must be word aligned");
+        assert!(
+            key_ptr + 16 * 4 <= w_ptr || h_ptr + 8 * 4 <= key_ptr,
+            "the key block overlaps the buffer"
+        );
+
+        let mut key = Vec::new();
+        let mut message = Vec::new();
+        let mut inner_hash = Vec::new();
+        let mut key_read_records = Vec::new();
+        let mut block_write_records = Vec::new();
+        let mut h_write_records = Vec::new();
+        let mut sha_syscalls = Vec::new();
+        let mut local_mem_access = Vec::new();
+        for step in 0..HMAC_SHA256_STEPS {
+            // Write the block compressed by this step, and reset the state before each hash.
+            let block = match step {
+                0 => {
+                    (key_read_records, key) = rt.mr_slice(key_ptr, 16);
+                    key.iter().map(|word| word ^ HMAC_SHA256_IPAD).collect::<Vec<_>>()
+                }
+                1 => message.clone(),
+                2 => key.iter().map(|word| word ^ HMAC_SHA256_OPAD).collect(),
+                _ => [inner_hash.as_slice(), &HMAC_SHA256_OUTER_PADDING[..]].concat(),
+            };
+            let records = rt.mw_slice(w_ptr, &block);
+            if step == 0 {
+                message = records.iter().map(|record| record.prev_value).collect();
+            }
+            block_write_records.push(records);
+            if step % 2 == 0 {
+                let records = rt.mw_slice(h_ptr, &SHA256_IV);
+                if step == 2 {
+                    inner_hash = records.iter().map(|record| record.prev_value).collect();
+                }
+                h_write_records.push(records);
+            }
+
+            // The extend and compress calls access the buffer in their own events, so the
+            // accesses of this step are split off before making them.
+            local_mem_access.extend(rt.postprocess());
+            rt.clk += 1;
+            let extend = rt.execute_nested(SyscallCode::SHA_EXTEND, w_ptr, 0);
+            let compress = rt.execute_nested(SyscallCode::SHA_COMPRESS, w_ptr, h_ptr);
+            sha_syscalls.push((extend, compress));
+            rt.clk += 1;
+        }
+
+        // Push the HMAC-SHA256 event.
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::HmacSha256(HmacSha256Event {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            key_ptr,
+            w_ptr,
+            key_read_records,
+            block_write_records,
+            h_write_records,
+            sha_syscalls,
+            local_mem_access,
+        });
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
@@ -1,2 +1,4 @@
+pub mod blocks;
 pub mod compress;
 pub mod extend;
+pub mod hmac;
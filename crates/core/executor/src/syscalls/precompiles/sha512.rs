@@ -0,0 +1,68 @@
+use crate::{
+    events::{
+        sha512_compress, PrecompileEvent, Sha512CompressEvent, SHA512_BLOCK_WORDS,
+        SHA512_STATE_WORDS,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Compresses a SHA-512 block into a SHA-512 state.
+///
+/// The syscall takes a pointer to the state, made of 8 little-endian 64-bit words, and a pointer
+/// to the block, made of 16 little-endian 64-bit words. The state is updated in place.
+pub(crate) struct Sha512CompressSyscall;
+
+impl Syscall for Sha512CompressSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        let block_ptr = arg2;
+        assert!(state_ptr % 4 == 0 && block_ptr % 4 == 0, "pointers must be word aligned");
+        assert!(
+            state_ptr + SHA512_STATE_WORDS as u32 * 4 <= block_ptr
+                || block_ptr + SHA512_BLOCK_WORDS as u32 * 4 <= state_ptr,
+            "the state overlaps the block"
+        );
+
+        let (block_read_records, block_words) = rt.mr_slice(block_ptr, SHA512_BLOCK_WORDS);
+        let state_words = rt.slice_unsafe(state_ptr, SHA512_STATE_WORDS);
+        let block: [u64; 16] = core::array::from_fn(|i| {
+            u64::from(block_words[2 * i]) | u64::from(block_words[2 * i + 1]) << 32
+        });
+        let state: [u64; 8] = core::array::from_fn(|i| {
+            u64::from(state_words[2 * i]) | u64::from(state_words[2 * i + 1]) << 32
+        });
+
+        let mut result = state;
+        sha512_compress(&mut result, &block);
+        let result_words =
+            result.iter().flat_map(|&word| [word as u32, (word >> 32) as u32]).collect::<Vec<_>>();
+        let state_write_records = rt.mw_slice(state_ptr, &result_words);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::Sha512Compress(Sha512CompressEvent {
+            lookup_id,
+            shard,
+            clk: start_clk,
+            state_ptr,
+            block_ptr,
+            state,
+            block,
+            block_read_records,
+            state_write_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+}
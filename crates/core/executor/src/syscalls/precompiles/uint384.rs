@@ -0,0 +1,95 @@
+use num::BigUint;
+
+use sp1_curves::{params::FieldParameters, weierstrass::bls12_381::Bls12381BaseField};
+use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec, WORD_SIZE};
+
+use crate::{
+    events::{PrecompileEvent, Uint384MulAddEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// The number of words in a uint384.
+const UINT384_NUM_WORDS: usize = 12;
+
+/// The number of bytes in a uint384.
+const UINT384_NUM_BYTES: usize = UINT384_NUM_WORDS * WORD_SIZE;
+
+pub(crate) struct Uint384MulAddSyscall;
+
+impl Syscall for Uint384MulAddSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if y_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // First read the words for the x value. We can read a slice_unsafe here because we write
+        // the computed result to x later.
+        let x = rt.slice_unsafe(x_ptr, UINT384_NUM_WORDS);
+
+        // Read the a value.
+        let (a_memory_records, a) = rt.mr_slice(y_ptr, UINT384_NUM_WORDS);
+
+        // The b value is stored after the a value. We increment the pointer by the number of words.
+        let b_ptr = y_ptr + UINT384_NUM_BYTES as u32;
+        let (b_memory_records, b) = rt.mr_slice(b_ptr, UINT384_NUM_WORDS);
+
+        // Get the BigUint values for x, a, b, and the modulus.
+        let uint384_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint384_a = BigUint::from_bytes_le(&words_to_bytes_le_vec(&a));
+        let uint384_b = BigUint::from_bytes_le(&words_to_bytes_le_vec(&b));
+        let modulus = Bls12381BaseField::modulus();
+
+        // Perform the multiply-add and take the result modulo the modulus.
+        let result: BigUint = (uint384_a * uint384_b + uint384_x) % modulus;
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(UINT384_NUM_BYTES, 0u8); // Pad the result to 48 bytes.
+
+        // Convert the result to little endian u32 words.
+        let result = bytes_to_words_le::<UINT384_NUM_WORDS>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the result to x and keep track of the memory records.
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::Uint384MulAdd(Uint384MulAddEvent {
+            lookup_id,
+            shard,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            a,
+            b,
+            x_memory_records,
+            a_memory_records,
+            b_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
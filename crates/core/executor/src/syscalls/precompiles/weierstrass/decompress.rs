@@ -1,9 +1,9 @@
 use std::marker::PhantomData;
 
-use sp1_curves::{CurveType, EllipticCurve};
+use sp1_curves::{weierstrass::WeierstrassParameters, CurveType, EllipticCurve};
 
 use crate::{
-    events::{create_ec_decompress_event, PrecompileEvent},
+    events::{create_bn254_g2_decompress_event, create_ec_decompress_event, PrecompileEvent},
     syscalls::{Syscall, SyscallCode, SyscallContext},
 };
 
@@ -18,7 +18,7 @@ impl<E: EllipticCurve> WeierstrassDecompressSyscall<E> {
     }
 }
 
-impl<E: EllipticCurve> Syscall for WeierstrassDecompressSyscall<E> {
+impl<E: EllipticCurve + WeierstrassParameters> Syscall for WeierstrassDecompressSyscall<E> {
     fn execute(
         &self,
         rt: &mut SyscallContext,
@@ -27,27 +27,72 @@ impl<E: EllipticCurve> Syscall for WeierstrassDecompressSyscall<E> {
         arg2: u32,
     ) -> Option<u32> {
         let event = create_ec_decompress_event::<E>(rt, arg1, arg2);
-        let syscall_event =
-            rt.rt.syscall_event(event.clk, syscall_code.syscall_id(), arg1, arg2, event.lookup_id);
+        let status = u32::from(event.is_invalid);
+        let syscall_event = rt.rt.syscall_event(
+            event.clk,
+            syscall_code.status_syscall_id(status),
+            arg1,
+            arg2,
+            event.lookup_id,
+        );
         match E::CURVE_TYPE {
             CurveType::Secp256k1 => rt.add_precompile_event(
                 syscall_code,
                 syscall_event,
                 PrecompileEvent::Secp256k1Decompress(event),
             ),
-            CurveType::Secp256r1 => rt.record_mut().add_precompile_event(
+            CurveType::Secp256r1 => rt.add_precompile_event(
                 syscall_code,
                 syscall_event,
                 PrecompileEvent::Secp256r1Decompress(event),
             ),
-            CurveType::Bls12381 => rt.record_mut().add_precompile_event(
+            CurveType::Bls12381 => rt.add_precompile_event(
                 syscall_code,
                 syscall_event,
                 PrecompileEvent::Bls12381Decompress(event),
             ),
+            CurveType::Bn254 => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::Bn254Decompress(event),
+            ),
             _ => panic!("Unsupported curve"),
         }
-        None
+        Some(status)
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        0
+    }
+}
+
+/// Decompresses a BN254 G2 point, whose coordinates are in the quadratic extension of the base
+/// field.
+pub(crate) struct Bn254G2DecompressSyscall;
+
+impl Syscall for Bn254G2DecompressSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let event = create_bn254_g2_decompress_event(rt, arg1, arg2);
+        let status = u32::from(event.is_invalid);
+        let syscall_event = rt.rt.syscall_event(
+            event.clk,
+            syscall_code.status_syscall_id(status),
+            arg1,
+            arg2,
+            event.lookup_id,
+        );
+        rt.add_precompile_event(
+            syscall_code,
+            syscall_event,
+            PrecompileEvent::Bn254G2Decompress(event),
+        );
+        Some(status)
     }
 
     fn num_extra_cycles(&self) -> u32 {
@@ -0,0 +1,101 @@
+use crate::{
+    events::{
+        ecrecover_keccak_state, secp256k1_ecrecover_witness, PrecompileEvent,
+        Secp256k1EcRecoverEvent, ECRECOVER_INPUT_WORDS, ECRECOVER_OUTPUT_OFFSET,
+        ECRECOVER_OUTPUT_WORDS, ECRECOVER_PERMUTE_OFFSET, ECRECOVER_STATE_OFFSET,
+    },
+    syscalls::{
+        precompiles::keccak256::permute::STATE_NUM_WORDS, Syscall, SyscallCode, SyscallContext,
+    },
+};
+
+/// Recovers the address of the secp256k1 key that signed a hash, with the semantics of the EVM
+/// `ecrecover` precompile.
+///
+/// The syscall takes a pointer to the 32-byte output, and a pointer to the 128-byte input of the
+/// precompile, which is followed by a keccak state used to hash the public key. On success, the
+/// output is the address left-padded with zeros, and the status is 0.
+///
+/// The status is 1 if the input is invalid, but also if the key cannot be recovered by the fixed
+/// ladder of the syscall, so a failed call only means that the address has to be recovered
+/// otherwise. A failed call accesses no memory.
+pub(crate) struct Secp256k1EcRecoverSyscall;
+
+impl Syscall for Secp256k1EcRecoverSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        ECRECOVER_OUTPUT_OFFSET
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let out_ptr = arg1;
+        let input_ptr = arg2;
+        let state_ptr = input_ptr + ECRECOVER_STATE_OFFSET;
+        assert!(out_ptr % 4 == 0 && input_ptr % 4 == 0, "pointers must be word aligned");
+        assert!(
+            out_ptr + ECRECOVER_OUTPUT_WORDS as u32 * 4 <= input_ptr
+                || state_ptr + STATE_NUM_WORDS as u32 * 4 <= out_ptr,
+            "the output overlaps the input"
+        );
+
+        let input = rt.slice_unsafe(input_ptr, ECRECOVER_INPUT_WORDS);
+        let input_bytes = input.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>();
+        let witness = secp256k1_ecrecover_witness(&input_bytes);
+
+        let mut event = Secp256k1EcRecoverEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            clk: start_clk,
+            out_ptr,
+            input_ptr,
+            is_recovered: witness.is_some(),
+            input,
+            ..Default::default()
+        };
+        if let Some(witness) = witness {
+            // Read the input and write the padded public key to the state.
+            (event.input_read_records, _) = rt.mr_slice(input_ptr, ECRECOVER_INPUT_WORDS);
+            let state = ecrecover_keccak_state(&witness.public_key);
+            event.state_write_records = rt.mw_slice(state_ptr, &state);
+
+            // The permutation accesses the state in its own event, so the accesses so far are
+            // split off before making it.
+            event.local_mem_access.extend(rt.postprocess());
+            rt.clk = start_clk + ECRECOVER_PERMUTE_OFFSET;
+            event.permute_syscall =
+                Some(rt.execute_nested(SyscallCode::KECCAK_PERMUTE, state_ptr, 0));
+            rt.clk = start_clk + ECRECOVER_OUTPUT_OFFSET;
+
+            // The address is the last 20 bytes of the hash, left-padded to 32 bytes.
+            let (hash_read_records, hash) = rt.mr_slice(state_ptr, ECRECOVER_OUTPUT_WORDS);
+            let mut out = [0u32; ECRECOVER_OUTPUT_WORDS];
+            out[3..].copy_from_slice(&hash[3..]);
+            event.hash_read_records = hash_read_records;
+            event.out_write_records = rt.mw_slice(out_ptr, &out);
+            event.local_mem_access.extend(rt.postprocess());
+        }
+
+        // Push the ecrecover event.
+        let status = u32::from(!event.is_recovered);
+        let syscall_event = rt.rt.syscall_event(
+            start_clk,
+            syscall_code.status_syscall_id(status),
+            arg1,
+            arg2,
+            event.lookup_id,
+        );
+        rt.add_precompile_event(
+            syscall_code,
+            syscall_event,
+            PrecompileEvent::Secp256k1EcRecover(event),
+        );
+
+        Some(status)
+    }
+}
@@ -1,3 +1,4 @@
 pub mod add;
 pub mod decompress;
 pub mod double;
+pub mod ecrecover;
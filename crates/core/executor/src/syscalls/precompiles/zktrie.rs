@@ -0,0 +1,76 @@
+use num::BigUint;
+
+use sp1_curves::{edwards::WORDS_FIELD_ELEMENT, poseidon::zktrie_hash_node};
+use sp1_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec};
+
+use crate::{
+    events::{PrecompileEvent, ZkTrieNodeEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+pub(crate) struct ZkTrieNodeSyscall;
+
+impl Syscall for ZkTrieNodeSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let hash_ptr = arg1;
+        if hash_ptr % 4 != 0 {
+            panic!();
+        }
+        let node_ptr = arg2;
+        if node_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // Read the node type, followed by the left and the right child.
+        let (node_memory_records, words) = rt.mr_slice(node_ptr, 1 + 2 * WORDS_FIELD_ELEMENT);
+        let node_type = words[0];
+        let (left, right) = words[1..].split_at(WORDS_FIELD_ELEMENT);
+
+        let hash = zktrie_hash_node(
+            node_type,
+            &BigUint::from_bytes_le(&words_to_bytes_le_vec(left)),
+            &BigUint::from_bytes_le(&words_to_bytes_le_vec(right)),
+        );
+        let mut hash_bytes = hash.to_bytes_le();
+        hash_bytes.resize(32, 0u8); // Pad the hash to 32 bytes.
+        let hash = bytes_to_words_le::<8>(&hash_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        // Write the hash and keep track of the memory records.
+        let hash_memory_records = rt.mw_slice(hash_ptr, &hash);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::ZkTrieNode(ZkTrieNodeEvent {
+            lookup_id,
+            shard,
+            clk,
+            hash_ptr,
+            node_ptr,
+            node_type,
+            left: left.to_vec(),
+            right: right.to_vec(),
+            node_memory_records,
+            hash_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+        let syscall_event =
+            rt.rt.syscall_event(clk, syscall_code.syscall_id(), arg1, arg2, lookup_id);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
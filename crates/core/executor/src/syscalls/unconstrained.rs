@@ -20,6 +20,8 @@ impl Syscall for EnterUnconstrainedSyscall {
             record: std::mem::take(&mut ctx.rt.record),
             op_record: std::mem::take(&mut ctx.rt.memory_accesses),
             executor_mode: ctx.rt.executor_mode,
+            registers_hi: ctx.rt.state.registers_hi,
+            call_depth: ctx.rt.call_stack.depth(),
         };
         ctx.rt.executor_mode = ExecutorMode::Simple;
         Some(1)
@@ -35,6 +37,8 @@ impl Syscall for ExitUnconstrainedSyscall {
             ctx.rt.state.global_clk = ctx.rt.unconstrained_state.global_clk;
             ctx.rt.state.clk = ctx.rt.unconstrained_state.clk;
             ctx.rt.state.pc = ctx.rt.unconstrained_state.pc;
+            ctx.rt.state.registers_hi = ctx.rt.unconstrained_state.registers_hi;
+            ctx.rt.call_stack.truncate(ctx.rt.unconstrained_state.call_depth);
             ctx.next_pc = ctx.rt.state.pc.wrapping_add(4);
             for (addr, value) in ctx.rt.unconstrained_state.memory_diff.drain() {
                 match value {
@@ -1,6 +1,6 @@
 use sp1_primitives::consts::num_to_comma_separated;
 
-use crate::{Executor, Register};
+use crate::{CycleTrackerEvent, Executor, Register};
 
 use super::{Syscall, SyscallCode, SyscallContext};
 
@@ -12,10 +12,13 @@ impl Syscall for WriteSyscall {
     /// If stdout (fd = 1):
     /// - If the stream is a cycle tracker, either log the cycle tracker or accumulate it in the
     ///   report.
-    /// - Else, print the stream to stdout.
+    /// - Else, print the stream to stdout and record it in the guest log of the report.
     ///
     /// If stderr (fd = 2):
-    /// - Print the stream to stderr.
+    /// - Print the stream to stderr and record it in the guest log of the report.
+    ///
+    /// The lines written to stdout and stderr are passed to the output callback of their fd
+    /// instead of being printed, if it has one.
     ///
     /// If fd = 3:
     /// - Update the public value stream.
@@ -23,7 +26,12 @@ impl Syscall for WriteSyscall {
     /// If fd = 4:
     /// - Update the input stream.
     ///
-    /// If the fd matches a hook in the hook registry, invoke the hook.
+    /// If the fd has an output callback, pass the stream to it.
+    ///
+    /// If the fd matches a hook in the hook registry, invoke the hook, unless its response to the
+    /// call was prefetched.
+    ///
+    /// When replaying the input stream, the writes to fd 4 and above are ignored.
     ///
     /// Else, log a warning.
     #[allow(clippy::pedantic)]
@@ -49,26 +57,33 @@ impl Syscall for WriteSyscall {
                 None => {
                     // If the string does not match any known command, print it to stdout.
                     let flush_s = update_io_buf(ctx, fd, s);
-                    if !flush_s.is_empty() {
-                        flush_s.into_iter().for_each(|line| println!("stdout: {}", line));
+                    for line in flush_s {
+                        ctx.rt.output_line(fd, &line);
                     }
                 }
             }
         } else if fd == 2 {
             let s = core::str::from_utf8(slice).unwrap();
             let flush_s = update_io_buf(ctx, fd, s);
-            if !flush_s.is_empty() {
-                flush_s.into_iter().for_each(|line| println!("stderr: {}", line));
+            for line in flush_s {
+                ctx.rt.output_line(fd, &line);
             }
         } else if fd == 3 {
             rt.state.public_values_stream.extend_from_slice(slice);
+        } else if fd > 4 && rt.intercept_output(fd, slice) {
+            // The output callback of the fd takes precedence over its hook.
+        } else if fd >= 4 && rt.state.replaying_hints {
+            // The replayed input stream already holds whatever these writes and hooks served.
         } else if fd == 4 {
+            // The writes come after all the inputs of the stdin stream.
+            while rt.read_stdin_stream() {}
             rt.state.input_stream.push(slice.to_vec());
+        } else if let Some(res) = rt.hint_prefetcher.as_mut().and_then(|p| p.take(fd, slice)) {
+            rt.hook_call(fd, slice, res);
         } else if let Some(mut hook) = rt.hook_registry.get(fd) {
             let res = hook.invoke_hook(rt.hook_env(), slice);
-            // Add result vectors to the beginning of the stream.
-            let ptr = rt.state.input_stream_ptr;
-            rt.state.input_stream.splice(ptr..ptr, res);
+            drop(hook);
+            rt.hook_call(fd, slice, res);
         } else {
             tracing::warn!("tried to write to unknown file descriptor {fd}");
         }
@@ -107,12 +122,12 @@ fn handle_cycle_tracker_command(rt: &mut Executor, command: CycleTrackerCommand)
             start_cycle_tracker(rt, &name);
         }
         CycleTrackerCommand::End(name) => {
-            end_cycle_tracker(rt, &name);
+            end_cycle_tracker(rt, &name, false);
         }
         CycleTrackerCommand::ReportEnd(name) => {
             // Attempt to end the cycle tracker and accumulate the total cycles in the fn_name's
             // entry in the ExecutionReport.
-            if let Some(total_cycles) = end_cycle_tracker(rt, &name) {
+            if let Some(total_cycles) = end_cycle_tracker(rt, &name, true) {
                 rt.report
                     .cycle_tracker
                     .entry(name.to_string())
@@ -126,18 +141,28 @@ fn handle_cycle_tracker_command(rt: &mut Executor, command: CycleTrackerCommand)
 /// Start tracking cycles for the given name at the specific depth and print out the log.
 fn start_cycle_tracker(rt: &mut Executor, name: &str) {
     let depth = rt.cycle_tracker.len() as u32;
-    rt.cycle_tracker.insert(name.to_string(), (rt.state.global_clk, depth));
+    let global_clk = rt.state.global_clk;
+    rt.cycle_tracker.insert(name.to_string(), (global_clk, depth));
     let padding = "│ ".repeat(depth as usize);
     log::info!("{}┌╴{}", padding, name);
+    rt.track_cycles(&CycleTrackerEvent::Start { name: name.to_string(), depth, global_clk });
 }
 
 /// End tracking cycles for the given name, print out the log, and return the total number of cycles
 /// in the span. If the name is not found in the cycle tracker cache, returns None.
-fn end_cycle_tracker(rt: &mut Executor, name: &str) -> Option<u64> {
+fn end_cycle_tracker(rt: &mut Executor, name: &str, report: bool) -> Option<u64> {
     if let Some((start, depth)) = rt.cycle_tracker.remove(name) {
         let padding = "│ ".repeat(depth as usize);
         let total_cycles = rt.state.global_clk - start;
         log::info!("{}└╴{} cycles", padding, num_to_comma_separated(total_cycles));
+        let end = rt.state.global_clk;
+        rt.trace_event(|trace| trace.span(name, start, end));
+        rt.track_cycles(&CycleTrackerEvent::End {
+            name: name.to_string(),
+            depth,
+            cycles: total_cycles,
+            report,
+        });
         return Some(total_cycles);
     }
     None
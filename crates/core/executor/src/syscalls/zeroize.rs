@@ -0,0 +1,19 @@
+use super::{Syscall, SyscallCode, SyscallContext};
+
+/// Registers a memory region holding secret data that the guest promises to zeroize before it
+/// halts.
+///
+/// The syscall itself does not touch memory: the guest overwrites the region with normal stores so
+/// that the writes are constrained like any other. The executor only remembers the region and
+/// checks, right before the final memory state is recorded, that every byte in it is zero.
+pub(crate) struct ZeroizeOnHaltSyscall;
+
+impl Syscall for ZeroizeOnHaltSyscall {
+    fn execute(&self, ctx: &mut SyscallContext, _: SyscallCode, ptr: u32, len: u32) -> Option<u32> {
+        assert!(ptr.checked_add(len).is_some(), "zeroize region overflows the address space");
+        if len > 0 {
+            ctx.rt.state.zeroize_regions.push((ptr, len));
+        }
+        None
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::{
+    fs::File,
+    io::{BufWriter, Result, Write},
+};
+
+use serde_json::{json, Value};
+
+use crate::syscalls::SyscallCode;
+
+/// The track of the cycle tracker spans.
+const SPANS_TID: u32 = 1;
+
+/// The track of the syscall invocations.
+const SYSCALLS_TID: u32 = 2;
+
+/// Writes the profile of an execution as a trace-event JSON file, which can be opened in Perfetto
+/// or `chrome://tracing`.
+///
+/// The timestamps are global clock cycles, displayed as microseconds. The cycle tracker spans and
+/// the syscall invocations are on two tracks of a single process, and the shard boundaries are
+/// instants spanning both tracks.
+///
+/// The events are written in the JSON array format, whose closing bracket is optional, so that the
+/// file can be opened even if the execution never returns.
+#[derive(Debug)]
+pub struct TraceEventWriter {
+    writer: BufWriter<File>,
+    first: bool,
+}
+
+impl TraceEventWriter {
+    /// Create a trace-event file at the given path.
+    #[cfg(feature = "fs")]
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(b"[")?;
+        let mut this = Self { writer, first: true };
+
+        this.write(&json!({
+            "name": "process_name", "ph": "M", "pid": 0, "args": { "name": "SP1 executor" }
+        }))?;
+        for (tid, name) in [(SPANS_TID, "cycle tracker"), (SYSCALLS_TID, "syscalls")] {
+            this.write(&json!({
+                "name": "thread_name", "ph": "M", "pid": 0, "tid": tid, "args": { "name": name }
+            }))?;
+        }
+        Ok(this)
+    }
+
+    /// Append a cycle tracker span, from the cycle it was started to the cycle it was ended.
+    pub(crate) fn span(&mut self, name: &str, start: u64, end: u64) -> Result<()> {
+        self.write(&json!({
+            "name": name, "ph": "X", "ts": start, "dur": end - start, "pid": 0, "tid": SPANS_TID
+        }))
+    }
+
+    /// Append a syscall invocation, with the number of cycles it used.
+    pub(crate) fn syscall(
+        &mut self,
+        syscall: SyscallCode,
+        global_clk: u64,
+        cycles: u64,
+        shard: u32,
+    ) -> Result<()> {
+        self.write(&json!({
+            "name": syscall.to_string(),
+            "ph": "X",
+            "ts": global_clk,
+            "dur": 1,
+            "pid": 0,
+            "tid": SYSCALLS_TID,
+            "args": { "cycles": cycles, "shard": shard }
+        }))
+    }
+
+    /// Append the start of a shard.
+    pub(crate) fn shard(&mut self, shard: u32, global_clk: u64) -> Result<()> {
+        self.write(&json!({
+            "name": format!("shard {shard}"), "ph": "i", "s": "p", "ts": global_clk, "pid": 0
+        }))
+    }
+
+    fn write(&mut self, event: &Value) -> Result<()> {
+        let separator: &[u8] = if self.first { b"\n" } else { b",\n" };
+        self.first = false;
+        self.writer.write_all(separator)?;
+        serde_json::to_writer(&mut self.writer, event)?;
+        Ok(())
+    }
+}
+
+impl Drop for TraceEventWriter {
+    fn drop(&mut self) {
+        // The file stays valid without the closing bracket, so a failure here loses nothing.
+        let _ = self.writer.write_all(b"\n]\n");
+        let _ = self.writer.flush();
+    }
+}
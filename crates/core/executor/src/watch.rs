@@ -0,0 +1,102 @@
+use core::fmt::Debug;
+use std::{
+    ops::Range,
+    sync::{Arc, RwLock},
+};
+
+use hashbrown::HashMap;
+
+use crate::{Executor, HookEnv};
+
+/// A breakpoint callback, wrapped in a smart pointer.
+pub type BoxedBreakpoint<'a> = Arc<RwLock<dyn FnMut(HookEnv) + Send + Sync + 'a>>;
+
+/// A watchpoint callback, wrapped in a smart pointer.
+pub type BoxedWatchpoint<'a> = Arc<RwLock<dyn FnMut(HookEnv, WatchedWrite) + Send + Sync + 'a>>;
+
+/// A write to a word of memory overlapping a watched address range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchedWrite {
+    /// The address of the written word.
+    pub addr: u32,
+    /// The value of the word before the write.
+    pub prev_value: u32,
+    /// The value of the word after the write.
+    pub value: u32,
+}
+
+/// A registry of the breakpoints and watchpoints of an execution, through which a host can
+/// inspect the state of the program while it runs.
+///
+/// A breakpoint is called with the state of the program before the instruction at its program
+/// counter is executed. A watchpoint is called after every write to a word of memory overlapping
+/// its address range, including the writes of the precompiles. The register `xN` is stored at the
+/// address `N`, so the writes to the registers can be watched too.
+#[derive(Clone, Default)]
+pub struct WatchRegistry<'a> {
+    pub(crate) breakpoints: HashMap<u32, Vec<BoxedBreakpoint<'a>>>,
+    pub(crate) watchpoints: Vec<(Range<u32>, BoxedWatchpoint<'a>)>,
+}
+
+impl<'a> WatchRegistry<'a> {
+    /// Create an empty [`WatchRegistry`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a breakpoint at the program counter `pc`.
+    pub fn breakpoint(&mut self, pc: u32, f: impl FnMut(HookEnv) + Send + Sync + 'a) {
+        self.breakpoints.entry(pc).or_default().push(Arc::new(RwLock::new(f)));
+    }
+
+    /// Add a watchpoint on the bytes of memory in `addrs`.
+    pub fn watchpoint(
+        &mut self,
+        addrs: Range<u32>,
+        f: impl FnMut(HookEnv, WatchedWrite) + Send + Sync + 'a,
+    ) {
+        self.watchpoints.push((addrs, Arc::new(RwLock::new(f))));
+    }
+
+    /// Whether the registry has no breakpoints nor watchpoints.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.breakpoints.is_empty() && self.watchpoints.is_empty()
+    }
+}
+
+impl<'a> Debug for WatchRegistry<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut pcs = self.breakpoints.keys().collect::<Vec<_>>();
+        pcs.sort_unstable();
+        let ranges = self.watchpoints.iter().map(|(addrs, _)| addrs).collect::<Vec<_>>();
+        f.debug_struct("WatchRegistry")
+            .field("breakpoints", &pcs)
+            .field("watchpoints", &ranges)
+            .finish()
+    }
+}
+
+impl Executor<'_> {
+    /// Call the breakpoints at the current program counter.
+    pub(crate) fn hit_breakpoints(&self) {
+        if let Some(breakpoints) = self.watch_registry.breakpoints.get(&self.state.pc) {
+            for breakpoint in breakpoints {
+                // Calling `.unwrap()` panics on a poisoned lock. Should never happen normally.
+                (breakpoint.write().unwrap())(self.hook_env());
+            }
+        }
+    }
+
+    /// Call the watchpoints whose address range overlaps the written word.
+    pub(crate) fn hit_watchpoints(&self, write: WatchedWrite) {
+        // The registers take a single address each.
+        let len = if write.addr < 32 { 1 } else { 4 };
+        for (addrs, watchpoint) in &self.watch_registry.watchpoints {
+            if addrs.start < write.addr.saturating_add(len) && write.addr < addrs.end {
+                (watchpoint.write().unwrap())(self.hook_env(), write);
+            }
+        }
+    }
+}
@@ -120,7 +120,9 @@ pub trait MemoryAirBuilder: BaseAirBuilder {
         // underflow in the prime field, resulting in a value that is `>= 2^24` as long as both
         // `current_comp_val, prev_comp_val` are range-checked to be `<2^24` and as long as we're
         // working in a field larger than `2 * 2^24` (which is true of the BabyBear and Mersenne31
-        // prime).
+        // prime). The shards of the memory and precompile chips are not range-checked here: they
+        // are bound to the shard the CPU chip range-checks to 24 bits by the syscall and memory
+        // interactions.
         let diff_minus_one = current_comp_val - prev_comp_value - Self::Expr::one();
 
         // Verify that mem_access.ts_diff = mem_access.ts_diff_16bit_limb
@@ -138,7 +140,7 @@ pub trait MemoryAirBuilder: BaseAirBuilder {
     /// This method verifies that the inputted is less than 2^24 by doing a 16 bit and 8 bit range
     /// check on it's limbs.  It will also verify that the limbs are correct.  This method is needed
     /// since the memory access timestamp check (see [Self::verify_mem_access_ts]) needs to assume
-    /// the clk is within 24 bits.
+    /// the shard and clk are within 24 bits.
     fn eval_range_check_24bits(
         &mut self,
         value: impl Into<Self::Expr>,
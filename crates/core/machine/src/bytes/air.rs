@@ -66,6 +66,25 @@ impl<AB: SP1AirBuilder + PairBuilder> Air<AB> for ByteChip<AB::F> {
                     AB::F::zero(),
                     mult,
                 ),
+                ByteOpcode::AesSbox => {
+                    builder.receive_byte(field_op, local.aes_sbox, local.b, AB::F::zero(), mult)
+                }
+                ByteOpcode::HexDecode => builder.receive_byte_pair(
+                    field_op,
+                    local.hex_decode,
+                    local.hex_valid,
+                    local.b,
+                    AB::F::zero(),
+                    mult,
+                ),
+                ByteOpcode::Base64Decode => builder.receive_byte_pair(
+                    field_op,
+                    local.base64_decode,
+                    local.base64_valid,
+                    local.b,
+                    AB::F::zero(),
+                    mult,
+                ),
             }
         }
     }
@@ -42,6 +42,17 @@ pub struct BytePreprocessedCols<T> {
 
     /// A u16 value used for `U16Range`.
     pub value_u16: T,
+
+    /// The result of the AES S-box on `b`.
+    pub aes_sbox: T,
+
+    /// The value of `b` as a hexadecimal digit, or zero if it is not one.
+    pub hex_decode: T,
+    pub hex_valid: T,
+
+    /// The value of `b` as a base64 character, or zero if it is not one.
+    pub base64_decode: T,
+    pub base64_valid: T,
 }
 
 /// For each byte operation in the preprocessed table, a corresponding ByteMultCols row tracks the
@@ -5,7 +5,10 @@ pub mod columns;
 pub mod trace;
 pub mod utils;
 
-use sp1_core_executor::{events::ByteLookupEvent, ByteOpcode};
+use sp1_core_executor::{
+    events::{base64_decode_char, hex_decode_char, ByteLookupEvent, AES_SBOX},
+    ByteOpcode,
+};
 
 use core::borrow::BorrowMut;
 use std::marker::PhantomData;
@@ -21,16 +24,28 @@ use self::{
 use crate::{bytes::trace::NUM_ROWS, utils::zeroed_f_vec};
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 12;
 
 /// A chip for computing byte operations.
 ///
 /// The chip contains a preprocessed table of all possible byte operations. Other chips can then
 /// use lookups into this table to compute their own operations.
+///
+/// The machine has one table per byte bus: the secondary table proves the lookups of the chips
+/// moved onto the secondary bus, so that the byte multiplicities of a shard are split in two.
 #[derive(Debug, Clone, Copy, Default)]
-pub struct ByteChip<F>(PhantomData<F>);
+pub struct ByteChip<F> {
+    /// Whether the chip receives the lookups of the secondary byte bus.
+    secondary: bool,
+    _marker: PhantomData<F>,
+}
 
 impl<F: Field> ByteChip<F> {
+    /// Creates the table receiving the lookups of the secondary byte bus.
+    pub const fn secondary() -> Self {
+        Self { secondary: true, _marker: PhantomData }
+    }
+
     /// Creates the preprocessed byte trace.
     ///
     /// This function returns a `trace` which is a matrix containing all possible byte operations.
@@ -100,6 +115,37 @@ impl<F: Field> ByteChip<F> {
                         col.value_u16 = F::from_canonical_u32(v);
                         ByteLookupEvent::new(shard, *opcode, v as u16, 0, 0, 0)
                     }
+                    ByteOpcode::AesSbox => {
+                        let sbox = AES_SBOX[b as usize];
+                        col.aes_sbox = F::from_canonical_u8(sbox);
+                        ByteLookupEvent::new(shard, *opcode, sbox as u16, 0, b, 0)
+                    }
+                    ByteOpcode::HexDecode => {
+                        let value = hex_decode_char(b);
+                        col.hex_decode = F::from_canonical_u8(value.unwrap_or_default());
+                        col.hex_valid = F::from_bool(value.is_some());
+                        ByteLookupEvent::new(
+                            shard,
+                            *opcode,
+                            value.unwrap_or_default() as u16,
+                            value.is_some() as u8,
+                            b,
+                            0,
+                        )
+                    }
+                    ByteOpcode::Base64Decode => {
+                        let value = base64_decode_char(b);
+                        col.base64_decode = F::from_canonical_u8(value.unwrap_or_default());
+                        col.base64_valid = F::from_bool(value.is_some());
+                        ByteLookupEvent::new(
+                            shard,
+                            *opcode,
+                            value.unwrap_or_default() as u16,
+                            value.is_some() as u8,
+                            b,
+                            0,
+                        )
+                    }
                 };
             }
         }
@@ -20,7 +20,11 @@ impl<F: Field> MachineAir<F> for ByteChip<F> {
     type Program = Program;
 
     fn name(&self) -> String {
-        "Byte".to_string()
+        if self.secondary {
+            "SecondaryByte".to_string()
+        } else {
+            "Byte".to_string()
+        }
     }
 
     fn preprocessed_width(&self) -> usize {
@@ -44,7 +48,9 @@ impl<F: Field> MachineAir<F> for ByteChip<F> {
         let mut trace =
             RowMajorMatrix::new(zeroed_f_vec(NUM_BYTE_MULT_COLS * NUM_ROWS), NUM_BYTE_MULT_COLS);
 
-        for (_, blu) in input.byte_lookups.iter() {
+        let byte_lookups =
+            if self.secondary { &input.secondary_byte_lookups } else { &input.byte_lookups };
+        for (_, blu) in byte_lookups.iter() {
             for (lookup, mult) in blu.iter() {
                 let row = if lookup.opcode != ByteOpcode::U16Range {
                     (((lookup.b as u16) << 8) + lookup.c as u16) as usize
@@ -1,6 +1,6 @@
 use p3_air::AirBuilder;
 use p3_field::AbstractField;
-use sp1_core_executor::syscalls::SyscallCode;
+use sp1_core_executor::syscalls::{SyscallCode, HALT_TYPED_EXIT};
 use sp1_stark::{
     air::{
         BaseAirBuilder, InteractionScope, PublicValues, SP1AirBuilder, POSEIDON_NUM_WORDS,
@@ -44,6 +44,7 @@ impl CpuChip {
         // with different information.
         let syscall_id = syscall_code[0];
         let send_to_table = syscall_code[1];
+        let returns_status = syscall_code[3];
 
         // Handle cases:
         // - is_ecall_instruction = 1 => ecall_mul_send_to_table == send_to_table
@@ -51,11 +52,25 @@ impl CpuChip {
         builder
             .assert_eq(local.ecall_mul_send_to_table, send_to_table * is_ecall_instruction.clone());
 
+        // A syscall that returns a status writes a bit to op_a. The status is zero for the other
+        // syscalls, and the handler's table receives it folded into the syscall id, so that the
+        // status written is the one the handler proved.
+        builder.when(is_ecall_instruction.clone()).assert_bool(ecall_cols.status);
+        builder
+            .when(is_ecall_instruction.clone())
+            .when_not(returns_status)
+            .assert_zero(ecall_cols.status);
+        builder.when(is_ecall_instruction.clone() * returns_status).assert_one(send_to_table);
+        builder.when(is_ecall_instruction.clone() * returns_status).assert_word_eq(
+            local.op_a_val(),
+            Word([ecall_cols.status.into(), AB::Expr::zero(), AB::Expr::zero(), AB::Expr::zero()]),
+        );
+
         builder.send_syscall(
             local.shard,
             local.clk,
             ecall_cols.syscall_nonce,
-            syscall_id,
+            syscall_id + ecall_cols.status * AB::F::from_canonical_u32(1 << 8),
             local.op_b_val().reduce::<AB>(),
             local.op_c_val().reduce::<AB>(),
             local.ecall_mul_send_to_table,
@@ -91,10 +106,11 @@ impl CpuChip {
             .when(is_ecall_instruction.clone() * is_enter_unconstrained)
             .assert_word_eq(local.op_a_val(), zero_word);
 
-        // When the syscall is not one of ENTER_UNCONSTRAINED or HINT_LEN, op_a shouldn't change.
+        // When the syscall is not one of ENTER_UNCONSTRAINED or HINT_LEN and does not return a
+        // status, op_a shouldn't change.
         builder
             .when(is_ecall_instruction.clone())
-            .when_not(is_enter_unconstrained + is_hint_len)
+            .when_not(is_enter_unconstrained + is_hint_len + returns_status)
             .assert_word_eq(local.op_a_val(), local.op_a_access.prev_value);
 
         // Verify value of ecall_range_check_operand column.
@@ -220,6 +236,18 @@ impl CpuChip {
         builder
             .when(is_halt.clone())
             .assert_eq(local.op_b_access.value().reduce::<AB>(), public_values.exit_code);
+
+        // Only a typed halt, whose second operand is `HALT_TYPED_EXIT`, can commit to a non-zero
+        // exit code, so that the exit code of a failure such as a panic cannot be proven. The top
+        // byte of the operand is zero, so that it reduces to `HALT_TYPED_EXIT` only if it is equal
+        // to it.
+        let exit_code: AB::Expr = public_values.exit_code.into();
+        let op_c = local.op_c_val();
+        builder.when(is_halt.clone()).assert_zero(
+            exit_code.clone()
+                * (op_c.reduce::<AB>() - AB::Expr::from_canonical_u32(HALT_TYPED_EXIT)),
+        );
+        builder.when(is_halt).assert_zero(exit_code * op_c[3]);
     }
 
     /// Returns a boolean expression indicating whether the instruction is a HALT instruction.
@@ -7,7 +7,6 @@ use core::borrow::Borrow;
 use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, BaseAir};
 use p3_field::AbstractField;
 use p3_matrix::Matrix;
-use sp1_core_executor::ByteOpcode;
 use sp1_stark::{
     air::{BaseAirBuilder, PublicValues, SP1AirBuilder, SP1_PROOF_NUM_PV_ELTS},
     Word,
@@ -243,8 +242,8 @@ impl CpuChip {
     /// Constraints related to the shard and clk.
     ///
     /// This method ensures that all of the shard values are the same and that the clk starts at 0
-    /// and is transitioned appropriately.  It will also check that shard values are within 16 bits
-    /// and clk values are within 24 bits.  Those range checks are needed for the memory access
+    /// and is transitioned appropriately.  It will also check that shard and clk values are within
+    /// 24 bits.  Those range checks are needed for the memory access
     /// timestamp check, which assumes those values are within 2^24.  See
     /// [`MemoryAirBuilder::verify_mem_access_ts`].
     pub(crate) fn eval_shard_clk<AB: SP1AirBuilder>(
@@ -256,12 +255,11 @@ impl CpuChip {
         // Verify that all shard values are the same.
         builder.when_transition().when(next.is_real).assert_eq(local.shard, next.shard);
 
-        // Verify that the shard value is within 16 bits.
-        builder.send_byte(
-            AB::Expr::from_canonical_u8(ByteOpcode::U16Range as u8),
+        // Verify that the shard value is within 24 bits using it's limb values.
+        builder.eval_range_check_24bits(
             local.shard,
-            AB::Expr::zero(),
-            AB::Expr::zero(),
+            local.shard_16bit_limb,
+            local.shard_8bit_limb,
             local.is_real,
         );
 
@@ -31,6 +31,9 @@ pub struct EcallCols<T> {
     /// The nonce of the syscall operation.
     pub syscall_nonce: T,
 
+    /// The status the syscall writes to op_a, if it returns one, and zero otherwise.
+    pub status: T,
+
     /// Columns to babybear range check the halt/commit_deferred_proofs operand.
     pub operand_range_check_cols: BabyBearWordRangeChecker<T>,
 
@@ -31,8 +31,12 @@ pub const CPU_COL_MAP: CpuCols<usize> = make_col_map();
 #[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
 #[repr(C)]
 pub struct CpuCols<T: Copy> {
-    /// The current shard.
+    /// The current shard.  This should be within 24 bits.
     pub shard: T,
+    /// The least significant 16 bit limb of shard.
+    pub shard_16bit_limb: T,
+    /// The most significant 8 bit limb of shard.
+    pub shard_8bit_limb: T,
 
     pub nonce: T,
 
@@ -213,6 +213,11 @@ impl CpuChip {
         cols.shard = F::from_canonical_u32(shard);
         cols.clk = F::from_canonical_u32(event.clk);
 
+        let shard_16bit_limb = (shard & 0xffff) as u16;
+        let shard_8bit_limb = ((shard >> 16) & 0xff) as u8;
+        cols.shard_16bit_limb = F::from_canonical_u16(shard_16bit_limb);
+        cols.shard_8bit_limb = F::from_canonical_u8(shard_8bit_limb);
+
         let clk_16bit_limb = (event.clk & 0xffff) as u16;
         let clk_8bit_limb = ((event.clk >> 16) & 0xff) as u8;
         cols.clk_16bit_limb = F::from_canonical_u16(clk_16bit_limb);
@@ -221,10 +226,18 @@ impl CpuChip {
         blu_events.add_byte_lookup_event(ByteLookupEvent::new(
             shard,
             U16Range,
-            shard as u16,
+            shard_16bit_limb,
+            0,
+            0,
+            0,
+        ));
+        blu_events.add_byte_lookup_event(ByteLookupEvent::new(
+            shard,
+            ByteOpcode::U8Range,
             0,
             0,
             0,
+            shard_8bit_limb,
         ));
         blu_events.add_byte_lookup_event(ByteLookupEvent::new(
             shard,
@@ -552,6 +565,11 @@ impl CpuChip {
             ecall_cols.syscall_nonce =
                 F::from_canonical_u32(nonce_lookup[event.syscall_lookup_id.0 as usize]);
 
+            // Write the status, if the syscall returns one.
+            if cols.op_a_access.prev_value[3] == F::one() {
+                ecall_cols.status = cols.op_a_access.value()[0];
+            }
+
             is_halt = syscall_id == F::from_canonical_u32(SyscallCode::HALT.syscall_id());
 
             // For halt and commit deferred proofs syscalls, we need to baby bear range check one of
@@ -1,5 +1,8 @@
+use std::io::Read;
+
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use sp1_core_executor::SP1ReduceProof;
+use sp1_core_executor::{Executor, SP1ReduceProof, StdinStream};
+use sp1_primitives::io::hint_digest;
 use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, StarkVerifyingKey};
 
 /// Standard input for the prover.
@@ -10,17 +13,30 @@ pub struct SP1Stdin {
     pub buffer: Vec<Vec<u8>>,
     pub ptr: usize,
     pub proofs: Vec<(SP1ReduceProof<BabyBearPoseidon2>, StarkVerifyingKey<BabyBearPoseidon2>)>,
+    /// The source the inputs following the buffer are read from lazily, as the program consumes
+    /// them. It is not serialized, and clones share it, so it serves a single execution.
+    #[serde(skip)]
+    pub stream: Option<StdinStream>,
 }
 
 impl SP1Stdin {
     /// Create a new `SP1Stdin`.
     pub const fn new() -> Self {
-        Self { buffer: Vec::new(), ptr: 0, proofs: Vec::new() }
+        Self { buffer: Vec::new(), ptr: 0, proofs: Vec::new(), stream: None }
     }
 
     /// Create a `SP1Stdin` from a slice of bytes.
     pub fn from(data: &[u8]) -> Self {
-        Self { buffer: vec![data.to_vec()], ptr: 0, proofs: Vec::new() }
+        Self { buffer: vec![data.to_vec()], ptr: 0, proofs: Vec::new(), stream: None }
+    }
+
+    /// Create a `SP1Stdin` whose inputs are read lazily from `reader` as the program consumes
+    /// them, so that large inputs (e.g. a file or a socket) are never held in memory all at once.
+    ///
+    /// The reader holds the inputs as frames written with [`StdinStream::write_frame`]. Inputs
+    /// written to the buffer are read by the program before those of the reader.
+    pub fn from_reader(reader: impl Read + Send + 'static) -> Self {
+        Self { stream: Some(StdinStream::new(reader)), ..Self::new() }
     }
 
     /// Read a value from the buffer.
@@ -44,6 +60,25 @@ impl SP1Stdin {
         self.buffer.push(tmp);
     }
 
+    /// Write a value to the buffer, returning the digest of its serialization, against which the
+    /// program checks it with `sp1_zkvm::io::read_verified`.
+    pub fn write_verified<T: Serialize>(&mut self, data: &T) -> [u8; 32] {
+        self.write(data);
+        hint_digest(self.buffer.last().unwrap())
+    }
+
+    /// Write the timestamp the program reads with `sp1_zkvm::env::timestamp`, which commits it to
+    /// the public values stream.
+    pub fn write_timestamp(&mut self, timestamp: u64) {
+        self.write(&timestamp);
+    }
+
+    /// Write the seed the program reads with `sp1_zkvm::env::seed`, which commits it to the public
+    /// values stream.
+    pub fn write_seed(&mut self, seed: [u8; 32]) {
+        self.write(&seed);
+    }
+
     /// Write a slice of bytes to the buffer.
     pub fn write_slice(&mut self, slice: &[u8]) {
         self.buffer.push(slice.to_vec());
@@ -60,6 +95,17 @@ impl SP1Stdin {
     ) {
         self.proofs.push((proof, vk));
     }
+
+    /// Write the inputs and the proofs to the streams of `runtime`.
+    pub fn write_to(&self, runtime: &mut Executor<'_>) {
+        runtime.write_vecs(&self.buffer);
+        if let Some(stream) = &self.stream {
+            runtime.write_stdin_stream(stream.clone());
+        }
+        for (proof, vk) in &self.proofs {
+            runtime.write_proof(proof.clone(), vk.clone());
+        }
+    }
 }
 
 pub mod proof_serde {
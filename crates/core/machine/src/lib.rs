@@ -24,6 +24,8 @@ pub mod operations;
 pub mod program;
 pub mod riscv;
 pub mod syscall;
+#[cfg(feature = "sys")]
+pub mod sys;
 pub mod utils;
 
 /// The global version for all components of SP1.
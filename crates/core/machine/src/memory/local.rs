@@ -21,7 +21,7 @@ pub(crate) const NUM_MEMORY_LOCAL_INIT_COLS: usize = size_of::<MemoryLocalCols<u
 
 #[derive(AlignedBorrow, Debug, Clone, Copy)]
 #[repr(C)]
-struct SingleMemoryLocal<T> {
+pub struct SingleMemoryLocal<T> {
     /// The address of the memory access.
     pub addr: T,
 
@@ -50,7 +50,7 @@ struct SingleMemoryLocal<T> {
 #[derive(AlignedBorrow, Debug, Clone, Copy)]
 #[repr(C)]
 pub struct MemoryLocalCols<T> {
-    memory_local_entries: [SingleMemoryLocal<T>; NUM_LOCAL_MEMORY_ENTRIES_PER_ROW],
+    pub memory_local_entries: [SingleMemoryLocal<T>; NUM_LOCAL_MEMORY_ENTRIES_PER_ROW],
 }
 
 pub struct MemoryLocalChip {}
@@ -187,12 +187,14 @@ where
 #[cfg(test)]
 mod tests {
     use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
     use p3_matrix::dense::RowMajorMatrix;
     use sp1_core_executor::{programs::tests::simple_program, ExecutionRecord, Executor};
     use sp1_stark::{
         air::{InteractionScope, MachineAir},
         baby_bear_poseidon2::BabyBearPoseidon2,
-        debug_interactions_with_all_chips, InteractionKind, SP1CoreOpts, StarkMachine,
+        debug_interactions_with_all_chips, interaction_stats, InteractionKind, SP1CoreOpts,
+        StarkMachine,
     };
 
     use crate::{
@@ -280,4 +282,40 @@ mod tests {
             InteractionScope::Global,
         );
     }
+
+    #[test]
+    fn test_interaction_stats() {
+        let program = simple_program();
+        let program_clone = program.clone();
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let (pkey, _) = machine.setup(&program_clone);
+        let opts = SP1CoreOpts::default();
+        machine.generate_dependencies(&mut runtime.records, &opts, None);
+
+        let mut shard = runtime.records[0].clone();
+        let stats = interaction_stats::<BabyBearPoseidon2, RiscvAir<BabyBear>>(
+            &machine,
+            &pkey,
+            &[shard.clone()],
+            InteractionScope::Local,
+        );
+        assert!(stats.is_balanced(), "{stats}");
+        assert!(stats.bus_tally(InteractionKind::Alu).count > 0);
+
+        // Dropping the byte lookups leaves the byte bus unbalanced, with the byte chip receiving
+        // nothing of what the other chips send.
+        shard.byte_lookups.clear();
+        let stats = interaction_stats::<BabyBearPoseidon2, RiscvAir<BabyBear>>(
+            &machine,
+            &pkey,
+            &[shard],
+            InteractionScope::Local,
+        );
+        assert_eq!(stats.unbalanced_kinds(), vec![InteractionKind::Byte]);
+        let tally = stats.bus_tally(InteractionKind::Byte);
+        assert_eq!(tally.received, BabyBear::zero());
+        assert_eq!(tally.net(), tally.sent);
+    }
 }
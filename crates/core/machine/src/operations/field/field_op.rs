@@ -61,7 +61,8 @@ impl<F: PrimeField32, P: FieldParameters> FieldOpCols<F, P> {
         let result = &mul_add % modulus;
         let carry = (mul_add - &result) / modulus;
         debug_assert!(&result < modulus);
-        debug_assert!(&carry < modulus);
+        // The carry only has to fit in the limbs, which allows operands larger than the modulus.
+        debug_assert!(carry.bits() <= P::nb_bits() as u64);
         debug_assert_eq!(&carry * modulus, a * b + c - &result);
 
         let p_modulus_limbs =
@@ -113,7 +114,8 @@ impl<F: PrimeField32, P: FieldParameters> FieldOpCols<F, P> {
             FieldOperation::Sub | FieldOperation::Div => unreachable!(),
         };
         debug_assert!(&result < modulus);
-        debug_assert!(&carry < modulus);
+        // The carry only has to fit in the limbs, which allows operands larger than the modulus.
+        debug_assert!(carry.bits() <= P::nb_bits() as u64);
         match op {
             FieldOperation::Add => debug_assert_eq!(&carry * modulus, a + b - &result),
             FieldOperation::Mul => debug_assert_eq!(&carry * modulus, a * b - &result),
@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+
+use num::BigUint;
+
+use p3_air::AirBuilder;
+use p3_field::{AbstractField, PrimeField32};
+
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    ByteOpcode,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{Polynomial, SP1AirBuilder};
+
+use super::util::compute_root_quotient_and_shift;
+use crate::air::WordAirBuilder;
+use sp1_curves::params::{FieldParameters, Limbs};
+
+/// A set of columns to compute `a * b mod n` for operands of a few hundred byte limbs, where the
+/// modulus `n` is an input of the operation.
+///
+/// The coefficients of the witness polynomial of such wide products do not fit in the two bytes
+/// that [`super::field_op::FieldOpCols`] range checks, so each of them is split into a `u16` low
+/// part and a high byte whose most significant bit is unset. With `P::WITNESS_OFFSET = 2^22`, the
+/// coefficients of the constraint polynomial stay below the order of the field.
+///
+/// *Safety* The operands and the modulus are assumed to be `P::NB_LIMBS` byte limbs. The result is
+/// not constrained to be reduced, which is left to the caller.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WideFieldMulCols<T, P: FieldParameters> {
+    /// The result of `a * b mod n`.
+    pub result: Limbs<T, P::Limbs>,
+    pub carry: Limbs<T, P::Limbs>,
+    pub(crate) witness_low: Limbs<T, P::Witness>,
+    pub(crate) witness_high: Limbs<T, P::Witness>,
+}
+
+impl<F: PrimeField32, P: FieldParameters> WideFieldMulCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        a: &BigUint,
+        b: &BigUint,
+        modulus: &BigUint,
+    ) -> BigUint {
+        let product = a * b;
+        let result = &product % modulus;
+        let carry = (&product - &result) / modulus;
+        debug_assert!(carry.bits() <= P::nb_bits() as u64);
+
+        let p_a: Polynomial<F> = P::to_limbs_field::<F, _>(a).into();
+        let p_b: Polynomial<F> = P::to_limbs_field::<F, _>(b).into();
+        let p_modulus: Polynomial<F> = P::to_limbs_field::<F, _>(modulus).into();
+        let p_result: Polynomial<F> = P::to_limbs_field::<F, _>(&result).into();
+        let p_carry: Polynomial<F> = P::to_limbs_field::<F, _>(&carry).into();
+
+        // Compute the vanishing polynomial.
+        let p_vanishing: Polynomial<F> = &p_a * &p_b - &p_result - &p_carry * &p_modulus;
+
+        let p_witness = compute_root_quotient_and_shift(
+            &p_vanishing,
+            P::WITNESS_OFFSET,
+            P::NB_BITS_PER_LIMB as u32,
+            P::NB_WITNESS_LIMBS,
+        );
+
+        self.result = p_result.into();
+        self.carry = p_carry.into();
+        for (i, coefficient) in p_witness.iter().enumerate() {
+            let coefficient = coefficient.as_canonical_u32();
+            debug_assert!(coefficient < 1 << 23);
+            let (low, high) = (coefficient as u16, (coefficient >> 16) as u8);
+            self.witness_low[i] = F::from_canonical_u16(low);
+            self.witness_high[i] = F::from_canonical_u8(high);
+            record.add_u16_range_check(shard, low);
+            record.add_byte_lookup_event(ByteLookupEvent::new(
+                shard,
+                ByteOpcode::MSB,
+                0,
+                0,
+                high,
+                0,
+            ));
+        }
+
+        record.add_u8_range_checks_field(shard, &self.result.0);
+        record.add_u8_range_checks_field(shard, &self.carry.0);
+
+        result
+    }
+}
+
+impl<V: Copy, P: FieldParameters> WideFieldMulCols<V, P> {
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        b: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        modulus: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+        Limbs<V, P::Limbs>: Copy,
+        Limbs<V, P::Witness>: Copy,
+    {
+        let p_a: Polynomial<AB::Expr> = (a).clone().into();
+        let p_b: Polynomial<AB::Expr> = (b).clone().into();
+        let p_modulus: Polynomial<AB::Expr> = (modulus).clone().into();
+        let p_result: Polynomial<AB::Expr> = self.result.into();
+        let p_carry: Polynomial<AB::Expr> = self.carry.into();
+        let p_vanishing = p_a * p_b - &p_result - &(&p_carry * &p_modulus);
+
+        // Reconstruct and shift back the witness polynomial.
+        let offset = AB::F::from_canonical_u32(P::WITNESS_OFFSET as u32);
+        let p_witness = Polynomial::new(
+            self.witness_low
+                .0
+                .iter()
+                .zip(self.witness_high.0.iter())
+                .map(|(&low, &high)| {
+                    low.into() + high.into() * AB::F::from_canonical_u32(1 << 16) - offset
+                })
+                .collect(),
+        );
+
+        // Multiply by (x-2^NB_BITS_PER_LIMB) and make the constraint.
+        let limb: AB::Expr = AB::F::from_canonical_u32(2u32.pow(P::NB_BITS_PER_LIMB as u32)).into();
+        let root_monomial = Polynomial::new(vec![-limb, AB::Expr::one()]);
+        let constraints = p_vanishing - &(p_witness * root_monomial);
+        for constr in constraints.as_coefficients() {
+            builder.when(is_real.clone()).assert_zero(constr);
+        }
+
+        // Range checks for the result, carry, and witness columns.
+        builder.slice_range_check_u8(&self.result.0, is_real.clone());
+        builder.slice_range_check_u8(&self.carry.0, is_real.clone());
+        builder.slice_range_check_u16(&self.witness_low.0, is_real.clone());
+        for &high in self.witness_high.0.iter() {
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::MSB as u32),
+                AB::Expr::zero(),
+                high,
+                AB::Expr::zero(),
+                is_real.clone(),
+            );
+        }
+    }
+}
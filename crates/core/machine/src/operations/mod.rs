@@ -19,6 +19,7 @@ mod is_zero_word;
 mod lt;
 mod not;
 mod or;
+mod word64;
 mod xor;
 
 pub use add::*;
@@ -35,4 +36,5 @@ pub use is_zero_word::*;
 pub use lt::*;
 pub use not::*;
 pub use or::*;
+pub use word64::*;
 pub use xor::*;
@@ -0,0 +1,336 @@
+//! Operations on 64-bit words, given as their 8 little-endian bytes.
+
+use p3_air::AirBuilder;
+use p3_field::{AbstractField, Field};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord},
+    ByteOpcode,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::SP1AirBuilder;
+
+use crate::{air::WordAirBuilder, bytes::utils::shr_carry};
+
+/// The number of bytes of a 64-bit word.
+pub const U64_SIZE: usize = 8;
+
+/// A 64-bit word, given as its little-endian bytes.
+pub type U64<T> = [T; U64_SIZE];
+
+/// Converts a 64-bit word to its little-endian bytes as field elements.
+pub fn u64_to_field<F: Field>(value: u64) -> U64<F> {
+    value.to_le_bytes().map(F::from_canonical_u8)
+}
+
+/// A set of columns needed to compute the xor of two 64-bit words.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Xor64Operation<T> {
+    /// The result of `x ^ y`.
+    pub value: U64<T>,
+}
+
+impl<F: Field> Xor64Operation<F> {
+    pub fn populate(&mut self, record: &mut impl ByteRecord, shard: u32, x: u64, y: u64) -> u64 {
+        let expected = x ^ y;
+        let x_bytes = x.to_le_bytes();
+        let y_bytes = y.to_le_bytes();
+        for i in 0..U64_SIZE {
+            let xor = x_bytes[i] ^ y_bytes[i];
+            self.value[i] = F::from_canonical_u8(xor);
+
+            let byte_event = ByteLookupEvent {
+                shard,
+                opcode: ByteOpcode::XOR,
+                a1: xor as u16,
+                a2: 0,
+                b: x_bytes[i],
+                c: y_bytes[i],
+            };
+            record.add_byte_lookup_event(byte_event);
+        }
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        a: U64<AB::Var>,
+        b: U64<AB::Var>,
+        cols: Xor64Operation<AB::Var>,
+        is_real: AB::Var,
+    ) {
+        for i in 0..U64_SIZE {
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                cols.value[i],
+                a[i],
+                b[i],
+                is_real,
+            );
+        }
+    }
+}
+
+/// A set of columns needed to compute the and of two 64-bit words.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct And64Operation<T> {
+    /// The result of `x & y`.
+    pub value: U64<T>,
+}
+
+impl<F: Field> And64Operation<F> {
+    pub fn populate(&mut self, record: &mut impl ByteRecord, shard: u32, x: u64, y: u64) -> u64 {
+        let expected = x & y;
+        let x_bytes = x.to_le_bytes();
+        let y_bytes = y.to_le_bytes();
+        for i in 0..U64_SIZE {
+            let and = x_bytes[i] & y_bytes[i];
+            self.value[i] = F::from_canonical_u8(and);
+
+            let byte_event = ByteLookupEvent {
+                shard,
+                opcode: ByteOpcode::AND,
+                a1: and as u16,
+                a2: 0,
+                b: x_bytes[i],
+                c: y_bytes[i],
+            };
+            record.add_byte_lookup_event(byte_event);
+        }
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        a: U64<AB::Var>,
+        b: U64<AB::Var>,
+        cols: And64Operation<AB::Var>,
+        is_real: AB::Var,
+    ) {
+        for i in 0..U64_SIZE {
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::AND as u32),
+                cols.value[i],
+                a[i],
+                b[i],
+                is_real,
+            );
+        }
+    }
+}
+
+/// A set of columns needed to compute the bitwise not of a 64-bit word.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Not64Operation<T> {
+    /// The result of `!x`.
+    pub value: U64<T>,
+}
+
+impl<F: Field> Not64Operation<F> {
+    pub fn populate(&mut self, record: &mut impl ByteRecord, shard: u32, x: u64) -> u64 {
+        let expected = !x;
+        let x_bytes = x.to_le_bytes();
+        for i in 0..U64_SIZE {
+            self.value[i] = F::from_canonical_u8(!x_bytes[i]);
+        }
+        record.add_u8_range_checks(shard, &x_bytes);
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        a: U64<AB::Var>,
+        cols: Not64Operation<AB::Var>,
+        is_real: AB::Var,
+    ) {
+        builder.slice_range_check_u8(&a, is_real);
+        for i in 0..U64_SIZE {
+            builder
+                .when(is_real)
+                .assert_eq(cols.value[i] + a[i], AB::F::from_canonical_u8(u8::MAX));
+        }
+    }
+}
+
+/// A set of columns needed to compute the rotation or the shift to the right of a 64-bit word by a
+/// fixed offset.
+///
+/// Like [`super::FixedRotateRightOperation`], the offset is decomposed into a byte shift and a bit
+/// shift.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixedShift64Operation<T> {
+    /// The output value.
+    pub value: U64<T>,
+
+    /// The shift output of `shrcarry` on each byte of the word.
+    pub shift: U64<T>,
+
+    /// The carry output of `shrcarry` on each byte of the word.
+    pub carry: U64<T>,
+}
+
+impl<F: Field> FixedShift64Operation<F> {
+    pub const fn nb_bytes_to_shift(offset: usize) -> usize {
+        offset / 8
+    }
+
+    pub const fn nb_bits_to_shift(offset: usize) -> usize {
+        offset % 8
+    }
+
+    pub const fn carry_multiplier(offset: usize) -> u32 {
+        1 << (8 - Self::nb_bits_to_shift(offset))
+    }
+
+    /// Populates the rotation of `input` by `offset` if `rotate` is set, and the shift otherwise.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        input: u64,
+        offset: usize,
+        rotate: bool,
+    ) -> u64 {
+        let expected = if rotate { input.rotate_right(offset as u32) } else { input >> offset };
+        let input_bytes = input.to_le_bytes();
+        let nb_bytes_to_shift = Self::nb_bytes_to_shift(offset);
+        let nb_bits_to_shift = Self::nb_bits_to_shift(offset) as u8;
+        let carry_multiplier = F::from_canonical_u32(Self::carry_multiplier(offset));
+
+        // The bytes shifted out of the word are zero, unless the word is rotated.
+        let shifted_bytes: [u8; U64_SIZE] = core::array::from_fn(|i| {
+            let index = i + nb_bytes_to_shift;
+            if rotate || index < U64_SIZE {
+                input_bytes[index % U64_SIZE]
+            } else {
+                0
+            }
+        });
+
+        for i in 0..U64_SIZE {
+            let (shift, carry) = shr_carry(shifted_bytes[i], nb_bits_to_shift);
+            let byte_event = ByteLookupEvent {
+                shard,
+                opcode: ByteOpcode::ShrCarry,
+                a1: shift as u16,
+                a2: carry,
+                b: shifted_bytes[i],
+                c: nb_bits_to_shift,
+            };
+            record.add_byte_lookup_event(byte_event);
+
+            self.shift[i] = F::from_canonical_u8(shift);
+            self.carry[i] = F::from_canonical_u8(carry);
+        }
+
+        // Each byte takes the bits carried out of the next one, which wraps around for rotations.
+        for i in 0..U64_SIZE {
+            let next_carry =
+                if i + 1 < U64_SIZE || rotate { self.carry[(i + 1) % U64_SIZE] } else { F::zero() };
+            self.value[i] = self.shift[i] + next_carry * carry_multiplier;
+        }
+        debug_assert_eq!(self.value, u64_to_field(expected));
+
+        expected
+    }
+
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        input: U64<AB::Var>,
+        offset: usize,
+        rotate: bool,
+        cols: FixedShift64Operation<AB::Var>,
+        is_real: AB::Var,
+    ) {
+        let nb_bytes_to_shift = Self::nb_bytes_to_shift(offset);
+        let nb_bits_to_shift = Self::nb_bits_to_shift(offset);
+        let carry_multiplier = AB::F::from_canonical_u32(Self::carry_multiplier(offset));
+
+        for i in 0..U64_SIZE {
+            let index = i + nb_bytes_to_shift;
+            let shifted_byte: AB::Expr = if rotate || index < U64_SIZE {
+                input[index % U64_SIZE].into()
+            } else {
+                AB::Expr::zero()
+            };
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::ShrCarry as u32),
+                cols.shift[i],
+                cols.carry[i],
+                shifted_byte,
+                AB::F::from_canonical_usize(nb_bits_to_shift),
+                is_real,
+            );
+        }
+
+        for i in 0..U64_SIZE {
+            let next_carry: AB::Expr = if i + 1 < U64_SIZE || rotate {
+                cols.carry[(i + 1) % U64_SIZE].into()
+            } else {
+                AB::Expr::zero()
+            };
+            builder.assert_eq(cols.value[i], cols.shift[i] + next_carry * carry_multiplier);
+        }
+    }
+}
+
+/// A set of columns needed to compute the wrapping sum of a few 64-bit words.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Add64Operation<T> {
+    /// The result of the sum.
+    pub value: U64<T>,
+
+    /// The carry out of each byte.
+    pub carry: U64<T>,
+}
+
+impl<F: Field> Add64Operation<F> {
+    pub fn populate(&mut self, record: &mut impl ByteRecord, shard: u32, operands: &[u64]) -> u64 {
+        let expected = operands.iter().fold(0u64, |acc, &operand| acc.wrapping_add(operand));
+        let mut carry = 0u32;
+        let mut carries = [0u8; U64_SIZE];
+        for i in 0..U64_SIZE {
+            let sum =
+                operands.iter().map(|&operand| (operand >> (8 * i)) as u8 as u32).sum::<u32>()
+                    + carry;
+            carry = sum >> 8;
+            carries[i] = carry as u8;
+        }
+        self.value = u64_to_field(expected);
+        self.carry = carries.map(F::from_canonical_u8);
+
+        record.add_u8_range_checks(shard, &expected.to_le_bytes());
+        record.add_u8_range_checks(shard, &carries);
+        expected
+    }
+
+    /// Evaluates the sum of the operands, whose bytes must be range checked by the caller.
+    ///
+    /// The carries are only range checked as bytes, which is enough for them to be unique: with
+    /// byte operands and results, the sum of each byte can not wrap around the field.
+    pub fn eval<AB: SP1AirBuilder>(
+        builder: &mut AB,
+        operands: &[U64<AB::Var>],
+        cols: Add64Operation<AB::Var>,
+        is_real: AB::Var,
+    ) {
+        builder.slice_range_check_u8(&cols.value, is_real);
+        builder.slice_range_check_u8(&cols.carry, is_real);
+
+        let base = AB::F::from_canonical_u32(256);
+        for i in 0..U64_SIZE {
+            let mut sum = operands
+                .iter()
+                .map(|operand| operand[i].into())
+                .fold(AB::Expr::zero(), |acc, byte: AB::Expr| acc + byte);
+            if i > 0 {
+                sum = sum + cols.carry[i - 1];
+            }
+            builder.when(is_real).assert_eq(sum, cols.value[i] + cols.carry[i] * base);
+        }
+    }
+}
@@ -185,7 +185,6 @@ mod tests {
 
     use std::sync::Arc;
 
-    use hashbrown::HashMap;
     use p3_baby_bear::BabyBear;
 
     use p3_matrix::dense::RowMajorMatrix;
@@ -206,13 +205,7 @@ mod tests {
             Instruction::new(Opcode::ADD, 31, 30, 29, false, false),
         ];
         let shard = ExecutionRecord {
-            program: Arc::new(Program {
-                instructions,
-                pc_start: 0,
-                pc_base: 0,
-                memory_image: HashMap::new(),
-                preprocessed_shape: None,
-            }),
+            program: Arc::new(Program::new(instructions, 0, 0)),
             ..Default::default()
         };
         let chip = ProgramChip::new();
@@ -0,0 +1,141 @@
+use hashbrown::{HashMap, HashSet};
+use p3_field::PrimeField32;
+use sp1_core_executor::{syscalls::SyscallCode, ExecutionRecord};
+use sp1_stark::air::MachineAir;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use super::RiscvAir;
+
+/// A precompile called by the program, whose chip is missing from the machine.
+///
+/// Proving such a program would only fail when verifying the lookups, with the events of the
+/// precompile sent by the CPU but received by no chip.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("record contains {syscall} events but machine has no {chip} chip")]
+pub struct MissingChipError {
+    /// The syscall of the precompile, as its events are keyed in the record.
+    pub syscall: SyscallCode,
+    /// The name of the missing chip.
+    pub chip: String,
+}
+
+/// Checks that a machine has the chips of every precompile the programs it proves call.
+///
+/// The chips proving a precompile are those of the RISC-V machine, along with the chips proving
+/// their lookups. Syscalls without a chip in the RISC-V machine, such as the out-of-tree
+/// precompiles, are not checked.
+#[derive(Debug, Clone)]
+pub struct ChipAudit {
+    required: HashMap<SyscallCode, Vec<String>>,
+    chips: HashSet<String>,
+}
+
+impl ChipAudit {
+    /// Creates an audit of the machine with the given chip names.
+    #[must_use]
+    pub fn new<F: PrimeField32>(chips: impl IntoIterator<Item = String>) -> Self {
+        let required = RiscvAir::<F>::get_all_precompile_airs()
+            .into_iter()
+            .map(|(air, _)| {
+                let names = std::iter::once(air.name())
+                    .chain(air.precompile_lookup_airs().iter().map(MachineAir::name))
+                    .collect();
+                (air.syscall_code(), names)
+            })
+            .collect();
+        Self { required, chips: chips.into_iter().collect() }
+    }
+
+    /// Checks that the machine has the chips of the given syscalls.
+    pub fn check_syscalls(
+        &self,
+        syscalls: impl IntoIterator<Item = SyscallCode>,
+    ) -> Result<(), MissingChipError> {
+        for syscall in syscalls {
+            let syscall = syscall.count_map();
+            let Some(required) = self.required.get(&syscall) else { continue };
+            if let Some(chip) = required.iter().find(|chip| !self.chips.contains(*chip)) {
+                return Err(MissingChipError { syscall, chip: chip.clone() });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the machine has the chips of the precompile events of the record.
+    pub fn check_record(&self, record: &ExecutionRecord) -> Result<(), MissingChipError> {
+        self.check_syscalls(SyscallCode::iter().filter(|syscall| {
+            syscall.should_send() == 1
+                && record.precompile_events.get_events(*syscall).is_some_and(|e| !e.is_empty())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use sp1_core_executor::{
+        events::{PrecompileEvent, SyscallEvent},
+        syscalls::SyscallCode,
+        ExecutionRecord, Program,
+    };
+    use sp1_stark::air::MachineAir;
+
+    use super::{ChipAudit, MissingChipError};
+    use crate::{riscv::RiscvAir, syscall::precompiles::keccak256::KeccakPermuteChip};
+
+    fn audit_without(missing: &str) -> ChipAudit {
+        let chips = RiscvAir::<BabyBear>::chips().into_iter().map(|chip| chip.name());
+        ChipAudit::new::<BabyBear>(chips.filter(|name| name != missing))
+    }
+
+    #[test]
+    fn test_chip_audit() {
+        let audit = audit_without("PoseidonMerkle");
+        assert_eq!(
+            audit.check_syscalls([SyscallCode::POSEIDON_MERKLE_ROOT]).unwrap_err().to_string(),
+            "record contains POSEIDON_MERKLE_ROOT events but machine has no PoseidonMerkle chip"
+        );
+
+        // Syscalls without a chip of their own, and precompiles whose chips are present, pass.
+        let syscalls = [SyscallCode::HALT, SyscallCode::SHA_EXTEND, SyscallCode::PLUGIN_0];
+        assert_eq!(audit.check_syscalls(syscalls), Ok(()));
+
+        // The chips proving the lookups of a precompile are required too.
+        let lookup = RiscvAir::<BabyBear>::Bn254MulAdd(Default::default())
+            .precompile_lookup_airs()
+            .pop()
+            .unwrap();
+        assert_eq!(
+            audit_without(&lookup.name()).check_syscalls([SyscallCode::BN254_MULADD_BE]),
+            Err(MissingChipError { syscall: SyscallCode::BN254_MULADD, chip: lookup.name() })
+        );
+    }
+
+    #[test]
+    fn test_chip_audit_record() {
+        let keccak = RiscvAir::<BabyBear>::KeccakP(KeccakPermuteChip::new()).name();
+        let audit = audit_without(&keccak);
+        let mut record = ExecutionRecord::new(Program::new(vec![], 0, 0).into());
+        assert_eq!(audit.check_record(&record), Ok(()));
+
+        let syscall_event = SyscallEvent {
+            shard: 1,
+            clk: 0,
+            lookup_id: Default::default(),
+            syscall_id: SyscallCode::KECCAK_PERMUTE.syscall_id(),
+            arg1: 0,
+            arg2: 0,
+            nonce: 0,
+        };
+        record.add_precompile_event(
+            SyscallCode::KECCAK_PERMUTE,
+            syscall_event,
+            PrecompileEvent::KeccakPermute(Default::default()),
+        );
+        assert_eq!(
+            audit.check_record(&record),
+            Err(MissingChipError { syscall: SyscallCode::KECCAK_PERMUTE, chip: keccak })
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::env;
+
+use hashbrown::HashMap;
+use p3_air::BaseAir;
+use p3_field::PrimeField32;
+use sp1_core_executor::syscalls::SyscallCode;
+use sp1_stark::air::MachineAir;
+
+use super::{CoreShapeConfig, RiscvAir};
+
+/// Caps on the trace area, in cells, of the precompile chips in a single shard.
+///
+/// The events of a precompile are split over as many shards as needed to keep its chip under the
+/// cap, so that bursty use of a precompile does not produce a trace too tall to be proven. Chips
+/// without a cap are split according to the [`sp1_stark::SplitOpts`] thresholds only.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceAreaCaps {
+    caps: HashMap<String, usize>,
+}
+
+impl TraceAreaCaps {
+    /// Creates an empty set of caps.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the caps from the `TRACE_AREA_CAPS` environment variable.
+    ///
+    /// The variable holds a comma separated list of `<chip>=<area>` entries, such as
+    /// `KeccakPermute=8388608,ChaCha20Block=4194304`. Malformed entries are ignored.
+    #[must_use]
+    pub fn from_env() -> Self {
+        env::var("TRACE_AREA_CAPS").map(|caps| Self::parse(&caps)).unwrap_or_default()
+    }
+
+    /// Parses a comma separated list of `<chip>=<area>` entries. Malformed entries are ignored.
+    #[must_use]
+    pub fn parse(caps: &str) -> Self {
+        let caps = caps
+            .split(',')
+            .filter_map(|entry| {
+                let (chip, area) = entry.split_once('=')?;
+                Some((chip.trim().to_string(), area.trim().parse().ok()?))
+            })
+            .collect();
+        Self { caps }
+    }
+
+    /// Caps the trace area of the chip with the given name.
+    pub fn insert(&mut self, chip: impl Into<String>, area: usize) {
+        self.caps.insert(chip.into(), area);
+    }
+
+    /// The cap on the trace area of the chip with the given name.
+    #[must_use]
+    pub fn get(&self, chip: &str) -> Option<usize> {
+        self.caps.get(chip).copied()
+    }
+
+    /// The largest number of events of each capped precompile that fit in a shard.
+    ///
+    /// If a shape config is given, every precompile is also capped by the tallest trace the shape
+    /// config allows for its chip.
+    #[must_use]
+    pub fn event_caps<F: PrimeField32>(
+        &self,
+        shape_config: Option<&CoreShapeConfig<F>>,
+    ) -> HashMap<SyscallCode, usize> {
+        let mut event_caps = HashMap::new();
+        for (air, _) in RiscvAir::<F>::get_all_precompile_airs() {
+            let area_cap = self
+                .get(&air.name())
+                .map(|area| area / (BaseAir::<F>::width(&air) * air.rows_per_event()));
+            let height_cap = shape_config
+                .and_then(|config| config.max_precompile_log_height(&air))
+                .map(|log_height| (1 << log_height) / air.rows_per_event());
+            let cap = match (area_cap, height_cap) {
+                (Some(area_cap), Some(height_cap)) => area_cap.min(height_cap),
+                (cap, None) | (None, cap) => match cap {
+                    Some(cap) => cap,
+                    None => continue,
+                },
+            };
+            event_caps.insert(air.syscall_code(), cap.max(1));
+        }
+        event_caps
+    }
+
+    /// The largest number of global memory initialize or finalize events that fit in a shard.
+    ///
+    /// The memory events are only known once execution is done, and a program touching a lot of
+    /// memory would otherwise finalize all of it in shards as tall as the split threshold allows.
+    /// Returns `None` if neither the memory chips are capped nor a shape config is given.
+    #[must_use]
+    pub fn memory_event_cap<F: PrimeField32>(
+        &self,
+        shape_config: Option<&CoreShapeConfig<F>>,
+    ) -> Option<usize> {
+        let area_cap = RiscvAir::<F>::memory_init_final_airs()
+            .into_iter()
+            .filter_map(|air| self.get(&air.name()).map(|area| area / BaseAir::<F>::width(&air)))
+            .min();
+        let height_cap = shape_config
+            .and_then(CoreShapeConfig::max_memory_log_height)
+            .map(|log_height| 1 << log_height);
+        area_cap.into_iter().chain(height_cap).min().map(|cap| cap.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use sp1_core_executor::{
+        events::{MemoryInitializeFinalizeEvent, PrecompileEvent, SyscallEvent},
+        syscalls::SyscallCode,
+        ExecutionRecord, Program,
+    };
+    use sp1_stark::{air::MachineAir, SplitOpts};
+
+    use super::TraceAreaCaps;
+    use crate::{riscv::RiscvAir, syscall::precompiles::keccak256::KeccakPermuteChip};
+
+    #[test]
+    fn test_trace_area_caps_spill_over() {
+        let keccak = RiscvAir::<BabyBear>::KeccakP(KeccakPermuteChip::new());
+        let width = p3_air::BaseAir::<BabyBear>::width(&keccak);
+        let caps = TraceAreaCaps::parse(&format!("{}={}, Bogus", keccak.name(), 3 * 24 * width));
+        assert_eq!(caps.get(&keccak.name()), Some(3 * 24 * width));
+        let event_caps = caps.event_caps::<BabyBear>(None);
+        assert_eq!(event_caps.get(&SyscallCode::KECCAK_PERMUTE), Some(&3));
+        assert_eq!(event_caps.len(), 1);
+
+        // Ten events split into three full shards, and the last one spills over to a fourth.
+        let mut record = ExecutionRecord::new(Program::new(vec![], 0, 0).into());
+        for clk in 0..10 {
+            let syscall_event = SyscallEvent {
+                shard: 1,
+                clk,
+                lookup_id: Default::default(),
+                syscall_id: SyscallCode::KECCAK_PERMUTE.syscall_id(),
+                arg1: 0,
+                arg2: 0,
+                nonce: clk,
+            };
+            record.add_precompile_event(
+                SyscallCode::KECCAK_PERMUTE,
+                syscall_event,
+                PrecompileEvent::KeccakPermute(Default::default()),
+            );
+        }
+        let shards = record.split(true, SplitOpts::new(1 << 18), &event_caps);
+        let sizes = shards
+            .iter()
+            .map(|shard| shard.get_precompile_events(SyscallCode::KECCAK_PERMUTE).len())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [3, 3, 3, 1]);
+    }
+
+    #[test]
+    fn test_memory_finalize_split_chains() {
+        let finalize = RiscvAir::<BabyBear>::memory_init_final_airs().pop().unwrap();
+        let width = p3_air::BaseAir::<BabyBear>::width(&finalize);
+        let caps = TraceAreaCaps::parse(&format!("{}={}", finalize.name(), 3 * width));
+        assert_eq!(caps.memory_event_cap::<BabyBear>(None), Some(3));
+        assert_eq!(TraceAreaCaps::new().memory_event_cap::<BabyBear>(None), None);
+
+        let mut record = ExecutionRecord::new(Program::new(vec![], 0, 0).into());
+        for addr in (0..10).rev() {
+            record.global_memory_finalize_events.push(MemoryInitializeFinalizeEvent {
+                addr: 4 * addr,
+                value: 0,
+                shard: 1,
+                timestamp: 1,
+                used: 1,
+            });
+        }
+        for addr in 0..4 {
+            record.global_memory_initialize_events.push(MemoryInitializeFinalizeEvent::initialize(
+                4 * addr,
+                0,
+                true,
+            ));
+        }
+
+        let mut opts = SplitOpts::new(1 << 18);
+        opts.memory = 3;
+        let shards = record.split(true, opts, &Default::default());
+        let sizes = shards
+            .iter()
+            .map(|shard| {
+                (
+                    shard.global_memory_initialize_events.len(),
+                    shard.global_memory_finalize_events.len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, [(3, 3), (1, 3), (0, 3), (0, 1)]);
+
+        // Each shard starts where the previous one left off, and the finalized addresses are sorted.
+        for pair in shards.windows(2) {
+            let (prev, next) = (&pair[0].public_values, &pair[1].public_values);
+            assert_eq!(prev.last_init_addr_bits, next.previous_init_addr_bits);
+            assert_eq!(prev.last_finalize_addr_bits, next.previous_finalize_addr_bits);
+        }
+        let addrs = shards
+            .iter()
+            .flat_map(|shard| shard.global_memory_finalize_events.iter().map(|event| event.addr))
+            .collect::<Vec<_>>();
+        assert_eq!(addrs, (0..10).map(|addr| 4 * addr).collect::<Vec<_>>());
+    }
+}
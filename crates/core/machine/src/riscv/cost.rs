@@ -41,6 +41,28 @@ impl CostEstimator for ExecutionReport {
         total_area += (sha_compress_events as u64) * costs[&RiscvAirDiscriminants::Sha256Compress];
         total_chips += 1;
 
+        // Each HMAC-SHA256 call also makes four sha256 extend and compress calls of its own.
+        let hmac_sha256_events = self.syscall_counts[SyscallCode::HMAC_SHA256];
+        total_area += (hmac_sha256_events as u64)
+            * (costs[&RiscvAirDiscriminants::HmacSha256]
+                + 4 * costs[&RiscvAirDiscriminants::Sha256Extend]
+                + 4 * costs[&RiscvAirDiscriminants::Sha256Compress]);
+        total_chips += 1;
+
+        // Each call compresses at most five blocks, with a sha256 extend and compress call each.
+        let sha256_compress_blocks_events =
+            self.syscall_counts[SyscallCode::SHA256_COMPRESS_BLOCKS];
+        total_area += (sha256_compress_blocks_events as u64)
+            * (costs[&RiscvAirDiscriminants::Sha256CompressBlocks]
+                + 5 * costs[&RiscvAirDiscriminants::Sha256Extend]
+                + 5 * costs[&RiscvAirDiscriminants::Sha256Compress]);
+        total_chips += 1;
+
+        let sha512_compress_events = self.syscall_counts[SyscallCode::SHA512_COMPRESS];
+        total_area +=
+            (sha512_compress_events as u64) * costs[&RiscvAirDiscriminants::Sha512Compress];
+        total_chips += 1;
+
         let ed_add_events = self.syscall_counts[SyscallCode::ED_ADD];
         total_area += (ed_add_events as u64) * costs[&RiscvAirDiscriminants::Ed25519Add];
         total_chips += 1;
@@ -50,6 +72,19 @@ impl CostEstimator for ExecutionReport {
             (ed_decompress_events as u64) * costs[&RiscvAirDiscriminants::Ed25519Decompress];
         total_chips += 1;
 
+        let ed25519_verify_events = self.syscall_counts[SyscallCode::ED25519_VERIFY];
+        total_area += (ed25519_verify_events as u64) * costs[&RiscvAirDiscriminants::Ed25519Verify];
+        total_chips += 1;
+
+        let jubjub_add_events = self.syscall_counts[SyscallCode::JUBJUB_ADD];
+        total_area += (jubjub_add_events as u64) * costs[&RiscvAirDiscriminants::JubjubAdd];
+        total_chips += 1;
+
+        let baby_jubjub_add_events = self.syscall_counts[SyscallCode::BABY_JUBJUB_ADD];
+        total_area +=
+            (baby_jubjub_add_events as u64) * costs[&RiscvAirDiscriminants::BabyJubjubAdd];
+        total_chips += 1;
+
         let k256_decompress_events = self.syscall_counts[SyscallCode::SECP256K1_DECOMPRESS];
         total_area +=
             (k256_decompress_events as u64) * costs[&RiscvAirDiscriminants::K256Decompress];
@@ -69,6 +104,13 @@ impl CostEstimator for ExecutionReport {
             (secp256k1_double_events as u64) * costs[&RiscvAirDiscriminants::Secp256k1Double];
         total_chips += 1;
 
+        // Each call hashes the recovered key with a keccak permute call.
+        let secp256k1_ecrecover_events = self.syscall_counts[SyscallCode::SECP256K1_ECRECOVER];
+        total_area += (secp256k1_ecrecover_events as u64)
+            * (costs[&RiscvAirDiscriminants::Secp256k1EcRecover]
+                + costs[&RiscvAirDiscriminants::KeccakP]);
+        total_chips += 1;
+
         let secp256r1_add_events = self.syscall_counts[SyscallCode::SECP256R1_ADD];
         total_area += (secp256r1_add_events as u64) * costs[&RiscvAirDiscriminants::Secp256r1Add];
         total_chips += 1;
@@ -82,6 +124,33 @@ impl CostEstimator for ExecutionReport {
         total_area += (keccak256_permute_events as u64) * costs[&RiscvAirDiscriminants::KeccakP];
         total_chips += 1;
 
+        let keccak256_accumulate_events = self.syscall_counts[SyscallCode::KECCAK_ACCUMULATE];
+        total_area +=
+            (keccak256_accumulate_events as u64) * costs[&RiscvAirDiscriminants::KeccakAccumulate];
+        total_chips += 1;
+
+        // Each absorb or finalize call also makes a keccak permute call of its own.
+        let keccak256_sponge_events = self.syscall_counts[SyscallCode::KECCAK_ABSORB]
+            + self.syscall_counts[SyscallCode::KECCAK_FINALIZE];
+        total_area += (keccak256_sponge_events as u64)
+            * (costs[&RiscvAirDiscriminants::KeccakSponge]
+                + costs[&RiscvAirDiscriminants::KeccakP]);
+        total_chips += 1;
+
+        // Each call hashes at most eight levels, with a keccak permute call each.
+        let keccak256_merkle_events = self.syscall_counts[SyscallCode::KECCAK_MERKLE_ROOT];
+        total_area += (keccak256_merkle_events as u64)
+            * (costs[&RiscvAirDiscriminants::KeccakMerkle]
+                + 8 * costs[&RiscvAirDiscriminants::KeccakP]);
+        total_chips += 1;
+
+        // Each call hashes at most fifteen nodes, with a keccak permute call each.
+        let withdraw_root_events = self.syscall_counts[SyscallCode::WITHDRAW_ROOT];
+        total_area += (withdraw_root_events as u64)
+            * (costs[&RiscvAirDiscriminants::WithdrawRoot]
+                + 15 * costs[&RiscvAirDiscriminants::KeccakP]);
+        total_chips += 1;
+
         let bn254_add_events = self.syscall_counts[SyscallCode::BN254_ADD];
         total_area += (bn254_add_events as u64) * costs[&RiscvAirDiscriminants::Bn254Add];
         total_chips += 1;
@@ -90,6 +159,16 @@ impl CostEstimator for ExecutionReport {
         total_area += (bn254_double_events as u64) * costs[&RiscvAirDiscriminants::Bn254Double];
         total_chips += 1;
 
+        let bn254_decompress_events = self.syscall_counts[SyscallCode::BN254_DECOMPRESS];
+        total_area +=
+            (bn254_decompress_events as u64) * costs[&RiscvAirDiscriminants::Bn254Decompress];
+        total_chips += 1;
+
+        let bn254_g2_decompress_events = self.syscall_counts[SyscallCode::BN254_G2_DECOMPRESS];
+        total_area +=
+            (bn254_g2_decompress_events as u64) * costs[&RiscvAirDiscriminants::Bn254G2Decompress];
+        total_chips += 1;
+
         let bls12381_add_events = self.syscall_counts[SyscallCode::BLS12381_ADD];
         total_area += (bls12381_add_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Add];
         total_chips += 1;
@@ -103,14 +182,87 @@ impl CostEstimator for ExecutionReport {
         total_area += (uint256_mul_events as u64) * costs[&RiscvAirDiscriminants::Uint256Mul];
         total_chips += 1;
 
-        let bn254_muladd_events = self.syscall_counts[SyscallCode::BN254_MULADD];
+        let bn254_muladd_events = self.syscall_counts[SyscallCode::BN254_MULADD]
+            + self.syscall_counts[SyscallCode::BN254_MULADD_BE];
         total_area += (bn254_muladd_events as u64) * costs[&RiscvAirDiscriminants::Bn254MulAdd];
         total_chips += 1;
 
+        // Without repeated operands, the memo chip has a row for every bn254 mul-add event.
+        total_area += (bn254_muladd_events as u64) * costs[&RiscvAirDiscriminants::Bn254MulMemo];
+        total_chips += 1;
+
+        let bn254_mont_events = self.syscall_counts[SyscallCode::BN254_SCALAR_TO_MONT]
+            + self.syscall_counts[SyscallCode::BN254_SCALAR_FROM_MONT];
+        total_area += (bn254_mont_events as u64) * costs[&RiscvAirDiscriminants::Bn254Mont];
+        total_chips += 1;
+
+        let bn254_inner_product_events = self.syscall_counts[SyscallCode::BN254_INNER_PRODUCT];
+        total_area +=
+            (bn254_inner_product_events as u64) * costs[&RiscvAirDiscriminants::Bn254InnerProduct];
+        total_chips += 1;
+
+        let fr_ntt_events = self.syscall_counts[SyscallCode::FR_NTT];
+        total_area += (fr_ntt_events as u64) * costs[&RiscvAirDiscriminants::FrNtt];
+        total_chips += 1;
+
+        let memcopy32_events = self.syscall_counts[SyscallCode::MEMCPY_32];
+        total_area += (memcopy32_events as u64) * costs[&RiscvAirDiscriminants::MemCopy32];
+        total_chips += 1;
+
+        // Coalesced `MEMCPY_32` calls are counted as `MEMCPY_64` events.
+        let memcopy64_events = self.syscall_counts[SyscallCode::MEMCPY_64];
+        total_area += (memcopy64_events as u64) * costs[&RiscvAirDiscriminants::MemCopy64];
+        total_chips += 1;
+
+        let csel256_events = self.syscall_counts[SyscallCode::CSEL256];
+        total_area += (csel256_events as u64) * costs[&RiscvAirDiscriminants::Csel256];
+        total_chips += 1;
+
+        let rlp_scan_events = self.syscall_counts[SyscallCode::RLP_SCAN];
+        total_area += (rlp_scan_events as u64) * costs[&RiscvAirDiscriminants::RlpScan];
+        total_chips += 1;
+
+        let poseidon_merkle_events = self.syscall_counts[SyscallCode::POSEIDON_MERKLE_ROOT];
+        total_area +=
+            (poseidon_merkle_events as u64) * costs[&RiscvAirDiscriminants::PoseidonMerkle];
+        total_chips += 1;
+
+        let zktrie_node_events = self.syscall_counts[SyscallCode::ZKTRIE_HASH_NODE];
+        total_area += (zktrie_node_events as u64) * costs[&RiscvAirDiscriminants::ZkTrieNode];
+        total_chips += 1;
+
+        let rsa2048_modexp_events = self.syscall_counts[SyscallCode::RSA2048_MODEXP];
+        total_area += (rsa2048_modexp_events as u64) * costs[&RiscvAirDiscriminants::Rsa2048ModExp];
+        total_chips += 1;
+
+        let rsa4096_modexp_events = self.syscall_counts[SyscallCode::RSA4096_MODEXP];
+        total_area += (rsa4096_modexp_events as u64) * costs[&RiscvAirDiscriminants::Rsa4096ModExp];
+        total_chips += 1;
+
         let u256xu2048_mul_events = self.syscall_counts[SyscallCode::U256XU2048_MUL];
         total_area += (u256xu2048_mul_events as u64) * costs[&RiscvAirDiscriminants::U256x2048Mul];
         total_chips += 1;
 
+        let u256xu4096_mul_events = self.syscall_counts[SyscallCode::U256XU4096_MUL];
+        total_area += (u256xu4096_mul_events as u64) * costs[&RiscvAirDiscriminants::U256x4096Mul];
+        total_chips += 1;
+
+        let barrett_reduce_events = self.syscall_counts[SyscallCode::BARRETT_REDUCE];
+        total_area += (barrett_reduce_events as u64) * costs[&RiscvAirDiscriminants::BarrettReduce];
+        total_chips += 1;
+
+        let aes_round_events = self.syscall_counts[SyscallCode::AES_ENC_ROUND]
+            + self.syscall_counts[SyscallCode::AES_ENC_LAST_ROUND];
+        total_area += (aes_round_events as u64) * costs[&RiscvAirDiscriminants::AesRound];
+
+        let chacha20_block_events = self.syscall_counts[SyscallCode::CHACHA20_BLOCK];
+        total_area += (chacha20_block_events as u64) * costs[&RiscvAirDiscriminants::ChaCha20Block];
+        total_chips += 1;
+
+        let uint384_muladd_events = self.syscall_counts[SyscallCode::UINT384_MULADD];
+        total_area += (uint384_muladd_events as u64) * costs[&RiscvAirDiscriminants::Uint384MulAdd];
+        total_chips += 1;
+
         let bls12381_fp_events = self.syscall_counts[SyscallCode::BLS12381_FP_ADD]
             + self.syscall_counts[SyscallCode::BLS12381_FP_SUB]
             + self.syscall_counts[SyscallCode::BLS12381_FP_MUL];
@@ -128,6 +280,11 @@ impl CostEstimator for ExecutionReport {
             (bls12381_fp2_mul_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Fp2Mul];
         total_chips += 1;
 
+        let bls12381_fp6_mul_events = self.syscall_counts[SyscallCode::BLS12381_FP6_MUL];
+        total_area +=
+            (bls12381_fp6_mul_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Fp6Mul];
+        total_chips += 1;
+
         let bn254_fp_events = self.syscall_counts[SyscallCode::BN254_FP_ADD]
             + self.syscall_counts[SyscallCode::BN254_FP_SUB]
             + self.syscall_counts[SyscallCode::BN254_FP_MUL];
@@ -144,11 +301,35 @@ impl CostEstimator for ExecutionReport {
         total_area += (bn254_fp2_mul_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fp2Mul];
         total_chips += 1;
 
+        let bn254_fp6_mul_events = self.syscall_counts[SyscallCode::BN254_FP6_MUL];
+        total_area += (bn254_fp6_mul_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fp6Mul];
+        total_chips += 1;
+
         let bls12381_decompress_events = self.syscall_counts[SyscallCode::BLS12381_DECOMPRESS];
         total_area +=
             (bls12381_decompress_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Decompress];
         total_chips += 1;
 
+        let f64_add_events = self.syscall_counts[SyscallCode::F64_ADD];
+        total_area += (f64_add_events as u64) * costs[&RiscvAirDiscriminants::F64Add];
+        total_chips += 1;
+
+        let f64_mul_events = self.syscall_counts[SyscallCode::F64_MUL];
+        total_area += (f64_mul_events as u64) * costs[&RiscvAirDiscriminants::F64Mul];
+        total_chips += 1;
+
+        let f64_div_events = self.syscall_counts[SyscallCode::F64_DIV];
+        total_area += (f64_div_events as u64) * costs[&RiscvAirDiscriminants::F64Div];
+        total_chips += 1;
+
+        let hex_decode_events = self.syscall_counts[SyscallCode::HEX_DECODE];
+        total_area += (hex_decode_events as u64) * costs[&RiscvAirDiscriminants::HexDecode];
+        total_chips += 1;
+
+        let base64_decode_events = self.syscall_counts[SyscallCode::BASE64_DECODE];
+        total_area += (base64_decode_events as u64) * costs[&RiscvAirDiscriminants::Base64Decode];
+        total_chips += 1;
+
         let syscall_events = self.syscall_counts.values().sum::<u64>();
         total_area += (syscall_events as u64) * costs[&RiscvAirDiscriminants::SyscallCore];
         total_chips += 1;
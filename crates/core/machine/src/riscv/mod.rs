@@ -1,11 +1,21 @@
+mod audit;
+mod caps;
 pub mod cost;
 
 mod shape;
 
+pub use audit::*;
+pub use caps::*;
 use itertools::Itertools;
 pub use shape::*;
 use sp1_core_executor::{
-    events::PrecompileLocalMemory, syscalls::SyscallCode, ExecutionRecord, Program,
+    events::{
+        PrecompileLocalMemory, BN254_INNER_PRODUCT_MAX_LEN, ECRECOVER_ROWS, ED25519_VERIFY_ROWS,
+        FR_NTT_MAX_LEN, KECCAK_MERKLE_MAX_DEPTH, POSEIDON_MERKLE_ROWS, RLP_SCAN_MAX_ITEMS,
+        RSA_LADDER_STEPS, SHA512_COMPRESS_ROWS, WITHDRAW_ROOT_HASHES, ZKTRIE_NODE_ROWS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
 };
 
 use crate::{
@@ -13,7 +23,9 @@ use crate::{
         MemoryChipType, MemoryLocalChip, MemoryProgramChip, NUM_LOCAL_MEMORY_ENTRIES_PER_ROW,
     },
     riscv::MemoryChipType::{Finalize, Initialize},
-    syscall::precompiles::fptower::{Fp2AddSubAssignChip, Fp2MulAssignChip, FpOpChip},
+    syscall::precompiles::fptower::{
+        Fp2AddSubAssignChip, Fp2MulAssignChip, Fp6MulAssignChip, FpOpChip,
+    },
 };
 use hashbrown::{HashMap, HashSet};
 use p3_field::PrimeField32;
@@ -21,13 +33,13 @@ pub use riscv_chips::*;
 use sp1_curves::weierstrass::{bls12_381::Bls12381BaseField, bn254::Bn254BaseField};
 use sp1_stark::{
     air::{InteractionScope, MachineAir, SP1_PROOF_NUM_PV_ELTS},
-    Chip, InteractionKind, StarkGenericConfig, StarkMachine,
+    Chip, InteractionKind, MachineBuilder, StarkGenericConfig, StarkMachine,
 };
 use strum_macros::{EnumDiscriminants, EnumIter};
 use tracing::instrument;
+use typenum::{U16, U8};
 
-pub const MAX_LOG_NUMBER_OF_SHARDS: usize = 16;
-pub const MAX_NUMBER_OF_SHARDS: usize = 1 << MAX_LOG_NUMBER_OF_SHARDS;
+pub use sp1_core_executor::{MAX_LOG_NUMBER_OF_SHARDS, MAX_NUMBER_OF_SHARDS};
 
 /// A module for importing all the different RISC-V chips.
 pub(crate) mod riscv_chips {
@@ -40,21 +52,47 @@ pub(crate) mod riscv_chips {
         syscall::{
             chip::SyscallChip,
             precompiles::{
-                bn254::mul_add_uint256::Bn254MulAddChip,
-                edwards::{EdAddAssignChip, EdDecompressChip},
+                aes::AesRoundChip,
+                barrett_reduce::BarrettReduceChip,
+                bn254::{
+                    inner_product::Bn254InnerProductChip, mont::Bn254MontChip,
+                    mul_add_uint256::Bn254MulAddChip, mul_memo::Bn254MulMemoChip, ntt::FrNttChip,
+                },
+                chacha20::ChaCha20BlockChip,
+                codec::{Base64DecodeChip, HexDecodeChip},
+                csel256::Csel256Chip,
+                edwards::{Ed25519VerifyChip, EdAddAssignChip, EdDecompressChip},
+                f64::{F64AddChip, F64DivChip, F64MulChip},
                 keccak256::KeccakPermuteChip,
-                sha256::{ShaCompressChip, ShaExtendChip},
+                keccak256_accumulate::KeccakAccumulateChip,
+                keccak256_merkle::KeccakMerkleChip,
+                keccak256_sponge::KeccakSpongeChip,
+                keccak256_withdraw_root::WithdrawRootChip,
+                memcpy::MemCopyChip,
+                poseidon::{PoseidonMerkleChip, ZkTrieNodeChip},
+                rlp_scan::RlpScanChip,
+                rsa::RsaModExpChip,
+                sha256::{
+                    HmacSha256Chip, Sha256CompressBlocksChip, ShaCompressChip, ShaExtendChip,
+                },
+                sha512::Sha512CompressChip,
                 u256x2048_mul::U256x2048MulChip,
+                u256x4096_mul::U256x4096MulChip,
                 uint256::Uint256MulChip,
+                uint384::Uint384MulAddChip,
                 weierstrass::{
-                    WeierstrassAddAssignChip, WeierstrassDecompressChip,
-                    WeierstrassDoubleAssignChip,
+                    Bn254G2DecompressChip, Secp256k1EcRecoverChip, WeierstrassAddAssignChip,
+                    WeierstrassDecompressChip, WeierstrassDoubleAssignChip,
                 },
             },
         },
     };
     pub use sp1_curves::{
-        edwards::{ed25519::Ed25519Parameters, EdwardsCurve},
+        edwards::{
+            baby_jubjub::BabyJubjubParameters, ed25519::Ed25519Parameters,
+            jubjub::JubjubParameters, EdwardsCurve,
+        },
+        rsa::{U2048Field, U4096Field},
         weierstrass::{
             bls12_381::Bls12381Parameters, bn254::Bn254Parameters, secp256k1::Secp256k1Parameters,
             secp256r1::Secp256r1Parameters, SwCurve,
@@ -90,6 +128,8 @@ pub enum RiscvAir<F: PrimeField32> {
     ShiftRight(ShiftRightChip),
     /// A lookup table for byte operations.
     ByteLookup(ByteChip<F>),
+    /// A lookup table for the byte operations carried on the secondary byte bus.
+    SecondaryByteLookup(ByteChip<F>),
     /// A table for initializing the global memory state.
     MemoryGlobalInit(MemoryGlobalChip),
     /// A table for finalizing the global memory state.
@@ -106,10 +146,23 @@ pub enum RiscvAir<F: PrimeField32> {
     Sha256Extend(ShaExtendChip),
     /// A precompile for sha256 compress.
     Sha256Compress(ShaCompressChip),
+    /// A precompile for HMAC-SHA256, built on the sha256 extend and compress precompiles.
+    HmacSha256(HmacSha256Chip),
+    /// A precompile for compressing consecutive sha256 blocks, built on the sha256 extend and
+    /// compress precompiles.
+    Sha256CompressBlocks(Sha256CompressBlocksChip),
+    /// A precompile for sha512 compress.
+    Sha512Compress(Sha512CompressChip),
     /// A precompile for addition on the Elliptic curve ed25519.
     Ed25519Add(EdAddAssignChip<EdwardsCurve<Ed25519Parameters>>),
     /// A precompile for decompressing a point on the Edwards curve ed25519.
     Ed25519Decompress(EdDecompressChip<Ed25519Parameters>),
+    /// A precompile for checking the verification equation of an ed25519 signature.
+    Ed25519Verify(Ed25519VerifyChip),
+    /// A precompile for addition on the Edwards curve Jubjub.
+    JubjubAdd(EdAddAssignChip<EdwardsCurve<JubjubParameters>>),
+    /// A precompile for addition on the Edwards curve Baby Jubjub.
+    BabyJubjubAdd(EdAddAssignChip<EdwardsCurve<BabyJubjubParameters>>),
     /// A precompile for decompressing a point on the K256 curve.
     K256Decompress(WeierstrassDecompressChip<SwCurve<Secp256k1Parameters>>),
     /// A precompile for decompressing a point on the P256 curve.
@@ -118,16 +171,33 @@ pub enum RiscvAir<F: PrimeField32> {
     Secp256k1Add(WeierstrassAddAssignChip<SwCurve<Secp256k1Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve secp256k1.
     Secp256k1Double(WeierstrassDoubleAssignChip<SwCurve<Secp256k1Parameters>>),
+    /// A precompile for recovering the address of a secp256k1 key with the semantics of the EVM
+    /// `ecrecover` precompile, built on the Keccak permutation.
+    Secp256k1EcRecover(Secp256k1EcRecoverChip),
     /// A precompile for addition on the Elliptic curve secp256r1.
     Secp256r1Add(WeierstrassAddAssignChip<SwCurve<Secp256r1Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve secp256r1.
     Secp256r1Double(WeierstrassDoubleAssignChip<SwCurve<Secp256r1Parameters>>),
     /// A precompile for the Keccak permutation.
     KeccakP(KeccakPermuteChip),
+    /// A precompile for absorbing a block into a Keccak accumulator.
+    KeccakAccumulate(KeccakAccumulateChip),
+    /// A precompile for absorbing a block into a Keccak sponge kept in memory, built on the Keccak
+    /// permutation.
+    KeccakSponge(KeccakSpongeChip),
+    /// A precompile for the root of a Keccak Merkle path, built on the Keccak permutation.
+    KeccakMerkle(KeccakMerkleChip),
+    /// A precompile for the root of a balanced Keccak Merkle tree, such as a withdrawal tree,
+    /// built on the Keccak permutation.
+    WithdrawRoot(WithdrawRootChip),
     /// A precompile for addition on the Elliptic curve bn254.
     Bn254Add(WeierstrassAddAssignChip<SwCurve<Bn254Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve bn254.
     Bn254Double(WeierstrassDoubleAssignChip<SwCurve<Bn254Parameters>>),
+    /// A precompile for decompressing a G1 point on the Elliptic curve bn254.
+    Bn254Decompress(WeierstrassDecompressChip<SwCurve<Bn254Parameters>>),
+    /// A precompile for decompressing a G2 point on the twist of the Elliptic curve bn254.
+    Bn254G2Decompress(Bn254G2DecompressChip),
     /// A precompile for addition on the Elliptic curve bls12_381.
     Bls12381Add(WeierstrassAddAssignChip<SwCurve<Bls12381Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve bls12_381.
@@ -136,8 +206,42 @@ pub enum RiscvAir<F: PrimeField32> {
     Uint256Mul(Uint256MulChip),
     /// A precompile for bn254 mul.
     Bn254MulAdd(Bn254MulAddChip),
+    /// A table for the products looked up by the bn254 mul precompile.
+    Bn254MulMemo(Bn254MulMemoChip),
+    /// A precompile for converting bn254 scalars to and from Montgomery form.
+    Bn254Mont(Bn254MontChip),
+    /// A precompile for bn254 scalar inner products.
+    Bn254InnerProduct(Bn254InnerProductChip),
+    /// A precompile for checking NTTs over the bn254 scalar field at a random point.
+    FrNtt(FrNttChip),
+    /// A precompile for copying 32 bytes of memory.
+    MemCopy32(MemCopyChip<U8>),
+    /// A precompile for copying 64 bytes of memory, or two coalesced 32 byte copies.
+    MemCopy64(MemCopyChip<U16>),
+    /// A precompile for selecting between two 32 byte values.
+    Csel256(Csel256Chip),
+    /// A precompile for scanning the items of an RLP payload.
+    RlpScan(RlpScanChip),
+    /// A precompile for the root of a Poseidon Merkle path.
+    PoseidonMerkle(PoseidonMerkleChip),
+    /// A precompile for the hash of a zkTrie node.
+    ZkTrieNode(ZkTrieNodeChip),
+    /// A precompile for RSA-2048 modular exponentiation.
+    Rsa2048ModExp(RsaModExpChip<U2048Field>),
+    /// A precompile for RSA-4096 modular exponentiation.
+    Rsa4096ModExp(RsaModExpChip<U4096Field>),
     /// A precompile for u256x2048 mul.
     U256x2048Mul(U256x2048MulChip),
+    /// A precompile for u256x4096 mul.
+    U256x4096Mul(U256x4096MulChip),
+    /// A precompile for reducing a 512-bit value modulo a 256-bit modulus.
+    BarrettReduce(BarrettReduceChip),
+    /// A precompile for AES encryption rounds.
+    AesRound(AesRoundChip),
+    /// A precompile for the ChaCha20 block function.
+    ChaCha20Block(ChaCha20BlockChip),
+    /// A precompile for uint384 mul-add.
+    Uint384MulAdd(Uint384MulAddChip),
     /// A precompile for decompressing a point on the BLS12-381 curve.
     Bls12381Decompress(WeierstrassDecompressChip<SwCurve<Bls12381Parameters>>),
     /// A precompile for BLS12-381 fp operation.
@@ -146,19 +250,37 @@ pub enum RiscvAir<F: PrimeField32> {
     Bls12381Fp2Mul(Fp2MulAssignChip<Bls12381BaseField>),
     /// A precompile for BLS12-381 fp2 addition/subtraction.
     Bls12381Fp2AddSub(Fp2AddSubAssignChip<Bls12381BaseField>),
+    /// A precompile for BLS12-381 fp6 multiplication.
+    Bls12381Fp6Mul(Fp6MulAssignChip<Bls12381BaseField>),
     /// A precompile for BN-254 fp operation.
     Bn254Fp(FpOpChip<Bn254BaseField>),
     /// A precompile for BN-254 fp2 multiplication.
     Bn254Fp2Mul(Fp2MulAssignChip<Bn254BaseField>),
+    /// A precompile for BN-254 fp6 multiplication.
+    Bn254Fp6Mul(Fp6MulAssignChip<Bn254BaseField>),
     /// A precompile for BN-254 fp2 addition/subtraction.
     Bn254Fp2AddSub(Fp2AddSubAssignChip<Bn254BaseField>),
+    /// A precompile for f64 addition.
+    F64Add(F64AddChip),
+    /// A precompile for f64 multiplication.
+    F64Mul(F64MulChip),
+    /// A precompile for f64 division.
+    F64Div(F64DivChip),
+    /// A precompile for decoding hexadecimal digits.
+    HexDecode(HexDecodeChip),
+    /// A precompile for decoding base64 characters.
+    Base64Decode(Base64DecodeChip),
 }
 
 impl<F: PrimeField32> RiscvAir<F> {
     #[instrument("construct RiscvAir machine", level = "debug", skip_all)]
     pub fn machine<SC: StarkGenericConfig<Val = F>>(config: SC) -> StarkMachine<SC, Self> {
-        let chips = Self::chips();
-        StarkMachine::new(config, chips, SP1_PROOF_NUM_PV_ELTS, true)
+        Self::machine_builder().build(config)
+    }
+
+    /// Get a builder for the RISC-V machine, with all the different RISC-V AIRs added.
+    pub fn machine_builder() -> MachineBuilder<F, Self> {
+        MachineBuilder::new(SP1_PROOF_NUM_PV_ELTS, true).add_chips(Self::chips())
     }
 
     /// Get all the different RISC-V AIRs.
@@ -199,6 +321,22 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Sha256Compress, 80 * sha_compress.cost());
         chips.push(sha_compress);
 
+        let hmac_sha256 = Chip::new(RiscvAir::HmacSha256(HmacSha256Chip::default()));
+        costs.insert(RiscvAirDiscriminants::HmacSha256, hmac_sha256.cost());
+        chips.push(hmac_sha256);
+
+        let sha256_compress_blocks =
+            Chip::new(RiscvAir::Sha256CompressBlocks(Sha256CompressBlocksChip::default()));
+        costs.insert(RiscvAirDiscriminants::Sha256CompressBlocks, sha256_compress_blocks.cost());
+        chips.push(sha256_compress_blocks);
+
+        let sha512_compress = Chip::new(RiscvAir::Sha512Compress(Sha512CompressChip::default()));
+        costs.insert(
+            RiscvAirDiscriminants::Sha512Compress,
+            SHA512_COMPRESS_ROWS as u64 * sha512_compress.cost(),
+        );
+        chips.push(sha512_compress);
+
         let ed_add_assign = Chip::new(RiscvAir::Ed25519Add(EdAddAssignChip::<
             EdwardsCurve<Ed25519Parameters>,
         >::new()));
@@ -211,6 +349,25 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Ed25519Decompress, ed_decompress.cost());
         chips.push(ed_decompress);
 
+        let ed25519_verify = Chip::new(RiscvAir::Ed25519Verify(Ed25519VerifyChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Ed25519Verify,
+            ED25519_VERIFY_ROWS as u64 * ed25519_verify.cost(),
+        );
+        chips.push(ed25519_verify);
+
+        let jubjub_add = Chip::new(RiscvAir::JubjubAdd(EdAddAssignChip::<
+            EdwardsCurve<JubjubParameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::JubjubAdd, jubjub_add.cost());
+        chips.push(jubjub_add);
+
+        let baby_jubjub_add = Chip::new(RiscvAir::BabyJubjubAdd(EdAddAssignChip::<
+            EdwardsCurve<BabyJubjubParameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::BabyJubjubAdd, baby_jubjub_add.cost());
+        chips.push(baby_jubjub_add);
+
         let k256_decompress = Chip::new(RiscvAir::K256Decompress(WeierstrassDecompressChip::<
             SwCurve<Secp256k1Parameters>,
         >::with_lsb_rule()));
@@ -230,6 +387,14 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Secp256k1Double, secp256k1_double_assign.cost());
         chips.push(secp256k1_double_assign);
 
+        let secp256k1_ecrecover =
+            Chip::new(RiscvAir::Secp256k1EcRecover(Secp256k1EcRecoverChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Secp256k1EcRecover,
+            ECRECOVER_ROWS as u64 * secp256k1_ecrecover.cost(),
+        );
+        chips.push(secp256k1_ecrecover);
+
         let p256_decompress = Chip::new(RiscvAir::P256Decompress(WeierstrassDecompressChip::<
             SwCurve<Secp256r1Parameters>,
         >::with_lsb_rule()));
@@ -253,6 +418,28 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::KeccakP, 24 * keccak_permute.cost());
         chips.push(keccak_permute);
 
+        let keccak_accumulate = Chip::new(RiscvAir::KeccakAccumulate(KeccakAccumulateChip::new()));
+        costs.insert(RiscvAirDiscriminants::KeccakAccumulate, 24 * keccak_accumulate.cost());
+        chips.push(keccak_accumulate);
+
+        let keccak_sponge = Chip::new(RiscvAir::KeccakSponge(KeccakSpongeChip::new()));
+        costs.insert(RiscvAirDiscriminants::KeccakSponge, keccak_sponge.cost());
+        chips.push(keccak_sponge);
+
+        let keccak_merkle = Chip::new(RiscvAir::KeccakMerkle(KeccakMerkleChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::KeccakMerkle,
+            KECCAK_MERKLE_MAX_DEPTH as u64 * keccak_merkle.cost(),
+        );
+        chips.push(keccak_merkle);
+
+        let withdraw_root = Chip::new(RiscvAir::WithdrawRoot(WithdrawRootChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::WithdrawRoot,
+            WITHDRAW_ROOT_HASHES as u64 * withdraw_root.cost(),
+        );
+        chips.push(withdraw_root);
+
         let bn254_add_assign = Chip::new(RiscvAir::Bn254Add(WeierstrassAddAssignChip::<
             SwCurve<Bn254Parameters>,
         >::new()));
@@ -265,6 +452,18 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bn254Double, bn254_double_assign.cost());
         chips.push(bn254_double_assign);
 
+        let bn254_decompress = Chip::new(RiscvAir::Bn254Decompress(WeierstrassDecompressChip::<
+            SwCurve<Bn254Parameters>,
+        >::with_lexicographic_rule(
+        )));
+        costs.insert(RiscvAirDiscriminants::Bn254Decompress, bn254_decompress.cost());
+        chips.push(bn254_decompress);
+
+        let bn254_g2_decompress =
+            Chip::new(RiscvAir::Bn254G2Decompress(Bn254G2DecompressChip::new()));
+        costs.insert(RiscvAirDiscriminants::Bn254G2Decompress, bn254_g2_decompress.cost());
+        chips.push(bn254_g2_decompress);
+
         let bls12381_add = Chip::new(RiscvAir::Bls12381Add(WeierstrassAddAssignChip::<
             SwCurve<Bls12381Parameters>,
         >::new()));
@@ -282,13 +481,91 @@ impl<F: PrimeField32> RiscvAir<F> {
         chips.push(uint256_mul);
 
         let bn254_muladd = Chip::new(RiscvAir::Bn254MulAdd(Bn254MulAddChip::default()));
-        costs.insert(RiscvAirDiscriminants::Uint256Mul, bn254_muladd.cost());
+        costs.insert(RiscvAirDiscriminants::Bn254MulAdd, bn254_muladd.cost());
         chips.push(bn254_muladd);
 
+        let bn254_mul_memo = Chip::new(RiscvAir::Bn254MulMemo(Bn254MulMemoChip::default()));
+        costs.insert(RiscvAirDiscriminants::Bn254MulMemo, bn254_mul_memo.cost());
+        chips.push(bn254_mul_memo);
+
+        let bn254_mont = Chip::new(RiscvAir::Bn254Mont(Bn254MontChip::default()));
+        costs.insert(RiscvAirDiscriminants::Bn254Mont, bn254_mont.cost());
+        chips.push(bn254_mont);
+
+        let bn254_inner_product =
+            Chip::new(RiscvAir::Bn254InnerProduct(Bn254InnerProductChip::default()));
+        costs.insert(RiscvAirDiscriminants::Bn254InnerProduct, bn254_inner_product.cost());
+        chips.push(bn254_inner_product);
+
+        let fr_ntt = Chip::new(RiscvAir::FrNtt(FrNttChip::default()));
+        costs.insert(RiscvAirDiscriminants::FrNtt, fr_ntt.cost());
+        chips.push(fr_ntt);
+
+        let memcopy32 = Chip::new(RiscvAir::MemCopy32(MemCopyChip::new()));
+        costs.insert(RiscvAirDiscriminants::MemCopy32, memcopy32.cost());
+        chips.push(memcopy32);
+
+        let memcopy64 = Chip::new(RiscvAir::MemCopy64(MemCopyChip::new()));
+        costs.insert(RiscvAirDiscriminants::MemCopy64, memcopy64.cost());
+        chips.push(memcopy64);
+
+        let csel256 = Chip::new(RiscvAir::Csel256(Csel256Chip::new()));
+        costs.insert(RiscvAirDiscriminants::Csel256, csel256.cost());
+        chips.push(csel256);
+
+        let rlp_scan = Chip::new(RiscvAir::RlpScan(RlpScanChip::new()));
+        costs.insert(RiscvAirDiscriminants::RlpScan, rlp_scan.cost());
+        chips.push(rlp_scan);
+
+        let poseidon_merkle = Chip::new(RiscvAir::PoseidonMerkle(PoseidonMerkleChip::default()));
+        costs.insert(RiscvAirDiscriminants::PoseidonMerkle, poseidon_merkle.cost());
+        chips.push(poseidon_merkle);
+
+        let zktrie_node = Chip::new(RiscvAir::ZkTrieNode(ZkTrieNodeChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::ZkTrieNode,
+            ZKTRIE_NODE_ROWS as u64 * zktrie_node.cost(),
+        );
+        chips.push(zktrie_node);
+
+        let rsa2048_modexp = Chip::new(RiscvAir::Rsa2048ModExp(RsaModExpChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Rsa2048ModExp,
+            RSA_LADDER_STEPS as u64 * rsa2048_modexp.cost(),
+        );
+        chips.push(rsa2048_modexp);
+
+        let rsa4096_modexp = Chip::new(RiscvAir::Rsa4096ModExp(RsaModExpChip::new()));
+        costs.insert(
+            RiscvAirDiscriminants::Rsa4096ModExp,
+            RSA_LADDER_STEPS as u64 * rsa4096_modexp.cost(),
+        );
+        chips.push(rsa4096_modexp);
+
         let u256x2048_mul = Chip::new(RiscvAir::U256x2048Mul(U256x2048MulChip::default()));
         costs.insert(RiscvAirDiscriminants::U256x2048Mul, u256x2048_mul.cost());
         chips.push(u256x2048_mul);
 
+        let u256x4096_mul = Chip::new(RiscvAir::U256x4096Mul(U256x4096MulChip::default()));
+        costs.insert(RiscvAirDiscriminants::U256x4096Mul, u256x4096_mul.cost());
+        chips.push(u256x4096_mul);
+
+        let barrett_reduce = Chip::new(RiscvAir::BarrettReduce(BarrettReduceChip::default()));
+        costs.insert(RiscvAirDiscriminants::BarrettReduce, barrett_reduce.cost());
+        chips.push(barrett_reduce);
+
+        let aes_round = Chip::new(RiscvAir::AesRound(AesRoundChip::default()));
+        costs.insert(RiscvAirDiscriminants::AesRound, aes_round.cost());
+        chips.push(aes_round);
+
+        let chacha20_block = Chip::new(RiscvAir::ChaCha20Block(ChaCha20BlockChip::default()));
+        costs.insert(RiscvAirDiscriminants::ChaCha20Block, 10 * chacha20_block.cost());
+        chips.push(chacha20_block);
+
+        let uint384_muladd = Chip::new(RiscvAir::Uint384MulAdd(Uint384MulAddChip::default()));
+        costs.insert(RiscvAirDiscriminants::Uint384MulAdd, uint384_muladd.cost());
+        chips.push(uint384_muladd);
+
         let bls12381_fp = Chip::new(RiscvAir::Bls12381Fp(FpOpChip::<Bls12381BaseField>::new()));
         costs.insert(RiscvAirDiscriminants::Bls12381Fp, bls12381_fp.cost());
         chips.push(bls12381_fp);
@@ -303,6 +580,11 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bls12381Fp2Mul, bls12381_fp2_mul.cost());
         chips.push(bls12381_fp2_mul);
 
+        let bls12381_fp6_mul =
+            Chip::new(RiscvAir::Bls12381Fp6Mul(Fp6MulAssignChip::<Bls12381BaseField>::new()));
+        costs.insert(RiscvAirDiscriminants::Bls12381Fp6Mul, bls12381_fp6_mul.cost());
+        chips.push(bls12381_fp6_mul);
+
         let bn254_fp = Chip::new(RiscvAir::Bn254Fp(FpOpChip::<Bn254BaseField>::new()));
         costs.insert(RiscvAirDiscriminants::Bn254Fp, bn254_fp.cost());
         chips.push(bn254_fp);
@@ -317,6 +599,11 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bn254Fp2Mul, bn254_fp2_mul.cost());
         chips.push(bn254_fp2_mul);
 
+        let bn254_fp6_mul =
+            Chip::new(RiscvAir::Bn254Fp6Mul(Fp6MulAssignChip::<Bn254BaseField>::new()));
+        costs.insert(RiscvAirDiscriminants::Bn254Fp6Mul, bn254_fp6_mul.cost());
+        chips.push(bn254_fp6_mul);
+
         let bls12381_decompress =
             Chip::new(RiscvAir::Bls12381Decompress(WeierstrassDecompressChip::<
                 SwCurve<Bls12381Parameters>,
@@ -324,6 +611,26 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bls12381Decompress, bls12381_decompress.cost());
         chips.push(bls12381_decompress);
 
+        let f64_add = Chip::new(RiscvAir::F64Add(F64AddChip::default()));
+        costs.insert(RiscvAirDiscriminants::F64Add, f64_add.cost());
+        chips.push(f64_add);
+
+        let f64_mul = Chip::new(RiscvAir::F64Mul(F64MulChip::default()));
+        costs.insert(RiscvAirDiscriminants::F64Mul, f64_mul.cost());
+        chips.push(f64_mul);
+
+        let f64_div = Chip::new(RiscvAir::F64Div(F64DivChip::default()));
+        costs.insert(RiscvAirDiscriminants::F64Div, f64_div.cost());
+        chips.push(f64_div);
+
+        let hex_decode = Chip::new(RiscvAir::HexDecode(HexDecodeChip::default()));
+        costs.insert(RiscvAirDiscriminants::HexDecode, hex_decode.cost());
+        chips.push(hex_decode);
+
+        let base64_decode = Chip::new(RiscvAir::Base64Decode(Base64DecodeChip::default()));
+        costs.insert(RiscvAirDiscriminants::Base64Decode, base64_decode.cost());
+        chips.push(base64_decode);
+
         let syscall_core = Chip::new(RiscvAir::SyscallCore(SyscallChip::core()));
         costs.insert(RiscvAirDiscriminants::SyscallCore, syscall_core.cost());
         chips.push(syscall_core);
@@ -383,15 +690,52 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::ByteLookup, byte.cost());
         chips.push(byte);
 
+        let secondary_byte = Chip::new(RiscvAir::SecondaryByteLookup(ByteChip::secondary()));
+        costs.insert(RiscvAirDiscriminants::SecondaryByteLookup, secondary_byte.cost());
+        chips.push(secondary_byte);
+
+        // Move the byte lookups of the ALU and precompile chips onto the secondary byte bus.
+        let chips = chips
+            .into_iter()
+            .map(|chip| {
+                if chip.air().uses_secondary_byte_bus() {
+                    chip.on_byte_bus(InteractionKind::SecondaryByte)
+                } else {
+                    chip
+                }
+            })
+            .collect();
+
         (chips, costs)
     }
 
+    /// Whether the byte lookups of the chip are carried on the secondary byte bus.
+    ///
+    /// The CPU, memory and syscall chips stay on the primary bus, while the ALU and precompile
+    /// chips are moved to the secondary one. Chips of the two groups share both core and
+    /// precompile shards, so each byte table only bounds part of the lookups of a shard.
+    pub(crate) fn uses_secondary_byte_bus(&self) -> bool {
+        !matches!(
+            self,
+            Self::Program(_)
+                | Self::Cpu(_)
+                | Self::ByteLookup(_)
+                | Self::MemoryGlobalInit(_)
+                | Self::MemoryGlobalFinal(_)
+                | Self::MemoryLocal(_)
+                | Self::ProgramMemory(_)
+                | Self::SyscallCore(_)
+                | Self::SyscallPrecompile(_)
+        )
+    }
+
     /// Get the heights of the preprocessed chips for a given program.
     pub(crate) fn preprocessed_heights(program: &Program) -> Vec<(Self, usize)> {
         vec![
             (RiscvAir::Program(ProgramChip::default()), program.instructions.len()),
             (RiscvAir::ProgramMemory(MemoryProgramChip::default()), program.memory_image.len()),
             (RiscvAir::ByteLookup(ByteChip::default()), 1 << 16),
+            (RiscvAir::SecondaryByteLookup(ByteChip::secondary()), 1 << 16),
         ]
     }
 
@@ -466,10 +810,17 @@ impl<F: PrimeField32> RiscvAir<F> {
         }
         airs.remove(&Self::SyscallPrecompile(SyscallChip::precompile()));
 
+        // Remove the chips which are sized along with the precompile whose lookups they prove.
+        let lookup_airs = airs.iter().flat_map(Self::precompile_lookup_airs).collect::<Vec<_>>();
+        for lookup_air in lookup_airs {
+            airs.remove(&lookup_air);
+        }
+
         // Remove the preprocessed chips.
         airs.remove(&Self::Program(ProgramChip::default()));
         airs.remove(&Self::ProgramMemory(MemoryProgramChip::default()));
         airs.remove(&Self::ByteLookup(ByteChip::default()));
+        airs.remove(&Self::SecondaryByteLookup(ByteChip::secondary()));
 
         airs.into_iter()
             .map(|air| {
@@ -493,31 +844,92 @@ impl<F: PrimeField32> RiscvAir<F> {
         match self {
             Self::Sha256Compress(_) => 80,
             Self::Sha256Extend(_) => 48,
-            Self::KeccakP(_) => 24,
+            Self::KeccakP(_) | Self::KeccakAccumulate(_) => 24,
+            Self::ChaCha20Block(_) => 10,
+            Self::Bn254InnerProduct(_) => BN254_INNER_PRODUCT_MAX_LEN,
+            Self::FrNtt(_) => FR_NTT_MAX_LEN,
+            Self::RlpScan(_) => RLP_SCAN_MAX_ITEMS,
+            Self::PoseidonMerkle(_) => POSEIDON_MERKLE_ROWS,
+            Self::ZkTrieNode(_) => ZKTRIE_NODE_ROWS,
+            Self::KeccakMerkle(_) => KECCAK_MERKLE_MAX_DEPTH,
+            Self::WithdrawRoot(_) => WITHDRAW_ROOT_HASHES,
+            Self::Rsa2048ModExp(_) | Self::Rsa4096ModExp(_) => RSA_LADDER_STEPS,
+            Self::Secp256k1EcRecover(_) => ECRECOVER_ROWS,
+            Self::Sha512Compress(_) => SHA512_COMPRESS_ROWS,
+            Self::Ed25519Verify(_) => ED25519_VERIFY_ROWS,
             _ => 1,
         }
     }
 
+    /// The number of syscalls a precompile chip may receive per row.
+    pub(crate) fn syscalls_per_row(&self) -> usize {
+        match self {
+            Self::MemCopy64(_) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Get the chips proving the lookups of a precompile chip.
+    ///
+    /// These chips appear in the same shards as the precompile chip, with the same height.
+    pub(crate) fn precompile_lookup_airs(&self) -> Vec<Self> {
+        match self {
+            Self::Bn254MulAdd(_) => vec![Self::Bn254MulMemo(Bn254MulMemoChip::default())],
+            _ => vec![],
+        }
+    }
+
     pub(crate) fn syscall_code(&self) -> SyscallCode {
         match self {
             Self::Bls12381Add(_) => SyscallCode::BLS12381_ADD,
             Self::Bn254Add(_) => SyscallCode::BN254_ADD,
             Self::Bn254Double(_) => SyscallCode::BN254_DOUBLE,
+            Self::Bn254Decompress(_) => SyscallCode::BN254_DECOMPRESS,
+            Self::Bn254G2Decompress(_) => SyscallCode::BN254_G2_DECOMPRESS,
             Self::Bn254Fp(_) => SyscallCode::BN254_FP_ADD,
             Self::Bn254Fp2AddSub(_) => SyscallCode::BN254_FP2_ADD,
             Self::Bn254Fp2Mul(_) => SyscallCode::BN254_FP2_MUL,
+            Self::Bn254Fp6Mul(_) => SyscallCode::BN254_FP6_MUL,
             Self::Ed25519Add(_) => SyscallCode::ED_ADD,
             Self::Ed25519Decompress(_) => SyscallCode::ED_DECOMPRESS,
+            Self::Ed25519Verify(_) => SyscallCode::ED25519_VERIFY,
+            Self::JubjubAdd(_) => SyscallCode::JUBJUB_ADD,
+            Self::BabyJubjubAdd(_) => SyscallCode::BABY_JUBJUB_ADD,
             Self::KeccakP(_) => SyscallCode::KECCAK_PERMUTE,
+            Self::KeccakAccumulate(_) => SyscallCode::KECCAK_ACCUMULATE,
+            Self::KeccakSponge(_) => SyscallCode::KECCAK_ABSORB,
+            Self::KeccakMerkle(_) => SyscallCode::KECCAK_MERKLE_ROOT,
+            Self::WithdrawRoot(_) => SyscallCode::WITHDRAW_ROOT,
             Self::Secp256k1Add(_) => SyscallCode::SECP256K1_ADD,
             Self::Secp256k1Double(_) => SyscallCode::SECP256K1_DOUBLE,
+            Self::Secp256k1EcRecover(_) => SyscallCode::SECP256K1_ECRECOVER,
             Self::Secp256r1Add(_) => SyscallCode::SECP256R1_ADD,
             Self::Secp256r1Double(_) => SyscallCode::SECP256R1_DOUBLE,
             Self::Sha256Compress(_) => SyscallCode::SHA_COMPRESS,
             Self::Sha256Extend(_) => SyscallCode::SHA_EXTEND,
+            Self::HmacSha256(_) => SyscallCode::HMAC_SHA256,
+            Self::Sha256CompressBlocks(_) => SyscallCode::SHA256_COMPRESS_BLOCKS,
+            Self::Sha512Compress(_) => SyscallCode::SHA512_COMPRESS,
             Self::Uint256Mul(_) => SyscallCode::UINT256_MUL,
             Self::Bn254MulAdd(_) => SyscallCode::BN254_MULADD,
+            Self::Bn254MulMemo(_) => SyscallCode::BN254_MULADD,
+            Self::Bn254Mont(_) => SyscallCode::BN254_SCALAR_TO_MONT,
+            Self::Bn254InnerProduct(_) => SyscallCode::BN254_INNER_PRODUCT,
+            Self::FrNtt(_) => SyscallCode::FR_NTT,
+            Self::MemCopy32(_) => SyscallCode::MEMCPY_32,
+            Self::MemCopy64(_) => SyscallCode::MEMCPY_64,
+            Self::Csel256(_) => SyscallCode::CSEL256,
+            Self::RlpScan(_) => SyscallCode::RLP_SCAN,
+            Self::PoseidonMerkle(_) => SyscallCode::POSEIDON_MERKLE_ROOT,
+            Self::ZkTrieNode(_) => SyscallCode::ZKTRIE_HASH_NODE,
+            Self::Rsa2048ModExp(_) => SyscallCode::RSA2048_MODEXP,
+            Self::Rsa4096ModExp(_) => SyscallCode::RSA4096_MODEXP,
             Self::U256x2048Mul(_) => SyscallCode::U256XU2048_MUL,
+            Self::U256x4096Mul(_) => SyscallCode::U256XU4096_MUL,
+            Self::BarrettReduce(_) => SyscallCode::BARRETT_REDUCE,
+            Self::AesRound(_) => SyscallCode::AES_ENC_ROUND,
+            Self::ChaCha20Block(_) => SyscallCode::CHACHA20_BLOCK,
+            Self::Uint384MulAdd(_) => SyscallCode::UINT384_MULADD,
             Self::Bls12381Decompress(_) => SyscallCode::BLS12381_DECOMPRESS,
             Self::K256Decompress(_) => SyscallCode::SECP256K1_DECOMPRESS,
             Self::P256Decompress(_) => SyscallCode::SECP256R1_DECOMPRESS,
@@ -525,6 +937,12 @@ impl<F: PrimeField32> RiscvAir<F> {
             Self::Bls12381Fp(_) => SyscallCode::BLS12381_FP_ADD,
             Self::Bls12381Fp2Mul(_) => SyscallCode::BLS12381_FP2_MUL,
             Self::Bls12381Fp2AddSub(_) => SyscallCode::BLS12381_FP2_ADD,
+            Self::Bls12381Fp6Mul(_) => SyscallCode::BLS12381_FP6_MUL,
+            Self::F64Add(_) => SyscallCode::F64_ADD,
+            Self::F64Mul(_) => SyscallCode::F64_MUL,
+            Self::F64Div(_) => SyscallCode::F64_DIV,
+            Self::HexDecode(_) => SyscallCode::HEX_DECODE,
+            Self::Base64Decode(_) => SyscallCode::BASE64_DECODE,
             Self::Add(_) => unreachable!("Invalid for core chip"),
             Self::Bitwise(_) => unreachable!("Invalid for core chip"),
             Self::DivRem(_) => unreachable!("Invalid for core chip"),
@@ -539,6 +957,7 @@ impl<F: PrimeField32> RiscvAir<F> {
             Self::ShiftRight(_) => unreachable!("Invalid for core chip"),
             Self::ShiftLeft(_) => unreachable!("Invalid for core chip"),
             Self::ByteLookup(_) => unreachable!("Invalid for core chip"),
+            Self::SecondaryByteLookup(_) => unreachable!("Invalid for core chip"),
             Self::SyscallCore(_) => unreachable!("Invalid for core chip"),
             Self::SyscallPrecompile(_) => unreachable!("Invalid for syscall precompile chip"),
         }
@@ -585,22 +1004,154 @@ impl<F: PrimeField32> core::hash::Hash for RiscvAir<F> {
 pub mod tests {
 
     use crate::{
+        alu::{
+            AddSubCols, BitwiseCols, DivRemCols, LtCols, MulCols, ShiftLeftCols, ShiftRightCols,
+        },
+        bytes::columns::ByteMultCols,
+        cpu::{columns::CpuCols, CpuChip},
         io::SP1Stdin,
+        memory::{MemoryInitCols, MemoryLocalCols, MemoryProgramMultCols},
+        program::ProgramMultiplicityCols,
         riscv::RiscvAir,
+        syscall::{
+            chip::SyscallCols,
+            precompiles::{
+                aes::AesRoundCols,
+                barrett_reduce::BarrettReduceCols,
+                bn254::{
+                    inner_product::Bn254InnerProductCols, mont::Bn254MontCols,
+                    mul_add_uint256::Bn254MulAddCols, mul_memo::Bn254MulMemoCols, ntt::FrNttCols,
+                },
+                chacha20::ChaCha20BlockCols,
+                codec::{Base64DecodeCols, HexDecodeCols},
+                csel256::Csel256Cols,
+                edwards::{Ed25519VerifyCols, EdAddAssignCols, EdDecompressCols},
+                f64::{F64AddCols, F64DivCols, F64MulCols},
+                fptower::{Fp2AddSubAssignCols, Fp2MulAssignCols, Fp6MulAssignCols, FpOpCols},
+                keccak256::columns::KeccakMemCols,
+                keccak256_accumulate::columns::KeccakAccumulateCols,
+                keccak256_merkle::KeccakMerkleCols,
+                keccak256_sponge::KeccakSpongeCols,
+                keccak256_withdraw_root::WithdrawRootCols,
+                memcpy::MemCopyCols,
+                poseidon::{PoseidonMerkleCols, ZkTrieNodeCols},
+                rlp_scan::RlpScanCols,
+                rsa::{RsaModExpCols, U2048Field, U4096Field},
+                sha256::{
+                    HmacSha256Cols, Sha256CompressBlocksCols, ShaCompressCols, ShaExtendCols,
+                },
+                sha512::Sha512CompressCols,
+                u256x2048_mul::U256x2048MulCols,
+                u256x4096_mul::U256x4096MulCols,
+                uint256::Uint256MulCols,
+                uint384::Uint384MulAddCols,
+                weierstrass::{
+                    Bn254G2DecompressCols, Secp256k1EcRecoverCols, WeierstrassAddAssignCols,
+                    WeierstrassDecompressCols, WeierstrassDoubleAssignCols,
+                },
+            },
+        },
         utils,
-        utils::{prove, run_test, setup_logger},
+        utils::{
+            generate_records, observe_records, prove, prove_from_records, run_test,
+            run_test_machine, setup_logger,
+        },
     };
 
     use sp1_core_executor::{
         programs::tests::{
             fibonacci_program, simple_memory_program, simple_program, ssz_withdrawals_program,
         },
-        Instruction, Opcode, Program,
+        syscalls::{SyscallCode, HALT_TYPED_EXIT},
+        ExecutionRecord, Executor, Instruction, Opcode, Program, SP1Context,
+    };
+    use std::{
+        borrow::{Borrow, BorrowMut},
+        collections::{BTreeMap, BTreeSet},
+        panic::AssertUnwindSafe,
+    };
+
+    use itertools::Itertools;
+    use p3_baby_bear::BabyBear;
+    use p3_field::{AbstractField, PrimeField32};
+    use p3_matrix::{dense::RowMajorMatrix, Matrix};
+    use sp1_curves::{
+        edwards::{
+            baby_jubjub::BabyJubjubBaseField, ed25519::Ed25519BaseField, jubjub::JubjubBaseField,
+        },
+        weierstrass::{
+            bls12_381::Bls12381BaseField, bn254::Bn254BaseField, secp256k1::Secp256k1BaseField,
+            secp256r1::Secp256r1BaseField,
+        },
     };
     use sp1_stark::{
-        baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, SP1CoreOpts, StarkProvingKey,
-        StarkVerifyingKey,
+        air::{MachineAir, PublicValues, SP1_PROOF_NUM_PV_ELTS},
+        baby_bear_poseidon2::BabyBearPoseidon2,
+        row_constraints_hold, ungated_interactions, CpuProver, InteractionKind, MachineBuilder,
+        MachineProof, MachineProver, MachineRecord, SP1CoreOpts, StarkGenericConfig,
+        StarkProvingKey, StarkVerifyingKey, UniConfig, Word,
     };
+    use test_artifacts::{
+        AES_CTR_ELF, BABY_JUBJUB_ELF, BARRETT_REDUCE_ELF, BASE64_DECODE_ELF, BLS12381_ADD_ELF,
+        BLS12381_DECOMPRESS_ELF, BLS12381_DOUBLE_ELF, BLS12381_FP2_ADDSUB_ELF,
+        BLS12381_FP2_MUL_ELF, BLS12381_FP6_MUL_ELF, BLS12381_FP_ELF, BN254_ADD_ELF,
+        BN254_DECOMPRESS_ELF, BN254_DOUBLE_ELF, BN254_FP12_ELF, BN254_FP2_ADDSUB_ELF,
+        BN254_FP2_MUL_ELF, BN254_FP_ELF, BN254_INNER_PRODUCT_ELF, BN254_MONT_ELF, CHACHA20_ELF,
+        CSEL256_ELF, ED25519_VERIFY_ELF, ED_ADD_ELF, ED_DECOMPRESS_ELF, EXIT_CODE_ELF,
+        F64_ARITH_ELF, FR_NTT_ELF, HEX_DECODE_ELF, HMAC_SHA256_ELF, JUBJUB_ELF,
+        KECCAK_ACCUMULATE_ELF, KECCAK_MERKLE_ELF, KECCAK_PERMUTE_ELF, KECCAK_SPONGE_ELF,
+        MEMCOPY_ELF, MORPH_SYSCALLS_ELF, POSEIDON_MERKLE_ELF, RLP_SCAN_ELF, RSA_ELF,
+        SECP256K1_ADD_ELF, SECP256K1_DECOMPRESS_ELF, SECP256K1_DOUBLE_ELF, SECP256K1_ECRECOVER_ELF,
+        SECP256R1_ADD_ELF, SECP256R1_DECOMPRESS_ELF, SECP256R1_DOUBLE_ELF, SHA256_BLOCKS_ELF,
+        SHA512_COMPRESS_ELF, SHA_COMPRESS_ELF, SHA_EXTEND_ELF, U256XU2048_MUL_ELF,
+        U256XU4096_MUL_ELF, UINT256_MULADD_ELF, UINT256_MUL_ELF, UINT384_MULADD_ELF,
+        WITHDRAW_ROOT_ELF, ZKTRIE_NODE_ELF,
+    };
+    use typenum::{U16, U8};
+
+    #[test]
+    fn test_byte_buses() {
+        for chip in RiscvAir::<BabyBear>::chips() {
+            let bus = chip.byte_bus();
+            assert_eq!(bus == InteractionKind::SecondaryByte, chip.air().uses_secondary_byte_bus());
+            for kind in InteractionKind::byte_buses().into_iter().filter(|kind| *kind != bus) {
+                assert_eq!(chip.num_sends_by_kind(kind), 0, "{} sends on {kind}", chip.name());
+                assert_eq!(
+                    chip.num_receives_by_kind(kind),
+                    0,
+                    "{} receives on {kind}",
+                    chip.name()
+                );
+            }
+        }
+    }
+
+    /// A machine extending the RISC-V machine, as a downstream fork would with its own chips.
+    #[derive(sp1_derive::MachineAir)]
+    enum ExtendedAir<F: PrimeField32> {
+        Riscv(RiscvAir<F>),
+    }
+
+    #[test]
+    fn test_machine_builder() {
+        let machine = MachineBuilder::new(SP1_PROOF_NUM_PV_ELTS, true)
+            .add_chips(RiscvAir::chips().into_iter().map(|chip| chip.map_air(ExtendedAir::Riscv)))
+            .build(BabyBearPoseidon2::new());
+        let expected = RiscvAir::machine(BabyBearPoseidon2::new());
+        for (chip, expected) in machine.chips().iter().zip_eq(expected.chips()) {
+            assert_eq!(chip.name(), expected.name());
+            assert_eq!(chip.byte_bus(), expected.byte_bus());
+            assert_eq!(chip.sends().len(), expected.sends().len());
+            assert_eq!(chip.receives().len(), expected.receives().len());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the machine already has a chip named CPU")]
+    fn test_machine_builder_duplicate_chip() {
+        let cpu = RiscvAir::<BabyBear>::chips().into_iter().next().unwrap();
+        let _ = RiscvAir::machine_builder().add_chip(cpu);
+    }
 
     #[test]
     fn test_simple_prove() {
@@ -760,6 +1311,108 @@ pub mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_fibonacci_prove_from_records() {
+        setup_logger();
+        let program = fibonacci_program();
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1024;
+
+        let mut runtime = Executor::new(program.clone(), opts);
+        runtime.run().unwrap();
+        let mut records = std::mem::take(&mut runtime.records);
+        records.iter_mut().enumerate().for_each(|(i, shard)| {
+            shard.public_values.shard = (i + 1) as u32;
+        });
+
+        let prover = CpuProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+        let (pk, vk) = prover.setup(&program);
+        prover.machine().generate_dependencies(&mut records, &opts, None);
+        let challenger_state = observe_records(&prover, &pk, &records);
+
+        // Hand off the serialized records to two separate proving workers.
+        let split = records.len() / 2;
+        let mut shard_proofs = Vec::new();
+        for chunk in [&records[..split], &records[split..]] {
+            let serialized = bincode::serialize(chunk).unwrap();
+            let chunk: Vec<ExecutionRecord> = bincode::deserialize(&serialized).unwrap();
+            let proof = prove_from_records(&prover, &pk, chunk, &challenger_state).unwrap();
+            shard_proofs.extend(proof.shard_proofs);
+        }
+
+        let proof = MachineProof { shard_proofs };
+        let mut challenger = prover.config().challenger();
+        prover.machine().verify(&vk, &proof, &mut challenger).unwrap();
+    }
+
+    /// Proves a record whose shard is past the former 16 bit range check of the shard, through the
+    /// CPU, the memory chips and a precompile chip.
+    #[test]
+    fn test_prove_shard_over_u16() {
+        setup_logger();
+        let program = Program::from(KECCAK_PERMUTE_ELF).unwrap();
+        let shard = (1 << 16) + 1;
+
+        let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+        runtime.state.current_shard = shard;
+        runtime.run().unwrap();
+        let mut records = std::mem::take(&mut runtime.records);
+        assert_eq!(records[0].public_values.execution_shard, shard);
+        assert!(!records[0].get_precompile_events(SyscallCode::KECCAK_PERMUTE).is_empty());
+        records.iter_mut().enumerate().for_each(|(i, record)| {
+            record.public_values.shard = shard + i as u32;
+        });
+
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let (pk, vk) = machine.setup(&program);
+        run_test_machine(records, machine, pk, vk).unwrap();
+    }
+
+    #[test]
+    fn test_fibonacci_generate_records() {
+        setup_logger();
+        let program = fibonacci_program();
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1024;
+        opts.shard_batch_size = 2;
+
+        // Re-executing the checkpoints concurrently yields the same records as one at a time.
+        let prover = CpuProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+        let (pk, vk) = prover.setup(&program);
+        let generate = |trace_gen_workers| {
+            let opts = SP1CoreOpts { trace_gen_workers, ..opts };
+            let stdin = SP1Stdin::new();
+            generate_records::<BabyBearPoseidon2>(
+                prover.machine(),
+                program.clone(),
+                &stdin,
+                opts,
+                SP1Context::default(),
+                None,
+            )
+            .unwrap()
+        };
+        let (records, public_values, cycles) = generate(4);
+        let (serial_records, serial_public_values, serial_cycles) = generate(1);
+        assert!(records.len() > 2);
+        assert_eq!((public_values, cycles), (serial_public_values, serial_cycles));
+        let summary = |records: &[ExecutionRecord]| {
+            records
+                .iter()
+                .map(|record| {
+                    let public_values = &record.public_values;
+                    (public_values.shard, public_values.start_pc, record.cpu_events.len())
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(summary(&records), summary(&serial_records));
+
+        let challenger_state = observe_records(&prover, &pk, &records);
+        let proof = prove_from_records(&prover, &pk, records, &challenger_state).unwrap();
+        let mut challenger = prover.config().challenger();
+        prover.machine().verify(&vk, &proof, &mut challenger).unwrap();
+    }
+
     #[test]
     fn test_simple_memory_program_prove() {
         setup_logger();
@@ -774,6 +1427,75 @@ pub mod tests {
         run_test::<CpuProver<_, _>>(program).unwrap();
     }
 
+    /// Proves a program using all of the Morph-added precompiles in one execution, which checks the
+    /// interactions between their chips that the per-chip tests do not.
+    #[test]
+    fn test_morph_syscalls_prove() {
+        setup_logger();
+        let program = Program::from(MORPH_SYSCALLS_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    /// Proves a program that halts with a typed exit code, which only the halting shard carries.
+    #[test]
+    fn test_exit_code_prove() {
+        setup_logger();
+        let program = Program::from(EXIT_CODE_ELF).unwrap();
+        let proof = run_test::<CpuProver<_, _>>(program).unwrap();
+        let exit_codes = proof
+            .shard_proofs
+            .iter()
+            .map(|shard_proof| {
+                let public_values: &PublicValues<Word<BabyBear>, BabyBear> =
+                    shard_proof.public_values.as_slice().borrow();
+                public_values.exit_code
+            })
+            .filter(|exit_code| *exit_code != BabyBear::zero())
+            .collect::<Vec<_>>();
+        assert_eq!(exit_codes, vec![BabyBear::from_canonical_u32(3)]);
+    }
+
+    /// Checks that the CPU chip only accepts a non-zero exit code from a typed halt, by proving
+    /// the trace of a typed `exit(7)` with and without the typed flag in its second operand.
+    #[test]
+    fn test_untyped_exit_code_does_not_verify() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HALT as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 7, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, HALT_TYPED_EXIT, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let mut runtime = Executor::new(Program::new(instructions, 0, 0), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let mut record = runtime.records.remove(0);
+        record.register_nonces(&SP1CoreOpts::default());
+        let halt_row = record.cpu_events.len() - 1;
+        let public_values = record.public_values.to_vec::<BabyBear>();
+        assert_eq!(record.public_values.exit_code, 7);
+
+        let chip = CpuChip::default();
+        let prove_and_verify = |untyped: bool| {
+            let mut trace: RowMajorMatrix<BabyBear> =
+                chip.generate_trace(&record, &mut ExecutionRecord::default());
+            if untyped {
+                let cols: &mut CpuCols<BabyBear> = trace.row_mut(halt_row).borrow_mut();
+                cols.op_c_access.access.value = Word::from(0u32);
+            }
+            // The prover checks the constraints and panics on failure with debug assertions.
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                let config = UniConfig(BabyBearPoseidon2::new());
+                let mut challenger = config.0.challenger();
+                let proof =
+                    p3_uni_stark::prove(&config, &chip, &mut challenger, trace, &public_values);
+                let mut challenger = config.0.challenger();
+                p3_uni_stark::verify(&config, &chip, &mut challenger, &proof, &public_values)
+            }));
+            matches!(result, Ok(Ok(())))
+        };
+        assert!(prove_and_verify(false));
+        assert!(!prove_and_verify(true));
+    }
+
     #[test]
     fn test_key_serde() {
         let program = ssz_withdrawals_program();
@@ -806,4 +1528,334 @@ pub mod tests {
         }
         assert_eq!(vk.chip_ordering, deserialized_vk.chip_ordering);
     }
+
+    type F = BabyBear;
+    type EF = <BabyBearPoseidon2 as StarkGenericConfig>::Challenge;
+
+    /// A flag, or an array of multiplicities, gating the interactions of a row.
+    trait Clear {
+        /// Zeroes the flag and returns whether it was set.
+        fn clear(&mut self) -> bool;
+    }
+
+    impl Clear for F {
+        fn clear(&mut self) -> bool {
+            std::mem::replace(self, F::zero()) != F::zero()
+        }
+    }
+
+    impl<const N: usize> Clear for [F; N] {
+        fn clear(&mut self) -> bool {
+            self.iter_mut().fold(false, |set, flag| flag.clear() | set)
+        }
+    }
+
+    /// Clears the given `is_real`-style flags of a row and returns whether the row was real.
+    macro_rules! clear {
+        ($cols:ty, $($is_real:ident).+ $(, $($flag:ident).+)*) => {
+            |row: &mut [F]| {
+                let cols: &mut $cols = row.borrow_mut();
+                cols.$($is_real).+.clear() $(| cols.$($flag).+.clear())*
+            }
+        };
+    }
+
+    /// Checks that clearing only the `is_real` flag of a real row of any chip either drops all of
+    /// its interactions or leaves a row that the AIR rejects, so that a prover cannot keep the
+    /// interactions of a row while skipping the constraints gated by `is_real`.
+    #[test]
+    fn test_interactions_gated_by_is_real() {
+        let machine = RiscvAir::<F>::machine(BabyBearPoseidon2::new());
+        // Share the products of the bn254 mul-adds, so that the memo chip is exercised too.
+        let mut opts = SP1CoreOpts::default();
+        opts.split_opts.bn254_mul_memo = true;
+
+        let mut bn254_decompress = SP1Stdin::new();
+        let g2_x = sp1_curves::weierstrass::bn254::bn254_g2_generator().0;
+        let to_bytes_be = |value: &num::BigUint| {
+            let mut bytes = value.to_bytes_le();
+            bytes.resize(32, 0);
+            bytes.reverse();
+            bytes
+        };
+        bn254_decompress.write_vec(to_bytes_be(&num::BigUint::from(1u32)));
+        bn254_decompress.write(&false);
+        bn254_decompress.write_vec([to_bytes_be(&g2_x[1]), to_bytes_be(&g2_x[0])].concat());
+        bn254_decompress.write(&false);
+        let mut keccak_accumulate = SP1Stdin::new();
+        keccak_accumulate.write(&(0..300u32).map(|i| i as u8).collect::<Vec<_>>());
+        let compressed = |point: &str| SP1Stdin::from(&hex::decode(point).unwrap());
+
+        let programs = [
+            (AES_CTR_ELF, SP1Stdin::new()),
+            (BABY_JUBJUB_ELF, SP1Stdin::new()),
+            (BARRETT_REDUCE_ELF, SP1Stdin::new()),
+            (BASE64_DECODE_ELF, SP1Stdin::new()),
+            (BLS12381_ADD_ELF, SP1Stdin::new()),
+            (
+                BLS12381_DECOMPRESS_ELF,
+                compressed(
+                    "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+                ),
+            ),
+            (BLS12381_DOUBLE_ELF, SP1Stdin::new()),
+            (BLS12381_FP_ELF, SP1Stdin::new()),
+            (BLS12381_FP2_ADDSUB_ELF, SP1Stdin::new()),
+            (BLS12381_FP2_MUL_ELF, SP1Stdin::new()),
+            (BLS12381_FP6_MUL_ELF, SP1Stdin::new()),
+            (BN254_ADD_ELF, SP1Stdin::new()),
+            (BN254_DECOMPRESS_ELF, bn254_decompress),
+            (BN254_DOUBLE_ELF, SP1Stdin::new()),
+            (BN254_FP_ELF, SP1Stdin::new()),
+            (BN254_FP12_ELF, SP1Stdin::new()),
+            (BN254_FP2_ADDSUB_ELF, SP1Stdin::new()),
+            (BN254_FP2_MUL_ELF, SP1Stdin::new()),
+            (BN254_INNER_PRODUCT_ELF, SP1Stdin::new()),
+            (BN254_MONT_ELF, SP1Stdin::new()),
+            (CHACHA20_ELF, SP1Stdin::new()),
+            (CSEL256_ELF, SP1Stdin::new()),
+            (ED25519_VERIFY_ELF, SP1Stdin::new()),
+            (ED_ADD_ELF, SP1Stdin::new()),
+            (ED_DECOMPRESS_ELF, SP1Stdin::new()),
+            (F64_ARITH_ELF, SP1Stdin::new()),
+            (FR_NTT_ELF, SP1Stdin::new()),
+            (HEX_DECODE_ELF, SP1Stdin::new()),
+            (HMAC_SHA256_ELF, SP1Stdin::new()),
+            (JUBJUB_ELF, SP1Stdin::new()),
+            (KECCAK_ACCUMULATE_ELF, keccak_accumulate),
+            (KECCAK_MERKLE_ELF, SP1Stdin::new()),
+            (KECCAK_PERMUTE_ELF, SP1Stdin::new()),
+            (KECCAK_SPONGE_ELF, SP1Stdin::new()),
+            (MEMCOPY_ELF, SP1Stdin::new()),
+            (POSEIDON_MERKLE_ELF, SP1Stdin::new()),
+            (RLP_SCAN_ELF, SP1Stdin::new()),
+            (RSA_ELF, SP1Stdin::new()),
+            (SECP256K1_ADD_ELF, SP1Stdin::new()),
+            (
+                SECP256K1_DECOMPRESS_ELF,
+                compressed("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            ),
+            (SECP256K1_DOUBLE_ELF, SP1Stdin::new()),
+            (SECP256K1_ECRECOVER_ELF, SP1Stdin::new()),
+            (SECP256R1_ADD_ELF, SP1Stdin::new()),
+            (
+                SECP256R1_DECOMPRESS_ELF,
+                compressed("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+            ),
+            (SECP256R1_DOUBLE_ELF, SP1Stdin::new()),
+            (SHA256_BLOCKS_ELF, SP1Stdin::new()),
+            (SHA512_COMPRESS_ELF, SP1Stdin::new()),
+            (SHA_COMPRESS_ELF, SP1Stdin::new()),
+            (SHA_EXTEND_ELF, SP1Stdin::new()),
+            (U256XU2048_MUL_ELF, SP1Stdin::new()),
+            (U256XU4096_MUL_ELF, SP1Stdin::new()),
+            (UINT256_MUL_ELF, SP1Stdin::new()),
+            (UINT256_MULADD_ELF, SP1Stdin::new()),
+            (UINT384_MULADD_ELF, SP1Stdin::new()),
+            (WITHDRAW_ROOT_ELF, SP1Stdin::new()),
+            (ZKTRIE_NODE_ELF, SP1Stdin::new()),
+        ];
+
+        let mut checked = BTreeSet::new();
+        for (elf, stdin) in programs {
+            let program = Program::from(elf).unwrap();
+            let mut runtime = Executor::new(program, opts);
+            stdin.write_to(&mut runtime);
+            runtime.run().unwrap();
+
+            let mut records = std::mem::take(&mut runtime.records);
+            machine.generate_dependencies(&mut records, &opts, None);
+            for record in &records {
+                let public_values = record.public_values::<F>();
+                for chip in machine.shard_chips(record) {
+                    let clear_is_real: fn(&mut [F]) -> bool = match chip.air() {
+                        RiscvAir::Program(_) => clear!(ProgramMultiplicityCols<F>, multiplicity),
+                        RiscvAir::Cpu(_) => clear!(CpuCols<F>, is_real),
+                        RiscvAir::Add(_) => clear!(AddSubCols<F>, is_add, is_sub),
+                        RiscvAir::Bitwise(_) => clear!(BitwiseCols<F>, is_xor, is_or, is_and),
+                        RiscvAir::Mul(_) => clear!(MulCols<F>, is_real),
+                        RiscvAir::DivRem(_) => clear!(DivRemCols<F>, is_real),
+                        RiscvAir::Lt(_) => clear!(LtCols<F>, is_slt, is_sltu),
+                        RiscvAir::ShiftLeft(_) => clear!(ShiftLeftCols<F>, is_real),
+                        RiscvAir::ShiftRight(_) => clear!(ShiftRightCols<F>, is_real),
+                        RiscvAir::ByteLookup(_) | RiscvAir::SecondaryByteLookup(_) => {
+                            clear!(ByteMultCols<F>, multiplicities)
+                        }
+                        RiscvAir::MemoryGlobalInit(_) | RiscvAir::MemoryGlobalFinal(_) => {
+                            clear!(MemoryInitCols<F>, is_real)
+                        }
+                        RiscvAir::MemoryLocal(_) => |row: &mut [F]| {
+                            let cols: &mut MemoryLocalCols<F> = row.borrow_mut();
+                            cols.memory_local_entries
+                                .iter_mut()
+                                .fold(false, |real, entry| entry.is_real.clear() | real)
+                        },
+                        RiscvAir::ProgramMemory(_) => {
+                            clear!(MemoryProgramMultCols<F>, multiplicity)
+                        }
+                        RiscvAir::SyscallCore(_) | RiscvAir::SyscallPrecompile(_) => {
+                            clear!(SyscallCols<F>, is_real)
+                        }
+                        RiscvAir::Sha256Extend(_) => clear!(ShaExtendCols<F>, is_real),
+                        RiscvAir::Sha256Compress(_) => clear!(ShaCompressCols<F>, is_real),
+                        RiscvAir::HmacSha256(_) => clear!(HmacSha256Cols<F>, is_real),
+                        RiscvAir::Sha256CompressBlocks(_) => {
+                            clear!(Sha256CompressBlocksCols<F>, is_real)
+                        }
+                        RiscvAir::Sha512Compress(_) => clear!(Sha512CompressCols<F>, is_real),
+                        RiscvAir::Ed25519Add(_) => {
+                            clear!(EdAddAssignCols<F, Ed25519BaseField>, is_real)
+                        }
+                        RiscvAir::Ed25519Decompress(_) => clear!(EdDecompressCols<F>, is_real),
+                        RiscvAir::Ed25519Verify(_) => clear!(Ed25519VerifyCols<F>, is_real),
+                        RiscvAir::JubjubAdd(_) => {
+                            clear!(EdAddAssignCols<F, JubjubBaseField>, is_real)
+                        }
+                        RiscvAir::BabyJubjubAdd(_) => {
+                            clear!(EdAddAssignCols<F, BabyJubjubBaseField>, is_real)
+                        }
+                        RiscvAir::K256Decompress(_) => {
+                            clear!(WeierstrassDecompressCols<F, Secp256k1BaseField>, is_real)
+                        }
+                        RiscvAir::P256Decompress(_) => {
+                            clear!(WeierstrassDecompressCols<F, Secp256r1BaseField>, is_real)
+                        }
+                        RiscvAir::Secp256k1Add(_) => {
+                            clear!(WeierstrassAddAssignCols<F, Secp256k1BaseField>, is_real)
+                        }
+                        RiscvAir::Secp256k1Double(_) => {
+                            clear!(WeierstrassDoubleAssignCols<F, Secp256k1BaseField>, is_real)
+                        }
+                        RiscvAir::Secp256k1EcRecover(_) => {
+                            clear!(Secp256k1EcRecoverCols<F>, is_real)
+                        }
+                        RiscvAir::Secp256r1Add(_) => {
+                            clear!(WeierstrassAddAssignCols<F, Secp256r1BaseField>, is_real)
+                        }
+                        RiscvAir::Secp256r1Double(_) => {
+                            clear!(WeierstrassDoubleAssignCols<F, Secp256r1BaseField>, is_real)
+                        }
+                        RiscvAir::KeccakP(_) => clear!(KeccakMemCols<F>, is_real),
+                        RiscvAir::KeccakAccumulate(_) => clear!(KeccakAccumulateCols<F>, is_real),
+                        RiscvAir::KeccakSponge(_) => clear!(KeccakSpongeCols<F>, is_real),
+                        RiscvAir::KeccakMerkle(_) => clear!(KeccakMerkleCols<F>, is_real),
+                        RiscvAir::WithdrawRoot(_) => clear!(WithdrawRootCols<F>, is_real),
+                        RiscvAir::Bn254Add(_) => {
+                            clear!(WeierstrassAddAssignCols<F, Bn254BaseField>, is_real)
+                        }
+                        RiscvAir::Bn254Double(_) => {
+                            clear!(WeierstrassDoubleAssignCols<F, Bn254BaseField>, is_real)
+                        }
+                        RiscvAir::Bn254Decompress(_) => {
+                            clear!(WeierstrassDecompressCols<F, Bn254BaseField>, is_real)
+                        }
+                        RiscvAir::Bn254G2Decompress(_) => {
+                            clear!(Bn254G2DecompressCols<F>, is_real)
+                        }
+                        RiscvAir::Bls12381Add(_) => {
+                            clear!(WeierstrassAddAssignCols<F, Bls12381BaseField>, is_real)
+                        }
+                        RiscvAir::Bls12381Double(_) => {
+                            clear!(WeierstrassDoubleAssignCols<F, Bls12381BaseField>, is_real)
+                        }
+                        RiscvAir::Uint256Mul(_) => clear!(Uint256MulCols<F>, is_real),
+                        RiscvAir::Bn254MulAdd(_) => clear!(Bn254MulAddCols<F>, is_real),
+                        RiscvAir::Bn254MulMemo(_) => clear!(Bn254MulMemoCols<F>, is_real),
+                        RiscvAir::Bn254Mont(_) => clear!(Bn254MontCols<F>, is_real),
+                        RiscvAir::Bn254InnerProduct(_) => {
+                            clear!(Bn254InnerProductCols<F>, is_real)
+                        }
+                        RiscvAir::FrNtt(_) => clear!(FrNttCols<F>, is_real),
+                        RiscvAir::MemCopy32(_) => clear!(MemCopyCols<F, U8>, is_real),
+                        RiscvAir::MemCopy64(_) => clear!(MemCopyCols<F, U16>, is_real),
+                        RiscvAir::Csel256(_) => clear!(Csel256Cols<F>, is_real),
+                        RiscvAir::RlpScan(_) => clear!(RlpScanCols<F>, is_real),
+                        RiscvAir::PoseidonMerkle(_) => clear!(PoseidonMerkleCols<F>, is_real),
+                        RiscvAir::ZkTrieNode(_) => clear!(ZkTrieNodeCols<F>, is_real),
+                        RiscvAir::Rsa2048ModExp(_) => {
+                            clear!(RsaModExpCols<F, U2048Field>, is_real)
+                        }
+                        RiscvAir::Rsa4096ModExp(_) => {
+                            clear!(RsaModExpCols<F, U4096Field>, is_real)
+                        }
+                        RiscvAir::U256x2048Mul(_) => clear!(U256x2048MulCols<F>, is_real),
+                        RiscvAir::U256x4096Mul(_) => clear!(U256x4096MulCols<F>, is_real),
+                        RiscvAir::BarrettReduce(_) => clear!(BarrettReduceCols<F>, is_real),
+                        RiscvAir::AesRound(_) => clear!(AesRoundCols<F>, is_real),
+                        RiscvAir::ChaCha20Block(_) => clear!(ChaCha20BlockCols<F>, is_real),
+                        RiscvAir::Uint384MulAdd(_) => clear!(Uint384MulAddCols<F>, is_real),
+                        RiscvAir::Bls12381Decompress(_) => {
+                            clear!(WeierstrassDecompressCols<F, Bls12381BaseField>, is_real)
+                        }
+                        RiscvAir::Bls12381Fp(_) => clear!(FpOpCols<F, Bls12381BaseField>, is_real),
+                        RiscvAir::Bls12381Fp2Mul(_) => {
+                            clear!(Fp2MulAssignCols<F, Bls12381BaseField>, is_real)
+                        }
+                        RiscvAir::Bls12381Fp2AddSub(_) => {
+                            clear!(Fp2AddSubAssignCols<F, Bls12381BaseField>, is_real)
+                        }
+                        RiscvAir::Bls12381Fp6Mul(_) => {
+                            clear!(Fp6MulAssignCols<F, Bls12381BaseField>, is_real)
+                        }
+                        RiscvAir::Bn254Fp(_) => clear!(FpOpCols<F, Bn254BaseField>, is_real),
+                        RiscvAir::Bn254Fp2Mul(_) => {
+                            clear!(Fp2MulAssignCols<F, Bn254BaseField>, is_real)
+                        }
+                        RiscvAir::Bn254Fp6Mul(_) => {
+                            clear!(Fp6MulAssignCols<F, Bn254BaseField>, is_real)
+                        }
+                        RiscvAir::Bn254Fp2AddSub(_) => {
+                            clear!(Fp2AddSubAssignCols<F, Bn254BaseField>, is_real)
+                        }
+                        RiscvAir::F64Add(_) => clear!(F64AddCols<F>, syscall.is_real),
+                        RiscvAir::F64Mul(_) => clear!(F64MulCols<F>, syscall.is_real),
+                        RiscvAir::F64Div(_) => clear!(F64DivCols<F>, syscall.is_real),
+                        RiscvAir::HexDecode(_) => clear!(HexDecodeCols<F>, is_real),
+                        RiscvAir::Base64Decode(_) => clear!(Base64DecodeCols<F>, is_real),
+                    };
+
+                    let preprocessed = chip.generate_preprocessed_trace(&record.program);
+                    let main = chip.generate_trace(record, &mut ExecutionRecord::default());
+                    let ungated =
+                        ungated_interactions(chip, preprocessed.as_ref(), &main, clear_is_real);
+
+                    // Interactions surviving the cleared flag are fine as long as the AIR ties the
+                    // flags gating them to `is_real`, so that the row no longer satisfies it. The
+                    // rows are checked once per set of surviving interactions.
+                    let mut by_row = BTreeMap::<usize, Vec<usize>>::new();
+                    for interaction in &ungated {
+                        by_row
+                            .entry(interaction.row)
+                            .or_default()
+                            .push(interaction.interaction_number);
+                    }
+                    let mut seen = BTreeSet::new();
+                    for (row, interactions) in by_row {
+                        if !seen.insert(interactions.clone()) {
+                            continue;
+                        }
+                        let mut local = main.row_slice(row).to_vec();
+                        clear_is_real(&mut local);
+                        assert!(
+                            !row_constraints_hold::<F, EF, _>(
+                                chip,
+                                preprocessed.as_ref(),
+                                &main,
+                                row,
+                                &local,
+                                &public_values,
+                            ),
+                            "{} keeps interactions {interactions:?} of row {row} without is_real",
+                            chip.name(),
+                        );
+                    }
+                    checked.insert(chip.name());
+                }
+            }
+        }
+
+        for chip in machine.chips() {
+            assert!(checked.contains(&chip.name()), "no program exercised {}", chip.name());
+        }
+    }
 }
@@ -203,15 +203,15 @@ impl<F: PrimeField32> CoreShapeConfig<F> {
         air: &RiscvAir<F>,
         mem_events_per_row: usize,
         allowed_log_height: usize,
-    ) -> Vec<[(String, usize); 3]> {
+    ) -> Vec<Vec<(String, usize)>> {
         (1..=air.rows_per_event())
             .rev()
             .map(|rows_per_event| {
-                [
+                let mut shape = vec![
                     (air.name(), allowed_log_height),
                     (
                         RiscvAir::<F>::SyscallPrecompile(SyscallChip::precompile()).name(),
-                        ((1 << allowed_log_height)
+                        (((1 << allowed_log_height) * air.syscalls_per_row())
                             .div_ceil(&air.rows_per_event())
                             .next_power_of_two()
                             .ilog2() as usize)
@@ -225,7 +225,13 @@ impl<F: PrimeField32> CoreShapeConfig<F> {
                             .ilog2() as usize)
                             .max(4),
                     ),
-                ]
+                ];
+                shape.extend(
+                    air.precompile_lookup_airs()
+                        .into_iter()
+                        .map(|lookup_air| (lookup_air.name(), allowed_log_height)),
+                );
+                shape
             })
             .collect()
     }
@@ -320,6 +326,21 @@ impl<F: PrimeField32> CoreShapeConfig<F> {
 
         max_core_shapes.collect()
     }
+
+    /// The largest log height allowed for a precompile chip, if any is allowed.
+    pub(crate) fn max_precompile_log_height(&self, air: &RiscvAir<F>) -> Option<usize> {
+        self.precompile_allowed_log_heights
+            .get(air)
+            .and_then(|(_, allowed_log_heights)| allowed_log_heights.iter().max().copied())
+    }
+
+    /// The largest log height allowed for both global memory chips, if any is allowed.
+    pub(crate) fn max_memory_log_height(&self) -> Option<usize> {
+        self.memory_allowed_log_heights
+            .values()
+            .filter_map(|allowed_log_heights| allowed_log_heights.iter().flatten().max().copied())
+            .min()
+    }
 }
 
 impl<F: PrimeField32> Default for CoreShapeConfig<F> {
@@ -332,6 +353,7 @@ impl<F: PrimeField32> Default for CoreShapeConfig<F> {
             (RiscvAir::Program(ProgramChip::default()), program_heights),
             (RiscvAir::ProgramMemory(MemoryProgramChip::default()), program_memory_heights),
             (RiscvAir::ByteLookup(ByteChip::default()), vec![Some(16)]),
+            (RiscvAir::SecondaryByteLookup(ByteChip::secondary()), vec![Some(16)]),
         ]);
 
         let core_shapes = [
@@ -781,6 +803,7 @@ pub mod tests {
             (RiscvAir::<BabyBear>::Program(ProgramChip::default()), 10),
             (RiscvAir::<BabyBear>::ProgramMemory(MemoryProgramChip::default()), 10),
             (RiscvAir::<BabyBear>::ByteLookup(ByteChip::default()), 16),
+            (RiscvAir::<BabyBear>::SecondaryByteLookup(ByteChip::secondary()), 16),
         ];
 
         let core_log_heights = [
@@ -0,0 +1,534 @@
+//! Row generation through the C++ implementations of `sp1-core-machine-sys`.
+//!
+//! The C++ side shares the layouts of the columns and of the `*EventFFI` structs below through
+//! cbindgen, which lets GPU provers generate the rows of these chips from the same definitions. The
+//! wrappers below produce the same rows as the `generate_trace` of the chips, except for the nonce,
+//! which is left to the caller, and they do not add the byte lookup events of the rows.
+
+use std::marker::PhantomData;
+
+use generic_array::ArrayLength;
+use p3_baby_bear::BabyBear;
+use sp1_core_executor::events::{
+    Bn254FieldArithEvent, Bn254MulAddEvent, MemCopyEvent, MemoryReadRecord, MemoryWriteRecord,
+    PrecompileEvents, NUM_WORDS_PER_FE,
+};
+
+use crate::syscall::precompiles::{
+    bn254::mul_add_uint256::Bn254MulAddCols, bn254_scalar::Bn254ScalarMulAddCols,
+    memcpy::MemCopyCols,
+};
+
+// Link the C++ library of the functions below.
+use sp1_core_machine_sys as _;
+
+/// A `BN254_SCALAR_MULADD` [`Bn254FieldArithEvent`], with the memory records the row generation
+/// reads.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Bn254ScalarMulAddEventFFI<'a> {
+    pub shard: u32,
+    pub clk: u32,
+    pub x_ptr: u32,
+    pub y_ptr: u32,
+    /// The [`NUM_WORDS_PER_FE`] write records of x.
+    pub x_memory_records: *const MemoryWriteRecord,
+    /// The two read records of the pointers to a and b.
+    pub y_memory_records: *const MemoryReadRecord,
+    /// The [`NUM_WORDS_PER_FE`] read records of a.
+    pub a_memory_records: *const MemoryReadRecord,
+    /// The [`NUM_WORDS_PER_FE`] read records of b.
+    pub b_memory_records: *const MemoryReadRecord,
+    _marker: PhantomData<&'a Bn254FieldArithEvent>,
+}
+
+impl<'a> Bn254ScalarMulAddEventFFI<'a> {
+    /// Borrows the memory records of a `BN254_SCALAR_MULADD` event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event is missing memory records, e.g. because it is of another operation.
+    pub fn new(event: &'a Bn254FieldArithEvent) -> Self {
+        let a = event.a.as_ref().expect("a is read by BN254_SCALAR_MULADD");
+        let b = event.b.as_ref().expect("b is read by BN254_SCALAR_MULADD");
+        assert_eq!(event.arg1.memory_records.len(), NUM_WORDS_PER_FE);
+        assert_eq!(event.arg2.memory_records.len(), 2);
+        assert_eq!(a.memory_records.len(), NUM_WORDS_PER_FE);
+        assert_eq!(b.memory_records.len(), NUM_WORDS_PER_FE);
+
+        Self {
+            shard: event.shard,
+            clk: event.clk,
+            x_ptr: event.arg1.ptr,
+            y_ptr: event.arg2.ptr,
+            x_memory_records: event.arg1.memory_records.as_ptr(),
+            y_memory_records: event.arg2.memory_records.as_ptr(),
+            a_memory_records: a.memory_records.as_ptr(),
+            b_memory_records: b.memory_records.as_ptr(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A `BN254_MULADD` or `BN254_MULADD_BE` [`Bn254MulAddEvent`], with the memory records the row
+/// generation reads.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Bn254MulAddEventFFI<'a> {
+    pub shard: u32,
+    pub clk: u32,
+    pub x_ptr: u32,
+    pub y_ptr: u32,
+    pub big_endian: bool,
+    /// The [`NUM_WORDS_PER_FE`] write records of x.
+    pub x_memory_records: *const MemoryWriteRecord,
+    /// The [`NUM_WORDS_PER_FE`] read records of a.
+    pub a_memory_records: *const MemoryReadRecord,
+    /// The [`NUM_WORDS_PER_FE`] read records of b.
+    pub b_memory_records: *const MemoryReadRecord,
+    _marker: PhantomData<&'a PrecompileEvents>,
+}
+
+impl<'a> Bn254MulAddEventFFI<'a> {
+    /// Borrows the memory records of a `BN254_MULADD` event from the arenas of its record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event is missing memory records.
+    pub fn new(event: &Bn254MulAddEvent, precompile_events: &'a PrecompileEvents) -> Self {
+        let x_memory_records = precompile_events.writes(event.x_memory_records);
+        let a_memory_records = precompile_events.reads(event.a_memory_records);
+        let b_memory_records = precompile_events.reads(event.b_memory_records);
+        assert_eq!(x_memory_records.len(), NUM_WORDS_PER_FE);
+        assert_eq!(a_memory_records.len(), NUM_WORDS_PER_FE);
+        assert_eq!(b_memory_records.len(), NUM_WORDS_PER_FE);
+
+        Self {
+            shard: event.shard,
+            clk: event.clk,
+            x_ptr: event.x_ptr,
+            y_ptr: event.y_ptr,
+            big_endian: event.big_endian,
+            x_memory_records: x_memory_records.as_ptr(),
+            a_memory_records: a_memory_records.as_ptr(),
+            b_memory_records: b_memory_records.as_ptr(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A `MEMCPY_32` or `MEMCPY_64` [`MemCopyEvent`], with the memory records the row generation
+/// reads.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MemCopyEventFFI<'a> {
+    pub shard: u32,
+    pub clk: u32,
+    /// The clock cycle of the call copying the upper half, which is `clk` unless coalesced.
+    pub upper_clk: u32,
+    pub is_coalesced: bool,
+    pub src_ptr: u32,
+    pub dst_ptr: u32,
+    /// The read records of the source, one per copied word.
+    pub read_records: *const MemoryReadRecord,
+    /// The write records of the destination, one per copied word.
+    pub write_records: *const MemoryWriteRecord,
+    _marker: PhantomData<&'a PrecompileEvents>,
+}
+
+impl<'a> MemCopyEventFFI<'a> {
+    /// Borrows the memory records of an event copying `num_words` words from the arenas of its
+    /// record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event does not copy `num_words` words.
+    pub fn new(
+        event: &MemCopyEvent,
+        precompile_events: &'a PrecompileEvents,
+        num_words: usize,
+    ) -> Self {
+        let read_records = precompile_events.reads(event.read_records);
+        let write_records = precompile_events.writes(event.write_records);
+        assert_eq!(read_records.len(), num_words);
+        assert_eq!(write_records.len(), num_words);
+
+        Self {
+            shard: event.shard,
+            clk: event.clk,
+            upper_clk: event.coalesced_syscall.as_ref().map_or(event.clk, |syscall| syscall.clk),
+            is_coalesced: event.coalesced_syscall.is_some(),
+            src_ptr: event.src_ptr,
+            dst_ptr: event.dst_ptr,
+            read_records: read_records.as_ptr(),
+            write_records: write_records.as_ptr(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+extern "C" {
+    fn bn254_scalar_mul_add_event_to_row_babybear(
+        event: &Bn254ScalarMulAddEventFFI,
+        row: *mut BabyBear,
+    );
+
+    fn bn254_mul_add_event_to_row_babybear(event: &Bn254MulAddEventFFI, row: *mut BabyBear);
+
+    fn memcopy32_event_to_row_babybear(event: &MemCopyEventFFI, row: *mut BabyBear);
+
+    fn memcopy64_event_to_row_babybear(event: &MemCopyEventFFI, row: *mut BabyBear);
+}
+
+/// Populates the zeroed columns of a `BN254_SCALAR_MULADD` event.
+pub fn bn254_scalar_mul_add_event_to_row(
+    event: &Bn254FieldArithEvent,
+    cols: &mut Bn254ScalarMulAddCols<BabyBear>,
+) {
+    let event = Bn254ScalarMulAddEventFFI::new(event);
+    unsafe {
+        bn254_scalar_mul_add_event_to_row_babybear(
+            &event,
+            (cols as *mut Bn254ScalarMulAddCols<BabyBear>).cast(),
+        )
+    }
+}
+
+/// Populates the zeroed columns of a `BN254_MULADD` or `BN254_MULADD_BE` event, whose memory
+/// records are in the arenas of `precompile_events`.
+pub fn bn254_mul_add_event_to_row(
+    event: &Bn254MulAddEvent,
+    precompile_events: &PrecompileEvents,
+    cols: &mut Bn254MulAddCols<BabyBear>,
+) {
+    let event = Bn254MulAddEventFFI::new(event, precompile_events);
+    unsafe {
+        bn254_mul_add_event_to_row_babybear(&event, (cols as *mut Bn254MulAddCols<BabyBear>).cast())
+    }
+}
+
+/// Populates the zeroed columns of a `MEMCPY_32` or `MEMCPY_64` event, whose memory records are
+/// in the arenas of `precompile_events`.
+pub fn memcopy_event_to_row<NumWords: ArrayLength>(
+    event: &MemCopyEvent,
+    precompile_events: &PrecompileEvents,
+    cols: &mut MemCopyCols<BabyBear, NumWords>,
+) {
+    let event = MemCopyEventFFI::new(event, precompile_events, NumWords::USIZE);
+    let row = (cols as *mut MemCopyCols<BabyBear, NumWords>).cast();
+    unsafe {
+        match NumWords::USIZE {
+            8 => memcopy32_event_to_row_babybear(&event, row),
+            16 => memcopy64_event_to_row_babybear(&event, row),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::BorrowMut;
+
+    use num::{bigint::RandBigInt, BigUint};
+    use p3_air::BaseAir;
+    use p3_field::AbstractField;
+    use p3_matrix::{dense::RowMajorMatrix, Matrix};
+    use rand::Rng;
+    use sp1_core_executor::{
+        events::{
+            bn254_swap_endianness, Bn254FieldOperation, FieldArithMemoryAccess, LookupId,
+            PrecompileEvent, SyscallEvent,
+        },
+        syscalls::SyscallCode,
+        ExecutionRecord,
+    };
+    use sp1_curves::{params::FieldParameters, weierstrass::bn254::Bn254ScalarField};
+    use sp1_stark::air::MachineAir;
+    use typenum::{U16, U8};
+
+    use super::*;
+    use crate::syscall::precompiles::{
+        bn254::mul_add_uint256::Bn254MulAddChip,
+        bn254_scalar::Bn254ScalarMulAddChip,
+        memcpy::{num_memcopy_cols, MemCopyChip},
+    };
+
+    const SHARD: u32 = 3;
+
+    /// The previous shard and timestamp of an access at `timestamp`, in this shard or an earlier
+    /// one.
+    fn prev_access(rng: &mut impl Rng, timestamp: u32) -> (u32, u32) {
+        if rng.gen() {
+            (SHARD, rng.gen_range(0..timestamp))
+        } else {
+            (rng.gen_range(1..SHARD), rng.gen_range(0..1 << 22))
+        }
+    }
+
+    fn read_record(rng: &mut impl Rng, value: u32, timestamp: u32) -> MemoryReadRecord {
+        let (prev_shard, prev_timestamp) = prev_access(rng, timestamp);
+        MemoryReadRecord { value, shard: SHARD, timestamp, prev_shard, prev_timestamp }
+    }
+
+    fn write_record(
+        rng: &mut impl Rng,
+        value: u32,
+        prev_value: u32,
+        timestamp: u32,
+    ) -> MemoryWriteRecord {
+        let (prev_shard, prev_timestamp) = prev_access(rng, timestamp);
+        MemoryWriteRecord { value, shard: SHARD, timestamp, prev_value, prev_shard, prev_timestamp }
+    }
+
+    fn words(value: &BigUint) -> Vec<u32> {
+        let mut words = value.to_u32_digits();
+        words.resize(NUM_WORDS_PER_FE, 0);
+        words
+    }
+
+    fn syscall_event(syscall_code: SyscallCode, clk: u32, arg1: u32, arg2: u32) -> SyscallEvent {
+        SyscallEvent {
+            shard: SHARD,
+            clk,
+            lookup_id: LookupId::default(),
+            syscall_id: syscall_code.syscall_id(),
+            arg1,
+            arg2,
+            nonce: 0,
+        }
+    }
+
+    #[test]
+    fn test_bn254_scalar_mul_add_event_to_row() {
+        let mut rng = rand::thread_rng();
+        let modulus = Bn254ScalarField::modulus();
+        let chip = Bn254ScalarMulAddChip::new();
+
+        for _ in 0..16 {
+            let clk = rng.gen_range(1 << 10..1 << 20);
+            let (x_ptr, y_ptr, a_ptr, b_ptr) = (0x1000, 0x2000, 0x3000, 0x4000);
+            let x = rng.gen_biguint_below(&modulus);
+            let a = rng.gen_biguint_below(&modulus);
+            let b = rng.gen_biguint_below(&modulus);
+            let result = (&a * &b + &x) % &modulus;
+
+            let event = Bn254FieldArithEvent {
+                lookup_id: LookupId::default(),
+                shard: SHARD,
+                clk,
+                op: Bn254FieldOperation::MulAdd,
+                arg1: FieldArithMemoryAccess {
+                    ptr: x_ptr,
+                    memory_records: words(&result)
+                        .into_iter()
+                        .zip(words(&x))
+                        .map(|(value, prev_value)| {
+                            write_record(&mut rng, value, prev_value, clk + 1)
+                        })
+                        .collect(),
+                },
+                arg2: FieldArithMemoryAccess {
+                    ptr: y_ptr,
+                    memory_records: vec![
+                        read_record(&mut rng, a_ptr, clk),
+                        read_record(&mut rng, b_ptr, clk),
+                    ],
+                },
+                a: Some(FieldArithMemoryAccess {
+                    ptr: a_ptr,
+                    memory_records: words(&a)
+                        .into_iter()
+                        .map(|value| read_record(&mut rng, value, clk))
+                        .collect(),
+                }),
+                b: Some(FieldArithMemoryAccess {
+                    ptr: b_ptr,
+                    memory_records: words(&b)
+                        .into_iter()
+                        .map(|value| read_record(&mut rng, value, clk))
+                        .collect(),
+                }),
+                local_mem_access: Vec::new(),
+            };
+
+            let mut record = ExecutionRecord::default();
+            let syscall_code = SyscallCode::BN254_SCALAR_MULADD;
+            record.precompile_events.add_event(
+                syscall_code,
+                syscall_event(syscall_code, clk, x_ptr, y_ptr),
+                PrecompileEvent::Bn254ScalarMulAdd(event.clone()),
+            );
+            let trace: RowMajorMatrix<BabyBear> =
+                chip.generate_trace(&record, &mut ExecutionRecord::default());
+
+            let mut row = vec![BabyBear::zero(); BaseAir::<BabyBear>::width(&chip)];
+            bn254_scalar_mul_add_event_to_row(&event, row.as_mut_slice().borrow_mut());
+            assert_eq!(row, trace.row_slice(0).to_vec());
+        }
+    }
+
+    #[test]
+    fn test_bn254_mul_add_event_to_row() {
+        let mut rng = rand::thread_rng();
+        let modulus = Bn254ScalarField::modulus();
+        let chip = Bn254MulAddChip::new();
+
+        for big_endian in [false, true] {
+            for _ in 0..16 {
+                let clk = rng.gen_range(1 << 10..1 << 20);
+                let (x_ptr, y_ptr) = (0x1000, 0x2000);
+                let x = rng.gen_biguint_below(&modulus);
+                let a = rng.gen_biguint_below(&modulus);
+                let b = rng.gen_biguint_below(&modulus);
+                let result = (&a * &b + &x) % &modulus;
+
+                // The words of the operands in memory.
+                let to_memory = |value: &BigUint| {
+                    if big_endian {
+                        bn254_swap_endianness(&words(value))
+                    } else {
+                        words(value)
+                    }
+                };
+
+                let mut record = ExecutionRecord::default();
+                let x_memory_records = record.precompile_events.write_arena.alloc(
+                    to_memory(&result)
+                        .into_iter()
+                        .zip(to_memory(&x))
+                        .map(|(value, prev_value)| {
+                            write_record(&mut rng, value, prev_value, clk + 1)
+                        })
+                        .collect::<Vec<_>>(),
+                );
+                let a_memory_records = record.precompile_events.read_arena.alloc(
+                    to_memory(&a)
+                        .into_iter()
+                        .map(|value| read_record(&mut rng, value, clk))
+                        .collect::<Vec<_>>(),
+                );
+                let b_memory_records = record.precompile_events.read_arena.alloc(
+                    to_memory(&b)
+                        .into_iter()
+                        .map(|value| read_record(&mut rng, value, clk))
+                        .collect::<Vec<_>>(),
+                );
+                let event = Bn254MulAddEvent {
+                    lookup_id: LookupId::default(),
+                    shard: SHARD,
+                    clk,
+                    big_endian,
+                    x_ptr,
+                    x: to_memory(&x),
+                    y_ptr,
+                    a: to_memory(&a),
+                    b: to_memory(&b),
+                    x_memory_records,
+                    a_memory_records,
+                    b_memory_records,
+                    local_mem_access: Vec::new(),
+                };
+
+                let syscall_code = SyscallCode::BN254_MULADD;
+                record.precompile_events.add_event(
+                    syscall_code,
+                    syscall_event(syscall_code, clk, x_ptr, y_ptr),
+                    PrecompileEvent::Bn254MulAdd(event.clone()),
+                );
+                let trace: RowMajorMatrix<BabyBear> =
+                    chip.generate_trace(&record, &mut ExecutionRecord::default());
+
+                let mut row = vec![BabyBear::zero(); BaseAir::<BabyBear>::width(&chip)];
+                bn254_mul_add_event_to_row(
+                    &event,
+                    &record.precompile_events,
+                    row.as_mut_slice().borrow_mut(),
+                );
+                assert_eq!(row, trace.row_slice(0).to_vec());
+            }
+        }
+    }
+
+    fn test_memcopy_event_to_row<NumWords: ArrayLength + Send + Sync>() {
+        let mut rng = rand::thread_rng();
+        let chip = MemCopyChip::<NumWords>::new();
+        let syscall_code = MemCopyChip::<NumWords>::syscall_code();
+
+        // Only the events of the larger chip can be coalesced from two calls.
+        let can_coalesce = MemCopyChip::<NumWords>::half_syscall_code().is_some();
+        for is_coalesced in
+            [false, true].into_iter().filter(|&is_coalesced| can_coalesce || !is_coalesced)
+        {
+            for _ in 0..16 {
+                let clk = rng.gen_range(1 << 10..1 << 20);
+                let upper_clk = if is_coalesced { clk + 4 } else { clk };
+                let (src_ptr, dst_ptr) = (0x1000, 0x2000);
+                let values = (0..NumWords::USIZE).map(|_| rng.gen()).collect::<Vec<u32>>();
+                let timestamp = |i: usize| if i < NumWords::USIZE / 2 { clk } else { upper_clk };
+
+                let mut record = ExecutionRecord::default();
+                let read_records = record.precompile_events.read_arena.alloc(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &value)| read_record(&mut rng, value, timestamp(i)))
+                        .collect::<Vec<_>>(),
+                );
+                let write_records = record.precompile_events.write_arena.alloc(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &value)| {
+                            let prev_value = rng.gen();
+                            write_record(&mut rng, value, prev_value, timestamp(i) + 1)
+                        })
+                        .collect::<Vec<_>>(),
+                );
+                let event = MemCopyEvent {
+                    lookup_id: LookupId::default(),
+                    shard: SHARD,
+                    clk,
+                    src_ptr,
+                    dst_ptr,
+                    read_records,
+                    write_records,
+                    coalesced_syscall: is_coalesced.then(|| {
+                        syscall_event(SyscallCode::MEMCPY_32, upper_clk, src_ptr + 32, dst_ptr + 32)
+                    }),
+                    local_mem_access: Vec::new(),
+                };
+
+                let precompile_event = match NumWords::USIZE {
+                    8 => PrecompileEvent::MemCopy32(event.clone()),
+                    16 => PrecompileEvent::MemCopy64(event.clone()),
+                    _ => unreachable!(),
+                };
+                record.precompile_events.add_event(
+                    syscall_code,
+                    syscall_event(syscall_code, clk, src_ptr, dst_ptr),
+                    precompile_event,
+                );
+                let trace: RowMajorMatrix<BabyBear> =
+                    chip.generate_trace(&record, &mut ExecutionRecord::default());
+
+                let mut row = vec![BabyBear::zero(); num_memcopy_cols::<NumWords>()];
+                memcopy_event_to_row(
+                    &event,
+                    &record.precompile_events,
+                    row.as_mut_slice().borrow_mut(),
+                );
+                assert_eq!(row, trace.row_slice(0).to_vec());
+            }
+        }
+    }
+
+    #[test]
+    fn test_memcopy32_event_to_row() {
+        test_memcopy_event_to_row::<U8>();
+    }
+
+    #[test]
+    fn test_memcopy64_event_to_row() {
+        test_memcopy_event_to_row::<U16>();
+    }
+}
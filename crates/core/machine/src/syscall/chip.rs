@@ -7,7 +7,10 @@ use std::{
 use p3_air::{Air, BaseAir};
 use p3_field::PrimeField32;
 use p3_matrix::{dense::RowMajorMatrix, Matrix};
-use sp1_core_executor::{events::SyscallEvent, ExecutionRecord, Program};
+use sp1_core_executor::{
+    events::{PrecompileEvent, SyscallEvent},
+    ExecutionRecord, Program,
+};
 use sp1_derive::AlignedBorrow;
 use sp1_stark::air::{InteractionScope, MachineAir, SP1AirBuilder};
 
@@ -107,9 +110,16 @@ impl<F: PrimeField32> MachineAir<F> for SyscallChip {
                 }
             }
             SyscallShardKind::Precompile => {
-                for event in input.precompile_events.all_events().map(|(event, _)| event) {
+                for (event, precompile_event) in input.precompile_events.all_events() {
                     let row = row_fn(event);
                     rows.push(row);
+
+                    // A coalesced memory copy event also carries the second call.
+                    if let PrecompileEvent::MemCopy64(copy) = precompile_event {
+                        if let Some(coalesced_syscall) = &copy.coalesced_syscall {
+                            rows.push(row_fn(coalesced_syscall));
+                        }
+                    }
                 }
             }
         };
@@ -130,9 +140,10 @@ impl<F: PrimeField32> MachineAir<F> for SyscallChip {
         } else {
             match self.shard_kind {
                 SyscallShardKind::Core => !shard.syscall_events.is_empty(),
+                // The precompile events kept in a CPU shard by the deferral policy are received
+                // in the shard itself.
                 SyscallShardKind::Precompile => {
-                    !shard.precompile_events.is_empty()
-                        && shard.cpu_events.is_empty()
+                    shard.precompile_events.all_events().next().is_some()
                         && shard.global_memory_initialize_events.is_empty()
                         && shard.global_memory_finalize_events.is_empty()
                 }
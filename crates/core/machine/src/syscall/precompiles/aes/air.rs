@@ -0,0 +1,397 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        aes_shift_rows_index, aes_xtime, ByteLookupEvent, ByteRecord, PrecompileEvent, AES_SBOX,
+        AES_STATE_BYTES, AES_STATE_WORDS,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::words_to_bytes_le;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the AesRoundCols.
+const NUM_COLS: usize = size_of::<AesRoundCols<u8>>();
+
+/// The number of XORs in the `MixColumns` step of a single output byte.
+const NUM_MIX_XORS: usize = 4;
+
+/// A chip applying an AES encryption round to a state.
+///
+/// `SubBytes` and the multiplication by `x` in `MixColumns` are byte lookups, `ShiftRows` is a
+/// fixed permutation of the columns, and the remaining XORs of `MixColumns` and `AddRoundKey` are
+/// byte lookups as well. The final round of a cipher skips `MixColumns`.
+#[derive(Default)]
+pub struct AesRoundChip;
+
+impl AesRoundChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the AesRound operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct AesRoundCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the state.
+    pub state_ptr: T,
+
+    /// The pointer to the round key.
+    pub round_key_ptr: T,
+
+    /// Whether the round applies `MixColumns`.
+    pub is_mix_columns: T,
+
+    /// Whether the round is the final round, which skips `MixColumns`.
+    pub is_last_round: T,
+
+    // Memory columns.
+    // state_memory is written to with the new state, which is why it is of type MemoryWriteCols.
+    pub state_memory: [MemoryWriteCols<T>; AES_STATE_WORDS],
+    pub round_key_memory: [MemoryReadCols<T>; AES_STATE_WORDS],
+
+    /// The state after `SubBytes`, in the byte order before `ShiftRows`.
+    pub sub_bytes: [T; AES_STATE_BYTES],
+
+    /// The most significant bits of the substituted bytes.
+    pub sub_bytes_msb: [T; AES_STATE_BYTES],
+
+    /// The substituted bytes multiplied by `x` in GF(2^8).
+    pub xtime: [T; AES_STATE_BYTES],
+
+    /// The running XORs of `MixColumns` for each output byte, the last of which is the mixed byte.
+    pub mix_columns: [[T; NUM_MIX_XORS]; AES_STATE_BYTES],
+
+    pub is_real: T,
+}
+
+/// The index in `sub_bytes` of the `r`-th input to `MixColumns` for the output byte `i`.
+const fn mix_columns_input(i: usize, r: usize) -> usize {
+    aes_shift_rows_index(i - i % 4 + (i + r) % 4)
+}
+
+impl<F: PrimeField32> MachineAir<F> for AesRoundChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "AesRound".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Generate the trace rows & corresponding records for each chunk of events concurrently.
+        let rows_and_records = input
+            .get_precompile_events(SyscallCode::AES_ENC_ROUND)
+            .chunks(1)
+            .map(|events| {
+                let mut records = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .map(|(_, event)| {
+                        let event = if let PrecompileEvent::AesRound(event) = event {
+                            event
+                        } else {
+                            unreachable!()
+                        };
+                        let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                        let cols: &mut AesRoundCols<F> = row.as_mut_slice().borrow_mut();
+                        let shard = event.shard;
+
+                        // Assign basic values to the columns.
+                        cols.is_real = F::one();
+                        cols.is_mix_columns = F::from_bool(event.mix_columns);
+                        cols.is_last_round = F::from_bool(!event.mix_columns);
+                        cols.shard = F::from_canonical_u32(event.shard);
+                        cols.clk = F::from_canonical_u32(event.clk);
+                        cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                        cols.round_key_ptr = F::from_canonical_u32(event.round_key_ptr);
+
+                        // Populate memory columns.
+                        for i in 0..AES_STATE_WORDS {
+                            cols.state_memory[i].populate(
+                                event.state_memory_records[i],
+                                &mut new_byte_lookup_events,
+                            );
+                            cols.round_key_memory[i].populate(
+                                event.round_key_memory_records[i],
+                                &mut new_byte_lookup_events,
+                            );
+                        }
+
+                        let state = words_to_bytes_le::<AES_STATE_BYTES>(&event.state);
+                        let round_key = words_to_bytes_le::<AES_STATE_BYTES>(&event.round_key);
+
+                        // SubBytes and the multiplication by x.
+                        let mut sub_bytes = [0u8; AES_STATE_BYTES];
+                        let mut xtime = [0u8; AES_STATE_BYTES];
+                        for i in 0..AES_STATE_BYTES {
+                            sub_bytes[i] = AES_SBOX[state[i] as usize];
+                            xtime[i] = aes_xtime(sub_bytes[i]);
+                            let msb = sub_bytes[i] >> 7;
+                            cols.sub_bytes[i] = F::from_canonical_u8(sub_bytes[i]);
+                            cols.sub_bytes_msb[i] = F::from_canonical_u8(msb);
+                            cols.xtime[i] = F::from_canonical_u8(xtime[i]);
+
+                            new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                                shard,
+                                ByteOpcode::AesSbox,
+                                sub_bytes[i] as u16,
+                                0,
+                                state[i],
+                                0,
+                            ));
+                            new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                                shard,
+                                ByteOpcode::MSB,
+                                msb as u16,
+                                0,
+                                sub_bytes[i],
+                                0,
+                            ));
+                            new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                                shard,
+                                ByteOpcode::XOR,
+                                xtime[i] as u16,
+                                0,
+                                sub_bytes[i] << 1,
+                                msb * 0x1b,
+                            ));
+                        }
+
+                        // ShiftRows, MixColumns and AddRoundKey.
+                        for i in 0..AES_STATE_BYTES {
+                            let a = |r: usize| sub_bytes[mix_columns_input(i, r)];
+                            let x = |r: usize| xtime[mix_columns_input(i, r)];
+                            let terms = [x(1), a(1), a(2), a(3)];
+
+                            let mut acc = x(0);
+                            for (j, term) in terms.into_iter().enumerate() {
+                                let xor = acc ^ term;
+                                new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                                    shard,
+                                    ByteOpcode::XOR,
+                                    xor as u16,
+                                    0,
+                                    acc,
+                                    term,
+                                ));
+                                cols.mix_columns[i][j] = F::from_canonical_u8(xor);
+                                acc = xor;
+                            }
+
+                            let mixed = if event.mix_columns { acc } else { a(0) };
+                            new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                                shard,
+                                ByteOpcode::XOR,
+                                (mixed ^ round_key[i]) as u16,
+                                0,
+                                mixed,
+                                round_key[i],
+                            ));
+                        }
+
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                records.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, records)
+            })
+            .collect::<Vec<_>>();
+
+        //  Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for (row, mut record) in rows_and_records {
+            rows.extend(row);
+            output.append(&mut record);
+        }
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut AesRoundCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        // The last round events are coalesced to the round syscall code, only check for that.
+        assert!(shard.get_precompile_events(SyscallCode::AES_ENC_LAST_ROUND).is_empty());
+
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::AES_ENC_ROUND).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for AesRoundChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for AesRoundChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &AesRoundCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &AesRoundCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Check that the round flags are boolean and that exactly one is set on real rows.
+        builder.assert_bool(local.is_mix_columns);
+        builder.assert_bool(local.is_last_round);
+        builder.assert_eq(local.is_mix_columns + local.is_last_round, local.is_real);
+
+        // The state is stored in the "prev_value" of the state_memory, since we write to it later.
+        let state =
+            local.state_memory.iter().flat_map(|access| access.prev_value().0).collect::<Vec<_>>();
+        let new_state = value_as_limbs(&local.state_memory);
+        let round_key = value_as_limbs(&local.round_key_memory);
+
+        // SubBytes and the multiplication by x.
+        for i in 0..AES_STATE_BYTES {
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::AesSbox as u32),
+                local.sub_bytes[i],
+                state[i],
+                AB::Expr::zero(),
+                local.is_real,
+            );
+
+            // Doubling a byte moves its most significant bit out of the byte, which is reduced by
+            // the AES polynomial.
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::MSB as u32),
+                local.sub_bytes_msb[i],
+                local.sub_bytes[i],
+                AB::Expr::zero(),
+                local.is_real,
+            );
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                local.xtime[i],
+                local.sub_bytes[i] * AB::F::from_canonical_u32(2)
+                    - local.sub_bytes_msb[i] * AB::F::from_canonical_u32(256),
+                local.sub_bytes_msb[i] * AB::F::from_canonical_u32(0x1b),
+                local.is_real,
+            );
+        }
+
+        // ShiftRows, MixColumns and AddRoundKey.
+        for i in 0..AES_STATE_BYTES {
+            let a = |r: usize| local.sub_bytes[mix_columns_input(i, r)];
+            let x = |r: usize| local.xtime[mix_columns_input(i, r)];
+            let terms = [x(1), a(1), a(2), a(3)];
+
+            let mut acc = x(0);
+            for (j, term) in terms.into_iter().enumerate() {
+                builder.send_byte(
+                    AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                    local.mix_columns[i][j],
+                    acc,
+                    term,
+                    local.is_real,
+                );
+                acc = local.mix_columns[i][j];
+            }
+
+            // The final round skips MixColumns.
+            let mixed = local.is_mix_columns * acc + local.is_last_round * a(0);
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                new_state[i],
+                mixed,
+                round_key[i],
+                local.is_real,
+            );
+        }
+
+        // Read and write the state.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.state_ptr,
+            &local.state_memory,
+            local.is_real,
+        );
+
+        // Read the round key.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.round_key_ptr,
+            &local.round_key_memory,
+            local.is_real,
+        );
+
+        // Select the correct syscall id based on the round flags.
+        let syscall_id_felt = local.is_mix_columns
+            * AB::F::from_canonical_u32(SyscallCode::AES_ENC_ROUND.syscall_id())
+            + local.is_last_round
+                * AB::F::from_canonical_u32(SyscallCode::AES_ENC_LAST_ROUND.syscall_id());
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.state_ptr,
+            local.round_key_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+    operations::field::{field_op::FieldOpCols, range::FieldLtCols},
+    utils::{limbs_from_access, limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le},
+};
+
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the BarrettReduceCols.
+const NUM_COLS: usize = size_of::<BarrettReduceCols<u8>>();
+
+/// A chip reducing a 512-bit value modulo a runtime-provided 256-bit modulus.
+///
+/// Writing `x = hi * 2^256 + lo`, the chip computes `k = 2^256 mod m` and then
+/// `r = (hi * k + lo) mod m`. The quotients of both steps are the carries of the field operations,
+/// so only `r < m` has to be checked on top of them.
+#[derive(Default)]
+pub struct BarrettReduceChip;
+
+impl BarrettReduceChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A set of columns for the BarrettReduce operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct BarrettReduceCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the reduced value.
+    pub x_ptr: T,
+
+    /// The pointer to the modulus.
+    pub modulus_ptr: T,
+
+    // Memory columns.
+    // x_memory is written to with the result, which is why it is of type MemoryWriteCols.
+    pub x_memory: [MemoryWriteCols<T>; WORDS_FIELD_ELEMENT * 2],
+    pub modulus_memory: [MemoryReadCols<T>; WORDS_FIELD_ELEMENT],
+
+    // Intermediate values. We compute (2^128 * 2^128) % modulus.
+    pub two_256_reduced: FieldOpCols<T, U256Field>,
+
+    // Output values. We compute (hi * two_256_reduced + lo) % modulus.
+    pub output: FieldOpCols<T, U256Field>,
+
+    pub output_range_check: FieldLtCols<T, U256Field>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for BarrettReduceChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "BarrettReduce".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Generate the trace rows & corresponding records for each chunk of events concurrently.
+        let rows_and_records = input
+            .get_precompile_events(SyscallCode::BARRETT_REDUCE)
+            .chunks(1)
+            .map(|events| {
+                let mut records = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .map(|(_, event)| {
+                        let event = if let PrecompileEvent::BarrettReduce(event) = event {
+                            event
+                        } else {
+                            unreachable!()
+                        };
+                        let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                        let cols: &mut BarrettReduceCols<F> = row.as_mut_slice().borrow_mut();
+
+                        // Split x into its low and high halves.
+                        let lo = BigUint::from_bytes_le(&words_to_bytes_le::<32>(
+                            &event.x[..WORDS_FIELD_ELEMENT],
+                        ));
+                        let hi = BigUint::from_bytes_le(&words_to_bytes_le::<32>(
+                            &event.x[WORDS_FIELD_ELEMENT..],
+                        ));
+                        let modulus =
+                            BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.modulus));
+
+                        // Assign basic values to the columns.
+                        cols.is_real = F::one();
+                        cols.shard = F::from_canonical_u32(event.shard);
+                        cols.clk = F::from_canonical_u32(event.clk);
+                        cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                        cols.modulus_ptr = F::from_canonical_u32(event.modulus_ptr);
+
+                        // Populate memory columns.
+                        for i in 0..WORDS_FIELD_ELEMENT * 2 {
+                            cols.x_memory[i]
+                                .populate(event.x_memory_records[i], &mut new_byte_lookup_events);
+                        }
+                        for i in 0..WORDS_FIELD_ELEMENT {
+                            cols.modulus_memory[i].populate(
+                                event.modulus_memory_records[i],
+                                &mut new_byte_lookup_events,
+                            );
+                        }
+
+                        let two_128 = BigUint::one() << 128;
+                        let two_256_reduced = cols.two_256_reduced.populate_with_modulus(
+                            &mut new_byte_lookup_events,
+                            event.shard,
+                            &two_128,
+                            &two_128,
+                            &modulus,
+                            FieldOperation::Mul,
+                        );
+
+                        let (result, _) = cols.output.populate_mul_and_carry(
+                            &mut new_byte_lookup_events,
+                            event.shard,
+                            &hi,
+                            &two_256_reduced,
+                            &lo,
+                            &modulus,
+                        );
+
+                        cols.output_range_check.populate(
+                            &mut new_byte_lookup_events,
+                            event.shard,
+                            &result,
+                            &modulus,
+                        );
+
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                records.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, records)
+            })
+            .collect::<Vec<_>>();
+
+        //  Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for (row, mut record) in rows_and_records {
+            rows.extend(row);
+            output.append(&mut record);
+        }
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut BarrettReduceCols<F> = row.as_mut_slice().borrow_mut();
+
+                let zero = BigUint::zero();
+                cols.two_256_reduced.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+                cols.output.populate_mul_and_carry(
+                    &mut vec![],
+                    0,
+                    &zero,
+                    &zero,
+                    &zero,
+                    &U256Field::modulus(),
+                );
+
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut BarrettReduceCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::BARRETT_REDUCE).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for BarrettReduceChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for BarrettReduceChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &BarrettReduceCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &BarrettReduceCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The value of x is stored in the "prev_value" of the x_memory, since we write to it later.
+        let lo_limbs = limbs_from_prev_access::<AB::Var, <U256Field as NumLimbs>::Limbs, _>(
+            &local.x_memory[..WORDS_FIELD_ELEMENT],
+        );
+        let hi_limbs = limbs_from_prev_access::<AB::Var, <U256Field as NumLimbs>::Limbs, _>(
+            &local.x_memory[WORDS_FIELD_ELEMENT..],
+        );
+        let modulus_limbs =
+            limbs_from_access::<AB::Var, <U256Field as NumLimbs>::Limbs, _>(&local.modulus_memory);
+
+        // The constant 2^128 is multiplied by is_real so that padding rows can be all zero.
+        let two_128_limbs = U256Field::to_limbs_field::<AB::F, AB::F>(&(BigUint::one() << 128));
+        let two_128 = Polynomial::from_coefficients(
+            &two_128_limbs.0.iter().map(|&limb| local.is_real * limb).collect::<Vec<_>>(),
+        );
+
+        // Compute 2^256 mod modulus, which is congruent to the weight of the high half of x.
+        local.two_256_reduced.eval_with_modulus(
+            builder,
+            &two_128,
+            &two_128,
+            &modulus_limbs,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+
+        // Compute (hi * (2^256 mod modulus) + lo) mod modulus, which is congruent to x.
+        local.output.eval_mul_and_carry(
+            builder,
+            &hi_limbs,
+            &local.two_256_reduced.result,
+            &lo_limbs,
+            &modulus_limbs,
+            local.is_real,
+        );
+
+        // Verify that the output is fully reduced.
+        local.output_range_check.eval(builder, &local.output.result, &modulus_limbs, local.is_real);
+
+        // Assert that the result is written to the low half of x and the high half is cleared.
+        builder.when(local.is_real).assert_all_eq(
+            local.output.result,
+            value_as_limbs(&local.x_memory[..WORDS_FIELD_ELEMENT]),
+        );
+        for limb in value_as_limbs(&local.x_memory[WORDS_FIELD_ELEMENT..]) {
+            builder.when(local.is_real).assert_zero(limb);
+        }
+
+        // Read and write x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Evaluate the modulus memory.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.modulus_ptr,
+            &local.modulus_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::BARRETT_REDUCE.syscall_id()),
+            local.x_ptr,
+            local.modulus_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
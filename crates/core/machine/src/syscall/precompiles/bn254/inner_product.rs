@@ -0,0 +1,372 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+    utils::{limbs_from_access, limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le},
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation, PrecompileEvent, BN254_INNER_PRODUCT_MAX_LEN},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Bn254InnerProductCols.
+const NUM_COLS: usize = size_of::<Bn254InnerProductCols<u8>>();
+
+/// The number of bytes taken by a single `(a, b)` pair.
+const PAIR_BYTES: u32 = 2 * WORDS_FIELD_ELEMENT as u32 * 4;
+
+#[derive(Default)]
+pub struct Bn254InnerProductChip;
+
+impl Bn254InnerProductChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A set of columns for the Bn254InnerProduct operation.
+///
+/// Every event takes `BN254_INNER_PRODUCT_MAX_LEN` rows. The row with index `i` adds the product
+/// of the `i`-th pair to the accumulator if `i` is less than the length of the array.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Bn254InnerProductCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the x value.
+    pub x_ptr: T,
+
+    /// The pointer to the length of the array, which is followed by the pairs.
+    pub pairs_ptr: T,
+
+    /// The number of pairs of the array.
+    pub len: T,
+
+    /// The index of the row within the event.
+    pub index: T,
+
+    /// Whether this is the first row of the event.
+    pub is_first: T,
+
+    /// Whether this is the last row of the event.
+    pub is_last: T,
+
+    /// Whether the row adds a pair, which holds for the first `len` rows of the event.
+    pub is_pair: T,
+
+    /// The number of pairs added up to and including this row.
+    pub num_pairs: T,
+
+    // Memory columns.
+    // The length is read on the first row, and x is written on the last row.
+    pub len_memory: MemoryReadCols<T>,
+    pub a_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub b_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+
+    /// The original x value, constant across the event.
+    pub x: Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>,
+
+    /// The accumulator before the pair of this row is added.
+    pub acc: Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>,
+
+    a_mul_b: FieldOpCols<T, Bn254ScalarField>, // a * b
+    acc_add: FieldOpCols<T, Bn254ScalarField>, // acc + (a * b)
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Bn254InnerProductChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Bn254InnerProduct".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::BN254_INNER_PRODUCT) {
+            let event = if let PrecompileEvent::Bn254InnerProduct(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let len = event.a.len();
+            let x = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
+            let mut acc = x.clone();
+
+            for i in 0..BN254_INNER_PRODUCT_MAX_LEN {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Bn254InnerProductCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.pairs_ptr = F::from_canonical_u32(event.pairs_ptr);
+                cols.len = F::from_canonical_usize(len);
+                cols.index = F::from_canonical_usize(i);
+                cols.is_first = F::from_bool(i == 0);
+                cols.is_last = F::from_bool(i == BN254_INNER_PRODUCT_MAX_LEN - 1);
+                cols.is_pair = F::from_bool(i < len);
+                cols.num_pairs = F::from_canonical_usize((i + 1).min(len));
+                cols.x = Bn254ScalarField::to_limbs_field::<F, _>(&x);
+                cols.acc = Bn254ScalarField::to_limbs_field::<F, _>(&acc);
+
+                if i == 0 {
+                    cols.len_memory.populate(event.len_memory_record, &mut new_byte_lookup_events);
+                }
+
+                // Rows past the end of the array multiply zeros, leaving the accumulator as is.
+                let (a, b) = if i < len {
+                    for j in 0..WORDS_FIELD_ELEMENT {
+                        cols.a_memory[j]
+                            .populate(event.a_memory_records[i][j], &mut new_byte_lookup_events);
+                        cols.b_memory[j]
+                            .populate(event.b_memory_records[i][j], &mut new_byte_lookup_events);
+                    }
+                    (
+                        BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.a[i])),
+                        BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.b[i])),
+                    )
+                } else {
+                    (BigUint::zero(), BigUint::zero())
+                };
+
+                let mul_result = cols.a_mul_b.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &a,
+                    &b,
+                    FieldOperation::Mul,
+                );
+                acc = cols.acc_add.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &acc,
+                    &mul_result,
+                    FieldOperation::Add,
+                );
+
+                if i == BN254_INNER_PRODUCT_MAX_LEN - 1 {
+                    for j in 0..WORDS_FIELD_ELEMENT {
+                        cols.x_memory[j]
+                            .populate(event.x_memory_records[j], &mut new_byte_lookup_events);
+                    }
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Bn254InnerProductCols<F> = row.as_mut_slice().borrow_mut();
+
+                let zero = BigUint::zero();
+                cols.a_mul_b.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+                cols.acc_add.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Add);
+
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Bn254InnerProductCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::BN254_INNER_PRODUCT).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Bn254InnerProductChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Bn254InnerProductChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Bn254InnerProductCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Bn254InnerProductCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans, and only set on real rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.assert_bool(local.is_pair);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+        builder.when(local.is_pair).assert_one(local.is_real);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(local.is_first);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+        builder.when_transition().when(local.is_last).when(next.is_real).assert_one(next.is_first);
+
+        // Every event has the same number of rows.
+        builder.when(local.is_first).assert_zero(local.index);
+        builder.when(local.is_last).assert_eq(
+            local.index,
+            AB::Expr::from_canonical_usize(BN254_INNER_PRODUCT_MAX_LEN - 1),
+        );
+
+        // The first row reads the length, which is at least one.
+        builder.when(local.is_first).assert_one(local.is_pair);
+        builder.when(local.is_first).assert_eq(local.len, local.len_memory.value().reduce::<AB>());
+        builder.when(local.is_first).assert_one(local.num_pairs);
+        builder.when(local.is_first).assert_all_eq(local.acc, local.x);
+        builder.eval_memory_access(
+            local.shard,
+            local.clk,
+            local.pairs_ptr,
+            &local.len_memory,
+            local.is_first,
+        );
+
+        // The rows of an event share the arguments, and carry the accumulator forward.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - local.is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_zero(next.is_first);
+        continuation.assert_eq(local.index + AB::Expr::one(), next.index);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.x_ptr, next.x_ptr);
+        continuation.assert_eq(local.pairs_ptr, next.pairs_ptr);
+        continuation.assert_eq(local.len, next.len);
+        continuation.assert_all_eq(local.x, next.x);
+        continuation.assert_all_eq(local.acc_add.result, next.acc);
+        // Once a row has no pair, neither do the following rows of the event.
+        continuation.when(next.is_pair).assert_one(local.is_pair);
+        continuation.assert_eq(local.num_pairs + next.is_pair, next.num_pairs);
+
+        // The last row has added all the pairs.
+        builder.when(local.is_last).assert_eq(local.num_pairs, local.len);
+
+        // Read the pair of the row, and add its product to the accumulator.
+        let a_limbs: Limbs<AB::Var, <Bn254ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.a_memory);
+        let b_limbs: Limbs<AB::Var, <Bn254ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.b_memory);
+        let a_ptr = local.pairs_ptr
+            + AB::Expr::from_canonical_u32(4)
+            + local.index * AB::Expr::from_canonical_u32(PAIR_BYTES);
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            a_ptr,
+            &[local.a_memory, local.b_memory].concat(),
+            local.is_pair,
+        );
+
+        local.a_mul_b.eval(builder, &a_limbs, &b_limbs, FieldOperation::Mul, local.is_real);
+        local.acc_add.eval(
+            builder,
+            &local.acc,
+            &local.a_mul_b.result,
+            FieldOperation::Add,
+            local.is_real,
+        );
+
+        // Rows without a pair add nothing.
+        for limb in local.a_mul_b.result.0 {
+            builder.when(local.is_real).when_not(local.is_pair).assert_zero(limb);
+        }
+
+        // Write the result to x on the last row. The value of x is stored in the "prev_value" of
+        // the x_memory.
+        let x_limbs = limbs_from_prev_access(&local.x_memory);
+        builder.when(local.is_last).assert_all_eq(local.x, x_limbs);
+        builder
+            .when(local.is_last)
+            .assert_all_eq(local.acc_add.result, value_as_limbs(&local.x_memory));
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_last,
+        );
+
+        // Receive the arguments on the first row of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::BN254_INNER_PRODUCT.syscall_id()),
+            local.x_ptr,
+            local.pairs_ptr,
+            local.is_first,
+            InteractionScope::Local,
+        );
+    }
+}
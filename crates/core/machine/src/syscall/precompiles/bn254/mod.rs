@@ -1,21 +1,129 @@
+pub mod inner_product;
+pub mod mont;
 pub mod mul_add_uint256;
+pub mod mul_memo;
+pub mod ntt;
 
 #[cfg(test)]
 mod tests {
 
-    use sp1_core_executor::Program;
-    use sp1_stark::CpuProver;
-    use test_artifacts::UINT256_MULADD_ELF;
+    use std::borrow::Borrow;
 
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use p3_matrix::dense::RowMajorMatrix;
+    use sp1_core_executor::{
+        events::{Bn254MulAddEvent, LookupId, PrecompileEvent, SyscallEvent},
+        syscalls::SyscallCode,
+        ExecutionRecord, Program,
+    };
+    use sp1_stark::{
+        air::MachineAir, baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, StarkGenericConfig,
+    };
+    use test_artifacts::{
+        BN254_INNER_PRODUCT_ELF, BN254_MONT_ELF, FR_NTT_ELF, POSEIDON_ELF, UINT256_MULADD_ELF,
+    };
+
+    use super::mul_memo::{Bn254MulMemoChip, Bn254MulMemoCols};
     use crate::{
         io::SP1Stdin,
-        utils::{self, run_test_io},
+        utils::{self, run_test_io, uni_stark_prove as prove, uni_stark_verify as verify},
     };
 
+    fn generate_test_execution_record(memo: bool) -> ExecutionRecord {
+        let mut execution_record = ExecutionRecord { bn254_mul_memo: memo, ..Default::default() };
+        let operands = [([3, 0, 0, 0, 0, 0, 0, 0], [5, 0, 0, 0, 0, 0, 0, 7]); 2]
+            .into_iter()
+            .chain([([u32::MAX; 8], [2, 0, 0, 0, 0, 0, 0, 0])]);
+        for (clk, (a, b)) in operands.enumerate() {
+            let clk = clk as u32 * 4;
+            let event = PrecompileEvent::Bn254MulAdd(Bn254MulAddEvent {
+                lookup_id: LookupId(clk as u64),
+                clk,
+                a: a.to_vec(),
+                b: b.to_vec(),
+                ..Default::default()
+            });
+            let syscall_event = SyscallEvent {
+                shard: 0,
+                clk,
+                lookup_id: LookupId(clk as u64),
+                syscall_id: SyscallCode::BN254_MULADD as u32,
+                arg1: 0,
+                arg2: 0,
+                nonce: 0,
+            };
+            execution_record.precompile_events.add_event(
+                SyscallCode::BN254_MULADD,
+                syscall_event,
+                event,
+            );
+        }
+        execution_record
+    }
+
+    fn multiplicities(trace: &RowMajorMatrix<BabyBear>) -> Vec<BabyBear> {
+        trace
+            .values
+            .chunks_exact(trace.width)
+            .map(|row| {
+                let cols: &Bn254MulMemoCols<BabyBear> = row.borrow();
+                cols.multiplicity
+            })
+            .filter(|multiplicity| !multiplicity.is_zero())
+            .collect()
+    }
+
+    #[test]
+    fn test_bn254_mul_memo() {
+        let config = BabyBearPoseidon2::new();
+        let chip = Bn254MulMemoChip::new();
+        for (memo, expected) in [(false, vec![1, 1, 1]), (true, vec![2, 1])] {
+            let execution_record = generate_test_execution_record(memo);
+            let trace: RowMajorMatrix<BabyBear> =
+                chip.generate_trace(&execution_record, &mut ExecutionRecord::default());
+            let expected =
+                expected.into_iter().map(BabyBear::from_canonical_u32).collect::<Vec<_>>();
+            assert_eq!(multiplicities(&trace), expected);
+
+            let proof =
+                prove::<BabyBearPoseidon2, _>(&config, &chip, &mut config.challenger(), trace);
+            verify(&config, &chip, &mut config.challenger(), &proof).unwrap();
+        }
+    }
+
     #[test]
     fn test_uint256_muladd() {
         utils::setup_logger();
         let program = Program::from(UINT256_MULADD_ELF).unwrap();
         run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
     }
+
+    #[test]
+    fn test_bn254_mont() {
+        utils::setup_logger();
+        let program = Program::from(BN254_MONT_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_bn254_inner_product() {
+        utils::setup_logger();
+        let program = Program::from(BN254_INNER_PRODUCT_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_fr_ntt() {
+        utils::setup_logger();
+        let program = Program::from(FR_NTT_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_poseidon() {
+        utils::setup_logger();
+        let program = Program::from(POSEIDON_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
 }
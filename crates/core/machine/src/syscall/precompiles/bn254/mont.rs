@@ -0,0 +1,292 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryWriteCols},
+    operations::field::{field_op::FieldOpCols, range::FieldLtCols},
+    utils::{limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le},
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{bn254_scalar_mont_factor, ByteRecord, FieldOperation, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Bn254MontCols.
+const NUM_COLS: usize = size_of::<Bn254MontCols<u8>>();
+
+/// A chip converting BN254 scalar field elements to and from Montgomery form.
+///
+/// Both conversions are a field multiplication by a constant: `R = 2^256 mod r` into Montgomery
+/// form and `R^-1 mod r` out of it.
+#[derive(Default)]
+pub struct Bn254MontChip;
+
+impl Bn254MontChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <Bn254ScalarField as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A set of columns for the Bn254Mont operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Bn254MontCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the converted value.
+    pub x_ptr: T,
+
+    /// Whether the value is converted into Montgomery form.
+    pub is_to_mont: T,
+
+    /// Whether the value is converted out of Montgomery form.
+    pub is_from_mont: T,
+
+    // Memory columns.
+    // x_memory is written to with the result, which is why it is of type MemoryWriteCols.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+
+    // Output values. We compute (x * factor) % modulus.
+    pub output: FieldOpCols<T, Bn254ScalarField>,
+
+    pub output_range_check: FieldLtCols<T, Bn254ScalarField>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Bn254MontChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Bn254Mont".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Generate the trace rows & corresponding records for each chunk of events concurrently.
+        let rows_and_records = input
+            .get_precompile_events(SyscallCode::BN254_SCALAR_TO_MONT)
+            .chunks(1)
+            .map(|events| {
+                let mut records = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .map(|(_, event)| {
+                        let event = if let PrecompileEvent::Bn254Mont(event) = event {
+                            event
+                        } else {
+                            unreachable!()
+                        };
+                        let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                        let cols: &mut Bn254MontCols<F> = row.as_mut_slice().borrow_mut();
+
+                        let x = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
+                        let factor = bn254_scalar_mont_factor(event.to_mont);
+
+                        // Assign basic values to the columns.
+                        cols.is_real = F::one();
+                        cols.is_to_mont = F::from_bool(event.to_mont);
+                        cols.is_from_mont = F::from_bool(!event.to_mont);
+                        cols.shard = F::from_canonical_u32(event.shard);
+                        cols.clk = F::from_canonical_u32(event.clk);
+                        cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+
+                        // Populate memory columns.
+                        for i in 0..WORDS_FIELD_ELEMENT {
+                            cols.x_memory[i]
+                                .populate(event.x_memory_records[i], &mut new_byte_lookup_events);
+                        }
+
+                        let result = cols.output.populate(
+                            &mut new_byte_lookup_events,
+                            event.shard,
+                            &x,
+                            &factor,
+                            FieldOperation::Mul,
+                        );
+
+                        cols.output_range_check.populate(
+                            &mut new_byte_lookup_events,
+                            event.shard,
+                            &result,
+                            &Bn254ScalarField::modulus(),
+                        );
+
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                records.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, records)
+            })
+            .collect::<Vec<_>>();
+
+        //  Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for (row, mut record) in rows_and_records {
+            rows.extend(row);
+            output.append(&mut record);
+        }
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Bn254MontCols<F> = row.as_mut_slice().borrow_mut();
+
+                let zero = BigUint::zero();
+                cols.output.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Bn254MontCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        // The from_mont events are coalesced to the to_mont syscall code, only check for that.
+        assert!(shard.get_precompile_events(SyscallCode::BN254_SCALAR_FROM_MONT).is_empty());
+
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::BN254_SCALAR_TO_MONT).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Bn254MontChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Bn254MontChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <Bn254ScalarField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Bn254MontCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Bn254MontCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Check that the conversion flags are boolean and that exactly one is set on real rows.
+        builder.assert_bool(local.is_to_mont);
+        builder.assert_bool(local.is_from_mont);
+        builder.assert_eq(local.is_to_mont + local.is_from_mont, local.is_real);
+
+        // The value of x is stored in the "prev_value" of the x_memory, since we write to it later.
+        let x_limbs = limbs_from_prev_access(&local.x_memory);
+
+        // Select the constant factor of the conversion.
+        let to_mont_limbs =
+            Bn254ScalarField::to_limbs_field::<AB::F, AB::F>(&bn254_scalar_mont_factor(true));
+        let from_mont_limbs =
+            Bn254ScalarField::to_limbs_field::<AB::F, AB::F>(&bn254_scalar_mont_factor(false));
+        let factor = Polynomial::from_coefficients(
+            &to_mont_limbs
+                .0
+                .iter()
+                .zip(from_mont_limbs.0.iter())
+                .map(|(&to, &from)| local.is_to_mont * to + local.is_from_mont * from)
+                .collect::<Vec<_>>(),
+        );
+
+        local.output.eval(builder, &x_limbs, &factor, FieldOperation::Mul, local.is_real);
+
+        // Verify that the output is canonical.
+        let modulus = Polynomial::from_iter(
+            Bn254ScalarField::modulus_field_iter::<AB::F>().map(AB::Expr::from),
+        );
+        local.output_range_check.eval(builder, &local.output.result, &modulus, local.is_real);
+
+        // Assert that the correct result is being written to x_memory.
+        builder
+            .when(local.is_real)
+            .assert_all_eq(local.output.result, value_as_limbs(&local.x_memory));
+
+        // Read and write x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Select the correct syscall id based on the conversion flags.
+        let syscall_id_felt = local.is_to_mont
+            * AB::F::from_canonical_u32(SyscallCode::BN254_SCALAR_TO_MONT.syscall_id())
+            + local.is_from_mont
+                * AB::F::from_canonical_u32(SyscallCode::BN254_SCALAR_FROM_MONT.syscall_id());
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.x_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
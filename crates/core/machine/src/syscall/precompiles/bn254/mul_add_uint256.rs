@@ -23,14 +23,16 @@ use sp1_core_executor::{
     ExecutionRecord, Program,
 };
 use sp1_curves::{
-    params::{Limbs, NumLimbs, NumWords},
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
     uint256::U256Field,
     weierstrass::bn254::Bn254ScalarField,
 };
 use sp1_derive::AlignedBorrow;
 use sp1_stark::{
-    air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder},
-    MachineRecord,
+    air::{
+        AirInteraction, BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder,
+    },
+    InteractionKind, MachineRecord,
 };
 use std::{
     borrow::{Borrow, BorrowMut},
@@ -53,7 +55,13 @@ impl Bn254MulAddChip {
 type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
 const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
 
+type FieldLimbs<T> = Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>;
+
 /// A set of columns for the Bn254MulAdd operation.
+///
+/// The operands of `BN254_MULADD_BE` are big-endian in memory, so their bytes are read in reverse
+/// order. The reversal only permutes the byte columns of the memory accesses, which are already
+/// constrained to be bytes.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
 pub struct Bn254MulAddCols<T> {
@@ -72,13 +80,22 @@ pub struct Bn254MulAddCols<T> {
     /// The pointer to the second input, which contains the a value and the b value.
     pub y_ptr: T,
 
+    /// Whether the operands and the result are big-endian, for `BN254_MULADD_BE`.
+    pub is_big_endian: T,
+
     // Memory columns.
     // x_memory is written to with the result, which is why it is of type MemoryWriteCols.
     pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
     pub a_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
     pub b_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
 
-    a_mul_b: FieldOpCols<T, Bn254ScalarField>,
+    /// The x, a and b values as little endian limbs.
+    pub x: FieldLimbs<T>,
+    pub a: FieldLimbs<T>,
+    pub b: FieldLimbs<T>,
+
+    /// The product a * b, looked up from the Bn254MulMemo chip.
+    a_mul_b: FieldLimbs<T>,
 
     add_eval: FieldOpCols<T, Bn254ScalarField>, // x += (a * b)
 
@@ -117,10 +134,13 @@ impl<F: PrimeField32> MachineAir<F> for Bn254MulAddChip {
                         let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
                         let cols: &mut Bn254MulAddCols<F> = row.as_mut_slice().borrow_mut();
 
-                        // Decode uint256 points
-                        let x = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.x));
-                        let a = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.a));
-                        let b = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.b));
+                        // Decode uint256 points, in little endian.
+                        let decode = |words: &[u32]| {
+                            BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.to_le(words)))
+                        };
+                        let x = decode(&event.x);
+                        let a = decode(&event.a);
+                        let b = decode(&event.b);
 
                         // Assign basic values to the columns.
                         cols.is_real = F::one();
@@ -128,24 +148,27 @@ impl<F: PrimeField32> MachineAir<F> for Bn254MulAddChip {
                         cols.clk = F::from_canonical_u32(event.clk);
                         cols.x_ptr = F::from_canonical_u32(event.x_ptr);
                         cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+                        cols.is_big_endian = F::from_bool(event.big_endian);
+                        cols.x = Bn254ScalarField::to_limbs_field::<F, _>(&x);
+                        cols.a = Bn254ScalarField::to_limbs_field::<F, _>(&a);
+                        cols.b = Bn254ScalarField::to_limbs_field::<F, _>(&b);
 
                         // Populate memory columns.
+                        let memory = &input.precompile_events;
+                        let x_memory_records = memory.writes(event.x_memory_records);
+                        let a_memory_records = memory.reads(event.a_memory_records);
+                        let b_memory_records = memory.reads(event.b_memory_records);
                         for i in 0..WORDS_FIELD_ELEMENT {
                             cols.x_memory[i]
-                                .populate(event.x_memory_records[i], &mut new_byte_lookup_events);
+                                .populate(x_memory_records[i], &mut new_byte_lookup_events);
                             cols.a_memory[i]
-                                .populate(event.a_memory_records[i], &mut new_byte_lookup_events);
+                                .populate(a_memory_records[i], &mut new_byte_lookup_events);
                             cols.b_memory[i]
-                                .populate(event.b_memory_records[i], &mut new_byte_lookup_events);
+                                .populate(b_memory_records[i], &mut new_byte_lookup_events);
                         }
 
-                        let mul_result = cols.a_mul_b.populate(
-                            &mut new_byte_lookup_events,
-                            event.shard,
-                            &a,
-                            &b,
-                            FieldOperation::Mul,
-                        );
+                        let mul_result = (&a * &b) % Bn254ScalarField::modulus();
+                        cols.a_mul_b = Bn254ScalarField::to_limbs_field::<F, _>(&mul_result);
 
                         cols.add_eval.populate(
                             &mut new_byte_lookup_events,
@@ -177,7 +200,6 @@ impl<F: PrimeField32> MachineAir<F> for Bn254MulAddChip {
                 let cols: &mut Bn254MulAddCols<F> = row.as_mut_slice().borrow_mut();
 
                 let zero = BigUint::zero();
-                cols.a_mul_b.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
                 cols.add_eval.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Add);
 
                 row
@@ -214,6 +236,16 @@ impl<F> BaseAir<F> for Bn254MulAddChip {
     }
 }
 
+/// The little endian limbs of a value whose bytes are in memory order, reversing them if
+/// `is_big_endian` is set.
+fn to_le_limbs<AB: SP1AirBuilder>(bytes: &[AB::Var], is_big_endian: AB::Var) -> Vec<AB::Expr> {
+    bytes
+        .iter()
+        .zip(bytes.iter().rev())
+        .map(|(&le, &be)| le.into() + is_big_endian * (be - le))
+        .collect()
+}
+
 impl<AB> Air<AB> for Bn254MulAddChip
 where
     AB: SP1AirBuilder,
@@ -230,27 +262,43 @@ where
         builder.when_first_row().assert_zero(local.nonce);
         builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
 
-        // We are computing (x * y) % modulus. The value of x is stored in the "prev_value" of
-        // the x_memory, since we write to it later.
-        let x_limbs = limbs_from_prev_access(&local.x_memory);
-        let a_limbs = limbs_from_access(&local.a_memory);
-        let b_limbs = limbs_from_access(&local.b_memory);
+        // Assert that is_big_endian is a boolean, only set on real rows.
+        builder.assert_bool(local.is_big_endian);
+        builder.when(local.is_big_endian).assert_one(local.is_real);
+
+        // We are computing (x + a * b) % modulus. The value of x is stored in the "prev_value" of
+        // the x_memory, since we write to it later. The operands are read in little endian,
+        // reversing the bytes of big-endian operands.
+        let x_limbs: FieldLimbs<AB::Var> = limbs_from_prev_access(&local.x_memory);
+        let a_limbs: FieldLimbs<AB::Var> = limbs_from_access(&local.a_memory);
+        let b_limbs: FieldLimbs<AB::Var> = limbs_from_access(&local.b_memory);
+        builder.assert_all_eq(local.x, to_le_limbs::<AB>(&x_limbs.0, local.is_big_endian));
+        builder.assert_all_eq(local.a, to_le_limbs::<AB>(&a_limbs.0, local.is_big_endian));
+        builder.assert_all_eq(local.b, to_le_limbs::<AB>(&b_limbs.0, local.is_big_endian));
+
+        // Look up the product a * b from the Bn254MulMemo chip.
+        let a_mul_b_values = local
+            .a
+            .0
+            .iter()
+            .chain(local.b.0.iter())
+            .chain(local.a_mul_b.0.iter())
+            .map(|&limb| limb.into())
+            .collect::<Vec<_>>();
+        builder.send(
+            AirInteraction::new(a_mul_b_values, local.is_real.into(), InteractionKind::Field),
+            InteractionScope::Local,
+        );
 
-        local.a_mul_b.eval(builder, &a_limbs, &b_limbs, FieldOperation::Mul, local.is_real);
+        local.add_eval.eval(builder, &local.x, &local.a_mul_b, FieldOperation::Add, local.is_real);
 
-        local.add_eval.eval(
-            builder,
-            &x_limbs,
-            &local.a_mul_b.result,
-            FieldOperation::Add,
-            local.is_real,
+        // Assert that the correct result is being written to x_memory, in the byte order of the
+        // operands.
+        builder.when(local.is_real).assert_all_eq(
+            local.add_eval.result,
+            to_le_limbs::<AB>(&value_as_limbs(&local.x_memory).0, local.is_big_endian),
         );
 
-        // Assert that the correct result is being written to x_memory.
-        builder
-            .when(local.is_real)
-            .assert_all_eq(local.add_eval.result, value_as_limbs(&local.x_memory));
-
         // Read and write x.
         builder.eval_memory_access_slice(
             local.shard,
@@ -270,12 +318,16 @@ where
             local.is_real,
         );
 
-        // Receive the arguments.
+        // Receive the arguments, with the syscall id of the byte order.
+        let syscall_id_felt = (AB::Expr::one() - local.is_big_endian)
+            * AB::F::from_canonical_u32(SyscallCode::BN254_MULADD.syscall_id())
+            + local.is_big_endian
+                * AB::F::from_canonical_u32(SyscallCode::BN254_MULADD_BE.syscall_id());
         builder.receive_syscall(
             local.shard,
             local.clk,
             local.nonce,
-            AB::F::from_canonical_u32(SyscallCode::BN254_MULADD.syscall_id()),
+            syscall_id_felt,
             local.x_ptr,
             local.y_ptr,
             local.is_real,
@@ -0,0 +1,175 @@
+use crate::{
+    operations::field::field_op::FieldOpCols,
+    utils::{pad_rows_fixed, words_to_bytes_le},
+};
+
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{bn254_mul_memo, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{Limbs, NumLimbs},
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{AirInteraction, InteractionScope, MachineAir, SP1AirBuilder},
+    InteractionKind,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the Bn254MulMemoCols.
+const NUM_COLS: usize = size_of::<Bn254MulMemoCols<u8>>();
+
+/// A chip proving the products `a * b` looked up by the [`super::mul_add_uint256::Bn254MulAddChip`].
+///
+/// Each row proves one product and receives it as many times as the multiplicity column says. If
+/// the shard's record has `bn254_mul_memo` set, the mul-add events multiplying the same operands
+/// share a row, otherwise every event gets a row of its own.
+#[derive(Default)]
+pub struct Bn254MulMemoChip;
+
+impl Bn254MulMemoChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Bn254MulMemo operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Bn254MulMemoCols<T> {
+    /// The a value.
+    pub a: Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>,
+
+    /// The b value.
+    pub b: Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>,
+
+    /// The product a * b.
+    pub a_mul_b: FieldOpCols<T, Bn254ScalarField>,
+
+    /// The number of mul-add operations looking up this product.
+    pub multiplicity: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Bn254MulMemoChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Bn254MulMemo".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(SyscallCode::BN254_MULADD);
+        let mut new_byte_lookup_events = Vec::new();
+
+        let mut rows = bn254_mul_memo(events, input.bn254_mul_memo)
+            .into_iter()
+            .map(|entry| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Bn254MulMemoCols<F> = row.as_mut_slice().borrow_mut();
+
+                let a_bytes = words_to_bytes_le::<32>(&entry.a);
+                let b_bytes = words_to_bytes_le::<32>(&entry.b);
+                for i in 0..Bn254ScalarField::NB_LIMBS {
+                    cols.a[i] = F::from_canonical_u8(a_bytes[i]);
+                    cols.b[i] = F::from_canonical_u8(b_bytes[i]);
+                }
+
+                cols.a_mul_b.populate(
+                    &mut new_byte_lookup_events,
+                    entry.shard,
+                    &BigUint::from_bytes_le(&a_bytes),
+                    &BigUint::from_bytes_le(&b_bytes),
+                    FieldOperation::Mul,
+                );
+
+                cols.multiplicity = F::from_canonical_usize(entry.multiplicity);
+                cols.is_real = F::one();
+
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Bn254MulMemoCols<F> = row.as_mut_slice().borrow_mut();
+
+                let zero = BigUint::zero();
+                cols.a_mul_b.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS)
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::BN254_MULADD).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Bn254MulMemoChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Bn254MulMemoChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <Bn254ScalarField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Bn254MulMemoCols<AB::Var> = (*local).borrow();
+
+        local.a_mul_b.eval(builder, &local.a, &local.b, FieldOperation::Mul, local.is_real);
+
+        // Only real rows can be looked up.
+        builder.when_not(local.is_real).assert_zero(local.multiplicity);
+
+        // Receive the product from the mul-add operations.
+        let values = local
+            .a
+            .0
+            .iter()
+            .chain(local.b.0.iter())
+            .chain(local.a_mul_b.result.0.iter())
+            .map(|&limb| limb.into())
+            .collect::<Vec<_>>();
+        builder.receive(
+            AirInteraction::new(values, local.multiplicity.into(), InteractionKind::Field),
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
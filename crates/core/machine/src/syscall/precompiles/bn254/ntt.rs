@@ -0,0 +1,533 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+    utils::{limbs_from_access, limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le},
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation, PrecompileEvent, FR_NTT_MAX_LEN, FR_NTT_STATE_WORDS},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the FrNttCols.
+const NUM_COLS: usize = size_of::<FrNttCols<u8>>();
+
+/// The number of bytes taken by a single `(c, y)` pair.
+const PAIR_BYTES: u32 = 2 * WORDS_FIELD_ELEMENT as u32 * 4;
+
+#[derive(Default)]
+pub struct FrNttChip;
+
+impl FrNttChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+type FrLimbs<T> = Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>;
+
+/// A set of columns for the FrNtt operation.
+///
+/// Every event takes `FR_NTT_MAX_LEN` rows. The row with index `i` folds the `i`-th `(c, y)` pair
+/// into the sums `c * r^k` and `y * w^k / (r - w^k)` and advances the powers `r^k` and `w^k` if `i`
+/// is less than the length of the array.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FrNttCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the state.
+    pub state_ptr: T,
+
+    /// The pointer to the length of the array, which is followed by the pairs.
+    pub pairs_ptr: T,
+
+    /// The number of pairs of the array.
+    pub len: T,
+
+    /// The index of the row within the event.
+    pub index: T,
+
+    /// Whether this is the first row of the event.
+    pub is_first: T,
+
+    /// Whether this is the last row of the event.
+    pub is_last: T,
+
+    /// Whether the row folds a pair, which holds for the first `len` rows of the event.
+    pub is_pair: T,
+
+    /// The number of pairs folded up to and including this row.
+    pub num_pairs: T,
+
+    // Memory columns.
+    // The length is read on the first row, and the state is written on the last row.
+    pub len_memory: MemoryReadCols<T>,
+    pub c_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub y_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub state_memory: [MemoryWriteCols<T>; FR_NTT_STATE_WORDS],
+
+    /// The point `r` and the root of unity `w`, constant across the event.
+    pub r: FrLimbs<T>,
+    pub w: FrLimbs<T>,
+
+    /// The original powers and sums of the state, constant across the event.
+    pub init_r_pow: FrLimbs<T>,
+    pub init_w_pow: FrLimbs<T>,
+    pub init_acc_c: FrLimbs<T>,
+    pub init_acc_y: FrLimbs<T>,
+
+    /// The powers and sums before the pair of this row is folded.
+    pub r_pow: FrLimbs<T>,
+    pub w_pow: FrLimbs<T>,
+    pub acc_c: FrLimbs<T>,
+    pub acc_y: FrLimbs<T>,
+
+    /// The factors advancing the powers: `r` and `w` on rows with a pair, and one otherwise.
+    pub r_step: FrLimbs<T>,
+    pub w_step: FrLimbs<T>,
+
+    c_mul: FieldOpCols<T, Bn254ScalarField>,     // c * r_pow
+    acc_c_add: FieldOpCols<T, Bn254ScalarField>, // acc_c + (c * r_pow)
+    y_mul: FieldOpCols<T, Bn254ScalarField>,     // y * w_pow
+    den_sub: FieldOpCols<T, Bn254ScalarField>,   // r - w_pow
+    quot_div: FieldOpCols<T, Bn254ScalarField>,  // (y * w_pow) / (r - w_pow)
+    acc_y_add: FieldOpCols<T, Bn254ScalarField>, // acc_y + (y * w_pow) / (r - w_pow)
+    r_pow_mul: FieldOpCols<T, Bn254ScalarField>, // r_pow * r_step
+    w_pow_mul: FieldOpCols<T, Bn254ScalarField>, // w_pow * w_step
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for FrNttChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "FrNtt".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::FR_NTT) {
+            let event =
+                if let PrecompileEvent::FrNtt(event) = event { event } else { unreachable!() };
+            let len = event.c.len();
+            let [r, w, init_r_pow, init_w_pow, init_acc_c, init_acc_y] =
+                [0, 1, 2, 3, 4, 5].map(|i| {
+                    BigUint::from_bytes_le(&words_to_bytes_le::<32>(
+                        &event.state[i * WORDS_FIELD_ELEMENT..(i + 1) * WORDS_FIELD_ELEMENT],
+                    ))
+                });
+            let (mut r_pow, mut w_pow) = (init_r_pow.clone(), init_w_pow.clone());
+            let (mut acc_c, mut acc_y) = (init_acc_c.clone(), init_acc_y.clone());
+
+            for i in 0..FR_NTT_MAX_LEN {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut FrNttCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                cols.pairs_ptr = F::from_canonical_u32(event.pairs_ptr);
+                cols.len = F::from_canonical_usize(len);
+                cols.index = F::from_canonical_usize(i);
+                cols.is_first = F::from_bool(i == 0);
+                cols.is_last = F::from_bool(i == FR_NTT_MAX_LEN - 1);
+                cols.is_pair = F::from_bool(i < len);
+                cols.num_pairs = F::from_canonical_usize((i + 1).min(len));
+                cols.r = Bn254ScalarField::to_limbs_field::<F, _>(&r);
+                cols.w = Bn254ScalarField::to_limbs_field::<F, _>(&w);
+                cols.init_r_pow = Bn254ScalarField::to_limbs_field::<F, _>(&init_r_pow);
+                cols.init_w_pow = Bn254ScalarField::to_limbs_field::<F, _>(&init_w_pow);
+                cols.init_acc_c = Bn254ScalarField::to_limbs_field::<F, _>(&init_acc_c);
+                cols.init_acc_y = Bn254ScalarField::to_limbs_field::<F, _>(&init_acc_y);
+                cols.r_pow = Bn254ScalarField::to_limbs_field::<F, _>(&r_pow);
+                cols.w_pow = Bn254ScalarField::to_limbs_field::<F, _>(&w_pow);
+                cols.acc_c = Bn254ScalarField::to_limbs_field::<F, _>(&acc_c);
+                cols.acc_y = Bn254ScalarField::to_limbs_field::<F, _>(&acc_y);
+
+                if i == 0 {
+                    cols.len_memory.populate(event.len_memory_record, &mut new_byte_lookup_events);
+                }
+
+                // Rows past the end of the array fold zeros and multiply the powers by one,
+                // leaving the state as is.
+                let (c, y, r_step, w_step) = if i < len {
+                    for j in 0..WORDS_FIELD_ELEMENT {
+                        cols.c_memory[j]
+                            .populate(event.c_memory_records[i][j], &mut new_byte_lookup_events);
+                        cols.y_memory[j]
+                            .populate(event.y_memory_records[i][j], &mut new_byte_lookup_events);
+                    }
+                    (
+                        BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.c[i])),
+                        BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.y[i])),
+                        r.clone(),
+                        w.clone(),
+                    )
+                } else {
+                    (BigUint::zero(), BigUint::zero(), BigUint::one(), BigUint::one())
+                };
+                cols.r_step = Bn254ScalarField::to_limbs_field::<F, _>(&r_step);
+                cols.w_step = Bn254ScalarField::to_limbs_field::<F, _>(&w_step);
+
+                let c_mul_result = cols.c_mul.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &c,
+                    &r_pow,
+                    FieldOperation::Mul,
+                );
+                let next_acc_c = cols.acc_c_add.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &acc_c,
+                    &c_mul_result,
+                    FieldOperation::Add,
+                );
+                let y_mul_result = cols.y_mul.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &y,
+                    &w_pow,
+                    FieldOperation::Mul,
+                );
+                let den = cols.den_sub.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &r,
+                    &w_pow,
+                    FieldOperation::Sub,
+                );
+                let quot = cols.quot_div.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &y_mul_result,
+                    &den,
+                    FieldOperation::Div,
+                );
+                let next_acc_y = cols.acc_y_add.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &acc_y,
+                    &quot,
+                    FieldOperation::Add,
+                );
+                let next_r_pow = cols.r_pow_mul.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &r_pow,
+                    &r_step,
+                    FieldOperation::Mul,
+                );
+                let next_w_pow = cols.w_pow_mul.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    &w_pow,
+                    &w_step,
+                    FieldOperation::Mul,
+                );
+                (r_pow, w_pow, acc_c, acc_y) = (next_r_pow, next_w_pow, next_acc_c, next_acc_y);
+
+                if i == FR_NTT_MAX_LEN - 1 {
+                    for j in 0..FR_NTT_STATE_WORDS {
+                        cols.state_memory[j]
+                            .populate(event.state_memory_records[j], &mut new_byte_lookup_events);
+                    }
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut FrNttCols<F> = row.as_mut_slice().borrow_mut();
+
+                let zero = BigUint::zero();
+                cols.c_mul.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+                cols.acc_c_add.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Add);
+                cols.y_mul.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+                cols.den_sub.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Sub);
+                cols.quot_div.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Div);
+                cols.acc_y_add.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Add);
+                cols.r_pow_mul.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+                cols.w_pow_mul.populate(&mut vec![], 0, &zero, &zero, FieldOperation::Mul);
+
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut FrNttCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::FR_NTT).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for FrNttChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for FrNttChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &FrNttCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &FrNttCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans, and only set on real rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.assert_bool(local.is_pair);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+        builder.when(local.is_pair).assert_one(local.is_real);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(local.is_first);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+        builder.when_transition().when(local.is_last).when(next.is_real).assert_one(next.is_first);
+
+        // Every event has the same number of rows.
+        builder.when(local.is_first).assert_zero(local.index);
+        builder
+            .when(local.is_last)
+            .assert_eq(local.index, AB::Expr::from_canonical_usize(FR_NTT_MAX_LEN - 1));
+
+        // The first row reads the length, which is at least one, and starts from the original
+        // state.
+        builder.when(local.is_first).assert_one(local.is_pair);
+        builder.when(local.is_first).assert_eq(local.len, local.len_memory.value().reduce::<AB>());
+        builder.when(local.is_first).assert_one(local.num_pairs);
+        builder.when(local.is_first).assert_all_eq(local.r_pow, local.init_r_pow);
+        builder.when(local.is_first).assert_all_eq(local.w_pow, local.init_w_pow);
+        builder.when(local.is_first).assert_all_eq(local.acc_c, local.init_acc_c);
+        builder.when(local.is_first).assert_all_eq(local.acc_y, local.init_acc_y);
+        builder.eval_memory_access(
+            local.shard,
+            local.clk,
+            local.pairs_ptr,
+            &local.len_memory,
+            local.is_first,
+        );
+
+        // The rows of an event share the arguments, and carry the powers and sums forward.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - local.is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_zero(next.is_first);
+        continuation.assert_eq(local.index + AB::Expr::one(), next.index);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.state_ptr, next.state_ptr);
+        continuation.assert_eq(local.pairs_ptr, next.pairs_ptr);
+        continuation.assert_eq(local.len, next.len);
+        continuation.assert_all_eq(local.r, next.r);
+        continuation.assert_all_eq(local.w, next.w);
+        continuation.assert_all_eq(local.init_r_pow, next.init_r_pow);
+        continuation.assert_all_eq(local.init_w_pow, next.init_w_pow);
+        continuation.assert_all_eq(local.init_acc_c, next.init_acc_c);
+        continuation.assert_all_eq(local.init_acc_y, next.init_acc_y);
+        continuation.assert_all_eq(local.r_pow_mul.result, next.r_pow);
+        continuation.assert_all_eq(local.w_pow_mul.result, next.w_pow);
+        continuation.assert_all_eq(local.acc_c_add.result, next.acc_c);
+        continuation.assert_all_eq(local.acc_y_add.result, next.acc_y);
+        // Once a row has no pair, neither do the following rows of the event.
+        continuation.when(next.is_pair).assert_one(local.is_pair);
+        continuation.assert_eq(local.num_pairs + next.is_pair, next.num_pairs);
+
+        // The last row has folded all the pairs.
+        builder.when(local.is_last).assert_eq(local.num_pairs, local.len);
+
+        // Read the pair of the row.
+        let c_limbs: FrLimbs<AB::Var> = limbs_from_access(&local.c_memory);
+        let y_limbs: FrLimbs<AB::Var> = limbs_from_access(&local.y_memory);
+        let c_ptr = local.pairs_ptr
+            + AB::Expr::from_canonical_u32(4)
+            + local.index * AB::Expr::from_canonical_u32(PAIR_BYTES);
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            c_ptr,
+            &[local.c_memory, local.y_memory].concat(),
+            local.is_pair,
+        );
+
+        // Rows with a pair advance the powers by `r` and `w`, and the other rows by one.
+        builder.when(local.is_pair).assert_all_eq(local.r_step, local.r);
+        builder.when(local.is_pair).assert_all_eq(local.w_step, local.w);
+        for (i, (r_limb, w_limb)) in local.r_step.0.iter().zip(local.w_step.0.iter()).enumerate() {
+            let one_limb = AB::Expr::from_bool(i == 0);
+            builder
+                .when(local.is_real)
+                .when_not(local.is_pair)
+                .assert_eq(*r_limb, one_limb.clone());
+            builder.when(local.is_real).when_not(local.is_pair).assert_eq(*w_limb, one_limb);
+        }
+
+        // Fold the pair into the sums, and advance the powers.
+        local.c_mul.eval(builder, &c_limbs, &local.r_pow, FieldOperation::Mul, local.is_real);
+        local.acc_c_add.eval(
+            builder,
+            &local.acc_c,
+            &local.c_mul.result,
+            FieldOperation::Add,
+            local.is_real,
+        );
+        local.y_mul.eval(builder, &y_limbs, &local.w_pow, FieldOperation::Mul, local.is_real);
+        local.den_sub.eval(builder, &local.r, &local.w_pow, FieldOperation::Sub, local.is_real);
+        local.quot_div.eval(
+            builder,
+            &local.y_mul.result,
+            &local.den_sub.result,
+            FieldOperation::Div,
+            local.is_real,
+        );
+        local.acc_y_add.eval(
+            builder,
+            &local.acc_y,
+            &local.quot_div.result,
+            FieldOperation::Add,
+            local.is_real,
+        );
+        local.r_pow_mul.eval(
+            builder,
+            &local.r_pow,
+            &local.r_step,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.w_pow_mul.eval(
+            builder,
+            &local.w_pow,
+            &local.w_step,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+
+        // Rows without a pair add nothing to the sums.
+        for limb in local.c_mul.result.0 {
+            builder.when(local.is_real).when_not(local.is_pair).assert_zero(limb);
+        }
+        for limb in local.quot_div.result.0 {
+            builder.when(local.is_real).when_not(local.is_pair).assert_zero(limb);
+        }
+
+        // Write the updated state on the last row. The original state is stored in the
+        // "prev_value" of the state_memory, and `r` and `w` are left as is.
+        let original = [
+            local.r,
+            local.w,
+            local.init_r_pow,
+            local.init_w_pow,
+            local.init_acc_c,
+            local.init_acc_y,
+        ];
+        let updated = [
+            local.r,
+            local.w,
+            local.r_pow_mul.result,
+            local.w_pow_mul.result,
+            local.acc_c_add.result,
+            local.acc_y_add.result,
+        ];
+        for (i, (original, updated)) in original.into_iter().zip(updated).enumerate() {
+            let memory =
+                &local.state_memory[i * WORDS_FIELD_ELEMENT..(i + 1) * WORDS_FIELD_ELEMENT];
+            let prev_limbs: FrLimbs<AB::Var> = limbs_from_prev_access(memory);
+            builder.when(local.is_last).assert_all_eq(original, prev_limbs);
+            builder.when(local.is_last).assert_all_eq(updated, value_as_limbs(memory));
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.state_ptr,
+            &local.state_memory,
+            local.is_last,
+        );
+
+        // Receive the arguments on the first row of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::FR_NTT.syscall_id()),
+            local.state_ptr,
+            local.pairs_ptr,
+            local.is_first,
+            InteractionScope::Local,
+        );
+    }
+}
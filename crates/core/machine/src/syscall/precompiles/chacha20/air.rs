@@ -0,0 +1,381 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryWriteCols},
+    operations::{AddOperation, FixedRotateRightOperation, XorOperation},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        ByteRecord, PrecompileEvent, CHACHA20_DOUBLE_ROUNDS, CHACHA20_QUARTER_ROUNDS,
+        CHACHA20_QUARTER_ROUND_STEPS, CHACHA20_STATE_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord, Word,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the ChaCha20BlockCols.
+const NUM_COLS: usize = size_of::<ChaCha20BlockCols<u8>>();
+
+/// The number of quarter rounds in a double round.
+const NUM_QUARTER_ROUNDS: usize = CHACHA20_QUARTER_ROUNDS.len();
+
+/// The number of add-xor-rotate steps in a quarter round.
+const NUM_QUARTER_ROUND_STEPS: usize = CHACHA20_QUARTER_ROUND_STEPS.len();
+
+/// A chip applying the ChaCha20 block function to a state.
+///
+/// Each event spans [`CHACHA20_DOUBLE_ROUNDS`] rows, one per double round. The first row receives
+/// the syscall and the last row adds the initial state to the permuted state and writes the result.
+#[derive(Default)]
+pub struct ChaCha20BlockChip;
+
+impl ChaCha20BlockChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// The columns of a single `a += b; d ^= a; d <<<= r` step of a quarter round.
+#[derive(Debug, Clone, Copy, AlignedBorrow, Default)]
+#[repr(C)]
+pub struct QuarterRoundStepCols<T> {
+    pub add: AddOperation<T>,
+    pub xor: XorOperation<T>,
+    pub rotate: FixedRotateRightOperation<T>,
+}
+
+/// A set of columns for the ChaCha20Block operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct ChaCha20BlockCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the state.
+    pub state_ptr: T,
+
+    /// The one-hot flags of the double round computed by the row.
+    pub round_flags: [T; CHACHA20_DOUBLE_ROUNDS],
+
+    /// Whether the row receives the syscall, which is the first row of a real event.
+    pub receive_ecall: T,
+
+    /// Whether the row writes the result, which is the last row of a real event.
+    pub finalize: T,
+
+    /// The state before the block function, which is the same on every row of an event.
+    pub initial_state: [Word<T>; CHACHA20_STATE_WORDS],
+
+    /// The state before the double round of the row.
+    pub state: [Word<T>; CHACHA20_STATE_WORDS],
+
+    /// The steps of the quarter rounds of the double round.
+    pub quarter_rounds: [[QuarterRoundStepCols<T>; NUM_QUARTER_ROUND_STEPS]; NUM_QUARTER_ROUNDS],
+
+    /// The addition of the initial state to the state after the last double round.
+    pub final_add: [AddOperation<T>; CHACHA20_STATE_WORDS],
+
+    // Memory columns.
+    // state_memory is written to with the new state, which is why it is of type MemoryWriteCols.
+    pub state_memory: [MemoryWriteCols<T>; CHACHA20_STATE_WORDS],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for ChaCha20BlockChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "ChaCha20Block".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Generate the trace rows & corresponding records for each chunk of events concurrently.
+        let rows_and_records = input
+            .get_precompile_events(SyscallCode::CHACHA20_BLOCK)
+            .chunks(1)
+            .map(|events| {
+                let mut records = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .flat_map(|(_, event)| {
+                        let event = if let PrecompileEvent::ChaCha20Block(event) = event {
+                            event
+                        } else {
+                            unreachable!()
+                        };
+                        let shard = event.shard;
+
+                        let mut state: [u32; CHACHA20_STATE_WORDS] =
+                            event.state.as_slice().try_into().unwrap();
+                        let mut rows = Vec::with_capacity(CHACHA20_DOUBLE_ROUNDS);
+                        for round in 0..CHACHA20_DOUBLE_ROUNDS {
+                            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                            let cols: &mut ChaCha20BlockCols<F> = row.as_mut_slice().borrow_mut();
+
+                            // Assign basic values to the columns.
+                            cols.is_real = F::one();
+                            cols.receive_ecall = F::from_bool(round == 0);
+                            cols.finalize = F::from_bool(round == CHACHA20_DOUBLE_ROUNDS - 1);
+                            cols.shard = F::from_canonical_u32(shard);
+                            cols.clk = F::from_canonical_u32(event.clk);
+                            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                            for i in 0..CHACHA20_STATE_WORDS {
+                                cols.initial_state[i] = Word::from(event.state[i]);
+                                cols.state[i] = Word::from(state[i]);
+                            }
+
+                            // The double round.
+                            for (indices, steps) in
+                                CHACHA20_QUARTER_ROUNDS.iter().zip(cols.quarter_rounds.iter_mut())
+                            {
+                                for (&(a, b, d, r), step) in
+                                    CHACHA20_QUARTER_ROUND_STEPS.iter().zip(steps.iter_mut())
+                                {
+                                    let (a, b, d) = (indices[a], indices[b], indices[d]);
+                                    state[a] = step.add.populate(
+                                        &mut new_byte_lookup_events,
+                                        shard,
+                                        state[a],
+                                        state[b],
+                                    );
+                                    let xor = step.xor.populate(
+                                        &mut new_byte_lookup_events,
+                                        shard,
+                                        state[d],
+                                        state[a],
+                                    );
+                                    state[d] = step.rotate.populate(
+                                        &mut new_byte_lookup_events,
+                                        shard,
+                                        xor,
+                                        32 - r as usize,
+                                    );
+                                }
+                            }
+
+                            // Add the initial state and write the result on the last row.
+                            if round == CHACHA20_DOUBLE_ROUNDS - 1 {
+                                for i in 0..CHACHA20_STATE_WORDS {
+                                    cols.final_add[i].populate(
+                                        &mut new_byte_lookup_events,
+                                        shard,
+                                        state[i],
+                                        event.state[i],
+                                    );
+                                    cols.state_memory[i].populate(
+                                        event.state_memory_records[i],
+                                        &mut new_byte_lookup_events,
+                                    );
+                                }
+                            }
+
+                            rows.push(row);
+                        }
+                        rows
+                    })
+                    .collect::<Vec<_>>();
+                records.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, records)
+            })
+            .collect::<Vec<_>>();
+
+        //  Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for (row, mut record) in rows_and_records {
+            rows.extend(row);
+            output.append(&mut record);
+        }
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces and the round flags to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut ChaCha20BlockCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+            cols.round_flags[i % CHACHA20_DOUBLE_ROUNDS] = F::one();
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::CHACHA20_BLOCK).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for ChaCha20BlockChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for ChaCha20BlockChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &ChaCha20BlockCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &ChaCha20BlockCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // The round flags are one-hot, start at the first round and cycle through the rounds.
+        for &flag in local.round_flags.iter() {
+            builder.assert_bool(flag);
+        }
+        builder
+            .assert_one(local.round_flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag));
+        builder.when_first_row().assert_one(local.round_flags[0]);
+        for i in 0..CHACHA20_DOUBLE_ROUNDS {
+            builder.when_transition().assert_eq(
+                local.round_flags[i],
+                next.round_flags[(i + 1) % CHACHA20_DOUBLE_ROUNDS],
+            );
+        }
+        let first_round = local.round_flags[0];
+        let last_round = local.round_flags[CHACHA20_DOUBLE_ROUNDS - 1];
+
+        // An event starts on the first round and ends on the last one. The last row of the trace
+        // ends an event, so an event cannot be cut short by the end of the trace.
+        builder.assert_eq(local.receive_ecall, first_round * local.is_real);
+        builder.assert_eq(local.finalize, last_round * local.is_real);
+        builder.when_last_row().assert_zero(local.is_real);
+
+        // The values of an event are the same on all of its rows.
+        {
+            let mut transition_builder = builder.when_transition();
+            let mut event_builder = transition_builder.when(AB::Expr::one() - last_round);
+            event_builder.assert_eq(local.shard, next.shard);
+            event_builder.assert_eq(local.clk, next.clk);
+            event_builder.assert_eq(local.state_ptr, next.state_ptr);
+            event_builder.assert_eq(local.is_real, next.is_real);
+            for i in 0..CHACHA20_STATE_WORDS {
+                event_builder.assert_all_eq(local.initial_state[i], next.initial_state[i]);
+            }
+        }
+
+        // The first double round is applied to the initial state.
+        for i in 0..CHACHA20_STATE_WORDS {
+            builder
+                .when(local.receive_ecall)
+                .assert_word_eq(local.state[i], local.initial_state[i]);
+        }
+
+        // The double round.
+        let mut state = local.state;
+        for (indices, steps) in CHACHA20_QUARTER_ROUNDS.iter().zip(local.quarter_rounds.iter()) {
+            for (&(a, b, d, r), step) in CHACHA20_QUARTER_ROUND_STEPS.iter().zip(steps.iter()) {
+                let (a, b, d) = (indices[a], indices[b], indices[d]);
+                AddOperation::<AB::F>::eval(
+                    builder,
+                    state[a],
+                    state[b],
+                    step.add,
+                    local.is_real.into(),
+                );
+                state[a] = step.add.value;
+                XorOperation::<AB::F>::eval(builder, state[d], state[a], step.xor, local.is_real);
+                FixedRotateRightOperation::<AB::F>::eval(
+                    builder,
+                    step.xor.value,
+                    32 - r as usize,
+                    step.rotate,
+                    local.is_real,
+                );
+                state[d] = step.rotate.value;
+            }
+        }
+
+        // The next row of the event continues from the state after the double round. On real rows,
+        // `is_real - finalize` is one exactly when the row is not the last one of its event.
+        for i in 0..CHACHA20_STATE_WORDS {
+            builder
+                .when_transition()
+                .when(local.is_real - local.finalize)
+                .assert_word_eq(state[i], next.state[i]);
+        }
+
+        // Add the initial state to the permuted state, and check that it is written to the state.
+        // The state is stored in the "prev_value" of the state_memory, since we write to it later.
+        for i in 0..CHACHA20_STATE_WORDS {
+            AddOperation::<AB::F>::eval(
+                builder,
+                state[i],
+                local.initial_state[i],
+                local.final_add[i],
+                local.finalize.into(),
+            );
+            builder
+                .when(local.finalize)
+                .assert_word_eq(*local.state_memory[i].prev_value(), local.initial_state[i]);
+            builder
+                .when(local.finalize)
+                .assert_word_eq(*local.state_memory[i].value(), local.final_add[i].value);
+        }
+
+        // Read and write the state.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.state_ptr,
+            &local.state_memory,
+            local.finalize,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::CHACHA20_BLOCK.syscall_id()),
+            local.state_ptr,
+            AB::Expr::zero(),
+            local.receive_ecall,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -0,0 +1,22 @@
+mod air;
+
+pub use air::*;
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::CHACHA20_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_chacha20_block() {
+        utils::setup_logger();
+        let program = Program::from(CHACHA20_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
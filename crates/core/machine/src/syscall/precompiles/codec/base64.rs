@@ -0,0 +1,351 @@
+use crate::{
+    air::MemoryAirBuilder,
+    bytes::utils::shr_carry,
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        base64_decode_char, ByteLookupEvent, ByteRecord, PrecompileEvent,
+        BASE64_DECODE_OUTPUT_WORDS, DECODE_INPUT_BYTES, DECODE_INPUT_WORDS,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::words_to_bytes_le;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the Base64DecodeCols.
+const NUM_COLS: usize = size_of::<Base64DecodeCols<u8>>();
+
+/// The number of groups of 4 characters, each of which is decoded into 3 bytes.
+const NUM_GROUPS: usize = DECODE_INPUT_BYTES / 4;
+
+/// A chip decoding 16 characters of the standard base64 alphabet into 12 bytes.
+///
+/// Each character is decoded into 6 bits by a byte lookup, which also returns whether the
+/// character is in the alphabet. The middle two sextets of each group straddle byte boundaries,
+/// so they are split with `ShrCarry` lookups. If any character is not in the alphabet, the
+/// syscall returns a nonzero status and the output is left unchanged.
+#[derive(Default)]
+pub struct Base64DecodeChip;
+
+impl Base64DecodeChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Base64Decode operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Base64DecodeCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the output.
+    pub output_ptr: T,
+
+    /// The pointer to the input.
+    pub input_ptr: T,
+
+    // Memory columns.
+    // output_memory is written to with the decoded bytes, which is why it is of type
+    // MemoryWriteCols.
+    pub output_memory: [MemoryWriteCols<T>; BASE64_DECODE_OUTPUT_WORDS],
+    pub input_memory: [MemoryReadCols<T>; DECODE_INPUT_WORDS],
+
+    /// The sextets of the input characters, which are zero for invalid characters.
+    pub sextets: [T; DECODE_INPUT_BYTES],
+
+    /// Whether each input character is in the alphabet.
+    pub is_base64: [T; DECODE_INPUT_BYTES],
+
+    /// The running products of `is_base64`, the last of which is whether the input is valid.
+    pub is_valid: [T; DECODE_INPUT_BYTES],
+
+    /// The high 2 and low 4 bits of the second sextet of each group.
+    pub second_hi: [T; NUM_GROUPS],
+    pub second_lo: [T; NUM_GROUPS],
+
+    /// The high 4 and low 2 bits of the third sextet of each group.
+    pub third_hi: [T; NUM_GROUPS],
+    pub third_lo: [T; NUM_GROUPS],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Base64DecodeChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Base64Decode".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Generate the trace rows & corresponding records for each chunk of events concurrently.
+        let rows_and_records = input
+            .get_precompile_events(SyscallCode::BASE64_DECODE)
+            .chunks(1)
+            .map(|events| {
+                let mut records = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .map(|(_, event)| {
+                        let event = if let PrecompileEvent::Base64Decode(event) = event {
+                            event
+                        } else {
+                            unreachable!()
+                        };
+                        let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                        let cols: &mut Base64DecodeCols<F> = row.as_mut_slice().borrow_mut();
+                        let shard = event.shard;
+
+                        // Assign basic values to the columns.
+                        cols.is_real = F::one();
+                        cols.shard = F::from_canonical_u32(event.shard);
+                        cols.clk = F::from_canonical_u32(event.clk);
+                        cols.output_ptr = F::from_canonical_u32(event.output_ptr);
+                        cols.input_ptr = F::from_canonical_u32(event.input_ptr);
+
+                        // Populate memory columns.
+                        for i in 0..BASE64_DECODE_OUTPUT_WORDS {
+                            cols.output_memory[i].populate(
+                                event.output_memory_records[i],
+                                &mut new_byte_lookup_events,
+                            );
+                        }
+                        for i in 0..DECODE_INPUT_WORDS {
+                            cols.input_memory[i].populate(
+                                event.input_memory_records[i],
+                                &mut new_byte_lookup_events,
+                            );
+                        }
+
+                        // Decode the characters.
+                        let input = words_to_bytes_le::<DECODE_INPUT_BYTES>(&event.input);
+                        let mut sextets = [0u8; DECODE_INPUT_BYTES];
+                        let mut is_valid = true;
+                        for (i, &c) in input.iter().enumerate() {
+                            let (sextet, is_base64) = match base64_decode_char(c) {
+                                Some(sextet) => (sextet, true),
+                                None => (0, false),
+                            };
+                            sextets[i] = sextet;
+                            is_valid &= is_base64;
+                            cols.sextets[i] = F::from_canonical_u8(sextet);
+                            cols.is_base64[i] = F::from_bool(is_base64);
+                            cols.is_valid[i] = F::from_bool(is_valid);
+
+                            new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                                shard,
+                                ByteOpcode::Base64Decode,
+                                sextet as u16,
+                                is_base64 as u8,
+                                c,
+                                0,
+                            ));
+                        }
+
+                        // Split the sextets straddling byte boundaries.
+                        for g in 0..NUM_GROUPS {
+                            let splits = [
+                                (sextets[4 * g + 1], 4, &mut cols.second_hi, &mut cols.second_lo),
+                                (sextets[4 * g + 2], 2, &mut cols.third_hi, &mut cols.third_lo),
+                            ];
+                            for (sextet, shift, hi_cols, lo_cols) in splits {
+                                let (hi, lo) = shr_carry(sextet, shift);
+                                hi_cols[g] = F::from_canonical_u8(hi);
+                                lo_cols[g] = F::from_canonical_u8(lo);
+
+                                new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                                    shard,
+                                    ByteOpcode::ShrCarry,
+                                    hi as u16,
+                                    lo,
+                                    sextet,
+                                    shift,
+                                ));
+                            }
+                        }
+                        debug_assert_eq!(is_valid, !event.is_invalid);
+
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                records.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, records)
+            })
+            .collect::<Vec<_>>();
+
+        //  Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for (row, mut record) in rows_and_records {
+            rows.extend(row);
+            output.append(&mut record);
+        }
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Base64DecodeCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::BASE64_DECODE).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Base64DecodeChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Base64DecodeChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Base64DecodeCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Base64DecodeCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let input = value_as_limbs(&local.input_memory);
+
+        // Decode the characters, and check whether all of them are valid.
+        for i in 0..DECODE_INPUT_BYTES {
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::Base64Decode as u32),
+                local.sextets[i],
+                local.is_base64[i],
+                input[i],
+                AB::Expr::zero(),
+                local.is_real,
+            );
+            if i == 0 {
+                builder.assert_eq(local.is_valid[i], local.is_base64[i]);
+            } else {
+                builder.assert_eq(local.is_valid[i], local.is_valid[i - 1] * local.is_base64[i]);
+            }
+        }
+        let is_valid = local.is_valid[DECODE_INPUT_BYTES - 1];
+        let is_invalid = local.is_real - is_valid;
+
+        // The output is the decoded bytes if the input is valid, and is unchanged otherwise.
+        let prev_output =
+            local.output_memory.iter().flat_map(|access| access.prev_value().0).collect::<Vec<_>>();
+        let output = value_as_limbs(&local.output_memory);
+        for g in 0..NUM_GROUPS {
+            let sextet = |k: usize| local.sextets[4 * g + k];
+
+            // Split the second and third sextets, which straddle byte boundaries.
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::ShrCarry as u32),
+                local.second_hi[g],
+                local.second_lo[g],
+                sextet(1),
+                AB::F::from_canonical_u32(4),
+                local.is_real,
+            );
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::ShrCarry as u32),
+                local.third_hi[g],
+                local.third_lo[g],
+                sextet(2),
+                AB::F::from_canonical_u32(2),
+                local.is_real,
+            );
+
+            let decoded: [AB::Expr; 3] = [
+                sextet(0) * AB::F::from_canonical_u32(4) + local.second_hi[g],
+                local.second_lo[g] * AB::F::from_canonical_u32(16) + local.third_hi[g],
+                local.third_lo[g] * AB::F::from_canonical_u32(64) + sextet(3),
+            ];
+            for (k, decoded) in decoded.into_iter().enumerate() {
+                let i = 3 * g + k;
+                builder.when(is_valid).assert_eq(output[i], decoded);
+                builder.when(is_invalid.clone()).assert_eq(output[i], prev_output[i]);
+            }
+        }
+
+        // Read the input.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.input_ptr,
+            &local.input_memory,
+            local.is_real,
+        );
+
+        // Write the output.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.output_ptr,
+            &local.output_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments, with the status of the call folded into the syscall id.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::Expr::from_canonical_u32(SyscallCode::BASE64_DECODE.syscall_id())
+                + is_invalid * AB::F::from_canonical_u32(1 << 8),
+            local.output_ptr,
+            local.input_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
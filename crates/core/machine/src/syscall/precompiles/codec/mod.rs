@@ -0,0 +1,38 @@
+//! Precompiles for decoding text encodings of binary data.
+//!
+//! Each call decodes a block of 16 ASCII characters, looking up the value of every character and
+//! whether it is in the alphabet of the encoding in the byte table. If any character is invalid,
+//! the call returns a nonzero status and leaves the output unchanged, after which the guest reports
+//! the error.
+
+mod base64;
+mod hex;
+
+pub use base64::*;
+pub use hex::*;
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::{BASE64_DECODE_ELF, HEX_DECODE_ELF};
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_hex_decode() {
+        utils::setup_logger();
+        let program = Program::from(HEX_DECODE_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_base64_decode() {
+        utils::setup_logger();
+        let program = Program::from(BASE64_DECODE_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{pad_rows_fixed, zeroed_f_vec},
+};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program, Register,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+
+/// The number of columns in the Csel256Cols.
+pub const NUM_CSEL256_COLS: usize = size_of::<Csel256Cols<u8>>();
+
+/// The number of words of the selected values.
+const NUM_WORDS: usize = 8;
+
+/// The register holding the condition.
+const COND_REGISTER: u32 = Register::X12 as u32;
+
+/// A chip for the `CSEL256` precompile, which writes `a` back to `a_ptr` if the condition is one,
+/// and `b` if it is zero.
+#[derive(Default)]
+pub struct Csel256Chip;
+
+impl Csel256Chip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Csel256 operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Csel256Cols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub nonce: T,
+    pub clk: T,
+    pub a_ptr: T,
+    pub b_ptr: T,
+    /// The condition, which is either zero or one.
+    pub cond: T,
+    pub cond_memory: MemoryReadCols<T>,
+    /// The accesses to a, whose previous values are the a value, and whose values are the selected
+    /// value.
+    pub a_memory: [MemoryWriteCols<T>; NUM_WORDS],
+    pub b_memory: [MemoryReadCols<T>; NUM_WORDS],
+}
+
+impl<F: PrimeField32> MachineAir<F> for Csel256Chip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Csel256".to_string()
+    }
+
+    fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::CSEL256) {
+            let event =
+                if let PrecompileEvent::Csel256(event) = event { event } else { unreachable!() };
+
+            let mut row = zeroed_f_vec(NUM_CSEL256_COLS);
+            let cols: &mut Csel256Cols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.a_ptr = F::from_canonical_u32(event.a_ptr);
+            cols.b_ptr = F::from_canonical_u32(event.b_ptr);
+            cols.cond = F::from_canonical_u32(event.cond);
+
+            // Populate the memory access columns.
+            cols.cond_memory.populate(event.cond_memory, &mut new_byte_lookup_events);
+            for i in 0..NUM_WORDS {
+                cols.a_memory[i].populate(event.a_memory_records[i], &mut new_byte_lookup_events);
+                cols.b_memory[i].populate(event.b_memory_records[i], &mut new_byte_lookup_events);
+            }
+            rows.push(row);
+        }
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || zeroed_f_vec(NUM_CSEL256_COLS),
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_CSEL256_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Csel256Cols<F> =
+                trace.values[i * NUM_CSEL256_COLS..(i + 1) * NUM_CSEL256_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::CSEL256).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Csel256Chip {
+    fn width(&self) -> usize {
+        NUM_CSEL256_COLS
+    }
+}
+
+impl<AB> Air<AB> for Csel256Chip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Csel256Cols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Csel256Cols<AB::Var> = (*next).borrow();
+
+        // Check that nonce is incremented.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.cond);
+
+        // The condition is the whole value of its register, and not only its reduction, so that a
+        // register holding any other value than zero or one is rejected.
+        let cond_value = local.cond_memory.value();
+        builder.when(local.is_real).assert_eq(local.cond, cond_value[0]);
+        for &byte in &cond_value.0[1..] {
+            builder.when(local.is_real).assert_zero(byte);
+        }
+
+        // Every byte written to a is the byte of a if the condition is set, and of b otherwise.
+        for (a, b) in local.a_memory.iter().zip(local.b_memory.iter()) {
+            for i in 0..4 {
+                let (a_byte, b_byte) = (a.prev_value()[i], b.value()[i]);
+                builder
+                    .when(local.is_real)
+                    .assert_eq(a.value()[i], b_byte + local.cond * (a_byte - b_byte));
+            }
+        }
+
+        // Read the condition and b, and write a one cycle later.
+        builder.eval_memory_access(
+            local.shard,
+            local.clk.into(),
+            AB::Expr::from_canonical_u32(COND_REGISTER),
+            &local.cond_memory,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.b_ptr,
+            &local.b_memory,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk + AB::F::one(),
+            local.a_ptr,
+            &local.a_memory,
+            local.is_real,
+        );
+
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::CSEL256.syscall_id()),
+            local.a_ptr,
+            local.b_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::CSEL256_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_csel256() {
+        utils::setup_logger();
+        let program = Program::from(CSEL256_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
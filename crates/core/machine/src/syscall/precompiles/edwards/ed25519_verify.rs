@@ -0,0 +1,848 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols},
+    operations::{
+        field::{
+            field_den::FieldDenCols, field_inner_product::FieldInnerProductCols,
+            field_op::FieldOpCols, range::FieldLtCols,
+        },
+        IsZeroOperation,
+    },
+    utils::{limbs_from_access, pad_rows_fixed, words_to_bytes_le_vec, zeroed_f_vec},
+};
+
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        ed25519_compress, ed25519_reduce_digest, ed25519_verify_table, ByteLookupEvent, ByteRecord,
+        Ed25519VerifyEvent, FieldOperation, PrecompileEvent, ED25519_KEY_WORDS,
+        ED25519_SIGNATURE_WORDS, ED25519_VERIFY_ROWS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    edwards::{
+        ed25519::{Ed25519, Ed25519BaseField, Ed25519Parameters, Ed25519ScalarField},
+        EdwardsParameters, NUM_LIMBS,
+    },
+    params::{limbs_from_vec, FieldParameters, Limbs, NumLimbs},
+    AffinePoint,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the Ed25519VerifyCols.
+const NUM_COLS: usize = size_of::<Ed25519VerifyCols<u8>>();
+
+/// The index of the last row of an event.
+const LAST_ROW: usize = ED25519_VERIFY_ROWS - 1;
+
+type BaseField = Ed25519BaseField;
+type ScalarField = Ed25519ScalarField;
+type FieldLimbs<T> = Limbs<T, <BaseField as NumLimbs>::Limbs>;
+
+/// A point given by its affine coordinates.
+type Point = (BigUint, BigUint);
+
+/// A chip checking the verification equation of an Ed25519 signature, that `[S]B - [k]A`
+/// compresses to `R`.
+///
+/// The syscall takes a pointer to the public key `A`, given by its affine coordinates, and a
+/// pointer to the signature `R || S`, followed by the SHA-512 digest of `R`, the compressed public
+/// key and the message, which is reduced into `k`.
+///
+/// Every event takes [`ED25519_VERIFY_ROWS`] rows, one for every bit of the registers holding the
+/// scalars. The first row reads the arguments, checks that `S` is reduced, computes `k`, and the
+/// points `-A` and `B - A`. Every row then doubles the accumulator, which starts from the identity,
+/// and adds the point selected by its bits of `S` and `k`, with the complete addition formulas of
+/// the curve. The last row compresses the accumulator and compares it to `R`, which sets the
+/// status of the syscall.
+#[derive(Default)]
+pub struct Ed25519VerifyChip;
+
+impl Ed25519VerifyChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Ed25519Verify operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Ed25519VerifyCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the public key.
+    pub key_ptr: T,
+
+    /// The pointer to the signature, which is followed by the digest.
+    pub sig_ptr: T,
+
+    /// The row of the event, as a one-hot encoding.
+    pub step: [T; ED25519_VERIFY_ROWS],
+
+    /// Whether the verification equation holds.
+    pub is_valid: T,
+
+    // Memory columns.
+    // The public key and the signature are read on the first row.
+    pub key_memory: [MemoryReadCols<T>; ED25519_KEY_WORDS],
+    pub sig_memory: [MemoryReadCols<T>; ED25519_SIGNATURE_WORDS],
+
+    /// The bytes of `R`, shared by the rows of the event to be compared on the last row.
+    pub r: [T; NUM_LIMBS],
+
+    /// The operations of the first row.
+    pub setup: Ed25519VerifySetupCols<T>,
+
+    /// The points added by the ladder, shared by the rows of the event.
+    pub table: Ed25519VerifyTableCols<T>,
+
+    /// The bits of `S` and `k`.
+    pub scalars: [Ed25519VerifyScalarCols<T>; 2],
+
+    /// The double-and-add step of the row.
+    pub ladder: Ed25519VerifyStepCols<T>,
+
+    /// The compression of the accumulator on the last row.
+    pub output: Ed25519VerifyOutputCols<T>,
+
+    pub is_real: T,
+}
+
+/// The columns checking `S`, and computing `k` and the points of the ladder.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Ed25519VerifySetupCols<T> {
+    /// Checks that `S` is smaller than the group order.
+    pub s_range_check: FieldLtCols<T, ScalarField>,
+
+    /// The scalar `k = h_high * 2^256 + h_low` modulo the group order, where `h_low` and `h_high`
+    /// are the halves of the digest.
+    pub k_high: FieldOpCols<T, ScalarField>,
+    pub k: FieldOpCols<T, ScalarField>,
+    pub k_range_check: FieldLtCols<T, ScalarField>,
+
+    /// The x-coordinate of `-A`.
+    pub neg_key_x: FieldOpCols<T, BaseField>,
+
+    /// The point `B - A`.
+    pub base_minus_key: EdwardsAddCols<T>,
+}
+
+/// The points `-A` and `B - A`. The other points of the ladder are the identity and `B`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Ed25519VerifyTableCols<T> {
+    pub neg_key_x: FieldLimbs<T>,
+    pub key_y: FieldLimbs<T>,
+    pub base_minus_key_x: FieldLimbs<T>,
+    pub base_minus_key_y: FieldLimbs<T>,
+}
+
+/// The bits of a scalar, as the bytes of a 257-bit register shifted left by the row, so that the
+/// last one is the bit of the row.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Ed25519VerifyScalarCols<T> {
+    /// The bytes of the scalar, shifted left by the row and truncated to 257 bits.
+    pub digits: [T; NUM_LIMBS + 1],
+
+    /// The top bits of the bytes of `digits`, which are carried into the next byte by the shift.
+    pub shift_carries: [T; NUM_LIMBS],
+}
+
+/// The columns computing the sum of two points with the complete addition formulas.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EdwardsAddCols<T> {
+    pub x3_numerator: FieldInnerProductCols<T, BaseField>,
+    pub y3_numerator: FieldInnerProductCols<T, BaseField>,
+    pub x1_mul_y1: FieldOpCols<T, BaseField>,
+    pub x2_mul_y2: FieldOpCols<T, BaseField>,
+    pub f: FieldOpCols<T, BaseField>,
+    pub d_mul_f: FieldOpCols<T, BaseField>,
+    pub x3_ins: FieldDenCols<T, BaseField>,
+    pub y3_ins: FieldDenCols<T, BaseField>,
+}
+
+/// The columns computing `2 * acc + t`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Ed25519VerifyStepCols<T> {
+    /// The accumulator.
+    pub acc_x: FieldLimbs<T>,
+    pub acc_y: FieldLimbs<T>,
+
+    /// Whether both bits of the row are 1.
+    pub both_bits: T,
+
+    /// The point added on the row, selected from the table by the bits.
+    pub point_x: FieldLimbs<T>,
+    pub point_y: FieldLimbs<T>,
+
+    /// The sum `acc + acc`.
+    pub double: EdwardsAddCols<T>,
+
+    /// The sum `2 * acc + t`.
+    pub add: EdwardsAddCols<T>,
+}
+
+/// The columns compressing the result of the ladder and comparing it to `R`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Ed25519VerifyOutputCols<T> {
+    /// Checks that the coordinates are reduced.
+    pub x_range_check: FieldLtCols<T, BaseField>,
+    pub y_range_check: FieldLtCols<T, BaseField>,
+
+    /// The lowest byte of the x-coordinate is `2 * x_half + x_parity`.
+    pub x_half: T,
+    pub x_parity: T,
+
+    /// The sum of the squares of the differences of the bytes of the compressed point and `R`.
+    pub diff_sum: T,
+    pub diff_is_zero: IsZeroOperation<T>,
+}
+
+impl Ed25519VerifyChip {
+    fn populate_add<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        cols: &mut EdwardsAddCols<F>,
+        (p_x, p_y): (&BigUint, &BigUint),
+        (q_x, q_y): (&BigUint, &BigUint),
+    ) -> Point {
+        let x3_numerator = cols.x3_numerator.populate(
+            record,
+            shard,
+            &[p_x.clone(), q_x.clone()],
+            &[q_y.clone(), p_y.clone()],
+        );
+        let y3_numerator = cols.y3_numerator.populate(
+            record,
+            shard,
+            &[p_y.clone(), p_x.clone()],
+            &[q_y.clone(), q_x.clone()],
+        );
+        let x1_mul_y1 = cols.x1_mul_y1.populate(record, shard, p_x, p_y, FieldOperation::Mul);
+        let x2_mul_y2 = cols.x2_mul_y2.populate(record, shard, q_x, q_y, FieldOperation::Mul);
+        let f = cols.f.populate(record, shard, &x1_mul_y1, &x2_mul_y2, FieldOperation::Mul);
+
+        let d = Ed25519Parameters::d_biguint();
+        let d_mul_f = cols.d_mul_f.populate(record, shard, &f, &d, FieldOperation::Mul);
+
+        let x3 = cols.x3_ins.populate(record, shard, &x3_numerator, &d_mul_f, true);
+        let y3 = cols.y3_ins.populate(record, shard, &y3_numerator, &d_mul_f, false);
+        (x3, y3)
+    }
+
+    /// Populates the operations of the first row from the event, or on zero inputs for the other
+    /// rows, which the constraints of the operations hold for.
+    fn populate_setup<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        cols: &mut Ed25519VerifySetupCols<F>,
+        event: Option<&Ed25519VerifyEvent>,
+    ) {
+        let zero = BigUint::zero();
+        let (key_x, key_y, sig) = match event {
+            Some(event) => {
+                let key = AffinePoint::<Ed25519>::from_words_le(&event.key);
+                (key.x, key.y, words_to_bytes_le_vec(&event.sig))
+            }
+            None => (zero.clone(), zero.clone(), vec![0; 4 * ED25519_SIGNATURE_WORDS]),
+        };
+        let s = BigUint::from_bytes_le(&sig[32..64]);
+        let h_low = BigUint::from_bytes_le(&sig[64..96]);
+        let h_high = BigUint::from_bytes_le(&sig[96..]);
+        let ((base_x, base_y), shift) = match event {
+            Some(_) => (Ed25519Parameters::generator(), two_pow_256_mod_order()),
+            None => ((zero.clone(), zero.clone()), zero.clone()),
+        };
+
+        let order = ScalarField::modulus();
+        let k_high = cols.k_high.populate(record, shard, &h_high, &shift, FieldOperation::Mul);
+        let k = cols.k.populate(record, shard, &k_high, &h_low, FieldOperation::Add);
+        let neg_key_x = cols.neg_key_x.populate(record, shard, &zero, &key_x, FieldOperation::Sub);
+        Self::populate_add(
+            record,
+            shard,
+            &mut cols.base_minus_key,
+            (&base_x, &base_y),
+            (&neg_key_x, &key_y),
+        );
+        if event.is_some() {
+            cols.s_range_check.populate(record, shard, &s, &order);
+            cols.k_range_check.populate(record, shard, &k, &order);
+        }
+    }
+
+    /// Populates the row of an event, and returns the accumulator for the next row.
+    fn populate_row<F: PrimeField32>(
+        record: &mut Vec<ByteLookupEvent>,
+        event: &Ed25519VerifyEvent,
+        scalars: &[BigUint; 2],
+        table: &[AffinePoint<Ed25519>; 4],
+        row: usize,
+        cols: &mut Ed25519VerifyCols<F>,
+        acc: &Point,
+    ) -> Point {
+        let shard = event.shard;
+        let sig = words_to_bytes_le_vec(&event.sig);
+        cols.r = core::array::from_fn(|i| F::from_canonical_u8(sig[i]));
+        cols.table = Ed25519VerifyTableCols {
+            neg_key_x: BaseField::to_limbs_field::<F, _>(&table[1].x),
+            key_y: BaseField::to_limbs_field::<F, _>(&table[1].y),
+            base_minus_key_x: BaseField::to_limbs_field::<F, _>(&table[3].x),
+            base_minus_key_y: BaseField::to_limbs_field::<F, _>(&table[3].y),
+        };
+
+        // The bits of the row are the top bits of the registers, shifted left by the row.
+        let register_mask = (BigUint::one() << ED25519_VERIFY_ROWS) - 1u32;
+        for (cols, scalar) in cols.scalars.iter_mut().zip(scalars) {
+            let register = (scalar << row) & &register_mask;
+            let mut bytes = register.to_bytes_le();
+            bytes.resize(NUM_LIMBS + 1, 0);
+            for i in 0..NUM_LIMBS {
+                cols.digits[i] = F::from_canonical_u8(bytes[i]);
+                cols.shift_carries[i] = F::from_canonical_u8(bytes[i] >> 7);
+            }
+            cols.digits[NUM_LIMBS] = F::from_canonical_u8(bytes[NUM_LIMBS]);
+            record.add_u8_range_checks(shard, &bytes[..NUM_LIMBS]);
+        }
+
+        // Double the accumulator and add the point of the row.
+        let bit = (LAST_ROW - row) as u64;
+        let (s_bit, k_bit) = (scalars[0].bit(bit), scalars[1].bit(bit));
+        let point = &table[2 * usize::from(s_bit) + usize::from(k_bit)];
+        let ladder = &mut cols.ladder;
+        ladder.acc_x = BaseField::to_limbs_field::<F, _>(&acc.0);
+        ladder.acc_y = BaseField::to_limbs_field::<F, _>(&acc.1);
+        ladder.both_bits = F::from_bool(s_bit && k_bit);
+        ladder.point_x = BaseField::to_limbs_field::<F, _>(&point.x);
+        ladder.point_y = BaseField::to_limbs_field::<F, _>(&point.y);
+        let double = Self::populate_add(
+            record,
+            shard,
+            &mut ladder.double,
+            (&acc.0, &acc.1),
+            (&acc.0, &acc.1),
+        );
+        let result = Self::populate_add(
+            record,
+            shard,
+            &mut ladder.add,
+            (&double.0, &double.1),
+            (&point.x, &point.y),
+        );
+
+        if row == 0 {
+            Self::populate_setup(record, shard, &mut cols.setup, Some(event));
+            for (col, memory_record) in cols.key_memory.iter_mut().zip(&event.key_read_records) {
+                col.populate(*memory_record, record);
+            }
+            for (col, memory_record) in cols.sig_memory.iter_mut().zip(&event.sig_read_records) {
+                col.populate(*memory_record, record);
+            }
+        } else {
+            Self::populate_setup(&mut Vec::<ByteLookupEvent>::new(), shard, &mut cols.setup, None);
+        }
+
+        if row == LAST_ROW {
+            let output = &mut cols.output;
+            let modulus = BaseField::modulus();
+            output.x_range_check.populate(record, shard, &result.0, &modulus);
+            output.y_range_check.populate(record, shard, &result.1, &modulus);
+            let x_low = BaseField::to_limbs(&result.0)[0];
+            output.x_half = F::from_canonical_u8(x_low >> 1);
+            output.x_parity = F::from_canonical_u8(x_low & 1);
+            record.add_u8_range_checks(shard, &[x_low >> 1]);
+
+            let compressed =
+                ed25519_compress(&AffinePoint::new(result.0.clone(), result.1.clone()));
+            let diff_sum = compressed
+                .iter()
+                .zip(&sig[..NUM_LIMBS])
+                .map(|(&a, &b)| (i32::from(a) - i32::from(b)).pow(2) as u32)
+                .sum::<u32>();
+            output.diff_sum = F::from_canonical_u32(diff_sum);
+            output.diff_is_zero.populate(diff_sum);
+            debug_assert_eq!(diff_sum == 0, event.is_valid);
+        }
+
+        result
+    }
+
+    /// Populates the operations with unconditional constraints on zero inputs, for the padding
+    /// rows.
+    fn populate_padding<F: PrimeField32>(cols: &mut Ed25519VerifyCols<F>) {
+        let zero = BigUint::zero();
+        let ladder = &mut cols.ladder;
+        Self::populate_add(
+            &mut Vec::<ByteLookupEvent>::new(),
+            0,
+            &mut ladder.double,
+            (&zero, &zero),
+            (&zero, &zero),
+        );
+        Self::populate_add(
+            &mut Vec::<ByteLookupEvent>::new(),
+            0,
+            &mut ladder.add,
+            (&zero, &zero),
+            (&zero, &zero),
+        );
+        Self::populate_setup(&mut Vec::<ByteLookupEvent>::new(), 0, &mut cols.setup, None);
+    }
+}
+
+/// The constant `2^256` modulo the group order, which shifts the high half of the digest.
+fn two_pow_256_mod_order() -> BigUint {
+    (BigUint::one() << 256) % ScalarField::modulus()
+}
+
+impl<F: PrimeField32> MachineAir<F> for Ed25519VerifyChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Ed25519Verify".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::ED25519_VERIFY) {
+            let event = if let PrecompileEvent::Ed25519Verify(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let key = AffinePoint::<Ed25519>::from_words_le(&event.key);
+            let sig = words_to_bytes_le_vec(&event.sig);
+            let scalars = [BigUint::from_bytes_le(&sig[32..64]), ed25519_reduce_digest(&sig[64..])];
+            let table = ed25519_verify_table(&key);
+
+            let mut acc = (BigUint::zero(), BigUint::one());
+            for row in 0..ED25519_VERIFY_ROWS {
+                let mut values = zeroed_f_vec(NUM_COLS);
+                let cols: &mut Ed25519VerifyCols<F> = values.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.key_ptr = F::from_canonical_u32(event.key_ptr);
+                cols.sig_ptr = F::from_canonical_u32(event.sig_ptr);
+                cols.step[row] = F::one();
+                cols.is_valid = F::from_bool(event.is_valid);
+
+                acc = Self::populate_row(
+                    &mut new_byte_lookup_events,
+                    event,
+                    &scalars,
+                    &table,
+                    row,
+                    cols,
+                    &acc,
+                );
+
+                rows.push(values);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut values = zeroed_f_vec(NUM_COLS);
+                let cols: &mut Ed25519VerifyCols<F> = values.as_mut_slice().borrow_mut();
+                Self::populate_padding(cols);
+                values
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Ed25519VerifyCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::ED25519_VERIFY).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Ed25519VerifyChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Ed25519VerifyChip
+where
+    AB: SP1AirBuilder,
+    FieldLimbs<AB::Var>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Ed25519VerifyCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Ed25519VerifyCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_valid);
+        for &step in local.step.iter() {
+            builder.assert_bool(step);
+        }
+
+        // Every real row has exactly one step.
+        let step_sum = local.step.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(step_sum, local.is_real);
+        let (is_first, is_last) = (local.step[0], local.step[LAST_ROW]);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(is_first);
+        builder.when_last_row().when(local.is_real).assert_one(is_last);
+        builder.when_transition().when(is_last).assert_eq(next.step[0], next.is_real);
+
+        // The rows of an event share the arguments, `R` and the table, go through the steps in
+        // order, and shift the bits of the scalars.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.key_ptr, next.key_ptr);
+        continuation.assert_eq(local.sig_ptr, next.sig_ptr);
+        continuation.assert_eq(local.is_valid, next.is_valid);
+        continuation.assert_zero(next.step[0]);
+        for k in 0..LAST_ROW {
+            continuation.assert_eq(local.step[k], next.step[k + 1]);
+        }
+        continuation.assert_all_eq(local.r, next.r);
+        let (local_table, next_table) = (&local.table, &next.table);
+        continuation.assert_all_eq(local_table.neg_key_x, next_table.neg_key_x);
+        continuation.assert_all_eq(local_table.key_y, next_table.key_y);
+        continuation.assert_all_eq(local_table.base_minus_key_x, next_table.base_minus_key_x);
+        continuation.assert_all_eq(local_table.base_minus_key_y, next_table.base_minus_key_y);
+        for (local_scalar, next_scalar) in local.scalars.iter().zip(next.scalars.iter()) {
+            for i in 0..NUM_LIMBS {
+                continuation.assert_eq(next_scalar.digits[i], shifted_digit::<AB>(local_scalar, i));
+            }
+            continuation.assert_eq(
+                next_scalar.digits[NUM_LIMBS],
+                local_scalar.shift_carries[NUM_LIMBS - 1],
+            );
+        }
+
+        // The first row reads the public key and the signature.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.key_ptr,
+            &local.key_memory,
+            is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.sig_ptr,
+            &local.sig_memory,
+            is_first,
+        );
+        let sig_bytes =
+            local.sig_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+        builder.when(is_first).assert_all_eq(local.r, sig_bytes[..NUM_LIMBS].to_vec());
+
+        eval_setup::<AB>(builder, local);
+        eval_scalars::<AB>(builder, local);
+
+        // Select the point of the row from the bits, which adds the identity, `-A`, `B` or
+        // `B - A`.
+        let ladder = &local.ladder;
+        let (s_bit, k_bit) =
+            (local.scalars[0].digits[NUM_LIMBS], local.scalars[1].digits[NUM_LIMBS]);
+        builder.assert_eq(ladder.both_bits, s_bit * k_bit);
+        let is_identity: AB::Expr = local.is_real - s_bit - k_bit + ladder.both_bits;
+        let is_neg_key: AB::Expr = k_bit - ladder.both_bits;
+        let is_base: AB::Expr = s_bit - ladder.both_bits;
+        let (base_x, base_y) = Ed25519Parameters::generator();
+        let base_x = BaseField::to_limbs_field::<AB::F, _>(&base_x);
+        let base_y = BaseField::to_limbs_field::<AB::F, _>(&base_y);
+        for i in 0..NUM_LIMBS {
+            builder.assert_eq(
+                ladder.point_x[i],
+                is_neg_key.clone() * local.table.neg_key_x[i]
+                    + is_base.clone() * base_x[i]
+                    + ladder.both_bits * local.table.base_minus_key_x[i],
+            );
+            let identity_y: AB::Expr = if i == 0 { is_identity.clone() } else { AB::Expr::zero() };
+            builder.assert_eq(
+                ladder.point_y[i],
+                identity_y
+                    + is_neg_key.clone() * local.table.key_y[i]
+                    + is_base.clone() * base_y[i]
+                    + ladder.both_bits * local.table.base_minus_key_y[i],
+            );
+        }
+
+        // Double the accumulator and add the point.
+        let (acc_x, acc_y): (Polynomial<AB::Expr>, Polynomial<AB::Expr>) =
+            (ladder.acc_x.into(), ladder.acc_y.into());
+        eval_add::<AB>(builder, &ladder.double, (&acc_x, &acc_y), (&acc_x, &acc_y), local.is_real);
+        eval_add::<AB>(
+            builder,
+            &ladder.add,
+            (&ladder.double.x3_ins.result.into(), &ladder.double.y3_ins.result.into()),
+            (&ladder.point_x.into(), &ladder.point_y.into()),
+            local.is_real,
+        );
+
+        // The accumulator starts from the identity, and every row passes its result to the next.
+        for i in 0..NUM_LIMBS {
+            builder.when(is_first).assert_zero(ladder.acc_x[i]);
+            if i == 0 {
+                builder.when(is_first).assert_one(ladder.acc_y[i]);
+            } else {
+                builder.when(is_first).assert_zero(ladder.acc_y[i]);
+            }
+        }
+        let (x, y) = (ladder.add.x3_ins.result, ladder.add.y3_ins.result);
+        let mut chain = builder.when_transition();
+        let mut chain = chain.when(local.is_real - is_last);
+        chain.assert_all_eq(next.ladder.acc_x, x);
+        chain.assert_all_eq(next.ladder.acc_y, y);
+
+        // The last row compresses the reduced result, and compares it to `R`.
+        let output = &local.output;
+        let modulus = limbs_from_vec::<AB::Expr, <BaseField as NumLimbs>::Limbs, AB::F>(
+            BaseField::to_limbs_field_vec(&BaseField::modulus()),
+        );
+        output.x_range_check.eval(builder, &x, &modulus, is_last);
+        output.y_range_check.eval(builder, &y, &modulus, is_last);
+        builder.when(is_last).assert_bool(output.x_parity);
+        builder.slice_range_check_u8(&[output.x_half], is_last);
+        builder
+            .when(is_last)
+            .assert_eq(x[0], output.x_half * AB::F::from_canonical_u32(2) + output.x_parity);
+        let diff_sum = (0..NUM_LIMBS).fold(AB::Expr::zero(), |acc, i| {
+            let compressed: AB::Expr = if i == NUM_LIMBS - 1 {
+                y[i] + output.x_parity * AB::F::from_canonical_u32(1 << 7)
+            } else {
+                y[i].into()
+            };
+            let diff = compressed - local.r[i];
+            acc + diff.clone() * diff
+        });
+        builder.when(is_last).assert_eq(output.diff_sum, diff_sum);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            output.diff_sum.into(),
+            output.diff_is_zero,
+            is_last.into(),
+        );
+        builder.when(is_last).assert_eq(local.is_valid, output.diff_is_zero.result);
+
+        // Receive the arguments on the first row of the event, with the status of the call.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::Expr::from_canonical_u32(SyscallCode::ED25519_VERIFY.syscall_id())
+                + (AB::Expr::one() - local.is_valid) * AB::F::from_canonical_u32(1 << 8),
+            local.key_ptr,
+            local.sig_ptr,
+            is_first,
+            InteractionScope::Local,
+        );
+    }
+}
+
+/// The byte `i` of the digits of the next row, which are shifted left by one bit.
+fn shifted_digit<AB: SP1AirBuilder>(
+    scalar: &Ed25519VerifyScalarCols<AB::Var>,
+    i: usize,
+) -> AB::Expr {
+    let carry_in: AB::Expr =
+        if i == 0 { AB::Expr::zero() } else { scalar.shift_carries[i - 1].into() };
+    scalar.digits[i] * AB::F::from_canonical_u32(2) + carry_in
+        - scalar.shift_carries[i] * AB::F::from_canonical_u32(1 << 8)
+}
+
+/// Constrains the digits of the scalars to be bits of registers shifted by one bit per row, which
+/// start on the first row as `S` and `k`.
+fn eval_scalars<AB: SP1AirBuilder>(builder: &mut AB, local: &Ed25519VerifyCols<AB::Var>)
+where
+    FieldLimbs<AB::Var>: Copy,
+{
+    let is_first = local.step[0];
+    let s: FieldLimbs<AB::Var> = limbs_from_access(&local.sig_memory[8..16]);
+    let scalars = [s, local.setup.k.result];
+    for (scalar, value) in local.scalars.iter().zip(scalars) {
+        builder.assert_bool(scalar.digits[NUM_LIMBS]);
+        for &carry in scalar.shift_carries.iter() {
+            builder.assert_bool(carry);
+        }
+        builder.slice_range_check_u8(&scalar.digits[..NUM_LIMBS], local.is_real);
+
+        builder.when(is_first).assert_all_eq(scalar.digits[..NUM_LIMBS].to_vec(), value);
+        builder.when(is_first).assert_zero(scalar.digits[NUM_LIMBS]);
+    }
+}
+
+/// Constrains the operations of the first row: the check of `S`, the scalar `k`, and the points
+/// `-A` and `B - A` written to the table.
+fn eval_setup<AB: SP1AirBuilder>(builder: &mut AB, local: &Ed25519VerifyCols<AB::Var>)
+where
+    FieldLimbs<AB::Var>: Copy,
+{
+    let setup = &local.setup;
+    let is_first = local.step[0];
+    let constant = |value: &BigUint| -> Polynomial<AB::Expr> {
+        BaseField::to_limbs_field::<AB::F, _>(value)
+            .into_iter()
+            .map(|limb| AB::Expr::from(is_first) * limb)
+            .collect()
+    };
+    let zero: Polynomial<AB::Expr> = Polynomial::from_coefficients(&[AB::Expr::zero()]);
+
+    let key_x: FieldLimbs<AB::Var> = limbs_from_access(&local.key_memory[0..8]);
+    let key_y: FieldLimbs<AB::Var> = limbs_from_access(&local.key_memory[8..16]);
+    let s: FieldLimbs<AB::Var> = limbs_from_access(&local.sig_memory[8..16]);
+    let h_low: FieldLimbs<AB::Var> = limbs_from_access(&local.sig_memory[16..24]);
+    let h_high: FieldLimbs<AB::Var> = limbs_from_access(&local.sig_memory[24..32]);
+
+    // Check that `S` is reduced, and compute `k` as the reduced digest.
+    let order = limbs_from_vec::<AB::Expr, <ScalarField as NumLimbs>::Limbs, AB::F>(
+        ScalarField::to_limbs_field_vec(&ScalarField::modulus()),
+    );
+    setup.s_range_check.eval(builder, &s, &order, is_first);
+    setup.k_high.eval(
+        builder,
+        &h_high,
+        &constant(&two_pow_256_mod_order()),
+        FieldOperation::Mul,
+        is_first,
+    );
+    setup.k.eval(builder, &setup.k_high.result, &h_low, FieldOperation::Add, is_first);
+    setup.k_range_check.eval(builder, &setup.k.result, &order, is_first);
+
+    // Compute `-A` and `B - A`.
+    setup.neg_key_x.eval(builder, &zero, &key_x, FieldOperation::Sub, is_first);
+    let (base_x, base_y) = Ed25519Parameters::generator();
+    eval_add::<AB>(
+        builder,
+        &setup.base_minus_key,
+        (&constant(&base_x), &constant(&base_y)),
+        (&setup.neg_key_x.result.into(), &key_y.into()),
+        is_first,
+    );
+
+    // Write the points to the table.
+    let table = &local.table;
+    builder.when(is_first).assert_all_eq(table.neg_key_x, setup.neg_key_x.result);
+    builder.when(is_first).assert_all_eq(table.key_y, key_y);
+    builder
+        .when(is_first)
+        .assert_all_eq(table.base_minus_key_x, setup.base_minus_key.x3_ins.result);
+    builder
+        .when(is_first)
+        .assert_all_eq(table.base_minus_key_y, setup.base_minus_key.y3_ins.result);
+}
+
+/// Constrains the sum of two points with the complete addition formulas of the curve.
+fn eval_add<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    cols: &EdwardsAddCols<AB::Var>,
+    (x1, y1): (&Polynomial<AB::Expr>, &Polynomial<AB::Expr>),
+    (x2, y2): (&Polynomial<AB::Expr>, &Polynomial<AB::Expr>),
+    is_real: AB::Var,
+) where
+    FieldLimbs<AB::Var>: Copy,
+{
+    // x3_numerator = x1 * y2 + x2 * y1.
+    cols.x3_numerator.eval(builder, &[x1.clone(), x2.clone()], &[y2.clone(), y1.clone()], is_real);
+
+    // y3_numerator = y1 * y2 + x1 * x2.
+    cols.y3_numerator.eval(builder, &[y1.clone(), x1.clone()], &[y2.clone(), x2.clone()], is_real);
+
+    // f = x1 * x2 * y1 * y2.
+    cols.x1_mul_y1.eval(builder, x1, y1, FieldOperation::Mul, is_real);
+    cols.x2_mul_y2.eval(builder, x2, y2, FieldOperation::Mul, is_real);
+    cols.f.eval(
+        builder,
+        &cols.x1_mul_y1.result,
+        &cols.x2_mul_y2.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+
+    // d * f.
+    let d = BaseField::to_limbs_field::<AB::Expr, _>(&Ed25519Parameters::d_biguint());
+    cols.d_mul_f.eval(builder, &cols.f.result, &d, FieldOperation::Mul, is_real);
+
+    // x3 = x3_numerator / (1 + d * f), and y3 = y3_numerator / (1 - d * f).
+    cols.x3_ins.eval(builder, &cols.x3_numerator.result, &cols.d_mul_f.result, true, is_real);
+    cols.y3_ins.eval(builder, &cols.y3_numerator.result, &cols.d_mul_f.result, false, is_real);
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::ED25519_VERIFY_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_ed25519_verify() {
+        utils::setup_logger();
+        let program = Program::from(ED25519_VERIFY_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
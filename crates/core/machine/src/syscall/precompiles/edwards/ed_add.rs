@@ -19,9 +19,9 @@ use sp1_core_executor::{
     ExecutionRecord, Program,
 };
 use sp1_curves::{
-    edwards::{ed25519::Ed25519BaseField, EdwardsParameters, NUM_LIMBS, WORDS_CURVE_POINT},
+    edwards::{EdwardsParameters, NUM_LIMBS, WORDS_CURVE_POINT},
     params::{FieldParameters, Limbs, NumLimbs},
-    AffinePoint, EllipticCurve,
+    AffinePoint, CurveType, EllipticCurve,
 };
 use sp1_derive::AlignedBorrow;
 use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
@@ -34,14 +34,17 @@ use crate::{
     utils::{limbs_from_prev_access, pad_rows_fixed},
 };
 
-pub const NUM_ED_ADD_COLS: usize = size_of::<EdAddAssignCols<u8>>();
+pub const fn num_ed_add_cols<P: FieldParameters + NumLimbs>() -> usize {
+    size_of::<EdAddAssignCols<u8, P>>()
+}
 
 /// A set of columns to compute `EdAdd` where a, b are field elements.
 /// Right now the number of limbs is assumed to be a constant, although this could be macro-ed
-/// or made generic in the future.
+/// or made generic in the future. The field arithmetic is generic over the base field `P`, which
+/// must fit in the same number of limbs as the Ed25519 base field.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
-pub struct EdAddAssignCols<T> {
+pub struct EdAddAssignCols<T, P: FieldParameters + NumLimbs> {
     pub is_real: T,
     pub shard: T,
     pub clk: T,
@@ -50,14 +53,14 @@ pub struct EdAddAssignCols<T> {
     pub q_ptr: T,
     pub p_access: [MemoryWriteCols<T>; WORDS_CURVE_POINT],
     pub q_access: [MemoryReadCols<T>; WORDS_CURVE_POINT],
-    pub(crate) x3_numerator: FieldInnerProductCols<T, Ed25519BaseField>,
-    pub(crate) y3_numerator: FieldInnerProductCols<T, Ed25519BaseField>,
-    pub(crate) x1_mul_y1: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) x2_mul_y2: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) f: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) d_mul_f: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) x3_ins: FieldDenCols<T, Ed25519BaseField>,
-    pub(crate) y3_ins: FieldDenCols<T, Ed25519BaseField>,
+    pub(crate) x3_numerator: FieldInnerProductCols<T, P>,
+    pub(crate) y3_numerator: FieldInnerProductCols<T, P>,
+    pub(crate) x1_mul_y1: FieldOpCols<T, P>,
+    pub(crate) x2_mul_y2: FieldOpCols<T, P>,
+    pub(crate) f: FieldOpCols<T, P>,
+    pub(crate) d_mul_f: FieldOpCols<T, P>,
+    pub(crate) x3_ins: FieldDenCols<T, P>,
+    pub(crate) y3_ins: FieldDenCols<T, P>,
 }
 
 #[derive(Default)]
@@ -70,11 +73,21 @@ impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
         Self { _marker: PhantomData }
     }
 
+    /// The syscall code of the add precompile for the curve `E`.
+    fn syscall_code() -> SyscallCode {
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => SyscallCode::ED_ADD,
+            CurveType::Jubjub => SyscallCode::JUBJUB_ADD,
+            CurveType::BabyJubjub => SyscallCode::BABY_JUBJUB_ADD,
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn populate_field_ops<F: PrimeField32>(
         record: &mut impl ByteRecord,
         shard: u32,
-        cols: &mut EdAddAssignCols<F>,
+        cols: &mut EdAddAssignCols<F, E::BaseField>,
         p_x: BigUint,
         p_y: BigUint,
         q_x: BigUint,
@@ -110,7 +123,12 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     type Program = Program;
 
     fn name(&self) -> String {
-        "EdAddAssign".to_string()
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => "EdAddAssign".to_string(),
+            CurveType::Jubjub => "JubjubAddAssign".to_string(),
+            CurveType::BabyJubjub => "BabyJubjubAddAssign".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
     }
 
     fn generate_trace(
@@ -118,19 +136,20 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
         input: &ExecutionRecord,
         _: &mut ExecutionRecord,
     ) -> RowMajorMatrix<F> {
-        let events = input.get_precompile_events(SyscallCode::ED_ADD);
+        let events = input.get_precompile_events(Self::syscall_code());
 
         let mut rows = events
             .par_iter()
             .map(|(_, event)| {
-                let event = if let PrecompileEvent::EdAdd(event) = event {
-                    event
-                } else {
-                    unreachable!();
+                let event = match event {
+                    PrecompileEvent::EdAdd(event)
+                    | PrecompileEvent::JubjubAdd(event)
+                    | PrecompileEvent::BabyJubjubAdd(event) => event,
+                    _ => unreachable!(),
                 };
 
-                let mut row = [F::zero(); NUM_ED_ADD_COLS];
-                let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+                let mut row = vec![F::zero(); num_ed_add_cols::<E::BaseField>()];
+                let cols: &mut EdAddAssignCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
                 let mut blu = Vec::new();
                 self.event_to_row(event, cols, &mut blu);
                 row
@@ -140,8 +159,8 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
         pad_rows_fixed(
             &mut rows,
             || {
-                let mut row = [F::zero(); NUM_ED_ADD_COLS];
-                let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+                let mut row = vec![F::zero(); num_ed_add_cols::<E::BaseField>()];
+                let cols: &mut EdAddAssignCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
                 let zero = BigUint::zero();
                 Self::populate_field_ops(
                     &mut vec![],
@@ -158,13 +177,14 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
         );
 
         // Convert the trace to a row major matrix.
+        let num_cols = num_ed_add_cols::<E::BaseField>();
         let mut trace =
-            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_ED_ADD_COLS);
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_cols);
 
         // Write the nonces to the trace.
         for i in 0..trace.height() {
-            let cols: &mut EdAddAssignCols<F> =
-                trace.values[i * NUM_ED_ADD_COLS..(i + 1) * NUM_ED_ADD_COLS].borrow_mut();
+            let cols: &mut EdAddAssignCols<F, E::BaseField> =
+                trace.values[i * num_cols..(i + 1) * num_cols].borrow_mut();
             cols.nonce = F::from_canonical_usize(i);
         }
 
@@ -172,7 +192,7 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     }
 
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
-        let events = input.get_precompile_events(SyscallCode::ED_ADD);
+        let events = input.get_precompile_events(Self::syscall_code());
         let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
 
         let blu_batches = events
@@ -180,14 +200,16 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
             .map(|events| {
                 let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
                 events.iter().for_each(|(_, event)| {
-                    let event = if let PrecompileEvent::EdAdd(event) = event {
-                        event
-                    } else {
-                        unreachable!();
+                    let event = match event {
+                        PrecompileEvent::EdAdd(event)
+                        | PrecompileEvent::JubjubAdd(event)
+                        | PrecompileEvent::BabyJubjubAdd(event) => event,
+                        _ => unreachable!(),
                     };
 
-                    let mut row = [F::zero(); NUM_ED_ADD_COLS];
-                    let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+                    let mut row = vec![F::zero(); num_ed_add_cols::<E::BaseField>()];
+                    let cols: &mut EdAddAssignCols<F, E::BaseField> =
+                        row.as_mut_slice().borrow_mut();
                     self.event_to_row(event, cols, &mut blu);
                 });
                 blu
@@ -201,7 +223,7 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
         if let Some(shape) = shard.shape.as_ref() {
             shape.included::<F, _>(self)
         } else {
-            !shard.get_precompile_events(SyscallCode::ED_ADD).is_empty()
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
         }
     }
 }
@@ -211,7 +233,7 @@ impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
     fn event_to_row<F: PrimeField32>(
         &self,
         event: &EllipticCurveAddEvent,
-        cols: &mut EdAddAssignCols<F>,
+        cols: &mut EdAddAssignCols<F, E::BaseField>,
         blu: &mut impl ByteRecord,
     ) {
         // Decode affine points.
@@ -243,7 +265,7 @@ impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
 
 impl<F, E: EllipticCurve + EdwardsParameters> BaseAir<F> for EdAddAssignChip<E> {
     fn width(&self) -> usize {
-        NUM_ED_ADD_COLS
+        num_ed_add_cols::<E::BaseField>()
     }
 }
 
@@ -254,21 +276,21 @@ where
     fn eval(&self, builder: &mut AB) {
         let main = builder.main();
         let local = main.row_slice(0);
-        let local: &EdAddAssignCols<AB::Var> = (*local).borrow();
+        let local: &EdAddAssignCols<AB::Var, E::BaseField> = (*local).borrow();
         let next = main.row_slice(1);
-        let next: &EdAddAssignCols<AB::Var> = (*next).borrow();
+        let next: &EdAddAssignCols<AB::Var, E::BaseField> = (*next).borrow();
 
         // Constrain the incrementing nonce.
         builder.when_first_row().assert_zero(local.nonce);
         builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
 
-        let x1: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+        let x1: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_prev_access(&local.p_access[0..8]);
-        let x2: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+        let x2: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_prev_access(&local.q_access[0..8]);
-        let y1: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+        let y1: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_prev_access(&local.p_access[8..16]);
-        let y2: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+        let y2: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_prev_access(&local.q_access[8..16]);
 
         // x3_numerator = x1 * y2 + x2 * y1.
@@ -329,7 +351,7 @@ where
             local.shard,
             local.clk,
             local.nonce,
-            AB::F::from_canonical_u32(SyscallCode::ED_ADD.syscall_id()),
+            AB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
             local.p_ptr,
             local.q_ptr,
             local.is_real,
@@ -342,7 +364,7 @@ where
 mod tests {
     use sp1_core_executor::Program;
     use sp1_stark::CpuProver;
-    use test_artifacts::{ED25519_ELF, ED_ADD_ELF};
+    use test_artifacts::{BABY_JUBJUB_ELF, ED25519_ELF, ED_ADD_ELF, JUBJUB_ELF};
 
     use crate::utils;
 
@@ -359,4 +381,18 @@ mod tests {
         let program = Program::from(ED25519_ELF).unwrap();
         utils::run_test::<CpuProver<_, _>>(program).unwrap();
     }
+
+    #[test]
+    fn test_jubjub_program() {
+        utils::setup_logger();
+        let program = Program::from(JUBJUB_ELF).unwrap();
+        utils::run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_baby_jubjub_program() {
+        utils::setup_logger();
+        let program = Program::from(BABY_JUBJUB_ELF).unwrap();
+        utils::run_test::<CpuProver<_, _>>(program).unwrap();
+    }
 }
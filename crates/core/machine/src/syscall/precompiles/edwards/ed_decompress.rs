@@ -39,6 +39,9 @@ pub const NUM_ED_DECOMPRESS_COLS: usize = size_of::<EdDecompressCols<u8>>();
 /// compressed Y (without sign bit).
 ///
 /// After `EdDecompress`, the first 32 bytes of the slice are overwritten with the decompressed X.
+/// If there is no point with the compressed Y, the syscall fails and the slice is left as it was.
+/// Failure is proven by a square root of `2 * u / v` and an inverse of it, which exist exactly when
+/// `u / v` has no square root since 2 is not a square in the field.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
 pub struct EdDecompressCols<T> {
@@ -48,6 +51,7 @@ pub struct EdDecompressCols<T> {
     pub nonce: T,
     pub ptr: T,
     pub sign: T,
+    pub is_invalid: T,
     pub x_access: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
     pub y_access: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
     pub(crate) y_range: FieldLtCols<T, Ed25519BaseField>,
@@ -56,6 +60,8 @@ pub struct EdDecompressCols<T> {
     pub(crate) dyy: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) v: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) u_div_v: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) sqrt_input: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) sqrt_input_inverse: FieldOpCols<T, Ed25519BaseField>,
     pub(crate) x: FieldSqrtCols<T, Ed25519BaseField>,
     pub(crate) neg_x: FieldOpCols<T, Ed25519BaseField>,
 }
@@ -75,13 +81,14 @@ impl<F: PrimeField32> EdDecompressCols<F> {
             record.nonce_lookup.get(event.lookup_id.0 as usize).copied().unwrap_or_default(),
         );
         self.sign = F::from_bool(event.sign);
+        self.is_invalid = F::from_bool(event.is_invalid);
         for i in 0..8 {
             self.x_access[i].populate(event.x_memory_records[i], &mut new_byte_lookup_events);
             self.y_access[i].populate(event.y_memory_records[i], &mut new_byte_lookup_events);
         }
 
         let y = &BigUint::from_bytes_le(&event.y_bytes);
-        self.populate_field_ops::<E>(&mut new_byte_lookup_events, event.shard, y);
+        self.populate_field_ops::<E>(&mut new_byte_lookup_events, event.shard, y, event.is_invalid);
 
         record.add_byte_lookup_events(new_byte_lookup_events);
     }
@@ -91,6 +98,7 @@ impl<F: PrimeField32> EdDecompressCols<F> {
         blu_events: &mut Vec<ByteLookupEvent>,
         shard: u32,
         y: &BigUint,
+        is_invalid: bool,
     ) {
         let one = BigUint::one();
         self.y_range.populate(blu_events, shard, y, &Ed25519BaseField::modulus());
@@ -99,7 +107,17 @@ impl<F: PrimeField32> EdDecompressCols<F> {
         let dyy = self.dyy.populate(blu_events, shard, &E::d_biguint(), &yy, FieldOperation::Mul);
         let v = self.v.populate(blu_events, shard, &one, &dyy, FieldOperation::Add);
         let u_div_v = self.u_div_v.populate(blu_events, shard, &u, &v, FieldOperation::Div);
-        let x = self.x.populate(blu_events, shard, &u_div_v, ed25519_sqrt);
+        let multiplier = BigUint::from(1 + u32::from(is_invalid));
+        let sqrt_input =
+            self.sqrt_input.populate(blu_events, shard, &u_div_v, &multiplier, FieldOperation::Mul);
+        self.sqrt_input_inverse.populate(
+            blu_events,
+            shard,
+            &BigUint::from(u32::from(is_invalid)),
+            &sqrt_input,
+            FieldOperation::Div,
+        );
+        let x = self.x.populate(blu_events, shard, &sqrt_input, ed25519_sqrt);
         self.neg_x.populate(blu_events, shard, &BigUint::zero(), &x, FieldOperation::Sub);
     }
 }
@@ -112,6 +130,8 @@ impl<V: Copy> EdDecompressCols<V> {
         V: Into<AB::Expr>,
     {
         builder.assert_bool(self.sign);
+        builder.assert_bool(self.is_invalid);
+        let is_valid: AB::Expr = self.is_real.into() - self.is_invalid.into();
 
         let y: Limbs<V, U32> = limbs_from_prev_access(&self.y_access);
         let max_num_limbs = P::to_limbs_field_vec(&Ed25519BaseField::modulus());
@@ -146,7 +166,22 @@ impl<V: Copy> EdDecompressCols<V> {
             FieldOperation::Div,
             self.is_real,
         );
-        self.x.eval(builder, &self.u_div_v.result, AB::F::zero(), self.is_real);
+        self.sqrt_input.eval(
+            builder,
+            &self.u_div_v.result,
+            &[AB::Expr::one() + self.is_invalid.into()].iter(),
+            FieldOperation::Mul,
+            self.is_real,
+        );
+        // If the point is invalid, the input of the square root is not zero.
+        self.sqrt_input_inverse.eval(
+            builder,
+            &[self.is_invalid].iter(),
+            &self.sqrt_input.result,
+            FieldOperation::Div,
+            self.is_real,
+        );
+        self.x.eval(builder, &self.sqrt_input.result, AB::F::zero(), self.is_real);
         self.neg_x.eval(
             builder,
             &[AB::Expr::zero()].iter(),
@@ -170,19 +205,23 @@ impl<V: Copy> EdDecompressCols<V> {
             self.is_real,
         );
 
-        // Constrain that the correct result is written into x.
+        // Constrain that the correct result is written into x, or that x is left as it was if the
+        // point is invalid.
         let x_limbs: Limbs<V, U32> = limbs_from_access(&self.x_access);
-        builder.when(self.is_real).when(self.sign).assert_all_eq(self.neg_x.result, x_limbs);
+        builder.when(is_valid.clone()).when(self.sign).assert_all_eq(self.neg_x.result, x_limbs);
         builder
-            .when(self.is_real)
+            .when(is_valid)
             .when_not(self.sign)
             .assert_all_eq(self.x.multiplication.result, x_limbs);
+        let prev_x_limbs: Limbs<V, U32> = limbs_from_prev_access(&self.x_access);
+        builder.when(self.is_invalid).assert_all_eq(prev_x_limbs, x_limbs);
 
         builder.receive_syscall(
             self.shard,
             self.clk,
             self.nonce,
-            AB::F::from_canonical_u32(SyscallCode::ED_DECOMPRESS.syscall_id()),
+            AB::Expr::from_canonical_u32(SyscallCode::ED_DECOMPRESS.syscall_id())
+                + self.is_invalid.into() * AB::F::from_canonical_u32(1 << 8),
             self.ptr,
             self.sign,
             self.is_real,
@@ -238,7 +277,7 @@ impl<F: PrimeField32, E: EdwardsParameters> MachineAir<F> for EdDecompressChip<E
                 let mut row = [F::zero(); NUM_ED_DECOMPRESS_COLS];
                 let cols: &mut EdDecompressCols<F> = row.as_mut_slice().borrow_mut();
                 let zero = BigUint::zero();
-                cols.populate_field_ops::<E>(&mut vec![], 0, &zero);
+                cols.populate_field_ops::<E>(&mut vec![], 0, &zero, false);
                 row
             },
             input.fixed_log2_rows::<F, _>(self),
@@ -1,5 +1,7 @@
+mod ed25519_verify;
 mod ed_add;
 mod ed_decompress;
 
+pub use ed25519_verify::*;
 pub use ed_add::*;
 pub use ed_decompress::*;
@@ -0,0 +1,363 @@
+use core::{
+    array,
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, F64OpEvent, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, Polynomial, SP1AirBuilder};
+
+use super::{
+    eval_remainder, exponent, limbs_poly, mantissa, mantissa_limbs, populate_remainder,
+    select_shift, selected_power, selected_shift, shifted, F64RoundCols, F64SyscallCols,
+    LimbIdentityCols, REMAINDER_BITS,
+};
+use crate::{
+    air::WordAirBuilder,
+    utils::{pad_rows_fixed, zeroed_f_vec},
+};
+
+/// The number of columns in the F64AddCols.
+const NUM_COLS: usize = size_of::<F64AddCols<u8>>();
+
+/// The bit of the normalized sum that holds the top bit of the kept mantissa.
+const NORMALIZED_TOP_BIT: u32 = REMAINDER_BITS + 52;
+
+/// A chip that computes `x = x + y` for doubles.
+#[derive(Default)]
+pub struct F64AddChip;
+
+impl F64AddChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the F64Add operation.
+///
+/// The mantissa of the operand with the larger magnitude is shifted up by the difference of the
+/// exponents, capped to 55 since every bit of the other operand then only affects the rounding
+/// through the sticky bits. The other mantissa is added or subtracted, and the sum is shifted up so
+/// that its top bit is at bit 107, leaving 55 bits below the kept mantissa.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct F64AddCols<T> {
+    pub syscall: F64SyscallCols<T>,
+
+    /// Whether `b` has a larger magnitude than `a`, in which case the operands are swapped.
+    pub is_swapped: T,
+
+    /// Whether the signs of the operands differ, in which case the mantissas are subtracted.
+    pub is_subtraction: T,
+
+    /// The exponent of the larger operand minus the exponent of the smaller one.
+    pub exponent_difference: T,
+
+    /// Whether the exponent difference is at least 55, in which case the alignment is capped.
+    pub is_far: T,
+
+    /// The bits of the exponent difference minus 55, when the alignment is capped.
+    pub far_difference_bits: [T; 11],
+
+    /// The mantissas of the larger and the smaller operand.
+    pub big_mantissa: [T; 7],
+    pub small_mantissa: [T; 7],
+
+    /// One-hot flags for the byte offset and the bit offset of the alignment shift.
+    pub align_byte: [T; 7],
+    pub align_bit: [T; 8],
+
+    /// The aligned sum of the mantissas, in byte limbs.
+    pub sum: [T; 14],
+
+    /// Proves `big_mantissa * 2^align ± small_mantissa = sum`.
+    pub sum_identity: LimbIdentityCols<T, 13>,
+
+    /// One-hot flags for the byte offset and the bit offset of the normalization shift.
+    pub normalize_byte: [T; 14],
+    pub normalize_bit: [T; 8],
+
+    /// The bits of the normalized sum below the kept mantissa, as 55 bits in byte limbs.
+    pub remainder: [T; 7],
+
+    /// Proves `sum * 2^normalize = q * 2^55 + remainder`, where `q` is the unrounded mantissa.
+    pub normalize_identity: LimbIdentityCols<T, 26>,
+
+    pub round: F64RoundCols<T>,
+}
+
+impl<T: Copy> F64AddCols<T> {
+    fn sum_vanishing<E: AbstractField>(&self) -> Polynomial<E>
+    where
+        T: Into<E>,
+    {
+        let aligned = &limbs_poly::<T, E>(&self.big_mantissa)
+            * &selected_power(&self.align_byte, &self.align_bit);
+        let sign = E::one() - self.is_subtraction.into() * E::two();
+        aligned + &(limbs_poly(&self.small_mantissa) * sign) - &limbs_poly(&self.sum)
+    }
+
+    fn normalize_vanishing<E: AbstractField>(&self) -> Polynomial<E>
+    where
+        T: Into<E>,
+    {
+        let normalized = &limbs_poly::<T, E>(&self.sum)
+            * &selected_power(&self.normalize_byte, &self.normalize_bit);
+        let unrounded = self.round.unrounded_mantissa::<E>(&self.syscall.c);
+        normalized
+            - &shifted(unrounded, 6, E::from_canonical_u32(128))
+            - &limbs_poly(&self.remainder)
+    }
+}
+
+impl F64AddChip {
+    fn populate<F: PrimeField32>(
+        cols: &mut F64AddCols<F>,
+        event: &F64OpEvent,
+        blu: &mut impl ByteRecord,
+    ) {
+        let Some((a, b, c)) = cols.syscall.populate(event, blu) else {
+            cols.sum_identity.populate_unused();
+            cols.normalize_identity.populate_unused();
+            return;
+        };
+
+        // Normal doubles of the same sign compare as integers.
+        let magnitude = |x: u64| x & !(1 << 63);
+        let is_swapped = magnitude(a) < magnitude(b);
+        let (big, small) = if is_swapped { (b, a) } else { (a, b) };
+        let difference = exponent(big) - exponent(small);
+        let is_far = difference >= REMAINDER_BITS;
+        let align = difference.min(REMAINDER_BITS);
+        let is_subtraction = (a ^ b) >> 63 == 1;
+        cols.is_swapped = F::from_bool(is_swapped);
+        cols.is_subtraction = F::from_bool(is_subtraction);
+        cols.exponent_difference = F::from_canonical_u32(difference);
+        cols.is_far = F::from_bool(is_far);
+        if is_far {
+            let far_difference = difference - REMAINDER_BITS;
+            cols.far_difference_bits =
+                array::from_fn(|i| F::from_canonical_u32((far_difference >> i) & 1));
+        }
+        cols.big_mantissa = mantissa_limbs(mantissa(big));
+        cols.small_mantissa = mantissa_limbs(mantissa(small));
+        select_shift(&mut cols.align_byte, &mut cols.align_bit, align);
+
+        let aligned = u128::from(mantissa(big)) << align;
+        let sum = if is_subtraction {
+            aligned - u128::from(mantissa(small))
+        } else {
+            aligned + u128::from(mantissa(small))
+        };
+        let sum_bytes = sum.to_le_bytes();
+        cols.sum = array::from_fn(|i| F::from_canonical_u8(sum_bytes[i]));
+        blu.add_u8_range_checks(event.shard, &sum_bytes[..14]);
+
+        // The sum is nonzero, since the result is normal.
+        let normalize = NORMALIZED_TOP_BIT - (127 - sum.leading_zeros());
+        select_shift(&mut cols.normalize_byte, &mut cols.normalize_bit, normalize);
+        let normalized = sum << normalize;
+        let q = (normalized >> REMAINDER_BITS) as u64;
+        let remainder = (normalized as u64) & ((1 << REMAINDER_BITS) - 1);
+
+        let (guard, sticky_sum) =
+            populate_remainder(&mut cols.remainder, blu, event.shard, remainder);
+        let rounded = cols.round.populate(blu, event.shard, q, guard, sticky_sum);
+        debug_assert_eq!(rounded, mantissa(c));
+        debug_assert_eq!(
+            exponent(c) + align + normalize,
+            exponent(big) + REMAINDER_BITS + cols.round.carry.as_canonical_u32()
+        );
+
+        let vanishing = cols.sum_vanishing();
+        cols.sum_identity.populate(blu, event.shard, &vanishing);
+        let vanishing = cols.normalize_vanishing();
+        cols.normalize_identity.populate(blu, event.shard, &vanishing);
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for F64AddChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "F64Add".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .get_precompile_events(SyscallCode::F64_ADD)
+            .iter()
+            .map(|(_, event)| {
+                let event =
+                    if let PrecompileEvent::F64Op(event) = event { event } else { unreachable!() };
+                let mut row = zeroed_f_vec(NUM_COLS);
+                let cols: &mut F64AddCols<F> = row.as_mut_slice().borrow_mut();
+                Self::populate(cols, event, &mut new_byte_lookup_events);
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(NUM_COLS);
+                let cols: &mut F64AddCols<F> = row.as_mut_slice().borrow_mut();
+                cols.sum_identity.populate_unused();
+                cols.normalize_identity.populate_unused();
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut F64AddCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.syscall.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::F64_ADD).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for F64AddChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for F64AddChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &F64AddCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &F64AddCols<AB::Var> = (*next).borrow();
+
+        let is_ok = local.syscall.eval(builder, &next.syscall, SyscallCode::F64_ADD);
+        let (a, b, c) = (&local.syscall.a, &local.syscall.b, &local.syscall.c);
+
+        builder.assert_bool(local.is_swapped);
+        builder.assert_bool(local.is_subtraction);
+        builder.assert_bool(local.is_far);
+        for &flag in local
+            .far_difference_bits
+            .iter()
+            .chain(&local.align_byte)
+            .chain(&local.align_bit)
+            .chain(&local.normalize_byte)
+            .chain(&local.normalize_bit)
+        {
+            builder.assert_bool(flag);
+        }
+        for flags in [
+            &local.align_byte[..],
+            &local.align_bit[..],
+            &local.normalize_byte[..],
+            &local.normalize_bit[..],
+        ] {
+            let flag_sum = flags.iter().fold(AB::Expr::zero(), |acc, &flag| acc + flag);
+            builder.when(is_ok.clone()).assert_one(flag_sum);
+        }
+
+        // The mantissas are subtracted when the signs differ.
+        let (sign_a, sign_b) = (a.sign::<AB::Expr>(), b.sign::<AB::Expr>());
+        builder.when(is_ok.clone()).assert_eq(
+            local.is_subtraction,
+            sign_a.clone() + sign_b.clone() - sign_a.clone() * sign_b.clone() * AB::F::two(),
+        );
+
+        // Order the operands. A wrong order makes the exponent difference or the sum negative,
+        // which neither of them can be.
+        let is_swapped: AB::Expr = local.is_swapped.into();
+        for i in 0..7 {
+            let (mantissa_a, mantissa_b) = (a.mantissa[i], b.mantissa[i]);
+            builder.when(is_ok.clone()).assert_eq(
+                local.big_mantissa[i],
+                mantissa_a.into() + is_swapped.clone() * (mantissa_b.into() - mantissa_a.into()),
+            );
+            builder.when(is_ok.clone()).assert_eq(
+                local.small_mantissa[i],
+                mantissa_b.into() + is_swapped.clone() * (mantissa_a.into() - mantissa_b.into()),
+            );
+        }
+        let (exponent_a, exponent_b) = (a.exponent::<AB::Expr>(), b.exponent::<AB::Expr>());
+        builder.when(is_ok.clone()).assert_eq(
+            local.exponent_difference,
+            (exponent_a.clone() - exponent_b.clone())
+                * (AB::Expr::one() - is_swapped.clone() * AB::F::two()),
+        );
+
+        // The alignment is the exponent difference, capped to 55.
+        let align = selected_shift::<AB::Var, AB::Expr>(&local.align_byte, &local.align_bit);
+        builder
+            .when(is_ok.clone())
+            .when_not(local.is_far)
+            .assert_eq(local.exponent_difference, align.clone());
+        builder
+            .when(local.is_far)
+            .assert_eq(align.clone(), AB::F::from_canonical_u32(REMAINDER_BITS));
+        let far_difference = local
+            .far_difference_bits
+            .iter()
+            .enumerate()
+            .map(|(i, &bit)| bit.into() * AB::F::from_canonical_u32(1 << i))
+            .sum::<AB::Expr>();
+        builder.when(local.is_far).assert_eq(
+            local.exponent_difference,
+            far_difference + AB::F::from_canonical_u32(REMAINDER_BITS),
+        );
+
+        // The result has the sign of the larger operand, and its exponent is shifted by the
+        // alignment and the normalization.
+        builder.when(is_ok.clone()).assert_eq(
+            c.sign::<AB::Expr>(),
+            sign_a.clone() + is_swapped.clone() * (sign_b - sign_a),
+        );
+        let normalize =
+            selected_shift::<AB::Var, AB::Expr>(&local.normalize_byte, &local.normalize_bit);
+        builder.when(is_ok.clone()).assert_eq(
+            c.exponent::<AB::Expr>() + align + normalize,
+            exponent_a.clone()
+                + is_swapped * (exponent_b - exponent_a)
+                + AB::F::from_canonical_u32(REMAINDER_BITS)
+                + local.round.carry,
+        );
+
+        builder.slice_range_check_u8(&local.sum, is_ok.clone());
+        let sticky_sum =
+            eval_remainder(builder, &local.remainder, local.round.guard, is_ok.clone());
+        local.round.eval(builder, c, sticky_sum, is_ok.clone());
+        local.sum_identity.eval(builder, &local.sum_vanishing(), is_ok.clone());
+        local.normalize_identity.eval(builder, &local.normalize_vanishing(), is_ok);
+    }
+}
@@ -0,0 +1,244 @@
+use core::{
+    array,
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, F64OpEvent, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, Polynomial, SP1AirBuilder};
+
+use super::{
+    exponent, limbs_poly, mantissa, shifted, F64RoundCols, F64SyscallCols, LimbIdentityCols,
+    EXPONENT_BIAS, FRACTION_BITS,
+};
+use crate::{
+    air::WordAirBuilder,
+    utils::{pad_rows_fixed, zeroed_f_vec},
+};
+
+/// The number of columns in the F64DivCols.
+const NUM_COLS: usize = size_of::<F64DivCols<u8>>();
+
+/// A chip that computes `x = x / y` for doubles.
+#[derive(Default)]
+pub struct F64DivChip;
+
+impl F64DivChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the F64Div operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct F64DivCols<T> {
+    pub syscall: F64SyscallCols<T>,
+
+    /// Whether the mantissa of `a` is less than the mantissa of `b`, so that the quotient is
+    /// computed one bit further.
+    pub is_low: T,
+
+    /// The remainder of the division of the shifted mantissa of `a` by the mantissa of `b`.
+    pub remainder: [T; 7],
+
+    /// The mantissa of `b` minus the remainder minus one, proving that the remainder is less than
+    /// the mantissa of `b`.
+    pub remainder_gap: [T; 7],
+
+    /// Proves `mantissa(a) * 2^(53 + is_low) = (2 * q + guard) * mantissa(b) + remainder`, where
+    /// `q` is the unrounded mantissa.
+    pub quotient: LimbIdentityCols<T, 12>,
+
+    /// Proves `mantissa(b) = remainder + remainder_gap + 1`, or `0 = 0` when the operation is not
+    /// supported.
+    pub gap: LimbIdentityCols<T, 6>,
+
+    pub round: F64RoundCols<T>,
+}
+
+impl<T: Copy> F64DivCols<T> {
+    fn quotient_vanishing<E: AbstractField>(&self) -> Polynomial<E>
+    where
+        T: Into<E>,
+    {
+        let syscall = &self.syscall;
+        let scale = E::from_canonical_u32(32) + self.is_low.into() * E::from_canonical_u32(32);
+        let numerator = shifted(syscall.a.mantissa_poly::<E>(), 6, scale);
+        let quotient =
+            self.round.unrounded_mantissa::<E>(&syscall.c) * E::two() + self.round.guard.into();
+        numerator - &(&quotient * &syscall.b.mantissa_poly()) - &limbs_poly(&self.remainder)
+    }
+
+    fn gap_vanishing<E: AbstractField>(&self) -> Polynomial<E>
+    where
+        T: Into<E>,
+    {
+        self.syscall.b.mantissa_poly::<E>()
+            - &limbs_poly(&self.remainder)
+            - &limbs_poly(&self.remainder_gap)
+            + (self.syscall.is_fallback.into() - self.syscall.is_real.into())
+    }
+}
+
+impl F64DivChip {
+    fn populate<F: PrimeField32>(
+        cols: &mut F64DivCols<F>,
+        event: &F64OpEvent,
+        blu: &mut impl ByteRecord,
+    ) {
+        let Some((a, b, c)) = cols.syscall.populate(event, blu) else {
+            cols.quotient.populate_unused();
+            cols.gap.populate_unused();
+            return;
+        };
+
+        let (mantissa_a, mantissa_b) = (mantissa(a), mantissa(b));
+        let is_low = mantissa_a < mantissa_b;
+        let numerator = u128::from(mantissa_a) << (FRACTION_BITS + 1 + u32::from(is_low));
+        let quotient = numerator / u128::from(mantissa_b);
+        let remainder = (numerator % u128::from(mantissa_b)) as u64;
+        cols.is_low = F::from_bool(is_low);
+
+        let remainder_bytes = remainder.to_le_bytes();
+        let gap_bytes = (mantissa_b - remainder - 1).to_le_bytes();
+        cols.remainder = array::from_fn(|i| F::from_canonical_u8(remainder_bytes[i]));
+        cols.remainder_gap = array::from_fn(|i| F::from_canonical_u8(gap_bytes[i]));
+        blu.add_u8_range_checks(event.shard, &remainder_bytes[..7]);
+        blu.add_u8_range_checks(event.shard, &gap_bytes[..7]);
+
+        let sticky_sum = remainder_bytes.iter().map(|&b| u32::from(b)).sum();
+        let q = (quotient >> 1) as u64;
+        let guard = quotient & 1 == 1;
+        let rounded = cols.round.populate(blu, event.shard, q, guard, sticky_sum);
+        debug_assert_eq!(rounded, mantissa(c));
+        debug_assert_eq!(
+            exponent(c) + exponent(b) + u32::from(is_low),
+            exponent(a) + EXPONENT_BIAS + cols.round.carry.as_canonical_u32()
+        );
+
+        let vanishing = cols.quotient_vanishing();
+        cols.quotient.populate(blu, event.shard, &vanishing);
+        let vanishing = cols.gap_vanishing();
+        cols.gap.populate(blu, event.shard, &vanishing);
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for F64DivChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "F64Div".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .get_precompile_events(SyscallCode::F64_DIV)
+            .iter()
+            .map(|(_, event)| {
+                let event =
+                    if let PrecompileEvent::F64Op(event) = event { event } else { unreachable!() };
+                let mut row = zeroed_f_vec(NUM_COLS);
+                let cols: &mut F64DivCols<F> = row.as_mut_slice().borrow_mut();
+                Self::populate(cols, event, &mut new_byte_lookup_events);
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(NUM_COLS);
+                let cols: &mut F64DivCols<F> = row.as_mut_slice().borrow_mut();
+                cols.quotient.populate_unused();
+                cols.gap.populate_unused();
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut F64DivCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.syscall.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::F64_DIV).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for F64DivChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for F64DivChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &F64DivCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &F64DivCols<AB::Var> = (*next).borrow();
+
+        let is_ok = local.syscall.eval(builder, &next.syscall, SyscallCode::F64_DIV);
+        let (a, b, c) = (&local.syscall.a, &local.syscall.b, &local.syscall.c);
+
+        builder.assert_bool(local.is_low);
+
+        // The sign of the quotient is the xor of the signs of the operands.
+        let (sign_a, sign_b) = (a.sign::<AB::Expr>(), b.sign::<AB::Expr>());
+        builder.when(is_ok.clone()).assert_eq(
+            c.sign::<AB::Expr>(),
+            sign_a.clone() + sign_b.clone() - sign_a * sign_b * AB::F::two(),
+        );
+
+        // The exponents subtract, minus one if the quotient of the mantissas is less than 1, plus
+        // one if rounding carried.
+        builder.when(is_ok.clone()).assert_eq(
+            c.exponent::<AB::Expr>(),
+            a.exponent::<AB::Expr>() - b.exponent::<AB::Expr>()
+                + AB::F::from_canonical_u32(EXPONENT_BIAS)
+                - local.is_low
+                + local.round.carry,
+        );
+
+        builder.slice_range_check_u8(&local.remainder, is_ok.clone());
+        builder.slice_range_check_u8(&local.remainder_gap, is_ok.clone());
+        let sticky_sum = local.remainder.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+
+        local.round.eval(builder, c, sticky_sum, is_ok.clone());
+        local.quotient.eval(builder, &local.quotient_vanishing(), is_ok.clone());
+        local.gap.eval(builder, &local.gap_vanishing(), is_ok);
+    }
+}
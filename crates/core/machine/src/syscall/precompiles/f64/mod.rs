@@ -0,0 +1,614 @@
+//! Precompiles for IEEE-754 double precision arithmetic.
+//!
+//! The chips only prove operations on normal operands with a normal result, rounded to nearest
+//! with ties to even. Every other operation is claimed as a fallback, which leaves `x` unchanged
+//! and returns a nonzero status, after which the guest computes the result in software. Claiming
+//! a fallback is never unsound, so those rows are only constrained to leave `x` unchanged.
+//!
+//! A double is decoded from its little-endian bytes. The low six bytes and the low nibble of the
+//! seventh byte hold the 52-bit fraction, which together with the hidden bit make up a 53-bit
+//! mantissa in seven byte limbs. The other bits of the top two bytes hold the exponent and the
+//! sign, and are decomposed into bits.
+//!
+//! Each chip splits an exact product, quotient or sum of the mantissas into a kept 53-bit
+//! mantissa and the bits below it, and checks the split with a polynomial identity on the byte
+//! limbs, in the same way as the field operations.
+
+mod add;
+mod div;
+mod mul;
+
+pub use add::*;
+pub use div::*;
+pub use mul::*;
+
+use core::array;
+
+use p3_air::AirBuilder;
+use p3_field::{AbstractField, Field, PrimeField32};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, F64OpEvent},
+    syscalls::SyscallCode,
+    ByteOpcode,
+};
+use sp1_curves::{params::FieldParameters, uint256::U256Field};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, Polynomial, SP1AirBuilder};
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{
+            util::{compute_root_quotient_and_shift, split_u16_limbs_to_u8_limbs},
+            util_air::eval_field_operation,
+        },
+        IsZeroOperation,
+    },
+};
+
+/// The number of bits of the fraction of a double.
+const FRACTION_BITS: u32 = 52;
+
+/// The bias of the exponent of a double.
+const EXPONENT_BIAS: u32 = 1023;
+
+/// The exponent of infinities and NaNs.
+const EXPONENT_MAX: u32 = 2047;
+
+/// The number of bits kept below the mantissa for rounding, the top one being the guard bit.
+const REMAINDER_BITS: u32 = 55;
+
+/// The mantissa of a normal double, including the hidden bit.
+fn mantissa(x: u64) -> u64 {
+    (x & ((1 << FRACTION_BITS) - 1)) | (1 << FRACTION_BITS)
+}
+
+/// The biased exponent of a double.
+fn exponent(x: u64) -> u32 {
+    ((x >> FRACTION_BITS) & u64::from(EXPONENT_MAX)) as u32
+}
+
+/// The byte limbs of a mantissa.
+fn mantissa_limbs<F: Field>(mantissa: u64) -> [F; 7] {
+    let bytes = mantissa.to_le_bytes();
+    array::from_fn(|i| F::from_canonical_u8(bytes[i]))
+}
+
+/// The polynomial whose coefficients are the given limbs.
+fn limbs_poly<T: Copy + Into<E>, E: AbstractField>(limbs: &[T]) -> Polynomial<E> {
+    limbs.iter().map(|&limb| limb.into()).collect()
+}
+
+/// Multiplies a polynomial by `scale * x^shift`.
+fn shifted<E: AbstractField>(p: Polynomial<E>, shift: usize, scale: E) -> Polynomial<E> {
+    core::iter::repeat(E::zero())
+        .take(shift)
+        .chain(p.as_coefficients().into_iter().map(|c| c * scale.clone()))
+        .collect()
+}
+
+/// The shift selected by one-hot flags for its byte offset and its bit offset.
+fn selected_shift<T: Copy + Into<E>, E: AbstractField>(bytes: &[T], bits: &[T; 8]) -> E {
+    let byte_shift = bytes
+        .iter()
+        .enumerate()
+        .map(|(i, &flag)| flag.into() * E::from_canonical_usize(8 * i))
+        .sum::<E>();
+    let bit_shift =
+        bits.iter().enumerate().map(|(i, &flag)| flag.into() * E::from_canonical_usize(i)).sum();
+    byte_shift + bit_shift
+}
+
+/// The polynomial `2^shift` for the shift selected by one-hot flags for its byte offset and its bit
+/// offset.
+fn selected_power<T: Copy + Into<E>, E: AbstractField>(
+    bytes: &[T],
+    bits: &[T; 8],
+) -> Polynomial<E> {
+    let bit_power = bits
+        .iter()
+        .enumerate()
+        .map(|(i, &flag)| flag.into() * E::from_canonical_u32(1 << i))
+        .sum::<E>();
+    limbs_poly::<T, E>(bytes) * bit_power
+}
+
+/// Sets the one-hot flags selecting a shift.
+fn select_shift<F: Field>(bytes: &mut [F], bits: &mut [F; 8], shift: u32) {
+    bytes[(shift / 8) as usize] = F::one();
+    bits[(shift % 8) as usize] = F::one();
+}
+
+/// Populates the byte limbs of a 55-bit remainder, returning its guard bit and the sum of the
+/// bytes below the guard bit.
+fn populate_remainder<F: PrimeField32>(
+    cols: &mut [F; 7],
+    blu: &mut impl ByteRecord,
+    shard: u32,
+    remainder: u64,
+) -> (bool, u32) {
+    let bytes = remainder.to_le_bytes();
+    *cols = array::from_fn(|i| F::from_canonical_u8(bytes[i]));
+    blu.add_u8_range_checks(shard, &bytes[..7]);
+
+    let guard = (remainder >> (REMAINDER_BITS - 1)) & 1 == 1;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(
+        shard,
+        ByteOpcode::MSB,
+        guard as u16,
+        0,
+        bytes[6] << 1,
+        0,
+    ));
+    let sticky_sum = bytes[..7].iter().map(|&b| u32::from(b)).sum::<u32>() - 64 * guard as u32;
+    (guard, sticky_sum)
+}
+
+/// Range checks the byte limbs of a 55-bit remainder and its guard bit, returning the sum of the
+/// bytes below the guard bit.
+fn eval_remainder<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    remainder: &[AB::Var; 7],
+    guard: AB::Var,
+    is_ok: AB::Expr,
+) -> AB::Expr {
+    builder.slice_range_check_u8(remainder, is_ok.clone());
+
+    // Doubling the top limb is still a byte, so the top limb has seven bits, the last of which is
+    // the guard bit.
+    builder.send_byte(
+        AB::F::from_canonical_u32(ByteOpcode::MSB as u32),
+        guard,
+        remainder[6].into() * AB::F::two(),
+        AB::Expr::zero(),
+        is_ok,
+    );
+
+    remainder.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb)
+        - guard.into() * AB::F::from_canonical_u32(64)
+}
+
+/// Columns for the memory accesses and the decoding of the operands and the result, shared by the
+/// f64 chips.
+///
+/// The value of `x` before the operation is `a`, the value of `y` is `b`, and the value written
+/// to `x` is the result `c`.
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct F64SyscallCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the first operand, which is overwritten with the result.
+    pub x_ptr: T,
+
+    /// The pointer to the second operand.
+    pub y_ptr: T,
+
+    pub x_access: [MemoryWriteCols<T>; 2],
+    pub y_access: [MemoryReadCols<T>; 2],
+
+    /// The decoded operands and result, only constrained when the operation is supported.
+    pub a: F64DecodeCols<T>,
+    pub b: F64DecodeCols<T>,
+    pub c: F64DecodeCols<T>,
+
+    /// The inverse of the exponent of the result minus one.
+    ///
+    /// A result with the smallest exponent may have been rounded from below the normal range,
+    /// where the precision is coarser, so such results are left to the software fallback.
+    pub c_exponent_inverse: T,
+
+    /// Whether the operation is not supported, leaving `x` unchanged.
+    pub is_fallback: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> F64SyscallCols<F> {
+    /// Populates the columns from the event, returning the operands and the result if the
+    /// operation is supported.
+    pub fn populate(
+        &mut self,
+        event: &F64OpEvent,
+        blu: &mut impl ByteRecord,
+    ) -> Option<(u64, u64, u64)> {
+        self.is_real = F::one();
+        self.shard = F::from_canonical_u32(event.shard);
+        self.clk = F::from_canonical_u32(event.clk);
+        self.x_ptr = F::from_canonical_u32(event.x_ptr);
+        self.y_ptr = F::from_canonical_u32(event.y_ptr);
+        self.is_fallback = F::from_bool(event.is_fallback);
+        for i in 0..2 {
+            self.x_access[i].populate(event.x_memory_records[i], blu);
+            self.y_access[i].populate(event.y_memory_records[i], blu);
+        }
+        if event.is_fallback {
+            return None;
+        }
+
+        let to_u64 = |words: [u32; 2]| u64::from(words[0]) | (u64::from(words[1]) << 32);
+        let a = to_u64(event.x);
+        let b = to_u64(event.y);
+        let c = to_u64([event.x_memory_records[0].value, event.x_memory_records[1].value]);
+        self.a.populate(a);
+        self.b.populate(b);
+        self.c.populate(c);
+        self.c_exponent_inverse = F::from_canonical_u32(exponent(c) - 1).inverse();
+        blu.add_u8_range_checks(event.shard, &c.to_le_bytes()[..6]);
+
+        Some((a, b, c))
+    }
+}
+
+impl<V: Copy> F64SyscallCols<V> {
+    /// Evaluates the memory accesses, the syscall and the decoding, returning whether the
+    /// operation is supported.
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        next: &Self,
+        syscall_code: SyscallCode,
+    ) -> AB::Expr
+    where
+        V: Into<AB::Expr>,
+    {
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(self.nonce);
+        builder.when_transition().assert_eq(self.nonce.into() + AB::Expr::one(), next.nonce);
+
+        builder.assert_bool(self.is_real);
+        builder.assert_bool(self.is_fallback);
+        builder.when(self.is_fallback).assert_one(self.is_real);
+        let is_ok = self.is_real.into() - self.is_fallback.into();
+
+        let prev_x: [V; 8] = array::from_fn(|i| self.x_access[i / 4].prev_value[i % 4]);
+        let y: [V; 8] = array::from_fn(|i| self.y_access[i / 4].access.value[i % 4]);
+        let x: [V; 8] = array::from_fn(|i| self.x_access[i / 4].access.value[i % 4]);
+        self.a.eval(builder, &prev_x, is_ok.clone());
+        self.b.eval(builder, &y, is_ok.clone());
+        self.c.eval(builder, &x, is_ok.clone());
+
+        // The low bytes of the operands come from memory, but the ones of the result are free.
+        builder.slice_range_check_u8(&self.c.mantissa[..6], is_ok.clone());
+        builder.when(is_ok.clone()).assert_one(
+            (self.c.exponent::<AB::Expr>() - AB::Expr::one()) * self.c_exponent_inverse.into(),
+        );
+
+        // An unsupported operation leaves x unchanged.
+        builder.when(self.is_fallback).assert_all_eq(prev_x, x);
+
+        // Read y, then write x at the next cycle, since they may be the same.
+        builder.eval_memory_access_slice(
+            self.shard,
+            self.clk.into(),
+            self.y_ptr,
+            &self.y_access,
+            self.is_real,
+        );
+        builder.eval_memory_access_slice(
+            self.shard,
+            self.clk.into() + AB::Expr::one(),
+            self.x_ptr,
+            &self.x_access,
+            self.is_real,
+        );
+
+        // Receive the arguments, along with the returned status.
+        builder.receive_syscall(
+            self.shard,
+            self.clk,
+            self.nonce,
+            AB::Expr::from_canonical_u32(syscall_code.syscall_id())
+                + self.is_fallback.into() * AB::F::from_canonical_u32(1 << 8),
+            self.x_ptr,
+            self.y_ptr,
+            self.is_real,
+            InteractionScope::Local,
+        );
+
+        is_ok
+    }
+}
+
+/// Columns decoding a normal double from its bytes.
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct F64DecodeCols<T> {
+    /// The bits of the top two bytes, which hold the top of the fraction, the exponent and the
+    /// sign.
+    pub high_bits: [T; 16],
+
+    /// The mantissa in byte limbs, including the hidden bit in the top limb.
+    pub mantissa: [T; 7],
+
+    /// The inverse of the exponent, proving that the double is not zero or subnormal.
+    pub exponent_inverse: T,
+
+    /// The inverse of the exponent subtracted from its maximum, proving that the double is not
+    /// infinite or a NaN.
+    pub exponent_max_inverse: T,
+}
+
+impl<F: PrimeField32> F64DecodeCols<F> {
+    pub fn populate(&mut self, x: u64) {
+        let bytes = x.to_le_bytes();
+        self.high_bits =
+            array::from_fn(|i| F::from_canonical_u8((bytes[6 + i / 8] >> (i % 8)) & 1));
+        self.mantissa = mantissa_limbs(mantissa(x));
+        self.exponent_inverse = F::from_canonical_u32(exponent(x)).inverse();
+        self.exponent_max_inverse = F::from_canonical_u32(EXPONENT_MAX - exponent(x)).inverse();
+    }
+}
+
+impl<T: Copy> F64DecodeCols<T> {
+    /// The sign bit.
+    pub fn sign<E: AbstractField>(&self) -> E
+    where
+        T: Into<E>,
+    {
+        self.high_bits[15].into()
+    }
+
+    /// The biased exponent.
+    pub fn exponent<E: AbstractField>(&self) -> E
+    where
+        T: Into<E>,
+    {
+        (4..15).map(|i| self.high_bits[i].into() * E::from_canonical_u32(1 << (i - 4))).sum()
+    }
+
+    /// The mantissa as a polynomial in its byte limbs.
+    pub fn mantissa_poly<E: AbstractField>(&self) -> Polynomial<E>
+    where
+        T: Into<E>,
+    {
+        limbs_poly(&self.mantissa)
+    }
+}
+
+impl<V: Copy> F64DecodeCols<V> {
+    /// Constrains the decoding of the given bytes, when the operation is supported.
+    ///
+    /// The low six bytes are not range checked, since the ones read from memory already are.
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        bytes: &[V; 8],
+        is_ok: AB::Expr,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        let bits_value = |bits: &[V]| {
+            bits.iter()
+                .enumerate()
+                .map(|(i, &bit)| bit.into() * AB::F::from_canonical_u32(1 << i))
+                .sum::<AB::Expr>()
+        };
+
+        for bit in self.high_bits {
+            builder.assert_bool(bit);
+        }
+        builder.when(is_ok.clone()).assert_eq(bytes[6], bits_value(&self.high_bits[..8]));
+        builder.when(is_ok.clone()).assert_eq(bytes[7], bits_value(&self.high_bits[8..]));
+
+        for i in 0..6 {
+            builder.when(is_ok.clone()).assert_eq(self.mantissa[i], bytes[i]);
+        }
+        builder.when(is_ok.clone()).assert_eq(
+            self.mantissa[6],
+            bits_value(&self.high_bits[..4]) + AB::F::from_canonical_u32(16),
+        );
+
+        let exponent = self.exponent::<AB::Expr>();
+        builder.when(is_ok.clone()).assert_one(exponent.clone() * self.exponent_inverse.into());
+        builder.when(is_ok).assert_one(
+            (AB::Expr::from_canonical_u32(EXPONENT_MAX) - exponent)
+                * self.exponent_max_inverse.into(),
+        );
+    }
+}
+
+/// Columns rounding a mantissa to nearest with ties to even.
+///
+/// The rounded mantissa is the one of the result. The unrounded one is recovered from it by
+/// undoing the rounding, see [`F64RoundCols::unrounded_mantissa`].
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct F64RoundCols<T> {
+    /// The bit right below the unrounded mantissa.
+    pub guard: T,
+
+    /// Whether the bits below the guard bit are all zero.
+    pub is_exact: IsZeroOperation<T>,
+
+    /// Whether the mantissa is rounded up.
+    pub round_up: T,
+
+    /// Whether rounding up carried out of the mantissa, which then renormalizes to `2^52` with
+    /// the exponent incremented.
+    pub carry: T,
+
+    /// Whether the fraction of the rounded mantissa is zero.
+    pub fraction_is_zero: IsZeroOperation<T>,
+
+    /// The lowest bit of the rounded mantissa.
+    pub lsb: T,
+
+    /// The other bits of the lowest byte of the rounded mantissa.
+    pub lowest_byte_high_bits: T,
+}
+
+impl<F: PrimeField32> F64RoundCols<F> {
+    /// Rounds the mantissa `q`, given its guard bit and the sum of the bytes below the guard bit,
+    /// returning the rounded mantissa.
+    pub fn populate(
+        &mut self,
+        blu: &mut impl ByteRecord,
+        shard: u32,
+        q: u64,
+        guard: bool,
+        sticky_sum: u32,
+    ) -> u64 {
+        let is_exact = self.is_exact.populate(sticky_sum) == 1;
+        let round_up = guard && (!is_exact || q & 1 == 1);
+        let mut rounded = q + u64::from(round_up);
+        let carry = rounded == 1 << (FRACTION_BITS + 1);
+        if carry {
+            rounded >>= 1;
+        }
+        self.guard = F::from_bool(guard);
+        self.round_up = F::from_bool(round_up);
+        self.carry = F::from_bool(carry);
+
+        let fraction = rounded & ((1 << FRACTION_BITS) - 1);
+        self.fraction_is_zero
+            .populate(fraction.to_le_bytes().iter().map(|&b| u32::from(b)).sum::<u32>());
+
+        let lowest_byte = rounded as u8;
+        self.lsb = F::from_canonical_u8(lowest_byte & 1);
+        self.lowest_byte_high_bits = F::from_canonical_u8(lowest_byte >> 1);
+        blu.add_u8_range_checks(shard, &[lowest_byte >> 1]);
+
+        rounded
+    }
+}
+
+impl<T: Copy> F64RoundCols<T> {
+    /// The mantissa before rounding, given the decoded result.
+    ///
+    /// This is the rounded mantissa minus `round_up`, or `2^53 - 1` if rounding carried.
+    pub fn unrounded_mantissa<E: AbstractField>(&self, result: &F64DecodeCols<T>) -> Polynomial<E>
+    where
+        T: Into<E>,
+    {
+        let mut limbs: [E; 7] = result.mantissa.map(Into::into);
+        limbs[0] = limbs[0].clone() - self.round_up.into();
+        limbs[6] = limbs[6].clone() + self.carry.into() * E::from_canonical_u32(16);
+        Polynomial::from_coefficients(&limbs)
+    }
+}
+
+impl<V: Copy> F64RoundCols<V> {
+    /// Constrains the rounding of the unrounded mantissa to the mantissa of the result, given the
+    /// sum of the bytes below the guard bit.
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        result: &F64DecodeCols<V>,
+        sticky_sum: AB::Expr,
+        is_ok: AB::Expr,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        builder.assert_bool(self.guard);
+        builder.assert_bool(self.round_up);
+        builder.assert_bool(self.carry);
+        builder.assert_bool(self.lsb);
+
+        IsZeroOperation::<AB::F>::eval(builder, sticky_sum, self.is_exact, is_ok.clone());
+        let fraction_sum = result.mantissa.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb)
+            - AB::F::from_canonical_u32(16);
+        IsZeroOperation::<AB::F>::eval(builder, fraction_sum, self.fraction_is_zero, is_ok.clone());
+
+        builder.when(is_ok.clone()).assert_eq(
+            result.mantissa[0],
+            self.lowest_byte_high_bits.into() * AB::F::two() + self.lsb.into(),
+        );
+        builder.slice_range_check_u8(&[self.lowest_byte_high_bits], is_ok);
+
+        // Round up when the discarded bits are above half of the last kept bit. On a tie, the
+        // rounded mantissa is even, which rounds up exactly when the unrounded one is odd.
+        builder.when_not(self.guard).assert_zero(self.round_up);
+        builder.when(self.guard).when_not(self.is_exact.result).assert_one(self.round_up);
+        builder.when(self.guard).when(self.is_exact.result).assert_zero(self.lsb);
+
+        // Rounding carries exactly when the rounded mantissa is `2^53`, which is stored as `2^52`.
+        // Without a carry, rounding up never results in `2^52`.
+        builder.when(self.carry).assert_one(self.round_up);
+        builder.when(self.carry).assert_one(self.fraction_is_zero.result);
+        builder.when_not(self.carry).when(self.round_up).assert_zero(self.fraction_is_zero.result);
+    }
+}
+
+/// Columns proving that a polynomial in byte limbs vanishes at 256, so that the integers encoded
+/// by its terms add up to zero.
+#[derive(AlignedBorrow, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct LimbIdentityCols<T, const N: usize> {
+    pub witness_low: [T; N],
+    pub witness_high: [T; N],
+}
+
+impl<F: PrimeField32, const N: usize> LimbIdentityCols<F, N> {
+    pub fn populate(&mut self, blu: &mut impl ByteRecord, shard: u32, vanishing: &Polynomial<F>) {
+        debug_assert!(vanishing.degree() <= N);
+        let witness = compute_root_quotient_and_shift(
+            vanishing,
+            U256Field::WITNESS_OFFSET,
+            U256Field::NB_BITS_PER_LIMB as u32,
+            N,
+        );
+        let (witness_low, witness_high) = split_u16_limbs_to_u8_limbs(&witness);
+        blu.add_u8_range_checks_field(shard, &witness_low);
+        blu.add_u8_range_checks_field(shard, &witness_high);
+        self.witness_low = witness_low.try_into().unwrap();
+        self.witness_high = witness_high.try_into().unwrap();
+    }
+
+    /// Populates the witness of the zero polynomial, for rows where the operation is not
+    /// supported.
+    pub fn populate_unused(&mut self) {
+        let offset = U256Field::WITNESS_OFFSET as u32;
+        self.witness_low = [F::from_canonical_u32(offset & 0xff); N];
+        self.witness_high = [F::from_canonical_u32(offset >> 8); N];
+    }
+}
+
+impl<V: Copy, const N: usize> LimbIdentityCols<V, N> {
+    /// Constrains the vanishing polynomial to vanish at 256.
+    ///
+    /// The constraints are not conditional, so rows where the operation is not supported must
+    /// have a zero vanishing polynomial.
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        vanishing: &Polynomial<AB::Expr>,
+        is_ok: AB::Expr,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        let witness_low = limbs_poly(&self.witness_low);
+        let witness_high = limbs_poly(&self.witness_high);
+        eval_field_operation::<AB, U256Field>(builder, vanishing, &witness_low, &witness_high);
+
+        builder.slice_range_check_u8(&self.witness_low, is_ok.clone());
+        builder.slice_range_check_u8(&self.witness_high, is_ok);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::F64_ARITH_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_f64_arith() {
+        utils::setup_logger();
+        let program = Program::from(F64_ARITH_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
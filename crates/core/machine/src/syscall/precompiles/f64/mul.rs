@@ -0,0 +1,207 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, F64OpEvent, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, Polynomial, SP1AirBuilder};
+
+use super::{
+    eval_remainder, exponent, limbs_poly, mantissa, populate_remainder, shifted, F64RoundCols,
+    F64SyscallCols, LimbIdentityCols, EXPONENT_BIAS, FRACTION_BITS, REMAINDER_BITS,
+};
+use crate::utils::{pad_rows_fixed, zeroed_f_vec};
+
+/// The number of columns in the F64MulCols.
+const NUM_COLS: usize = size_of::<F64MulCols<u8>>();
+
+/// A chip that computes `x = x * y` for doubles.
+#[derive(Default)]
+pub struct F64MulChip;
+
+impl F64MulChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the F64Mul operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct F64MulCols<T> {
+    pub syscall: F64SyscallCols<T>,
+
+    /// Whether the product of the mantissas is at least `2^105`, so that the kept mantissa starts
+    /// one bit higher.
+    pub is_high: T,
+
+    /// The bits of the product below the kept mantissa, as 55 bits in byte limbs.
+    pub remainder: [T; 7],
+
+    /// Proves `mantissa(a) * mantissa(b) * 2^(3 - is_high) = q * 2^55 + remainder`, where `q` is
+    /// the unrounded mantissa.
+    pub product: LimbIdentityCols<T, 12>,
+
+    pub round: F64RoundCols<T>,
+}
+
+impl<T: Copy> F64MulCols<T> {
+    fn product_vanishing<E: AbstractField>(&self) -> Polynomial<E>
+    where
+        T: Into<E>,
+    {
+        let syscall = &self.syscall;
+        let scale = E::from_canonical_u32(8) - self.is_high.into() * E::from_canonical_u32(4);
+        let product = &syscall.a.mantissa_poly::<E>() * &syscall.b.mantissa_poly::<E>() * scale;
+        let unrounded = self.round.unrounded_mantissa::<E>(&syscall.c);
+        product - &shifted(unrounded, 6, E::from_canonical_u32(128)) - &limbs_poly(&self.remainder)
+    }
+}
+
+impl F64MulChip {
+    fn populate<F: PrimeField32>(
+        cols: &mut F64MulCols<F>,
+        event: &F64OpEvent,
+        blu: &mut impl ByteRecord,
+    ) {
+        let Some((a, b, c)) = cols.syscall.populate(event, blu) else {
+            cols.product.populate_unused();
+            return;
+        };
+
+        let product = u128::from(mantissa(a)) * u128::from(mantissa(b));
+        let is_high = product >> (2 * FRACTION_BITS + 1) == 1;
+        let shift = FRACTION_BITS + u32::from(is_high);
+        let q = (product >> shift) as u64;
+        let remainder =
+            ((product << (REMAINDER_BITS - shift)) as u64) & ((1 << REMAINDER_BITS) - 1);
+        cols.is_high = F::from_bool(is_high);
+
+        let (guard, sticky_sum) =
+            populate_remainder(&mut cols.remainder, blu, event.shard, remainder);
+        let rounded = cols.round.populate(blu, event.shard, q, guard, sticky_sum);
+        debug_assert_eq!(rounded, mantissa(c));
+        debug_assert_eq!(
+            exponent(c) + EXPONENT_BIAS,
+            exponent(a) + exponent(b) + u32::from(is_high) + cols.round.carry.as_canonical_u32()
+        );
+
+        let vanishing = cols.product_vanishing();
+        cols.product.populate(blu, event.shard, &vanishing);
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for F64MulChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "F64Mul".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .get_precompile_events(SyscallCode::F64_MUL)
+            .iter()
+            .map(|(_, event)| {
+                let event =
+                    if let PrecompileEvent::F64Op(event) = event { event } else { unreachable!() };
+                let mut row = zeroed_f_vec(NUM_COLS);
+                let cols: &mut F64MulCols<F> = row.as_mut_slice().borrow_mut();
+                Self::populate(cols, event, &mut new_byte_lookup_events);
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(NUM_COLS);
+                let cols: &mut F64MulCols<F> = row.as_mut_slice().borrow_mut();
+                cols.product.populate_unused();
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(rows.into_iter().flatten().collect(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut F64MulCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.syscall.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::F64_MUL).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for F64MulChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for F64MulChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &F64MulCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &F64MulCols<AB::Var> = (*next).borrow();
+
+        let is_ok = local.syscall.eval(builder, &next.syscall, SyscallCode::F64_MUL);
+        let (a, b, c) = (&local.syscall.a, &local.syscall.b, &local.syscall.c);
+
+        builder.assert_bool(local.is_high);
+
+        // The sign of the product is the xor of the signs of the operands.
+        let (sign_a, sign_b) = (a.sign::<AB::Expr>(), b.sign::<AB::Expr>());
+        builder.when(is_ok.clone()).assert_eq(
+            c.sign::<AB::Expr>(),
+            sign_a.clone() + sign_b.clone() - sign_a * sign_b * AB::F::two(),
+        );
+
+        // The exponents add up, plus one if the product of the mantissas is at least 2 or if
+        // rounding carried.
+        builder.when(is_ok.clone()).assert_eq(
+            c.exponent::<AB::Expr>(),
+            a.exponent::<AB::Expr>() + b.exponent::<AB::Expr>()
+                - AB::F::from_canonical_u32(EXPONENT_BIAS)
+                + local.is_high
+                + local.round.carry,
+        );
+
+        let sticky_sum =
+            eval_remainder(builder, &local.remainder, local.round.guard, is_ok.clone());
+        local.round.eval(builder, c, sticky_sum, is_ok.clone());
+        local.product.eval(builder, &local.product_vanishing(), is_ok);
+    }
+}
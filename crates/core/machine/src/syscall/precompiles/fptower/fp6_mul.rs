@@ -0,0 +1,465 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+    mem::size_of,
+};
+
+use crate::{air::MemoryAirBuilder, utils::zeroed_f_vec};
+use generic_array::GenericArray;
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{FieldType, FpOpField},
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+use typenum::Unsigned;
+
+use crate::{
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::{field_inner_product::FieldInnerProductCols, field_op::FieldOpCols},
+    utils::{limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le_vec},
+};
+
+type FpLimbs<T, P> = Limbs<T, <P as NumLimbs>::Limbs>;
+
+pub const fn num_fp6_mul_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<Fp6MulAssignCols<u8, P>>()
+}
+
+/// A set of columns multiplying two degree 2 field elements.
+///
+/// `a * b = (a0 * b0 - a1 * b1) + (a0 * b1 + a1 * b0) * u`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2MulCols<T, P: FieldParameters + NumWords> {
+    pub(crate) a0_mul_b0: FieldOpCols<T, P>,
+    pub(crate) a1_mul_b1: FieldOpCols<T, P>,
+    pub(crate) c0: FieldOpCols<T, P>,
+    pub(crate) c1: FieldInnerProductCols<T, P>,
+}
+
+/// A set of columns adding two degree 2 field elements.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2AddCols<T, P: FieldParameters + NumWords> {
+    pub(crate) c0: FieldOpCols<T, P>,
+    pub(crate) c1: FieldOpCols<T, P>,
+}
+
+/// A set of columns multiplying a degree 2 field element by the cubic non-residue `c + u`.
+///
+/// `(c + u) * a = (c * a0 - a1) + (a0 + c * a1) * u`, where `-a1` is computed as `(p - 1) * a1`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2MulByNonResidueCols<T, P: FieldParameters + NumWords> {
+    pub(crate) c0: FieldInnerProductCols<T, P>,
+    pub(crate) c1: FieldInnerProductCols<T, P>,
+}
+
+/// A set of columns for the Fp6Mul operation.
+///
+/// The degree 6 field is `Fp2[v] / (v^3 - xi)` with `xi = c + u`, so that
+///
+/// - `c0 = a0 * b0 + xi * (a1 * b2 + a2 * b1)`,
+/// - `c1 = a0 * b1 + a1 * b0 + xi * a2 * b2`,
+/// - `c2 = a0 * b2 + a1 * b1 + a2 * b0`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp6MulAssignCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub nonce: T,
+    pub clk: T,
+    pub x_ptr: T,
+    pub y_ptr: T,
+    /// The memory accesses of the degree 2 coefficients of x.
+    pub x_access: [GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>; 3],
+    /// The memory accesses of the degree 2 coefficients of y.
+    pub y_access: [GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>; 3],
+    /// The products `a_i * b_j`, at index `3 * i + j`.
+    pub(crate) products: [Fp2MulCols<T, P>; 9],
+    pub(crate) c0_sum: Fp2AddCols<T, P>,
+    pub(crate) c0_sum_mul_xi: Fp2MulByNonResidueCols<T, P>,
+    pub(crate) c0: Fp2AddCols<T, P>,
+    pub(crate) c1_sum: Fp2AddCols<T, P>,
+    pub(crate) c1_mul_xi: Fp2MulByNonResidueCols<T, P>,
+    pub(crate) c1: Fp2AddCols<T, P>,
+    pub(crate) c2_sum: Fp2AddCols<T, P>,
+    pub(crate) c2: Fp2AddCols<T, P>,
+}
+
+/// The constant operands `[c, p - 1]` and `[1, c]` of the inner products multiplying by the
+/// non-residue `c + u`.
+fn non_residue_operands<P: FpOpField>() -> [[BigUint; 2]; 2] {
+    let c = BigUint::from(P::FIELD_TYPE.fp6_non_residue());
+    let minus_one = P::modulus() - 1u32;
+    [[c.clone(), minus_one], [BigUint::from(1u32), c]]
+}
+
+impl<F: PrimeField32, P: FpOpField> Fp2MulCols<F, P> {
+    fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        a: &[BigUint; 2],
+        b: &[BigUint; 2],
+    ) -> [BigUint; 2] {
+        let a0_mul_b0 = self.a0_mul_b0.populate(record, shard, &a[0], &b[0], FieldOperation::Mul);
+        let a1_mul_b1 = self.a1_mul_b1.populate(record, shard, &a[1], &b[1], FieldOperation::Mul);
+        let c0 = self.c0.populate(record, shard, &a0_mul_b0, &a1_mul_b1, FieldOperation::Sub);
+        let c1 = self.c1.populate(record, shard, a, &[b[1].clone(), b[0].clone()]);
+        [c0, c1]
+    }
+}
+
+impl<F: PrimeField32, P: FpOpField> Fp2AddCols<F, P> {
+    fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        a: &[BigUint; 2],
+        b: &[BigUint; 2],
+    ) -> [BigUint; 2] {
+        [
+            self.c0.populate(record, shard, &a[0], &b[0], FieldOperation::Add),
+            self.c1.populate(record, shard, &a[1], &b[1], FieldOperation::Add),
+        ]
+    }
+}
+
+impl<F: PrimeField32, P: FpOpField> Fp2MulByNonResidueCols<F, P> {
+    fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        a: &[BigUint; 2],
+    ) -> [BigUint; 2] {
+        let [xi_c0, xi_c1] = non_residue_operands::<P>();
+        [self.c0.populate(record, shard, a, &xi_c0), self.c1.populate(record, shard, a, &xi_c1)]
+    }
+}
+
+impl<V: Copy, P: FpOpField> Fp2MulCols<V, P> {
+    fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &[FpLimbs<V, P>; 2],
+        b: &[FpLimbs<V, P>; 2],
+        is_real: V,
+    ) where
+        V: Into<AB::Expr>,
+        FpLimbs<V, P>: Copy,
+    {
+        self.a0_mul_b0.eval(builder, &a[0], &b[0], FieldOperation::Mul, is_real);
+        self.a1_mul_b1.eval(builder, &a[1], &b[1], FieldOperation::Mul, is_real);
+        self.c0.eval(
+            builder,
+            &self.a0_mul_b0.result,
+            &self.a1_mul_b1.result,
+            FieldOperation::Sub,
+            is_real,
+        );
+        self.c1.eval(builder, a, &[b[1], b[0]], is_real);
+    }
+
+    fn result(&self) -> [FpLimbs<V, P>; 2]
+    where
+        FpLimbs<V, P>: Copy,
+    {
+        [self.c0.result, self.c1.result]
+    }
+}
+
+impl<V: Copy, P: FpOpField> Fp2AddCols<V, P> {
+    fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: [FpLimbs<V, P>; 2],
+        b: [FpLimbs<V, P>; 2],
+        is_real: V,
+    ) where
+        V: Into<AB::Expr>,
+        FpLimbs<V, P>: Copy,
+    {
+        self.c0.eval(builder, &a[0], &b[0], FieldOperation::Add, is_real);
+        self.c1.eval(builder, &a[1], &b[1], FieldOperation::Add, is_real);
+    }
+
+    fn result(&self) -> [FpLimbs<V, P>; 2]
+    where
+        FpLimbs<V, P>: Copy,
+    {
+        [self.c0.result, self.c1.result]
+    }
+}
+
+impl<V: Copy, P: FpOpField> Fp2MulByNonResidueCols<V, P> {
+    fn eval<AB: SP1AirBuilder<Var = V>>(&self, builder: &mut AB, a: [FpLimbs<V, P>; 2], is_real: V)
+    where
+        V: Into<AB::Expr>,
+        FpLimbs<V, P>: Copy,
+    {
+        let [xi_c0, xi_c1] = non_residue_operands::<P>()
+            .map(|operands| operands.map(|x| P::to_limbs_field::<AB::F, _>(&x)));
+        self.c0.eval(builder, &a, &xi_c0, is_real);
+        self.c1.eval(builder, &a, &xi_c1, is_real);
+    }
+
+    fn result(&self) -> [FpLimbs<V, P>; 2]
+    where
+        FpLimbs<V, P>: Copy,
+    {
+        [self.c0.result, self.c1.result]
+    }
+}
+
+#[derive(Default)]
+pub struct Fp6MulAssignChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: FpOpField> Fp6MulAssignChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => SyscallCode::BN254_FP6_MUL,
+            FieldType::Bls12381 => SyscallCode::BLS12381_FP6_MUL,
+        }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        cols: &mut Fp6MulAssignCols<F, P>,
+        a: &[[BigUint; 2]; 3],
+        b: &[[BigUint; 2]; 3],
+    ) {
+        let mut products: [[BigUint; 2]; 9] = Default::default();
+        for i in 0..3 {
+            for j in 0..3 {
+                products[3 * i + j] =
+                    cols.products[3 * i + j].populate(record, shard, &a[i], &b[j]);
+            }
+        }
+
+        let c0_sum = cols.c0_sum.populate(record, shard, &products[5], &products[7]);
+        let c0_sum_mul_xi = cols.c0_sum_mul_xi.populate(record, shard, &c0_sum);
+        cols.c0.populate(record, shard, &products[0], &c0_sum_mul_xi);
+
+        let c1_sum = cols.c1_sum.populate(record, shard, &products[1], &products[3]);
+        let c1_mul_xi = cols.c1_mul_xi.populate(record, shard, &products[8]);
+        cols.c1.populate(record, shard, &c1_sum, &c1_mul_xi);
+
+        let c2_sum = cols.c2_sum.populate(record, shard, &products[2], &products[4]);
+        cols.c2.populate(record, shard, &c2_sum, &products[6]);
+    }
+}
+
+/// Splits the words of a degree 6 field element into its degree 2 coefficients.
+fn fp6_from_words<P: FpOpField>(words: &[u32]) -> [[BigUint; 2]; 3] {
+    let mut coeffs = words
+        .chunks_exact(<P as NumWords>::WordsFieldElement::USIZE)
+        .map(|chunk| BigUint::from_bytes_le(&words_to_bytes_le_vec(chunk)));
+    core::array::from_fn(|_| core::array::from_fn(|_| coeffs.next().unwrap()))
+}
+
+/// Returns the limbs of a degree 2 field element in memory.
+fn fp2_limbs_from_prev_access<T: Copy, P: FpOpField, M: MemoryCols<T>>(
+    access: &[M],
+) -> [FpLimbs<T, P>; 2] {
+    let (c0, c1) = access.split_at(access.len() / 2);
+    [limbs_from_prev_access(c0), limbs_from_prev_access(c1)]
+}
+
+impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp6MulAssignChip<P> {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254Fp6MulAssign".to_string(),
+            FieldType::Bls12381 => "Bls12381Fp6MulAssign".to_string(),
+        }
+    }
+
+    fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(Self::syscall_code());
+
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in events {
+            let event = match (P::FIELD_TYPE, event) {
+                (FieldType::Bn254, PrecompileEvent::Bn254Fp6Mul(event)) => event,
+                (FieldType::Bls12381, PrecompileEvent::Bls12381Fp6Mul(event)) => event,
+                _ => unreachable!(),
+            };
+
+            let mut row = zeroed_f_vec(num_fp6_mul_cols::<P>());
+            let cols: &mut Fp6MulAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+            Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                cols,
+                &fp6_from_words::<P>(&event.x),
+                &fp6_from_words::<P>(&event.y),
+            );
+
+            // Populate the memory access columns.
+            let y_access = cols.y_access.iter_mut().flat_map(|access| access.iter_mut());
+            for (access, record) in y_access.zip(&event.y_memory_records) {
+                access.populate(*record, &mut new_byte_lookup_events);
+            }
+            let x_access = cols.x_access.iter_mut().flat_map(|access| access.iter_mut());
+            for (access, record) in x_access.zip(&event.x_memory_records) {
+                access.populate(*record, &mut new_byte_lookup_events);
+            }
+            rows.push(row);
+        }
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(num_fp6_mul_cols::<P>());
+                let cols: &mut Fp6MulAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+                let zero: [[BigUint; 2]; 3] = Default::default();
+                Self::populate_field_ops(&mut vec![], 0, cols, &zero, &zero);
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_fp6_mul_cols::<P>(),
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Fp6MulAssignCols<F, P> = trace.values
+                [i * num_fp6_mul_cols::<P>()..(i + 1) * num_fp6_mul_cols::<P>()]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+}
+
+impl<F, P: FpOpField> BaseAir<F> for Fp6MulAssignChip<P> {
+    fn width(&self) -> usize {
+        num_fp6_mul_cols::<P>()
+    }
+}
+
+impl<AB, P: FpOpField> Air<AB> for Fp6MulAssignChip<P>
+where
+    AB: SP1AirBuilder,
+    FpLimbs<AB::Var, P>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Fp6MulAssignCols<AB::Var, P> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Fp6MulAssignCols<AB::Var, P> = (*next).borrow();
+
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let a =
+            local.x_access.each_ref().map(|access| fp2_limbs_from_prev_access::<_, P, _>(access));
+        let b =
+            local.y_access.each_ref().map(|access| fp2_limbs_from_prev_access::<_, P, _>(access));
+
+        for i in 0..3 {
+            for j in 0..3 {
+                local.products[3 * i + j].eval(builder, &a[i], &b[j], local.is_real);
+            }
+        }
+        let product = |i: usize, j: usize| local.products[3 * i + j].result();
+
+        local.c0_sum.eval(builder, product(1, 2), product(2, 1), local.is_real);
+        local.c0_sum_mul_xi.eval(builder, local.c0_sum.result(), local.is_real);
+        local.c0.eval(builder, product(0, 0), local.c0_sum_mul_xi.result(), local.is_real);
+
+        local.c1_sum.eval(builder, product(0, 1), product(1, 0), local.is_real);
+        local.c1_mul_xi.eval(builder, product(2, 2), local.is_real);
+        local.c1.eval(builder, local.c1_sum.result(), local.c1_mul_xi.result(), local.is_real);
+
+        local.c2_sum.eval(builder, product(0, 2), product(1, 1), local.is_real);
+        local.c2.eval(builder, local.c2_sum.result(), product(2, 0), local.is_real);
+
+        // The result is written over x.
+        let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+        for (c, access) in [&local.c0, &local.c1, &local.c2].into_iter().zip(&local.x_access) {
+            let (c0_access, c1_access) = access.split_at(num_words_field_element);
+            builder.when(local.is_real).assert_all_eq(c.c0.result, value_as_limbs(c0_access));
+            builder.when(local.is_real).assert_all_eq(c.c1.result, value_as_limbs(c1_access));
+        }
+
+        // Each degree 2 coefficient directly follows the previous one in memory.
+        let num_words_fp2 = <P as NumWords>::WordsCurvePoint::USIZE;
+        for (k, access) in local.y_access.iter().enumerate() {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk.into(),
+                local.y_ptr + AB::F::from_canonical_usize(4 * k * num_words_fp2),
+                access,
+                local.is_real,
+            );
+        }
+        // We write x at +1 since x and y could be the same.
+        for (k, access) in local.x_access.iter().enumerate() {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk + AB::F::from_canonical_u32(1),
+                local.x_ptr + AB::F::from_canonical_usize(4 * k * num_words_fp2),
+                access,
+                local.is_real,
+            );
+        }
+
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
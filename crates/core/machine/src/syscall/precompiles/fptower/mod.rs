@@ -1,10 +1,12 @@
 mod fp;
 mod fp2_addsub;
 mod fp2_mul;
+mod fp6_mul;
 
 pub use fp::*;
 pub use fp2_addsub::*;
 pub use fp2_mul::*;
+pub use fp6_mul::*;
 
 #[cfg(test)]
 mod tests {
@@ -12,8 +14,8 @@ mod tests {
 
     use sp1_core_executor::Program;
     use test_artifacts::{
-        BLS12381_FP2_ADDSUB_ELF, BLS12381_FP2_MUL_ELF, BLS12381_FP_ELF, BN254_FP2_ADDSUB_ELF,
-        BN254_FP2_MUL_ELF, BN254_FP_ELF,
+        BLS12381_FP2_ADDSUB_ELF, BLS12381_FP2_MUL_ELF, BLS12381_FP6_MUL_ELF, BLS12381_FP_ELF,
+        BN254_FP12_ELF, BN254_FP2_ADDSUB_ELF, BN254_FP2_MUL_ELF, BN254_FP_ELF,
     };
 
     use crate::utils;
@@ -39,6 +41,13 @@ mod tests {
         utils::run_test::<CpuProver<_, _>>(program).unwrap();
     }
 
+    #[test]
+    fn test_bls12381_fp6_mul() {
+        utils::setup_logger();
+        let program = Program::from(BLS12381_FP6_MUL_ELF).unwrap();
+        utils::run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
     #[test]
     fn test_bn254_fp_ops() {
         utils::setup_logger();
@@ -46,6 +55,13 @@ mod tests {
         utils::run_test::<CpuProver<_, _>>(program).unwrap();
     }
 
+    #[test]
+    fn test_bn254_fp12_ops() {
+        utils::setup_logger();
+        let program = Program::from(BN254_FP12_ELF).unwrap();
+        utils::run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
     #[test]
     fn test_bn254_fp2_addsub() {
         utils::setup_logger();
@@ -22,10 +22,17 @@ impl KeccakPermuteChip {
 #[cfg(test)]
 pub mod permute_tests {
     use sp1_core_executor::{syscalls::SyscallCode, Executor, Instruction, Opcode, Program};
-    use sp1_stark::{CpuProver, SP1CoreOpts};
+    use sp1_stark::{
+        baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, SP1CoreOpts, ShardProof,
+        StarkGenericConfig,
+    };
     use test_artifacts::KECCAK_PERMUTE_ELF;
 
-    use crate::utils::{self, run_test};
+    use crate::{
+        io::SP1Stdin,
+        riscv::RiscvAir,
+        utils::{self, prove, run_test},
+    };
 
     pub fn keccak_permute_program() -> Program {
         let digest_ptr = 100;
@@ -67,4 +74,31 @@ pub mod permute_tests {
         let program = Program::from(KECCAK_PERMUTE_ELF).unwrap();
         run_test::<CpuProver<_, _>>(program).unwrap();
     }
+
+    #[test]
+    fn test_keccak_permute_inline() {
+        utils::setup_logger();
+        let program = Program::from(KECCAK_PERMUTE_ELF).unwrap();
+
+        // Keep the keccak events in the CPU shards which emitted them.
+        let mut opts = SP1CoreOpts::default();
+        opts.split_opts.deferral.keccak = false;
+
+        let config = BabyBearPoseidon2::new();
+        let (proof, _, _) =
+            prove::<_, CpuProver<_, _>>(program.clone(), &SP1Stdin::new(), config, opts, None)
+                .unwrap();
+        let keccak_shards = proof
+            .shard_proofs
+            .iter()
+            .filter(|shard| shard.chip_ordering.contains_key("KeccakPermute"));
+        assert!(keccak_shards.clone().count() > 0);
+        assert!(keccak_shards.clone().all(ShardProof::contains_cpu));
+
+        let config = BabyBearPoseidon2::new();
+        let mut challenger = config.challenger();
+        let machine = RiscvAir::machine(config);
+        let (_, vk) = machine.setup(&program);
+        machine.verify(&vk, &proof, &mut challenger).unwrap();
+    }
 }
@@ -0,0 +1,253 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_keccak_air::{KeccakAir, NUM_KECCAK_COLS, NUM_ROUNDS, U64_LIMBS};
+use p3_matrix::Matrix;
+use sp1_core_executor::syscalls::SyscallCode;
+use sp1_stark::{
+    air::{AirInteraction, InteractionScope, SP1AirBuilder, SubAirBuilder},
+    InteractionKind, Word,
+};
+
+use super::{
+    columns::{KeccakAccumulateCols, NUM_KECCAK_ACCUMULATE_COLS},
+    KeccakAccumulateChip, DIGEST_NUM_WORDS, RATE_NUM_WORDS, STATE_SIZE,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    operations::XorOperation,
+};
+
+impl<F> BaseAir<F> for KeccakAccumulateChip {
+    fn width(&self) -> usize {
+        NUM_KECCAK_ACCUMULATE_COLS
+    }
+}
+
+/// Interprets a u32 word, encoded as 4 8-bit columns, as two u16 limbs.
+fn word_to_limbs<AB: SP1AirBuilder>(word: &Word<AB::Var>) -> [AB::Expr; 2] {
+    let expr_2_pow_8 = AB::Expr::from_canonical_u32(2u32.pow(8));
+    [word[0] + word[1] * expr_2_pow_8.clone(), word[2] + word[3] * expr_2_pow_8]
+}
+
+impl<AB> Air<AB> for KeccakAccumulateChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &KeccakAccumulateCols<AB::Var> = (*local).borrow();
+        let next: &KeccakAccumulateCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let first_step = local.keccak.step_flags[0];
+        let final_step = local.keccak.step_flags[NUM_ROUNDS - 1];
+        let not_final_step = AB::Expr::one() - final_step;
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_final);
+
+        // Constrain the flags selecting which interactions happen on this row.
+        builder.assert_eq(local.receive_ecall, first_step * local.is_real);
+        builder.assert_eq(
+            local.receive_state,
+            local.receive_ecall * (AB::Expr::one() - local.is_first),
+        );
+        builder.assert_eq(
+            local.send_state,
+            final_step * local.is_real * (AB::Expr::one() - local.is_final),
+        );
+        builder.assert_eq(local.write_digest, final_step * local.is_real * local.is_final);
+
+        // Read the control word and the block in the first cycle.
+        builder.eval_memory_access(
+            local.shard,
+            local.clk,
+            local.acc_ptr,
+            &local.flags_mem,
+            local.receive_ecall,
+        );
+        for i in 0..RATE_NUM_WORDS as u32 {
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.block_ptr + AB::Expr::from_canonical_u32(i * 4),
+                &local.block_mem[i as usize],
+                local.receive_ecall,
+            );
+        }
+
+        // Write the digest in the last cycle of the final block. The clk increments by 1 after a
+        // final step.
+        for i in 0..DIGEST_NUM_WORDS as u32 {
+            builder.eval_memory_access(
+                local.shard,
+                local.clk + AB::Expr::one(),
+                local.acc_ptr + AB::Expr::from_canonical_u32((i + 1) * 4),
+                &local.digest_mem[i as usize],
+                local.write_digest,
+            );
+        }
+
+        // The control word holds the first and final flags in its low byte.
+        let flags = local.flags_mem.value();
+        let mut flags_builder = builder.when(local.receive_ecall);
+        flags_builder
+            .assert_eq(flags[0], local.is_first + local.is_final * AB::Expr::from_canonical_u32(2));
+        flags_builder.assert_zero(flags[1]);
+        flags_builder.assert_zero(flags[2]);
+        flags_builder.assert_zero(flags[3]);
+
+        // Receive the syscall in the first row of each 24-cycle
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::KECCAK_ACCUMULATE.syscall_id()),
+            local.block_ptr,
+            local.acc_ptr,
+            local.receive_ecall,
+            InteractionScope::Local,
+        );
+
+        // Constrain that the inputs stay the same throughout the 24 rows of each cycle
+        let mut transition_builder = builder.when_transition();
+        let mut transition_not_final_builder = transition_builder.when(not_final_step);
+        transition_not_final_builder.assert_eq(local.shard, next.shard);
+        transition_not_final_builder.assert_eq(local.clk, next.clk);
+        transition_not_final_builder.assert_eq(local.block_ptr, next.block_ptr);
+        transition_not_final_builder.assert_eq(local.acc_ptr, next.acc_ptr);
+        transition_not_final_builder.assert_eq(local.acc_shard, next.acc_shard);
+        transition_not_final_builder.assert_eq(local.acc_clk, next.acc_clk);
+        transition_not_final_builder.assert_eq(local.index, next.index);
+        transition_not_final_builder.assert_eq(local.is_first, next.is_first);
+        transition_not_final_builder.assert_eq(local.is_final, next.is_final);
+        transition_not_final_builder.assert_eq(local.is_real, next.is_real);
+
+        // The last row must be nonreal because NUM_ROUNDS is not a power of 2. This constraint
+        // ensures that the table does not end abruptly.
+        builder.when_last_row().assert_zero(local.is_real);
+
+        // Absorb the block by xoring it into the rate portion of the previous state. The xor
+        // lookups also range check `prev_rate` to be bytes.
+        for i in 0..RATE_NUM_WORDS {
+            XorOperation::<AB::F>::eval(
+                builder,
+                local.prev_rate[i],
+                *local.block_mem[i].value(),
+                local.absorbed[i],
+                local.receive_ecall,
+            );
+        }
+
+        // The first block starts the chain of states of the accumulator at index 0, identified by
+        // its own shard and clk, which no other syscall shares. Every later block receives the
+        // state sent by the block before it, at the next index, so the states of two accumulations
+        // reusing the same control word can't be swapped.
+        let mut first_builder = builder.when(local.receive_ecall * local.is_first);
+        first_builder.assert_zero(local.index);
+        first_builder.assert_eq(local.acc_shard, local.shard);
+        first_builder.assert_eq(local.acc_clk, local.clk);
+
+        // The previous state of the first block is all zero.
+        for i in 0..RATE_NUM_WORDS {
+            for byte in local.prev_rate[i].0 {
+                first_builder.assert_zero(byte);
+            }
+        }
+
+        // On a first step row, verify that local.p3_keccak_cols.a is the absorbed state, and
+        // collect the previous state limbs, keyed by the accumulator and block index.
+        let mut prev_state: Vec<AB::Expr> = vec![
+            local.acc_shard.into(),
+            local.acc_clk.into(),
+            local.acc_ptr.into(),
+            local.index.into(),
+        ];
+        for i in 0..STATE_SIZE {
+            let y_idx = i / 5;
+            let x_idx = i % 5;
+            let a_value_limbs = local.keccak.a[y_idx][x_idx];
+
+            if i < RATE_NUM_WORDS / 2 {
+                let absorbed_limbs = [
+                    word_to_limbs::<AB>(&local.absorbed[i * 2].value),
+                    word_to_limbs::<AB>(&local.absorbed[i * 2 + 1].value),
+                ]
+                .concat();
+                for (limb, absorbed_limb) in absorbed_limbs.into_iter().enumerate() {
+                    builder.when(local.receive_ecall).assert_eq(absorbed_limb, a_value_limbs[limb]);
+                }
+                prev_state.extend(word_to_limbs::<AB>(&local.prev_rate[i * 2]));
+                prev_state.extend(word_to_limbs::<AB>(&local.prev_rate[i * 2 + 1]));
+            } else {
+                // The capacity is untouched by the block.
+                for limb in a_value_limbs {
+                    builder.when(local.receive_ecall * local.is_first).assert_zero(limb);
+                    prev_state.push(limb.into());
+                }
+            }
+        }
+
+        // Receive the previous state from the block before this one.
+        builder.receive(
+            AirInteraction::new(
+                prev_state,
+                local.receive_state.into(),
+                InteractionKind::KeccakState,
+            ),
+            InteractionScope::Global,
+        );
+
+        // Send the permuted state to the block after this one, keyed by the next block index.
+        let mut next_state: Vec<AB::Expr> = vec![
+            local.acc_shard.into(),
+            local.acc_clk.into(),
+            local.acc_ptr.into(),
+            local.index + AB::Expr::one(),
+        ];
+        for i in 0..STATE_SIZE {
+            for limb in 0..U64_LIMBS {
+                next_state.push(local.keccak.a_prime_prime_prime(i / 5, i % 5, limb).into());
+            }
+        }
+        builder.send(
+            AirInteraction::new(next_state, local.send_state.into(), InteractionKind::KeccakState),
+            InteractionScope::Global,
+        );
+
+        // On the final block, verify the digest matches the first lanes of
+        // local.p3_keccak_cols.a_prime_prime_prime
+        for i in 0..DIGEST_NUM_WORDS / 2 {
+            let digest_limbs = [
+                word_to_limbs::<AB>(local.digest_mem[i * 2].value()),
+                word_to_limbs::<AB>(local.digest_mem[i * 2 + 1].value()),
+            ]
+            .concat();
+            for (limb, digest_limb) in digest_limbs.into_iter().enumerate() {
+                builder
+                    .when(local.write_digest)
+                    .assert_eq(digest_limb, local.keccak.a_prime_prime_prime(i / 5, i % 5, limb));
+            }
+        }
+
+        // Range check all the values in `digest_mem` to be bytes.
+        for i in 0..DIGEST_NUM_WORDS {
+            builder.slice_range_check_u8(&local.digest_mem[i].value().0, local.write_digest);
+        }
+
+        let mut sub_builder =
+            SubAirBuilder::<AB, KeccakAir, AB::Var>::new(builder, 0..NUM_KECCAK_COLS);
+
+        // Eval the plonky3 keccak air
+        self.p3_keccak.eval(&mut sub_builder);
+    }
+}
@@ -0,0 +1,68 @@
+use core::mem::size_of;
+
+use p3_keccak_air::KeccakCols;
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::XorOperation,
+};
+
+use super::{DIGEST_NUM_WORDS, RATE_NUM_WORDS};
+
+/// KeccakAccumulateCols is the column layout for absorbing one block into a keccak accumulator.
+///
+/// The columns defined in the `p3_keccak_air` crate are embedded here as `keccak`. Other columns
+/// are used to track the VM context and the sponge state carried between blocks.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub(crate) struct KeccakAccumulateCols<T> {
+    /// Keccak columns from p3_keccak_air. Note it is assumed in trace gen to be the first field.
+    pub keccak: KeccakCols<T>,
+
+    pub shard: T,
+    pub clk: T,
+    pub nonce: T,
+    pub block_ptr: T,
+    pub acc_ptr: T,
+
+    /// The shard and clk of the first block of the accumulator, which identify it on the global
+    /// bus, since the control word may be reused by a later accumulation.
+    pub acc_shard: T,
+    pub acc_clk: T,
+
+    /// The position of the block within the accumulator.
+    pub index: T,
+    pub is_first: T,
+    pub is_final: T,
+
+    /// Memory columns for the control word and the block.
+    pub flags_mem: MemoryReadCols<T>,
+    pub block_mem: [MemoryReadCols<T>; RATE_NUM_WORDS],
+
+    /// The rate portion of the sponge state before absorbing the block.
+    pub prev_rate: [Word<T>; RATE_NUM_WORDS],
+
+    /// The rate portion of the sponge state xored with the block.
+    pub absorbed: [XorOperation<T>; RATE_NUM_WORDS],
+
+    /// Memory columns for the digest, written on the final block.
+    pub digest_mem: [MemoryWriteCols<T>; DIGEST_NUM_WORDS],
+
+    // If row is real and first cycle of 24-cycle
+    pub receive_ecall: T,
+
+    // If row is real, first cycle of 24-cycle and not the first block
+    pub receive_state: T,
+
+    // If row is real, last cycle of 24-cycle and not the final block
+    pub send_state: T,
+
+    // If row is real, last cycle of 24-cycle and the final block
+    pub write_digest: T,
+
+    pub is_real: T,
+}
+
+pub const NUM_KECCAK_ACCUMULATE_COLS: usize = size_of::<KeccakAccumulateCols<u8>>();
@@ -0,0 +1,95 @@
+mod air;
+pub mod columns;
+mod trace;
+
+use p3_keccak_air::KeccakAir;
+
+pub(crate) const STATE_SIZE: usize = 25;
+
+// The rate of keccak-256 is 136 bytes, i.e. 17 of the 25 u64 lanes, or 34 words.
+pub const RATE_NUM_WORDS: usize = 34;
+
+// The digest is the first 4 lanes of the state after the final block, i.e. 8 words.
+pub const DIGEST_NUM_WORDS: usize = 8;
+
+/// A chip that absorbs one block per syscall into a keccak-256 accumulator.
+///
+/// Unlike [`super::keccak256::KeccakPermuteChip`], the sponge state is not kept in guest memory.
+/// Instead, each event receives the state left behind by the previous block of the same
+/// accumulator, and sends the state after its own block, over the global bus. This lets a single
+/// hash span any number of shards without shard cuts having to align with hash boundaries.
+pub struct KeccakAccumulateChip {
+    p3_keccak: KeccakAir,
+}
+
+impl KeccakAccumulateChip {
+    pub const fn new() -> Self {
+        Self { p3_keccak: KeccakAir {} }
+    }
+}
+
+#[cfg(test)]
+pub mod accumulate_tests {
+    use sp1_core_executor::Program;
+    use sp1_primitives::io::SP1PublicValues;
+    use sp1_stark::{
+        baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, SP1CoreOpts, StarkGenericConfig,
+    };
+    use test_artifacts::KECCAK_ACCUMULATE_ELF;
+    use tiny_keccak::Hasher;
+
+    use crate::{
+        io::SP1Stdin,
+        riscv::RiscvAir,
+        utils::{self, prove, run_test_io},
+    };
+
+    fn keccak256(input: &[u8]) -> [u8; 32] {
+        let mut keccak = tiny_keccak::Keccak::v256();
+        keccak.update(input);
+        let mut hash = [0u8; 32];
+        keccak.finalize(&mut hash);
+        hash
+    }
+
+    #[test]
+    fn test_keccak_accumulate_program_prove() {
+        utils::setup_logger();
+        let program = Program::from(KECCAK_ACCUMULATE_ELF).unwrap();
+        let input = (0..1000u32).map(|i| i as u8).collect::<Vec<_>>();
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&input);
+
+        let mut public_values = run_test_io::<CpuProver<_, _>>(program, stdin).unwrap();
+        assert_eq!(public_values.read::<[u8; 32]>(), keccak256(&input));
+    }
+
+    #[test]
+    fn test_keccak_accumulate_across_shards() {
+        utils::setup_logger();
+        let program = Program::from(KECCAK_ACCUMULATE_ELF).unwrap();
+        let input = (0..20_000u32).map(|i| (i * 7) as u8).collect::<Vec<_>>();
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&input);
+
+        // Use small shards and a low keccak threshold so the blocks of the hash are spread over
+        // several core and precompile shards.
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 12;
+        opts.split_opts.keccak = 16;
+
+        let config = BabyBearPoseidon2::new();
+        let (proof, public_values, _) =
+            prove::<_, CpuProver<_, _>>(program.clone(), &stdin, config, opts, None).unwrap();
+        assert!(proof.shard_proofs.len() > 2);
+
+        let config = BabyBearPoseidon2::new();
+        let mut challenger = config.challenger();
+        let machine = RiscvAir::machine(config);
+        let (_, vk) = machine.setup(&program);
+        machine.verify(&vk, &proof, &mut challenger).unwrap();
+
+        let mut public_values = SP1PublicValues::from(&public_values);
+        assert_eq!(public_values.read::<[u8; 32]>(), keccak256(&input));
+    }
+}
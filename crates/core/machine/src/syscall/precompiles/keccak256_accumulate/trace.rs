@@ -0,0 +1,194 @@
+use std::borrow::BorrowMut;
+
+use p3_field::PrimeField32;
+use p3_keccak_air::{generate_trace_rows, NUM_KECCAK_COLS, NUM_ROUNDS};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelBridge, ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, KeccakAccumulateEvent, PrecompileEvent, SyscallEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_stark::air::{InteractionScope, MachineAir};
+
+use crate::utils::zeroed_f_vec;
+
+use super::{
+    columns::{KeccakAccumulateCols, NUM_KECCAK_ACCUMULATE_COLS},
+    KeccakAccumulateChip, RATE_NUM_WORDS, STATE_SIZE,
+};
+use sp1_core_executor::events::ByteRecord;
+
+impl<F: PrimeField32> MachineAir<F> for KeccakAccumulateChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "KeccakAccumulate".to_string()
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let chunk_size = 8;
+
+        let blu_events: Vec<Vec<ByteLookupEvent>> = input
+            .get_precompile_events(SyscallCode::KECCAK_ACCUMULATE)
+            .par_chunks(chunk_size)
+            .map(|ops: &[(SyscallEvent, PrecompileEvent)]| {
+                let mut blu = Vec::new();
+                let mut chunk = zeroed_f_vec::<F>(NUM_KECCAK_ACCUMULATE_COLS * NUM_ROUNDS);
+                ops.iter().for_each(|(_, op)| {
+                    if let PrecompileEvent::KeccakAccumulate(event) = op {
+                        Self::populate_chunk(event, &mut chunk, &mut blu);
+                    } else {
+                        unreachable!();
+                    }
+                });
+                blu
+            })
+            .collect();
+        for blu in blu_events {
+            output.add_byte_lookup_events(blu);
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(SyscallCode::KECCAK_ACCUMULATE);
+        let num_events = events.len();
+        let num_rows = (num_events * NUM_ROUNDS).next_power_of_two();
+        let chunk_size = 8;
+        let mut values = zeroed_f_vec::<F>(num_rows * NUM_KECCAK_ACCUMULATE_COLS);
+
+        let dummy_keccak_rows = generate_trace_rows::<F>(vec![[0; STATE_SIZE]]);
+        let mut dummy_chunk = Vec::new();
+        for i in 0..NUM_ROUNDS {
+            let dummy_row = dummy_keccak_rows.row(i);
+            let mut row = [F::zero(); NUM_KECCAK_ACCUMULATE_COLS];
+            row[..NUM_KECCAK_COLS].copy_from_slice(dummy_row.collect::<Vec<_>>().as_slice());
+            dummy_chunk.extend_from_slice(&row);
+        }
+
+        values
+            .chunks_mut(chunk_size * NUM_KECCAK_ACCUMULATE_COLS * NUM_ROUNDS)
+            .enumerate()
+            .par_bridge()
+            .for_each(|(i, rows)| {
+                rows.chunks_mut(NUM_ROUNDS * NUM_KECCAK_ACCUMULATE_COLS).enumerate().for_each(
+                    |(j, rounds)| {
+                        let idx = i * chunk_size + j;
+                        if idx < num_events {
+                            let mut new_byte_lookup_events = Vec::new();
+                            if let PrecompileEvent::KeccakAccumulate(event) = &events[idx].1 {
+                                Self::populate_chunk(event, rounds, &mut new_byte_lookup_events);
+                            } else {
+                                unreachable!();
+                            }
+                        } else {
+                            rounds.copy_from_slice(&dummy_chunk[..rounds.len()]);
+                        }
+                    },
+                );
+            });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(values, NUM_KECCAK_ACCUMULATE_COLS);
+
+        // Write the nonce to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut KeccakAccumulateCols<F> = trace.values
+                [i * NUM_KECCAK_ACCUMULATE_COLS..(i + 1) * NUM_KECCAK_ACCUMULATE_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::KECCAK_ACCUMULATE).is_empty()
+        }
+    }
+
+    fn commit_scope(&self) -> InteractionScope {
+        InteractionScope::Global
+    }
+}
+
+impl KeccakAccumulateChip {
+    pub fn populate_chunk<F: PrimeField32>(
+        event: &KeccakAccumulateEvent,
+        chunk: &mut [F],
+        new_byte_lookup_events: &mut Vec<ByteLookupEvent>,
+    ) {
+        let start_clk = event.clk;
+        let shard = event.shard;
+
+        // Absorb the block into the rate portion of the previous state.
+        let mut absorbed = event.pre_state;
+        for (i, words) in event.block.chunks_exact(2).enumerate() {
+            absorbed[i] ^= words[0] as u64 + ((words[1] as u64) << 32);
+        }
+        let p3_keccak_trace = generate_trace_rows::<F>(vec![absorbed]);
+
+        // Create all the rows for the permutation.
+        for i in 0..NUM_ROUNDS {
+            let p3_keccak_row = p3_keccak_trace.row(i);
+            let row =
+                &mut chunk[i * NUM_KECCAK_ACCUMULATE_COLS..(i + 1) * NUM_KECCAK_ACCUMULATE_COLS];
+            // Copy p3_keccak_row into start of cols
+            row[..NUM_KECCAK_COLS].copy_from_slice(p3_keccak_row.collect::<Vec<_>>().as_slice());
+            let cols: &mut KeccakAccumulateCols<F> = row.borrow_mut();
+
+            cols.shard = F::from_canonical_u32(shard);
+            cols.clk = F::from_canonical_u32(start_clk);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.acc_ptr = F::from_canonical_u32(event.acc_ptr);
+            cols.acc_shard = F::from_canonical_u32(event.acc_shard);
+            cols.acc_clk = F::from_canonical_u32(event.acc_clk);
+            cols.index = F::from_canonical_u32(event.index);
+            cols.is_first = F::from_bool(event.is_first);
+            cols.is_final = F::from_bool(event.is_final);
+            cols.is_real = F::one();
+
+            // If this is the first row, then populate the reads and the absorbed block.
+            if i == 0 {
+                cols.flags_mem.populate(event.flags_read_record, new_byte_lookup_events);
+                for j in 0..RATE_NUM_WORDS {
+                    cols.block_mem[j].populate(event.block_read_records[j], new_byte_lookup_events);
+
+                    let lane = event.pre_state[j / 2];
+                    let prev_word = if j % 2 == 0 { lane as u32 } else { (lane >> 32) as u32 };
+                    cols.prev_rate[j] = prev_word.into();
+                    cols.absorbed[j].populate(
+                        new_byte_lookup_events,
+                        shard,
+                        prev_word,
+                        event.block[j],
+                    );
+                }
+                cols.receive_ecall = F::one();
+                cols.receive_state = F::from_bool(!event.is_first);
+            }
+
+            // If this is the last row, then either write the digest or pass on the state.
+            if i == NUM_ROUNDS - 1 {
+                if event.is_final {
+                    for (j, write_record) in event.digest_write_records.iter().enumerate() {
+                        cols.digest_mem[j].populate(*write_record, new_byte_lookup_events);
+                        new_byte_lookup_events
+                            .add_u8_range_checks(shard, &write_record.value.to_le_bytes());
+                    }
+                    cols.write_digest = F::one();
+                } else {
+                    cols.send_state = F::one();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        keccak_merkle_state, ByteRecord, PrecompileEvent, KECCAK_MERKLE_MAX_DEPTH,
+        KECCAK_MERKLE_STEP_CYCLES,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the KeccakMerkleCols.
+const NUM_COLS: usize = size_of::<KeccakMerkleCols<u8>>();
+
+/// The number of words in a keccak state.
+const STATE_NUM_WORDS: usize = 50;
+
+/// The number of words in a node.
+const NODE_NUM_WORDS: usize = 8;
+
+/// The index of the last level of an event.
+const LAST_LEVEL: usize = KECCAK_MERKLE_MAX_DEPTH - 1;
+
+/// A chip computing the root of a keccak-256 Merkle path of up to [`KECCAK_MERKLE_MAX_DEPTH`]
+/// levels.
+///
+/// The syscall takes a pointer to a keccak state whose first 8 words hold the node, and a pointer
+/// to the depth of the path, the index of the node and the sibling of every level.
+///
+/// Every event takes a row per level. The row of a level that is part of the path overwrites the
+/// state with the padded concatenation of the node and its sibling, and then sends a
+/// `KECCAK_PERMUTE` syscall on the state, which is proven by the
+/// [`super::keccak256::KeccakPermuteChip`] and leaves the hash in the first 8 words of the state.
+/// The node of a level is thus the previous value of the first 8 words of the state it writes.
+#[derive(Default)]
+pub struct KeccakMerkleChip;
+
+impl KeccakMerkleChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the KeccakMerkle operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct KeccakMerkleCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the keccak state, which starts with the node.
+    pub state_ptr: T,
+
+    /// The pointer to the depth of the path, which is followed by the index and the siblings.
+    pub path_ptr: T,
+
+    /// The number of levels of the path.
+    pub depth: T,
+
+    /// The bits of the index of the node.
+    pub index_bits: [T; KECCAK_MERKLE_MAX_DEPTH],
+
+    /// The level of the row, as a one-hot encoding.
+    pub level: [T; KECCAK_MERKLE_MAX_DEPTH],
+
+    /// Whether the level is part of the path, which holds for the first `depth` levels.
+    pub is_level: T,
+
+    /// The number of levels of the path up to and including this level.
+    pub num_levels: T,
+
+    /// Whether the node is the right child at this level.
+    pub is_right: T,
+
+    /// The nonce of the `KECCAK_PERMUTE` syscall of the level.
+    pub permute_nonce: T,
+
+    // Memory columns.
+    // The depth and the index are read on the first level.
+    pub depth_memory: MemoryReadCols<T>,
+    pub index_memory: MemoryReadCols<T>,
+    pub sibling_memory: [MemoryReadCols<T>; NODE_NUM_WORDS],
+
+    /// The state, written with the padded children of the level.
+    pub state_memory: [MemoryWriteCols<T>; STATE_NUM_WORDS],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for KeccakMerkleChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "KeccakMerkle".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::KECCAK_MERKLE_ROOT) {
+            let event = if let PrecompileEvent::KeccakMerkle(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let depth = event.depth as usize;
+
+            for level in 0..KECCAK_MERKLE_MAX_DEPTH {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut KeccakMerkleCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                cols.path_ptr = F::from_canonical_u32(event.path_ptr);
+                cols.depth = F::from_canonical_usize(depth);
+                for (i, bit) in cols.index_bits.iter_mut().enumerate() {
+                    *bit = F::from_canonical_u32((event.index >> i) & 1);
+                }
+                cols.level[level] = F::one();
+                cols.is_level = F::from_bool(level < depth);
+                cols.num_levels = F::from_canonical_usize((level + 1).min(depth));
+                cols.is_right = F::from_canonical_u32((event.index >> level) & 1);
+
+                // Populate memory columns.
+                if level == 0 {
+                    cols.depth_memory
+                        .populate(event.depth_memory_record, &mut new_byte_lookup_events);
+                    cols.index_memory
+                        .populate(event.index_memory_record, &mut new_byte_lookup_events);
+                }
+                if level < depth {
+                    cols.permute_nonce = F::from_canonical_u32(event.permute_syscalls[level].nonce);
+                    for (col, record) in
+                        cols.sibling_memory.iter_mut().zip(&event.sibling_read_records[level])
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                    for (col, record) in
+                        cols.state_memory.iter_mut().zip(&event.state_write_records[level])
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut KeccakMerkleCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::KECCAK_MERKLE_ROOT).is_empty()
+        }
+    }
+
+    fn commit_scope(&self) -> InteractionScope {
+        InteractionScope::Global
+    }
+}
+
+impl<F> BaseAir<F> for KeccakMerkleChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for KeccakMerkleChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &KeccakMerkleCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &KeccakMerkleCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans, and only set on real rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_level);
+        builder.assert_bool(local.is_right);
+        for bit in local.index_bits.iter().chain(local.level.iter()) {
+            builder.assert_bool(*bit);
+        }
+        builder.when(local.is_level).assert_one(local.is_real);
+
+        // Every real row has exactly one level.
+        let level_sum = local.level.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(level_sum, local.is_real);
+        let (is_first, is_last) = (local.level[0], local.level[LAST_LEVEL]);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(is_first);
+        builder.when_last_row().when(local.is_real).assert_one(is_last);
+        builder.when_transition().when(is_last).assert_eq(next.level[0], next.is_real);
+
+        // The rows of an event share the arguments, and go through the levels in order.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.state_ptr, next.state_ptr);
+        continuation.assert_eq(local.path_ptr, next.path_ptr);
+        continuation.assert_eq(local.depth, next.depth);
+        continuation.assert_all_eq(local.index_bits, next.index_bits);
+        continuation.assert_zero(next.level[0]);
+        for k in 0..LAST_LEVEL {
+            continuation.assert_eq(local.level[k], next.level[k + 1]);
+        }
+        // Once a level is not part of the path, neither are the following levels of the event.
+        continuation.when(next.is_level).assert_one(local.is_level);
+        continuation.assert_eq(local.num_levels + next.is_level, next.num_levels);
+
+        // The first level reads the depth and the index, and the last level has gone through all
+        // the levels of the path.
+        builder.when(is_first).assert_one(local.is_level);
+        builder.when(is_first).assert_one(local.num_levels);
+        builder.when(is_last).assert_eq(local.num_levels, local.depth);
+        let depth_bytes = local.depth_memory.value();
+        let index_bytes = local.index_memory.value();
+        builder.when(is_first).assert_eq(local.depth, depth_bytes[0]);
+        let index =
+            local.index_bits.iter().enumerate().fold(AB::Expr::zero(), |acc, (i, &bit)| {
+                acc + bit * AB::F::from_canonical_u32(1 << i)
+            });
+        builder.when(is_first).assert_eq(index, index_bytes[0]);
+        for i in 1..4 {
+            builder.when(is_first).assert_zero(depth_bytes[i]);
+            builder.when(is_first).assert_zero(index_bytes[i]);
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.path_ptr,
+            &[local.depth_memory, local.index_memory],
+            is_first,
+        );
+
+        // The node is the right child if the bit of the level is set.
+        let is_right = local
+            .level
+            .iter()
+            .zip(local.index_bits.iter())
+            .fold(AB::Expr::zero(), |acc, (&level, &bit)| acc + level * bit);
+        builder.assert_eq(local.is_right, is_right);
+
+        // Every level of the path starts its own step of the syscall.
+        let level_index =
+            local.level.iter().enumerate().fold(AB::Expr::zero(), |acc, (k, &level)| {
+                acc + level * AB::F::from_canonical_usize(k)
+            });
+        let step_clk: AB::Expr =
+            local.clk + level_index.clone() * AB::F::from_canonical_u32(KECCAK_MERKLE_STEP_CYCLES);
+
+        // Read the sibling and write the padded children to the state.
+        builder.eval_memory_access_slice(
+            local.shard,
+            step_clk.clone(),
+            local.path_ptr
+                + AB::Expr::from_canonical_u32(8)
+                + level_index * AB::Expr::from_canonical_usize(NODE_NUM_WORDS * 4),
+            &local.sibling_memory,
+            local.is_level,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            step_clk.clone(),
+            local.state_ptr,
+            &local.state_memory,
+            local.is_level,
+        );
+
+        // The node is the previous value of the first words of the state. The children are
+        // swapped if the node is the right child, and the rest of the state is the padding.
+        for i in 0..NODE_NUM_WORDS {
+            let node = local.state_memory[i].prev_value();
+            let sibling = local.sibling_memory[i].value();
+            let left = local.state_memory[i].value();
+            let right = local.state_memory[NODE_NUM_WORDS + i].value();
+            for j in 0..4 {
+                let swap: AB::Expr = local.is_right * (sibling[j] - node[j]);
+                builder.when(local.is_level).assert_eq(left[j], swap.clone() + node[j]);
+                builder.when(local.is_level).assert_eq(right[j], AB::Expr::from(sibling[j]) - swap);
+            }
+        }
+        let padding = keccak_merkle_state(&[0; NODE_NUM_WORDS], &[0; NODE_NUM_WORDS]);
+        for i in 2 * NODE_NUM_WORDS..STATE_NUM_WORDS {
+            let value = local.state_memory[i].value();
+            for (j, byte) in padding[i].to_le_bytes().into_iter().enumerate() {
+                builder.when(local.is_level).assert_eq(value[j], AB::F::from_canonical_u8(byte));
+            }
+        }
+
+        // Send the permutation of the state. It is proven in its own shard, so it is sent
+        // globally, as the core shards do.
+        builder.send_syscall(
+            local.shard,
+            step_clk + AB::Expr::one(),
+            local.permute_nonce,
+            AB::F::from_canonical_u32(SyscallCode::KECCAK_PERMUTE.syscall_id()),
+            local.state_ptr,
+            AB::Expr::zero(),
+            local.is_level,
+            InteractionScope::Global,
+        );
+
+        // Receive the arguments on the first level of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::KECCAK_MERKLE_ROOT.syscall_id()),
+            local.state_ptr,
+            local.path_ptr,
+            is_first,
+            InteractionScope::Local,
+        );
+    }
+}
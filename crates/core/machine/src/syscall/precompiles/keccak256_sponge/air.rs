@@ -0,0 +1,315 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        keccak_pad_block, ByteLookupEvent, ByteRecord, PrecompileEvent, KECCAK_SPONGE_RATE_BYTES,
+        KECCAK_SPONGE_RATE_WORDS,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::{
+    air::{InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord, Word,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the KeccakSpongeCols.
+const NUM_COLS: usize = size_of::<KeccakSpongeCols<u8>>();
+
+/// A chip absorbing blocks into a keccak-256 sponge kept in guest memory.
+///
+/// Both syscalls take a pointer to the 25-lane sponge state and a pointer to a block. The block
+/// of `KECCAK_ABSORB` is absorbed as is, while the block of `KECCAK_FINALIZE` holds the tail of the
+/// input followed by its length in bytes, and is padded with the keccak padding first.
+///
+/// The padded block is xored into the rate of the state, which then gets permuted by a
+/// `KECCAK_PERMUTE` syscall proven by the [`super::keccak256::KeccakPermuteChip`].
+#[derive(Default)]
+pub struct KeccakSpongeChip;
+
+impl KeccakSpongeChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the KeccakSponge operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct KeccakSpongeCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the sponge state.
+    pub state_ptr: T,
+
+    /// The pointer to the block.
+    pub block_ptr: T,
+
+    /// The nonce of the `KECCAK_PERMUTE` syscall.
+    pub permute_nonce: T,
+
+    // Memory columns.
+    pub block_memory: [MemoryReadCols<T>; KECCAK_SPONGE_RATE_WORDS],
+
+    /// The length of the tail, read after the block on `KECCAK_FINALIZE`.
+    pub len_memory: MemoryReadCols<T>,
+
+    /// The rate of the state, written with the padded block xored in.
+    pub state_memory: [MemoryWriteCols<T>; KECCAK_SPONGE_RATE_WORDS],
+
+    /// Flags the position of the first padding byte, which is the length of the tail.
+    pub pad_flags: [T; KECCAK_SPONGE_RATE_BYTES],
+
+    /// The block after padding.
+    pub padded: [Word<T>; KECCAK_SPONGE_RATE_WORDS],
+
+    pub is_absorb: T,
+    pub is_finalize: T,
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for KeccakSpongeChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "KeccakSponge".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::KECCAK_ABSORB) {
+            let event = if let PrecompileEvent::KeccakSponge(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+            let cols: &mut KeccakSpongeCols<F> = row.as_mut_slice().borrow_mut();
+
+            // Assign basic values to the columns.
+            cols.is_real = F::one();
+            cols.is_absorb = F::from_bool(!event.is_final);
+            cols.is_finalize = F::from_bool(event.is_final);
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.permute_nonce = F::from_canonical_u32(event.permute_syscall.nonce);
+
+            // Populate memory columns.
+            for i in 0..KECCAK_SPONGE_RATE_WORDS {
+                cols.block_memory[i]
+                    .populate(event.block_read_records[i], &mut new_byte_lookup_events);
+                cols.state_memory[i]
+                    .populate(event.state_write_records[i], &mut new_byte_lookup_events);
+            }
+
+            // Pad the block.
+            let block =
+                event.block_read_records.iter().map(|record| record.value).collect::<Vec<_>>();
+            let padded = match event.len_read_record {
+                Some(record) => {
+                    cols.len_memory.populate(record, &mut new_byte_lookup_events);
+                    cols.pad_flags[record.value as usize] = F::one();
+                    keccak_pad_block(&block, record.value as usize)
+                }
+                None => block,
+            };
+
+            // The padded block is xored into the rate byte by byte.
+            for i in 0..KECCAK_SPONGE_RATE_WORDS {
+                cols.padded[i] = Word::from(padded[i]);
+                let record = event.state_write_records[i];
+                let (prev, value) = (record.prev_value.to_le_bytes(), record.value.to_le_bytes());
+                for j in 0..WORD_SIZE {
+                    new_byte_lookup_events.push(ByteLookupEvent {
+                        shard: event.shard,
+                        opcode: ByteOpcode::XOR,
+                        a1: value[j] as u16,
+                        a2: 0,
+                        b: prev[j],
+                        c: padded[i].to_le_bytes()[j],
+                    });
+                }
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut KeccakSpongeCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::KECCAK_ABSORB).is_empty()
+        }
+    }
+
+    fn commit_scope(&self) -> InteractionScope {
+        InteractionScope::Global
+    }
+}
+
+impl<F> BaseAir<F> for KeccakSpongeChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for KeccakSpongeChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &KeccakSpongeCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &KeccakSpongeCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // A real row is either an absorb or a finalize.
+        builder.assert_bool(local.is_absorb);
+        builder.assert_bool(local.is_finalize);
+        builder.assert_bool(local.is_real);
+        builder.assert_eq(local.is_real, local.is_absorb + local.is_finalize);
+
+        // Exactly one padding byte is flagged on a finalize, at the length of the tail.
+        let mut num_flags = AB::Expr::zero();
+        let mut len = AB::Expr::zero();
+        for (i, &flag) in local.pad_flags.iter().enumerate() {
+            builder.assert_bool(flag);
+            num_flags = num_flags + flag;
+            len = len + flag * AB::F::from_canonical_usize(i);
+        }
+        builder.assert_eq(num_flags, local.is_finalize);
+        builder.when(local.is_finalize).assert_eq(local.len_memory.value().reduce::<AB>(), len);
+
+        // The padded block keeps the bytes before the first padding byte, which is 0x01, and
+        // zeroes the ones after it. The last byte of a finalized block is also xored with 0x80.
+        let mut before = AB::Expr::one();
+        for i in 0..KECCAK_SPONGE_RATE_BYTES {
+            let (word, byte) = (i / WORD_SIZE, i % WORD_SIZE);
+            let flag = local.pad_flags[i];
+            before = before - flag;
+            let mut expected = before.clone() * local.block_memory[word].value()[byte] + flag;
+            if i == KECCAK_SPONGE_RATE_BYTES - 1 {
+                expected = expected + local.is_finalize * AB::F::from_canonical_u8(0x80);
+            }
+            builder.when(local.is_real).assert_eq(local.padded[word][byte], expected);
+        }
+
+        // The padded block is xored into the rate. The lookups also range check the padded bytes.
+        for i in 0..KECCAK_SPONGE_RATE_WORDS {
+            let prev = local.state_memory[i].prev_value();
+            let value = local.state_memory[i].value();
+            for j in 0..WORD_SIZE {
+                builder.send_byte(
+                    AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                    value[j],
+                    prev[j],
+                    local.padded[i][j],
+                    local.is_real,
+                );
+            }
+        }
+
+        // Read the block and write the rate of the state.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.block_ptr,
+            &local.block_memory,
+            local.is_real,
+        );
+        builder.eval_memory_access(
+            local.shard,
+            local.clk,
+            local.block_ptr + AB::F::from_canonical_usize(KECCAK_SPONGE_RATE_BYTES),
+            &local.len_memory,
+            local.is_finalize,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.state_ptr,
+            &local.state_memory,
+            local.is_real,
+        );
+
+        // Send the permutation of the state. It is proven in its own shard, so it is sent
+        // globally, as the core shards do.
+        builder.send_syscall(
+            local.shard,
+            local.clk + AB::Expr::one(),
+            local.permute_nonce,
+            AB::F::from_canonical_u32(SyscallCode::KECCAK_PERMUTE.syscall_id()),
+            local.state_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+            InteractionScope::Global,
+        );
+
+        // Receive the arguments.
+        let syscall_id = local.is_absorb
+            * AB::F::from_canonical_u32(SyscallCode::KECCAK_ABSORB.syscall_id())
+            + local.is_finalize
+                * AB::F::from_canonical_u32(SyscallCode::KECCAK_FINALIZE.syscall_id());
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            syscall_id,
+            local.state_ptr,
+            local.block_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
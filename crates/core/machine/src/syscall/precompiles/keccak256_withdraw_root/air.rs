@@ -0,0 +1,384 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        keccak_merkle_state, withdraw_root_is_hash, withdraw_root_node, ByteRecord,
+        PrecompileEvent, WITHDRAW_ROOT_HASHES, WITHDRAW_ROOT_MAX_DEPTH, WITHDRAW_ROOT_STEP_CYCLES,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the WithdrawRootCols.
+const NUM_COLS: usize = size_of::<WithdrawRootCols<u8>>();
+
+/// The number of words in a keccak state.
+const STATE_NUM_WORDS: usize = 50;
+
+/// The number of words in a node.
+const NODE_NUM_WORDS: usize = 8;
+
+/// The index of the last step of an event.
+const LAST_STEP: usize = WITHDRAW_ROOT_HASHES - 1;
+
+/// A chip computing the root of a balanced keccak-256 Merkle tree of up to
+/// [`WITHDRAW_ROOT_MAX_DEPTH`] levels, such as the withdrawal tree of a batch.
+///
+/// The syscall takes a pointer to a keccak state, and a pointer to the depth of the tree followed
+/// by its leaves, which are overwritten level by level with the nodes of the tree.
+///
+/// Every event takes a row per node of a tree of [`WITHDRAW_ROOT_MAX_DEPTH`] levels, and each row
+/// is bound to a fixed node, so the shape of the tree only depends on its depth. The row of a node
+/// that is part of the tree overwrites the state with the padded concatenation of its children,
+/// sends a `KECCAK_PERMUTE` syscall on the state, which is proven by the
+/// [`super::keccak256::KeccakPermuteChip`], and copies the hash left in the first 8 words of the
+/// state over the leaf of the same index as the node.
+#[derive(Default)]
+pub struct WithdrawRootChip;
+
+impl WithdrawRootChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the WithdrawRoot operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WithdrawRootCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the keccak state.
+    pub state_ptr: T,
+
+    /// The pointer to the depth of the tree, which is followed by the leaves.
+    pub tree_ptr: T,
+
+    /// The number of levels of the tree, as a one-hot encoding starting at one level.
+    pub depth: [T; WITHDRAW_ROOT_MAX_DEPTH],
+
+    /// The step of the row, as a one-hot encoding.
+    pub step: [T; WITHDRAW_ROOT_HASHES],
+
+    /// Whether the node of the step is part of the tree.
+    pub is_hash: T,
+
+    /// The nonce of the `KECCAK_PERMUTE` syscall of the step.
+    pub permute_nonce: T,
+
+    // Memory columns.
+    // The depth is read on the first step.
+    pub depth_memory: MemoryReadCols<T>,
+    pub children_memory: [MemoryReadCols<T>; 2 * NODE_NUM_WORDS],
+
+    /// The state, written with the padded children of the node.
+    pub state_memory: [MemoryWriteCols<T>; STATE_NUM_WORDS],
+
+    /// The hash of the node, read from the permuted state.
+    pub hash_memory: [MemoryReadCols<T>; NODE_NUM_WORDS],
+
+    /// The node, written over the leaf of the same index.
+    pub node_memory: [MemoryWriteCols<T>; NODE_NUM_WORDS],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for WithdrawRootChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "WithdrawRoot".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::WITHDRAW_ROOT) {
+            let event = if let PrecompileEvent::WithdrawRoot(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let depth = event.depth as usize;
+
+            // The records of the event only cover the nodes of the tree, in the order of the steps.
+            let mut hash = 0;
+            for step in 0..WITHDRAW_ROOT_HASHES {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut WithdrawRootCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                cols.tree_ptr = F::from_canonical_u32(event.tree_ptr);
+                cols.depth[depth - 1] = F::one();
+                cols.step[step] = F::one();
+
+                // Populate memory columns.
+                if step == 0 {
+                    cols.depth_memory
+                        .populate(event.depth_memory_record, &mut new_byte_lookup_events);
+                }
+                if withdraw_root_is_hash(step, depth) {
+                    cols.is_hash = F::one();
+                    cols.permute_nonce = F::from_canonical_u32(event.permute_syscalls[hash].nonce);
+                    for (col, record) in
+                        cols.children_memory.iter_mut().zip(&event.children_read_records[hash])
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                    for (col, record) in
+                        cols.state_memory.iter_mut().zip(&event.state_write_records[hash])
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                    for (col, record) in
+                        cols.hash_memory.iter_mut().zip(&event.hash_read_records[hash])
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                    for (col, record) in
+                        cols.node_memory.iter_mut().zip(&event.node_write_records[hash])
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                    hash += 1;
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut WithdrawRootCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::WITHDRAW_ROOT).is_empty()
+        }
+    }
+
+    fn commit_scope(&self) -> InteractionScope {
+        InteractionScope::Global
+    }
+}
+
+impl<F> BaseAir<F> for WithdrawRootChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for WithdrawRootChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WithdrawRootCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WithdrawRootCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans, and only set on real rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_hash);
+        for flag in local.depth.iter().chain(local.step.iter()) {
+            builder.assert_bool(*flag);
+        }
+
+        // Every real row has exactly one depth and one step.
+        let depth_sum = local.depth.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(depth_sum, local.is_real);
+        let step_sum = local.step.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(step_sum, local.is_real);
+        let (is_first, is_last) = (local.step[0], local.step[LAST_STEP]);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(is_first);
+        builder.when_last_row().when(local.is_real).assert_one(is_last);
+        builder.when_transition().when(is_last).assert_eq(next.step[0], next.is_real);
+
+        // The rows of an event share the arguments, and go through the steps in order.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.state_ptr, next.state_ptr);
+        continuation.assert_eq(local.tree_ptr, next.tree_ptr);
+        continuation.assert_all_eq(local.depth, next.depth);
+        continuation.assert_zero(next.step[0]);
+        for k in 0..LAST_STEP {
+            continuation.assert_eq(local.step[k], next.step[k + 1]);
+        }
+
+        // The first step reads the depth of the tree.
+        let depth_bytes = local.depth_memory.value();
+        let depth = local.depth.iter().enumerate().fold(AB::Expr::zero(), |acc, (d, &flag)| {
+            acc + flag * AB::F::from_canonical_usize(d + 1)
+        });
+        builder.when(is_first).assert_eq(depth, depth_bytes[0]);
+        for i in 1..4 {
+            builder.when(is_first).assert_zero(depth_bytes[i]);
+        }
+        builder.eval_memory_access(
+            local.shard,
+            local.clk,
+            local.tree_ptr,
+            &local.depth_memory,
+            is_first,
+        );
+
+        // The node of a step is part of the tree if its level and index fit in the depth, which
+        // keeps the tree balanced.
+        let mut is_hash = AB::Expr::zero();
+        let mut step_index = AB::Expr::zero();
+        let mut node_offset = AB::Expr::zero();
+        for (k, &step) in local.step.iter().enumerate() {
+            for (d, &flag) in local.depth.iter().enumerate() {
+                if withdraw_root_is_hash(k, d + 1) {
+                    is_hash = is_hash + step * flag;
+                }
+            }
+            let (_, index) = withdraw_root_node(k);
+            step_index = step_index + step * AB::F::from_canonical_usize(k);
+            node_offset =
+                node_offset + step * AB::F::from_canonical_usize(index * NODE_NUM_WORDS * 4);
+        }
+        builder.assert_eq(local.is_hash, is_hash);
+
+        // Every step starts its own cycles.
+        let step_clk: AB::Expr =
+            local.clk + step_index * AB::F::from_canonical_u32(WITHDRAW_ROOT_STEP_CYCLES);
+
+        // Read the children and write them, padded, to the state. The children of the node of
+        // index `j` are at the indices `2j` and `2j + 1` of the level below.
+        let leaves_ptr: AB::Expr = local.tree_ptr + AB::Expr::from_canonical_u32(4);
+        builder.eval_memory_access_slice(
+            local.shard,
+            step_clk.clone(),
+            leaves_ptr.clone() + node_offset.clone() * AB::F::two(),
+            &local.children_memory,
+            local.is_hash,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            step_clk.clone(),
+            local.state_ptr,
+            &local.state_memory,
+            local.is_hash,
+        );
+        for i in 0..2 * NODE_NUM_WORDS {
+            builder
+                .when(local.is_hash)
+                .assert_word_eq(*local.state_memory[i].value(), *local.children_memory[i].value());
+        }
+        let padding = keccak_merkle_state(&[0; NODE_NUM_WORDS], &[0; NODE_NUM_WORDS]);
+        for i in 2 * NODE_NUM_WORDS..STATE_NUM_WORDS {
+            let value = local.state_memory[i].value();
+            for (j, byte) in padding[i].to_le_bytes().into_iter().enumerate() {
+                builder.when(local.is_hash).assert_eq(value[j], AB::F::from_canonical_u8(byte));
+            }
+        }
+
+        // Send the permutation of the state. It is proven in its own shard, so it is sent
+        // globally, as the core shards do.
+        builder.send_syscall(
+            local.shard,
+            step_clk.clone() + AB::Expr::one(),
+            local.permute_nonce,
+            AB::F::from_canonical_u32(SyscallCode::KECCAK_PERMUTE.syscall_id()),
+            local.state_ptr,
+            AB::Expr::zero(),
+            local.is_hash,
+            InteractionScope::Global,
+        );
+
+        // Read the hash once the permutation has written it, and copy it to the node.
+        let copy_clk = step_clk + AB::F::from_canonical_u32(WITHDRAW_ROOT_STEP_CYCLES - 1);
+        builder.eval_memory_access_slice(
+            local.shard,
+            copy_clk.clone(),
+            local.state_ptr,
+            &local.hash_memory,
+            local.is_hash,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            copy_clk,
+            leaves_ptr + node_offset,
+            &local.node_memory,
+            local.is_hash,
+        );
+        for i in 0..NODE_NUM_WORDS {
+            builder
+                .when(local.is_hash)
+                .assert_word_eq(*local.node_memory[i].value(), *local.hash_memory[i].value());
+        }
+
+        // Receive the arguments on the first step of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::WITHDRAW_ROOT.syscall_id()),
+            local.state_ptr,
+            local.tree_ptr,
+            is_first,
+            InteractionScope::Local,
+        );
+    }
+}
@@ -1,62 +1,281 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+    mem::size_of,
+};
 
-pub fn memory_copy_32<F: PrimeField32>(
-    local_chip: &MemoryLocalChip,
-    src: *const Fr,
-    dst: *mut Fr
-) {
- 
-    let event = MemoryLocalEvent {
-        addr: src as u32,
-        initial_mem_access: MemoryRecord {
-            shard: current_shard,
-            timestamp: current_clk,
-            value: unsafe { *src }
-        },
-        final_mem_access: MemoryRecord {
-            shard: current_shard, 
-            timestamp: current_clk + 1,
-            value: unsafe { *src }
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{pad_rows_fixed, zeroed_f_vec},
+};
+use generic_array::{ArrayLength, GenericArray};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+
+pub const fn num_memcopy_cols<NumWords: ArrayLength>() -> usize {
+    size_of::<MemCopyCols<u8, NumWords>>()
+}
+
+/// A chip for the `MEMCPY_32` and `MEMCPY_64` precompiles, copying `NumWords` words.
+///
+/// A row may also copy two contiguous chunks of half the size from two back-to-back calls of the
+/// half-sized precompile, which the executor coalesces into a single event.
+pub struct MemCopyChip<NumWords> {
+    _marker: PhantomData<NumWords>,
+}
+
+/// A set of columns for the MemCopy operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct MemCopyCols<T, NumWords: ArrayLength> {
+    pub is_real: T,
+    pub shard: T,
+    pub nonce: T,
+    pub clk: T,
+    /// The clock cycle of the call copying the upper half, which is `clk` unless coalesced.
+    pub upper_clk: T,
+    /// Whether the row is two coalesced calls of the half-sized precompile.
+    pub is_coalesced: T,
+    pub src_ptr: T,
+    pub dst_ptr: T,
+    pub src_access: GenericArray<MemoryReadCols<T>, NumWords>,
+    pub dst_access: GenericArray<MemoryWriteCols<T>, NumWords>,
+}
+
+impl<NumWords: ArrayLength> MemCopyChip<NumWords> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    /// The syscall proven by the chip.
+    pub fn syscall_code() -> SyscallCode {
+        match NumWords::USIZE {
+            8 => SyscallCode::MEMCPY_32,
+            16 => SyscallCode::MEMCPY_64,
+            _ => unreachable!(),
         }
-    };
-    
-    local_chip.process_memory_event(event);
-}
-
-
-pub fn memory_copy_64<F: PrimeField32>(
-    local_chip: &MemoryLocalChip,
-    src: *const Fr,
-    dst: *mut Fr
-) {
-    
-    let low_event = MemoryLocalEvent {
-        addr: src as u32,
-        initial_mem_access: MemoryRecord {
-            shard: current_shard,
-            timestamp: current_clk,
-            value: unsafe { *src }
-        },
-        final_mem_access: MemoryRecord {
-            shard: current_shard,
-            timestamp: current_clk + 1, 
-            value: unsafe { *src }
+    }
+
+    /// The half-sized syscall whose back-to-back calls may be coalesced into a row of the chip.
+    pub fn half_syscall_code() -> Option<SyscallCode> {
+        match NumWords::USIZE {
+            16 => Some(SyscallCode::MEMCPY_32),
+            _ => None,
         }
-    };
+    }
+}
+
+impl<F: PrimeField32, NumWords: ArrayLength + Send + Sync> MachineAir<F> for MemCopyChip<NumWords> {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        format!("MemCopy{}", NumWords::USIZE * 4)
+    }
+
+    fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(Self::syscall_code()) {
+            let event = match event {
+                PrecompileEvent::MemCopy32(event) | PrecompileEvent::MemCopy64(event) => event,
+                _ => unreachable!(),
+            };
 
-    let high_event = MemoryLocalEvent {
-        addr: (src as u32) + 4,
-        initial_mem_access: MemoryRecord {
-            shard: current_shard,
-            timestamp: current_clk,
-            value: unsafe { *src.offset(1) }
-        },
-        final_mem_access: MemoryRecord {
-            shard: current_shard,
-            timestamp: current_clk + 1,
-            value: unsafe { *src.offset(1) }
+            let mut row = zeroed_f_vec(num_memcopy_cols::<NumWords>());
+            let cols: &mut MemCopyCols<F, NumWords> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.upper_clk = F::from_canonical_u32(
+                event.coalesced_syscall.as_ref().map_or(event.clk, |syscall| syscall.clk),
+            );
+            cols.is_coalesced = F::from_bool(event.coalesced_syscall.is_some());
+            cols.src_ptr = F::from_canonical_u32(event.src_ptr);
+            cols.dst_ptr = F::from_canonical_u32(event.dst_ptr);
+
+            // Populate the memory access columns.
+            let read_records = input.precompile_events.reads(event.read_records);
+            let write_records = input.precompile_events.writes(event.write_records);
+            for i in 0..cols.src_access.len() {
+                cols.src_access[i].populate(read_records[i], &mut new_byte_lookup_events);
+            }
+            for i in 0..cols.dst_access.len() {
+                cols.dst_access[i].populate(write_records[i], &mut new_byte_lookup_events);
+            }
+            rows.push(row);
         }
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || zeroed_f_vec(num_memcopy_cols::<NumWords>()),
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_memcopy_cols::<NumWords>(),
+        );
+
+        // Write the nonces to the trace.
+        let width = num_memcopy_cols::<NumWords>();
+        for i in 0..trace.height() {
+            let cols: &mut MemCopyCols<F, NumWords> =
+                trace.values[i * width..(i + 1) * width].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+}
+
+impl<F, NumWords: ArrayLength + Send + Sync> BaseAir<F> for MemCopyChip<NumWords> {
+    fn width(&self) -> usize {
+        num_memcopy_cols::<NumWords>()
+    }
+}
+
+impl<AB, NumWords: ArrayLength + Send + Sync> Air<AB> for MemCopyChip<NumWords>
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &MemCopyCols<AB::Var, NumWords> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &MemCopyCols<AB::Var, NumWords> = (*next).borrow();
+
+        // Check that nonce is incremented.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_coalesced);
+        builder.when(local.is_coalesced).assert_one(local.is_real);
+
+        // Unless coalesced, the upper half is copied by the same call.
+        builder.when_not(local.is_coalesced).assert_eq(local.upper_clk, local.clk);
+
+        // The copied words are written to the destination.
+        for (src, dst) in local.src_access.iter().zip(local.dst_access.iter()) {
+            builder.when(local.is_real).assert_all_eq(*src.value(), *dst.value());
+        }
+
+        // Read the source and write the destination one cycle later, for each half.
+        let half = NumWords::USIZE / 2;
+        let half_bytes = AB::F::from_canonical_usize(half * 4);
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.src_ptr,
+            &local.src_access[..half],
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.upper_clk.into(),
+            local.src_ptr + half_bytes,
+            &local.src_access[half..],
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk + AB::F::one(),
+            local.dst_ptr,
+            &local.dst_access[..half],
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.upper_clk + AB::F::one(),
+            local.dst_ptr + half_bytes,
+            &local.dst_access[half..],
+            local.is_real,
+        );
+
+        // Receive the call, or the two coalesced calls of the half-sized precompile.
+        let syscall_id = AB::F::from_canonical_u32(Self::syscall_code().syscall_id());
+        match Self::half_syscall_code() {
+            Some(half_syscall_code) => {
+                let half_syscall_id = AB::F::from_canonical_u32(half_syscall_code.syscall_id());
+                builder.receive_syscall(
+                    local.shard,
+                    local.clk,
+                    local.nonce,
+                    local.is_coalesced * half_syscall_id
+                        + (AB::Expr::one() - local.is_coalesced) * syscall_id,
+                    local.src_ptr,
+                    local.dst_ptr,
+                    local.is_real,
+                    InteractionScope::Local,
+                );
+                builder.receive_syscall(
+                    local.shard,
+                    local.upper_clk,
+                    local.nonce,
+                    half_syscall_id,
+                    local.src_ptr + half_bytes,
+                    local.dst_ptr + half_bytes,
+                    local.is_coalesced,
+                    InteractionScope::Local,
+                );
+            }
+            None => {
+                builder.assert_zero(local.is_coalesced);
+                builder.receive_syscall(
+                    local.shard,
+                    local.clk,
+                    local.nonce,
+                    syscall_id,
+                    local.src_ptr,
+                    local.dst_ptr,
+                    local.is_real,
+                    InteractionScope::Local,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::MEMCOPY_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
     };
 
-    local_chip.process_memory_event(low_event);
-    local_chip.process_memory_event(high_event); 
-}
\ No newline at end of file
+    #[test]
+    fn test_memcopy() {
+        utils::setup_logger();
+        let program = Program::from(MEMCOPY_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
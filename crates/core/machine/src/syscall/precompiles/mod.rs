@@ -1,9 +1,26 @@
+pub mod aes;
+pub mod barrett_reduce;
 pub mod bn254;
 pub mod bn254_scalar;
+pub mod chacha20;
+pub mod codec;
+pub mod csel256;
 pub mod edwards;
+pub mod f64;
 pub mod fptower;
 pub mod keccak256;
+pub mod keccak256_accumulate;
+pub mod keccak256_merkle;
+pub mod keccak256_sponge;
+pub mod keccak256_withdraw_root;
+pub mod memcpy;
+pub mod poseidon;
+pub mod rlp_scan;
+pub mod rsa;
 pub mod sha256;
+pub mod sha512;
 pub mod u256x2048_mul;
+pub mod u256x4096_mul;
 pub mod uint256;
+pub mod uint384;
 pub mod weierstrass;
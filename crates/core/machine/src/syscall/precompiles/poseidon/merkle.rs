@@ -0,0 +1,470 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{limbs_from_access, limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le},
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, PrecompileEvent, POSEIDON_MERKLE_MAX_DEPTH},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{Limbs, NumLimbs, NumWords},
+    poseidon::POSEIDON_NUM_ROUNDS,
+    uint256::U256Field,
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+use super::round::{FieldLimbs, PoseidonRoundCols};
+
+/// The number of columns in the PoseidonMerkleCols.
+const NUM_COLS: usize = size_of::<PoseidonMerkleCols<u8>>();
+
+/// The index of the last round of the permutation.
+const LAST_ROUND: usize = POSEIDON_NUM_ROUNDS - 1;
+
+/// The index of the last level of an event.
+const LAST_LEVEL: usize = POSEIDON_MERKLE_MAX_DEPTH - 1;
+
+/// The number of bytes taken by the sibling and the domain of a level.
+const LEVEL_BYTES: u32 = 2 * WORDS_FIELD_ELEMENT as u32 * 4;
+
+#[derive(Default)]
+pub struct PoseidonMerkleChip;
+
+impl PoseidonMerkleChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// A set of columns for the PoseidonMerkle operation.
+///
+/// Every event takes `POSEIDON_MERKLE_MAX_DEPTH` levels of `POSEIDON_NUM_ROUNDS` rows. The rows of
+/// a level apply the rounds of the permutation hashing the node of the level with its sibling, and
+/// the levels past the depth of the path leave the node as is.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct PoseidonMerkleCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the node, which is replaced by the root.
+    pub node_ptr: T,
+
+    /// The pointer to the depth of the path, which is followed by the index and the levels.
+    pub path_ptr: T,
+
+    /// The number of levels of the path.
+    pub depth: T,
+
+    /// The bits of the index of the node.
+    pub index_bits: [T; POSEIDON_MERKLE_MAX_DEPTH],
+
+    /// The level of the row, as a one-hot encoding.
+    pub level: [T; POSEIDON_MERKLE_MAX_DEPTH],
+
+    /// The round of the permutation of the row, as a one-hot encoding.
+    pub round: [T; POSEIDON_NUM_ROUNDS],
+
+    /// Whether this is the first row of the event.
+    pub is_first: T,
+
+    /// Whether this is the last row of the event.
+    pub is_last: T,
+
+    /// Whether the level is part of the path, which holds for the first `depth` levels.
+    pub is_level: T,
+
+    /// The number of levels of the path up to and including this level.
+    pub num_levels: T,
+
+    /// Whether the node is the right child at this level.
+    pub is_right: T,
+
+    /// Whether the row reads the sibling and the domain of its level, on the first round.
+    pub reads_level: T,
+
+    // Memory columns.
+    // The depth and the index are read on the first row, and the node is written on the last row.
+    pub depth_memory: MemoryReadCols<T>,
+    pub index_memory: MemoryReadCols<T>,
+    pub sibling_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub domain_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+    pub node_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+
+    /// The original node, constant across the event.
+    pub leaf: FieldLimbs<T>,
+
+    /// The node hashed at this level, constant across the level.
+    pub node: FieldLimbs<T>,
+
+    /// The node of the next level, set on the last round of the level.
+    pub next_node: FieldLimbs<T>,
+
+    /// The round of the permutation applied by the row.
+    pub permutation: PoseidonRoundCols<T>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for PoseidonMerkleChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "PoseidonMerkle".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::POSEIDON_MERKLE_ROOT) {
+            let event = if let PrecompileEvent::PoseidonMerkle(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let depth = event.depth as usize;
+            let leaf = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.node));
+            let mut node = leaf.clone();
+
+            for level in 0..POSEIDON_MERKLE_MAX_DEPTH {
+                let is_level = level < depth;
+                let is_right = (event.index >> level) & 1 == 1;
+
+                // Levels past the end of the path hash zeros, and leave the node as is.
+                let (sibling, domain) = if is_level {
+                    (
+                        BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.siblings[level])),
+                        BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.domains[level])),
+                    )
+                } else {
+                    (BigUint::zero(), BigUint::zero())
+                };
+                let mut state = if is_right {
+                    [domain, sibling, node.clone()]
+                } else {
+                    [domain, node.clone(), sibling]
+                };
+
+                for round in 0..POSEIDON_NUM_ROUNDS {
+                    let mut row: Vec<F> = vec![F::zero(); NUM_COLS];
+                    let cols: &mut PoseidonMerkleCols<F> = row.as_mut_slice().borrow_mut();
+
+                    // Assign basic values to the columns.
+                    cols.is_real = F::one();
+                    cols.shard = F::from_canonical_u32(event.shard);
+                    cols.clk = F::from_canonical_u32(event.clk);
+                    cols.node_ptr = F::from_canonical_u32(event.node_ptr);
+                    cols.path_ptr = F::from_canonical_u32(event.path_ptr);
+                    cols.depth = F::from_canonical_usize(depth);
+                    for (i, bit) in cols.index_bits.iter_mut().enumerate() {
+                        *bit = F::from_canonical_u32((event.index >> i) & 1);
+                    }
+                    cols.level[level] = F::one();
+                    cols.round[round] = F::one();
+                    cols.is_first = F::from_bool(level == 0 && round == 0);
+                    cols.is_last = F::from_bool(level == LAST_LEVEL && round == LAST_ROUND);
+                    cols.is_level = F::from_bool(is_level);
+                    cols.num_levels = F::from_canonical_usize((level + 1).min(depth));
+                    cols.is_right = F::from_bool(is_right);
+                    cols.reads_level = F::from_bool(is_level && round == 0);
+                    cols.leaf = Bn254ScalarField::to_limbs_field::<F, _>(&leaf);
+                    cols.node = Bn254ScalarField::to_limbs_field::<F, _>(&node);
+
+                    if level == 0 && round == 0 {
+                        cols.depth_memory
+                            .populate(event.depth_memory_record, &mut new_byte_lookup_events);
+                        cols.index_memory
+                            .populate(event.index_memory_record, &mut new_byte_lookup_events);
+                    }
+                    if is_level && round == 0 {
+                        let records = &event.level_memory_records[level];
+                        for j in 0..WORDS_FIELD_ELEMENT {
+                            cols.sibling_memory[j]
+                                .populate(records[j], &mut new_byte_lookup_events);
+                            cols.domain_memory[j].populate(
+                                records[WORDS_FIELD_ELEMENT + j],
+                                &mut new_byte_lookup_events,
+                            );
+                        }
+                    }
+
+                    state = cols.permutation.populate(
+                        &mut new_byte_lookup_events,
+                        event.shard,
+                        round,
+                        &state,
+                    );
+
+                    if round == LAST_ROUND {
+                        if is_level {
+                            node = state[0].clone();
+                        }
+                        cols.next_node = Bn254ScalarField::to_limbs_field::<F, _>(&node);
+                    }
+
+                    if level == LAST_LEVEL && round == LAST_ROUND {
+                        for j in 0..WORDS_FIELD_ELEMENT {
+                            cols.node_memory[j].populate(
+                                event.node_memory_records[j],
+                                &mut new_byte_lookup_events,
+                            );
+                        }
+                    }
+
+                    rows.push(row);
+                }
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || vec![F::zero(); NUM_COLS],
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut PoseidonMerkleCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::POSEIDON_MERKLE_ROOT).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for PoseidonMerkleChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for PoseidonMerkleChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &PoseidonMerkleCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &PoseidonMerkleCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans, and only set on real rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.assert_bool(local.is_level);
+        builder.assert_bool(local.is_right);
+        builder.assert_bool(local.reads_level);
+        for bit in local.index_bits.iter().chain(local.level.iter()).chain(local.round.iter()) {
+            builder.assert_bool(*bit);
+        }
+        builder.when(local.is_level).assert_one(local.is_real);
+
+        // Every real row has exactly one level and one round.
+        let level_sum = local.level.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        let round_sum = local.round.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(level_sum, local.is_real);
+        builder.assert_eq(round_sum, local.is_real);
+        let last_round = local.round[LAST_ROUND];
+        builder.assert_eq(local.is_first, local.level[0] * local.round[0]);
+        builder.assert_eq(local.is_last, local.level[LAST_LEVEL] * last_round);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(local.is_first);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+        builder.when_transition().when(local.is_last).when(next.is_real).assert_one(next.is_first);
+
+        // The rows of an event share the arguments, and go through the rounds of every level.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - local.is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.node_ptr, next.node_ptr);
+        continuation.assert_eq(local.path_ptr, next.path_ptr);
+        continuation.assert_eq(local.depth, next.depth);
+        continuation.assert_all_eq(local.index_bits, next.index_bits);
+        continuation.assert_all_eq(local.leaf, next.leaf);
+        continuation.assert_eq(local.round[LAST_ROUND], next.round[0]);
+        for r in 0..LAST_ROUND {
+            continuation.assert_eq(local.round[r], next.round[r + 1]);
+        }
+
+        // The rows of a level share the node, and carry the permutation state forward.
+        let mut same_level = builder.when_transition();
+        let mut same_level = same_level.when(local.is_real - last_round);
+        same_level.assert_all_eq(local.level, next.level);
+        same_level.assert_eq(local.is_level, next.is_level);
+        same_level.assert_eq(local.num_levels, next.num_levels);
+        same_level.assert_all_eq(local.node, next.node);
+        for (output, state) in local.permutation.output().into_iter().zip(next.permutation.state) {
+            same_level.assert_all_eq(output, state);
+        }
+
+        // The last round of a level passes the node on to the next level of the event.
+        let mut level_end = builder.when_transition();
+        let mut level_end = level_end.when(last_round - local.is_last);
+        level_end.assert_zero(next.level[0]);
+        for k in 0..LAST_LEVEL {
+            level_end.assert_eq(local.level[k], next.level[k + 1]);
+        }
+        level_end.assert_all_eq(local.next_node, next.node);
+        // Once a level is not part of the path, neither are the following levels of the event.
+        level_end.when(next.is_level).assert_one(local.is_level);
+        level_end.assert_eq(local.num_levels + next.is_level, next.num_levels);
+
+        // The node of the next level is the hash if the level is part of the path.
+        let hash = local.permutation.output()[0];
+        for ((next_node, node), hash) in local.next_node.0.into_iter().zip(local.node.0).zip(hash.0)
+        {
+            let node: AB::Expr = node.into();
+            builder
+                .when(last_round)
+                .assert_eq(next_node, node.clone() + local.is_level * (hash - node));
+        }
+
+        // The first row reads the depth and the index, and starts from the original node.
+        builder.when(local.is_first).assert_one(local.is_level);
+        builder.when(local.is_first).assert_one(local.num_levels);
+        builder.when(local.is_first).assert_all_eq(local.node, local.leaf);
+        let depth_bytes = local.depth_memory.value();
+        let index_bytes = local.index_memory.value();
+        builder.when(local.is_first).assert_eq(local.depth, depth_bytes[0]);
+        let index =
+            local.index_bits.iter().enumerate().fold(AB::Expr::zero(), |acc, (i, &bit)| {
+                acc + bit * AB::F::from_canonical_u32(1 << i)
+            });
+        builder.when(local.is_first).assert_eq(index, index_bytes[0]);
+        for i in 1..4 {
+            builder.when(local.is_first).assert_zero(depth_bytes[i]);
+            builder.when(local.is_first).assert_zero(index_bytes[i]);
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.path_ptr,
+            &[local.depth_memory, local.index_memory],
+            local.is_first,
+        );
+
+        // The last level of the event has gone through all the levels of the path.
+        builder.when(local.is_last).assert_eq(local.num_levels, local.depth);
+
+        // The node is the right child if the bit of the level is set.
+        let is_right = local
+            .level
+            .iter()
+            .zip(local.index_bits.iter())
+            .fold(AB::Expr::zero(), |acc, (&level, &bit)| acc + level * bit);
+        builder.assert_eq(local.is_right, is_right);
+
+        // The first round of a level that is part of the path reads the sibling and the domain,
+        // and starts the permutation from the domain and the two children.
+        builder.assert_eq(local.reads_level, local.is_level * local.round[0]);
+        let level_index =
+            local.level.iter().enumerate().fold(AB::Expr::zero(), |acc, (k, &level)| {
+                acc + level * AB::F::from_canonical_usize(k)
+            });
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.path_ptr
+                + AB::Expr::from_canonical_u32(8)
+                + level_index * AB::Expr::from_canonical_u32(LEVEL_BYTES),
+            &[local.sibling_memory, local.domain_memory].concat(),
+            local.reads_level,
+        );
+        let sibling: FieldLimbs<AB::Var> = limbs_from_access(&local.sibling_memory);
+        let domain: FieldLimbs<AB::Var> = limbs_from_access(&local.domain_memory);
+        let state = &local.permutation.state;
+        builder.when(local.reads_level).assert_all_eq(state[0], domain);
+        for ((sibling, node), (left, right)) in
+            sibling.0.into_iter().zip(local.node.0).zip(state[1].0.into_iter().zip(state[2].0))
+        {
+            let swap: AB::Expr = local.is_right * (sibling - node);
+            builder.when(local.reads_level).assert_eq(left, swap.clone() + node);
+            builder.when(local.reads_level).assert_eq(right, AB::Expr::from(sibling) - swap);
+        }
+
+        // Apply the round of the permutation of the row.
+        local.permutation.eval(builder, &local.round, local.is_real);
+
+        // Write the root to the node on the last row. The original node is stored in the
+        // "prev_value" of the node_memory.
+        let node_limbs = limbs_from_prev_access(&local.node_memory);
+        builder.when(local.is_last).assert_all_eq(local.leaf, node_limbs);
+        builder
+            .when(local.is_last)
+            .assert_all_eq(local.next_node, value_as_limbs(&local.node_memory));
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.node_ptr,
+            &local.node_memory,
+            local.is_last,
+        );
+
+        // Receive the arguments on the first row of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::POSEIDON_MERKLE_ROOT.syscall_id()),
+            local.node_ptr,
+            local.path_ptr,
+            local.is_first,
+            InteractionScope::Local,
+        );
+    }
+}
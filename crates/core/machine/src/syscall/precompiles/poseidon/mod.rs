@@ -0,0 +1,33 @@
+mod merkle;
+mod round;
+mod zktrie;
+
+pub use merkle::*;
+pub use round::*;
+pub use zktrie::*;
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::{POSEIDON_MERKLE_ELF, ZKTRIE_NODE_ELF};
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_poseidon_merkle() {
+        utils::setup_logger();
+        let program = Program::from(POSEIDON_MERKLE_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_zktrie_node() {
+        utils::setup_logger();
+        let program = Program::from(ZKTRIE_NODE_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
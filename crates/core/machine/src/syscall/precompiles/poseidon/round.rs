@@ -0,0 +1,144 @@
+use crate::operations::field::field_op::FieldOpCols;
+
+use num::{BigUint, Zero};
+use p3_field::{AbstractField, PrimeField32};
+use sp1_core_executor::events::{ByteRecord, FieldOperation};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs},
+    poseidon::{
+        is_full_round, poseidon_mds, poseidon_round_constants, POSEIDON_NUM_ROUNDS, POSEIDON_WIDTH,
+    },
+    weierstrass::bn254::Bn254ScalarField,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{Polynomial, SP1AirBuilder};
+
+pub(crate) type FieldLimbs<T> = Limbs<T, <Bn254ScalarField as NumLimbs>::Limbs>;
+
+/// A set of columns applying a round of the Poseidon permutation to a state, for a round given as
+/// a one-hot encoding.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct PoseidonRoundCols<T> {
+    /// The permutation state before the round.
+    pub state: [FieldLimbs<T>; POSEIDON_WIDTH],
+
+    ark: [FieldOpCols<T, Bn254ScalarField>; POSEIDON_WIDTH], // state + round constant
+    square: [FieldOpCols<T, Bn254ScalarField>; POSEIDON_WIDTH], // ark^2
+    quad: [FieldOpCols<T, Bn254ScalarField>; POSEIDON_WIDTH], // ark^4
+    sbox: [FieldOpCols<T, Bn254ScalarField>; POSEIDON_WIDTH], // ark^5
+    mds: [[FieldOpCols<T, Bn254ScalarField>; POSEIDON_WIDTH]; POSEIDON_WIDTH], // sum of m * sbox
+}
+
+impl<F: PrimeField32> PoseidonRoundCols<F> {
+    /// Populates the round `round` applied to `state`, and returns the state after the round.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        round: usize,
+        state: &[BigUint; POSEIDON_WIDTH],
+    ) -> [BigUint; POSEIDON_WIDTH] {
+        let modulus = Bn254ScalarField::modulus();
+        let round_constants = poseidon_round_constants();
+        let mds = poseidon_mds();
+
+        for (col, value) in self.state.iter_mut().zip(state.iter()) {
+            *col = Bn254ScalarField::to_limbs_field::<F, _>(value);
+        }
+
+        // Add the round constants, apply the S-box, and mix the state.
+        let mut sbox = Vec::with_capacity(POSEIDON_WIDTH);
+        for i in 0..POSEIDON_WIDTH {
+            let ark = self.ark[i].populate(
+                record,
+                shard,
+                &state[i],
+                &round_constants[round][i],
+                FieldOperation::Add,
+            );
+            let square = self.square[i].populate(record, shard, &ark, &ark, FieldOperation::Mul);
+            let quad = self.quad[i].populate(record, shard, &square, &square, FieldOperation::Mul);
+            let pow5 = self.sbox[i].populate(record, shard, &quad, &ark, FieldOperation::Mul);
+            sbox.push(if i == 0 || is_full_round(round) { pow5 } else { ark });
+        }
+        core::array::from_fn(|i| {
+            let mut acc = BigUint::zero();
+            for j in 0..POSEIDON_WIDTH {
+                (acc, _) = self.mds[i][j]
+                    .populate_mul_and_carry(record, shard, &mds[i][j], &sbox[j], &acc, &modulus);
+            }
+            acc
+        })
+    }
+}
+
+/// A field element as a polynomial of constant limbs.
+fn constant_polynomial<AB: SP1AirBuilder>(value: &BigUint) -> Polynomial<AB::Expr> {
+    Bn254ScalarField::to_limbs_field::<AB::F, _>(value).0.into_iter().map(AB::Expr::from).collect()
+}
+
+impl<V: Copy> PoseidonRoundCols<V> {
+    /// The permutation state after the round.
+    pub fn output(&self) -> [FieldLimbs<V>; POSEIDON_WIDTH] {
+        core::array::from_fn(|i| self.mds[i][POSEIDON_WIDTH - 1].result)
+    }
+
+    /// Evaluates the round given by the one-hot encoding `round`.
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        round: &[V; POSEIDON_NUM_ROUNDS],
+        is_real: V,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        // Add the round constants, selected by the round of the row.
+        let round_constants = poseidon_round_constants();
+        for i in 0..POSEIDON_WIDTH {
+            let constant = round.iter().enumerate().fold(
+                Polynomial::from_coefficients(&[AB::Expr::zero()]),
+                |acc, (r, &round)| {
+                    acc + constant_polynomial::<AB>(&round_constants[r][i]) * round.into()
+                },
+            );
+            self.ark[i].eval(builder, &self.state[i], &constant, FieldOperation::Add, is_real);
+        }
+
+        // Apply the S-box to the whole state on full rounds, and to the first element otherwise.
+        let is_full = (0..POSEIDON_NUM_ROUNDS)
+            .filter(|&r| is_full_round(r))
+            .fold(AB::Expr::zero(), |acc, r| acc + round[r]);
+        let mut sbox = Vec::with_capacity(POSEIDON_WIDTH);
+        for i in 0..POSEIDON_WIDTH {
+            let ark = self.ark[i].result;
+            self.square[i].eval(builder, &ark, &ark, FieldOperation::Mul, is_real);
+            let square = self.square[i].result;
+            self.quad[i].eval(builder, &square, &square, FieldOperation::Mul, is_real);
+            self.sbox[i].eval(builder, &self.quad[i].result, &ark, FieldOperation::Mul, is_real);
+            let pow5: Polynomial<AB::Expr> = self.sbox[i].result.into();
+            let ark: Polynomial<AB::Expr> = ark.into();
+            sbox.push(if i == 0 { pow5 } else { ark.clone() + (pow5 - ark) * is_full.clone() });
+        }
+
+        // Mix the state with the MDS matrix, accumulating the products of every row.
+        let mds = poseidon_mds();
+        let modulus = Polynomial::from_iter(
+            Bn254ScalarField::modulus_field_iter::<AB::F>().map(AB::Expr::from),
+        );
+        for i in 0..POSEIDON_WIDTH {
+            let mut acc = Polynomial::from_coefficients(&[AB::Expr::zero()]);
+            for j in 0..POSEIDON_WIDTH {
+                self.mds[i][j].eval_mul_and_carry(
+                    builder,
+                    &constant_polynomial::<AB>(&mds[i][j]),
+                    &sbox[j],
+                    &acc,
+                    &modulus,
+                    is_real,
+                );
+                acc = self.mds[i][j].result.into();
+            }
+        }
+    }
+}
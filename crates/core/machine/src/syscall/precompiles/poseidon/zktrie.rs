@@ -0,0 +1,319 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{limbs_from_access, pad_rows_fixed, words_to_bytes_le},
+};
+
+use generic_array::GenericArray;
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{Limbs, NumLimbs, NumWords},
+    poseidon::{POSEIDON_NUM_ROUNDS, ZKTRIE_NODE_TYPES},
+    uint256::U256Field,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+use super::round::{FieldLimbs, PoseidonRoundCols};
+
+/// The number of columns in the ZkTrieNodeCols.
+const NUM_COLS: usize = size_of::<ZkTrieNodeCols<u8>>();
+
+/// The index of the last round of the permutation.
+const LAST_ROUND: usize = POSEIDON_NUM_ROUNDS - 1;
+
+/// The number of node types.
+const NUM_NODE_TYPES: usize = ZKTRIE_NODE_TYPES.len();
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+
+/// The number of words read for a node: the node type, and the left and the right child.
+const NODE_WORDS: usize = 1 + 2 * WORDS_FIELD_ELEMENT;
+
+#[derive(Default)]
+pub struct ZkTrieNodeChip;
+
+impl ZkTrieNodeChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the ZkTrieNode operation.
+///
+/// Every event takes `POSEIDON_NUM_ROUNDS` rows, which apply the rounds of the permutation to the
+/// state `[type, left, right]` of the node.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct ZkTrieNodeCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the hash.
+    pub hash_ptr: T,
+
+    /// The pointer to the node type, which is followed by the left and the right child.
+    pub node_ptr: T,
+
+    /// The type of the node, as a one-hot encoding of the zkTrie node types.
+    pub node_type: [T; NUM_NODE_TYPES],
+
+    /// The round of the permutation of the row, as a one-hot encoding.
+    pub round: [T; POSEIDON_NUM_ROUNDS],
+
+    /// Whether this is the first row of the event.
+    pub is_first: T,
+
+    /// Whether this is the last row of the event.
+    pub is_last: T,
+
+    // Memory columns.
+    // The node is read on the first row, and the hash is written on the last row.
+    pub node_memory: [MemoryReadCols<T>; NODE_WORDS],
+    pub hash_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+
+    /// The round of the permutation applied by the row.
+    pub permutation: PoseidonRoundCols<T>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for ZkTrieNodeChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "ZkTrieNode".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::ZKTRIE_HASH_NODE) {
+            let event =
+                if let PrecompileEvent::ZkTrieNode(event) = event { event } else { unreachable!() };
+            let node_type = ZKTRIE_NODE_TYPES
+                .iter()
+                .position(|&node_type| node_type == event.node_type)
+                .expect("invalid zktrie node type");
+            let mut state = [
+                BigUint::from(event.node_type),
+                BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.left)),
+                BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.right)),
+            ];
+
+            for round in 0..POSEIDON_NUM_ROUNDS {
+                let mut row: Vec<F> = vec![F::zero(); NUM_COLS];
+                let cols: &mut ZkTrieNodeCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.hash_ptr = F::from_canonical_u32(event.hash_ptr);
+                cols.node_ptr = F::from_canonical_u32(event.node_ptr);
+                cols.node_type[node_type] = F::one();
+                cols.round[round] = F::one();
+                cols.is_first = F::from_bool(round == 0);
+                cols.is_last = F::from_bool(round == LAST_ROUND);
+
+                if round == 0 {
+                    for (col, record) in cols.node_memory.iter_mut().zip(&event.node_memory_records)
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                }
+
+                state = cols.permutation.populate(
+                    &mut new_byte_lookup_events,
+                    event.shard,
+                    round,
+                    &state,
+                );
+
+                if round == LAST_ROUND {
+                    for (col, record) in cols.hash_memory.iter_mut().zip(&event.hash_memory_records)
+                    {
+                        col.populate(*record, &mut new_byte_lookup_events);
+                    }
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || vec![F::zero(); NUM_COLS],
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut ZkTrieNodeCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::ZKTRIE_HASH_NODE).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for ZkTrieNodeChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for ZkTrieNodeChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <U256Field as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &ZkTrieNodeCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &ZkTrieNodeCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans.
+        builder.assert_bool(local.is_real);
+        for bit in local.node_type.iter().chain(local.round.iter()) {
+            builder.assert_bool(*bit);
+        }
+
+        // Every real row has exactly one node type and one round, and the first and last rows of
+        // the event are the first and last rounds.
+        let node_type_sum = local.node_type.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        let round_sum = local.round.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(node_type_sum, local.is_real);
+        builder.assert_eq(round_sum, local.is_real);
+        builder.assert_eq(local.is_first, local.round[0]);
+        builder.assert_eq(local.is_last, local.round[LAST_ROUND]);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(local.is_first);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+        builder.when_transition().when(local.is_last).when(next.is_real).assert_one(next.is_first);
+
+        // The rows of an event share the arguments, go through the rounds, and carry the
+        // permutation state forward.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - local.is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.hash_ptr, next.hash_ptr);
+        continuation.assert_eq(local.node_ptr, next.node_ptr);
+        continuation.assert_all_eq(local.node_type, next.node_type);
+        continuation.assert_eq(local.round[LAST_ROUND], next.round[0]);
+        for r in 0..LAST_ROUND {
+            continuation.assert_eq(local.round[r], next.round[r + 1]);
+        }
+        for (output, state) in local.permutation.output().into_iter().zip(next.permutation.state) {
+            continuation.assert_all_eq(output, state);
+        }
+
+        // The first row reads the node, and starts the permutation from the node type as the
+        // domain and the two children.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.node_ptr,
+            &local.node_memory,
+            local.is_first,
+        );
+        let node_type = local
+            .node_type
+            .iter()
+            .zip(ZKTRIE_NODE_TYPES)
+            .fold(AB::Expr::zero(), |acc, (&x, t)| acc + x * AB::F::from_canonical_u32(t));
+        let node_type_bytes = local.node_memory[0].value();
+        builder.when(local.is_first).assert_eq(node_type.clone(), node_type_bytes[0]);
+        for &byte in &node_type_bytes.0[1..] {
+            builder.when(local.is_first).assert_zero(byte);
+        }
+        let state = &local.permutation.state;
+        builder.when(local.is_first).assert_eq(state[0][0], node_type);
+        for &limb in state[0].0.iter().skip(1) {
+            builder.when(local.is_first).assert_zero(limb);
+        }
+        let left: FieldLimbs<AB::Var> =
+            limbs_from_access(&local.node_memory[1..1 + WORDS_FIELD_ELEMENT]);
+        let right: FieldLimbs<AB::Var> =
+            limbs_from_access(&local.node_memory[1 + WORDS_FIELD_ELEMENT..]);
+        builder.when(local.is_first).assert_all_eq(state[1], left);
+        builder.when(local.is_first).assert_all_eq(state[2], right);
+
+        // Apply the round of the permutation of the row.
+        local.permutation.eval(builder, &local.round, local.is_real);
+
+        // Write the hash on the last row.
+        builder
+            .when(local.is_last)
+            .assert_all_eq(local.permutation.output()[0], value_as_limbs(&local.hash_memory));
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.hash_ptr,
+            &local.hash_memory,
+            local.is_last,
+        );
+
+        // Receive the arguments on the first row of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::ZKTRIE_HASH_NODE.syscall_id()),
+            local.hash_ptr,
+            local.node_ptr,
+            local.is_first,
+            InteractionScope::Local,
+        );
+    }
+}
@@ -0,0 +1,578 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        ByteLookupEvent, ByteRecord, PrecompileEvent, RlpHeader, RlpKind, RLP_MAX_HEADER_BYTES,
+        RLP_SCAN_ENTRY_WORDS, RLP_SCAN_MAX_ITEMS, RLP_SCAN_STATE_WORDS, RLP_SCAN_WINDOW_WORDS,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::{words_to_bytes_le, WORD_SIZE};
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder};
+
+/// The number of columns in the RlpScanCols.
+const NUM_COLS: usize = size_of::<RlpScanCols<u8>>();
+
+/// The number of kinds of RLP items.
+const NUM_KINDS: usize = RlpKind::ALL.len();
+
+/// The maximum number of bytes of the length of a long item.
+const MAX_LEN_LEN: usize = RLP_MAX_HEADER_BYTES - 1;
+
+/// The number of bytes of the offsets and lengths, which are below `2^24`.
+const LEN_BYTES: usize = 3;
+
+/// The index of the state word holding the offset of the next item.
+const STATE_OFFSET: usize = 2;
+
+/// The index of the state word holding the number of items scanned.
+const STATE_COUNT: usize = 3;
+
+#[derive(Default)]
+pub struct RlpScanChip;
+
+impl RlpScanChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the RlpScan operation.
+///
+/// Every event takes `RLP_SCAN_MAX_ITEMS` rows. The row with index `i` decodes the header of the
+/// `i`-th item from the offset of the state, and writes the `i`-th entry of the table, if the
+/// payload doesn't end before it.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct RlpScanCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the state.
+    pub state_ptr: T,
+
+    /// The pointer to the table of items.
+    pub table_ptr: T,
+
+    /// The pointer to the payload, its length, and the original offset, constant across the event.
+    pub payload_ptr: T,
+    pub payload_len: T,
+    pub start_offset: T,
+
+    /// The index of the row within the event.
+    pub index: T,
+
+    /// Whether this is the first row of the event.
+    pub is_first: T,
+
+    /// Whether this is the last row of the event.
+    pub is_last: T,
+
+    /// Whether the row scans an item, which holds until the end of the payload.
+    pub is_item: T,
+
+    /// The number of items scanned up to and including this row.
+    pub num_items: T,
+
+    /// The offset of the item within the payload, and its little-endian bytes.
+    pub offset: T,
+    pub offset_bytes: [T; LEN_BYTES],
+
+    /// The position of the item within its word, as a one-hot flag.
+    pub align: [T; WORD_SIZE],
+
+    /// The first bytes of the item, which cover its header.
+    pub header: [T; RLP_MAX_HEADER_BYTES],
+
+    /// The kind of the item, as a one-hot flag in the order of [`RlpKind::ALL`].
+    pub kind: [T; NUM_KINDS],
+
+    /// The number of bytes of the length of a long item, as a one-hot flag.
+    pub len_len: [T; MAX_LEN_LEN],
+
+    /// The length of the payload of the item.
+    pub len: T,
+
+    /// The inverse of the first byte of the length of a long item, which is not zero.
+    pub len_inv: T,
+
+    /// Whether the item is a string of a single byte, and the inverse of the prefix of a short
+    /// string minus `0x81` otherwise.
+    pub is_single: T,
+    pub single_inv: T,
+
+    /// The amount by which the length of a long item exceeds 55, or the byte of a single byte
+    /// string exceeds `0x7f`.
+    pub excess: T,
+
+    /// The number of bytes of the payload past the item, as little-endian bytes.
+    pub remaining: [T; LEN_BYTES],
+
+    // Memory columns.
+    // The header is read and the entry is written on rows with an item, and the state is written
+    // on the last row.
+    pub window_memory: [MemoryReadCols<T>; RLP_SCAN_WINDOW_WORDS],
+    pub entry_memory: [MemoryWriteCols<T>; RLP_SCAN_ENTRY_WORDS],
+    pub state_memory: [MemoryWriteCols<T>; RLP_SCAN_STATE_WORDS],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for RlpScanChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "RlpScan".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::RLP_SCAN) {
+            let event =
+                if let PrecompileEvent::RlpScan(event) = event { event } else { unreachable!() };
+            let shard = event.shard;
+            let num_items = event.entries.len();
+            let (payload_ptr, payload_len) = (event.state[0], event.state[1]);
+            let mut offset = event.state[STATE_OFFSET];
+
+            for i in 0..RLP_SCAN_MAX_ITEMS {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut RlpScanCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                cols.table_ptr = F::from_canonical_u32(event.table_ptr);
+                cols.payload_ptr = F::from_canonical_u32(payload_ptr);
+                cols.payload_len = F::from_canonical_u32(payload_len);
+                cols.start_offset = F::from_canonical_u32(event.state[STATE_OFFSET]);
+                cols.index = F::from_canonical_usize(i);
+                cols.is_first = F::from_bool(i == 0);
+                cols.is_last = F::from_bool(i == RLP_SCAN_MAX_ITEMS - 1);
+                cols.is_item = F::from_bool(i < num_items);
+                cols.num_items = F::from_canonical_usize((i + 1).min(num_items));
+                cols.offset = F::from_canonical_u32(offset);
+
+                // Rows past the end of the payload leave the offset as is.
+                if i < num_items {
+                    for j in 0..RLP_SCAN_WINDOW_WORDS {
+                        cols.window_memory[j].populate(
+                            event.window_memory_records[i][j],
+                            &mut new_byte_lookup_events,
+                        );
+                    }
+                    for j in 0..RLP_SCAN_ENTRY_WORDS {
+                        cols.entry_memory[j].populate(
+                            event.entry_memory_records[i][j],
+                            &mut new_byte_lookup_events,
+                        );
+                    }
+
+                    let offset_bytes = offset.to_le_bytes();
+                    cols.offset_bytes =
+                        core::array::from_fn(|k| F::from_canonical_u8(offset_bytes[k]));
+                    let align = offset as usize % WORD_SIZE;
+                    cols.align[align] = F::one();
+                    new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                        shard,
+                        ByteOpcode::AND,
+                        u16::from(offset_bytes[0] & 3),
+                        0,
+                        offset_bytes[0],
+                        3,
+                    ));
+
+                    // Decode the header.
+                    let window = words_to_bytes_le::<{ RLP_SCAN_WINDOW_WORDS * WORD_SIZE }>(
+                        &event.windows[i],
+                    );
+                    let header_bytes = &window[align..align + RLP_MAX_HEADER_BYTES];
+                    let header = RlpHeader::decode(header_bytes).unwrap();
+                    cols.header = core::array::from_fn(|k| F::from_canonical_u8(header_bytes[k]));
+                    let kind = header.kind;
+                    let kind_offset = header_bytes[0] - kind.base();
+                    cols.kind[RlpKind::ALL.iter().position(|&k| k == kind).unwrap()] = F::one();
+                    cols.len = F::from_canonical_u32(header.len);
+                    let mut excess = 0;
+                    if kind.is_long() {
+                        cols.len_len[kind_offset as usize] = F::one();
+                        cols.len_inv = F::from_canonical_u8(header_bytes[1]).inverse();
+                        if kind_offset == 0 {
+                            excess = header_bytes[1] - 56;
+                        }
+                    }
+                    if kind == RlpKind::ShortString {
+                        if kind_offset == 1 {
+                            cols.is_single = F::one();
+                            excess = header_bytes[1] - 0x80;
+                        } else {
+                            cols.single_inv =
+                                (F::from_canonical_u8(kind_offset) - F::one()).inverse();
+                        }
+                    }
+                    cols.excess = F::from_canonical_u8(excess);
+
+                    let end = offset + header.header_len + header.len;
+                    let remaining = (payload_len - end).to_le_bytes();
+                    cols.remaining = core::array::from_fn(|k| F::from_canonical_u8(remaining[k]));
+
+                    let entry = words_to_bytes_le::<{ RLP_SCAN_ENTRY_WORDS * WORD_SIZE }>(
+                        &event.entries[i],
+                    );
+                    let mut range_checks =
+                        vec![kind_offset, kind.width() - 1 - kind_offset, excess];
+                    range_checks.extend_from_slice(&offset_bytes[..LEN_BYTES]);
+                    range_checks.extend_from_slice(&remaining[..LEN_BYTES]);
+                    range_checks.extend_from_slice(&entry[..LEN_BYTES]);
+                    range_checks.extend_from_slice(&entry[WORD_SIZE..WORD_SIZE + LEN_BYTES]);
+                    new_byte_lookup_events.add_u8_range_checks(shard, &range_checks);
+
+                    offset = end;
+                }
+
+                if i == RLP_SCAN_MAX_ITEMS - 1 {
+                    for j in 0..RLP_SCAN_STATE_WORDS {
+                        cols.state_memory[j]
+                            .populate(event.state_memory_records[j], &mut new_byte_lookup_events);
+                    }
+                    let end_bytes = event.state_memory_records[STATE_OFFSET].value.to_le_bytes();
+                    new_byte_lookup_events.add_u8_range_checks(shard, &end_bytes[..LEN_BYTES]);
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut RlpScanCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::RLP_SCAN).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for RlpScanChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for RlpScanChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &RlpScanCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &RlpScanCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans, and only set on real rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.assert_bool(local.is_item);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+        builder.when(local.is_item).assert_one(local.is_real);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(local.is_first);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+        builder.when_transition().when(local.is_last).when(next.is_real).assert_one(next.is_first);
+
+        // Every event has the same number of rows.
+        builder.when(local.is_first).assert_zero(local.index);
+        builder
+            .when(local.is_last)
+            .assert_eq(local.index, AB::Expr::from_canonical_usize(RLP_SCAN_MAX_ITEMS - 1));
+
+        // The first row starts from the original offset.
+        builder.when(local.is_first).assert_eq(local.offset, local.start_offset);
+        builder.when(local.is_first).assert_eq(local.num_items, local.is_item);
+
+        // Locate the item within its word, from the low bits of the offset.
+        builder
+            .when(local.is_item)
+            .assert_eq(local.offset, from_bytes_le::<AB>(&local.offset_bytes));
+        let mut num_align = AB::Expr::zero();
+        let mut align = AB::Expr::zero();
+        for (k, &flag) in local.align.iter().enumerate() {
+            builder.assert_bool(flag);
+            num_align = num_align + flag;
+            align = align + flag * AB::F::from_canonical_usize(k);
+        }
+        builder.assert_eq(num_align, local.is_item);
+        builder.send_byte(
+            ByteOpcode::AND.as_field::<AB::F>(),
+            align.clone(),
+            local.offset_bytes[0],
+            AB::F::from_canonical_u32(3),
+            local.is_item,
+        );
+
+        // Read the words around the header of the item, and select the bytes of the header.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk + local.index * AB::F::from_canonical_u32(2),
+            local.payload_ptr + local.offset - align,
+            &local.window_memory,
+            local.is_item,
+        );
+        let window = value_as_limbs(&local.window_memory);
+        for (j, &byte) in local.header.iter().enumerate() {
+            let selected = local
+                .align
+                .iter()
+                .enumerate()
+                .fold(AB::Expr::zero(), |acc, (k, &flag)| acc + flag * window[k + j]);
+            builder.assert_eq(byte, selected);
+        }
+
+        // The prefix is in the range of exactly one kind.
+        let mut num_kinds = AB::Expr::zero();
+        let mut base = AB::Expr::zero();
+        let mut last_offset = AB::Expr::zero();
+        for (&flag, kind) in local.kind.iter().zip(RlpKind::ALL) {
+            builder.assert_bool(flag);
+            num_kinds = num_kinds + flag;
+            base = base + flag * AB::F::from_canonical_u8(kind.base());
+            last_offset = last_offset + flag * AB::F::from_canonical_u8(kind.width() - 1);
+        }
+        builder.assert_eq(num_kinds, local.is_item);
+        let kind_offset = local.header[0] - base;
+        let [is_byte, is_short_string, is_long_string, is_short_list, is_long_list] = local.kind;
+        let is_short = is_short_string + is_short_list;
+        let is_long = is_long_string + is_long_list;
+
+        // Long items take one to three bytes of length, without leading zeros, which encode a
+        // length that doesn't fit in the short form.
+        let mut num_len_len = AB::Expr::zero();
+        let mut len_len = AB::Expr::zero();
+        for (k, &flag) in local.len_len.iter().enumerate() {
+            builder.assert_bool(flag);
+            num_len_len = num_len_len + flag;
+            len_len = len_len + flag * AB::F::from_canonical_usize(k);
+        }
+        builder.assert_eq(num_len_len, is_long.clone());
+        builder.when(is_long.clone()).assert_eq(kind_offset.clone(), len_len.clone());
+        builder.when(is_long.clone()).assert_one(local.header[1] * local.len_inv);
+        builder
+            .when(local.len_len[0])
+            .assert_eq(local.excess, local.header[1] - AB::F::from_canonical_u32(56));
+
+        // A string of a single byte below `0x80` is encoded as that byte.
+        builder.assert_bool(local.is_single);
+        builder.when(local.is_single).assert_one(kind_offset.clone());
+        builder.assert_eq(
+            is_short_string * (kind_offset.clone() - AB::Expr::one()) * local.single_inv,
+            is_short_string - local.is_single,
+        );
+        builder
+            .when(local.is_single)
+            .assert_eq(local.excess, local.header[1] - AB::F::from_canonical_u32(0x80));
+
+        // The length of the payload follows from the prefix for the single bytes and the short
+        // items, and is encoded in big-endian after the prefix for the long items.
+        let mut long_len = AB::Expr::zero();
+        for (k, &flag) in local.len_len.iter().enumerate() {
+            long_len = long_len + flag * from_bytes_be::<AB>(&local.header[1..k + 2]);
+        }
+        builder.assert_eq(local.len, is_byte + is_short.clone() * kind_offset.clone() + long_len);
+        let header_len = is_short + is_long * AB::F::from_canonical_u32(2) + len_len;
+
+        // The item ends within the payload, and the rows past the end of the payload have no
+        // item.
+        let end = local.offset + header_len.clone() + local.len;
+        builder
+            .when(local.is_item)
+            .assert_eq(local.payload_len - end.clone(), from_bytes_le::<AB>(&local.remaining));
+        builder
+            .when(local.is_real)
+            .when_not(local.is_item)
+            .assert_eq(local.offset, local.payload_len);
+
+        // Write the offset and the length of the payload of the item, with the list flag in the
+        // top byte of the length.
+        let entry = value_as_limbs(&local.entry_memory);
+        builder
+            .when(local.is_item)
+            .assert_eq(from_bytes_le::<AB>(&entry[..LEN_BYTES]), local.offset + header_len);
+        builder.when(local.is_item).assert_zero(entry[LEN_BYTES]);
+        builder
+            .when(local.is_item)
+            .assert_eq(from_bytes_le::<AB>(&entry[WORD_SIZE..WORD_SIZE + LEN_BYTES]), local.len);
+        builder
+            .when(local.is_item)
+            .assert_eq(entry[WORD_SIZE + LEN_BYTES], is_short_list + is_long_list);
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk + local.index * AB::F::from_canonical_u32(2) + AB::Expr::one(),
+            local.table_ptr
+                + local.index * AB::F::from_canonical_usize(RLP_SCAN_ENTRY_WORDS * WORD_SIZE),
+            &local.entry_memory,
+            local.is_item,
+        );
+
+        // Range check the bytes of the row.
+        let last_offset = last_offset - kind_offset.clone();
+        let mut range_checks = vec![kind_offset, last_offset, local.excess.into()];
+        range_checks.extend(local.offset_bytes.iter().map(|&byte| byte.into()));
+        range_checks.extend(local.remaining.iter().map(|&byte| byte.into()));
+        range_checks.extend(entry[..LEN_BYTES].iter().map(|&byte| byte.into()));
+        range_checks
+            .extend(entry[WORD_SIZE..WORD_SIZE + LEN_BYTES].iter().map(|&byte| byte.into()));
+        builder.slice_range_check_u8(&range_checks, local.is_item);
+
+        // The rows of an event share the arguments, and carry the offset and the count forward.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - local.is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_zero(next.is_first);
+        continuation.assert_eq(local.index + AB::Expr::one(), next.index);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.state_ptr, next.state_ptr);
+        continuation.assert_eq(local.table_ptr, next.table_ptr);
+        continuation.assert_eq(local.payload_ptr, next.payload_ptr);
+        continuation.assert_eq(local.payload_len, next.payload_len);
+        continuation.assert_eq(local.start_offset, next.start_offset);
+        continuation.assert_eq(end.clone(), next.offset);
+        // Once a row has no item, neither do the following rows of the event.
+        continuation.when(next.is_item).assert_one(local.is_item);
+        continuation.assert_eq(local.num_items + next.is_item, next.num_items);
+
+        // Write the updated state on the last row. The original state is stored in the
+        // "prev_value" of the state_memory, and the payload is left as is.
+        let payload_ptr = &local.state_memory[0];
+        builder
+            .when(local.is_last)
+            .assert_eq(payload_ptr.prev_value().reduce::<AB>(), local.payload_ptr);
+        builder
+            .when(local.is_last)
+            .assert_all_eq(payload_ptr.value().0, payload_ptr.prev_value().0);
+        let payload_len = &local.state_memory[1];
+        builder
+            .when(local.is_last)
+            .assert_eq(payload_len.prev_value().reduce::<AB>(), local.payload_len);
+        builder.when(local.is_last).assert_zero(payload_len.prev_value()[WORD_SIZE - 1]);
+        builder
+            .when(local.is_last)
+            .assert_all_eq(payload_len.value().0, payload_len.prev_value().0);
+        let offset = &local.state_memory[STATE_OFFSET];
+        builder
+            .when(local.is_last)
+            .assert_eq(offset.prev_value().reduce::<AB>(), local.start_offset);
+        builder
+            .when(local.is_last)
+            .assert_eq(from_bytes_le::<AB>(&offset.value().0[..LEN_BYTES]), end);
+        builder.when(local.is_last).assert_zero(offset.value()[LEN_BYTES]);
+        builder.slice_range_check_u8(&offset.value().0[..LEN_BYTES], local.is_last);
+        let count = local.state_memory[STATE_COUNT].value();
+        builder.when(local.is_last).assert_eq(count[0], local.num_items);
+        for &byte in &count.0[1..] {
+            builder.when(local.is_last).assert_zero(byte);
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk + AB::F::from_canonical_usize(2 * RLP_SCAN_MAX_ITEMS),
+            local.state_ptr,
+            &local.state_memory,
+            local.is_last,
+        );
+
+        // Receive the arguments on the first row of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::RLP_SCAN.syscall_id()),
+            local.state_ptr,
+            local.table_ptr,
+            local.is_first,
+            InteractionScope::Local,
+        );
+    }
+}
+
+/// The value of little-endian bytes.
+fn from_bytes_le<AB: SP1AirBuilder>(bytes: &[AB::Var]) -> AB::Expr {
+    from_bytes_be::<AB>(&bytes.iter().rev().copied().collect::<Vec<_>>())
+}
+
+/// The value of big-endian bytes.
+fn from_bytes_be<AB: SP1AirBuilder>(bytes: &[AB::Var]) -> AB::Expr {
+    let byte_base = AB::Expr::from_canonical_u32(1 << 8);
+    bytes.iter().fold(AB::Expr::zero(), |acc, &byte| acc * byte_base.clone() + byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::RLP_SCAN_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_rlp_scan() {
+        utils::setup_logger();
+        let program = Program::from(RLP_SCAN_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
@@ -0,0 +1,392 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::{field_op_wide::WideFieldMulCols, range::FieldLtCols},
+    utils::{
+        limbs_from_access, limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le_vec,
+        zeroed_f_vec,
+    },
+};
+
+use generic_array::GenericArray;
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{rsa_ladder, ByteRecord, PrecompileEvent, RSA_LADDER_STEPS},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    rsa::{U2048Field, U4096Field},
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+    mem::size_of,
+};
+
+/// The index of the last step of an event.
+const LAST_STEP: usize = RSA_LADDER_STEPS - 1;
+
+pub const fn num_rsa_modexp_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<RsaModExpCols<u8, P>>()
+}
+
+/// The parameters of an RSA modexp syscall.
+pub trait RsaParameters: FieldParameters + NumWords {
+    /// The syscall proven by the chip.
+    const SYSCALL_CODE: SyscallCode;
+
+    /// The name of the chip.
+    const NAME: &'static str;
+}
+
+impl RsaParameters for U2048Field {
+    const SYSCALL_CODE: SyscallCode = SyscallCode::RSA2048_MODEXP;
+    const NAME: &'static str = "Rsa2048ModExp";
+}
+
+impl RsaParameters for U4096Field {
+    const SYSCALL_CODE: SyscallCode = SyscallCode::RSA4096_MODEXP;
+    const NAME: &'static str = "Rsa4096ModExp";
+}
+
+/// A chip raising a base to a public exponent modulo an RSA modulus, with the square-and-multiply
+/// ladder of [`rsa_ladder`].
+///
+/// The syscall takes a pointer to the base, which is overwritten with the result, and a pointer to
+/// the modulus, which is followed by the exponent.
+///
+/// Every event takes [`RSA_LADDER_STEPS`] rows. A row multiplies the accumulator either by itself
+/// or by the base, and the rows after the end of the ladder are idle and keep the accumulator. The
+/// accumulator is thus always the base raised to the exponent built so far, which the last row
+/// checks against the exponent read from memory.
+#[derive(Default)]
+pub struct RsaModExpChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: RsaParameters> RsaModExpChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+/// A set of columns for the RsaModExp operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct RsaModExpCols<T, P: FieldParameters + NumWords> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the base, which is overwritten with the result.
+    pub x_ptr: T,
+
+    /// The pointer to the modulus, which is followed by the exponent.
+    pub params_ptr: T,
+
+    /// The step of the row, as a one-hot encoding.
+    pub step: [T; RSA_LADDER_STEPS],
+
+    /// Whether the row squares the accumulator.
+    pub is_square: T,
+
+    /// Whether the row multiplies the accumulator by the base.
+    pub is_multiply: T,
+
+    /// The exponent of the base in the accumulator, before and after the step.
+    pub exp_in: T,
+    pub exp_out: T,
+
+    // Memory columns.
+    // The memory is accessed on the first step, and its values are shared by the other steps.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, P::WordsFieldElement>,
+    pub modulus_memory: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
+    pub exponent_memory: MemoryReadCols<T>,
+
+    /// The accumulator, which starts as the base.
+    pub acc: Limbs<T, P::Limbs>,
+
+    /// The factor of the step, which is either the accumulator or the base.
+    pub multiplier: Limbs<T, P::Limbs>,
+
+    /// The product of the accumulator and the multiplier, which is the next accumulator.
+    pub product: WideFieldMulCols<T, P>,
+
+    /// Checks that the result is reduced on the last step.
+    pub result_range_check: FieldLtCols<T, P>,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32, P: RsaParameters> MachineAir<F> for RsaModExpChip<P> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        P::NAME.to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let num_cols = num_rsa_modexp_cols::<P>();
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(P::SYSCALL_CODE) {
+            let event =
+                if let PrecompileEvent::RsaModExp(event) = event { event } else { unreachable!() };
+            let base = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.x));
+            let modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.modulus));
+            let ladder = rsa_ladder(event.exponent).unwrap();
+
+            let mut acc = base.clone();
+            let mut exp = 1u32;
+            for step in 0..RSA_LADDER_STEPS {
+                let mut row = zeroed_f_vec(num_cols);
+                let cols: &mut RsaModExpCols<F, P> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.params_ptr = F::from_canonical_u32(event.params_ptr);
+                cols.step[step] = F::one();
+
+                // Populate memory columns. Only the first step accesses the memory, so the other
+                // steps do not record the lookups of the accesses.
+                let mut discarded_byte_lookup_events = Vec::new();
+                let memory_byte_lookup_events = if step == 0 {
+                    &mut new_byte_lookup_events
+                } else {
+                    &mut discarded_byte_lookup_events
+                };
+                for (col, record) in cols.x_memory.iter_mut().zip(&event.x_memory_records) {
+                    col.populate(*record, memory_byte_lookup_events);
+                }
+                for (col, record) in
+                    cols.modulus_memory.iter_mut().zip(&event.params_memory_records)
+                {
+                    col.populate(*record, memory_byte_lookup_events);
+                }
+                cols.exponent_memory.populate(
+                    *event.params_memory_records.last().unwrap(),
+                    memory_byte_lookup_events,
+                );
+
+                // Populate the step of the ladder.
+                cols.acc = P::to_limbs_field::<F, _>(&acc);
+                cols.exp_in = F::from_canonical_u32(exp);
+                if let Some(&is_square) = ladder.get(step) {
+                    let multiplier = if is_square { acc.clone() } else { base.clone() };
+                    cols.is_square = F::from_bool(is_square);
+                    cols.is_multiply = F::from_bool(!is_square);
+                    cols.multiplier = P::to_limbs_field::<F, _>(&multiplier);
+                    acc = cols.product.populate(
+                        &mut new_byte_lookup_events,
+                        event.shard,
+                        &acc,
+                        &multiplier,
+                        &modulus,
+                    );
+                    exp = if is_square { 2 * exp } else { exp + 1 };
+                } else {
+                    cols.product.result = cols.acc.clone();
+                }
+                cols.exp_out = F::from_canonical_u32(exp);
+
+                if step == LAST_STEP {
+                    cols.result_range_check.populate(
+                        &mut new_byte_lookup_events,
+                        event.shard,
+                        &acc,
+                        &modulus,
+                    );
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(&mut rows, || zeroed_f_vec(num_cols), input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_cols);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut RsaModExpCols<F, P> =
+                trace.values[i * num_cols..(i + 1) * num_cols].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(P::SYSCALL_CODE).is_empty()
+        }
+    }
+}
+
+impl<F, P: RsaParameters> BaseAir<F> for RsaModExpChip<P> {
+    fn width(&self) -> usize {
+        num_rsa_modexp_cols::<P>()
+    }
+}
+
+impl<AB, P: RsaParameters> Air<AB> for RsaModExpChip<P>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+    Limbs<AB::Var, <P as NumLimbs>::Witness>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &RsaModExpCols<AB::Var, P> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &RsaModExpCols<AB::Var, P> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans, and that only real rows take a step of the ladder.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_square);
+        builder.assert_bool(local.is_multiply);
+        for &step in local.step.iter() {
+            builder.assert_bool(step);
+        }
+        let is_active: AB::Expr = local.is_square + local.is_multiply;
+        builder.assert_bool(is_active.clone());
+        builder.when_not(local.is_real).assert_zero(is_active.clone());
+
+        // Every real row has exactly one step.
+        let step_sum = local.step.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(step_sum, local.is_real);
+        let (is_first, is_last) = (local.step[0], local.step[LAST_STEP]);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(is_first);
+        builder.when_last_row().when(local.is_real).assert_one(is_last);
+        builder.when_transition().when(is_last).assert_eq(next.step[0], next.is_real);
+
+        // The rows of an event share the arguments and the memory, go through the steps in order,
+        // and pass the accumulator along.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.x_ptr, next.x_ptr);
+        continuation.assert_eq(local.params_ptr, next.params_ptr);
+        continuation.assert_zero(next.step[0]);
+        for k in 0..LAST_STEP {
+            continuation.assert_eq(local.step[k], next.step[k + 1]);
+        }
+        for (local_memory, next_memory) in local.x_memory.iter().zip(next.x_memory.iter()) {
+            continuation.assert_word_eq(*local_memory.prev_value(), *next_memory.prev_value());
+            continuation.assert_word_eq(*local_memory.value(), *next_memory.value());
+        }
+        for (local_memory, next_memory) in
+            local.modulus_memory.iter().zip(next.modulus_memory.iter())
+        {
+            continuation.assert_word_eq(*local_memory.value(), *next_memory.value());
+        }
+        continuation.assert_word_eq(*local.exponent_memory.value(), *next.exponent_memory.value());
+        continuation.assert_all_eq(local.product.result, next.acc);
+        continuation.assert_eq(local.exp_out, next.exp_in);
+        // Once the ladder is over, the remaining steps of the event are idle.
+        continuation.when_not(is_active.clone()).assert_zero(next.is_square + next.is_multiply);
+
+        // The base is the previous value of the memory it is written to.
+        let base: Limbs<AB::Var, <P as NumLimbs>::Limbs> = limbs_from_prev_access(&local.x_memory);
+        let modulus: Limbs<AB::Var, <P as NumLimbs>::Limbs> =
+            limbs_from_access(&local.modulus_memory);
+
+        // The first step starts from the base, raised to the power one.
+        builder.when(is_first).assert_all_eq(local.acc, base);
+        builder.when(is_first).assert_one(local.exp_in);
+
+        // Every active step multiplies the accumulator by itself or by the base, and updates the
+        // exponent accordingly. Idle steps keep the accumulator.
+        builder.assert_eq(
+            local.exp_out,
+            local.exp_in * (AB::Expr::one() + local.is_square) + local.is_multiply,
+        );
+        for i in 0..P::NB_LIMBS {
+            builder.assert_eq(
+                local.multiplier[i],
+                local.is_square * local.acc[i] + local.is_multiply * base[i],
+            );
+        }
+        local.product.eval(builder, &local.acc, &local.multiplier, &modulus, is_active.clone());
+        builder.when(local.is_real - is_active).assert_all_eq(local.product.result, local.acc);
+
+        // The last step has reached the exponent, and writes the reduced result.
+        let exponent = local.exponent_memory.value();
+        builder.when(is_last).assert_eq(
+            local.exp_out,
+            exponent[0]
+                + exponent[1] * AB::F::from_canonical_u32(1 << 8)
+                + exponent[2] * AB::F::from_canonical_u32(1 << 16),
+        );
+        builder.when(is_last).assert_zero(exponent[3]);
+        local.result_range_check.eval(builder, &local.product.result, &modulus, is_last);
+        builder.when(is_last).assert_all_eq(local.product.result, value_as_limbs(&local.x_memory));
+
+        // Read the modulus and the exponent, and then write the result.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.params_ptr,
+            &[&local.modulus_memory[..], &[local.exponent_memory]].concat(),
+            is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            is_first,
+        );
+
+        // Receive the arguments on the first step of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(P::SYSCALL_CODE.syscall_id()),
+            local.x_ptr,
+            local.params_ptr,
+            is_first,
+            InteractionScope::Local,
+        );
+    }
+}
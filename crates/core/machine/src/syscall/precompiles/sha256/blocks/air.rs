@@ -0,0 +1,286 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        ByteRecord, PrecompileEvent, SHA256_BLOCKS_COMPRESS_OFFSET, SHA256_BLOCKS_COUNT_OFFSET,
+        SHA256_BLOCKS_EXTEND_OFFSET, SHA256_BLOCKS_H_OFFSET, SHA256_BLOCKS_MAX_BLOCKS,
+        SHA256_BLOCKS_STEP_CYCLES,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the Sha256CompressBlocksCols.
+const NUM_COLS: usize = size_of::<Sha256CompressBlocksCols<u8>>();
+
+/// The number of words in a SHA-256 block.
+const BLOCK_WORDS: usize = 16;
+
+/// A chip compressing up to [`SHA256_BLOCKS_MAX_BLOCKS`] consecutive blocks into a SHA-256 state.
+///
+/// The syscall takes a pointer to a buffer made of a 64-word message schedule, the 8-word SHA-256
+/// state and the number of blocks, and a pointer to the blocks.
+///
+/// Each block is copied to the start of the schedule, and then a `SHA_EXTEND` and a
+/// `SHA_COMPRESS` syscall are sent on the buffer, which are proven by the
+/// [`super::ShaExtendChip`] and [`super::ShaCompressChip`].
+#[derive(Default)]
+pub struct Sha256CompressBlocksChip;
+
+impl Sha256CompressBlocksChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Sha256CompressBlocks operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Sha256CompressBlocksCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the message schedule, followed by the SHA-256 state and the number of
+    /// blocks.
+    pub w_ptr: T,
+
+    /// The pointer to the blocks.
+    pub blocks_ptr: T,
+
+    /// Whether each block is compressed. The flags of the compressed blocks come first.
+    pub is_block: [T; SHA256_BLOCKS_MAX_BLOCKS],
+
+    /// The nonces of the `SHA_EXTEND` syscalls of each block.
+    pub extend_nonces: [T; SHA256_BLOCKS_MAX_BLOCKS],
+
+    /// The nonces of the `SHA_COMPRESS` syscalls of each block.
+    pub compress_nonces: [T; SHA256_BLOCKS_MAX_BLOCKS],
+
+    // Memory columns.
+    pub count_memory: MemoryReadCols<T>,
+    pub block_memory: [[MemoryReadCols<T>; BLOCK_WORDS]; SHA256_BLOCKS_MAX_BLOCKS],
+
+    /// The block copied to the start of the message schedule.
+    pub w_memory: [[MemoryWriteCols<T>; BLOCK_WORDS]; SHA256_BLOCKS_MAX_BLOCKS],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Sha256CompressBlocksChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Sha256CompressBlocks".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::SHA256_COMPRESS_BLOCKS) {
+            let event = if let PrecompileEvent::Sha256CompressBlocks(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+            let cols: &mut Sha256CompressBlocksCols<F> = row.as_mut_slice().borrow_mut();
+
+            // Assign basic values to the columns.
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.w_ptr = F::from_canonical_u32(event.w_ptr);
+            cols.blocks_ptr = F::from_canonical_u32(event.blocks_ptr);
+            for (i, (extend, compress)) in event.sha_syscalls.iter().enumerate() {
+                cols.is_block[i] = F::one();
+                cols.extend_nonces[i] = F::from_canonical_u32(extend.nonce);
+                cols.compress_nonces[i] = F::from_canonical_u32(compress.nonce);
+            }
+
+            // Populate memory columns.
+            cols.count_memory.populate(event.count_read_record, &mut new_byte_lookup_events);
+            for (i, records) in event.block_read_records.iter().enumerate() {
+                for j in 0..BLOCK_WORDS {
+                    cols.block_memory[i][j].populate(records[j], &mut new_byte_lookup_events);
+                }
+            }
+            for (i, records) in event.w_write_records.iter().enumerate() {
+                for j in 0..BLOCK_WORDS {
+                    cols.w_memory[i][j].populate(records[j], &mut new_byte_lookup_events);
+                }
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Sha256CompressBlocksCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::SHA256_COMPRESS_BLOCKS).is_empty()
+        }
+    }
+
+    fn commit_scope(&self) -> InteractionScope {
+        InteractionScope::Global
+    }
+}
+
+impl<F> BaseAir<F> for Sha256CompressBlocksChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Sha256CompressBlocksChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Sha256CompressBlocksCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Sha256CompressBlocksCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let h_ptr: AB::Expr = local.w_ptr + AB::F::from_canonical_u32(SHA256_BLOCKS_H_OFFSET);
+        let step_clk = |i: usize| -> AB::Expr {
+            local.clk + AB::F::from_canonical_u32(i as u32 * SHA256_BLOCKS_STEP_CYCLES)
+        };
+
+        // A real row compresses the first block, and a block is only compressed after the one
+        // before it.
+        builder.assert_eq(local.is_block[0], local.is_real);
+        for i in 0..SHA256_BLOCKS_MAX_BLOCKS {
+            builder.assert_bool(local.is_block[i]);
+            if i > 0 {
+                builder.when(local.is_block[i]).assert_one(local.is_block[i - 1]);
+            }
+        }
+
+        // The number of blocks is the number of compressed blocks.
+        let num_blocks =
+            local.is_block.iter().fold(AB::Expr::zero(), |acc, &is_block| acc + is_block);
+        builder.eval_memory_access(
+            local.shard,
+            local.clk,
+            local.w_ptr + AB::F::from_canonical_u32(SHA256_BLOCKS_COUNT_OFFSET),
+            &local.count_memory,
+            local.is_real,
+        );
+        builder
+            .when(local.is_real)
+            .assert_eq(local.count_memory.value().reduce::<AB>(), num_blocks);
+
+        for i in 0..SHA256_BLOCKS_MAX_BLOCKS {
+            // Copy the block to the start of the schedule.
+            for j in 0..BLOCK_WORDS {
+                builder.when(local.is_block[i]).assert_word_eq(
+                    *local.w_memory[i][j].value(),
+                    *local.block_memory[i][j].value(),
+                );
+            }
+            builder.eval_memory_access_slice(
+                local.shard,
+                step_clk(i),
+                local.blocks_ptr + AB::F::from_canonical_u32((i * BLOCK_WORDS * 4) as u32),
+                &local.block_memory[i],
+                local.is_block[i],
+            );
+            builder.eval_memory_access_slice(
+                local.shard,
+                step_clk(i),
+                local.w_ptr,
+                &local.w_memory[i],
+                local.is_block[i],
+            );
+
+            // Send the extend and compress syscalls of the block. They are proven in their own
+            // shards, so they are sent globally, as the core shards do.
+            builder.send_syscall(
+                local.shard,
+                step_clk(i) + AB::F::from_canonical_u32(SHA256_BLOCKS_EXTEND_OFFSET),
+                local.extend_nonces[i],
+                AB::F::from_canonical_u32(SyscallCode::SHA_EXTEND.syscall_id()),
+                local.w_ptr,
+                AB::Expr::zero(),
+                local.is_block[i],
+                InteractionScope::Global,
+            );
+            builder.send_syscall(
+                local.shard,
+                step_clk(i) + AB::F::from_canonical_u32(SHA256_BLOCKS_COMPRESS_OFFSET),
+                local.compress_nonces[i],
+                AB::F::from_canonical_u32(SyscallCode::SHA_COMPRESS.syscall_id()),
+                local.w_ptr,
+                h_ptr.clone(),
+                local.is_block[i],
+                InteractionScope::Global,
+            );
+        }
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::SHA256_COMPRESS_BLOCKS.syscall_id()),
+            local.w_ptr,
+            local.blocks_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
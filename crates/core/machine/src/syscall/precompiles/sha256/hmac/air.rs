@@ -0,0 +1,358 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        ByteLookupEvent, ByteRecord, PrecompileEvent, HMAC_SHA256_COMPRESS_OFFSET,
+        HMAC_SHA256_EXTEND_OFFSET, HMAC_SHA256_H_OFFSET, HMAC_SHA256_IPAD, HMAC_SHA256_OPAD,
+        HMAC_SHA256_OUTER_PADDING, HMAC_SHA256_STEPS, HMAC_SHA256_STEP_CYCLES, SHA256_IV,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    MachineRecord, Word,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the HmacSha256Cols.
+const NUM_COLS: usize = size_of::<HmacSha256Cols<u8>>();
+
+/// The number of words in a SHA-256 block.
+const BLOCK_WORDS: usize = 16;
+
+/// The number of words in the SHA-256 state.
+const STATE_WORDS: usize = 8;
+
+/// A chip computing HMAC-SHA256 over a single block message.
+///
+/// The syscall takes a pointer to the key, zero padded to a block, and a pointer to a buffer made
+/// of a 64-word message schedule followed by the 8-word SHA-256 state. The first block of the
+/// schedule holds the padded message and the state receives the tag.
+///
+/// Each step writes a block to the schedule and sends a `SHA_EXTEND` and a `SHA_COMPRESS` syscall
+/// on the buffer, which are proven by the [`super::ShaExtendChip`] and [`super::ShaCompressChip`].
+/// The state is reset to the initial hash value before the inner and the outer hash, and the inner
+/// hash is read back while resetting it for the outer one.
+#[derive(Default)]
+pub struct HmacSha256Chip;
+
+impl HmacSha256Chip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the HmacSha256 operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct HmacSha256Cols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the key block.
+    pub key_ptr: T,
+
+    /// The pointer to the message schedule, followed by the SHA-256 state.
+    pub w_ptr: T,
+
+    /// The nonces of the `SHA_EXTEND` syscalls of each step.
+    pub extend_nonces: [T; HMAC_SHA256_STEPS],
+
+    /// The nonces of the `SHA_COMPRESS` syscalls of each step.
+    pub compress_nonces: [T; HMAC_SHA256_STEPS],
+
+    // Memory columns.
+    pub key_memory: [MemoryReadCols<T>; BLOCK_WORDS],
+
+    /// The block written at each step. The message is the "prev_value" of the first block.
+    pub block_memory: [[MemoryWriteCols<T>; BLOCK_WORDS]; HMAC_SHA256_STEPS],
+
+    /// The state reset before the inner and the outer hash. The inner hash is the "prev_value" of
+    /// the second reset.
+    pub h_memory: [[MemoryWriteCols<T>; STATE_WORDS]; 2],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for HmacSha256Chip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "HmacSha256".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Generate the trace rows & corresponding records for each chunk of events concurrently.
+        let rows_and_records = input
+            .get_precompile_events(SyscallCode::HMAC_SHA256)
+            .chunks(1)
+            .map(|events| {
+                let mut records = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .map(|(_, event)| {
+                        let event = if let PrecompileEvent::HmacSha256(event) = event {
+                            event
+                        } else {
+                            unreachable!()
+                        };
+                        let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                        let cols: &mut HmacSha256Cols<F> = row.as_mut_slice().borrow_mut();
+
+                        // Assign basic values to the columns.
+                        cols.is_real = F::one();
+                        cols.shard = F::from_canonical_u32(event.shard);
+                        cols.clk = F::from_canonical_u32(event.clk);
+                        cols.key_ptr = F::from_canonical_u32(event.key_ptr);
+                        cols.w_ptr = F::from_canonical_u32(event.w_ptr);
+                        for (step, (extend, compress)) in event.sha_syscalls.iter().enumerate() {
+                            cols.extend_nonces[step] = F::from_canonical_u32(extend.nonce);
+                            cols.compress_nonces[step] = F::from_canonical_u32(compress.nonce);
+                        }
+
+                        // Populate memory columns.
+                        for i in 0..BLOCK_WORDS {
+                            cols.key_memory[i]
+                                .populate(event.key_read_records[i], &mut new_byte_lookup_events);
+                        }
+                        for (step, records) in event.block_write_records.iter().enumerate() {
+                            for i in 0..BLOCK_WORDS {
+                                cols.block_memory[step][i]
+                                    .populate(records[i], &mut new_byte_lookup_events);
+                            }
+                        }
+                        for (reset, records) in event.h_write_records.iter().enumerate() {
+                            for i in 0..STATE_WORDS {
+                                cols.h_memory[reset][i]
+                                    .populate(records[i], &mut new_byte_lookup_events);
+                            }
+                        }
+
+                        // The inner and outer key blocks are xored with the paddings byte by byte.
+                        for (step, pad) in [(0, HMAC_SHA256_IPAD), (2, HMAC_SHA256_OPAD)] {
+                            for i in 0..BLOCK_WORDS {
+                                let key = event.key_read_records[i].value.to_le_bytes();
+                                let block = event.block_write_records[step][i].value.to_le_bytes();
+                                for j in 0..WORD_SIZE {
+                                    new_byte_lookup_events.push(ByteLookupEvent {
+                                        shard: event.shard,
+                                        opcode: ByteOpcode::XOR,
+                                        a1: block[j] as u16,
+                                        a2: 0,
+                                        b: key[j],
+                                        c: pad.to_le_bytes()[j],
+                                    });
+                                }
+                            }
+                        }
+
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                records.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, records)
+            })
+            .collect::<Vec<_>>();
+
+        //  Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for (row, mut record) in rows_and_records {
+            rows.extend(row);
+            output.append(&mut record);
+        }
+
+        pad_rows_fixed(&mut rows, || [F::zero(); NUM_COLS], input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut HmacSha256Cols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::HMAC_SHA256).is_empty()
+        }
+    }
+
+    fn commit_scope(&self) -> InteractionScope {
+        InteractionScope::Global
+    }
+}
+
+impl<F> BaseAir<F> for HmacSha256Chip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for HmacSha256Chip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &HmacSha256Cols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &HmacSha256Cols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let h_ptr: AB::Expr = local.w_ptr + AB::F::from_canonical_u32(HMAC_SHA256_H_OFFSET);
+        let step_clk = |step: usize| -> AB::Expr {
+            local.clk + AB::F::from_canonical_u32(step as u32 * HMAC_SHA256_STEP_CYCLES)
+        };
+
+        // The inner and outer key blocks are the key xored with the inner and outer paddings.
+        for (step, pad) in [(0, HMAC_SHA256_IPAD), (2, HMAC_SHA256_OPAD)] {
+            for i in 0..BLOCK_WORDS {
+                let key = local.key_memory[i].value();
+                let block = local.block_memory[step][i].value();
+                for j in 0..WORD_SIZE {
+                    builder.send_byte(
+                        AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                        block[j],
+                        key[j],
+                        AB::F::from_canonical_u8(pad.to_le_bytes()[j]),
+                        local.is_real,
+                    );
+                }
+            }
+        }
+
+        // The message block is the one the inner key block was written over.
+        for i in 0..BLOCK_WORDS {
+            builder.when(local.is_real).assert_word_eq(
+                *local.block_memory[1][i].value(),
+                *local.block_memory[0][i].prev_value(),
+            );
+        }
+
+        // The last block is the inner hash followed by the padding of the outer hash.
+        for i in 0..STATE_WORDS {
+            builder.when(local.is_real).assert_word_eq(
+                *local.block_memory[3][i].value(),
+                *local.h_memory[1][i].prev_value(),
+            );
+            builder.when(local.is_real).assert_all_eq(
+                *local.block_memory[3][STATE_WORDS + i].value(),
+                Word::<AB::F>::from(HMAC_SHA256_OUTER_PADDING[i]),
+            );
+        }
+
+        // The state is reset to the initial hash value before each hash.
+        for h_memory in local.h_memory.iter() {
+            for i in 0..STATE_WORDS {
+                builder
+                    .when(local.is_real)
+                    .assert_all_eq(*h_memory[i].value(), Word::<AB::F>::from(SHA256_IV[i]));
+            }
+        }
+
+        // Read the key and write the blocks and the state resets at the start of their steps.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.key_ptr,
+            &local.key_memory,
+            local.is_real,
+        );
+        for step in 0..HMAC_SHA256_STEPS {
+            builder.eval_memory_access_slice(
+                local.shard,
+                step_clk(step),
+                local.w_ptr,
+                &local.block_memory[step],
+                local.is_real,
+            );
+        }
+        for (reset, h_memory) in local.h_memory.iter().enumerate() {
+            builder.eval_memory_access_slice(
+                local.shard,
+                step_clk(2 * reset),
+                h_ptr.clone(),
+                h_memory,
+                local.is_real,
+            );
+        }
+
+        // Send the extend and compress syscalls of each step. They are proven in their own shards,
+        // so they are sent globally, as the core shards do.
+        for step in 0..HMAC_SHA256_STEPS {
+            builder.send_syscall(
+                local.shard,
+                step_clk(step) + AB::F::from_canonical_u32(HMAC_SHA256_EXTEND_OFFSET),
+                local.extend_nonces[step],
+                AB::F::from_canonical_u32(SyscallCode::SHA_EXTEND.syscall_id()),
+                local.w_ptr,
+                AB::Expr::zero(),
+                local.is_real,
+                InteractionScope::Global,
+            );
+            builder.send_syscall(
+                local.shard,
+                step_clk(step) + AB::F::from_canonical_u32(HMAC_SHA256_COMPRESS_OFFSET),
+                local.compress_nonces[step],
+                AB::F::from_canonical_u32(SyscallCode::SHA_COMPRESS.syscall_id()),
+                local.w_ptr,
+                h_ptr.clone(),
+                local.is_real,
+                InteractionScope::Global,
+            );
+        }
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::HMAC_SHA256.syscall_id()),
+            local.key_ptr,
+            local.w_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -1,5 +1,9 @@
+mod blocks;
 mod compress;
 mod extend;
+mod hmac;
 
+pub use blocks::*;
 pub use compress::*;
 pub use extend::*;
+pub use hmac::*;
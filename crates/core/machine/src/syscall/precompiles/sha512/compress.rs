@@ -0,0 +1,620 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::{
+        u64_to_field, Add64Operation, And64Operation, FixedShift64Operation, Not64Operation,
+        Xor64Operation, U64,
+    },
+    utils::pad_rows_fixed,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        ByteRecord, PrecompileEvent, SHA512_BLOCK_WORDS, SHA512_COMPRESS_ROWS, SHA512_K,
+        SHA512_STATE_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, SP1AirBuilder},
+    Word,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the Sha512CompressCols.
+const NUM_COLS: usize = size_of::<Sha512CompressCols<u8>>();
+
+/// The index of the last round of the compression.
+const LAST_ROUND: usize = SHA512_COMPRESS_ROWS - 1;
+
+#[derive(Default)]
+pub struct Sha512CompressChip;
+
+impl Sha512CompressChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Sha512Compress operation.
+///
+/// Every event takes `SHA512_COMPRESS_ROWS` rows, one for every round of the compression. Each row
+/// holds the working variables `a` to `h` at the start of its round and a window of the next 16
+/// words of the message schedule, of which it extends the last one.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Sha512CompressCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the state.
+    pub state_ptr: T,
+
+    /// The pointer to the block.
+    pub block_ptr: T,
+
+    /// The round of the row, as a one-hot encoding.
+    pub round: [T; SHA512_COMPRESS_ROWS],
+
+    /// Whether this is the first row of the event.
+    pub is_first: T,
+
+    /// Whether this is the last row of the event.
+    pub is_last: T,
+
+    // Memory columns.
+    // The block is read on the first row, and the state is written on the last row.
+    pub block_memory: [MemoryReadCols<T>; SHA512_BLOCK_WORDS],
+    pub state_memory: [MemoryWriteCols<T>; SHA512_STATE_WORDS],
+
+    /// The state before the compression, which is added back to the working variables on the
+    /// last row.
+    pub initial: [U64<T>; 8],
+
+    /// The working variables `a` to `h` at the start of the round.
+    pub vars: [U64<T>; 8],
+
+    /// The words `w[i..i + 16]` of the message schedule, where `i` is the round.
+    pub w: [U64<T>; 16],
+
+    /// The round constant `K[i]`.
+    pub k: U64<T>,
+
+    pub e_rr_14: FixedShift64Operation<T>,
+    pub e_rr_18: FixedShift64Operation<T>,
+    pub e_rr_41: FixedShift64Operation<T>,
+    pub s1_intermediate: Xor64Operation<T>,
+    /// `S1 := (e rightrotate 14) xor (e rightrotate 18) xor (e rightrotate 41)`.
+    pub s1: Xor64Operation<T>,
+
+    pub e_and_f: And64Operation<T>,
+    pub e_not: Not64Operation<T>,
+    pub e_not_and_g: And64Operation<T>,
+    /// `ch := (e and f) xor ((not e) and g)`.
+    pub ch: Xor64Operation<T>,
+
+    /// `temp1 := h + S1 + ch + k[i] + w[i]`.
+    pub temp1: Add64Operation<T>,
+
+    pub a_rr_28: FixedShift64Operation<T>,
+    pub a_rr_34: FixedShift64Operation<T>,
+    pub a_rr_39: FixedShift64Operation<T>,
+    pub s0_intermediate: Xor64Operation<T>,
+    /// `S0 := (a rightrotate 28) xor (a rightrotate 34) xor (a rightrotate 39)`.
+    pub s0: Xor64Operation<T>,
+
+    pub a_and_b: And64Operation<T>,
+    pub a_and_c: And64Operation<T>,
+    pub b_and_c: And64Operation<T>,
+    pub maj_intermediate: Xor64Operation<T>,
+    /// `maj := (a and b) xor (a and c) xor (b and c)`.
+    pub maj: Xor64Operation<T>,
+
+    /// `temp2 := S0 + maj`.
+    pub temp2: Add64Operation<T>,
+
+    /// The next value of `e` is `d + temp1`.
+    pub d_add_temp1: Add64Operation<T>,
+    /// The next value of `a` is `temp1 + temp2`.
+    pub temp1_add_temp2: Add64Operation<T>,
+
+    pub w1_rr_1: FixedShift64Operation<T>,
+    pub w1_rr_8: FixedShift64Operation<T>,
+    pub w1_shr_7: FixedShift64Operation<T>,
+    pub sigma0_intermediate: Xor64Operation<T>,
+    /// `s0 := (w[i + 1] rightrotate 1) xor (w[i + 1] rightrotate 8) xor (w[i + 1] rightshift 7)`.
+    pub sigma0: Xor64Operation<T>,
+
+    pub w14_rr_19: FixedShift64Operation<T>,
+    pub w14_rr_61: FixedShift64Operation<T>,
+    pub w14_shr_6: FixedShift64Operation<T>,
+    pub sigma1_intermediate: Xor64Operation<T>,
+    /// `s1 := (w[i + 14] rightrotate 19) xor (w[i + 14] rightrotate 61) xor (w[i + 14] rightshift
+    /// 6)`.
+    pub sigma1: Xor64Operation<T>,
+
+    /// `w[i + 16] := w[i] + s0 + w[i + 9] + s1`.
+    pub w_next: Add64Operation<T>,
+
+    /// The new state, written on the last row.
+    pub finalize: [Add64Operation<T>; 8],
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Sha512CompressChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Sha512Compress".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::SHA512_COMPRESS) {
+            let event = if let PrecompileEvent::Sha512Compress(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let blu = &mut new_byte_lookup_events;
+            let shard = event.shard;
+            let mut vars = event.state;
+            let mut w = event.block;
+
+            for round in 0..SHA512_COMPRESS_ROWS {
+                let mut row: Vec<F> = vec![F::zero(); NUM_COLS];
+                let cols: &mut Sha512CompressCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+                cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+                cols.round[round] = F::one();
+                cols.is_first = F::from_bool(round == 0);
+                cols.is_last = F::from_bool(round == LAST_ROUND);
+                cols.initial = event.state.map(u64_to_field);
+                cols.vars = vars.map(u64_to_field);
+                cols.k = u64_to_field(SHA512_K[round]);
+
+                if round == 0 {
+                    for (col, record) in cols.block_memory.iter_mut().zip(&event.block_read_records)
+                    {
+                        col.populate(*record, blu);
+                    }
+                    for word in event.block.iter().chain(&event.state) {
+                        blu.add_u8_range_checks(shard, &word.to_le_bytes());
+                    }
+                }
+
+                // The message schedule window, extended by one word. The words past the 80 used by
+                // the rounds are still computed, so that every row shifts the window the same way.
+                cols.w = w.map(u64_to_field);
+                let w1_rr_1 = cols.w1_rr_1.populate(blu, shard, w[1], 1, true);
+                let w1_rr_8 = cols.w1_rr_8.populate(blu, shard, w[1], 8, true);
+                let w1_shr_7 = cols.w1_shr_7.populate(blu, shard, w[1], 7, false);
+                let sigma0_intermediate =
+                    cols.sigma0_intermediate.populate(blu, shard, w1_rr_1, w1_rr_8);
+                let sigma0 = cols.sigma0.populate(blu, shard, sigma0_intermediate, w1_shr_7);
+                let w14_rr_19 = cols.w14_rr_19.populate(blu, shard, w[14], 19, true);
+                let w14_rr_61 = cols.w14_rr_61.populate(blu, shard, w[14], 61, true);
+                let w14_shr_6 = cols.w14_shr_6.populate(blu, shard, w[14], 6, false);
+                let sigma1_intermediate =
+                    cols.sigma1_intermediate.populate(blu, shard, w14_rr_19, w14_rr_61);
+                let sigma1 = cols.sigma1.populate(blu, shard, sigma1_intermediate, w14_shr_6);
+                let w_next = cols.w_next.populate(blu, shard, &[w[0], sigma0, w[9], sigma1]);
+
+                // The round of the compression.
+                let [a, b, c, d, e, f, g, h] = vars;
+                let e_rr_14 = cols.e_rr_14.populate(blu, shard, e, 14, true);
+                let e_rr_18 = cols.e_rr_18.populate(blu, shard, e, 18, true);
+                let e_rr_41 = cols.e_rr_41.populate(blu, shard, e, 41, true);
+                let s1_intermediate = cols.s1_intermediate.populate(blu, shard, e_rr_14, e_rr_18);
+                let s1 = cols.s1.populate(blu, shard, s1_intermediate, e_rr_41);
+
+                let e_and_f = cols.e_and_f.populate(blu, shard, e, f);
+                let e_not = cols.e_not.populate(blu, shard, e);
+                let e_not_and_g = cols.e_not_and_g.populate(blu, shard, e_not, g);
+                let ch = cols.ch.populate(blu, shard, e_and_f, e_not_and_g);
+
+                let temp1 = cols.temp1.populate(blu, shard, &[h, s1, ch, SHA512_K[round], w[0]]);
+
+                let a_rr_28 = cols.a_rr_28.populate(blu, shard, a, 28, true);
+                let a_rr_34 = cols.a_rr_34.populate(blu, shard, a, 34, true);
+                let a_rr_39 = cols.a_rr_39.populate(blu, shard, a, 39, true);
+                let s0_intermediate = cols.s0_intermediate.populate(blu, shard, a_rr_28, a_rr_34);
+                let s0 = cols.s0.populate(blu, shard, s0_intermediate, a_rr_39);
+
+                let a_and_b = cols.a_and_b.populate(blu, shard, a, b);
+                let a_and_c = cols.a_and_c.populate(blu, shard, a, c);
+                let b_and_c = cols.b_and_c.populate(blu, shard, b, c);
+                let maj_intermediate = cols.maj_intermediate.populate(blu, shard, a_and_b, a_and_c);
+                let maj = cols.maj.populate(blu, shard, maj_intermediate, b_and_c);
+
+                let temp2 = cols.temp2.populate(blu, shard, &[s0, maj]);
+
+                let d_add_temp1 = cols.d_add_temp1.populate(blu, shard, &[d, temp1]);
+                let temp1_add_temp2 = cols.temp1_add_temp2.populate(blu, shard, &[temp1, temp2]);
+
+                vars = [temp1_add_temp2, a, b, c, d_add_temp1, e, f, g];
+                w.copy_within(1.., 0);
+                w[15] = w_next;
+
+                if round == LAST_ROUND {
+                    for (i, finalize) in cols.finalize.iter_mut().enumerate() {
+                        finalize.populate(blu, shard, &[event.state[i], vars[i]]);
+                    }
+                    for (col, record) in
+                        cols.state_memory.iter_mut().zip(&event.state_write_records)
+                    {
+                        col.populate(*record, blu);
+                    }
+                }
+
+                rows.push(row);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || vec![F::zero(); NUM_COLS],
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Sha512CompressCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::SHA512_COMPRESS).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Sha512CompressChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+/// The 64-bit word stored in two consecutive memory words, low word first.
+fn u64_from_words<T: Copy>(low: &Word<T>, high: &Word<T>) -> U64<T> {
+    core::array::from_fn(|i| if i < 4 { low[i] } else { high[i - 4] })
+}
+
+impl<AB> Air<AB> for Sha512CompressChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Sha512CompressCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Sha512CompressCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans.
+        builder.assert_bool(local.is_real);
+        for bit in local.round.iter() {
+            builder.assert_bool(*bit);
+        }
+
+        // Every real row has exactly one round, and the first and last rows of the event are the
+        // first and last rounds.
+        let round_sum = local.round.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(round_sum, local.is_real);
+        builder.assert_eq(local.is_first, local.round[0]);
+        builder.assert_eq(local.is_last, local.round[LAST_ROUND]);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(local.is_first);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+        builder.when_transition().when(local.is_last).when(next.is_real).assert_one(next.is_first);
+
+        // The round constant is selected by the round.
+        for j in 0..8 {
+            let k = local.round.iter().zip(SHA512_K).fold(AB::Expr::zero(), |acc, (&x, k)| {
+                acc + x * AB::F::from_canonical_u8(k.to_le_bytes()[j])
+            });
+            builder.assert_eq(local.k[j], k);
+        }
+
+        // The first row reads the block into the message schedule, and starts from the initial
+        // state, which are both range checked.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.block_ptr,
+            &local.block_memory,
+            local.is_first,
+        );
+        for (i, w) in local.w.iter().enumerate() {
+            let block = u64_from_words(
+                local.block_memory[2 * i].value(),
+                local.block_memory[2 * i + 1].value(),
+            );
+            builder.when(local.is_first).assert_all_eq(*w, block);
+            builder.slice_range_check_u8(w, local.is_first);
+        }
+        for (var, initial) in local.vars.iter().zip(local.initial.iter()) {
+            builder.when(local.is_first).assert_all_eq(*var, *initial);
+            builder.slice_range_check_u8(initial, local.is_first);
+        }
+
+        // Extend the message schedule.
+        let w = &local.w;
+        FixedShift64Operation::<AB::F>::eval(builder, w[1], 1, true, local.w1_rr_1, local.is_real);
+        FixedShift64Operation::<AB::F>::eval(builder, w[1], 8, true, local.w1_rr_8, local.is_real);
+        FixedShift64Operation::<AB::F>::eval(
+            builder,
+            w[1],
+            7,
+            false,
+            local.w1_shr_7,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.w1_rr_1.value,
+            local.w1_rr_8.value,
+            local.sigma0_intermediate,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.sigma0_intermediate.value,
+            local.w1_shr_7.value,
+            local.sigma0,
+            local.is_real,
+        );
+        FixedShift64Operation::<AB::F>::eval(
+            builder,
+            w[14],
+            19,
+            true,
+            local.w14_rr_19,
+            local.is_real,
+        );
+        FixedShift64Operation::<AB::F>::eval(
+            builder,
+            w[14],
+            61,
+            true,
+            local.w14_rr_61,
+            local.is_real,
+        );
+        FixedShift64Operation::<AB::F>::eval(
+            builder,
+            w[14],
+            6,
+            false,
+            local.w14_shr_6,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.w14_rr_19.value,
+            local.w14_rr_61.value,
+            local.sigma1_intermediate,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.sigma1_intermediate.value,
+            local.w14_shr_6.value,
+            local.sigma1,
+            local.is_real,
+        );
+        Add64Operation::<AB::F>::eval(
+            builder,
+            &[w[0], local.sigma0.value, w[9], local.sigma1.value],
+            local.w_next,
+            local.is_real,
+        );
+
+        // Compute `S1` and `ch`.
+        let [a, b, c, d, e, f, g, h] = local.vars;
+        FixedShift64Operation::<AB::F>::eval(builder, e, 14, true, local.e_rr_14, local.is_real);
+        FixedShift64Operation::<AB::F>::eval(builder, e, 18, true, local.e_rr_18, local.is_real);
+        FixedShift64Operation::<AB::F>::eval(builder, e, 41, true, local.e_rr_41, local.is_real);
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.e_rr_14.value,
+            local.e_rr_18.value,
+            local.s1_intermediate,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.s1_intermediate.value,
+            local.e_rr_41.value,
+            local.s1,
+            local.is_real,
+        );
+        And64Operation::<AB::F>::eval(builder, e, f, local.e_and_f, local.is_real);
+        Not64Operation::<AB::F>::eval(builder, e, local.e_not, local.is_real);
+        And64Operation::<AB::F>::eval(
+            builder,
+            local.e_not.value,
+            g,
+            local.e_not_and_g,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.e_and_f.value,
+            local.e_not_and_g.value,
+            local.ch,
+            local.is_real,
+        );
+
+        // Compute `temp1`.
+        Add64Operation::<AB::F>::eval(
+            builder,
+            &[h, local.s1.value, local.ch.value, local.k, w[0]],
+            local.temp1,
+            local.is_real,
+        );
+
+        // Compute `S0` and `maj`.
+        FixedShift64Operation::<AB::F>::eval(builder, a, 28, true, local.a_rr_28, local.is_real);
+        FixedShift64Operation::<AB::F>::eval(builder, a, 34, true, local.a_rr_34, local.is_real);
+        FixedShift64Operation::<AB::F>::eval(builder, a, 39, true, local.a_rr_39, local.is_real);
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.a_rr_28.value,
+            local.a_rr_34.value,
+            local.s0_intermediate,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.s0_intermediate.value,
+            local.a_rr_39.value,
+            local.s0,
+            local.is_real,
+        );
+        And64Operation::<AB::F>::eval(builder, a, b, local.a_and_b, local.is_real);
+        And64Operation::<AB::F>::eval(builder, a, c, local.a_and_c, local.is_real);
+        And64Operation::<AB::F>::eval(builder, b, c, local.b_and_c, local.is_real);
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.a_and_b.value,
+            local.a_and_c.value,
+            local.maj_intermediate,
+            local.is_real,
+        );
+        Xor64Operation::<AB::F>::eval(
+            builder,
+            local.maj_intermediate.value,
+            local.b_and_c.value,
+            local.maj,
+            local.is_real,
+        );
+
+        // Compute `temp2` and the next values of `a` and `e`.
+        Add64Operation::<AB::F>::eval(
+            builder,
+            &[local.s0.value, local.maj.value],
+            local.temp2,
+            local.is_real,
+        );
+        Add64Operation::<AB::F>::eval(
+            builder,
+            &[d, local.temp1.value],
+            local.d_add_temp1,
+            local.is_real,
+        );
+        Add64Operation::<AB::F>::eval(
+            builder,
+            &[local.temp1.value, local.temp2.value],
+            local.temp1_add_temp2,
+            local.is_real,
+        );
+        let vars = [local.temp1_add_temp2.value, a, b, c, local.d_add_temp1.value, e, f, g];
+
+        // The rows of an event share the arguments, go through the rounds, and carry the working
+        // variables and the message schedule window forward.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - local.is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.state_ptr, next.state_ptr);
+        continuation.assert_eq(local.block_ptr, next.block_ptr);
+        continuation.assert_eq(local.round[LAST_ROUND], next.round[0]);
+        for r in 0..LAST_ROUND {
+            continuation.assert_eq(local.round[r], next.round[r + 1]);
+        }
+        for (initial, next_initial) in local.initial.iter().zip(next.initial.iter()) {
+            continuation.assert_all_eq(*initial, *next_initial);
+        }
+        for (var, next_var) in vars.iter().zip(next.vars.iter()) {
+            continuation.assert_all_eq(*var, *next_var);
+        }
+        for i in 0..15 {
+            continuation.assert_all_eq(local.w[i + 1], next.w[i]);
+        }
+        continuation.assert_all_eq(local.w_next.value, next.w[15]);
+
+        // The last row adds the working variables to the initial state, and writes the result
+        // over it.
+        for (i, finalize) in local.finalize.iter().enumerate() {
+            Add64Operation::<AB::F>::eval(
+                builder,
+                &[local.initial[i], vars[i]],
+                *finalize,
+                local.is_last,
+            );
+            let (low, high) = (&local.state_memory[2 * i], &local.state_memory[2 * i + 1]);
+            builder
+                .when(local.is_last)
+                .assert_all_eq(finalize.value, u64_from_words(low.value(), high.value()));
+            builder.when(local.is_last).assert_all_eq(
+                local.initial[i],
+                u64_from_words(low.prev_value(), high.prev_value()),
+            );
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.state_ptr,
+            &local.state_memory,
+            local.is_last,
+        );
+
+        // Receive the arguments on the first row of the event.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::SHA512_COMPRESS.syscall_id()),
+            local.state_ptr,
+            local.block_ptr,
+            local.is_first,
+            InteractionScope::Local,
+        );
+    }
+}
@@ -0,0 +1,22 @@
+mod compress;
+
+pub use compress::*;
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::SHA512_COMPRESS_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_sha512_compress() {
+        utils::setup_logger();
+        let program = Program::from(SHA512_COMPRESS_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
@@ -0,0 +1,378 @@
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+    utils::{limbs_from_access, pad_rows_fixed, words_to_bytes_le},
+};
+
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program, Register,
+};
+use sp1_curves::{
+    params::{NumLimbs, NumWords},
+    uint256::U256Field,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder},
+    MachineRecord,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the U256x4096MulCols.
+const NUM_COLS: usize = size_of::<U256x4096MulCols<u8>>();
+
+#[derive(Default)]
+pub struct U256x4096MulChip;
+
+impl U256x4096MulChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+const LO_REGISTER: u32 = Register::X12 as u32;
+const HI_REGISTER: u32 = Register::X13 as u32;
+
+/// The number of 256-bit chunks in the 4096-bit operand.
+const NUM_CHUNKS: usize = 16;
+
+/// A set of columns for the U256x4096Mul operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct U256x4096MulCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the first input.
+    pub a_ptr: T,
+
+    /// The pointer to the second input.
+    pub b_ptr: T,
+
+    pub lo_ptr: T,
+    pub hi_ptr: T,
+
+    pub lo_ptr_memory: MemoryReadCols<T>,
+    pub hi_ptr_memory: MemoryReadCols<T>,
+
+    // Memory columns.
+    pub a_memory: [MemoryReadCols<T>; WORDS_FIELD_ELEMENT],
+    pub b_memory: [MemoryReadCols<T>; WORDS_FIELD_ELEMENT * NUM_CHUNKS],
+    pub lo_memory: [MemoryWriteCols<T>; WORDS_FIELD_ELEMENT * NUM_CHUNKS],
+    pub hi_memory: [MemoryWriteCols<T>; WORDS_FIELD_ELEMENT],
+
+    // Output values. We compute (x * y) % 2^4096 and (x * y) / 2^4096, one 256-bit chunk of y at
+    // a time, carrying the high half of each partial product into the next chunk.
+    pub ab_plus_carry: [FieldOpCols<T, U256Field>; NUM_CHUNKS],
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for U256x4096MulChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "U256XU4096Mul".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // Implement trace generation logic.
+        let rows_and_records = input
+            .get_precompile_events(SyscallCode::U256XU4096_MUL)
+            .chunks(1)
+            .map(|events| {
+                let mut records = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .map(|(_, event)| {
+                        let event = if let PrecompileEvent::U256xU4096Mul(event) = event {
+                            event
+                        } else {
+                            unreachable!()
+                        };
+                        let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                        let cols: &mut U256x4096MulCols<F> = row.as_mut_slice().borrow_mut();
+
+                        // Assign basic values to the columns.
+                        cols.is_real = F::one();
+                        cols.shard = F::from_canonical_u32(event.shard);
+                        cols.clk = F::from_canonical_u32(event.clk);
+                        cols.a_ptr = F::from_canonical_u32(event.a_ptr);
+                        cols.b_ptr = F::from_canonical_u32(event.b_ptr);
+                        cols.lo_ptr = F::from_canonical_u32(event.lo_ptr);
+                        cols.hi_ptr = F::from_canonical_u32(event.hi_ptr);
+
+                        // Populate memory accesses for lo_ptr and hi_ptr.
+                        cols.lo_ptr_memory
+                            .populate(event.lo_ptr_memory, &mut new_byte_lookup_events);
+                        cols.hi_ptr_memory
+                            .populate(event.hi_ptr_memory, &mut new_byte_lookup_events);
+
+                        // Populate memory columns.
+                        for i in 0..WORDS_FIELD_ELEMENT {
+                            cols.a_memory[i]
+                                .populate(event.a_memory_records[i], &mut new_byte_lookup_events);
+                            cols.hi_memory[i]
+                                .populate(event.hi_memory_records[i], &mut new_byte_lookup_events);
+                        }
+
+                        for i in 0..WORDS_FIELD_ELEMENT * NUM_CHUNKS {
+                            cols.b_memory[i]
+                                .populate(event.b_memory_records[i], &mut new_byte_lookup_events);
+                            cols.lo_memory[i]
+                                .populate(event.lo_memory_records[i], &mut new_byte_lookup_events);
+                        }
+
+                        let a = BigUint::from_bytes_le(&words_to_bytes_le::<32>(&event.a));
+                        let b_chunks = event
+                            .b
+                            .chunks(WORDS_FIELD_ELEMENT)
+                            .map(|chunk| BigUint::from_bytes_le(&words_to_bytes_le::<32>(chunk)))
+                            .collect::<Vec<_>>();
+
+                        let effective_modulus = BigUint::one() << 256;
+
+                        let mut carry = BigUint::zero();
+                        for (col, b) in cols.ab_plus_carry.iter_mut().zip(b_chunks.iter()) {
+                            (_, carry) = col.populate_mul_and_carry(
+                                &mut new_byte_lookup_events,
+                                event.shard,
+                                &a,
+                                b,
+                                &carry,
+                                &effective_modulus,
+                            );
+                        }
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                records.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, records)
+            })
+            .collect::<Vec<_>>();
+
+        // Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for (row, mut record) in rows_and_records {
+            rows.extend(row);
+            output.append(&mut record);
+        }
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut U256x4096MulCols<F> = row.as_mut_slice().borrow_mut();
+
+                let zero = BigUint::zero();
+                let modulus = BigUint::one() << 256;
+
+                // Populate all the mul and carry columns with zero values.
+                for col in cols.ab_plus_carry.iter_mut() {
+                    col.populate_mul_and_carry(&mut vec![], 0, &zero, &zero, &zero, &modulus);
+                }
+
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut U256x4096MulCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::U256XU4096_MUL).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for U256x4096MulChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for U256x4096MulChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &U256x4096MulCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &U256x4096MulCols<AB::Var> = (*next).borrow();
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::U256XU4096_MUL.syscall_id()),
+            local.a_ptr,
+            local.b_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Evaluate that the lo_ptr and hi_ptr are read from the correct memory locations.
+        builder.eval_memory_access(
+            local.shard,
+            local.clk.into(),
+            AB::Expr::from_canonical_u32(LO_REGISTER),
+            &local.lo_ptr_memory,
+            local.is_real,
+        );
+
+        builder.eval_memory_access(
+            local.shard,
+            local.clk.into(),
+            AB::Expr::from_canonical_u32(HI_REGISTER),
+            &local.hi_ptr_memory,
+            local.is_real,
+        );
+
+        // Evaluate the memory accesses for a_memory and b_memory.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.a_ptr,
+            &local.a_memory,
+            local.is_real,
+        );
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.b_ptr,
+            &local.b_memory,
+            local.is_real,
+        );
+
+        // Evaluate the memory accesses for lo_memory and hi_memory.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.lo_ptr,
+            &local.lo_memory,
+            local.is_real,
+        );
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.hi_ptr,
+            &local.hi_memory,
+            local.is_real,
+        );
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let a_limbs =
+            limbs_from_access::<AB::Var, <U256Field as NumLimbs>::Limbs, _>(&local.a_memory);
+
+        // Iterate through chunks of b_memory and convert each chunk to its limbs.
+        let b_limb_array = local
+            .b_memory
+            .chunks(WORDS_FIELD_ELEMENT)
+            .map(limbs_from_access::<AB::Var, <U256Field as NumLimbs>::Limbs, _>)
+            .collect::<Vec<_>>();
+
+        let mut coeff_2_256 = Vec::new();
+        coeff_2_256.resize(32, AB::Expr::zero());
+        coeff_2_256.push(AB::Expr::one());
+        let modulus_polynomial: Polynomial<AB::Expr> = Polynomial::from_coefficients(&coeff_2_256);
+
+        // Evaluate that each of the mul and carry columns are valid.
+        let outputs = &local.ab_plus_carry;
+
+        outputs[0].eval_mul_and_carry(
+            builder,
+            &a_limbs,
+            &b_limb_array[0],
+            &Polynomial::from_coefficients(&[AB::Expr::zero()]), // Zero polynomial for no previous carry
+            &modulus_polynomial,
+            local.is_real,
+        );
+
+        for i in 1..NUM_CHUNKS {
+            outputs[i].eval_mul_and_carry(
+                builder,
+                &a_limbs,
+                &b_limb_array[i],
+                &outputs[i - 1].carry,
+                &modulus_polynomial,
+                local.is_real,
+            );
+        }
+
+        // Assert that the correct result is being written to hi_memory.
+        builder
+            .when(local.is_real)
+            .assert_all_eq(outputs[NUM_CHUNKS - 1].carry, value_as_limbs(&local.hi_memory));
+
+        // Loop through chunks of lo_memory and assert that each chunk is equal to the
+        // corresponding result of outputs.
+        for i in 0..NUM_CHUNKS {
+            builder.when(local.is_real).assert_all_eq(
+                outputs[i].result,
+                value_as_limbs(
+                    &local.lo_memory[i * WORDS_FIELD_ELEMENT..(i + 1) * WORDS_FIELD_ELEMENT],
+                ),
+            );
+        }
+
+        // Constrain that the lo_ptr is the value of lo_ptr_memory.
+        builder
+            .when(local.is_real)
+            .assert_eq(local.lo_ptr, local.lo_ptr_memory.value().reduce::<AB>());
+
+        // Constrain that the hi_ptr is the value of hi_ptr_memory.
+        builder
+            .when(local.is_real)
+            .assert_eq(local.hi_ptr, local.hi_ptr_memory.value().reduce::<AB>());
+    }
+}
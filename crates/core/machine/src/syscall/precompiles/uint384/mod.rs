@@ -0,0 +1,22 @@
+mod mul_add;
+
+pub use mul_add::*;
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::UINT384_MULADD_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_uint384_muladd() {
+        utils::setup_logger();
+        let program = Program::from(UINT384_MULADD_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
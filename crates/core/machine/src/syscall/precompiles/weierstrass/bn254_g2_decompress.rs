@@ -0,0 +1,672 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs},
+    weierstrass::bn254::{
+        bn254_fp2_sqrt, bn254_g2_generator, bn254_twist_b, Bn254BaseField, Bn254Fp2,
+    },
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder};
+use typenum::Unsigned;
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{
+            field_inner_product::FieldInnerProductCols, field_op::FieldOpCols, range::FieldLtCols,
+        },
+        IsZeroOperation,
+    },
+    utils::{
+        bytes_to_words_le_vec, limbs_from_access, limbs_from_prev_access, pad_rows_fixed,
+        zeroed_f_vec,
+    },
+};
+
+/// The number of words of an element of the quadratic extension of the BN254 base field.
+const NUM_WORDS_FP2: usize = 2 * <Bn254BaseField as NumLimbs>::Limbs::USIZE / 4;
+
+/// The number of columns in the Bn254G2DecompressCols.
+const NUM_COLS: usize = size_of::<Bn254G2DecompressCols<u8>>();
+
+type FieldLimbs<T> = Limbs<T, <Bn254BaseField as NumLimbs>::Limbs>;
+
+/// A chip that decompresses a BN254 G2 point, given its x coordinate `x0 + x1 * u` in
+/// `Fq2 = Fq[u] / (u^2 + 1)`, by computing a square root of `x^3 + b` on the twist.
+///
+/// When there is no such point, the chip instead proves that `(9 + u) * (x^3 + b)` is a nonzero
+/// square, which is enough since `9 + u` is not a square. The sign bit selects the root that is
+/// lexicographically largest, in the convention of gnark.
+#[derive(Default)]
+pub struct Bn254G2DecompressChip;
+
+impl Bn254G2DecompressChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Bn254G2Decompress operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Bn254G2DecompressCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub clk: T,
+    pub nonce: T,
+    pub ptr: T,
+    pub sign_bit: T,
+    pub is_invalid: T,
+    pub x_access: [MemoryReadCols<T>; NUM_WORDS_FP2],
+    pub y_access: [MemoryWriteCols<T>; NUM_WORDS_FP2],
+    pub(crate) range_x_c0: FieldLtCols<T, Bn254BaseField>,
+    pub(crate) range_x_c1: FieldLtCols<T, Bn254BaseField>,
+
+    /// `x^2 = (x0^2 - x1^2) + 2 * x0 * x1 * u`.
+    pub(crate) x0_sq: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) x1_sq: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) x_2_c0: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) x_2_c1: FieldInnerProductCols<T, Bn254BaseField>,
+
+    /// `x^3 = x^2 * x`.
+    pub(crate) x_3_a0_b0: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) x_3_a1_b1: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) x_3_c0: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) x_3_c1: FieldInnerProductCols<T, Bn254BaseField>,
+
+    /// `x^3 + b`.
+    pub(crate) rhs_c0: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) rhs_c1: FieldOpCols<T, Bn254BaseField>,
+
+    /// The norm of `x^3 + b` and its inverse, which proves that `x^3 + b` is not zero when the
+    /// point is invalid.
+    pub(crate) rhs_norm: FieldInnerProductCols<T, Bn254BaseField>,
+    pub(crate) rhs_norm_inverse: FieldOpCols<T, Bn254BaseField>,
+
+    /// `x^3 + b`, multiplied by `9 + u` if the point is invalid.
+    pub(crate) sqrt_input_c0: FieldInnerProductCols<T, Bn254BaseField>,
+    pub(crate) sqrt_input_c1: FieldInnerProductCols<T, Bn254BaseField>,
+
+    /// The square root of the input, and its square.
+    pub(crate) y_c0: FieldLimbs<T>,
+    pub(crate) y_c1: FieldLimbs<T>,
+    pub(crate) y0_sq: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) y1_sq: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) y_2_c0: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) y_2_c1: FieldInnerProductCols<T, Bn254BaseField>,
+    pub(crate) range_y_c0: FieldLtCols<T, Bn254BaseField>,
+    pub(crate) range_y_c1: FieldLtCols<T, Bn254BaseField>,
+    pub(crate) neg_y_c0: FieldOpCols<T, Bn254BaseField>,
+    pub(crate) neg_y_c1: FieldOpCols<T, Bn254BaseField>,
+
+    /// Whether `y1` is zero, in which case the sign is given by `y0` instead of `y1`.
+    pub(crate) y_c1_is_zero: IsZeroOperation<T>,
+
+    /// The components of `y` and `-y` that are compared.
+    pub(crate) compared_y: FieldLimbs<T>,
+    pub(crate) compared_neg_y: FieldLimbs<T>,
+    pub(crate) compared_neg_y_range_check: FieldLtCols<T, Bn254BaseField>,
+    pub(crate) when_y_is_lt: T,
+    pub(crate) when_neg_y_is_lt: T,
+    pub(crate) comparison_lt_cols: FieldLtCols<T, Bn254BaseField>,
+}
+
+/// The coefficients by which `c0` and `c1` of `x^3 + b` are multiplied to get each component of
+/// the square root input, which are those of `1` or of `9 + u`.
+fn sqrt_input_multipliers(is_invalid: bool) -> [[BigUint; 2]; 2] {
+    let modulus = Bn254BaseField::modulus();
+    if is_invalid {
+        let nine = BigUint::from(9u32);
+        [[nine.clone(), modulus - BigUint::one()], [BigUint::one(), nine]]
+    } else {
+        [[BigUint::one(), BigUint::zero()], [BigUint::zero(), BigUint::one()]]
+    }
+}
+
+fn to_limbs<F: PrimeField32>(value: &BigUint) -> FieldLimbs<F> {
+    Bn254BaseField::to_limbs_field::<F, _>(value)
+}
+
+impl Bn254G2DecompressChip {
+    fn populate_field_ops<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        cols: &mut Bn254G2DecompressCols<F>,
+        x: &Bn254Fp2,
+        is_invalid: bool,
+    ) -> Bn254Fp2 {
+        let modulus = Bn254BaseField::modulus();
+        let [x0, x1] = x;
+
+        let x0_sq = cols.x0_sq.populate(record, shard, x0, x0, FieldOperation::Mul);
+        let x1_sq = cols.x1_sq.populate(record, shard, x1, x1, FieldOperation::Mul);
+        let x_2_c0 = cols.x_2_c0.populate(record, shard, &x0_sq, &x1_sq, FieldOperation::Sub);
+        let x_2_c1 = cols.x_2_c1.populate(
+            record,
+            shard,
+            &[x0.clone(), x1.clone()],
+            &[x1.clone(), x0.clone()],
+        );
+
+        let a0_b0 = cols.x_3_a0_b0.populate(record, shard, &x_2_c0, x0, FieldOperation::Mul);
+        let a1_b1 = cols.x_3_a1_b1.populate(record, shard, &x_2_c1, x1, FieldOperation::Mul);
+        let x_3_c0 = cols.x_3_c0.populate(record, shard, &a0_b0, &a1_b1, FieldOperation::Sub);
+        let x_3_c1 =
+            cols.x_3_c1.populate(record, shard, &[x_2_c0, x_2_c1], &[x1.clone(), x0.clone()]);
+
+        let [b0, b1] = bn254_twist_b();
+        let rhs_c0 = cols.rhs_c0.populate(record, shard, &x_3_c0, &b0, FieldOperation::Add);
+        let rhs_c1 = cols.rhs_c1.populate(record, shard, &x_3_c1, &b1, FieldOperation::Add);
+        let rhs = [rhs_c0, rhs_c1];
+
+        let rhs_norm = cols.rhs_norm.populate(record, shard, &rhs, &rhs);
+        cols.rhs_norm_inverse.populate(
+            record,
+            shard,
+            &BigUint::from(u32::from(is_invalid)),
+            &rhs_norm,
+            FieldOperation::Div,
+        );
+
+        let [m0, m1] = sqrt_input_multipliers(is_invalid);
+        let sqrt_input = [
+            cols.sqrt_input_c0.populate(record, shard, &rhs, &m0),
+            cols.sqrt_input_c1.populate(record, shard, &rhs, &m1),
+        ];
+
+        let y = bn254_fp2_sqrt(&sqrt_input).expect("the square root input must be a square");
+        let [y0, y1] = &y;
+        cols.y_c0 = to_limbs(y0);
+        cols.y_c1 = to_limbs(y1);
+        record.add_u8_range_checks(shard, &Bn254BaseField::to_limbs(y0));
+        record.add_u8_range_checks(shard, &Bn254BaseField::to_limbs(y1));
+
+        let y0_sq = cols.y0_sq.populate(record, shard, y0, y0, FieldOperation::Mul);
+        let y1_sq = cols.y1_sq.populate(record, shard, y1, y1, FieldOperation::Mul);
+        cols.y_2_c0.populate(record, shard, &y0_sq, &y1_sq, FieldOperation::Sub);
+        cols.y_2_c1.populate(record, shard, &[y0.clone(), y1.clone()], &[y1.clone(), y0.clone()]);
+
+        let zero = BigUint::zero();
+        let neg_y0 = cols.neg_y_c0.populate(record, shard, &zero, y0, FieldOperation::Sub);
+        let neg_y1 = cols.neg_y_c1.populate(record, shard, &zero, y1, FieldOperation::Sub);
+
+        let y_c1_sum = Bn254BaseField::to_limbs(y1).iter().map(|&b| u32::from(b)).sum::<u32>();
+        cols.y_c1_is_zero.populate(y_c1_sum);
+        let (compared_y, compared_neg_y) =
+            if y1.is_zero() { (y0.clone(), neg_y0) } else { (y1.clone(), neg_y1) };
+        cols.compared_y = to_limbs(&compared_y);
+        cols.compared_neg_y = to_limbs(&compared_neg_y);
+
+        if !is_invalid {
+            cols.range_y_c0.populate(record, shard, y0, &modulus);
+            cols.range_y_c1.populate(record, shard, y1, &modulus);
+            cols.compared_neg_y_range_check.populate(record, shard, &compared_neg_y, &modulus);
+        }
+
+        y
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for Bn254G2DecompressChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Bn254G2Decompress".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let modulus = Bn254BaseField::modulus();
+        let num_limbs = <Bn254BaseField as NumLimbs>::Limbs::USIZE;
+
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::BN254_G2_DECOMPRESS) {
+            let event = if let PrecompileEvent::Bn254G2Decompress(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+
+            let mut row = zeroed_f_vec(NUM_COLS);
+            let cols: &mut Bn254G2DecompressCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.ptr = F::from_canonical_u32(event.ptr);
+            cols.sign_bit = F::from_bool(event.sign_bit);
+            cols.is_invalid = F::from_bool(event.is_invalid);
+
+            let x = [
+                BigUint::from_bytes_le(&event.x_bytes[..num_limbs]),
+                BigUint::from_bytes_le(&event.x_bytes[num_limbs..]),
+            ];
+            cols.range_x_c0.populate(&mut new_byte_lookup_events, event.shard, &x[0], &modulus);
+            cols.range_x_c1.populate(&mut new_byte_lookup_events, event.shard, &x[1], &modulus);
+            let y = Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                cols,
+                &x,
+                event.is_invalid,
+            );
+
+            if !event.is_invalid {
+                let [y0, y1] = &y;
+                debug_assert_eq!(
+                    BigUint::from_bytes_le(&event.decompressed_y_bytes[..num_limbs]),
+                    *y0
+                );
+                debug_assert_eq!(
+                    BigUint::from_bytes_le(&event.decompressed_y_bytes[num_limbs..]),
+                    *y1
+                );
+
+                // The sign is that of `y1`, or of `y0` if `y1` is zero.
+                let compared_y = if y1.is_zero() { y0 } else { y1 };
+                let compared_neg_y = (&modulus - compared_y) % &modulus;
+                if event.sign_bit {
+                    cols.when_neg_y_is_lt = F::one();
+                    cols.comparison_lt_cols.populate(
+                        &mut new_byte_lookup_events,
+                        event.shard,
+                        &compared_neg_y,
+                        compared_y,
+                    );
+                } else {
+                    cols.when_y_is_lt = F::one();
+                    cols.comparison_lt_cols.populate(
+                        &mut new_byte_lookup_events,
+                        event.shard,
+                        compared_y,
+                        &compared_neg_y,
+                    );
+                }
+            }
+
+            for i in 0..cols.x_access.len() {
+                cols.x_access[i].populate(event.x_memory_records[i], &mut new_byte_lookup_events);
+            }
+            for i in 0..cols.y_access.len() {
+                cols.y_access[i].populate(event.y_memory_records[i], &mut new_byte_lookup_events);
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(NUM_COLS);
+                let cols: &mut Bn254G2DecompressCols<F> = row.as_mut_slice().borrow_mut();
+
+                // take X of the generator as a dummy value to make sure Y^2 = X^3 + b holds
+                let (dummy_x, _) = bn254_g2_generator();
+                let mut dummy_bytes = Vec::with_capacity(2 * num_limbs);
+                for c in &dummy_x {
+                    let mut bytes = c.to_bytes_le();
+                    bytes.resize(num_limbs, 0u8);
+                    dummy_bytes.extend(bytes);
+                }
+                let words = bytes_to_words_le_vec(&dummy_bytes);
+                for i in 0..cols.x_access.len() {
+                    cols.x_access[i].access.value = words[i].into();
+                }
+
+                Self::populate_field_ops(&mut vec![], 0, cols, &dummy_x, false);
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Bn254G2DecompressCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::BN254_G2_DECOMPRESS).is_empty()
+        }
+    }
+}
+
+impl<F> BaseAir<F> for Bn254G2DecompressChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Bn254G2DecompressChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Bn254G2DecompressCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Bn254G2DecompressCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let num_words_field_element = NUM_WORDS_FP2 / 2;
+
+        builder.assert_bool(local.sign_bit);
+        builder.assert_bool(local.is_invalid);
+        let is_valid: AB::Expr = local.is_real.into() - local.is_invalid.into();
+
+        let modulus_limbs: FieldLimbs<AB::Expr> =
+            Bn254BaseField::to_limbs_field::<AB::Expr, AB::F>(&Bn254BaseField::modulus());
+        let constant = |value: &BigUint| -> Polynomial<AB::Expr> {
+            Bn254BaseField::to_limbs_field::<AB::F, _>(value).into()
+        };
+
+        let x0: FieldLimbs<AB::Var> =
+            limbs_from_prev_access(&local.x_access[0..num_words_field_element]);
+        let x1: FieldLimbs<AB::Var> =
+            limbs_from_prev_access(&local.x_access[num_words_field_element..]);
+        local.range_x_c0.eval(builder, &x0, &modulus_limbs, local.is_real);
+        local.range_x_c1.eval(builder, &x1, &modulus_limbs, local.is_real);
+
+        // x^2 = (x0^2 - x1^2) + 2 * x0 * x1 * u.
+        local.x0_sq.eval(builder, &x0, &x0, FieldOperation::Mul, local.is_real);
+        local.x1_sq.eval(builder, &x1, &x1, FieldOperation::Mul, local.is_real);
+        local.x_2_c0.eval(
+            builder,
+            &local.x0_sq.result,
+            &local.x1_sq.result,
+            FieldOperation::Sub,
+            local.is_real,
+        );
+        local.x_2_c1.eval(builder, &[x0, x1], &[x1, x0], local.is_real);
+
+        // x^3 = x^2 * x.
+        let (x_2_c0, x_2_c1) = (local.x_2_c0.result, local.x_2_c1.result);
+        local.x_3_a0_b0.eval(builder, &x_2_c0, &x0, FieldOperation::Mul, local.is_real);
+        local.x_3_a1_b1.eval(builder, &x_2_c1, &x1, FieldOperation::Mul, local.is_real);
+        local.x_3_c0.eval(
+            builder,
+            &local.x_3_a0_b0.result,
+            &local.x_3_a1_b1.result,
+            FieldOperation::Sub,
+            local.is_real,
+        );
+        local.x_3_c1.eval(builder, &[x_2_c0, x_2_c1], &[x1, x0], local.is_real);
+
+        // x^3 + b.
+        let [b0, b1] = bn254_twist_b();
+        local.rhs_c0.eval(
+            builder,
+            &local.x_3_c0.result,
+            &constant(&b0),
+            FieldOperation::Add,
+            local.is_real,
+        );
+        local.rhs_c1.eval(
+            builder,
+            &local.x_3_c1.result,
+            &constant(&b1),
+            FieldOperation::Add,
+            local.is_real,
+        );
+        let rhs = [local.rhs_c0.result, local.rhs_c1.result];
+
+        // If the point is invalid, `x^3 + b` is not zero, which is the case if and only if its
+        // norm is not zero since `-1` is not a square.
+        local.rhs_norm.eval(builder, &rhs, &rhs, local.is_real);
+        local.rhs_norm_inverse.eval(
+            builder,
+            &[local.is_invalid].iter(),
+            &local.rhs_norm.result,
+            FieldOperation::Div,
+            local.is_real,
+        );
+
+        // The input of the square root is `x^3 + b`, multiplied by the non-square `9 + u` if the
+        // point is invalid.
+        let [valid_multipliers, invalid_multipliers] =
+            [sqrt_input_multipliers(false), sqrt_input_multipliers(true)];
+        let multiplier = |i: usize, j: usize| -> Polynomial<AB::Expr> {
+            let valid = constant(&valid_multipliers[i][j]);
+            let invalid = constant(&invalid_multipliers[i][j]);
+            valid
+                .coefficients()
+                .iter()
+                .zip(invalid.coefficients())
+                .map(|(valid, invalid)| {
+                    valid.clone() * (AB::Expr::one() - local.is_invalid.into())
+                        + invalid.clone() * local.is_invalid
+                })
+                .collect()
+        };
+        local.sqrt_input_c0.eval(
+            builder,
+            &rhs,
+            &[multiplier(0, 0), multiplier(0, 1)],
+            local.is_real,
+        );
+        local.sqrt_input_c1.eval(
+            builder,
+            &rhs,
+            &[multiplier(1, 0), multiplier(1, 1)],
+            local.is_real,
+        );
+
+        // y^2 is the input of the square root.
+        let (y0, y1) = (local.y_c0, local.y_c1);
+        builder.slice_range_check_u8(&y0.0, local.is_real);
+        builder.slice_range_check_u8(&y1.0, local.is_real);
+        local.y0_sq.eval(builder, &y0, &y0, FieldOperation::Mul, local.is_real);
+        local.y1_sq.eval(builder, &y1, &y1, FieldOperation::Mul, local.is_real);
+        local.y_2_c0.eval(
+            builder,
+            &local.y0_sq.result,
+            &local.y1_sq.result,
+            FieldOperation::Sub,
+            local.is_real,
+        );
+        local.y_2_c1.eval(builder, &[y0, y1], &[y1, y0], local.is_real);
+        builder.when(local.is_real).assert_all_eq(local.y_2_c0.result, local.sqrt_input_c0.result);
+        builder.when(local.is_real).assert_all_eq(local.y_2_c1.result, local.sqrt_input_c1.result);
+
+        // If the point is valid, y is written to memory, otherwise it is left as it was.
+        let y0_access: FieldLimbs<AB::Var> =
+            limbs_from_access(&local.y_access[0..num_words_field_element]);
+        let y1_access: FieldLimbs<AB::Var> =
+            limbs_from_access(&local.y_access[num_words_field_element..]);
+        builder.when(is_valid.clone()).assert_all_eq(y0, y0_access);
+        builder.when(is_valid.clone()).assert_all_eq(y1, y1_access);
+        let prev_y0: FieldLimbs<AB::Var> =
+            limbs_from_prev_access(&local.y_access[0..num_words_field_element]);
+        let prev_y1: FieldLimbs<AB::Var> =
+            limbs_from_prev_access(&local.y_access[num_words_field_element..]);
+        builder.when(local.is_invalid).assert_all_eq(prev_y0, y0_access);
+        builder.when(local.is_invalid).assert_all_eq(prev_y1, y1_access);
+
+        // Constrain the y value according to the lexicographic sign rule, which compares `y1`
+        // with `-y1`, or `y0` with `-y0` if `y1` is zero. The components of `y` are canonical, and
+        // so are those of `-y`.
+        local.range_y_c0.eval(builder, &y0, &modulus_limbs, is_valid.clone());
+        local.range_y_c1.eval(builder, &y1, &modulus_limbs, is_valid.clone());
+        let zero: Polynomial<AB::Expr> = constant(&BigUint::zero());
+        local.neg_y_c0.eval(builder, &zero, &y0, FieldOperation::Sub, local.is_real);
+        local.neg_y_c1.eval(builder, &zero, &y1, FieldOperation::Sub, local.is_real);
+
+        let y1_sum = y1.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+        IsZeroOperation::<AB::F>::eval(builder, y1_sum, local.y_c1_is_zero, local.is_real.into());
+        let y1_is_zero = local.y_c1_is_zero.result;
+        for i in 0..<Bn254BaseField as NumLimbs>::Limbs::USIZE {
+            builder
+                .when(is_valid.clone())
+                .assert_eq(local.compared_y[i], y1[i] + y1_is_zero * (y0[i] - y1[i]));
+            builder.when(is_valid.clone()).assert_eq(
+                local.compared_neg_y[i],
+                local.neg_y_c1.result[i]
+                    + y1_is_zero * (local.neg_y_c0.result[i] - local.neg_y_c1.result[i]),
+            );
+        }
+        local.compared_neg_y_range_check.eval(
+            builder,
+            &local.compared_neg_y,
+            &modulus_limbs,
+            is_valid.clone(),
+        );
+
+        // When the sign bit is set, `-y < y`, and `y < -y` otherwise.
+        builder.assert_eq(local.when_neg_y_is_lt, is_valid.clone() * local.sign_bit);
+        builder
+            .assert_eq(local.when_y_is_lt, is_valid.clone() * (AB::Expr::one() - local.sign_bit));
+        local.comparison_lt_cols.eval(
+            builder,
+            &local.compared_y,
+            &local.compared_neg_y,
+            local.when_y_is_lt,
+        );
+        local.comparison_lt_cols.eval(
+            builder,
+            &local.compared_neg_y,
+            &local.compared_y,
+            local.when_neg_y_is_lt,
+        );
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.ptr.into() + AB::F::from_canonical_u32((NUM_WORDS_FP2 * 4) as u32),
+            &local.x_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.ptr,
+            &local.y_access,
+            local.is_real,
+        );
+
+        let syscall_id = AB::F::from_canonical_u32(SyscallCode::BN254_G2_DECOMPRESS.syscall_id());
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            syscall_id + local.is_invalid.into() * AB::F::from_canonical_u32(1 << 8),
+            local.ptr,
+            local.sign_bit,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{bigint::RandBigInt, BigUint};
+    use rand::thread_rng;
+    use sp1_core_executor::Program;
+    use sp1_curves::{
+        params::FieldParameters,
+        utils::is_square,
+        weierstrass::bn254::{
+            bn254_decompress, bn254_g2_decompress, bn254_g2_generator, Bn254, Bn254BaseField,
+        },
+        AffinePoint,
+    };
+    use sp1_stark::CpuProver;
+    use test_artifacts::BN254_DECOMPRESS_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    fn to_bytes_be(value: &BigUint) -> [u8; 32] {
+        let mut bytes = value.to_bytes_le();
+        bytes.resize(32, 0);
+        bytes.reverse();
+        bytes.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_bn254_decompress() {
+        utils::setup_logger();
+        let mut rng = thread_rng();
+        let modulus = Bn254BaseField::modulus();
+
+        // The generator of G2, then random x coordinates, about half of which are not on the
+        // curve.
+        let mut g2_x = bn254_g2_generator().0;
+        for _ in 0..4 {
+            let g1_x = rng.gen_biguint_below(&modulus);
+            let g1_sign_bit = rand::random::<bool>();
+            let g2_sign_bit = rand::random::<bool>();
+
+            let mut stdin = SP1Stdin::new();
+            stdin.write_vec(to_bytes_be(&g1_x).to_vec());
+            stdin.write(&g1_sign_bit);
+            stdin.write_vec([to_bytes_be(&g2_x[1]), to_bytes_be(&g2_x[0])].concat());
+            stdin.write(&g2_sign_bit);
+
+            let mut public_values =
+                run_test_io::<CpuProver<_, _>>(Program::from(BN254_DECOMPRESS_ELF).unwrap(), stdin)
+                    .unwrap();
+
+            let y_squared = (&g1_x * &g1_x * &g1_x + BigUint::from(3u32)) % &modulus;
+            let g1_ok = public_values.read::<bool>();
+            let mut g1_point = [0u8; 64];
+            public_values.read_slice(&mut g1_point);
+            assert_eq!(g1_ok, is_square(&y_squared, &modulus));
+            if g1_ok {
+                let point: AffinePoint<Bn254> =
+                    bn254_decompress(&g1_point[..32], u32::from(g1_sign_bit));
+                assert_eq!(g1_point[32..], to_bytes_be(&point.y));
+            }
+
+            let g2_ok = public_values.read::<bool>();
+            let mut g2_point = [0u8; 128];
+            public_values.read_slice(&mut g2_point);
+            match bn254_g2_decompress(&g2_x, u32::from(g2_sign_bit)) {
+                Some(y) => {
+                    assert!(g2_ok);
+                    assert_eq!(g2_point[64..], [to_bytes_be(&y[1]), to_bytes_be(&y[0])].concat());
+                }
+                None => assert!(!g2_ok),
+            }
+
+            g2_x = [rng.gen_biguint_below(&modulus), rng.gen_biguint_below(&modulus)];
+        }
+    }
+}
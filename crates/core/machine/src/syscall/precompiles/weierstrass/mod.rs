@@ -1,7 +1,11 @@
+mod bn254_g2_decompress;
+mod secp256k1_ecrecover;
 mod weierstrass_add;
 mod weierstrass_decompress;
 mod weierstrass_double;
 
+pub use bn254_g2_decompress::*;
+pub use secp256k1_ecrecover::*;
 pub use weierstrass_add::*;
 pub use weierstrass_decompress::*;
 pub use weierstrass_double::*;
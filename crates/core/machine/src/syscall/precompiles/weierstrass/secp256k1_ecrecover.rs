@@ -0,0 +1,1248 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{field_op::FieldOpCols, field_sqrt::FieldSqrtCols, range::FieldLtCols},
+        IsZeroOperation,
+    },
+    utils::{pad_rows_fixed, words_to_bytes_le_vec, zeroed_f_vec},
+};
+
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{
+        ecrecover_offset, ecrecover_offset_correction, keccak_merkle_state,
+        secp256k1_ecrecover_witness, ByteLookupEvent, ByteRecord, EcRecoverPoint, EcRecoverWitness,
+        FieldOperation, PrecompileEvent, Secp256k1EcRecoverEvent, ECRECOVER_INPUT_WORDS,
+        ECRECOVER_OUTPUT_OFFSET, ECRECOVER_OUTPUT_WORDS, ECRECOVER_PERMUTE_OFFSET, ECRECOVER_ROWS,
+        ECRECOVER_STATE_OFFSET,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{limbs_from_vec, FieldParameters, Limbs, NumLimbs},
+    weierstrass::{
+        secp256k1::{Secp256k1BaseField, Secp256k1Parameters, Secp256k1ScalarField},
+        WeierstrassParameters,
+    },
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the Secp256k1EcRecoverCols.
+const NUM_COLS: usize = size_of::<Secp256k1EcRecoverCols<u8>>();
+
+/// The index of the last row of an event.
+const LAST_ROW: usize = ECRECOVER_ROWS - 1;
+
+/// The number of limbs of the field elements and of the scalars.
+const NUM_LIMBS: usize = 32;
+
+/// The number of words in a keccak state.
+const STATE_NUM_WORDS: usize = 50;
+
+/// The number of words of a coordinate of the public key.
+const COORDINATE_NUM_WORDS: usize = 8;
+
+type BaseField = Secp256k1BaseField;
+type ScalarField = Secp256k1ScalarField;
+type FieldLimbs<T> = Limbs<T, <BaseField as NumLimbs>::Limbs>;
+
+/// A chip recovering a secp256k1 public key from a hash and a signature, and hashing it into an
+/// address, with the semantics of the EVM `ecrecover` precompile.
+///
+/// The syscall takes a pointer to the 32-byte output, and a pointer to the 128-byte input, which
+/// is followed by a keccak state used to hash the public key.
+///
+/// Every event takes [`ECRECOVER_ROWS`] rows, one for every digit of the signed-digit expansions
+/// of the two scalars of [`EcRecoverWitness`]. The first row checks the signature, decompresses
+/// `R`, computes the scalars and their digits, and the points `G + R` and `G - R`. Every row then
+/// doubles the accumulator, which starts from [`ecrecover_offset`], and adds the point of its
+/// digits. The last row removes the offset to get the key, writes it to the state, sends a
+/// `KECCAK_PERMUTE` syscall on it, which is proven by the
+/// [`super::super::keccak256::KeccakPermuteChip`], and writes the address.
+///
+/// An event that does not recover the key only receives the syscall, with the failure status.
+#[derive(Default)]
+pub struct Secp256k1EcRecoverChip;
+
+impl Secp256k1EcRecoverChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Secp256k1EcRecover operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Secp256k1EcRecoverCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the output.
+    pub out_ptr: T,
+
+    /// The pointer to the input, which is followed by the keccak state.
+    pub input_ptr: T,
+
+    /// The row of the event, as a one-hot encoding.
+    pub step: [T; ECRECOVER_ROWS],
+
+    /// Whether the public key is recovered.
+    pub is_recovered: T,
+
+    /// Whether this is the first row of an event recovering the key.
+    pub is_setup: T,
+
+    /// Whether this is the last row of an event recovering the key.
+    pub is_output: T,
+
+    /// The nonce of the `KECCAK_PERMUTE` syscall hashing the public key.
+    pub permute_nonce: T,
+
+    // Memory columns.
+    // The input is read on the first row. On the last row, the public key is written to the state,
+    // and after the permutation, the hash is read and the address is written.
+    pub input_memory: [MemoryReadCols<T>; ECRECOVER_INPUT_WORDS],
+    pub state_memory: [MemoryWriteCols<T>; STATE_NUM_WORDS],
+    pub hash_memory: [MemoryReadCols<T>; ECRECOVER_OUTPUT_WORDS],
+    pub out_memory: [MemoryWriteCols<T>; ECRECOVER_OUTPUT_WORDS],
+
+    /// The operations of the first row.
+    pub setup: EcRecoverSetupCols<T>,
+
+    /// The points added by the ladder, shared by the rows of the event.
+    pub table: EcRecoverTableCols<T>,
+
+    /// The digits of the two scalars.
+    pub scalars: [EcRecoverScalarCols<T>; 2],
+
+    /// The double-and-add step of the row.
+    pub ladder: EcRecoverStepCols<T>,
+
+    /// The addition of [`ecrecover_offset_correction`] to the accumulator on the last row.
+    pub correction: EcRecoverAddCols<T>,
+
+    /// Checks that the public key is reduced on the last row.
+    pub x_range_check: FieldLtCols<T, BaseField>,
+    pub y_range_check: FieldLtCols<T, BaseField>,
+
+    pub is_real: T,
+}
+
+/// The columns checking the signature, and computing the scalars and the points of the ladder.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcRecoverSetupCols<T> {
+    /// Whether the y-coordinate of `R` is odd.
+    pub recid: T,
+
+    /// Checks that `r` and `s` are in `[1, n)`.
+    pub r_range_check: FieldLtCols<T, ScalarField>,
+    pub s_range_check: FieldLtCols<T, ScalarField>,
+    pub r_is_zero: IsZeroOperation<T>,
+    pub s_is_zero: IsZeroOperation<T>,
+
+    /// The scalars `u1 = -z / r` and `u2 = s / r` modulo the group order.
+    pub z_reduced: FieldOpCols<T, ScalarField>,
+    pub neg_z: FieldOpCols<T, ScalarField>,
+    pub u1: FieldOpCols<T, ScalarField>,
+    pub u2: FieldOpCols<T, ScalarField>,
+
+    /// The decompression of `R`, whose y-coordinate is the square root of `r^3 + 7`.
+    pub x_2: FieldOpCols<T, BaseField>,
+    pub x_3: FieldOpCols<T, BaseField>,
+    pub x_3_plus_b: FieldOpCols<T, BaseField>,
+    pub y: FieldSqrtCols<T, BaseField>,
+
+    /// The differences and the sum of the x-coordinates of `G` and `R`, shared by both points.
+    pub dx: FieldOpCols<T, BaseField>,
+    pub dx_inverse: FieldOpCols<T, BaseField>,
+    pub sum_x: FieldOpCols<T, BaseField>,
+
+    /// The point `G + R`.
+    pub plus_dy: FieldOpCols<T, BaseField>,
+    pub plus_slope: FieldOpCols<T, BaseField>,
+    pub plus_slope_squared: FieldOpCols<T, BaseField>,
+    pub plus_x: FieldOpCols<T, BaseField>,
+    pub plus_x_diff: FieldOpCols<T, BaseField>,
+    pub plus_slope_times_x_diff: FieldOpCols<T, BaseField>,
+    pub plus_y: FieldOpCols<T, BaseField>,
+
+    /// The point `G - R`.
+    pub minus_dy: FieldOpCols<T, BaseField>,
+    pub minus_slope: FieldOpCols<T, BaseField>,
+    pub minus_slope_squared: FieldOpCols<T, BaseField>,
+    pub minus_x: FieldOpCols<T, BaseField>,
+    pub minus_x_diff: FieldOpCols<T, BaseField>,
+    pub minus_slope_times_x_diff: FieldOpCols<T, BaseField>,
+    pub minus_y: FieldOpCols<T, BaseField>,
+
+    /// The negations of the y-coordinates of both points.
+    pub neg_plus_y: FieldOpCols<T, BaseField>,
+    pub neg_minus_y: FieldOpCols<T, BaseField>,
+}
+
+/// The points `G + R` and `G - R`, and the negations of their y-coordinates.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcRecoverTableCols<T> {
+    pub plus_x: FieldLimbs<T>,
+    pub plus_y: FieldLimbs<T>,
+    pub neg_plus_y: FieldLimbs<T>,
+    pub minus_x: FieldLimbs<T>,
+    pub minus_y: FieldLimbs<T>,
+    pub neg_minus_y: FieldLimbs<T>,
+}
+
+/// The digits of a scalar `u`, which are the bits of `m` such that `k = 2 * m - (2^257 - 1)`,
+/// where `k` is `u` made odd by adding the group order if needed.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcRecoverScalarCols<T> {
+    /// Whether the group order is added to the scalar.
+    pub odd_shift: T,
+
+    /// The bytes of `m`, shifted left by the row and truncated to 257 bits, so that the last one
+    /// is the bit of the row.
+    pub digits: [T; NUM_LIMBS + 1],
+
+    /// The top bits of the bytes of `digits`, which are carried into the next byte by the shift.
+    pub shift_carries: [T; NUM_LIMBS],
+
+    /// The carries of the sum `u + odd_shift * n + 2^257 - 1` on the first row.
+    pub setup_carries: [T; NUM_LIMBS],
+}
+
+/// The columns computing the sum of two points with distinct x-coordinates.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcRecoverAddCols<T> {
+    pub dy: FieldOpCols<T, BaseField>,
+    pub dx: FieldOpCols<T, BaseField>,
+    pub dx_inverse: FieldOpCols<T, BaseField>,
+    pub slope: FieldOpCols<T, BaseField>,
+    pub slope_squared: FieldOpCols<T, BaseField>,
+    pub sum_x: FieldOpCols<T, BaseField>,
+    pub x: FieldOpCols<T, BaseField>,
+    pub x_diff: FieldOpCols<T, BaseField>,
+    pub slope_times_x_diff: FieldOpCols<T, BaseField>,
+    pub y: FieldOpCols<T, BaseField>,
+}
+
+/// The columns computing `2 * acc + t` as `(acc + t) + acc`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcRecoverStepCols<T> {
+    /// The accumulator.
+    pub acc_x: FieldLimbs<T>,
+    pub acc_y: FieldLimbs<T>,
+
+    /// Whether both digits of the row are 1.
+    pub both_digits: T,
+
+    /// The point added on the row, selected from the table by the digits.
+    pub point_x: FieldLimbs<T>,
+    pub point_y: FieldLimbs<T>,
+
+    /// The sum `acc + t`.
+    pub dy: FieldOpCols<T, BaseField>,
+    pub dx: FieldOpCols<T, BaseField>,
+    pub dx_inverse: FieldOpCols<T, BaseField>,
+    pub slope: FieldOpCols<T, BaseField>,
+    pub slope_squared: FieldOpCols<T, BaseField>,
+    pub sum_x: FieldOpCols<T, BaseField>,
+    pub x3: FieldOpCols<T, BaseField>,
+
+    /// The sum `(acc + t) + acc`, whose slope is `-slope - 2 * y / (x3 - x)`.
+    pub x3_diff: FieldOpCols<T, BaseField>,
+    pub x3_diff_inverse: FieldOpCols<T, BaseField>,
+    pub two_y: FieldOpCols<T, BaseField>,
+    pub quotient: FieldOpCols<T, BaseField>,
+    pub neg_slope: FieldOpCols<T, BaseField>,
+    pub neg_slope_squared: FieldOpCols<T, BaseField>,
+    pub sum_x3: FieldOpCols<T, BaseField>,
+    pub x4: FieldOpCols<T, BaseField>,
+    pub x4_diff: FieldOpCols<T, BaseField>,
+    pub neg_slope_times_x4_diff: FieldOpCols<T, BaseField>,
+    pub y4: FieldOpCols<T, BaseField>,
+}
+
+impl Secp256k1EcRecoverChip {
+    /// Populates the operations of the first row from the witness, or on zero inputs for the
+    /// other rows, which the constraints of the operations hold for.
+    fn populate_setup<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        cols: &mut EcRecoverSetupCols<F>,
+        witness: Option<&EcRecoverWitness>,
+    ) {
+        let zero = BigUint::zero();
+        let order = ScalarField::modulus();
+        let (z, r, s, r_y) = match witness {
+            Some(witness) => {
+                (witness.z.clone(), witness.r.clone(), witness.s.clone(), witness.r_y.clone())
+            }
+            None => (zero.clone(), zero.clone(), zero.clone(), zero.clone()),
+        };
+        // The constants are gated by the first row in the constraints.
+        let (one, b, (gx, gy)) = match witness {
+            Some(_) => {
+                (BigUint::one(), Secp256k1Parameters::b_int(), Secp256k1Parameters::generator())
+            }
+            None => (zero.clone(), zero.clone(), (zero.clone(), zero.clone())),
+        };
+        cols.recid = F::from_bool(witness.is_some_and(|witness| witness.recid));
+
+        // Check the signature and compute the scalars.
+        if witness.is_some() {
+            cols.r_range_check.populate(record, shard, &r, &order);
+            cols.s_range_check.populate(record, shard, &s, &order);
+        }
+        cols.r_is_zero.populate(ScalarField::to_limbs(&r).iter().map(|&b| b as u32).sum());
+        cols.s_is_zero.populate(ScalarField::to_limbs(&s).iter().map(|&b| b as u32).sum());
+        let z = cols.z_reduced.populate(record, shard, &z, &zero, FieldOperation::Add);
+        let neg_z = cols.neg_z.populate(record, shard, &zero, &z, FieldOperation::Sub);
+        let u1 = cols.u1.populate(record, shard, &neg_z, &r, FieldOperation::Div);
+        let u2 = cols.u2.populate(record, shard, &s, &r, FieldOperation::Div);
+        if let Some(witness) = witness {
+            debug_assert_eq!([u1, u2], witness.scalars);
+        }
+
+        // Decompress `R`.
+        let x_2 = cols.x_2.populate(record, shard, &r, &r, FieldOperation::Mul);
+        let x_3 = cols.x_3.populate(record, shard, &x_2, &r, FieldOperation::Mul);
+        let x_3_plus_b = cols.x_3_plus_b.populate(record, shard, &x_3, &b, FieldOperation::Add);
+        let y = cols.y.populate(record, shard, &x_3_plus_b, |_| r_y.clone());
+
+        // Compute `G + R` and `G - R`.
+        let dx = cols.dx.populate(record, shard, &gx, &r, FieldOperation::Sub);
+        let dx_inverse = cols.dx_inverse.populate(record, shard, &one, &dx, FieldOperation::Div);
+        let sum_x = cols.sum_x.populate(record, shard, &gx, &r, FieldOperation::Add);
+
+        let plus_dy = cols.plus_dy.populate(record, shard, &gy, &y, FieldOperation::Sub);
+        let plus_slope =
+            cols.plus_slope.populate(record, shard, &plus_dy, &dx_inverse, FieldOperation::Mul);
+        let plus_slope_squared = cols.plus_slope_squared.populate(
+            record,
+            shard,
+            &plus_slope,
+            &plus_slope,
+            FieldOperation::Mul,
+        );
+        let plus_x =
+            cols.plus_x.populate(record, shard, &plus_slope_squared, &sum_x, FieldOperation::Sub);
+        let plus_x_diff =
+            cols.plus_x_diff.populate(record, shard, &gx, &plus_x, FieldOperation::Sub);
+        let plus_slope_times_x_diff = cols.plus_slope_times_x_diff.populate(
+            record,
+            shard,
+            &plus_slope,
+            &plus_x_diff,
+            FieldOperation::Mul,
+        );
+        let plus_y =
+            cols.plus_y.populate(record, shard, &plus_slope_times_x_diff, &gy, FieldOperation::Sub);
+
+        let minus_dy = cols.minus_dy.populate(record, shard, &gy, &y, FieldOperation::Add);
+        let minus_slope =
+            cols.minus_slope.populate(record, shard, &minus_dy, &dx_inverse, FieldOperation::Mul);
+        let minus_slope_squared = cols.minus_slope_squared.populate(
+            record,
+            shard,
+            &minus_slope,
+            &minus_slope,
+            FieldOperation::Mul,
+        );
+        let minus_x =
+            cols.minus_x.populate(record, shard, &minus_slope_squared, &sum_x, FieldOperation::Sub);
+        let minus_x_diff =
+            cols.minus_x_diff.populate(record, shard, &gx, &minus_x, FieldOperation::Sub);
+        let minus_slope_times_x_diff = cols.minus_slope_times_x_diff.populate(
+            record,
+            shard,
+            &minus_slope,
+            &minus_x_diff,
+            FieldOperation::Mul,
+        );
+        let minus_y = cols.minus_y.populate(
+            record,
+            shard,
+            &minus_slope_times_x_diff,
+            &gy,
+            FieldOperation::Sub,
+        );
+        if let Some(witness) = witness {
+            debug_assert_eq!([(plus_x, plus_y.clone()), (minus_x, minus_y.clone())], witness.table);
+        }
+
+        cols.neg_plus_y.populate(record, shard, &zero, &plus_y, FieldOperation::Sub);
+        cols.neg_minus_y.populate(record, shard, &zero, &minus_y, FieldOperation::Sub);
+    }
+
+    /// Populates the step of the row. The inverses are the ones of `is_real`, as in the
+    /// constraints, so that the rows without a step are populated on zero inputs.
+    fn populate_step<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        cols: &mut EcRecoverStepCols<F>,
+        acc: &EcRecoverPoint,
+        t: &EcRecoverPoint,
+        is_real: bool,
+    ) -> EcRecoverPoint {
+        let one = BigUint::from(u32::from(is_real));
+        cols.acc_x = BaseField::to_limbs_field::<F, _>(&acc.0);
+        cols.acc_y = BaseField::to_limbs_field::<F, _>(&acc.1);
+        cols.point_x = BaseField::to_limbs_field::<F, _>(&t.0);
+        cols.point_y = BaseField::to_limbs_field::<F, _>(&t.1);
+
+        let dy = cols.dy.populate(record, shard, &t.1, &acc.1, FieldOperation::Sub);
+        let dx = cols.dx.populate(record, shard, &t.0, &acc.0, FieldOperation::Sub);
+        let dx_inverse = cols.dx_inverse.populate(record, shard, &one, &dx, FieldOperation::Div);
+        let slope = cols.slope.populate(record, shard, &dy, &dx_inverse, FieldOperation::Mul);
+        let slope_squared =
+            cols.slope_squared.populate(record, shard, &slope, &slope, FieldOperation::Mul);
+        let sum_x = cols.sum_x.populate(record, shard, &acc.0, &t.0, FieldOperation::Add);
+        let x3 = cols.x3.populate(record, shard, &slope_squared, &sum_x, FieldOperation::Sub);
+
+        let x3_diff = cols.x3_diff.populate(record, shard, &x3, &acc.0, FieldOperation::Sub);
+        let x3_diff_inverse =
+            cols.x3_diff_inverse.populate(record, shard, &one, &x3_diff, FieldOperation::Div);
+        let two_y = cols.two_y.populate(record, shard, &acc.1, &acc.1, FieldOperation::Add);
+        let quotient =
+            cols.quotient.populate(record, shard, &two_y, &x3_diff_inverse, FieldOperation::Mul);
+        let neg_slope =
+            cols.neg_slope.populate(record, shard, &slope, &quotient, FieldOperation::Add);
+        let neg_slope_squared = cols.neg_slope_squared.populate(
+            record,
+            shard,
+            &neg_slope,
+            &neg_slope,
+            FieldOperation::Mul,
+        );
+        let sum_x3 = cols.sum_x3.populate(record, shard, &acc.0, &x3, FieldOperation::Add);
+        let x4 = cols.x4.populate(record, shard, &neg_slope_squared, &sum_x3, FieldOperation::Sub);
+        let x4_diff = cols.x4_diff.populate(record, shard, &x4, &acc.0, FieldOperation::Sub);
+        let neg_slope_times_x4_diff = cols.neg_slope_times_x4_diff.populate(
+            record,
+            shard,
+            &neg_slope,
+            &x4_diff,
+            FieldOperation::Mul,
+        );
+        let y4 =
+            cols.y4.populate(record, shard, &neg_slope_times_x4_diff, &acc.1, FieldOperation::Sub);
+
+        (x4, y4)
+    }
+
+    /// Populates the sum of two points, with the inverse of `is_real` as in
+    /// [`Self::populate_step`].
+    fn populate_add<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        shard: u32,
+        cols: &mut EcRecoverAddCols<F>,
+        a: &EcRecoverPoint,
+        b: &EcRecoverPoint,
+        is_real: bool,
+    ) -> EcRecoverPoint {
+        let one = BigUint::from(u32::from(is_real));
+        let dy = cols.dy.populate(record, shard, &b.1, &a.1, FieldOperation::Sub);
+        let dx = cols.dx.populate(record, shard, &b.0, &a.0, FieldOperation::Sub);
+        let dx_inverse = cols.dx_inverse.populate(record, shard, &one, &dx, FieldOperation::Div);
+        let slope = cols.slope.populate(record, shard, &dy, &dx_inverse, FieldOperation::Mul);
+        let slope_squared =
+            cols.slope_squared.populate(record, shard, &slope, &slope, FieldOperation::Mul);
+        let sum_x = cols.sum_x.populate(record, shard, &a.0, &b.0, FieldOperation::Add);
+        let x = cols.x.populate(record, shard, &slope_squared, &sum_x, FieldOperation::Sub);
+        let x_diff = cols.x_diff.populate(record, shard, &a.0, &x, FieldOperation::Sub);
+        let slope_times_x_diff =
+            cols.slope_times_x_diff.populate(record, shard, &slope, &x_diff, FieldOperation::Mul);
+        let y = cols.y.populate(record, shard, &slope_times_x_diff, &a.1, FieldOperation::Sub);
+        (x, y)
+    }
+
+    fn populate_row<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        event: &Secp256k1EcRecoverEvent,
+        witness: &EcRecoverWitness,
+        row: usize,
+        cols: &mut Secp256k1EcRecoverCols<F>,
+        acc: &EcRecoverPoint,
+    ) -> EcRecoverPoint {
+        let shard = event.shard;
+        let order_bytes = ScalarField::to_limbs(&ScalarField::modulus());
+        cols.is_setup = F::from_bool(row == 0);
+        cols.is_output = F::from_bool(row == LAST_ROW);
+
+        let modulus = BaseField::modulus();
+        let [(plus_x, plus_y), (minus_x, minus_y)] = &witness.table;
+        let table = [plus_x, plus_y, &((&modulus - plus_y) % &modulus)]
+            .map(|value| BaseField::to_limbs_field::<F, _>(value));
+        let [plus_x, plus_y, neg_plus_y] = table;
+        let table = [minus_x, minus_y, &((&modulus - minus_y) % &modulus)]
+            .map(|value| BaseField::to_limbs_field::<F, _>(value));
+        let [minus_x, minus_y, neg_minus_y] = table;
+        cols.table =
+            EcRecoverTableCols { plus_x, plus_y, neg_plus_y, minus_x, minus_y, neg_minus_y };
+
+        // The digits of the row are the top bits of the registers, shifted left by the row.
+        let register_mask = (BigUint::one() << ECRECOVER_ROWS) - 1u32;
+        for (k, scalar) in cols.scalars.iter_mut().enumerate() {
+            let register = (&witness.digits[k] << row) & &register_mask;
+            let mut bytes = register.to_bytes_le();
+            bytes.resize(NUM_LIMBS + 1, 0);
+            scalar.odd_shift = F::from_bool(witness.odd_shifts[k]);
+            for i in 0..NUM_LIMBS {
+                scalar.digits[i] = F::from_canonical_u8(bytes[i]);
+                scalar.shift_carries[i] = F::from_canonical_u8(bytes[i] >> 7);
+            }
+            scalar.digits[NUM_LIMBS] = F::from_canonical_u8(bytes[NUM_LIMBS]);
+            record.add_u8_range_checks(shard, &bytes[..NUM_LIMBS]);
+
+            if row == 0 {
+                let u_bytes = ScalarField::to_limbs(&witness.scalars[k]);
+                let odd_shift = u32::from(witness.odd_shifts[k]);
+                let mut carry = 0u32;
+                for i in 0..NUM_LIMBS {
+                    let sum = u_bytes[i] as u32 + odd_shift * order_bytes[i] as u32 + 255 + carry;
+                    carry = sum >> 8;
+                    scalar.setup_carries[i] = F::from_canonical_u32(carry);
+                }
+            }
+        }
+
+        let [d1, d2] = witness.row_digits(row);
+        cols.ladder.both_digits = F::from_bool(d1 && d2);
+        let point = witness.row_point(row);
+        let result = Self::populate_step(record, shard, &mut cols.ladder, acc, &point, true);
+
+        if row == 0 {
+            Self::populate_setup(record, shard, &mut cols.setup, Some(witness));
+            for (col, memory_record) in cols.input_memory.iter_mut().zip(&event.input_read_records)
+            {
+                col.populate(*memory_record, record);
+            }
+        } else {
+            Self::populate_setup(&mut Vec::<ByteLookupEvent>::new(), shard, &mut cols.setup, None);
+        }
+
+        if row == LAST_ROW {
+            let key = Self::populate_add(
+                record,
+                shard,
+                &mut cols.correction,
+                &result,
+                &ecrecover_offset_correction(),
+                true,
+            );
+            debug_assert_eq!(key, witness.public_key);
+            cols.x_range_check.populate(record, shard, &key.0, &modulus);
+            cols.y_range_check.populate(record, shard, &key.1, &modulus);
+            cols.permute_nonce =
+                F::from_canonical_u32(event.permute_syscall.as_ref().unwrap().nonce);
+            for (col, memory_record) in cols.state_memory.iter_mut().zip(&event.state_write_records)
+            {
+                col.populate(*memory_record, record);
+            }
+            for (col, memory_record) in cols.hash_memory.iter_mut().zip(&event.hash_read_records) {
+                col.populate(*memory_record, record);
+            }
+            for (col, memory_record) in cols.out_memory.iter_mut().zip(&event.out_write_records) {
+                col.populate(*memory_record, record);
+            }
+        } else {
+            let zero = (BigUint::zero(), BigUint::zero());
+            Self::populate_add(
+                &mut Vec::<ByteLookupEvent>::new(),
+                shard,
+                &mut cols.correction,
+                &zero,
+                &zero,
+                false,
+            );
+        }
+
+        result
+    }
+
+    /// Populates the operations with unconditional constraints on zero inputs, for the rows of
+    /// the events that do not recover the key and for the padding rows.
+    fn populate_zero<F: PrimeField32>(cols: &mut Secp256k1EcRecoverCols<F>) {
+        let zero = (BigUint::zero(), BigUint::zero());
+        let mut record = Vec::<ByteLookupEvent>::new();
+        Self::populate_setup(&mut record, 0, &mut cols.setup, None);
+        Self::populate_step(&mut record, 0, &mut cols.ladder, &zero, &zero, false);
+        Self::populate_add(&mut record, 0, &mut cols.correction, &zero, &zero, false);
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for Secp256k1EcRecoverChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Secp256k1EcRecover".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in input.get_precompile_events(SyscallCode::SECP256K1_ECRECOVER) {
+            let event = if let PrecompileEvent::Secp256k1EcRecover(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            let witness = event.is_recovered.then(|| {
+                secp256k1_ecrecover_witness(&words_to_bytes_le_vec(&event.input)).unwrap()
+            });
+
+            let mut acc = witness.as_ref().map(|_| ecrecover_offset());
+            for row in 0..ECRECOVER_ROWS {
+                let mut values = zeroed_f_vec(NUM_COLS);
+                let cols: &mut Secp256k1EcRecoverCols<F> = values.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.out_ptr = F::from_canonical_u32(event.out_ptr);
+                cols.input_ptr = F::from_canonical_u32(event.input_ptr);
+                cols.step[row] = F::one();
+                cols.is_recovered = F::from_bool(event.is_recovered);
+
+                if let (Some(witness), Some(acc)) = (&witness, acc.as_mut()) {
+                    *acc = Self::populate_row(
+                        &mut new_byte_lookup_events,
+                        event,
+                        witness,
+                        row,
+                        cols,
+                        acc,
+                    );
+                } else {
+                    Self::populate_zero(cols);
+                }
+
+                rows.push(values);
+            }
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut values = zeroed_f_vec(NUM_COLS);
+                let cols: &mut Secp256k1EcRecoverCols<F> = values.as_mut_slice().borrow_mut();
+                Self::populate_zero(cols);
+                values
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Secp256k1EcRecoverCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::SECP256K1_ECRECOVER).is_empty()
+        }
+    }
+
+    fn commit_scope(&self) -> InteractionScope {
+        InteractionScope::Global
+    }
+}
+
+impl<F> BaseAir<F> for Secp256k1EcRecoverChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Secp256k1EcRecoverChip
+where
+    AB: SP1AirBuilder,
+    FieldLimbs<AB::Var>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Secp256k1EcRecoverCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Secp256k1EcRecoverCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that the flags are booleans.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_recovered);
+        builder.assert_bool(local.setup.recid);
+        for &step in local.step.iter() {
+            builder.assert_bool(step);
+        }
+        builder.when(local.is_recovered).assert_one(local.is_real);
+
+        // Every real row has exactly one step.
+        let step_sum = local.step.iter().fold(AB::Expr::zero(), |acc, &x| acc + x);
+        builder.assert_eq(step_sum, local.is_real);
+        let (is_first, is_last) = (local.step[0], local.step[LAST_ROW]);
+        builder.assert_eq(local.is_setup, is_first * local.is_recovered);
+        builder.assert_eq(local.is_output, is_last * local.is_recovered);
+
+        // The real rows come before the padding rows, and consist of whole events.
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_first_row().when(local.is_real).assert_one(is_first);
+        builder.when_last_row().when(local.is_real).assert_one(is_last);
+        builder.when_transition().when(is_last).assert_eq(next.step[0], next.is_real);
+
+        // The rows of an event share the arguments and the table, go through the steps in order,
+        // and shift the digits of the scalars.
+        let mut continuation = builder.when_transition();
+        let mut continuation = continuation.when(local.is_real - is_last);
+        continuation.assert_one(next.is_real);
+        continuation.assert_eq(local.shard, next.shard);
+        continuation.assert_eq(local.clk, next.clk);
+        continuation.assert_eq(local.out_ptr, next.out_ptr);
+        continuation.assert_eq(local.input_ptr, next.input_ptr);
+        continuation.assert_eq(local.is_recovered, next.is_recovered);
+        continuation.assert_zero(next.step[0]);
+        for k in 0..LAST_ROW {
+            continuation.assert_eq(local.step[k], next.step[k + 1]);
+        }
+        let (local_table, next_table) = (&local.table, &next.table);
+        continuation.assert_all_eq(local_table.plus_x, next_table.plus_x);
+        continuation.assert_all_eq(local_table.plus_y, next_table.plus_y);
+        continuation.assert_all_eq(local_table.neg_plus_y, next_table.neg_plus_y);
+        continuation.assert_all_eq(local_table.minus_x, next_table.minus_x);
+        continuation.assert_all_eq(local_table.minus_y, next_table.minus_y);
+        continuation.assert_all_eq(local_table.neg_minus_y, next_table.neg_minus_y);
+        for (local_scalar, next_scalar) in local.scalars.iter().zip(next.scalars.iter()) {
+            for i in 0..NUM_LIMBS {
+                continuation.assert_eq(next_scalar.digits[i], shifted_digit::<AB>(local_scalar, i));
+            }
+            continuation.assert_eq(next_scalar.digits[NUM_LIMBS], local_scalar.shift_carries[31]);
+        }
+
+        // The input is the hash, `v`, `r` and `s` as 32-byte big-endian integers.
+        let input_bytes =
+            local.input_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+        let big_endian = |offset: usize| -> Polynomial<AB::Expr> {
+            (0..NUM_LIMBS).rev().map(|i| input_bytes[offset + i].into()).collect()
+        };
+        let (z, r, s) = (big_endian(0), big_endian(64), big_endian(96));
+        for &byte in &input_bytes[32..63] {
+            builder.when(local.is_setup).assert_zero(byte);
+        }
+        builder
+            .when(local.is_setup)
+            .assert_eq(input_bytes[63], local.setup.recid + AB::Expr::from_canonical_u32(27));
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.input_ptr,
+            &local.input_memory,
+            local.is_setup,
+        );
+
+        eval_setup::<AB>(builder, local, &z, &r, &s, &input_bytes);
+        eval_scalars::<AB>(builder, local);
+
+        // Select the point of the row from the digits, which adds `G + R` if both digits are 1,
+        // its negation if both are -1, and `G - R` or its negation otherwise.
+        let ladder = &local.ladder;
+        let (d1, d2) = (local.scalars[0].digits[NUM_LIMBS], local.scalars[1].digits[NUM_LIMBS]);
+        builder.assert_eq(ladder.both_digits, d1 * d2);
+        let is_plus: AB::Expr = ladder.both_digits.into();
+        let is_neg_plus: AB::Expr = local.is_recovered - d1 - d2 + ladder.both_digits;
+        let is_minus: AB::Expr = d1 - ladder.both_digits;
+        let is_neg_minus: AB::Expr = d2 - ladder.both_digits;
+        for i in 0..NUM_LIMBS {
+            builder.assert_eq(
+                ladder.point_x[i],
+                (is_plus.clone() + is_neg_plus.clone()) * local.table.plus_x[i]
+                    + (is_minus.clone() + is_neg_minus.clone()) * local.table.minus_x[i],
+            );
+            builder.assert_eq(
+                ladder.point_y[i],
+                is_plus.clone() * local.table.plus_y[i]
+                    + is_neg_plus.clone() * local.table.neg_plus_y[i]
+                    + is_minus.clone() * local.table.minus_y[i]
+                    + is_neg_minus.clone() * local.table.neg_minus_y[i],
+            );
+        }
+
+        eval_step::<AB>(builder, ladder, local.is_recovered);
+
+        // The accumulator starts from the offset, and every row passes its result to the next.
+        let (offset_x, offset_y) = ecrecover_offset();
+        builder
+            .when(local.is_setup)
+            .assert_all_eq(ladder.acc_x, BaseField::to_limbs_field::<AB::F, _>(&offset_x));
+        builder
+            .when(local.is_setup)
+            .assert_all_eq(ladder.acc_y, BaseField::to_limbs_field::<AB::F, _>(&offset_y));
+        let mut chain = builder.when_transition();
+        let mut chain = chain.when(local.is_recovered - local.is_output);
+        chain.assert_all_eq(next.ladder.acc_x, ladder.x4.result);
+        chain.assert_all_eq(next.ladder.acc_y, ladder.y4.result);
+
+        // The last row removes the offset from the accumulator to get the key.
+        let gated = |limbs: FieldLimbs<AB::Var>| -> Polynomial<AB::Expr> {
+            limbs.into_iter().map(|limb| limb * local.is_output).collect()
+        };
+        let constant = |value: &BigUint| -> Polynomial<AB::Expr> {
+            BaseField::to_limbs_field::<AB::F, _>(value)
+                .into_iter()
+                .map(|limb| AB::Expr::from(local.is_output) * limb)
+                .collect()
+        };
+        let (correction_x, correction_y) = ecrecover_offset_correction();
+        eval_add::<AB>(
+            builder,
+            &local.correction,
+            (&gated(ladder.x4.result), &gated(ladder.y4.result)),
+            (&constant(&correction_x), &constant(&correction_y)),
+            local.is_output,
+        );
+
+        // The last row writes the reduced public key to the state, with the padding of a single
+        // block.
+        let (x, y) = (local.correction.x.result, local.correction.y.result);
+        let modulus = limbs_from_vec::<AB::Expr, <BaseField as NumLimbs>::Limbs, AB::F>(
+            BaseField::to_limbs_field_vec(&BaseField::modulus()),
+        );
+        local.x_range_check.eval(builder, &x, &modulus, local.is_output);
+        local.y_range_check.eval(builder, &y, &modulus, local.is_output);
+        for w in 0..COORDINATE_NUM_WORDS {
+            let x_word = local.state_memory[w].value();
+            let y_word = local.state_memory[COORDINATE_NUM_WORDS + w].value();
+            for j in 0..4 {
+                let limb = NUM_LIMBS - 1 - (4 * w + j);
+                builder.when(local.is_output).assert_eq(x_word[j], x[limb]);
+                builder.when(local.is_output).assert_eq(y_word[j], y[limb]);
+            }
+        }
+        let padding = keccak_merkle_state(&[0; COORDINATE_NUM_WORDS], &[0; COORDINATE_NUM_WORDS]);
+        for i in 2 * COORDINATE_NUM_WORDS..STATE_NUM_WORDS {
+            let value = local.state_memory[i].value();
+            for (j, byte) in padding[i].to_le_bytes().into_iter().enumerate() {
+                builder.when(local.is_output).assert_eq(value[j], AB::F::from_canonical_u8(byte));
+            }
+        }
+        let state_ptr: AB::Expr =
+            local.input_ptr + AB::Expr::from_canonical_u32(ECRECOVER_STATE_OFFSET);
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            state_ptr.clone(),
+            &local.state_memory,
+            local.is_output,
+        );
+
+        // Send the permutation of the state. It is proven in its own shard, so it is sent
+        // globally, as the core shards do.
+        builder.send_syscall(
+            local.shard,
+            local.clk + AB::Expr::from_canonical_u32(ECRECOVER_PERMUTE_OFFSET),
+            local.permute_nonce,
+            AB::F::from_canonical_u32(SyscallCode::KECCAK_PERMUTE.syscall_id()),
+            state_ptr.clone(),
+            AB::Expr::zero(),
+            local.is_output,
+            InteractionScope::Global,
+        );
+
+        // Read the hash, and write its last 20 bytes as the address, left-padded with zeros.
+        let output_clk: AB::Expr =
+            local.clk + AB::Expr::from_canonical_u32(ECRECOVER_OUTPUT_OFFSET);
+        builder.eval_memory_access_slice(
+            local.shard,
+            output_clk.clone(),
+            state_ptr,
+            &local.hash_memory,
+            local.is_output,
+        );
+        for (i, (out, hash)) in local.out_memory.iter().zip(local.hash_memory.iter()).enumerate() {
+            if i < 3 {
+                builder.when(local.is_output).assert_word_zero(*out.value());
+            } else {
+                builder.when(local.is_output).assert_word_eq(*out.value(), *hash.value());
+            }
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            output_clk,
+            local.out_ptr,
+            &local.out_memory,
+            local.is_output,
+        );
+
+        // Receive the arguments on the first row of the event, with the status of the call.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            local.nonce,
+            AB::Expr::from_canonical_u32(SyscallCode::SECP256K1_ECRECOVER.syscall_id())
+                + (AB::Expr::one() - local.is_recovered) * AB::F::from_canonical_u32(1 << 8),
+            local.out_ptr,
+            local.input_ptr,
+            is_first,
+            InteractionScope::Local,
+        );
+    }
+}
+
+/// The byte `i` of the digits of the next row, which are shifted left by one bit.
+fn shifted_digit<AB: SP1AirBuilder>(scalar: &EcRecoverScalarCols<AB::Var>, i: usize) -> AB::Expr {
+    let carry_in: AB::Expr =
+        if i == 0 { AB::Expr::zero() } else { scalar.shift_carries[i - 1].into() };
+    scalar.digits[i] * AB::F::from_canonical_u32(2) + carry_in
+        - scalar.shift_carries[i] * AB::F::from_canonical_u32(1 << 8)
+}
+
+/// Constrains the digits of the scalars to be bits of registers shifted by one bit per row, which
+/// start on the first row as `m = (u + odd_shift * n + 2^257 - 1) / 2`.
+fn eval_scalars<AB: SP1AirBuilder>(builder: &mut AB, local: &Secp256k1EcRecoverCols<AB::Var>) {
+    let order = ScalarField::to_limbs(&ScalarField::modulus());
+    let scalars = [local.setup.u1.result, local.setup.u2.result];
+    for (scalar, u) in local.scalars.iter().zip(scalars) {
+        builder.assert_bool(scalar.odd_shift);
+        builder.assert_bool(scalar.digits[NUM_LIMBS]);
+        for &carry in scalar.shift_carries.iter() {
+            builder.assert_bool(carry);
+        }
+        for &carry in scalar.setup_carries.iter() {
+            builder.assert_zero(
+                carry * (carry - AB::Expr::one()) * (carry - AB::Expr::from_canonical_u32(2)),
+            );
+        }
+        builder.slice_range_check_u8(&scalar.digits[..NUM_LIMBS], local.is_recovered);
+
+        // The doubled register `2 * m` is `u + odd_shift * n + (2^256 - 1) + 2^256`.
+        for i in 0..NUM_LIMBS {
+            let carry_in: AB::Expr =
+                if i == 0 { AB::Expr::zero() } else { scalar.setup_carries[i - 1].into() };
+            builder.when(local.is_setup).assert_eq(
+                u[i] + scalar.odd_shift * AB::F::from_canonical_u8(order[i])
+                    + AB::Expr::from_canonical_u32(255)
+                    + carry_in,
+                shifted_digit::<AB>(scalar, i)
+                    + scalar.setup_carries[i] * AB::F::from_canonical_u32(1 << 8),
+            );
+        }
+        builder.when(local.is_setup).assert_eq(
+            AB::Expr::one() + scalar.setup_carries[NUM_LIMBS - 1],
+            scalar.digits[NUM_LIMBS] * AB::F::from_canonical_u32(2)
+                + scalar.shift_carries[NUM_LIMBS - 1],
+        );
+    }
+}
+
+/// Constrains the operations of the first row: the checks of the signature, the scalars, the
+/// decompression of `R`, and the points `G + R` and `G - R` written to the table.
+fn eval_setup<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    local: &Secp256k1EcRecoverCols<AB::Var>,
+    z: &Polynomial<AB::Expr>,
+    r: &Polynomial<AB::Expr>,
+    s: &Polynomial<AB::Expr>,
+    input_bytes: &[AB::Var],
+) where
+    FieldLimbs<AB::Var>: Copy,
+{
+    let setup = &local.setup;
+    let is_setup = local.is_setup;
+    let constant = |value: &BigUint| -> Polynomial<AB::Expr> {
+        BaseField::to_limbs_field::<AB::F, _>(value)
+            .into_iter()
+            .map(|limb| AB::Expr::from(is_setup) * limb)
+            .collect()
+    };
+    let zero: Polynomial<AB::Expr> = Polynomial::from_coefficients(&[AB::Expr::zero()]);
+
+    // Check that `r` and `s` are in `[1, n)`.
+    let order = limbs_from_vec::<AB::Expr, <ScalarField as NumLimbs>::Limbs, AB::F>(
+        ScalarField::to_limbs_field_vec(&ScalarField::modulus()),
+    );
+    setup.r_range_check.eval(builder, r, &order, is_setup);
+    setup.s_range_check.eval(builder, s, &order, is_setup);
+    for (offset, is_zero) in [(64, setup.r_is_zero), (96, setup.s_is_zero)] {
+        let sum = input_bytes[offset..offset + NUM_LIMBS]
+            .iter()
+            .fold(AB::Expr::zero(), |acc, &byte| acc + byte);
+        IsZeroOperation::<AB::F>::eval(builder, sum, is_zero, is_setup.into());
+        builder.when(is_setup).assert_zero(is_zero.result);
+    }
+
+    // Compute `u1 = -z / r` and `u2 = s / r` modulo the group order.
+    setup.z_reduced.eval(builder, z, &zero, FieldOperation::Add, is_setup);
+    setup.neg_z.eval(builder, &zero, &setup.z_reduced.result, FieldOperation::Sub, is_setup);
+    setup.u1.eval(builder, &setup.neg_z.result, r, FieldOperation::Div, is_setup);
+    setup.u2.eval(builder, s, r, FieldOperation::Div, is_setup);
+
+    // Decompress `R`, with the parity of `recid`.
+    setup.x_2.eval(builder, r, r, FieldOperation::Mul, is_setup);
+    setup.x_3.eval(builder, &setup.x_2.result, r, FieldOperation::Mul, is_setup);
+    setup.x_3_plus_b.eval(
+        builder,
+        &setup.x_3.result,
+        &constant(&Secp256k1Parameters::b_int()),
+        FieldOperation::Add,
+        is_setup,
+    );
+    setup.y.eval(builder, &setup.x_3_plus_b.result, setup.recid, is_setup);
+    let y = setup.y.multiplication.result;
+
+    // Compute `G + R` and `G - R`, whose x-coordinates differ from the one of `R`.
+    let (gx, gy) = Secp256k1Parameters::generator();
+    let (gx, gy) = (constant(&gx), constant(&gy));
+    setup.dx.eval(builder, &gx, r, FieldOperation::Sub, is_setup);
+    setup.dx_inverse.eval(
+        builder,
+        &[is_setup].iter(),
+        &setup.dx.result,
+        FieldOperation::Div,
+        is_setup,
+    );
+    setup.sum_x.eval(builder, &gx, r, FieldOperation::Add, is_setup);
+
+    setup.plus_dy.eval(builder, &gy, &y, FieldOperation::Sub, is_setup);
+    setup.minus_dy.eval(builder, &gy, &y, FieldOperation::Add, is_setup);
+    for (dy, slope, slope_squared, x, x_diff, slope_times_x_diff, y, neg_y) in [
+        (
+            &setup.plus_dy,
+            &setup.plus_slope,
+            &setup.plus_slope_squared,
+            &setup.plus_x,
+            &setup.plus_x_diff,
+            &setup.plus_slope_times_x_diff,
+            &setup.plus_y,
+            &setup.neg_plus_y,
+        ),
+        (
+            &setup.minus_dy,
+            &setup.minus_slope,
+            &setup.minus_slope_squared,
+            &setup.minus_x,
+            &setup.minus_x_diff,
+            &setup.minus_slope_times_x_diff,
+            &setup.minus_y,
+            &setup.neg_minus_y,
+        ),
+    ] {
+        slope.eval(builder, &dy.result, &setup.dx_inverse.result, FieldOperation::Mul, is_setup);
+        slope_squared.eval(builder, &slope.result, &slope.result, FieldOperation::Mul, is_setup);
+        x.eval(builder, &slope_squared.result, &setup.sum_x.result, FieldOperation::Sub, is_setup);
+        x_diff.eval(builder, &gx, &x.result, FieldOperation::Sub, is_setup);
+        slope_times_x_diff.eval(
+            builder,
+            &slope.result,
+            &x_diff.result,
+            FieldOperation::Mul,
+            is_setup,
+        );
+        y.eval(builder, &slope_times_x_diff.result, &gy, FieldOperation::Sub, is_setup);
+        neg_y.eval(builder, &zero, &y.result, FieldOperation::Sub, is_setup);
+    }
+
+    // Write the points to the table.
+    let table = &local.table;
+    builder.when(is_setup).assert_all_eq(table.plus_x, setup.plus_x.result);
+    builder.when(is_setup).assert_all_eq(table.plus_y, setup.plus_y.result);
+    builder.when(is_setup).assert_all_eq(table.neg_plus_y, setup.neg_plus_y.result);
+    builder.when(is_setup).assert_all_eq(table.minus_x, setup.minus_x.result);
+    builder.when(is_setup).assert_all_eq(table.minus_y, setup.minus_y.result);
+    builder.when(is_setup).assert_all_eq(table.neg_minus_y, setup.neg_minus_y.result);
+}
+
+/// Constrains the sum of two points with distinct x-coordinates.
+fn eval_add<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    cols: &EcRecoverAddCols<AB::Var>,
+    (a_x, a_y): (&Polynomial<AB::Expr>, &Polynomial<AB::Expr>),
+    (b_x, b_y): (&Polynomial<AB::Expr>, &Polynomial<AB::Expr>),
+    is_real: AB::Var,
+) where
+    FieldLimbs<AB::Var>: Copy,
+{
+    cols.dy.eval(builder, b_y, a_y, FieldOperation::Sub, is_real);
+    cols.dx.eval(builder, b_x, a_x, FieldOperation::Sub, is_real);
+    cols.dx_inverse.eval(builder, &[is_real].iter(), &cols.dx.result, FieldOperation::Div, is_real);
+    cols.slope.eval(
+        builder,
+        &cols.dy.result,
+        &cols.dx_inverse.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.slope_squared.eval(
+        builder,
+        &cols.slope.result,
+        &cols.slope.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.sum_x.eval(builder, a_x, b_x, FieldOperation::Add, is_real);
+    cols.x.eval(
+        builder,
+        &cols.slope_squared.result,
+        &cols.sum_x.result,
+        FieldOperation::Sub,
+        is_real,
+    );
+    cols.x_diff.eval(builder, a_x, &cols.x.result, FieldOperation::Sub, is_real);
+    cols.slope_times_x_diff.eval(
+        builder,
+        &cols.slope.result,
+        &cols.x_diff.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.y.eval(builder, &cols.slope_times_x_diff.result, a_y, FieldOperation::Sub, is_real);
+}
+
+/// Constrains the step `2 * acc + t` of the row, computed as `(acc + t) + acc` with the slopes of
+/// both additions, which requires the x-coordinates of the operands to differ.
+fn eval_step<AB: SP1AirBuilder>(
+    builder: &mut AB,
+    cols: &EcRecoverStepCols<AB::Var>,
+    is_real: AB::Var,
+) where
+    FieldLimbs<AB::Var>: Copy,
+{
+    let (acc_x, acc_y) = (cols.acc_x, cols.acc_y);
+    cols.dy.eval(builder, &cols.point_y, &acc_y, FieldOperation::Sub, is_real);
+    cols.dx.eval(builder, &cols.point_x, &acc_x, FieldOperation::Sub, is_real);
+    cols.dx_inverse.eval(builder, &[is_real].iter(), &cols.dx.result, FieldOperation::Div, is_real);
+    cols.slope.eval(
+        builder,
+        &cols.dy.result,
+        &cols.dx_inverse.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.slope_squared.eval(
+        builder,
+        &cols.slope.result,
+        &cols.slope.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.sum_x.eval(builder, &acc_x, &cols.point_x, FieldOperation::Add, is_real);
+    cols.x3.eval(
+        builder,
+        &cols.slope_squared.result,
+        &cols.sum_x.result,
+        FieldOperation::Sub,
+        is_real,
+    );
+
+    cols.x3_diff.eval(builder, &cols.x3.result, &acc_x, FieldOperation::Sub, is_real);
+    cols.x3_diff_inverse.eval(
+        builder,
+        &[is_real].iter(),
+        &cols.x3_diff.result,
+        FieldOperation::Div,
+        is_real,
+    );
+    cols.two_y.eval(builder, &acc_y, &acc_y, FieldOperation::Add, is_real);
+    cols.quotient.eval(
+        builder,
+        &cols.two_y.result,
+        &cols.x3_diff_inverse.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.neg_slope.eval(
+        builder,
+        &cols.slope.result,
+        &cols.quotient.result,
+        FieldOperation::Add,
+        is_real,
+    );
+    cols.neg_slope_squared.eval(
+        builder,
+        &cols.neg_slope.result,
+        &cols.neg_slope.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.sum_x3.eval(builder, &acc_x, &cols.x3.result, FieldOperation::Add, is_real);
+    cols.x4.eval(
+        builder,
+        &cols.neg_slope_squared.result,
+        &cols.sum_x3.result,
+        FieldOperation::Sub,
+        is_real,
+    );
+    cols.x4_diff.eval(builder, &cols.x4.result, &acc_x, FieldOperation::Sub, is_real);
+    cols.neg_slope_times_x4_diff.eval(
+        builder,
+        &cols.neg_slope.result,
+        &cols.x4_diff.result,
+        FieldOperation::Mul,
+        is_real,
+    );
+    cols.y4.eval(
+        builder,
+        &cols.neg_slope_times_x4_diff.result,
+        &acc_y,
+        FieldOperation::Sub,
+        is_real,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Program;
+    use sp1_stark::CpuProver;
+    use test_artifacts::SECP256K1_ECRECOVER_ELF;
+
+    use crate::{
+        io::SP1Stdin,
+        utils::{self, run_test_io},
+    };
+
+    #[test]
+    fn test_secp256k1_ecrecover() {
+        utils::setup_logger();
+        let program = Program::from(SECP256K1_ECRECOVER_ELF).unwrap();
+        run_test_io::<CpuProver<_, _>>(program, SP1Stdin::new()).unwrap();
+    }
+}
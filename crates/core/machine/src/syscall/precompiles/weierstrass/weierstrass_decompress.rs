@@ -18,8 +18,8 @@ use sp1_core_executor::{
 use sp1_curves::{
     params::{limbs_from_vec, FieldParameters, Limbs, NumLimbs, NumWords},
     weierstrass::{
-        bls12_381::bls12381_sqrt, secp256k1::secp256k1_sqrt, secp256r1::secp256r1_sqrt,
-        WeierstrassParameters,
+        bls12_381::bls12381_sqrt, bn254::bn254_sqrt, secp256k1::secp256k1_sqrt,
+        secp256r1::secp256r1_sqrt, WeierstrassParameters,
     },
     CurveType, EllipticCurve,
 };
@@ -43,6 +43,11 @@ pub const fn num_weierstrass_decompress_cols<P: FieldParameters + NumWords>() ->
 
 /// A set of columns to compute `WeierstrassDecompress` that decompresses a point on a Weierstrass
 /// curve.
+///
+/// If there is no point with the given `x`, the syscall fails and `y` is left as it was. Failure is
+/// proven by a square root of `-(x^3 + ax + b)` and an inverse of it, which exist exactly when
+/// `x^3 + ax + b` has no square root since -1 is not a square in the base fields of the supported
+/// curves.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
 pub struct WeierstrassDecompressCols<T, P: FieldParameters + NumWords> {
@@ -52,6 +57,7 @@ pub struct WeierstrassDecompressCols<T, P: FieldParameters + NumWords> {
     pub nonce: T,
     pub ptr: T,
     pub sign_bit: T,
+    pub is_invalid: T,
     pub x_access: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
     pub y_access: GenericArray<MemoryReadWriteCols<T>, P::WordsFieldElement>,
     pub(crate) range_x: FieldLtCols<T, P>,
@@ -59,6 +65,8 @@ pub struct WeierstrassDecompressCols<T, P: FieldParameters + NumWords> {
     pub(crate) x_3: FieldOpCols<T, P>,
     pub(crate) ax_plus_b: FieldInnerProductCols<T, P>,
     pub(crate) x_3_plus_b_plus_ax: FieldOpCols<T, P>,
+    pub(crate) sqrt_input: FieldOpCols<T, P>,
+    pub(crate) sqrt_input_inverse: FieldOpCols<T, P>,
     pub(crate) y: FieldSqrtCols<T, P>,
     pub(crate) neg_y: FieldOpCols<T, P>,
 }
@@ -113,6 +121,7 @@ impl<E: EllipticCurve + WeierstrassParameters> WeierstrassDecompressChip<E> {
         shard: u32,
         cols: &mut WeierstrassDecompressCols<F, E::BaseField>,
         x: BigUint,
+        is_invalid: bool,
     ) {
         // Y = sqrt(x^3 + ax + b)
         cols.range_x.populate(record, shard, &x, &E::BaseField::modulus());
@@ -130,10 +139,27 @@ impl<E: EllipticCurve + WeierstrassParameters> WeierstrassDecompressChip<E> {
             CurveType::Secp256k1 => secp256k1_sqrt,
             CurveType::Secp256r1 => secp256r1_sqrt,
             CurveType::Bls12381 => bls12381_sqrt,
+            CurveType::Bn254 => bn254_sqrt,
             _ => panic!("Unsupported curve"),
         };
 
-        let y = cols.y.populate(record, shard, &x_3_plus_b_plus_ax, sqrt_fn);
+        let multiplier =
+            if is_invalid { E::BaseField::modulus() - BigUint::one() } else { BigUint::one() };
+        let sqrt_input = cols.sqrt_input.populate(
+            record,
+            shard,
+            &x_3_plus_b_plus_ax,
+            &multiplier,
+            FieldOperation::Mul,
+        );
+        cols.sqrt_input_inverse.populate(
+            record,
+            shard,
+            &BigUint::from(u32::from(is_invalid)),
+            &sqrt_input,
+            FieldOperation::Div,
+        );
+        let y = cols.y.populate(record, shard, &sqrt_input, sqrt_fn);
         let zero = BigUint::zero();
         cols.neg_y.populate(record, shard, &zero, &y, FieldOperation::Sub);
     }
@@ -150,6 +176,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => "Secp256k1Decompress".to_string(),
             CurveType::Secp256r1 => "Secp256r1Decompress".to_string(),
             CurveType::Bls12381 => "Bls12381Decompress".to_string(),
+            CurveType::Bn254 => "Bn254Decompress".to_string(),
             _ => panic!("Unsupported curve"),
         }
     }
@@ -163,6 +190,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256k1 => input.get_precompile_events(SyscallCode::SECP256K1_DECOMPRESS),
             CurveType::Secp256r1 => input.get_precompile_events(SyscallCode::SECP256R1_DECOMPRESS),
             CurveType::Bls12381 => input.get_precompile_events(SyscallCode::BLS12381_DECOMPRESS),
+            CurveType::Bn254 => input.get_precompile_events(SyscallCode::BN254_DECOMPRESS),
             _ => panic!("Unsupported curve"),
         };
 
@@ -179,6 +207,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                 (CurveType::Secp256k1, PrecompileEvent::Secp256k1Decompress(event)) => event,
                 (CurveType::Secp256r1, PrecompileEvent::Secp256r1Decompress(event)) => event,
                 (CurveType::Bls12381, PrecompileEvent::Bls12381Decompress(event)) => event,
+                (CurveType::Bn254, PrecompileEvent::Bn254Decompress(event)) => event,
                 _ => panic!("Unsupported curve"),
             };
 
@@ -191,9 +220,16 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             cols.clk = F::from_canonical_u32(event.clk);
             cols.ptr = F::from_canonical_u32(event.ptr);
             cols.sign_bit = F::from_bool(event.sign_bit);
+            cols.is_invalid = F::from_bool(event.is_invalid);
 
             let x = BigUint::from_bytes_le(&event.x_bytes);
-            Self::populate_field_ops(&mut new_byte_lookup_events, event.shard, cols, x);
+            Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                cols,
+                x,
+                event.is_invalid,
+            );
 
             for i in 0..cols.x_access.len() {
                 cols.x_access[i].populate(event.x_memory_records[i], &mut new_byte_lookup_events);
@@ -203,7 +239,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                     .populate_write(event.y_memory_records[i], &mut new_byte_lookup_events);
             }
 
-            if matches!(self.sign_rule, SignChoiceRule::Lexicographic) {
+            if matches!(self.sign_rule, SignChoiceRule::Lexicographic) && !event.is_invalid {
                 let lsb = cols.y.lsb;
                 let choice_cols: &mut LexicographicChoiceCols<F, E::BaseField> =
                     row[weierstrass_width..width].borrow_mut();
@@ -272,7 +308,7 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                     cols.x_access[i].access.value = words[i].into();
                 }
 
-                Self::populate_field_ops(&mut vec![], 0, cols, dummy_value);
+                Self::populate_field_ops(&mut vec![], 0, cols, dummy_value, false);
                 row
             },
             input.fixed_log2_rows::<F, _>(self),
@@ -304,6 +340,9 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                 CurveType::Bls12381 => {
                     !shard.get_precompile_events(SyscallCode::BLS12381_DECOMPRESS).is_empty()
                 }
+                CurveType::Bn254 => {
+                    !shard.get_precompile_events(SyscallCode::BN254_DECOMPRESS).is_empty()
+                }
                 _ => panic!("Unsupported curve"),
             }
         }
@@ -346,6 +385,8 @@ where
         let num_words_field_element = num_limbs / 4;
 
         builder.assert_bool(local.sign_bit);
+        builder.assert_bool(local.is_invalid);
+        let is_valid: AB::Expr = local.is_real.into() - local.is_invalid.into();
 
         let x: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_prev_access(&local.x_access);
@@ -373,6 +414,34 @@ where
             local.is_real,
         );
 
+        // The input of the square root is `x^3 + ax + b`, negated if the point is invalid, in which
+        // case it is not zero.
+        let one_limbs = E::BaseField::to_limbs_field_vec::<AB::Expr, AB::F>(&BigUint::one());
+        let neg_one_limbs = E::BaseField::to_limbs_field_vec::<AB::Expr, AB::F>(
+            &(E::BaseField::modulus() - BigUint::one()),
+        );
+        let multiplier: Polynomial<AB::Expr> = one_limbs
+            .into_iter()
+            .zip(neg_one_limbs)
+            .map(|(one, neg_one)| {
+                one * (AB::Expr::one() - local.is_invalid.into()) + neg_one * local.is_invalid
+            })
+            .collect();
+        local.sqrt_input.eval(
+            builder,
+            &local.x_3_plus_b_plus_ax.result,
+            &multiplier,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.sqrt_input_inverse.eval(
+            builder,
+            &[local.is_invalid].iter(),
+            &local.sqrt_input.result,
+            FieldOperation::Div,
+            local.is_real,
+        );
+
         local.neg_y.eval(
             builder,
             &[AB::Expr::zero()].iter(),
@@ -381,11 +450,16 @@ where
             local.is_real,
         );
 
-        local.y.eval(builder, &local.x_3_plus_b_plus_ax.result, local.y.lsb, local.is_real);
+        local.y.eval(builder, &local.sqrt_input.result, local.y.lsb, local.is_real);
 
         let y_limbs: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
             limbs_from_access(&local.y_access);
 
+        // If the point is invalid, y is left as it was.
+        let prev_y_limbs: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.y_access);
+        builder.when(local.is_invalid).assert_all_eq(prev_y_limbs, y_limbs);
+
         // Constrain the y value according the sign rule convention.
         match self.sign_rule {
             SignChoiceRule::LeastSignificantBit => {
@@ -395,11 +469,11 @@ where
                 // should be the square root of the y value. Otherwise, the result should be the
                 // negative square root of the y value.
                 builder
-                    .when(local.is_real)
+                    .when(is_valid.clone())
                     .when_ne(local.y.lsb, AB::Expr::one() - local.sign_bit)
                     .assert_all_eq(local.y.multiplication.result, y_limbs);
                 builder
-                    .when(local.is_real)
+                    .when(is_valid.clone())
                     .when_ne(local.y.lsb, local.sign_bit)
                     .assert_all_eq(local.neg_y.result, y_limbs);
             }
@@ -427,7 +501,7 @@ where
                     builder,
                     &local.neg_y.result,
                     &modulus_limbs,
-                    local.is_real,
+                    is_valid.clone(),
                 );
 
                 // Assert that the flags are booleans.
@@ -436,25 +510,25 @@ where
                 builder.assert_bool(choice_cols.when_neg_y_res_is_lt);
 
                 // Assert that the `when` flags are disjoint:
-                builder.when(local.is_real).assert_one(
+                builder.when(is_valid.clone()).assert_one(
                     choice_cols.when_sqrt_y_res_is_lt + choice_cols.when_neg_y_res_is_lt,
                 );
 
                 // Assert that the value of `y` matches the claimed value by the flags.
 
                 builder
-                    .when(local.is_real)
+                    .when(is_valid.clone())
                     .when(choice_cols.is_y_eq_sqrt_y_result)
                     .assert_all_eq(local.y.multiplication.result, y_limbs);
 
                 builder
-                    .when(local.is_real)
+                    .when(is_valid.clone())
                     .when_not(choice_cols.is_y_eq_sqrt_y_result)
                     .assert_all_eq(local.neg_y.result, y_limbs);
 
-                // Assert that the comparison only turns on when `is_real` is true.
-                builder.when_not(local.is_real).assert_zero(choice_cols.when_sqrt_y_res_is_lt);
-                builder.when_not(local.is_real).assert_zero(choice_cols.when_neg_y_res_is_lt);
+                // Assert that the comparison only turns on when the point is real and valid.
+                builder.when_not(is_valid.clone()).assert_zero(choice_cols.when_sqrt_y_res_is_lt);
+                builder.when_not(is_valid.clone()).assert_zero(choice_cols.when_neg_y_res_is_lt);
 
                 // Assert that the flags are set correctly. When the sign_bit is true, we want that
                 // `neg_y < y`, and vice versa when the sign_bit is false. Hence, when should have:
@@ -467,10 +541,10 @@ where
                 // - When `sign_bit` is true , then is_y_eq_sqrt_y_result == when_neg_y_res_is_lt.
                 // - When `sign_bit` is false, then is_y_eq_sqrt_y_result == when_sqrt_y_res_is_lt.
                 builder
-                    .when(local.is_real)
+                    .when(is_valid.clone())
                     .when(local.sign_bit)
                     .assert_eq(choice_cols.is_y_eq_sqrt_y_result, choice_cols.when_neg_y_res_is_lt);
-                builder.when(local.is_real).when_not(local.sign_bit).assert_eq(
+                builder.when(is_valid.clone()).when_not(local.sign_bit).assert_eq(
                     choice_cols.is_y_eq_sqrt_y_result,
                     choice_cols.when_sqrt_y_res_is_lt,
                 );
@@ -522,6 +596,9 @@ where
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_DECOMPRESS.syscall_id())
             }
+            CurveType::Bn254 => {
+                AB::F::from_canonical_u32(SyscallCode::BN254_DECOMPRESS.syscall_id())
+            }
             _ => panic!("Unsupported curve"),
         };
 
@@ -529,7 +606,7 @@ where
             local.shard,
             local.clk,
             local.nonce,
-            syscall_id,
+            syscall_id + local.is_invalid.into() * AB::F::from_canonical_u32(1 << 8),
             local.ptr,
             local.sign_bit,
             local.is_real,
@@ -1,14 +1,12 @@
 use std::{
     collections::VecDeque,
     fs::File,
-    io::{
-        Seek, {self},
-    },
+    io,
     sync::{mpsc::sync_channel, Arc, Mutex},
 };
 use web_time::Instant;
 
-use crate::riscv::{CoreShapeConfig, RiscvAir};
+use crate::riscv::{ChipAudit, CoreShapeConfig, MissingChipError, RiscvAir, TraceAreaCaps};
 use p3_challenger::FieldChallenger;
 use p3_maybe_rayon::prelude::*;
 use serde::{de::DeserializeOwned, Serialize};
@@ -29,8 +27,10 @@ use crate::{
     riscv::cost::CostEstimator,
     utils::{chunk_vec, concurrency::TurnBasedSync},
 };
+use hashbrown::HashMap;
 use sp1_core_executor::{
     events::{format_table_line, sorted_table_lines},
+    syscalls::SyscallCode,
     ExecutionState,
 };
 use sp1_primitives::io::SP1PublicValues;
@@ -41,8 +41,9 @@ use sp1_core_executor::{
 };
 use sp1_stark::{
     air::{MachineAir, PublicValues},
-    Com, CpuProver, DebugConstraintBuilder, InteractionBuilder, MachineProof, MachineProver,
-    MachineRecord, OpeningProof, PcsProverData, ProverConstraintFolder, SP1CoreOpts,
+    Com, CpuProver, DebugConstraintBuilder, DeferralPolicy, InteractionBuilder, MachineProof,
+    MachineProver, MachineRecord, MemoryCapExceeded, MemoryCaps, MemoryWatermarkTracker,
+    OpeningProof, PcsProverData, ProverConstraintFolder, ProvingStage, SP1CoreOpts,
     StarkGenericConfig, StarkMachine, StarkProvingKey, StarkVerifyingKey, UniConfig, Val,
     VerifierConstraintFolder,
 };
@@ -55,6 +56,10 @@ pub enum SP1CoreProverError {
     IoError(io::Error),
     #[error("serialization error: {0}")]
     SerializationError(bincode::Error),
+    #[error("{0}")]
+    MemoryCapExceeded(MemoryCapExceeded),
+    #[error("{0}")]
+    MissingChip(MissingChipError),
 }
 
 pub fn prove_simple<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
@@ -116,6 +121,7 @@ where
     let machine = RiscvAir::machine(config);
     let prover = P::new(machine);
     let (pk, _) = prover.setup(&program);
+    let memory = MemoryWatermarkTracker::new(opts.memory_caps);
     prove_with_context::<SC, _>(
         &prover,
         &pk,
@@ -124,6 +130,7 @@ where
         opts,
         Default::default(),
         shape_config,
+        &memory,
     )
 }
 
@@ -135,6 +142,7 @@ pub fn prove_with_context<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<
     opts: SP1CoreOpts,
     context: SP1Context,
     shape_config: Option<&CoreShapeConfig<SC::Val>>,
+    memory: &MemoryWatermarkTracker,
 ) -> Result<(MachineProof<SC>, Vec<u8>, u64), SP1CoreProverError>
 where
     SC::Val: PrimeField32,
@@ -143,15 +151,32 @@ where
     Com<SC>: Send + Sync,
     PcsProverData<SC>: Send + Sync,
 {
+    // Spill the precompile events over as many shards as needed to keep their traces in check.
+    let caps = TraceAreaCaps::from_env();
+    let event_caps = &caps.event_caps(shape_config);
+
+    // Split the memory finalization over as many shards as needed to keep the global memory
+    // chips within their caps.
+    let mut opts = opts;
+    if let Some(cap) = caps.memory_event_cap(shape_config) {
+        opts.split_opts.memory = opts.split_opts.memory.min(cap);
+    }
+
+    // The shapes of the CPU shards don't cover the precompile chips, so all their events are
+    // deferred when the shapes are fixed.
+    if shape_config.is_some() {
+        opts.split_opts.deferral = DeferralPolicy::default();
+    }
+
+    // Check the precompiles called by the program against the chips of the machine.
+    let audit = ChipAudit::new::<SC::Val>(prover.machine().chips().iter().map(|chip| chip.name()));
+
     // Setup the runtime.
     let mut runtime = Executor::with_context(program.clone(), opts, context);
     runtime.maximal_shapes = shape_config
         .map(|config| config.maximal_core_shapes().into_iter().map(|s| s.inner).collect());
-    runtime.write_vecs(&stdin.buffer);
-    for proof in stdin.proofs.iter() {
-        let (proof, vk) = proof.clone();
-        runtime.write_proof(proof, vk);
-    }
+    runtime.split_caps = caps.clone();
+    stdin.write_to(&mut runtime);
 
     #[cfg(feature = "debug")]
     let (all_records_tx, all_records_rx) = std::sync::mpsc::channel::<Vec<ExecutionRecord>>();
@@ -181,6 +206,13 @@ where
                             .execute_state(false)
                             .map_err(SP1CoreProverError::ExecutionError)?;
 
+                        // Fail before tracing the checkpoint if the machine has no chip for a
+                        // precompile called so far, rather than with unbalanced lookups when
+                        // verifying.
+                        audit
+                            .check_syscalls(runtime.state.syscall_counts.keys().copied())
+                            .map_err(SP1CoreProverError::MissingChip)?;
+
                         // Save the checkpoint to a temp file.
                         let mut checkpoint_file =
                             tempfile::tempfile().map_err(SP1CoreProverError::IoError)?;
@@ -191,6 +223,13 @@ where
                         // Send the checkpoint.
                         checkpoints_tx.send((index, checkpoint_file, done)).unwrap();
 
+                        // Stop executing if a memory cap has been exceeded by this or any of the
+                        // downstream stages.
+                        memory
+                            .record(ProvingStage::Execution)
+                            .and_then(|()| memory.check())
+                            .map_err(SP1CoreProverError::MemoryCapExceeded)?;
+
                         // If we've reached the final checkpoint, break out of the loop.
                         if done {
                             break Ok(runtime.state.public_values_stream);
@@ -213,6 +252,7 @@ where
         let checkpoints_rx = Arc::new(Mutex::new(checkpoints_rx));
 
         let checkpoints = Arc::new(Mutex::new(VecDeque::new()));
+        let report_aggregate = Arc::new(Mutex::new(ExecutionReport::default()));
         let state = Arc::new(Mutex::new(PublicValues::<u32, u32>::default().reset()));
         let deferred = Arc::new(Mutex::new(ExecutionRecord::new(program.clone().into())));
         let mut p1_record_and_trace_gen_handles = Vec::new();
@@ -223,6 +263,7 @@ where
             let records_and_traces_tx = Arc::clone(&p1_records_and_traces_tx);
 
             let checkpoints = Arc::clone(&checkpoints);
+            let report_aggregate = Arc::clone(&report_aggregate);
             let state = Arc::clone(&state);
             let deferred = Arc::clone(&deferred);
             let program = program.clone();
@@ -236,77 +277,51 @@ where
                         // Receive the latest checkpoint.
                         let received = { checkpoints_rx.lock().unwrap().recv() };
 
-                        if let Ok((index, mut checkpoint, done)) = received {
+                        if let Ok((index, checkpoint, done)) = received {
                             // Trace the checkpoint and reconstruct the execution records.
-                            let (mut records, _) = tracing::debug_span!("trace checkpoint")
+                            let (mut records, report) = tracing::debug_span!("trace checkpoint")
                                 .in_scope(|| {
                                     trace_checkpoint::<SC>(
                                         program.clone(),
                                         &checkpoint,
                                         opts,
                                         shape_config,
+                                        event_caps,
                                     )
                                 });
                             tracing::debug!("generated {} records", records.len());
-                            reset_seek(&mut checkpoint);
+                            *report_aggregate.lock().unwrap() += report;
 
                             // Wait for our turn to update the state.
                             tracing::debug!("waiting for turn {}", index);
                             record_gen_sync.wait_for_turn(index);
 
-                            // Update the public values & prover state for the shards which contain
-                            // "cpu events".
+                            // Update the public values & prover state, and commit to the deferred
+                            // shards that are ready.
                             let mut state = state.lock().unwrap();
-                            for record in records.iter_mut() {
-                                state.shard += 1;
-                                state.execution_shard = record.public_values.execution_shard;
-                                state.start_pc = record.public_values.start_pc;
-                                state.next_pc = record.public_values.next_pc;
-                                state.committed_value_digest =
-                                    record.public_values.committed_value_digest;
-                                state.deferred_proofs_digest =
-                                    record.public_values.deferred_proofs_digest;
-                                record.public_values = *state;
-                            }
-
-                            // Defer events that are too expensive to include in every shard.
                             let mut deferred = deferred.lock().unwrap();
-                            for record in records.iter_mut() {
-                                deferred.append(&mut record.defer());
-                            }
-
-                            // See if any deferred shards are ready to be committed to.
-                            let mut deferred = deferred.split(done, opts.split_opts);
-                            tracing::debug!("deferred {} records", deferred.len());
-
-                            // Update the public values & prover state for the shards which do not
-                            // contain "cpu events" before committing to them.
-                            if !done {
-                                state.execution_shard += 1;
-                            }
-                            for record in deferred.iter_mut() {
-                                state.shard += 1;
-                                state.previous_init_addr_bits =
-                                    record.public_values.previous_init_addr_bits;
-                                state.last_init_addr_bits =
-                                    record.public_values.last_init_addr_bits;
-                                state.previous_finalize_addr_bits =
-                                    record.public_values.previous_finalize_addr_bits;
-                                state.last_finalize_addr_bits =
-                                    record.public_values.last_finalize_addr_bits;
-                                state.start_pc = state.next_pc;
-                                record.public_values = *state;
-                            }
-                            records.append(&mut deferred);
-
-                            // Collect the checkpoints to be used again in the phase 2 prover.
-                            tracing::debug!("collecting checkpoints");
-                            let mut checkpoints = checkpoints.lock().unwrap();
-                            checkpoints.push_back((index, checkpoint, done));
+                            finalize_checkpoint_records(
+                                &mut records,
+                                &mut state,
+                                &mut deferred,
+                                done,
+                                opts,
+                                event_caps,
+                            );
 
                             // Let another worker update the state.
                             record_gen_sync.advance_turn();
 
+                            // Save the finalized records to be loaded again by the phase 2
+                            // prover, rather than re-executing the checkpoint.
+                            tracing::debug_span!("save records", index).in_scope(|| {
+                                let mut records_file =
+                                    tempfile::tempfile().expect("failed to create tempfile");
+                                ExecutionRecord::save_all(&records, &mut records_file)
+                                    .expect("failed to save records");
+                                checkpoints.lock().unwrap().push_back((index, records_file));
+                            });
+
                             // Fix the shape of the records.
                             if let Some(shape_config) = shape_config {
                                 for record in records.iter_mut() {
@@ -326,6 +341,10 @@ where
                                     .collect::<Vec<_>>();
                             });
 
+                            // A cap violation stops the checkpoint generator, which drains the
+                            // pipeline.
+                            let _ = memory.record(ProvingStage::TraceGeneration);
+
                             // Wait for our turn.
                             trace_gen_sync.wait_for_turn(index);
 
@@ -396,6 +415,7 @@ where
                                 }
 
                                 let data = prover.commit(&record, traces);
+                                let _ = memory.record(ProvingStage::Commit);
                                 let phase1_main_commit = data.main_commit.clone();
                                 drop(data);
                                 phase1_main_commit
@@ -416,13 +436,14 @@ where
         });
 
         // Wait until the checkpoint generator handle has fully finished.
-        let public_values_stream = checkpoint_generator_handle.join().unwrap().unwrap();
+        let public_values_stream = checkpoint_generator_handle.join().unwrap()?;
 
         // Wait until the records and traces have been fully generated.
         p1_record_and_trace_gen_handles.into_iter().for_each(|handle| handle.join().unwrap());
 
         // Wait until the phase 1 prover has completely finished.
         let mut challenger = phase_1_prover_handle.join().unwrap();
+        memory.check().map_err(SP1CoreProverError::MemoryCapExceeded)?;
 
         // Sample for the global permutation challenges.
         // Obtain the challenges used for the global permutation argument.
@@ -431,8 +452,10 @@ where
             global_permutation_challenges.push(challenger.sample_ext_element());
         }
 
+        // The records of the checkpoints were saved as they were finalized, so restore their order.
+        checkpoints.lock().unwrap().make_contiguous().sort_unstable_by_key(|(index, _)| *index);
+
         // Spawn the phase 2 record generator thread.
-        let p2_record_gen_sync = Arc::new(TurnBasedSync::new());
         let p2_trace_gen_sync = Arc::new(TurnBasedSync::new());
         let (p2_records_and_traces_tx, p2_records_and_traces_rx) =
             sync_channel::<(
@@ -444,20 +467,14 @@ where
             )>(opts.records_and_traces_channel_capacity);
         let p2_records_and_traces_tx = Arc::new(Mutex::new(p2_records_and_traces_tx));
 
-        let report_aggregate = Arc::new(Mutex::new(ExecutionReport::default()));
-        let state = Arc::new(Mutex::new(PublicValues::<u32, u32>::default().reset()));
-        let deferred = Arc::new(Mutex::new(ExecutionRecord::new(program.clone().into())));
+        let program = Arc::new(program.clone());
         let mut p2_record_and_trace_gen_handles = Vec::new();
         for _ in 0..opts.trace_gen_workers {
-            let record_gen_sync = Arc::clone(&p2_record_gen_sync);
             let trace_gen_sync = Arc::clone(&p2_trace_gen_sync);
             let records_and_traces_tx = Arc::clone(&p2_records_and_traces_tx);
 
-            let report_aggregate = Arc::clone(&report_aggregate);
             let checkpoints = Arc::clone(&checkpoints);
-            let state = Arc::clone(&state);
-            let deferred = Arc::clone(&deferred);
-            let program = program.clone();
+            let program = Arc::clone(&program);
 
             let span = tracing::Span::current().clone();
 
@@ -470,77 +487,19 @@ where
                     loop {
                         // Receive the latest checkpoint.
                         let received = { checkpoints.lock().unwrap().pop_front() };
-                        if let Some((index, mut checkpoint, done)) = received {
-                            // Trace the checkpoint and reconstruct the execution records.
-                            let (mut records, report) = tracing::debug_span!("trace checkpoint")
-                                .in_scope(|| {
-                                    trace_checkpoint::<SC>(
-                                        program.clone(),
-                                        &checkpoint,
-                                        opts,
-                                        shape_config,
-                                    )
-                                });
-                            log::debug!("generated {} records", records.len());
-                            *report_aggregate.lock().unwrap() += report;
-                            reset_seek(&mut checkpoint);
-
-                            // Wait for our turn to update the state.
-                            record_gen_sync.wait_for_turn(index);
-
-                            // Update the public values & prover state for the shards which contain
-                            // "cpu events".
-                            let mut state = state.lock().unwrap();
-                            for record in records.iter_mut() {
-                                state.shard += 1;
-                                state.execution_shard = record.public_values.execution_shard;
-                                state.start_pc = record.public_values.start_pc;
-                                state.next_pc = record.public_values.next_pc;
-                                state.committed_value_digest =
-                                    record.public_values.committed_value_digest;
-                                state.deferred_proofs_digest =
-                                    record.public_values.deferred_proofs_digest;
-                                record.public_values = *state;
-                            }
-
-                            // Defer events that are too expensive to include in every shard.
-                            let mut deferred = deferred.lock().unwrap();
-                            for record in records.iter_mut() {
-                                deferred.append(&mut record.defer());
-                            }
-
-                            // See if any deferred shards are ready to be committed to.
-                            let mut deferred = deferred.split(done, opts.split_opts);
-                            log::debug!("deferred {} records", deferred.len());
-
-                            // Update the public values & prover state for the shards which do not
-                            // contain "cpu events" before committing to them.
-                            if !done {
-                                state.execution_shard += 1;
-                            }
-                            for record in deferred.iter_mut() {
-                                state.shard += 1;
-                                state.previous_init_addr_bits =
-                                    record.public_values.previous_init_addr_bits;
-                                state.last_init_addr_bits =
-                                    record.public_values.last_init_addr_bits;
-                                state.previous_finalize_addr_bits =
-                                    record.public_values.previous_finalize_addr_bits;
-                                state.last_finalize_addr_bits =
-                                    record.public_values.last_finalize_addr_bits;
-                                state.start_pc = state.next_pc;
-                                record.public_values = *state;
-                            }
-                            records.append(&mut deferred);
+                        if let Some((index, records_file)) = received {
+                            // Load the records finalized by the phase 1 prover.
+                            let mut records = tracing::debug_span!("load records", index)
+                                .in_scope(|| ExecutionRecord::load_all(&records_file, &program))
+                                .expect("failed to load records");
+                            drop(records_file);
+                            log::debug!("loaded {} records", records.len());
 
                             // Generate the dependencies.
                             tracing::debug_span!("generate dependencies", index).in_scope(|| {
                                 prover.machine().generate_dependencies(&mut records, &opts, None);
                             });
 
-                            // Let another worker update the state.
-                            record_gen_sync.advance_turn();
-
                             // Fix the shape of the records.
                             if let Some(shape_config) = shape_config {
                                 for record in records.iter_mut() {
@@ -572,6 +531,12 @@ where
                                     .collect::<Vec<_>>();
                             });
 
+                            // On a cap violation, drop the remaining checkpoints so that the
+                            // pipeline drains.
+                            if memory.record(ProvingStage::TraceGeneration).is_err() {
+                                checkpoints.lock().unwrap().clear();
+                            }
+
                             trace_gen_sync.wait_for_turn(index);
 
                             // Send the records to the phase 2 prover.
@@ -607,6 +572,7 @@ where
 
         // Spawn the phase 2 prover thread.
         let p2_prover_span = tracing::Span::current().clone();
+        let p2_checkpoints = Arc::clone(&checkpoints);
         let p2_prover_handle = s.spawn(move || {
             let _span = p2_prover_span.enter();
             let mut shard_proofs = Vec::new();
@@ -627,6 +593,9 @@ where
                                         tracing::debug_span!("commit to local traces").entered();
                                     let local_data = prover.commit(&record, local_traces);
                                     local_commit_span.exit();
+                                    if memory.record(ProvingStage::Commit).is_err() {
+                                        p2_checkpoints.lock().unwrap().clear();
+                                    }
 
                                     let opening_span = tracing::debug_span!("opening").entered();
                                     let proof = prover
@@ -639,6 +608,9 @@ where
                                         )
                                         .unwrap();
                                     opening_span.exit();
+                                    if memory.record(ProvingStage::Open).is_err() {
+                                        p2_checkpoints.lock().unwrap().clear();
+                                    }
 
                                     #[cfg(debug_assertions)]
                                     {
@@ -664,6 +636,8 @@ where
 
         // Wait until the phase 2 prover has finished.
         let shard_proofs = p2_prover_handle.join().unwrap();
+        memory.check().map_err(SP1CoreProverError::MemoryCapExceeded)?;
+        memory.report();
 
         // Log some of the `ExecutionReport` information.
         let report_aggregate = report_aggregate.lock().unwrap();
@@ -696,6 +670,13 @@ where
             }
         }
 
+        tracing::info!("execution report (syscall cycles):");
+        let breakdown = report_aggregate.syscall_breakdown();
+        let width = breakdown.first().map_or(0, |report| report.cycles.to_string().len());
+        for report in &breakdown {
+            tracing::info!("  {:>width$} {report}", report.cycles);
+        }
+
         let proof = MachineProof::<SC> { shard_proofs };
         let cycles = report_aggregate.total_instruction_count();
 
@@ -722,6 +703,237 @@ where
     })
 }
 
+/// Executes a program and generates its execution records, re-executing the checkpoints of the
+/// execution on `opts.trace_gen_workers` threads.
+///
+/// The checkpoints are generated serially, and the records of every checkpoint are generated as
+/// soon as it is available, concurrently with those of the other checkpoints. The returned records
+/// are finalized, with their public values assigned, their dependencies generated and their shapes
+/// fixed, so they can be handed to [`observe_records`] and [`prove_from_records`]. The public values
+/// stream and the number of cycles of the execution are returned along with them.
+pub fn generate_records<SC: StarkGenericConfig>(
+    machine: &StarkMachine<SC, RiscvAir<SC::Val>>,
+    program: Program,
+    stdin: &SP1Stdin,
+    opts: SP1CoreOpts,
+    context: SP1Context,
+    shape_config: Option<&CoreShapeConfig<SC::Val>>,
+) -> Result<(Vec<ExecutionRecord>, Vec<u8>, u64), SP1CoreProverError>
+where
+    SC::Val: PrimeField32,
+{
+    let caps = TraceAreaCaps::from_env();
+    let event_caps = &caps.event_caps(shape_config);
+    let mut opts = opts;
+    if let Some(cap) = caps.memory_event_cap(shape_config) {
+        opts.split_opts.memory = opts.split_opts.memory.min(cap);
+    }
+    if shape_config.is_some() {
+        opts.split_opts.deferral = DeferralPolicy::default();
+    }
+
+    // Setup the runtime.
+    let mut runtime = Executor::with_context(program.clone(), opts, context);
+    runtime.maximal_shapes = shape_config
+        .map(|config| config.maximal_core_shapes().into_iter().map(|s| s.inner).collect());
+    runtime.split_caps = caps.clone();
+    stdin.write_to(&mut runtime);
+
+    std::thread::scope(move |s| {
+        // Spawn the checkpoint generator thread.
+        let (checkpoints_tx, checkpoints_rx) =
+            sync_channel::<(usize, File, bool)>(opts.checkpoints_channel_capacity);
+        let checkpoint_generator_handle: ScopedJoinHandle<Result<_, SP1CoreProverError>> =
+            s.spawn(move || {
+                tracing::debug_span!("checkpoint generator").in_scope(|| {
+                    let mut index = 0;
+                    loop {
+                        // Execute the runtime until we reach a checkpoint.
+                        let (checkpoint, done) = runtime
+                            .execute_state(false)
+                            .map_err(SP1CoreProverError::ExecutionError)?;
+
+                        // Save the checkpoint to a temp file, and send it.
+                        let mut checkpoint_file =
+                            tempfile::tempfile().map_err(SP1CoreProverError::IoError)?;
+                        checkpoint
+                            .save(&mut checkpoint_file)
+                            .map_err(SP1CoreProverError::IoError)?;
+                        checkpoints_tx.send((index, checkpoint_file, done)).unwrap();
+
+                        if done {
+                            break Ok((
+                                runtime.state.public_values_stream,
+                                runtime.state.global_clk,
+                            ));
+                        }
+                        index += 1;
+                    }
+                })
+            });
+
+        // Spawn the workers re-executing the checkpoints.
+        let record_gen_sync = Arc::new(TurnBasedSync::new());
+        let checkpoints_rx = Arc::new(Mutex::new(checkpoints_rx));
+        let state = Arc::new(Mutex::new(PublicValues::<u32, u32>::default().reset()));
+        let deferred = Arc::new(Mutex::new(ExecutionRecord::new(program.clone().into())));
+        let generated = Arc::new(Mutex::new(Vec::new()));
+        let mut record_gen_handles = Vec::new();
+        for _ in 0..opts.trace_gen_workers.max(1) {
+            let record_gen_sync = Arc::clone(&record_gen_sync);
+            let checkpoints_rx = Arc::clone(&checkpoints_rx);
+            let state = Arc::clone(&state);
+            let deferred = Arc::clone(&deferred);
+            let generated = Arc::clone(&generated);
+            let program = program.clone();
+
+            let handle = s.spawn(move || {
+                tracing::debug_span!("record generation").in_scope(|| loop {
+                    // Receive the latest checkpoint.
+                    let received = { checkpoints_rx.lock().unwrap().recv() };
+                    let Ok((index, checkpoint, done)) = received else {
+                        break;
+                    };
+
+                    // Trace the checkpoint and reconstruct the execution records.
+                    let (mut records, _) =
+                        tracing::debug_span!("trace checkpoint").in_scope(|| {
+                            trace_checkpoint::<SC>(
+                                program.clone(),
+                                &checkpoint,
+                                opts,
+                                shape_config,
+                                event_caps,
+                            )
+                        });
+
+                    // The public values carry over from one checkpoint to the next, so they are
+                    // assigned in the order of the checkpoints.
+                    record_gen_sync.wait_for_turn(index);
+                    finalize_checkpoint_records(
+                        &mut records,
+                        &mut state.lock().unwrap(),
+                        &mut deferred.lock().unwrap(),
+                        done,
+                        opts,
+                        event_caps,
+                    );
+                    machine.generate_dependencies(&mut records, &opts, None);
+                    record_gen_sync.advance_turn();
+
+                    // Fix the shape of the records.
+                    if let Some(shape_config) = shape_config {
+                        for record in records.iter_mut() {
+                            shape_config.fix_shape(record).unwrap();
+                        }
+                    }
+                    generated.lock().unwrap().push((index, records));
+                })
+            });
+            record_gen_handles.push(handle);
+        }
+
+        let result = checkpoint_generator_handle.join().unwrap();
+        for handle in record_gen_handles {
+            handle.join().unwrap();
+        }
+        let (public_values_stream, cycles) = result?;
+
+        let mut generated = std::mem::take(&mut *generated.lock().unwrap());
+        generated.sort_by_key(|(index, _)| *index);
+        let records = generated.into_iter().flat_map(|(_, records)| records).collect();
+        Ok((records, public_values_stream, cycles))
+    })
+}
+
+/// Computes the challenger state that a proving worker needs in order to prove a set of finalized
+/// execution records with [`prove_from_records`].
+///
+/// The returned challenger has observed the preprocessed commitment, along with the global main
+/// commitment and public values of every record, in order. The records must be the complete set
+/// of shards for the execution, with public values assigned, dependencies generated and shapes
+/// fixed.
+pub fn observe_records<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
+    prover: &P,
+    pk: &P::DeviceProvingKey,
+    records: &[ExecutionRecord],
+) -> SC::Challenger
+where
+    SC::Val: PrimeField32,
+    Com<SC>: Send + Sync,
+    PcsProverData<SC>: Send + Sync,
+{
+    let mut challenger = prover.config().challenger();
+    pk.observe_into(&mut challenger);
+
+    let commitments = tracing::debug_span!("commit to global traces").in_scope(|| {
+        records
+            .par_iter()
+            .map(|record| {
+                let traces = prover.generate_traces(record, InteractionScope::Global);
+                prover.commit(record, traces).main_commit
+            })
+            .collect::<Vec<_>>()
+    });
+
+    for (commit, record) in commitments.into_iter().zip(records.iter()) {
+        let public_values =
+            record.public_values::<SC::Val>()[0..prover.machine().num_pv_elts()].to_vec();
+        prover.observe(&mut challenger, commit, &public_values);
+    }
+
+    challenger
+}
+
+/// Proves a set of finalized execution records, starting from a challenger state produced by
+/// [`observe_records`].
+///
+/// This allows execution and record splitting to happen on a separate machine (or an earlier
+/// pipeline stage) and the serialized records to be handed off to dedicated proving workers. The
+/// records may be any subset of the shards used to compute `challenger_state`, and the resulting
+/// shard proofs are returned in the same order as the records.
+pub fn prove_from_records<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
+    prover: &P,
+    pk: &P::DeviceProvingKey,
+    records: Vec<ExecutionRecord>,
+    challenger_state: &SC::Challenger,
+) -> Result<MachineProof<SC>, P::Error>
+where
+    SC::Val: PrimeField32,
+    SC::Challenger: Clone,
+    OpeningProof<SC>: Send,
+    Com<SC>: Send + Sync,
+    PcsProverData<SC>: Send + Sync,
+{
+    // Obtain the challenges used for the global permutation argument.
+    let mut challenger = challenger_state.clone();
+    let mut global_permutation_challenges: Vec<SC::Challenge> = Vec::new();
+    for _ in 0..2 {
+        global_permutation_challenges.push(challenger.sample_ext_element());
+    }
+
+    let shard_proofs = tracing::info_span!("prove_shards").in_scope(|| {
+        records
+            .into_par_iter()
+            .map(|record| {
+                let global_traces = prover.generate_traces(&record, InteractionScope::Global);
+                let local_traces = prover.generate_traces(&record, InteractionScope::Local);
+                let global_data = prover.commit(&record, global_traces);
+                let local_data = prover.commit(&record, local_traces);
+                prover.open(
+                    pk,
+                    Some(global_data),
+                    local_data,
+                    &mut challenger.clone(),
+                    &global_permutation_challenges,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    Ok(MachineProof { shard_proofs })
+}
+
 /// Runs a program and returns the public values stream.
 pub fn run_test_io<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
     mut program: Program,
@@ -733,7 +945,7 @@ pub fn run_test_io<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
         let mut runtime = Executor::new(program, SP1CoreOpts::default());
         runtime.maximal_shapes =
             Some(shape_config.maximal_core_shapes().into_iter().map(|s| s.inner).collect());
-        runtime.write_vecs(&inputs.buffer);
+        inputs.write_to(&mut runtime);
         runtime.run().unwrap();
         runtime
     });
@@ -777,6 +989,7 @@ pub fn run_test_core<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
         SP1CoreOpts::default(),
         SP1Context::default(),
         shape_config,
+        &MemoryWatermarkTracker::new(MemoryCaps::default()),
     )
     .unwrap();
 
@@ -854,21 +1067,73 @@ where
     run_test_machine_with_prover::<SC, A, CpuProver<_, _>>(&prover, records, pk, vk)
 }
 
+/// Assigns the public values of the records traced from a checkpoint, and appends the shards of
+/// deferred events that are ready to be committed to.
+///
+/// The public values carry over from one checkpoint to the next, so this must be called on the
+/// checkpoints in order.
+fn finalize_checkpoint_records(
+    records: &mut Vec<ExecutionRecord>,
+    state: &mut PublicValues<u32, u32>,
+    deferred: &mut ExecutionRecord,
+    done: bool,
+    opts: SP1CoreOpts,
+    event_caps: &HashMap<SyscallCode, usize>,
+) {
+    // Update the public values & prover state for the shards which contain "cpu events".
+    for record in records.iter_mut() {
+        state.shard += 1;
+        state.execution_shard = record.public_values.execution_shard;
+        state.start_pc = record.public_values.start_pc;
+        state.next_pc = record.public_values.next_pc;
+        state.committed_value_digest = record.public_values.committed_value_digest;
+        state.deferred_proofs_digest = record.public_values.deferred_proofs_digest;
+        state.exit_code = record.public_values.exit_code;
+        record.public_values = *state;
+    }
+
+    // Defer events that are too expensive to include in every shard.
+    for record in records.iter_mut() {
+        deferred.append(&mut record.defer(opts.split_opts));
+    }
+
+    // See if any deferred shards are ready to be committed to.
+    let mut deferred = deferred.split(done, opts.split_opts, event_caps);
+    tracing::debug!("deferred {} records", deferred.len());
+
+    // Update the public values & prover state for the shards which do not contain "cpu events"
+    // before committing to them.
+    if !done {
+        state.execution_shard += 1;
+    }
+    for record in deferred.iter_mut() {
+        state.shard += 1;
+        state.previous_init_addr_bits = record.public_values.previous_init_addr_bits;
+        state.last_init_addr_bits = record.public_values.last_init_addr_bits;
+        state.previous_finalize_addr_bits = record.public_values.previous_finalize_addr_bits;
+        state.last_finalize_addr_bits = record.public_values.last_finalize_addr_bits;
+        state.start_pc = state.next_pc;
+        state.exit_code = 0;
+        record.public_values = *state;
+    }
+    records.append(&mut deferred);
+}
+
 fn trace_checkpoint<SC: StarkGenericConfig>(
     program: Program,
     file: &File,
     opts: SP1CoreOpts,
     shape_config: Option<&CoreShapeConfig<SC::Val>>,
+    split_caps: &HashMap<SyscallCode, usize>,
 ) -> (Vec<ExecutionRecord>, ExecutionReport)
 where
     <SC as StarkGenericConfig>::Val: PrimeField32,
 {
-    let mut reader = std::io::BufReader::new(file);
-    let state: ExecutionState =
-        bincode::deserialize_from(&mut reader).expect("failed to deserialize state");
+    let state = ExecutionState::load(file).expect("failed to load state");
     let mut runtime = Executor::recover(program, state, opts);
     runtime.maximal_shapes = shape_config
         .map(|config| config.maximal_core_shapes().into_iter().map(|s| s.inner).collect());
+    runtime.split_caps = split_caps.clone();
 
     // We already passed the deferred proof verifier when creating checkpoints, so the proofs were
     // already verified. So here we use a noop verifier to not print any warnings.
@@ -880,10 +1145,6 @@ where
     (records, runtime.report)
 }
 
-fn reset_seek(file: &mut File) {
-    file.seek(std::io::SeekFrom::Start(0)).expect("failed to seek to start of tempfile");
-}
-
 #[cfg(debug_assertions)]
 #[cfg(not(doctest))]
 pub fn uni_stark_prove<SC, A>(
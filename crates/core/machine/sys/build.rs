@@ -0,0 +1,61 @@
+use std::{env, fs, path::PathBuf};
+
+/// The Rust sources defining the items exported to C++, relative to the manifest directory.
+///
+/// The sources are parsed on their own rather than as part of their crates, which would make this
+/// crate depend on `sp1-core-machine` and the other way around.
+const BINDINGS_SOURCES: &[&str] = &[
+    "../../../stark/src/word.rs",
+    "../../executor/src/events/memory.rs",
+    "../src/memory/columns.rs",
+    "../src/sys.rs",
+    "../src/syscall/precompiles/bn254/mul_add_uint256.rs",
+    "../src/syscall/precompiles/bn254_scalar/mul_add.rs",
+    "../src/syscall/precompiles/memcpy.rs",
+];
+
+/// The name of the header generated by cbindgen.
+const BINDINGS_HEADER: &str = "sp1-core-machine-sys-cbindgen.hpp";
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let include_dir = manifest_dir.join("include");
+    let out_include_dir = out_dir.join("include");
+    fs::create_dir_all(&out_include_dir).unwrap();
+
+    // Generate the C++ definitions of the allowlisted items.
+    let config = cbindgen::Config::from_file(manifest_dir.join("cbindgen.toml")).unwrap();
+    let mut builder = cbindgen::Builder::new().with_config(config);
+    for source in BINDINGS_SOURCES {
+        let path = manifest_dir.join(source);
+        println!("cargo:rerun-if-changed={}", path.display());
+        builder = builder.with_src(path);
+    }
+    builder
+        .generate()
+        .expect("Unable to generate the C++ bindings")
+        .write_to_file(out_include_dir.join(BINDINGS_HEADER));
+
+    // Compile the C++ row generation functions.
+    let mut sources = fs::read_dir(manifest_dir.join("lib"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "cpp"))
+        .collect::<Vec<_>>();
+    sources.sort();
+    cc::Build::new()
+        .cpp(true)
+        .std("c++17")
+        .include(&include_dir)
+        .include(&out_include_dir)
+        .files(&sources)
+        .compile("sp1-core-machine-sys");
+
+    // Export the headers to the build scripts of the dependent crates, e.g. for CUDA kernels.
+    let include_dirs = env::join_paths([&include_dir, &out_include_dir]).unwrap();
+    println!("cargo:include={}", include_dirs.to_str().unwrap());
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-changed=include");
+    println!("cargo:rerun-if-changed=lib");
+}
@@ -0,0 +1,42 @@
+#pragma once
+
+#include "config.hpp"
+
+namespace sp1_core_machine_sys {
+
+/// A BabyBear element in the representation of the `BabyBear` of Plonky3, which keeps elements in
+/// Montgomery form `x * 2^32 mod p`.
+struct BabyBearP3 {
+    static constexpr uint32_t MOD = 0x78000001;
+
+    uint32_t val;
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ static constexpr BabyBearP3 zero() {
+        return BabyBearP3 {0};
+    }
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ static constexpr BabyBearP3 one() {
+        return from_canonical_u32(1);
+    }
+
+    /// The element `x`, for `x < p`.
+    __SP1_HOSTDEV__ __SP1_INLINE__ static constexpr BabyBearP3 from_canonical_u32(uint32_t x) {
+        return BabyBearP3 {static_cast<uint32_t>((static_cast<uint64_t>(x) << 32) % MOD)};
+    }
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ static constexpr BabyBearP3 from_canonical_u8(uint8_t x) {
+        return from_canonical_u32(x);
+    }
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ static constexpr BabyBearP3 from_canonical_u16(uint16_t x) {
+        return from_canonical_u32(x);
+    }
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ static constexpr BabyBearP3 from_bool(bool x) {
+        return x ? one() : zero();
+    }
+};
+
+static_assert(sizeof(BabyBearP3) == sizeof(uint32_t));
+
+}  // namespace sp1_core_machine_sys
@@ -0,0 +1,69 @@
+#pragma once
+
+#include "field_op.hpp"
+#include "memory.hpp"
+#include "prelude.hpp"
+
+namespace sp1_core_machine_sys::bn254 {
+
+/// Reverses the bytes of a word, as `u32::swap_bytes`.
+__SP1_HOSTDEV__ __SP1_INLINE__ uint32_t swap_bytes(uint32_t word) {
+    return (word >> 24) | ((word >> 8) & 0xff00) | ((word << 8) & 0xff0000) | (word << 24);
+}
+
+/// Populates the row of a `BN254_MULADD` or `BN254_MULADD_BE` event, as
+/// `Bn254MulAddChip::generate_trace`.
+///
+/// The row is expected to be zeroed, and its nonce is left to the caller.
+template<class F>
+__SP1_HOSTDEV__ __SP1_INLINE__ void
+mul_add_event_to_row(const Bn254MulAddEventFFI& event, Bn254MulAddCols<F>& cols) {
+    constexpr size_t NUM_WORDS = WordsFieldElement::USIZE;
+
+    // Decode the operands as little endian words, reversing the bytes of big-endian operands.
+    uint32_t x[NUM_WORDS];
+    uint32_t a[NUM_WORDS];
+    uint32_t b[NUM_WORDS];
+    for (size_t i = 0; i < NUM_WORDS; i++) {
+        const size_t j = event.big_endian ? NUM_WORDS - 1 - i : i;
+        x[i] = event.x_memory_records[j].prev_value;
+        a[i] = event.a_memory_records[j].value;
+        b[i] = event.b_memory_records[j].value;
+        if (event.big_endian) {
+            x[i] = swap_bytes(x[i]);
+            a[i] = swap_bytes(a[i]);
+            b[i] = swap_bytes(b[i]);
+        }
+    }
+
+    cols.is_real = F::one();
+    cols.shard = F::from_canonical_u32(event.shard);
+    cols.clk = F::from_canonical_u32(event.clk);
+    cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+    cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+    cols.is_big_endian = F::from_bool(event.big_endian);
+    for (size_t i = 0; i < Bn254ScalarField::NB_LIMBS; i++) {
+        cols.x[i] = F::from_canonical_u8(field_op::byte(x, i));
+        cols.a[i] = F::from_canonical_u8(field_op::byte(a, i));
+        cols.b[i] = F::from_canonical_u8(field_op::byte(b, i));
+    }
+
+    for (size_t i = 0; i < NUM_WORDS; i++) {
+        memory::populate_write(cols.x_memory[i], event.x_memory_records[i]);
+        memory::populate_read(cols.a_memory[i], event.a_memory_records[i]);
+        memory::populate_read(cols.b_memory[i], event.b_memory_records[i]);
+    }
+
+    // The reduced product, which is looked up from the Bn254MulMemo chip.
+    uint32_t mul_result[NUM_WORDS];
+    uint32_t mul_carry[2 * NUM_WORDS];
+    field_op::apply_mod<Bn254ScalarField>(a, b, field_op::FieldOperation::Mul, mul_result, mul_carry);
+    for (size_t i = 0; i < Bn254ScalarField::NB_LIMBS; i++) {
+        cols.a_mul_b[i] = F::from_canonical_u8(field_op::byte(mul_result, i));
+    }
+
+    uint32_t result[NUM_WORDS];
+    field_op::populate(cols.add_eval, x, mul_result, field_op::FieldOperation::Add, result);
+}
+
+}  // namespace sp1_core_machine_sys::bn254
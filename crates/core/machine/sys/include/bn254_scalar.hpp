@@ -0,0 +1,44 @@
+#pragma once
+
+#include "field_op.hpp"
+#include "memory.hpp"
+#include "prelude.hpp"
+
+namespace sp1_core_machine_sys::bn254_scalar {
+
+/// Populates the row of a `BN254_SCALAR_MULADD` event, as `Bn254ScalarMulAddChip::generate_trace`.
+///
+/// The row is expected to be zeroed, and its nonce is left to the caller.
+template<class F>
+__SP1_HOSTDEV__ __SP1_INLINE__ void
+mul_add_event_to_row(const Bn254ScalarMulAddEventFFI& event, Bn254ScalarMulAddCols<F>& cols) {
+    uint32_t x[NUM_WORDS_PER_FE];
+    uint32_t a[NUM_WORDS_PER_FE];
+    uint32_t b[NUM_WORDS_PER_FE];
+    for (size_t i = 0; i < NUM_WORDS_PER_FE; i++) {
+        x[i] = event.x_memory_records[i].prev_value;
+        a[i] = event.a_memory_records[i].value;
+        b[i] = event.b_memory_records[i].value;
+    }
+
+    cols.is_real = F::one();
+    cols.shard = F::from_canonical_u32(event.shard);
+    cols.clk = F::from_canonical_u32(event.clk);
+    cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+    cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+    uint32_t mul_result[NUM_WORDS_PER_FE];
+    field_op::populate(cols.a_mul_b, a, b, field_op::FieldOperation::Mul, mul_result);
+    uint32_t add_result[NUM_WORDS_PER_FE];
+    field_op::populate(cols.add_eval, x, mul_result, field_op::FieldOperation::Add, add_result);
+
+    memory::populate_read(cols.a_ptr_memory, event.y_memory_records[0]);
+    memory::populate_read(cols.b_ptr_memory, event.y_memory_records[1]);
+    for (size_t i = 0; i < NUM_WORDS_PER_FE; i++) {
+        memory::populate_write(cols.x_memory[i], event.x_memory_records[i]);
+        memory::populate_read(cols.a_memory[i], event.a_memory_records[i]);
+        memory::populate_read(cols.b_memory[i], event.b_memory_records[i]);
+    }
+}
+
+}  // namespace sp1_core_machine_sys::bn254_scalar
@@ -0,0 +1,14 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+
+// The functions of the headers are compiled for the host by the `lib` of this crate, and for the
+// device by the CUDA kernels including them.
+#if defined(__CUDACC__)
+#define __SP1_HOSTDEV__ __host__ __device__
+#else
+#define __SP1_HOSTDEV__
+#endif
+
+#define __SP1_INLINE__ inline
@@ -0,0 +1,171 @@
+#pragma once
+
+#include "prelude.hpp"
+
+namespace sp1_core_machine_sys::field_op {
+
+/// The operations supported by `populate`.
+enum class FieldOperation {
+    Add,
+    Mul,
+};
+
+/// The byte `i` of a little endian integer given by its 32-bit words.
+__SP1_HOSTDEV__ __SP1_INLINE__ uint8_t byte(const uint32_t* words, size_t i) {
+    return static_cast<uint8_t>(words[i / 4] >> (8 * (i % 4)));
+}
+
+/// Computes `quotient = n / m` and `remainder = n % m` for integers of `N` and `M` words by long
+/// division, bit by bit.
+template<size_t N, size_t M>
+__SP1_HOSTDEV__ __SP1_INLINE__ void
+divmod(const uint32_t (&n)[N], const uint32_t (&m)[M], uint32_t (&quotient)[N], uint32_t (&remainder)[M]) {
+    for (size_t i = 0; i < N; i++) {
+        quotient[i] = 0;
+    }
+    for (size_t i = 0; i < M; i++) {
+        remainder[i] = 0;
+    }
+
+    for (size_t bit = 32 * N; bit-- > 0;) {
+        // Shift the next bit of `n` into the remainder, keeping the bit shifted out of it.
+        uint32_t carry = (n[bit / 32] >> (bit % 32)) & 1;
+        for (size_t i = 0; i < M; i++) {
+            const uint32_t next_carry = remainder[i] >> 31;
+            remainder[i] = (remainder[i] << 1) | carry;
+            carry = next_carry;
+        }
+
+        bool geq = carry != 0;
+        if (!geq) {
+            geq = true;
+            for (size_t i = M; i-- > 0;) {
+                if (remainder[i] != m[i]) {
+                    geq = remainder[i] > m[i];
+                    break;
+                }
+            }
+        }
+
+        if (geq) {
+            uint32_t borrow = 0;
+            for (size_t i = 0; i < M; i++) {
+                const uint64_t difference =
+                    static_cast<uint64_t>(remainder[i]) - m[i] - borrow;
+                remainder[i] = static_cast<uint32_t>(difference);
+                borrow = static_cast<uint32_t>(difference >> 63);
+            }
+            quotient[bit / 32] |= 1u << (bit % 32);
+        }
+    }
+}
+
+/// Computes `a op b` for integers of `N` words.
+template<size_t N>
+__SP1_HOSTDEV__ __SP1_INLINE__ void
+apply(const uint32_t (&a)[N], const uint32_t (&b)[N], FieldOperation op, uint32_t (&a_op_b)[2 * N]) {
+    for (size_t i = 0; i < 2 * N; i++) {
+        a_op_b[i] = 0;
+    }
+
+    if (op == FieldOperation::Mul) {
+        for (size_t i = 0; i < N; i++) {
+            uint64_t carry = 0;
+            for (size_t j = 0; j < N; j++) {
+                const uint64_t product =
+                    static_cast<uint64_t>(a[i]) * b[j] + a_op_b[i + j] + carry;
+                a_op_b[i + j] = static_cast<uint32_t>(product);
+                carry = product >> 32;
+            }
+            a_op_b[i + N] = static_cast<uint32_t>(carry);
+        }
+    } else {
+        uint64_t carry = 0;
+        for (size_t i = 0; i < N; i++) {
+            const uint64_t sum = static_cast<uint64_t>(a[i]) + b[i] + carry;
+            a_op_b[i] = static_cast<uint32_t>(sum);
+            carry = sum >> 32;
+        }
+        a_op_b[N] = static_cast<uint32_t>(carry);
+    }
+}
+
+/// Computes `result = a op b mod P::MODULUS` and `carry = (a op b) / P::MODULUS`.
+template<class P>
+__SP1_HOSTDEV__ __SP1_INLINE__ void apply_mod(
+    const uint32_t (&a)[P::NB_LIMBS / 4],
+    const uint32_t (&b)[P::NB_LIMBS / 4],
+    FieldOperation op,
+    uint32_t (&result)[P::NB_LIMBS / 4],
+    uint32_t (&carry)[P::NB_LIMBS / 2]
+) {
+    constexpr size_t NB_WORDS = P::NB_LIMBS / 4;
+
+    uint32_t a_op_b[2 * NB_WORDS];
+    apply(a, b, op, a_op_b);
+
+    uint32_t modulus[NB_WORDS] = {};
+    for (size_t i = 0; i < P::NB_LIMBS; i++) {
+        modulus[i / 4] |= static_cast<uint32_t>(P::MODULUS[i]) << (8 * (i % 4));
+    }
+    divmod(a_op_b, modulus, carry, result);
+}
+
+/// Populates the columns of `result = a op b mod P::MODULUS`, writing the words of the result, as
+/// `FieldOpCols::populate`.
+///
+/// The operands are given by their little endian words. The carry `(a op b) / P::MODULUS` is
+/// assumed to fit in the limbs, as the Rust implementation asserts.
+template<class F, class P>
+__SP1_HOSTDEV__ __SP1_INLINE__ void populate(
+    FieldOpCols<F, P>& cols,
+    const uint32_t (&a)[P::NB_LIMBS / 4],
+    const uint32_t (&b)[P::NB_LIMBS / 4],
+    FieldOperation op,
+    uint32_t (&result)[P::NB_LIMBS / 4]
+) {
+    constexpr size_t NB_LIMBS = P::NB_LIMBS;
+    constexpr size_t NB_WORDS = NB_LIMBS / 4;
+    constexpr size_t NB_WITNESS_LIMBS = P::NB_WITNESS_LIMBS;
+    static_assert(NB_WITNESS_LIMBS == 2 * NB_LIMBS - 2);
+
+    uint32_t carry[2 * NB_WORDS];
+    apply_mod<P>(a, b, op, result, carry);
+
+    // Compute the vanishing polynomial `a op b - result - carry * modulus` in the limbs.
+    int64_t vanishing[2 * NB_LIMBS - 1] = {};
+    for (size_t i = 0; i < NB_LIMBS; i++) {
+        const int64_t a_limb = byte(a, i);
+        if (op == FieldOperation::Mul) {
+            for (size_t j = 0; j < NB_LIMBS; j++) {
+                vanishing[i + j] += a_limb * byte(b, j);
+            }
+        } else {
+            vanishing[i] += a_limb + byte(b, i);
+        }
+        vanishing[i] -= byte(result, i);
+        const int64_t carry_limb = byte(carry, i);
+        for (size_t j = 0; j < NB_LIMBS; j++) {
+            vanishing[i + j] -= carry_limb * P::MODULUS[j];
+        }
+    }
+
+    // Divide it by `x - 2^8`, which is a root, and shift the coefficients to make them positive.
+    int64_t witness[NB_WITNESS_LIMBS];
+    witness[NB_WITNESS_LIMBS - 1] = vanishing[NB_WITNESS_LIMBS];
+    for (size_t i = NB_WITNESS_LIMBS - 1; i > 0; i--) {
+        witness[i - 1] = vanishing[i] + 256 * witness[i];
+    }
+
+    for (size_t i = 0; i < NB_LIMBS; i++) {
+        cols.result[i] = F::from_canonical_u8(byte(result, i));
+        cols.carry[i] = F::from_canonical_u8(byte(carry, i));
+    }
+    for (size_t i = 0; i < NB_WITNESS_LIMBS; i++) {
+        const uint32_t shifted = static_cast<uint32_t>(witness[i] + P::WITNESS_OFFSET);
+        cols.witness_low[i] = F::from_canonical_u8(static_cast<uint8_t>(shifted));
+        cols.witness_high[i] = F::from_canonical_u8(static_cast<uint8_t>(shifted >> 8));
+    }
+}
+
+}  // namespace sp1_core_machine_sys::field_op
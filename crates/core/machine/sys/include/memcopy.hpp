@@ -0,0 +1,29 @@
+#pragma once
+
+#include "memory.hpp"
+#include "prelude.hpp"
+
+namespace sp1_core_machine_sys::memcopy {
+
+/// Populates the row of a `MEMCPY_32` or `MEMCPY_64` event copying `NumWords` words, as
+/// `MemCopyChip::generate_trace`.
+///
+/// The row is expected to be zeroed, and its nonce is left to the caller.
+template<class F, class NumWords>
+__SP1_HOSTDEV__ __SP1_INLINE__ void
+event_to_row(const MemCopyEventFFI& event, MemCopyCols<F, NumWords>& cols) {
+    cols.is_real = F::one();
+    cols.shard = F::from_canonical_u32(event.shard);
+    cols.clk = F::from_canonical_u32(event.clk);
+    cols.upper_clk = F::from_canonical_u32(event.upper_clk);
+    cols.is_coalesced = F::from_bool(event.is_coalesced);
+    cols.src_ptr = F::from_canonical_u32(event.src_ptr);
+    cols.dst_ptr = F::from_canonical_u32(event.dst_ptr);
+
+    for (size_t i = 0; i < NumWords::USIZE; i++) {
+        memory::populate_read(cols.src_access[i], event.read_records[i]);
+        memory::populate_write(cols.dst_access[i], event.write_records[i]);
+    }
+}
+
+}  // namespace sp1_core_machine_sys::memcopy
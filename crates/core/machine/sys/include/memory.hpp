@@ -0,0 +1,69 @@
+#pragma once
+
+#include "prelude.hpp"
+
+namespace sp1_core_machine_sys::memory {
+
+/// Populates a word with the little endian bytes of `value`, as `Word::from(value)`.
+template<class F>
+__SP1_HOSTDEV__ __SP1_INLINE__ void populate_word(Word<F>& word, uint32_t value) {
+    for (size_t i = 0; i < WORD_SIZE; i++) {
+        word._0[i] = F::from_canonical_u8(static_cast<uint8_t>(value >> (8 * i)));
+    }
+}
+
+/// Populates the columns of an access of `value` at `(shard, timestamp)`, following the access at
+/// `(prev_shard, prev_timestamp)`, as `MemoryAccessCols::populate_access`.
+template<class F>
+__SP1_HOSTDEV__ __SP1_INLINE__ void populate_access(
+    MemoryAccessCols<F>& cols,
+    uint32_t value,
+    uint32_t shard,
+    uint32_t timestamp,
+    uint32_t prev_shard,
+    uint32_t prev_timestamp
+) {
+    populate_word(cols.value, value);
+
+    cols.prev_shard = F::from_canonical_u32(prev_shard);
+    cols.prev_clk = F::from_canonical_u32(prev_timestamp);
+
+    // The accesses are ordered by clock cycle within a shard, and by shard otherwise.
+    const bool use_clk_comparison = prev_shard == shard;
+    cols.compare_clk = F::from_bool(use_clk_comparison);
+    const uint32_t prev_time_value = use_clk_comparison ? prev_timestamp : prev_shard;
+    const uint32_t current_time_value = use_clk_comparison ? timestamp : shard;
+
+    const uint32_t diff_minus_one = current_time_value - prev_time_value - 1;
+    cols.diff_16bit_limb = F::from_canonical_u16(static_cast<uint16_t>(diff_minus_one & 0xffff));
+    cols.diff_8bit_limb = F::from_canonical_u8(static_cast<uint8_t>((diff_minus_one >> 16) & 0xff));
+}
+
+/// Populates the columns of a memory read, as `MemoryReadCols::populate`.
+template<class F>
+__SP1_HOSTDEV__ __SP1_INLINE__ void populate_read(MemoryReadCols<F>& cols, const MemoryReadRecord& record) {
+    populate_access(
+        cols.access,
+        record.value,
+        record.shard,
+        record.timestamp,
+        record.prev_shard,
+        record.prev_timestamp
+    );
+}
+
+/// Populates the columns of a memory write, as `MemoryWriteCols::populate`.
+template<class F>
+__SP1_HOSTDEV__ __SP1_INLINE__ void populate_write(MemoryWriteCols<F>& cols, const MemoryWriteRecord& record) {
+    populate_word(cols.prev_value, record.prev_value);
+    populate_access(
+        cols.access,
+        record.value,
+        record.shard,
+        record.timestamp,
+        record.prev_shard,
+        record.prev_timestamp
+    );
+}
+
+}  // namespace sp1_core_machine_sys::memory
@@ -0,0 +1,5 @@
+#pragma once
+
+#include "babybear.hpp"
+#include "config.hpp"
+#include "sp1-core-machine-sys-cbindgen.hpp"
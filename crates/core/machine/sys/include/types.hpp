@@ -0,0 +1,83 @@
+#pragma once
+
+#include "config.hpp"
+
+// The layouts of the types of the columns which cbindgen cannot export, because their sizes are
+// given by `typenum` or by constants of other crates. They are included by the generated header.
+
+namespace sp1_core_machine_sys {
+
+/// The constants used as array lengths by the exported structs.
+constexpr size_t WORD_SIZE = 4;
+constexpr size_t NUM_WORDS_PER_FE = 8;
+
+/// A `typenum` unsigned integer, as used for the lengths of a `GenericArray`.
+template<size_t N>
+struct TypeNum {
+    static constexpr size_t USIZE = N;
+};
+
+using U8 = TypeNum<8>;
+using U16 = TypeNum<16>;
+using U32 = TypeNum<32>;
+using U62 = TypeNum<62>;
+
+/// The layout of a `generic_array::GenericArray<T, N>`.
+template<typename T, typename N>
+struct GenericArray {
+    T _0[N::USIZE];
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ T& operator[](size_t i) {
+        return _0[i];
+    }
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ const T& operator[](size_t i) const {
+        return _0[i];
+    }
+};
+
+/// The layout of a `sp1_curves::params::Limbs<T, N>`.
+template<typename T, typename N>
+struct Limbs {
+    GenericArray<T, N> _0;
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ T& operator[](size_t i) {
+        return _0[i];
+    }
+
+    __SP1_HOSTDEV__ __SP1_INLINE__ const T& operator[](size_t i) const {
+        return _0[i];
+    }
+};
+
+/// The parameters of `sp1_curves::weierstrass::bn254::Bn254ScalarField`.
+struct Bn254ScalarField {
+    using Limbs = U32;
+    using Witness = U62;
+
+    static constexpr size_t NB_LIMBS = Limbs::USIZE;
+    static constexpr size_t NB_WITNESS_LIMBS = Witness::USIZE;
+    static constexpr uint32_t WITNESS_OFFSET = 1u << 13;
+
+    /// The little endian bytes of the modulus.
+    static constexpr uint8_t MODULUS[NB_LIMBS] = {
+        1,  0,   0,   240, 147, 245, 225, 67, 145, 112, 185, 121, 72,  232, 51, 40,
+        93, 88, 129, 129, 182, 69,  80,  184, 41, 160, 49,  225, 114, 78,  100, 48,
+    };
+};
+
+/// The layout of a `sp1_core_machine::operations::field::field_op::FieldOpCols<T, P>`.
+template<typename T, typename P>
+struct FieldOpCols {
+    Limbs<T, typename P::Limbs> result;
+    Limbs<T, typename P::Limbs> carry;
+    Limbs<T, typename P::Witness> witness_low;
+    Limbs<T, typename P::Witness> witness_high;
+};
+
+/// The type aliases used by the exported columns.
+using WordsFieldElement = U8;
+template<typename T>
+using FieldLimbs = Limbs<T, Bn254ScalarField::Limbs>;
+
+}  // namespace sp1_core_machine_sys
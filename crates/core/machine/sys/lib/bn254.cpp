@@ -0,0 +1,9 @@
+#include "bn254.hpp"
+
+namespace sp1_core_machine_sys {
+
+extern "C" void bn254_mul_add_event_to_row_babybear(const Bn254MulAddEventFFI* event, BabyBearP3* row) {
+    bn254::mul_add_event_to_row(*event, *reinterpret_cast<Bn254MulAddCols<BabyBearP3>*>(row));
+}
+
+}  // namespace sp1_core_machine_sys
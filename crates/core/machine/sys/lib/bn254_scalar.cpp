@@ -0,0 +1,15 @@
+#include "bn254_scalar.hpp"
+
+namespace sp1_core_machine_sys {
+
+extern "C" void bn254_scalar_mul_add_event_to_row_babybear(
+    const Bn254ScalarMulAddEventFFI* event,
+    BabyBearP3* row
+) {
+    bn254_scalar::mul_add_event_to_row(
+        *event,
+        *reinterpret_cast<Bn254ScalarMulAddCols<BabyBearP3>*>(row)
+    );
+}
+
+}  // namespace sp1_core_machine_sys
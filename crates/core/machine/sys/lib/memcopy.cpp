@@ -0,0 +1,13 @@
+#include "memcopy.hpp"
+
+namespace sp1_core_machine_sys {
+
+extern "C" void memcopy32_event_to_row_babybear(const MemCopyEventFFI* event, BabyBearP3* row) {
+    memcopy::event_to_row(*event, *reinterpret_cast<MemCopyCols<BabyBearP3, U8>*>(row));
+}
+
+extern "C" void memcopy64_event_to_row_babybear(const MemCopyEventFFI* event, BabyBearP3* row) {
+    memcopy::event_to_row(*event, *reinterpret_cast<MemCopyCols<BabyBearP3, U16>*>(row));
+}
+
+}  // namespace sp1_core_machine_sys
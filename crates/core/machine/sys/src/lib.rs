@@ -0,0 +1,7 @@
+//! C++ implementations of the row generation of the SP1 core chips, for GPU provers and other FFI
+//! consumers.
+//!
+//! The layouts of the columns and of the events on the C++ side are generated by cbindgen from
+//! their Rust definitions, for the items allowlisted in `cbindgen.toml`. The header directories
+//! are exported to the build scripts of dependent crates as `DEP_SP1_CORE_MACHINE_SYS_INCLUDE`,
+//! and the functions are declared and wrapped by the `sys` module of `sp1-core-machine`.
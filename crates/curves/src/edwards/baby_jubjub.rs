@@ -0,0 +1,145 @@
+use generic_array::GenericArray;
+use num::{BigUint, Num};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use crate::{
+    edwards::{EdwardsCurve, EdwardsParameters},
+    params::{FieldParameters, NumLimbs},
+    CurveType, EllipticCurveParameters,
+};
+
+/// The Baby Jubjub curve in reduced twisted Edwards form, `-x^2 + y^2 = 1 + d x^2 y^2` over the
+/// scalar field of BN254.
+///
+/// The curve used by circom and EIP-2494 is `168700 x^2 + y^2 = 1 + 168696 x^2 y^2`. Since
+/// `-168700` is a square, the map `(x, y) -> (sqrt(-168700) * x, y)` is an isomorphism onto this
+/// form with `d = -168696 / 168700`, which lets the curve share the `a = -1` addition chip. See
+/// [`baby_jubjub_sqrt_neg_a`].
+pub type BabyJubjub = EdwardsCurve<BabyJubjubParameters>;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BabyJubjubParameters;
+
+/// The base field of Baby Jubjub, which is the scalar field of BN254.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BabyJubjubBaseField;
+
+impl FieldParameters for BabyJubjubBaseField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 240, 147, 245, 225, 67, 145, 112, 185, 121, 72, 232, 51, 40, 93, 88, 129, 129,
+        182, 69, 80, 184, 41, 160, 49, 225, 114, 78, 100, 48,
+    ];
+
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            10,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for BabyJubjubBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for BabyJubjubParameters {
+    type BaseField = BabyJubjubBaseField;
+    const CURVE_TYPE: CurveType = CurveType::BabyJubjub;
+}
+
+impl EdwardsParameters for BabyJubjubParameters {
+    /// `d = -168696 / 168700`.
+    const D: GenericArray<u8, U32> = GenericArray::from_array([
+        142, 235, 215, 244, 140, 202, 117, 208, 103, 200, 183, 235, 89, 41, 155, 3, 252, 17, 253,
+        153, 215, 114, 240, 61, 105, 137, 33, 95, 241, 144, 238, 26,
+    ]);
+
+    fn prime_group_order() -> BigUint {
+        BigUint::from_str_radix(
+            "2736030358979909402780800718157159386076813972158567259200215660948447373041",
+            10,
+        )
+        .unwrap()
+    }
+
+    /// The image of the EIP-2494 base point `Base8`, which generates the prime order subgroup.
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str_radix(
+            "12216525397769193039033285140139874868932027386087289415053270333399021305954",
+            10,
+        )
+        .unwrap();
+        let y = BigUint::from_str_radix(
+            "16950150798460657717958625567821834550301663161624707787222815936182638968203",
+            10,
+        )
+        .unwrap();
+        (x, y)
+    }
+}
+
+/// The square root of `-168700` that maps the x coordinate of a point on the EIP-2494 curve to the
+/// x coordinate of the same point on [`BabyJubjub`].
+pub fn baby_jubjub_sqrt_neg_a() -> BigUint {
+    BigUint::from_str_radix(
+        "6360561867910373094066688120553762416144456282423235903351243436111059670888",
+        10,
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{One, Zero};
+
+    use super::*;
+    use crate::{AffinePoint, EllipticCurve};
+
+    #[test]
+    fn test_baby_jubjub_parameters() {
+        let p = BabyJubjubBaseField::modulus();
+        let mut modulus = BigUint::zero();
+        for (i, limb) in BabyJubjubBaseField::MODULUS.iter().enumerate() {
+            modulus += BigUint::from(*limb) << (8 * i);
+        }
+        assert_eq!(modulus, p);
+
+        // d * 168700 = -168696.
+        let d = BabyJubjubParameters::d_biguint();
+        assert_eq!((d * 168700u32) % &p, &p - 168696u32);
+
+        // sqrt(-a)^2 = -168700.
+        let s = baby_jubjub_sqrt_neg_a();
+        assert_eq!((&s * &s) % &p, &p - 168700u32);
+    }
+
+    #[test]
+    fn test_baby_jubjub_generator() {
+        let p = BabyJubjubBaseField::modulus();
+        let base = BabyJubjub::ec_generator();
+
+        // The generator is the image of `Base8` from EIP-2494.
+        let base8_x = BigUint::from_str_radix(
+            "5299619240641551281634865583518297030282874472190772894086521144482721001553",
+            10,
+        )
+        .unwrap();
+        assert_eq!((base8_x * baby_jubjub_sqrt_neg_a()) % &p, base.x);
+
+        // -x^2 + y^2 = 1 + d x^2 y^2.
+        let x2 = (&base.x * &base.x) % &p;
+        let y2 = (&base.y * &base.y) % &p;
+        let lhs = (&p - &x2 + &y2) % &p;
+        let rhs = (BigUint::one() + BabyJubjubParameters::d_biguint() * &x2 * &y2) % &p;
+        assert_eq!(lhs, rhs);
+
+        let order = BabyJubjubParameters::prime_group_order();
+        let neutral: AffinePoint<BabyJubjub> = BabyJubjub::neutral();
+        assert_eq!(&base * &order, neutral);
+    }
+}
@@ -9,6 +9,7 @@ use typenum::{U32, U62};
 use crate::{
     edwards::{EdwardsCurve, EdwardsParameters},
     params::{FieldParameters, NumLimbs},
+    utils::is_square,
     AffinePoint, CurveType, EllipticCurveParameters,
 };
 
@@ -38,6 +39,30 @@ impl NumLimbs for Ed25519BaseField {
     type Witness = U62;
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Ed25519 scalar field parameter, whose modulus is the order of the prime subgroup of the curve.
+pub struct Ed25519ScalarField;
+
+impl FieldParameters for Ed25519ScalarField {
+    const MODULUS: &'static [u8] = &[
+        0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde,
+        0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x10,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for Ed25519ScalarField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
 impl EllipticCurveParameters for Ed25519Parameters {
     type BaseField = Ed25519BaseField;
     const CURVE_TYPE: CurveType = CurveType::Ed25519;
@@ -120,6 +145,12 @@ pub fn ed25519_sqrt(a: &BigUint) -> BigUint {
 }
 
 pub fn decompress(compressed_point: &CompressedEdwardsY) -> AffinePoint<Ed25519> {
+    try_decompress(compressed_point).expect("the point is not on the curve")
+}
+
+/// Decompresses a point, or returns `None` if there is no point on the curve with its `y`
+/// coordinate.
+pub fn try_decompress(compressed_point: &CompressedEdwardsY) -> Option<AffinePoint<Ed25519>> {
     let mut point_bytes = *compressed_point.as_bytes();
     let sign = point_bytes[31] >> 7 == 1;
     // mask out the sign bit
@@ -133,6 +164,9 @@ pub fn decompress(compressed_point: &CompressedEdwardsY) -> AffinePoint<Ed25519>
 
     let v_inv = v.modpow(&(modulus - BigUint::from(2u64)), modulus);
     let u_div_v = (u * &v_inv) % modulus;
+    if !is_square(&u_div_v, modulus) {
+        return None;
+    }
 
     let mut x = ed25519_sqrt(&u_div_v);
 
@@ -142,7 +176,7 @@ pub fn decompress(compressed_point: &CompressedEdwardsY) -> AffinePoint<Ed25519>
         x = modulus - &x;
     }
 
-    AffinePoint::new(x, y.clone())
+    Some(AffinePoint::new(x, y.clone()))
 }
 
 #[cfg(test)]
@@ -153,6 +187,11 @@ mod tests {
 
     const NUM_TEST_CASES: usize = 100;
 
+    #[test]
+    fn test_ed25519_scalar_field() {
+        assert_eq!(Ed25519ScalarField::modulus(), Ed25519Parameters::prime_group_order());
+    }
+
     #[test]
     fn test_ed25519_decompress() {
         // This test checks that decompression of generator, 2x generator, 4x generator, etc. works.
@@ -0,0 +1,120 @@
+use generic_array::GenericArray;
+use num::{BigUint, Num};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use crate::{
+    edwards::{EdwardsCurve, EdwardsParameters},
+    params::{FieldParameters, NumLimbs},
+    CurveType, EllipticCurveParameters,
+};
+
+/// The Jubjub curve, a twisted Edwards curve `-x^2 + y^2 = 1 + d x^2 y^2` defined over the scalar
+/// field of BLS12-381.
+pub type Jubjub = EdwardsCurve<JubjubParameters>;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JubjubParameters;
+
+/// The base field of Jubjub, which is the scalar field of BLS12-381.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JubjubBaseField;
+
+impl FieldParameters for JubjubBaseField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 0, 255, 255, 255, 255, 254, 91, 254, 255, 2, 164, 189, 83, 5, 216, 161, 9, 8, 216,
+        57, 51, 72, 125, 157, 41, 83, 167, 237, 115,
+    ];
+
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+            10,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for JubjubBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for JubjubParameters {
+    type BaseField = JubjubBaseField;
+    const CURVE_TYPE: CurveType = CurveType::Jubjub;
+}
+
+impl EdwardsParameters for JubjubParameters {
+    /// `d = -(10240/10241)`.
+    const D: GenericArray<u8, U32> = GenericArray::from_array([
+        177, 62, 52, 214, 214, 95, 6, 1, 38, 157, 87, 55, 109, 127, 45, 41, 212, 127, 189, 230, 7,
+        146, 253, 245, 72, 43, 250, 75, 231, 24, 147, 42,
+    ]);
+
+    fn prime_group_order() -> BigUint {
+        BigUint::from_str_radix(
+            "6554484396890773809930967563523245729705921265872317281365359162392183254199",
+            10,
+        )
+        .unwrap()
+    }
+
+    /// The generator of the prime order subgroup, obtained by clearing the cofactor of the
+    /// generator of the full group with `y = 11`.
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str_radix(
+            "28336281903124990867587793011069573392383982287722241916350956173377953689573",
+            10,
+        )
+        .unwrap();
+        let y = BigUint::from_str_radix(
+            "39385640392217313770878525135509063452020585410343666726093009378539878503883",
+            10,
+        )
+        .unwrap();
+        (x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{One, Zero};
+
+    use super::*;
+    use crate::{AffinePoint, EllipticCurve};
+
+    #[test]
+    fn test_jubjub_parameters() {
+        let p = JubjubBaseField::modulus();
+        let mut modulus = BigUint::zero();
+        for (i, limb) in JubjubBaseField::MODULUS.iter().enumerate() {
+            modulus += BigUint::from(*limb) << (8 * i);
+        }
+        assert_eq!(modulus, p);
+
+        // d * 10241 = -10240.
+        let d = JubjubParameters::d_biguint();
+        assert_eq!((d * 10241u32) % &p, &p - 10240u32);
+    }
+
+    #[test]
+    fn test_jubjub_generator() {
+        let p = JubjubBaseField::modulus();
+        let base = Jubjub::ec_generator();
+
+        // -x^2 + y^2 = 1 + d x^2 y^2.
+        let x2 = (&base.x * &base.x) % &p;
+        let y2 = (&base.y * &base.y) % &p;
+        let lhs = (&p - &x2 + &y2) % &p;
+        let rhs = (BigUint::one() + JubjubParameters::d_biguint() * &x2 * &y2) % &p;
+        assert_eq!(lhs, rhs);
+
+        let order = JubjubParameters::prime_group_order();
+        let neutral: AffinePoint<Jubjub> = Jubjub::neutral();
+        assert_eq!(&base * &order, neutral);
+        assert_eq!(base, &base + &(&base * &order));
+    }
+}
@@ -1,4 +1,6 @@
+pub mod baby_jubjub;
 pub mod ed25519;
+pub mod jubjub;
 
 use generic_array::GenericArray;
 use num::{BigUint, Zero};
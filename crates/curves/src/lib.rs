@@ -1,5 +1,7 @@
 pub mod edwards;
 pub mod params;
+pub mod poseidon;
+pub mod rsa;
 // pub mod polynomial;
 pub mod scalar_mul;
 pub mod uint256;
@@ -50,6 +52,8 @@ pub enum CurveType {
     Bn254,
     Ed25519,
     Bls12381,
+    Jubjub,
+    BabyJubjub,
 }
 
 impl Display for CurveType {
@@ -60,6 +64,8 @@ impl Display for CurveType {
             CurveType::Bn254 => write!(f, "Bn254"),
             CurveType::Ed25519 => write!(f, "Ed25519"),
             CurveType::Bls12381 => write!(f, "Bls12381"),
+            CurveType::Jubjub => write!(f, "Jubjub"),
+            CurveType::BabyJubjub => write!(f, "BabyJubjub"),
         }
     }
 }
@@ -0,0 +1,65 @@
+use typenum::{U1022, U256, U510, U512};
+
+use serde::{Deserialize, Serialize};
+
+use crate::params::{FieldParameters, NumLimbs};
+
+/// Returns the little-endian bytes of `2^(8 * (N - 1))`.
+const fn power_of_two_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0; N];
+    bytes[N - 1] = 1;
+    bytes
+}
+
+/// The parameters of the integers modulo an RSA-2048 modulus.
+///
+/// Like [`crate::uint256::U256Field`], these are only used for their limb counts: the modulus of
+/// an RSA operation is an input, and `2^2048` stands in for it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct U2048Field;
+
+impl FieldParameters for U2048Field {
+    /// The wide carries of 256-limb products are range checked on 23 bits.
+    const WITNESS_OFFSET: usize = 1usize << 22;
+
+    const MODULUS: &'static [u8] = &power_of_two_bytes::<257>();
+}
+
+impl NumLimbs for U2048Field {
+    type Limbs = U256;
+    // The modulus has as many limbs as the operands, so the witness has one limb less than the
+    // product.
+    type Witness = U510;
+}
+
+/// The parameters of the integers modulo an RSA-4096 modulus.
+///
+/// See [`U2048Field`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct U4096Field;
+
+impl FieldParameters for U4096Field {
+    /// The wide carries of 512-limb products are range checked on 23 bits.
+    const WITNESS_OFFSET: usize = 1usize << 22;
+
+    const MODULUS: &'static [u8] = &power_of_two_bytes::<513>();
+}
+
+impl NumLimbs for U4096Field {
+    type Limbs = U512;
+    type Witness = U1022;
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{BigUint, One};
+
+    use super::*;
+    use crate::utils::biguint_from_limbs;
+
+    #[test]
+    fn test_rsa_moduli() {
+        assert_eq!(biguint_from_limbs(U2048Field::MODULUS), BigUint::one() << 2048);
+        assert_eq!(biguint_from_limbs(U4096Field::MODULUS), BigUint::one() << 4096);
+    }
+}
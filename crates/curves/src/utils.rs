@@ -1,4 +1,4 @@
-use num::BigUint;
+use num::{BigUint, One, Zero};
 
 pub fn biguint_to_bits_le(integer: &BigUint, num_bits: usize) -> Vec<bool> {
     let byte_vec = integer.to_bytes_le();
@@ -13,6 +13,11 @@ pub fn biguint_to_bits_le(integer: &BigUint, num_bits: usize) -> Vec<bool> {
     bits
 }
 
+/// Whether `a` is a square modulo the odd prime `modulus`, by Euler's criterion.
+pub fn is_square(a: &BigUint, modulus: &BigUint) -> bool {
+    a.is_zero() || a.modpow(&(modulus >> 1), modulus).is_one()
+}
+
 pub fn biguint_to_limbs<const N: usize>(integer: &BigUint) -> [u8; N] {
     let mut bytes = integer.to_bytes_le();
     debug_assert!(bytes.len() <= N, "Number too large to fit in {N} limbs");
@@ -1,12 +1,13 @@
 use generic_array::GenericArray;
-use num::{BigUint, Num, Zero};
+use num::{BigUint, Num, One, Zero};
 use serde::{Deserialize, Serialize};
 use typenum::{U32, U62};
 
 use super::{FieldType, FpOpField, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
-    CurveType, EllipticCurveParameters,
+    utils::is_square,
+    AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -115,14 +116,205 @@ impl WeierstrassParameters for Bn254Parameters {
     }
 }
 
+/// Whether `y` is larger than `-y` when both are embedded as integers in `[0, p)`, which is the
+/// sign convention of compressed points in gnark.
+pub fn bn254_is_lexicographically_largest(y: &BigUint) -> bool {
+    let modulus = Bn254BaseField::modulus();
+    y > &((modulus - BigUint::one()) >> 1)
+}
+
+/// Computes a square root of `n`, which must be a square. Since `p = 3 mod 4`, it is
+/// `n^((p + 1) / 4)`.
+pub fn bn254_sqrt(n: &BigUint) -> BigUint {
+    let modulus = Bn254BaseField::modulus();
+    let sqrt = n.modpow(&((&modulus + BigUint::one()) >> 2), &modulus);
+    debug_assert_eq!((&sqrt * &sqrt) % &modulus, n % &modulus);
+    sqrt
+}
+
+/// Decompresses a G1 point from its big-endian x coordinate, choosing the y coordinate that is
+/// lexicographically largest if `sign_bit` is set.
+pub fn bn254_decompress<E: EllipticCurve>(bytes_be: &[u8], sign_bit: u32) -> AffinePoint<E> {
+    let modulus = Bn254BaseField::modulus();
+    let x = BigUint::from_bytes_be(bytes_be);
+    let y_squared = (&x * &x * &x + Bn254Parameters::b_int()) % &modulus;
+    assert!(is_square(&y_squared, &modulus), "no point with this x coordinate");
+
+    let y = bn254_sqrt(&y_squared);
+    let y = if bn254_is_lexicographically_largest(&y) == (sign_bit == 1) {
+        y
+    } else {
+        (&modulus - &y) % &modulus
+    };
+    AffinePoint::new(x, y)
+}
+
+/// An element `c0 + c1 * u` of `Fq2 = Fq[u] / (u^2 + 1)`, as `[c0, c1]`.
+pub type Bn254Fp2 = [BigUint; 2];
+
+fn fp2_mul(a: &Bn254Fp2, b: &Bn254Fp2) -> Bn254Fp2 {
+    let modulus = Bn254BaseField::modulus();
+    [
+        (&a[0] * &b[0] + &modulus - (&a[1] * &b[1]) % &modulus) % &modulus,
+        (&a[0] * &b[1] + &a[1] * &b[0]) % &modulus,
+    ]
+}
+
+/// The coefficient `b = 3 / (9 + u)` of the sextic twist `y^2 = x^3 + b` on which the G2 points
+/// lie.
+pub fn bn254_twist_b() -> Bn254Fp2 {
+    let modulus = Bn254BaseField::modulus();
+    // 3 / (9 + u) = 3 * (9 - u) / 82.
+    let inverse = BigUint::from(82u32).modpow(&(&modulus - BigUint::from(2u32)), &modulus);
+    let c0 = (BigUint::from(27u32) * &inverse) % &modulus;
+    let c1 = (&modulus - (BigUint::from(3u32) * &inverse) % &modulus) % &modulus;
+    [c0, c1]
+}
+
+/// The generator of G2, as in EIP-197.
+pub fn bn254_g2_generator() -> (Bn254Fp2, Bn254Fp2) {
+    let from_decimal = |s: &str| BigUint::from_str_radix(s, 10).unwrap();
+    let x = [
+        from_decimal(
+            "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+        ),
+        from_decimal(
+            "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+        ),
+    ];
+    let y = [
+        from_decimal(
+            "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+        ),
+        from_decimal(
+            "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+        ),
+    ];
+    (x, y)
+}
+
+/// Computes `x^3 + b` on the twist, which is `y^2` for the G2 points with x coordinate `x`.
+pub fn bn254_g2_y_squared(x: &Bn254Fp2) -> Bn254Fp2 {
+    let modulus = Bn254BaseField::modulus();
+    let x_3 = fp2_mul(&fp2_mul(x, x), x);
+    let b = bn254_twist_b();
+    [(&x_3[0] + &b[0]) % &modulus, (&x_3[1] + &b[1]) % &modulus]
+}
+
+/// Computes a square root of `a` in `Fq2`, or `None` if `a` is not a square.
+///
+/// The square root of `a0 + a1 * u` is `x0 + x1 * u` with `x0^2 = (a0 +- sqrt(a0^2 + a1^2)) / 2`
+/// and `x1 = a1 / (2 * x0)`, so `a` is a square exactly when its norm `a0^2 + a1^2` is.
+pub fn bn254_fp2_sqrt(a: &Bn254Fp2) -> Option<Bn254Fp2> {
+    let modulus = Bn254BaseField::modulus();
+    let two_inverse = (&modulus + BigUint::one()) >> 1;
+    let [a0, a1] = a;
+
+    if a1.is_zero() {
+        // Since `-1` is not a square, either `a0` or `-a0` is.
+        return if is_square(a0, &modulus) {
+            Some([bn254_sqrt(a0), BigUint::zero()])
+        } else {
+            Some([BigUint::zero(), bn254_sqrt(&((&modulus - a0) % &modulus))])
+        };
+    }
+
+    let norm = (a0 * a0 + a1 * a1) % &modulus;
+    if !is_square(&norm, &modulus) {
+        return None;
+    }
+    let norm_sqrt = bn254_sqrt(&norm);
+    let mut x0_squared = ((a0 + &norm_sqrt) * &two_inverse) % &modulus;
+    if !is_square(&x0_squared, &modulus) {
+        x0_squared = ((a0 + &modulus - &norm_sqrt) * &two_inverse) % &modulus;
+    }
+    // `x0` is not zero, since otherwise `a0^2 = a0^2 + a1^2` with `a1` not zero.
+    let x0 = bn254_sqrt(&x0_squared);
+    let x0_inverse = x0.modpow(&(&modulus - BigUint::from(2u32)), &modulus);
+    let x1 = (a1 * &two_inverse * x0_inverse) % &modulus;
+
+    let sqrt = [x0, x1];
+    debug_assert_eq!(&fp2_mul(&sqrt, &sqrt), a);
+    Some(sqrt)
+}
+
+/// Whether `y` is larger than `-y` in the lexicographic order of gnark, which compares the `c1`
+/// components first and the `c0` components if the `c1` components are zero.
+pub fn bn254_fp2_is_lexicographically_largest(y: &Bn254Fp2) -> bool {
+    if y[1].is_zero() {
+        bn254_is_lexicographically_largest(&y[0])
+    } else {
+        bn254_is_lexicographically_largest(&y[1])
+    }
+}
+
+/// Decompresses a G2 point from its x coordinate, choosing the y coordinate that is
+/// lexicographically largest if `sign_bit` is set. Returns `None` if there is no point with this
+/// x coordinate.
+pub fn bn254_g2_decompress(x: &Bn254Fp2, sign_bit: u32) -> Option<Bn254Fp2> {
+    let modulus = Bn254BaseField::modulus();
+    let y = bn254_fp2_sqrt(&bn254_g2_y_squared(x))?;
+    if bn254_fp2_is_lexicographically_largest(&y) == (sign_bit == 1) {
+        Some(y)
+    } else {
+        Some(y.map(|c| (&modulus - c) % &modulus))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
     use crate::utils::biguint_from_limbs;
+    use num::bigint::RandBigInt;
+    use rand::thread_rng;
 
     #[test]
     fn test_weierstrass_biguint_scalar_mul() {
         assert_eq!(biguint_from_limbs(Bn254BaseField::MODULUS), Bn254BaseField::modulus());
     }
+
+    #[test]
+    fn test_bn254_decompress() {
+        // This test checks that decompression of generator, 2x generator, 4x generator, etc. works.
+        let mut point = {
+            let (x, y) = Bn254Parameters::generator();
+            AffinePoint::<Bn254>::new(x, y)
+        };
+        for _ in 0..10 {
+            let sign_bit = u32::from(bn254_is_lexicographically_largest(&point.y));
+            let x_bytes_be = point.x.to_bytes_be();
+            assert_eq!(point, bn254_decompress(&x_bytes_be, sign_bit));
+
+            point = point.clone().sw_double();
+        }
+    }
+
+    #[test]
+    fn test_bn254_g2_decompress() {
+        let (x, y) = bn254_g2_generator();
+        assert_eq!(bn254_g2_y_squared(&x), fp2_mul(&y, &y));
+
+        let sign_bit = u32::from(bn254_fp2_is_lexicographically_largest(&y));
+        assert_eq!(bn254_g2_decompress(&x, sign_bit), Some(y.clone()));
+
+        let neg_y = y.map(|c| Bn254BaseField::modulus() - c);
+        assert_eq!(bn254_g2_decompress(&x, 1 - sign_bit), Some(neg_y));
+    }
+
+    #[test]
+    fn test_bn254_fp2_sqrt() {
+        let mut rng = thread_rng();
+        let modulus = Bn254BaseField::modulus();
+        for _ in 0..10 {
+            let x = [rng.gen_biguint(256) % &modulus, rng.gen_biguint(256) % &modulus];
+            let x_2 = fp2_mul(&x, &x);
+            let sqrt = bn254_fp2_sqrt(&x_2).unwrap();
+            assert_eq!(fp2_mul(&sqrt, &sqrt), x_2);
+
+            // `9 + u` is not a square, so neither is its product with a nonzero square.
+            let non_square = fp2_mul(&x_2, &[BigUint::from(9u32), BigUint::one()]);
+            assert_eq!(bn254_fp2_sqrt(&non_square), None);
+        }
+    }
 }
@@ -285,6 +285,18 @@ pub enum FieldType {
     Bn254,
 }
 
+impl FieldType {
+    /// The constant `c` of the cubic non-residue `c + u` of the degree 2 extension, which defines
+    /// the degree 6 extension `Fp2[v] / (v^3 - (c + u))` of the pairing tower.
+    #[must_use]
+    pub const fn fp6_non_residue(self) -> u32 {
+        match self {
+            FieldType::Bls12381 => 1,
+            FieldType::Bn254 => 9,
+        }
+    }
+}
+
 pub trait FpOpField: FieldParameters + NumWords {
     const FIELD_TYPE: FieldType;
 }
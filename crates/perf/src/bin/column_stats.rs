@@ -0,0 +1,77 @@
+use clap::{command, Parser};
+use p3_baby_bear::BabyBear;
+use sp1_core_executor::{ExecutionRecord, Executor, Program};
+use sp1_core_machine::riscv::RiscvAir;
+use sp1_sdk::SP1Stdin;
+use sp1_stark::{
+    air::MachineAir, baby_bear_poseidon2::BabyBearPoseidon2, ColumnStats, SP1CoreOpts,
+};
+use test_artifacts::{
+    ED25519_ELF, FIBONACCI_ELF, KECCAK256_ELF, SECP256K1_ECRECOVER_ELF, SHA2_ELF,
+    U256XU2048_MUL_ELF,
+};
+
+#[derive(Parser, Clone)]
+#[command(about = "Report the columns of each chip that are always zero or constant.")]
+struct ColumnStatsArgs {
+    /// The programs to trace. Defaults to a corpus of test programs.
+    #[arg(short, long)]
+    pub program: Vec<String>,
+    /// The stdin of each program, in the same order. Programs without one run on an empty stdin.
+    #[arg(short, long)]
+    pub stdin: Vec<String>,
+}
+
+fn main() {
+    sp1_sdk::utils::setup_logger();
+    let args = ColumnStatsArgs::parse();
+
+    let corpus: Vec<(String, Vec<u8>)> = if args.program.is_empty() {
+        [
+            ("fibonacci", FIBONACCI_ELF),
+            ("ed25519", ED25519_ELF),
+            ("keccak256", KECCAK256_ELF),
+            ("sha2", SHA2_ELF),
+            ("secp256k1-ecrecover", SECP256K1_ECRECOVER_ELF),
+            ("u256xu2048-mul", U256XU2048_MUL_ELF),
+        ]
+        .into_iter()
+        .map(|(name, elf)| (name.to_string(), elf.to_vec()))
+        .collect()
+    } else {
+        args.program
+            .iter()
+            .map(|path| (path.clone(), std::fs::read(path).expect("failed to read program")))
+            .collect()
+    };
+
+    let machine = RiscvAir::<BabyBear>::machine(BabyBearPoseidon2::new());
+    let opts = SP1CoreOpts::default();
+    let mut stats = ColumnStats::new();
+    for (i, (name, elf)) in corpus.iter().enumerate() {
+        let stdin = match args.stdin.get(i) {
+            Some(path) => {
+                let stdin = std::fs::read(path).expect("failed to read stdin");
+                bincode::deserialize(&stdin).expect("failed to deserialize stdin")
+            }
+            None => SP1Stdin::new(),
+        };
+
+        let program = Program::from(elf).expect("failed to decode program");
+        let mut runtime = Executor::new(program, opts);
+        stdin.write_to(&mut runtime);
+        runtime.run().expect("failed to execute program");
+
+        let mut records = std::mem::take(&mut runtime.records);
+        machine.generate_dependencies(&mut records, &opts, None);
+        for record in &records {
+            for chip in machine.shard_chips(record) {
+                let trace = chip.generate_trace(record, &mut ExecutionRecord::default());
+                stats.observe(&chip.name(), &trace);
+            }
+        }
+        println!("traced {name}: {} shards", records.len());
+    }
+
+    print!("{stats}");
+}
@@ -80,6 +80,12 @@ impl SP1PublicValues {
     }
 }
 
+/// The SHA-256 digest of a hint, as checked by `sp1_zkvm::io::read_verified` and committed by
+/// `sp1_zkvm::io::read_committed`.
+pub fn hint_digest(hint: &[u8]) -> [u8; 32] {
+    Sha256::digest(hint).into()
+}
+
 impl AsRef<[u8]> for SP1PublicValues {
     fn as_ref(&self) -> &[u8] {
         &self.buffer.data
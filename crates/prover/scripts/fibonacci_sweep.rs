@@ -62,6 +62,7 @@ fn main() {
             buffer: vec![bincode::serialize::<u32>(&iterations).unwrap()],
             ptr: 0,
             proofs: vec![],
+            stream: None,
         };
         let leaf_proving_start = Instant::now();
         let proof = prover
@@ -13,6 +13,7 @@
 
 pub mod build;
 pub mod components;
+pub mod scheduler;
 pub mod shapes;
 pub mod types;
 pub mod utils;
@@ -37,7 +38,9 @@ use p3_baby_bear::BabyBear;
 use p3_challenger::CanObserve;
 use p3_field::{AbstractField, PrimeField, PrimeField32};
 use p3_matrix::dense::RowMajorMatrix;
-use sp1_core_executor::{ExecutionError, ExecutionReport, Executor, Program, SP1Context};
+use sp1_core_executor::{
+    ExecutionError, ExecutionReport, Executor, OpcodeDenylist, Program, SP1Context,
+};
 use sp1_core_machine::{
     io::SP1Stdin,
     reduce::SP1ReduceProof,
@@ -73,15 +76,18 @@ use sp1_recursion_gnark_ffi::{groth16_bn254::Groth16Bn254Prover, plonk_bn254::Pl
 use sp1_stark::{air::InteractionScope, MachineProvingKey, ProofShape};
 use sp1_stark::{
     air::PublicValues, baby_bear_poseidon2::BabyBearPoseidon2, Challenge, Challenger,
-    MachineProver, SP1CoreOpts, SP1ProverOpts, ShardProof, StarkGenericConfig, StarkVerifyingKey,
-    Val, Word, DIGEST_SIZE,
+    MachineProver, MemoryWatermarkTracker, MemoryWatermarks, ProvingStage, SP1CoreOpts,
+    SP1ProverOpts, ShardProof, StarkGenericConfig, StarkVerifyingKey, Val, Word, DIGEST_SIZE,
 };
 use tracing::instrument;
 
 pub use types::*;
-use utils::{sp1_committed_values_digest_bn254, sp1_vkey_digest_bn254, words_to_bytes};
+use utils::{
+    reduce_tree_height, sp1_committed_values_digest_bn254, sp1_vkey_digest_bn254, words_to_bytes,
+};
 
 use components::{DefaultProverComponents, SP1ProverComponents};
+use scheduler::{prove_tree, NumaTopology, ReduceTree, TreeNode};
 
 pub use sp1_core_machine::SP1_CIRCUIT_VERSION;
 
@@ -152,6 +158,9 @@ pub struct SP1Prover<C: SP1ProverComponents = DefaultProverComponents> {
     pub wrap_vk: OnceLock<StarkVerifyingKey<OuterSC>>,
 
     pub vk_verification: bool,
+
+    /// The opcodes forbidden by the machine profile, committed into the verifying key.
+    pub opcode_denylist: OpcodeDenylist,
 }
 
 impl<C: SP1ProverComponents> SP1Prover<C> {
@@ -234,9 +243,17 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             vk_verification,
             wrap_program: OnceLock::new(),
             wrap_vk: OnceLock::new(),
+            opcode_denylist: OpcodeDenylist::new(),
         }
     }
 
+    /// Restricts the programs handled by this prover to a profile forbidding the given opcodes.
+    #[must_use]
+    pub fn with_opcode_denylist(mut self, opcode_denylist: OpcodeDenylist) -> Self {
+        self.opcode_denylist = opcode_denylist;
+        self
+    }
+
     /// Fully initializes the programs, proving keys, and verifying keys that are normally
     /// lazily initialized. TODO: remove this.
     pub fn initialize(&mut self) {}
@@ -257,7 +274,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
     /// Get a program with an allowed preprocessed shape.
     pub fn get_program(&self, elf: &[u8]) -> eyre::Result<Program> {
-        let mut program = Program::from(elf)?;
+        let mut program = Program::from(elf)?.with_opcode_denylist(self.opcode_denylist)?;
         if let Some(core_shape_config) = &self.core_shape_config {
             core_shape_config.fix_preprocessed_shape(&mut program)?;
         }
@@ -276,10 +293,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         let program = self.get_program(elf).unwrap();
         let opts = SP1CoreOpts::default();
         let mut runtime = Executor::with_context(program, opts, context);
-        runtime.write_vecs(&stdin.buffer);
-        for (proof, vkey) in stdin.proofs.iter() {
-            runtime.write_proof(proof.clone(), vkey.clone());
-        }
+        stdin.write_to(&mut runtime);
         runtime.run_fast()?;
         Ok((SP1PublicValues::from(&runtime.state.public_values_stream), runtime.report))
     }
@@ -297,6 +311,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         context.subproof_verifier.replace(Arc::new(self));
         let program = self.get_program(&pk.elf).unwrap();
         let pk = self.core_prover.pk_to_device(&pk.pk);
+        let memory = MemoryWatermarkTracker::new(opts.core_opts.memory_caps);
         let (proof, public_values_stream, cycles) =
             sp1_core_machine::utils::prove_with_context::<_, C::CoreProver>(
                 &self.core_prover,
@@ -306,6 +321,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                 opts.core_opts,
                 context,
                 self.core_shape_config.as_ref(),
+                &memory,
             )?;
         Self::check_for_high_cycles(cycles);
         let public_values = SP1PublicValues::from(&public_values_stream);
@@ -314,9 +330,31 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             stdin: stdin.clone(),
             public_values,
             cycles,
+            memory: memory.watermarks(),
         })
     }
 
+    /// Generate shard proofs for a set of execution records which were produced and finalized
+    /// elsewhere, e.g. by a separate execution stage. The challenger state should be computed with
+    /// [`sp1_core_machine::utils::observe_records`] over the complete set of records.
+    #[instrument(name = "prove_from_records", level = "info", skip_all)]
+    pub fn prove_from_records(
+        &self,
+        pk: &SP1ProvingKey,
+        records: Vec<sp1_core_executor::ExecutionRecord>,
+        challenger_state: &Challenger<CoreSC>,
+    ) -> Result<SP1CoreProofData, <C::CoreProver as MachineProver<CoreSC, RiscvAir<BabyBear>>>::Error>
+    {
+        let pk = self.core_prover.pk_to_device(&pk.pk);
+        let proof = sp1_core_machine::utils::prove_from_records::<_, C::CoreProver>(
+            &self.core_prover,
+            &pk,
+            records,
+            challenger_state,
+        )?;
+        Ok(SP1CoreProofData(proof.shard_proofs))
+    }
+
     pub fn recursion_program(
         &self,
         input: &SP1RecursionWitnessValues<CoreSC>,
@@ -619,7 +657,6 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
     }
 
     /// Reduce shards proofs to a single shard proof using the recursion prover.
-    #[instrument(name = "compress", level = "info", skip_all)]
     pub fn compress(
         &self,
         vk: &SP1VerifyingKey,
@@ -627,6 +664,22 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         deferred_proofs: Vec<SP1ReduceProof<InnerSC>>,
         opts: SP1ProverOpts,
     ) -> Result<SP1ReduceProof<InnerSC>, SP1RecursionProverError> {
+        self.compress_with_watermarks(vk, proof, deferred_proofs, opts).map(|(proof, _)| proof)
+    }
+
+    /// Reduce shards proofs to a single shard proof using the recursion prover, returning the
+    /// memory watermarks of the core proof extended with the peak of the recursion stage.
+    #[instrument(name = "compress", level = "info", skip_all)]
+    pub fn compress_with_watermarks(
+        &self,
+        vk: &SP1VerifyingKey,
+        proof: SP1CoreProof,
+        deferred_proofs: Vec<SP1ReduceProof<InnerSC>>,
+        opts: SP1ProverOpts,
+    ) -> Result<(SP1ReduceProof<InnerSC>, MemoryWatermarks), SP1RecursionProverError> {
+        let memory =
+            MemoryWatermarkTracker::with_watermarks(opts.recursion_opts.memory_caps, proof.memory);
+
         // The batch size for reducing two layers of recursion.
         let batch_size = REDUCE_BATCH_SIZE;
         // The batch size for reducing the first layer of recursion.
@@ -652,16 +705,46 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         );
 
         // Calculate the expected height of the tree.
-        let mut expected_height = if first_layer_inputs.len() == 1 { 0 } else { 1 };
         let num_first_layer_inputs = first_layer_inputs.len();
-        let mut num_layer_inputs = num_first_layer_inputs;
-        while num_layer_inputs > batch_size {
-            num_layer_inputs = num_layer_inputs.div_ceil(2);
-            expected_height += 1;
+        let expected_height = reduce_tree_height(num_first_layer_inputs);
+
+        // Prove the independent nodes of the tree in parallel, if enabled.
+        if opts.recursion_opts.reduce_workers > 0 {
+            let tree = ReduceTree::new(num_first_layer_inputs, batch_size);
+            let topology = opts.recursion_opts.numa_pinning.then(NumaTopology::detect).flatten();
+            let (vk, proof) = prove_tree(
+                &tree,
+                first_layer_inputs,
+                opts.recursion_opts.reduce_workers,
+                topology.as_ref(),
+                |node| {
+                    memory.check().map_err(SP1RecursionProverError::MemoryCapExceeded)?;
+                    let input = match node {
+                        TreeNode::Leaf(input) => input,
+                        TreeNode::Inner { children, is_root } => {
+                            SP1CircuitWitness::Compress(SP1CompressWitnessValues {
+                                vks_and_proofs: children,
+                                is_complete: is_root,
+                            })
+                        }
+                    };
+                    let (program, record, traces) =
+                        self.recursion_record_and_traces(input, &opts.recursion_opts);
+                    let proof = self.prove_recursion_record(&program, record, traces);
+                    memory
+                        .record(ProvingStage::Recursion)
+                        .map_err(SP1RecursionProverError::MemoryCapExceeded)?;
+                    Ok(proof)
+                },
+            )?;
+            memory.report();
+
+            return Ok((SP1ReduceProof { vk, proof }, memory.watermarks()));
         }
 
         // Generate the proofs.
         let span = tracing::Span::current().clone();
+        let memory = &memory;
         let (vk, proof) = thread::scope(|s| {
             let _span = span.enter();
 
@@ -677,7 +760,11 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                 s.spawn(move || {
                     for (index, input) in first_layer_inputs.into_iter().enumerate() {
                         input_sync.wait_for_turn(index);
-                        input_tx.lock().unwrap().send((index, 0, input)).unwrap();
+                        // Once a memory cap is exceeded, keep taking turns without sending so
+                        // that the next layer generator is never left waiting.
+                        if memory.check().is_ok() {
+                            input_tx.lock().unwrap().send((index, 0, input)).unwrap();
+                        }
                         input_sync.advance_turn();
                     }
                 });
@@ -706,68 +793,8 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                     loop {
                         let received = { input_rx.lock().unwrap().recv() };
                         if let Ok((index, height, input)) = received {
-                            // Get the program and witness stream.
-                            let (program, witness_stream) = tracing::debug_span!(
-                                "get program and witness stream"
-                            )
-                            .in_scope(|| match input {
-                                SP1CircuitWitness::Core(input) => {
-                                    let mut witness_stream = Vec::new();
-                                    Witnessable::<InnerConfig>::write(&input, &mut witness_stream);
-                                    (self.recursion_program(&input), witness_stream)
-                                }
-                                SP1CircuitWitness::Deferred(input) => {
-                                    let mut witness_stream = Vec::new();
-                                    Witnessable::<InnerConfig>::write(&input, &mut witness_stream);
-                                    (self.deferred_program(&input), witness_stream)
-                                }
-                                SP1CircuitWitness::Compress(input) => {
-                                    let mut witness_stream = Vec::new();
-
-                                    let input_with_merkle = self.make_merkle_proofs(input);
-
-                                    Witnessable::<InnerConfig>::write(
-                                        &input_with_merkle,
-                                        &mut witness_stream,
-                                    );
-
-                                    (self.compress_program(&input_with_merkle), witness_stream)
-                                }
-                            });
-
-                            // Execute the runtime.
-                            let record = tracing::debug_span!("execute runtime").in_scope(|| {
-                                let mut runtime =
-                                    RecursionRuntime::<Val<InnerSC>, Challenge<InnerSC>, _>::new(
-                                        program.clone(),
-                                        self.compress_prover.config().perm.clone(),
-                                    );
-                                runtime.witness_stream = witness_stream.into();
-                                runtime
-                                    .run()
-                                    .map_err(|e| {
-                                        SP1RecursionProverError::RuntimeError(e.to_string())
-                                    })
-                                    .unwrap();
-                                runtime.record
-                            });
-
-                            // Generate the dependencies.
-                            let mut records = vec![record];
-                            tracing::debug_span!("generate dependencies").in_scope(|| {
-                                self.compress_prover.machine().generate_dependencies(
-                                    &mut records,
-                                    &opts.recursion_opts,
-                                    None,
-                                )
-                            });
-
-                            // Generate the traces.
-                            let record = records.into_iter().next().unwrap();
-                            let traces = tracing::debug_span!("generate traces").in_scope(|| {
-                                self.compress_prover
-                                    .generate_traces(&record, InteractionScope::Local)
-                            });
+                            let (program, record, traces) =
+                                self.recursion_record_and_traces(input, &opts.recursion_opts);
 
                             // Wait for our turn to update the state.
                             record_and_trace_sync.wait_for_turn(index);
@@ -808,62 +835,12 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                         let received = { record_and_trace_rx.lock().unwrap().recv() };
                         if let Ok((index, height, program, record, traces)) = received {
                             tracing::debug_span!("batch").in_scope(|| {
-                                // Get the keys.
-                                let (pk, vk) = tracing::debug_span!("Setup compress program")
-                                    .in_scope(|| self.compress_prover.setup(&program));
-
-                                // Observe the proving key.
-                                let mut challenger = self.compress_prover.config().challenger();
-                                tracing::debug_span!("observe proving key").in_scope(|| {
-                                    pk.observe_into(&mut challenger);
-                                });
-
-                                #[cfg(feature = "debug")]
-                                self.compress_prover.debug_constraints(
-                                    &self.compress_prover.pk_to_host(&pk),
-                                    vec![record.clone()],
-                                    &mut challenger.clone(),
-                                );
-
-                                // Commit to the record and traces.
-                                let local_data = tracing::debug_span!("commit")
-                                    .in_scope(|| self.compress_prover.commit(&record, traces));
-
-                                // Observe the commitment.
-                                tracing::debug_span!("observe public values").in_scope(|| {
-                                    challenger.observe_slice(
-                                        &local_data.public_values[0..self.compress_prover.num_pv_elts()],
-                                    );
-                                });
-
-                                // Generate the proof.
-                                let proof = tracing::debug_span!("open").in_scope(|| {
-                                    self.compress_prover
-                                        .open(
-                                            &pk,
-                                            None,
-                                            local_data,
-                                            &mut challenger,
-                                            &[
-                                                <BabyBearPoseidon2 as StarkGenericConfig>::Challenge::zero(),
-                                                <BabyBearPoseidon2 as StarkGenericConfig>::Challenge::zero(),
-                                            ],
-                                        )
-                                        .unwrap()
-                                });
-
-                                // Verify the proof.
-                                #[cfg(feature = "debug")]
-                                self.compress_prover
-                                    .machine()
-                                    .verify(
-                                        &vk,
-                                        &sp1_stark::MachineProof {
-                                            shard_proofs: vec![proof.clone()],
-                                        },
-                                        &mut self.compress_prover.config().challenger(),
-                                    )
-                                    .unwrap();
+                                let (vk, proof) =
+                                    self.prove_recursion_record(&program, record, traces);
+
+                                // A cap violation is picked up by the next layer generator once
+                                // it receives this proof.
+                                let _ = memory.record(ProvingStage::Recursion);
 
                                 // Wait for our turn to update the state.
                                 prover_sync.wait_for_turn(index);
@@ -899,6 +876,10 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                     loop {
                         let received = { proofs_rx.lock().unwrap().recv() };
                         if let Ok((index, height, vk, proof)) = received {
+                            // Stop generating inputs if a memory cap has been exceeded.
+                            if memory.check().is_err() {
+                                break;
+                            }
                             batch.push((index, height, vk, proof));
 
                             // If we haven't reached the batch size, continue.
@@ -966,12 +947,143 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                 handle.join().unwrap();
             }
             handle.join().unwrap();
+            memory.check().map_err(SP1RecursionProverError::MemoryCapExceeded)?;
 
             let (_, _, vk, proof) = proofs_rx.lock().unwrap().recv().unwrap();
-            (vk, proof)
+            Ok((vk, proof))
+        })?;
+        memory.report();
+
+        Ok((SP1ReduceProof { vk, proof }, memory.watermarks()))
+    }
+
+    /// Execute the recursion program of a node of the reduce tree, and generate its record and
+    /// traces.
+    #[allow(clippy::type_complexity)]
+    fn recursion_record_and_traces(
+        &self,
+        input: SP1CircuitWitness,
+        opts: &SP1CoreOpts,
+    ) -> (
+        Arc<RecursionProgram<BabyBear>>,
+        ExecutionRecord<BabyBear>,
+        Vec<(String, RowMajorMatrix<BabyBear>)>,
+    ) {
+        // Get the program and witness stream.
+        let (program, witness_stream) = tracing::debug_span!("get program and witness stream")
+            .in_scope(|| match input {
+                SP1CircuitWitness::Core(input) => {
+                    let mut witness_stream = Vec::new();
+                    Witnessable::<InnerConfig>::write(&input, &mut witness_stream);
+                    (self.recursion_program(&input), witness_stream)
+                }
+                SP1CircuitWitness::Deferred(input) => {
+                    let mut witness_stream = Vec::new();
+                    Witnessable::<InnerConfig>::write(&input, &mut witness_stream);
+                    (self.deferred_program(&input), witness_stream)
+                }
+                SP1CircuitWitness::Compress(input) => {
+                    let mut witness_stream = Vec::new();
+
+                    let input_with_merkle = self.make_merkle_proofs(input);
+
+                    Witnessable::<InnerConfig>::write(&input_with_merkle, &mut witness_stream);
+
+                    (self.compress_program(&input_with_merkle), witness_stream)
+                }
+            });
+
+        // Execute the runtime.
+        let record = tracing::debug_span!("execute runtime").in_scope(|| {
+            let mut runtime = RecursionRuntime::<Val<InnerSC>, Challenge<InnerSC>, _>::new(
+                program.clone(),
+                self.compress_prover.config().perm.clone(),
+            );
+            runtime.witness_stream = witness_stream.into();
+            runtime
+                .run()
+                .map_err(|e| SP1RecursionProverError::RuntimeError(e.to_string()))
+                .unwrap();
+            runtime.record
+        });
+
+        // Generate the dependencies.
+        let mut records = vec![record];
+        tracing::debug_span!("generate dependencies").in_scope(|| {
+            self.compress_prover.machine().generate_dependencies(&mut records, opts, None)
         });
 
-        Ok(SP1ReduceProof { vk, proof })
+        // Generate the traces.
+        let record = records.into_iter().next().unwrap();
+        let traces = tracing::debug_span!("generate traces")
+            .in_scope(|| self.compress_prover.generate_traces(&record, InteractionScope::Local));
+
+        (program, record, traces)
+    }
+
+    /// Prove the record of a node of the reduce tree, returning the verifying key of its program
+    /// along with the proof.
+    fn prove_recursion_record(
+        &self,
+        program: &RecursionProgram<BabyBear>,
+        record: ExecutionRecord<BabyBear>,
+        traces: Vec<(String, RowMajorMatrix<BabyBear>)>,
+    ) -> (StarkVerifyingKey<InnerSC>, ShardProof<InnerSC>) {
+        // Get the keys.
+        let (pk, vk) = tracing::debug_span!("Setup compress program")
+            .in_scope(|| self.compress_prover.setup(&program));
+
+        // Observe the proving key.
+        let mut challenger = self.compress_prover.config().challenger();
+        tracing::debug_span!("observe proving key").in_scope(|| {
+            pk.observe_into(&mut challenger);
+        });
+
+        #[cfg(feature = "debug")]
+        self.compress_prover.debug_constraints(
+            &self.compress_prover.pk_to_host(&pk),
+            vec![record.clone()],
+            &mut challenger.clone(),
+        );
+
+        // Commit to the record and traces.
+        let local_data = tracing::debug_span!("commit")
+            .in_scope(|| self.compress_prover.commit(&record, traces));
+
+        // Observe the commitment.
+        tracing::debug_span!("observe public values").in_scope(|| {
+            challenger
+                .observe_slice(&local_data.public_values[0..self.compress_prover.num_pv_elts()]);
+        });
+
+        // Generate the proof.
+        let proof = tracing::debug_span!("open").in_scope(|| {
+            self.compress_prover
+                .open(
+                    &pk,
+                    None,
+                    local_data,
+                    &mut challenger,
+                    &[
+                        <BabyBearPoseidon2 as StarkGenericConfig>::Challenge::zero(),
+                        <BabyBearPoseidon2 as StarkGenericConfig>::Challenge::zero(),
+                    ],
+                )
+                .unwrap()
+        });
+
+        // Verify the proof.
+        #[cfg(feature = "debug")]
+        self.compress_prover
+            .machine()
+            .verify(
+                &vk,
+                &sp1_stark::MachineProof { shard_proofs: vec![proof.clone()] },
+                &mut self.compress_prover.config().challenger(),
+            )
+            .unwrap();
+
+        (vk, proof)
     }
 
     /// Wrap a reduce proof into a STARK proven over a SNARK-friendly field.
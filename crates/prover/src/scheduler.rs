@@ -0,0 +1,340 @@
+//! Scheduling of the proofs of the nodes of the reduce tree on a pool of workers.
+//!
+//! The nodes of the tree whose children are all proven are independent of each other, so they are
+//! proven in parallel as soon as they are ready rather than in the order of the layers. On
+//! machines with several NUMA nodes, the workers can be pinned to the CPUs of the nodes in turn,
+//! so that the memory of the records and traces they allocate is local to the CPUs proving them.
+
+use std::{
+    any::Any,
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs,
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+    sync::{Condvar, Mutex},
+    thread,
+};
+
+/// The layout of a reduce tree, whose nodes reduce up to `batch_size` consecutive nodes of the
+/// layer below.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReduceTree {
+    /// The number of nodes of every layer, from the leaves to the root.
+    layers: Vec<usize>,
+    /// The maximum number of children of a node.
+    batch_size: usize,
+}
+
+impl ReduceTree {
+    /// The layout of the tree reducing `num_leaves` leaves by batches of `batch_size`.
+    #[must_use]
+    pub fn new(num_leaves: usize, batch_size: usize) -> Self {
+        assert!(num_leaves > 0, "a reduce tree needs a leaf");
+        assert!(batch_size > 1, "a reduce tree needs batches of two nodes or more");
+        let mut layers = vec![num_leaves];
+        while layers[layers.len() - 1] > 1 {
+            layers.push(layers[layers.len() - 1].div_ceil(batch_size));
+        }
+        Self { layers, batch_size }
+    }
+
+    /// The number of layers above the leaves.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// The number of nodes of a layer.
+    #[must_use]
+    pub fn layer_len(&self, layer: usize) -> usize {
+        self.layers[layer]
+    }
+
+    /// The indices in the layer below of the children of a node.
+    #[must_use]
+    pub fn children(&self, layer: usize, index: usize) -> Range<usize> {
+        let start = index * self.batch_size;
+        start..(start + self.batch_size).min(self.layers[layer - 1])
+    }
+}
+
+/// A node of a reduce tree to prove.
+#[derive(Debug)]
+pub enum TreeNode<W, P> {
+    /// A leaf, with its witness.
+    Leaf(W),
+    /// A node above the leaves, with the proofs of its children.
+    Inner {
+        /// The proofs of the children, in order.
+        children: Vec<P>,
+        /// Whether the node is the root of the tree.
+        is_root: bool,
+    },
+}
+
+/// The NUMA nodes of the machine, with the CPUs of every node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumaTopology {
+    nodes: Vec<Vec<usize>>,
+}
+
+impl NumaTopology {
+    /// Detect the NUMA nodes of the machine from sysfs, returning `None` if it has a single node
+    /// or the topology can't be read.
+    #[must_use]
+    pub fn detect() -> Option<Self> {
+        let mut nodes = Vec::new();
+        for entry in fs::read_dir("/sys/devices/system/node").ok()? {
+            let entry = entry.ok()?;
+            let name = entry.file_name();
+            let Some(id) = name.to_str()?.strip_prefix("node") else { continue };
+            let Ok(id) = id.parse::<usize>() else { continue };
+            let cpus = parse_cpu_list(&fs::read_to_string(entry.path().join("cpulist")).ok()?)?;
+            if !cpus.is_empty() {
+                nodes.push((id, cpus));
+            }
+        }
+        nodes.sort_unstable();
+        (nodes.len() > 1).then(|| Self { nodes: nodes.into_iter().map(|(_, cpus)| cpus).collect() })
+    }
+
+    /// The CPUs of every node.
+    #[must_use]
+    pub fn nodes(&self) -> &[Vec<usize>] {
+        &self.nodes
+    }
+
+    /// Pin the current thread to the CPUs of a node, returning whether it succeeded.
+    pub fn pin_current_thread(&self, node: usize) -> bool {
+        set_affinity(&self.nodes[node % self.nodes.len()])
+    }
+}
+
+/// Parse a list of CPUs in the format of sysfs, e.g. `0-3,8,10-11`.
+fn parse_cpu_list(list: &str) -> Option<Vec<usize>> {
+    let mut cpus = Vec::new();
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        match range.split_once('-') {
+            Some((start, end)) => cpus.extend(start.parse::<usize>().ok()?..=end.parse().ok()?),
+            None => cpus.push(range.parse().ok()?),
+        }
+    }
+    Some(cpus)
+}
+
+/// Restrict the current thread to the given CPUs.
+#[cfg(target_os = "linux")]
+fn set_affinity(cpus: &[usize]) -> bool {
+    extern "C" {
+        fn sched_setaffinity(pid: i32, cpusetsize: usize, mask: *const u64) -> i32;
+    }
+    // The size of the `cpu_set_t` of glibc, which covers 1024 CPUs.
+    let mut mask = [0u64; 16];
+    for &cpu in cpus.iter().filter(|&&cpu| cpu < 1024) {
+        mask[cpu / 64] |= 1 << (cpu % 64);
+    }
+    // SAFETY: the mask is a valid CPU set of the given size, and pid 0 is the calling thread.
+    unsafe { sched_setaffinity(0, std::mem::size_of_val(&mask), mask.as_ptr()) == 0 }
+}
+
+/// Restrict the current thread to the given CPUs.
+#[cfg(not(target_os = "linux"))]
+fn set_affinity(_cpus: &[usize]) -> bool {
+    false
+}
+
+/// The progress of the proofs of a tree, shared by the workers.
+struct TreeState<W, P, E> {
+    /// The witnesses of the leaves which are not proven yet.
+    leaves: Vec<Option<W>>,
+    /// The proofs of the nodes whose parent is not proven yet, by layer.
+    proofs: Vec<Vec<Option<P>>>,
+    /// The number of children of every node above the leaves which are not proven yet, by layer.
+    pending: Vec<Vec<usize>>,
+    /// The nodes ready to be proven, from the highest layer.
+    ready: BinaryHeap<(usize, Reverse<usize>)>,
+    /// The proof of the root, once proven.
+    root: Option<P>,
+    /// The first error of a worker, which stops the others.
+    error: Option<E>,
+    /// The payload of the first panic of a worker, which stops the others and is resumed once
+    /// they have stopped.
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+/// Prove the nodes of a reduce tree with `workers` threads, returning the proof of its root.
+///
+/// A node is proven as soon as its children are, the nodes higher in the tree first, so that the
+/// proofs of their children are freed early. The workers are pinned to the NUMA nodes of
+/// `topology` in turn, if any. The proving stops at the first error, or at the first panic, which
+/// is resumed on the calling thread.
+pub fn prove_tree<W, P, E>(
+    tree: &ReduceTree,
+    leaves: Vec<W>,
+    workers: usize,
+    topology: Option<&NumaTopology>,
+    prove: impl Fn(TreeNode<W, P>) -> Result<P, E> + Sync,
+) -> Result<P, E>
+where
+    W: Send,
+    P: Send,
+    E: Send,
+{
+    assert_eq!(leaves.len(), tree.layer_len(0), "the number of leaves doesn't match the tree");
+    let height = tree.height();
+    let state = Mutex::new(TreeState {
+        ready: (0..leaves.len()).map(|index| (0, Reverse(index))).collect(),
+        leaves: leaves.into_iter().map(Some).collect(),
+        proofs: (0..height)
+            .map(|layer| (0..tree.layer_len(layer)).map(|_| None).collect())
+            .collect(),
+        pending: (0..=height)
+            .map(|layer| {
+                (0..tree.layer_len(layer))
+                    .map(|index| if layer == 0 { 0 } else { tree.children(layer, index).len() })
+                    .collect()
+            })
+            .collect(),
+        root: None,
+        error: None,
+        panic: None,
+    });
+    let changed = Condvar::new();
+
+    let worker = |id: usize| {
+        if let Some(topology) = topology {
+            if !topology.pin_current_thread(id) {
+                tracing::warn!("failed to pin the reduce worker {id} to a NUMA node");
+            }
+        }
+        loop {
+            // Take the next ready node, along with its input.
+            let (layer, index, node) = {
+                let mut state = state.lock().unwrap();
+                loop {
+                    if state.root.is_some() || state.error.is_some() || state.panic.is_some() {
+                        return;
+                    }
+                    if let Some((layer, Reverse(index))) = state.ready.pop() {
+                        let node = if layer == 0 {
+                            TreeNode::Leaf(state.leaves[index].take().unwrap())
+                        } else {
+                            let children = tree
+                                .children(layer, index)
+                                .map(|child| state.proofs[layer - 1][child].take().unwrap())
+                                .collect();
+                            TreeNode::Inner { children, is_root: layer == height }
+                        };
+                        break (layer, index, node);
+                    }
+                    state = changed.wait(state).unwrap();
+                }
+            };
+
+            // A panic is caught so that the other workers are woken up to stop, rather than
+            // waiting for the proof of this node forever.
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                tracing::debug_span!("prove reduce node", layer, index).in_scope(|| prove(node))
+            }));
+
+            let mut state = state.lock().unwrap();
+            match result {
+                Ok(Ok(proof)) if layer == height => state.root = Some(proof),
+                Ok(Ok(proof)) => {
+                    state.proofs[layer][index] = Some(proof);
+                    let parent = index / tree.batch_size;
+                    state.pending[layer + 1][parent] -= 1;
+                    if state.pending[layer + 1][parent] == 0 {
+                        state.ready.push((layer + 1, Reverse(parent)));
+                    }
+                }
+                Ok(Err(e)) => {
+                    state.error.get_or_insert(e);
+                }
+                Err(payload) => {
+                    state.panic.get_or_insert(payload);
+                }
+            }
+            changed.notify_all();
+        }
+    };
+
+    thread::scope(|s| {
+        for id in 0..workers.max(1) {
+            let worker = &worker;
+            s.spawn(move || worker(id));
+        }
+    });
+
+    let state = state.into_inner().unwrap();
+    if let Some(payload) = state.panic {
+        panic::resume_unwind(payload);
+    }
+    match state.error {
+        Some(e) => Err(e),
+        None => Ok(state.root.expect("the workers stopped before proving the root")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use super::{parse_cpu_list, prove_tree, ReduceTree, TreeNode};
+    use crate::{utils::reduce_tree_height, REDUCE_BATCH_SIZE};
+
+    #[test]
+    fn test_reduce_tree_layout() {
+        for num_leaves in 1..100 {
+            let tree = ReduceTree::new(num_leaves, REDUCE_BATCH_SIZE);
+            assert_eq!(tree.height(), reduce_tree_height(num_leaves), "{num_leaves} leaves");
+        }
+        let tree = ReduceTree::new(5, 2);
+        assert_eq!((1..=3).map(|layer| tree.layer_len(layer)).collect::<Vec<_>>(), [3, 2, 1]);
+        assert_eq!(tree.children(1, 2), 4..5);
+        assert_eq!(tree.children(3, 0), 0..2);
+    }
+
+    #[test]
+    fn test_prove_tree() {
+        // Every proof is the list of the leaves below the node, which must come in order.
+        let tree = ReduceTree::new(11, 2);
+        let root = prove_tree::<_, _, ()>(&tree, (0..11).collect(), 4, None, |node| match node {
+            TreeNode::Leaf(leaf) => Ok(vec![leaf]),
+            TreeNode::Inner { children, is_root } => {
+                assert_eq!(is_root, children.iter().map(Vec::len).sum::<usize>() == 11);
+                Ok(children.concat())
+            }
+        });
+        assert_eq!(root, Ok((0..11).collect::<Vec<_>>()));
+
+        // A single leaf is the root.
+        let tree = ReduceTree::new(1, 2);
+        assert_eq!(prove_tree::<_, _, ()>(&tree, vec![7], 2, None, |_| Ok(7)), Ok(7));
+
+        // The first error stops the workers.
+        let tree = ReduceTree::new(8, 2);
+        let result = prove_tree(&tree, (0..8).collect(), 3, None, |node| match node {
+            TreeNode::Leaf(5) => Err("leaf 5"),
+            _ => Ok(()),
+        });
+        assert_eq!(result, Err("leaf 5"));
+
+        // A panic stops the workers too, and is resumed by the caller.
+        let result = panic::catch_unwind(|| {
+            prove_tree::<_, _, ()>(&tree, (0..8).collect(), 3, None, |node| match node {
+                TreeNode::Leaf(5) => panic!("leaf 5"),
+                _ => Ok(()),
+            })
+        });
+        assert_eq!(*result.unwrap_err().downcast::<&str>().unwrap(), "leaf 5");
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,8,10-11\n"), Some(vec![0, 1, 2, 3, 8, 10, 11]));
+        assert_eq!(parse_cpu_list(""), Some(vec![]));
+        assert_eq!(parse_cpu_list("0-x"), None);
+    }
+}
@@ -16,7 +16,10 @@ use sp1_recursion_circuit::machine::{
 
 use sp1_recursion_gnark_ffi::proof::{Groth16Bn254Proof, PlonkBn254Proof};
 
-use sp1_stark::{ShardProof, StarkGenericConfig, StarkProvingKey, StarkVerifyingKey, DIGEST_SIZE};
+use sp1_stark::{
+    MemoryCapExceeded, MemoryWatermarks, ShardProof, StarkGenericConfig, StarkProvingKey,
+    StarkVerifyingKey, DIGEST_SIZE,
+};
 use thiserror::Error;
 
 use crate::{
@@ -114,6 +117,9 @@ pub struct SP1ProofWithMetadata<P: Clone> {
     pub stdin: SP1Stdin,
     pub public_values: SP1PublicValues,
     pub cycles: u64,
+    /// The peak resident set size of each proving stage.
+    #[serde(default)]
+    pub memory: MemoryWatermarks,
 }
 
 impl<P: Serialize + DeserializeOwned + Clone> SP1ProofWithMetadata<P> {
@@ -220,6 +226,8 @@ pub enum SP1ReduceProofWrapper {
 pub enum SP1RecursionProverError {
     #[error("Runtime error: {0}")]
     RuntimeError(String),
+    #[error("{0}")]
+    MemoryCapExceeded(MemoryCapExceeded),
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -19,7 +19,7 @@ use sp1_recursion_core::{
 };
 use sp1_stark::{baby_bear_poseidon2::MyHash as InnerHash, SP1CoreOpts, Word};
 
-use crate::{InnerSC, SP1CoreProofData};
+use crate::{InnerSC, SP1CoreProofData, REDUCE_BATCH_SIZE};
 
 /// Get the SP1 vkey BabyBear Poseidon2 digest this reduce proof is representing.
 pub fn sp1_vkey_digest_babybear(proof: &SP1ReduceProof<BabyBearPoseidon2Outer>) -> [BabyBear; 8] {
@@ -103,11 +103,23 @@ impl SP1CoreProofData {
 pub fn get_cycles(elf: &[u8], stdin: &SP1Stdin) -> u64 {
     let program = Program::from(elf).unwrap();
     let mut runtime = Executor::new(program, SP1CoreOpts::default());
-    runtime.write_vecs(&stdin.buffer);
+    stdin.write_to(&mut runtime);
     runtime.run_fast().unwrap();
     runtime.state.global_clk
 }
 
+/// The height of the tree of recursive proofs that `SP1Prover::compress` builds over the given
+/// number of first layer inputs, which are the shard proofs and the batches of deferred proofs.
+pub fn reduce_tree_height(num_first_layer_inputs: usize) -> usize {
+    let mut height = if num_first_layer_inputs == 1 { 0 } else { 1 };
+    let mut num_layer_inputs = num_first_layer_inputs;
+    while num_layer_inputs > REDUCE_BATCH_SIZE {
+        num_layer_inputs = num_layer_inputs.div_ceil(2);
+        height += 1;
+    }
+    height
+}
+
 /// Load an ELF file from a given path.
 pub fn load_elf(path: &str) -> Result<Vec<u8>, std::io::Error> {
     let mut elf_code = Vec::new();
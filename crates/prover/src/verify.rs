@@ -164,13 +164,23 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
         // Exit code constraints.
         //
-        // - In every shard, the exit code should be zero.
+        // - If it's not a shard with "CPU", then the exit code should be zero.
+        // - If it's a shard with "CPU" that does not halt, then the exit code should be zero.
+        //
+        // The CPU chip only lets a typed halt commit to a non-zero exit code, so the exit code of
+        // a failure such as a panic cannot be proven.
         for shard_proof in proof.0.iter() {
             let public_values: &PublicValues<Word<_>, _> =
                 shard_proof.public_values.as_slice().borrow();
-            if public_values.exit_code != BabyBear::zero() {
+            if !shard_proof.contains_cpu() && public_values.exit_code != BabyBear::zero() {
+                return Err(MachineVerificationError::InvalidPublicValues(
+                    "exit_code != 0: exit code should be zero for non-cpu shards",
+                ));
+            } else if public_values.next_pc != BabyBear::zero()
+                && public_values.exit_code != BabyBear::zero()
+            {
                 return Err(MachineVerificationError::InvalidPublicValues(
-                    "exit_code != 0: exit code should be zero for all shards",
+                    "exit_code != 0: exit code should be zero for shards that do not halt",
                 ));
             }
         }
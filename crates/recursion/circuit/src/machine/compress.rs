@@ -124,7 +124,7 @@ where
         let mut pc: Felt<_> = unsafe { MaybeUninit::zeroed().assume_init() };
         let mut shard: Felt<_> = unsafe { MaybeUninit::zeroed().assume_init() };
 
-        let mut exit_code: Felt<_> = builder.uninit();
+        let mut exit_code: Felt<_> = builder.eval(C::F::zero());
 
         let mut execution_shard: Felt<_> = unsafe { MaybeUninit::zeroed().assume_init() };
         let mut initial_reconstruct_challenger_values: ChallengerPublicValues<Felt<C::F>> =
@@ -193,8 +193,9 @@ where
                 builder.assert_felt_eq(*expected, *actual);
             }
 
-            // Set the exit code, it is already constrained to be zero in the previous proof.
-            exit_code = current_public_values.exit_code;
+            // Only the proof containing the halting shard has a non-zero exit code, so the sum over
+            // the proofs is the exit code of the program.
+            exit_code = builder.eval(exit_code + current_public_values.exit_code);
 
             if i == 0 {
                 // Initialize global and accumulated values.
@@ -156,7 +156,7 @@ where
             unsafe { MaybeUninit::zeroed().assume_init() };
 
         // Initialize the exit code variable.
-        let mut exit_code: Felt<_> = unsafe { MaybeUninit::zeroed().assume_init() };
+        let mut exit_code: Felt<_> = builder.eval(C::F::zero());
 
         // Initialize the public values digest.
         let mut committed_value_digest: [Word<Felt<_>>; PV_DIGEST_NUM_WORDS] =
@@ -222,9 +222,6 @@ where
                     *first_bit = *pub_bit;
                 }
 
-                // Exit code.
-                exit_code = public_values.exit_code;
-
                 // Committed public values digests.
                 for (word, first_word) in committed_value_digest
                     .iter_mut()
@@ -363,8 +360,22 @@ where
 
             // Exit code constraints.
             {
-                // Assert that the exit code is zero (success) for all proofs.
-                builder.assert_felt_eq(exit_code, C::F::zero());
+                if contains_cpu {
+                    // If it's a shard with "CPU", then the exit code can only be non-zero if the
+                    // shard halts.
+                    builder.assert_felt_eq(
+                        public_values.exit_code * public_values.next_pc,
+                        C::F::zero(),
+                    );
+                } else {
+                    // If it's not a shard with "CPU", then the exit code should be zero.
+                    builder.assert_felt_eq(public_values.exit_code, C::F::zero());
+                }
+
+                // Only the shard that halts can have a non-zero exit code, which the CPU chip only
+                // allows for a typed halt, so the sum over the shards is the exit code the program
+                // chose.
+                exit_code = builder.eval(exit_code + public_values.exit_code);
             }
 
             // Memory initialization & finalization constraints.
@@ -476,9 +487,6 @@ where
                     }
                 }
 
-                // Update the exit code.
-                exit_code = public_values.exit_code;
-
                 // If `deferred_proofs_digest` is not zero, then the current value should be equal
                 // to `public_values.deferred_proofs_digest.
 
@@ -535,9 +543,6 @@ where
             }
         }
 
-        // Assert that the last exit code is zero.
-        builder.assert_felt_eq(exit_code, C::F::zero());
-
         // Write all values to the public values struct and commit to them.
         {
             // Compute the vk digest.
@@ -191,6 +191,9 @@ where
             // Assert that the proof is complete.
             builder.assert_felt_eq(current_public_values.is_complete, C::F::one());
 
+            // Assert that the deferred execution exited successfully.
+            builder.assert_felt_eq(current_public_values.exit_code, C::F::zero());
+
             // Update deferred proof digest
             // poseidon2( current_digest[..8] || pv.sp1_vk_digest[..8] ||
             // pv.committed_value_digest[..32] )
@@ -237,7 +240,8 @@ where
         deferred_public_values.leaf_challenger = values;
         deferred_public_values.start_reconstruct_challenger = values;
         deferred_public_values.end_reconstruct_challenger = values;
-        // Set the exit code to be zero for now.
+        // Set the exit code to be zero, since the exit code of the program is carried by the proof
+        // of its halting shard.
         deferred_public_values.exit_code = builder.eval(C::F::zero());
         // Assign the deferred proof digests.
         deferred_public_values.end_reconstruct_deferred_digest = reconstruct_deferred_digest;
@@ -16,8 +16,8 @@ use sp1_recursion_compiler::{
 };
 use sp1_stark::{
     air::InteractionScope, baby_bear_poseidon2::BabyBearPoseidon2, AirOpenedValues, Challenger,
-    Chip, ChipOpenedValues, InnerChallenge, ProofShape, ShardCommitment, ShardOpenedValues,
-    ShardProof, Val, PROOF_MAX_NUM_PVS,
+    Chip, ChipOpenedValues, InnerChallenge, InteractionKind, ProofShape, ShardCommitment,
+    ShardOpenedValues, ShardProof, Val, PROOF_MAX_NUM_PVS,
 };
 use sp1_stark::{air::MachineAir, StarkGenericConfig, StarkMachine, StarkVerifyingKey};
 
@@ -282,22 +282,25 @@ where
             public_values,
         } = proof;
 
-        // Assert that the byte multiplicities don't overflow.
-        let mut max_byte_lookup_mult = 0u64;
-        chips.iter().zip(opened_values.chips.iter()).for_each(|(chip, val)| {
-            max_byte_lookup_mult = max_byte_lookup_mult
-                .checked_add(
-                    (chip.num_sent_byte_lookups() as u64)
-                        .checked_mul(1u64.checked_shl(val.log_degree as u32).unwrap())
-                        .unwrap(),
-                )
-                .unwrap();
-        });
-
-        assert!(
-            max_byte_lookup_mult <= SC::Val::order().to_u64().unwrap(),
-            "Byte multiplicities overflow"
-        );
+        // Assert that the byte multiplicities don't overflow. Each byte bus has its own table, so
+        // the bound applies to the lookups of every bus separately.
+        for bus in InteractionKind::byte_buses() {
+            let mut max_byte_lookup_mult = 0u64;
+            chips.iter().zip(opened_values.chips.iter()).for_each(|(chip, val)| {
+                max_byte_lookup_mult = max_byte_lookup_mult
+                    .checked_add(
+                        (chip.num_sends_by_kind(bus) as u64)
+                            .checked_mul(1u64.checked_shl(val.log_degree as u32).unwrap())
+                            .unwrap(),
+                    )
+                    .unwrap();
+            });
+
+            assert!(
+                max_byte_lookup_mult <= SC::Val::order().to_u64().unwrap(),
+                "{bus} multiplicities overflow"
+            );
+        }
 
         let log_degrees = opened_values.chips.iter().map(|val| val.log_degree).collect::<Vec<_>>();
 
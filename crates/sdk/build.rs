@@ -1,3 +1,3 @@
 fn main() {
-    vergen::EmitBuilder::builder().build_timestamp().git_sha(true).emit().unwrap();
+    vergen::EmitBuilder::builder().build_timestamp().git_sha(true).rustc_semver().emit().unwrap();
 }
@@ -1,13 +1,19 @@
-use sp1_core_executor::{ExecutionReport, HookEnv, SP1ContextBuilder};
+use sp1_core_executor::{
+    ExecutionReport, GasCostTable, HintPrefetch, HintReplay, HookEnv, InstructionFilter,
+    MemcpySymbols, SP1ContextBuilder, TracedInstruction, WatchedWrite,
+};
 use sp1_core_machine::io::SP1Stdin;
 use sp1_primitives::io::SP1PublicValues;
 use sp1_prover::{components::DefaultProverComponents, SP1ProvingKey};
 
 use anyhow::{Ok, Result};
 use sp1_stark::{SP1CoreOpts, SP1ProverOpts};
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
-use crate::{provers::ProofOpts, Prover, SP1ProofKind, SP1ProofWithPublicValues};
+use crate::{
+    bundle::SP1ProofBundle, provers::ProofOpts, HashableKey, Prover, SP1ProofKind,
+    SP1ProofWithPublicValues,
+};
 
 /// Builder to prepare and configure execution of a program on an input.
 /// May be run with [Self::run].
@@ -16,6 +22,7 @@ pub struct Execute<'a> {
     context_builder: SP1ContextBuilder<'a>,
     elf: &'a [u8],
     stdin: SP1Stdin,
+    memcpy_advice: bool,
 }
 
 impl<'a> Execute<'a> {
@@ -28,12 +35,16 @@ impl<'a> Execute<'a> {
         elf: &'a [u8],
         stdin: SP1Stdin,
     ) -> Self {
-        Self { prover, elf, stdin, context_builder: Default::default() }
+        Self { prover, elf, stdin, context_builder: Default::default(), memcpy_advice: false }
     }
 
     /// Execute the program on the input, consuming the built action `self`.
     pub fn run(self) -> Result<(SP1PublicValues, ExecutionReport)> {
-        let Self { prover, elf, stdin, mut context_builder } = self;
+        let Self { prover, elf, stdin, mut context_builder, memcpy_advice } = self;
+        if memcpy_advice {
+            let symbols = MemcpySymbols::from_elf(elf).map_err(|e| anyhow::anyhow!(e))?;
+            context_builder.memcpy_advice(symbols);
+        }
         let context = context_builder.build();
         Ok(prover.sp1_prover().execute(elf, &stdin, context)?)
     }
@@ -52,6 +63,25 @@ impl<'a> Execute<'a> {
         self
     }
 
+    /// Add a breakpoint, called before the instruction at the program counter `pc` is executed.
+    ///
+    /// The state of the program can be inspected through the [`HookEnv`], without changing the
+    /// program.
+    pub fn with_breakpoint(mut self, pc: u32, f: impl FnMut(HookEnv) + Send + Sync + 'a) -> Self {
+        self.context_builder.breakpoint(pc, f);
+        self
+    }
+
+    /// Add a watchpoint, called after every write to a word of memory overlapping `addrs`.
+    pub fn with_watchpoint(
+        mut self,
+        addrs: std::ops::Range<u32>,
+        f: impl FnMut(HookEnv, WatchedWrite) + Send + Sync + 'a,
+    ) -> Self {
+        self.context_builder.watchpoint(addrs, f);
+        self
+    }
+
     /// Avoid registering the default hooks in the runtime.
     ///
     /// It is not necessary to call this to override hooks --- instead, simply
@@ -64,7 +94,7 @@ impl<'a> Execute<'a> {
     /// Set the maximum number of cpu cycles to use for execution.
     ///
     /// If the cycle limit is exceeded, execution will return
-    /// [`sp1_core_executor::ExecutionError::ExceededCycleLimit`].
+    /// [`sp1_core_executor::ExecutionError::CycleLimitExceeded`].
     pub fn max_cycles(mut self, max_cycles: u64) -> Self {
         self.context_builder.max_cycles(max_cycles);
         self
@@ -75,6 +105,79 @@ impl<'a> Execute<'a> {
         self.context_builder.set_skip_deferred_proof_verification(value);
         self
     }
+
+    /// Report the call sites of the software `memcpy`, `memmove` and `memset` routines, with
+    /// advice on which of them would save cycles by using a memcopy precompile.
+    ///
+    /// See [`ExecutionReport::memcpy_advice`].
+    pub fn memcpy_advice(mut self) -> Self {
+        self.memcpy_advice = true;
+        self
+    }
+
+    /// Set the weights used to meter the gas reported in [`ExecutionReport::gas_used`].
+    pub fn gas_costs(mut self, gas_costs: GasCostTable) -> Self {
+        self.context_builder.gas_costs(gas_costs);
+        self
+    }
+
+    /// Record every access of the program to its inputs to a replay file at `path`.
+    ///
+    /// See [`SP1ContextBuilder::record_hints`].
+    pub fn record_hints(mut self, path: impl Into<PathBuf>) -> Self {
+        self.context_builder.record_hints(path);
+        self
+    }
+
+    /// Reproduce an execution recorded with [`Self::record_hints`], serving the program the
+    /// recorded inputs instead of the stdin and the outputs of the hooks.
+    pub fn replay_hints(mut self, replay: HintReplay) -> Self {
+        self.context_builder.replay_hints(replay);
+        self
+    }
+
+    /// Fetch the responses of the hook calls recorded by a prior run on a background thread.
+    ///
+    /// See [`SP1ContextBuilder::prefetch_hints`].
+    pub fn prefetch_hints(mut self, prefetch: HintPrefetch) -> Self {
+        self.context_builder.prefetch_hints(prefetch);
+        self
+    }
+
+    /// Write the profile of the execution to a trace-event file at `path`, which can be opened in
+    /// Perfetto.
+    ///
+    /// See [`SP1ContextBuilder::trace_events`].
+    pub fn trace_events(mut self, path: impl Into<PathBuf>) -> Self {
+        self.context_builder.trace_events(path);
+        self
+    }
+
+    /// Report the words of memory changed by the execution, with their values before and after
+    /// it, in [`ExecutionReport::state_diff`].
+    pub fn state_diff(mut self) -> Self {
+        self.context_builder.state_diff();
+        self
+    }
+
+    /// Profile the basic blocks and loops executed by the program, to find the hottest loops with
+    /// [`ExecutionReport::hot_loops`].
+    pub fn profile_blocks(mut self) -> Self {
+        self.context_builder.profile_blocks();
+        self
+    }
+
+    /// Stream the executed instructions passing `filter` to `f`.
+    ///
+    /// See [`SP1ContextBuilder::trace_instructions`].
+    pub fn trace_instructions(
+        mut self,
+        filter: InstructionFilter,
+        f: impl FnMut(TracedInstruction) + Send + Sync + 'a,
+    ) -> Self {
+        self.context_builder.trace_instructions(filter, f);
+        self
+    }
 }
 
 /// Builder to prepare and configure proving execution of a program on an input.
@@ -142,6 +245,23 @@ impl<'a> Prove<'a> {
         prover.prove(pk, stdin, proof_opts, context, kind)
     }
 
+    /// Prove the execution of the program on the input like [Self::run], and bundle the proof with
+    /// the report of the execution for archival.
+    ///
+    /// The program is executed once more to collect the report, with the same context.
+    pub fn run_bundle(self) -> Result<SP1ProofBundle> {
+        let (prover, pk) = (self.prover, self.pk);
+        let context = self.context_builder.clone().build();
+        let (public_values, report) = prover.sp1_prover().execute(&pk.elf, &self.stdin, context)?;
+        let proof = self.run()?;
+        if proof.public_values.as_slice() != public_values.as_slice() {
+            return Err(anyhow::anyhow!(
+                "the public values of the proof do not match the execution"
+            ));
+        }
+        Ok(SP1ProofBundle::new(proof, report, &pk.elf, pk.vk.bytes32()))
+    }
+
     /// Set the proof kind to the core mode. This is the default.
     pub fn core(mut self) -> Self {
         self.kind = SP1ProofKind::Core;
@@ -210,7 +330,7 @@ impl<'a> Prove<'a> {
     /// Set the maximum number of cpu cycles to use for execution.
     ///
     /// If the cycle limit is exceeded, execution will return
-    /// [`sp1_core_executor::ExecutionError::ExceededCycleLimit`].
+    /// [`sp1_core_executor::ExecutionError::CycleLimitExceeded`].
     pub fn cycle_limit(mut self, cycle_limit: u64) -> Self {
         self.context_builder.max_cycles(cycle_limit);
         self
@@ -229,4 +349,36 @@ impl<'a> Prove<'a> {
         self.context_builder.set_skip_deferred_proof_verification(value);
         self
     }
+
+    /// Record every access of the program to its inputs to a replay file at `path`.
+    ///
+    /// See [`SP1ContextBuilder::record_hints`].
+    pub fn record_hints(mut self, path: impl Into<PathBuf>) -> Self {
+        self.context_builder.record_hints(path);
+        self
+    }
+
+    /// Reproduce an execution recorded with [`Self::record_hints`], serving the program the
+    /// recorded inputs instead of the stdin and the outputs of the hooks.
+    pub fn replay_hints(mut self, replay: HintReplay) -> Self {
+        self.context_builder.replay_hints(replay);
+        self
+    }
+
+    /// Fetch the responses of the hook calls recorded by a prior run on a background thread.
+    ///
+    /// See [`SP1ContextBuilder::prefetch_hints`].
+    pub fn prefetch_hints(mut self, prefetch: HintPrefetch) -> Self {
+        self.context_builder.prefetch_hints(prefetch);
+        self
+    }
+
+    /// Write the profile of the execution to a trace-event file at `path`, which can be opened in
+    /// Perfetto.
+    ///
+    /// See [`SP1ContextBuilder::trace_events`].
+    pub fn trace_events(mut self, path: impl Into<PathBuf>) -> Self {
+        self.context_builder.trace_events(path);
+        self
+    }
 }
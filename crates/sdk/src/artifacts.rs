@@ -10,7 +10,9 @@ use {
     std::{cmp::min, fs::File, io::Write},
 };
 
-pub use sp1_prover::build::build_plonk_bn254_artifacts_with_dummy;
+pub use sp1_prover::build::{
+    build_groth16_bn254_artifacts_with_dummy, build_plonk_bn254_artifacts_with_dummy,
+};
 
 use crate::install::try_install_circuit_artifacts;
 
@@ -0,0 +1,139 @@
+//! Archival bundles of a proof and the execution it proves.
+//!
+//! An [`SP1ProofBundle`] keeps a proof together with the execution report of the same program and
+//! input, the digest of its guest log, and the versions that produced them, so that a proof can be
+//! audited long after it was generated from a single file. Bundles are produced by
+//! [`Prove::run_bundle`](crate::action::Prove::run_bundle) and read back with
+//! [`SP1ProofBundle::load`].
+
+use std::{fs::File, path::Path};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sp1_core_executor::ExecutionReport;
+use sp1_core_machine::SP1_CIRCUIT_VERSION;
+
+use crate::SP1ProofWithPublicValues;
+
+/// The version of the bundle format written by this crate.
+pub const SP1_PROOF_BUNDLE_VERSION: u32 = 2;
+
+/// The versions and digests identifying what produced a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleMetadata {
+    /// The version of the bundle format.
+    pub bundle_version: u32,
+    /// The SP1 circuit version of the prover.
+    pub sp1_version: String,
+    /// The version of the SDK.
+    pub sdk_version: String,
+    /// The SHA-256 digest of the ELF, in hex.
+    pub elf_digest: String,
+    /// The verifying key hash of the program, in the format of `SP1VerifyingKey::bytes32`.
+    pub vkey_hash: String,
+}
+
+/// A proof bundled together with the execution report of the proven execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SP1ProofBundle {
+    /// The versions and digests identifying what produced the bundle.
+    pub metadata: BundleMetadata,
+    /// The proof, with its stdin and public values.
+    pub proof: SP1ProofWithPublicValues,
+    /// The report of the execution of the program on the stdin of the proof.
+    pub report: ExecutionReport,
+    /// The running digest of the guest log of the report, in hex.
+    pub guest_log_digest: String,
+}
+
+impl SP1ProofBundle {
+    /// Bundles a proof with the report of its execution.
+    pub fn new(
+        proof: SP1ProofWithPublicValues,
+        report: ExecutionReport,
+        elf: &[u8],
+        vkey_hash: String,
+    ) -> Self {
+        let metadata = BundleMetadata {
+            bundle_version: SP1_PROOF_BUNDLE_VERSION,
+            sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+            sdk_version: env!("CARGO_PKG_VERSION").to_string(),
+            elf_digest: hex::encode(Sha256::digest(elf)),
+            vkey_hash,
+        };
+        let guest_log_digest = hex::encode(report.guest_log.digest);
+        Self { metadata, proof, report, guest_log_digest }
+    }
+
+    /// Saves the bundle to a path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        bincode::serialize_into(File::create(path)?, self).map_err(Into::into)
+    }
+
+    /// Loads a bundle from a path, and checks that it is consistent.
+    ///
+    /// See [`Self::check`] for what is checked.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let bundle: Self = bincode::deserialize_from(File::open(path)?)?;
+        bundle.check()?;
+        Ok(bundle)
+    }
+
+    /// Checks that the bundle was written in a supported format, that the guest log matches its
+    /// digest, and that the exit code of the report matches the one the proof commits to.
+    ///
+    /// The lines of the guest log are only checked against the digest if the report kept all of
+    /// them. This does not verify the proof itself.
+    pub fn check(&self) -> Result<()> {
+        if self.metadata.bundle_version != SP1_PROOF_BUNDLE_VERSION {
+            return Err(anyhow!(
+                "unsupported bundle version {}, expected {}",
+                self.metadata.bundle_version,
+                SP1_PROOF_BUNDLE_VERSION
+            ));
+        }
+        if hex::encode(self.report.guest_log.digest) != self.guest_log_digest
+            || !self.report.guest_log.is_consistent()
+        {
+            return Err(anyhow!("the guest log does not match its digest"));
+        }
+        if let Some(exit_code) = self.proof.exit_code() {
+            if exit_code != self.report.exit_code {
+                return Err(anyhow!(
+                    "the proof commits to exit code {exit_code}, but the report has {}",
+                    self.report.exit_code
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils, HashableKey, ProverClient, SP1Stdin};
+
+    use super::SP1ProofBundle;
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let (pk, vk) = client.setup(test_artifacts::FIBONACCI_ELF);
+        let bundle = client.prove(&pk, SP1Stdin::new()).run_bundle().unwrap();
+        assert_eq!(bundle.metadata.vkey_hash, vk.bytes32());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bundle.bin");
+        bundle.save(&path).unwrap();
+        let loaded = SP1ProofBundle::load(&path).unwrap();
+        assert_eq!(loaded.metadata, bundle.metadata);
+        assert_eq!(loaded.report, bundle.report);
+        assert_eq!(loaded.proof.public_values.to_vec(), bundle.proof.public_values.to_vec());
+
+        let mut tampered = loaded;
+        tampered.report.guest_log.push("stdout: tampered".to_string());
+        assert!(tampered.check().is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Fingerprints of the environment that produced a proof.
+//!
+//! Every [`SP1ProofWithPublicValues`](crate::SP1ProofWithPublicValues) records the
+//! [`ProofEnvironment`] of the prover that generated it, so that a proof whose verification starts
+//! failing long after it was generated can be traced back to the exact toolchain, features, machine
+//! profile and commit of its prover.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use sp1_prover::{components::SP1ProverComponents, SP1Prover};
+use sp1_stark::MachineProver;
+
+/// The name of the machine profile of a prover without an opcode denylist.
+pub const DEFAULT_MACHINE_PROFILE: &str = "default";
+
+/// The toolchain, features, machine profile and commit of the prover that generated a proof.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofEnvironment {
+    /// The version of rustc the SDK was compiled with.
+    pub rustc_version: String,
+    /// The cargo features the SDK was compiled with.
+    pub features: Vec<String>,
+    /// The name of the machine profile of the prover.
+    ///
+    /// This is [`DEFAULT_MACHINE_PROFILE`] unless the prover restricts opcodes with a denylist, in
+    /// which case the name holds the denylist as a hex bitmask.
+    pub machine_profile: String,
+    /// The names of the chips of the core machine, in machine order.
+    pub chips: Vec<String>,
+    /// The commit hash of the sources the SDK was compiled from.
+    pub commit: String,
+}
+
+impl ProofEnvironment {
+    /// The environment of the given prover.
+    pub fn new<C: SP1ProverComponents>(prover: &SP1Prover<C>) -> Self {
+        let machine_profile = if prover.opcode_denylist.is_empty() {
+            DEFAULT_MACHINE_PROFILE.to_string()
+        } else {
+            let [low, high] = prover.opcode_denylist.to_words();
+            format!("denylist-{high:08x}{low:08x}")
+        };
+        let chips = prover.core_prover.machine().chips().iter().map(|chip| chip.name()).collect();
+
+        Self {
+            rustc_version: env!("VERGEN_RUSTC_SEMVER").to_string(),
+            features: enabled_features(),
+            machine_profile,
+            chips,
+            commit: env!("VERGEN_GIT_SHA").to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProofEnvironment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "rustc version: {}", self.rustc_version)?;
+        writeln!(f, "features: [{}]", self.features.join(", "))?;
+        writeln!(f, "machine profile: {}", self.machine_profile)?;
+        writeln!(f, "chips: {}", self.chips.len())?;
+        write!(f, "commit: {}", self.commit)
+    }
+}
+
+/// The prover related cargo features the SDK was compiled with.
+fn enabled_features() -> Vec<String> {
+    [
+        ("network", cfg!(feature = "network")),
+        ("network-v2", cfg!(feature = "network-v2")),
+        ("cuda", cfg!(feature = "cuda")),
+        ("native-gnark", cfg!(feature = "native-gnark")),
+    ]
+    .into_iter()
+    .filter(|&(_, enabled)| enabled)
+    .map(|(feature, _)| feature.to_string())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::{Opcode, OpcodeDenylist};
+    use sp1_prover::{components::DefaultProverComponents, SP1Prover};
+
+    use super::*;
+
+    #[test]
+    fn test_proof_environment() {
+        let prover = SP1Prover::<DefaultProverComponents>::new();
+        let environment = ProofEnvironment::new(&prover);
+        assert_eq!(environment.machine_profile, DEFAULT_MACHINE_PROFILE);
+        assert_eq!(environment.chips.len(), prover.core_prover.machine().chips().len());
+        assert!(environment.chips.iter().any(|chip| chip == "CPU"));
+        assert!(!environment.rustc_version.is_empty());
+
+        let prover = prover.with_opcode_denylist(OpcodeDenylist::from_iter([Opcode::MUL]));
+        let environment = ProofEnvironment::new(&prover);
+        assert_eq!(
+            environment.machine_profile,
+            format!("denylist-{:016x}", 1u64 << Opcode::MUL as u64)
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Tools for measuring the gas cost of verifying proofs onchain.
+//!
+//! [`VerifierGasBench`] deploys the SP1 verifier contract of the installed circuit artifacts into
+//! an in-memory EVM, and [`VerifierGasBench::measure`] submits a Plonk or Groth16 proof to it and
+//! reports the exact gas used, so that changes to the proof encoding or the calldata can be
+//! checked against the onchain budget.
+//!
+//! The contracts are compiled with `solc`, which must be installed. The `SOLC` environment
+//! variable overrides the path of the compiler.
+
+use std::{fmt, path::Path, process::Command};
+
+use alloy_sol_types::{sol, SolCall};
+use anyhow::{anyhow, bail, Context, Result};
+use revm::{
+    db::InMemoryDB,
+    primitives::{Address, Bytes, ExecutionResult, Output, TxKind, B256},
+    Evm,
+};
+use sp1_prover::{HashableKey, SP1VerifyingKey};
+
+use crate::{install::try_install_circuit_artifacts, SP1ProofKind, SP1ProofWithPublicValues};
+
+sol! {
+    function verifyProof(
+        bytes32 programVKey,
+        bytes calldata publicValues,
+        bytes calldata proofBytes
+    ) external view;
+}
+
+/// The name of the verifier contract in the circuit artifacts.
+const VERIFIER_CONTRACT: &str = "SP1Verifier";
+
+/// The account deploying the verifier and submitting the proofs.
+const CALLER: Address = Address::repeat_byte(0x51);
+
+/// The gas every transaction pays before any calldata or execution.
+const TX_BASE_GAS: u64 = 21_000;
+
+/// The gas used to verify a proof onchain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierGasReport {
+    /// The kind of the proof.
+    pub kind: SP1ProofKind,
+    /// The number of bytes of the calldata of the verification call.
+    pub calldata_bytes: usize,
+    /// The gas charged for the calldata.
+    pub calldata_gas: u64,
+    /// The gas used by the verifier contract.
+    pub execution_gas: u64,
+    /// The gas used by the transaction, including the base cost of a transaction.
+    pub total_gas: u64,
+}
+
+impl fmt::Display for VerifierGasReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} verification: {} gas ({} calldata bytes for {} gas, {} execution gas)",
+            self.kind, self.total_gas, self.calldata_bytes, self.calldata_gas, self.execution_gas
+        )
+    }
+}
+
+/// An in-memory EVM with an SP1 verifier contract deployed.
+pub struct VerifierGasBench {
+    evm: Evm<'static, (), InMemoryDB>,
+    kind: SP1ProofKind,
+    verifier: Address,
+}
+
+impl VerifierGasBench {
+    /// Compiles the verifier contract of the circuit artifacts of the given kind of proof, and
+    /// deploys it.
+    ///
+    /// WARNING: If you are on development mode, this function assumes that the circuit artifacts
+    /// have already been built.
+    pub fn new(kind: SP1ProofKind) -> Result<Self> {
+        let (artifacts_dir, source) = match kind {
+            SP1ProofKind::Plonk if sp1_prover::build::sp1_dev_mode() => {
+                (sp1_prover::build::plonk_bn254_artifacts_dev_dir(), "SP1VerifierPlonk.sol")
+            }
+            SP1ProofKind::Plonk => (try_install_circuit_artifacts("plonk"), "SP1VerifierPlonk.sol"),
+            SP1ProofKind::Groth16 if sp1_prover::build::sp1_dev_mode() => {
+                (sp1_prover::build::groth16_bn254_artifacts_dev_dir(), "SP1VerifierGroth16.sol")
+            }
+            SP1ProofKind::Groth16 => {
+                (try_install_circuit_artifacts("groth16"), "SP1VerifierGroth16.sol")
+            }
+            _ => bail!("only Plonk and Groth16 proofs are verifiable onchain"),
+        };
+        let bytecode = compile_verifier(&artifacts_dir, source)?;
+        Self::with_bytecode(kind, bytecode)
+    }
+
+    /// Deploys a verifier contract for the given kind of proof from its creation bytecode.
+    pub fn with_bytecode(kind: SP1ProofKind, bytecode: Vec<u8>) -> Result<Self> {
+        let mut evm = Evm::builder().with_db(InMemoryDB::default()).build();
+        let tx = evm.tx_mut();
+        tx.caller = CALLER;
+        tx.transact_to = TxKind::Create;
+        tx.data = Bytes::from(bytecode);
+
+        let result =
+            evm.transact_commit().map_err(|err| anyhow!("failed to deploy verifier: {err:?}"))?;
+        let verifier = match result {
+            ExecutionResult::Success { output: Output::Create(_, Some(verifier)), .. } => verifier,
+            result => bail!("failed to deploy verifier: {result:?}"),
+        };
+        Ok(Self { evm, kind, verifier })
+    }
+
+    /// The address of the deployed verifier contract.
+    pub fn verifier(&self) -> Address {
+        self.verifier
+    }
+
+    /// Submits a proof to the verifier contract, and reports the gas used to verify it.
+    ///
+    /// Returns an error if the proof is not of the kind of the verifier, or if the verifier
+    /// rejects it.
+    pub fn measure(
+        &mut self,
+        proof: &SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+    ) -> Result<VerifierGasReport> {
+        let kind = SP1ProofKind::from(&proof.proof);
+        if kind != self.kind {
+            bail!("cannot verify a {kind:?} proof with a {:?} verifier", self.kind);
+        }
+
+        let program_vkey: B256 = vk.bytes32().parse().context("invalid verifying key hash")?;
+        let calldata = verifyProofCall {
+            programVKey: program_vkey,
+            publicValues: proof.public_values.to_vec().into(),
+            proofBytes: proof.bytes().into(),
+        }
+        .abi_encode();
+        let calldata_gas = calldata_gas(&calldata);
+        let calldata_bytes = calldata.len();
+
+        let tx = self.evm.tx_mut();
+        tx.caller = CALLER;
+        tx.transact_to = TxKind::Call(self.verifier);
+        tx.data = Bytes::from(calldata);
+
+        let result =
+            self.evm.transact_commit().map_err(|err| anyhow!("failed to submit proof: {err:?}"))?;
+        let total_gas = match result {
+            ExecutionResult::Success { gas_used, .. } => gas_used,
+            ExecutionResult::Revert { output, .. } => {
+                bail!("verifier rejected the proof: 0x{}", hex::encode(output))
+            }
+            ExecutionResult::Halt { reason, .. } => bail!("verifier halted: {reason:?}"),
+        };
+        Ok(VerifierGasReport {
+            kind,
+            calldata_bytes,
+            calldata_gas,
+            execution_gas: total_gas - TX_BASE_GAS - calldata_gas,
+            total_gas,
+        })
+    }
+}
+
+/// The gas charged for the calldata of a transaction, as priced by EIP-2028.
+fn calldata_gas(calldata: &[u8]) -> u64 {
+    calldata.iter().map(|&byte| if byte == 0 { 4 } else { 16 }).sum()
+}
+
+/// Compiles a verifier contract of the circuit artifacts with `solc`, and returns its creation
+/// bytecode.
+fn compile_verifier(artifacts_dir: &Path, source: &str) -> Result<Vec<u8>> {
+    let solc = std::env::var("SOLC").unwrap_or_else(|_| "solc".to_string());
+    let output = Command::new(&solc)
+        .current_dir(artifacts_dir)
+        .args(["--optimize", "--bin", source])
+        .output()
+        .with_context(|| format!("failed to run {solc}"))?;
+    if !output.status.success() {
+        bail!("failed to compile {source}: {}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    // The output lists every contract as a header, followed by a `Binary:` line and the bytecode.
+    let stdout = String::from_utf8(output.stdout).context("invalid solc output")?;
+    let header = format!(":{VERIFIER_CONTRACT} =======");
+    let bytecode = stdout
+        .lines()
+        .skip_while(|line| !line.ends_with(&header))
+        .nth(2)
+        .ok_or_else(|| anyhow!("{VERIFIER_CONTRACT} not found in the output of {solc}"))?;
+    hex::decode(bytecode.trim()).context("invalid verifier bytecode")
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_machine::io::SP1Stdin;
+
+    use super::*;
+    use crate::{utils, ProverClient, SP1PublicValues};
+
+    #[test]
+    fn test_calldata_gas() {
+        assert_eq!(calldata_gas(&[]), 0);
+        assert_eq!(calldata_gas(&[0, 1, 0, 255]), 4 + 16 + 4 + 16);
+    }
+
+    #[test]
+    #[ignore = "requires solc and the plonk circuit artifacts"]
+    fn test_e2e_plonk_verifier_gas() {
+        utils::setup_logger();
+        let client = ProverClient::cpu();
+        let elf = test_artifacts::FIBONACCI_ELF;
+        let (pk, vk) = client.setup(elf);
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        let proof = client.prove(&pk, stdin).plonk().run().unwrap();
+
+        let mut bench = VerifierGasBench::new(SP1ProofKind::Plonk).unwrap();
+        let report = bench.measure(&proof, &vk).unwrap();
+        tracing::info!("{report}");
+        assert_eq!(report.total_gas, TX_BASE_GAS + report.calldata_gas + report.execution_gas);
+
+        // A proof of other public values is rejected.
+        let mut proof = proof;
+        proof.public_values = SP1PublicValues::from(&[255, 4, 84]);
+        assert!(bench.measure(&proof, &vk).is_err());
+    }
+
+    #[test]
+    #[ignore = "requires solc and the groth16 circuit artifacts"]
+    fn test_e2e_groth16_verifier_gas() {
+        utils::setup_logger();
+        let client = ProverClient::cpu();
+        let elf = test_artifacts::FIBONACCI_ELF;
+        let (pk, vk) = client.setup(elf);
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        let proof = client.prove(&pk, stdin).groth16().run().unwrap();
+
+        let mut bench = VerifierGasBench::new(SP1ProofKind::Groth16).unwrap();
+        let report = bench.measure(&proof, &vk).unwrap();
+        tracing::info!("{report}");
+        assert_eq!(report.total_gas, TX_BASE_GAS + report.calldata_gas + report.execution_gas);
+
+        // A proof of other public values is rejected.
+        let mut proof = proof;
+        proof.public_values = SP1PublicValues::from(&[255, 4, 84]);
+        assert!(bench.measure(&proof, &vk).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+//! Tools for inspecting proofs.
+//!
+//! [`inspect`] summarizes the parts of a proof that matter when a proof is rejected downstream: the
+//! verifying key it was generated for, the public values it commits to, and the shape of the STARK
+//! proofs or the size of the wrapped proof.
+
+use std::{borrow::Borrow, fmt};
+
+use num_bigint::BigUint;
+use p3_baby_bear::BabyBear;
+use p3_field::{PrimeField, PrimeField32};
+use sp1_prover::utils::{babybears_to_bn254, reduce_tree_height};
+use sp1_recursion_core::air::{RecursionPublicValues, RECURSIVE_PROOF_NUM_PV_ELTS};
+use sp1_stark::{
+    air::{PublicValues, SP1_PROOF_NUM_PV_ELTS},
+    ShardProof, StarkGenericConfig, Word,
+};
+
+use crate::{environment::ProofEnvironment, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues};
+
+/// A summary of a STARK shard proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShardSummary {
+    /// The shard number, or the first shard covered by a compressed proof.
+    pub shard: u32,
+    /// The start pc of the shard.
+    pub start_pc: u32,
+    /// The start pc of the next shard.
+    pub next_pc: u32,
+    /// The names and log2 trace heights of the chips in the shard, in proof order.
+    pub chips: Vec<(String, usize)>,
+}
+
+/// A summary of a proof, as reported by [`inspect`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofSummary {
+    /// The kind of the proof.
+    pub kind: SP1ProofKind,
+    /// The SP1 version the proof was generated with.
+    pub sp1_version: String,
+    /// The environment of the prover that generated the proof.
+    pub environment: ProofEnvironment,
+    /// The verifying key hash the proof commits to, in the format of `SP1VerifyingKey::bytes32`.
+    ///
+    /// Core proofs do not commit to a verifying key, so this is `None` for them.
+    pub vkey_hash: Option<String>,
+    /// The public values of the proof.
+    pub public_values: Vec<u8>,
+    /// The exit code of the program, if the proof commits to it.
+    pub exit_code: Option<u32>,
+    /// The shard proofs of a core proof, or the shard proof of a compressed proof.
+    pub shards: Vec<ShardSummary>,
+    /// The number of core shards covered by a compressed proof.
+    pub num_core_shards: Option<u32>,
+    /// The height of the recursion tree of a compressed proof, not counting deferred proofs.
+    pub recursion_depth: Option<usize>,
+    /// The size in bytes of the encoded Plonk or Groth16 proof.
+    pub wrap_proof_size: Option<usize>,
+}
+
+/// Summarizes a proof.
+pub fn inspect(proof: &SP1ProofWithPublicValues) -> ProofSummary {
+    let mut summary = ProofSummary {
+        kind: SP1ProofKind::from(&proof.proof),
+        sp1_version: proof.sp1_version.clone(),
+        environment: proof.environment.clone(),
+        vkey_hash: None,
+        public_values: proof.public_values.to_vec(),
+        exit_code: proof.exit_code(),
+        shards: vec![],
+        num_core_shards: None,
+        recursion_depth: None,
+        wrap_proof_size: None,
+    };
+
+    match &proof.proof {
+        SP1Proof::Core(shard_proofs) => {
+            for shard_proof in shard_proofs {
+                if shard_proof.public_values.len() < SP1_PROOF_NUM_PV_ELTS {
+                    continue;
+                }
+                let public_values: &PublicValues<Word<BabyBear>, BabyBear> =
+                    shard_proof.public_values.as_slice().borrow();
+                summary.shards.push(ShardSummary {
+                    shard: public_values.shard.as_canonical_u32(),
+                    start_pc: public_values.start_pc.as_canonical_u32(),
+                    next_pc: public_values.next_pc.as_canonical_u32(),
+                    chips: chip_heights(shard_proof),
+                });
+            }
+        }
+        SP1Proof::Compressed(reduce_proof) => {
+            let shard_proof = &reduce_proof.proof;
+            if shard_proof.public_values.len() >= RECURSIVE_PROOF_NUM_PV_ELTS {
+                let public_values: &RecursionPublicValues<BabyBear> =
+                    shard_proof.public_values.as_slice().borrow();
+                let num_core_shards = public_values.next_shard.as_canonical_u32()
+                    - public_values.start_shard.as_canonical_u32();
+
+                summary.vkey_hash = Some(bytes32(
+                    babybears_to_bn254(&public_values.sp1_vk_digest).as_canonical_biguint(),
+                ));
+                summary.num_core_shards = Some(num_core_shards);
+                summary.recursion_depth = Some(reduce_tree_height(num_core_shards as usize));
+                summary.shards.push(ShardSummary {
+                    shard: public_values.start_shard.as_canonical_u32(),
+                    start_pc: public_values.start_pc.as_canonical_u32(),
+                    next_pc: public_values.next_pc.as_canonical_u32(),
+                    chips: chip_heights(shard_proof),
+                });
+            }
+        }
+        SP1Proof::Plonk(plonk_proof) => {
+            summary.vkey_hash = parse_vkey_hash(&plonk_proof.public_inputs[0]);
+            summary.wrap_proof_size = Some(plonk_proof.encoded_proof.len() / 2);
+        }
+        SP1Proof::Groth16(groth16_proof) => {
+            summary.vkey_hash = parse_vkey_hash(&groth16_proof.public_inputs[0]);
+            summary.wrap_proof_size = Some(groth16_proof.encoded_proof.len() / 2);
+        }
+    }
+
+    summary
+}
+
+fn chip_heights<SC: StarkGenericConfig>(shard_proof: &ShardProof<SC>) -> Vec<(String, usize)> {
+    let mut chips = shard_proof.chip_ordering.iter().collect::<Vec<_>>();
+    chips.sort_by_key(|(_, &index)| index);
+    chips
+        .into_iter()
+        .map(|(name, &index)| (name.clone(), shard_proof.opened_values.chips[index].log_degree))
+        .collect()
+}
+
+fn bytes32(value: BigUint) -> String {
+    format!("0x{:0>64}", value.to_str_radix(16))
+}
+
+/// Parses the decimal verifying key hash in the public inputs of a wrapped proof.
+fn parse_vkey_hash(public_input: &str) -> Option<String> {
+    BigUint::parse_bytes(public_input.as_bytes(), 10).map(bytes32)
+}
+
+impl fmt::Display for ProofSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "proof kind: {:?}", self.kind)?;
+        writeln!(f, "sp1 version: {}", self.sp1_version)?;
+        writeln!(f, "{}", self.environment)?;
+        if let Some(vkey_hash) = &self.vkey_hash {
+            writeln!(f, "vkey hash: {vkey_hash}")?;
+        }
+        writeln!(
+            f,
+            "public values: 0x{} ({} bytes)",
+            hex::encode(&self.public_values),
+            self.public_values.len()
+        )?;
+        if let Some(exit_code) = self.exit_code {
+            writeln!(f, "exit code: {exit_code}")?;
+        }
+        if let Some(num_core_shards) = self.num_core_shards {
+            writeln!(f, "core shards: {num_core_shards}")?;
+        }
+        if let Some(recursion_depth) = self.recursion_depth {
+            writeln!(f, "recursion depth: {recursion_depth}")?;
+        }
+        if let Some(wrap_proof_size) = self.wrap_proof_size {
+            writeln!(f, "wrap proof size: {wrap_proof_size} bytes")?;
+        }
+        if self.kind == SP1ProofKind::Core {
+            writeln!(f, "shards: {}", self.shards.len())?;
+        }
+        for shard in &self.shards {
+            writeln!(f, "shard {}: pc {:#x} -> {:#x}", shard.shard, shard.start_pc, shard.next_pc)?;
+            for (name, log_height) in &shard.chips {
+                writeln!(f, "  {name}: 2^{log_height}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils, HashableKey, ProverClient, SP1Stdin};
+
+    use super::inspect;
+
+    #[test]
+    fn test_inspect_plonk() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let (pk, vk) = client.setup(test_artifacts::FIBONACCI_ELF);
+        let proof = client.prove(&pk, SP1Stdin::new()).plonk().run().unwrap();
+
+        let summary = inspect(&proof);
+        assert_eq!(summary.vkey_hash, Some(vk.bytes32()));
+        assert_eq!(summary.public_values, proof.public_values.to_vec());
+        assert_eq!(summary.wrap_proof_size, Some(0));
+        assert!(summary.shards.is_empty());
+    }
+}
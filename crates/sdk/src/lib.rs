@@ -7,6 +7,11 @@
 
 pub mod action;
 pub mod artifacts;
+pub mod bundle;
+pub mod environment;
+#[cfg(feature = "evm-gas")]
+pub mod evm_gas;
+pub mod inspect;
 pub mod install;
 #[cfg(feature = "network")]
 pub mod network;
@@ -25,6 +30,8 @@ pub use crate::provers::CudaProver;
 
 pub mod proof;
 pub mod provers;
+pub mod shadow;
+pub mod vk;
 pub mod utils {
     pub use sp1_core_machine::utils::setup_logger;
 }
@@ -40,7 +47,9 @@ use {std::future::Future, tokio::task::block_in_place};
 pub use provers::{CpuProver, MockProver, Prover};
 
 pub use sp1_build::include_elf;
-pub use sp1_core_executor::{ExecutionReport, HookEnv, SP1Context, SP1ContextBuilder};
+pub use sp1_core_executor::{
+    ExecutionReport, GasCostTable, HookEnv, SP1Context, SP1ContextBuilder,
+};
 pub use sp1_core_machine::{io::SP1Stdin, riscv::cost::CostEstimator, SP1_CIRCUIT_VERSION};
 pub use sp1_primitives::io::SP1PublicValues;
 pub use sp1_prover::{
@@ -562,4 +571,36 @@ mod tests {
         let proof = client.prove(&pk, stdin).plonk().run().unwrap();
         client.verify(&proof, &vk).unwrap();
     }
+
+    #[test]
+    fn test_e2e_prove_groth16() {
+        utils::setup_logger();
+        let client = ProverClient::cpu();
+        let elf = test_artifacts::FIBONACCI_ELF;
+        let (pk, vk) = client.setup(elf);
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+
+        // Generate proof & verify.
+        let mut proof = client.prove(&pk, stdin).groth16().run().unwrap();
+        client.verify(&proof, &vk).unwrap();
+
+        // Test invalid public values.
+        proof.public_values = SP1PublicValues::from(&[255, 4, 84]);
+        if client.verify(&proof, &vk).is_ok() {
+            panic!("verified proof with invalid public values")
+        }
+    }
+
+    #[test]
+    fn test_e2e_prove_groth16_mock() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let elf = test_artifacts::FIBONACCI_ELF;
+        let (pk, vk) = client.setup(elf);
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        let proof = client.prove(&pk, stdin).groth16().run().unwrap();
+        client.verify(&proof, &vk).unwrap();
+    }
 }
@@ -1,14 +1,21 @@
-use std::{fmt::Debug, fs::File, path::Path};
+use std::{borrow::Borrow, fmt::Debug, fs::File, path::Path};
 
 use anyhow::Result;
+use p3_baby_bear::BabyBear;
+use p3_field::{AbstractField, PrimeField32};
 use serde::{Deserialize, Serialize};
 use sp1_core_executor::SP1ReduceProof;
 use sp1_core_machine::io::SP1Stdin;
 use sp1_primitives::io::SP1PublicValues;
+use sp1_recursion_core::air::{RecursionPublicValues, RECURSIVE_PROOF_NUM_PV_ELTS};
 use strum_macros::{EnumDiscriminants, EnumTryAs};
 
+use crate::environment::ProofEnvironment;
 use sp1_prover::{CoreSC, Groth16Bn254Proof, InnerSC, PlonkBn254Proof};
-use sp1_stark::{MachineVerificationError, ShardProof};
+use sp1_stark::{
+    air::{PublicValues, SP1_PROOF_NUM_PV_ELTS},
+    MachineVerificationError, ShardProof, Word,
+};
 
 /// A proof generated with SP1 of a particular proof mode.
 #[derive(Debug, Clone, Serialize, Deserialize, EnumDiscriminants, EnumTryAs)]
@@ -22,13 +29,15 @@ pub enum SP1Proof {
     Groth16(Groth16Bn254Proof),
 }
 
-/// A proof generated with SP1, bundled together with stdin, public values, and the SP1 version.
+/// A proof generated with SP1, bundled together with stdin, public values, the SP1 version, and
+/// the environment of the prover.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SP1ProofWithPublicValues {
     pub proof: SP1Proof,
     pub stdin: SP1Stdin,
     pub public_values: SP1PublicValues,
     pub sp1_version: String,
+    pub environment: ProofEnvironment,
 }
 
 impl SP1ProofWithPublicValues {
@@ -44,6 +53,44 @@ impl SP1ProofWithPublicValues {
             .map_err(Into::into)
     }
 
+    /// Returns the environment of the prover that generated the proof.
+    pub fn environment(&self) -> &ProofEnvironment {
+        &self.environment
+    }
+
+    /// Returns the exit code the program halted with, as committed to by the proof.
+    ///
+    /// Plonk and Groth16 proofs only expose the verifying key hash and the public values digest,
+    /// so this is `None` for them. A program that halts with `sp1_zkvm::exit` also writes its exit
+    /// code to the public values, as their last four bytes in big-endian order, so that the digest
+    /// covers it: verifiers of these proofs, such as contracts, read it from there.
+    pub fn exit_code(&self) -> Option<u32> {
+        match &self.proof {
+            SP1Proof::Core(shard_proofs) => {
+                // Only the shard that halts has a non-zero exit code.
+                let exit_code = shard_proofs
+                    .iter()
+                    .filter(|shard_proof| shard_proof.public_values.len() >= SP1_PROOF_NUM_PV_ELTS)
+                    .map(|shard_proof| {
+                        let public_values: &PublicValues<Word<BabyBear>, BabyBear> =
+                            shard_proof.public_values.as_slice().borrow();
+                        public_values.exit_code
+                    })
+                    .fold(BabyBear::zero(), |acc, exit_code| acc + exit_code);
+                Some(exit_code.as_canonical_u32())
+            }
+            SP1Proof::Compressed(reduce_proof) => {
+                let public_values = &reduce_proof.proof.public_values;
+                (public_values.len() >= RECURSIVE_PROOF_NUM_PV_ELTS).then(|| {
+                    let public_values: &RecursionPublicValues<BabyBear> =
+                        public_values.as_slice().borrow();
+                    public_values.exit_code.as_canonical_u32()
+                })
+            }
+            SP1Proof::Plonk(_) | SP1Proof::Groth16(_) => None,
+        }
+    }
+
     /// Returns the raw proof as a string.
     pub fn raw(&self) -> String {
         match &self.proof {
@@ -105,6 +152,7 @@ mod tests {
             stdin: SP1Stdin::new(),
             public_values: SP1PublicValues::new(),
             sp1_version: "".to_string(),
+            environment: ProofEnvironment::default(),
         };
         let expected_bytes = [vec![0, 0, 0, 0], hex::decode("ab").unwrap()].concat();
         assert_eq!(plonk_proof.bytes(), expected_bytes);
@@ -122,6 +170,7 @@ mod tests {
             stdin: SP1Stdin::new(),
             public_values: SP1PublicValues::new(),
             sp1_version: "".to_string(),
+            environment: ProofEnvironment::default(),
         };
         let expected_bytes = [vec![0, 0, 0, 0], hex::decode("ab").unwrap()].concat();
         assert_eq!(groth16_proof.bytes(), expected_bytes);
@@ -139,6 +188,7 @@ mod tests {
             stdin: SP1Stdin::new(),
             public_values: SP1PublicValues::new(),
             sp1_version: "".to_string(),
+            environment: ProofEnvironment::default(),
         };
         assert_eq!(mock_plonk_proof.bytes(), Vec::<u8>::new());
     }
@@ -155,6 +205,7 @@ mod tests {
             stdin: SP1Stdin::new(),
             public_values: SP1PublicValues::new(),
             sp1_version: "".to_string(),
+            environment: ProofEnvironment::default(),
         };
         assert_eq!(mock_groth16_proof.bytes(), Vec::<u8>::new());
     }
@@ -167,6 +218,7 @@ mod tests {
             stdin: SP1Stdin::new(),
             public_values: SP1PublicValues::new(),
             sp1_version: "".to_string(),
+            environment: ProofEnvironment::default(),
         };
         core_proof.bytes();
     }
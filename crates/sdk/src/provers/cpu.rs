@@ -59,6 +59,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin: proof.stdin,
                 public_values: proof.public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         }
 
@@ -75,6 +76,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         }
 
@@ -100,6 +102,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         } else if kind == SP1ProofKind::Groth16 {
             let groth16_bn254_artifacts = if sp1_prover::build::sp1_dev_mode() {
@@ -117,6 +120,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         }
 
@@ -55,6 +55,7 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 stdin: proof.stdin,
                 public_values: proof.public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         }
 
@@ -70,6 +71,7 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         }
 
@@ -94,6 +96,7 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         } else if kind == SP1ProofKind::Groth16 {
             let groth16_bn254_artifacts = if sp1_prover::build::sp1_dev_mode() {
@@ -111,6 +114,7 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 stdin,
                 public_values,
                 sp1_version: self.version().to_string(),
+                environment: self.environment(),
             });
         }
 
@@ -24,6 +24,7 @@ use sp1_stark::{air::PublicValues, MachineVerificationError, SP1ProverOpts, Word
 use strum_macros::EnumString;
 use thiserror::Error;
 
+use crate::environment::ProofEnvironment;
 use crate::install::try_install_circuit_artifacts;
 use crate::{SP1Proof, SP1ProofKind, SP1ProofWithPublicValues};
 
@@ -71,6 +72,11 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
         SP1_CIRCUIT_VERSION
     }
 
+    /// The environment recorded in the proofs generated by this prover.
+    fn environment(&self) -> ProofEnvironment {
+        ProofEnvironment::new(self.sp1_prover())
+    }
+
     fn setup(&self, elf: &[u8]) -> (SP1ProvingKey, SP1VerifyingKey);
 
     /// Prove the execution of a RISCV ELF with the given inputs, according to the given proof mode.
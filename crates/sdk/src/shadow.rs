@@ -0,0 +1,211 @@
+//! Tools for qualifying executor changes.
+//!
+//! Shadow mode runs the same program and input on two builds of the executor and compares what
+//! each of them observed. [`summarize`] executes a program with the executor this crate is built
+//! with, and [`compare`] checks two summaries, typically one written by the released build and one
+//! by the candidate, and produces a [`QualificationReport`].
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sp1_core_executor::{Executor, Program};
+use sp1_core_machine::{io::SP1Stdin, SP1_CIRCUIT_VERSION};
+use sp1_stark::SP1CoreOpts;
+
+/// What an executor observed while running a program on an input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionSummary {
+    /// The SP1 version of the executor.
+    pub sp1_version: String,
+    /// The SHA-256 digest of the ELF, in hex.
+    pub elf_digest: String,
+    /// The SHA-256 digest of the bincode encoded input, in hex.
+    pub stdin_digest: String,
+    /// The execution error, if the execution failed.
+    pub error: Option<String>,
+    /// The number of cycles executed.
+    pub cycles: u64,
+    /// The program counter the execution stopped at.
+    pub final_pc: u32,
+    /// The SHA-256 digest of the non-zero words of the final memory, registers included, in hex.
+    pub state_digest: String,
+    /// The public values committed by the program.
+    pub public_values: Vec<u8>,
+    /// The number of calls to each syscall made by the program, for the syscalls it made.
+    pub syscall_counts: BTreeMap<String, u64>,
+}
+
+/// Executes a program on an input and summarizes the execution.
+///
+/// A failed execution is summarized rather than returned as an error, so that the failures of two
+/// executors can be compared too. Deferred proofs in `stdin` are not verified.
+pub fn summarize(elf: &[u8], stdin: &SP1Stdin) -> Result<ExecutionSummary> {
+    let program = Program::from(elf).map_err(|err| anyhow!("failed to load the elf: {err}"))?;
+    let mut runtime = Executor::new(program, SP1CoreOpts::default());
+    stdin.write_to(&mut runtime);
+    let error = runtime.run_fast().err().map(|err| err.to_string());
+
+    // Words that were only ever read as zero are left out, so that an executor touching more
+    // memory than another does not change the digest.
+    let memory = &runtime.state.memory;
+    let mut words = memory
+        .keys()
+        .filter_map(|addr| memory.get(addr).map(|record| (addr, record.value)))
+        .filter(|&(_, value)| value != 0)
+        .collect::<Vec<_>>();
+    words.sort_unstable();
+    let mut hasher = Sha256::new();
+    for (addr, value) in words {
+        hasher.update(addr.to_le_bytes());
+        hasher.update(value.to_le_bytes());
+    }
+
+    let syscall_counts = runtime
+        .report
+        .syscall_counts
+        .iter()
+        .filter(|(_, &count)| count != 0)
+        .map(|(syscall, &count)| (syscall.to_string(), count))
+        .collect();
+
+    Ok(ExecutionSummary {
+        sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+        elf_digest: hex::encode(Sha256::digest(elf)),
+        stdin_digest: hex::encode(Sha256::digest(bincode::serialize(stdin)?)),
+        error,
+        cycles: runtime.state.global_clk,
+        final_pc: runtime.state.pc,
+        state_digest: hex::encode(hasher.finalize()),
+        public_values: runtime.state.public_values_stream.clone(),
+        syscall_counts,
+    })
+}
+
+/// A difference between two executions that disqualifies the new executor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// What differs.
+    pub field: String,
+    /// The value observed by the old executor.
+    pub old: String,
+    /// The value observed by the new executor.
+    pub new: String,
+}
+
+/// The result of comparing two executions of the same program on the same input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QualificationReport {
+    /// The SP1 version of the old executor.
+    pub old_version: String,
+    /// The SP1 version of the new executor.
+    pub new_version: String,
+    /// The cycle counts of the old and the new executor. Cycle counts may change between
+    /// executors, for example when a syscall takes more cycles, so they do not disqualify.
+    pub cycles: (u64, u64),
+    /// The differences that disqualify the new executor.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl QualificationReport {
+    /// Whether the new executor behaved like the old one.
+    pub fn is_qualified(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Compares the summaries of the same program and input on an old and a new executor.
+///
+/// Fails if the summaries are not of the same program and input.
+pub fn compare(old: &ExecutionSummary, new: &ExecutionSummary) -> Result<QualificationReport> {
+    if old.elf_digest != new.elf_digest {
+        return Err(anyhow!("the summaries are of different programs"));
+    }
+    if old.stdin_digest != new.stdin_digest {
+        return Err(anyhow!("the summaries are of different inputs"));
+    }
+
+    let mut mismatches = Vec::new();
+    let mut check = |field: &str, old: String, new: String| {
+        if old != new {
+            mismatches.push(Mismatch { field: field.to_string(), old, new });
+        }
+    };
+    check("error", format!("{:?}", old.error), format!("{:?}", new.error));
+    check("final pc", format!("{:#x}", old.final_pc), format!("{:#x}", new.final_pc));
+    check("state digest", old.state_digest.clone(), new.state_digest.clone());
+    check("public values", hex::encode(&old.public_values), hex::encode(&new.public_values));
+    let syscalls = old.syscall_counts.keys().chain(new.syscall_counts.keys());
+    for syscall in syscalls.collect::<BTreeSet<_>>() {
+        let count = |summary: &ExecutionSummary| {
+            summary.syscall_counts.get(syscall).copied().unwrap_or_default().to_string()
+        };
+        check(&format!("{syscall} calls"), count(old), count(new));
+    }
+
+    Ok(QualificationReport {
+        old_version: old.sp1_version.clone(),
+        new_version: new.sp1_version.clone(),
+        cycles: (old.cycles, new.cycles),
+        mismatches,
+    })
+}
+
+impl fmt::Display for QualificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "old executor: {}", self.old_version)?;
+        writeln!(f, "new executor: {}", self.new_version)?;
+        let (old_cycles, new_cycles) = self.cycles;
+        writeln!(
+            f,
+            "cycles: {old_cycles} -> {new_cycles} ({:+})",
+            new_cycles as i128 - old_cycles as i128
+        )?;
+        for mismatch in &self.mismatches {
+            writeln!(f, "mismatch in {}: {} -> {}", mismatch.field, mismatch.old, mismatch.new)?;
+        }
+        if self.is_qualified() {
+            writeln!(f, "qualified")
+        } else {
+            writeln!(f, "not qualified: {} mismatches", self.mismatches.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils, SP1Stdin};
+
+    use super::{compare, summarize};
+
+    #[test]
+    fn test_shadow_identical() {
+        utils::setup_logger();
+        let old = summarize(test_artifacts::FIBONACCI_ELF, &SP1Stdin::new()).unwrap();
+        let new = summarize(test_artifacts::FIBONACCI_ELF, &SP1Stdin::new()).unwrap();
+        assert!(old.error.is_none());
+
+        let report = compare(&old, &new).unwrap();
+        assert!(report.is_qualified(), "{report}");
+    }
+
+    #[test]
+    fn test_shadow_mismatch() {
+        utils::setup_logger();
+        let old = summarize(test_artifacts::FIBONACCI_ELF, &SP1Stdin::new()).unwrap();
+        let mut new = old.clone();
+        new.public_values.push(1);
+        new.syscall_counts.insert("COMMIT".to_string(), 1000);
+
+        let report = compare(&old, &new).unwrap();
+        let fields = report.mismatches.iter().map(|m| m.field.as_str()).collect::<Vec<_>>();
+        assert_eq!(fields, ["public values", "COMMIT calls"]);
+
+        new.stdin_digest = String::new();
+        assert!(compare(&old, &new).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+//! Tools for inspecting verifying keys.
+//!
+//! A verifying key commits to the preprocessed traces of a program and to the shape of every chip
+//! with a preprocessed trace. When a proof is rejected because of a verifying key mismatch, [`diff`]
+//! explains which of these parts changed.
+
+use std::fmt;
+
+use hashbrown::HashMap;
+use p3_baby_bear::BabyBear;
+use p3_field::PrimeField32;
+use sp1_prover::{HashableKey, SP1VerifyingKey};
+
+/// The shape of a chip's preprocessed trace in a verifying key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChipShape {
+    /// The number of preprocessed columns.
+    pub width: usize,
+    /// The log2 of the preprocessed trace height.
+    pub log_height: usize,
+    /// The shift of the trace domain.
+    pub shift: u32,
+}
+
+/// A chip whose preprocessed shape differs between two verifying keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChipShapeDiff {
+    /// The name of the chip.
+    pub name: String,
+    /// The shape in the first key.
+    pub a: ChipShape,
+    /// The shape in the second key.
+    pub b: ChipShape,
+}
+
+/// The differences between two verifying keys, as reported by [`diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VkDiff {
+    /// The digests of the two keys, if they differ.
+    pub digest: Option<([u32; 8], [u32; 8])>,
+    /// The commitments to the preprocessed traces of the two keys, if they differ.
+    pub commit: Option<([u32; 8], [u32; 8])>,
+    /// The start pcs of the two keys, if they differ.
+    pub pc_start: Option<(u32, u32)>,
+    /// The chips only present in the second key.
+    pub added_chips: Vec<String>,
+    /// The chips only present in the first key.
+    pub removed_chips: Vec<String>,
+    /// The chips present in both keys whose shapes differ.
+    pub changed_chips: Vec<ChipShapeDiff>,
+    /// Whether the chips present in both keys appear in a different order.
+    pub reordered: bool,
+}
+
+impl VkDiff {
+    /// Whether the two keys are identical.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Computes the differences between two verifying keys.
+pub fn diff(vk_a: &SP1VerifyingKey, vk_b: &SP1VerifyingKey) -> VkDiff {
+    let digest_a = vk_a.hash_u32();
+    let digest_b = vk_b.hash_u32();
+    let commit_a = commit_u32(vk_a);
+    let commit_b = commit_u32(vk_b);
+    let pc_start_a = vk_a.vk.pc_start.as_canonical_u32();
+    let pc_start_b = vk_b.vk.pc_start.as_canonical_u32();
+
+    let shapes_a = chip_shapes(vk_a);
+    let shapes_b = chip_shapes(vk_b);
+    let lookup_a = shapes_a.iter().cloned().collect::<HashMap<_, _>>();
+    let lookup_b = shapes_b.iter().cloned().collect::<HashMap<_, _>>();
+
+    let added_chips = shapes_b
+        .iter()
+        .filter(|(name, _)| !lookup_a.contains_key(name))
+        .map(|(name, _)| name.clone())
+        .collect();
+    let removed_chips = shapes_a
+        .iter()
+        .filter(|(name, _)| !lookup_b.contains_key(name))
+        .map(|(name, _)| name.clone())
+        .collect();
+    let changed_chips = shapes_a
+        .iter()
+        .filter_map(|(name, a)| {
+            let b = lookup_b.get(name)?;
+            (a != b).then(|| ChipShapeDiff { name: name.clone(), a: *a, b: *b })
+        })
+        .collect();
+
+    let common_a =
+        shapes_a.iter().map(|(name, _)| name).filter(|name| lookup_b.contains_key(*name));
+    let common_b =
+        shapes_b.iter().map(|(name, _)| name).filter(|name| lookup_a.contains_key(*name));
+    let reordered = !common_a.eq(common_b);
+
+    VkDiff {
+        digest: (digest_a != digest_b).then_some((digest_a, digest_b)),
+        commit: (commit_a != commit_b).then_some((commit_a, commit_b)),
+        pc_start: (pc_start_a != pc_start_b).then_some((pc_start_a, pc_start_b)),
+        added_chips,
+        removed_chips,
+        changed_chips,
+        reordered,
+    }
+}
+
+fn commit_u32(vk: &SP1VerifyingKey) -> [u32; 8] {
+    let commit: &[BabyBear; 8] = vk.vk.commit.as_ref();
+    commit.map(|x| x.as_canonical_u32())
+}
+
+fn chip_shapes(vk: &SP1VerifyingKey) -> Vec<(String, ChipShape)> {
+    vk.vk
+        .chip_information
+        .iter()
+        .map(|(name, domain, dimensions)| {
+            let shape = ChipShape {
+                width: dimensions.width,
+                log_height: domain.log_n,
+                shift: domain.shift.as_canonical_u32(),
+            };
+            (name.clone(), shape)
+        })
+        .collect()
+}
+
+fn fmt_words(words: &[u32; 8]) -> String {
+    words.iter().map(|word| format!("{word:08x}")).collect()
+}
+
+impl fmt::Display for VkDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "the verifying keys are identical");
+        }
+
+        if let Some((a, b)) = &self.digest {
+            writeln!(f, "digest: {} -> {}", fmt_words(a), fmt_words(b))?;
+        }
+        if let Some((a, b)) = &self.pc_start {
+            writeln!(f, "pc start: {a:#x} -> {b:#x}")?;
+        }
+        for name in &self.added_chips {
+            writeln!(f, "added chip: {name}")?;
+        }
+        for name in &self.removed_chips {
+            writeln!(f, "removed chip: {name}")?;
+        }
+        for chip in &self.changed_chips {
+            write!(f, "changed chip {}:", chip.name)?;
+            if chip.a.width != chip.b.width {
+                write!(f, " width {} -> {}", chip.a.width, chip.b.width)?;
+            }
+            if chip.a.log_height != chip.b.log_height {
+                write!(f, " log height {} -> {}", chip.a.log_height, chip.b.log_height)?;
+            }
+            if chip.a.shift != chip.b.shift {
+                write!(f, " domain shift {} -> {}", chip.a.shift, chip.b.shift)?;
+            }
+            writeln!(f)?;
+        }
+        if self.reordered {
+            writeln!(f, "the chips are in a different order")?;
+        }
+        if let Some((a, b)) = &self.commit {
+            writeln!(f, "preprocessed commitment: {} -> {}", fmt_words(a), fmt_words(b))?;
+            let same_shape = self.added_chips.is_empty()
+                && self.removed_chips.is_empty()
+                && self.changed_chips.is_empty();
+            if same_shape {
+                writeln!(
+                    f,
+                    "the chip shapes match, so the preprocessed contents differ (e.g. the program \
+                     or its memory image)"
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{utils, ProverClient};
+
+    use super::diff;
+
+    #[test]
+    fn test_diff_identical() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let (_, vk) = client.setup(test_artifacts::FIBONACCI_ELF);
+        let vk_diff = diff(&vk, &vk);
+        assert!(vk_diff.is_empty(), "{vk_diff}");
+    }
+
+    #[test]
+    fn test_diff_programs() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let (_, vk_a) = client.setup(test_artifacts::FIBONACCI_ELF);
+        let (_, vk_b) = client.setup(test_artifacts::PANIC_ELF);
+        let vk_diff = diff(&vk_a, &vk_b);
+        assert!(vk_diff.digest.is_some());
+        assert!(vk_diff.commit.is_some());
+        assert!(vk_diff.added_chips.is_empty());
+        assert!(vk_diff.removed_chips.is_empty());
+    }
+
+    #[test]
+    fn test_diff_chips() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let (_, vk_a) = client.setup(test_artifacts::FIBONACCI_ELF);
+
+        // Drop the last chip and widen the first one.
+        let mut vk_b = vk_a.clone();
+        let (removed, _, _) = vk_b.vk.chip_information.pop().unwrap();
+        vk_b.vk.chip_information[0].2.width += 1;
+
+        let vk_diff = diff(&vk_a, &vk_b);
+        assert_eq!(vk_diff.removed_chips, vec![removed.clone()]);
+        assert_eq!(vk_diff.changed_chips.len(), 1);
+        assert_eq!(vk_diff.changed_chips[0].b.width, vk_diff.changed_chips[0].a.width + 1);
+        assert!(!vk_diff.reordered);
+
+        let vk_diff = diff(&vk_b, &vk_a);
+        assert_eq!(vk_diff.added_chips, vec![removed]);
+    }
+}
@@ -25,6 +25,8 @@ pub struct Chip<F: Field, A> {
     receives: Vec<Interaction<F>>,
     /// The relative log degree of the quotient polynomial, i.e. `log2(max_constraint_degree - 1)`.
     log_quotient_degree: usize,
+    /// The bus carrying the byte lookups of the chip.
+    byte_bus: InteractionKind,
 }
 
 impl<F: Field, A> Chip<F, A> {
@@ -43,10 +45,50 @@ impl<F: Field, A> Chip<F, A> {
         self.log_quotient_degree
     }
 
+    /// The bus carrying the byte lookups of the chip.
+    pub const fn byte_bus(&self) -> InteractionKind {
+        self.byte_bus
+    }
+
+    /// Moves the byte lookups of the chip onto the given byte bus.
+    ///
+    /// The byte interactions of the chip are re-tagged with `bus`, so they are balanced against the
+    /// byte table receiving on that bus instead of the primary one.
+    #[must_use]
+    pub fn on_byte_bus(mut self, bus: InteractionKind) -> Self {
+        assert!(InteractionKind::byte_buses().contains(&bus), "{bus} is not a byte bus");
+        for interaction in self.sends.iter_mut().chain(self.receives.iter_mut()) {
+            if interaction.kind == self.byte_bus {
+                interaction.kind = bus;
+            }
+        }
+        self.byte_bus = bus;
+        self
+    }
+
+    /// Returns the underlying air.
+    pub const fn air(&self) -> &A {
+        &self.air
+    }
+
     /// Consumes the chip and returns the underlying air.
     pub fn into_inner(self) -> A {
         self.air
     }
+
+    /// Wraps the underlying air, keeping the interactions and the byte bus of the chip.
+    ///
+    /// The new air must evaluate the same constraints and interactions as the old one, typically
+    /// because it is a variant of an enum delegating to it.
+    pub fn map_air<B>(self, f: impl FnOnce(A) -> B) -> Chip<F, B> {
+        Chip {
+            air: f(self.air),
+            sends: self.sends,
+            receives: self.receives,
+            log_quotient_degree: self.log_quotient_degree,
+            byte_bus: self.byte_bus,
+        }
+    }
 }
 
 impl<F: PrimeField32, A: MachineAir<F>> Chip<F, A> {
@@ -86,7 +128,7 @@ where
         }
         let log_quotient_degree = log2_ceil_usize(max_constraint_degree - 1);
 
-        Self { air, sends, receives, log_quotient_degree }
+        Self { air, sends, receives, log_quotient_degree, byte_bus: InteractionKind::Byte }
     }
 
     /// Returns the number of interactions in the chip.
@@ -95,10 +137,10 @@ where
         self.sends.len() + self.receives.len()
     }
 
-    /// Returns the number of sent byte lookups in the chip.
+    /// Returns the number of sent byte lookups in the chip, on whichever bus carries them.
     #[inline]
     pub fn num_sent_byte_lookups(&self) -> usize {
-        self.sends.iter().filter(|i| i.kind == InteractionKind::Byte).count()
+        self.num_sends_by_kind(self.byte_bus)
     }
 
     /// Returns the number of sends of the given kind.
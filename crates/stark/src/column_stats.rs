@@ -0,0 +1,175 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use p3_field::Field;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+
+/// The values taken by a column across all the traces observed so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnValues<F> {
+    /// The column has held the same value in every row.
+    Constant(F),
+    /// The column has held at least two different values.
+    Varying,
+}
+
+/// The usage of the columns of a chip across all the traces observed so far.
+#[derive(Debug, Clone)]
+pub struct ChipColumnStats<F> {
+    /// The number of traces observed.
+    pub num_traces: usize,
+    /// The total number of rows observed, padding included.
+    pub num_rows: usize,
+    /// The values taken by each column, or `None` if no row has been observed yet.
+    columns: Vec<Option<ColumnValues<F>>>,
+}
+
+impl<F: Field> ChipColumnStats<F> {
+    fn new(width: usize) -> Self {
+        Self { num_traces: 0, num_rows: 0, columns: vec![None; width] }
+    }
+
+    /// The width of the chip.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    fn observe(&mut self, trace: &RowMajorMatrix<F>) {
+        assert_eq!(trace.width(), self.width(), "the width of a chip changed between traces");
+        self.num_traces += 1;
+        self.num_rows += trace.height();
+        for row in trace.rows() {
+            for (column, value) in self.columns.iter_mut().zip(row) {
+                *column = match *column {
+                    None => Some(ColumnValues::Constant(value)),
+                    Some(ColumnValues::Constant(c)) if c == value => *column,
+                    _ => Some(ColumnValues::Varying),
+                };
+            }
+        }
+    }
+
+    /// The indices of the columns that were zero in every row observed.
+    #[must_use]
+    pub fn dead_columns(&self) -> Vec<usize> {
+        self.constant_columns().into_iter().filter(|(_, c)| c.is_zero()).map(|(i, _)| i).collect()
+    }
+
+    /// The indices and values of the columns that held a single value in every row observed,
+    /// including the dead columns.
+    #[must_use]
+    pub fn constant_columns(&self) -> Vec<(usize, F)> {
+        self.columns
+            .iter()
+            .enumerate()
+            .filter_map(|(i, column)| match column {
+                Some(ColumnValues::Constant(c)) => Some((i, *c)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Statistics on the usage of the main trace columns of each chip, gathered over many traces.
+///
+/// Columns that are always zero, or always hold the same value, cost commitment work without
+/// carrying any information, so they are candidates for removal. Since a trace is padded with
+/// rows of zeros, a column set to one in every real row is reported as varying.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnStats<F> {
+    chips: BTreeMap<String, ChipColumnStats<F>>,
+}
+
+impl<F: Field> ColumnStats<F> {
+    /// Creates empty statistics.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { chips: BTreeMap::new() }
+    }
+
+    /// Records the values of a trace generated by the chip with the given name.
+    pub fn observe(&mut self, chip_name: &str, trace: &RowMajorMatrix<F>) {
+        self.chips
+            .entry(chip_name.to_string())
+            .or_insert_with(|| ChipColumnStats::new(trace.width()))
+            .observe(trace);
+    }
+
+    /// Records the values of the traces generated for a shard, as returned by
+    /// [`crate::MachineProver::generate_traces`].
+    pub fn observe_all(&mut self, traces: &[(String, RowMajorMatrix<F>)]) {
+        for (name, trace) in traces {
+            self.observe(name, trace);
+        }
+    }
+
+    /// Returns the statistics of the chip with the given name, if any of its traces was observed.
+    #[must_use]
+    pub fn chip(&self, chip_name: &str) -> Option<&ChipColumnStats<F>> {
+        self.chips.get(chip_name)
+    }
+
+    /// Returns the statistics of every chip observed, ordered by name.
+    pub fn chips(&self) -> impl Iterator<Item = (&String, &ChipColumnStats<F>)> {
+        self.chips.iter()
+    }
+}
+
+impl<F: Field> Display for ColumnStats<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, stats) in &self.chips {
+            let dead = stats.dead_columns();
+            let constant = stats
+                .constant_columns()
+                .into_iter()
+                .filter(|(_, c)| !c.is_zero())
+                .map(|(i, c)| format!("{i}={c}"))
+                .collect::<Vec<_>>();
+            writeln!(
+                f,
+                "{name}: width {}, {} traces, {} rows, {} dead, {} constant",
+                stats.width(),
+                stats.num_traces,
+                stats.num_rows,
+                dead.len(),
+                constant.len()
+            )?;
+            if !dead.is_empty() {
+                writeln!(f, "  dead: {dead:?}")?;
+            }
+            if !constant.is_empty() {
+                writeln!(f, "  constant: [{}]", constant.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+
+    use super::*;
+
+    #[test]
+    fn test_column_stats() {
+        let f = BabyBear::from_canonical_u32;
+        let mut stats = ColumnStats::new();
+        stats.observe("Chip", &RowMajorMatrix::new(vec![f(0), f(1), f(3), f(0), f(1), f(3)], 3));
+        assert_eq!(stats.chip("Chip").unwrap().dead_columns(), vec![0]);
+        assert_eq!(
+            stats.chip("Chip").unwrap().constant_columns(),
+            vec![(0, f(0)), (1, f(1)), (2, f(3))]
+        );
+
+        stats.observe("Chip", &RowMajorMatrix::new(vec![f(0), f(1), f(4)], 3));
+        stats.observe("Other", &RowMajorMatrix::new(vec![f(2); 4], 2));
+        let chip = stats.chip("Chip").unwrap();
+        assert_eq!((chip.num_traces, chip.num_rows), (2, 3));
+        assert_eq!(chip.dead_columns(), vec![0]);
+        assert_eq!(chip.constant_columns(), vec![(0, f(0)), (1, f(1))]);
+        assert_eq!(stats.chip("Other").unwrap().constant_columns(), vec![(0, f(2)), (1, f(2))]);
+        assert!(stats.chip("Missing").is_none());
+    }
+}
@@ -17,7 +17,7 @@ use p3_matrix::{
 use p3_maybe_rayon::prelude::ParallelBridge;
 use p3_maybe_rayon::prelude::ParallelIterator;
 
-use super::{MachineChip, StarkGenericConfig, Val};
+use super::{Chip, MachineChip, StarkGenericConfig, Val};
 use crate::air::{EmptyMessageBuilder, MachineAir, MultiTableAirBuilder};
 
 /// Checks that the constraints of the given AIR are satisfied, including the permutation trace.
@@ -89,6 +89,7 @@ pub fn debug_constraints<SC, A>(
             is_last_row: Val::<SC>::zero(),
             is_transition: Val::<SC>::one(),
             public_values,
+            report_failures: true,
         };
         if i == 0 {
             builder.is_first_row = Val::<SC>::one();
@@ -109,6 +110,56 @@ pub fn debug_constraints<SC, A>(
     });
 }
 
+/// Returns whether a row satisfies the constraints of a chip, leaving out the transition
+/// constraints and those on the first and the last rows.
+///
+/// `local` stands in for the row `row` of `main`, and the row after it is the next one of `main`.
+/// The permutation constraints are not evaluated. A row failing only the constraints left out, such
+/// as a padding row followed by a real one, still satisfies them.
+pub fn row_constraints_hold<F, EF, A>(
+    chip: &Chip<F, A>,
+    preprocessed: Option<&RowMajorMatrix<F>>,
+    main: &RowMajorMatrix<F>,
+    row: usize,
+    local: &[F],
+    public_values: &[F],
+) -> bool
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    A: for<'a> Air<DebugConstraintBuilder<'a, F, EF>>,
+{
+    let next = (row + 1) % main.height();
+    let main_next = main.row_slice(next).to_vec();
+    let (preprocessed_local, preprocessed_next) = preprocessed
+        .map(|t| (t.row_slice(row).to_vec(), t.row_slice(next).to_vec()))
+        .unwrap_or_default();
+    let perm: &[EF] = &[];
+
+    let mut builder = DebugConstraintBuilder {
+        preprocessed: VerticalPair::new(
+            RowMajorMatrixView::new_row(&preprocessed_local),
+            RowMajorMatrixView::new_row(&preprocessed_next),
+        ),
+        main: VerticalPair::new(
+            RowMajorMatrixView::new_row(local),
+            RowMajorMatrixView::new_row(&main_next),
+        ),
+        perm: VerticalPair::new(
+            RowMajorMatrixView::new_row(perm),
+            RowMajorMatrixView::new_row(perm),
+        ),
+        perm_challenges: &[],
+        cumulative_sums: &[],
+        is_first_row: F::zero(),
+        is_last_row: F::zero(),
+        is_transition: F::zero(),
+        public_values,
+        report_failures: false,
+    };
+    catch_unwind_silent(AssertUnwindSafe(|| chip.air().eval(&mut builder))).is_ok()
+}
+
 fn catch_unwind_silent<F: FnOnce() -> R + panic::UnwindSafe, R>(f: F) -> std::thread::Result<R> {
     let prev_hook = panic::take_hook();
     panic::set_hook(Box::new(|_| {}));
@@ -136,6 +187,8 @@ pub struct DebugConstraintBuilder<'a, F: Field, EF: ExtensionField<F>> {
     pub(crate) is_last_row: F,
     pub(crate) is_transition: F,
     pub(crate) public_values: &'a [F],
+    /// Whether to print the failed constraints along with a backtrace.
+    pub(crate) report_failures: bool,
 }
 
 impl<'a, F, EF> ExtensionBuilder for DebugConstraintBuilder<'a, F, EF>
@@ -192,8 +245,10 @@ where
     #[inline]
     fn debug_constraint(&self, x: F, y: F) {
         if x != y {
-            let backtrace = std::backtrace::Backtrace::force_capture();
-            eprintln!("constraint failed: {x:?} != {y:?}\n{backtrace}");
+            if self.report_failures {
+                let backtrace = std::backtrace::Backtrace::force_capture();
+                eprintln!("constraint failed: {x:?} != {y:?}\n{backtrace}");
+            }
             panic!();
         }
     }
@@ -245,8 +300,10 @@ where
     fn assert_bool<I: Into<Self::Expr>>(&mut self, x: I) {
         let x = x.into();
         if x != F::zero() && x != F::one() {
-            let backtrace = std::backtrace::Backtrace::force_capture();
-            eprintln!("constraint failed: {x:?} is not a bool\n{backtrace}");
+            if self.report_failures {
+                let backtrace = std::backtrace::Backtrace::force_capture();
+                eprintln!("constraint failed: {x:?} is not a bool\n{backtrace}");
+            }
             panic!();
         }
     }
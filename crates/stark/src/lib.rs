@@ -23,11 +23,13 @@
 pub mod air;
 mod bb31_poseidon2;
 mod chip;
+mod column_stats;
 mod config;
 mod debug;
 mod folder;
 mod lookup;
 mod machine;
+mod memory;
 mod opts;
 mod permutation;
 mod prover;
@@ -40,11 +42,13 @@ mod word;
 
 pub use bb31_poseidon2::*;
 pub use chip::*;
+pub use column_stats::*;
 pub use config::*;
 pub use debug::*;
 pub use folder::*;
 pub use lookup::*;
 pub use machine::*;
+pub use memory::*;
 pub use opts::*;
 pub use permutation::*;
 pub use prover::*;
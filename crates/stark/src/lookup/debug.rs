@@ -1,13 +1,16 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 use p3_baby_bear::BabyBear;
 use p3_field::{AbstractField, Field, PrimeField32, PrimeField64};
-use p3_matrix::Matrix;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
 
-use super::InteractionKind;
+use super::{Interaction, InteractionKind};
 use crate::{
     air::{InteractionScope, MachineAir},
-    MachineChip, StarkGenericConfig, StarkMachine, StarkProvingKey, Val,
+    Chip, MachineChip, StarkGenericConfig, StarkMachine, StarkProvingKey, Val,
 };
 
 /// The data for an interaction.
@@ -56,19 +59,18 @@ fn field_to_int<F: PrimeField32>(x: F) -> i32 {
     }
 }
 
-/// Debugs the interactions of a chip.
+/// Calls `f` with every interaction of `scope` a chip makes over the rows of its trace for
+/// `record`, along with its row, its number, whether it is a send, its multiplicity and its values.
+///
+/// Interactions with a zero multiplicity are skipped.
 #[allow(clippy::type_complexity)]
-#[allow(clippy::needless_pass_by_value)]
-pub fn debug_interactions<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
+fn for_each_interaction<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
     chip: &MachineChip<SC, A>,
     pkey: &StarkProvingKey<SC>,
     record: &A::Record,
-    interaction_kinds: Vec<InteractionKind>,
     scope: InteractionScope,
-) -> (BTreeMap<String, Vec<InteractionData<Val<SC>>>>, BTreeMap<String, Val<SC>>) {
-    let mut key_to_vec_data = BTreeMap::new();
-    let mut key_to_count = BTreeMap::new();
-
+    mut f: impl FnMut(usize, usize, &Interaction<Val<SC>>, bool, Val<SC>, Vec<Val<SC>>),
+) {
     let trace = chip.generate_trace(record, &mut A::Record::default());
     let mut pre_traces = pkey.traces.clone();
     let mut preprocessed_trace =
@@ -82,9 +84,6 @@ pub fn debug_interactions<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
     let nb_send_interactions = sends.len();
     for row in 0..height {
         for (m, interaction) in sends.iter().chain(receives.iter()).enumerate() {
-            if !interaction_kinds.contains(&interaction.kind) {
-                continue;
-            }
             let mut empty = vec![];
             let preprocessed_row = preprocessed_trace
                 .as_mut()
@@ -101,31 +100,95 @@ pub fn debug_interactions<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
                     let expr: Val<SC> = value.apply(preprocessed_row, main.row_mut(row));
                     values.push(expr);
                 }
-                let key = format!(
-                    "{} {} {}",
-                    &interaction.scope.to_string(),
-                    &interaction.kind.to_string(),
-                    vec_to_string(values)
-                );
-                key_to_vec_data.entry(key.clone()).or_insert_with(Vec::new).push(InteractionData {
+                f(row, m, interaction, is_send, multiplicity_eval, values);
+            }
+        }
+    }
+}
+
+/// Debugs the interactions of a chip.
+#[allow(clippy::type_complexity)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn debug_interactions<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
+    chip: &MachineChip<SC, A>,
+    pkey: &StarkProvingKey<SC>,
+    record: &A::Record,
+    interaction_kinds: Vec<InteractionKind>,
+    scope: InteractionScope,
+) -> (BTreeMap<String, Vec<InteractionData<Val<SC>>>>, BTreeMap<String, Val<SC>>) {
+    let mut key_to_vec_data = BTreeMap::new();
+    let mut key_to_count = BTreeMap::new();
+
+    for_each_interaction(
+        chip,
+        pkey,
+        record,
+        scope,
+        |row, m, interaction, is_send, mult, values| {
+            if !interaction_kinds.contains(&interaction.kind) {
+                return;
+            }
+            let key = format!(
+                "{} {} {}",
+                &interaction.scope.to_string(),
+                &interaction.kind.to_string(),
+                vec_to_string(values)
+            );
+            key_to_vec_data.entry(key.clone()).or_insert_with(Vec::new).push(InteractionData {
+                chip_name: chip.name(),
+                kind: interaction.kind,
+                row,
+                interaction_number: m,
+                is_send,
+                multiplicity: mult,
+            });
+            let current = key_to_count.entry(key.clone()).or_insert(Val::<SC>::zero());
+            if is_send {
+                *current += mult;
+            } else {
+                *current -= mult;
+            }
+        },
+    );
+
+    (key_to_vec_data, key_to_count)
+}
+
+/// Finds the interactions of a chip that survive disabling its real rows.
+///
+/// For each row of `main`, `disable_row` clears the `is_real` flag of a copy of the row and returns
+/// whether the row was real. An interaction of a real row that does not vanish lets a prover keep
+/// it while skipping the constraints gated by `is_real`, unless the AIR rejects the disabled row.
+/// The interactions that do not vanish are returned.
+pub fn ungated_interactions<F: Field, A: MachineAir<F>>(
+    chip: &Chip<F, A>,
+    preprocessed: Option<&RowMajorMatrix<F>>,
+    main: &RowMajorMatrix<F>,
+    disable_row: impl Fn(&mut [F]) -> bool,
+) -> Vec<InteractionData<F>> {
+    let nb_send_interactions = chip.sends().len();
+    let mut ungated = Vec::new();
+    for (row, main_row) in main.rows().enumerate() {
+        let mut main_row = main_row.collect::<Vec<_>>();
+        if !disable_row(&mut main_row) {
+            continue;
+        }
+        let preprocessed_row = preprocessed.map(|t| t.row_slice(row).to_vec()).unwrap_or_default();
+        for (m, interaction) in chip.sends().iter().chain(chip.receives()).enumerate() {
+            let multiplicity = interaction.multiplicity.apply::<F, F>(&preprocessed_row, &main_row);
+            if !multiplicity.is_zero() {
+                ungated.push(InteractionData {
                     chip_name: chip.name(),
                     kind: interaction.kind,
                     row,
                     interaction_number: m,
-                    is_send,
-                    multiplicity: multiplicity_eval,
+                    is_send: m < nb_send_interactions,
+                    multiplicity,
                 });
-                let current = key_to_count.entry(key.clone()).or_insert(Val::<SC>::zero());
-                if is_send {
-                    *current += multiplicity_eval;
-                } else {
-                    *current -= multiplicity_eval;
-                }
             }
         }
     }
-
-    (key_to_vec_data, key_to_count)
+    ungated
 }
 
 /// Calculate the number of times we send and receive each event of the given interaction type,
@@ -216,3 +279,209 @@ where
 
     !any_nonzero
 }
+
+/// The maximum number of unbalanced interactions shown by the [`Display`] of [`InteractionStats`].
+pub const MAX_SHOWN_IMBALANCES: usize = 32;
+
+/// The multiplicities a chip sends and receives on a bus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusTally<F> {
+    /// The sum of the multiplicities of the sends.
+    pub sent: F,
+    /// The sum of the multiplicities of the receives.
+    pub received: F,
+    /// The number of sends and receives with a non-zero multiplicity.
+    pub count: usize,
+}
+
+impl<F: Field> Default for BusTally<F> {
+    fn default() -> Self {
+        Self { sent: F::zero(), received: F::zero(), count: 0 }
+    }
+}
+
+impl<F: Field> BusTally<F> {
+    /// The multiplicities sent minus those received.
+    pub fn net(&self) -> F {
+        self.sent - self.received
+    }
+}
+
+/// A message of a bus whose sends and receives don't balance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InteractionImbalance<F> {
+    /// The bus of the message.
+    pub kind: InteractionKind,
+    /// The values of the message.
+    pub values: Vec<F>,
+    /// The multiplicities sent minus those received, over all the chips.
+    pub discrepancy: F,
+    /// The multiplicities sent minus those received by the chips sending or receiving the message.
+    pub chips: BTreeMap<String, F>,
+}
+
+/// The tallies of the interactions of a machine over some shards, per bus and per chip, along with
+/// the messages whose sends and receives don't balance.
+///
+/// A non-zero cumulative sum only tells that some bus is unbalanced. The tallies tell which bus,
+/// and which chips send more than they receive on it, while the imbalances give the values of the
+/// messages at fault.
+#[derive(Debug, Clone)]
+pub struct InteractionStats<F> {
+    /// The scope of the interactions.
+    pub scope: InteractionScope,
+    /// The tallies of every chip, per bus.
+    pub tallies: BTreeMap<InteractionKind, BTreeMap<String, BusTally<F>>>,
+    /// The unbalanced messages, by bus and values.
+    pub imbalances: Vec<InteractionImbalance<F>>,
+}
+
+impl<F: Field> InteractionStats<F> {
+    /// Whether the sends and receives of every message balance.
+    pub fn is_balanced(&self) -> bool {
+        self.imbalances.is_empty()
+    }
+
+    /// The buses with unbalanced messages.
+    pub fn unbalanced_kinds(&self) -> Vec<InteractionKind> {
+        let mut kinds = self.imbalances.iter().map(|imbalance| imbalance.kind).collect::<Vec<_>>();
+        kinds.dedup();
+        kinds
+    }
+
+    /// The tally of a bus over all the chips.
+    pub fn bus_tally(&self, kind: InteractionKind) -> BusTally<F> {
+        let mut total = BusTally::default();
+        for tally in self.tallies.get(&kind).into_iter().flat_map(BTreeMap::values) {
+            total.sent += tally.sent;
+            total.received += tally.received;
+            total.count += tally.count;
+        }
+        total
+    }
+}
+
+impl<F: PrimeField32> Display for InteractionStats<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let unbalanced = self.unbalanced_kinds();
+        writeln!(f, "{} interactions:", self.scope)?;
+        for (kind, chips) in &self.tallies {
+            let total = self.bus_tally(*kind);
+            let status = if unbalanced.contains(kind) { "UNBALANCED" } else { "balanced" };
+            writeln!(
+                f,
+                "  {kind} bus ({status}): sent {}, received {}, net {}",
+                field_to_int(total.sent),
+                field_to_int(total.received),
+                field_to_int(total.net())
+            )?;
+            for (chip, tally) in chips {
+                writeln!(
+                    f,
+                    "    {chip:<24} sent {:>10}  received {:>10}  net {:>10}  ({} interactions)",
+                    field_to_int(tally.sent),
+                    field_to_int(tally.received),
+                    field_to_int(tally.net()),
+                    tally.count
+                )?;
+            }
+        }
+
+        if self.imbalances.is_empty() {
+            return writeln!(f, "All the sends and receives balance.");
+        }
+        writeln!(f, "{} unbalanced messages (sent minus received):", self.imbalances.len())?;
+        for imbalance in self.imbalances.iter().take(MAX_SHOWN_IMBALANCES) {
+            let chips = imbalance
+                .chips
+                .iter()
+                .map(|(chip, net)| format!("{chip}: {}", field_to_int(*net)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                f,
+                "  {} {}: {} [{chips}]",
+                imbalance.kind,
+                vec_to_string(imbalance.values.clone()),
+                field_to_int(imbalance.discrepancy)
+            )?;
+        }
+        if self.imbalances.len() > MAX_SHOWN_IMBALANCES {
+            writeln!(f, "  ... and {} more", self.imbalances.len() - MAX_SHOWN_IMBALANCES)?;
+        }
+        Ok(())
+    }
+}
+
+/// Tallies the sends and receives of every chip of the machine over the given shards, per bus,
+/// and finds the messages whose sends and receives don't balance.
+///
+/// For the local scope, the shards are tallied as one, so a single shard should be given.
+pub fn interaction_stats<SC, A>(
+    machine: &StarkMachine<SC, A>,
+    pkey: &StarkProvingKey<SC>,
+    shards: &[A::Record],
+    scope: InteractionScope,
+) -> InteractionStats<Val<SC>>
+where
+    SC: StarkGenericConfig,
+    SC::Val: PrimeField32,
+    A: MachineAir<SC::Val>,
+{
+    let mut tallies: BTreeMap<InteractionKind, BTreeMap<String, BusTally<Val<SC>>>> =
+        BTreeMap::new();
+    let mut messages: BTreeMap<(InteractionKind, Vec<u32>), BTreeMap<String, Val<SC>>> =
+        BTreeMap::new();
+    for chip in machine.chips() {
+        for shard in shards {
+            if !chip.included(shard) {
+                continue;
+            }
+            let name = chip.name();
+            for_each_interaction(
+                chip,
+                pkey,
+                shard,
+                scope,
+                |_, _, interaction, is_send, mult, values| {
+                    let tally = tallies
+                        .entry(interaction.kind)
+                        .or_default()
+                        .entry(name.clone())
+                        .or_default();
+                    let net = if is_send {
+                        tally.sent += mult;
+                        mult
+                    } else {
+                        tally.received += mult;
+                        -mult
+                    };
+                    tally.count += 1;
+
+                    let key =
+                        (interaction.kind, values.iter().map(|v| v.as_canonical_u32()).collect());
+                    *messages
+                        .entry(key)
+                        .or_default()
+                        .entry(name.clone())
+                        .or_insert(Val::<SC>::zero()) += net;
+                },
+            );
+        }
+    }
+
+    let imbalances = messages
+        .into_iter()
+        .filter_map(|((kind, values), chips)| {
+            let discrepancy = chips.values().copied().sum::<Val<SC>>();
+            (!discrepancy.is_zero()).then(|| InteractionImbalance {
+                kind,
+                values: values.into_iter().map(Val::<SC>::from_canonical_u32).collect(),
+                discrepancy,
+                chips,
+            })
+        })
+        .collect();
+
+    InteractionStats { scope, tallies, imbalances }
+}
@@ -44,6 +44,13 @@ pub enum InteractionKind {
 
     /// Interaction with a syscall.
     Syscall = 8,
+
+    /// Interaction carrying a keccak sponge state between blocks of an accumulator.
+    KeccakState = 9,
+
+    /// Interaction with the secondary byte lookup table, carrying the byte operations of the chips
+    /// moved off the primary byte bus.
+    SecondaryByte = 10,
 }
 
 impl InteractionKind {
@@ -59,8 +66,16 @@ impl InteractionKind {
             InteractionKind::Range,
             InteractionKind::Field,
             InteractionKind::Syscall,
+            InteractionKind::KeccakState,
+            InteractionKind::SecondaryByte,
         ]
     }
+
+    /// Returns the kinds of the byte lookup buses.
+    #[must_use]
+    pub const fn byte_buses() -> [InteractionKind; 2] {
+        [InteractionKind::Byte, InteractionKind::SecondaryByte]
+    }
 }
 
 impl<F: Field> Interaction<F> {
@@ -100,6 +115,8 @@ impl Display for InteractionKind {
             InteractionKind::Range => write!(f, "Range"),
             InteractionKind::Field => write!(f, "Field"),
             InteractionKind::Syscall => write!(f, "Syscall"),
+            InteractionKind::KeccakState => write!(f, "KeccakState"),
+            InteractionKind::SecondaryByte => write!(f, "SecondaryByte"),
         }
     }
 }
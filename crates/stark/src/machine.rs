@@ -13,7 +13,7 @@ use tracing::instrument;
 use super::{debug_constraints, Dom};
 use crate::{
     air::{InteractionScope, MachineAir, MachineProgram},
-    lookup::{debug_interactions_with_all_chips, InteractionKind},
+    lookup::{interaction_stats, InteractionKind},
     record::MachineRecord,
     DebugConstraintBuilder, ShardProof, VerifierConstraintFolder,
 };
@@ -51,6 +51,56 @@ impl<SC: StarkGenericConfig, A> StarkMachine<SC, A> {
     }
 }
 
+/// A builder for a [`StarkMachine`].
+///
+/// Downstream machines can extend an existing one with new chips, by wrapping its air in an enum
+/// deriving `MachineAir` together with the airs of the new chips, and adding the existing chips
+/// with [`Chip::map_air`].
+pub struct MachineBuilder<F: Field, A> {
+    chips: Vec<Chip<F, A>>,
+    num_pv_elts: usize,
+    contains_global_bus: bool,
+}
+
+impl<F: Field, A: MachineAir<F>> MachineBuilder<F, A> {
+    /// Creates a builder for a machine without chips.
+    #[must_use]
+    pub const fn new(num_pv_elts: usize, contains_global_bus: bool) -> Self {
+        Self { chips: Vec::new(), num_pv_elts, contains_global_bus }
+    }
+
+    /// Adds a chip to the machine, after the chips added so far.
+    ///
+    /// The order of the chips is the order of trace generation, so a chip should be added after
+    /// the chips whose traces it depends on.
+    #[must_use]
+    pub fn add_chip(mut self, chip: Chip<F, A>) -> Self {
+        assert!(
+            self.chips.iter().all(|other| other.name() != chip.name()),
+            "the machine already has a chip named {}",
+            chip.name()
+        );
+        self.chips.push(chip);
+        self
+    }
+
+    /// Adds chips to the machine, in order, after the chips added so far.
+    #[must_use]
+    pub fn add_chips(self, chips: impl IntoIterator<Item = Chip<F, A>>) -> Self {
+        chips.into_iter().fold(self, Self::add_chip)
+    }
+
+    /// The chips added so far.
+    pub fn chips(&self) -> &[Chip<F, A>] {
+        &self.chips
+    }
+
+    /// Builds the machine with the given config.
+    pub fn build<SC: StarkGenericConfig<Val = F>>(self, config: SC) -> StarkMachine<SC, A> {
+        StarkMachine::new(config, self.chips, self.num_pv_elts, self.contains_global_bus)
+    }
+}
+
 /// A proving key for a STARK.
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(bound(serialize = "PcsProverData<SC>: Serialize"))]
@@ -276,6 +326,9 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
                 tracing::debug_span!("chip dependencies", chip = chip.name()).in_scope(|| {
                     let mut output = A::Record::default();
                     chip.generate_dependencies(record, &mut output);
+                    if chip.byte_bus() == InteractionKind::SecondaryByte {
+                        output.move_byte_lookups_to_secondary_bus();
+                    }
                     record.append(&mut output);
                 });
             });
@@ -392,7 +445,7 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
         }
 
         let mut global_cumulative_sum = SC::Challenge::zero();
-        for shard in records.iter() {
+        for (index, shard) in records.iter().enumerate() {
             // Filter the chips based on what is used.
             let chips = self.shard_chips(shard).collect::<Vec<_>>();
 
@@ -432,16 +485,14 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
                 cumulative_sums.iter().map(|sum| sum[1]).sum::<SC::Challenge>();
             if !local_cumulative_sum.is_zero() {
                 tracing::warn!("Local cumulative sum is not zero");
-                tracing::debug_span!("debug local interactions").in_scope(|| {
-                    debug_interactions_with_all_chips::<SC, A>(
-                        self,
-                        pk,
-                        &[shard.clone()],
-                        InteractionKind::all_kinds(),
-                        InteractionScope::Local,
-                    )
+                let stats = tracing::debug_span!("debug local interactions").in_scope(|| {
+                    interaction_stats::<SC, A>(self, pk, &[shard.clone()], InteractionScope::Local)
                 });
-                panic!("Local cumulative sum is not zero");
+                tracing::warn!("{stats}");
+                panic!(
+                    "Local cumulative sum is not zero in shard {index}, unbalanced buses: {:?}",
+                    stats.unbalanced_kinds()
+                );
             }
 
             // Compute some statistics.
@@ -486,16 +537,14 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
         // If the global cumulative sum is not zero, debug the interactions.
         if !global_cumulative_sum.is_zero() {
             tracing::warn!("Global cumulative sum is not zero");
-            tracing::debug_span!("debug global interactions").in_scope(|| {
-                debug_interactions_with_all_chips::<SC, A>(
-                    self,
-                    pk,
-                    &records,
-                    InteractionKind::all_kinds(),
-                    InteractionScope::Global,
-                )
+            let stats = tracing::debug_span!("debug global interactions").in_scope(|| {
+                interaction_stats::<SC, A>(self, pk, &records, InteractionScope::Global)
             });
-            panic!("Global cumulative sum is not zero");
+            tracing::warn!("{stats}");
+            panic!(
+                "Global cumulative sum is not zero, unbalanced buses: {:?}",
+                stats.unbalanced_kinds()
+            );
         }
     }
 }
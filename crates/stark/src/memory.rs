@@ -0,0 +1,278 @@
+use std::{
+    env,
+    fmt::{Display, Formatter},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, System};
+
+/// A stage of the proving pipeline whose memory usage is tracked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProvingStage {
+    /// Executing the program to generate checkpoints.
+    Execution,
+    /// Generating the records and traces from the checkpoints.
+    TraceGeneration,
+    /// Committing to the traces.
+    Commit,
+    /// Computing the quotient and opening the commitments.
+    Open,
+    /// Recursively compressing the shard proofs.
+    Recursion,
+}
+
+impl ProvingStage {
+    /// All the stages, in pipeline order.
+    pub const ALL: [Self; 5] =
+        [Self::Execution, Self::TraceGeneration, Self::Commit, Self::Open, Self::Recursion];
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for ProvingStage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Execution => write!(f, "execution"),
+            Self::TraceGeneration => write!(f, "trace generation"),
+            Self::Commit => write!(f, "commit"),
+            Self::Open => write!(f, "open"),
+            Self::Recursion => write!(f, "recursion"),
+        }
+    }
+}
+
+/// Optional caps on the resident set size of the prover during each stage, in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryCaps {
+    /// The cap during execution.
+    pub execution: Option<u64>,
+    /// The cap during trace generation.
+    pub trace_generation: Option<u64>,
+    /// The cap while committing to traces.
+    pub commit: Option<u64>,
+    /// The cap while opening commitments.
+    pub open: Option<u64>,
+    /// The cap during recursion.
+    pub recursion: Option<u64>,
+}
+
+impl MemoryCaps {
+    /// Reads the caps from the `EXECUTION_RSS_CAP_MB`, `TRACE_GEN_RSS_CAP_MB`, `COMMIT_RSS_CAP_MB`,
+    /// `OPEN_RSS_CAP_MB` and `RECURSION_RSS_CAP_MB` environment variables, given in MiB.
+    #[must_use]
+    pub fn from_env() -> Self {
+        let cap = |name: &str| {
+            env::var(name).ok().and_then(|s| s.parse::<u64>().ok()).map(|mb| mb * 1024 * 1024)
+        };
+        Self {
+            execution: cap("EXECUTION_RSS_CAP_MB"),
+            trace_generation: cap("TRACE_GEN_RSS_CAP_MB"),
+            commit: cap("COMMIT_RSS_CAP_MB"),
+            open: cap("OPEN_RSS_CAP_MB"),
+            recursion: cap("RECURSION_RSS_CAP_MB"),
+        }
+    }
+
+    /// The cap for the given stage, if any.
+    #[must_use]
+    pub const fn get(&self, stage: ProvingStage) -> Option<u64> {
+        match stage {
+            ProvingStage::Execution => self.execution,
+            ProvingStage::TraceGeneration => self.trace_generation,
+            ProvingStage::Commit => self.commit,
+            ProvingStage::Open => self.open,
+            ProvingStage::Recursion => self.recursion,
+        }
+    }
+}
+
+/// The peak resident set size observed during each stage, in bytes. A stage that was not run is
+/// reported as zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryWatermarks {
+    /// The peak during execution.
+    pub execution: u64,
+    /// The peak during trace generation.
+    pub trace_generation: u64,
+    /// The peak while committing to traces.
+    pub commit: u64,
+    /// The peak while opening commitments.
+    pub open: u64,
+    /// The peak during recursion.
+    pub recursion: u64,
+}
+
+impl MemoryWatermarks {
+    /// The peak for the given stage.
+    #[must_use]
+    pub const fn get(&self, stage: ProvingStage) -> u64 {
+        match stage {
+            ProvingStage::Execution => self.execution,
+            ProvingStage::TraceGeneration => self.trace_generation,
+            ProvingStage::Commit => self.commit,
+            ProvingStage::Open => self.open,
+            ProvingStage::Recursion => self.recursion,
+        }
+    }
+
+    /// The peak over all the stages.
+    #[must_use]
+    pub fn max(&self) -> u64 {
+        ProvingStage::ALL.iter().map(|&stage| self.get(stage)).max().unwrap_or_default()
+    }
+}
+
+/// The error returned when the prover exceeds the memory cap of a stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryCapExceeded {
+    /// The stage in which the cap was exceeded.
+    pub stage: ProvingStage,
+    /// The resident set size that was observed, in bytes.
+    pub rss: u64,
+    /// The cap of the stage, in bytes.
+    pub cap: u64,
+}
+
+impl Display for MemoryCapExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "resident set size of {} MiB during {} exceeds the cap of {} MiB",
+            self.rss / (1024 * 1024),
+            self.stage,
+            self.cap / (1024 * 1024)
+        )
+    }
+}
+
+impl std::error::Error for MemoryCapExceeded {}
+
+/// Records the peak resident set size of each stage of the proving pipeline and enforces the
+/// [`MemoryCaps`].
+///
+/// The resident set size is sampled whenever a stage finishes a unit of work, so the watermarks
+/// are a lower bound on the true peaks. The tracker is shared between the threads of the pipeline:
+/// the first cap that is exceeded is remembered, so that whichever thread is able to stop the
+/// pipeline cleanly can pick it up with [`MemoryWatermarkTracker::check`].
+pub struct MemoryWatermarkTracker {
+    caps: MemoryCaps,
+    peaks: [AtomicU64; 5],
+    exceeded: Mutex<Option<MemoryCapExceeded>>,
+    system: Mutex<System>,
+    pid: Option<Pid>,
+}
+
+impl MemoryWatermarkTracker {
+    /// Creates a tracker enforcing the given caps.
+    #[must_use]
+    pub fn new(caps: MemoryCaps) -> Self {
+        Self::with_watermarks(caps, MemoryWatermarks::default())
+    }
+
+    /// Creates a tracker enforcing the given caps, starting from previously recorded watermarks.
+    #[must_use]
+    pub fn with_watermarks(caps: MemoryCaps, watermarks: MemoryWatermarks) -> Self {
+        Self {
+            caps,
+            peaks: ProvingStage::ALL.map(|stage| AtomicU64::new(watermarks.get(stage))),
+            exceeded: Mutex::new(None),
+            system: Mutex::new(System::new()),
+            pid: sysinfo::get_current_pid().ok(),
+        }
+    }
+
+    /// Samples the resident set size of the process, in bytes.
+    ///
+    /// Returns `None` if the platform does not report it.
+    pub fn sample(&self) -> Option<u64> {
+        let pid = self.pid?;
+        let mut system = self.system.lock().unwrap();
+        if !system.refresh_process(pid) {
+            return None;
+        }
+        system.process(pid).map(sysinfo::Process::memory)
+    }
+
+    /// Samples the resident set size at the end of a unit of work of `stage`, updates its
+    /// watermark and checks it against the cap of the stage.
+    pub fn record(&self, stage: ProvingStage) -> Result<(), MemoryCapExceeded> {
+        let Some(rss) = self.sample() else {
+            return Ok(());
+        };
+        self.peaks[stage.index()].fetch_max(rss, Ordering::Relaxed);
+
+        match self.caps.get(stage) {
+            Some(cap) if rss > cap => {
+                let error = MemoryCapExceeded { stage, rss, cap };
+                self.exceeded.lock().unwrap().get_or_insert(error);
+                Err(error)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the first cap that was exceeded by any stage, if any.
+    pub fn check(&self) -> Result<(), MemoryCapExceeded> {
+        match *self.exceeded.lock().unwrap() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// The watermarks recorded so far.
+    #[must_use]
+    pub fn watermarks(&self) -> MemoryWatermarks {
+        let peak = |stage: ProvingStage| self.peaks[stage.index()].load(Ordering::Relaxed);
+        MemoryWatermarks {
+            execution: peak(ProvingStage::Execution),
+            trace_generation: peak(ProvingStage::TraceGeneration),
+            commit: peak(ProvingStage::Commit),
+            open: peak(ProvingStage::Open),
+            recursion: peak(ProvingStage::Recursion),
+        }
+    }
+
+    /// Logs the watermarks recorded so far.
+    pub fn report(&self) {
+        let watermarks = self.watermarks();
+        for stage in ProvingStage::ALL {
+            let peak = watermarks.get(stage);
+            if peak > 0 {
+                tracing::info!("peak rss during {}: {} MiB", stage, peak / (1024 * 1024));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_caps_are_enforced() {
+        let caps = MemoryCaps { commit: Some(1), ..MemoryCaps::default() };
+        let tracker = MemoryWatermarkTracker::new(caps);
+        if tracker.sample().is_none() {
+            return;
+        }
+
+        assert!(tracker.record(ProvingStage::Execution).is_ok());
+        assert!(tracker.check().is_ok());
+
+        let error = tracker.record(ProvingStage::Commit).unwrap_err();
+        assert_eq!(error.stage, ProvingStage::Commit);
+        assert_eq!(error.cap, 1);
+        assert_eq!(tracker.check(), Err(error));
+
+        let watermarks = tracker.watermarks();
+        assert!(watermarks.execution > 0);
+        assert!(watermarks.commit > 0);
+        assert_eq!(watermarks.open, 0);
+    }
+}
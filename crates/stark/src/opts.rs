@@ -3,6 +3,8 @@ use std::env;
 use serde::{Deserialize, Serialize};
 use sysinfo::System;
 
+use crate::MemoryCaps;
+
 const MAX_SHARD_SIZE: usize = 1 << 21;
 const RECURSION_MAX_SHARD_SIZE: usize = 1 << 22;
 const MAX_SHARD_BATCH_SIZE: usize = 8;
@@ -33,6 +35,8 @@ impl Default for SP1ProverOpts {
 pub struct SP1CoreOpts {
     /// The size of a shard in terms of cycles.
     pub shard_size: usize,
+    /// How the boundaries of the shards are picked.
+    pub shard_policy: ShardPolicy,
     /// The size of a batch of shards in terms of cycles.
     pub shard_batch_size: usize,
     /// Options for splitting deferred events.
@@ -45,6 +49,15 @@ pub struct SP1CoreOpts {
     pub checkpoints_channel_capacity: usize,
     /// The capacity of the channel for records and traces.
     pub records_and_traces_channel_capacity: usize,
+    /// Caps on the resident set size of the prover during each stage.
+    pub memory_caps: MemoryCaps,
+    /// The maximum number of cycles an execution may run before it is aborted, if any.
+    pub max_cycles: Option<u64>,
+    /// The number of workers proving the nodes of the reduce tree, each node as soon as its
+    /// children are proven. Zero keeps the pipelined reduction of the tree in layer order.
+    pub reduce_workers: usize,
+    /// Whether to pin the reduce workers to the NUMA nodes of the machine in turn.
+    pub numa_pinning: bool,
 }
 
 /// Calculate the default shard size using an empirically determined formula.
@@ -90,16 +103,28 @@ impl Default for SP1CoreOpts {
         let default_shard_size = shard_size(total_available_mem);
         let default_shard_batch_size = shard_batch_size(total_available_mem);
 
+        let mut split_opts = SplitOpts::new(split_threshold);
+        split_opts.bn254_mul_memo = env::var("BN254_MUL_MEMO").is_ok_and(|s| s == "true");
+        split_opts.deferral = DeferralPolicy::from_env();
+        if let Some(memory) = env::var("MEMORY_SPLIT_THRESHOLD")
+            .ok()
+            .and_then(|s| s.parse::<usize>().ok())
+            .filter(|&threshold| threshold > 0)
+        {
+            split_opts.memory = memory;
+        }
+
         Self {
             shard_size: env::var("SHARD_SIZE").map_or_else(
                 |_| default_shard_size,
                 |s| s.parse::<usize>().unwrap_or(default_shard_size),
             ),
+            shard_policy: ShardPolicy::from_env(),
             shard_batch_size: env::var("SHARD_BATCH_SIZE").map_or_else(
                 |_| default_shard_batch_size,
                 |s| s.parse::<usize>().unwrap_or(default_shard_batch_size),
             ),
-            split_opts: SplitOpts::new(split_threshold),
+            split_opts,
             reconstruct_commitments: true,
             trace_gen_workers: env::var("TRACE_GEN_WORKERS").map_or_else(
                 |_| DEFAULT_TRACE_GEN_WORKERS,
@@ -114,6 +139,13 @@ impl Default for SP1CoreOpts {
                     |_| DEFAULT_RECORDS_AND_TRACES_CHANNEL_CAPACITY,
                     |s| s.parse::<usize>().unwrap_or(DEFAULT_RECORDS_AND_TRACES_CHANNEL_CAPACITY),
                 ),
+            memory_caps: MemoryCaps::from_env(),
+            max_cycles: env::var("MAX_CYCLES").ok().and_then(|s| s.parse::<u64>().ok()),
+            reduce_workers: env::var("REDUCE_WORKERS")
+                .ok()
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(0),
+            numa_pinning: env::var("NUMA_PINNING").is_ok_and(|s| s == "true"),
         }
     }
 }
@@ -131,6 +163,47 @@ impl SP1CoreOpts {
     }
 }
 
+/// How the executor picks the boundaries of the shards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShardPolicy {
+    /// Every shard runs for `shard_size` cycles.
+    #[default]
+    Fixed,
+    /// Every shard runs until its estimated area reaches that of `shard_size` cycles, where every
+    /// row its precompile events take in the tables of their chips counts as `precompile_weight`
+    /// cycles. Shards in precompile-heavy regions thus end earlier, which evens out the time to
+    /// prove them.
+    Adaptive {
+        /// The number of cycles a row of a precompile table counts as.
+        precompile_weight: u32,
+    },
+}
+
+impl ShardPolicy {
+    /// The default number of cycles a row of a precompile table counts as.
+    pub const DEFAULT_PRECOMPILE_WEIGHT: u32 = 4;
+
+    /// The adaptive policy with the default weight of the precompile rows.
+    #[must_use]
+    pub const fn adaptive() -> Self {
+        Self::Adaptive { precompile_weight: Self::DEFAULT_PRECOMPILE_WEIGHT }
+    }
+
+    /// Read the policy from the `SHARD_POLICY` environment variable, which is either `fixed` or
+    /// `adaptive`, and the weight of the adaptive policy from `SHARD_PRECOMPILE_WEIGHT`.
+    fn from_env() -> Self {
+        match env::var("SHARD_POLICY").as_deref() {
+            Ok("adaptive") => Self::Adaptive {
+                precompile_weight: env::var("SHARD_PRECOMPILE_WEIGHT")
+                    .ok()
+                    .and_then(|s| s.parse::<u32>().ok())
+                    .unwrap_or(Self::DEFAULT_PRECOMPILE_WEIGHT),
+            },
+            _ => Self::Fixed,
+        }
+    }
+}
+
 /// Options for splitting deferred events.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SplitOpts {
@@ -142,8 +215,39 @@ pub struct SplitOpts {
     pub sha_extend: usize,
     /// The threshold for sha compress events.
     pub sha_compress: usize,
-    /// The threshold for memory events.
+    /// The threshold for chacha20 block events.
+    pub chacha20: usize,
+    /// The threshold for bn254 inner product events, which take 32 rows each.
+    pub bn254_inner_product: usize,
+    /// The threshold for fr ntt events, which take 32 rows each.
+    pub fr_ntt: usize,
+    /// The threshold for rlp scan events, which take 16 rows each.
+    pub rlp_scan: usize,
+    /// The threshold for poseidon merkle root events, which take 520 rows each.
+    pub poseidon_merkle: usize,
+    /// The threshold for zktrie node hash events, which take 65 rows each.
+    pub zktrie_node: usize,
+    /// The threshold for keccak merkle root events, which take 8 rows each.
+    pub keccak_merkle: usize,
+    /// The threshold for withdrawal root events, which take 15 rows each.
+    pub withdraw_root: usize,
+    /// The threshold for RSA modexp events, which take 17 rows each.
+    pub rsa_modexp: usize,
+    /// The threshold for secp256k1 ecrecover events, which take 257 rows each.
+    pub ecrecover: usize,
+    /// The threshold for sha512 compress events, which take 80 rows each.
+    pub sha512_compress: usize,
+    /// The threshold for ed25519 verify events, which take 257 rows each.
+    pub ed25519_verify: usize,
+    /// The threshold for global memory initialize and finalize events.
+    ///
+    /// Memory events are split into shards of at most this many initialize and finalize events
+    /// each, chained together by the addresses of the last events in the public values.
     pub memory: usize,
+    /// Whether bn254 mul-add events with identical operands share a product row.
+    pub bn254_mul_memo: bool,
+    /// Which precompile events are deferred into dedicated shards.
+    pub deferral: DeferralPolicy,
 }
 
 impl SplitOpts {
@@ -155,7 +259,62 @@ impl SplitOpts {
             keccak: deferred_shift_threshold / 24,
             sha_extend: deferred_shift_threshold / 48,
             sha_compress: deferred_shift_threshold / 80,
+            chacha20: deferred_shift_threshold / 10,
+            bn254_inner_product: deferred_shift_threshold / 32,
+            fr_ntt: deferred_shift_threshold / 32,
+            rlp_scan: deferred_shift_threshold / 16,
+            poseidon_merkle: deferred_shift_threshold / 520,
+            zktrie_node: deferred_shift_threshold / 65,
+            keccak_merkle: deferred_shift_threshold / 8,
+            withdraw_root: deferred_shift_threshold / 15,
+            rsa_modexp: deferred_shift_threshold / 17,
+            ecrecover: deferred_shift_threshold / 257,
+            sha512_compress: deferred_shift_threshold / 80,
+            ed25519_verify: deferred_shift_threshold / 257,
             memory: deferred_shift_threshold * 4,
+            bn254_mul_memo: false,
+            deferral: DeferralPolicy::default(),
+        }
+    }
+}
+
+/// Which precompile events are deferred into dedicated shards, rather than kept in the CPU shards
+/// of the cycles which emitted them.
+///
+/// Deferring the events keeps the CPU shards uniform, but a workload calling a precompile heavily
+/// gets a few large shards of its events. Keeping the events in the CPU shards instead spreads
+/// their rows over the shards which called it. The events of the precompiles not covered by the
+/// policy are always deferred, and so are all of them when the shapes of the shards are fixed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeferralPolicy {
+    /// Whether to defer the keccak permute and accumulate events.
+    pub keccak: bool,
+    /// Whether to defer the bn254 mul-add events.
+    pub bn254_muladd: bool,
+    /// Whether to defer the memory copy events.
+    pub memcpy: bool,
+}
+
+impl Default for DeferralPolicy {
+    fn default() -> Self {
+        Self { keccak: true, bn254_muladd: true, memcpy: true }
+    }
+}
+
+impl DeferralPolicy {
+    /// Read the policy from the `INLINE_PRECOMPILES` environment variable, a comma-separated list
+    /// of the precompiles kept in the CPU shards among `keccak`, `bn254_muladd` and `memcpy`.
+    fn from_env() -> Self {
+        let mut policy = Self::default();
+        let Ok(inline) = env::var("INLINE_PRECOMPILES") else { return policy };
+        for name in inline.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            match name {
+                "keccak" => policy.keccak = false,
+                "bn254_muladd" => policy.bn254_muladd = false,
+                "memcpy" => policy.memcpy = false,
+                _ => tracing::warn!("unknown precompile {name:?} in INLINE_PRECOMPILES"),
+            }
         }
+        policy
     }
 }
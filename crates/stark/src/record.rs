@@ -13,6 +13,9 @@ pub trait MachineRecord: Default + Sized + Send + Sync + Clone {
     /// Appends two records together.
     fn append(&mut self, other: &mut Self);
 
+    /// Moves the byte lookups of the record onto the secondary byte bus.
+    fn move_byte_lookups_to_secondary_bus(&mut self) {}
+
     /// Registers the nonces of the record.
     fn register_nonces(&mut self, _opts: &Self::Config) {}
 
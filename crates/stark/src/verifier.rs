@@ -18,7 +18,7 @@ use super::{
 };
 use crate::{
     air::{InteractionScope, MachineAir},
-    MachineChip,
+    InteractionKind, MachineChip,
 };
 
 /// A verifier for a collection of air chips.
@@ -57,22 +57,25 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> Verifier<SC, A> {
 
         let chip_scopes = chips.iter().map(|chip| chip.commit_scope()).collect::<Vec<_>>();
 
-        // Assert that the byte multiplicities don't overflow.
-        let mut max_byte_lookup_mult = 0u64;
-        chips.iter().zip(opened_values.chips.iter()).for_each(|(chip, val)| {
-            max_byte_lookup_mult = max_byte_lookup_mult
-                .checked_add(
-                    (chip.num_sent_byte_lookups() as u64)
-                        .checked_mul(1u64.checked_shl(val.log_degree as u32).unwrap())
-                        .unwrap(),
-                )
-                .unwrap();
-        });
+        // Assert that the byte multiplicities don't overflow. Each byte bus has its own table, so
+        // the bound applies to the lookups of every bus separately.
+        for bus in InteractionKind::byte_buses() {
+            let mut max_byte_lookup_mult = 0u64;
+            chips.iter().zip(opened_values.chips.iter()).for_each(|(chip, val)| {
+                max_byte_lookup_mult = max_byte_lookup_mult
+                    .checked_add(
+                        (chip.num_sends_by_kind(bus) as u64)
+                            .checked_mul(1u64.checked_shl(val.log_degree as u32).unwrap())
+                            .unwrap(),
+                    )
+                    .unwrap();
+            });
 
-        assert!(
-            max_byte_lookup_mult <= SC::Val::order().to_u64().unwrap(),
-            "Byte multiplicities overflow"
-        );
+            assert!(
+                max_byte_lookup_mult <= SC::Val::order().to_u64().unwrap(),
+                "{bus} multiplicities overflow"
+            );
+        }
 
         let log_degrees = opened_values.chips.iter().map(|val| val.log_degree).collect::<Vec<_>>();
 
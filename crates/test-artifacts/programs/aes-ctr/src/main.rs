@@ -0,0 +1,70 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::aes::{Aes, AesCtr};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+pub fn main() {
+    // FIPS-197 appendix C.
+    let plaintext: [u8; 16] = hex("00112233445566778899aabbccddeeff").try_into().unwrap();
+    let mut block = plaintext;
+    Aes::new_128(&hex("000102030405060708090a0b0c0d0e0f").try_into().unwrap())
+        .encrypt_block(&mut block);
+    assert_eq!(block.to_vec(), hex("69c4e0d86a7b0430d8cdb78070b4c55a"));
+
+    let mut block = plaintext;
+    Aes::new_256(
+        &hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+            .try_into()
+            .unwrap(),
+    )
+    .encrypt_block(&mut block);
+    assert_eq!(block.to_vec(), hex("8ea2b7ca516745bfeafc49904b496089"));
+
+    // NIST SP 800-38A F.5.1 and F.5.5.
+    let iv: [u8; 16] = hex("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff").try_into().unwrap();
+    let plaintext = hex(concat!(
+        "6bc1bee22e409f96e93d7e117393172a",
+        "ae2d8a571e03ac9c9eb76fac45af8e51",
+        "30c81c46a35ce411e5fbc1191a0a52ef",
+        "f69f2445df4f9b17ad2b417be66c3710",
+    ));
+    let ciphers = [
+        (
+            Aes::new_128(&hex("2b7e151628aed2a6abf7158809cf4f3c").try_into().unwrap()),
+            hex(concat!(
+                "874d6191b620e3261bef6864990db6ce",
+                "9806f66b7970fdff8617187bb9fffdff",
+                "5ae4df3edbd5d35e5b4f09020db03eab",
+                "1e031dda2fbe03d1792170a0f3009cee",
+            )),
+        ),
+        (
+            Aes::new_256(
+                &hex("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+                    .try_into()
+                    .unwrap(),
+            ),
+            hex(concat!(
+                "601ec313775789a5b7a7f504bbf3d228",
+                "f443e3ca4d62b59aca84e990cacaf5c5",
+                "2b0930daa23de94ce87017ba2d84988d",
+                "dfc9c58db67aada613c2dd08457941a6",
+            )),
+        ),
+    ];
+    for (cipher, ciphertext) in ciphers {
+        // Encrypt in uneven pieces so that the keystream straddles calls.
+        let mut ctr = AesCtr::new(cipher, &iv);
+        let mut data = plaintext.clone();
+        for chunk in data.chunks_mut(7) {
+            ctr.apply_keystream(chunk);
+        }
+        assert_eq!(data, ciphertext);
+    }
+
+    println!("All tests passed successfully!");
+}
@@ -0,0 +1,78 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::{
+    baby_jubjub::BabyJubjubAffinePoint,
+    utils::{bytes_to_words_le, AffinePoint},
+};
+
+pub fn main() {
+    for _ in 0..4 {
+        // 3 * G
+        // 13101045637236771833402109748239486221155516252995707168837790198388440799085
+        // 15305195750036305661220525648961313310481046260814497672243197092298550508693
+        let a: [u8; 64] = [
+            109, 151, 13, 35, 104, 3, 210, 145, 98, 6, 45, 7, 100, 123, 188, 13, 128, 140, 80, 186,
+            173, 154, 208, 144, 204, 48, 172, 148, 214, 237, 246, 28, 149, 124, 253, 67, 27, 99,
+            228, 169, 107, 244, 243, 239, 113, 223, 180, 193, 156, 49, 249, 137, 88, 242, 148, 68,
+            149, 174, 149, 34, 14, 111, 214, 33,
+        ];
+
+        // 1000 * G
+        // 15804601724369313792126331732291770953400520363529691616038701934445270746174
+        // 17979751125406099319734770781608767238997398840154679652223692501113096137972
+        let b: [u8; 64] = [
+            62, 68, 209, 246, 176, 166, 116, 244, 199, 133, 6, 7, 146, 10, 39, 99, 206, 158, 215,
+            215, 8, 145, 123, 122, 118, 62, 128, 64, 107, 22, 241, 34, 244, 108, 68, 75, 241, 221,
+            156, 151, 217, 34, 103, 127, 48, 4, 136, 183, 227, 209, 46, 148, 242, 138, 153, 84, 63,
+            209, 49, 159, 178, 45, 192, 39,
+        ];
+
+        let mut a_point = BabyJubjubAffinePoint::from_le_bytes(&a);
+        let b_point = BabyJubjubAffinePoint::from_le_bytes(&b);
+
+        // Addition.
+        a_point.add_assign(&b_point);
+        // 1003 * G
+        // 7402294280282029647606903333444111113283285670814254114458676388916555426663
+        // 15542262643634729016787691389408745085622857050514380472192287949066272644067
+        let c: [u8; 64] = [
+            103, 43, 116, 87, 126, 191, 233, 168, 57, 230, 32, 252, 216, 105, 186, 238, 87, 151,
+            216, 40, 65, 130, 231, 74, 120, 225, 119, 188, 210, 140, 93, 16, 227, 27, 150, 190, 85,
+            8, 129, 174, 205, 73, 93, 74, 17, 57, 70, 223, 99, 31, 147, 211, 107, 181, 226, 67,
+            155, 125, 6, 239, 225, 155, 92, 34,
+        ];
+        assert_eq!(a_point.to_le_bytes(), c);
+
+        // Conversion to and from the EIP-2494 curve.
+        // 9358428285618164852950231539202462512147404375024939230780676237419316483372
+        let twisted_x: [u8; 32] = [
+            44, 149, 159, 173, 240, 50, 27, 150, 221, 237, 77, 171, 224, 44, 16, 100, 235, 138,
+            117, 95, 233, 61, 44, 198, 55, 9, 236, 147, 197, 174, 176, 20,
+        ];
+        let (x, y) = a_point.to_twisted_edwards();
+        assert_eq!(x.to_vec(), bytes_to_words_le(&twisted_x));
+        let converted = BabyJubjubAffinePoint::from_twisted_edwards(&x, &y);
+        assert_eq!(converted.to_le_bytes(), c);
+
+        // Doubling.
+        a_point.double();
+        // 2006 * G
+        // 4105904170967776163445398459870676190044814232043191455469061885293976412459
+        // 1495146873606067117451082479157589311539607298451543035498878614326683877630
+        let d: [u8; 64] = [
+            43, 225, 220, 67, 133, 77, 208, 159, 125, 34, 234, 62, 197, 194, 192, 107, 82, 252,
+            105, 196, 217, 174, 95, 111, 160, 177, 5, 13, 2, 220, 19, 9, 254, 192, 247, 251, 133,
+            185, 2, 129, 112, 207, 78, 227, 19, 127, 142, 182, 189, 13, 0, 188, 196, 96, 216, 82,
+            181, 247, 47, 204, 31, 57, 78, 3,
+        ];
+        assert_eq!(a_point.to_le_bytes(), d);
+
+        // Scalar multiplication of the generator.
+        let mut g_point = BabyJubjubAffinePoint::new(BabyJubjubAffinePoint::GENERATOR);
+        g_point.mul_assign(&[1000, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        assert_eq!(g_point.to_le_bytes(), b);
+    }
+
+    println!("done");
+}
@@ -0,0 +1,65 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num, One};
+use rand::Rng;
+use sp1_zkvm::syscalls::syscall_barrett_reduce;
+
+fn barrett_reduce(x: &BigUint, m: &BigUint) -> BigUint {
+    let mut x_bytes = x.to_bytes_le();
+    x_bytes.resize(64, 0);
+    let mut m_bytes = m.to_bytes_le();
+    m_bytes.resize(32, 0);
+
+    let mut x_words = bytemuck::cast::<[u8; 64], [u32; 16]>(x_bytes.try_into().unwrap());
+    let m_words = bytemuck::cast::<[u8; 32], [u32; 8]>(m_bytes.try_into().unwrap());
+    syscall_barrett_reduce(&mut x_words, &m_words);
+
+    // The high half of x is cleared.
+    assert!(x_words[8..].iter().all(|&word| word == 0));
+    BigUint::from_bytes_le(&bytemuck::cast::<[u32; 16], [u8; 64]>(x_words))
+}
+
+pub fn main() {
+    // The STARK curve base field modulus.
+    let stark = BigUint::from_str_radix(
+        "800000000000011000000000000000000000000000000000000000000000001",
+        16,
+    )
+    .unwrap();
+    // The Pallas base field modulus.
+    let pallas = BigUint::from_str_radix(
+        "40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
+        16,
+    )
+    .unwrap();
+    let two_256 = BigUint::one() << 256;
+    let moduli = [stark, pallas, BigUint::from(2u32), &two_256 - 1u32, &two_256 >> 1];
+
+    let mut rng = rand::thread_rng();
+    for m in moduli.iter() {
+        // Test with random values.
+        for _ in 0..5 {
+            let bytes: [u8; 64] = std::array::from_fn(|_| rng.gen());
+            let x = BigUint::from_bytes_le(&bytes);
+            assert_eq!(barrett_reduce(&x, m), &x % m);
+        }
+
+        // Test with the edge cases zero, the modulus and the largest 512-bit value.
+        let max = (BigUint::one() << 512) - 1u32;
+        for x in [BigUint::from(0u32), m.clone(), max] {
+            assert_eq!(barrett_reduce(&x, m), &x % m);
+        }
+    }
+
+    // Test with random moduli.
+    for _ in 0..5 {
+        let bytes: [u8; 32] = rng.gen();
+        let m = BigUint::from_bytes_le(&bytes) | BigUint::from(2u32);
+        let bytes: [u8; 64] = std::array::from_fn(|_| rng.gen());
+        let x = BigUint::from_bytes_le(&bytes);
+        assert_eq!(barrett_reduce(&x, &m), &x % &m);
+    }
+
+    println!("All tests passed successfully!");
+}
@@ -0,0 +1,33 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::base64::{decode, DecodeError};
+
+pub fn main() {
+    // A full block decoded by the precompile, an unpadded group and a padded final group.
+    assert_eq!(
+        decode("VGhlIHF1aWNrIGJyb3duIGZveCBqdW1wcw==").unwrap(),
+        b"The quick brown fox jumps"
+    );
+    assert_eq!(decode("SGVsbG8sIHdvcmxkIQ==").unwrap(), b"Hello, world!");
+    assert_eq!(decode("SGVsbG8sIHdvcmxkIQ").unwrap_err(), DecodeError::InvalidLength(18));
+    assert_eq!(
+        decode("+/+/+/+/+/+/+/+/AAAA").unwrap(),
+        [0xfb, 0xff, 0xbf, 0xfb, 0xff, 0xbf, 0xfb, 0xff, 0xbf, 0xfb, 0xff, 0xbf, 0, 0, 0]
+    );
+    assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+    assert_eq!(decode("YQ==").unwrap(), b"a");
+    assert_eq!(decode("YWI=").unwrap(), b"ab");
+    assert_eq!(decode("YWJj").unwrap(), b"abc");
+
+    // An invalid character in a block decoded by the precompile.
+    assert_eq!(decode("SGVsbG8s-HdvcmxkIQ=="), Err(DecodeError::InvalidByte(8, b'-')));
+
+    // Malformed padding and nonzero trailing bits.
+    assert_eq!(decode("Y==="), Err(DecodeError::InvalidPadding));
+    assert_eq!(decode("YR=="), Err(DecodeError::InvalidLastSymbol(1, b'R')));
+    assert_eq!(decode("YWJ="), Err(DecodeError::InvalidLastSymbol(2, b'J')));
+    assert_eq!(decode("Y=Jj"), Err(DecodeError::InvalidByte(1, b'=')));
+
+    println!("done");
+}
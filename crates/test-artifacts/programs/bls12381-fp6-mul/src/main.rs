@@ -0,0 +1,121 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num_bigint::{BigUint, RandBigInt};
+use sp1_lib::bls12381::{fp12_mul, fp6_mul, FP12_WORDS, FP6_WORDS};
+use std::str::FromStr;
+
+const MODULUS: &str = "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787";
+
+type Fp2 = [BigUint; 2];
+type Fp6 = [Fp2; 3];
+type Fp12 = [Fp6; 2];
+
+fn fp2_add(a: &Fp2, b: &Fp2, p: &BigUint) -> Fp2 {
+    [(&a[0] + &b[0]) % p, (&a[1] + &b[1]) % p]
+}
+
+fn fp2_sub(a: &Fp2, b: &Fp2, p: &BigUint) -> Fp2 {
+    [(p + &a[0] - &b[0]) % p, (p + &a[1] - &b[1]) % p]
+}
+
+fn fp2_mul(a: &Fp2, b: &Fp2, p: &BigUint) -> Fp2 {
+    [(p * p + &a[0] * &b[0] - &a[1] * &b[1]) % p, (&a[0] * &b[1] + &a[1] * &b[0]) % p]
+}
+
+fn fp2_mul_by_xi(a: &Fp2, p: &BigUint) -> Fp2 {
+    [(p + &a[0] - &a[1]) % p, (&a[0] + &a[1]) % p]
+}
+
+fn fp6_add(a: &Fp6, b: &Fp6, p: &BigUint) -> Fp6 {
+    [fp2_add(&a[0], &b[0], p), fp2_add(&a[1], &b[1], p), fp2_add(&a[2], &b[2], p)]
+}
+
+fn fp6_mul(a: &Fp6, b: &Fp6, p: &BigUint) -> Fp6 {
+    let mut c: Fp6 = Default::default();
+    for i in 0..3 {
+        for j in 0..3 {
+            let product = fp2_mul(&a[i], &b[j], p);
+            // v^3 = 1 + u.
+            let product = if i + j >= 3 { fp2_mul_by_xi(&product, p) } else { product };
+            c[(i + j) % 3] = fp2_add(&c[(i + j) % 3], &product, p);
+        }
+    }
+    c
+}
+
+fn fp6_mul_by_v(a: &Fp6, p: &BigUint) -> Fp6 {
+    [fp2_mul_by_xi(&a[2], p), a[0].clone(), a[1].clone()]
+}
+
+fn fp12_mul(a: &Fp12, b: &Fp12, p: &BigUint) -> Fp12 {
+    let c0 = fp6_add(&fp6_mul(&a[0], &b[0], p), &fp6_mul_by_v(&fp6_mul(&a[1], &b[1], p), p), p);
+    let c1 = fp6_add(&fp6_mul(&a[0], &b[1], p), &fp6_mul(&a[1], &b[0], p), p);
+    [c0, c1]
+}
+
+fn to_words(coeffs: impl IntoIterator<Item = BigUint>, words: &mut [u32]) {
+    for (chunk, coeff) in words.chunks_exact_mut(12).zip(coeffs) {
+        let digits = coeff.to_u32_digits();
+        chunk.fill(0);
+        chunk[..digits.len()].copy_from_slice(&digits);
+    }
+}
+
+fn from_words(words: &[u32]) -> Vec<BigUint> {
+    words.chunks_exact(12).map(BigUint::from_slice).collect()
+}
+
+fn random_fp2(p: &BigUint) -> Fp2 {
+    let mut rng = rand::thread_rng();
+    [rng.gen_biguint_below(p), rng.gen_biguint_below(p)]
+}
+
+fn random_fp6(p: &BigUint) -> Fp6 {
+    [random_fp2(p), random_fp2(p), random_fp2(p)]
+}
+
+fn flatten_fp6(a: &Fp6) -> impl Iterator<Item = BigUint> + '_ {
+    a.iter().flatten().cloned()
+}
+
+pub fn main() {
+    let p = BigUint::from_str(MODULUS).unwrap();
+
+    for _ in 0..10 {
+        let a = random_fp6(&p);
+        let b = random_fp6(&p);
+
+        let mut a_words = [0u32; FP6_WORDS];
+        let mut b_words = [0u32; FP6_WORDS];
+        to_words(flatten_fp6(&a), &mut a_words);
+        to_words(flatten_fp6(&b), &mut b_words);
+        fp6_mul(&mut a_words, &b_words);
+
+        let expected = fp6_mul(&a, &b, &p);
+        assert_eq!(from_words(&a_words), flatten_fp6(&expected).collect::<Vec<_>>());
+
+        // The product of x with itself, where x and y are the same pointer.
+        to_words(flatten_fp6(&a), &mut a_words);
+        let a_copy = a_words;
+        fp6_mul(&mut a_words, &a_copy);
+        let expected = fp6_mul(&a, &a, &p);
+        assert_eq!(from_words(&a_words), flatten_fp6(&expected).collect::<Vec<_>>());
+    }
+
+    for _ in 0..5 {
+        let a: Fp12 = [random_fp6(&p), random_fp6(&p)];
+        let b: Fp12 = [random_fp6(&p), random_fp6(&p)];
+
+        let mut a_words = [0u32; FP12_WORDS];
+        let mut b_words = [0u32; FP12_WORDS];
+        to_words(a.iter().flat_map(flatten_fp6), &mut a_words);
+        to_words(b.iter().flat_map(flatten_fp6), &mut b_words);
+        fp12_mul(&mut a_words, &b_words);
+
+        let expected = fp12_mul(&a, &b, &p);
+        assert_eq!(from_words(&a_words), expected.iter().flat_map(flatten_fp6).collect::<Vec<_>>());
+    }
+
+    println!("All tests passed!");
+}
@@ -0,0 +1,24 @@
+#![no_main]
+
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::lib::bn254::{decompress, decompress_g2};
+
+pub fn main() {
+    let g1_x: [u8; 32] = sp1_zkvm::io::read_vec().try_into().unwrap();
+    let g1_sign_bit = sp1_zkvm::io::read::<bool>();
+    let g2_x: [u8; 64] = sp1_zkvm::io::read_vec().try_into().unwrap();
+    let g2_sign_bit = sp1_zkvm::io::read::<bool>();
+
+    let mut g1_point = [0u8; 64];
+    g1_point[..32].copy_from_slice(&g1_x);
+    let g1_ok = decompress(&mut g1_point, g1_sign_bit).is_ok();
+    sp1_zkvm::io::commit(&g1_ok);
+    sp1_zkvm::io::commit_slice(&g1_point);
+
+    let mut g2_point = [0u8; 128];
+    g2_point[..64].copy_from_slice(&g2_x);
+    let g2_ok = decompress_g2(&mut g2_point, g2_sign_bit).is_ok();
+    sp1_zkvm::io::commit(&g2_ok);
+    sp1_zkvm::io::commit_slice(&g2_point);
+}
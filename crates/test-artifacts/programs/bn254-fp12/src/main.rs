@@ -0,0 +1,132 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num_bigint::{BigUint, RandBigInt};
+use sp1_zkvm::syscalls::{
+    syscall_bn254_fp12_inverse, syscall_bn254_fp12_mul, syscall_bn254_fp12_square,
+    syscall_bn254_fp6_mulmod,
+};
+use std::str::FromStr;
+
+const MODULUS: &str =
+    "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+type Fp2 = [BigUint; 2];
+type Fp6 = [Fp2; 3];
+type Fp12 = [Fp6; 2];
+
+fn fp2_add(a: &Fp2, b: &Fp2, p: &BigUint) -> Fp2 {
+    [(&a[0] + &b[0]) % p, (&a[1] + &b[1]) % p]
+}
+
+fn fp2_mul(a: &Fp2, b: &Fp2, p: &BigUint) -> Fp2 {
+    [(p * p + &a[0] * &b[0] - &a[1] * &b[1]) % p, (&a[0] * &b[1] + &a[1] * &b[0]) % p]
+}
+
+/// Multiplies by the cubic non-residue `9 + u`.
+fn fp2_mul_by_xi(a: &Fp2, p: &BigUint) -> Fp2 {
+    [(&a[0] * 9u32 + p - &a[1]) % p, (&a[0] + &a[1] * 9u32) % p]
+}
+
+fn fp6_add(a: &Fp6, b: &Fp6, p: &BigUint) -> Fp6 {
+    [fp2_add(&a[0], &b[0], p), fp2_add(&a[1], &b[1], p), fp2_add(&a[2], &b[2], p)]
+}
+
+fn fp6_mul(a: &Fp6, b: &Fp6, p: &BigUint) -> Fp6 {
+    let mut c: Fp6 = Default::default();
+    for i in 0..3 {
+        for j in 0..3 {
+            let product = fp2_mul(&a[i], &b[j], p);
+            // v^3 = 9 + u.
+            let product = if i + j >= 3 { fp2_mul_by_xi(&product, p) } else { product };
+            c[(i + j) % 3] = fp2_add(&c[(i + j) % 3], &product, p);
+        }
+    }
+    c
+}
+
+fn fp6_mul_by_v(a: &Fp6, p: &BigUint) -> Fp6 {
+    [fp2_mul_by_xi(&a[2], p), a[0].clone(), a[1].clone()]
+}
+
+fn fp12_mul(a: &Fp12, b: &Fp12, p: &BigUint) -> Fp12 {
+    let c0 = fp6_add(&fp6_mul(&a[0], &b[0], p), &fp6_mul_by_v(&fp6_mul(&a[1], &b[1], p), p), p);
+    let c1 = fp6_add(&fp6_mul(&a[0], &b[1], p), &fp6_mul(&a[1], &b[0], p), p);
+    [c0, c1]
+}
+
+fn to_words(coeffs: impl IntoIterator<Item = BigUint>, words: &mut [u32]) {
+    for (chunk, coeff) in words.chunks_exact_mut(8).zip(coeffs) {
+        let digits = coeff.to_u32_digits();
+        chunk.fill(0);
+        chunk[..digits.len()].copy_from_slice(&digits);
+    }
+}
+
+fn from_words(words: &[u32]) -> Vec<BigUint> {
+    words.chunks_exact(8).map(BigUint::from_slice).collect()
+}
+
+fn random_fp6(p: &BigUint) -> Fp6 {
+    let mut rng = rand::thread_rng();
+    core::array::from_fn(|_| [rng.gen_biguint_below(p), rng.gen_biguint_below(p)])
+}
+
+fn flatten_fp6(a: &Fp6) -> impl Iterator<Item = BigUint> + '_ {
+    a.iter().flatten().cloned()
+}
+
+fn flatten_fp12(a: &Fp12) -> Vec<BigUint> {
+    a.iter().flat_map(flatten_fp6).collect()
+}
+
+pub fn main() {
+    let p = BigUint::from_str(MODULUS).unwrap();
+
+    for _ in 0..10 {
+        let a = random_fp6(&p);
+        let b = random_fp6(&p);
+
+        let mut a_words = [0u32; 48];
+        let mut b_words = [0u32; 48];
+        to_words(flatten_fp6(&a), &mut a_words);
+        to_words(flatten_fp6(&b), &mut b_words);
+        syscall_bn254_fp6_mulmod(a_words.as_mut_ptr(), b_words.as_ptr());
+
+        let expected = fp6_mul(&a, &b, &p);
+        assert_eq!(from_words(&a_words), flatten_fp6(&expected).collect::<Vec<_>>());
+    }
+
+    let mut one = [0u32; 96];
+    one[0] = 1;
+
+    for _ in 0..3 {
+        let a: Fp12 = [random_fp6(&p), random_fp6(&p)];
+        let b: Fp12 = [random_fp6(&p), random_fp6(&p)];
+
+        let mut a_words = [0u32; 96];
+        let mut b_words = [0u32; 96];
+        to_words(flatten_fp12(&a), &mut a_words);
+        to_words(flatten_fp12(&b), &mut b_words);
+
+        // Multiplication.
+        let mut product = a_words;
+        unsafe { syscall_bn254_fp12_mul(product.as_mut_ptr(), b_words.as_ptr()) };
+        assert_eq!(from_words(&product), flatten_fp12(&fp12_mul(&a, &b, &p)));
+
+        // Squaring.
+        let mut square = a_words;
+        unsafe { syscall_bn254_fp12_square(square.as_mut_ptr()) };
+        assert_eq!(from_words(&square), flatten_fp12(&fp12_mul(&a, &a, &p)));
+
+        // Inversion.
+        let mut inverse = a_words;
+        unsafe {
+            syscall_bn254_fp12_inverse(inverse.as_mut_ptr());
+            syscall_bn254_fp12_mul(inverse.as_mut_ptr(), a_words.as_ptr());
+        }
+        assert_eq!(inverse, one);
+    }
+
+    println!("All tests passed!");
+}
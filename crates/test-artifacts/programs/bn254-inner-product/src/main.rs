@@ -0,0 +1,62 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num};
+use rand::Rng;
+use sp1_zkvm::{lib::bn254::bn254_inner_product, syscalls::syscall_bn254_inner_product};
+
+fn biguint_to_words(x: &BigUint) -> [u32; 8] {
+    let mut words = x.to_u32_digits();
+    words.resize(8, 0);
+    words.try_into().unwrap()
+}
+
+fn words_to_biguint(x: &[u32; 8]) -> BigUint {
+    BigUint::from_slice(x)
+}
+
+fn random_element(rng: &mut impl Rng, modulus: &BigUint) -> [u32; 8] {
+    let words: [u32; 8] = rng.gen();
+    biguint_to_words(&(words_to_biguint(&words) % modulus))
+}
+
+pub fn main() {
+    // bn254 scalar field modulus
+    let modulus = BigUint::from_str_radix(
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10,
+    )
+    .unwrap();
+
+    // Test a single call with a single pair, and with the largest number of pairs.
+    let mut rng = rand::thread_rng();
+    for len in [1usize, 5, 32] {
+        let mut x = random_element(&mut rng, &modulus);
+        let mut expected = words_to_biguint(&x);
+        let mut pairs = vec![len as u32];
+        for _ in 0..len {
+            let (a, b) = (random_element(&mut rng, &modulus), random_element(&mut rng, &modulus));
+            expected += words_to_biguint(&a) * words_to_biguint(&b);
+            pairs.extend_from_slice(&a);
+            pairs.extend_from_slice(&b);
+        }
+
+        syscall_bn254_inner_product(&mut x, pairs.as_ptr());
+        assert_eq!(words_to_biguint(&x), expected % &modulus);
+    }
+
+    // Test a vector split into several calls, with the edge case of the modulus minus one.
+    let max = biguint_to_words(&(&modulus - 1u32));
+    let pairs = (0..70)
+        .map(|i| if i % 7 == 0 { (max, max) } else { (random_element(&mut rng, &modulus), max) })
+        .collect::<Vec<_>>();
+    let mut x = max;
+    let expected = pairs
+        .iter()
+        .fold(words_to_biguint(&x), |acc, (a, b)| acc + words_to_biguint(a) * words_to_biguint(b))
+        % &modulus;
+    bn254_inner_product(&mut x, &pairs);
+    assert_eq!(words_to_biguint(&x), expected);
+
+    println!("All tests passed successfully!");
+}
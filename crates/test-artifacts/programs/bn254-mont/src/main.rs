@@ -0,0 +1,60 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num, One};
+use rand::Rng;
+use sp1_zkvm::syscalls::{syscall_bn254_scalar_from_mont, syscall_bn254_scalar_to_mont};
+
+fn to_mont(x: &[u8; 32]) -> [u8; 32] {
+    let mut words = bytemuck::cast::<[u8; 32], [u32; 8]>(*x);
+    syscall_bn254_scalar_to_mont(&mut words);
+    bytemuck::cast::<[u32; 8], [u8; 32]>(words)
+}
+
+fn from_mont(x: &[u8; 32]) -> [u8; 32] {
+    let mut words = bytemuck::cast::<[u8; 32], [u32; 8]>(*x);
+    syscall_bn254_scalar_from_mont(&mut words);
+    bytemuck::cast::<[u32; 8], [u8; 32]>(words)
+}
+
+fn biguint_to_bytes_le(x: &BigUint) -> [u8; 32] {
+    let mut bytes = x.to_bytes_le();
+    bytes.resize(32, 0);
+    bytes.try_into().unwrap()
+}
+
+pub fn main() {
+    // bn254 scalar field modulus
+    let modulus = BigUint::from_str_radix(
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10,
+    )
+    .unwrap();
+    let r = (BigUint::one() << 256) % &modulus;
+
+    // Test with random canonical elements.
+    let mut rng = rand::thread_rng();
+    for _ in 0..10 {
+        let bytes: [u8; 32] = rng.gen();
+        let x = BigUint::from_bytes_le(&bytes) % &modulus;
+        let x_bytes = biguint_to_bytes_le(&x);
+
+        let mont = to_mont(&x_bytes);
+        assert_eq!(BigUint::from_bytes_le(&mont), (&x * &r) % &modulus);
+
+        let canonical = from_mont(&mont);
+        assert_eq!(canonical, x_bytes);
+    }
+
+    // Test with the edge cases zero, one and the modulus minus one.
+    for x in [BigUint::from(0u32), BigUint::one(), &modulus - 1u32] {
+        let x_bytes = biguint_to_bytes_le(&x);
+        assert_eq!(from_mont(&to_mont(&x_bytes)), x_bytes);
+    }
+
+    // A non-canonical input is reduced.
+    let x_bytes = biguint_to_bytes_le(&(&modulus + 1u32));
+    assert_eq!(BigUint::from_bytes_le(&from_mont(&to_mont(&x_bytes))), BigUint::one());
+
+    println!("All tests passed successfully!");
+}
@@ -0,0 +1,33 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::syscalls::syscall_chacha20_block;
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+fn words(bytes: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    bytes.chunks_exact(4).map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+}
+
+pub fn main() {
+    // RFC 7539 section 2.3.2.
+    let key = hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+    let nonce = hex("000000090000004a00000000");
+    let mut state = [0u32; 16];
+    state[..4].copy_from_slice(&[0x61707865, 0x3320646e, 0x79622d32, 0x6b206574]);
+    state[4..12].iter_mut().zip(words(&key)).for_each(|(word, key)| *word = key);
+    state[12] = 1;
+    state[13..].iter_mut().zip(words(&nonce)).for_each(|(word, nonce)| *word = nonce);
+
+    syscall_chacha20_block(&mut state);
+
+    let expected = hex(concat!(
+        "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e",
+        "d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e",
+    ));
+    assert_eq!(state.iter().copied().collect::<Vec<_>>(), words(&expected).collect::<Vec<_>>());
+
+    println!("done");
+}
@@ -0,0 +1,43 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use core::fmt::Write;
+
+use sp1_zkvm::collections::{String, Vec};
+
+pub fn main() {
+    // A lone vector grows in place, as its buffer always ends at the heap position.
+    let mut words = Vec::new();
+    for i in 0..1000u32 {
+        words.push(i.wrapping_mul(0x9e37_79b9));
+    }
+    assert_eq!(words.len(), 1000);
+    assert_eq!(words.capacity(), 1024);
+    assert!(words.iter().enumerate().all(|(i, &w)| w == (i as u32).wrapping_mul(0x9e37_79b9)));
+
+    // Interleaved vectors block each other's in-place growth, so their buffers get relocated.
+    let mut evens = Vec::new();
+    let mut odds = Vec::new();
+    for i in 0..500u64 {
+        evens.push(2 * i);
+        odds.push(2 * i + 1);
+    }
+    assert!(evens.iter().enumerate().all(|(i, &x)| x == 2 * i as u64));
+    assert!(odds.iter().enumerate().all(|(i, &x)| x == 2 * i as u64 + 1));
+
+    let mut bytes = Vec::from(&b"zkvm"[..]);
+    bytes.extend_from_slice(&[0xab; 100]);
+    assert_eq!(&bytes[..4], b"zkvm");
+    assert_eq!(bytes.pop(), Some(0xab));
+    assert_eq!(bytes.into_vec().len(), 103);
+
+    let mut string = String::from("sp1");
+    for i in 0..50 {
+        write!(string, ",{i}").unwrap();
+    }
+    string.push('!');
+    let expected =
+        (0..50).fold(std::string::String::from("sp1"), |s, i| s + &format!(",{i}")) + "!";
+    assert_eq!(string, expected.as_str());
+    assert_eq!(string.into_string(), expected);
+}
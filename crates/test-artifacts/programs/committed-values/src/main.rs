@@ -0,0 +1,20 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let swap = sp1_zkvm::io::read::<bool>();
+    if swap {
+        sp1_zkvm::io::commit_slice(b"out");
+        sp1_zkvm::io::commit(&7u32);
+    } else {
+        sp1_zkvm::io::commit(&7u32);
+        sp1_zkvm::io::commit_slice(b"out");
+    }
+
+    // The output must be committed in the order the verifier expects.
+    let committed = sp1_zkvm::io::committed();
+    assert_eq!(committed.len, 7);
+    assert!(committed.matches(&[7, 0, 0, 0, b'o', b'u', b't']));
+
+    sp1_zkvm::io::commit_slice(&committed.digest);
+}
@@ -0,0 +1,23 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::ct::{select, select_words};
+
+pub fn main() {
+    let a: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let b: [u8; 32] = core::array::from_fn(|i| 0xff - i as u8);
+    assert_eq!(select(true, &a, &b), a);
+    assert_eq!(select(false, &a, &b), b);
+    assert_eq!(select(true, &a, &a), a);
+
+    // The condition is read from the program, so that the selections are not folded away.
+    let words_a = [u32::MAX, 0, 1, 2, 3, 4, 5, u32::MAX];
+    let words_b = [0, u32::MAX, 0x8000_0000, 7, 6, 5, 4, 3];
+    for i in 0..4u32 {
+        let cond = core::hint::black_box(i % 2 == 1);
+        let expected = if cond { words_a } else { words_b };
+        assert_eq!(select_words(cond, &words_a, &words_b), expected);
+    }
+
+    println!("done");
+}
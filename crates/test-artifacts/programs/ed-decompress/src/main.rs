@@ -1,7 +1,7 @@
 #![no_main]
 
 use hex_literal::hex;
-use sp1_zkvm::syscalls::syscall_ed_decompress;
+use sp1_zkvm::lib::{ed25519::decompress, utils::SyscallError};
 
 sp1_zkvm::entrypoint!(main);
 
@@ -14,7 +14,7 @@ pub fn main() {
 
         println!("before: {:?}", decompressed);
 
-        syscall_ed_decompress(&mut decompressed);
+        decompress(&mut decompressed).unwrap();
 
         let expected: [u8; 64] = [
             47, 252, 114, 91, 153, 234, 110, 201, 201, 153, 152, 14, 68, 231, 90, 221, 137, 110,
@@ -27,5 +27,12 @@ pub fn main() {
         println!("after: {:?}", decompressed);
     }
 
+    // There is no point with Y = 2, so the decompression fails and leaves X as it was.
+    let mut invalid = [0xaa_u8; 64];
+    invalid[32..].copy_from_slice(&[0; 32]);
+    invalid[32] = 2;
+    assert_eq!(decompress(&mut invalid), Err(SyscallError::InvalidPoint));
+    assert_eq!(invalid[..32], [0xaa; 32]);
+
     println!("done");
 }
@@ -0,0 +1,52 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::lib::ed25519::{verify, Ed25519Verifier};
+
+// The signature verified by the `ed25519-program` test with `ed25519-dalek`.
+const PUBLIC_KEY: &str = "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf";
+const MESSAGE: &[u8] = b"abcabcabcabcabcabcabcabcabcabcabcabcabc";
+const SIGNATURE: &str = "46557efe96d22d07e104d9d7fab558fb02f6b13116056e6d7c300d7bb1320599\
+                         07d538eac68ec7864aa2ac2e23ea7082a04002b0acdac2ff8ccad7e80e64dd00";
+
+/// The order of the prime subgroup of Ed25519, as little-endian bytes.
+const ORDER: &str = "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010";
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+pub fn main() {
+    let public_key: [u8; 32] = decode_hex(PUBLIC_KEY).try_into().unwrap();
+    let signature: [u8; 64] = decode_hex(SIGNATURE).try_into().unwrap();
+    assert!(verify(&public_key, &signature, MESSAGE));
+
+    // The message can be streamed in chunks.
+    let mut verifier = Ed25519Verifier::new(&public_key, &signature);
+    for chunk in MESSAGE.chunks(5) {
+        verifier.update(chunk);
+    }
+    assert!(verifier.finalize());
+
+    // A tampered message, signature or key fails the verification.
+    let mut message = MESSAGE.to_vec();
+    message[0] ^= 1;
+    assert!(!verify(&public_key, &signature, &message));
+
+    let mut tampered_r = signature;
+    tampered_r[0] ^= 1;
+    assert!(!verify(&public_key, &tampered_r, MESSAGE));
+
+    let mut tampered_s = signature;
+    tampered_s[32] ^= 1;
+    assert!(!verify(&public_key, &tampered_s, MESSAGE));
+
+    let mut tampered_key = public_key;
+    tampered_key[0] ^= 1;
+    assert!(!verify(&tampered_key, &signature, MESSAGE));
+
+    // A signature with `S` not reduced is rejected.
+    let mut high_s = signature;
+    high_s[32..].copy_from_slice(&decode_hex(ORDER));
+    assert!(!verify(&public_key, &high_s, MESSAGE));
+}
@@ -0,0 +1,14 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+pub fn main() {
+    let timestamp = sp1_zkvm::env::timestamp();
+    let seed = sp1_zkvm::env::seed();
+
+    // Later accesses return the committed values, without reading or committing them again.
+    assert_eq!(sp1_zkvm::env::timestamp(), timestamp);
+    assert_eq!(sp1_zkvm::env::seed(), seed);
+
+    let n = sp1_zkvm::io::read::<u32>();
+    sp1_zkvm::io::commit(&n);
+}
@@ -0,0 +1,75 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::f64::{add, div, mul, sub};
+
+/// Asserts that the two results are the same double, or both NaNs.
+fn assert_same(result: f64, expected: f64) {
+    assert!(
+        result.to_bits() == expected.to_bits() || (result.is_nan() && expected.is_nan()),
+        "{result:e} != {expected:e}"
+    );
+}
+
+pub fn main() {
+    let values = [
+        1.0,
+        -1.0,
+        0.1,
+        -0.2,
+        1.5,
+        3.0,
+        core::f64::consts::PI,
+        -core::f64::consts::E,
+        1.0 + f64::EPSILON,
+        1.0 - f64::EPSILON / 2.0,
+        123456789.0,
+        1e-300,
+        -1e300,
+        1e308,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        3.0 * f64::MIN_POSITIVE,
+        // Values that are not normal, which always fall back to software.
+        0.0,
+        -0.0,
+        f64::MIN_POSITIVE / 4.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    for &a in &values {
+        for &b in &values {
+            assert_same(add(a, b), a + b);
+            assert_same(sub(a, b), a - b);
+            assert_same(mul(a, b), a * b);
+            assert_same(div(a, b), a / b);
+        }
+    }
+
+    // Cancellation, ties and carries in the rounding.
+    assert_same(add(1.0, -(1.0 - f64::EPSILON / 2.0)), f64::EPSILON / 2.0);
+    assert_same(add(1.0, f64::EPSILON / 2.0), 1.0);
+    assert_same(add(1.0 + f64::EPSILON, f64::EPSILON / 2.0), 1.0 + 2.0 * f64::EPSILON);
+    assert_same(add(2.0 - f64::EPSILON, f64::EPSILON / 2.0), 2.0);
+    assert_same(mul(1.0 + f64::EPSILON, 1.0 - f64::EPSILON / 2.0), 1.0);
+    assert_same(div(1.0, 3.0), 1.0 / 3.0);
+    assert_same(div(2.0, 3.0), 2.0 / 3.0);
+
+    // A walk through a range of magnitudes.
+    let mut x = 1.0f64;
+    let mut y = 0.75f64;
+    for i in 0..200 {
+        let z = mul(x, y);
+        assert_same(z, x * y);
+        let w = div(z, 1.0 + i as f64);
+        assert_same(w, z / (1.0 + i as f64));
+        let s = sub(w, x);
+        assert_same(s, w - x);
+        x = add(s, y * 3.0);
+        y = div(x, 7.0 - y);
+    }
+
+    println!("done");
+}
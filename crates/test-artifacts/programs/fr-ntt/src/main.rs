@@ -0,0 +1,62 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num, One};
+use rand::Rng;
+use sp1_zkvm::lib::ntt::{fr_ntt, fr_root_of_unity, FR_TWO_ADICITY};
+
+fn biguint_to_words(x: &BigUint) -> [u32; 8] {
+    let mut words = x.to_u32_digits();
+    words.resize(8, 0);
+    words.try_into().unwrap()
+}
+
+fn words_to_biguint(x: &[u32; 8]) -> BigUint {
+    BigUint::from_slice(x)
+}
+
+fn random_element(rng: &mut impl Rng, modulus: &BigUint) -> [u32; 8] {
+    let words: [u32; 8] = rng.gen();
+    biguint_to_words(&(words_to_biguint(&words) % modulus))
+}
+
+pub fn main() {
+    // bn254 scalar field modulus
+    let modulus = BigUint::from_str_radix(
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10,
+    )
+    .unwrap();
+
+    // The roots of unity are primitive.
+    for log_n in [1, 2, 5, 16, FR_TWO_ADICITY] {
+        let w = words_to_biguint(&fr_root_of_unity(log_n));
+        let half = w.modpow(&(BigUint::one() << (log_n - 1)), &modulus);
+        assert_eq!(half, &modulus - 1u32);
+        assert!(half.modpow(&BigUint::from(2u32), &modulus).is_one());
+    }
+
+    // Test a single element, a single call, and several calls with a partial last chunk of the
+    // edge case of the modulus minus one.
+    let mut rng = rand::thread_rng();
+    let max = biguint_to_words(&(&modulus - 1u32));
+    for n in [1usize, 8, 64] {
+        let coeffs = (0..n)
+            .map(|i| if i % 7 == 0 { max } else { random_element(&mut rng, &modulus) })
+            .collect::<Vec<_>>();
+        let evals = fr_ntt(&coeffs);
+
+        let w = words_to_biguint(&fr_root_of_unity(n.trailing_zeros() as usize));
+        let mut point = BigUint::one();
+        for eval in evals {
+            let expected = coeffs
+                .iter()
+                .rev()
+                .fold(BigUint::default(), |acc, c| (acc * &point + words_to_biguint(c)) % &modulus);
+            assert_eq!(words_to_biguint(&eval), expected);
+            point = point * &w % &modulus;
+        }
+    }
+
+    println!("done");
+}
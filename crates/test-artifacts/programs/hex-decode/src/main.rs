@@ -0,0 +1,39 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::hex::{decode, decode_to_slice, FromHexError};
+
+pub fn main() {
+    // Two full blocks decoded by the precompile and a tail decoded in software.
+    let input = "00112233445566778899aabbccddeeffDEADBEEFcafeF00D0123";
+    let expected = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xf0, 0x0d, 0x01, 0x23,
+    ];
+    assert_eq!(decode(input).unwrap(), expected);
+    assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+
+    // An invalid digit in a block decoded by the precompile.
+    assert_eq!(
+        decode("0011223344556677g899aabbccddeeff"),
+        Err(FromHexError::InvalidHexCharacter { c: 'g', index: 16 })
+    );
+    assert_eq!(
+        decode("0x11223344556677"),
+        Err(FromHexError::InvalidHexCharacter { c: 'x', index: 1 })
+    );
+
+    // An invalid digit in the tail.
+    assert_eq!(
+        decode("00112233445566778899 a"),
+        Err(FromHexError::InvalidHexCharacter { c: ' ', index: 20 })
+    );
+
+    assert_eq!(decode("001"), Err(FromHexError::OddLength));
+    let mut output = [0u8; 4];
+    assert_eq!(decode_to_slice("001122", &mut output), Err(FromHexError::InvalidStringLength));
+    decode_to_slice("a1B2c3D4", &mut output).unwrap();
+    assert_eq!(output, [0xa1, 0xb2, 0xc3, 0xd4]);
+
+    println!("done");
+}
@@ -0,0 +1,13 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+/// The maximum number of bytes the program accepts per hint.
+const MAX_HINT_LEN: usize = 64;
+
+pub fn main() {
+    let a = sp1_zkvm::io::read_vec_bounded(MAX_HINT_LEN);
+    let b = sp1_zkvm::io::read_bounded::<Vec<u8>>(MAX_HINT_LEN + 8);
+
+    assert_eq!(a, b);
+    sp1_zkvm::io::commit_slice(&a);
+}
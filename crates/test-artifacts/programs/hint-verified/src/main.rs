@@ -0,0 +1,11 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+pub fn main() {
+    // The digest would be trusted in a real program, e.g. a constant or a committed value.
+    let digest = sp1_zkvm::io::read::<[u8; 32]>();
+    let a = sp1_zkvm::io::read_verified::<Vec<u8>>(&digest);
+    let b = sp1_zkvm::io::read_committed::<Vec<u8>>();
+
+    assert_eq!(a, b);
+}
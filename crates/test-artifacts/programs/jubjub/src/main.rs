@@ -0,0 +1,64 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::{jubjub::JubjubAffinePoint, utils::AffinePoint};
+
+pub fn main() {
+    for _ in 0..4 {
+        // 5 * G
+        // 46037580203438066765405229507649644425780970512522822336637661968249826130047
+        // 26189429486186784039799689203850934078756791903368248146476421754146336352630
+        let a: [u8; 64] = [
+            127, 56, 77, 107, 49, 48, 198, 157, 26, 131, 212, 240, 104, 227, 153, 230, 100, 69,
+            197, 84, 36, 25, 109, 7, 200, 169, 152, 136, 212, 88, 200, 101, 118, 41, 29, 200, 60,
+            189, 119, 252, 78, 40, 230, 18, 208, 221, 38, 214, 176, 250, 4, 10, 77, 101, 26, 216,
+            193, 198, 226, 84, 25, 177, 230, 57,
+        ];
+
+        // 12345 * G
+        // 1914743222257478407163814202117263020858704688091498094144911655627470604937
+        // 37092003077164576870713451654441773931184159294480200812903353189095900507621
+        let b: [u8; 64] = [
+            137, 10, 223, 25, 66, 164, 65, 241, 10, 236, 239, 22, 212, 212, 60, 218, 85, 175, 211,
+            33, 214, 142, 61, 241, 250, 39, 174, 94, 205, 180, 59, 4, 229, 237, 236, 100, 2, 184,
+            156, 90, 136, 16, 253, 210, 236, 240, 9, 206, 34, 104, 126, 113, 48, 61, 118, 143, 65,
+            172, 37, 141, 107, 84, 1, 82,
+        ];
+
+        let mut a_point = JubjubAffinePoint::from_le_bytes(&a);
+        let b_point = JubjubAffinePoint::from_le_bytes(&b);
+
+        // Addition.
+        a_point.add_assign(&b_point);
+        // 12350 * G
+        // 48506209563630352821371984039524708401571227277180836066109803438672660266578
+        // 26448777393589091783016064600370064999310401377929762988238384326552394384916
+        let c: [u8; 64] = [
+            82, 254, 141, 25, 19, 221, 4, 180, 235, 105, 187, 120, 173, 16, 180, 61, 145, 47, 140,
+            185, 136, 102, 254, 13, 238, 78, 121, 153, 172, 138, 61, 107, 20, 198, 175, 195, 124,
+            165, 190, 185, 176, 139, 111, 107, 211, 153, 98, 242, 23, 46, 112, 71, 35, 131, 235,
+            198, 136, 114, 88, 221, 61, 122, 121, 58,
+        ];
+        assert_eq!(a_point.to_le_bytes(), c);
+
+        // Doubling.
+        a_point.double();
+        // 24700 * G
+        // 32863206534071832969144268176467290872647931869570430119200301165993421534847
+        // 49602156695570408936519896895987247882915145445937753235634946650913559653958
+        let d: [u8; 64] = [
+            127, 118, 209, 152, 239, 181, 11, 70, 247, 229, 121, 81, 0, 66, 35, 1, 215, 34, 229,
+            54, 122, 179, 18, 51, 137, 215, 44, 19, 110, 234, 167, 72, 70, 174, 141, 104, 124, 217,
+            36, 208, 224, 222, 199, 144, 49, 137, 145, 145, 36, 76, 252, 32, 177, 221, 166, 194,
+            228, 241, 148, 6, 103, 211, 169, 109,
+        ];
+        assert_eq!(a_point.to_le_bytes(), d);
+
+        // Scalar multiplication of the generator.
+        let mut g_point = JubjubAffinePoint::new(JubjubAffinePoint::GENERATOR);
+        g_point.mul_assign(&[12345, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        assert_eq!(g_point.to_le_bytes(), b);
+    }
+
+    println!("done");
+}
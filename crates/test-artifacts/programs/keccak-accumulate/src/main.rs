@@ -0,0 +1,17 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::keccak::KeccakAccumulator;
+
+pub fn main() {
+    let input = sp1_zkvm::io::read::<Vec<u8>>();
+
+    // Feed the input in uneven pieces so that blocks straddle calls to `update`.
+    let mut hasher = KeccakAccumulator::new();
+    for chunk in input.chunks(100) {
+        hasher.update(chunk);
+    }
+    let output = hasher.finalize();
+
+    sp1_zkvm::io::commit(&output);
+}
@@ -0,0 +1,41 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::keccak::{keccak_merkle_root, verify_keccak_merkle_proof};
+use tiny_keccak::{Hasher, Keccak};
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(left);
+    hasher.update(right);
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+fn expected_root(leaf: &[u8; 32], index: u64, siblings: &[[u8; 32]]) -> [u8; 32] {
+    siblings.iter().enumerate().fold(*leaf, |node, (i, sibling)| {
+        if (index >> i) & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        }
+    })
+}
+
+pub fn main() {
+    let leaf: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let siblings = (0..20u8).map(|i| [i.wrapping_mul(37).wrapping_add(1); 32]).collect::<Vec<_>>();
+
+    // Cover a single level, a full call, and paths split over several calls.
+    for (depth, index) in [(1, 1), (3, 0b101), (8, 0xa5), (9, 0x1ff), (20, 0x5_a5a5)] {
+        let path = &siblings[..depth];
+        let root = keccak_merkle_root(&leaf, index, path);
+        assert_eq!(root, expected_root(&leaf, index, path), "mismatch for depth {depth}");
+        assert!(verify_keccak_merkle_proof(&leaf, index, path, &root));
+        assert!(!verify_keccak_merkle_proof(&leaf, index ^ 1, path, &root));
+    }
+
+    // An empty path leaves the leaf as the root.
+    assert_eq!(keccak_merkle_root(&leaf, 0, &[]), leaf);
+}
@@ -0,0 +1,25 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::keccak::KeccakSponge;
+use tiny_keccak::{Hasher, Keccak};
+
+pub fn main() {
+    // Cover empty input, tails right before, at and after a block boundary, and several blocks.
+    for len in [0, 1, 135, 136, 137, 271, 272, 500] {
+        let input = (0..len).map(|i| (i * 7 + 3) as u8).collect::<Vec<_>>();
+
+        // Feed the input in uneven pieces so that blocks straddle calls to `update`.
+        let mut sponge = KeccakSponge::new();
+        for chunk in input.chunks(50) {
+            sponge.update(chunk);
+        }
+        let output = sponge.finalize();
+
+        let mut hasher = Keccak::v256();
+        hasher.update(&input);
+        let mut expected = [0u8; 32];
+        hasher.finalize(&mut expected);
+        assert_eq!(output, expected, "mismatch for {len} bytes");
+    }
+}
@@ -0,0 +1,60 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::syscalls::{syscall_memcopy32, syscall_memcopy64};
+
+const WORDS: usize = 64;
+
+pub fn main() {
+    let src: [u32; WORDS] = core::array::from_fn(|i| (i as u32).wrapping_mul(0x9e37_79b9));
+
+    // Contiguous 32 byte copies, which are coalesced in pairs.
+    let mut dst = [0u32; WORDS];
+    for i in (0..WORDS).step_by(8) {
+        syscall_memcopy32(
+            src[i..].as_ptr() as *const [u32; 8],
+            dst[i..].as_mut_ptr() as *mut [u32; 8],
+        );
+    }
+    assert_eq!(dst, src);
+
+    // An odd number of contiguous copies leaves the last one alone.
+    let mut dst = [0u32; WORDS];
+    for i in (0..24).step_by(8) {
+        syscall_memcopy32(
+            src[i..].as_ptr() as *const [u32; 8],
+            dst[i..].as_mut_ptr() as *mut [u32; 8],
+        );
+    }
+    assert_eq!(dst[..24], src[..24]);
+    assert!(dst[24..].iter().all(|&w| w == 0));
+
+    // 64 byte copies.
+    let mut dst = [0u32; WORDS];
+    for i in (0..WORDS).step_by(16) {
+        syscall_memcopy64(
+            src[i..].as_ptr() as *const [u32; 16],
+            dst[i..].as_mut_ptr() as *mut [u32; 16],
+        );
+    }
+    assert_eq!(dst, src);
+
+    // Back-to-back copies that are not contiguous are not coalesced.
+    let mut dst = [0u32; WORDS];
+    syscall_memcopy32(src[32..].as_ptr() as *const [u32; 8], dst.as_mut_ptr() as *mut [u32; 8]);
+    syscall_memcopy32(src.as_ptr() as *const [u32; 8], dst[8..].as_mut_ptr() as *mut [u32; 8]);
+    assert_eq!(dst[..8], src[32..40]);
+    assert_eq!(dst[8..16], src[..8]);
+
+    // A forward copy within the same buffer, where the second copy reads what the first wrote.
+    let mut buf = src;
+    let ptr = buf.as_mut_ptr();
+    unsafe {
+        syscall_memcopy32(ptr as *const [u32; 8], ptr.add(8) as *mut [u32; 8]);
+        syscall_memcopy32(ptr.add(8) as *const [u32; 8], ptr.add(16) as *mut [u32; 8]);
+    }
+    assert_eq!(buf[8..16], src[..8]);
+    assert_eq!(buf[16..24], src[..8]);
+
+    println!("done");
+}
@@ -0,0 +1,46 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use std::hint::black_box;
+
+const ROUNDS: usize = 32;
+
+/// Copies a large buffer, which should be advised to use a memcopy precompile.
+#[inline(never)]
+fn copy_bulk(dst: &mut [u8], src: &[u8]) {
+    dst.copy_from_slice(src);
+}
+
+/// Copies a few bytes, which should not be worth a memcopy precompile.
+#[inline(never)]
+fn copy_small(dst: &mut [u8], src: &[u8]) {
+    dst.copy_from_slice(src);
+}
+
+/// Clears a large buffer.
+#[inline(never)]
+fn set_bulk(dst: &mut [u8]) {
+    dst.fill(0);
+}
+
+pub fn main() {
+    let len = black_box(4096);
+    let src: Vec<u8> = (0..len).map(|i| i as u8).collect();
+    let mut dst = vec![0u8; len];
+
+    for _ in 0..ROUNDS {
+        copy_bulk(&mut dst, black_box(&src));
+    }
+    assert_eq!(dst, src);
+
+    let small = black_box(5);
+    for _ in 0..ROUNDS {
+        copy_small(&mut dst[..small], black_box(&src[len - small..]));
+    }
+    assert_eq!(&dst[..small], &src[len - small..]);
+
+    for _ in 0..ROUNDS {
+        set_bulk(black_box(&mut dst));
+    }
+    assert!(dst.iter().all(|&b| b == 0));
+}
@@ -0,0 +1,149 @@
+//! Exercises every Morph-added syscall that is wired into the executor and the machine in a single
+//! execution, so that the chips share byte lookups and memory accesses within the same shard.
+//!
+//! `BN254_SCALAR_MAC` has no syscall in this tree and `BN254_SCALAR_MULADD` has no chip in the
+//! RISC-V machine, so they are not covered here.
+
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num, One};
+use sp1_zkvm::{
+    lib::{
+        keccak::{keccak_merkle_root, withdraw_root},
+        poseidon::{
+            poseidon_merkle_root, zktrie_hash_node, MerkleLevel, ZKTRIE_NODE_TYPE_BRANCHES,
+        },
+    },
+    syscalls::{
+        sys_bn254_muladd, syscall_bn254_scalar_from_mont, syscall_bn254_scalar_to_mont,
+        syscall_memcopy32,
+    },
+};
+
+fn bn254_muladd(x: &[u8; 32], y: &[u8; 32], z: &[u8; 32]) -> [u8; 32] {
+    let mut result = [0u32; 8];
+    sys_bn254_muladd(
+        result.as_mut_ptr() as *mut [u32; 8],
+        0,
+        x.as_ptr() as *const [u32; 8],
+        y.as_ptr() as *const [u32; 8],
+        z.as_ptr() as *const [u32; 8],
+    );
+    bytemuck::cast::<[u32; 8], [u8; 32]>(result)
+}
+
+fn to_mont(x: &[u8; 32]) -> [u8; 32] {
+    let mut words = bytemuck::cast::<[u8; 32], [u32; 8]>(*x);
+    syscall_bn254_scalar_to_mont(&mut words);
+    bytemuck::cast::<[u32; 8], [u8; 32]>(words)
+}
+
+fn from_mont(x: &[u8; 32]) -> [u8; 32] {
+    let mut words = bytemuck::cast::<[u8; 32], [u32; 8]>(*x);
+    syscall_bn254_scalar_from_mont(&mut words);
+    bytemuck::cast::<[u32; 8], [u8; 32]>(words)
+}
+
+fn biguint_to_words(x: &BigUint) -> [u32; 8] {
+    bytemuck::cast::<[u8; 32], [u32; 8]>(biguint_to_bytes_le(x))
+}
+
+fn biguint_to_bytes_le(x: &BigUint) -> [u8; 32] {
+    let mut bytes = x.to_bytes_le();
+    bytes.resize(32, 0);
+    bytes.try_into().unwrap()
+}
+
+pub fn main() {
+    // bn254 scalar field modulus
+    let modulus = BigUint::from_str_radix(
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10,
+    )
+    .unwrap();
+    let r = (BigUint::one() << 256) % &modulus;
+
+    let mut acc = BigUint::from(7u32);
+    let mut x = BigUint::from_str_radix(
+        "12345678901234567890123456789012345678901234567890123456789012345678901234567",
+        10,
+    )
+    .unwrap();
+    let y = BigUint::from_str_radix(
+        "9876543210987654321098765432109876543210987654321098765432109876543210987654",
+        10,
+    )
+    .unwrap();
+
+    for _ in 0..8 {
+        // acc = x * y + acc with `BN254_MULADD`.
+        let result = bn254_muladd(
+            &biguint_to_bytes_le(&x),
+            &biguint_to_bytes_le(&y),
+            &biguint_to_bytes_le(&acc),
+        );
+        let expected = (&x * &y + &acc) % &modulus;
+        assert_eq!(BigUint::from_bytes_le(&result), expected);
+        acc = expected;
+
+        // Round trip the accumulator through the Montgomery form.
+        let acc_bytes = biguint_to_bytes_le(&acc);
+        let mont = to_mont(&acc_bytes);
+        assert_eq!(BigUint::from_bytes_le(&mont), (&acc * &r) % &modulus);
+        assert_eq!(from_mont(&mont), acc_bytes);
+
+        // Feed the Montgomery form back in as the next multiplicand, alongside the accumulator
+        // copied with two contiguous `MEMCPY_32` calls.
+        let mut words = [0u32; 16];
+        let src = [bytemuck::cast::<[u8; 32], [u32; 8]>(mont), bytemuck::cast(acc_bytes)];
+        syscall_memcopy32(&src[0], words.as_mut_ptr() as *mut [u32; 8]);
+        syscall_memcopy32(&src[1], words[8..].as_mut_ptr() as *mut [u32; 8]);
+        assert_eq!(bytemuck::cast::<[u32; 16], [[u32; 8]; 2]>(words), src);
+        x = BigUint::from_bytes_le(&bytemuck::cast::<[u32; 8], [u8; 32]>(src[0]));
+    }
+
+    // Hash the accumulator up a two level Poseidon Merkle path, which matches hashing it one
+    // level at a time.
+    let levels = [
+        MerkleLevel { sibling: biguint_to_words(&x), domain: [0; 8], is_right: false },
+        MerkleLevel {
+            sibling: biguint_to_words(&acc),
+            domain: [1, 0, 0, 0, 0, 0, 0, 0],
+            is_right: true,
+        },
+    ];
+    let mut root = biguint_to_words(&acc);
+    poseidon_merkle_root(&mut root, &levels);
+    let mut expected = biguint_to_words(&acc);
+    for level in &levels {
+        poseidon_merkle_root(&mut expected, core::slice::from_ref(level));
+    }
+    assert_eq!(root, expected);
+
+    // The second level is a zkTrie branch node hash with the domain as its type.
+    let branch = zktrie_hash_node(ZKTRIE_NODE_TYPE_BRANCHES[3], &biguint_to_words(&acc), &root);
+    let level = MerkleLevel { sibling: root, domain: [9, 0, 0, 0, 0, 0, 0, 0], is_right: false };
+    let mut expected = biguint_to_words(&acc);
+    poseidon_merkle_root(&mut expected, &[level]);
+    assert_eq!(branch, expected);
+
+    // Do the same with a two level keccak Merkle path.
+    let leaf = biguint_to_bytes_le(&acc);
+    let siblings = [biguint_to_bytes_le(&x), bytemuck::cast::<[u32; 8], [u8; 32]>(root)];
+    let mut expected = leaf;
+    for (i, sibling) in siblings.iter().enumerate() {
+        expected = keccak_merkle_root(&expected, 0b10 >> i, core::slice::from_ref(sibling));
+    }
+    assert_eq!(keccak_merkle_root(&leaf, 0b10, &siblings), expected);
+
+    // The withdrawal root of a balanced tree matches the root of the path of its first leaf.
+    let subtree = withdraw_root(&siblings);
+    let leaves = [leaf, biguint_to_bytes_le(&x), siblings[0], siblings[1]];
+    assert_eq!(
+        withdraw_root(&leaves),
+        keccak_merkle_root(&leaf, 0, &[biguint_to_bytes_le(&x), subtree])
+    );
+
+    println!("done");
+}
@@ -0,0 +1,65 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num};
+use sp1_zkvm::{
+    lib::poseidon::{poseidon_merkle_root, MerkleLevel},
+    syscalls::syscall_poseidon_merkle_root,
+};
+
+fn biguint_to_words(x: &BigUint) -> [u32; 8] {
+    let mut words = x.to_u32_digits();
+    words.resize(8, 0);
+    words.try_into().unwrap()
+}
+
+fn hex_to_words(x: &str) -> [u32; 8] {
+    biguint_to_words(&BigUint::from_str_radix(x, 16).unwrap())
+}
+
+fn element(x: u32) -> [u32; 8] {
+    biguint_to_words(&BigUint::from(x))
+}
+
+pub fn main() {
+    // A single level matches circomlib's `Poseidon([1, 2])`, whichever side the node is on.
+    let expected = hex_to_words("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a");
+    for (node, sibling, index) in [(1, 2, 0u32), (2, 1, 1)] {
+        let mut x = element(node);
+        let mut path = vec![1u32, index];
+        path.extend_from_slice(&element(sibling));
+        path.extend_from_slice(&element(0));
+        syscall_poseidon_merkle_root(&mut x, path.as_ptr());
+        assert_eq!(x, expected);
+    }
+
+    // A path of three levels with domains, in a single call and level by level.
+    let levels = [
+        MerkleLevel { sibling: element(2), domain: element(0), is_right: false },
+        MerkleLevel { sibling: element(3), domain: element(5), is_right: true },
+        MerkleLevel { sibling: element(4), domain: element(6), is_right: false },
+    ];
+    let expected = hex_to_words("1636f532a5becdc013bacaa4e199b65a876c8033485d77db23b2ee00d197ef8a");
+    let mut x = element(1);
+    poseidon_merkle_root(&mut x, &levels);
+    assert_eq!(x, expected);
+    let mut x = element(1);
+    for level in &levels {
+        poseidon_merkle_root(&mut x, core::slice::from_ref(level));
+    }
+    assert_eq!(x, expected);
+
+    // A path longer than a single call is split into several calls.
+    let levels = (0..20u32)
+        .map(|i| MerkleLevel { sibling: element(i), domain: element(i % 3), is_right: i % 2 == 1 })
+        .collect::<Vec<_>>();
+    let mut x = element(7);
+    poseidon_merkle_root(&mut x, &levels);
+    let mut y = element(7);
+    for level in &levels {
+        poseidon_merkle_root(&mut y, core::slice::from_ref(level));
+    }
+    assert_eq!(x, y);
+
+    println!("done");
+}
@@ -0,0 +1,67 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::syscalls::syscall_poseidon_merkle_root;
+
+/// Converts a big-endian hex string of 64 digits to little-endian words.
+fn hex_to_words(hex: &str) -> [u32; 8] {
+    core::array::from_fn(|i| u32::from_str_radix(&hex[56 - 8 * i..64 - 8 * i], 16).unwrap())
+}
+
+fn element(x: u32) -> [u32; 8] {
+    let mut words = [0u32; 8];
+    words[0] = x;
+    words
+}
+
+/// Hashes `left` and `right` with a domain separator, as a Merkle path of a single level.
+fn poseidon_hash(left: [u32; 8], right: [u32; 8], domain: [u32; 8]) -> [u32; 8] {
+    let mut node = left;
+    let mut path = vec![1u32, 0];
+    path.extend_from_slice(&right);
+    path.extend_from_slice(&domain);
+    syscall_poseidon_merkle_root(&mut node, path.as_ptr());
+    node
+}
+
+pub fn main() {
+    // Bn254 scalar field elements close to the modulus.
+    let p_minus_1 =
+        hex_to_words("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000");
+    let p_minus_2 =
+        hex_to_words("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593efffffff");
+
+    // The first two hashes match circomlib's `Poseidon([1, 2])` and `Poseidon([0, 0])`, and the
+    // last ones were computed with a reference implementation of the same parameters.
+    let cases = [
+        (
+            element(1),
+            element(2),
+            element(0),
+            "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a",
+        ),
+        (
+            element(0),
+            element(0),
+            element(0),
+            "2098f5fb9e239eab3ceac3f27b81e481dc3124d55ffed523a839ee8446b64864",
+        ),
+        (
+            element(1),
+            element(2),
+            element(3),
+            "29f818774a5a86068f0e4998780d6b1003ab6b45ab1b661145e71897c923a648",
+        ),
+        (
+            p_minus_1,
+            p_minus_2,
+            element(1),
+            "3052af7fbb74c6b9d91191f1ad6b45f9249a31d5bf2a0570de631a74e32723b0",
+        ),
+    ];
+    for (left, right, domain, expected) in cases {
+        assert_eq!(poseidon_hash(left, right, domain), hex_to_words(expected));
+    }
+
+    println!("done");
+}
@@ -0,0 +1,134 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::rlp::{scan, RlpItem};
+
+/// The big-endian bytes of a length, without leading zeros.
+fn be_bytes(len: usize) -> Vec<u8> {
+    len.to_be_bytes().into_iter().skip_while(|&byte| byte == 0).collect()
+}
+
+fn encode(payload: &[u8], is_list: bool) -> Vec<u8> {
+    let (short, long) = if is_list { (0xc0, 0xf7) } else { (0x80, 0xb7) };
+    let mut out = if !is_list && payload.len() == 1 && payload[0] < 0x80 {
+        vec![]
+    } else if payload.len() < 56 {
+        vec![short + payload.len() as u8]
+    } else {
+        let len = be_bytes(payload.len());
+        let mut header = vec![long + len.len() as u8];
+        header.extend(len);
+        header
+    };
+    out.extend_from_slice(payload);
+    out
+}
+
+fn encode_string(bytes: &[u8]) -> Vec<u8> {
+    encode(bytes, false)
+}
+
+fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    encode(&items.concat(), true)
+}
+
+/// Scans a payload in software.
+fn scan_software(payload: &[u8]) -> Vec<RlpItem> {
+    let mut items = Vec::new();
+    let mut offset = 0;
+    while offset < payload.len() {
+        let prefix = payload[offset];
+        let (header_len, len, is_list) = match prefix {
+            0x00..=0x7f => (0, 1, false),
+            0x80..=0xb7 => (1, (prefix - 0x80) as usize, false),
+            0xc0..=0xf7 => (1, (prefix - 0xc0) as usize, true),
+            _ => {
+                let is_list = prefix >= 0xf8;
+                let len_len = (prefix - if is_list { 0xf7 } else { 0xb7 }) as usize;
+                let len = payload[offset + 1..offset + 1 + len_len]
+                    .iter()
+                    .fold(0, |acc, &byte| (acc << 8) | byte as usize);
+                (1 + len_len, len, is_list)
+            }
+        };
+        items.push(RlpItem { offset: offset + header_len, len, is_list });
+        offset += header_len + len;
+    }
+    items
+}
+
+fn check(payload: &[u8]) -> Vec<RlpItem> {
+    let items = scan(payload);
+    assert_eq!(items, scan_software(payload));
+    // Scan the payload at every alignment.
+    for shift in 1..4 {
+        let mut shifted = vec![0u8; shift];
+        shifted.extend_from_slice(payload);
+        assert_eq!(scan(&shifted[shift..]), items);
+    }
+    items
+}
+
+pub fn main() {
+    // Single bytes, the empty string, and a string of a single byte of at least 0x80.
+    let items = check(&[0x05, 0x80, 0x81, 0x80, 0x00]);
+    assert_eq!(
+        items,
+        vec![
+            RlpItem { offset: 0, len: 1, is_list: false },
+            RlpItem { offset: 2, len: 0, is_list: false },
+            RlpItem { offset: 3, len: 1, is_list: false },
+            RlpItem { offset: 4, len: 1, is_list: false },
+        ]
+    );
+
+    // Long strings and lists, with one and two bytes of length.
+    let long_string = encode_string(&[0xab; 100]);
+    assert_eq!(&long_string[..2], &[0xb8, 100]);
+    let longer_string = encode_string(&[0xcd; 300]);
+    assert_eq!(&longer_string[..3], &[0xb9, 0x01, 0x2c]);
+    let long_list = encode_list(&vec![encode_string(b"dog"); 20]);
+    assert_eq!(long_list[0], 0xf8);
+    let payload = [long_string, longer_string, long_list.clone()].concat();
+    let items = check(&payload);
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].payload(&payload), &[0xab; 100]);
+    assert_eq!(items[1].payload(&payload), &[0xcd; 300]);
+    assert!(items[2].is_list);
+
+    // Descend into nested lists.
+    let nested = encode_list(&[
+        encode_list(&[encode_string(&[1]), encode_string(&[2, 3])]),
+        encode_list(&[]),
+        encode_string(b"cat"),
+    ]);
+    let items = check(&nested);
+    assert_eq!(items.len(), 1);
+    let inner = items[0].payload(&nested);
+    let items = check(inner);
+    assert_eq!(items.iter().map(|item| item.is_list).collect::<Vec<_>>(), [true, true, false]);
+    assert_eq!(items[2].payload(inner), b"cat");
+    let innermost = items[0].payload(inner);
+    assert_eq!(check(innermost).len(), 2);
+    assert_eq!(check(items[1].payload(inner)), vec![]);
+
+    // More items than a single call scans, like the fields of a transaction.
+    let fields = (0..40u32)
+        .map(|i| match i % 4 {
+            0 => encode_string(&[i as u8]),
+            1 => encode_string(&i.to_be_bytes()),
+            2 => encode_string(&vec![i as u8; i as usize * 2]),
+            _ => encode_list(&[encode_string(b"to"), encode_string(&[0x80 + i as u8])]),
+        })
+        .collect::<Vec<_>>();
+    let tx = encode_list(&fields);
+    let items = check(&tx);
+    let items = check(items[0].payload(&tx));
+    assert_eq!(items.len(), 40);
+
+    // Descend into a long list.
+    let items = check(&long_list);
+    assert_eq!(check(items[0].payload(&long_list)).len(), 20);
+
+    println!("done");
+}
@@ -0,0 +1,116 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::rsa::{rsa_modexp, verify_pkcs1v15_sha256, verify_pss_sha256};
+
+// Keys and signatures generated with the Python `cryptography` package, with public exponents
+// 65537 for the 2048-bit key and 3 for the 4096-bit key, and 32 byte PSS salts.
+const MODULUS_2048: &str = "\
+    b4e86201ca6f07854e2e971cb7489eb14ca7bb3a89564ceb5eb3d3d3e7e5d1eef7583d3857c2a8e529b6ba9ae1a72ee6\
+    777ca50b0dbb3c43a5518b980763f218f8822c909d04663e27734fa4e9b410d87b325b38c2cbe53befb87a29d995fdab\
+    9464f974bd4e766b6569b12debd111ec19432891f5a3aa22586e11a0317d8328d4b92f2a8b29a57843e57d2110220922\
+    acc40db86cbfeb39f64171708457203633d85d734e6d94699d7f318d0de3fab215f26ead60f2407a218d87a545660c3e\
+    4c658c2022c2c71d08182d26a04e710fd733cfccb7cac365ae821e89c9a3ca13cbcc607ecc00b91a6212f4fec80c2692\
+    6ab93a47821a3561b0fe6dc9ee224a91";
+
+const PKCS1V15_SIGNATURE_2048: &str = "\
+    09dcaf5cc147d2f97027faf80e0952fb17f7895292ec0b5054668ab50f1cc3004ec1c650351a3b5666d41736f7ff1b41\
+    b9947d54ae1d9557467dae5acefaf6d77ff2cb426f1805d75fc51337d33aaab9fafecde7150d6e54278496ce5e456395\
+    a3b7b441c982343f3ba88bddc2640cd74e2d98c18cb4e339a04fc5e8bc9ef5fe4bda4a969ff094da262a4f1faa14cbca\
+    27006999d90e9dbee68b13fb37788cb80f76c9671423eacb2cb7f53a739428d48d0c0356c022695a101e92b4a5cc7dc3\
+    50cd4ece5ae8e9e4193e95217262f0a940f0ddf0bd6022281073817146c39d3c20cf5003e4a022828fbd2659181088b5\
+    a49e0a083f091585fcba78418fc169e1";
+
+const PSS_SIGNATURE_2048: &str = "\
+    75b3e794318df69ba1ad89e3055296f4103faf9c129db32438147967c77bfc7501362a60d4876a4acb4683013d70de78\
+    c0ed39f55567dfe2bd3c51d887965c5dc2cfb349e532d03fe6f1a8d207d2f3aca1957d3624347ab0e530fe5915f92eab\
+    5afcf85eaa34140294edc0b44d26ec3417c3a6a06b7585933c2f57287e724625d20fefe14c1852082761ec7db43b3ca9\
+    5ae84fd2d087f220373256b9fa81c942d59160fbc264ecc95d640b7ccfb86b1200e5b1412c46242a00e8c8a63eea9c7b\
+    df233db67969c752342c00f2c86194f2216d0917518879d0bbe65453591d3ef7883f393ddd9cc9a512a2321a33b38949\
+    5331e50a7814cdd0ef77e63dbfefff6e";
+
+const MODULUS_4096: &str = "\
+    d18b35990307147f10594cacb6c7864f021594fa2e09efa92f39425e0d666fecfee17db62f909e9e10c02d3e762e9864\
+    36539e99a0f12f6f52b7c30482bab6dfb13b3a6fd61605be5abaf51e8a78351b3b62020864fe2d0eab37dfde291f776e\
+    0015db74f55d50ea8ff85c401caf0c51fe5514c0dc1c706d6c6676009125f7087d855900e59a5b75b6b25bc06a39450e\
+    6780d794c0c6c9560c6c4d16bed27ac3736db548d778e8d8419da047dd2899df871dd4636ad9c6af90a8f93709dc6663\
+    e3dcad798d5fec5d06215a3a2e8b906cb67a7018f31d9f98eb1452ef568ce2433d98ca9f485f599c2adf5bd4dd749c39\
+    9170b8e8663a92acec9da5b1752d31efd0b5bd174ef5d947292b44e035243130634a96d56ffd3e66813016da41944372\
+    3e3d5b1c1652e87ad0508c6ad88b17f056e4a0a3e9cf9bb02c4851b12c6ea7c190d3042b2eedd9c085c55432e1679c78\
+    b25d953261f33cce998f2b2a10e8d4e1a4d305b8454a5655293bf781533a8c159f8828970efa33acbff5445f9e5dc9ce\
+    db6d181eb3768fad8b116be4a7482cfe26e9825f2517f229a43f12f4d1c9d812c7ff068f541a5d20b29e5fe5c91d22c3\
+    feb072156d515923b37eb9588f186e2fddafb799390499a93513f3149c959ea3114dfb17613985479f4b343abad1e536\
+    6a2f140682b818878185096df2d2e6e69f5b93d4aa3073f1ebde633fcc2c99f1";
+
+const PKCS1V15_SIGNATURE_4096: &str = "\
+    6521bd80243255027d392ba34c975cf11dc821fb00defc98d3998791b3b0260d5a8f75f1f9e05320826ae72a0d9a45f3\
+    c9d7082573027581da8cc50b85ca233a975c985dbbe89fe0ad667cc49e1cc2b4d4331ac21df825a9116ddbfbb0976584\
+    5d825204052d30c4404bfb94b429b3d2a0097ccb550268dd46cd476fa9c60d19f6ab46e567ef55d914f19798cf778dc2\
+    3ae35521eff0770bb24b8fa993761ced98b424029b974e2e6737494a5ca6ae5ea8a883495201d3142b851ba59a0d5c50\
+    59df8135a22dbd78841256d6f5d12b6bdd99117734709dd38b6c5eca78ec605ec136985a0eaaf7542ecf98a58a02e301\
+    3d3e64d72acabccad604825bacdccc5b164a88af418fc0647fe0faf606e6e1b99fd2faca6c9ad8fe37e30069b06ed992\
+    21e6fce479dca66525020418dbe25885f9d480c93990873c84dc50be4ef4a0a59325cb07246a523d7f4e6da80ba4da01\
+    3aa0e07eb72abbd33d3aa431cabd6016da3480bf4f30ff5b7f21b2ea1f2a6f1719b964a5d143c15b3d454cb2d9602558\
+    9ad49eff0b73d57bd5ff70522be463642be4f569b00a81726473ffd1a3fd038c8b6a8b6b9f3535de511bf3c3c7378284\
+    cf9a3ec8936222da63a95d85fc9624857dbc2402389aaf42698120464ab54da38639b8078d56a4f4a36980f70ea63305\
+    8cf94ff17675a0e9b7e940184e4d69c7a776dc15abb517826c43a40978907a52";
+
+const PSS_SIGNATURE_4096: &str = "\
+    244e4c011a977ff07be858bdb3fa79ba86ed0849806d8e97b19d49466cbaaff259bb6628554da0af9458d41ebf088e72\
+    98bae5581de0394c6f70c06d8e4ff14bbbf2695b23e5655c85f27a890f4663199efb5280a92b25eb98e2679734af6bb4\
+    01aa769d5b639f4abac9233cd1a49b5011178055c6fd31e18659ac5204a60ccc9afba2ace8419d11fdd6eda4651f3d18\
+    e103c1b5c809b58a770bed40494f5d44f730fccff995848dda15c1b5545f2fc80c9245d850b7d39fc3b5c45c94ef58a3\
+    54c5f2fb375ab5df40fc8a2e193725d54baa9edf74ab20966bb77d1376523bbd538fc6036bd58dceb60c12b7b1d866b3\
+    af51e0a45d4a541b9584d4ada7792a84763311164b703e235e966a69c33a1a71ec22cf51e282e8d629d2a33e01a8188b\
+    c931cb0e1ca2fc2e2d6307ba572bf1c40a612262c01cd636fb84cd23b4e3bdb9f251f8de1bc62c50d2dff3670ecc2ac4\
+    df856c64c2126de65405d04c6fbef72504199cd5b8d293dab8c0b77940c43f4c626c8950875064524c0af75c795eaa6e\
+    e585701376bace4ab2c0e2ac85f5a2ca23bee92d89e4c6931b32c1c6aa3f9abc658cabdf8f3106d9a830fec7bfbbd2eb\
+    2bee59f0e07cee36c7c0e5a2109d1537c64d2ee421940e5a21a9a7edc0898a227a4eaed357366ec40f3fe1eed0d2709c\
+    5ede50e30ab8a00a243bedf00e1b503a17e1500e2fe40675010ca4fa6f4b5d84";
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+fn check_key(
+    bits: usize,
+    exponent: u32,
+    modulus: &str,
+    pkcs1v15_signature: &str,
+    pss_signature: &str,
+) {
+    let modulus = decode_hex(modulus);
+    let pkcs1v15_signature = decode_hex(pkcs1v15_signature);
+    let pss_signature = decode_hex(pss_signature);
+    let message = format!("RSA-{bits} test message").into_bytes();
+
+    assert!(verify_pkcs1v15_sha256(&modulus, exponent, &message, &pkcs1v15_signature));
+    assert!(verify_pss_sha256(&modulus, exponent, &message, &pss_signature));
+
+    // Signatures of another message, or with another padding, are rejected.
+    let mut tampered_message = message.clone();
+    tampered_message.push(b'!');
+    assert!(!verify_pkcs1v15_sha256(&modulus, exponent, &tampered_message, &pkcs1v15_signature));
+    assert!(!verify_pss_sha256(&modulus, exponent, &tampered_message, &pss_signature));
+    assert!(!verify_pkcs1v15_sha256(&modulus, exponent, &message, &pss_signature));
+    assert!(!verify_pss_sha256(&modulus, exponent, &message, &pkcs1v15_signature));
+
+    // Tampered signatures are rejected, as are signatures that are not reduced.
+    let mut tampered_signature = pkcs1v15_signature.clone();
+    tampered_signature[bits / 16] ^= 1;
+    assert!(!verify_pkcs1v15_sha256(&modulus, exponent, &message, &tampered_signature));
+    assert!(!verify_pkcs1v15_sha256(&modulus, exponent, &message, &modulus));
+    assert!(rsa_modexp(&modulus, &modulus, exponent).is_none());
+
+    // Small powers are computed exactly.
+    let mut base = vec![0u8; bits / 8];
+    base[bits / 8 - 1] = 2;
+    let mut expected = vec![0u8; bits / 8];
+    expected[bits / 8 - 1] = 8;
+    assert_eq!(rsa_modexp(&base, &modulus, 3).unwrap(), expected);
+}
+
+pub fn main() {
+    check_key(2048, 65537, MODULUS_2048, PKCS1V15_SIGNATURE_2048, PSS_SIGNATURE_2048);
+    check_key(4096, 3, MODULUS_4096, PKCS1V15_SIGNATURE_4096, PSS_SIGNATURE_4096);
+}
@@ -1,6 +1,6 @@
 #![no_main]
 
-use sp1_zkvm::syscalls::syscall_secp256k1_decompress;
+use sp1_zkvm::lib::{secp256k1::decompress, utils::SyscallError};
 
 sp1_zkvm::entrypoint!(main);
 
@@ -15,7 +15,7 @@ pub fn main() {
             3 => true,
             _ => panic!("Invalid compressed key"),
         };
-        syscall_secp256k1_decompress(&mut decompressed_key, is_odd);
+        decompress(&mut decompressed_key, is_odd).unwrap();
 
         let mut result: [u8; 65] = [0; 65];
         result[0] = 4;
@@ -23,4 +23,11 @@ pub fn main() {
 
         sp1_zkvm::io::commit_slice(&result);
     }
+
+    // There is no point with X = 5, so the decompression fails and leaves Y as it was.
+    let mut invalid_key = [0xaa; 64];
+    invalid_key[..32].copy_from_slice(&[0; 32]);
+    invalid_key[31] = 5;
+    assert_eq!(decompress(&mut invalid_key, false), Err(SyscallError::InvalidPoint));
+    assert_eq!(invalid_key[32..], [0xaa; 32]);
 }
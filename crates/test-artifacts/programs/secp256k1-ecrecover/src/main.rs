@@ -0,0 +1,72 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::lib::secp256k1::ecrecover;
+
+// The first input is the `ValidKey` test of the go-ethereum precompiles, and the others are signed
+// with the key 0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318, the last one
+// with a hash larger than the group order.
+const SIGNATURES: [(&str, &str); 3] = [
+    (
+        "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c\
+         000000000000000000000000000000000000000000000000000000000000001c\
+         73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f\
+         eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549",
+        "a94f5374fce5edbc8e2a8697c15331677e6ebf0b",
+    ),
+    (
+        "8c3ce18b987029f7e3517f7e6a2a2690371489ed6f64d9f011137699ac809766\
+         000000000000000000000000000000000000000000000000000000000000001b\
+         d47644539acec3da5e3ecf5fe8863c628a9c97e8b71e9ea9167a6f4f83c03c32\
+         02c61ed7c33dba0b1aa294af47a08efb8c0f228768400c62baad4a3371cc8aa3",
+        "2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+    ),
+    (
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+         000000000000000000000000000000000000000000000000000000000000001b\
+         f30e4bd8094e53a679ddb8f55b5216b03c44623fc4279ef0791f9aa1f6930d49\
+         179a68b3a1a1c241e2429f42fd482026e90529ca5e219bde993211d69e71537b",
+        "2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+    ),
+];
+
+/// The order of the Secp256k1 group.
+const ORDER: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+pub fn main() {
+    for (input, address) in SIGNATURES {
+        let input: [u8; 128] = decode_hex(input).try_into().unwrap();
+        let recovered = ecrecover(&input).unwrap();
+        assert_eq!(recovered.to_vec(), decode_hex(address));
+        sp1_zkvm::io::commit_slice(&recovered);
+    }
+
+    // The invalid inputs fail to recover a key.
+    let valid: [u8; 128] = decode_hex(SIGNATURES[0].0).try_into().unwrap();
+
+    let mut invalid_v = valid;
+    invalid_v[63] = 29;
+    assert_eq!(ecrecover(&invalid_v), None);
+
+    let mut high_v = valid;
+    high_v[32] = 1;
+    assert_eq!(ecrecover(&high_v), None);
+
+    let mut zero_r = valid;
+    zero_r[64..96].fill(0);
+    assert_eq!(ecrecover(&zero_r), None);
+
+    let mut high_s = valid;
+    high_s[96..].copy_from_slice(&decode_hex(ORDER));
+    assert_eq!(ecrecover(&high_s), None);
+
+    // There is no point with X = 5.
+    let mut invalid_r = valid;
+    invalid_r[64..96].fill(0);
+    invalid_r[95] = 5;
+    assert_eq!(ecrecover(&invalid_r), None);
+}
@@ -2,7 +2,7 @@
 sp1_zkvm::entrypoint!(main);
 
 use sp1_zkvm::lib::secp256k1::Secp256k1Point;
-use sp1_zkvm::lib::utils::AffinePoint;
+use sp1_zkvm::lib::utils::{AffinePoint, WeierstrassAffinePoint};
 
 #[sp1_derive::cycle_tracker]
 pub fn main() {
@@ -40,5 +40,45 @@ pub fn main() {
         assert_eq!(a_point.to_le_bytes(), c);
     }
 
+    let generator = Secp256k1Point::new(Secp256k1Point::GENERATOR);
+
+    // The endomorphism multiplies points by lambda.
+    let lambda: [u32; 8] = [
+        0x1b23bd72, 0xdf02967c, 0x20816678, 0x122e22ea, 0x8812645a, 0xa5261c02, 0xc05c30e0,
+        0x5363ad4c,
+    ];
+    let mut point = generator;
+    point.mul_assign(&lambda).unwrap();
+    assert_eq!(point.to_le_bytes(), generator.endomorphism().to_le_bytes());
+
+    // (n - 1) * generator is the negation of the generator.
+    let order_minus_one: [u32; 8] = [
+        0xd0364140, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff,
+        0xffffffff,
+    ];
+    let mut point = generator;
+    point.mul_assign(&order_minus_one).unwrap();
+    assert_eq!(point.to_le_bytes(), generator.negate().to_le_bytes());
+
+    // n * generator is the point at infinity.
+    let order: [u32; 8] = [
+        0xd0364141, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff,
+        0xffffffff,
+    ];
+    let mut point = generator;
+    point.mul_assign(&order).unwrap();
+    assert!(point.is_infinity());
+
+    // lambda * generator + (n - 1) * generator.
+    let mut expected = generator;
+    expected.mul_assign(&lambda).unwrap();
+    expected.complete_add_assign(&generator.negate());
+    let sum = Secp256k1Point::glv_multi_scalar_multiplication(&[
+        (generator, lambda),
+        (generator, order_minus_one),
+    ])
+    .unwrap();
+    assert_eq!(sum.to_le_bytes(), expected.to_le_bytes());
+
     println!("done");
 }
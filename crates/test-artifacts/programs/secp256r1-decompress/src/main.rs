@@ -1,6 +1,6 @@
 #![no_main]
 
-use sp1_zkvm::syscalls::syscall_secp256r1_decompress;
+use sp1_zkvm::lib::secp256r1::decompress;
 
 sp1_zkvm::entrypoint!(main);
 
@@ -15,7 +15,7 @@ pub fn main() {
             3 => true,
             _ => panic!("Invalid compressed key"),
         };
-        syscall_secp256r1_decompress(&mut decompressed_key, is_odd);
+        decompress(&mut decompressed_key, is_odd).unwrap();
 
         let mut result: [u8; 65] = [0; 65];
         result[0] = 4;
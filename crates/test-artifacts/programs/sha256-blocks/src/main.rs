@@ -0,0 +1,34 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::sha256::compress_blocks;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+pub fn main() {
+    // A 700-byte message pads to 12 blocks, which takes two full calls and a partial one.
+    let message = (0..700u32).map(|i| (i * 31 + 7) as u8).collect::<Vec<_>>();
+    let mut padded = message.clone();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&((message.len() * 8) as u64).to_be_bytes());
+    let blocks = padded
+        .chunks_exact(64)
+        .map(|block| {
+            core::array::from_fn(|i| {
+                u32::from_be_bytes(block[4 * i..4 * i + 4].try_into().unwrap())
+            })
+        })
+        .collect::<Vec<[u32; 16]>>();
+    assert_eq!(blocks.len(), 12);
+
+    let mut state = IV;
+    compress_blocks(&mut state, &blocks);
+
+    let digest = state.iter().map(|word| format!("{word:08x}")).collect::<String>();
+    assert_eq!(digest, "2c800c1b9ae2863671b7389376da8add9f5850cbf3f348f9b024ed5df895ac27");
+}
@@ -0,0 +1,38 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_zkvm::lib::sha512::{sha512, Sha512};
+
+// The one-block and two-block examples of FIPS 180-2.
+const MESSAGES: [(&[u8], &str); 2] = [
+    (
+        b"abc",
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+         2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+    ),
+    (
+        b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmno\
+          ijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018\
+         501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909",
+    ),
+];
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+pub fn main() {
+    for (message, digest) in MESSAGES {
+        assert_eq!(sha512(message).to_vec(), decode_hex(digest));
+
+        // Streaming the message in uneven chunks gives the same digest.
+        let mut hasher = Sha512::new();
+        for chunk in message.chunks(7) {
+            hasher.update(chunk);
+        }
+        let streamed = hasher.finalize();
+        assert_eq!(streamed.to_vec(), decode_hex(digest));
+        sp1_zkvm::io::commit_slice(&streamed);
+    }
+}
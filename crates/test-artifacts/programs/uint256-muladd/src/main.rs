@@ -3,7 +3,7 @@ sp1_zkvm::entrypoint!(main);
 
 use num::{BigUint, Num, One};
 use rand::Rng;
-use sp1_zkvm::syscalls::sys_bn254_muladd;
+use sp1_zkvm::syscalls::{sys_bn254_muladd, syscall_bn254_muladd_be};
 
 fn uint256_muladd(x: &[u8; 32], y: &[u8; 32], z: &[u8; 32]) -> [u8; 32] {
     println!("cycle-tracker-start: uint256_muladd");
@@ -19,6 +19,15 @@ fn uint256_muladd(x: &[u8; 32], y: &[u8; 32], z: &[u8; 32]) -> [u8; 32] {
     bytemuck::cast::<[u32; 8], [u8; 32]>(result)
 }
 
+fn bn254_muladd_be(x: &[u8; 32], y: &[u8; 32], z: &[u8; 32]) -> [u8; 32] {
+    let mut result = bytemuck::cast::<[u8; 32], [u32; 8]>(*z);
+    let mut concat_x_y = [0u32; 16];
+    concat_x_y[..8].copy_from_slice(&bytemuck::cast::<[u8; 32], [u32; 8]>(*x));
+    concat_x_y[8..].copy_from_slice(&bytemuck::cast::<[u8; 32], [u32; 8]>(*y));
+    syscall_bn254_muladd_be(&mut result, concat_x_y.as_ptr() as *const [u32; 8]);
+    bytemuck::cast::<[u32; 8], [u8; 32]>(result)
+}
+
 fn biguint_to_bytes_le(x: BigUint) -> [u8; 32] {
     let mut bytes = x.to_bytes_le();
     bytes.resize(32, 0);
@@ -55,4 +64,14 @@ pub fn main() {
     let result_syscall = BigUint::from_bytes_le(&result_bytes);
 
     assert_eq!(result, result_syscall);
+
+    // The big-endian syscall computes the same value on byte-reversed operands.
+    let reversed = |bytes: &[u8; 32]| {
+        let mut bytes = *bytes;
+        bytes.reverse();
+        bytes
+    };
+    let mut result_bytes_be = bn254_muladd_be(&reversed(&x), &reversed(&y), &reversed(&z));
+    result_bytes_be.reverse();
+    assert_eq!(result_bytes_be, result_bytes);
 }
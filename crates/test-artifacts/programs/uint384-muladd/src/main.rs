@@ -0,0 +1,67 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num, Zero};
+use rand::Rng;
+use sp1_zkvm::syscalls::syscall_uint384_muladd;
+
+fn uint384_muladd(x: &[u8; 48], a: &[u8; 48], b: &[u8; 48]) -> [u8; 48] {
+    let mut result = bytemuck::cast::<[u8; 48], [u32; 12]>(*x);
+    let mut y = [0u32; 24];
+    y[..12].copy_from_slice(&bytemuck::cast::<[u8; 48], [u32; 12]>(*a));
+    y[12..].copy_from_slice(&bytemuck::cast::<[u8; 48], [u32; 12]>(*b));
+
+    println!("cycle-tracker-start: uint384_muladd");
+    syscall_uint384_muladd(&mut result, &y);
+    println!("cycle-tracker-end: uint384_muladd");
+
+    bytemuck::cast::<[u32; 12], [u8; 48]>(result)
+}
+
+fn biguint_to_bytes_le(x: &BigUint) -> [u8; 48] {
+    let mut bytes = x.to_bytes_le();
+    bytes.resize(48, 0);
+    bytes.try_into().unwrap()
+}
+
+#[sp1_derive::cycle_tracker]
+pub fn main() {
+    // BLS12-381 base field modulus.
+    let modulus = BigUint::from_str_radix(
+        "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787",
+        10,
+    )
+    .unwrap();
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..10 {
+        let x = BigUint::from_bytes_le(&rng.gen::<[u8; 32]>()) % &modulus;
+        let a = BigUint::from_bytes_le(&rng.gen::<[u8; 32]>()) % &modulus;
+        let b = BigUint::from_bytes_le(&rng.gen::<[u8; 32]>()) % &modulus;
+
+        let result_bytes = uint384_muladd(
+            &biguint_to_bytes_le(&x),
+            &biguint_to_bytes_le(&a),
+            &biguint_to_bytes_le(&b),
+        );
+        let result = BigUint::from_bytes_le(&result_bytes);
+
+        assert_eq!(result, (a * b + x) % &modulus);
+    }
+
+    // Edge case: (p - 1) * (p - 1) + (p - 1).
+    let max = &modulus - 1u32;
+    let result_bytes = uint384_muladd(
+        &biguint_to_bytes_le(&max),
+        &biguint_to_bytes_le(&max),
+        &biguint_to_bytes_le(&max),
+    );
+    assert_eq!(BigUint::from_bytes_le(&result_bytes), (&max * &max + &max) % &modulus);
+
+    // Edge case: multiplying by zero leaves x unchanged.
+    let zero = biguint_to_bytes_le(&BigUint::zero());
+    let result_bytes = uint384_muladd(&biguint_to_bytes_le(&max), &zero, &zero);
+    assert_eq!(BigUint::from_bytes_le(&result_bytes), max);
+
+    println!("All tests passed!");
+}
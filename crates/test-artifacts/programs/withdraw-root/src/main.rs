@@ -0,0 +1,37 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use sp1_lib::keccak::withdraw_root;
+use tiny_keccak::{Hasher, Keccak};
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(left);
+    hasher.update(right);
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+fn expected_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut nodes = leaves.to_vec();
+    nodes.resize(leaves.len().next_power_of_two(), [0; 32]);
+    while nodes.len() > 1 {
+        nodes = nodes.chunks_exact(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect();
+    }
+    nodes.first().copied().unwrap_or_default()
+}
+
+pub fn main() {
+    let leaves = (0..100u8).map(|i| [i.wrapping_mul(37).wrapping_add(1); 32]).collect::<Vec<_>>();
+
+    // Cover every depth of a single call, padded trees, and trees split over several calls.
+    for len in [2, 3, 4, 8, 16, 17, 32, 100] {
+        let root = withdraw_root(&leaves[..len]);
+        assert_eq!(root, expected_root(&leaves[..len]), "mismatch for {len} leaves");
+    }
+
+    // A single leaf is its own root, and the root of no leaves is zero.
+    assert_eq!(withdraw_root(&leaves[..1]), leaves[0]);
+    assert_eq!(withdraw_root(&[]), [0; 32]);
+}
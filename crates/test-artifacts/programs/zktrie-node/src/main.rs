@@ -0,0 +1,53 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use num::{BigUint, Num};
+use sp1_zkvm::lib::poseidon::{
+    poseidon_merkle_root, zktrie_hash_node, MerkleLevel, ZKTRIE_NODE_TYPE_BRANCHES,
+    ZKTRIE_NODE_TYPE_LEAF,
+};
+
+fn biguint_to_words(x: &BigUint) -> [u32; 8] {
+    let mut words = x.to_u32_digits();
+    words.resize(8, 0);
+    words.try_into().unwrap()
+}
+
+fn hex_to_words(x: &str) -> [u32; 8] {
+    biguint_to_words(&BigUint::from_str_radix(x, 16).unwrap())
+}
+
+fn element(x: u32) -> [u32; 8] {
+    biguint_to_words(&BigUint::from(x))
+}
+
+pub fn main() {
+    // A leaf and a branch hash their children with the node type as the domain.
+    let leaf = zktrie_hash_node(ZKTRIE_NODE_TYPE_LEAF, &element(1), &element(2));
+    assert_eq!(
+        leaf,
+        hex_to_words("247655235ec886952c4446080de63183c4bb3289a90a8ac4f4df9235642fec30")
+    );
+    let branch = zktrie_hash_node(ZKTRIE_NODE_TYPE_BRANCHES[0], &element(3), &element(4));
+    assert_eq!(
+        branch,
+        hex_to_words("0fbede63490aeaccff263e6930f45f63197884e92708c32380ce1f421316e17b")
+    );
+    let root = zktrie_hash_node(ZKTRIE_NODE_TYPE_BRANCHES[3], &leaf, &branch);
+    assert_eq!(
+        root,
+        hex_to_words("02246f9e477f42d905eef3a818ea7ea117ff289611649b6fb32dfcaedee5e1f7")
+    );
+
+    // Every node type agrees with a single level of a Poseidon Merkle path with the type as the
+    // domain.
+    for node_type in core::iter::once(ZKTRIE_NODE_TYPE_LEAF).chain(ZKTRIE_NODE_TYPE_BRANCHES) {
+        let hash = zktrie_hash_node(node_type, &leaf, &branch);
+        let mut x = leaf;
+        let level = MerkleLevel { sibling: branch, domain: element(node_type), is_right: false };
+        poseidon_merkle_root(&mut x, &[level]);
+        assert_eq!(hash, x);
+    }
+
+    println!("done");
+}
@@ -12,14 +12,38 @@ pub const FIBONACCI_ELF: &[u8] = include_elf!("fibonacci-program-tests");
 
 pub const ED25519_ELF: &[u8] = include_elf!("ed25519-program");
 
+pub const ED25519_VERIFY_ELF: &[u8] = include_elf!("ed25519-verify-test");
+
 pub const CYCLE_TRACKER_ELF: &[u8] = include_elf!("cycle-tracker-test");
 
 pub const ED_ADD_ELF: &[u8] = include_elf!("ed-add-test");
 
+pub const JUBJUB_ELF: &[u8] = include_elf!("jubjub-test");
+
+pub const BABY_JUBJUB_ELF: &[u8] = include_elf!("baby-jubjub-test");
+
 pub const ED_DECOMPRESS_ELF: &[u8] = include_elf!("ed-decompress-test");
 
+pub const COMMITTED_VALUES_ELF: &[u8] = include_elf!("committed-values-test");
+
+pub const ENV_VALUES_ELF: &[u8] = include_elf!("env-values-test");
+
+pub const EXIT_CODE_ELF: &[u8] = include_elf!("exit-code-test");
+
+pub const HINT_BOUNDED_ELF: &[u8] = include_elf!("hint-bounded-test");
+
+pub const HINT_VERIFIED_ELF: &[u8] = include_elf!("hint-verified-test");
+
+pub const KECCAK_ACCUMULATE_ELF: &[u8] = include_elf!("keccak-accumulate-test");
+
 pub const KECCAK_PERMUTE_ELF: &[u8] = include_elf!("keccak-permute-test");
 
+pub const KECCAK_SPONGE_ELF: &[u8] = include_elf!("keccak-sponge-test");
+
+pub const KECCAK_MERKLE_ELF: &[u8] = include_elf!("keccak-merkle-test");
+
+pub const WITHDRAW_ROOT_ELF: &[u8] = include_elf!("withdraw-root-test");
+
 pub const KECCAK256_ELF: &[u8] = include_elf!("keccak256-test");
 
 pub const SECP256K1_ADD_ELF: &[u8] = include_elf!("secp256k1-add-test");
@@ -28,6 +52,8 @@ pub const SECP256K1_DECOMPRESS_ELF: &[u8] = include_elf!("secp256k1-decompress-t
 
 pub const SECP256K1_DOUBLE_ELF: &[u8] = include_elf!("secp256k1-double-test");
 
+pub const SECP256K1_ECRECOVER_ELF: &[u8] = include_elf!("secp256k1-ecrecover-test");
+
 pub const SECP256R1_ADD_ELF: &[u8] = include_elf!("secp256r1-add-test");
 
 pub const SECP256R1_DECOMPRESS_ELF: &[u8] = include_elf!("secp256r1-decompress-test");
@@ -40,10 +66,14 @@ pub const SHA_EXTEND_ELF: &[u8] = include_elf!("sha-extend-test");
 
 pub const SHA2_ELF: &[u8] = include_elf!("sha2-test");
 
+pub const SHA512_COMPRESS_ELF: &[u8] = include_elf!("sha512-compress-test");
+
 pub const BN254_ADD_ELF: &[u8] = include_elf!("bn254-add-test");
 
 pub const BN254_DOUBLE_ELF: &[u8] = include_elf!("bn254-double-test");
 
+pub const BN254_DECOMPRESS_ELF: &[u8] = include_elf!("bn254-decompress-test");
+
 pub const BN254_MUL_ELF: &[u8] = include_elf!("bn254-mul-test");
 
 pub const SECP256K1_MUL_ELF: &[u8] = include_elf!("secp256k1-mul-test");
@@ -66,16 +96,66 @@ pub const BLS12381_FP_ELF: &[u8] = include_elf!("bls12381-fp-test");
 
 pub const BLS12381_FP2_MUL_ELF: &[u8] = include_elf!("bls12381-fp2-mul-test");
 
+pub const BLS12381_FP6_MUL_ELF: &[u8] = include_elf!("bls12381-fp6-mul-test");
+
 pub const BLS12381_FP2_ADDSUB_ELF: &[u8] = include_elf!("bls12381-fp2-addsub-test");
 
 pub const BN254_FP_ELF: &[u8] = include_elf!("bn254-fp-test");
 
+pub const BN254_FP12_ELF: &[u8] = include_elf!("bn254-fp12-test");
+
 pub const BN254_FP2_ADDSUB_ELF: &[u8] = include_elf!("bn254-fp2-addsub-test");
 
 pub const BN254_FP2_MUL_ELF: &[u8] = include_elf!("bn254-fp2-mul-test");
 
+pub const BN254_MONT_ELF: &[u8] = include_elf!("bn254-mont-test");
+
+pub const BN254_INNER_PRODUCT_ELF: &[u8] = include_elf!("bn254-inner-product-test");
+
 pub const TENDERMINT_BENCHMARK_ELF: &[u8] = include_elf!("tendermint-benchmark-program");
 
 pub const U256XU2048_MUL_ELF: &[u8] = include_elf!("u256x2048-mul");
 
+pub const U256XU4096_MUL_ELF: &[u8] = include_elf!("u256x4096-mul");
+
+pub const BARRETT_REDUCE_ELF: &[u8] = include_elf!("barrett-reduce-test");
+
+pub const AES_CTR_ELF: &[u8] = include_elf!("aes-ctr-test");
+
+pub const CHACHA20_ELF: &[u8] = include_elf!("chacha20-test");
+
+pub const HMAC_SHA256_ELF: &[u8] = include_elf!("hmac-sha256-test");
+
+pub const SHA256_BLOCKS_ELF: &[u8] = include_elf!("sha256-blocks-test");
+
+pub const F64_ARITH_ELF: &[u8] = include_elf!("f64-arith-test");
+
+pub const CSEL256_ELF: &[u8] = include_elf!("csel256-test");
+
+pub const FR_NTT_ELF: &[u8] = include_elf!("fr-ntt-test");
+
+pub const RLP_SCAN_ELF: &[u8] = include_elf!("rlp-scan-test");
+
+pub const HEX_DECODE_ELF: &[u8] = include_elf!("hex-decode-test");
+
+pub const BASE64_DECODE_ELF: &[u8] = include_elf!("base64-decode-test");
+
 pub const UINT256_MULADD_ELF: &[u8] = include_elf!("biguint-muladd-test");
+
+pub const UINT384_MULADD_ELF: &[u8] = include_elf!("uint384-muladd-test");
+
+pub const MORPH_SYSCALLS_ELF: &[u8] = include_elf!("morph-syscalls-test");
+
+pub const MEMCOPY_ELF: &[u8] = include_elf!("memcopy-test");
+
+pub const COLLECTIONS_ELF: &[u8] = include_elf!("collections-test");
+
+pub const POSEIDON_ELF: &[u8] = include_elf!("poseidon-test");
+
+pub const POSEIDON_MERKLE_ELF: &[u8] = include_elf!("poseidon-merkle-test");
+
+pub const RSA_ELF: &[u8] = include_elf!("rsa-test");
+
+pub const MEMCPY_BENCH_ELF: &[u8] = include_elf!("memcpy-bench-test");
+
+pub const ZKTRIE_NODE_ELF: &[u8] = include_elf!("zktrie-node-test");
@@ -8,7 +8,7 @@ pub(crate) use verify::*;
 
 use error::Groth16Error;
 
-use crate::{decode_sp1_vkey_hash, error::Error, hash_public_inputs};
+use crate::{decode_sp1_vkey_hash, error::Error, hash_public_inputs, CircuitVersion};
 
 use alloc::vec::Vec;
 use sha2::{Digest, Sha256};
@@ -71,6 +71,29 @@ impl Groth16Verifier {
         )
     }
 
+    /// Verifies an SP1 Groth16 proof of any of the given circuit versions.
+    ///
+    /// The version is picked from the 4 bytes of the verifying key hash the proof is prepended
+    /// with, so that proofs of several SP1 versions can be verified side by side, e.g. with
+    /// [`CircuitVersion::known`] during a migration between versions.
+    ///
+    /// # Returns
+    ///
+    /// The version the proof was verified against, or a [`Groth16Error`] if verification fails.
+    /// [`Groth16Error::Groth16VkeyHashMismatch`] is returned if the proof is of none of the
+    /// versions.
+    pub fn verify_any_version<'a>(
+        proof: &[u8],
+        sp1_public_inputs: &[u8],
+        sp1_vkey_hash: &str,
+        versions: &'a [CircuitVersion],
+    ) -> Result<&'a CircuitVersion, Groth16Error> {
+        let version = CircuitVersion::of_groth16_proof(proof, versions)
+            .ok_or(Groth16Error::Groth16VkeyHashMismatch)?;
+        Self::verify(proof, sp1_public_inputs, sp1_vkey_hash, version.groth16_vk)?;
+        Ok(version)
+    }
+
     /// Verifies a Gnark Groth16 proof using raw byte inputs.
     ///
     /// WARNING: if you're verifying an SP1 proof, you should use [`verify`] instead.
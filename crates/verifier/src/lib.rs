@@ -16,6 +16,20 @@ lazy_static! {
     pub static ref GROTH16_VK_BYTES: &'static [u8] = include_bytes!("../bn254-vk/groth16_vk.bin");
 }
 
+#[cfg(feature = "upstream")]
+lazy_static! {
+    /// The PLONK verifying key of the upstream SP1 release whose proofs are also accepted.
+    pub static ref UPSTREAM_PLONK_VK_BYTES: &'static [u8] =
+        include_bytes!("../bn254-vk/upstream/plonk_vk.bin");
+}
+
+#[cfg(feature = "upstream")]
+lazy_static! {
+    /// The Groth16 verifying key of the upstream SP1 release whose proofs are also accepted.
+    pub static ref UPSTREAM_GROTH16_VK_BYTES: &'static [u8] =
+        include_bytes!("../bn254-vk/upstream/groth16_vk.bin");
+}
+
 mod constants;
 mod converter;
 mod error;
@@ -23,6 +37,9 @@ mod error;
 mod utils;
 pub use utils::*;
 
+mod versions;
+pub use versions::CircuitVersion;
+
 pub use groth16::error::Groth16Error;
 pub use groth16::Groth16Verifier;
 mod groth16;
@@ -31,7 +48,7 @@ mod groth16;
 pub use groth16::ark_converter::*;
 
 pub use plonk::error::PlonkError;
-pub use plonk::PlonkVerifier;
+pub use plonk::{PlonkVerifier, TranscriptHasher};
 mod plonk;
 
 #[cfg(test)]
@@ -1,19 +1,22 @@
 use alloc::vec;
 use alloc::vec::Vec;
-use core::hash::Hasher;
-use sha2::Digest;
+use core::{hash::Hasher, marker::PhantomData};
+use sha2::Sha256;
 
 use crate::PlonkError;
 
-pub(crate) struct WrappedHashToField {
+use super::TranscriptHasher;
+
+pub(crate) struct WrappedHashToField<H = Sha256> {
     domain: Vec<u8>,
     to_hash: Vec<u8>,
+    _hasher: PhantomData<H>,
 }
 
-impl WrappedHashToField {
+impl<H: TranscriptHasher> WrappedHashToField<H> {
     // Creates a new instance with a domain separator
     pub(crate) fn new(domain_separator: &[u8]) -> Result<Self, PlonkError> {
-        Ok(Self { domain: domain_separator.to_vec(), to_hash: Vec::new() })
+        Ok(Self { domain: domain_separator.to_vec(), to_hash: Vec::new(), _hasher: PhantomData })
     }
 
     // Hashes the bytes to a field element and returns the byte representation
@@ -43,7 +46,7 @@ impl WrappedHashToField {
     }
 
     fn expand_msg_xmd(msg: Vec<u8>, dst: Vec<u8>, len: usize) -> Result<Vec<u8>, PlonkError> {
-        let mut h = sha2::Sha256::new();
+        let mut h = H::default();
 
         let ell = (len + 32 - 1) / 32;
 
@@ -59,18 +62,18 @@ impl WrappedHashToField {
         h.reset();
 
         // b_0 = H(msg_prime)
-        h.update([0u8; 64]); // Assuming the block size is 64 bytes for SHA-256
+        h.update(&vec![0u8; H::BLOCK_SIZE]); // Z_pad, a block of zeros
         h.update(&msg);
-        h.update([(len >> 8) as u8, len as u8, 0]);
+        h.update(&[(len >> 8) as u8, len as u8, 0]);
         h.update(&dst);
-        h.update([size_domain as u8]);
+        h.update(&[size_domain as u8]);
         let b0 = h.finalize_reset();
 
         // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
-        h.update(b0);
-        h.update([1]); // I2OSP(1, 1)
+        h.update(&b0);
+        h.update(&[1]); // I2OSP(1, 1)
         h.update(&dst);
-        h.update([size_domain as u8]);
+        h.update(&[size_domain as u8]);
         let mut b1 = h.finalize_reset();
 
         let mut res = vec![0u8; len];
@@ -83,9 +86,9 @@ impl WrappedHashToField {
                 strxor[j] = b0_byte ^ b1_byte;
             }
             h.update(&strxor);
-            h.update([i as u8]);
+            h.update(&[i as u8]);
             h.update(&dst);
-            h.update([size_domain as u8]);
+            h.update(&[size_domain as u8]);
             b1 = h.finalize_reset();
 
             let start = 32 * (i - 1);
@@ -97,7 +100,7 @@ impl WrappedHashToField {
     }
 }
 
-impl Hasher for WrappedHashToField {
+impl<H> Hasher for WrappedHashToField<H> {
     fn finish(&self) -> u64 {
         // This method is not directly applicable to field elements, so it's a stub
         unimplemented!();
@@ -108,13 +111,13 @@ impl Hasher for WrappedHashToField {
     }
 }
 
-impl Default for WrappedHashToField {
+impl<H: TranscriptHasher> Default for WrappedHashToField<H> {
     fn default() -> Self {
         Self::new(&[]).unwrap()
     }
 }
 
-impl WrappedHashToField {
+impl<H> WrappedHashToField<H> {
     // Resets the state of the hasher
     pub(crate) fn reset(&mut self) {
         self.to_hash.clear();
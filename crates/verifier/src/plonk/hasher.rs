@@ -0,0 +1,104 @@
+use sha2::{Digest, Sha256};
+
+/// A hash function for the Fiat-Shamir transcript and the hash to field of the PLONK verifier.
+///
+/// gnark derives the challenges of a PLONK proof with SHA-256 by default, but a prover can be
+/// configured with another hash function, in which case it must be verified with the same one.
+/// SHA-256 and, with the `keccak` feature, Keccak-256 are provided; other hash functions such as
+/// Poseidon can be supported by implementing this trait.
+pub trait TranscriptHasher: Default {
+    /// The size in bytes of the input blocks of the hash function.
+    const BLOCK_SIZE: usize;
+
+    /// Absorbs some data.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the 32-byte digest of the data absorbed so far, and resets the hasher.
+    fn finalize_reset(&mut self) -> [u8; 32];
+
+    /// Resets the hasher.
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+macro_rules! impl_transcript_hasher {
+    ($hasher:ty, $block_size:expr) => {
+        impl TranscriptHasher for $hasher {
+            const BLOCK_SIZE: usize = $block_size;
+
+            fn update(&mut self, data: &[u8]) {
+                Digest::update(self, data);
+            }
+
+            fn finalize_reset(&mut self) -> [u8; 32] {
+                Digest::finalize_reset(self).into()
+            }
+        }
+    };
+}
+
+impl_transcript_hasher!(Sha256, 64);
+
+#[cfg(feature = "keccak")]
+impl_transcript_hasher!(sha3::Keccak256, 136);
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec, vec::Vec};
+    use core::hash::Hasher;
+
+    use super::*;
+    use crate::plonk::{hash_to_field::WrappedHashToField, transcript::Transcript, BETA, GAMMA};
+
+    /// Computes two chained challenges and a hash to field, to pin the output of a hasher.
+    fn golden<H: TranscriptHasher>() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let mut transcript =
+            Transcript::<H>::new(Some(vec![GAMMA.to_string(), BETA.to_string()])).unwrap();
+        transcript.bind(GAMMA, &[1, 2]).unwrap();
+        transcript.bind(GAMMA, &[3]).unwrap();
+        let gamma = transcript.compute_challenge(GAMMA).unwrap();
+        let beta = transcript.compute_challenge(BETA).unwrap();
+
+        let mut hash_to_field = WrappedHashToField::<H>::new(b"BSB22-Plonk").unwrap();
+        hash_to_field.write(b"abc");
+        (gamma, beta, hash_to_field.sum().unwrap())
+    }
+
+    #[test]
+    fn test_sha256_golden() {
+        let (gamma, beta, hash) = golden::<Sha256>();
+        assert_eq!(
+            hex::encode(gamma),
+            "bf484846e4a85912774a2e073c965cfb0b128e224fbd8b1b8ce2ed6548a8069e"
+        );
+        assert_eq!(
+            hex::encode(beta),
+            "3dfb6e1089deefa7c4f9e9cdb2a3473c97023ebd2d7dcdc8a0d1530f761134a5"
+        );
+        assert_eq!(
+            hex::encode(hash),
+            "72bc73e379560b159e933cf708c2816592c93a62fd0a78faa0feff3431da2e41\
+             e35bf5e2d829046ceceb8d943cc0cd03"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "keccak")]
+    fn test_keccak256_golden() {
+        let (gamma, beta, hash) = golden::<sha3::Keccak256>();
+        assert_eq!(
+            hex::encode(gamma),
+            "c4f7104ddd1be54f4316db05c89c945c28b1bd169c9cb003054bdf6d5225dbdd"
+        );
+        assert_eq!(
+            hex::encode(beta),
+            "8db061352290b39062e9ebc365af91037d84167faf5854751bbcb9b1606c7193"
+        );
+        assert_eq!(
+            hex::encode(hash),
+            "1b59ea008f243b134af8672e6c04cecc8b58e2082c2415c757680c0128562af9\
+             0550f841e0f1c5c0aceaa26d5d24fd55"
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use alloc::{string::ToString, vec, vec::Vec};
 use bn::{pairing_batch, AffineG1, Fr, G1, G2};
 
-use crate::{error::Error, plonk::transcript::Transcript};
+use crate::{
+    error::Error,
+    plonk::{transcript::Transcript, TranscriptHasher},
+};
 
 use super::{converter::g1_to_bytes, error::PlonkError, GAMMA, U};
 
@@ -45,13 +48,13 @@ pub(crate) struct OpeningProof {
 /// Derives the folding factor for the batched opening proof.
 ///
 /// Uses a separate transcript than the main transcript used for the other fiat shamir randomness.
-fn derive_gamma(
+fn derive_gamma<H: TranscriptHasher>(
     point: &Fr,
     digests: Vec<Digest>,
     claimed_values: Vec<Fr>,
     data_transcript: Option<Vec<u8>>,
 ) -> Result<Fr, PlonkError> {
-    let mut transcript = Transcript::new(Some([GAMMA.to_string()].to_vec()))?;
+    let mut transcript = Transcript::<H>::new(Some([GAMMA.to_string()].to_vec()))?;
     transcript.bind(GAMMA, &point.into_u256().to_bytes_be())?;
 
     for digest in digests.iter() {
@@ -87,12 +90,12 @@ fn fold(di: Vec<Digest>, fai: Vec<Fr>, ci: Vec<Fr>) -> Result<(AffineG1, Fr), Pl
     Ok((folded_digests, folded_evaluations))
 }
 
-pub(crate) fn fold_proof(
+pub(crate) fn fold_proof<H: TranscriptHasher>(
     digests: Vec<Digest>,
     batch_opening_proof: &BatchOpeningProof,
     point: &Fr,
     data_transcript: Option<Vec<u8>>,
-    global_transcript: &mut Transcript,
+    global_transcript: &mut Transcript<H>,
 ) -> Result<(OpeningProof, AffineG1), PlonkError> {
     let nb_digests = digests.len();
 
@@ -100,7 +103,7 @@ pub(crate) fn fold_proof(
         return Err(PlonkError::InvalidNumberOfDigests);
     }
 
-    let gamma = derive_gamma(
+    let gamma = derive_gamma::<H>(
         point,
         digests.clone(),
         batch_opening_proof.claimed_values.clone(),
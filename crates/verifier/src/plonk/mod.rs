@@ -6,6 +6,7 @@ pub(crate) const U: &str = "u";
 
 mod converter;
 mod hash_to_field;
+mod hasher;
 mod kzg;
 mod proof;
 mod transcript;
@@ -13,6 +14,8 @@ mod verify;
 
 pub(crate) mod error;
 
+pub use hasher::TranscriptHasher;
+
 pub(crate) use converter::{load_plonk_proof_from_bytes, load_plonk_verifying_key_from_bytes};
 pub(crate) use proof::PlonkProof;
 pub(crate) use verify::verify_plonk_algebraic;
@@ -22,7 +25,7 @@ use bn::Fr;
 use error::PlonkError;
 use sha2::{Digest, Sha256};
 
-use crate::{decode_sp1_vkey_hash, error::Error, hash_public_inputs};
+use crate::{decode_sp1_vkey_hash, error::Error, hash_public_inputs, CircuitVersion};
 /// A verifier for Plonk zero-knowledge proofs.
 #[derive(Debug)]
 pub struct PlonkVerifier;
@@ -78,6 +81,28 @@ impl PlonkVerifier {
         )
     }
 
+    /// Verifies an SP1 PLONK proof of any of the given circuit versions.
+    ///
+    /// The version is picked from the 4 bytes of the verifying key hash the proof is prepended
+    /// with, so that proofs of several SP1 versions can be verified side by side, e.g. with
+    /// [`CircuitVersion::known`] during a migration between versions.
+    ///
+    /// # Returns
+    ///
+    /// The version the proof was verified against, or a [`PlonkError`] if verification fails.
+    /// [`PlonkError::PlonkVkeyHashMismatch`] is returned if the proof is of none of the versions.
+    pub fn verify_any_version<'a>(
+        proof: &[u8],
+        sp1_public_inputs: &[u8],
+        sp1_vkey_hash: &str,
+        versions: &'a [CircuitVersion],
+    ) -> Result<&'a CircuitVersion, PlonkError> {
+        let version = CircuitVersion::of_plonk_proof(proof, versions)
+            .ok_or(PlonkError::PlonkVkeyHashMismatch)?;
+        Self::verify(proof, sp1_public_inputs, sp1_vkey_hash, version.plonk_vk)?;
+        Ok(version)
+    }
+
     /// Verifies a Gnark PLONK proof using raw byte inputs.
     ///
     /// WARNING: if you're verifying an SP1 proof, you should use [`verify`] instead.
@@ -98,12 +123,74 @@ impl PlonkVerifier {
         proof: &[u8],
         public_inputs: &[[u8; 32]],
         plonk_vk: &[u8],
+    ) -> Result<(), PlonkError> {
+        Self::verify_gnark_proof_with_hasher::<Sha256>(proof, public_inputs, plonk_vk)
+    }
+
+    /// Verifies a Gnark PLONK proof whose challenges are derived with the hasher `H`.
+    ///
+    /// This is [`Self::verify_gnark_proof`] for proofs generated by a gnark prover configured with
+    /// another challenge hash than SHA-256, such as Keccak-256 with the `keccak` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The raw PLONK proof bytes (without the 4-byte vkey hash prefix)
+    /// * `public_inputs` - The public inputs to the circuit
+    /// * `plonk_vk` - The PLONK verifying key bytes
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing unit `()` if the proof is valid,
+    /// or a [`PlonkError`] if verification fails.
+    pub fn verify_gnark_proof_with_hasher<H: TranscriptHasher>(
+        proof: &[u8],
+        public_inputs: &[[u8; 32]],
+        plonk_vk: &[u8],
     ) -> Result<(), PlonkError> {
         let plonk_vk = load_plonk_verifying_key_from_bytes(plonk_vk).unwrap();
         let proof = load_plonk_proof_from_bytes(proof, plonk_vk.qcp.len()).unwrap();
 
-        let public_inputs =
-            public_inputs.iter().map(|input| Fr::from_slice(input).unwrap()).collect::<Vec<_>>();
-        verify_plonk_algebraic(&plonk_vk, &proof, &public_inputs)
+        let public_inputs = public_inputs
+            .iter()
+            .map(|input| Fr::from_slice(input).map_err(|_| PlonkError::BeyondTheModulus))
+            .collect::<Result<Vec<_>, _>>()?;
+        verify_plonk_algebraic::<H>(&plonk_vk, &proof, &public_inputs)
+    }
+
+    /// Verifies a Gnark PLONK proof whose public inputs are given as a single byte stream.
+    ///
+    /// The stream is packed into field elements as in gnark's witness encoding: every 32 bytes
+    /// are a big-endian field element, and a trailing chunk of fewer than 32 bytes is a big-endian
+    /// field element on its own, as if it were left-padded with zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The raw PLONK proof bytes (without the 4-byte vkey hash prefix)
+    /// * `public_inputs` - The public inputs to the circuit, as a byte stream
+    /// * `plonk_vk` - The PLONK verifying key bytes
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] containing unit `()` if the proof is valid, or a [`PlonkError`] if
+    /// verification fails. [`PlonkError::BeyondTheModulus`] is returned if a chunk is not less
+    /// than the scalar field modulus.
+    pub fn verify_gnark_proof_with_public_input_bytes(
+        proof: &[u8],
+        public_inputs: &[u8],
+        plonk_vk: &[u8],
+    ) -> Result<(), PlonkError> {
+        Self::verify_gnark_proof(proof, &pack_public_inputs(public_inputs), plonk_vk)
     }
 }
+
+/// Splits a byte stream into 32-byte big-endian chunks, left-padding the last one with zeros.
+pub(crate) fn pack_public_inputs(public_inputs: &[u8]) -> Vec<[u8; 32]> {
+    public_inputs
+        .chunks(32)
+        .map(|chunk| {
+            let mut packed = [0u8; 32];
+            packed[32 - chunk.len()..].copy_from_slice(chunk);
+            packed
+        })
+        .collect()
+}
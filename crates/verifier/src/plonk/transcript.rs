@@ -1,8 +1,10 @@
 use alloc::{collections::btree_map::BTreeMap, string::String, vec::Vec};
-use sha2::{Digest, Sha256};
+use sha2::Sha256;
 
 use crate::PlonkError;
 
+use super::TranscriptHasher;
+
 /// A challenge in the transcript, derived with randomness from `bindings` and the previous
 /// challenge.
 #[derive(Clone, Debug)]
@@ -13,19 +15,19 @@ pub(crate) struct Challenge {
     is_computed: bool,
 }
 
-/// A Fiat-Shamir transcript.
+/// A Fiat-Shamir transcript, deriving its challenges with the hasher `H`.
 #[derive(Clone, Debug)]
-pub(crate) struct Transcript {
-    pub(crate) h: Sha256,
+pub(crate) struct Transcript<H = Sha256> {
+    pub(crate) h: H,
 
     pub(crate) challenges: BTreeMap<String, Challenge>,
     previous_challenge: Option<Challenge>,
 }
 
-impl Transcript {
+impl<H: TranscriptHasher> Transcript<H> {
     /// Creates a new transcript.
     pub(crate) fn new(challenges_id: Option<Vec<String>>) -> Result<Self, PlonkError> {
-        let h = Sha256::new();
+        let h = H::default();
 
         if let Some(challenges_id) = challenges_id {
             let mut challenges = BTreeMap::new();
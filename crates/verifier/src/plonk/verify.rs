@@ -4,7 +4,7 @@ use core::hash::Hasher;
 
 use crate::{
     error::Error,
-    plonk::{kzg::BatchOpeningProof, transcript::Transcript},
+    plonk::{kzg::BatchOpeningProof, transcript::Transcript, TranscriptHasher},
 };
 
 use super::{
@@ -41,10 +41,12 @@ pub(crate) struct PlonkVerifyingKey {
 /// * `proof` - The PLONK proof
 /// * `public_inputs` - The public inputs to the circuit
 ///
+/// The challenges and the hashes of the BSB22 commitments are derived with the hasher `H`.
+///
 /// # Returns
 ///
 /// * `Result<bool, PlonkError>` - Returns true if the proof is valid, or an error if verification fails
-pub(crate) fn verify_plonk_algebraic(
+pub(crate) fn verify_plonk_algebraic<H: TranscriptHasher>(
     vk: &PlonkVerifyingKey,
     proof: &PlonkProof,
     public_inputs: &[Fr],
@@ -60,7 +62,7 @@ pub(crate) fn verify_plonk_algebraic(
     }
 
     // Initialize the Fiat-Shamir transcript
-    let mut fs = Transcript::new(Some(
+    let mut fs = Transcript::<H>::new(Some(
         [GAMMA.to_string(), BETA.to_string(), ALPHA.to_string(), ZETA.to_string(), U.to_string()]
             .to_vec(),
     ))?;
@@ -130,7 +132,8 @@ pub(crate) fn verify_plonk_algebraic(
     }
 
     // Handle BSB22 commitments
-    let mut hash_to_field = crate::plonk::hash_to_field::WrappedHashToField::new(b"BSB22-Plonk")?;
+    let mut hash_to_field =
+        crate::plonk::hash_to_field::WrappedHashToField::<H>::new(b"BSB22-Plonk")?;
 
     for i in 0..vk.commitment_constraint_indexes.len() {
         hash_to_field.write(&g1_to_bytes(&proof.bsb22_commitments[i])?);
@@ -318,8 +321,8 @@ pub(crate) fn verify_plonk_algebraic(
 }
 
 /// Binds all plonk public data to the transcript.
-fn bind_public_data(
-    transcript: &mut Transcript,
+fn bind_public_data<H: TranscriptHasher>(
+    transcript: &mut Transcript<H>,
     challenge: &str,
     vk: &PlonkVerifyingKey,
     public_inputs: &[Fr],
@@ -349,8 +352,8 @@ fn bind_public_data(
 ///
 /// If you want to include some data for a challenge that isn't an affine g1 point, use
 /// [`Transcript::bind`] to bind the data to the transcript before deriving the randomness.
-fn derive_randomness(
-    transcript: &mut Transcript,
+fn derive_randomness<H: TranscriptHasher>(
+    transcript: &mut Transcript<H>,
     challenge: &str,
     points: Option<Vec<AffineG1>>,
 ) -> Result<Fr, PlonkError> {
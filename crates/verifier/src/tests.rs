@@ -36,6 +36,114 @@ fn test_verify_plonk() {
         .expect("Plonk proof is invalid");
 }
 
+#[test]
+fn test_verify_any_version() {
+    use crate::{CircuitVersion, Groth16Error, Groth16Verifier, PlonkError, PlonkVerifier};
+
+    let vkey_hash = "0x00e60860c07bfc6e4c480286c0ddbb879674eb47f84b4ef041cf858b17aa0ed1";
+    let load = |proof_file| {
+        let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(proof_file).unwrap();
+        (sp1_proof_with_public_values.bytes(), sp1_proof_with_public_values.public_values.to_vec())
+    };
+    let (groth16_proof, groth16_inputs) = load("test_binaries/fibonacci-groth16.bin");
+    let (plonk_proof, plonk_inputs) = load("test_binaries/fibonacci-plonk.bin");
+
+    // The version is picked by the prefix of the proof, whatever its position.
+    let current = CircuitVersion::current();
+    let decoy = CircuitVersion {
+        name: "decoy",
+        groth16_vk: current.plonk_vk,
+        plonk_vk: current.groth16_vk,
+    };
+    let versions = [decoy, current];
+    let version =
+        Groth16Verifier::verify_any_version(&groth16_proof, &groth16_inputs, vkey_hash, &versions)
+            .expect("Groth16 proof is invalid");
+    assert_eq!(version.name, "current");
+    let version =
+        PlonkVerifier::verify_any_version(&plonk_proof, &plonk_inputs, vkey_hash, &versions)
+            .expect("Plonk proof is invalid");
+    assert_eq!(version.name, "current");
+    assert!(CircuitVersion::known().iter().any(|version| version.name == "current"));
+
+    // A proof of none of the versions is rejected.
+    assert!(matches!(
+        Groth16Verifier::verify_any_version(&groth16_proof, &groth16_inputs, vkey_hash, &[decoy]),
+        Err(Groth16Error::Groth16VkeyHashMismatch)
+    ));
+    assert!(matches!(
+        PlonkVerifier::verify_any_version(&plonk_proof, &plonk_inputs, vkey_hash, &[decoy]),
+        Err(PlonkError::PlonkVkeyHashMismatch)
+    ));
+}
+
+#[test]
+#[cfg(feature = "upstream")]
+fn test_verify_upstream() {
+    use crate::{CircuitVersion, Groth16Verifier, PlonkVerifier};
+
+    // The fixtures were proven by the upstream SP1 release.
+    let vkey_hash = "0x00e60860c07bfc6e4c480286c0ddbb879674eb47f84b4ef041cf858b17aa0ed1";
+    let load = |proof_file| {
+        let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(proof_file).unwrap();
+        (sp1_proof_with_public_values.bytes(), sp1_proof_with_public_values.public_values.to_vec())
+    };
+    let (groth16_proof, groth16_inputs) = load("test_binaries/fibonacci-groth16.bin");
+    let (plonk_proof, plonk_inputs) = load("test_binaries/fibonacci-plonk.bin");
+
+    let versions = [CircuitVersion::upstream()];
+    let version =
+        Groth16Verifier::verify_any_version(&groth16_proof, &groth16_inputs, vkey_hash, &versions)
+            .expect("Groth16 proof is invalid");
+    assert_eq!(version.name, "upstream");
+    let version =
+        PlonkVerifier::verify_any_version(&plonk_proof, &plonk_inputs, vkey_hash, &versions)
+            .expect("Plonk proof is invalid");
+    assert_eq!(version.name, "upstream");
+    assert!(CircuitVersion::known().iter().any(|version| version.name == "upstream"));
+}
+
+#[test]
+fn test_verify_plonk_with_public_input_bytes() {
+    let proof_file = "test_binaries/fibonacci-plonk.bin";
+    let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(proof_file).unwrap();
+
+    let proof = sp1_proof_with_public_values.bytes();
+    let public_inputs = sp1_proof_with_public_values.public_values.to_vec();
+    let vkey_hash = "0x00e60860c07bfc6e4c480286c0ddbb879674eb47f84b4ef041cf858b17aa0ed1";
+
+    // The public inputs of the circuit are the vkey hash and the digest of the public values.
+    let mut public_input_bytes = crate::decode_sp1_vkey_hash(vkey_hash).unwrap().to_vec();
+    public_input_bytes.extend(crate::hash_public_inputs(&public_inputs));
+
+    crate::PlonkVerifier::verify_gnark_proof_with_public_input_bytes(
+        &proof[4..],
+        &public_input_bytes,
+        &crate::PLONK_VK_BYTES,
+    )
+    .expect("Plonk proof is invalid");
+
+    // A chunk that is not less than the modulus is rejected.
+    public_input_bytes[32..].fill(0xff);
+    assert!(matches!(
+        crate::PlonkVerifier::verify_gnark_proof_with_public_input_bytes(
+            &proof[4..],
+            &public_input_bytes,
+            &crate::PLONK_VK_BYTES,
+        ),
+        Err(crate::PlonkError::BeyondTheModulus)
+    ));
+}
+
+#[test]
+fn test_pack_public_inputs() {
+    let packed = crate::plonk::pack_public_inputs(&[1u8; 40]);
+    assert_eq!(packed.len(), 2);
+    assert_eq!(packed[0], [1u8; 32]);
+    assert_eq!(packed[1][..24], [0u8; 24]);
+    assert_eq!(packed[1][24..], [1u8; 8]);
+}
+
 #[test]
 fn test_vkeys() {
     let groth16_path = try_install_circuit_artifacts("groth16");
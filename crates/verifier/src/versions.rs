@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+use crate::{GROTH16_VK_BYTES, PLONK_VK_BYTES};
+
+/// The verifying keys of the Groth16 and Plonk circuits of a lineage of SP1 proofs.
+///
+/// SP1 prepends every Groth16 and Plonk proof with the first 4 bytes of the hash of the verifying
+/// key it was generated for, which identifies the circuit version of the proof.
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitVersion {
+    /// A name for the version, for diagnostics.
+    pub name: &'static str,
+    /// The Groth16 verifying key bytes.
+    pub groth16_vk: &'static [u8],
+    /// The Plonk verifying key bytes.
+    pub plonk_vk: &'static [u8],
+}
+
+impl CircuitVersion {
+    /// The circuit version of this SP1 version.
+    pub fn current() -> Self {
+        Self { name: "current", groth16_vk: *GROTH16_VK_BYTES, plonk_vk: *PLONK_VK_BYTES }
+    }
+
+    /// The circuit version of the upstream SP1 release whose proofs are also accepted.
+    #[cfg(feature = "upstream")]
+    pub fn upstream() -> Self {
+        Self {
+            name: "upstream",
+            groth16_vk: *crate::UPSTREAM_GROTH16_VK_BYTES,
+            plonk_vk: *crate::UPSTREAM_PLONK_VK_BYTES,
+        }
+    }
+
+    /// The circuit versions embedded in this verifier: the current one, followed by the upstream
+    /// one with the `upstream` feature. The versions of other releases are built from their
+    /// verifying keys.
+    pub fn known() -> Vec<Self> {
+        #[allow(unused_mut)]
+        let mut versions = alloc::vec![Self::current()];
+        #[cfg(feature = "upstream")]
+        versions.push(Self::upstream());
+        versions
+    }
+
+    /// Finds the version whose Groth16 verifying key a proof was generated for.
+    pub fn of_groth16_proof<'a>(proof: &[u8], versions: &'a [Self]) -> Option<&'a Self> {
+        let prefix = proof.get(..4)?;
+        versions.iter().find(|version| vk_hash(version.groth16_vk) == prefix)
+    }
+
+    /// Finds the version whose Plonk verifying key a proof was generated for.
+    pub fn of_plonk_proof<'a>(proof: &[u8], versions: &'a [Self]) -> Option<&'a Self> {
+        let prefix = proof.get(..4)?;
+        versions.iter().find(|version| vk_hash(version.plonk_vk) == prefix)
+    }
+}
+
+/// The first 4 bytes of the hash of a verifying key, which SP1 prepends to its proofs.
+fn vk_hash(vk: &[u8]) -> [u8; 4] {
+    Sha256::digest(vk)[..4].try_into().unwrap()
+}
@@ -0,0 +1,49 @@
+//! Growable collections tuned for the zkVM's memory model.
+//!
+//! Growing a standard [`alloc::vec::Vec`] past its capacity allocates a fresh buffer and copies
+//! the elements over with a byte loop, which costs several cycles per word in hot guest paths. The
+//! types in this module grow in powers of two and, since the zkVM allocator never frees memory,
+//! try to extend the most recent allocation in place before relocating anything. When a
+//! relocation is unavoidable and the `memcopy` feature is enabled, it is done with the
+//! `MEMCPY_64`/`MEMCPY_32` precompiles.
+//!
+//! In-place growth assumes the program uses the default [`SimpleAlloc`](crate::heap::SimpleAlloc)
+//! global allocator.
+
+mod string;
+mod vec;
+
+pub use string::String;
+pub use vec::Vec;
+
+/// The smallest buffer, in bytes, that a collection allocates once it holds an element.
+const MIN_ALLOCATION_BYTES: usize = 64;
+
+/// Copies `bytes` bytes from `src` to `dst`, which must not overlap.
+///
+/// # Safety
+///
+/// `src` must be valid for reads and `dst` must be valid for writes of `bytes` bytes.
+unsafe fn relocate(src: *const u8, dst: *mut u8, bytes: usize) {
+    #[cfg(all(target_os = "zkvm", feature = "memcopy"))]
+    if src as usize % 4 == 0 && dst as usize % 4 == 0 {
+        use crate::syscalls::{syscall_memcopy32, syscall_memcopy64};
+
+        let mut offset = 0;
+        while bytes - offset >= 64 {
+            syscall_memcopy64(
+                src.add(offset) as *const [u32; 16],
+                dst.add(offset) as *mut [u32; 16],
+            );
+            offset += 64;
+        }
+        if bytes - offset >= 32 {
+            syscall_memcopy32(src.add(offset) as *const [u32; 8], dst.add(offset) as *mut [u32; 8]);
+            offset += 32;
+        }
+        core::ptr::copy_nonoverlapping(src.add(offset), dst.add(offset), bytes - offset);
+        return;
+    }
+
+    core::ptr::copy_nonoverlapping(src, dst, bytes);
+}
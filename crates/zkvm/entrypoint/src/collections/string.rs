@@ -0,0 +1,169 @@
+use core::{fmt, ops::Deref};
+
+use super::Vec;
+
+/// A growable UTF-8 string backed by a [`Vec<u8>`](super::Vec).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct String {
+    bytes: Vec<u8>,
+}
+
+impl String {
+    /// Creates an empty string without allocating.
+    pub const fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    /// Creates an empty string with room for at least `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { bytes: Vec::with_capacity(capacity) }
+    }
+
+    /// Returns the length of the string in bytes.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the number of bytes the string can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// Makes room for at least `additional` more bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        self.bytes.reserve(additional);
+    }
+
+    /// Appends `ch` to the end of the string.
+    pub fn push(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        self.push_str(ch.encode_utf8(&mut buf));
+    }
+
+    /// Appends `s` to the end of the string.
+    pub fn push_str(&mut self, s: &str) {
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    /// Removes the last character and returns it, or `None` if the string is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.bytes.truncate(self.len() - ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Removes all characters, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Returns the string as a `&str`.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The bytes are only ever written from `&str`s.
+        unsafe { core::str::from_utf8_unchecked(&self.bytes) }
+    }
+
+    /// Returns the string as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Converts the string into a standard [`alloc::string::String`] without copying.
+    pub fn into_string(self) -> alloc::string::String {
+        // SAFETY: The bytes are only ever written from `&str`s.
+        unsafe { alloc::string::String::from_utf8_unchecked(self.bytes.into_vec()) }
+    }
+}
+
+impl Deref for String {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Write for String {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, ch: char) -> fmt::Result {
+        self.push(ch);
+        Ok(())
+    }
+}
+
+impl PartialEq<str> for String {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for String {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<&str> for String {
+    fn from(s: &str) -> Self {
+        let mut string = Self::new();
+        string.push_str(s);
+        string
+    }
+}
+
+impl From<alloc::string::String> for String {
+    fn from(s: alloc::string::String) -> Self {
+        Self { bytes: s.into_bytes().into() }
+    }
+}
+
+impl From<String> for alloc::string::String {
+    fn from(s: String) -> Self {
+        s.into_string()
+    }
+}
+
+impl Extend<char> for String {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            self.push(ch);
+        }
+    }
+}
+
+impl<'a> Extend<&'a str> for String {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl FromIterator<char> for String {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut string = Self::new();
+        string.extend(iter);
+        string
+    }
+}
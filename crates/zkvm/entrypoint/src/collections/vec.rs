@@ -0,0 +1,251 @@
+use core::{
+    fmt, mem,
+    ops::{Deref, DerefMut},
+};
+
+use super::{relocate, MIN_ALLOCATION_BYTES};
+
+/// A contiguous growable array with power-of-two growth.
+///
+/// This is a thin wrapper around [`alloc::vec::Vec`] that takes over growth: see the
+/// [module documentation](super) for how it differs. Anything not covered by the methods below is
+/// reachable through the slice it dereferences to, or by converting it with [`Vec::into_vec`].
+pub struct Vec<T> {
+    inner: alloc::vec::Vec<T>,
+}
+
+impl<T> Vec<T> {
+    /// Creates an empty vector without allocating.
+    pub const fn new() -> Self {
+        Self { inner: alloc::vec::Vec::new() }
+    }
+
+    /// Creates an empty vector with room for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut vec = Self::new();
+        vec.reserve(capacity);
+        vec
+    }
+
+    /// Returns the number of elements in the vector.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns whether the vector holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the number of elements the vector can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Makes room for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.inner.len().checked_add(additional).expect("capacity overflow");
+        if required > self.inner.capacity() {
+            self.grow(required);
+        }
+    }
+
+    /// Appends `value` to the end of the vector.
+    pub fn push(&mut self, value: T) {
+        self.reserve(1);
+        self.inner.push(value);
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it to the right.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.reserve(1);
+        self.inner.insert(index, value);
+    }
+
+    /// Removes and returns the element at `index`, shifting the elements after it to the left.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.inner.remove(index)
+    }
+
+    /// Shortens the vector to `len` elements, dropping the rest.
+    pub fn truncate(&mut self, len: usize) {
+        self.inner.truncate(len);
+    }
+
+    /// Removes all elements, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Returns the elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.inner
+    }
+
+    /// Returns the elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+
+    /// Converts the vector into a standard [`alloc::vec::Vec`] without copying.
+    pub fn into_vec(self) -> alloc::vec::Vec<T> {
+        self.inner
+    }
+
+    /// Grows the buffer so that it holds at least `required` elements.
+    fn grow(&mut self, required: usize) {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            self.inner.reserve(required - self.inner.len());
+            return;
+        }
+
+        let capacity = required.next_power_of_two().max((MIN_ALLOCATION_BYTES / size).max(1));
+
+        #[cfg(target_os = "zkvm")]
+        if self.inner.capacity() > 0 {
+            let ptr = self.inner.as_mut_ptr() as *mut u8;
+            let old_bytes = self.inner.capacity() * size;
+            let bytes = capacity.checked_mul(size).expect("capacity overflow");
+            if unsafe { crate::syscalls::sys_alloc_extend(ptr, old_bytes, bytes) } {
+                let mut inner = mem::ManuallyDrop::new(mem::take(&mut self.inner));
+                // SAFETY: The buffer was extended in place to hold `capacity` elements.
+                self.inner = unsafe {
+                    alloc::vec::Vec::from_raw_parts(inner.as_mut_ptr(), inner.len(), capacity)
+                };
+                return;
+            }
+        }
+
+        let mut buffer = alloc::vec::Vec::with_capacity(capacity);
+        let len = self.inner.len();
+        // SAFETY: `buffer` has room for `len` elements, and the elements are moved out of
+        // `self.inner` by setting its length to zero.
+        unsafe {
+            relocate(self.inner.as_ptr() as *const u8, buffer.as_mut_ptr() as *mut u8, len * size);
+            buffer.set_len(len);
+            self.inner.set_len(0);
+        }
+        self.inner = buffer;
+    }
+}
+
+impl<T: Clone> Vec<T> {
+    /// Appends a copy of every element of `other` to the end of the vector.
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.reserve(other.len());
+        self.inner.extend_from_slice(other);
+    }
+}
+
+impl<T> Default for Vec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for Vec<T> {
+    fn clone(&self) -> Self {
+        let mut vec = Self::with_capacity(self.len());
+        vec.extend_from_slice(self);
+        vec
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Vec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Vec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq> Eq for Vec<T> {}
+
+impl<T> Deref for Vec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for Vec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+}
+
+impl<T> From<alloc::vec::Vec<T>> for Vec<T> {
+    fn from(inner: alloc::vec::Vec<T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> From<Vec<T>> for alloc::vec::Vec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.inner
+    }
+}
+
+impl<T: Clone> From<&[T]> for Vec<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut vec = Self::new();
+        vec.extend_from_slice(slice);
+        vec
+    }
+}
+
+impl<T> Extend<T> for Vec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Vec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T> IntoIterator for Vec<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vec<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
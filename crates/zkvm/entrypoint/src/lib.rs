@@ -1,8 +1,15 @@
 extern crate alloc;
 
+pub mod collections;
 pub mod heap;
+pub mod secure;
 pub mod syscalls;
 
+#[cfg(feature = "lib")]
+pub mod env {
+    pub use sp1_lib::env::*;
+}
+
 #[cfg(feature = "lib")]
 pub mod io {
     pub use sp1_lib::io::*;
@@ -16,6 +23,20 @@ pub mod lib {
 #[cfg(all(target_os = "zkvm", feature = "libm"))]
 mod libm;
 
+/// Halts the program with the given exit code, which is committed to in the public values of the
+/// proof. See [`syscalls::syscall_exit`].
+pub fn exit(exit_code: u32) -> ! {
+    syscalls::syscall_exit(exit_code)
+}
+
+/// The version of the syscall ABI the programs are built against.
+///
+/// It is recorded in the `.sp1.abi` section of the ELF of the programs, and checked by the
+/// executor when it loads them, so that a program using syscalls unknown to an older executor
+/// fails to load rather than misbehaves. It must be bumped along with `SYSCALL_ABI_VERSION` in
+/// `sp1-core-executor` whenever the syscalls change.
+pub const SYSCALL_ABI_VERSION: u32 = 1;
+
 /// The number of 32 bit words that the public values digest is composed of.
 pub const PV_DIGEST_NUM_WORDS: usize = 8;
 pub const POSEIDON_NUM_WORDS: usize = 8;
@@ -38,6 +59,9 @@ mod zkvm {
 
     pub static mut PUBLIC_VALUES_HASHER: Option<Sha256> = None;
 
+    /// The number of bytes written to the public values stream so far.
+    pub static mut PUBLIC_VALUES_LEN: usize = 0;
+
     #[no_mangle]
     unsafe extern "C" fn __start() {
         {
@@ -73,6 +97,13 @@ mod zkvm {
         la sp, {0}
         lw sp, 0(sp)
         call __start;
+
+    # The version of the syscall ABI, which must match `SYSCALL_ABI_VERSION`. The section isn't
+    # allocated, so it is not part of the memory image of the program.
+    .pushsection .sp1.abi, "", @progbits;
+    .balign 4;
+    .word 1;
+    .popsection;
     "#,
         sym STACK_TOP
     );
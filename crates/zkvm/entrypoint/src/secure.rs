@@ -0,0 +1,46 @@
+//! Helpers for keeping secret guest data out of the final memory state.
+//!
+//! Secrets provided through hints (e.g. private keys) live in guest memory until the program
+//! halts, so by default they show up in the memory finalize events of the execution record, which
+//! may be shipped to a remote prover. Regions registered with [`zeroize_on_halt`] are overwritten
+//! with zeroes by the guest right before it halts, and the executor refuses to finish execution if
+//! any of them still holds a non-zero byte.
+//!
+//! Note that the initial value of hinted memory is still part of the memory initialize events, as
+//! it is needed for the memory argument.
+
+use alloc::vec::Vec;
+
+/// The regions registered with [`zeroize_on_halt`], as `(ptr, len)` pairs.
+static mut ZEROIZE_REGIONS: Vec<(usize, usize)> = Vec::new();
+
+/// Declares `region` as secret, so that it is overwritten with zeroes before the program halts.
+///
+/// The memory backing `region` is never reclaimed by the zkVM allocator, so it is fine for
+/// `region` to be dropped before the program halts.
+pub fn zeroize_on_halt(region: &mut [u8]) {
+    if region.is_empty() {
+        return;
+    }
+
+    #[cfg(target_os = "zkvm")]
+    crate::syscalls::syscall_zeroize_on_halt(region.as_ptr(), region.len());
+
+    let regions = unsafe { &mut *core::ptr::addr_of_mut!(ZEROIZE_REGIONS) };
+    regions.push((region.as_mut_ptr() as usize, region.len()));
+}
+
+/// Overwrites every region registered with [`zeroize_on_halt`] with zeroes.
+///
+/// This is called by `syscall_halt`, and should not need to be called directly.
+#[allow(dead_code)]
+pub(crate) unsafe fn zeroize_registered_regions() {
+    let regions = core::mem::take(&mut *core::ptr::addr_of_mut!(ZEROIZE_REGIONS));
+    for (ptr, len) in regions {
+        let ptr = ptr as *mut u8;
+        for i in 0..len {
+            // Volatile writes so that the compiler cannot elide the stores.
+            core::ptr::write_volatile(ptr.add(i), 0);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Applies an AES encryption round to the state.
+///
+/// The round is `SubBytes`, `ShiftRows`, `MixColumns` and `AddRoundKey`. The result is written
+/// over the state.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `round_key` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes_enc_round(state: *mut [u32; 4], round_key: *const [u32; 4]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::AES_ENC_ROUND,
+            in("a0") state,
+            in("a1") round_key,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Applies the final AES encryption round to the state.
+///
+/// The round is `SubBytes`, `ShiftRows` and `AddRoundKey`. The result is written over the state.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `round_key` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes_enc_last_round(state: *mut [u32; 4], round_key: *const [u32; 4]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::AES_ENC_LAST_ROUND,
+            in("a0") state,
+            in("a1") round_key,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,27 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Reduces a 512-bit value modulo a 256-bit modulus.
+///
+/// The result is written over the low half of `x` and the high half of `x` is cleared.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `m` are valid pointers to data that is aligned along a four
+/// byte boundary. The modulus must be greater than one.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_barrett_reduce(x: *mut [u32; 16], m: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BARRETT_REDUCE,
+            in("a0") x,
+            in("a1") m,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -53,27 +53,32 @@ pub extern "C" fn syscall_bls12381_double(p: *mut [u32; 24]) {
 /// The first half of the input array should contain the X coordinate. The second half of the input
 /// array will be overwritten with the Y coordinate.
 ///
+/// Returns 0 on success. Returns 1 if there is no point with the given X coordinate, in which case
+/// the second half of the input array is left unchanged.
+///
 /// ### Safety
 ///
 /// The caller must ensure that `point` is valid pointer to data that is aligned along a four byte
 /// boundary.
 #[allow(unused_variables)]
 #[no_mangle]
-pub extern "C" fn syscall_bls12381_decompress(point: &mut [u8; 96], sign_bit: bool) {
+pub extern "C" fn syscall_bls12381_decompress(point: &mut [u8; 96], sign_bit: bool) -> u32 {
     #[cfg(target_os = "zkvm")]
     {
+        let status;
         // Memory system/FpOps are little endian so we'll just flip the whole array before/after
         point.reverse();
         let p = point.as_mut_ptr();
         unsafe {
             asm!(
                 "ecall",
-                in("t0") crate::syscalls::BLS12381_DECOMPRESS,
+                inlateout("t0") crate::syscalls::BLS12381_DECOMPRESS => status,
                 in("a0") p,
                 in("a1") sign_bit as u8,
             );
         }
         point.reverse();
+        status
     }
 
     #[cfg(not(target_os = "zkvm"))]
@@ -50,3 +50,187 @@ pub extern "C" fn syscall_bn254_double(p: *mut [u32; 16]) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Decompresses a compressed Bn254 G1 point.
+///
+/// The input array should be 64 bytes long, with the first 32 bytes containing the X coordinate in
+/// big-endian format. The `sign_bit` selects the Y coordinate that is lexicographically largest, as
+/// in gnark. The second half of the input will be overwritten with the Y coordinate of the
+/// decompressed point in big-endian format.
+///
+/// Returns 0 on success. Returns 1 if there is no point with the given X coordinate, in which case
+/// the second half of the input array is left unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `point` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_decompress(point: &mut [u8; 64], sign_bit: bool) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        // Memory system/FpOps are little endian so we'll just flip the whole array before/after
+        point.reverse();
+        let p = point.as_mut_ptr();
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::BN254_DECOMPRESS => status,
+                in("a0") p,
+                in("a1") sign_bit as u8,
+            );
+        }
+        point.reverse();
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Decompresses a compressed Bn254 G2 point.
+///
+/// The input array should be 128 bytes long, with the first 64 bytes containing the X coordinate
+/// `x0 + x1 * u` as `x1 || x0` in big-endian format, as in gnark. The `sign_bit` selects the Y
+/// coordinate that is lexicographically largest. The second half of the input will be overwritten
+/// with the Y coordinate of the decompressed point in the same format.
+///
+/// Returns 0 on success. Returns 1 if there is no point with the given X coordinate, in which case
+/// the second half of the input array is left unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `point` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_g2_decompress(point: &mut [u8; 128], sign_bit: bool) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        // Flipping the whole array turns `x1 || x0` in big-endian into `x0 || x1` in little-endian.
+        point.reverse();
+        let p = point.as_mut_ptr();
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::BN254_G2_DECOMPRESS => status,
+                in("a0") p,
+                in("a1") sign_bit as u8,
+            );
+        }
+        point.reverse();
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Converts a Bn254 scalar field element to Montgomery form.
+///
+/// The result is stored in place.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_scalar_to_mont(x: *mut [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_SCALAR_TO_MONT,
+            in("a0") x,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Converts a Bn254 scalar field element out of Montgomery form.
+///
+/// The result is stored in place.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_scalar_from_mont(x: *mut [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_SCALAR_FROM_MONT,
+            in("a0") x,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Adds the inner product of an array of Bn254 scalar field elements to `x`.
+///
+/// `pairs` points to the number of pairs, between 1 and 32, followed by the `(a, b)` pairs, each
+/// element taking 8 words. The result `x + sum(a_i * b_i)` is written over `x`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `pairs` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_inner_product(x: *mut [u32; 8], pairs: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_INNER_PRODUCT,
+            in("a0") x,
+            in("a1") pairs,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Folds an array of Bn254 scalar field elements into the state of the random-point check of an
+/// NTT.
+///
+/// `state` holds the point `r`, the root of unity `w` of the domain, the powers `r^i` and `w^i` of
+/// the index of the first pair, and the sums `sum(c_i * r^i)` and `sum(y_i * w^i / (r - w^i))`.
+/// `pairs` points to the number of pairs, between 1 and 32, followed by the `(c, y)` pairs of
+/// coefficients and evaluations, each element taking 8 words. Every pair is added to the sums,
+/// and the powers are advanced past the pairs.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `pairs` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fr_ntt(state: *mut [u32; 48], pairs: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::FR_NTT,
+            in("a0") state,
+            in("a1") pairs,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
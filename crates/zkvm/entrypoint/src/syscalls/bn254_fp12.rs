@@ -0,0 +1,170 @@
+//! BN254 degree 12 field arithmetic built from the fptower precompiles.
+//!
+//! The field is the tower `Fp12 = Fp6[w] / (w^2 - v)`, `Fp6 = Fp2[v] / (v^3 - (9 + u))` and
+//! `Fp2 = Fp[u] / (u^2 + 1)`. An element is the concatenation of its coefficients, lowest degree
+//! first, each base field element being 8 little-endian words as for `BN254_FP2_MUL` and
+//! `BN254_FP6_MUL`.
+
+use crate::syscalls::{
+    syscall_bn254_fp2_addmod, syscall_bn254_fp2_mulmod, syscall_bn254_fp2_submod,
+    syscall_bn254_fp6_mulmod, syscall_bn254_fp_addmod, syscall_bn254_fp_mulmod,
+    syscall_bn254_fp_submod,
+};
+
+type Fp = [u32; 8];
+type Fp2 = [Fp; 2];
+type Fp6 = [Fp2; 3];
+type Fp12 = [Fp6; 2];
+
+/// The cubic non-residue `9 + u`.
+const XI: Fp2 = [[9, 0, 0, 0, 0, 0, 0, 0], [1, 0, 0, 0, 0, 0, 0, 0]];
+
+/// The exponent `p - 2` of the inverse in the base field.
+const MODULUS_MINUS_TWO: Fp = [
+    0xd87cfd45, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+fn fp_op(op: extern "C" fn(*mut u32, *const u32), a: &Fp, b: &Fp) -> Fp {
+    let mut result = *a;
+    op(result.as_mut_ptr(), b.as_ptr());
+    result
+}
+
+/// Inverts a base field element by exponentiation, mapping zero to zero.
+fn fp_inverse(a: &Fp) -> Fp {
+    let mut result: Fp = [1, 0, 0, 0, 0, 0, 0, 0];
+    for bit in (0..256).rev() {
+        result = fp_op(syscall_bn254_fp_mulmod, &result, &result);
+        if (MODULUS_MINUS_TWO[bit / 32] >> (bit % 32)) & 1 == 1 {
+            result = fp_op(syscall_bn254_fp_mulmod, &result, a);
+        }
+    }
+    result
+}
+
+fn fp2_op(op: extern "C" fn(*mut u32, *const u32), a: &Fp2, b: &Fp2) -> Fp2 {
+    let mut result = *a;
+    op(result.as_mut_ptr().cast(), b.as_ptr().cast());
+    result
+}
+
+fn fp2_add(a: &Fp2, b: &Fp2) -> Fp2 {
+    fp2_op(syscall_bn254_fp2_addmod, a, b)
+}
+
+fn fp2_sub(a: &Fp2, b: &Fp2) -> Fp2 {
+    fp2_op(syscall_bn254_fp2_submod, a, b)
+}
+
+fn fp2_mul(a: &Fp2, b: &Fp2) -> Fp2 {
+    fp2_op(syscall_bn254_fp2_mulmod, a, b)
+}
+
+/// Inverts a degree 2 field element as `(a0 - a1 * u) / (a0^2 + a1^2)`.
+fn fp2_inverse(a: &Fp2) -> Fp2 {
+    let norm = fp_op(
+        syscall_bn254_fp_addmod,
+        &fp_op(syscall_bn254_fp_mulmod, &a[0], &a[0]),
+        &fp_op(syscall_bn254_fp_mulmod, &a[1], &a[1]),
+    );
+    let norm_inv = fp_inverse(&norm);
+    let neg_a1 = fp_op(syscall_bn254_fp_submod, &[0; 8], &a[1]);
+    [
+        fp_op(syscall_bn254_fp_mulmod, &a[0], &norm_inv),
+        fp_op(syscall_bn254_fp_mulmod, &neg_a1, &norm_inv),
+    ]
+}
+
+fn fp6_add(a: &Fp6, b: &Fp6) -> Fp6 {
+    core::array::from_fn(|i| fp2_add(&a[i], &b[i]))
+}
+
+fn fp6_sub(a: &Fp6, b: &Fp6) -> Fp6 {
+    core::array::from_fn(|i| fp2_sub(&a[i], &b[i]))
+}
+
+fn fp6_mul(a: &Fp6, b: &Fp6) -> Fp6 {
+    let mut result = *a;
+    syscall_bn254_fp6_mulmod(result.as_mut_ptr().cast(), b.as_ptr().cast());
+    result
+}
+
+/// Multiplies a degree 6 field element by `v`, mapping `(c0, c1, c2)` to `(xi * c2, c0, c1)`.
+fn fp6_mul_by_v(a: &Fp6) -> Fp6 {
+    [fp2_mul(&a[2], &XI), a[0], a[1]]
+}
+
+/// Inverts a degree 6 field element.
+fn fp6_inverse(a: &Fp6) -> Fp6 {
+    let c0 = fp2_sub(&fp2_mul(&a[0], &a[0]), &fp2_mul(&XI, &fp2_mul(&a[1], &a[2])));
+    let c1 = fp2_sub(&fp2_mul(&XI, &fp2_mul(&a[2], &a[2])), &fp2_mul(&a[0], &a[1]));
+    let c2 = fp2_sub(&fp2_mul(&a[1], &a[1]), &fp2_mul(&a[0], &a[2]));
+    let norm = fp2_add(
+        &fp2_mul(&a[0], &c0),
+        &fp2_mul(&XI, &fp2_add(&fp2_mul(&a[2], &c1), &fp2_mul(&a[1], &c2))),
+    );
+    let norm_inv = fp2_inverse(&norm);
+    [fp2_mul(&c0, &norm_inv), fp2_mul(&c1, &norm_inv), fp2_mul(&c2, &norm_inv)]
+}
+
+fn fp12_mul(a: &Fp12, b: &Fp12) -> Fp12 {
+    let t0 = fp6_mul(&a[0], &b[0]);
+    let t1 = fp6_mul(&a[1], &b[1]);
+    let t2 = fp6_mul(&fp6_add(&a[0], &a[1]), &fp6_add(&b[0], &b[1]));
+    [fp6_add(&t0, &fp6_mul_by_v(&t1)), fp6_sub(&fp6_sub(&t2, &t0), &t1)]
+}
+
+fn fp12_square(a: &Fp12) -> Fp12 {
+    // (a0 + a1 * w)^2 = ((a0 + a1) * (a0 + v * a1) - t - v * t) + 2 * t * w with t = a0 * a1.
+    let t = fp6_mul(&a[0], &a[1]);
+    let s = fp6_mul(&fp6_add(&a[0], &a[1]), &fp6_add(&a[0], &fp6_mul_by_v(&a[1])));
+    [fp6_sub(&fp6_sub(&s, &t), &fp6_mul_by_v(&t)), fp6_add(&t, &t)]
+}
+
+fn fp12_inverse(a: &Fp12) -> Fp12 {
+    // (a0 + a1 * w)^-1 = (a0 - a1 * w) / (a0^2 - v * a1^2).
+    let norm = fp6_sub(&fp6_mul(&a[0], &a[0]), &fp6_mul_by_v(&fp6_mul(&a[1], &a[1])));
+    let norm_inv = fp6_inverse(&norm);
+    [fp6_mul(&a[0], &norm_inv), fp6_sub(&[[[0; 8]; 2]; 3], &fp6_mul(&a[1], &norm_inv))]
+}
+
+/// BN254 Fp12 multiplication operation.
+///
+/// The result is written over the first input.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to 96 words each.
+#[no_mangle]
+pub unsafe extern "C" fn syscall_bn254_fp12_mul(x: *mut u32, y: *const u32) {
+    // Copy y first, since x and y could be the same.
+    let y = *y.cast::<Fp12>();
+    let x = &mut *x.cast::<Fp12>();
+    *x = fp12_mul(x, &y);
+}
+
+/// BN254 Fp12 squaring operation.
+///
+/// The result is written over the input.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` is a valid pointer to 96 words.
+#[no_mangle]
+pub unsafe extern "C" fn syscall_bn254_fp12_square(x: *mut u32) {
+    let x = &mut *x.cast::<Fp12>();
+    *x = fp12_square(x);
+}
+
+/// BN254 Fp12 inversion operation.
+///
+/// The result is written over the input. Zero is mapped to zero.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` is a valid pointer to 96 words.
+#[no_mangle]
+pub unsafe extern "C" fn syscall_bn254_fp12_inverse(x: *mut u32) {
+    let x = &mut *x.cast::<Fp12>();
+    *x = fp12_inverse(x);
+}
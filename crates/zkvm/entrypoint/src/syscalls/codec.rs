@@ -0,0 +1,62 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Decodes 16 hexadecimal digits into 8 bytes.
+///
+/// Both lower and upper case digits are accepted. Returns 0 on success. Returns 1 if a character
+/// is not a digit, in which case the output is left unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_hex_decode(output: *mut [u32; 2], input: *const [u32; 4]) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::HEX_DECODE => status,
+                in("a0") output,
+                in("a1") input,
+            );
+        }
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Decodes 16 characters of the standard base64 alphabet into 12 bytes.
+///
+/// Padding is not accepted. Returns 0 on success. Returns 1 if a character is not in the
+/// alphabet, in which case the output is left unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_base64_decode(output: *mut [u32; 3], input: *const [u32; 4]) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::BASE64_DECODE => status,
+                in("a0") output,
+                in("a1") input,
+            );
+        }
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
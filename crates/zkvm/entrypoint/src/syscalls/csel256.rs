@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Selects between the 32-byte values `a` and `b` without branching on `cond`.
+///
+/// The value of `a` is kept if `cond` is 1, and replaced with the value of `b` if `cond` is 0. Any
+/// other condition halts the program.
+///
+/// ### Safety
+///
+/// The caller must ensure that `a` and `b` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_csel256(a: *mut [u32; 8], b: *const [u32; 8], cond: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::CSEL256,
+            in("a0") a,
+            in("a1") b,
+            in("a2") cond,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
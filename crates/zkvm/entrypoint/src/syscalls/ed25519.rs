@@ -32,26 +32,65 @@ pub extern "C" fn syscall_ed_add(p: *mut [u32; 16], q: *const [u32; 16]) {
 /// the sign bit. The first half of the input array will be overwritten with the decompressed point,
 /// and the sign bit will be removed.
 ///
+/// Returns 0 on success. Returns 1 if there is no point with the given Y coordinate, in which case
+/// the first half of the input array is left unchanged.
+///
 /// ### Safety
 ///
 /// The caller must ensure that `point` is valid pointer to data that is aligned along a four byte
 /// boundary.
 #[allow(unused_variables)]
 #[no_mangle]
-pub extern "C" fn syscall_ed_decompress(point: &mut [u8; 64]) {
+pub extern "C" fn syscall_ed_decompress(point: &mut [u8; 64]) -> u32 {
     #[cfg(target_os = "zkvm")]
     {
+        let status;
         let sign = point[63] >> 7;
         point[63] &= 0b0111_1111;
         let p = point.as_mut_ptr() as *mut u8;
         unsafe {
             asm!(
                 "ecall",
-                in("t0") crate::syscalls::ED_DECOMPRESS,
+                inlateout("t0") crate::syscalls::ED_DECOMPRESS => status,
                 in("a0") p,
                 in("a1") sign
             );
         }
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Checks the verification equation of an Ed25519 signature, that `[S]B - [k]A` compresses to `R`.
+///
+/// The public key `A` is given by its affine coordinates. The signature starts with the 64-byte
+/// signature `R || S`, followed by the 64-byte SHA-512 digest of `R`, the compressed public key and
+/// the message, which is reduced into `k`. `S` must be smaller than the order of the prime
+/// subgroup.
+///
+/// Returns 0 if the equation holds, and 1 otherwise.
+///
+/// ### Safety
+///
+/// The caller must ensure that `key` and `sig` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_ed25519_verify(key: *const [u32; 16], sig: *const [u32; 32]) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::ED25519_VERIFY => status,
+                in("a0") key,
+                in("a1") sig
+            );
+        }
+        status
     }
 
     #[cfg(not(target_os = "zkvm"))]
@@ -0,0 +1,92 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Adds two doubles, given by their bits.
+///
+/// The result is written over the first input. Returns 0 on success. Returns 1 if an operand or
+/// the result is not a normal number, in which case the first input is left unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_f64_add(x: *mut u64, y: *const u64) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::F64_ADD => status,
+                in("a0") x,
+                in("a1") y,
+            );
+        }
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Multiplies two doubles, given by their bits.
+///
+/// The result is written over the first input. Returns 0 on success. Returns 1 if an operand or
+/// the result is not a normal number, in which case the first input is left unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_f64_mul(x: *mut u64, y: *const u64) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::F64_MUL => status,
+                in("a0") x,
+                in("a1") y,
+            );
+        }
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Divides two doubles, given by their bits.
+///
+/// The result is written over the first input. Returns 0 on success. Returns 1 if an operand or
+/// the result is not a normal number, in which case the first input is left unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_f64_div(x: *mut u64, y: *const u64) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::F64_DIV => status,
+                in("a0") x,
+                in("a1") y,
+            );
+        }
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -13,13 +13,58 @@ cfg_if::cfg_if! {
     }
 }
 
+/// The value passed in `a1` to `HALT` to mark the exit code as chosen by the program. Without it,
+/// the executor treats a non-zero exit code as a failure, e.g. a panic.
+#[cfg(target_os = "zkvm")]
+const HALT_TYPED_EXIT: u32 = 1;
+
+/// The file descriptor of the public values stream.
+#[cfg(target_os = "zkvm")]
+const FD_PUBLIC_VALUES: u32 = 3;
+
+/// The BabyBear modulus. Exit codes are committed to as field elements, so they must be below it.
+const BABYBEAR_MODULUS: u32 = 0x7800_0001;
+
 /// Halts the program with the given exit code.
 ///
-/// Before halting, the syscall will commit to the public values.
+/// Before halting, the syscall will commit to the public values. A non-zero exit code marks the
+/// execution as failed, and it cannot be proven.
 #[allow(unused_variables)]
 pub extern "C" fn syscall_halt(exit_code: u8) -> ! {
+    halt(exit_code as u32, false)
+}
+
+/// Halts the program with the given exit code, which is committed to in the public values of the
+/// proof.
+///
+/// Unlike [`syscall_halt`], a non-zero exit code does not mark the execution as failed, so it can
+/// be used to prove which of several outcomes the program ended with. The exit code must be less
+/// than the BabyBear modulus `2^31 - 2^27 + 1`; by convention, `1` is also used by panics.
+///
+/// The exit code is also written to the public values stream, as its last four bytes in big-endian
+/// order, so that the public values digest covers it. Plonk and Groth16 proofs only expose that
+/// digest, so this is how a verifier of such a proof reads the exit code.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_exit(exit_code: u32) -> ! {
+    assert!(exit_code < BABYBEAR_MODULUS, "exit code {exit_code} is out of range");
+
+    #[cfg(target_os = "zkvm")]
+    {
+        let exit_code = exit_code.to_be_bytes();
+        crate::syscalls::syscall_write(FD_PUBLIC_VALUES, exit_code.as_ptr(), exit_code.len());
+    }
+
+    halt(exit_code, true)
+}
+
+#[allow(unused_variables)]
+fn halt(exit_code: u32, typed: bool) -> ! {
     #[cfg(target_os = "zkvm")]
     unsafe {
+        // Wipe any secret regions first, so that they are zero in the final memory state.
+        crate::secure::zeroize_registered_regions();
+
         // When we halt, we retrieve the public values finalized digest.  This is the hash of all
         // the bytes written to the public values fd.
         let pv_digest_bytes =
@@ -54,7 +99,8 @@ pub extern "C" fn syscall_halt(exit_code: u8) -> ! {
         asm!(
             "ecall",
             in("t0") crate::syscalls::HALT,
-            in("a0") exit_code
+            in("a0") exit_code,
+            in("a1") if typed { HALT_TYPED_EXIT } else { 0 }
         );
         unreachable!()
     }
@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Computes an HMAC-SHA256 tag over a single block message.
+///
+/// `key` holds the key, zero padded to a block, and `buf` holds a 64-word message schedule followed
+/// by an 8-word SHA-256 state. The first 16 words of `buf` must hold the message, padded to a block
+/// as if it followed a 64-byte block. The tag is written to the last 8 words of `buf`, as SHA-256
+/// state words.
+///
+/// ### Safety
+///
+/// The caller must ensure that `key` and `buf` are valid pointers to data that is aligned along a
+/// four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_hmac_sha256(key: *const [u32; 16], buf: *mut [u32; 72]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::HMAC_SHA256,
+            in("a0") key,
+            in("a1") buf
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
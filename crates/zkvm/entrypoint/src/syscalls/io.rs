@@ -28,7 +28,33 @@ pub extern "C" fn syscall_write(fd: u32, write_buf: *const u8, nbytes: usize) {
             // version of this hash.
             if fd == FD_PUBLIC_VALUES {
                 let pi_slice: &[u8] = unsafe { core::slice::from_raw_parts(write_buf, nbytes) };
-                unsafe { zkvm::PUBLIC_VALUES_HASHER.as_mut().unwrap().update(pi_slice) };
+                unsafe {
+                    zkvm::PUBLIC_VALUES_HASHER.as_mut().unwrap().update(pi_slice);
+                    zkvm::PUBLIC_VALUES_LEN += nbytes;
+                }
+            }
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+/// Writes the digest of the bytes written to the public values stream so far to `digest`,
+/// returning their number.
+///
+/// The digest is the one the public values digest of the proof would be if the program halted
+/// now.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn sys_public_values_digest(digest: *mut [u8; 32]) -> usize {
+    cfg_if::cfg_if! {
+        if #[cfg(target_os = "zkvm")] {
+            use sha2::digest::FixedOutput;
+
+            unsafe {
+                let hasher = (*core::ptr::addr_of!(zkvm::PUBLIC_VALUES_HASHER)).clone().unwrap();
+                *digest = hasher.finalize_fixed().into();
+                zkvm::PUBLIC_VALUES_LEN
             }
         } else {
             unreachable!()
@@ -72,3 +98,42 @@ pub extern "C" fn syscall_hint_read(ptr: *mut u8, len: usize) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Maps the next element in the hint stream to `len` bytes of read-only memory at `ptr`.
+///
+/// The memory is loaded lazily on first access, so `ptr` must point to memory that has not been
+/// accessed yet, and must not be written to afterwards.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_hint_map(ptr: *const u8, len: usize) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::HINT_MAP,
+            in("a0") ptr,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Declares `len` bytes starting at `ptr` as secret, to be zeroized before the program halts.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_zeroize_on_halt(ptr: *const u8, len: usize) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::ZEROIZE_ON_HALT,
+            in("a0") ptr,
+            in("a1") len,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
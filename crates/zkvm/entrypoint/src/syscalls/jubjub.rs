@@ -0,0 +1,54 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Adds two Jubjub points.
+///
+/// The result is stored in the first point. Since the Edwards addition law is complete, `p` and `q`
+/// may point to the same point, in which case the point is doubled.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_jubjub_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::JUBJUB_ADD,
+            in("a0") p,
+            in("a1") q
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Adds two Baby Jubjub points in reduced twisted Edwards form.
+///
+/// The result is stored in the first point. Since the Edwards addition law is complete, `p` and `q`
+/// may point to the same point, in which case the point is doubled.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_baby_jubjub_add(p: *mut [u32; 16], q: *const [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BABY_JUBJUB_ADD,
+            in("a0") p,
+            in("a1") q
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
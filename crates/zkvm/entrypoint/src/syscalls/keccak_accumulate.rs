@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Absorbs a 136-byte block into the Keccak-256 accumulator identified by `acc`.
+///
+/// The first word of `acc` holds the flags for this block: bit 0 marks the first block of the
+/// hash and bit 1 marks the final (already padded) block. On the final block, the digest is
+/// written to the remaining 8 words of `acc`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `block` and `acc` are valid pointers to data that is aligned along
+/// a four byte boundary, and that `acc` does not move between the first and the final block.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_keccak_accumulate(block: *const [u32; 34], acc: *mut [u32; 9]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::KECCAK_ACCUMULATE,
+            in("a0") block,
+            in("a1") acc
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,109 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Absorbs a 136-byte block into the Keccak-256 sponge state, then permutes the state.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `block` are valid pointers to data that is aligned
+/// along a four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_keccak_absorb(state: *mut [u64; 25], block: *const [u32; 34]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::KECCAK_ABSORB,
+            in("a0") state,
+            in("a1") block
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Pads and absorbs the tail of the input into the Keccak-256 sponge state, then permutes the
+/// state. The digest is the first 32 bytes of the state.
+///
+/// The first 34 words of `tail` hold the tail and the last word holds its length in bytes, which
+/// must be less than 136. The bytes of the block past the tail are ignored.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `tail` are valid pointers to data that is aligned along
+/// a four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_keccak_finalize(state: *mut [u64; 25], tail: *const [u32; 35]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::KECCAK_FINALIZE,
+            in("a0") state,
+            in("a1") tail
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Replaces the node held in the first 32 bytes of `state` with the root of a Keccak-256 Merkle
+/// path, using the rest of the state as scratch space.
+///
+/// `path` points to the depth of the path, between 1 and 8, and to the index of the node, whose
+/// `i`-th bit is set if the node is the right child at level `i`. They are followed by the sibling
+/// of every level from the bottom up, each taking 8 words. Every level hashes the concatenation of
+/// the left and the right child.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `path` are valid pointers to data that is aligned along
+/// a four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_keccak_merkle_root(state: *mut [u64; 25], path: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::KECCAK_MERKLE_ROOT,
+            in("a0") state,
+            in("a1") path
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Executes the Keccak-256 withdrawal root precompile, replacing the first leaf of `tree` with the
+/// root of the balanced Merkle tree over its leaves, using `state` as scratch space.
+///
+/// `tree` points to the depth of the tree, between 1 and 4, followed by its `2^depth` leaves, each
+/// taking 8 words. The leaves are overwritten with the nodes of the tree, level by level, and every
+/// node hashes the concatenation of its left and right children.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `tree` are valid pointers to data that is aligned along
+/// a four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_withdraw_root(state: *mut [u64; 25], tree: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::WITHDRAW_ROOT,
+            in("a0") state,
+            in("a1") tree
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,50 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Copies 32 bytes from `src` to `dst`.
+///
+/// Back-to-back calls copying contiguous chunks are proven together, as a single 64 byte copy.
+///
+/// ### Safety
+///
+/// The caller must ensure that `src` and `dst` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_memcopy32(src: *const [u32; 8], dst: *mut [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::MEMCPY_32,
+            in("a0") src,
+            in("a1") dst,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Copies 64 bytes from `src` to `dst`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `src` and `dst` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_memcopy64(src: *const [u32; 16], dst: *mut [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::MEMCPY_64,
+            in("a0") src,
+            in("a1") dst,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -15,6 +15,9 @@
 // Memory addresses must be lower than BabyBear prime.
 const MAX_MEMORY: usize = 0x78000000;
 
+/// Pointer to next heap address to use, or 0 if the heap has not yet been initialized.
+static mut HEAP_POS: usize = 0;
+
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub unsafe extern "C" fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u8 {
@@ -23,10 +26,6 @@ pub unsafe extern "C" fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u
         static _end: u8;
     }
 
-    // Pointer to next heap address to use, or 0 if the heap has not yet been
-    // initialized.
-    static mut HEAP_POS: usize = 0;
-
     // SAFETY: Single threaded, so nothing else can touch this while we're working.
     let mut heap_pos = unsafe { HEAP_POS };
 
@@ -49,3 +48,27 @@ pub unsafe extern "C" fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u
     unsafe { HEAP_POS = heap_pos };
     ptr
 }
+
+/// Grows the allocation at `ptr` from `bytes` to `new_bytes` without moving it.
+///
+/// This only succeeds if the allocation is the most recent one handed out by
+/// [`sys_alloc_aligned`], i.e. if it ends at the current heap position. Returns whether the
+/// allocation was grown.
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn sys_alloc_extend(ptr: *mut u8, bytes: usize, new_bytes: usize) -> bool {
+    // SAFETY: Single threaded, so nothing else can touch this while we're working.
+    let heap_pos = unsafe { HEAP_POS };
+    let start = ptr as usize;
+
+    if heap_pos == 0 || start.wrapping_add(bytes) != heap_pos || new_bytes < bytes {
+        return false;
+    }
+
+    match start.checked_add(new_bytes) {
+        Some(end) if end <= MAX_MEMORY => {
+            unsafe { HEAP_POS = end };
+            true
+        }
+        _ => false,
+    }
+}
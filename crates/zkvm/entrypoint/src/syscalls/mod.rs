@@ -1,39 +1,77 @@
+mod aes;
+mod barrett_reduce;
 mod bigint;
 mod bls12381;
 mod bn254;
+mod bn254_fp12;
+mod chacha20;
+mod codec;
+mod csel256;
 mod ed25519;
+mod f64;
 mod fptower;
 mod halt;
+mod hmac_sha256;
 mod io;
+mod jubjub;
+mod keccak_accumulate;
 mod keccak_permute;
+mod keccak_sponge;
+mod memcopy;
 mod memory;
+mod poseidon;
+mod rlp_scan;
+mod rsa;
 mod secp256k1;
 mod secp256r1;
+mod sha256_compress_blocks;
+mod sha512_compress;
 mod sha_compress;
 mod sha_extend;
 mod sys;
 mod u256x2048_mul;
+mod u256x4096_mul;
 mod uint256_mul;
+mod uint384_muladd;
 mod unconstrained;
 #[cfg(feature = "verify")]
 mod verify;
 
+pub use aes::*;
+pub use barrett_reduce::*;
 pub use bigint::*;
 pub use bls12381::*;
 pub use bn254::*;
+pub use bn254_fp12::*;
+pub use chacha20::*;
+pub use codec::*;
+pub use csel256::*;
 pub use ed25519::*;
+pub use f64::*;
 pub use fptower::*;
 pub use halt::*;
+pub use hmac_sha256::*;
 pub use io::*;
+pub use jubjub::*;
+pub use keccak_accumulate::*;
 pub use keccak_permute::*;
+pub use keccak_sponge::*;
+pub use memcopy::*;
 pub use memory::*;
+pub use poseidon::*;
+pub use rlp_scan::*;
+pub use rsa::*;
 pub use secp256k1::*;
 pub use secp256r1::*;
+pub use sha256_compress_blocks::*;
+pub use sha512_compress::*;
 pub use sha_compress::*;
 pub use sha_extend::*;
 pub use sys::*;
 pub use u256x2048_mul::*;
+pub use u256x4096_mul::*;
 pub use uint256_mul::*;
+pub use uint384_muladd::*;
 pub use unconstrained::*;
 #[cfg(feature = "verify")]
 pub use verify::*;
@@ -63,7 +101,10 @@ pub const SHA_COMPRESS: u32 = 0x00_01_01_06;
 pub const ED_ADD: u32 = 0x00_01_01_07;
 
 /// Executes `ED_DECOMPRESS`.
-pub const ED_DECOMPRESS: u32 = 0x00_00_01_08;
+pub const ED_DECOMPRESS: u32 = 0x01_00_01_08;
+
+/// Executes `ED25519_VERIFY`.
+pub const ED25519_VERIFY: u32 = 0x01_00_01_55;
 
 /// Executes `KECCAK_PERMUTE`.
 pub const KECCAK_PERMUTE: u32 = 0x00_01_01_09;
@@ -75,7 +116,10 @@ pub const SECP256K1_ADD: u32 = 0x00_01_01_0A;
 pub const SECP256K1_DOUBLE: u32 = 0x00_00_01_0B;
 
 /// Executes `K256_DECOMPRESS`.
-pub const SECP256K1_DECOMPRESS: u32 = 0x00_00_01_0C;
+pub const SECP256K1_DECOMPRESS: u32 = 0x01_00_01_0C;
+
+/// Executes `SECP256K1_ECRECOVER`.
+pub const SECP256K1_ECRECOVER: u32 = 0x01_03_01_53;
 
 /// Executes `SECP256R1_ADD`.
 pub const SECP256R1_ADD: u32 = 0x00_01_01_2C;
@@ -84,11 +128,116 @@ pub const SECP256R1_ADD: u32 = 0x00_01_01_2C;
 pub const SECP256R1_DOUBLE: u32 = 0x00_00_01_2D;
 
 /// Executes `SECP256R1_DECOMPRESS`.
-pub const SECP256R1_DECOMPRESS: u32 = 0x00_00_01_2E;
+pub const SECP256R1_DECOMPRESS: u32 = 0x01_00_01_2E;
 
 /// Executes `U256XU2048_MUL`.
 pub const U256XU2048_MUL: u32 = 0x00_01_01_2F;
 
+/// Executes `U256XU4096_MUL`.
+pub const U256XU4096_MUL: u32 = 0x00_01_01_34;
+
+/// Executes `BN254_SCALAR_TO_MONT`.
+pub const BN254_SCALAR_TO_MONT: u32 = 0x00_01_01_35;
+
+/// Executes `BN254_SCALAR_FROM_MONT`.
+pub const BN254_SCALAR_FROM_MONT: u32 = 0x00_01_01_36;
+
+/// Executes `BARRETT_REDUCE`.
+pub const BARRETT_REDUCE: u32 = 0x00_01_01_37;
+
+/// Executes `AES_ENC_ROUND`.
+pub const AES_ENC_ROUND: u32 = 0x00_01_01_38;
+
+/// Executes `AES_ENC_LAST_ROUND`.
+pub const AES_ENC_LAST_ROUND: u32 = 0x00_01_01_39;
+
+/// Executes `CHACHA20_BLOCK`.
+pub const CHACHA20_BLOCK: u32 = 0x00_01_01_3A;
+
+/// Executes `HMAC_SHA256`.
+pub const HMAC_SHA256: u32 = 0x00_CC_01_3B;
+
+/// Executes `KECCAK_ABSORB`.
+pub const KECCAK_ABSORB: u32 = 0x00_02_01_3C;
+
+/// Executes `KECCAK_FINALIZE`.
+pub const KECCAK_FINALIZE: u32 = 0x00_02_01_3D;
+
+/// Executes `SHA256_COMPRESS_BLOCKS`.
+pub const SHA256_COMPRESS_BLOCKS: u32 = 0x00_FF_01_3E;
+
+/// Executes `F64_ADD`.
+pub const F64_ADD: u32 = 0x01_01_01_3F;
+
+/// Executes `F64_MUL`.
+pub const F64_MUL: u32 = 0x01_01_01_40;
+
+/// Executes `F64_DIV`.
+pub const F64_DIV: u32 = 0x01_01_01_41;
+
+/// Executes `BN254_DECOMPRESS`.
+pub const BN254_DECOMPRESS: u32 = 0x01_00_01_42;
+
+/// Executes `BN254_G2_DECOMPRESS`.
+pub const BN254_G2_DECOMPRESS: u32 = 0x01_00_01_43;
+
+/// Executes `JUBJUB_ADD`.
+pub const JUBJUB_ADD: u32 = 0x00_01_01_44;
+
+/// Executes `BABY_JUBJUB_ADD`.
+pub const BABY_JUBJUB_ADD: u32 = 0x00_01_01_45;
+
+/// Executes `HEX_DECODE`.
+pub const HEX_DECODE: u32 = 0x01_01_01_46;
+
+/// Executes `BASE64_DECODE`.
+pub const BASE64_DECODE: u32 = 0x01_01_01_47;
+
+/// Executes the `BLS12381_FP6_MUL` precompile.
+pub const BLS12381_FP6_MUL: u32 = 0x00_01_01_48;
+
+/// Executes the `BN254_FP6_MUL` precompile.
+pub const BN254_FP6_MUL: u32 = 0x00_01_01_49;
+
+/// Executes the `BN254_INNER_PRODUCT` precompile.
+pub const BN254_INNER_PRODUCT: u32 = 0x00_01_01_4A;
+
+/// Executes the `MEMCPY_32` precompile.
+pub const MEMCPY_32: u32 = 0x00_01_01_4B;
+
+/// Executes the `MEMCPY_64` precompile.
+pub const MEMCPY_64: u32 = 0x00_01_01_4C;
+
+/// Executes the `POSEIDON_MERKLE_ROOT` precompile.
+pub const POSEIDON_MERKLE_ROOT: u32 = 0x00_01_01_4D;
+
+/// Executes the `KECCAK_MERKLE_ROOT` precompile.
+pub const KECCAK_MERKLE_ROOT: u32 = 0x00_18_01_4E;
+
+/// Executes the `WITHDRAW_ROOT` precompile.
+pub const WITHDRAW_ROOT: u32 = 0x00_3C_01_56;
+
+/// Executes the `CSEL256` precompile.
+pub const CSEL256: u32 = 0x00_01_01_57;
+
+/// Executes the `FR_NTT` precompile.
+pub const FR_NTT: u32 = 0x00_01_01_58;
+
+/// Executes the `RLP_SCAN` precompile.
+pub const RLP_SCAN: u32 = 0x00_20_01_59;
+
+/// Executes the `RSA2048_MODEXP` precompile.
+pub const RSA2048_MODEXP: u32 = 0x00_01_01_4F;
+
+/// Executes the `RSA4096_MODEXP` precompile.
+pub const RSA4096_MODEXP: u32 = 0x00_01_01_50;
+
+/// Executes the `ZKTRIE_HASH_NODE` precompile.
+pub const ZKTRIE_HASH_NODE: u32 = 0x00_01_01_51;
+
+/// Executes `SHA512_COMPRESS`.
+pub const SHA512_COMPRESS: u32 = 0x00_01_01_54;
+
 /// Executes `BN254_ADD`.
 pub const BN254_ADD: u32 = 0x00_01_01_0E;
 
@@ -98,6 +247,9 @@ pub const BN254_DOUBLE: u32 = 0x00_00_01_0F;
 /// Executes the `COMMIT` precompile.
 pub const COMMIT: u32 = 0x00_00_00_10;
 
+/// Declares a secret region to be zeroized before halting.
+pub const ZEROIZE_ON_HALT: u32 = 0x00_00_00_11;
+
 /// Executes the `COMMIT_DEFERRED_PROOFS` precompile.
 pub const COMMIT_DEFERRED_PROOFS: u32 = 0x00_00_00_1A;
 
@@ -110,8 +262,11 @@ pub const HINT_LEN: u32 = 0x00_00_00_F0;
 /// Executes `HINT_READ`.
 pub const HINT_READ: u32 = 0x00_00_00_F1;
 
+/// Executes `HINT_MAP`.
+pub const HINT_MAP: u32 = 0x00_00_00_F2;
+
 /// Executes `BLS12381_DECOMPRESS`.
-pub const BLS12381_DECOMPRESS: u32 = 0x00_00_01_1C;
+pub const BLS12381_DECOMPRESS: u32 = 0x01_00_01_1C;
 
 /// Executes the `UINT256_MUL` precompile.
 pub const UINT256_MUL: u32 = 0x00_01_01_1D;
@@ -160,3 +315,24 @@ pub const BN254_FP2_MUL: u32 = 0x00_01_01_2B;
 
 /// Executes the `BN254_MULADD` precompile.
 pub const BN254_MULADD: u32 = 0x00_01_01_1F;
+
+/// Executes the `BN254_MULADD` precompile on big-endian operands.
+pub const BN254_MULADD_BE: u32 = 0x00_01_01_52;
+
+/// Executes the `UINT384_MULADD` precompile.
+pub const UINT384_MULADD: u32 = 0x00_01_01_32;
+
+/// Executes the `KECCAK_ACCUMULATE` precompile.
+pub const KECCAK_ACCUMULATE: u32 = 0x00_01_01_33;
+
+/// Reserved for an out-of-tree precompile registered with the executor.
+pub const PLUGIN_0: u32 = 0x00_01_01_E0;
+
+/// Reserved for an out-of-tree precompile registered with the executor.
+pub const PLUGIN_1: u32 = 0x00_01_01_E1;
+
+/// Reserved for an out-of-tree precompile registered with the executor.
+pub const PLUGIN_2: u32 = 0x00_01_01_E2;
+
+/// Reserved for an out-of-tree precompile registered with the executor.
+pub const PLUGIN_3: u32 = 0x00_01_01_E3;
@@ -0,0 +1,58 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Replaces `node` with the root of a Poseidon Merkle path over the Bn254 scalar field.
+///
+/// `path` points to the depth of the path, between 1 and 8, and to the index of the node, whose
+/// `i`-th bit is set if the node is the right child at level `i`. They are followed by the sibling
+/// and the domain of every level from the bottom up, each taking 8 words. Every level hashes the
+/// state `[domain, left, right]` with the Poseidon permutation used by zkTrie.
+///
+/// ### Safety
+///
+/// The caller must ensure that `node` and `path` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_poseidon_merkle_root(node: *mut [u32; 8], path: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::POSEIDON_MERKLE_ROOT,
+            in("a0") node,
+            in("a1") path,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Writes to `hash` the hash of a zkTrie node over the Bn254 scalar field.
+///
+/// `node` points to the type of the node, followed by its left and right child, each taking 8
+/// words. The type is either the leaf type 4, whose children are the node key and the value hash,
+/// or one of the branch types 6 to 9. The node hashes the state `[type, left, right]` with the
+/// Poseidon permutation used by zkTrie.
+///
+/// ### Safety
+///
+/// The caller must ensure that `hash` and `node` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_zktrie_hash_node(hash: *mut [u32; 8], node: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::ZKTRIE_HASH_NODE,
+            in("a0") hash,
+            in("a1") node,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,33 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Scans up to 16 items of an RLP payload, checking that their headers are canonical and that
+/// they end within the payload.
+///
+/// `state` holds the pointer to the payload, the length of the payload, the offset of the next
+/// item, and a count. The entry `i` of `table` is written with the offset of the payload of the
+/// `i`-th item scanned, and with its length, whose top byte is one for lists. The scan stops at the
+/// end of the payload, after which the offset of `state` is advanced past the items scanned, and
+/// the count is set to their number. Malformed items halt the program.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state`, `table` and the payload are valid pointers to data that
+/// is aligned along a four byte boundary, and that the payload is followed by at least 4 readable
+/// bytes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_rlp_scan(state: *mut [u32; 4], table: *mut [u32; 32]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::RLP_SCAN,
+            in("a0") state,
+            in("a1") table,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,52 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// RSA-2048 modular exponentiation operation.
+///
+/// Raises `x` to the exponent stored after the modulus in `params`, and writes the result over `x`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `params` are valid pointers to data that is aligned along a
+/// four byte boundary. The base must be smaller than the modulus.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_rsa2048_modexp(x: *mut [u32; 64], params: *const [u32; 65]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::RSA2048_MODEXP,
+            in("a0") x,
+            in("a1") params,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// RSA-4096 modular exponentiation operation.
+///
+/// Raises `x` to the exponent stored after the modulus in `params`, and writes the result over `x`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `params` are valid pointers to data that is aligned along a
+/// four byte boundary. The base must be smaller than the modulus.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_rsa4096_modexp(x: *mut [u32; 128], params: *const [u32; 129]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::RSA4096_MODEXP,
+            in("a0") x,
+            in("a1") params,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
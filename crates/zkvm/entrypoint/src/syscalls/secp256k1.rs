@@ -58,27 +58,68 @@ pub extern "C" fn syscall_secp256k1_double(p: *mut [u32; 16]) {
 /// big-endian format. The second half of the input will be overwritten with the Y coordinate of the
 /// decompressed point in big-endian format using the point's parity (is_odd).
 ///
+/// Returns 0 on success. Returns 1 if there is no point with the given X coordinate, in which case
+/// the second half of the input array is left unchanged.
+///
 /// ### Safety
 ///
 /// The caller must ensure that `point` is valid pointer to data that is aligned along a four byte
 /// boundary.
 #[allow(unused_variables)]
 #[no_mangle]
-pub extern "C" fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool) {
+pub extern "C" fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool) -> u32 {
     #[cfg(target_os = "zkvm")]
     {
+        let status;
         // Memory system/FpOps are little endian so we'll just flip the whole array before/after
         point.reverse();
         let p = point.as_mut_ptr();
         unsafe {
             asm!(
                 "ecall",
-                in("t0") crate::syscalls::SECP256K1_DECOMPRESS,
+                inlateout("t0") crate::syscalls::SECP256K1_DECOMPRESS => status,
                 in("a0") p,
                 in("a1") is_odd as u8
             );
         }
         point.reverse();
+        status
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Recovers the address of the Secp256k1 key that signed a hash, with the semantics of the EVM
+/// `ecrecover` precompile.
+///
+/// The input starts with the 128-byte input of the precompile: the hash, `v`, `r` and `s` as
+/// 32-byte big-endian integers. It is followed by a keccak state, which is overwritten to hash the
+/// public key. On success, the output is the address left-padded to 32 bytes.
+///
+/// Returns 0 on success. Returns 1 if the input is invalid, or if the key cannot be recovered by the
+/// syscall, in which case the address has to be recovered otherwise and the output is left
+/// unchanged.
+///
+/// ### Safety
+///
+/// The caller must ensure that `out` and `input` are valid pointers to data that is aligned along a
+/// four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_ecrecover(out: *mut [u32; 8], input: *mut [u32; 82]) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let status;
+        unsafe {
+            asm!(
+                "ecall",
+                inlateout("t0") crate::syscalls::SECP256K1_ECRECOVER => status,
+                in("a0") out,
+                in("a1") input
+            );
+        }
+        status
     }
 
     #[cfg(not(target_os = "zkvm"))]
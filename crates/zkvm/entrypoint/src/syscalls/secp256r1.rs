@@ -58,27 +58,32 @@ pub extern "C" fn syscall_secp256r1_double(p: *mut [u32; 16]) {
 /// big-endian format. The second half of the input will be overwritten with the Y coordinate of the
 /// decompressed point in big-endian format using the point's parity (is_odd).
 ///
+/// Returns 0 on success. Returns 1 if there is no point with the given X coordinate, in which case
+/// the second half of the input array is left unchanged.
+///
 /// ### Safety
 ///
 /// The caller must ensure that `point` is valid pointer to data that is aligned along a four byte
 /// boundary.
 #[allow(unused_variables)]
 #[no_mangle]
-pub extern "C" fn syscall_secp256r1_decompress(point: &mut [u8; 64], is_odd: bool) {
+pub extern "C" fn syscall_secp256r1_decompress(point: &mut [u8; 64], is_odd: bool) -> u32 {
     #[cfg(target_os = "zkvm")]
     {
+        let status;
         // Memory system/FpOps are little endian so we'll just flip the whole array before/after
         point.reverse();
         let p = point.as_mut_ptr();
         unsafe {
             asm!(
                 "ecall",
-                in("t0") crate::syscalls::SECP256R1_DECOMPRESS,
+                inlateout("t0") crate::syscalls::SECP256R1_DECOMPRESS => status,
                 in("a0") p,
                 in("a1") is_odd as u8
             );
         }
         point.reverse();
+        status
     }
 
     #[cfg(not(target_os = "zkvm"))]
@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Compresses up to five consecutive blocks into a SHA-256 state.
+///
+/// `buf` holds a 64-word message schedule, followed by the 8-word SHA-256 state and the number of
+/// blocks, which must be between 1 and 5. `blocks` points to the blocks, as big-endian words. The
+/// state is updated in place, and the schedule is left holding the schedule of the last block.
+///
+/// ### Safety
+///
+/// The caller must ensure that `buf` and `blocks` are valid pointers to data that is aligned along
+/// a four byte boundary, and that they do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_sha256_compress_blocks(buf: *mut [u32; 73], blocks: *const [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SHA256_COMPRESS_BLOCKS,
+            in("a0") buf,
+            in("a1") blocks
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,28 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Compresses a SHA-512 block into the given state.
+///
+/// The state and the block are made of 64-bit words in the native little-endian layout. The block
+/// words are the big-endian words of the message, as for the SHA-512 specification.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `block` are valid pointers to data that is aligned along
+/// an eight byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_sha512_compress(state: *mut [u64; 8], block: *const [u64; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SHA512_COMPRESS,
+            in("a0") state,
+            in("a1") block,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Multiplication operation between a 256-bit and a 4096-bit unsigned integer.
+///
+/// The low 4096-bit result is written to the `lo` pointer, and the high 256-bit overflow is written to the `hi` pointer.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_u256x4096_mul(
+    a: *const [u32; 8],
+    b: *const [u32; 128],
+    lo: *mut [u32; 128],
+    hi: *mut [u32; 8],
+) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::U256XU4096_MUL,
+            in("a0") a,
+            in("a1") b,
+            in("a2") lo,
+            in("a3") hi,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
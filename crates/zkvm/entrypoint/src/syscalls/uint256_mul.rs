@@ -50,3 +50,29 @@ pub extern "C" fn syscall_uint256_muladd(x: *mut [u32; 8], y: *const [u32; 8]) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Uint256 multiplication and addition operation over the BN254 scalar field, on big-endian
+/// operands.
+///
+/// The result is written over the first input.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_muladd_be(x: *mut [u32; 8], y: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_MULADD_BE,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
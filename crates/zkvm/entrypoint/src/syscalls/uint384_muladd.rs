@@ -0,0 +1,27 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint384 multiply-add operation modulo the BLS12-381 base field.
+///
+/// `y` points to the concatenation of `a` and `b`, and `x` is overwritten with `(a * b + x) % p`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint384_muladd(x: *mut [u32; 12], y: *const [u32; 24]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT384_MULADD,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
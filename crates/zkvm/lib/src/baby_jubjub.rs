@@ -0,0 +1,94 @@
+use crate::{sys_bn254_muladd, syscall_baby_jubjub_add, utils::AffinePoint};
+
+/// The number of limbs in [BabyJubjubAffinePoint].
+pub const N: usize = 16;
+
+/// `sqrt(-168700)` in the BN254 scalar field, as little endian words.
+const SQRT_NEG_A: [u32; 8] =
+    [3635599208, 1820058305, 840868119, 1957033674, 2229670263, 746242254, 97969483, 235926352];
+
+/// The inverse of [SQRT_NEG_A], as little endian words.
+const SQRT_NEG_A_INV: [u32; 8] =
+    [267815615, 569743552, 229069303, 1711555046, 3158793753, 1492801180, 1063799618, 740960665];
+
+/// An affine point on the Baby Jubjub curve in reduced twisted Edwards form
+/// `-x^2 + y^2 = 1 + d x^2 y^2`.
+///
+/// Points of the EIP-2494 curve `168700 x^2 + y^2 = 1 + 168696 x^2 y^2` used by circom are
+/// converted with [BabyJubjubAffinePoint::from_twisted_edwards] and
+/// [BabyJubjubAffinePoint::to_twisted_edwards].
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct BabyJubjubAffinePoint(pub [u32; N]);
+
+impl AffinePoint<N> for BabyJubjubAffinePoint {
+    /// The image of the EIP-2494 base point `Base8`, which generates the prime order subgroup.
+    const GENERATOR: [u32; N] = [
+        4054499426, 1374215224, 2462502425, 4038554541, 3219615504, 771853841, 1951400692,
+        453136111, 2267905419, 1262232954, 3118543735, 4242539807, 3513514477, 626470428,
+        4154503753, 628716035,
+    ];
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_baby_jubjub_add(a, b);
+        }
+    }
+
+    /// In Edwards curves, doubling is the same as adding a point to itself.
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_baby_jubjub_add(a, a);
+        }
+    }
+}
+
+impl BabyJubjubAffinePoint {
+    /// The neutral element `(0, 1)`.
+    const IDENTITY: [u32; N] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+
+    pub fn identity() -> Self {
+        Self(Self::IDENTITY)
+    }
+
+    /// Maps the point `(x, y)` of the EIP-2494 curve, given as little endian words, to the reduced
+    /// twisted Edwards form.
+    pub fn from_twisted_edwards(x: &[u32; 8], y: &[u32; 8]) -> Self {
+        let mut limbs = [0u32; N];
+        limbs[..8].copy_from_slice(&mul_mod(x, &SQRT_NEG_A));
+        limbs[8..].copy_from_slice(y);
+        Self(limbs)
+    }
+
+    /// Maps the point back to the EIP-2494 curve, returning `(x, y)` as little endian words.
+    pub fn to_twisted_edwards(&self) -> ([u32; 8], [u32; 8]) {
+        let x: [u32; 8] = self.0[..8].try_into().unwrap();
+        let y: [u32; 8] = self.0[8..].try_into().unwrap();
+        (mul_mod(&x, &SQRT_NEG_A_INV), y)
+    }
+}
+
+/// Multiplies two elements of the BN254 scalar field with the `BN254_MULADD` precompile.
+fn mul_mod(x: &[u32; 8], y: &[u32; 8]) -> [u32; 8] {
+    let mut result = [0u32; 8];
+    let zero = [0u32; 8];
+    unsafe {
+        sys_bn254_muladd(&mut result, 0, x, y, &zero);
+    }
+    result
+}
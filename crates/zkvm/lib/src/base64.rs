@@ -0,0 +1,111 @@
+//! Base64 decoding accelerated by the `BASE64_DECODE` precompile.
+//!
+//! Only the standard alphabet with canonical padding is supported, as in the `STANDARD` engine
+//! of the `base64` crate. The input is decoded 16 characters at a time by the precompile, and the
+//! remaining characters and the padded final group are decoded in software. The errors mirror
+//! those of the `base64` crate, so that a patch of the crate can forward to [`decode`].
+
+use crate::syscall_base64_decode;
+
+/// The number of characters decoded by a single call to the precompile.
+const BLOCK_CHARS: usize = 16;
+
+/// The number of bytes produced by a single call to the precompile.
+const BLOCK_BYTES: usize = BLOCK_CHARS / 4 * 3;
+
+/// The padding character.
+const PAD: u8 = b'=';
+
+/// An error decoding a base64 string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The byte at the given index is not in the alphabet.
+    InvalidByte(usize, u8),
+    /// The length of the input, which is not a multiple of 4.
+    InvalidLength(usize),
+    /// The last character at the given index has nonzero bits which are not decoded.
+    InvalidLastSymbol(usize, u8),
+    /// The padding is malformed.
+    InvalidPadding,
+}
+
+/// Decodes a single character into 6 bits.
+fn decode_char(c: u8, index: usize) -> Result<u8, DecodeError> {
+    match c {
+        b'A'..=b'Z' => Ok(c - b'A'),
+        b'a'..=b'z' => Ok(c - b'a' + 26),
+        b'0'..=b'9' => Ok(c - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(DecodeError::InvalidByte(index, c)),
+    }
+}
+
+/// Decodes a group of 4 characters in software, where `offset` is the index of the first one.
+fn decode_group(group: &[u8], offset: usize) -> Result<[u8; 3], DecodeError> {
+    let mut acc = 0u32;
+    for (i, &c) in group.iter().enumerate() {
+        acc = (acc << 6) | u32::from(decode_char(c, offset + i)?);
+    }
+    Ok([(acc >> 16) as u8, (acc >> 8) as u8, acc as u8])
+}
+
+/// Decodes the characters in `input`.
+pub fn decode(input: impl AsRef<[u8]>) -> Result<Vec<u8>, DecodeError> {
+    let input = input.as_ref();
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+    if input.len() % 4 != 0 {
+        return Err(DecodeError::InvalidLength(input.len()));
+    }
+
+    // Everything but the final group is unpadded.
+    let (body, last) = input.split_at(input.len() - 4);
+    let mut output = Vec::with_capacity(input.len() / 4 * 3);
+
+    let mut blocks = body.chunks_exact(BLOCK_CHARS);
+    for (i, block) in (&mut blocks).enumerate() {
+        // Copy the characters to an aligned buffer.
+        let mut chars = [0u32; 4];
+        for (word, chunk) in chars.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let mut bytes = [0u32; 3];
+        let status = unsafe { syscall_base64_decode(&mut bytes, &chars) };
+        if status != 0 {
+            // Decode the block in software to find the invalid character.
+            for (j, group) in block.chunks_exact(4).enumerate() {
+                decode_group(group, i * BLOCK_CHARS + 4 * j)?;
+            }
+        }
+        output.extend(bytes.iter().flat_map(|word| word.to_le_bytes()));
+    }
+    debug_assert_eq!(output.len() % BLOCK_BYTES, 0);
+
+    let offset = body.len() - blocks.remainder().len();
+    for (j, group) in blocks.remainder().chunks_exact(4).enumerate() {
+        output.extend(decode_group(group, offset + 4 * j)?);
+    }
+
+    // Decode the final group, which may be padded.
+    let offset = body.len();
+    let padding = last.iter().rev().take_while(|&&c| c == PAD).count();
+    if padding > 2 {
+        return Err(DecodeError::InvalidPadding);
+    }
+    let mut group = [b'A'; 4];
+    group[..4 - padding].copy_from_slice(&last[..4 - padding]);
+    let bytes = decode_group(&group, offset)?;
+
+    // The bits of the last character which are not decoded must be zero.
+    let unused = [0, 2, 4][padding];
+    let last_index = 3 - padding;
+    let last_char = last[last_index];
+    if decode_char(last_char, offset + last_index)? & ((1 << unused) - 1) != 0 {
+        return Err(DecodeError::InvalidLastSymbol(offset + last_index, last_char));
+    }
+    output.extend_from_slice(&bytes[..3 - padding]);
+
+    Ok(output)
+}
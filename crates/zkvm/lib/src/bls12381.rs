@@ -2,7 +2,9 @@ use std::io::ErrorKind;
 
 use crate::{
     syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    syscall_bls12381_fp2_addmod, syscall_bls12381_fp2_mulmod, syscall_bls12381_fp2_submod,
+    syscall_bls12381_fp6_mulmod,
+    utils::{AffinePoint, SyscallError, WeierstrassAffinePoint, WeierstrassPoint},
 };
 
 /// The number of limbs in [Bls12381AffinePoint].
@@ -72,6 +74,15 @@ impl AffinePoint<N> for Bls12381Point {
     }
 }
 
+/// Decompresses a point with the `BLS12381_DECOMPRESS` precompile.
+///
+/// The first half of `point` is the big-endian X coordinate, and the second half is overwritten
+/// with the big-endian Y coordinate chosen by `sign_bit`. Fails if there is no point with the X
+/// coordinate, in which case the second half is left unchanged.
+pub fn decompress(point: &mut [u8; 96], sign_bit: bool) -> Result<(), SyscallError> {
+    SyscallError::InvalidPoint.check(unsafe { syscall_bls12381_decompress(point, sign_bit) })
+}
+
 /// Decompresses a compressed public key using bls12381_decompress precompile.
 pub fn decompress_pubkey(compressed_key: &[u8; 48]) -> Result<[u8; 96], ErrorKind> {
     let mut decompressed_key = [0u8; 96];
@@ -79,9 +90,102 @@ pub fn decompress_pubkey(compressed_key: &[u8; 48]) -> Result<[u8; 96], ErrorKin
 
     let sign_bit = ((decompressed_key[0] & 0b_0010_0000) >> 5) == 1;
     decompressed_key[0] &= 0b_0001_1111;
-    unsafe {
-        syscall_bls12381_decompress(&mut decompressed_key, sign_bit);
-    }
+    decompress(&mut decompressed_key, sign_bit).map_err(|_| ErrorKind::InvalidData)?;
 
     Ok(decompressed_key)
 }
+
+/// The number of words in an element of the degree 2 extension `Fp2 = Fp[u] / (u^2 + 1)`.
+pub const FP2_WORDS: usize = 24;
+
+/// The number of words in an element of the degree 6 extension `Fp6 = Fp2[v] / (v^3 - (1 + u))`.
+pub const FP6_WORDS: usize = 3 * FP2_WORDS;
+
+/// The number of words in an element of the degree 12 extension `Fp12 = Fp6[w] / (w^2 - v)`.
+pub const FP12_WORDS: usize = 2 * FP6_WORDS;
+
+/// The non-residue `1 + u` as an element of `Fp2`.
+const XI: [u32; FP2_WORDS] = {
+    let mut xi = [0; FP2_WORDS];
+    xi[0] = 1;
+    xi[FP2_WORDS / 2] = 1;
+    xi
+};
+
+/// Applies `op` to each `Fp2` coefficient of `a` and `b`, writing the result over `a`.
+fn fp6_coefficientwise(
+    a: &mut [u32; FP6_WORDS],
+    b: &[u32; FP6_WORDS],
+    op: unsafe extern "C" fn(*mut u32, *const u32),
+) {
+    for (x, y) in a.chunks_exact_mut(FP2_WORDS).zip(b.chunks_exact(FP2_WORDS)) {
+        unsafe { op(x.as_mut_ptr(), y.as_ptr()) };
+    }
+}
+
+/// Adds two `Fp6` elements with the `BLS12381_FP2_ADD` precompile, writing the result over `a`.
+pub fn fp6_add(a: &mut [u32; FP6_WORDS], b: &[u32; FP6_WORDS]) {
+    fp6_coefficientwise(a, b, syscall_bls12381_fp2_addmod);
+}
+
+/// Subtracts two `Fp6` elements with the `BLS12381_FP2_SUB` precompile, writing the result over
+/// `a`.
+pub fn fp6_sub(a: &mut [u32; FP6_WORDS], b: &[u32; FP6_WORDS]) {
+    fp6_coefficientwise(a, b, syscall_bls12381_fp2_submod);
+}
+
+/// Multiplies two `Fp6` elements with the `BLS12381_FP6_MUL` precompile, writing the result over
+/// `a`.
+pub fn fp6_mul(a: &mut [u32; FP6_WORDS], b: &[u32; FP6_WORDS]) {
+    unsafe { syscall_bls12381_fp6_mulmod(a.as_mut_ptr(), b.as_ptr()) };
+}
+
+/// Multiplies an `Fp6` element by `v`, mapping `(c0, c1, c2)` to `((1 + u) * c2, c0, c1)`.
+pub fn fp6_mul_by_v(a: &mut [u32; FP6_WORDS]) {
+    a.rotate_right(FP2_WORDS);
+    unsafe { syscall_bls12381_fp2_mulmod(a.as_mut_ptr(), XI.as_ptr()) };
+}
+
+/// Multiplies two `Fp12` elements, writing the result over `a`.
+///
+/// Each element is a pair `(c0, c1)` of `Fp6` coefficients representing `c0 + c1 * w`, and the
+/// product is computed with the Karatsuba method, which takes three `Fp6` multiplications.
+pub fn fp12_mul(a: &mut [u32; FP12_WORDS], b: &[u32; FP12_WORDS]) {
+    let (a0, a1) = split_fp12_mut(a);
+    let (b0, b1) = split_fp12(b);
+
+    // (a0 + a1) * (b0 + b1)
+    let mut sum = *a0;
+    fp6_add(&mut sum, a1);
+    let mut b_sum = *b0;
+    fp6_add(&mut b_sum, b1);
+    fp6_mul(&mut sum, &b_sum);
+
+    fp6_mul(a0, b0);
+    fp6_mul(a1, b1);
+
+    // c1 = (a0 + a1) * (b0 + b1) - a0 * b0 - a1 * b1
+    fp6_sub(&mut sum, a0);
+    fp6_sub(&mut sum, a1);
+
+    // c0 = a0 * b0 + v * a1 * b1
+    fp6_mul_by_v(a1);
+    fp6_add(a0, a1);
+    *a1 = sum;
+}
+
+/// Squares an `Fp12` element in place.
+pub fn fp12_square(a: &mut [u32; FP12_WORDS]) {
+    let b = *a;
+    fp12_mul(a, &b);
+}
+
+fn split_fp12(a: &[u32; FP12_WORDS]) -> (&[u32; FP6_WORDS], &[u32; FP6_WORDS]) {
+    let (c0, c1) = a.split_at(FP6_WORDS);
+    (c0.try_into().unwrap(), c1.try_into().unwrap())
+}
+
+fn split_fp12_mut(a: &mut [u32; FP12_WORDS]) -> (&mut [u32; FP6_WORDS], &mut [u32; FP6_WORDS]) {
+    let (c0, c1) = a.split_at_mut(FP6_WORDS);
+    (c0.try_into().unwrap(), c1.try_into().unwrap())
+}
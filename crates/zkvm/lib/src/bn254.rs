@@ -1,11 +1,15 @@
 use crate::{
-    syscall_bn254_add, syscall_bn254_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    syscall_bn254_add, syscall_bn254_decompress, syscall_bn254_double, syscall_bn254_g2_decompress,
+    syscall_bn254_inner_product,
+    utils::{AffinePoint, SyscallError, WeierstrassAffinePoint, WeierstrassPoint},
 };
 
 /// The number of limbs in [Bn254AffinePoint].
 pub const N: usize = 16;
 
+/// The maximum number of pairs of a single `syscall_bn254_inner_product` call.
+pub const INNER_PRODUCT_MAX_LEN: usize = 32;
+
 /// A point on the Bn254 curve.
 #[derive(Copy, Clone)]
 #[repr(align(4))]
@@ -64,3 +68,40 @@ impl AffinePoint<N> for Bn254Point {
         }
     }
 }
+
+/// Decompresses a G1 point with the `BN254_DECOMPRESS` precompile.
+///
+/// The first half of `point` is the big-endian X coordinate, and the second half is overwritten
+/// with the big-endian Y coordinate chosen by `sign_bit`, which is set for the lexicographically
+/// largest one as in gnark. Fails if there is no point with the X coordinate, in which case the
+/// second half is left unchanged.
+pub fn decompress(point: &mut [u8; 64], sign_bit: bool) -> Result<(), SyscallError> {
+    SyscallError::InvalidPoint.check(unsafe { syscall_bn254_decompress(point, sign_bit) })
+}
+
+/// Decompresses a G2 point with the `BN254_G2_DECOMPRESS` precompile.
+///
+/// The first half of `point` is the X coordinate `x0 + x1 * u` as `x1 || x0` in big-endian, and
+/// the second half is overwritten with the Y coordinate chosen by `sign_bit` in the same format.
+/// Fails if there is no point with the X coordinate, in which case the second half is left
+/// unchanged.
+pub fn decompress_g2(point: &mut [u8; 128], sign_bit: bool) -> Result<(), SyscallError> {
+    SyscallError::InvalidPoint.check(unsafe { syscall_bn254_g2_decompress(point, sign_bit) })
+}
+
+/// Adds the inner product of the `(a, b)` pairs of Bn254 scalar field elements to `x`, as little
+/// endian words, splitting the pairs into calls of at most [INNER_PRODUCT_MAX_LEN] pairs.
+pub fn bn254_inner_product(x: &mut [u32; 8], pairs: &[([u32; 8], [u32; 8])]) {
+    let mut buffer = Vec::with_capacity(1 + 16 * INNER_PRODUCT_MAX_LEN);
+    for chunk in pairs.chunks(INNER_PRODUCT_MAX_LEN) {
+        buffer.clear();
+        buffer.push(chunk.len() as u32);
+        for (a, b) in chunk {
+            buffer.extend_from_slice(a);
+            buffer.extend_from_slice(b);
+        }
+        unsafe {
+            syscall_bn254_inner_product(x, buffer.as_ptr());
+        }
+    }
+}
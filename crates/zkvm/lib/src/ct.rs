@@ -0,0 +1,27 @@
+//! Constant-time selection accelerated by the `CSEL256` precompile.
+//!
+//! A selection takes the same cycles and the same rows whatever the condition, so that guests can
+//! select between secret values without branching on secrets.
+
+use crate::syscall_csel256;
+
+/// Returns `a` if `cond` is true, and `b` otherwise.
+pub fn select_words(cond: bool, a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let mut result = *a;
+    unsafe { syscall_csel256(&mut result, b, cond as u32) };
+    result
+}
+
+/// Returns the bytes of `a` if `cond` is true, and those of `b` otherwise.
+pub fn select(cond: bool, a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let words = |bytes: &[u8; 32]| -> [u32; 8] {
+        core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+    };
+    let result = select_words(cond, &words(a), &words(b));
+
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(result) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
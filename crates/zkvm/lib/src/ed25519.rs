@@ -1,4 +1,8 @@
-use crate::{syscall_ed_add, utils::AffinePoint};
+use crate::{
+    sha512::Sha512,
+    syscall_ed25519_verify, syscall_ed_add, syscall_ed_decompress,
+    utils::{AffinePoint, SyscallError},
+};
 
 /// The number of limbs in [Ed25519AffinePoint].
 pub const N: usize = 16;
@@ -52,3 +56,80 @@ impl Ed25519AffinePoint {
         Self(Self::IDENTITY)
     }
 }
+
+/// Decompresses a point with the `ED_DECOMPRESS` precompile.
+///
+/// The second half of `point` is the compressed Y coordinate, whose last bit is the sign of X. The
+/// sign bit is cleared and the first half is overwritten with the X coordinate. Fails if there is
+/// no point with the Y coordinate, in which case the first half is left unchanged.
+pub fn decompress(point: &mut [u8; 64]) -> Result<(), SyscallError> {
+    SyscallError::InvalidPoint.check(unsafe { syscall_ed_decompress(point) })
+}
+
+/// The order of the prime subgroup of Ed25519, as little-endian bytes.
+const ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+];
+
+/// A streaming verifier of Ed25519 signatures, backed by the `SHA512_COMPRESS` and
+/// `ED25519_VERIFY` precompiles.
+///
+/// The message is hashed as it is streamed, so it never has to be held in memory at once.
+#[derive(Clone)]
+pub struct Ed25519Verifier {
+    public_key: [u8; 32],
+    signature: [u8; 64],
+    hasher: Sha512,
+}
+
+impl Ed25519Verifier {
+    /// Creates a verifier of `signature` under `public_key`, given in their compressed forms.
+    pub fn new(public_key: &[u8; 32], signature: &[u8; 64]) -> Self {
+        let mut hasher = Sha512::new();
+        hasher.update(&signature[..32]);
+        hasher.update(public_key);
+        Self { public_key: *public_key, signature: *signature, hasher }
+    }
+
+    /// Absorbs the next part of the message.
+    pub fn update(&mut self, message: &[u8]) {
+        self.hasher.update(message);
+    }
+
+    /// Returns whether the signature is valid for the message absorbed so far.
+    ///
+    /// Fails if `S` is not reduced, or if the public key cannot be decompressed.
+    pub fn finalize(self) -> bool {
+        let s = &self.signature[32..];
+        if s.iter().rev().cmp(ORDER.iter().rev()) != core::cmp::Ordering::Less {
+            return false;
+        }
+
+        let mut point = [0u8; 64];
+        point[32..].copy_from_slice(&self.public_key);
+        if decompress(&mut point).is_err() {
+            return false;
+        }
+
+        let mut key = [0u32; 16];
+        for (word, chunk) in key.iter_mut().zip(point.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let mut sig = [0u32; 32];
+        let digest = self.hasher.finalize();
+        for (word, chunk) in
+            sig.iter_mut().zip(self.signature.chunks_exact(4).chain(digest.chunks_exact(4)))
+        {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        unsafe { syscall_ed25519_verify(&key, &sig) == 0 }
+    }
+}
+
+/// Returns whether `signature` is a valid Ed25519 signature of `message` under `public_key`.
+pub fn verify(public_key: &[u8; 32], signature: &[u8; 64], message: &[u8]) -> bool {
+    let mut verifier = Ed25519Verifier::new(public_key, signature);
+    verifier.update(message);
+    verifier.finalize()
+}
@@ -0,0 +1,48 @@
+//! Values of the environment a program runs in, such as the timestamp of an L1 block or the output
+//! of a randomness beacon.
+//!
+//! A value is read from the input stream the first time it is accessed, and committed to the
+//! public values stream right away, so that the verifier of the proof sees which value the program
+//! ran with. Later accesses return the same value without reading or committing anything.
+//!
+//! The host writes the values with `SP1Stdin::write_timestamp` and `SP1Stdin::write_seed`, in the
+//! order the program first accesses them.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::io::{commit, read};
+
+static mut TIMESTAMP: Option<u64> = None;
+
+static mut SEED: Option<[u8; 32]> = None;
+
+/// The timestamp the program runs at, committed to the public values stream on first access.
+///
+/// ### Examples
+/// ```ignore
+/// let timestamp = sp1_zkvm::env::timestamp();
+/// assert!(timestamp >= DEADLINE);
+/// ```
+pub fn timestamp() -> u64 {
+    let timestamp = unsafe { &mut *core::ptr::addr_of_mut!(TIMESTAMP) };
+    *timestamp.get_or_insert_with(read_committed_value)
+}
+
+/// The seed the program runs with, committed to the public values stream on first access.
+///
+/// ### Examples
+/// ```ignore
+/// let seed = sp1_zkvm::env::seed();
+/// let winner = u64::from_le_bytes(seed[..8].try_into().unwrap()) % participants;
+/// ```
+pub fn seed() -> [u8; 32] {
+    let seed = unsafe { &mut *core::ptr::addr_of_mut!(SEED) };
+    *seed.get_or_insert_with(read_committed_value)
+}
+
+/// Read a value from the input stream and commit it to the public values stream.
+fn read_committed_value<T: Serialize + DeserializeOwned>() -> T {
+    let value = read::<T>();
+    commit(&value);
+    value
+}
@@ -0,0 +1,44 @@
+//! Double precision arithmetic accelerated by the `F64_ADD`, `F64_MUL` and `F64_DIV` precompiles.
+//!
+//! The precompiles only handle normal operands with a normal result. Any other operation, such as
+//! one involving a zero, an infinity or a NaN, or one that underflows or overflows, is computed in
+//! software instead, so the results always match the native operators.
+
+use crate::{syscall_f64_add, syscall_f64_div, syscall_f64_mul};
+
+/// Applies a precompile to `a` and `b`, falling back to `software` if it fails.
+fn apply(
+    syscall: unsafe extern "C" fn(*mut u64, *const u64) -> u32,
+    a: f64,
+    b: f64,
+    software: impl FnOnce(f64, f64) -> f64,
+) -> f64 {
+    let mut x = a.to_bits();
+    let y = b.to_bits();
+    let status = unsafe { syscall(&mut x, &y) };
+    if status == 0 {
+        f64::from_bits(x)
+    } else {
+        software(a, b)
+    }
+}
+
+/// Computes `a + b`.
+pub fn add(a: f64, b: f64) -> f64 {
+    apply(syscall_f64_add, a, b, |a, b| a + b)
+}
+
+/// Computes `a - b`.
+pub fn sub(a: f64, b: f64) -> f64 {
+    add(a, -b)
+}
+
+/// Computes `a * b`.
+pub fn mul(a: f64, b: f64) -> f64 {
+    apply(syscall_f64_mul, a, b, |a, b| a * b)
+}
+
+/// Computes `a / b`.
+pub fn div(a: f64, b: f64) -> f64 {
+    apply(syscall_f64_div, a, b, |a, b| a / b)
+}
@@ -0,0 +1,88 @@
+//! Hexadecimal decoding accelerated by the `HEX_DECODE` precompile.
+//!
+//! The input is decoded 16 digits at a time by the precompile, and the remaining digits are
+//! decoded in software. The errors mirror those of the `hex` crate, so that a patch of the crate
+//! can forward to [`decode_to_slice`].
+
+use crate::syscall_hex_decode;
+
+/// The number of digits decoded by a single call to the precompile.
+const BLOCK_DIGITS: usize = 16;
+
+/// The number of bytes produced by a single call to the precompile.
+const BLOCK_BYTES: usize = BLOCK_DIGITS / 2;
+
+/// An error decoding a hexadecimal string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromHexError {
+    /// A character at the given index is not a hexadecimal digit.
+    InvalidHexCharacter { c: char, index: usize },
+    /// The input has an odd number of digits.
+    OddLength,
+    /// The input does not have twice as many digits as the length of the output.
+    InvalidStringLength,
+}
+
+/// Decodes a single digit.
+fn decode_digit(c: u8, index: usize) -> Result<u8, FromHexError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(FromHexError::InvalidHexCharacter { c: c as char, index }),
+    }
+}
+
+/// Decodes a slice of digits in software, where `offset` is the index of the first digit.
+fn decode_software(input: &[u8], output: &mut [u8], offset: usize) -> Result<(), FromHexError> {
+    for (i, (byte, pair)) in output.iter_mut().zip(input.chunks_exact(2)).enumerate() {
+        let index = offset + 2 * i;
+        *byte = (decode_digit(pair[0], index)? << 4) | decode_digit(pair[1], index + 1)?;
+    }
+    Ok(())
+}
+
+/// Decodes the digits in `input` into `output`, which must be half as long.
+pub fn decode_to_slice(input: impl AsRef<[u8]>, output: &mut [u8]) -> Result<(), FromHexError> {
+    let input = input.as_ref();
+    if input.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    if input.len() != 2 * output.len() {
+        return Err(FromHexError::InvalidStringLength);
+    }
+
+    let mut blocks = input.chunks_exact(BLOCK_DIGITS);
+    let mut outputs = output.chunks_exact_mut(BLOCK_BYTES);
+    for (i, (block, out)) in (&mut blocks).zip(&mut outputs).enumerate() {
+        // Copy the digits to an aligned buffer.
+        let mut digits = [0u32; 4];
+        for (word, chunk) in digits.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let mut bytes = [0u32; 2];
+        let status = unsafe { syscall_hex_decode(&mut bytes, &digits) };
+        if status != 0 {
+            // Decode the block in software to find the invalid digit.
+            decode_software(block, out, i * BLOCK_DIGITS)?;
+            continue;
+        }
+        for (chunk, word) in out.chunks_exact_mut(4).zip(bytes) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    let offset = input.len() - blocks.remainder().len();
+    decode_software(blocks.remainder(), outputs.into_remainder(), offset)
+}
+
+/// Decodes the digits in `input`.
+pub fn decode(input: impl AsRef<[u8]>) -> Result<Vec<u8>, FromHexError> {
+    let input = input.as_ref();
+    if input.len() % 2 != 0 {
+        return Err(FromHexError::OddLength);
+    }
+    let mut output = vec![0u8; input.len() / 2];
+    decode_to_slice(input, &mut output)?;
+    Ok(output)
+}
@@ -0,0 +1,109 @@
+use crate::{
+    sha256::{compress_blocks, MAX_BLOCKS_PER_CALL},
+    syscall_hmac_sha256,
+};
+
+/// The SHA-256 block size in bytes.
+const BLOCK: usize = 64;
+
+/// The SHA-256 initial hash value.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The inner padding, repeated over a word.
+const IPAD: u32 = 0x3636_3636;
+
+/// The outer padding, repeated over a word.
+const OPAD: u32 = 0x5c5c_5c5c;
+
+/// The longest message the `HMAC_SHA256` precompile authenticates, which is the longest message
+/// whose padding fits in a single block.
+pub const MAX_PRECOMPILE_MESSAGE_LEN: usize = BLOCK - 9;
+
+/// Computes the HMAC-SHA256 tag of `message` under `key`.
+///
+/// Messages of at most [`MAX_PRECOMPILE_MESSAGE_LEN`] bytes are authenticated by a single call to
+/// the `HMAC_SHA256` precompile. Longer messages are hashed with the `SHA256_COMPRESS_BLOCKS`
+/// precompile.
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut key_block = [0u32; 16];
+    if key.len() > BLOCK {
+        let digest = sha256(None, key);
+        key_block[..8].copy_from_slice(&digest);
+    } else {
+        let mut bytes = [0u8; BLOCK];
+        bytes[..key.len()].copy_from_slice(key);
+        key_block = block_words(&bytes);
+    }
+
+    let tag = if message.len() <= MAX_PRECOMPILE_MESSAGE_LEN {
+        let mut buf = [0u32; 72];
+        let mut bytes = [0u8; BLOCK];
+        bytes[..message.len()].copy_from_slice(message);
+        bytes[message.len()] = 0x80;
+        bytes[BLOCK - 8..].copy_from_slice(&(((BLOCK + message.len()) * 8) as u64).to_be_bytes());
+        buf[..16].copy_from_slice(&block_words(&bytes));
+        unsafe {
+            syscall_hmac_sha256(&key_block, &mut buf);
+        }
+        buf[64..].try_into().unwrap()
+    } else {
+        let inner = sha256(Some(&key_block.map(|word| word ^ IPAD)), message);
+        let mut inner_bytes = [0u8; 32];
+        for (chunk, word) in inner_bytes.chunks_exact_mut(4).zip(inner.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        sha256(Some(&key_block.map(|word| word ^ OPAD)), &inner_bytes)
+    };
+
+    let mut out = [0u8; 32];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(tag.iter()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
+/// Hashes `data` with SHA-256, after the block `prefix` if there is one.
+pub(crate) fn sha256(prefix: Option<&[u32; 16]>, data: &[u8]) -> [u32; 8] {
+    let mut state = IV;
+    let mut len = data.len();
+    if let Some(prefix) = prefix {
+        compress_blocks(&mut state, &[*prefix]);
+        len += BLOCK;
+    }
+
+    let mut blocks = data.chunks_exact(BLOCK);
+    let mut batch = [[0u32; 16]; MAX_BLOCKS_PER_CALL];
+    loop {
+        let mut n = 0;
+        for block in blocks.by_ref().take(MAX_BLOCKS_PER_CALL) {
+            batch[n] = block_words(block.try_into().unwrap());
+            n += 1;
+        }
+        if n == 0 {
+            break;
+        }
+        compress_blocks(&mut state, &batch[..n]);
+    }
+
+    // Pad the remainder with a one bit and the length in bits, spilling over a second block if
+    // the length does not fit.
+    let remainder = blocks.remainder();
+    let mut tail = [0u8; 2 * BLOCK];
+    tail[..remainder.len()].copy_from_slice(remainder);
+    tail[remainder.len()] = 0x80;
+    let tail_len = if remainder.len() < BLOCK - 8 { BLOCK } else { 2 * BLOCK };
+    tail[tail_len - 8..tail_len].copy_from_slice(&((len * 8) as u64).to_be_bytes());
+    for (words, block) in batch.iter_mut().zip(tail[..tail_len].chunks_exact(BLOCK)) {
+        *words = block_words(block.try_into().unwrap());
+    }
+    compress_blocks(&mut state, &batch[..tail_len / BLOCK]);
+
+    state
+}
+
+/// Reads a block as big-endian words.
+fn block_words(block: &[u8; BLOCK]) -> [u32; 16] {
+    core::array::from_fn(|i| u32::from_be_bytes(block[4 * i..4 * i + 4].try_into().unwrap()))
+}
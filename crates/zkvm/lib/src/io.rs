@@ -1,6 +1,9 @@
 #![allow(unused_unsafe)]
-use crate::{syscall_hint_len, syscall_hint_read, syscall_write};
-use serde::{de::DeserializeOwned, Serialize};
+use crate::{
+    sys_public_values_digest, syscall_hint_len, syscall_hint_map, syscall_hint_read, syscall_write,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::{
     alloc::Layout,
     io::{Result, Write},
@@ -16,6 +19,9 @@ pub const FD_HINT: u32 = 4;
 pub const K1_ECRECOVER_HOOK: u32 = 5;
 pub const R1_ECRECOVER_HOOK: u32 = 6;
 
+/// The file descriptor for the `fr_ntt` hook.
+pub const FR_NTT_HOOK: u32 = 7;
+
 /// A writer that writes to a file descriptor inside the zkVM.
 struct SyscallWriter {
     fd: u32,
@@ -43,8 +49,54 @@ impl Write for SyscallWriter {
 /// let data: Vec<u8> = sp1_zkvm::io::read_vec();
 /// ```
 pub fn read_vec() -> Vec<u8> {
-    // Round up to the nearest multiple of 4 so that the memory allocated is in whole words
     let len = unsafe { syscall_hint_len() };
+    read_vec_with_len(len)
+}
+
+/// Read a buffer of at most `max_len` bytes from the input stream.
+///
+/// The length of the next buffer is checked before any memory is allocated for it, so a host
+/// supplying an oversized hint cannot inflate the memory and cycle usage of the guest.
+///
+/// ### Panics
+/// Panics if the next buffer in the input stream is longer than `max_len` bytes.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u8> = sp1_zkvm::io::read_vec_bounded(1024);
+/// ```
+pub fn read_vec_bounded(max_len: usize) -> Vec<u8> {
+    let len = unsafe { syscall_hint_len() };
+    if len > max_len {
+        panic!("hint of {len} bytes exceeds the maximum of {max_len} bytes");
+    }
+    read_vec_with_len(len)
+}
+
+/// Read a buffer from the input stream, checking it against the SHA-256 digest the program expects.
+///
+/// The input stream is supplied by the host and is not trusted: the digest must come from a source
+/// the program trusts, such as a constant of the program or a committed value, and the host can
+/// compute it with `SP1Stdin::write_verified`.
+///
+/// ### Panics
+/// Panics if the digest of the buffer is not `digest`.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u8> = sp1_zkvm::io::read_vec_verified(&DIGEST);
+/// ```
+pub fn read_vec_verified(digest: &[u8; 32]) -> Vec<u8> {
+    let vec = read_vec();
+    if Sha256::digest(&vec).as_slice() != digest {
+        panic!("hint does not match its expected digest");
+    }
+    vec
+}
+
+/// Read the next buffer of the input stream, of the given length.
+fn read_vec_with_len(len: usize) -> Vec<u8> {
+    // Round up to the nearest multiple of 4 so that the memory allocated is in whole words
     let capacity = (len + 3) / 4 * 4;
 
     // Allocate a buffer of the required length that is 4 byte aligned
@@ -68,6 +120,45 @@ pub fn read_vec() -> Vec<u8> {
     vec
 }
 
+/// Map a buffer from the input stream to read-only memory.
+///
+/// Unlike [`read_vec`], the buffer is not copied into memory upfront: each word is loaded when it is
+/// first accessed, so mapping a large input is cheap and the parts of it that are never accessed
+/// cost nothing.
+///
+/// ### Examples
+/// ```ignore
+/// let data: &[u8] = sp1_zkvm::io::map_vec();
+/// ```
+pub fn map_vec() -> &'static [u8] {
+    let len = unsafe { syscall_hint_len() };
+
+    // Reserve whole words of fresh memory, which the allocator never hands out again.
+    let capacity = (len + 3) / 4 * 4;
+    let layout = Layout::from_size_align(capacity.max(4), 4).expect("vec is too large");
+    let ptr = unsafe { std::alloc::alloc(layout) };
+
+    // SAFETY: the allocator does not dealloc and the memory was never accessed, so the mapped
+    // region stays valid and unaliased for the rest of the program.
+    unsafe {
+        syscall_hint_map(ptr, len);
+        std::slice::from_raw_parts(ptr, len)
+    }
+}
+
+/// Read a deserializable object borrowing from a buffer of the input stream mapped to memory.
+///
+/// Borrowed fields, such as `&[u8]` or `&str`, point into the mapped buffer instead of being
+/// copied, see [`map_vec`].
+///
+/// ### Examples
+/// ```ignore
+/// let data: &[u8] = sp1_zkvm::io::read_mapped();
+/// ```
+pub fn read_mapped<T: Deserialize<'static>>() -> T {
+    bincode::deserialize(map_vec()).expect("deserialization failed")
+}
+
 /// Read a deserializable object from the input stream.
 ///
 /// ### Examples
@@ -87,6 +178,51 @@ pub fn read<T: DeserializeOwned>() -> T {
     bincode::deserialize(&vec).expect("deserialization failed")
 }
 
+/// Read a deserializable object, serialized in at most `max_len` bytes, from the input stream.
+///
+/// ### Panics
+/// Panics if the serialized object is longer than `max_len` bytes.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u32> = sp1_zkvm::io::read_bounded(1024);
+/// ```
+pub fn read_bounded<T: DeserializeOwned>(max_len: usize) -> T {
+    let vec = read_vec_bounded(max_len);
+    bincode::deserialize(&vec).expect("deserialization failed")
+}
+
+/// Read a deserializable object from the input stream, checking its serialization against the
+/// SHA-256 digest the program expects, see [`read_vec_verified`].
+///
+/// ### Panics
+/// Panics if the digest of the serialized object is not `digest`.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u32> = sp1_zkvm::io::read_verified(&DIGEST);
+/// ```
+pub fn read_verified<T: DeserializeOwned>(digest: &[u8; 32]) -> T {
+    let vec = read_vec_verified(digest);
+    bincode::deserialize(&vec).expect("deserialization failed")
+}
+
+/// Read a deserializable object from the input stream, committing the SHA-256 digest of its
+/// serialization to the public values stream.
+///
+/// The object itself is not checked, so the verifier of the proof must check the committed digest
+/// against the one computed by `SP1Stdin::write_verified` on the host.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u32> = sp1_zkvm::io::read_committed();
+/// ```
+pub fn read_committed<T: DeserializeOwned>() -> T {
+    let vec = read_vec();
+    commit_slice(&Sha256::digest(&vec));
+    bincode::deserialize(&vec).expect("deserialization failed")
+}
+
 /// Commit a serializable object to the public values stream.
 ///
 /// ### Examples
@@ -122,6 +258,40 @@ pub fn commit_slice(buf: &[u8]) {
     my_writer.write_all(buf).unwrap();
 }
 
+/// The public values committed so far, as seen by the verifier of the proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommittedValues {
+    /// The SHA-256 digest of the committed bytes, which is the public values digest of the proof
+    /// if the program halts without committing anything else.
+    pub digest: [u8; 32],
+    /// The number of committed bytes.
+    pub len: usize,
+}
+
+impl CommittedValues {
+    /// Whether the committed bytes are exactly `expected`.
+    pub fn matches(&self, expected: &[u8]) -> bool {
+        self.len == expected.len() && self.digest == <[u8; 32]>::from(Sha256::digest(expected))
+    }
+}
+
+/// Get the digest and the length of the public values committed so far, e.g. to check right
+/// before halting that the program committed its output in the order the verifier expects.
+///
+/// ### Examples
+/// ```ignore
+/// sp1_zkvm::io::commit(&a);
+/// sp1_zkvm::io::commit(&b);
+///
+/// let expected = bincode::serialize(&(a, b)).unwrap();
+/// assert!(sp1_zkvm::io::committed().matches(&expected));
+/// ```
+pub fn committed() -> CommittedValues {
+    let mut digest = [0u8; 32];
+    let len = unsafe { sys_public_values_digest(&mut digest) };
+    CommittedValues { digest, len }
+}
+
 /// Hint a serializable object to the hint stream.
 ///
 /// ### Examples
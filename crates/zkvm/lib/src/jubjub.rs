@@ -0,0 +1,56 @@
+use crate::{syscall_jubjub_add, utils::AffinePoint};
+
+/// The number of limbs in [JubjubAffinePoint].
+pub const N: usize = 16;
+
+/// An affine point on the Jubjub curve.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct JubjubAffinePoint(pub [u32; N]);
+
+impl AffinePoint<N> for JubjubAffinePoint {
+    /// The generator of the prime order subgroup of Jubjub, i.e. eight times the point of the full
+    /// group with `y = 11`.
+    const GENERATOR: [u32; N] = [
+        416640997, 1361968803, 2143172447, 70343781, 2977101294, 1590918883, 974265507, 1051051111,
+        3574355403, 3238849080, 1082164243, 3114954382, 1063349427, 2029507341, 3933133647,
+        1460894595,
+    ];
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_jubjub_add(a, b);
+        }
+    }
+
+    /// In Edwards curves, doubling is the same as adding a point to itself.
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_jubjub_add(a, a);
+        }
+    }
+}
+
+impl JubjubAffinePoint {
+    /// The neutral element `(0, 1)`.
+    const IDENTITY: [u32; N] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+
+    pub fn identity() -> Self {
+        Self(Self::IDENTITY)
+    }
+}
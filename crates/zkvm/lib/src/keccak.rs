@@ -0,0 +1,238 @@
+use crate::{
+    syscall_keccak_absorb, syscall_keccak_accumulate, syscall_keccak_finalize,
+    syscall_keccak_merkle_root, syscall_withdraw_root,
+};
+
+/// The Keccak-256 rate in bytes.
+const RATE: usize = 136;
+
+/// The maximum number of levels of a single `syscall_keccak_merkle_root` call.
+pub const MERKLE_MAX_DEPTH: usize = 8;
+
+/// The maximum number of levels of a single `syscall_withdraw_root` call.
+pub const WITHDRAW_ROOT_MAX_DEPTH: usize = 4;
+
+/// Flag marking the first block absorbed by an accumulator.
+const FIRST: u32 = 1;
+
+/// Flag marking the final block absorbed by an accumulator.
+const FINAL: u32 = 2;
+
+#[repr(C, align(4))]
+struct Block([u8; RATE]);
+
+/// A Keccak-256 hasher backed by the `KECCAK_ACCUMULATE` precompile.
+///
+/// The sponge state is carried by the prover from one block to the next rather than kept in guest
+/// memory, so a single hash may span any number of shards.
+pub struct KeccakAccumulator {
+    /// The flags for the next block, followed by the digest once finalized. Its address
+    /// identifies the accumulator, so it is boxed to keep it fixed while `self` moves.
+    acc: Box<[u32; 9]>,
+    buffer: Block,
+    len: usize,
+    started: bool,
+}
+
+impl KeccakAccumulator {
+    /// Creates a new, empty accumulator.
+    pub fn new() -> Self {
+        Self { acc: Box::new([0; 9]), buffer: Block([0; RATE]), len: 0, started: false }
+    }
+
+    /// Absorbs `input` into the hash.
+    pub fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            let n = core::cmp::min(RATE - self.len, input.len());
+            self.buffer.0[self.len..self.len + n].copy_from_slice(&input[..n]);
+            self.len += n;
+            input = &input[n..];
+            if self.len == RATE {
+                self.absorb(0);
+            }
+        }
+    }
+
+    /// Pads and absorbs the remaining input, returning the Keccak-256 digest.
+    pub fn finalize(mut self) -> [u8; 32] {
+        self.buffer.0[self.len] |= 0x01;
+        self.buffer.0[RATE - 1] |= 0x80;
+        self.absorb(FINAL);
+
+        let mut digest = [0u8; 32];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.acc[1..].iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        digest
+    }
+
+    fn absorb(&mut self, flags: u32) {
+        self.acc[0] = if self.started { flags } else { flags | FIRST };
+        unsafe {
+            syscall_keccak_accumulate(
+                self.buffer.0.as_ptr() as *const [u32; 34],
+                self.acc.as_mut() as *mut [u32; 9],
+            );
+        }
+        self.started = true;
+        self.buffer.0 = [0; RATE];
+        self.len = 0;
+    }
+}
+
+impl Default for KeccakAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The input not yet absorbed by a [`KeccakSponge`], laid out as `KECCAK_FINALIZE` expects it.
+#[derive(Clone)]
+#[repr(C, align(4))]
+struct Tail {
+    block: [u8; RATE],
+    len: u32,
+}
+
+/// A Keccak-256 hasher backed by the `KECCAK_ABSORB` and `KECCAK_FINALIZE` precompiles.
+///
+/// The sponge state is kept in guest memory, so only the input of the current block is buffered
+/// while streaming.
+#[derive(Clone)]
+pub struct KeccakSponge {
+    state: [u64; 25],
+    tail: Tail,
+}
+
+impl KeccakSponge {
+    /// Creates a new, empty sponge.
+    pub fn new() -> Self {
+        Self { state: [0; 25], tail: Tail { block: [0; RATE], len: 0 } }
+    }
+
+    /// Absorbs `input` into the hash.
+    pub fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            let len = self.tail.len as usize;
+            let n = core::cmp::min(RATE - len, input.len());
+            self.tail.block[len..len + n].copy_from_slice(&input[..n]);
+            self.tail.len += n as u32;
+            input = &input[n..];
+            if self.tail.len as usize == RATE {
+                unsafe {
+                    syscall_keccak_absorb(
+                        &mut self.state,
+                        self.tail.block.as_ptr() as *const [u32; 34],
+                    );
+                }
+                self.tail.len = 0;
+            }
+        }
+    }
+
+    /// Pads and absorbs the remaining input, returning the Keccak-256 digest.
+    pub fn finalize(mut self) -> [u8; 32] {
+        unsafe {
+            syscall_keccak_finalize(&mut self.state, &self.tail as *const Tail as *const [u32; 35]);
+        }
+
+        let mut digest = [0u8; 32];
+        for (chunk, lane) in digest.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        digest
+    }
+}
+
+impl Default for KeccakSponge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the root of the Keccak-256 Merkle path of `leaf`, given the siblings from the bottom
+/// up, splitting the path into calls of at most [MERKLE_MAX_DEPTH] levels.
+///
+/// The `i`-th bit of `index` is set if the node is the right child at level `i`, in which case
+/// the level hashes the sibling followed by the node.
+pub fn keccak_merkle_root(leaf: &[u8; 32], index: u64, siblings: &[[u8; 32]]) -> [u8; 32] {
+    assert!(siblings.len() <= 64, "keccak merkle path of {} levels is too deep", siblings.len());
+
+    let mut state = [0u64; 25];
+    for (lane, chunk) in state.iter_mut().zip(leaf.chunks_exact(8)) {
+        *lane = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    let mut buffer = Vec::with_capacity(2 + 8 * MERKLE_MAX_DEPTH);
+    for (i, chunk) in siblings.chunks(MERKLE_MAX_DEPTH).enumerate() {
+        let index = (index >> (i * MERKLE_MAX_DEPTH)) as u32 & ((1 << chunk.len()) - 1);
+        buffer.clear();
+        buffer.push(chunk.len() as u32);
+        buffer.push(index);
+        for sibling in chunk {
+            buffer.extend(
+                sibling.chunks_exact(4).map(|word| u32::from_le_bytes(word.try_into().unwrap())),
+            );
+        }
+        unsafe {
+            syscall_keccak_merkle_root(&mut state, buffer.as_ptr());
+        }
+    }
+
+    let mut root = [0u8; 32];
+    for (chunk, lane) in root.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+    root
+}
+
+/// Returns whether `siblings` is a Keccak-256 Merkle proof that `leaf` is at `index` in the tree
+/// with the given `root`. See [`keccak_merkle_root`].
+pub fn verify_keccak_merkle_proof(
+    leaf: &[u8; 32],
+    index: u64,
+    siblings: &[[u8; 32]],
+    root: &[u8; 32],
+) -> bool {
+    keccak_merkle_root(leaf, index, siblings) == *root
+}
+
+/// Returns the root of the balanced Keccak-256 Merkle tree over `leaves`, such as the withdrawal
+/// tree of a batch, padding the leaves with zeros up to a power of two.
+///
+/// The tree is split into subtrees of at most [WITHDRAW_ROOT_MAX_DEPTH] levels, each hashed by a
+/// single call, and the roots of the subtrees are the leaves of the next levels. A single leaf is
+/// its own root, and the root of no leaves is zero.
+pub fn withdraw_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut nodes = leaves.to_vec();
+    nodes.resize(leaves.len().next_power_of_two(), [0; 32]);
+
+    let mut state = [0u64; 25];
+    let mut tree = Vec::with_capacity(1 + (8 << WITHDRAW_ROOT_MAX_DEPTH));
+    while nodes.len() > 1 {
+        let depth = (nodes.len().trailing_zeros() as usize).min(WITHDRAW_ROOT_MAX_DEPTH);
+        nodes = nodes
+            .chunks_exact(1 << depth)
+            .map(|subtree| {
+                tree.clear();
+                tree.push(depth as u32);
+                for leaf in subtree {
+                    tree.extend(
+                        leaf.chunks_exact(4)
+                            .map(|word| u32::from_le_bytes(word.try_into().unwrap())),
+                    );
+                }
+                unsafe {
+                    syscall_withdraw_root(&mut state, tree.as_mut_ptr());
+                }
+
+                let mut root = [0u8; 32];
+                for (chunk, word) in root.chunks_exact_mut(4).zip(&tree[1..9]) {
+                    chunk.copy_from_slice(&word.to_le_bytes());
+                }
+                root
+            })
+            .collect();
+    }
+    nodes.first().copied().unwrap_or_default()
+}
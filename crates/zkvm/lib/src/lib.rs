@@ -3,12 +3,28 @@
 //! Documentation for these syscalls can be found in the zkVM entrypoint
 //! `sp1_zkvm::syscalls` module.
 
+pub mod aes;
+pub mod baby_jubjub;
+pub mod base64;
 pub mod bls12381;
 pub mod bn254;
+pub mod ct;
 pub mod ed25519;
+pub mod env;
+pub mod f64;
+pub mod hex;
+pub mod hmac;
 pub mod io;
+pub mod jubjub;
+pub mod keccak;
+pub mod ntt;
+pub mod poseidon;
+pub mod rlp;
+pub mod rsa;
 pub mod secp256k1;
 pub mod secp256r1;
+pub mod sha256;
+pub mod sha512;
 pub mod unconstrained;
 pub mod utils;
 #[cfg(feature = "verify")]
@@ -18,6 +34,9 @@ extern "C" {
     /// Halts the program with the given exit code.
     pub fn syscall_halt(exit_code: u8) -> !;
 
+    /// Halts the program with an exit code that is committed to in the public values.
+    pub fn syscall_exit(exit_code: u32) -> !;
+
     /// Writes the bytes in the given buffer to the given file descriptor.
     pub fn syscall_write(fd: u32, write_buf: *const u8, nbytes: usize);
 
@@ -30,11 +49,23 @@ extern "C" {
     /// Executes the SHA-256 compress operation on the given word array and a given state.
     pub fn syscall_sha256_compress(w: *mut [u32; 64], state: *mut [u32; 8]);
 
+    /// Executes the SHA-512 compress operation on the given state and block.
+    pub fn syscall_sha512_compress(state: *mut [u64; 8], block: *const [u64; 16]);
+
     /// Executes an Ed25519 curve addition on the given points.
     pub fn syscall_ed_add(p: *mut [u32; 16], q: *const [u32; 16]);
 
-    /// Executes an Ed25519 curve decompression on the given point.
-    pub fn syscall_ed_decompress(point: &mut [u8; 64]);
+    /// Executes an Ed25519 curve decompression on the given point, returning 0 on success.
+    pub fn syscall_ed_decompress(point: &mut [u8; 64]) -> u32;
+
+    /// Checks the verification equation of an Ed25519 signature, returning 0 if it holds.
+    pub fn syscall_ed25519_verify(key: *const [u32; 16], sig: *const [u32; 32]) -> u32;
+
+    /// Executes a Jubjub curve addition on the given points.
+    pub fn syscall_jubjub_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Executes a Baby Jubjub curve addition on the given points.
+    pub fn syscall_baby_jubjub_add(p: *mut [u32; 16], q: *const [u32; 16]);
 
     /// Executes an Sepc256k1 curve addition on the given points.
     pub fn syscall_secp256k1_add(p: *mut [u32; 16], q: *const [u32; 16]);
@@ -42,8 +73,11 @@ extern "C" {
     /// Executes an Secp256k1 curve doubling on the given point.
     pub fn syscall_secp256k1_double(p: *mut [u32; 16]);
 
-    /// Executes an Secp256k1 curve decompression on the given point.
-    pub fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool);
+    /// Executes an Secp256k1 curve decompression on the given point, returning 0 on success.
+    pub fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool) -> u32;
+
+    /// Executes the EVM `ecrecover` precompile on Secp256k1, returning 0 on success.
+    pub fn syscall_secp256k1_ecrecover(out: *mut [u32; 8], input: *mut [u32; 82]) -> u32;
 
     /// Executes an Secp256r1 curve addition on the given points.
     pub fn syscall_secp256r1_add(p: *mut [u32; 16], q: *const [u32; 16]);
@@ -51,8 +85,8 @@ extern "C" {
     /// Executes an Secp256r1 curve doubling on the given point.
     pub fn syscall_secp256r1_double(p: *mut [u32; 16]);
 
-    /// Executes an Secp256r1 curve decompression on the given point.
-    pub fn syscall_secp256r1_decompress(point: &mut [u8; 64], is_odd: bool);
+    /// Executes an Secp256r1 curve decompression on the given point, returning 0 on success.
+    pub fn syscall_secp256r1_decompress(point: &mut [u8; 64], is_odd: bool) -> u32;
 
     /// Executes a Bn254 curve addition on the given points.
     pub fn syscall_bn254_add(p: *mut [u32; 16], q: *const [u32; 16]);
@@ -60,6 +94,45 @@ extern "C" {
     /// Executes a Bn254 curve doubling on the given point.
     pub fn syscall_bn254_double(p: *mut [u32; 16]);
 
+    /// Executes a Bn254 G1 point decompression on the given point, returning 0 on success.
+    pub fn syscall_bn254_decompress(point: &mut [u8; 64], sign_bit: bool) -> u32;
+
+    /// Executes a Bn254 G2 point decompression on the given point, returning 0 on success.
+    pub fn syscall_bn254_g2_decompress(point: &mut [u8; 128], sign_bit: bool) -> u32;
+
+    /// Converts a Bn254 scalar field element to Montgomery form.
+    pub fn syscall_bn254_scalar_to_mont(x: *mut [u32; 8]);
+
+    /// Converts a Bn254 scalar field element out of Montgomery form.
+    pub fn syscall_bn254_scalar_from_mont(x: *mut [u32; 8]);
+
+    /// Adds the inner product of a length-prefixed array of Bn254 scalar field element pairs to
+    /// the given element.
+    pub fn syscall_bn254_inner_product(x: *mut [u32; 8], pairs: *const u32);
+
+    /// Folds a length-prefixed array of Bn254 scalar field element pairs into the state of the
+    /// random-point check of an NTT.
+    pub fn syscall_fr_ntt(state: *mut [u32; 48], pairs: *const u32);
+
+    /// Copies 32 bytes from `src` to `dst`.
+    pub fn syscall_memcopy32(src: *const [u32; 8], dst: *mut [u32; 8]);
+
+    /// Copies 64 bytes from `src` to `dst`.
+    pub fn syscall_memcopy64(src: *const [u32; 16], dst: *mut [u32; 16]);
+
+    /// Keeps `a` if `cond` is 1 and replaces it with `b` if `cond` is 0.
+    pub fn syscall_csel256(a: *mut [u32; 8], b: *const [u32; 8], cond: u32);
+
+    /// Scans up to 16 items of an RLP payload from the offset of `state`, writing their offsets
+    /// and lengths to `table`.
+    pub fn syscall_rlp_scan(state: *mut [u32; 4], table: *mut [u32; 32]);
+
+    /// Replaces the node with the root of a length-prefixed Poseidon Merkle path.
+    pub fn syscall_poseidon_merkle_root(node: *mut [u32; 8], path: *const u32);
+
+    /// Writes the hash of the zkTrie node given by its type and its two children.
+    pub fn syscall_zktrie_hash_node(hash: *mut [u32; 8], node: *const u32);
+
     /// Executes a BLS12-381 curve addition on the given points.
     pub fn syscall_bls12381_add(p: *mut [u32; 24], q: *const [u32; 24]);
 
@@ -69,9 +142,31 @@ extern "C" {
     /// Executes the Keccak-256 permutation on the given state.
     pub fn syscall_keccak_permute(state: *mut [u64; 25]);
 
+    /// Absorbs a block into the Keccak-256 accumulator identified by the given control words.
+    pub fn syscall_keccak_accumulate(block: *const [u32; 34], acc: *mut [u32; 9]);
+
+    /// Absorbs a block into the given Keccak-256 sponge state.
+    pub fn syscall_keccak_absorb(state: *mut [u64; 25], block: *const [u32; 34]);
+
+    /// Pads and absorbs the tail of the input into the given Keccak-256 sponge state.
+    pub fn syscall_keccak_finalize(state: *mut [u64; 25], tail: *const [u32; 35]);
+
+    /// Replaces the node at the start of the given Keccak-256 state with the root of a
+    /// length-prefixed Merkle path.
+    pub fn syscall_keccak_merkle_root(state: *mut [u64; 25], path: *const u32);
+
+    /// Replaces the first leaf of a length-prefixed balanced Keccak-256 Merkle tree with its root.
+    pub fn syscall_withdraw_root(state: *mut [u64; 25], tree: *mut u32);
+
     /// Executes an uint256 multiplication on the given inputs.
     pub fn syscall_uint256_mulmod(x: *mut [u32; 8], y: *const [u32; 8]);
 
+    /// Executes a multiply-add over the BN254 scalar field on the given big-endian inputs.
+    pub fn syscall_bn254_muladd_be(x: *mut [u32; 8], y: *const [u32; 8]);
+
+    /// Executes a uint384 multiply-add modulo the BLS12-381 base field on the given inputs.
+    pub fn syscall_uint384_muladd(x: *mut [u32; 12], y: *const [u32; 24]);
+
     /// Executes a 256-bit by 2048-bit multiplication on the given inputs.
     pub fn syscall_u256x2048_mul(
         x: *const [u32; 8],
@@ -79,6 +174,54 @@ extern "C" {
         lo: *mut [u32; 64],
         hi: *mut [u32; 8],
     );
+
+    /// Executes a 256-bit by 4096-bit multiplication on the given inputs.
+    pub fn syscall_u256x4096_mul(
+        x: *const [u32; 8],
+        y: *const [u32; 128],
+        lo: *mut [u32; 128],
+        hi: *mut [u32; 8],
+    );
+
+    /// Raises the given base to the exponent that follows the RSA-2048 modulus in `params`.
+    pub fn syscall_rsa2048_modexp(x: *mut [u32; 64], params: *const [u32; 65]);
+
+    /// Raises the given base to the exponent that follows the RSA-4096 modulus in `params`.
+    pub fn syscall_rsa4096_modexp(x: *mut [u32; 128], params: *const [u32; 129]);
+
+    /// Reduces a 512-bit value modulo a 256-bit modulus.
+    pub fn syscall_barrett_reduce(x: *mut [u32; 16], m: *const [u32; 8]);
+
+    /// Applies an AES encryption round to the given state.
+    pub fn syscall_aes_enc_round(state: *mut [u32; 4], round_key: *const [u32; 4]);
+
+    /// Applies the final AES encryption round to the given state.
+    pub fn syscall_aes_enc_last_round(state: *mut [u32; 4], round_key: *const [u32; 4]);
+
+    /// Applies the ChaCha20 block function to the given state.
+    pub fn syscall_chacha20_block(state: *mut [u32; 16]);
+
+    /// Computes an HMAC-SHA256 tag over a single block message.
+    pub fn syscall_hmac_sha256(key: *const [u32; 16], buf: *mut [u32; 72]);
+
+    /// Compresses up to five consecutive blocks into a SHA-256 state.
+    pub fn syscall_sha256_compress_blocks(buf: *mut [u32; 73], blocks: *const [u32; 16]);
+
+    /// Adds two doubles, returning 1 if the result must be computed in software.
+    pub fn syscall_f64_add(x: *mut u64, y: *const u64) -> u32;
+
+    /// Multiplies two doubles, returning 1 if the result must be computed in software.
+    pub fn syscall_f64_mul(x: *mut u64, y: *const u64) -> u32;
+
+    /// Divides two doubles, returning 1 if the result must be computed in software.
+    pub fn syscall_f64_div(x: *mut u64, y: *const u64) -> u32;
+
+    /// Decodes 16 hexadecimal digits, returning 1 if a character is not a digit.
+    pub fn syscall_hex_decode(output: *mut [u32; 2], input: *const [u32; 4]) -> u32;
+
+    /// Decodes 16 base64 characters, returning 1 if a character is not in the alphabet.
+    pub fn syscall_base64_decode(output: *mut [u32; 3], input: *const [u32; 4]) -> u32;
+
     /// Enters unconstrained mode.
     pub fn syscall_enter_unconstrained() -> bool;
 
@@ -94,11 +237,20 @@ extern "C" {
     /// Reads the next element in the hint stream into the given buffer.
     pub fn syscall_hint_read(ptr: *mut u8, len: usize);
 
+    /// Maps the next element in the hint stream to read-only memory at the given address.
+    pub fn syscall_hint_map(ptr: *const u8, len: usize);
+
+    /// Declares `len` bytes starting at `ptr` as secret, to be zeroized before halting.
+    pub fn syscall_zeroize_on_halt(ptr: *const u8, len: usize);
+
+    /// Writes the digest of the public values committed so far, returning their length in bytes.
+    pub fn sys_public_values_digest(digest: *mut [u8; 32]) -> usize;
+
     /// Allocates a buffer aligned to the given alignment.
     pub fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u8;
 
-    /// Decompresses a BLS12-381 point.
-    pub fn syscall_bls12381_decompress(point: &mut [u8; 96], is_odd: bool);
+    /// Decompresses a BLS12-381 point, returning 0 on success.
+    pub fn syscall_bls12381_decompress(point: &mut [u8; 96], is_odd: bool) -> u32;
 
     /// Computes a big integer operation with a modulus.
     pub fn sys_bigint(
@@ -136,6 +288,9 @@ extern "C" {
     /// Executes a BLS12-381 Fp2 multiplication on the given inputs.
     pub fn syscall_bls12381_fp2_mulmod(p: *mut u32, q: *const u32);
 
+    /// Executes a BLS12-381 Fp6 multiplication on the given inputs.
+    pub fn syscall_bls12381_fp6_mulmod(p: *mut u32, q: *const u32);
+
     /// Executes a BN254 field addition on the given inputs.
     pub fn syscall_bn254_fp_addmod(p: *mut u32, q: *const u32);
 
@@ -154,4 +309,16 @@ extern "C" {
     /// Executes a BN254 Fp2 multiplication on the given inputs.
     pub fn syscall_bn254_fp2_mulmod(p: *mut u32, q: *const u32);
 
+    /// Executes a BN254 Fp6 multiplication on the given inputs.
+    pub fn syscall_bn254_fp6_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a BN254 Fp12 multiplication on the given inputs.
+    pub fn syscall_bn254_fp12_mul(p: *mut u32, q: *const u32);
+
+    /// Executes a BN254 Fp12 squaring on the given input.
+    pub fn syscall_bn254_fp12_square(p: *mut u32);
+
+    /// Executes a BN254 Fp12 inversion on the given input.
+    pub fn syscall_bn254_fp12_inverse(p: *mut u32);
+
 }
@@ -0,0 +1,239 @@
+//! NTTs over the BN254 scalar field `Fr`, checked by the `FR_NTT` precompile.
+//!
+//! The evaluations of a polynomial over a radix-2 domain are computed by the host, and the guest
+//! only checks them against the coefficients at a random point, in `O(n)` instead of the
+//! `O(n log n)` field multiplications of computing the NTT itself.
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    bn254::bn254_inner_product,
+    io::{read_vec, write, FR_NTT_HOOK},
+    syscall_fr_ntt,
+};
+
+/// The two-adicity of `Fr`: `2^28` divides `r - 1`, so the largest radix-2 domain has `2^28`
+/// elements.
+pub const FR_TWO_ADICITY: usize = 28;
+
+/// The maximum number of `(c, y)` pairs of a single `syscall_fr_ntt` call.
+pub const FR_NTT_MAX_LEN: usize = 32;
+
+/// The modulus of `Fr`, as little endian words.
+const FR_MODULUS: [u32; 8] = [
+    0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// The primitive `2^k`-th roots of unity of `Fr`, as little endian words, for every `k` up to the
+/// two-adicity.
+pub const FR_ROOTS_OF_UNITY: [[u32; 8]; FR_TWO_ADICITY + 1] = [
+    [
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000,
+    ],
+    [
+        0xf0000000, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029,
+        0x30644e72,
+    ],
+    [
+        0x8f703636, 0x23120470, 0xfd736bec, 0x5cea24f6, 0x3fd84104, 0x048b6e19, 0xe131a029,
+        0x30644e72,
+    ],
+    [
+        0xc1bd5e80, 0x948dad4a, 0xf8170a0a, 0x52627366, 0x96afef36, 0xec9b9e2f, 0xc8c14f22,
+        0x2b337de1,
+    ],
+    [
+        0xe306460b, 0xb11509c6, 0x174efb98, 0x996dfbe1, 0x94dd508c, 0x1c6e4f45, 0x16cbbf4e,
+        0x21082ca2,
+    ],
+    [
+        0x3bb512d0, 0x3eed4c53, 0x838eeb1d, 0x9c18d51b, 0x47c0b2a9, 0x9678200d, 0x306b93d2,
+        0x09c532c6,
+    ],
+    [
+        0x118f023a, 0xdb94fb05, 0x26e324be, 0x46a6cb24, 0x49bdadf2, 0xc24cdb76, 0x5b080fca,
+        0x1418144d,
+    ],
+    [
+        0xba9d1811, 0x9d0e470c, 0xb6f24c79, 0x1dcb5564, 0xe85943e0, 0xdf5ce19c, 0xad310991,
+        0x16e73dfd,
+    ],
+    [
+        0x74a57a76, 0xc8936191, 0x6750f230, 0x61794254, 0x9f36ffb0, 0xf086204a, 0xa6148404,
+        0x07b0c561,
+    ],
+    [
+        0x470157ce, 0x893a7fa1, 0xfc782d75, 0xe8302a41, 0xdd9b0675, 0xffc02c0e, 0xf6e72f5b,
+        0x0f1ded1e,
+    ],
+    [
+        0xbc2e5912, 0x11f995e1, 0xa8d2d7ab, 0x39ba79c0, 0xb08771e3, 0xebbebc2b, 0x7017a420,
+        0x06fd19c1,
+    ],
+    [
+        0x769a2ee2, 0xd00a58f9, 0x7494f0ca, 0xb8c12c17, 0xa5355d71, 0xb4027fd7, 0x99c5042b,
+        0x027a3584,
+    ],
+    [
+        0x0042d43a, 0x1c477572, 0x6f039bb9, 0x76f169c7, 0xfd5a90a9, 0x01ddd073, 0xde2fd10f,
+        0x0931d596,
+    ],
+    [
+        0x9bbdd310, 0x4aa49b8d, 0x8e3a2d76, 0xd31bf3e2, 0x78b2667b, 0x001deac8, 0xb869ae62,
+        0x006fab49,
+    ],
+    [
+        0x617c6e85, 0xadaa01c2, 0x7420aae6, 0xb4a93ee1, 0x0ddca8a8, 0x1f4e51b8, 0xcdd9e481,
+        0x2d965651,
+    ],
+    [
+        0x4e26ecfb, 0xa93458fd, 0x4115a009, 0x022a2a2d, 0x69ec2bd0, 0x017171fa, 0x5941dc91,
+        0x2d1ba66f,
+    ],
+    [
+        0xdaac43b7, 0xd1628ba2, 0xe4347e7d, 0x16c8601d, 0xe081dcff, 0x649abebd, 0x5981ed45,
+        0x00eeb2cb,
+    ],
+    [
+        0xce8f58e5, 0x276e5858, 0x5655210e, 0x0512eca9, 0xe70e61f3, 0xc3708cc6, 0xa7d74902,
+        0x1bf82deb,
+    ],
+    [
+        0x7dcdc0e0, 0x84c6bfa5, 0x13f4d1bd, 0xc57088ff, 0xb5b95e4d, 0x5c0176fb, 0x3a8d46c1,
+        0x19ddbcaf,
+    ],
+    [
+        0x613f6cbd, 0x5c1d597f, 0x8357473a, 0x30525841, 0x968e4915, 0x51829353, 0x844bca52,
+        0x2260e724,
+    ],
+    [
+        0x53337857, 0x53422da9, 0xdbed349f, 0xac616632, 0x06d1e303, 0x27508aba, 0x0a0ed063,
+        0x26125da1,
+    ],
+    [
+        0xfcd0b523, 0xb2c87885, 0xca5a5ce3, 0x58f50577, 0x8598fc8c, 0x4222150e, 0xae2bdd1a,
+        0x1ded8980,
+    ],
+    [
+        0xa219447e, 0xa76dde56, 0x359eebbb, 0xec1a1f05, 0x8be08215, 0xcda0ceb6, 0xb1f8d9a7,
+        0x1ad92f46,
+    ],
+    [
+        0xab80c59d, 0xb54d4506, 0x22dd991f, 0x5680c640, 0xbc23a139, 0x6b7bcf70, 0x5ab4c74d,
+        0x0210fe63,
+    ],
+    [
+        0xe32b045b, 0x1c25f1e3, 0x2e832696, 0x145e0db8, 0x71c6441f, 0x852e2a03, 0x845d50d2,
+        0x0c9fabc7,
+    ],
+    [
+        0xb878331a, 0xeccd4f3e, 0x8dc6d26e, 0x7b26b748, 0xd9130cd4, 0xa19b0361, 0x326341ef,
+        0x2a734ebb,
+    ],
+    [
+        0x2f4e9212, 0x1c79bd57, 0x3d68f9ae, 0x605b52b6, 0xb8d89d4a, 0x0113eff9, 0xf1ff73b2,
+        0x1067569a,
+    ],
+    [
+        0x80928c44, 0x034afc45, 0xf6437da2, 0xb4823532, 0x6dc6e364, 0x5f256a9f, 0xb363ebe8,
+        0x049ae702,
+    ],
+    [
+        0x725b19f0, 0x9bd61b6e, 0x41112ed4, 0x402d111e, 0x8ef62abc, 0x00e0a7eb, 0xa58a7e85,
+        0x2a3c09f0,
+    ],
+];
+
+/// A primitive `2^log_n`-th root of unity of `Fr`, which generates the domain of an NTT of
+/// `2^log_n` elements.
+///
+/// ### Panics
+/// Panics if `log_n` exceeds [FR_TWO_ADICITY], as `Fr` has no such root.
+pub fn fr_root_of_unity(log_n: usize) -> [u32; 8] {
+    assert!(
+        log_n <= FR_TWO_ADICITY,
+        "domain of size 2^{log_n} exceeds the two-adicity {FR_TWO_ADICITY} of the bn254 scalar field"
+    );
+    FR_ROOTS_OF_UNITY[log_n]
+}
+
+/// Whether the little endian words hold an element of `Fr` in canonical form.
+fn is_reduced(x: &[u32; 8]) -> bool {
+    x.iter().rev().cmp(FR_MODULUS.iter().rev()).is_lt()
+}
+
+/// Returns the NTT of the coefficients of a polynomial over `Fr`, as little endian words: its
+/// evaluations at the successive powers of [fr_root_of_unity] of the domain of the same size.
+///
+/// The evaluations are supplied by the host, and checked against the coefficients at a point
+/// derived from the hash of both, with the `FR_NTT` precompile.
+///
+/// ### Panics
+/// Panics if the number of coefficients is not a power of two, exceeds the largest domain, or if
+/// the evaluations supplied by the host are wrong.
+pub fn fr_ntt(coeffs: &[[u32; 8]]) -> Vec<[u32; 8]> {
+    let n = coeffs.len();
+    assert!(n.is_power_of_two(), "ntt size {n} is not a power of two");
+    let w = fr_root_of_unity(n.trailing_zeros() as usize);
+
+    // Ask the host for the evaluations.
+    let coeff_bytes =
+        coeffs.iter().flatten().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>();
+    write(FR_NTT_HOOK, &coeff_bytes);
+    let eval_bytes = read_vec();
+    assert_eq!(eval_bytes.len(), coeff_bytes.len(), "ntt hint has the wrong length");
+    let evals = eval_bytes
+        .chunks_exact(32)
+        .map(|bytes| {
+            let eval = core::array::from_fn(|i| {
+                u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap())
+            });
+            assert!(is_reduced(&eval), "ntt hint is not reduced");
+            eval
+        })
+        .collect::<Vec<[u32; 8]>>();
+
+    // The point is bound to both the coefficients and the evaluations, and below `2^253 < r`.
+    let mut hasher = Sha256::new();
+    hasher.update(&coeff_bytes);
+    hasher.update(&eval_bytes);
+    let mut point = [0u8; 32];
+    point.copy_from_slice(&hasher.finalize());
+    point[31] &= 0x1f;
+    let r: [u32; 8] =
+        core::array::from_fn(|i| u32::from_le_bytes(point[4 * i..4 * i + 4].try_into().unwrap()));
+
+    // Fold the pairs into the sums `c_i * r^i` and `y_i * w^i / (r - w^i)`.
+    let mut one = [0u32; 8];
+    one[0] = 1;
+    let mut state = [0u32; 48];
+    for (i, value) in [r, w, one, one].iter().enumerate() {
+        state[8 * i..8 * i + 8].copy_from_slice(value);
+    }
+    let mut buffer = Vec::with_capacity(1 + 16 * FR_NTT_MAX_LEN);
+    for (c, y) in coeffs.chunks(FR_NTT_MAX_LEN).zip(evals.chunks(FR_NTT_MAX_LEN)) {
+        buffer.clear();
+        buffer.push(c.len() as u32);
+        for (c_i, y_i) in c.iter().zip(y) {
+            buffer.extend_from_slice(c_i);
+            buffer.extend_from_slice(y_i);
+        }
+        unsafe {
+            syscall_fr_ntt(&mut state, buffer.as_ptr());
+        }
+    }
+
+    // By the barycentric formula, `n * P(r) = (r^n - 1) * sum(y_i * w^i / (r - w^i))`.
+    let word = |i: usize| -> [u32; 8] { state[8 * i..8 * i + 8].try_into().unwrap() };
+    let (r_pow, acc_c, acc_y) = (word(2), word(4), word(5));
+    let mut size = [0u32; 8];
+    size[0] = n as u32;
+    let mut lhs = acc_y;
+    bn254_inner_product(&mut lhs, &[(acc_c, size)]);
+    let mut rhs = [0u32; 8];
+    bn254_inner_product(&mut rhs, &[(acc_y, r_pow)]);
+    assert_eq!(lhs, rhs, "ntt hint does not match the coefficients");
+
+    evals
+}
@@ -0,0 +1,59 @@
+use crate::{syscall_poseidon_merkle_root, syscall_zktrie_hash_node};
+
+/// The type of a zkTrie leaf node, whose children are the node key and the value hash.
+pub const ZKTRIE_NODE_TYPE_LEAF: u32 = 4;
+
+/// The types of the zkTrie branch nodes, telling which of the two children are branches.
+pub const ZKTRIE_NODE_TYPE_BRANCHES: [u32; 4] = [6, 7, 8, 9];
+
+/// The maximum number of levels of a single `syscall_poseidon_merkle_root` call.
+pub const MERKLE_MAX_DEPTH: usize = 8;
+
+/// A level of a Poseidon Merkle path, with the elements as little endian words.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MerkleLevel {
+    /// The sibling of the node at this level.
+    pub sibling: [u32; 8],
+    /// The domain separator of the hash of this level.
+    pub domain: [u32; 8],
+    /// Whether the node is the right child at this level.
+    pub is_right: bool,
+}
+
+/// Replaces `node` with the root of the Poseidon Merkle path of its levels from the bottom up,
+/// splitting the path into calls of at most [MERKLE_MAX_DEPTH] levels.
+pub fn poseidon_merkle_root(node: &mut [u32; 8], path: &[MerkleLevel]) {
+    let mut buffer = Vec::with_capacity(2 + 16 * MERKLE_MAX_DEPTH);
+    for chunk in path.chunks(MERKLE_MAX_DEPTH) {
+        let index = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |index, (i, level)| index | ((level.is_right as u32) << i));
+        buffer.clear();
+        buffer.push(chunk.len() as u32);
+        buffer.push(index);
+        for level in chunk {
+            buffer.extend_from_slice(&level.sibling);
+            buffer.extend_from_slice(&level.domain);
+        }
+        unsafe {
+            syscall_poseidon_merkle_root(node, buffer.as_ptr());
+        }
+    }
+}
+
+/// Returns the hash of a zkTrie node of the given type from its two children, using the type as
+/// the domain of the Poseidon hash.
+///
+/// The type must be [ZKTRIE_NODE_TYPE_LEAF] or one of [ZKTRIE_NODE_TYPE_BRANCHES].
+pub fn zktrie_hash_node(node_type: u32, left: &[u32; 8], right: &[u32; 8]) -> [u32; 8] {
+    let mut node = [0u32; 17];
+    node[0] = node_type;
+    node[1..9].copy_from_slice(left);
+    node[9..].copy_from_slice(right);
+    let mut hash = [0u32; 8];
+    unsafe {
+        syscall_zktrie_hash_node(&mut hash, node.as_ptr());
+    }
+    hash
+}
@@ -0,0 +1,81 @@
+//! Scanning of RLP payloads accelerated by the `RLP_SCAN` precompile.
+//!
+//! The precompile checks the framing of the items of a payload, i.e. that their headers are in
+//! canonical form and that they end within the payload, and returns the offset and the length of
+//! the payload of every item. Decoders then only read the items from the verified table, and
+//! descend into lists by scanning their payloads in turn.
+
+use crate::syscall_rlp_scan;
+
+/// The maximum number of items scanned by a single call to the precompile.
+pub const RLP_SCAN_MAX_ITEMS: usize = 16;
+
+/// The top byte of the length of an entry of the table, which flags lists.
+const LIST_FLAG_SHIFT: u32 = 24;
+
+/// An item of an RLP payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RlpItem {
+    /// The offset of the payload of the item, past its header.
+    pub offset: usize,
+    /// The length of the payload of the item.
+    pub len: usize,
+    /// Whether the item is a list, whose payload holds the encodings of its items.
+    pub is_list: bool,
+}
+
+impl RlpItem {
+    /// The payload of the item within `data`, the payload it was scanned from.
+    pub fn payload<'a>(&self, data: &'a [u8]) -> &'a [u8] {
+        &data[self.offset..self.offset + self.len]
+    }
+}
+
+/// Scans the items of an RLP payload, such as the payload of a list.
+///
+/// The payload is read in place if it is aligned along a four byte boundary, and copied to an
+/// aligned buffer otherwise.
+///
+/// ### Panics
+/// Panics if an item is not in canonical form, has a payload of `2^24` bytes or more, or overruns
+/// the payload.
+pub fn scan(payload: &[u8]) -> Vec<RlpItem> {
+    assert!(payload.len() < 1 << 24, "rlp payload of {} bytes is too long", payload.len());
+    if payload.is_empty() {
+        return Vec::new();
+    }
+
+    // The precompile reads the words around the headers, which may go past the end of the
+    // payload. The memory of the zkVM is readable past an aligned payload, and the copy of an
+    // unaligned payload has a word of padding.
+    let words: Vec<u32>;
+    let payload_ptr = if payload.as_ptr() as usize % 4 == 0 {
+        payload.as_ptr() as usize
+    } else {
+        let mut buffer = vec![0u32; payload.len() / 4 + 2];
+        for (word, chunk) in buffer.iter_mut().zip(payload.chunks(4)) {
+            let mut bytes = [0u8; 4];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            *word = u32::from_le_bytes(bytes);
+        }
+        words = buffer;
+        words.as_ptr() as usize
+    };
+
+    let mut state = [payload_ptr as u32, payload.len() as u32, 0, 0];
+    let mut table = [0u32; 2 * RLP_SCAN_MAX_ITEMS];
+    let mut items = Vec::new();
+    while (state[2] as usize) < payload.len() {
+        unsafe {
+            syscall_rlp_scan(&mut state, &mut table);
+        }
+        for entry in table.chunks_exact(2).take(state[3] as usize) {
+            items.push(RlpItem {
+                offset: entry[0] as usize,
+                len: (entry[1] & ((1 << LIST_FLAG_SHIFT) - 1)) as usize,
+                is_list: entry[1] >> LIST_FLAG_SHIFT != 0,
+            });
+        }
+    }
+    items
+}
@@ -0,0 +1,161 @@
+use crate::{hmac::sha256, syscall_rsa2048_modexp, syscall_rsa4096_modexp};
+
+/// The number of steps of the exponentiation ladder of a single modexp precompile call.
+pub const MAX_LADDER_STEPS: u32 = 17;
+
+/// The DER encoding of the `DigestInfo` of a SHA-256 digest, which precedes the digest in a
+/// PKCS#1 v1.5 signature.
+const SHA256_DIGEST_INFO: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+/// The SHA-256 digest size in bytes.
+const DIGEST_LEN: usize = 32;
+
+/// Raises the big-endian `base` to `exponent` modulo the big-endian `modulus`, with the
+/// `RSA2048_MODEXP` precompile for a 256 byte modulus and the `RSA4096_MODEXP` precompile for a
+/// 512 byte one.
+///
+/// Returns the big-endian result, with as many bytes as the modulus, or `None` if the base does not
+/// have as many bytes as the modulus or is not smaller than it.
+///
+/// Panics if the modulus has another size, or if the ladder of the exponent takes more than
+/// [`MAX_LADDER_STEPS`] steps, which holds for the common exponents 3, 17, 257 and 65537.
+pub fn rsa_modexp(base: &[u8], modulus: &[u8], exponent: u32) -> Option<Vec<u8>> {
+    assert!(
+        exponent >= 2
+            && (31 - exponent.leading_zeros()) + (exponent.count_ones() - 1) <= MAX_LADDER_STEPS,
+        "unsupported RSA exponent {exponent}"
+    );
+    if base.len() != modulus.len() || base >= modulus {
+        return None;
+    }
+    match modulus.len() {
+        256 => Some(modexp::<64, 65>(base, modulus, exponent, syscall_rsa2048_modexp)),
+        512 => Some(modexp::<128, 129>(base, modulus, exponent, syscall_rsa4096_modexp)),
+        len => panic!("unsupported RSA modulus of {len} bytes"),
+    }
+}
+
+/// Lays out the arguments of a modexp precompile call as little-endian words, and converts the
+/// result back to big-endian bytes.
+fn modexp<const N: usize, const M: usize>(
+    base: &[u8],
+    modulus: &[u8],
+    exponent: u32,
+    syscall: unsafe extern "C" fn(*mut [u32; N], *const [u32; M]),
+) -> Vec<u8> {
+    let mut x = [0u32; N];
+    let mut params = [0u32; M];
+    for (word, chunk) in x.iter_mut().zip(base.rchunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    for (word, chunk) in params.iter_mut().zip(modulus.rchunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    params[N] = exponent;
+
+    unsafe {
+        syscall(&mut x, &params);
+    }
+
+    x.iter().rev().flat_map(|word| word.to_be_bytes()).collect()
+}
+
+/// Verifies a PKCS#1 v1.5 signature of `message` with SHA-256, under the public key of the
+/// big-endian `modulus` and `exponent`. See [`rsa_modexp`] for the supported keys.
+pub fn verify_pkcs1v15_sha256(
+    modulus: &[u8],
+    exponent: u32,
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    let Some(encoded) = rsa_modexp(signature, modulus, exponent) else {
+        return false;
+    };
+
+    // The encoded message is `0x00 || 0x01 || 0xff.. || 0x00 || DigestInfo || digest`.
+    let padding_len = encoded.len() - SHA256_DIGEST_INFO.len() - DIGEST_LEN - 3;
+    let (header, rest) = encoded.split_at(2);
+    let (padding, rest) = rest.split_at(padding_len);
+    let (separator, rest) = rest.split_at(1);
+    let (digest_info, digest) = rest.split_at(SHA256_DIGEST_INFO.len());
+    header == [0x00, 0x01]
+        && padding.iter().all(|&byte| byte == 0xff)
+        && separator == [0x00]
+        && digest_info == SHA256_DIGEST_INFO
+        && digest == sha256_bytes(&[message])
+}
+
+/// Verifies a PSS signature of `message` with SHA-256 and MGF1 with SHA-256, under the public key
+/// of the big-endian `modulus` and `exponent`. The salt may have any length. See [`rsa_modexp`] for
+/// the supported keys.
+pub fn verify_pss_sha256(modulus: &[u8], exponent: u32, message: &[u8], signature: &[u8]) -> bool {
+    let Some(encoded) = rsa_modexp(signature, modulus, exponent) else {
+        return false;
+    };
+
+    // The encoded message has one bit less than the modulus, and is one byte shorter if that bit
+    // is the only one of its byte.
+    let modulus_bits = modulus.len() * 8 - modulus[0].leading_zeros() as usize;
+    let encoded_bits = modulus_bits - 1;
+    let encoded = if encoded_bits % 8 == 0 {
+        if encoded[0] != 0 {
+            return false;
+        }
+        &encoded[1..]
+    } else {
+        &encoded[..]
+    };
+    if encoded.len() < DIGEST_LEN + 2 || encoded[encoded.len() - 1] != 0xbc {
+        return false;
+    }
+
+    // The encoded message is `masked_db || hash || 0xbc`.
+    let (masked_db, rest) = encoded.split_at(encoded.len() - DIGEST_LEN - 1);
+    let hash = &rest[..DIGEST_LEN];
+    let unused_bits = 8 * encoded.len() - encoded_bits;
+    if masked_db[0] & !(0xff >> unused_bits) != 0 {
+        return false;
+    }
+
+    // Unmask `db`, which is `0x00.. || 0x01 || salt`.
+    let mut db = masked_db.to_vec();
+    for (byte, mask) in db.iter_mut().zip(mgf1_sha256(hash, masked_db.len())) {
+        *byte ^= mask;
+    }
+    db[0] &= 0xff >> unused_bits;
+    let Some(separator) = db.iter().position(|&byte| byte != 0) else {
+        return false;
+    };
+    if db[separator] != 0x01 {
+        return false;
+    }
+    let salt = &db[separator + 1..];
+
+    let digest = sha256_bytes(&[message]);
+    sha256_bytes(&[&[0u8; 8], &digest, salt]) == hash
+}
+
+/// The mask generation function MGF1 with SHA-256.
+fn mgf1_sha256(seed: &[u8], len: usize) -> Vec<u8> {
+    let mut mask = Vec::with_capacity(len + DIGEST_LEN);
+    let mut counter = 0u32;
+    while mask.len() < len {
+        mask.extend_from_slice(&sha256_bytes(&[seed, &counter.to_be_bytes()]));
+        counter += 1;
+    }
+    mask.truncate(len);
+    mask
+}
+
+/// Hashes the concatenation of `parts` with SHA-256.
+fn sha256_bytes(parts: &[&[u8]]) -> [u8; DIGEST_LEN] {
+    let digest = sha256(None, &parts.concat());
+    let mut out = [0u8; DIGEST_LEN];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(digest.iter()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
@@ -1,6 +1,11 @@
 use crate::{
-    syscall_secp256k1_add, syscall_secp256k1_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    keccak::KeccakSponge,
+    syscall_secp256k1_add, syscall_secp256k1_decompress, syscall_secp256k1_double,
+    syscall_secp256k1_ecrecover, syscall_uint256_mulmod,
+    utils::{
+        words_to_bytes_le, AffinePoint, MulAssignError, SyscallError, WeierstrassAffinePoint,
+        WeierstrassPoint,
+    },
 };
 
 /// The number of limbs in [Secp256k1Point].
@@ -51,6 +56,38 @@ impl AffinePoint<N> for Secp256k1Point {
         self.weierstrass_add_assign(other);
     }
 
+    /// Multiplies `self` by the given scalar, splitting it with [`Secp256k1Point::glv_split`].
+    ///
+    /// A nonzero scalar which is a multiple of the group order gives the point at infinity.
+    fn mul_assign(&mut self, scalar: &[u32]) -> Result<(), MulAssignError> {
+        debug_assert!(scalar.len() == N / 2);
+
+        if scalar.iter().all(|&word| word == 0) {
+            return Err(MulAssignError::ScalarIsZero);
+        }
+        let scalar: [u32; N / 2] = scalar.try_into().unwrap();
+        *self = Self::glv_multi_scalar_multiplication(&[(*self, scalar)])
+            .unwrap_or_else(Self::infinity);
+        Ok(())
+    }
+
+    /// Computes `a * a_bits + b * b_bits`, splitting both scalars with
+    /// [`Secp256k1Point::glv_split`].
+    fn multi_scalar_multiplication(
+        a_bits_le: &[bool],
+        a: Self,
+        b_bits_le: &[bool],
+        b: Self,
+    ) -> Option<Self> {
+        // The length of the bit vectors must be the same.
+        debug_assert!(a_bits_le.len() == b_bits_le.len());
+
+        Self::glv_multi_scalar_multiplication(&[
+            (a, bits_to_words(a_bits_le)),
+            (b, bits_to_words(b_bits_le)),
+        ])
+    }
+
     fn add_assign(&mut self, other: &Self) {
         let a = self.limbs_mut();
         let b = other.limbs_ref();
@@ -68,3 +105,347 @@ impl AffinePoint<N> for Secp256k1Point {
         }
     }
 }
+
+/// The order of the Secp256k1 group.
+const ORDER: [u32; 8] = [
+    0xd0364141, 0xbfd25e8c, 0xaf48a03b, 0xbaaedce6, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+
+/// The order of the Secp256k1 group, divided by two.
+const HALF_ORDER: [u32; 8] = [
+    0x681b20a0, 0xdfe92f46, 0x57a4501d, 0x5d576e73, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+];
+
+/// The modulus of the Secp256k1 base field.
+const MODULUS: [u32; 8] = [
+    0xfffffc2f, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+
+/// The cube root of unity in the base field that defines the endomorphism `(x, y) -> (beta * x, y)`.
+const BETA: [u32; 8] = [
+    0x719501ee, 0xc1396c28, 0x12f58995, 0x9cf04975, 0xac3434e9, 0x6e64479e, 0x657c0710, 0x7ae96a2b,
+];
+
+/// The negation of the cube root of unity `lambda` in the scalar field, by which the endomorphism
+/// multiplies points.
+const MINUS_LAMBDA: [u32; 8] = [
+    0xb51283cf, 0xe0cfc810, 0x8ec739c2, 0xa880b9fc, 0x77ed9ba4, 0x5ad9e3fd, 0x3fa3cf1f, 0xac9c52b3,
+];
+
+/// The negations of the second coordinates `b1` and `b2` of the short basis of the GLV lattice.
+const MINUS_B1: [u32; 8] = [0x0abfe4c3, 0x6f547fa9, 0x010e8828, 0xe4437ed6, 0, 0, 0, 0];
+const MINUS_B2: [u32; 8] = [
+    0x3db1562c, 0xd765cda8, 0x0774346d, 0x8a280ac5, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff,
+];
+
+/// `round(2^384 * b2 / n)` and `round(2^384 * -b1 / n)`, which approximate the coefficients of a
+/// scalar in the basis of the GLV lattice.
+const G1: [u32; 8] = [
+    0x45dbb031, 0xe893209a, 0x71e8ca7f, 0x3daa8a14, 0x9284eb15, 0xe86c90e4, 0xa7d46bcd, 0x3086d221,
+];
+const G2: [u32; 8] = [
+    0x8ac47f71, 0x1571b4ae, 0x9df506c6, 0x221208ac, 0x0abfe4c4, 0x6f547fa9, 0x010e8828, 0xe4437ed6,
+];
+
+/// The number of bits of the halves of a split scalar.
+const HALF_SCALAR_BITS: usize = 128;
+
+/// A scalar split by [`Secp256k1Point::glv_split`] into `k1 + k2 * lambda`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GlvScalar {
+    /// The absolute value of `k1`.
+    pub k1: [u32; 4],
+    /// Whether `k1` is negative.
+    pub k1_neg: bool,
+    /// The absolute value of `k2`.
+    pub k2: [u32; 4],
+    /// Whether `k2` is negative.
+    pub k2_neg: bool,
+}
+
+impl Secp256k1Point {
+    /// Applies the endomorphism `(x, y) -> (beta * x, y)`, which multiplies the point by `lambda`.
+    pub fn endomorphism(&self) -> Self {
+        match &self.0 {
+            WeierstrassPoint::Infinity => *self,
+            WeierstrassPoint::Affine(limbs) => {
+                let mut limbs = *limbs;
+                let x: &mut [u32; 8] = (&mut limbs[..N / 2]).try_into().unwrap();
+                *x = mulmod(x, &BETA, &MODULUS);
+                Self::new(limbs)
+            }
+        }
+    }
+
+    /// Negates the point.
+    pub fn negate(&self) -> Self {
+        match &self.0 {
+            WeierstrassPoint::Infinity => *self,
+            WeierstrassPoint::Affine(limbs) => {
+                let mut limbs = *limbs;
+                let y: &mut [u32; 8] = (&mut limbs[N / 2..]).try_into().unwrap();
+                if y.iter().any(|&word| word != 0) {
+                    *y = sub(&MODULUS, y).0;
+                }
+                Self::new(limbs)
+            }
+        }
+    }
+
+    /// Splits a scalar `k` into `k1 + k2 * lambda` modulo the group order, where `k1` and `k2` are
+    /// less than `2^128` in absolute value.
+    ///
+    /// This follows `secp256k1_scalar_split_lambda` of libsecp256k1.
+    pub fn glv_split(scalar: &[u32; 8]) -> GlvScalar {
+        let (reduced, borrow) = sub(scalar, &ORDER);
+        let k = if borrow { *scalar } else { reduced };
+
+        let c1 = mulmod(&mul_shift_384(&k, &G1), &MINUS_B1, &ORDER);
+        let c2 = mulmod(&mul_shift_384(&k, &G2), &MINUS_B2, &ORDER);
+        let k2 = add_mod(&c1, &c2, &ORDER);
+        let k1 = add_mod(&mulmod(&k2, &MINUS_LAMBDA, &ORDER), &k, &ORDER);
+
+        let (k1, k1_neg) = abs(k1);
+        let (k2, k2_neg) = abs(k2);
+        GlvScalar { k1, k1_neg, k2, k2_neg }
+    }
+
+    /// Computes the sum of the points multiplied by their scalars, or `None` if it is the point at
+    /// infinity.
+    ///
+    /// Each scalar is split with [`Secp256k1Point::glv_split`], and the resulting half-width
+    /// multiplications share a single chain of 128 doublings. At each step, a single addition of a
+    /// precomputed sum of the points adds the bits of all the halves.
+    pub fn glv_multi_scalar_multiplication(terms: &[(Self, [u32; 8])]) -> Option<Self> {
+        let mut points = Vec::with_capacity(2 * terms.len());
+        let mut scalars = Vec::with_capacity(2 * terms.len());
+        for (point, scalar) in terms {
+            if point.is_infinity() {
+                continue;
+            }
+            let split = Self::glv_split(scalar);
+            let point = *point;
+            points.push(if split.k1_neg { point.negate() } else { point });
+            scalars.push(split.k1);
+            let point = point.endomorphism();
+            points.push(if split.k2_neg { point.negate() } else { point });
+            scalars.push(split.k2);
+        }
+
+        // The sums of all the subsets of the points, indexed by the bitmask of the subset.
+        let mut table = vec![Self::infinity(); 1 << points.len()];
+        for mask in 1..table.len() {
+            let lowest = mask.trailing_zeros() as usize;
+            let mut sum = table[mask & (mask - 1)];
+            sum.complete_add_assign(&points[lowest]);
+            table[mask] = sum;
+        }
+
+        let mut res = Self::infinity();
+        for i in (0..HALF_SCALAR_BITS).rev() {
+            res.double();
+            let mask = scalars
+                .iter()
+                .enumerate()
+                .fold(0, |mask, (j, scalar)| mask | (((scalar[i / 32] >> (i % 32)) & 1) << j));
+            if mask != 0 {
+                res.complete_add_assign(&table[mask as usize]);
+            }
+        }
+
+        (!res.is_infinity()).then_some(res)
+    }
+}
+
+/// Packs little endian bits into the words of a scalar.
+fn bits_to_words(bits_le: &[bool]) -> [u32; 8] {
+    debug_assert!(bits_le.len() <= 256);
+
+    let mut words = [0u32; 8];
+    for (i, &bit) in bits_le.iter().enumerate() {
+        words[i / 32] |= (bit as u32) << (i % 32);
+    }
+    words
+}
+
+/// Computes `x * y` modulo the modulus with the `UINT256_MUL` precompile.
+fn mulmod(x: &[u32; 8], y: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    // The modulus is read after `y`.
+    let mut y_and_modulus = [0u32; 16];
+    y_and_modulus[..8].copy_from_slice(y);
+    y_and_modulus[8..].copy_from_slice(modulus);
+
+    let mut result = *x;
+    unsafe {
+        syscall_uint256_mulmod(&mut result, y_and_modulus.as_ptr() as *const [u32; 8]);
+    }
+    result
+}
+
+/// Computes `a + b`, returning the carry.
+fn add(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], bool) {
+    let mut result = [0u32; 8];
+    let mut carry = false;
+    for i in 0..8 {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u32);
+        result[i] = sum;
+        carry = c1 || c2;
+    }
+    (result, carry)
+}
+
+/// Computes `a - b`, returning the borrow.
+fn sub(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], bool) {
+    let mut result = [0u32; 8];
+    let mut borrow = false;
+    for i in 0..8 {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u32);
+        result[i] = diff;
+        borrow = b1 || b2;
+    }
+    (result, borrow)
+}
+
+/// Computes `a + b` modulo the modulus, for `a` and `b` less than the modulus.
+fn add_mod(a: &[u32; 8], b: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let (sum, carry) = add(a, b);
+    let (reduced, borrow) = sub(&sum, modulus);
+    if carry || !borrow {
+        reduced
+    } else {
+        sum
+    }
+}
+
+/// Computes `round(a * b / 2^384)`.
+fn mul_shift_384(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let mut product = [0u32; 16];
+    for i in 0..8 {
+        let mut carry = 0u64;
+        for j in 0..8 {
+            let t = product[i + j] as u64 + a[i] as u64 * b[j] as u64 + carry;
+            product[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        product[i + 8] = carry as u32;
+    }
+
+    let mut result = [0u32; 8];
+    result[..4].copy_from_slice(&product[12..]);
+    let round = [product[11] >> 31, 0, 0, 0, 0, 0, 0, 0];
+    add(&result, &round).0
+}
+
+/// Interprets a scalar greater than half of the group order as negative, returning the absolute
+/// value and whether it is negative.
+fn abs(scalar: [u32; 8]) -> ([u32; 4], bool) {
+    let neg = sub(&HALF_ORDER, &scalar).1;
+    let scalar = if neg { sub(&ORDER, &scalar).0 } else { scalar };
+    debug_assert!(scalar[4..].iter().all(|&word| word == 0));
+    (scalar[..4].try_into().unwrap(), neg)
+}
+
+/// Decompresses a point with the `SECP256K1_DECOMPRESS` precompile.
+///
+/// The first half of `point` is the big-endian X coordinate, and the second half is overwritten
+/// with the big-endian Y coordinate whose parity is `is_odd`. Fails if there is no point with the
+/// X coordinate, in which case the second half is left unchanged.
+pub fn decompress(point: &mut [u8; 64], is_odd: bool) -> Result<(), SyscallError> {
+    SyscallError::InvalidPoint.check(unsafe { syscall_secp256k1_decompress(point, is_odd) })
+}
+
+/// The number of words of the input of the `SECP256K1_ECRECOVER` precompile, followed by the
+/// keccak state it hashes the public key with.
+const ECRECOVER_BUFFER_WORDS: usize = 32 + 50;
+
+/// Recovers the address of the key that signed a hash, with the semantics of the EVM `ecrecover`
+/// precompile, whose input is the hash, `v`, `r` and `s` as 32-byte big-endian integers.
+///
+/// Returns `None` if the signature is invalid. The address is recovered by the
+/// `SECP256K1_ECRECOVER` precompile, and the few keys it cannot recover are recovered with the
+/// other precompiles.
+pub fn ecrecover(input: &[u8; 128]) -> Option<[u8; 20]> {
+    let mut buffer = [0u32; ECRECOVER_BUFFER_WORDS];
+    for (word, chunk) in buffer.iter_mut().zip(input.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    let mut out = [0u32; 8];
+    if unsafe { syscall_secp256k1_ecrecover(&mut out, &mut buffer) } == 0 {
+        return Some(words_to_bytes_le(&out)[12..].try_into().unwrap());
+    }
+    ecrecover_fallback(input)
+}
+
+/// Recovers the address of the key that signed a hash without the `SECP256K1_ECRECOVER`
+/// precompile, as `keccak(u1 * G + u2 * R)` with `u1 = -z / r` and `u2 = s / r`.
+fn ecrecover_fallback(input: &[u8; 128]) -> Option<[u8; 20]> {
+    if input[32..63].iter().any(|&byte| byte != 0) {
+        return None;
+    }
+    let recid = match input[63] {
+        27 => false,
+        28 => true,
+        _ => return None,
+    };
+    let z = be_bytes_to_words(&input[..32]);
+    let r = be_bytes_to_words(&input[64..96]);
+    let s = be_bytes_to_words(&input[96..]);
+    let zero = [0u32; 8];
+    if r == zero || s == zero || !sub(&r, &ORDER).1 || !sub(&s, &ORDER).1 {
+        return None;
+    }
+
+    // Decompress `R`, whose y-coordinate has the parity of `recid`.
+    let mut point = [0u8; 64];
+    point[..32].copy_from_slice(&input[64..96]);
+    decompress(&mut point, recid).ok()?;
+    let mut limbs = [0u32; N];
+    limbs[..N / 2].copy_from_slice(&r);
+    limbs[N / 2..].copy_from_slice(&be_bytes_to_words(&point[32..]));
+    let big_r = Secp256k1Point::new(limbs);
+
+    let r_inverse = inverse_mod_order(&r);
+    let (reduced, borrow) = sub(&z, &ORDER);
+    let z = if borrow { z } else { reduced };
+    let neg_z = if z == zero { zero } else { sub(&ORDER, &z).0 };
+    let u1 = mulmod(&neg_z, &r_inverse, &ORDER);
+    let u2 = mulmod(&s, &r_inverse, &ORDER);
+
+    let generator = Secp256k1Point::new(Secp256k1Point::GENERATOR);
+    let key = Secp256k1Point::glv_multi_scalar_multiplication(&[(generator, u1), (big_r, u2)])?;
+
+    // The address is the last 20 bytes of the hash of the uncompressed key, without its prefix.
+    let mut public_key = [0u8; 64];
+    for (chunk, word) in public_key.chunks_exact_mut(32).zip(key.limbs_ref().chunks_exact(N / 2)) {
+        for (bytes, limb) in chunk.chunks_exact_mut(4).zip(word.iter().rev()) {
+            bytes.copy_from_slice(&limb.to_be_bytes());
+        }
+    }
+    let mut sponge = KeccakSponge::new();
+    sponge.update(&public_key);
+    Some(sponge.finalize()[12..].try_into().unwrap())
+}
+
+/// Converts a 32-byte big-endian integer into little-endian words.
+fn be_bytes_to_words(bytes: &[u8]) -> [u32; 8] {
+    let mut words = [0u32; 8];
+    for (word, chunk) in words.iter_mut().zip(bytes.rchunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+/// Computes the inverse of a nonzero scalar modulo the group order, as `x^(n - 2)`.
+fn inverse_mod_order(x: &[u32; 8]) -> [u32; 8] {
+    let exponent = sub(&ORDER, &[2, 0, 0, 0, 0, 0, 0, 0]).0;
+    let mut result = [1, 0, 0, 0, 0, 0, 0, 0];
+    for i in (0..256).rev() {
+        result = mulmod(&result, &result, &ORDER);
+        if (exponent[i / 32] >> (i % 32)) & 1 == 1 {
+            result = mulmod(&result, x, &ORDER);
+        }
+    }
+    result
+}
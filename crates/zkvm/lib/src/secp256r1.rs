@@ -1,6 +1,6 @@
 use crate::{
-    syscall_secp256r1_add, syscall_secp256r1_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    syscall_secp256r1_add, syscall_secp256r1_decompress, syscall_secp256r1_double,
+    utils::{AffinePoint, SyscallError, WeierstrassAffinePoint, WeierstrassPoint},
 };
 
 /// The number of limbs in [Secp256r1Point].
@@ -68,3 +68,12 @@ impl AffinePoint<N> for Secp256r1Point {
         }
     }
 }
+
+/// Decompresses a point with the `SECP256R1_DECOMPRESS` precompile.
+///
+/// The first half of `point` is the big-endian X coordinate, and the second half is overwritten
+/// with the big-endian Y coordinate whose parity is `is_odd`. Fails if there is no point with the
+/// X coordinate, in which case the second half is left unchanged.
+pub fn decompress(point: &mut [u8; 64], is_odd: bool) -> Result<(), SyscallError> {
+    SyscallError::InvalidPoint.check(unsafe { syscall_secp256r1_decompress(point, is_odd) })
+}
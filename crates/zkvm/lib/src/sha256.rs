@@ -0,0 +1,21 @@
+use crate::syscall_sha256_compress_blocks;
+
+/// The largest number of blocks compressed by a single call to the `SHA256_COMPRESS_BLOCKS`
+/// precompile.
+pub const MAX_BLOCKS_PER_CALL: usize = 5;
+
+/// Compresses consecutive blocks, given as big-endian words, into a SHA-256 state.
+///
+/// The blocks are compressed by the `SHA256_COMPRESS_BLOCKS` precompile, up to
+/// [`MAX_BLOCKS_PER_CALL`] blocks at a time.
+pub fn compress_blocks(state: &mut [u32; 8], blocks: &[[u32; 16]]) {
+    let mut buf = [0u32; 73];
+    buf[64..72].copy_from_slice(state);
+    for chunk in blocks.chunks(MAX_BLOCKS_PER_CALL) {
+        buf[72] = chunk.len() as u32;
+        unsafe {
+            syscall_sha256_compress_blocks(&mut buf, chunk.as_ptr());
+        }
+    }
+    state.copy_from_slice(&buf[64..72]);
+}
@@ -0,0 +1,90 @@
+use crate::syscall_sha512_compress;
+
+/// The SHA-512 block size in bytes.
+const BLOCK: usize = 128;
+
+/// The SHA-512 initial hash value.
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// A SHA-512 hasher backed by the `SHA512_COMPRESS` precompile.
+///
+/// Only the input of the current block is buffered while streaming.
+#[derive(Clone)]
+pub struct Sha512 {
+    state: [u64; 8],
+    buffer: [u8; BLOCK],
+    len: usize,
+    total: u128,
+}
+
+impl Sha512 {
+    /// Creates a new, empty hasher.
+    pub fn new() -> Self {
+        Self { state: IV, buffer: [0; BLOCK], len: 0, total: 0 }
+    }
+
+    /// Absorbs `input` into the hash.
+    pub fn update(&mut self, mut input: &[u8]) {
+        self.total += input.len() as u128;
+        while !input.is_empty() {
+            let n = core::cmp::min(BLOCK - self.len, input.len());
+            self.buffer[self.len..self.len + n].copy_from_slice(&input[..n]);
+            self.len += n;
+            input = &input[n..];
+            if self.len == BLOCK {
+                self.compress();
+            }
+        }
+    }
+
+    /// Pads and compresses the remaining input, returning the SHA-512 digest.
+    pub fn finalize(mut self) -> [u8; 64] {
+        let bits = self.total * 8;
+        self.buffer[self.len] = 0x80;
+        if self.len >= BLOCK - 16 {
+            self.compress();
+        }
+        self.buffer[BLOCK - 16..].copy_from_slice(&bits.to_be_bytes());
+        self.compress();
+
+        let mut digest = [0u8; 64];
+        for (chunk, word) in digest.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    fn compress(&mut self) {
+        let mut block = [0u64; 16];
+        for (word, chunk) in block.iter_mut().zip(self.buffer.chunks_exact(8)) {
+            *word = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+        unsafe {
+            syscall_sha512_compress(&mut self.state, &block);
+        }
+        self.buffer = [0; BLOCK];
+        self.len = 0;
+    }
+}
+
+impl Default for Sha512 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hashes `data` with SHA-512.
+pub fn sha512(data: &[u8]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    hasher.update(data);
+    hasher.finalize()
+}
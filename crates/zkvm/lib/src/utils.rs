@@ -127,6 +127,33 @@ pub enum MulAssignError {
     ScalarIsZero,
 }
 
+/// Errors returned by the syscalls that return a status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyscallError {
+    /// There is no point on the curve with the given coordinate.
+    InvalidPoint,
+}
+
+impl SyscallError {
+    /// Converts the status returned by a syscall into a result, failing with `self`.
+    pub(crate) fn check(self, status: u32) -> Result<(), Self> {
+        match status {
+            0 => Ok(()),
+            _ => Err(self),
+        }
+    }
+}
+
+impl core::fmt::Display for SyscallError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SyscallError::InvalidPoint => write!(f, "there is no point with the given coordinate"),
+        }
+    }
+}
+
+impl std::error::Error for SyscallError {}
+
 /// Converts a slice of words to a byte array in little endian.
 pub fn words_to_bytes_le(words: &[u32]) -> Vec<u8> {
     words.iter().flat_map(|word| word.to_le_bytes().to_vec()).collect::<Vec<_>>()
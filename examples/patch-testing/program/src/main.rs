@@ -13,6 +13,10 @@ use ed25519_dalek::{
     Signature as Ed25519DalekSignature, Verifier, VerifyingKey as Ed25519DalekVerifyingKey,
 };
 
+use rand_chacha::{
+    rand_core::{RngCore, SeedableRng},
+    ChaCha20Rng,
+};
 use sha2_v0_10_6::{Digest as Digest_10_6, Sha256 as Sha256_10_6};
 // use sha2_v0_10_8::{Digest as Digest_10_8, Sha256 as Sha256_10_8};
 use secp256k1::{
@@ -101,6 +105,40 @@ fn test_keccak() {
     assert_eq!(output, expected_output);
 }
 
+/// Emits CHACHA20_BLOCK syscalls.
+fn test_chacha20() {
+    // RFC 7539 appendix A.1, test vector #1.
+    let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+    let output: [u32; 4] = core::array::from_fn(|_| rng.next_u32());
+    assert_eq!(output, [0xade0b876, 0x903df1a0, 0xe56a5d40, 0x28bd8653]);
+}
+
+/// Emits HEX_DECODE syscalls.
+fn test_hex() {
+    // 38 digits, so that two blocks are decoded by the precompile and the last 6 digits in software.
+    let input = "48656c6c6f2c20776f726c6421204b49574921";
+    let expected_output = b"Hello, world! KIWI!";
+
+    let output = hex_v0_4_3::decode(input).unwrap();
+    assert_eq!(output, expected_output);
+
+    let mut output = [0u8; 19];
+    hex_v0_4_3::decode_to_slice(input.to_uppercase(), &mut output).unwrap();
+    assert_eq!(&output, expected_output);
+
+    // The errors match the software decoding, including the index of an invalid digit inside a
+    // block.
+    assert_eq!(
+        hex_v0_4_3::decode("48656c6c6g2c20776f726c6421"),
+        Err(hex_v0_4_3::FromHexError::InvalidHexCharacter { c: 'g', index: 9 })
+    );
+    assert_eq!(hex_v0_4_3::decode("123"), Err(hex_v0_4_3::FromHexError::OddLength));
+    assert_eq!(
+        hex_v0_4_3::decode_to_slice("48656c6c", &mut output),
+        Err(hex_v0_4_3::FromHexError::InvalidStringLength)
+    );
+}
+
 /// Emits SHA_COMPRESS and SHA_EXTEND syscalls.
 fn test_sha256() {
     let input = [1u8; 32];
@@ -148,7 +186,7 @@ fn test_p256_patch() {
     }
 }
 
-/// Emits SECP256K1_ADD, SECP256K1_DOUBLE, and SECP256K1_DECOMPRESS syscalls.
+/// Emits SECP256K1_ADD, SECP256K1_DOUBLE, SECP256K1_DECOMPRESS and SECP256K1_ECRECOVER syscalls.
 /// Source: https://github.com/alloy-rs/core/blob/adcf7adfa1f35c56e6331bab85b8c56d32a465f1/crates/primitives/src/signature/sig.rs#L620-L631
 fn test_k256_patch() {
     // A valid signature.
@@ -218,12 +256,50 @@ fn test_secp256k1_patch() {
     assert_eq!(hex::encode(serialized_key), expected);
 }
 
+/// Emits SECP256K1_ECRECOVER syscalls.
+///
+/// Runs the ecrecover vectors read from the input through revm's `ecrecover` precompile, whose keys
+/// are recovered by the patched `k256`, and through the `ecrecover` of `sp1-lib`. Checks that they
+/// agree, and commits the outputs of revm so that the script can compare them with the outputs of
+/// revm on the host.
+fn test_ecrecover_vectors() {
+    let vectors = sp1_zkvm::io::read::<Vec<Vec<u8>>>();
+
+    let mut outputs = Vec::with_capacity(vectors.len());
+    for vector in vectors {
+        println!("cycle-tracker-start: k256 ecrecover");
+        let output: Vec<u8> =
+            revm_precompile::secp256k1::ec_recover_run(&vector.clone().into(), u64::MAX)
+                .map(|output| output.bytes.to_vec())
+                .unwrap_or_default();
+        println!("cycle-tracker-end: k256 ecrecover");
+
+        // Like the EVM, the input is padded with zeroes or truncated to 128 bytes.
+        let mut input = [0u8; 128];
+        let len = vector.len().min(128);
+        input[..len].copy_from_slice(&vector[..len]);
+
+        println!("cycle-tracker-start: secp256k1 ecrecover");
+        let expected: Vec<u8> = sp1_zkvm::lib::secp256k1::ecrecover(&input)
+            .map(|address| [[0u8; 12].as_slice(), &address].concat())
+            .unwrap_or_default();
+        println!("cycle-tracker-end: secp256k1 ecrecover");
+
+        assert_eq!(output, expected, "ecrecover mismatch for input {}", hex::encode(&vector));
+        outputs.push(output);
+    }
+
+    sp1_zkvm::io::commit(&outputs);
+}
+
 /// To add testing for a new patch, add a new case to the function below.
 pub fn main() {
     // TODO: Specify which syscalls are linked to each function invocation, iterate
     // over this list that is shared between the program and script.
     test_keccak();
     test_sha256();
+    test_chacha20();
+    test_hex();
 
     test_curve25519_dalek_ng();
     test_curve25519_dalek();
@@ -234,4 +310,6 @@ pub fn main() {
     test_k256_patch();
     test_secp256k1_patch();
     test_p256_patch();
+
+    test_ecrecover_vectors();
 }
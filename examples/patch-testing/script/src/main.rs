@@ -2,14 +2,53 @@ use sp1_sdk::{include_elf, utils, ProverClient, SP1Stdin};
 
 const PATCH_TEST_ELF: &[u8] = include_elf!("patch-testing-program");
 
+/// Inputs of the EVM `ecrecover` precompile, covering valid signatures, high `s` values, invalid
+/// `v`, `r` and `s` values, unrecoverable keys and malformed lengths.
+const ECRECOVER_VECTORS: &[&str] = &[
+    // Valid signatures.
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549",
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001b73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f1146bf4e2fc4de1c94f1b81868960f68bc842490f1b6bc98387ba6b57599fbf8",
+    "a79c77e94d0cd778e606e61130d9065e718eced9408e63df3a71919d5830d82d000000000000000000000000000000000000000000000000000000000000001cd685e79fb0b7ff849cbc6283dd1174b4a06f2aa556f019169a99396fc052b42e2c0ff35d08662f2685929c20ce8eaab568a404d61cf2aa837f1f431e2aef6211",
+    "15499a876f0d57fdc360c760aec98245eba1902610140c14d5f0c3c0284e28a7000000000000000000000000000000000000000000000000000000000000001c2106219ec2e5ef9f7d5ffb303fac05c4066e66db6d501d2e5b1626f2cc8fbe1c316d4e90b09819db9c261017f18e1b5b105855922ec962fd58e83c943e4c4ba3",
+    // Unrecoverable key, as there is no point with x = r.
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001b00000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000001",
+    // Invalid `v`.
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001d73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549",
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000011c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549",
+    // `r` and `s` out of range.
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c0000000000000000000000000000000000000000000000000000000000000000eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549",
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f0000000000000000000000000000000000000000000000000000000000000000",
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001cfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549",
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75ffffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+    // Truncated and oversized inputs.
+    "",
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f",
+    "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549ffff",
+];
+
 /// This script is used to test that SP1 patches are correctly applied and syscalls are triggered.
 pub fn main() {
     utils::setup_logger();
 
-    let stdin = SP1Stdin::new();
+    let vectors: Vec<Vec<u8>> =
+        ECRECOVER_VECTORS.iter().map(|vector| hex::decode(vector).unwrap()).collect();
+    let mut stdin = SP1Stdin::new();
+    stdin.write(&vectors);
 
     let client = ProverClient::new();
-    let (_, report) = client.execute(PATCH_TEST_ELF, stdin).run().expect("executing failed");
+    let (mut public_values, report) =
+        client.execute(PATCH_TEST_ELF, stdin).run().expect("executing failed");
+
+    // Confirm the patched ecrecover matches revm on the host.
+    let outputs = public_values.read::<Vec<Vec<u8>>>();
+    assert_eq!(outputs.len(), vectors.len());
+    for (vector, output) in vectors.iter().zip(outputs) {
+        let expected: Vec<u8> =
+            revm_precompile::secp256k1::ec_recover_run(&vector.clone().into(), u64::MAX)
+                .map(|output| output.bytes.to_vec())
+                .unwrap_or_default();
+        assert_eq!(output, expected, "ecrecover mismatch for input {}", hex::encode(vector));
+    }
 
     // Confirm there was at least 1 SHA_COMPUTE syscall.
     assert_ne!(report.syscall_counts[sp1_core_executor::syscalls::SyscallCode::SHA_COMPRESS], 0);
@@ -22,6 +61,12 @@ pub fn main() {
     // Confirm there was at least 1 KECCAK_PERMUTE syscall.
     assert_ne!(report.syscall_counts[sp1_core_executor::syscalls::SyscallCode::KECCAK_PERMUTE], 0);
 
+    // Confirm there was at least 1 CHACHA20_BLOCK syscall.
+    assert_ne!(report.syscall_counts[sp1_core_executor::syscalls::SyscallCode::CHACHA20_BLOCK], 0);
+
+    // Confirm there was at least 1 HEX_DECODE syscall.
+    assert_ne!(report.syscall_counts[sp1_core_executor::syscalls::SyscallCode::HEX_DECODE], 0);
+
     // Confirm there was at least 1 SECP256K1_ADD, SECP256K1_DOUBLE and SECP256K1_DECOMPRESS syscall.
     assert_ne!(report.syscall_counts[sp1_core_executor::syscalls::SyscallCode::SECP256K1_ADD], 0);
     assert_ne!(
@@ -40,6 +85,13 @@ pub fn main() {
         0
     );
 
+    // Confirm the patched `k256` made SECP256K1_ECRECOVER syscalls, on top of the single syscall
+    // per vector of the direct calls to `sp1_lib::secp256k1::ecrecover`.
+    assert!(
+        report.syscall_counts[sp1_core_executor::syscalls::SyscallCode::SECP256K1_ECRECOVER]
+            > vectors.len() as u64
+    );
+
     println!("Total instructions: {:?}", report.total_instruction_count());
     println!("Successfully executed the program & confirmed syscalls.");
 }
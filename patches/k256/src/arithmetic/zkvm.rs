@@ -0,0 +1,78 @@
+//! Group operations accelerated by the precompiles of the SP1 zkVM.
+//!
+//! The points of `sp1-lib` are affine, with limbs holding the little-endian words of the
+//! coordinates, whereas the field elements of this crate are encoded in big-endian.
+
+use super::{AffinePoint, FieldElement, ProjectivePoint, Scalar};
+use crate::FieldBytes;
+use alloc::vec::Vec;
+use elliptic_curve::subtle::{Choice, CtOption};
+use sp1_lib::{
+    secp256k1::Secp256k1Point,
+    utils::{AffinePoint as _, WeierstrassAffinePoint},
+};
+
+/// The number of terms of a single multi-scalar multiplication of `sp1-lib`, whose table of
+/// precomputed sums has `2^(2 * n)` entries for `n` terms.
+const LINCOMB_CHUNK: usize = 2;
+
+/// Converts a point to a point of `sp1-lib`.
+fn to_sp1_point(point: &AffinePoint) -> Secp256k1Point {
+    if point.infinity == 1 {
+        return Secp256k1Point::infinity();
+    }
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&point.x.to_bytes());
+    bytes[32..].copy_from_slice(&point.y.to_bytes());
+    bytes[..32].reverse();
+    bytes[32..].reverse();
+    Secp256k1Point::from_le_bytes(&bytes)
+}
+
+/// Converts a point of `sp1-lib`, whose coordinates are reduced by the precompiles, to a point.
+fn from_sp1_point(point: &Secp256k1Point) -> AffinePoint {
+    if point.is_infinity() {
+        return AffinePoint::IDENTITY;
+    }
+    let mut bytes = point.to_le_bytes();
+    bytes[..32].reverse();
+    bytes[32..].reverse();
+    let x = FieldElement::from_bytes(FieldBytes::from_slice(&bytes[..32])).unwrap();
+    let y = FieldElement::from_bytes(FieldBytes::from_slice(&bytes[32..])).unwrap();
+    AffinePoint::new(x, y)
+}
+
+/// Converts a scalar to its little-endian words.
+fn to_words(scalar: &Scalar) -> [u32; 8] {
+    let bytes = scalar.to_bytes();
+    core::array::from_fn(|i| u32::from_be_bytes(bytes[28 - 4 * i..32 - 4 * i].try_into().unwrap()))
+}
+
+/// Computes `sum(k * x)` with the GLV multi-scalar multiplication of `sp1-lib`, which adds and
+/// doubles with the `SECP256K1_ADD` and `SECP256K1_DOUBLE` precompiles.
+///
+/// The terms are multiplied two at a time, so that the tables of precomputed sums stay small.
+pub(crate) fn lincomb(xks: &[(ProjectivePoint, Scalar)]) -> ProjectivePoint {
+    let mut acc = Secp256k1Point::infinity();
+    for chunk in xks.chunks(LINCOMB_CHUNK) {
+        let terms = chunk
+            .iter()
+            .map(|(x, k)| (to_sp1_point(&x.to_affine()), to_words(k)))
+            .collect::<Vec<_>>();
+        if let Some(sum) = Secp256k1Point::glv_multi_scalar_multiplication(&terms) {
+            acc.complete_add_assign(&sum);
+        }
+    }
+    from_sp1_point(&acc).into()
+}
+
+/// Decompresses a point with the `SECP256K1_DECOMPRESS` precompile.
+pub(crate) fn decompress(x_bytes: &FieldBytes, y_is_odd: Choice) -> CtOption<AffinePoint> {
+    FieldElement::from_bytes(x_bytes).and_then(|x| {
+        let mut point = [0u8; 64];
+        point[..32].copy_from_slice(x_bytes);
+        let is_some = sp1_lib::secp256k1::decompress(&mut point, y_is_odd.into()).is_ok();
+        let y = FieldElement::from_bytes(FieldBytes::from_slice(&point[32..])).unwrap();
+        CtOption::new(AffinePoint::new(x, y), Choice::from(is_some as u8))
+    })
+}
@@ -0,0 +1,159 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![allow(clippy::needless_range_loop)]
+#![forbid(unsafe_code)]
+#![warn(
+    clippy::mod_module_files,
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! ## `serde` support
+//!
+//! When the `serde` feature of this crate is enabled, `Serialize` and
+//! `Deserialize` are impl'd for the following types:
+//!
+//! - [`AffinePoint`]
+//! - [`Scalar`]
+//! - [`ecdsa::VerifyingKey`]
+//!
+//! Please see type-specific documentation for more information.
+
+#[cfg(any(feature = "alloc", target_os = "zkvm"))]
+#[allow(unused_imports)]
+#[macro_use]
+extern crate alloc;
+
+#[cfg(feature = "arithmetic")]
+mod arithmetic;
+
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+
+#[cfg(feature = "ecdsa-core")]
+pub mod ecdsa;
+
+#[cfg(feature = "schnorr")]
+pub mod schnorr;
+
+#[cfg(any(feature = "test-vectors", test))]
+pub mod test_vectors;
+
+pub use elliptic_curve::{self, bigint::U256};
+
+#[cfg(feature = "arithmetic")]
+pub use arithmetic::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
+
+#[cfg(feature = "expose-field")]
+pub use arithmetic::FieldElement;
+
+#[cfg(feature = "pkcs8")]
+pub use elliptic_curve::pkcs8;
+
+#[cfg(feature = "sha2")]
+pub use sha2;
+
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    consts::{U32, U33, U64},
+    generic_array::GenericArray,
+    FieldBytesEncoding,
+};
+
+/// Order of the secp256k1 elliptic curve in hexadecimal.
+const ORDER_HEX: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
+
+/// Order of the secp256k1 elliptic curve.
+const ORDER: U256 = U256::from_be_hex(ORDER_HEX);
+
+/// secp256k1 (K-256) elliptic curve.
+///
+/// Specified in Certicom's SECG in "SEC 2: Recommended Elliptic Curve Domain Parameters":
+///
+/// <https://www.secg.org/sec2-v2.pdf>
+///
+/// The curve's equation is `y² = x³ + 7` over a ~256-bit prime field.
+///
+/// It's primarily notable for usage in Bitcoin and other cryptocurrencies,
+/// particularly in conjunction with the Elliptic Curve Digital Signature
+/// Algorithm (ECDSA).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Secp256k1;
+
+impl elliptic_curve::Curve for Secp256k1 {
+    /// 32-byte serialized field elements.
+    type FieldBytesSize = U32;
+
+    /// 256-bit field modulus.
+    type Uint = U256;
+
+    /// Curve order.
+    const ORDER: U256 = ORDER;
+}
+
+impl elliptic_curve::PrimeCurve for Secp256k1 {}
+
+impl elliptic_curve::point::PointCompression for Secp256k1 {
+    /// secp256k1 points are typically compressed.
+    const COMPRESS_POINTS: bool = true;
+}
+
+#[cfg(feature = "jwk")]
+impl elliptic_curve::JwkParameters for Secp256k1 {
+    const CRV: &'static str = "secp256k1";
+}
+
+#[cfg(feature = "pkcs8")]
+impl pkcs8::AssociatedOid for Secp256k1 {
+    const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.3.132.0.10");
+}
+
+/// Compressed SEC1-encoded secp256k1 (K-256) curve point.
+pub type CompressedPoint = GenericArray<u8, U33>;
+
+/// SEC1-encoded secp256k1 (K-256) curve point.
+pub type EncodedPoint = elliptic_curve::sec1::EncodedPoint<Secp256k1>;
+
+/// secp256k1 (K-256) field element serialized as bytes.
+///
+/// Byte array containing a serialized field element value (base field or scalar).
+pub type FieldBytes = elliptic_curve::FieldBytes<Secp256k1>;
+
+impl FieldBytesEncoding<Secp256k1> for U256 {
+    fn decode_field_bytes(field_bytes: &FieldBytes) -> Self {
+        U256::from_be_byte_array(*field_bytes)
+    }
+
+    fn encode_field_bytes(&self) -> FieldBytes {
+        self.to_be_byte_array()
+    }
+}
+
+/// Bytes used by a wide reduction: twice the width of [`FieldBytes`].
+pub type WideBytes = GenericArray<u8, U64>;
+
+/// Non-zero secp256k1 (K-256) scalar field element.
+#[cfg(feature = "arithmetic")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<Secp256k1>;
+
+/// secp256k1 (K-256) public key.
+#[cfg(feature = "arithmetic")]
+pub type PublicKey = elliptic_curve::PublicKey<Secp256k1>;
+
+/// secp256k1 (K-256) secret key.
+pub type SecretKey = elliptic_curve::SecretKey<Secp256k1>;
+
+#[cfg(not(feature = "arithmetic"))]
+impl elliptic_curve::sec1::ValidatePublicKey for Secp256k1 {}
+
+/// Bit representation of a secp256k1 (K-256) scalar field element.
+#[cfg(feature = "bits")]
+pub type ScalarBits = elliptic_curve::scalar::ScalarBits<Secp256k1>;